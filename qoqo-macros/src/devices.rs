@@ -48,7 +48,7 @@ pub fn device_wrapper_def(
             /// choice from a list of edges and can be used for applications like routing in quantum algorithms.
             ///
             /// Returns:
-            ///     Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
+            ///     List[Tuple[int, int]]: List of two qubit edges in the undirected connectivity graph
             ///
             pub fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
                 self.internal.two_qubit_edges()
@@ -125,6 +125,34 @@ pub fn device_wrapper_def(
                 self.internal.multi_qubit_gate_time(hqslang, &qubits)
             }
 
+            /// Returns the calibrated error rate of a single qubit operation if it is known for the device.
+            ///
+            /// Args:
+            ///     hqslang (str): The hqslang name of a single qubit gate.
+            ///     qubit (int): The qubit the gate acts on
+            ///
+            /// Returns:
+            ///     Option[float]: None if no error rate is known for the gate
+            #[pyo3(text_signature = "(gate, qubit)")]
+            pub fn single_qubit_gate_error(&self, hqslang: &str, qubit: usize) -> Option<f64> {
+                self.internal.single_qubit_gate_error(hqslang, &qubit)
+            }
+
+            /// Returns the calibrated error rate of a two qubit operation if it is known for the device.
+            ///
+            /// Args:
+            ///     hqslang (str): The hqslang name of a two qubit gate.
+            ///     control (int): The control qubit the gate acts on.
+            ///     target (int): The target qubit the gate acts on.
+            ///
+            /// Returns:
+            ///     Option[float]: None if no error rate is known for the gate
+            #[pyo3(text_signature = "(gate, control, target)")]
+            pub fn two_qubit_gate_error(&self, hqslang: &str, control: usize, target: usize) -> Option<f64> {
+                self.internal
+                    .two_qubit_gate_error(hqslang, &control, &target)
+            }
+
             /// Set the gate time of a single qubit gate.
             ///
             /// Args:
@@ -173,6 +201,41 @@ pub fn device_wrapper_def(
                     PyValueError::new_err(format!("{:?}", err)))
             }
 
+            /// Set the calibrated error rate of a single qubit gate.
+            ///
+            /// Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+            ///
+            /// Args:
+            ///     gate (str): hqslang name of the single-qubit-gate.
+            ///     qubit (int): The qubit for which the gate error is set
+            ///     error (float): The error rate of the gate, between 0 and 1.
+            ///
+            /// Raises:
+            ///     PyValueError: Qubit is not in device or error is not between 0 and 1
+            #[pyo3(text_signature = "(gate, qubit, error)")]
+            pub fn set_single_qubit_gate_error(&mut self, gate: &str, qubit: usize, error: f64) -> PyResult<()> {
+                self.internal.set_single_qubit_gate_error(gate, qubit, error).map_err(|err|
+                    PyValueError::new_err(format!("{:?}", err)))
+            }
+
+            /// Set the calibrated error rate of a two qubit gate.
+            ///
+            /// Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+            ///
+            /// Args:
+            ///     gate (str): hqslang name of the two-qubit-gate.
+            ///     control (int): The control qubit for which the gate error is set
+            ///     target (int): The target qubit for which the gate error is set
+            ///     error (float): The error rate of the gate, between 0 and 1.
+            ///
+            /// Raises:
+            ///     PyValueError: Qubits are not in device or error is not between 0 and 1
+            #[pyo3(text_signature = "(gate, control, target, error)")]
+            pub fn set_two_qubit_gate_error(&mut self, gate: &str, control: usize, target: usize, error: f64) -> PyResult<()> {
+                self.internal.set_two_qubit_gate_error(gate, control, target, error).map_err(|err|
+                    PyValueError::new_err(format!("{:?}", err)))
+            }
+
 
             /// Set the gate time of a single qubit gate.
             ///
@@ -229,6 +292,56 @@ pub fn device_wrapper_def(
                 })
             }
 
+            /// Return the gate times of a single qubit gate on all qubits of the device.
+            ///
+            /// Args:
+            ///     hqslang (str): The hqslang name of a single qubit gate.
+            ///
+            /// Returns:
+            ///     np.ndarray: The gate time on each qubit, NaN where the gate is not available.
+            #[pyo3(text_signature = "(hqslang)")]
+            pub fn single_qubit_gate_time_matrix(&self, hqslang: &str) -> Py<numpy::PyArray1<f64>> {
+                Python::with_gil(|py| -> Py<numpy::PyArray1<f64>> {
+                    self.internal
+                        .to_generic_device()
+                        .single_qubit_gate_time_matrix(hqslang)
+                        .to_pyarray_bound(py)
+                        .unbind()
+                })
+            }
+
+            /// Return the gate times of a two qubit gate on all pairs of qubits of the device.
+            ///
+            /// Args:
+            ///     hqslang (str): The hqslang name of a two qubit gate.
+            ///
+            /// Returns:
+            ///     np.ndarray: The gate time with the control qubit as row and the target qubit as column, NaN where the gate is not available.
+            #[pyo3(text_signature = "(hqslang)")]
+            pub fn two_qubit_gate_time_matrix(&self, hqslang: &str) -> Py<PyArray2<f64>> {
+                Python::with_gil(|py| -> Py<PyArray2<f64>> {
+                    self.internal
+                        .to_generic_device()
+                        .two_qubit_gate_time_matrix(hqslang)
+                        .to_pyarray_bound(py)
+                        .unbind()
+                })
+            }
+
+            /// Return the decoherence rates of all qubits of the device.
+            ///
+            /// Returns:
+            ///     np.ndarray: number_qubits by 3 by 3 numpy array of decoherence rates, zero for qubits without decoherence rates.
+            pub fn qubit_decoherence_rates_all(&self) -> Py<numpy::PyArray3<f64>> {
+                Python::with_gil(|py| -> Py<numpy::PyArray3<f64>> {
+                    self.internal
+                        .to_generic_device()
+                        .qubit_decoherence_rates_all()
+                        .to_pyarray_bound(py)
+                        .unbind()
+                })
+            }
+
             /// Adds single qubit damping to noise rates.
             ///
             /// Args:
@@ -327,19 +440,22 @@ pub fn device_wrapper_def(
                 self.internal.multi_qubit_gate_names()
             }
 
-            /// Returns a copy of the device (copy here produces a deepcopy).
+            /// Return a copy of the device (copy here produces a deepcopy).
             ///
             /// Returns:
-            ///     A deep copy of self.
+            ///     Self: A deep copy of self.
             ///
             pub fn __copy__(&self) -> Self {
                 self.clone()
             }
 
-            /// Creates deep copy of Device.
+            /// Return a deep copy of the device.
+            ///
+            /// Args:
+            ///     _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
             ///
             /// Returns:
-            ///     A deep copy of self.
+            ///     Self: A deep copy of self.
             ///
             pub fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
                 self.clone()
@@ -382,7 +498,7 @@ pub fn device_wrapper_def(
             ///     input (ByteArray): The serialized Device (in bincode form).
             ///
             /// Returns:
-            ///     The deserialized Device.
+            ///     Self: The deserialized Device.
             ///
             /// Raises:
             ///     TypeError: Input cannot be converted to byte array.
@@ -408,7 +524,7 @@ pub fn device_wrapper_def(
             ///     input (str): The serialized device in json form.
             ///
             /// Returns:
-            ///     The deserialized device.
+            ///     Self: The deserialized device.
             ///
             /// Raises:
             ///     ValueError: Input cannot be deserialized to selected Device.
@@ -422,16 +538,20 @@ pub fn device_wrapper_def(
                 })
             }
 
+            /// Return the representation of the device.
+            ///
+            /// Returns:
+            ///     str: The debug representation of the device.
             fn __repr__(&self) -> String{
                 format!("{:?}", self.internal)
             }
 
 
-            /// Return the __richcmp__ magic method to perform rich comparison operations on mixed system.
+            /// Return the __richcmp__ magic method to perform rich comparison operations on the device.
             ///
             /// Args:
-            ///     other: The object to compare self to.
-            ///     op: Whether they should be equal or not.
+            ///     other (object): The object to compare self to.
+            ///     op (CompareOp): Whether they should be equal or not.
             ///
             /// Returns:
             ///     bool: Whether they are equal or not.
@@ -527,6 +647,9 @@ pub fn device_chain_env_wrapper_def(
             }
 
             /// Helper function signifying support for chain_with_environment.
+            ///
+            /// Returns:
+            ///     bool: Always True.
             pub fn __implements_environment_chains(&self) -> bool
             {
                 true
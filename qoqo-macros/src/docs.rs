@@ -0,0 +1,293 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Docstrings of the generated Python methods.
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::collections::HashMap;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse2, Attribute, Expr, GenericArgument, ImplItem, ItemImpl, Lit, LitStr, Meta, MetaNameValue,
+    PathArguments, Token, Type,
+};
+
+/// Name of the attribute overriding the docstrings of generated methods of a wrapped struct.
+pub const DOC_OVERRIDE_ATTRIBUTE: &str = "wrap_doc";
+
+/// Returns the lines of the doc comments in a list of attributes.
+pub fn docstring_lines(attributes: &[Attribute]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for attribute in attributes {
+        if let Meta::NameValue(MetaNameValue {
+            path,
+            value: Expr::Lit(expr),
+            ..
+        }) = &attribute.meta
+        {
+            if let (true, Lit::Str(text)) = (path.is_ident("doc"), &expr.lit) {
+                for line in text.value().split('\n') {
+                    lines.push(line.strip_prefix(' ').unwrap_or(line).to_string());
+                }
+            }
+        }
+    }
+    lines
+}
+
+/// Returns the type and description of the arguments in the `Args:` section of a docstring.
+///
+/// Arguments are documented as `name (type): description`.
+pub fn documented_arguments(lines: &[String]) -> HashMap<String, (String, String)> {
+    let mut arguments: HashMap<String, (String, String)> = HashMap::new();
+    let mut in_arguments = false;
+    let mut last: Option<String> = None;
+    for line in lines {
+        let trimmed = line.trim();
+        if !line.starts_with(' ') {
+            in_arguments = trimmed == "Args:";
+            last = None;
+            continue;
+        }
+        if !in_arguments {
+            continue;
+        }
+        // Indented lines continue the description of the previous argument
+        if line.starts_with("        ") {
+            if let Some((_, description)) = last.as_ref().and_then(|name| arguments.get_mut(name)) {
+                description.push(' ');
+                description.push_str(trimmed);
+            }
+            continue;
+        }
+        last = None;
+        let (Some(open), Some(close)) = (trimmed.find(" ("), trimmed.find("):")) else {
+            continue;
+        };
+        if close < open {
+            continue;
+        }
+        let name = &trimmed[..open];
+        if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            arguments.insert(
+                name.to_string(),
+                (
+                    trimmed[open + 2..close].to_string(),
+                    trimmed[close + 2..].trim().to_string(),
+                ),
+            );
+            last = Some(name.to_string());
+        }
+    }
+    arguments
+}
+
+/// Returns the Python type of a struct field as it is converted by the generated methods.
+pub fn python_type_name(ty: &Type) -> String {
+    let path = match ty {
+        Type::Path(path) => path,
+        Type::Tuple(tuple) => {
+            let elements: Vec<String> = tuple.elems.iter().map(python_type_name).collect();
+            return format!("Tuple[{}]", elements.join(", "));
+        }
+        _ => return ty.to_token_stream().to_string(),
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return ty.to_token_stream().to_string();
+    };
+    let arguments: Vec<String> = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(python_type_name(ty)),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    match segment.ident.to_string().as_str() {
+        "usize" | "u8" | "u16" | "u32" | "u64" | "isize" | "i8" | "i16" | "i32" | "i64" => {
+            "int".to_string()
+        }
+        "f32" | "f64" => "float".to_string(),
+        "String" => "str".to_string(),
+        "Complex64" => "complex".to_string(),
+        "SpinHamiltonian" => "SpinHamiltonianSystem".to_string(),
+        "Array1" | "Array2" => "np.ndarray".to_string(),
+        "Vec" => format!("List[{}]", arguments.join(", ")),
+        "Option" => format!("Optional[{}]", arguments.join(", ")),
+        "HashMap" => format!("Dict[{}]", arguments.join(", ")),
+        "HashSet" => format!("Set[{}]", arguments.join(", ")),
+        name => name.to_string(),
+    }
+}
+
+/// Returns the docstring of the generated getter of a struct field.
+///
+/// The description of the returned value is taken from the `Args:` section of the struct
+/// docstring when the field is documented there.
+pub fn getter_docstring(
+    field: &str,
+    ty: &Type,
+    arguments: &HashMap<String, (String, String)>,
+) -> String {
+    format!(
+        "Return the value of the `{field}` field.\n\nReturns:\n    {}: {}",
+        python_type_name(ty),
+        field_description(field, arguments)
+    )
+}
+
+/// Returns the docstring of the generated constructor of a struct.
+///
+/// Fields documented in the `Args:` section of the struct docstring keep their documented type
+/// and description.
+pub fn constructor_docstring(
+    ident: &str,
+    fields: &[(String, Type)],
+    arguments: &HashMap<String, (String, String)>,
+) -> String {
+    let mut docstring = format!("Create a new {ident}.\n");
+    if !fields.is_empty() {
+        docstring.push_str("\nArgs:\n");
+        for (field, ty) in fields {
+            let python_type = arguments
+                .get(field)
+                .map(|(python_type, _)| python_type.clone())
+                .unwrap_or_else(|| python_type_name(ty));
+            docstring.push_str(&format!(
+                "    {field} ({python_type}): {}\n",
+                field_description(field, arguments)
+            ));
+        }
+    }
+    docstring.push_str(&format!("\nReturns:\n    {ident}: The new operation."));
+    if fields.iter().any(|(_, ty)| is_converted_type(ty)) {
+        docstring.push_str(
+            "\n\nRaises:\n    TypeError: An argument cannot be converted to the type of its field.",
+        );
+    }
+    docstring
+}
+
+// Returns the documented description of a field or a generic one.
+fn field_description(field: &str, arguments: &HashMap<String, (String, String)>) -> String {
+    arguments
+        .get(field)
+        .map(|(_, description)| description.clone())
+        .filter(|description| !description.is_empty())
+        .unwrap_or_else(|| format!("The value of the field `{field}`."))
+}
+
+// Returns true for the field types the constructor converts from arbitrary Python objects.
+fn is_converted_type(ty: &Type) -> bool {
+    matches!(
+        python_type_name(ty).as_str(),
+        "CalculatorFloat" | "Circuit" | "Optional[Circuit]" | "SpinHamiltonianSystem"
+    )
+}
+
+/// Returns the `#[doc]` attributes of a docstring.
+///
+/// Each line becomes one attribute with a leading space, as written by `///` comments.
+pub fn doc_attributes(docstring: &str) -> Vec<Attribute> {
+    docstring
+        .split('\n')
+        .map(|line| {
+            let line = LitStr::new(&format!(" {line}"), proc_macro2::Span::call_site());
+            syn::parse_quote!(#[doc = #line])
+        })
+        .collect()
+}
+
+/// Removes the `#[wrap_doc(...)]` attribute from the attributes of a struct.
+///
+/// The attribute maps the names of generated methods to the docstrings replacing their
+/// generated docstrings, for example `#[wrap_doc(theta = "...")]`.
+///
+/// Returns:
+///
+/// * `Ok(HashMap<String, String>)` - The docstrings by method name.
+/// * `Err(syn::Error)` - The attribute is not a list of `name = "docstring"` pairs.
+pub fn take_doc_overrides(attributes: &mut Vec<Attribute>) -> syn::Result<HashMap<String, String>> {
+    let mut overrides = HashMap::new();
+    let mut remaining = Vec::new();
+    for attribute in attributes.drain(..) {
+        if !attribute.path().is_ident(DOC_OVERRIDE_ATTRIBUTE) {
+            remaining.push(attribute);
+            continue;
+        }
+        let entries =
+            attribute.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+        for entry in entries {
+            let Some(name) = entry.path.get_ident() else {
+                return Err(syn::Error::new_spanned(
+                    entry.path,
+                    "Expected a method name",
+                ));
+            };
+            let docstring = match &entry.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(text) => text.value(),
+                    _ => return Err(syn::Error::new_spanned(&entry.value, "Expected a string")),
+                },
+                _ => return Err(syn::Error::new_spanned(&entry.value, "Expected a string")),
+            };
+            overrides.insert(name.to_string(), docstring);
+        }
+    }
+    *attributes = remaining;
+    Ok(overrides)
+}
+
+/// Replaces the docstrings of the methods in a `#[pymethods]` impl block.
+///
+/// Returns an error if a docstring is given for a method that is not generated.
+pub fn apply_doc_overrides(
+    impl_block: TokenStream,
+    overrides: &HashMap<String, String>,
+) -> syn::Result<TokenStream> {
+    if overrides.is_empty() {
+        return Ok(impl_block);
+    }
+    let mut item: ItemImpl = parse2(impl_block)?;
+    let mut replaced: Vec<&str> = Vec::new();
+    for impl_item in item.items.iter_mut() {
+        if let ImplItem::Fn(function) = impl_item {
+            let name = function.sig.ident.to_string();
+            if let Some((name, docstring)) = overrides.get_key_value(&name) {
+                function
+                    .attrs
+                    .retain(|attribute| !attribute.path().is_ident("doc"));
+                let mut attributes = doc_attributes(docstring);
+                attributes.append(&mut function.attrs);
+                function.attrs = attributes;
+                replaced.push(name);
+            }
+        }
+    }
+    let mut names: Vec<&String> = overrides.keys().collect();
+    names.sort();
+    for name in names {
+        if !replaced.contains(&name.as_str()) {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "{} does not override a generated method: {}",
+                    DOC_OVERRIDE_ATTRIBUTE, name
+                ),
+            ));
+        }
+    }
+    Ok(item.into_token_stream())
+}
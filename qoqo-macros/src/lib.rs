@@ -20,10 +20,11 @@ use std::collections::HashSet;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{
-    parse2, parse_macro_input, DataStruct, DeriveInput, Fields, GenericArgument, Ident, ItemStruct,
-    PathArguments, Token, Type, TypePath,
+    parse2, DataStruct, DeriveInput, Fields, GenericArgument, Ident, ItemStruct, PathArguments,
+    Token, Type, TypePath,
 };
 mod devices;
+mod docs;
 mod noise_models;
 mod operate;
 
@@ -100,16 +101,29 @@ impl Parse for AttributeMacroArguments {
 }
 
 /// Attribute macro for constructing the pyo3 wrappers for operation structs
+///
+/// The docstrings of generated methods can be replaced with a `#[wrap_doc(...)]` attribute
+/// on the struct mapping method names to docstrings, e.g. `#[wrap_doc(theta = "...")]`.
+/// Trailing fields with a `#[wrap_default(...)]` attribute, e.g. `#[wrap_default(0)]`,
+/// are optional arguments of the Python constructor.
 #[proc_macro_attribute]
 pub fn wrap(
     metadata: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let attribute_arguments = parse_macro_input!(metadata as AttributeMacroArguments);
-    let input2: TokenStream = input.clone().into();
-    let parsed_input = parse_macro_input!(input as ItemStruct);
+    wrap_struct(metadata.into(), input.into())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Generates the pyo3 wrapper of an operation struct for the `wrap` attribute macro.
+fn wrap_struct(metadata: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attribute_arguments: AttributeMacroArguments = parse2(metadata)?;
+    let input2: TokenStream = input.clone();
+    let parsed_input: ItemStruct = parse2(input)?;
     let ident = parsed_input.ident;
-    let struct_attributes = parsed_input.attrs;
+    let mut struct_attributes = parsed_input.attrs;
+    let doc_overrides = docs::take_doc_overrides(&mut struct_attributes)?;
     let str_ident = ident.to_string();
     let wrapper_ident = format_ident!("{}Wrapper", ident.to_string());
     let operate_quote = if attribute_arguments.contains("Operate") {
@@ -119,19 +133,25 @@ pub fn wrap(
     };
     let rotate_quote = if attribute_arguments.contains("Rotate") {
         quote! {
-            /// Returns Rotated gate raised to power
+            /// Return the rotation gate raised to a power.
             ///
             /// Args:
-            ///     `power`(CalculatorFloat): exponent of the power operation.
+            ///     power (CalculatorFloat): The exponent of the power operation.
             ///
             /// Returns:
-            ///     Self: gate raised to the power of `power`
-            ///
+            ///     Self: The gate raised to the power of `power`.
             pub fn powercf(&self, power: CalculatorFloatWrapper) -> Self{
                 Self{internal: self.internal.powercf(power.internal)}
             }
             #[cfg(feature = "overrotate")]
-            /// Returns clone of the gate with one parameter statistically overrotated.
+            /// Return a clone of the gate with one parameter statistically overrotated.
+            ///
+            /// Args:
+            ///     amplitude (float): The amplitude of the overrotation.
+            ///     variance (float): The variance of the overrotation.
+            ///
+            /// Returns:
+            ///     Self: The overrotated gate.
             fn overrotate(&self, amplitude: &f64, variance: &f64) -> Self {
                 Self{internal: self.internal.overrotate(amplitude, variance)}
 
@@ -147,24 +167,22 @@ pub fn wrap(
     };
     let operate_pragma_noise_quote = if attribute_arguments.contains("OperatePragmaNoise") {
         quote! {
-            /// Return the superoperator defining the evolution of the density matrix under the noise gate
+            /// Return the superoperator defining the evolution of the density matrix under the noise gate.
             ///
             /// Returns:
-            ///     np.ndarray: superoperator of gate.
-            ///
+            ///     np.ndarray: The superoperator of the gate.
             pub fn superoperator(&self) -> PyResult<Py<PyArray2<f64>>>{
                 Python::with_gil(|py| -> PyResult<Py<PyArray2<f64>>> {
                     Ok(self.internal.superoperator().unwrap().to_pyarray_bound(py).as_gil_ref().into())
                 })
             }
-            /// Return the power of the noise gate
+            /// Return the noise gate raised to a power.
             ///
             /// Args:
-            ///     `power` (CalculatorFloat): exponent in the power operation of the noise gate
+            ///     power (CalculatorFloat): The exponent in the power operation of the noise gate.
             ///
             /// Returns:
-            ///     Self
-            ///
+            ///     Self: The noise gate raised to the power of `power`.
             pub fn powercf(&self, power: CalculatorFloatWrapper) -> Self{
                 Self{internal: self.internal.powercf(power.internal)}
             }
@@ -175,10 +193,10 @@ pub fn wrap(
     let operate_pragma_noise_proba_quote =
         if attribute_arguments.contains("OperatePragmaNoiseProba") {
             quote! {
-                /// Returns the probability associated with the noise operation
+                /// Return the probability associated with the noise operation.
                 ///
                 /// Returns:
-                ///     CalculatorFloat
+                ///     CalculatorFloat: The probability of the noise operation.
                 pub fn probability(&self) -> CalculatorFloatWrapper{
                     CalculatorFloatWrapper{internal: self.internal.probability().clone()}
                 }
@@ -188,10 +206,10 @@ pub fn wrap(
         };
     let operate_single_qubit_quote = if attribute_arguments.contains("OperateSingleQubit") {
         quote! {
-            /// Return the qubit the operation acts on
+            /// Return the qubit the operation acts on.
             ///
             /// Returns:
-            ///     int
+            ///     int: The qubit the operation acts on.
             pub fn qubit(&self) -> usize{
                 self.internal.qubit().clone()
             }
@@ -202,7 +220,7 @@ pub fn wrap(
     let operate_single_qubit_gate_quote = if attribute_arguments.contains("OperateSingleQubitGate")
     {
         quote! {
-            /// Return the global phase :math:`g` of a unitary gate acting on one qubit
+            /// Return the global phase :math:`g` of a unitary gate acting on one qubit.
             ///
             /// Here global_phase is defined by
             ///
@@ -213,11 +231,11 @@ pub fn wrap(
             ///     \end{pmatrix}
             ///
             /// Returns:
-            ///     CalculatorFloat
+            ///     CalculatorFloat: The global phase :math:`g` of the gate.
             pub fn global_phase(&self) -> CalculatorFloatWrapper{
                 CalculatorFloatWrapper{internal: self.internal.global_phase().clone()}
             }
-            /// Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
+            /// Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
             ///
             /// Here alpha_r is defined by
             ///
@@ -228,11 +246,11 @@ pub fn wrap(
             ///     \end{pmatrix}
             ///
             /// Returns:
-            ///     CalculatorFloat
+            ///     CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
             pub fn alpha_r(&self) -> CalculatorFloatWrapper{
                 CalculatorFloatWrapper{internal: self.internal.alpha_r().clone()}
             }
-            /// Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
+            /// Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
             ///
             /// .. math::
             ///     U =e^{i \cdot g}\begin{pmatrix}
@@ -241,11 +259,11 @@ pub fn wrap(
             ///     \end{pmatrix}
             ///
             /// Returns:
-            ///     CalculatorFloat
+            ///     CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
             pub fn alpha_i(&self) -> CalculatorFloatWrapper{
                 CalculatorFloatWrapper{internal: self.internal.alpha_i().clone()}
             }
-            /// Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
+            /// Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
             ///
             /// Here beta_r is defined by
             ///
@@ -256,11 +274,11 @@ pub fn wrap(
             ///     \end{pmatrix}
             ///
             /// Returns:
-            ///     CalculatorFloat
+            ///     CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
             pub fn beta_r(&self) -> CalculatorFloatWrapper{
                 CalculatorFloatWrapper{internal: self.internal.beta_r().clone()}
             }
-            /// Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
+            /// Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
             ///
             /// Here beta_i is defined by
             ///
@@ -270,9 +288,8 @@ pub fn wrap(
             ///     \beta_r+i \beta_i & \alpha_r-i\alpha_i
             ///     \end{pmatrix}
             ///
-            ///
             /// Returns:
-            ///     CalculatorFloat
+            ///     CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
             pub fn beta_i(&self) -> CalculatorFloatWrapper{
                 CalculatorFloatWrapper{internal: self.internal.beta_i().clone()}
             }
@@ -283,10 +300,15 @@ pub fn wrap(
             /// Only Operations
             ///
             /// Args:
-            ///     `other` - An Operation implementing [OperateSingleQubitGate].
+            ///     other (Operation): An Operation implementing OperateSingleQubitGate.
             ///
             /// Returns:
-            ///     Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            ///     SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+            ///
+            /// Raises:
+            ///     TypeError: Right hand side cannot be converted to Operation.
+            ///     RuntimeError: Right hand side is not a single qubit gate.
+            ///     ValueError: The two gates act on different qubits or their product is not unitary.
             ///
             /// Example:
             /// ```
@@ -306,21 +328,69 @@ pub fn wrap(
                     pyo3::exceptions::PyRuntimeError::new_err(format!("Conversion to SingleQubitGateOperation failed {:?}",x))
                 })?;
                 let multiplied = self.internal.mul(&other_converted).map_err(|x| {
-                    pyo3::exceptions::PyRuntimeError::new_err(format!("Multiplication failed {:?}",x))
+                    pyo3::exceptions::PyValueError::new_err(format!("Multiplication failed {:?}",x))
                 })?;
                 Ok(SingleQubitGateWrapper{ internal: multiplied})
             }
+
+            /// Multiplies two compatible operations implementing OperateSingleQubitGate.
+            ///
+            /// Alias for `mul`, allowing the use of the `*` operator.
+            ///
+            /// Args:
+            ///     other (Operation): An Operation implementing OperateSingleQubitGate.
+            ///
+            /// Returns:
+            ///     SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+            ///
+            /// Raises:
+            ///     TypeError: Right hand side cannot be converted to Operation.
+            ///     RuntimeError: Right hand side is not a single qubit gate.
+            ///     ValueError: The two gates act on different qubits or their product is not unitary.
+            pub fn __mul__(&self, other: &Bound<PyAny>) -> PyResult<SingleQubitGateWrapper> {
+                self.mul(other)
+            }
+
+            /// Return the equivalent SingleQubitGate of the single qubit gate.
+            ///
+            /// Returns:
+            ///     SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+            pub fn to_single_qubit_gate(&self) -> SingleQubitGateWrapper {
+                SingleQubitGateWrapper{ internal: self.internal.to_single_qubit_gate()}
+            }
+
+            /// Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+            ///
+            /// The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
+            ///
+            /// Returns:
+            ///     Circuit: The rotations followed by a PragmaGlobalPhase.
+            ///
+            /// Raises:
+            ///     ValueError: The parameters of the gate are symbolic.
+            pub fn to_zyz_circuit(&self) -> PyResult<crate::CircuitWrapper> {
+                let circuit = self.internal.to_zyz_circuit().map_err(|x| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Decomposition failed {:?}",x))
+                })?;
+                Ok(crate::CircuitWrapper{ internal: circuit })
+            }
         }
     } else {
         TokenStream::new()
     };
     let operate_two_qubit_quote = if attribute_arguments.contains("OperateTwoQubit") {
         quote! {
-            /// Returns control qubit of the two-qubit operation
+            /// Return the control qubit of the two-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The control qubit of the operation.
             pub fn control(&self) -> usize {
                 self.internal.control().clone()
             }
-            /// Returns target qubit of the two-qubit operation
+            /// Return the target qubit of the two-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The target qubit of the operation.
             pub fn target(&self) -> usize {
                 self.internal.target().clone()
             }
@@ -328,27 +398,48 @@ pub fn wrap(
     } else {
         TokenStream::new()
     };
-    // let operate_two_qubit_gate_quote = if attribute_arguments.contains("OperateTwoQubitGate") {
-    //     quote! {
-    //         /// Returns kak decomposition of the two-qubit-gate operation
-    //         pub fn kak_decomposition(&self) -> KakDecompositionWrapper {
-    //             KakDecompositionWrapper{internal: self.internal.kak_decomposition().clone()}
-    //         }
-    //     }
-    // } else {
-    //     TokenStream::new()
-    // };
+    let operate_two_qubit_gate_quote = if attribute_arguments.contains("OperateTwoQubitGate") {
+        quote! {
+            /// Decompose the gate into CNOT gates and single-qubit gates.
+            ///
+            /// The decomposition uses at most three CNOT gates. The global phase of the gate
+            /// is added as a PragmaGlobalPhase at the end of the circuit.
+            ///
+            /// Returns:
+            ///     Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+            ///
+            /// Raises:
+            ///     ValueError: The parameters of the gate are symbolic.
+            pub fn to_cnot_circuit(&self) -> PyResult<crate::CircuitWrapper> {
+                let circuit = self.internal.to_cnot_circuit().map_err(|x| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Decomposition failed {:?}",x))
+                })?;
+                Ok(crate::CircuitWrapper{ internal: circuit })
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     let operate_three_qubit_quote = if attribute_arguments.contains("OperateThreeQubit") {
         quote! {
-            /// Returns control_0 qubit of the three-qubit operation
+            /// Return the control_0 qubit of the three-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The control_0 qubit of the operation.
             pub fn control_0(&self) -> usize {
                 self.internal.control_0().clone()
             }
-            /// Returns control_1 qubit of the three-qubit operation
+            /// Return the control_1 qubit of the three-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The control_1 qubit of the operation.
             pub fn control_1(&self) -> usize {
                 self.internal.control_1().clone()
             }
-            /// Returns target qubit of the three-qubit operation
+            /// Return the target qubit of the three-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The target qubit of the operation.
             pub fn target(&self) -> usize {
                 self.internal.target().clone()
             }
@@ -358,10 +449,10 @@ pub fn wrap(
     };
     let operate_three_qubit_gate_quote = if attribute_arguments.contains("OperateThreeQubitGate") {
         quote! {
-            /// Returns circuit implementing the ThreeQubitGateOperation
+            /// Return the circuit implementing the ThreeQubitGateOperation.
             ///
             /// Returns:
-            ///     Circuit
+            ///     Circuit: The circuit implementing the gate.
             pub fn circuit(&self) -> CircuitWrapper {
                 CircuitWrapper { internal: self.internal.circuit().clone() }
             }
@@ -369,21 +460,65 @@ pub fn wrap(
     } else {
         TokenStream::new()
     };
+    let operate_three_qubit_gate_basis_quote = if attribute_arguments
+        .contains("OperateThreeQubitGateBasis")
+    {
+        quote! {
+            /// Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
+            ///
+            /// Args:
+            ///     basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
+            ///
+            /// Returns:
+            ///     Circuit: The circuit implementing the gate with the entangling gates of the basis.
+            ///
+            /// Raises:
+            ///     ValueError: The basis is not supported.
+            pub fn circuit_with_basis(&self, basis: &str) -> PyResult<CircuitWrapper> {
+                let basis = match basis {
+                    "CNOT" => roqoqo::operations::TwoQubitBasis::CNOT,
+                    "CZ" | "ControlledPauliZ" => roqoqo::operations::TwoQubitBasis::ControlledPauliZ,
+                    "ISwap" => roqoqo::operations::TwoQubitBasis::ISwap,
+                    _ => {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "Basis {} is not supported, use one of CNOT, CZ and ISwap",
+                            basis
+                        )))
+                    }
+                };
+                Ok(CircuitWrapper { internal: self.internal.circuit_with_basis(basis) })
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     let operate_four_qubit_quote = if attribute_arguments.contains("OperateFourQubit") {
         quote! {
-            /// Returns control_0 qubit of the four-qubit operation
+            /// Return the control_0 qubit of the four-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The control_0 qubit of the operation.
             pub fn control_0(&self) -> usize {
                 self.internal.control_0().clone()
             }
-            /// Returns control_1 qubit of the four-qubit operation
+            /// Return the control_1 qubit of the four-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The control_1 qubit of the operation.
             pub fn control_1(&self) -> usize {
                 self.internal.control_1().clone()
             }
-            /// Returns control_2 qubit of the four-qubit operation
+            /// Return the control_2 qubit of the four-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The control_2 qubit of the operation.
             pub fn control_2(&self) -> usize {
                 self.internal.control_2().clone()
             }
-            /// Returns target qubit of the four-qubit operation
+            /// Return the target qubit of the four-qubit operation.
+            ///
+            /// Returns:
+            ///     int: The target qubit of the operation.
             pub fn target(&self) -> usize {
                 self.internal.target().clone()
             }
@@ -393,10 +528,10 @@ pub fn wrap(
     };
     let operate_four_qubit_gate_quote = if attribute_arguments.contains("OperateFourQubitGate") {
         quote! {
-            /// Returns circuit implementing the FourQubitGateOperation
+            /// Return the circuit implementing the FourQubitGateOperation.
             ///
             /// Returns:
-            ///     Circuit
+            ///     Circuit: The circuit implementing the gate.
             pub fn circuit(&self) -> CircuitWrapper {
                 CircuitWrapper { internal: self.internal.circuit().clone() }
             }
@@ -406,13 +541,13 @@ pub fn wrap(
     };
     let operate_gate_quote = if attribute_arguments.contains("OperateGate") {
         quote! {
-            /// Return unitary matrix of gate.
+            /// Return the unitary matrix of the gate.
             ///
             /// Returns:
-            ///     np.ndarray: matrix of gate.
+            ///     np.ndarray: The unitary matrix of the gate.
             ///
             /// Raises:
-            ///     ValueError: Error symbolic operation cannot return float unitary matrix
+            ///     ValueError: Error symbolic operation cannot return float unitary matrix.
             pub fn unitary_matrix(&self) -> PyResult<Py<PyArray2<Complex64>>>{
                 Python::with_gil(|py| -> PyResult<Py<PyArray2<Complex64>>> {
                     Ok(self.internal.unitary_matrix().map_err(|x| PyValueError::new_err(format!("Error symbolic operation cannot return float unitary matrix {:?}",x)))?
@@ -427,10 +562,10 @@ pub fn wrap(
     };
     let operate_multi_qubit_quote = if attribute_arguments.contains("OperateMultiQubit") {
         quote! {
-            /// Return list of qubits of the multi qubit operation in order of descending significance
+            /// Return the list of qubits of the multi qubit operation in order of descending significance.
             ///
             /// Returns:
-            ///     List[int]
+            ///     List[int]: The qubits the operation acts on.
             pub fn qubits(&self) -> Vec<usize>{
                 self.internal.qubits().clone()
             }
@@ -440,10 +575,10 @@ pub fn wrap(
     };
     let operate_multi_qubit_gate_quote = if attribute_arguments.contains("OperateMultiQubitGate") {
         quote! {
-            /// Return circuit implementing MultiQubitGateOperation
+            /// Return the circuit implementing the MultiQubitGateOperation.
             ///
             /// Returns:
-            ///     Circuit
+            ///     Circuit: The circuit implementing the gate.
             pub fn circuit(&self) -> CircuitWrapper{
                 CircuitWrapper { internal: self.internal.circuit().clone() }
             }
@@ -453,10 +588,10 @@ pub fn wrap(
     };
     let define_quote = if attribute_arguments.contains("Define") {
         quote! {
-        /// Return name of definition operation.
+        /// Return the name of the definition operation.
         ///
         /// Returns:
-        ///     str
+        ///     str: The name of the defined register or variable.
         pub fn name(&self) -> String {
                 self.internal.name().clone()
             }
@@ -466,10 +601,10 @@ pub fn wrap(
     };
     let operate_constant_gate_quote = if attribute_arguments.contains("OperateConstantGate") {
         quote! {
-        /// Return inverse of GateOperation:
+        /// Return the inverse of the constant gate operation.
         ///
         /// Returns:
-        ///     GateOperation
+        ///     Operation: The inverse gate operation.
         pub fn inverse(&self) -> GateOperationWrapper {
                 GateOperationWrapper { internal: self.internal.inverse().clone() }
             }
@@ -480,10 +615,10 @@ pub fn wrap(
 
     let involve_modes_quote = if attribute_arguments.contains("InvolveModes") {
         quote! {
-        /// List of modes the operation acts on.
+        /// Return the set of modes the operation acts on.
         ///
         /// Returns:
-        ///     Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        ///     Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         pub fn involved_modes(&self) -> PyObject {
             Python::with_gil(|py| -> PyObject {
                 let involved = self.internal.involved_modes();
@@ -517,16 +652,16 @@ pub fn wrap(
 
     let substitute_modes_quote = if attribute_arguments.contains("SubstituteModes") {
         quote! {
-        /// Remap the bosonic modes in copy of the operation.
+        /// Remap the bosonic modes in a copy of the operation.
         ///
         /// Args:
         ///     mapping (Dict[int, int]): Mapping for bosonic modes in operation.
         ///
         /// Returns:
-        ///     self
+        ///     Self: The operation with the remapped modes.
         ///
         /// Raises:
-        ///     PyValueError: Remapping could not be performed
+        ///     ValueError: Remapping could not be performed.
         pub fn remap_modes(&self, mapping: HashMap<usize, usize>) -> PyResult<Self> {
             let new_internal = self.internal.remap_modes(&mapping).map_err(|x|
                 PyRuntimeError::new_err(format!("Mode remapping failed: {:?}",x))
@@ -546,10 +681,10 @@ pub fn wrap(
 
     let operate_single_mode_quote = if attribute_arguments.contains("OperateSingleMode") {
         quote! {
-        /// Return `mode` the bosonic Operation acts on.
+        /// Return the `mode` the bosonic Operation acts on.
         ///
         /// Returns:
-        ///     int
+        ///     int: The bosonic mode of the operation.
         pub fn mode(&self) -> usize {
                 self.internal.mode().clone()
             }
@@ -559,10 +694,10 @@ pub fn wrap(
     };
     let operate_spins_analog_quote = if attribute_arguments.contains("OperateSpinsAnalog") {
         quote! {
-            /// /// Returns a vector of all the spins present in the analog operation (Hamiltonian)..
+            /// Return the spins the analog operation acts on.
             ///
             /// Returns:
-            ///     Vec<usize>
+            ///     List[int]: All the spins present in the Hamiltonian of the operation.
             pub fn spin(&self) -> PyResult<Vec<usize>> {
                 Python::with_gil(|py| -> PyResult<Vec<usize>> {
                     Ok(self.internal.spin().map_err(|x| PyValueError::new_err(format!("Error operation cannot return spins {:?}",x)))?.to_owned())
@@ -574,17 +709,17 @@ pub fn wrap(
     };
     let operate_two_mode_quote = if attribute_arguments.contains("OperateTwoMode") {
         quote! {
-        /// Return `mode_0` bosonic mode of two bosonic mode Operation.
+        /// Return the `mode_0` bosonic mode of the two bosonic mode Operation.
         ///
         /// Returns:
-        ///     int
+        ///     int: The mode_0 bosonic mode of the operation.
         pub fn mode_0(&self) -> usize {
                 self.internal.mode_0().clone()
             }
-        /// Return `mode_1` bosonic mode of two bosonic mode Operation.
+        /// Return the `mode_1` bosonic mode of the two bosonic mode Operation.
         ///
         /// Returns:
-        ///     int
+        ///     int: The mode_1 bosonic mode of the operation.
         pub fn mode_1(&self) -> usize {
                 self.internal.mode_1().clone()
             }
@@ -602,10 +737,38 @@ pub fn wrap(
     } else {
         TokenStream::new()
     };
+    let operate_fock_matrix_quote = if attribute_arguments.contains("OperateFockMatrix") {
+        quote! {
+            #[cfg(feature = "unstable_fock_matrix")]
+            /// Return the matrix of the gate in the truncated Fock space.
+            ///
+            /// The Fock space of each mode is truncated to the photon numbers 0 to cutoff - 1.
+            /// For two-mode gates the basis state |n_0, n_1> has the index n_0 * cutoff + n_1.
+            ///
+            /// Args:
+            ///     cutoff (int): The number of Fock states per mode.
+            ///
+            /// Returns:
+            ///     np.ndarray: The unitary matrix of the gate in the truncated Fock space.
+            ///
+            /// Raises:
+            ///     ValueError: The cutoff is zero or the operation is symbolic.
+            pub fn fock_matrix(&self, cutoff: usize) -> PyResult<Py<PyArray2<Complex64>>> {
+                Python::with_gil(|py| -> PyResult<Py<PyArray2<Complex64>>> {
+                    Ok(self.internal.fock_matrix(cutoff).map_err(|x| PyValueError::new_err(format!("Error operation cannot return Fock matrix {:?}",x)))?
+                        .to_pyarray_bound(py)
+                        .as_gil_ref()
+                        .into())
+                })
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     let json_schema_quote = if attribute_arguments.contains("JsonSchema") {
         quote! {
             #[cfg(feature = "json_schema")]
-            /// Returns the current version of the qoqo library .
+            /// Return the current version of the qoqo library.
             ///
             /// Returns:
             ///     str: The current version of the library.
@@ -628,7 +791,7 @@ pub fn wrap(
             /// Return the JsonSchema for the json serialisation of the class.
             ///
             /// Returns:
-            ///     str: The json schema serialized to json
+            ///     str: The json schema serialized to json.
             #[staticmethod]
             pub fn json_schema() -> String {
                 let schema = schemars::schema_for!(#ident);
@@ -640,15 +803,7 @@ pub fn wrap(
     };
 
     let msg = format!("Internal storage of {} object", ident);
-    let q = quote! {
-        #[automatically_derived]
-        #[pyclass(name=#str_ident)]
-        #(#struct_attributes)*
-        #[derive(Debug, Clone, PartialEq)]
-        pub struct #wrapper_ident{
-            #[doc = #msg]
-            pub internal: #ident
-        }
+    let methods = quote! {
         #[automatically_derived]
         #[pymethods]
         impl #wrapper_ident{
@@ -656,9 +811,10 @@ pub fn wrap(
             #operate_single_qubit_quote
             #operate_single_qubit_gate_quote
             #operate_two_qubit_quote
-            // #operate_two_qubit_gate_quote
+            #operate_two_qubit_gate_quote
             #operate_three_qubit_quote
             #operate_three_qubit_gate_quote
+            #operate_three_qubit_gate_basis_quote
             #operate_four_qubit_quote
             #operate_four_qubit_gate_quote
             #operate_multi_qubit_quote
@@ -677,29 +833,40 @@ pub fn wrap(
             #operate_two_mode_quote
             #operate_single_mode_gate_quote
             #operate_two_mode_gate_quote
+            #operate_fock_matrix_quote
             #operate_spins_analog_quote
             #json_schema_quote
+            /// Return the string representation of the operation.
+            ///
+            /// Args:
+            ///     _format_spec (str): The format specification, it is ignored.
+            ///
+            /// Returns:
+            ///     str: The debug representation of the operation.
             fn __format__(&self, _format_spec: &str) -> PyResult<String> {
                 Ok(format!("{:?}", self.internal))
             }
 
+            /// Return the representation of the operation.
+            ///
+            /// Returns:
+            ///     str: The debug representation of the operation.
             fn __repr__(&self) -> PyResult<String> {
                 Ok(format!("{:?}", self.internal))
             }
 
-            /// Returns the __richcmp__ magic method to perform rich comparison
-            /// operations on Operation.
+            /// Return the __richcmp__ magic method to perform rich comparison operations on Operation.
             ///
             /// Args:
-            ///
-            /// * `&self` - the OperationWrapper object
-            /// * `other` - the object to compare self to
-            /// * `op` - equal or not equal
+            ///     other (Operation): The object to compare self to.
+            ///     op (CompareOp): Equal or not equal.
             ///
             /// Returns:
+            ///     bool: Whether the two operations compared evaluated to True or False.
             ///
-            /// `PyResult<bool>` - whether the two operations compared evaluated to True or False
-            ///
+            /// Raises:
+            ///     TypeError: Right hand side cannot be converted to Operation.
+            ///     NotImplementedError: Other comparison not implemented.
             fn __richcmp__(&self, other: &Bound<PyAny>, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
                 let other: Operation = crate::operations::convert_pyany_to_operation(other).map_err(|x| {
                     pyo3::exceptions::PyTypeError::new_err(format!("Right hand side cannot be converted to Operation {:?}",x))
@@ -714,7 +881,18 @@ pub fn wrap(
             }
         }
     };
-    q.into()
+    let methods = docs::apply_doc_overrides(methods, &doc_overrides)?;
+    Ok(quote! {
+        #[automatically_derived]
+        #[pyclass(name=#str_ident)]
+        #(#struct_attributes)*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct #wrapper_ident{
+            #[doc = #msg]
+            pub internal: #ident
+        }
+        #methods
+    })
 }
 
 fn derive_wrap_operate(input: TokenStream) -> TokenStream {
@@ -780,3 +958,6 @@ fn extract_fields_with_types(ds: DataStruct) -> Vec<(Ident, Option<String>, Type
         (id, type_string, ty)
     }).collect()
 }
+
+#[cfg(test)]
+mod tests;
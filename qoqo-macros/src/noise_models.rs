@@ -26,19 +26,22 @@ pub fn noise_model_wrapper_def(
         impl #ident {
             #(#items)*
 
-            /// Returns a copy of the device (copy here produces a deepcopy).
+            /// Return a copy of the noise model (copy here produces a deepcopy).
             ///
             /// Returns:
-            ///     A deep copy of self.
+            ///     Self: A deep copy of self.
             ///
             pub fn __copy__(&self) -> Self {
                 self.clone()
             }
 
-            /// Creates deep copy of Noise-Model.
+            /// Return a deep copy of the noise model.
+            ///
+            /// Args:
+            ///     _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
             ///
             /// Returns:
-            ///     A deep copy of self.
+            ///     Self: A deep copy of self.
             ///
             pub fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
                 self.clone()
@@ -98,11 +101,11 @@ pub fn noise_model_wrapper_def(
                 format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
             }
 
-            /// Return the __richcmp__ magic method to perform rich comparison operations on mixed system.
+            /// Return the __richcmp__ magic method to perform rich comparison operations on the noise model.
             ///
             /// Args:
-            ///     other: The object to compare self to.
-            ///     op: Whether they should be equal or not.
+            ///     other (object): The object to compare self to.
+            ///     op (CompareOp): Whether they should be equal or not.
             ///
             /// Returns:
             ///     bool: Whether they are equal or not.
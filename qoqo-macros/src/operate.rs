@@ -10,25 +10,60 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::docs::{
+    constructor_docstring, doc_attributes, docstring_lines, documented_arguments, getter_docstring,
+};
 use crate::{extract_fields_with_types, RESERVED_FIELDS};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use std::collections::HashSet;
-use syn::{Data, DataStruct, DeriveInput, Ident};
+use std::collections::{HashMap, HashSet};
+use syn::{Data, DataStruct, DeriveInput, Expr, Fields, Ident};
+
+/// Name of the field attribute setting the default value of the constructor argument of a field.
+const FIELD_DEFAULT_ATTRIBUTE: &str = "wrap_default";
 
 /// Dispatch to derive Operate for enums and structs
 pub fn dispatch_struct(input: DeriveInput) -> TokenStream {
     let ident = input.ident;
+    let arguments = documented_arguments(&docstring_lines(&input.attrs));
     match input.data {
-        Data::Struct(ds) => operate_struct(ds, ident),
+        Data::Struct(ds) => operate_struct(ds, ident, &arguments),
         _ => panic!("InvolveQubits can only be derived on structs"),
     }
 }
 
 /// Generate TokenStream of implementation of Operate for structs
-fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
+///
+/// The docstrings of the getters and the constructor describe the fields with the `Args:` section
+/// of the struct docstring.
+fn operate_struct(
+    ds: DataStruct,
+    ident: Ident,
+    documented: &HashMap<String, (String, String)>,
+) -> TokenStream {
     let reserved_fields: HashSet<&str> = RESERVED_FIELDS.iter().cloned().collect();
+    let defaults = match field_defaults(&ds) {
+        Ok(defaults) => defaults,
+        Err(err) => return err.to_compile_error(),
+    };
     let fields_with_type = extract_fields_with_types(ds).into_iter();
+    let fields: Vec<(String, syn::Type)> = fields_with_type
+        .clone()
+        .map(|(id, _, ty)| (id.to_string(), ty))
+        .collect();
+    // Fields with a default value make the constructor arguments optional in Python
+    let signature_quote = if defaults.is_empty() {
+        TokenStream::new()
+    } else {
+        let signature_arguments =
+            fields_with_type
+                .clone()
+                .map(|(id, _, _)| match defaults.get(&id.to_string()) {
+                    Some(default) => quote! {#id = #default},
+                    None => quote! {#id},
+                });
+        quote! {#[pyo3(signature = (#(#signature_arguments),*))]}
+    };
     let input_arguments = fields_with_type
         .clone()
         .map(|(id, type_string, ty)| match type_string {
@@ -108,9 +143,12 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             "SpinHamiltonian" => {
                 let id_extracted = format_ident!("{}_extracted", id);
                 quote! {
-                    let temp_op: struqture::spins::SpinHamiltonianSystem = SpinHamiltonianSystemWrapper::from_pyany(#id).map_err(|x| {
-                        pyo3::exceptions::PyTypeError::new_err(format!("Argument cannot be converted to SpinHamiltonianSystem {:?}",x))
-                    })?;
+                    let temp_op: struqture::spins::SpinHamiltonianSystem = match SpinHamiltonianSystemWrapper::from_pyany(#id) {
+                        Ok(x) => x,
+                        Err(_) => SpinHamiltonianSystemWrapper::from_struqture_2(#id).map_err(|x| {
+                            pyo3::exceptions::PyTypeError::new_err(format!("Argument cannot be converted to SpinHamiltonianSystem from either struqture 1.x or struqture 2.x {:?}",x))
+                        })?.internal,
+                    };
                     let #id_extracted: #ty = temp_op.hamiltonian().clone();
                 }
             },
@@ -127,27 +165,27 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
         .map(|(id, type_string, ty)| match type_string {
             Some(s) => match s.as_str() {
                 "CalculatorFloat" => {
-                    let msg = format!("Returns value of attribute {}", id);
+                    let msg = doc_attributes(&getter_docstring(&id.to_string(), &ty, documented));
                     quote! {
-                        #[doc = #msg]
+                        #(#msg)*
                         pub fn #id(&self) -> CalculatorFloatWrapper{
                             CalculatorFloatWrapper{internal: self.internal.#id().clone()}
                         }
                     }
                 }
                 "Circuit" => {
-                    let msg = format!("Get value of struct field {}", id);
+                    let msg = doc_attributes(&getter_docstring(&id.to_string(), &ty, documented));
                     quote! {
-                        #[doc = #msg]
+                        #(#msg)*
                         pub fn #id(&self) -> CircuitWrapper{
                             CircuitWrapper{internal: self.internal.#id().clone()}
                         }
                     }
                 }
                 "Option<Circuit>" => {
-                    let msg = format!("Get value of struct field {}", id);
+                    let msg = doc_attributes(&getter_docstring(&id.to_string(), &ty, documented));
                     quote! {
-                            #[doc = #msg]
+                            #(#msg)*
                             pub fn #id(&self) -> Option<CircuitWrapper>{
                                 match self.internal.#id().as_ref(){
                                     None => None,
@@ -157,9 +195,9 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                     }
                 }
                 "SpinHamiltonian" => {
-                    let msg = format!("Get value of struct field {}", id);
+                    let msg = doc_attributes(&getter_docstring(&id.to_string(), &ty, documented));
                     quote! {
-                        #[doc = #msg]
+                        #(#msg)*
                         pub fn #id(&self) -> SpinHamiltonianSystemWrapper{
                             let shs = struqture::spins::SpinHamiltonianSystem::from_hamiltonian(self.internal.#id().clone(), None).expect("Unexpectedly could not construct SpinHamiltonianSystem from SpinHamiltonian");
                             SpinHamiltonianSystemWrapper{internal: shs}
@@ -167,9 +205,9 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                     }
                 }
                 _ => {
-                    let msg = format!("Get value of struct field {}", id);
+                    let msg = doc_attributes(&getter_docstring(&id.to_string(), &ty, documented));
                     quote! {
-                        #[doc = #msg]
+                        #(#msg)*
                         pub fn #id(&self) -> #ty{
                             self.internal.#id().clone()
                         }
@@ -177,9 +215,9 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                 }
             },
             _ => {
-                let msg = format!("Get value of struct field {}", id);
+                let msg = doc_attributes(&getter_docstring(&id.to_string(), &ty, documented));
                 quote! {
-                    #[doc=#msg]
+                    #(#msg)*
                     pub fn #id(&self) -> #ty{
                         self.internal.#id().clone()
                     }
@@ -187,13 +225,18 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             }
         });
 
-    let new_msg = format!("Creates new instance of Operations {}", ident);
+    let new_msg = doc_attributes(&constructor_docstring(
+        &ident.to_string(),
+        &fields,
+        documented,
+    ));
     quote! {
 
         #(#getter_fields)*
 
         #[new]
-        #[doc = #new_msg]
+        #signature_quote
+        #(#new_msg)*
         fn new(#(#input_arguments),*) -> PyResult<Self>{
             #(#conversion_quotes)*
             Ok(Self{internal: #ident::new(#(#arguments),*)})
@@ -207,6 +250,30 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                 self.internal.is_parametrized()
         }
 
+        /// Returns true if the operation is a gate operation
+        ///
+        /// Returns:
+        ///     bool: Whether or not the operation has the tag `GateOperation`.
+        fn is_gate_operation(&self) -> bool {
+            self.internal.tags().contains(&"GateOperation")
+        }
+
+        /// Returns true if the operation is a PRAGMA operation
+        ///
+        /// Returns:
+        ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+        fn is_pragma(&self) -> bool {
+            self.internal.tags().contains(&"PragmaOperation")
+        }
+
+        /// Returns true if the operation is a measurement
+        ///
+        /// Returns:
+        ///     bool: Whether or not the operation has the tag `Measurement`.
+        fn is_measurement(&self) -> bool {
+            self.internal.tags().contains(&"Measurement")
+        }
+
         /// Returns tags identifying the Operation
         ///
         /// Returns:
@@ -294,16 +361,40 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             })
         }
 
-        /// Copies Operation
+        /// Return a copy of the Operation (copy here produces a deepcopy).
         ///
-        /// For qoqo operations copy is always a deep copy
+        /// Returns:
+        ///     Self: A deep copy of self.
         fn __copy__(&self) -> Self {
             self.clone()
         }
 
-        /// Creates deep copy of Operation
+        /// Return a deep copy of the Operation.
+        ///
+        /// Args:
+        ///     _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+        ///
+        /// Returns:
+        ///     Self: A deep copy of self.
         fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
             self.clone()
         }
     }
 }
+
+// Returns the default values of the fields set with `#[wrap_default(...)]`, by field name.
+fn field_defaults(ds: &DataStruct) -> syn::Result<HashMap<String, Expr>> {
+    let mut defaults = HashMap::new();
+    if let Fields::Named(fields) = &ds.fields {
+        for field in fields.named.iter() {
+            for attribute in field.attrs.iter() {
+                if attribute.path().is_ident(FIELD_DEFAULT_ATTRIBUTE) {
+                    if let Some(ident) = &field.ident {
+                        defaults.insert(ident.to_string(), attribute.parse_args::<Expr>()?);
+                    }
+                }
+            }
+        }
+    }
+    Ok(defaults)
+}
@@ -0,0 +1,251 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshot tests of the expanded `wrap` macro.
+//!
+//! The snapshots list the attributes, signatures and docstrings of the generated items.
+//! Run the tests with the environment variable `UPDATE_SNAPSHOTS` set to rewrite them.
+
+use crate::docs::docstring_lines;
+use crate::wrap_struct;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::path::PathBuf;
+use syn::{Attribute, ImplItem, Item};
+
+const ROTATE_X_ARGUMENTS: &str =
+    "Operate, OperateSingleQubit, Rotate, OperateGate, OperateSingleQubitGate, JsonSchema";
+
+// Representative operation with a CalculatorFloat field, documented like the operations of qoqo.
+fn rotate_x(extra_attributes: TokenStream) -> TokenStream {
+    quote! {
+        /// The XPower gate :math:`e^{-i \frac{\theta}{2} \sigma^x}`.
+        ///
+        /// Args:
+        ///     qubit (int): The qubit the unitary gate is applied to.
+        ///     theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+        #extra_attributes
+        struct RotateX {
+            qubit: usize,
+            theta: CalculatorFloat,
+        }
+    }
+}
+
+// Renders the generated items as readable text, one block per struct and method.
+fn render(expanded: TokenStream) -> String {
+    let file: syn::File = syn::parse2(expanded).expect("Expanded macro is not valid Rust");
+    let mut text = String::new();
+    for item in file.items {
+        match item {
+            Item::Struct(item_struct) => {
+                render_attributes(&mut text, &item_struct.attrs, "");
+                text.push_str(&format!("struct {}\n\n", item_struct.ident));
+            }
+            Item::Impl(item_impl) => {
+                render_attributes(&mut text, &item_impl.attrs, "");
+                text.push_str(&format!("impl {}\n\n", item_impl.self_ty.to_token_stream()));
+                for impl_item in item_impl.items {
+                    if let ImplItem::Fn(function) = impl_item {
+                        render_attributes(&mut text, &function.attrs, "    ");
+                        text.push_str(&format!("    {}\n", function.sig.to_token_stream()));
+                        for line in docstring_lines(&function.attrs) {
+                            text.push_str(format!("        | {}", line).trim_end());
+                            text.push('\n');
+                        }
+                        text.push('\n');
+                    }
+                }
+            }
+            other => panic!("Unexpected item {}", other.to_token_stream()),
+        }
+    }
+    text
+}
+
+fn render_attributes(text: &mut String, attributes: &[Attribute], indent: &str) {
+    for attribute in attributes {
+        if !attribute.path().is_ident("doc") {
+            text.push_str(&format!("{}{}\n", indent, attribute.to_token_stream()));
+        }
+    }
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.txt", name));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "Could not read snapshot {}: {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display(),
+            err
+        )
+    });
+    assert!(
+        expected == actual,
+        "Snapshot {} differs from the expanded macro, run with UPDATE_SNAPSHOTS=1 to update it\n{}",
+        path.display(),
+        actual
+    );
+}
+
+/// Test the expansion of the wrap macro for a rotation gate against the snapshot
+#[test]
+fn test_wrap_rotate_x_snapshot() {
+    let expanded = wrap_struct(ROTATE_X_ARGUMENTS.parse().unwrap(), rotate_x(quote! {})).unwrap();
+    assert_snapshot("wrap_rotate_x", &render(expanded));
+}
+
+/// Test that every generated method has a docstring with a Returns section
+#[test]
+fn test_wrap_docstrings_complete() {
+    let expanded = wrap_struct(ROTATE_X_ARGUMENTS.parse().unwrap(), rotate_x(quote! {})).unwrap();
+    let file: syn::File = syn::parse2(expanded).unwrap();
+    for item in file.items {
+        if let Item::Impl(item_impl) = item {
+            for impl_item in item_impl.items {
+                if let ImplItem::Fn(function) = impl_item {
+                    let docs = docstring_lines(&function.attrs);
+                    let name = function.sig.ident.to_string();
+                    assert!(
+                        docs.first().map(|line| !line.is_empty()).unwrap_or(false),
+                        "{} has no description",
+                        name
+                    );
+                    assert!(
+                        docs.iter().any(|line| line == "Returns:"),
+                        "{} has no Returns section",
+                        name
+                    );
+                    let has_arguments = function
+                        .sig
+                        .inputs
+                        .iter()
+                        .any(|input| matches!(input, syn::FnArg::Typed(_)));
+                    assert_eq!(
+                        has_arguments,
+                        docs.iter().any(|line| line == "Args:"),
+                        "Args section of {} does not match its arguments",
+                        name
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Test that wrap_doc replaces the docstring of a generated method
+#[test]
+fn test_wrap_doc_override() {
+    let expanded = wrap_struct(
+        ROTATE_X_ARGUMENTS.parse().unwrap(),
+        rotate_x(quote! {
+            #[wrap_doc(theta = "Return the rotation angle.\n\nReturns:\n    CalculatorFloat: The angle.")]
+        }),
+    )
+    .unwrap();
+    let file: syn::File = syn::parse2(expanded).unwrap();
+    let mut found = false;
+    for item in file.items {
+        match item {
+            Item::Struct(item_struct) => assert!(!item_struct
+                .attrs
+                .iter()
+                .any(|attribute| attribute.path().is_ident("wrap_doc"))),
+            Item::Impl(item_impl) => {
+                for impl_item in item_impl.items {
+                    if let ImplItem::Fn(function) = impl_item {
+                        if function.sig.ident == "theta" {
+                            found = true;
+                            assert_eq!(
+                                docstring_lines(&function.attrs),
+                                vec![
+                                    "Return the rotation angle.",
+                                    "",
+                                    "Returns:",
+                                    "    CalculatorFloat: The angle."
+                                ]
+                            );
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    assert!(found);
+}
+
+/// Test that wrap_doc for a method that is not generated is an error
+#[test]
+fn test_wrap_doc_unknown_method() {
+    let error = wrap_struct(
+        ROTATE_X_ARGUMENTS.parse().unwrap(),
+        rotate_x(quote! {
+            #[wrap_doc(phi = "Not generated.")]
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "wrap_doc does not override a generated method: phi"
+    );
+}
+
+/// Test that wrap_default makes the constructor argument of a field optional
+#[test]
+fn test_wrap_default_signature() {
+    let input = quote! {
+        /// This PRAGMA measurement operation returns a Pauli product expectation value.
+        ///
+        /// Args:
+        ///     readout (string): The name of the classical readout register.
+        ///     readout_index (int): The index of the register entry. Defaults to 0.
+        struct PragmaGetPauliProduct {
+            readout: String,
+            #[wrap_default(0)]
+            readout_index: usize,
+        }
+    };
+    let expanded = wrap_struct("Operate".parse().unwrap(), input).unwrap();
+    let file: syn::File = syn::parse2(expanded).unwrap();
+    let mut found = false;
+    for item in file.items {
+        if let Item::Impl(item_impl) = item {
+            for impl_item in item_impl.items {
+                if let ImplItem::Fn(function) = impl_item {
+                    if function.sig.ident == "new" {
+                        found = true;
+                        let signature: Vec<String> = function
+                            .attrs
+                            .iter()
+                            .filter(|attribute| attribute.path().is_ident("pyo3"))
+                            .map(|attribute| attribute.to_token_stream().to_string())
+                            .collect();
+                        assert_eq!(
+                            signature,
+                            vec!["# [pyo3 (signature = (readout , readout_index = 0))]"]
+                        );
+                    }
+                }
+            }
+        }
+    }
+    assert!(found);
+}
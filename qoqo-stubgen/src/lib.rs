@@ -0,0 +1,200 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![deny(missing_docs)]
+#![deny(rustdoc::missing_crate_level_docs)]
+#![deny(missing_debug_implementations)]
+
+//! Generator of the Python type stubs of qoqo.
+//!
+//! The stubs are generated from the Rust sources of the qoqo Python interface.
+//! Classes, methods and functions are read from the `#[pyclass]`, `#[pymethods]` and `#[pyfunction]`
+//! items in qoqo and from the `quote!` blocks of the qoqo-macros crate, which generate most of the
+//! methods of the operation wrappers.
+//! The stubs are written to `qoqo/python/qoqo` by running `cargo run -p qoqo-stubgen`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+mod macros;
+// Docstrings of the methods generated by qoqo-macros, shared with the macros
+#[allow(dead_code)]
+#[path = "../../qoqo-macros/src/docs.rs"]
+mod macro_docs;
+mod render;
+mod signature;
+mod sources;
+
+use macros::MacroMethods;
+use render::ModuleRenderer;
+use sources::{Class, Sources};
+
+/// Python modules with stubs: the `#[pymodule]` function, the Python module and the stub file.
+const MODULES: &[(&str, &str, &str)] = &[
+    ("qoqo", "qoqo", "__init__.pyi"),
+    ("operations", "qoqo.operations", "operations.pyi"),
+    ("measurements", "qoqo.measurements", "measurements.pyi"),
+    ("devices", "qoqo.devices", "devices.pyi"),
+    ("noise_models", "qoqo.noise_models", "noise_models.pyi"),
+];
+
+/// Generated stub file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stub {
+    /// Name of the file in the stub directory.
+    pub file_name: String,
+    /// Content of the file.
+    pub content: String,
+}
+
+/// Returns the directory the stubs are written to.
+///
+/// # Arguments
+///
+/// * `workspace` - The root directory of the qoqo workspace.
+pub fn stub_directory(workspace: &Path) -> PathBuf {
+    workspace.join("qoqo").join("python").join("qoqo")
+}
+
+/// Generates the Python type stubs of qoqo.
+///
+/// # Arguments
+///
+/// * `workspace` - The root directory of the qoqo workspace.
+///
+/// # Returns
+///
+/// * `Vec<Stub>` - The stub files, one for the qoqo package and one for each submodule.
+///
+/// # Panics
+///
+/// Panics if the sources of qoqo or qoqo-macros cannot be read or parsed.
+pub fn generate_stubs(workspace: &Path) -> Vec<Stub> {
+    let macros = MacroMethods::from_source(&workspace.join("qoqo-macros").join("src"));
+    let sources = Sources::from_source(&workspace.join("qoqo").join("src"), &macros);
+
+    // Base class of all operations, it only exists in the stubs
+    let operation = Class {
+        name: "Operation".to_string(),
+        docs: sources
+            .modules
+            .get("operations")
+            .map(|module| module.docs.clone())
+            .unwrap_or_default(),
+        methods: macros
+            .operate
+            .iter()
+            .chain(macros.wrap_always.iter())
+            .cloned()
+            .collect(),
+    };
+
+    let mut known_classes: HashMap<String, String> = HashMap::new();
+    let mut wrappers: HashMap<String, String> = HashMap::new();
+    known_classes.insert(operation.name.clone(), "qoqo.operations".to_string());
+    for (function, python_module, _) in MODULES {
+        let module = module(&sources, function);
+        for ident in module.classes.iter() {
+            let class = class(&sources, ident);
+            known_classes.insert(class.name.clone(), python_module.to_string());
+            wrappers.insert(ident.clone(), class.name.clone());
+        }
+    }
+
+    let mut stubs = Vec::new();
+    for (function, python_module, file_name) in MODULES {
+        let module = module(&sources, function);
+        let is_operations = *function == "operations";
+        let mut classes: Vec<(&Class, Option<&Class>)> = Vec::new();
+        if is_operations {
+            classes.push((&operation, None));
+        }
+        for ident in module.classes.iter() {
+            let class = class(&sources, ident);
+            classes.push((class, is_operations.then_some(&operation)));
+        }
+        let functions: Vec<_> = module
+            .functions
+            .iter()
+            .map(|ident| {
+                sources
+                    .functions
+                    .get(ident)
+                    .unwrap_or_else(|| panic!("Function {} not found in qoqo", ident))
+            })
+            .collect();
+        let preamble: Vec<String> = if *function == "qoqo" {
+            package_preamble()
+        } else {
+            Vec::new()
+        };
+        let renderer = ModuleRenderer::new(python_module, &known_classes, &wrappers);
+        stubs.push(Stub {
+            file_name: file_name.to_string(),
+            content: renderer.render(&module.docs, &preamble, &classes, &functions),
+        });
+    }
+    stubs
+}
+
+/// Writes the Python type stubs of qoqo to the stub directory.
+///
+/// # Arguments
+///
+/// * `workspace` - The root directory of the qoqo workspace.
+///
+/// # Returns
+///
+/// * `Ok(Vec<PathBuf>)` - The paths of the written files.
+/// * `Err(std::io::Error)` - A file could not be written.
+pub fn write_stubs(workspace: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let directory = stub_directory(workspace);
+    let mut paths = Vec::new();
+    for stub in generate_stubs(workspace) {
+        let path = directory.join(&stub.file_name);
+        std::fs::write(&path, stub.content)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+// Re-exports of the package mirroring qoqo/python/qoqo/__init__.py.
+fn package_preamble() -> Vec<String> {
+    let mut preamble: Vec<String> = MODULES
+        .iter()
+        .filter(|(function, _, _)| *function != "qoqo")
+        .map(|(function, _, _)| format!("from . import {function} as {function}"))
+        .collect();
+    preamble.extend(
+        MODULES
+            .iter()
+            .filter(|(function, _, _)| *function != "qoqo")
+            .map(|(function, _, _)| format!("from .{function} import *")),
+    );
+    preamble.push(String::new());
+    preamble.push("__license__: str".to_string());
+    preamble
+}
+
+fn module<'a>(sources: &'a Sources, function: &str) -> &'a sources::Module {
+    sources
+        .modules
+        .get(function)
+        .unwrap_or_else(|| panic!("Module {} not found in qoqo", function))
+}
+
+fn class<'a>(sources: &'a Sources, ident: &str) -> &'a Class {
+    sources
+        .classes
+        .get(ident)
+        .unwrap_or_else(|| panic!("Class {} not found in qoqo", ident))
+}
@@ -0,0 +1,305 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Methods generated by the qoqo-macros crate.
+//!
+//! The methods are read from the `quote!` blocks of the macros.
+//! Methods whose signature depends on the input of the macro (for example the getters of the
+//! struct fields) cannot be read from the quotes and are generated in [crate::sources].
+
+use crate::signature::{method_from_signature, Method};
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use std::collections::HashMap;
+use std::path::Path;
+use syn::visit::Visit;
+use syn::{Expr, ImplItemFn, Item, ItemFn, Lit, Stmt};
+
+/// Python methods added by the macros of qoqo-macros.
+#[derive(Debug, Default)]
+pub(crate) struct MacroMethods {
+    /// Methods added by the `#[wrap(...)]` macro for each of its arguments, in source order.
+    pub wrap: Vec<(String, Vec<Method>)>,
+    /// Methods added by the `#[wrap(...)]` macro independently of its arguments.
+    pub wrap_always: Vec<Method>,
+    /// Methods added by the `#[wrap(Operate)]` argument besides the field getters and `__init__`.
+    pub operate: Vec<Method>,
+    /// Methods added by the attribute macros on `#[pymethods]` blocks, by macro name.
+    pub attributes: HashMap<String, Vec<Method>>,
+    /// Struct fields that get no getter from the `#[wrap(Operate)]` argument.
+    pub reserved_fields: Vec<String>,
+}
+
+/// Attribute macros on impl blocks, the file and the function implementing them.
+const ATTRIBUTE_MACROS: &[(&str, &str, &str)] = &[
+    (
+        "noise_model_wrapper",
+        "noise_models.rs",
+        "noise_model_wrapper_def",
+    ),
+    ("devicewrapper", "devices.rs", "device_wrapper_def"),
+    (
+        "devicechainenvironmentwrapper",
+        "devices.rs",
+        "device_chain_env_wrapper_def",
+    ),
+];
+
+impl MacroMethods {
+    /// Reads the generated methods from the sources of qoqo-macros.
+    ///
+    /// # Arguments
+    ///
+    /// * `macros_source` - The `src` directory of qoqo-macros.
+    pub(crate) fn from_source(macros_source: &Path) -> Self {
+        let mut methods = MacroMethods::default();
+
+        let lib = parse_file(&macros_source.join("lib.rs"));
+        methods.reserved_fields = reserved_fields(&lib);
+        let wrap = find_function(&lib, "wrap_struct");
+        for statement in wrap.block.stmts.iter() {
+            let Stmt::Local(local) = statement else {
+                continue;
+            };
+            let Some(init) = &local.init else {
+                continue;
+            };
+            match init.expr.as_ref() {
+                Expr::If(expr_if) => {
+                    if let Some(argument) = contained_argument(&expr_if.cond) {
+                        let quotes = quotes_in_block(&expr_if.then_branch);
+                        methods.wrap.push((argument, methods_from_quotes(&quotes)));
+                    }
+                }
+                Expr::Macro(expr_macro) if expr_macro.mac.path.is_ident("quote") => {
+                    methods.wrap_always = methods_from_tokens(expr_macro.mac.tokens.clone());
+                }
+                _ => (),
+            }
+        }
+
+        let operate = parse_file(&macros_source.join("operate.rs"));
+        methods.operate = methods_from_quotes(&quotes_in_function(find_function(
+            &operate,
+            "operate_struct",
+        )));
+
+        for (attribute, file, function) in ATTRIBUTE_MACROS {
+            let file = parse_file(&macros_source.join(file));
+            let quotes = quotes_in_function(find_function(&file, function));
+            methods
+                .attributes
+                .insert(attribute.to_string(), methods_from_quotes(&quotes));
+        }
+        methods
+    }
+}
+
+/// Parses a Rust source file.
+pub(crate) fn parse_file(path: &Path) -> syn::File {
+    let source = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Could not read {}: {}", path.display(), err));
+    syn::parse_file(&source)
+        .unwrap_or_else(|err| panic!("Could not parse {}: {}", path.display(), err))
+}
+
+// Returns the top level function `name` of a file.
+fn find_function<'a>(file: &'a syn::File, name: &str) -> &'a ItemFn {
+    file.items
+        .iter()
+        .find_map(|item| match item {
+            Item::Fn(function) if function.sig.ident == name => Some(function),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Function {} not found in qoqo-macros", name))
+}
+
+// Returns the entries of the RESERVED_FIELDS constant.
+fn reserved_fields(file: &syn::File) -> Vec<String> {
+    let constant = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Const(constant) if constant.ident == "RESERVED_FIELDS" => Some(constant),
+            _ => None,
+        })
+        .expect("RESERVED_FIELDS not found in qoqo-macros");
+    let mut expr = constant.expr.as_ref();
+    if let Expr::Reference(reference) = expr {
+        expr = reference.expr.as_ref();
+    }
+    match expr {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .filter_map(|element| match element {
+                Expr::Lit(literal) => match &literal.lit {
+                    Lit::Str(text) => Some(text.value()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => panic!("RESERVED_FIELDS is not an array"),
+    }
+}
+
+// Returns `X` of a condition `attribute_arguments.contains("X")`.
+fn contained_argument(condition: &Expr) -> Option<String> {
+    match condition {
+        Expr::MethodCall(call) if call.method == "contains" => match call.args.first() {
+            Some(Expr::Lit(literal)) => match &literal.lit {
+                Lit::Str(text) => Some(text.value()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Collects the tokens of all `quote!` macros in a syntax tree.
+#[derive(Default)]
+struct QuoteCollector {
+    quotes: Vec<TokenStream>,
+}
+
+impl<'ast> Visit<'ast> for QuoteCollector {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if mac.path.is_ident("quote") {
+            self.quotes.push(mac.tokens.clone());
+        }
+    }
+}
+
+// Returns the tokens of the `quote!` macros in a function.
+fn quotes_in_function(function: &ItemFn) -> Vec<TokenStream> {
+    let mut collector = QuoteCollector::default();
+    collector.visit_item_fn(function);
+    collector.quotes
+}
+
+// Returns the tokens of the `quote!` macros in a block.
+fn quotes_in_block(block: &syn::Block) -> Vec<TokenStream> {
+    let mut collector = QuoteCollector::default();
+    collector.visit_block(block);
+    collector.quotes
+}
+
+fn methods_from_quotes(quotes: &[TokenStream]) -> Vec<Method> {
+    quotes
+        .iter()
+        .flat_map(|quote| methods_from_tokens(quote.clone()))
+        .collect()
+}
+
+/// Returns the Python methods defined in the tokens of a `quote!` block.
+///
+/// Functions are recognised at the top level of the quote and inside `#[pymethods]` impl blocks.
+/// Functions whose signature contains interpolated variables are skipped.
+fn methods_from_tokens(tokens: TokenStream) -> Vec<Method> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut methods = Vec::new();
+    let mut pending: Vec<TokenTree> = Vec::new();
+    let mut in_pymethods_impl = false;
+    let mut index = 0;
+    while index < tokens.len() {
+        match &tokens[index] {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => match tokens.get(index + 1) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                    if !contains_interpolation(group.stream()) {
+                        pending.push(tokens[index].clone());
+                        pending.push(tokens[index + 1].clone());
+                    }
+                    index += 2;
+                }
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    // Repetition `#(...)*` or `#(...),*`
+                    pending.clear();
+                    index += 2;
+                    while let Some(TokenTree::Punct(punct)) = tokens.get(index) {
+                        index += 1;
+                        if punct.as_char() == '*' {
+                            break;
+                        }
+                    }
+                }
+                _ => {
+                    pending.clear();
+                    index += 2;
+                }
+            },
+            TokenTree::Ident(ident) if ident == "pub" => {
+                pending.push(tokens[index].clone());
+                index += 1;
+                if let Some(TokenTree::Group(group)) = tokens.get(index) {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        pending.push(tokens[index].clone());
+                        index += 1;
+                    }
+                }
+            }
+            TokenTree::Ident(ident) if ident == "impl" => {
+                in_pymethods_impl = pending
+                    .iter()
+                    .any(|token| token.to_string().contains("pymethods"));
+                pending.clear();
+                index += 1;
+            }
+            TokenTree::Ident(ident) if ident == "fn" => {
+                let start = index;
+                while index < tokens.len()
+                    && !matches!(&tokens[index], TokenTree::Group(group) if group.delimiter() == Delimiter::Brace)
+                {
+                    index += 1;
+                }
+                let signature: TokenStream = tokens[start..index].iter().cloned().collect();
+                if !contains_interpolation(signature.clone()) {
+                    let mut function: TokenStream = pending.drain(..).collect();
+                    function.extend(signature);
+                    function.extend(std::iter::once(TokenTree::Group(Group::new(
+                        Delimiter::Brace,
+                        TokenStream::new(),
+                    ))));
+                    let function: ImplItemFn = syn::parse2(function)
+                        .unwrap_or_else(|err| panic!("Could not parse generated method: {}", err));
+                    if let Some(method) = method_from_signature(&function.attrs, &function.sig) {
+                        methods.push(method);
+                    }
+                }
+                pending.clear();
+                index += 1;
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                if in_pymethods_impl {
+                    methods.extend(methods_from_tokens(group.stream()));
+                }
+                in_pymethods_impl = false;
+                pending.clear();
+                index += 1;
+            }
+            _ => {
+                pending.clear();
+                index += 1;
+            }
+        }
+    }
+    methods
+}
+
+// Returns true if the tokens contain an interpolation `#variable` of the quote macro.
+fn contains_interpolation(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Punct(punct) => punct.as_char() == '#',
+        TokenTree::Group(group) => contains_interpolation(group.stream()),
+        _ => false,
+    })
+}
@@ -0,0 +1,646 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering of the Python stub files.
+//!
+//! Types are taken from the `Args:` and `Returns:` sections of the docstrings when they name
+//! known Python types and are derived from the Rust signatures otherwise.
+
+use crate::signature::{Method, MethodKind, Parameter};
+use crate::sources::Class;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Names imported from the typing module when they are used.
+const TYPING: &[&str] = &[
+    "Any", "Dict", "List", "Mapping", "Optional", "Sequence", "Set", "Tuple", "Union",
+];
+
+/// Python builtins that can appear in types.
+const BUILTINS: &[&str] = &[
+    "None",
+    "bool",
+    "bytearray",
+    "bytes",
+    "complex",
+    "dict",
+    "float",
+    "int",
+    "list",
+    "object",
+    "set",
+    "str",
+    "tuple",
+    "type",
+];
+
+/// Classes of struqture-py used by qoqo.
+const STRUQTURE: &[&str] = &[
+    "PlusMinusLindbladNoiseOperator",
+    "SpinHamiltonianSystem",
+    "SpinLindbladNoiseSystem",
+    "SpinSystem",
+];
+
+/// Methods pyo3 maps to slots of `object` that do not need a stub.
+const SKIPPED_METHODS: &[&str] = &[
+    "__format__",
+    "__repr__",
+    "__richcmp__",
+    "__str__",
+    "__hash__",
+];
+
+/// Header of every generated stub file.
+const HEADER: &str = "# This file is generated by qoqo-stubgen from the Rust sources of qoqo.
+# Do not edit it by hand, regenerate it with `cargo run -p qoqo-stubgen` instead.
+";
+
+/// Renders the stub of one Python module.
+pub(crate) struct ModuleRenderer<'a> {
+    /// Python module of the stub, for example `qoqo.operations`.
+    module: &'a str,
+    /// Python modules of the known classes by class name.
+    known_classes: &'a HashMap<String, String>,
+    /// Python names of the known classes by the identifier of their Rust struct.
+    wrappers: &'a HashMap<String, String>,
+    /// Names used in the types of the stub.
+    used_names: BTreeSet<String>,
+    /// Name of the class that is currently rendered.
+    class_name: String,
+}
+
+impl<'a> ModuleRenderer<'a> {
+    /// Creates a renderer for the stub of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The Python module of the stub.
+    /// * `known_classes` - The Python modules of all classes with stubs by class name.
+    /// * `wrappers` - The Python names of all classes with stubs by the Rust identifier.
+    pub(crate) fn new(
+        module: &'a str,
+        known_classes: &'a HashMap<String, String>,
+        wrappers: &'a HashMap<String, String>,
+    ) -> Self {
+        ModuleRenderer {
+            module,
+            known_classes,
+            wrappers,
+            used_names: BTreeSet::new(),
+            class_name: String::new(),
+        }
+    }
+
+    /// Renders the complete stub file.
+    ///
+    /// # Arguments
+    ///
+    /// * `docs` - The docstring of the module.
+    /// * `preamble` - Statements placed after the imports, for example re-exports.
+    /// * `classes` - The classes of the module together with their base class.
+    /// * `functions` - The functions of the module.
+    pub(crate) fn render(
+        mut self,
+        docs: &[String],
+        preamble: &[String],
+        classes: &[(&Class, Option<&Class>)],
+        functions: &[&Method],
+    ) -> String {
+        let mut body = String::new();
+        for (class, base) in classes {
+            body.push('\n');
+            body.push_str(&self.render_class(class, *base));
+        }
+        for function in functions {
+            self.class_name.clear();
+            body.push('\n');
+            body.push_str(&self.render_method(function, "", None, &[]));
+        }
+
+        let mut stub = String::from(HEADER);
+        stub.push('\n');
+        stub.push_str(&docstring(docs, ""));
+        stub.push('\n');
+        stub.push_str(&self.render_imports());
+        if !preamble.is_empty() {
+            stub.push('\n');
+            for line in preamble {
+                stub.push_str(line);
+                stub.push('\n');
+            }
+        }
+        stub.push_str(&body);
+        stub
+    }
+
+    // Renders the import statements of the names used in the stub.
+    fn render_imports(&self) -> String {
+        let mut imports = String::new();
+        let typing: Vec<&str> = TYPING
+            .iter()
+            .filter(|name| self.used_names.contains(**name))
+            .cloned()
+            .collect();
+        if self.used_names.contains("numpy.ndarray") {
+            imports.push_str("import numpy\n");
+        }
+        if !typing.is_empty() {
+            let _ = writeln!(imports, "from typing import {}", typing.join(", "));
+        }
+        if self.used_names.contains("CalculatorFloat") {
+            imports.push_str("from qoqo_calculator_pyo3 import CalculatorFloat\n");
+        }
+        let struqture: Vec<&str> = STRUQTURE
+            .iter()
+            .filter(|name| self.used_names.contains(**name))
+            .cloned()
+            .collect();
+        if !struqture.is_empty() {
+            let _ = writeln!(
+                imports,
+                "from struqture_py.spins import {}",
+                struqture.join(", ")
+            );
+        }
+        let mut by_module: HashMap<&str, Vec<&str>> = HashMap::new();
+        for name in self.used_names.iter() {
+            if let Some(module) = self.known_classes.get(name) {
+                if module != self.module {
+                    by_module.entry(module).or_default().push(name);
+                }
+            }
+        }
+        let mut modules: Vec<&&str> = by_module.keys().collect();
+        modules.sort();
+        for module in modules {
+            let _ = writeln!(
+                imports,
+                "from {} import {}",
+                module,
+                by_module[*module].join(", ")
+            );
+        }
+        imports
+    }
+
+    // Renders a class with its methods.
+    fn render_class(&mut self, class: &Class, base: Option<&Class>) -> String {
+        self.class_name = class.name.clone();
+        let mut rendered = match base {
+            Some(base) => format!("class {}({}):\n", class.name, base.name),
+            None => format!("class {}:\n", class.name),
+        };
+        let inherited: Vec<&str> = base
+            .map(|base| {
+                base.methods
+                    .iter()
+                    .map(|method| method.name.as_str())
+                    .filter(|name| *name != "__init__")
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut methods: Vec<&Method> = class
+            .methods
+            .iter()
+            .filter(|method| {
+                !SKIPPED_METHODS.contains(&method.name.as_str())
+                    && !inherited.contains(&method.name.as_str())
+            })
+            .collect();
+        // The constructor comes first, the remaining methods keep their source order
+        methods.sort_by_key(|method| method.kind != MethodKind::Constructor);
+
+        let mut parts: Vec<String> = Vec::new();
+        if !class.docs.is_empty() {
+            parts.push(docstring(&class.docs, "    "));
+        }
+        for method in methods {
+            parts.push(self.render_method(method, "    ", Some(class), &class.docs));
+        }
+        if parts.is_empty() {
+            rendered.push_str("    pass\n");
+        } else {
+            rendered.push_str(&parts.join("\n"));
+        }
+        rendered
+    }
+
+    // Renders a method or a module level function.
+    fn render_method(
+        &mut self,
+        method: &Method,
+        indent: &str,
+        class: Option<&Class>,
+        class_docs: &[String],
+    ) -> String {
+        let (doc_arguments, doc_return) = parse_docstring_types(&method.docs);
+        let (class_arguments, _) = parse_docstring_types(class_docs);
+
+        let mut parameters: Vec<String> = Vec::new();
+        match method.kind {
+            MethodKind::Static => (),
+            MethodKind::Class => parameters.push("cls".to_string()),
+            _ if class.is_some() => parameters.push("self".to_string()),
+            _ => (),
+        }
+        for parameter in method.parameters.iter() {
+            match parameter {
+                Parameter::KeywordOnly => parameters.push("*".to_string()),
+                Parameter::VarArgs(name) => parameters.push(format!("*{}", name)),
+                Parameter::KwArgs(name) => parameters.push(format!("**{}", name)),
+                Parameter::Named {
+                    name,
+                    rust_type,
+                    python_type,
+                    has_default,
+                } => {
+                    let documented = doc_arguments.get(name).or_else(|| {
+                        if method.kind == MethodKind::Constructor {
+                            class_arguments.get(name)
+                        } else {
+                            None
+                        }
+                    });
+                    // Types of the converted struct fields are more precise than the docstrings
+                    let python_type = python_type
+                        .as_ref()
+                        .map(|python_type| {
+                            self.use_names(python_type);
+                            python_type.clone()
+                        })
+                        .or_else(|| {
+                            documented.and_then(|documented| self.documented_type(documented))
+                        })
+                        .unwrap_or_else(|| match rust_type {
+                            Some(rust_type) => self.python_type(rust_type),
+                            None => self.use_name("Any"),
+                        });
+                    if *has_default {
+                        parameters.push(format!("{}: {} = ...", name, python_type));
+                    } else {
+                        parameters.push(format!("{}: {}", name, python_type));
+                    }
+                }
+            }
+        }
+
+        let return_type = if method.kind == MethodKind::Constructor {
+            "None".to_string()
+        } else {
+            method
+                .python_return_type
+                .as_ref()
+                .map(|python_type| {
+                    self.use_names(python_type);
+                    python_type.clone()
+                })
+                .or_else(|| doc_return.and_then(|documented| self.documented_type(&documented)))
+                .unwrap_or_else(|| match &method.return_type {
+                    Some(rust_type) => self.python_type(rust_type),
+                    None => "None".to_string(),
+                })
+        };
+
+        let mut rendered = String::new();
+        match method.kind {
+            MethodKind::Static => {
+                let _ = writeln!(rendered, "{}@staticmethod", indent);
+            }
+            MethodKind::Class => {
+                let _ = writeln!(rendered, "{}@classmethod", indent);
+            }
+            MethodKind::Property => {
+                let _ = writeln!(rendered, "{}@property", indent);
+            }
+            _ => (),
+        }
+        let signature = format!(
+            "{}def {}({}) -> {}:",
+            indent,
+            method.name,
+            parameters.join(", "),
+            return_type
+        );
+        if method.docs.is_empty() {
+            let _ = writeln!(rendered, "{} ...", signature);
+        } else {
+            let _ = writeln!(rendered, "{}", signature);
+            rendered.push_str(&docstring(&method.docs, &format!("{}    ", indent)));
+        }
+        rendered
+    }
+
+    // Returns the Python type of a type from a docstring if it only uses known names.
+    fn documented_type(&mut self, documented: &str) -> Option<String> {
+        let normalized = normalize_documented_type(documented, &self.class_name);
+        if normalized.is_empty()
+            || !normalized
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_.[], |".contains(c))
+        {
+            return None;
+        }
+        // Descriptions like `int, int, complex` or `in bincode form` are not types
+        if normalized
+            .replace(", ", ",")
+            .replace(" | ", "|")
+            .contains(' ')
+        {
+            return None;
+        }
+        let mut depth = 0_i32;
+        for c in normalized.chars() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                ',' if depth == 0 => return None,
+                _ => (),
+            }
+            if depth < 0 {
+                return None;
+            }
+        }
+        if depth != 0 {
+            return None;
+        }
+        if !identifiers(&normalized)
+            .iter()
+            .all(|identifier| self.is_known(identifier))
+        {
+            return None;
+        }
+        self.use_names(&normalized);
+        Some(normalized)
+    }
+
+    // Returns true if a name can be used in a type of the stub.
+    fn is_known(&self, name: &str) -> bool {
+        TYPING.contains(&name)
+            || BUILTINS.contains(&name)
+            || STRUQTURE.contains(&name)
+            || name == "numpy.ndarray"
+            || name == "CalculatorFloat"
+            || self.known_classes.contains_key(name)
+    }
+
+    // Marks the names in a Python type as used.
+    fn use_names(&mut self, python_type: &str) {
+        for identifier in identifiers(python_type) {
+            self.used_names.insert(identifier);
+        }
+    }
+
+    // Marks a name as used and returns it.
+    fn use_name(&mut self, name: &str) -> String {
+        self.used_names.insert(name.to_string());
+        name.to_string()
+    }
+
+    /// Returns the Python type of a Rust type in a pyo3 signature.
+    fn python_type(&mut self, rust_type: &Type) -> String {
+        match rust_type {
+            Type::Reference(reference) => self.python_type(&reference.elem),
+            Type::Paren(paren) => self.python_type(&paren.elem),
+            Type::Group(group) => self.python_type(&group.elem),
+            Type::Tuple(tuple) if tuple.elems.is_empty() => "None".to_string(),
+            Type::Tuple(tuple) => {
+                let elements: Vec<String> =
+                    tuple.elems.iter().map(|ty| self.python_type(ty)).collect();
+                format!("{}[{}]", self.use_name("Tuple"), elements.join(", "))
+            }
+            Type::Path(path) => {
+                let Some(segment) = path.path.segments.last() else {
+                    return self.use_name("Any");
+                };
+                let arguments: Vec<&Type> = match &segment.arguments {
+                    PathArguments::AngleBracketed(arguments) => arguments
+                        .args
+                        .iter()
+                        .filter_map(|argument| match argument {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                let name = segment.ident.to_string();
+                match (name.as_str(), arguments.as_slice()) {
+                    (
+                        "PyResult" | "Result" | "Py" | "Bound" | "PyRef" | "PyRefMut" | "Box",
+                        [inner, ..],
+                    ) => self.python_type(inner),
+                    ("Option", [inner]) => {
+                        format!("{}[{}]", self.use_name("Optional"), self.python_type(inner))
+                    }
+                    ("Vec" | "VecDeque", [inner]) => {
+                        format!("{}[{}]", self.use_name("List"), self.python_type(inner))
+                    }
+                    ("HashSet" | "BTreeSet", [inner]) => {
+                        format!("{}[{}]", self.use_name("Set"), self.python_type(inner))
+                    }
+                    ("HashMap" | "BTreeMap" | "IndexMap", [key, value]) => format!(
+                        "{}[{}, {}]",
+                        self.use_name("Dict"),
+                        self.python_type(key),
+                        self.python_type(value)
+                    ),
+                    (
+                        "usize" | "u8" | "u16" | "u32" | "u64" | "isize" | "i8" | "i16" | "i32"
+                        | "i64",
+                        _,
+                    ) => "int".to_string(),
+                    ("f32" | "f64", _) => "float".to_string(),
+                    ("bool", _) => "bool".to_string(),
+                    ("String" | "str" | "char", _) => "str".to_string(),
+                    ("Complex" | "Complex64", _) => "complex".to_string(),
+                    ("PyByteArray", _) => "bytearray".to_string(),
+                    ("PyBytes", _) => "bytes".to_string(),
+                    ("PyDict", _) => "dict".to_string(),
+                    ("PyList", _) => "list".to_string(),
+                    ("PyType", _) => "type".to_string(),
+                    (
+                        "PyArray" | "PyArray1" | "PyArray2" | "PyReadonlyArray1"
+                        | "PyReadonlyArray2" | "Array1" | "Array2",
+                        _,
+                    ) => self.use_name("numpy.ndarray"),
+                    ("CalculatorFloat" | "CalculatorFloatWrapper", _) => {
+                        self.use_name("CalculatorFloat")
+                    }
+                    ("Self", _) if !self.class_name.is_empty() => self.class_name.clone(),
+                    _ => {
+                        if let Some(python_name) = self.wrappers.get(&name) {
+                            let python_name = python_name.clone();
+                            return self.use_name(&python_name);
+                        }
+                        if let Some(stripped) = name.strip_suffix("Wrapper") {
+                            if STRUQTURE.contains(&stripped) {
+                                return self.use_name(stripped);
+                            }
+                        }
+                        self.use_name("Any")
+                    }
+                }
+            }
+            _ => self.use_name("Any"),
+        }
+    }
+}
+
+/// Returns the argument types and the return type documented in a docstring.
+///
+/// Arguments are documented as `name (type): description` in the `Args:` section,
+/// the return type as `type: description` in the first line of the `Returns:` section.
+fn parse_docstring_types(docs: &[String]) -> (HashMap<String, String>, Option<String>) {
+    let mut arguments = HashMap::new();
+    let mut return_type = None;
+    let mut section = "";
+    for line in docs {
+        let trimmed = line.trim();
+        if !line.starts_with(' ') && trimmed.ends_with(':') {
+            section = match trimmed {
+                "Args:" | "Arguments:" => "Args",
+                "Returns:" => "Returns",
+                _ => "",
+            };
+            continue;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            section = "";
+            continue;
+        }
+        match section {
+            "Args" => {
+                if let Some((name, documented)) = documented_argument(trimmed) {
+                    arguments.entry(name).or_insert(documented);
+                }
+            }
+            "Returns" if return_type.is_none() => {
+                let documented = match trimmed.find(": ") {
+                    Some(end) => &trimmed[..end],
+                    None => trimmed.trim_end_matches(':'),
+                };
+                return_type = Some(documented.to_string());
+            }
+            _ => (),
+        }
+    }
+    (arguments, return_type)
+}
+
+// Parses `name (type): description` and returns the name and the type.
+fn documented_argument(line: &str) -> Option<(String, String)> {
+    let open = line.find('(')?;
+    let name = line[..open].trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return None;
+    }
+    let mut depth = 0;
+    for (index, c) in line[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((
+                        name.to_string(),
+                        line[open + 1..open + index].trim().to_string(),
+                    ));
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+// Rewrites the spellings of types used in the docstrings to the names used in the stubs.
+fn normalize_documented_type(documented: &str, class_name: &str) -> String {
+    let mut normalized = String::new();
+    let mut identifier = String::new();
+    let mut chars = documented.trim().chars().peekable();
+    loop {
+        let next = chars.next();
+        match next {
+            Some(c) if c.is_ascii_alphanumeric() || c == '_' || c == '.' => identifier.push(c),
+            _ => {
+                if !identifier.is_empty() {
+                    let generic = next == Some('[');
+                    let name = identifier.rsplit('.').next().unwrap_or_default();
+                    let replaced = match identifier.as_str() {
+                        "np.ndarray" | "numpy.ndarray" | "ndarray" | "np.array" => {
+                            "numpy.ndarray".to_string()
+                        }
+                        "Option" => "Optional".to_string(),
+                        "ByteArray" => "bytearray".to_string(),
+                        "string" => "str".to_string(),
+                        "uint" => "int".to_string(),
+                        "CircuitWrapper" => "Circuit".to_string(),
+                        "SpinHamiltonian" => "SpinHamiltonianSystem".to_string(),
+                        "Self" if !class_name.is_empty() => class_name.to_string(),
+                        "list" if generic => "List".to_string(),
+                        "dict" if generic => "Dict".to_string(),
+                        "set" if generic => "Set".to_string(),
+                        "tuple" if generic => "Tuple".to_string(),
+                        _ if identifier.starts_with("qoqo.")
+                            || identifier.starts_with("struqture_py.") =>
+                        {
+                            name.to_string()
+                        }
+                        _ => identifier.clone(),
+                    };
+                    normalized.push_str(&replaced);
+                    identifier.clear();
+                }
+                match next {
+                    Some(c) => normalized.push(c),
+                    None => break,
+                }
+            }
+        }
+    }
+    normalized
+}
+
+// Returns the identifiers in a Python type.
+fn identifiers(python_type: &str) -> Vec<String> {
+    python_type
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+        .filter(|identifier| !identifier.is_empty())
+        .map(|identifier| identifier.to_string())
+        .collect()
+}
+
+/// Renders a docstring with the given indentation.
+fn docstring(docs: &[String], indent: &str) -> String {
+    let mut rendered = String::new();
+    let raw = if docs.iter().any(|line| line.contains('\\')) {
+        "r"
+    } else {
+        ""
+    };
+    let _ = writeln!(rendered, "{}{}\"\"\"", indent, raw);
+    for line in docs {
+        let line = line.replace("\"\"\"", "'''");
+        if line.is_empty() {
+            rendered.push('\n');
+        } else {
+            let _ = writeln!(rendered, "{}{}", indent, line);
+        }
+    }
+    let _ = writeln!(rendered, "{}\"\"\"", indent);
+    rendered
+}
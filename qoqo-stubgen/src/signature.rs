@@ -0,0 +1,298 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Python signatures of the functions and methods exported with pyo3.
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use syn::{Attribute, Expr, FnArg, Lit, Meta, Pat, Signature, Type};
+
+/// Kind of a method exported by a `#[pymethods]` block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MethodKind {
+    /// Method taking `self`.
+    Instance,
+    /// The `#[new]` constructor, exported as `__init__`.
+    Constructor,
+    /// A `#[staticmethod]`.
+    Static,
+    /// A `#[classmethod]`.
+    Class,
+    /// A `#[getter]`, exported as a property.
+    Property,
+}
+
+/// Parameter of a Python function.
+#[derive(Debug, Clone)]
+pub(crate) enum Parameter {
+    /// A named parameter with its Rust type if it is known.
+    Named {
+        name: String,
+        rust_type: Option<Box<Type>>,
+        python_type: Option<String>,
+        has_default: bool,
+    },
+    /// The bare `*` separating keyword-only parameters.
+    KeywordOnly,
+    /// A `*args` parameter.
+    VarArgs(String),
+    /// A `**kwargs` parameter.
+    KwArgs(String),
+}
+
+/// Python function or method exported with pyo3.
+#[derive(Debug, Clone)]
+pub(crate) struct Method {
+    /// Name of the function in Python.
+    pub name: String,
+    /// Lines of the docstring.
+    pub docs: Vec<String>,
+    /// Parameters without `self`, `cls` and the GIL token.
+    pub parameters: Vec<Parameter>,
+    /// Rust return type, `None` for functions returning `()`.
+    pub return_type: Option<Type>,
+    /// Python return type overriding the Rust return type.
+    pub python_return_type: Option<String>,
+    /// Kind of the method.
+    pub kind: MethodKind,
+}
+
+/// Returns the lines of the doc comments in a list of attributes.
+pub(crate) fn docs_from_attributes(attributes: &[Attribute]) -> Vec<String> {
+    let mut docs = Vec::new();
+    for attribute in attributes {
+        if let Meta::NameValue(name_value) = &attribute.meta {
+            if !name_value.path.is_ident("doc") {
+                continue;
+            }
+            if let Expr::Lit(expr) = &name_value.value {
+                if let Lit::Str(text) = &expr.lit {
+                    for line in text.value().split('\n') {
+                        let line = line.strip_prefix(' ').unwrap_or(line);
+                        docs.push(line.trim_end().to_string());
+                    }
+                }
+            }
+        }
+    }
+    while docs.last().map(|line| line.is_empty()).unwrap_or(false) {
+        docs.pop();
+    }
+    while docs.first().map(|line| line.is_empty()).unwrap_or(false) {
+        docs.remove(0);
+    }
+    docs
+}
+
+/// Returns true if the list of attributes contains the attribute `name`.
+pub(crate) fn has_attribute(attributes: &[Attribute], name: &str) -> bool {
+    attributes
+        .iter()
+        .any(|attribute| last_segment(attribute.path()) == name)
+}
+
+/// Returns the last segment of a path as a string.
+pub(crate) fn last_segment(path: &syn::Path) -> String {
+    path.segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .unwrap_or_default()
+}
+
+/// Returns the Python method of a Rust function in a `#[pymethods]` block or a `#[pyfunction]`.
+///
+/// Returns `None` for setters, which are not part of the stubs.
+pub(crate) fn method_from_signature(
+    attributes: &[Attribute],
+    signature: &Signature,
+) -> Option<Method> {
+    let mut kind = MethodKind::Instance;
+    let mut name = signature.ident.to_string();
+    let mut python_signature: Option<Vec<(String, bool)>> = None;
+    for attribute in attributes {
+        match last_segment(attribute.path()).as_str() {
+            "new" => kind = MethodKind::Constructor,
+            "staticmethod" => kind = MethodKind::Static,
+            "classmethod" => kind = MethodKind::Class,
+            "setter" => return None,
+            "getter" => {
+                kind = MethodKind::Property;
+                name = match &attribute.meta {
+                    Meta::List(list) => list.tokens.to_string(),
+                    _ => name
+                        .strip_prefix("get_")
+                        .map(|stripped| stripped.to_string())
+                        .unwrap_or(name),
+                };
+            }
+            "pyo3" => {
+                if let Meta::List(list) = &attribute.meta {
+                    if let Some(renamed) = pyo3_name(list.tokens.clone()) {
+                        name = renamed;
+                    }
+                    if let Some(parsed) = pyo3_signature(list.tokens.clone()) {
+                        python_signature = Some(parsed);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    if kind == MethodKind::Constructor {
+        name = "__init__".to_string();
+    }
+
+    let mut arguments: Vec<(String, Type)> = Vec::new();
+    let mut skip_class = kind == MethodKind::Class;
+    for input in signature.inputs.iter() {
+        if let FnArg::Typed(typed) = input {
+            if skip_class {
+                skip_class = false;
+                continue;
+            }
+            if is_gil_token(&typed.ty) {
+                continue;
+            }
+            if let Pat::Ident(ident) = typed.pat.as_ref() {
+                // `slf: PyRef<Self>` replaces the `&self` receiver
+                if ident.ident == "slf" {
+                    continue;
+                }
+                arguments.push((ident.ident.to_string(), typed.ty.as_ref().clone()));
+            }
+        }
+    }
+
+    let parameters = match python_signature {
+        None => arguments
+            .into_iter()
+            .map(|(name, rust_type)| Parameter::Named {
+                name,
+                rust_type: Some(Box::new(rust_type)),
+                python_type: None,
+                has_default: false,
+            })
+            .collect(),
+        Some(entries) => entries
+            .into_iter()
+            .map(|(entry, has_default)| {
+                if entry == "*" {
+                    Parameter::KeywordOnly
+                } else if let Some(kwargs) = entry.strip_prefix("**") {
+                    Parameter::KwArgs(kwargs.to_string())
+                } else if let Some(args) = entry.strip_prefix('*') {
+                    Parameter::VarArgs(args.to_string())
+                } else {
+                    let rust_type = arguments
+                        .iter()
+                        .find(|(name, _)| name == &entry)
+                        .map(|(_, rust_type)| Box::new(rust_type.clone()));
+                    Parameter::Named {
+                        name: entry,
+                        rust_type,
+                        python_type: None,
+                        has_default,
+                    }
+                }
+            })
+            .collect(),
+    };
+
+    let return_type = match &signature.output {
+        syn::ReturnType::Default => None,
+        syn::ReturnType::Type(_, return_type) => Some(return_type.as_ref().clone()),
+    };
+
+    Some(Method {
+        name,
+        docs: docs_from_attributes(attributes),
+        parameters,
+        return_type,
+        python_return_type: None,
+        kind,
+    })
+}
+
+// Returns true for the `Python` GIL token, which is not a Python level argument.
+fn is_gil_token(rust_type: &Type) -> bool {
+    match rust_type {
+        Type::Path(path) => last_segment(&path.path) == "Python",
+        _ => false,
+    }
+}
+
+// Returns the value of `name = "..."` in the arguments of a `#[pyo3(...)]` attribute.
+pub(crate) fn pyo3_name(tokens: TokenStream) -> Option<String> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    for window in tokens.windows(3) {
+        if let (TokenTree::Ident(ident), TokenTree::Punct(punct), TokenTree::Literal(literal)) =
+            (&window[0], &window[1], &window[2])
+        {
+            if ident == "name" && punct.as_char() == '=' {
+                return syn::parse_str::<syn::LitStr>(&literal.to_string())
+                    .ok()
+                    .map(|literal| literal.value());
+            }
+        }
+    }
+    None
+}
+
+// Returns the entries of `signature = (...)` in the arguments of a `#[pyo3(...)]` attribute
+// together with a flag marking entries with a default value.
+fn pyo3_signature(tokens: TokenStream) -> Option<Vec<(String, bool)>> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let group = tokens.windows(3).find_map(|window| match window {
+        [TokenTree::Ident(ident), TokenTree::Punct(punct), TokenTree::Group(group)]
+            if ident == "signature"
+                && punct.as_char() == '='
+                && group.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(group.clone())
+        }
+        _ => None,
+    })?;
+    let mut entries = Vec::new();
+    let mut current: Vec<TokenTree> = Vec::new();
+    for token in group
+        .stream()
+        .into_iter()
+        .chain(std::iter::once(TokenTree::Punct(proc_macro2::Punct::new(
+            ',',
+            proc_macro2::Spacing::Alone,
+        ))))
+    {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                if current.is_empty() {
+                    continue;
+                }
+                let mut name = String::new();
+                let mut has_default = false;
+                for part in current.iter() {
+                    match part {
+                        TokenTree::Punct(punct) if punct.as_char() == '=' => {
+                            has_default = true;
+                            break;
+                        }
+                        TokenTree::Punct(punct) if punct.as_char() == '*' => name.push('*'),
+                        TokenTree::Ident(ident) => name.push_str(&ident.to_string()),
+                        _ => (),
+                    }
+                }
+                entries.push((name, has_default));
+                current.clear();
+            }
+            _ => current.push(token),
+        }
+    }
+    Some(entries)
+}
@@ -0,0 +1,362 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Python classes, functions and modules defined in the sources of qoqo.
+
+use crate::macro_docs::{
+    constructor_docstring, docstring_lines, documented_arguments, getter_docstring,
+    take_doc_overrides,
+};
+use crate::macros::{parse_file, MacroMethods};
+use crate::signature::{
+    docs_from_attributes, has_attribute, last_segment, method_from_signature, pyo3_name, Method,
+    MethodKind, Parameter,
+};
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use syn::visit::Visit;
+use syn::{Fields, ImplItem, Item, ItemStruct, Meta, Type};
+
+/// Python class exported by qoqo.
+#[derive(Debug, Clone)]
+pub(crate) struct Class {
+    /// Name of the class in Python.
+    pub name: String,
+    /// Lines of the class docstring.
+    pub docs: Vec<String>,
+    /// Methods of the class in source order.
+    pub methods: Vec<Method>,
+}
+
+/// Python module exported by qoqo.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Module {
+    /// Lines of the module docstring.
+    pub docs: Vec<String>,
+    /// Rust identifiers of the classes added to the module.
+    pub classes: Vec<String>,
+    /// Rust identifiers of the functions added to the module.
+    pub functions: Vec<String>,
+}
+
+/// Python classes, functions and modules found in the sources of qoqo.
+#[derive(Debug, Default)]
+pub(crate) struct Sources {
+    /// Classes by the identifier of their Rust struct.
+    pub classes: HashMap<String, Class>,
+    /// Functions by the identifier of their Rust function.
+    pub functions: HashMap<String, Method>,
+    /// Modules by the identifier of their `#[pymodule]` function.
+    pub modules: HashMap<String, Module>,
+}
+
+impl Sources {
+    /// Reads the Python interface from the sources of qoqo.
+    ///
+    /// # Arguments
+    ///
+    /// * `qoqo_source` - The `src` directory of qoqo.
+    /// * `macros` - The methods generated by the macros of qoqo-macros.
+    pub(crate) fn from_source(qoqo_source: &Path, macros: &MacroMethods) -> Self {
+        let mut sources = Sources::default();
+        let mut impl_methods: Vec<(String, Vec<Method>)> = Vec::new();
+        for path in rust_files(qoqo_source) {
+            let file = parse_file(&path);
+            for item in file.items.iter() {
+                match item {
+                    Item::Struct(item_struct) => {
+                        if let Some(arguments) = wrap_arguments(item_struct) {
+                            let class = wrapped_class(item_struct, &arguments, macros);
+                            sources
+                                .classes
+                                .insert(format!("{}Wrapper", item_struct.ident), class);
+                        } else if let Some(name) = pyclass_name(item_struct) {
+                            sources.classes.insert(
+                                item_struct.ident.to_string(),
+                                Class {
+                                    name,
+                                    docs: docs_from_attributes(&item_struct.attrs),
+                                    methods: Vec::new(),
+                                },
+                            );
+                        }
+                    }
+                    Item::Impl(item_impl) => {
+                        let macro_methods = item_impl.attrs.iter().find_map(|attribute| {
+                            macros.attributes.get(&last_segment(attribute.path()))
+                        });
+                        if !has_attribute(&item_impl.attrs, "pymethods") && macro_methods.is_none()
+                        {
+                            continue;
+                        }
+                        let Type::Path(self_type) = item_impl.self_ty.as_ref() else {
+                            continue;
+                        };
+                        let mut methods: Vec<Method> = item_impl
+                            .items
+                            .iter()
+                            .filter_map(|impl_item| match impl_item {
+                                ImplItem::Fn(function) => {
+                                    method_from_signature(&function.attrs, &function.sig)
+                                }
+                                _ => None,
+                            })
+                            .collect();
+                        if let Some(macro_methods) = macro_methods {
+                            methods.extend(macro_methods.iter().cloned());
+                        }
+                        impl_methods.push((last_segment(&self_type.path), methods));
+                    }
+                    Item::Fn(function) => {
+                        if has_attribute(&function.attrs, "pyfunction") {
+                            if let Some(method) =
+                                method_from_signature(&function.attrs, &function.sig)
+                            {
+                                sources
+                                    .functions
+                                    .insert(function.sig.ident.to_string(), method);
+                            }
+                        } else if has_attribute(&function.attrs, "pymodule") {
+                            let mut collector = ModuleCollector::default();
+                            collector.visit_block(&function.block);
+                            collector.module.docs = docs_from_attributes(&function.attrs);
+                            sources
+                                .modules
+                                .insert(function.sig.ident.to_string(), collector.module);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        for (ident, methods) in impl_methods {
+            if let Some(class) = sources.classes.get_mut(&ident) {
+                for method in methods {
+                    if !class
+                        .methods
+                        .iter()
+                        .any(|existing| existing.name == method.name)
+                    {
+                        class.methods.push(method);
+                    }
+                }
+            }
+        }
+        sources
+    }
+}
+
+/// Returns the Rust files in a directory and its subdirectories in a stable order.
+fn rust_files(directory: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(directory)
+        .unwrap_or_else(|err| panic!("Could not read {}: {}", directory.display(), err))
+        .map(|entry| entry.expect("Could not read directory entry").path())
+        .collect();
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            files.extend(rust_files(&entry));
+        } else if entry.extension().map(|ext| ext == "rs").unwrap_or(false) {
+            files.push(entry);
+        }
+    }
+    files
+}
+
+// Returns the arguments of a `#[wrap(...)]` attribute.
+fn wrap_arguments(item_struct: &ItemStruct) -> Option<Vec<String>> {
+    item_struct.attrs.iter().find_map(|attribute| {
+        if last_segment(attribute.path()) != "wrap" {
+            return None;
+        }
+        match &attribute.meta {
+            Meta::List(list) => Some(
+                list.tokens
+                    .clone()
+                    .into_iter()
+                    .filter_map(|token| match token {
+                        TokenTree::Ident(ident) => Some(ident.to_string()),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            _ => Some(Vec::new()),
+        }
+    })
+}
+
+// Returns the Python name of a `#[pyclass]` struct.
+fn pyclass_name(item_struct: &ItemStruct) -> Option<String> {
+    let attribute = item_struct
+        .attrs
+        .iter()
+        .find(|attribute| last_segment(attribute.path()) == "pyclass")?;
+    let name = match &attribute.meta {
+        Meta::List(list) => pyo3_name(list.tokens.clone()),
+        _ => None,
+    };
+    Some(name.unwrap_or_else(|| item_struct.ident.to_string()))
+}
+
+/// Returns the class generated by the `#[wrap(...)]` macro for a roqoqo struct.
+fn wrapped_class(item_struct: &ItemStruct, arguments: &[String], macros: &MacroMethods) -> Class {
+    let name = item_struct.ident.to_string();
+    let mut methods = Vec::new();
+    if arguments.iter().any(|argument| argument == "Operate") {
+        let fields: Vec<(String, Type)> = match &item_struct.fields {
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .filter_map(|field| {
+                    field
+                        .ident
+                        .as_ref()
+                        .map(|ident| (ident.to_string(), field.ty.clone()))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        // Fields with `#[wrap_default(...)]` are optional constructor arguments
+        let defaults: Vec<String> = match &item_struct.fields {
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .filter(|field| {
+                    field
+                        .attrs
+                        .iter()
+                        .any(|attribute| last_segment(attribute.path()) == "wrap_default")
+                })
+                .filter_map(|field| field.ident.as_ref().map(|ident| ident.to_string()))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let documented = documented_arguments(&docstring_lines(&item_struct.attrs));
+        methods.push(Method {
+            name: "__init__".to_string(),
+            docs: docstring(&constructor_docstring(&name, &fields, &documented)),
+            parameters: fields
+                .iter()
+                .map(|(field, ty)| Parameter::Named {
+                    name: field.clone(),
+                    rust_type: Some(Box::new(ty.clone())),
+                    python_type: wrapped_field_type(ty, true),
+                    has_default: defaults.contains(field),
+                })
+                .collect(),
+            return_type: None,
+            python_return_type: None,
+            kind: MethodKind::Constructor,
+        });
+        for (field, ty) in fields.iter() {
+            if macros.reserved_fields.contains(field) {
+                continue;
+            }
+            methods.push(Method {
+                name: field.clone(),
+                docs: docstring(&getter_docstring(field, ty, &documented)),
+                parameters: Vec::new(),
+                return_type: Some(ty.clone()),
+                python_return_type: wrapped_field_type(ty, false),
+                kind: MethodKind::Instance,
+            });
+        }
+        methods.extend(macros.operate.iter().cloned());
+    }
+    for (argument, argument_methods) in macros.wrap.iter() {
+        if arguments.contains(argument) {
+            methods.extend(argument_methods.iter().cloned());
+        }
+    }
+    methods.extend(macros.wrap_always.iter().cloned());
+    // Docstrings replaced with `#[wrap_doc(...)]`
+    let mut attributes = item_struct.attrs.clone();
+    let overrides = take_doc_overrides(&mut attributes)
+        .unwrap_or_else(|err| panic!("Invalid wrap_doc attribute of {}: {}", name, err));
+    for method in methods.iter_mut() {
+        let rust_name = match method.kind {
+            MethodKind::Constructor => "new",
+            _ => method.name.as_str(),
+        };
+        if let Some(text) = overrides.get(rust_name) {
+            method.docs = docstring(text);
+        }
+    }
+    Class {
+        name,
+        docs: docs_from_attributes(&item_struct.attrs),
+        methods,
+    }
+}
+
+// Returns the lines of a docstring generated by qoqo-macros.
+fn docstring(text: &str) -> Vec<String> {
+    text.split('\n')
+        .map(|line| line.trim_end().to_string())
+        .collect()
+}
+
+// Returns the Python type of the struct fields the Operate wrapper converts from and to Python objects.
+fn wrapped_field_type(ty: &Type, argument: bool) -> Option<String> {
+    match type_string(ty).as_str() {
+        "CalculatorFloat" if argument => Some("Union[CalculatorFloat, float, str]".to_string()),
+        "CalculatorFloat" => Some("CalculatorFloat".to_string()),
+        "Circuit" => Some("Circuit".to_string()),
+        "Option<Circuit>" => Some("Optional[Circuit]".to_string()),
+        "SpinHamiltonian" => Some("SpinHamiltonianSystem".to_string()),
+        _ => None,
+    }
+}
+
+// Returns the Rust type as a string without whitespace.
+fn type_string(ty: &Type) -> String {
+    ty.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Collects the classes and functions added in the body of a `#[pymodule]` function.
+#[derive(Default)]
+struct ModuleCollector {
+    module: Module,
+}
+
+impl<'ast> Visit<'ast> for ModuleCollector {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if call.method == "add_class" {
+            if let Some(turbofish) = &call.turbofish {
+                if let Some(syn::GenericArgument::Type(Type::Path(path))) = turbofish.args.first() {
+                    self.module.classes.push(last_segment(&path.path));
+                }
+            }
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if last_segment(&mac.path) == "wrap_pyfunction" {
+            if let Some(TokenTree::Ident(ident)) = mac
+                .tokens
+                .clone()
+                .into_iter()
+                .take_while(
+                    |token| !matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+                )
+                .filter(|token| matches!(token, TokenTree::Ident(_)))
+                .last()
+            {
+                self.module.functions.push(ident.to_string());
+            }
+        }
+    }
+}
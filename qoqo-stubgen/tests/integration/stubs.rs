@@ -0,0 +1,60 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_stubgen::{generate_stubs, stub_directory};
+use std::path::Path;
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Test that the committed stubs are identical to the stubs generated from the current sources
+#[test]
+fn test_stubs_up_to_date() {
+    let directory = stub_directory(workspace());
+    for stub in generate_stubs(workspace()) {
+        let committed =
+            std::fs::read_to_string(directory.join(&stub.file_name)).unwrap_or_default();
+        assert!(
+            committed == stub.content,
+            "{} is outdated, regenerate the stubs with `cargo run -p qoqo-stubgen`",
+            stub.file_name
+        );
+    }
+}
+
+/// Test that the stubs contain the generated constructors and methods of the operation wrappers
+#[test]
+fn test_operation_signatures() {
+    let stubs = generate_stubs(workspace());
+    let operations = &stubs
+        .iter()
+        .find(|stub| stub.file_name == "operations.pyi")
+        .unwrap()
+        .content;
+    assert!(operations.contains("class RotateX(Operation):"));
+    assert!(operations.contains(
+        "def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:"
+    ));
+    assert!(operations.contains("def theta(self) -> CalculatorFloat:"));
+    assert!(operations.contains("def is_parametrized(self) -> bool:"));
+    assert!(operations.contains("from qoqo import Circuit"));
+
+    let package = &stubs
+        .iter()
+        .find(|stub| stub.file_name == "__init__.pyi")
+        .unwrap()
+        .content;
+    assert!(package.contains("class Circuit:"));
+    assert!(package.contains("from .operations import *"));
+    assert!(package.contains("def available_gates_hqslang() -> List[str]:"));
+}
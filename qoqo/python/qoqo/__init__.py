@@ -26,6 +26,11 @@ qoqo is the HQS python package to represent quantum circuits.
     measurements
     devices
     noise_models
+    interop
+    passes
+    analog
+    backends
+    testing
 """
 
 from .qoqo import *
@@ -0,0 +1,1393 @@
+# This file is generated by qoqo-stubgen from the Rust sources of qoqo.
+# Do not edit it by hand, regenerate it with `cargo run -p qoqo-stubgen` instead.
+
+"""
+Quantum Operation Quantum Operation (qoqo)
+
+Yes, we use reduplication.
+
+qoqo is the HQS python package to represent quantum circuits.
+
+.. autosummary::
+    :toctree: generated/
+
+    Circuit
+    CircuitDag
+    QuantumProgram
+    operations
+    measurements
+    devices
+    noise_models
+    interop
+    passes
+    analog
+    backends
+    testing
+    available_gates_hqslang
+    counts_from_registers
+    register_from_counts
+    registers_to_json
+    registers_from_json
+"""
+
+from typing import Any, Dict, List, Optional, Set, Tuple, Union
+from qoqo_calculator_pyo3 import CalculatorFloat
+from qoqo.operations import Operation
+
+from . import operations as operations
+from . import measurements as measurements
+from . import devices as devices
+from . import noise_models as noise_models
+from .operations import *
+from .measurements import *
+from .devices import *
+from .noise_models import *
+
+__license__: str
+
+class Circuit:
+    """
+    Circuit of Operations.
+
+    A quantum program is represented as a linear sequence of Operations.
+    """
+
+    def __init__(self) -> None:
+        """
+        Create an empty quantum Circuit.
+
+        Returns:
+            self: The new, empty Circuit.
+        """
+
+    def substitute_parameters(self, substitution_parameters: Dict[str, float]) -> Circuit:
+        """
+        Substitute the symbolic parameters in a clone of the Circuit according to the substitution_parameters input.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the Circuit.
+
+        Returns:
+            self: The Circuit with the parameters substituted.
+
+        Raises:
+            RuntimeError: The parameter substitution failed.
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Circuit:
+        """
+        Remap qubits in operations in clone of Circuit.
+
+        Args:
+            mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the Circuit.
+
+        Returns:
+            self: The Circuit with the qubits remapped.
+
+        Raises:
+            RuntimeError: The qubit remapping failed.
+        """
+
+    def remap_registers(self, mapping: Dict[str, str]) -> Circuit:
+        """
+        Rename the classical registers in a clone of the Circuit.
+
+        The registers are renamed consistently in definitions, InputBit operations, measurement operations,
+        PRAGMA measurement operations and the condition registers of conditional PRAGMAs.
+        Registers that are not in the keys of the mapping keep their name.
+
+        Args:
+            mapping (Dict[str, str]): The dictionary containing the {old_name: new_name} mapping of the registers.
+
+        Returns:
+            Circuit: The Circuit with the registers renamed.
+
+        Raises:
+            RuntimeError: A register is mapped onto an existing register or two registers are mapped onto the same name.
+        """
+
+    def validate_pragmas(self) -> None:
+        """
+        Validate the PRAGMA operations setting the quantum state in the Circuit.
+
+        Every PragmaSetStateVector and PragmaSetDensityMatrix, including those in the circuits
+        of operations like PragmaConditional or PragmaLoop, is checked with its `validate` method.
+
+        Raises:
+            ValueError: A PRAGMA operation sets an invalid statevector or density matrix.
+        """
+
+    def check_definitions(self) -> None:
+        """
+        Check that the classical registers of the Circuit are not defined in conflicting ways.
+
+        Definitions with the same type, name and length are merged when they are added to the Circuit.
+        Two definitions of a register with different lengths or different types are a conflict.
+
+        Raises:
+            ValueError: A register is defined in conflicting ways, the message names the register, both lengths and indices.
+        """
+
+    def definitions_map(self) -> Dict[str, Tuple[int, bool]]:
+        """
+        Return the classical registers defined in the Circuit.
+
+        For registers with conflicting definitions the first definition is used.
+
+        Returns:
+            Dict[str, Tuple[int, bool]]: The length and output flag of each register by name.
+        """
+
+    def used_qubits(self) -> Set[int]:
+        """
+        Return the qubits the operations of the Circuit act on.
+
+        Operations acting on all qubits, like PragmaGetStateVector, do not add qubits.
+
+        Returns:
+            Set[int]: The qubits used in the Circuit.
+        """
+
+    def compactify_qubits(self) -> Tuple[Circuit, Dict[int, int]]:
+        """
+        Renumber the used qubits of a clone of the Circuit to 0..n preserving their order.
+
+        When all MeasureQubit operations writing to a readout register use the measured qubit as readout index,
+        the readout indices are renumbered together with the qubits.
+
+        Returns:
+            Tuple[Circuit, Dict[int, int]]: The compacted Circuit and the applied {old_qubit: new_qubit} mapping.
+
+        Raises:
+            RuntimeError: Remapping the qubits of an operation failed.
+        """
+
+    def tensor(self, other: Circuit, qubit_offset: int) -> Circuit:
+        """
+        Append another circuit acting on a disjoint set of qubits.
+
+        All qubits of the other circuit are shifted by qubit_offset before it is appended.
+
+        Args:
+            other (Circuit): The circuit that is appended.
+            qubit_offset (int): The offset added to all qubits of other.
+
+        Returns:
+            Circuit: The combined circuit.
+
+        Raises:
+            TypeError: Other cannot be converted to Circuit.
+            RuntimeError: Register names clash or the shifted qubits overlap with the qubits of self.
+        """
+
+    def compose(self, other: Circuit) -> Circuit:
+        """
+        Append another circuit acting on the same qubits.
+
+        Definitions that are identical in both circuits are only kept once.
+
+        Args:
+            other (Circuit): The circuit that is appended.
+
+        Returns:
+            Circuit: The concatenated circuit.
+
+        Raises:
+            TypeError: Other cannot be converted to Circuit.
+            RuntimeError: The circuits contain different definitions with the same name.
+        """
+
+    def to_latex(self, hide_pragmas: bool = ...) -> str:
+        """
+        Export the circuit to a LaTeX circuit diagram using the quantikz package.
+
+        Every qubit is drawn as one wire. Common gates use their standard symbols, measurements are
+        drawn as meters and all other operations as boxes labelled with their hqslang name.
+
+        Args:
+            hide_pragmas (bool): Do not draw PRAGMA operations. Defaults to False.
+
+        Returns:
+            str: The quantikz environment drawing the circuit.
+
+        Raises:
+            RuntimeError: The circuit could not be drawn.
+        """
+
+    def to_cirq_json(self) -> str:
+        """
+        Export the circuit to the JSON serialization format of Cirq.
+
+        Operations are grouped into Cirq Moments using the parallel blocks of the CircuitDag.
+        Supported operations are PauliX, PauliY, PauliZ, Hadamard, SGate, TGate, RotateX, RotateY, RotateZ,
+        CNOT, ControlledPauliZ, SWAP, ISwap and MeasureQubit, definitions are skipped.
+
+        Returns:
+            str: The Cirq JSON representation of the circuit, can be loaded with `cirq.read_json(json_text=...)`.
+
+        Raises:
+            RuntimeError: The circuit contains operations that cannot be exported to Cirq.
+        """
+
+    def overrotate(self) -> Circuit:
+        """
+        Return clone of the circuit with all overrotation Pragmas applied.
+
+        Returns:
+            Circuit: Circuit with the overrotation applied
+
+        Raises:
+            RuntimeError: Error applying PragmaOverrotation in circuit.
+        Example:
+
+        >>> circuit = Circuit()
+        >>> circuit += PragmaOverrotation("RotateY", [1,], 20.0, 30.0)
+        >>> circuit += RotateX(0, 0.0)
+        >>> circuit += RotateY(0, 1.0)
+        >>> circuit += RotateY(1, 2.0)
+        >>> circuit += RotateY(1, 3.0)
+        >>> circuit_overrotated = circuit.overrotate()
+        print(circuit)
+        print(circuit_overrotated)
+        """
+
+    def semantically_equal(self, other: Circuit, ignore_definition_order: bool = ..., ignore_pragmas: bool = ..., angle_tolerance: Optional[float] = ..., compare_annotations: bool = ...) -> bool:
+        """
+        Compare the circuit to another circuit up to harmless differences.
+
+        Without any option set, the comparison is identical to `==` apart from ignoring PragmaAnnotation operations.
+
+        Args:
+            other (Circuit): The circuit self is compared to.
+            ignore_definition_order (bool): Compare the definitions independent of their order.
+            ignore_pragmas (bool): Ignore PragmaStopParallelBlock and PragmaSleep operations.
+            angle_tolerance (Optional[float]): Treat rotation angles as equal when they agree modulo 2π within this tolerance.
+            compare_annotations (bool): Compare the PragmaAnnotation operations.
+
+        Returns:
+            bool: Whether the two circuits are semantically equal.
+
+        Raises:
+            TypeError: Other cannot be converted to Circuit.
+        """
+
+    def total_global_phase(self) -> CalculatorFloat:
+        """
+        Return the sum of the phases of all PragmaGlobalPhase operations in the circuit.
+
+        Symbolic phases are summed symbolically. PragmaGlobalPhase operations in nested
+        circuits (for example in a PragmaLoop) are not included.
+
+        Returns:
+            CalculatorFloat: The total global phase, zero if the circuit contains no PragmaGlobalPhase.
+        """
+
+    def consolidate_global_phase(self) -> Circuit:
+        """
+        Return a copy of the circuit with all global phases combined into a single PragmaGlobalPhase.
+
+        All PragmaGlobalPhase operations are removed and, if there were any,
+        a single PragmaGlobalPhase with the total global phase is appended at the end.
+
+        Returns:
+            Circuit: The circuit with the consolidated global phase.
+        """
+
+    def annotations(self) -> Dict[str, str]:
+        """
+        Return the annotations of the circuit.
+
+        Collects the keys and values of all PragmaAnnotation operations in the circuit.
+        When a key is annotated several times, the last value is returned.
+
+        Returns:
+            Dict[str, str]: The annotations of the circuit.
+        """
+
+    def annotate(self, key: str, value: str) -> None:
+        """
+        Annotate the circuit with a key-value pair of metadata.
+
+        Appends a PragmaAnnotation to the circuit.
+
+        Args:
+            key (str): The key of the annotation.
+            value (str): The value of the annotation.
+        """
+
+    def count_occurences(self, operations: List[str]) -> int:
+        """
+        Count the number of occurences of a set of operation tags in the circuit.
+
+        Args:
+            operations (List[str]): List of operation tags that should be counted.
+
+        Returns:
+            int: The number of occurences of these operation tags.
+        """
+
+    def size_report(self) -> Dict[str, int]:
+        """
+        Return statistics about the size of the circuit.
+
+        The statistics include operations in nested circuits (e.g. the body of a PragmaLoop).
+
+        Returns:
+            Dict[str, int]: The total number of operations ("number_operations"),
+                            the size of the bincode serialization in bytes ("serialized_bytes"),
+                            the largest dimension of a set statevector or density matrix ("max_state_dimension")
+                            and the maximal nesting depth of circuits in the circuit ("max_nesting_depth").
+        """
+
+    def get_operation_types(self) -> Set[str]:
+        """
+        Return a list of the hqslang names of all operations occuring in the circuit.
+
+        Returns:
+            Set[str]: The operation types in the Circuit.
+        """
+
+    def involved_modes(self) -> Union[Set[int], str]:
+        """
+        Return the set of bosonic modes the circuit acts on.
+
+        Operations not acting on bosonic modes (e.g. pure qubit operations) do not contribute.
+
+        Returns:
+            Union[Set[int], str]: The involved modes as a set or 'All' if all modes are involved.
+        """
+
+    def involved_classical(self) -> Union[Set[Tuple[str, int]], Tuple[str, str]]:
+        """
+        Return the classical register entries the circuit involves.
+
+        Operations containing circuits (e.g. PragmaConditional or PragmaLoop) contribute the entries
+        of their inner circuits. If an operation involves all entries of a register, the first such
+        register is returned, with registers involved up to the number of qubits only returned
+        when no register is involved completely.
+
+        Returns:
+            Union[Set[Tuple[str, int]], Tuple[str, str]]: The involved (register name, index) entries as a set,
+                ('All', name) if all entries of a register are involved or ('AllQubits', name)
+                if the entries of a register up to the number of qubits are involved.
+        """
+
+    def number_of_modes(self) -> int:
+        """
+        Return the number of bosonic modes needed to run the circuit.
+
+        The number of modes is the largest mode index involved in an operation plus one.
+
+        Returns:
+            int: The number of bosonic modes, zero if the circuit acts on no bosonic modes.
+        """
+
+    def __copy__(self) -> Circuit:
+        """
+        Return a copy of the Circuit (copy here produces a deepcopy).
+
+        Returns:
+            Circuit: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> Circuit:
+        """
+        Return a deep copy of the Circuit.
+
+        Returns:
+            Circuit: A deep copy of self.
+        """
+
+    def _qoqo_versions(self) -> Tuple[str, str]:
+        """
+        Return the roqoqo and qoqo versions from when the code was compiled.
+
+        Returns:
+            Tuple[str, str]: The roqoqo and qoqo versions.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the Circuit using the [bincode] crate.
+
+        Returns:
+            ByteArray: The serialized Circuit (in [bincode] form).
+
+        Raises:
+            ValueError: Cannot serialize Circuit to bytes.
+        """
+
+    @staticmethod
+    def from_bincode(input: bytearray) -> Circuit:
+        """
+        Convert the bincode representation of the Circuit to a Circuit using the [bincode] crate.
+
+        Args:
+            input (ByteArray): The serialized Circuit (in [bincode] form).
+
+        Returns:
+            Circuit: The deserialized Circuit.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to Circuit.
+        """
+
+    def to_json(self) -> str:
+        """
+        Return the json representation of the Circuit.
+
+        Returns:
+            str: The serialized form of Circuit.
+
+        Raises:
+            ValueError: Cannot serialize Circuit to json.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    @staticmethod
+    def from_json(json_string: str, strict: bool = ...) -> Circuit:
+        """
+        Convert the json representation of a Circuit to a Circuit.
+
+        Args:
+            input (str): The serialized Circuit in json form.
+            strict (bool): Whether fields unknown to the Circuit or its operations raise an error instead of being ignored.
+
+        Returns:
+            Circuit: The deserialized Circuit.
+
+        Raises:
+            ValueError: Input cannot be deserialized to Circuit or contains unknown fields in strict mode.
+        """
+
+    def get(self, index: int) -> Operation:
+        """
+        Return a copy of the Operation at a certain index of the Circuit.
+
+        Args:
+            index (int): The index of the Operation to get in the Circuit.
+
+        Returns:
+            Operation: The operation at the given index (if it exists).
+
+        Raises:
+            IndexError: Index out of range.
+        """
+
+    def get_slice(self, start: Optional[int], stop: Optional[int]) -> Circuit:
+        """
+        Return the copy of a slice of the Circuit.
+
+        Args:
+            start (Optional[int]): The starting index of the slice (inclusive).
+            stop (Optional[int]): The stopping index of the slice (exclusive).
+
+        Returns:
+            Circuit: The slice of the operations in the Circuit with the specified indices.
+
+        Raises:
+            IndexError: Stop index smaller than start index.
+            IndexError: Stop index out of range.
+            IndexError: Start index out of range.
+        """
+
+    def definitions(self) -> List[Operation]:
+        """
+        Return a list of definitions in the Circuit.
+
+        Definitions need to be unique.
+
+        Returns:
+            List[Operation]: A vector of the definitions in the Circuit.
+        """
+
+    def operations(self) -> List[Operation]:
+        """
+        Return a list of all operations in the Circuit.
+
+        Returns:
+            List[Operation]: A vector of the operations in the Circuit.
+        """
+
+    def filter_by_tag(self, tag: str) -> List[Operation]:
+        """
+        Return a list of operations with given tag.
+
+        Args:
+            tag (str): tag by which to filter operations.
+
+        Returns:
+            List[Operation]: A vector of the operations with the specified tag in the Circuit.
+        """
+
+    def add(self, op: Operation) -> None:
+        """
+        Add an Operation to Circuit.
+
+        Args:
+            op (Operation): The Operation to add to the Circuit.
+        """
+
+    def __iter__(self) -> Any:
+        """
+        Create an iterator of the Circuit.
+
+        Returns:
+            OperationIterator: The Circuit in iterator form.
+        """
+
+    def __len__(self) -> int:
+        """
+        Return the length of the Circuit.
+
+        Returns:
+            int: The length of the Circuit.
+        """
+
+    def __getitem__(self, index: int) -> Operation:
+        """
+        Return a copy of the Operation at a certain index of the Circuit.
+
+        Args:
+            index (int): The index of the Operation to get in the Circuit.
+
+        Returns:
+            Operation: The operation at the given index (if it exists).
+
+        Raises:
+            IndexError: Index out of range.
+        """
+
+    def __setitem__(self, index: int, value: Operation) -> None:
+        """
+        Set an Operation at the specified index in the Circuit.
+
+        Args:
+            index (int): The index of the Operation to set in the Circuit.
+            value (Operation): The Operation to set in the Circuit.
+
+        Raises:
+            TypeError: Cannot convert python object to Operation.
+            IndexError: Index out of range.
+        """
+
+    def __iadd__(self, other: Operation) -> None:
+        """
+        Implement the `+=` (__iadd__) magic method to add a Operation to a Circuit.
+
+        Args:
+            other (Operation): The Operation object to be added to self.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation or Circuit.
+        """
+
+    def __add__(self, other: Any) -> Circuit:
+        """
+        Implement the `+` (__add__) magic method to add two Circuits.
+
+        Args:
+            self (CircuitWrapper): The first Circuit object in this operation.
+            rhs (Circuit): The second Circuit object in this operation.
+
+        Returns:
+            self + rhs (Circuit): the two Circuits added together.
+
+        Raises:
+            TypeError: Left hand side can not be converted to Circuit.
+            TypeError: Right hand side cannot be converted to Operation or Circuit.
+        """
+
+class QuantumProgram:
+    """
+    Represents a quantum program evaluating measurements based on a one or more free float parameters.
+
+    The main use of QuantumProgram is to contain a Measurements implementing [crate::measurements::Measure]
+    that measures expectation values or output registers of [crate::Circuit] quantum circuits that contain
+    symbolic parameters. Circuit with symbolic parameters can not be simulated or executed on real hardware.
+    The symbolic parameters need to be replaced with real floating point numbers first.
+    A QuantumProgram contains a list of the free parameters (`input_parameter_names`) and can automatically
+    replace the parameters with its `run` methods and return the result.
+
+    The QuantumProgram should correspond as closely as possible to a normal multi-parameter function
+    in classical computing that can be called with a set of parameters and returns a result.
+    It is the intended way to interface between normal program code and roqoqo based quantum programs.
+    """
+
+    def __init__(self, measurement: Any, input_parameter_names: List[str]) -> None:
+        """
+        Create a QuantumProgram.
+
+        Args:
+            measurement:
+            input_parameter_names (List[str]):
+
+        Returns:
+            self: The new .
+        """
+
+    def measurement_type(self) -> str:
+        """
+        Returns the name of the measurement type of the QuantumProgram.
+
+        Returns:
+            str: The measurement type, i.e. PauliZProduct, CheatedPauliZProduct, Cheated or ClassicalRegister.
+        """
+
+    def measurement(self) -> Any:
+        """
+        Returns the measurement attribute of the QuantumProgram as Python object.
+
+        Returns:
+            PyObject corresponding to the qoqo measurement type of the QuantumProgram,
+            i.e. PauliZProduct, CheatedPauliZProduct, Cheated or ClassicalRegister.
+        """
+
+    def input_parameter_names(self) -> List[str]:
+        """
+        Returns the input_parameter_names attribute of the qoqo QuantumProgram.
+
+        Returns:
+            List of input parameter names.
+        """
+
+    def validate(self) -> None:
+        """
+        Check that all classical registers used by the measurement are defined and large enough.
+
+        Called automatically by `run` and `run_registers` before the program is sent to the backend.
+
+        Raises:
+            ValueError: A classical register is missing or too small.
+        """
+
+    def run(self, backend: Any, parameters: Optional[List[float]]) -> Any:
+        """
+        Runs the QuantumProgram and returns expectation values.
+
+        Runs the quantum programm for a given set of parameters passed in the same order as the parameters
+        listed in `input_parameter_names` and returns expectation values.
+
+        Args:
+            backend (Backend): The backend the program is executed on.
+            parameters (Optional[List[float]]): List of float  parameters of the function call in order of `input_parameter_names`
+        """
+
+    def run_registers(self, backend: Any, parameters: Optional[List[float]]) -> Any:
+        """
+        Runs the QuantumProgram and returns the classical registers of the quantum program.
+
+        Runs the quantum programm for a given set of parameters passed in the same order as the parameters
+        listed in `input_parameter_names` and returns the classical register output.
+        The classical registers usually contain a record of measurement values for the repeated execution
+        of a [crate::Circuit] quantum circuit for real quantum hardware
+        or the readout of the statevector or the density matrix for simulators.
+
+        Args:
+            backend (Backend): The backend the program is executed on.
+            parameters (Optional[List[float]]): List of float  parameters of the function call in order of `input_parameter_names`
+        """
+
+    def __copy__(self) -> QuantumProgram:
+        """
+        Return a copy of the QuantumProgram (copy here produces a deepcopy).
+
+        Returns:
+            QuantumProgram: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> QuantumProgram:
+        """
+        Return a deep copy of the QuantumProgram.
+
+        Returns:
+            QuantumProgram: A deep copy of self.
+        """
+
+    def _qoqo_versions(self) -> Tuple[str, str]:
+        """
+        Return the roqoqo and qoqo versions from when the code was compiled.
+
+        Returns:
+            Tuple[str, str]: The roqoqo and qoqo versions.
+        """
+
+    def size_report(self) -> Dict[str, int]:
+        """
+        Return statistics about the size of the QuantumProgram.
+
+        The statistics are aggregated over all circuits of the measurement,
+        the byte size is the size of the bincode serialization of the whole QuantumProgram.
+
+        Returns:
+            Dict[str, int]: The total number of operations ("number_operations"),
+                            the size of the bincode serialization in bytes ("serialized_bytes"),
+                            the largest dimension of a set statevector or density matrix ("max_state_dimension")
+                            and the maximal nesting depth of circuits ("max_nesting_depth").
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the QuantumProgram using the [bincode] crate.
+
+        Returns:
+            ByteArray: The serialized QuantumProgram (in [bincode] form).
+
+        Raises:
+            ValueError: Cannot serialize QuantumProgram to bytes.
+        """
+
+    @staticmethod
+    def from_bincode(input: bytearray) -> QuantumProgram:
+        """
+        Convert the bincode representation of the QuantumProgram to a QuantumProgram using the [bincode] crate.
+
+        Args:
+            input (ByteArray): The serialized QuantumProgram (in [bincode] form).
+
+        Returns:
+            QuantumProgram: The deserialized QuantumProgram.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to QuantumProgram.
+        """
+
+    def to_json(self) -> str:
+        """
+        Return the json representation of the QuantumProgram.
+
+        Returns:
+            str: The serialized form of QuantumProgram.
+
+        Raises:
+            ValueError: Cannot serialize QuantumProgram to json.
+        """
+
+    @staticmethod
+    def from_json(input: str, strict: bool = ...) -> QuantumProgram:
+        """
+        Convert the json representation of a QuantumProgram to a QuantumProgram.
+
+        Args:
+            input (str): The serialized QuantumProgram in json form.
+            strict (bool): Whether fields unknown to the QuantumProgram, its measurement or circuits raise an error instead of being ignored.
+
+        Returns:
+            QuantumProgram: The deserialized QuantumProgram.
+
+        Raises:
+            ValueError: Input cannot be deserialized to QuantumProgram or contains unknown fields in strict mode.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+class CircuitDag:
+    """
+    Represents the Direct Acyclic Graph (DAG) of a Circuit.
+    """
+
+    def __init__(self, node_number: int = ..., edge_number: int = ...) -> None:
+        """
+        Create an empty CircuitDag.
+
+        Args:
+            node_number (int): The node max capacity of the new CircuitDag.
+            edge_number (int): The edge max capacity of the new CircuitDag.
+
+        Returns:
+            self: The new, empty CircuitDag.
+        """
+
+    @staticmethod
+    def from_circuit(circuit: Circuit) -> CircuitDag:
+        """
+        Create a CircuitDag from a given Circuit.
+
+        Args:
+            circuit (Circuit): The Circuit to build the new CircuitDag from.
+
+        Returns:
+            self: The new CircuitDag.
+
+        Raises:
+            TypeError: The Python Object cannot be converted to Circuit.
+        """
+
+    def to_circuit(self) -> Circuit:
+        """
+        Transforms the CircuitDag into a Circuit.
+
+        Returns:
+            Circuit: The Circuit containing the operations of the CircuitDag.
+        """
+
+    def add_to_back(self, op: Operation) -> Optional[int]:
+        """
+        Add an Operation to the back of the CircuitDag, if necessary.
+
+        Args:
+            op (Operation): The Operation to add to the back of the CircuitDag.
+
+        Returns:
+            Optional[int]: The NodeIndex of the added operation, None if the operation was not added.
+
+        Raises:
+            TypeError: The Python Object cannot be converted to Operation.
+        """
+
+    def add_to_front(self, op: Operation) -> Optional[int]:
+        """
+        Add an Operation to the front of the CircuitDag, if necessary.
+
+        Args:
+            op (Operation): The Operation to add to the front of the CircuitDag.
+
+        Returns:
+            Optional[int]: The NodeIndex of the added operation, None if the operation was not added.
+
+        Raises:
+            TypeError: The Python Object cannot be converted to Operation.
+        """
+
+    def extend_back(self, ops: List[Operation]) -> List[Optional[int]]:
+        """
+        Add a list of Operations to the back of the CircuitDag in order.
+
+        The operations are only added if all of them can be converted.
+
+        Args:
+            ops (List[Operation]): The Operations to add to the back of the CircuitDag.
+
+        Returns:
+            List[Optional[int]]: The NodeIndices of the added operations, None for operations that were not added.
+
+        Raises:
+            TypeError: A Python Object in the list cannot be converted to Operation.
+        """
+
+    def execution_blocked(self, already_executed: List[int], to_be_executed: int) -> List[int]:
+        """
+        Checks if executing an operation is blocked by any not-yet executed operation.
+
+        Args:
+            already_executed (List[int]): List of NodeIndices of Nodes that have already been executed in the Circuit.
+            to_be_executed (int): NodeIndex of the operation that should be executed next.
+
+        Returns:
+            List[int]: List containing the sorted blocking elements.
+        """
+
+    def blocking_predecessors(self, already_executed: List[int], to_be_executed: int) -> List[int]:
+        """
+        Checks which of the direct predecessors of an Operation in the CircuitDag blocks the execution.
+
+        Warning:
+        This method can only be used to determine if an operation can be executed when `already_executed` is consistent.
+        When the list  `already_executed` is inconsistent (a n operation is reported as executed that could not have been executed yet)
+        this method returning an empty vector does not imply that the `to_be_executed` operation can be executed.
+
+        Args:
+            already_executed (List[int]): List of NodeIndices of Nodes that have already been executed in the Circuit.
+            to_be_executed (int): NodeIndex of the Operation that should be executed next.
+
+        Returns:
+            List[int]: List containing the sorted blocking elements.
+        """
+
+    def new_front_layer(self, already_executed: List[int], current_front_layer: List[int], to_be_executed: int) -> List[int]:
+        """
+        Returns a new front-layer after executing an operation from the current front layer.
+
+        Returns an error if operation to be executed is not in the current front layer.
+
+        Args:
+            already_executed (List[int]): List of NodeIndices of Nodes that have already been executed in the Circuit.
+            current_front_layer (List[int]): List of NodeIndices in the current front layer ready to be executed if physically possible.
+            to_be_executed (int): NodeIndex of the operation that should be executed next.
+        """
+
+    def parallel_blocks(self) -> List[List[int]]:
+        """
+        Returns an iterator over the possible parallel blocks in circuit that can be executed simultaneously
+
+        Returns an Iterator over Vectors of references to the NodeIndices in the parallel block as well
+        as references to the Operation in the blocks
+        """
+
+    def get(self, index: int) -> Operation:
+        """
+        Given a NodeIndex, returns the Operation contained in the node of
+        the CircuitDag.
+
+        Args:
+            index (int): The index of the node to get from the CircuitDag.
+
+        Returns:
+            Operation: The Operation at the given index (if it exists).
+
+        Raises:
+            IndexError: Index out of range.
+        """
+
+    def __copy__(self) -> CircuitDag:
+        """
+        Returns a copy of the CircuitDag (produces a deepcopy).
+
+        Returns:
+            CircuitDag: A copy of self.
+        """
+
+    def _qoqo_versions(self) -> Tuple[str, str]:
+        """
+        Return the roqoqo and qoqo versions from when the code was compiled.
+
+        Returns:
+            Tuple[str, str]: The roqoqo and qoqo versions.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the CircuitDag using the [bincode] crate.
+
+        Returns:
+            ByteArray: The serialized CircuitDag (in [bincode] form).
+
+        Raises:
+            ValueError: Cannot serialize CircuitDag to bytes.
+        """
+
+    @staticmethod
+    def from_bincode(input: bytearray) -> CircuitDag:
+        """
+        Convert the bincode representation of the CircuitDag to a CircuitDag using the [bincode] crate.
+
+        Args:
+            input (ByteArray): The serialized CircuitDag (in [bincode] form).
+
+        Returns:
+            CircuitDag: The deserialized CircuitDag.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to CircuitDag.
+        """
+
+    def successors(self, node: int) -> List[int]:
+        """
+        Returns the list of the successors of a given node in the CircuitDag.
+        """
+
+    def predecessors(self, node: int) -> List[int]:
+        """
+        Returns the direct predecessors in the CircuitDag of a given node.
+
+        Args:
+            node (int): The NodeIndex of the operation whose predecessors are returned.
+
+        Returns:
+            List[int]: The sorted list of the direct predecessors.
+        """
+
+    def ready_after(self, already_executed: List[int]) -> List[int]:
+        """
+        Returns the nodes that can be executed once the given nodes have been executed.
+
+        A node is ready when it has not been executed and all its direct predecessors have been executed.
+
+        Args:
+            already_executed (List[int]): List of NodeIndices of Nodes that have already been executed in the Circuit.
+
+        Returns:
+            List[int]: The sorted list of NodeIndices of the operations ready to be executed.
+        """
+
+    def commuting_operations(self) -> List[int]:
+        """
+        Returns the list of nodes of commuting operations in CircuitDag.
+
+        Returns:
+            List[int]: The list of nodes of commuting operations.
+        """
+
+    def first_parallel_block(self) -> Set[int]:
+        """
+        Returns a set containing the nodes in the first parallel block.
+
+        Returns:
+            Set[int]: The set of nodes in the first parallel block.
+        """
+
+    def last_parallel_block(self) -> Set[int]:
+        """
+        Returns a set containing the nodes in the last parallel block.
+
+        Returns:
+            Set[int]: The set of nodes in the last parallel block.
+        """
+
+    def first_operation_involving_qubit(self) -> Dict[int, int]:
+        """
+        Returns a dictionary where a key represents a qubit and its value represents
+        the first node that involves that qubit.
+
+        Returns:
+            Dict[int, int]: The dictionary of {qubit: node} elements.
+        """
+
+    def last_operation_involving_qubit(self) -> Dict[int, int]:
+        """
+        Returns a dictionary where a key represents a qubit and its value represents
+        the last node that involves that qubit.
+
+        Returns:
+            Dict[int, int]: The dictionary of {qubit: node} elements.
+        """
+
+    def first_operation_involving_classical(self) -> Any:
+        """
+        Returns a dictionary where a key is composed by the name and the size
+        of the classical register and its value represents the first node that involves that
+        register.
+
+        Returns:
+            Dict[(str, int), int]: The dictionary of {(str, int), int} elements.
+        """
+
+    def last_operation_involving_classical(self) -> Any:
+        """
+        Returns a dictionary where a key is composed by the name and the size
+        of the classical register and its value represents the last node that involves that
+        register.
+
+        Returns:
+            Dict[(str, int), int]: The dictionary of {(str, int), int} elements.
+        """
+
+    def qubit_lifetimes(self) -> Dict[int, Tuple[int, int]]:
+        """
+        Returns the lifetime of every qubit in the CircuitDag.
+
+        The lifetime is given by the positions of the first and the last operation involving the qubit
+        in the topological order of the CircuitDag. For a CircuitDag created from a Circuit, the positions
+        are the indices of the operations in the Circuit.
+
+        Returns:
+            Dict[int, Tuple[int, int]]: The dictionary of {qubit: (first, last)} elements.
+        """
+
+    def max_concurrent_qubits(self) -> int:
+        """
+        Returns the maximal number of qubits that are alive at the same time.
+
+        Returns:
+            int: The maximal number of concurrently alive qubits.
+        """
+
+    def repetitions(self, index: int) -> int:
+        """
+        Returns how often the operation of a node is executed.
+
+        The circuit of a PragmaLoop is executed the number of repetitions of the loop,
+        all other operations are executed once.
+
+        Args:
+            index (int): The index of the node in the CircuitDag.
+
+        Returns:
+            int: The number of executions of the operation.
+
+        Raises:
+            ValueError: The node is not in the CircuitDag or is a PragmaLoop with symbolic repetitions.
+        """
+
+    def depth(self) -> int:
+        """
+        Returns the depth of the CircuitDag.
+
+        A PragmaLoop contributes the depth of its circuit times its number of repetitions.
+
+        Returns:
+            int: The number of operations on the longest path through the CircuitDag.
+
+        Raises:
+            ValueError: A PragmaLoop has symbolic repetitions.
+        """
+
+    def execution_time(self, device: Any) -> float:
+        """
+        Returns the time needed to execute the CircuitDag on a device.
+
+        A PragmaLoop takes the execution time of its circuit times its number of repetitions.
+
+        Args:
+            device: The device providing the gate times.
+
+        Returns:
+            float: The execution time of the CircuitDag.
+
+        Raises:
+            TypeError: Input cannot be converted to a device.
+            ValueError: A gate is not available on the device or a PragmaLoop has symbolic repetitions.
+        """
+
+class ScheduledCircuit:
+    """
+    A Circuit together with the start times of its operations.
+
+    The start times are given in the order of the operations when iterating over the Circuit,
+    i.e. the definitions first, followed by the other operations.
+    A ScheduledCircuit is created by scheduling a Circuit on a device with schedule_asap.
+    """
+
+    def circuit(self) -> Circuit:
+        """
+        Return the scheduled Circuit.
+
+        Returns:
+            Circuit: The scheduled Circuit.
+        """
+
+    def start_times(self) -> List[float]:
+        """
+        Return the start times of the operations of the scheduled Circuit.
+
+        Returns:
+            List[float]: The start time of each operation in the order of the Circuit.
+        """
+
+    def durations(self) -> List[float]:
+        """
+        Return the durations of the operations of the scheduled Circuit.
+
+        Returns:
+            List[float]: The time each operation takes in the order of the Circuit.
+        """
+
+    def total_duration(self) -> float:
+        """
+        Return the total duration of the scheduled Circuit.
+
+        Returns:
+            float: The latest end time of all operations, zero for an empty Circuit.
+        """
+
+    def qubit_assignment(self) -> Optional[Dict[int, int]]:
+        """
+        Return the mapping of the qubits of the Circuit to the qubits of the device.
+
+        Returns:
+            Optional[Dict[int, int]]: The qubit assignment, None if the qubits are not remapped.
+        """
+
+    def to_timeline_json(self) -> str:
+        """
+        Export the timeline of the scheduled Circuit to json for rendering a Gantt chart.
+
+        Returns:
+            str: A json list with the fields `qubits`, `hqslang`, `start`, `duration` and `index` for each operation.
+        """
+
+    def per_qubit_timeline(self) -> Dict[int, List[Dict[str, Any]]]:
+        """
+        Return the timeline entries grouped by qubit and sorted by start time.
+
+        Each entry is a dict with the fields `qubits`, `hqslang`, `start`, `duration` and `index`.
+        Operations without qubits are not part of any qubit row.
+
+        Returns:
+            Dict[int, List[Dict[str, Any]]]: The timeline entries of each qubit.
+
+        Raises:
+            ValueError: Two operations on the same qubit overlap in time.
+        """
+
+    def __copy__(self) -> ScheduledCircuit:
+        """
+        Return a copy of the ScheduledCircuit (copy here produces a deepcopy).
+
+        Returns:
+            ScheduledCircuit: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> ScheduledCircuit:
+        """
+        Return a deep copy of the ScheduledCircuit.
+
+        Returns:
+            ScheduledCircuit: A deep copy of self.
+        """
+
+    def to_json(self) -> str:
+        """
+        Return the json representation of the ScheduledCircuit.
+
+        Returns:
+            str: The serialized form of ScheduledCircuit.
+
+        Raises:
+            ValueError: Cannot serialize ScheduledCircuit to json.
+        """
+
+    @staticmethod
+    def from_json(input: str) -> ScheduledCircuit:
+        """
+        Convert the json representation of a ScheduledCircuit to a ScheduledCircuit.
+
+        Args:
+            input (str): The serialized ScheduledCircuit in json form.
+
+        Returns:
+            ScheduledCircuit: The deserialized ScheduledCircuit.
+
+        Raises:
+            ValueError: Input cannot be deserialized to ScheduledCircuit.
+        """
+
+def schedule_asap(circuit: Circuit, device: Any) -> ScheduledCircuit:
+    """
+    Schedule the operations of a Circuit as soon as possible on a device.
+
+    Every operation starts as soon as all operations it depends on in the CircuitDag of the Circuit
+    have finished. Gates take the gate time of the device, noise PRAGMAs their gate time and
+    PragmaSleep its sleep time. Operations without a defined time, like measurements, take no time.
+
+    Args:
+        circuit (Circuit): The Circuit that is scheduled.
+        device (Device): The device providing the gate times.
+
+    Returns:
+        ScheduledCircuit: The Circuit with the start times of its operations.
+
+    Raises:
+        TypeError: Input cannot be converted to Circuit or device.
+        ValueError: A gate of the Circuit is not available on the device.
+    """
+
+def available_gates_hqslang() -> List[str]:
+    """
+    List of hqslang of all available gates
+    """
+
+def counts_from_registers(registers: Union[Dict[str, List[List[bool]]], Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]], readout: str, bit_order: str = ...) -> Dict[str, int]:
+    """
+    Return the counts dictionary of a bit register returned by a backend.
+
+    Args:
+        registers (Union[Dict[str, List[List[bool]]], Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]]): The bit registers or the registers returned by running a circuit.
+        readout (str): The name of the bit register.
+        bit_order (str): "msb" to write the bit of qubit 0 last, "lsb" to write it first. Defaults to "msb".
+
+    Returns:
+        Dict[str, int]: The number of measurement records for each measured bitstring.
+
+    Raises:
+        TypeError: The registers cannot be converted to bit registers.
+        KeyError: The registers contain no bit register with the name readout.
+        ValueError: The bit order is neither "msb" nor "lsb".
+    """
+
+def register_from_counts(counts: Dict[str, int], number_shots: int, bit_order: str = ...) -> List[List[bool]]:
+    """
+    Construct a bit register from a counts dictionary.
+
+    When number_shots equals the total of the counts, each bitstring appears as often as it is counted.
+    Otherwise the counts are scaled to number_shots measurement records.
+
+    Args:
+        counts (Dict[str, int]): The number of measurement records for each bitstring.
+        number_shots (int): The number of measurement records in the returned register.
+        bit_order (str): "msb" if the bit of qubit 0 is written last, "lsb" if it is written first. Defaults to "msb".
+
+    Returns:
+        List[List[bool]]: The bit register sorted by bitstring.
+
+    Raises:
+        ValueError: The bit order or a bitstring is invalid, or all counts are zero.
+    """
+
+def registers_to_json(registers: Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]], lossy: bool = ...) -> str:
+    """
+    Serialize the registers returned by running a circuit to json.
+
+    The registers are written as a list of the bit, float and complex registers, each a dictionary sorted by register name.
+    JSON has no representation of NaN and infinite values. With lossy=True they are written as the
+    strings "NaN", "Infinity" and "-Infinity", otherwise they raise a ValueError.
+
+    Args:
+        registers (Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]): The registers returned by running a circuit.
+        lossy (bool): Whether values that are not finite are written as strings. Defaults to False.
+
+    Returns:
+        str: The serialized registers.
+
+    Raises:
+        TypeError: The input cannot be converted to registers.
+        ValueError: A register contains a value that is not finite and lossy is False.
+    """
+
+def registers_from_json(input: str) -> Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]:
+    """
+    Deserialize registers from json.
+
+    Reads values that are not finite written as the strings "NaN", "Infinity" and "-Infinity".
+
+    Args:
+        input (str): The serialized registers.
+
+    Returns:
+        Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]: The bit, float and complex registers.
+
+    Raises:
+        ValueError: The input is not valid json of registers.
+    """
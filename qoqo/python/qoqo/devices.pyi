@@ -1,14 +1,13 @@
-# This is an auto generated file containing only the documentation.
-# You can find the full implementation on this page:
-# https://github.com/HQSquantumsimulations/qoqo
+# This file is generated by qoqo-stubgen from the Rust sources of qoqo.
+# Do not edit it by hand, regenerate it with `cargo run -p qoqo-stubgen` instead.
 
-"""
+r"""
 Devices in qoqo have two use cases:
 
 * Abstract devices: Contain abstract information for the model of a quantum computer and its parameters.
     They can be used to determine which Operations are available on a specific device model.
     A typical example are abstract linear chains of square lattices in which two-qubit operations are only
-    available between neighbouring qubits.  
+    available between neighbouring qubits.
 
     The abstract devices can also encode a noise model. Qoqo noise models are in general based on a (pseudo) time
     needed to execute a quantum operation and Lindblad rates for the qubits in the device.
@@ -31,14 +30,18 @@ The devices were introduced after qoqo 1.0.0, but their design may be refactored
 
 .. autosummary::
     :toctree: generated/
-    
+
     AllToAllDevice
     GenericDevice
     SquareLatticeDevice
+    gate_time
+    add_active_gate_noise
 """
 
 import numpy
-from typing import Optional, List, Sequence
+from typing import Any, List, Optional, Tuple
+from qoqo import Circuit
+from qoqo.operations import Operation
 
 class AllToAllDevice:
     """
@@ -51,18 +54,21 @@ class AllToAllDevice:
         default_gate_time (float): The default startig gate time.
     """
 
-    def __init__(
-        self,
-        number_qubits: int,
-        single_qubit_gates: List[str],
-        two_qubit_gates: List[str],
-        default_gate_time: float,
-    ):
-        return
+    def __init__(self, number_qubits: int, single_qubit_gates: List[str], two_qubit_gates: List[str], default_gate_time: float) -> None:
+        """
+        Create new AllToAllDevice device
+
+        Args:
+            number_qubits (int): The fixed number of qubits.
+            single_qubit_gates (List[str]): A list of 'hqslang' names of single-qubit-gates supported by the device.
+            two_qubit_gates (List[str]): A list of 'hqslang' names of basic two-qubit-gates supported by the device.
+            default_gate_time (float): The default starting gate time.
+
+        Returns:
+            Self: The new device with the new properties
+        """
 
-    def set_all_two_qubit_gate_times(
-        self, gate: str, gate_time: float
-    ) -> AllToAllDevice:
+    def set_all_two_qubit_gate_times(self, gate: str, gate_time: float) -> AllToAllDevice:
         """
         Set gate time of all single-qubit gates of specific type
 
@@ -72,12 +78,9 @@ class AllToAllDevice:
 
         Returns:
             Self: The new device with the new properties.
-
         """
 
-    def set_all_single_qubit_gate_times(
-        self, gate: str, gate_time: float
-    ) -> AllToAllDevice:
+    def set_all_single_qubit_gate_times(self, gate: str, gate_time: float) -> AllToAllDevice:
         """
         Set gate time of all single-qubit gates of specific type
 
@@ -87,10 +90,9 @@ class AllToAllDevice:
 
         Returns:
             Self: The new device with the new properties
-
         """
 
-    def set_all_qubit_decoherence_rates(self, rates) -> AllToAllDevice:
+    def set_all_qubit_decoherence_rates(self, rates: numpy.ndarray) -> AllToAllDevice:
         """
         Function to set the decoherence rates for all qubits in the AllToAllDevice device.
 
@@ -137,7 +139,126 @@ class AllToAllDevice:
             Self: The new device with the new properties
         """
 
-    def json_schema(self) -> str:
+    def with_single_qubit_gate_time(self, gate: str, gate_time: float) -> AllToAllDevice:
+        """
+        Set a uniform gate time for a single-qubit-gate, adding the gate if it is not available yet.
+
+        Args:
+            gate (str): The hqslang name of the single-qubit-gate.
+            gate_time (float): Gate time for the given gate, valid for all qubits in the device.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def with_two_qubit_gate_time(self, gate: str, gate_time: float) -> AllToAllDevice:
+        """
+        Set a uniform gate time for a two-qubit-gate, adding the gate if it is not available yet.
+
+        Args:
+            gate (str): The hqslang name of the two-qubit-gate.
+            gate_time (float): Gate time for the given gate, valid for all edges in the device.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def without_single_qubit_gate(self, gate: str) -> AllToAllDevice:
+        """
+        Remove a single-qubit-gate from the available gates of the device.
+
+        Args:
+            gate (str): The hqslang name of the single-qubit-gate.
+
+        Returns:
+            Self: The new device without the gate
+        """
+
+    def without_two_qubit_gate(self, gate: str) -> AllToAllDevice:
+        """
+        Remove a two-qubit-gate from the available gates of the device.
+
+        Args:
+            gate (str): The hqslang name of the two-qubit-gate.
+
+        Returns:
+            Self: The new device without the gate
+        """
+
+    def with_damping_rates(self, damping: float) -> AllToAllDevice:
+        """
+        Add damping to the noise rates of all qubits.
+
+        Args:
+            damping (float): The damping rate.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def with_dephasing_rates(self, dephasing: float) -> AllToAllDevice:
+        """
+        Add dephasing to the noise rates of all qubits.
+
+        Args:
+            dephasing (float): The dephasing rate.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def with_depolarising_rates(self, depolarising: float) -> AllToAllDevice:
+        """
+        Add depolarising noise to the noise rates of all qubits.
+
+        Args:
+            depolarising (float): The depolarising rate.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    @staticmethod
+    def from_config_json(config: str) -> AllToAllDevice:
+        """
+        Create a AllToAllDevice from a human-editable json config.
+
+        The config contains default gate times per gate, overrides of the gate times on single qubits,
+        edges or sets of qubits, and damping, dephasing and depolarising rates with per-qubit overrides.
+        Calibrated gate errors are not part of the config.
+
+        Args:
+            config (str): The json config of the device.
+
+        Returns:
+            AllToAllDevice: The device described by the config.
+
+        Raises:
+            ValueError: The config cannot be deserialized or contains invalid qubits.
+        """
+
+    def to_config_json(self) -> str:
+        """
+        Return the human-editable json config of the device.
+
+        Returns:
+            str: The json config of the device.
+
+        Raises:
+            ValueError: The decoherence rates of a qubit cannot be expressed in the config.
+        """
+
+    @staticmethod
+    def config_json_schema() -> str:
+        """
+        Return the JsonSchema of the json config of the device.
+
+        Returns:
+            str: The json schema of the config serialized to json
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -145,7 +266,8 @@ class AllToAllDevice:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -169,7 +291,7 @@ class AllToAllDevice:
             int: The number of qubits.
         """
 
-    def two_qubit_edges(self) -> Sequence[(int, int)]:
+    def two_qubit_edges(self) -> List[Tuple[int, int]]:
         """
         Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
 
@@ -185,8 +307,7 @@ class AllToAllDevice:
         choice from a list of edges and can be used for applications like routing in quantum algorithms.
 
         Returns:
-            Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
-
+            List[Tuple[int, int]]: List of two qubit edges in the undirected connectivity graph
         """
 
     def single_qubit_gate_time(self, hqslang: str, qubit: int) -> Optional[float]:
@@ -204,9 +325,7 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def two_qubit_gate_time(
-        self, hqslang: str, control: int, target: int
-    ) -> Optional[float]:
+    def two_qubit_gate_time(self, hqslang: str, control: int, target: int) -> Optional[float]:
         """
         Returns the gate time of a two qubit operation if the two qubit operation is available on device.
 
@@ -220,16 +339,10 @@ class AllToAllDevice:
 
         Raises:
             PyValueError: Qubit is not in device
-
         """
 
-    def three_qubit_gate_time(
-        self, hqslang: str, control_0: int, control_1: int, target: int
-    ) -> Optional[float]:
+    def three_qubit_gate_time(self, hqslang: str, control_0: int, control_1: int, target: int) -> Optional[float]:
         """
-        three_qubit_gate_time(gate, control_0, control_1, target
-        --
-
         Returns the gate time of a three qubit operation if the three qubit operation is available on device.
 
         Args:
@@ -243,7 +356,6 @@ class AllToAllDevice:
 
         Raises:
             PyValueError: Qubit is not in device
-
         """
 
     def multi_qubit_gate_time(self, hqslang: str, qubits: List[int]) -> Optional[float]:
@@ -261,7 +373,32 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_single_qubit_gate_time(self, gate: str, qubit: int, gate_time: float):
+    def single_qubit_gate_error(self, hqslang: str, qubit: int) -> Optional[float]:
+        """
+        Returns the calibrated error rate of a single qubit operation if it is known for the device.
+
+        Args:
+            hqslang (str): The hqslang name of a single qubit gate.
+            qubit (int): The qubit the gate acts on
+
+        Returns:
+            Option[float]: None if no error rate is known for the gate
+        """
+
+    def two_qubit_gate_error(self, hqslang: str, control: int, target: int) -> Optional[float]:
+        """
+        Returns the calibrated error rate of a two qubit operation if it is known for the device.
+
+        Args:
+            hqslang (str): The hqslang name of a two qubit gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Option[float]: None if no error rate is known for the gate
+        """
+
+    def set_single_qubit_gate_time(self, gate: str, qubit: int, gate_time: float) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -274,9 +411,7 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_two_qubit_gate_time(
-        self, gate: str, control: int, target: int, gate_time: float
-    ):
+    def set_two_qubit_gate_time(self, gate: str, control: int, target: int, gate_time: float) -> None:
         """
         Set the gate time of a two qubit gate.
 
@@ -290,9 +425,7 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_three_qubit_gate_time(
-        self, gate: str, control_0: int, control_1: int, target: int, gate_time: float
-    ):
+    def set_three_qubit_gate_time(self, gate: str, control_0: int, control_1: int, target: int, gate_time: float) -> None:
         """
         Set the gate time of a three qubit gate.
 
@@ -307,7 +440,38 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_qubit_decoherence_rates(self, gate: str, qubits: int, gate_time: float):
+    def set_single_qubit_gate_error(self, gate: str, qubit: int, error: float) -> None:
+        """
+        Set the calibrated error rate of a single qubit gate.
+
+        Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            gate (str): hqslang name of the single-qubit-gate.
+            qubit (int): The qubit for which the gate error is set
+            error (float): The error rate of the gate, between 0 and 1.
+
+        Raises:
+            PyValueError: Qubit is not in device or error is not between 0 and 1
+        """
+
+    def set_two_qubit_gate_error(self, gate: str, control: int, target: int, error: float) -> None:
+        """
+        Set the calibrated error rate of a two qubit gate.
+
+        Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            gate (str): hqslang name of the two-qubit-gate.
+            control (int): The control qubit for which the gate error is set
+            target (int): The target qubit for which the gate error is set
+            error (float): The error rate of the gate, between 0 and 1.
+
+        Raises:
+            PyValueError: Qubits are not in device or error is not between 0 and 1
+        """
+
+    def set_qubit_decoherence_rates(self, qubit: int, rates: numpy.ndarray) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -320,7 +484,7 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_multi_qubit_gate_time(self, gate: str, qubits: List[int], gate_time: float):
+    def set_multi_qubit_gate_time(self, gate: str, qubits: List[int], gate_time: float) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -333,7 +497,7 @@ class AllToAllDevice:
             PyValueError: Qubits not in device
         """
 
-    def qubit_decoherence_rates(self, qubit: int) -> numpy.array:
+    def qubit_decoherence_rates(self, qubit: int) -> numpy.ndarray:
         """
         Return the matrix of the decoherence rates of the Lindblad equation.
 
@@ -342,10 +506,39 @@ class AllToAllDevice:
 
         Returns:
             np.array: 3 by 3 numpy array of decoherence rates
+        """
 
+    def single_qubit_gate_time_matrix(self, hqslang: str) -> numpy.ndarray:
         """
+        Return the gate times of a single qubit gate on all qubits of the device.
 
-    def add_damping(self, qubit: int, damping: float):
+        Args:
+            hqslang (str): The hqslang name of a single qubit gate.
+
+        Returns:
+            np.ndarray: The gate time on each qubit, NaN where the gate is not available.
+        """
+
+    def two_qubit_gate_time_matrix(self, hqslang: str) -> numpy.ndarray:
+        """
+        Return the gate times of a two qubit gate on all pairs of qubits of the device.
+
+        Args:
+            hqslang (str): The hqslang name of a two qubit gate.
+
+        Returns:
+            np.ndarray: The gate time with the control qubit as row and the target qubit as column, NaN where the gate is not available.
+        """
+
+    def qubit_decoherence_rates_all(self) -> numpy.ndarray:
+        """
+        Return the decoherence rates of all qubits of the device.
+
+        Returns:
+            np.ndarray: number_qubits by 3 by 3 numpy array of decoherence rates, zero for qubits without decoherence rates.
+        """
+
+    def add_damping(self, qubit: int, damping: float) -> None:
         """
         Adds single qubit damping to noise rates.
 
@@ -357,7 +550,7 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def add_dephasing(self, qubit: int, dephasing: float):
+    def add_dephasing(self, qubit: int, dephasing: float) -> None:
         """
         Adds single qubit dephasing to noise rates.
 
@@ -369,7 +562,7 @@ class AllToAllDevice:
             PyValueError: Qubit is not in device
         """
 
-    def add_depolarising(self, qubit: int, depolarising: float):
+    def add_depolarising(self, qubit: int, depolarising: float) -> None:
         """
         Adds single qubit depolarising to noise rates.
 
@@ -435,7 +628,25 @@ class AllToAllDevice:
 
         Returns:
             List[str]: The list of gate names.
+        """
+
+    def __copy__(self) -> AllToAllDevice:
+        """
+        Return a copy of the device (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> AllToAllDevice:
+        """
+        Return a deep copy of the device.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
 
+        Returns:
+            Self: A deep copy of self.
         """
 
     def to_bincode(self) -> bytearray:
@@ -447,7 +658,6 @@ class AllToAllDevice:
 
         Raises:
             ValueError: Cannot serialize Device to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -459,54 +669,66 @@ class AllToAllDevice:
 
         Raises:
             ValueError: Cannot serialize Device to json.
+        """
 
+class GenericDevice:
+    """
+    A generic device assuming all-to-all connectivity between all involved qubits.
+
+    Args:
+        number_qubits (int): The number of qubits in the device
+
+    Note:
+        GenericDevice uses nested HashMaps to represent the most general device connectivity.
+        The memory usage will be inefficient for devices with large qubit numbers.
+    """
+
+    def __init__(self, number_qubits: int) -> None:
+        """
+        Create new generic device
         """
 
-    def from_bincode(self, input: bytearray):
+    @staticmethod
+    def from_config_json(config: str) -> GenericDevice:
         """
-        Convert the bincode representation of the qoqo device to a device using the bincode crate.
+        Create a GenericDevice from a human-editable json config.
+
+        The config contains default gate times per gate, overrides of the gate times on single qubits,
+        edges or sets of qubits, and damping, dephasing and depolarising rates with per-qubit overrides.
+        Calibrated gate errors are not part of the config.
 
         Args:
-            input (ByteArray): The serialized Device (in bincode form).
+            config (str): The json config of the device.
 
         Returns:
-            The deserialized Device.
+            GenericDevice: The device described by the config.
 
         Raises:
-            TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized to selected Device.
+            ValueError: The config cannot be deserialized or contains invalid qubits.
         """
 
-    def from_json(self, input: str):
+    def to_config_json(self) -> str:
         """
-        Convert the json representation of a device to a qoqo device.
-
-        Args:
-            input (str): The serialized device in json form.
+        Return the human-editable json config of the device.
 
         Returns:
-            The deserialized device.
+            str: The json config of the device.
 
         Raises:
-            ValueError: Input cannot be deserialized to selected Device.
+            ValueError: The decoherence rates of a qubit cannot be expressed in the config.
         """
 
-class GenericDevice:
-    """
-    A generic device assuming all-to-all connectivity between all involved qubits.
-
-    Args:
-        number_qubits (int): The number of qubits in the device
-
-    Note:
-        GenericDevice uses nested HashMaps to represent the most general device connectivity.
-        The memory usage will be inefficient for devices with large qubit numbers.
-    """
+    @staticmethod
+    def config_json_schema() -> str:
+        """
+        Return the JsonSchema of the json config of the device.
 
-    def __init__(self, number_qubits: int):
-        return
+        Returns:
+            str: The json schema of the config serialized to json
+        """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -514,7 +736,8 @@ class GenericDevice:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -538,7 +761,7 @@ class GenericDevice:
             int: The number of qubits.
         """
 
-    def two_qubit_edges(self) -> Sequence[(int, int)]:
+    def two_qubit_edges(self) -> List[Tuple[int, int]]:
         """
         Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
 
@@ -554,8 +777,7 @@ class GenericDevice:
         choice from a list of edges and can be used for applications like routing in quantum algorithms.
 
         Returns:
-            Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
-
+            List[Tuple[int, int]]: List of two qubit edges in the undirected connectivity graph
         """
 
     def single_qubit_gate_time(self, hqslang: str, qubit: int) -> Optional[float]:
@@ -573,9 +795,7 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def two_qubit_gate_time(
-        self, hqslang: str, control: int, target: int
-    ) -> Optional[float]:
+    def two_qubit_gate_time(self, hqslang: str, control: int, target: int) -> Optional[float]:
         """
         Returns the gate time of a two qubit operation if the two qubit operation is available on device.
 
@@ -589,16 +809,10 @@ class GenericDevice:
 
         Raises:
             PyValueError: Qubit is not in device
-
         """
 
-    def three_qubit_gate_time(
-        self, hqslang: str, control_0: int, control_1: int, target: int
-    ) -> Optional[float]:
+    def three_qubit_gate_time(self, hqslang: str, control_0: int, control_1: int, target: int) -> Optional[float]:
         """
-        three_qubit_gate_time(gate, control_0, control_1, target
-        --
-
         Returns the gate time of a three qubit operation if the three qubit operation is available on device.
 
         Args:
@@ -612,7 +826,6 @@ class GenericDevice:
 
         Raises:
             PyValueError: Qubit is not in device
-
         """
 
     def multi_qubit_gate_time(self, hqslang: str, qubits: List[int]) -> Optional[float]:
@@ -630,7 +843,32 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_single_qubit_gate_time(self, gate: str, qubit: int, gate_time: float):
+    def single_qubit_gate_error(self, hqslang: str, qubit: int) -> Optional[float]:
+        """
+        Returns the calibrated error rate of a single qubit operation if it is known for the device.
+
+        Args:
+            hqslang (str): The hqslang name of a single qubit gate.
+            qubit (int): The qubit the gate acts on
+
+        Returns:
+            Option[float]: None if no error rate is known for the gate
+        """
+
+    def two_qubit_gate_error(self, hqslang: str, control: int, target: int) -> Optional[float]:
+        """
+        Returns the calibrated error rate of a two qubit operation if it is known for the device.
+
+        Args:
+            hqslang (str): The hqslang name of a two qubit gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Option[float]: None if no error rate is known for the gate
+        """
+
+    def set_single_qubit_gate_time(self, gate: str, qubit: int, gate_time: float) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -643,9 +881,7 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_two_qubit_gate_time(
-        self, gate: str, control: int, target: int, gate_time: float
-    ):
+    def set_two_qubit_gate_time(self, gate: str, control: int, target: int, gate_time: float) -> None:
         """
         Set the gate time of a two qubit gate.
 
@@ -659,9 +895,7 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_three_qubit_gate_time(
-        self, gate: str, control_0: int, control_1: int, target: int, gate_time: float
-    ):
+    def set_three_qubit_gate_time(self, gate: str, control_0: int, control_1: int, target: int, gate_time: float) -> None:
         """
         Set the gate time of a three qubit gate.
 
@@ -676,7 +910,38 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_qubit_decoherence_rates(self, gate: str, qubits: int, gate_time: float):
+    def set_single_qubit_gate_error(self, gate: str, qubit: int, error: float) -> None:
+        """
+        Set the calibrated error rate of a single qubit gate.
+
+        Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            gate (str): hqslang name of the single-qubit-gate.
+            qubit (int): The qubit for which the gate error is set
+            error (float): The error rate of the gate, between 0 and 1.
+
+        Raises:
+            PyValueError: Qubit is not in device or error is not between 0 and 1
+        """
+
+    def set_two_qubit_gate_error(self, gate: str, control: int, target: int, error: float) -> None:
+        """
+        Set the calibrated error rate of a two qubit gate.
+
+        Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            gate (str): hqslang name of the two-qubit-gate.
+            control (int): The control qubit for which the gate error is set
+            target (int): The target qubit for which the gate error is set
+            error (float): The error rate of the gate, between 0 and 1.
+
+        Raises:
+            PyValueError: Qubits are not in device or error is not between 0 and 1
+        """
+
+    def set_qubit_decoherence_rates(self, qubit: int, rates: numpy.ndarray) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -689,7 +954,7 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_multi_qubit_gate_time(self, gate: str, qubits: List[int], gate_time: float):
+    def set_multi_qubit_gate_time(self, gate: str, qubits: List[int], gate_time: float) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -702,7 +967,7 @@ class GenericDevice:
             PyValueError: Qubits not in device
         """
 
-    def qubit_decoherence_rates(self, qubit: int) -> numpy.array:
+    def qubit_decoherence_rates(self, qubit: int) -> numpy.ndarray:
         """
         Return the matrix of the decoherence rates of the Lindblad equation.
 
@@ -711,10 +976,39 @@ class GenericDevice:
 
         Returns:
             np.array: 3 by 3 numpy array of decoherence rates
+        """
+
+    def single_qubit_gate_time_matrix(self, hqslang: str) -> numpy.ndarray:
+        """
+        Return the gate times of a single qubit gate on all qubits of the device.
+
+        Args:
+            hqslang (str): The hqslang name of a single qubit gate.
 
+        Returns:
+            np.ndarray: The gate time on each qubit, NaN where the gate is not available.
         """
 
-    def add_damping(self, qubit: int, damping: float):
+    def two_qubit_gate_time_matrix(self, hqslang: str) -> numpy.ndarray:
+        """
+        Return the gate times of a two qubit gate on all pairs of qubits of the device.
+
+        Args:
+            hqslang (str): The hqslang name of a two qubit gate.
+
+        Returns:
+            np.ndarray: The gate time with the control qubit as row and the target qubit as column, NaN where the gate is not available.
+        """
+
+    def qubit_decoherence_rates_all(self) -> numpy.ndarray:
+        """
+        Return the decoherence rates of all qubits of the device.
+
+        Returns:
+            np.ndarray: number_qubits by 3 by 3 numpy array of decoherence rates, zero for qubits without decoherence rates.
+        """
+
+    def add_damping(self, qubit: int, damping: float) -> None:
         """
         Adds single qubit damping to noise rates.
 
@@ -726,7 +1020,7 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def add_dephasing(self, qubit: int, dephasing: float):
+    def add_dephasing(self, qubit: int, dephasing: float) -> None:
         """
         Adds single qubit dephasing to noise rates.
 
@@ -738,7 +1032,7 @@ class GenericDevice:
             PyValueError: Qubit is not in device
         """
 
-    def add_depolarising(self, qubit: int, depolarising: float):
+    def add_depolarising(self, qubit: int, depolarising: float) -> None:
         """
         Adds single qubit depolarising to noise rates.
 
@@ -804,60 +1098,47 @@ class GenericDevice:
 
         Returns:
             List[str]: The list of gate names.
-
         """
 
-    def to_bincode(self) -> bytearray:
+    def __copy__(self) -> GenericDevice:
         """
-        Return the bincode representation of the Device using the bincode crate.
+        Return a copy of the device (copy here produces a deepcopy).
 
         Returns:
-            ByteArray: The serialized Device (in bincode form).
-
-        Raises:
-            ValueError: Cannot serialize Device to bytes.
-
+            Self: A deep copy of self.
         """
 
-    def to_json(self) -> str:
+    def __deepcopy__(self, _memodict: dict) -> GenericDevice:
         """
-        Return the json representation of the Device.
+        Return a deep copy of the device.
 
-        Returns:
-            str: The serialized form of Device.
-
-        Raises:
-            ValueError: Cannot serialize Device to json.
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
 
+        Returns:
+            Self: A deep copy of self.
         """
 
-    def from_bincode(self, input: bytearray):
+    def to_bincode(self) -> bytearray:
         """
-        Convert the bincode representation of the qoqo device to a device using the bincode crate.
-
-        Args:
-            input (ByteArray): The serialized Device (in bincode form).
+        Return the bincode representation of the Device using the bincode crate.
 
         Returns:
-            The deserialized Device.
+            ByteArray: The serialized Device (in bincode form).
 
         Raises:
-            TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized to selected Device.
+            ValueError: Cannot serialize Device to bytes.
         """
 
-    def from_json(self, input: str):
+    def to_json(self) -> str:
         """
-        Convert the json representation of a device to a qoqo device.
-
-        Args:
-            input (str): The serialized device in json form.
+        Return the json representation of the Device.
 
         Returns:
-            The deserialized device.
+            str: The serialized form of Device.
 
         Raises:
-            ValueError: Input cannot be deserialized to selected Device.
+            ValueError: Cannot serialize Device to json.
         """
 
 class SquareLatticeDevice:
@@ -872,15 +1153,20 @@ class SquareLatticeDevice:
         default_gate_time (float): The default startig gate time.
     """
 
-    def __init__(
-        self,
-        number_rows: int,
-        number_columns: int,
-        single_qubit_gates: List[str],
-        two_qubit_gates: List[str],
-        default_gate_time: float,
-    ):
-        return
+    def __init__(self, number_rows: int, number_columns: int, single_qubit_gates: List[str], two_qubit_gates: List[str], default_gate_time: float) -> None:
+        """
+        Create new SquareLatticeDevice device
+
+        Args:
+            number_rows (int): The fixed number of rows in device, needs to be the same for all layouts.
+            number_columns (int): Fixed number of tweezers in each row, needs to be the same for all layouts.
+            single_qubit_gates (List[str]): A list of 'hqslang' names of single-qubit-gates supported by the device.
+            two_qubit_gates (List[str]): A list of 'hqslang' names of basic two-qubit-gates supported by the device.
+            default_gate_time (float): The default startig gate time.
+
+        Returns:
+            SquareLatticeDevice
+        """
 
     def number_rows(self) -> int:
         """
@@ -888,7 +1174,6 @@ class SquareLatticeDevice:
 
         Returns:
             int: The number of rows.
-
         """
 
     def number_columns(self) -> int:
@@ -897,12 +1182,65 @@ class SquareLatticeDevice:
 
         Returns:
             int: The number of columns.
+        """
+
+    def qubit_index(self, row: int, column: int) -> Optional[int]:
+        """
+        Return the qubit index at a position in the lattice.
 
+        Args:
+            row (int): The row of the qubit.
+            column (int): The column of the qubit.
+
+        Returns:
+            Optional[int]: The index of the qubit (row-major) or None if the position lies outside of the lattice.
         """
 
-    def set_all_two_qubit_gate_times(
-        self, gate: str, gate_time: float
-    ) -> SquareLatticeDevice:
+    def qubit_position(self, qubit: int) -> Optional[Tuple[int, int]]:
+        """
+        Return the position of a qubit in the lattice.
+
+        Args:
+            qubit (int): The index of the qubit.
+
+        Returns:
+            Optional[Tuple[int, int]]: The (row, column) position of the qubit or None if the qubit is not part of the device.
+        """
+
+    def row(self, row: int) -> List[int]:
+        """
+        Return the qubits in a row of the lattice.
+
+        Args:
+            row (int): The row of the lattice.
+
+        Returns:
+            List[int]: The qubit indices in the row ordered by column.
+        """
+
+    def column(self, column: int) -> List[int]:
+        """
+        Return the qubits in a column of the lattice.
+
+        Args:
+            column (int): The column of the lattice.
+
+        Returns:
+            List[int]: The qubit indices in the column ordered by row.
+        """
+
+    def neighbours(self, qubit: int) -> List[int]:
+        """
+        Return the nearest neighbours of a qubit in the lattice.
+
+        Args:
+            qubit (int): The index of the qubit.
+
+        Returns:
+            List[int]: The sorted indices of the qubits connected to `qubit` by an edge of the lattice.
+        """
+
+    def set_all_two_qubit_gate_times(self, gate: str, gate_time: float) -> SquareLatticeDevice:
         """
         Set gate time of all two-qubit gates of specific type
 
@@ -912,12 +1250,9 @@ class SquareLatticeDevice:
 
         Returns:
             Self: A qoqo Device with updated gate times.
-
         """
 
-    def set_all_single_qubit_gate_times(
-        self, gate: str, gate_time: float
-    ) -> SquareLatticeDevice:
+    def set_all_single_qubit_gate_times(self, gate: str, gate_time: float) -> SquareLatticeDevice:
         """
         Set gate time of all single-qubit gates of specific type
 
@@ -927,10 +1262,9 @@ class SquareLatticeDevice:
 
         Returns:
             Self: A qoqo Device with updated gate times.
-
         """
 
-    def set_all_qubit_decoherence_rates(self, rates) -> SquareLatticeDevice:
+    def set_all_qubit_decoherence_rates(self, rates: numpy.ndarray) -> SquareLatticeDevice:
         """
         Set the decoherence rates for all qubits in the SquareLatticeDevice device.
 
@@ -977,7 +1311,126 @@ class SquareLatticeDevice:
             Self: The new device with the new properties
         """
 
-    def json_schema(self) -> str:
+    def with_single_qubit_gate_time(self, gate: str, gate_time: float) -> SquareLatticeDevice:
+        """
+        Set a uniform gate time for a single-qubit-gate, adding the gate if it is not available yet.
+
+        Args:
+            gate (str): The hqslang name of the single-qubit-gate.
+            gate_time (float): Gate time for the given gate, valid for all qubits in the device.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def with_two_qubit_gate_time(self, gate: str, gate_time: float) -> SquareLatticeDevice:
+        """
+        Set a uniform gate time for a two-qubit-gate, adding the gate if it is not available yet.
+
+        Args:
+            gate (str): The hqslang name of the two-qubit-gate.
+            gate_time (float): Gate time for the given gate, valid for all edges in the device.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def without_single_qubit_gate(self, gate: str) -> SquareLatticeDevice:
+        """
+        Remove a single-qubit-gate from the available gates of the device.
+
+        Args:
+            gate (str): The hqslang name of the single-qubit-gate.
+
+        Returns:
+            Self: The new device without the gate
+        """
+
+    def without_two_qubit_gate(self, gate: str) -> SquareLatticeDevice:
+        """
+        Remove a two-qubit-gate from the available gates of the device.
+
+        Args:
+            gate (str): The hqslang name of the two-qubit-gate.
+
+        Returns:
+            Self: The new device without the gate
+        """
+
+    def with_damping_rates(self, damping: float) -> SquareLatticeDevice:
+        """
+        Add damping to the noise rates of all qubits.
+
+        Args:
+            damping (float): The damping rate.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def with_dephasing_rates(self, dephasing: float) -> SquareLatticeDevice:
+        """
+        Add dephasing to the noise rates of all qubits.
+
+        Args:
+            dephasing (float): The dephasing rate.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    def with_depolarising_rates(self, depolarising: float) -> SquareLatticeDevice:
+        """
+        Add depolarising noise to the noise rates of all qubits.
+
+        Args:
+            depolarising (float): The depolarising rate.
+
+        Returns:
+            Self: The new device with the new properties
+        """
+
+    @staticmethod
+    def from_config_json(config: str) -> SquareLatticeDevice:
+        """
+        Create a SquareLatticeDevice from a human-editable json config.
+
+        The config contains default gate times per gate, overrides of the gate times on single qubits,
+        edges or sets of qubits, and damping, dephasing and depolarising rates with per-qubit overrides.
+        Calibrated gate errors are not part of the config.
+
+        Args:
+            config (str): The json config of the device.
+
+        Returns:
+            SquareLatticeDevice: The device described by the config.
+
+        Raises:
+            ValueError: The config cannot be deserialized or contains invalid qubits.
+        """
+
+    def to_config_json(self) -> str:
+        """
+        Return the human-editable json config of the device.
+
+        Returns:
+            str: The json config of the device.
+
+        Raises:
+            ValueError: The decoherence rates of a qubit cannot be expressed in the config.
+        """
+
+    @staticmethod
+    def config_json_schema() -> str:
+        """
+        Return the JsonSchema of the json config of the device.
+
+        Returns:
+            str: The json schema of the config serialized to json
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -985,7 +1438,8 @@ class SquareLatticeDevice:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -1009,7 +1463,7 @@ class SquareLatticeDevice:
             int: The number of qubits.
         """
 
-    def two_qubit_edges(self) -> Sequence[(int, int)]:
+    def two_qubit_edges(self) -> List[Tuple[int, int]]:
         """
         Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
 
@@ -1025,8 +1479,7 @@ class SquareLatticeDevice:
         choice from a list of edges and can be used for applications like routing in quantum algorithms.
 
         Returns:
-            Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
-
+            List[Tuple[int, int]]: List of two qubit edges in the undirected connectivity graph
         """
 
     def single_qubit_gate_time(self, hqslang: str, qubit: int) -> Optional[float]:
@@ -1044,9 +1497,7 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def two_qubit_gate_time(
-        self, hqslang: str, control: int, target: int
-    ) -> Optional[float]:
+    def two_qubit_gate_time(self, hqslang: str, control: int, target: int) -> Optional[float]:
         """
         Returns the gate time of a two qubit operation if the two qubit operation is available on device.
 
@@ -1060,16 +1511,10 @@ class SquareLatticeDevice:
 
         Raises:
             PyValueError: Qubit is not in device
-
         """
 
-    def three_qubit_gate_time(
-        self, hqslang: str, control_0: int, control_1: int, target: int
-    ) -> Optional[float]:
+    def three_qubit_gate_time(self, hqslang: str, control_0: int, control_1: int, target: int) -> Optional[float]:
         """
-        three_qubit_gate_time(gate, control_0, control_1, target
-        --
-
         Returns the gate time of a three qubit operation if the three qubit operation is available on device.
 
         Args:
@@ -1083,7 +1528,6 @@ class SquareLatticeDevice:
 
         Raises:
             PyValueError: Qubit is not in device
-
         """
 
     def multi_qubit_gate_time(self, hqslang: str, qubits: List[int]) -> Optional[float]:
@@ -1101,7 +1545,32 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_single_qubit_gate_time(self, gate: str, qubit: int, gate_time: float):
+    def single_qubit_gate_error(self, hqslang: str, qubit: int) -> Optional[float]:
+        """
+        Returns the calibrated error rate of a single qubit operation if it is known for the device.
+
+        Args:
+            hqslang (str): The hqslang name of a single qubit gate.
+            qubit (int): The qubit the gate acts on
+
+        Returns:
+            Option[float]: None if no error rate is known for the gate
+        """
+
+    def two_qubit_gate_error(self, hqslang: str, control: int, target: int) -> Optional[float]:
+        """
+        Returns the calibrated error rate of a two qubit operation if it is known for the device.
+
+        Args:
+            hqslang (str): The hqslang name of a two qubit gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Option[float]: None if no error rate is known for the gate
+        """
+
+    def set_single_qubit_gate_time(self, gate: str, qubit: int, gate_time: float) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -1114,9 +1583,7 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_two_qubit_gate_time(
-        self, gate: str, control: int, target: int, gate_time: float
-    ):
+    def set_two_qubit_gate_time(self, gate: str, control: int, target: int, gate_time: float) -> None:
         """
         Set the gate time of a two qubit gate.
 
@@ -1130,9 +1597,7 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_three_qubit_gate_time(
-        self, gate: str, control_0: int, control_1: int, target: int, gate_time: float
-    ):
+    def set_three_qubit_gate_time(self, gate: str, control_0: int, control_1: int, target: int, gate_time: float) -> None:
         """
         Set the gate time of a three qubit gate.
 
@@ -1147,7 +1612,38 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_qubit_decoherence_rates(self, gate: str, qubits: int, gate_time: float):
+    def set_single_qubit_gate_error(self, gate: str, qubit: int, error: float) -> None:
+        """
+        Set the calibrated error rate of a single qubit gate.
+
+        Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            gate (str): hqslang name of the single-qubit-gate.
+            qubit (int): The qubit for which the gate error is set
+            error (float): The error rate of the gate, between 0 and 1.
+
+        Raises:
+            PyValueError: Qubit is not in device or error is not between 0 and 1
+        """
+
+    def set_two_qubit_gate_error(self, gate: str, control: int, target: int, error: float) -> None:
+        """
+        Set the calibrated error rate of a two qubit gate.
+
+        Devices with gate errors can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            gate (str): hqslang name of the two-qubit-gate.
+            control (int): The control qubit for which the gate error is set
+            target (int): The target qubit for which the gate error is set
+            error (float): The error rate of the gate, between 0 and 1.
+
+        Raises:
+            PyValueError: Qubits are not in device or error is not between 0 and 1
+        """
+
+    def set_qubit_decoherence_rates(self, qubit: int, rates: numpy.ndarray) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -1160,7 +1656,7 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def set_multi_qubit_gate_time(self, gate: str, qubits: List[int], gate_time: float):
+    def set_multi_qubit_gate_time(self, gate: str, qubits: List[int], gate_time: float) -> None:
         """
         Set the gate time of a single qubit gate.
 
@@ -1173,7 +1669,7 @@ class SquareLatticeDevice:
             PyValueError: Qubits not in device
         """
 
-    def qubit_decoherence_rates(self, qubit: int) -> numpy.array:
+    def qubit_decoherence_rates(self, qubit: int) -> numpy.ndarray:
         """
         Return the matrix of the decoherence rates of the Lindblad equation.
 
@@ -1182,10 +1678,39 @@ class SquareLatticeDevice:
 
         Returns:
             np.array: 3 by 3 numpy array of decoherence rates
+        """
 
+    def single_qubit_gate_time_matrix(self, hqslang: str) -> numpy.ndarray:
         """
+        Return the gate times of a single qubit gate on all qubits of the device.
 
-    def add_damping(self, qubit: int, damping: float):
+        Args:
+            hqslang (str): The hqslang name of a single qubit gate.
+
+        Returns:
+            np.ndarray: The gate time on each qubit, NaN where the gate is not available.
+        """
+
+    def two_qubit_gate_time_matrix(self, hqslang: str) -> numpy.ndarray:
+        """
+        Return the gate times of a two qubit gate on all pairs of qubits of the device.
+
+        Args:
+            hqslang (str): The hqslang name of a two qubit gate.
+
+        Returns:
+            np.ndarray: The gate time with the control qubit as row and the target qubit as column, NaN where the gate is not available.
+        """
+
+    def qubit_decoherence_rates_all(self) -> numpy.ndarray:
+        """
+        Return the decoherence rates of all qubits of the device.
+
+        Returns:
+            np.ndarray: number_qubits by 3 by 3 numpy array of decoherence rates, zero for qubits without decoherence rates.
+        """
+
+    def add_damping(self, qubit: int, damping: float) -> None:
         """
         Adds single qubit damping to noise rates.
 
@@ -1197,7 +1722,7 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def add_dephasing(self, qubit: int, dephasing: float):
+    def add_dephasing(self, qubit: int, dephasing: float) -> None:
         """
         Adds single qubit dephasing to noise rates.
 
@@ -1209,7 +1734,7 @@ class SquareLatticeDevice:
             PyValueError: Qubit is not in device
         """
 
-    def add_depolarising(self, qubit: int, depolarising: float):
+    def add_depolarising(self, qubit: int, depolarising: float) -> None:
         """
         Adds single qubit depolarising to noise rates.
 
@@ -1275,7 +1800,25 @@ class SquareLatticeDevice:
 
         Returns:
             List[str]: The list of gate names.
+        """
 
+    def __copy__(self) -> SquareLatticeDevice:
+        """
+        Return a copy of the device (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> SquareLatticeDevice:
+        """
+        Return a deep copy of the device.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
         """
 
     def to_bincode(self) -> bytearray:
@@ -1287,7 +1830,6 @@ class SquareLatticeDevice:
 
         Raises:
             ValueError: Cannot serialize Device to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -1299,34 +1841,45 @@ class SquareLatticeDevice:
 
         Raises:
             ValueError: Cannot serialize Device to json.
-
         """
 
-    def from_bincode(self, input: bytearray):
-        """
-        Convert the bincode representation of the qoqo device to a device using the bincode crate.
+def gate_time(device: Any, operation: Operation) -> Optional[float]:
+    """
+    Return the gate time of an operation on a device.
 
-        Args:
-            input (ByteArray): The serialized Device (in bincode form).
+    Gates are looked up in the device with their hqslang name and qubits.
+    Noise PRAGMAs return their gate_time, PragmaSleep returns its sleep_time
+    and definitions return 0.0.
 
-        Returns:
-            The deserialized Device.
+    Args:
+        device (Device): The device providing the gate times.
+        operation (Operation): The operation for which the gate time is returned.
 
-        Raises:
-            TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized to selected Device.
-        """
+    Returns:
+        Optional[float]: The gate time, None if the operation is not available on the device,
+                         has no gate time or the time is symbolic.
 
-    def from_json(self, input: str):
-        """
-        Convert the json representation of a device to a qoqo device.
+    Raises:
+        TypeError: Input cannot be converted to a device or Operation.
+    """
 
-        Args:
-            input (str): The serialized device in json form.
+def add_active_gate_noise(device: Any, circuit: Circuit) -> Circuit:
+    """
+    Add the noise acting on the qubits of each gate while the gate is applied.
 
-        Returns:
-            The deserialized device.
+    The circuit is a set of operations applied in parallel, so no two gates may act on the same qubit.
+    After each gate the decoherence rates of each qubit of the gate are applied for the gate time
+    of the gate on the device, as PragmaDamping, PragmaDephasing and PragmaDepolarising
+    or as PragmaGeneralNoise for rates that are not a combination of those.
 
-        Raises:
-            ValueError: Input cannot be deserialized to selected Device.
-        """
+    Args:
+        device (Device): The device providing the gate times and decoherence rates.
+        circuit (Circuit): The circuit of parallel native operations for which the noise is inserted.
+
+    Returns:
+        Circuit: The circuit containing the noise PRAGMAs.
+
+    Raises:
+        TypeError: Input cannot be converted to a device or Circuit.
+        ValueError: Several gates act on the same qubit or a gate is not available on the device.
+    """
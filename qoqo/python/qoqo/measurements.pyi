@@ -1,10 +1,9 @@
-# This is an auto generated file containing only the documentation.
-# You can find the full implementation on this page:
-# https://github.com/HQSquantumsimulations/qoqo
+# This file is generated by qoqo-stubgen from the Rust sources of qoqo.
+# Do not edit it by hand, regenerate it with `cargo run -p qoqo-stubgen` instead.
 
 """
 Measurements
-    
+
 .. autosummary::
     :toctree: generated/
 
@@ -16,10 +15,13 @@ Measurements
     DecoherenceOnIdleModel
     Cheated
     ClassicalRegister
+    registers_to_records
+    expectation_values_to_records
+    statistics
 """
 
-from typing import Optional, List, Dict, Union
-from .qoqo import Circuit
+from typing import Any, Dict, List, Optional, Tuple, Union
+from qoqo import Circuit
 
 class PauliZProductInput:
     """
@@ -40,8 +42,24 @@ class PauliZProductInput:
               dictionary, and whether to use flipped measurements as specified in input.
     """
 
-    def __init__(self, number_qubits: int, use_flipped_measurement: bool):
-        return
+    def __init__(self, number_qubits: int, use_flipped_measurement: bool) -> None:
+        """
+        Create new PauliZProductInput.
+
+        The PauliZProductInput starts with just the number of qubtis and flipped measurements set.
+        The pauli_poduct_qubit_masks and measured_exp_vals start empty
+        and can be extended with [PauliZProductInput::add_pauliz_product]
+        [PauliZProductInput::add_linear_exp_val] and [PauliZProductInput::add_symbolic_exp_val]
+
+        Args:
+            number_qubits (int): The number of qubits in the PauliZProduct measurement.
+            use_flipped_measurement (bool): Whether or not to use flipped measurements.
+
+        Returns:
+            self: The new instance of PauliZProductInput with pauli_product_qubit_masks = an empty dictionary, the
+                  specified number of qubits in input, number_pauli_products = 0, measured_exp_vals = an empty
+                  dictionary, and whether to use flipped measurements as specified in input.
+        """
 
     def add_pauliz_product(self, readout: str, pauli_product_mask: List[int]) -> int:
         """
@@ -61,7 +79,25 @@ class PauliZProductInput:
             RuntimeError: Failed to add pauli product.
         """
 
-    def add_linear_exp_val(self, name: str, linear: Dict[int, float]):
+    def add_pauliz_products(self, readout: str, products: List[List[int]]) -> List[int]:
+        """
+        Add many measured Pauli products on the same readout register at once.
+
+        Equivalent to calling add_pauliz_product for every product in order.
+        When a Pauli product is already in the measurement input its existing index is returned.
+
+        Args:
+            readout (str): The name of the readout register the Pauli products are defined on.
+            products (List[List[int]]): The lists of the qubits involved in each Pauli product measurement.
+
+        Returns:
+            List[int]: The indices of the Pauli products in the order of products.
+
+        Raises:
+            RuntimeError: Failed to add pauli products.
+        """
+
+    def add_linear_exp_val(self, name: str, linear: Dict[int, float]) -> None:
         """
         Add linear definition of expectation value to measurement input.
 
@@ -76,7 +112,66 @@ class PauliZProductInput:
             RuntimeError: Failed to add linear expectation value.
         """
 
-    def add_symbolic_exp_val(self, name: str, symbolic: str):
+    def add_linear_exp_vals(self, entries: List[Tuple[str, Dict[int, float]]]) -> None:
+        """
+        Add many linear definitions of expectation values at once.
+
+        Args:
+            entries (List[Tuple[str, Dict[int, float]]]): The names of the expectation values and their linear combinations.
+
+        Raises:
+            RuntimeError: Failed to add linear expectation values.
+        """
+
+    def number_qubits(self) -> int:
+        """
+        Return the number of qubits that are measured.
+
+        Returns:
+            int: The number of qubits.
+        """
+
+    def number_pauli_products(self) -> int:
+        """
+        Return the number of registered Pauli products.
+
+        Returns:
+            int: The number of Pauli products.
+        """
+
+    def measured_exp_val_names(self) -> List[str]:
+        """
+        Return the sorted names of the registered expectation values.
+
+        Returns:
+            List[str]: The names of the expectation values.
+        """
+
+    def pauli_products(self) -> List[Tuple[str, List[int]]]:
+        """
+        Return the registered Pauli products ordered by their index.
+
+        Returns:
+            List[Tuple[str, List[int]]]: The readout register and qubit mask of every Pauli product.
+        """
+
+    def remap_registers(self, mapping: Dict[str, str]) -> PauliZProductInput:
+        """
+        Rename the readout registers in a clone of the PauliZProductInput.
+
+        Readout registers that are not in the keys of the mapping keep their name.
+
+        Args:
+            mapping (Dict[str, str]): The dictionary containing the {old_name: new_name} mapping of the readout registers.
+
+        Returns:
+            PauliZProductInput: The PauliZProductInput with the readout registers renamed.
+
+        Raises:
+            RuntimeError: A readout register is mapped onto an existing readout register or two readout registers are mapped onto the same name.
+        """
+
+    def add_symbolic_exp_val(self, name: str, symbolic: str) -> None:
         """
         Add symbolic definition of expectation value to measurement input.
 
@@ -105,7 +200,8 @@ class PauliZProductInput:
             PyRuntimeError: Unexpected error serializing PauliZProductInput.
         """
 
-    def from_json(self) -> PauliZProductInput:
+    @staticmethod
+    def from_json(json_string: str) -> PauliZProductInput:
         """
         Deserialize the PauliZProductInput from json form.
 
@@ -127,7 +223,8 @@ class PauliZProductInput:
             ValueError: Cannot serialize PauliZProductInput to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> PauliZProductInput:
+    @staticmethod
+    def from_bincode(input: bytearray) -> PauliZProductInput:
         """
         Convert the bincode representation of the PauliZProductInput to a PauliZProductInput using the [bincode] crate.
 
@@ -142,7 +239,18 @@ class PauliZProductInput:
             ValueError: Input cannot be deserialized to PauliZProductInput.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> PauliZProductInput:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> PauliZProductInput:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -150,7 +258,8 @@ class PauliZProductInput:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -180,24 +289,58 @@ class CheatedPauliZProductInput:
                HashMap and pauli_product_keys = an empty HashMap.
     """
 
-    def __init__(self):
-        return
+    def __init__(self) -> None:
+        """
+        Create new CheatedPauliZProductInput.
+
+        The CheatedPauliZProductInput starts with just the number of qubtis and flipped measurements set.
+        The pauli_poduct_qubit_masks and measured_exp_vals start empty
+        and can be extended with [CheatedPauliZProductInput::add_linear_exp_val] and
+        [CheatedPauliZProductInput::add_symbolic_exp_val].
 
-    def add_pauliz_product(self, readout: str) -> int:
+        Returns:
+            self: The new instance of CheatedPauliZProductInput with measured_exp_vals = an empty
+                   HashMap and pauli_product_keys = an empty HashMap.
+        """
+
+    def add_pauliz_product(self, readout: str, readout_index: int = ...) -> int:
         """
         Add measured Pauli product to CheatedPauliZProductInput and returns index of Pauli product.
 
         When the pauli product is already in the measurement input the function only returns
-        its index.
+        its index. Pauli products measured with PragmaGetIndexedPauliProduct are read from the
+        register entry `readout_index`. Inputs with a `readout_index` other than 0 can not be
+        serialized with to_bincode, use to_json instead.
 
         Args:
             readout (str): The name of the readout register containing the pauli_product expectation value.
+            readout_index (int): The index of the register entry containing the pauli_product expectation value. Defaults to 0.
+
+        Returns:
+            int: The index of the added Pauli product in the list of all Pauli products.
+        """
+
+    def add_pauli_product(self, readout: str, pauli_product: str) -> int:
+        """
+        Add a Pauli product with X, Y and Z factors and return its index.
+
+        The expectation value of the Pauli product is evaluated directly on the statevector or
+        density matrix in the complex readout register. When the Pauli product is already in the
+        measurement input for the same readout the function only returns its index.
+        Inputs with these Pauli products can not be serialized with to_bincode, use to_json instead.
+
+        Args:
+            readout (str): The name of the complex readout register containing the statevector or density matrix.
+            pauli_product (str): The Pauli product as space separated factors, e.g. "X0 Y2 Z3". The empty string and "I" are the identity.
 
         Returns:
             int: The index of the added Pauli product in the list of all Pauli products.
+
+        Raises:
+            ValueError: The Pauli product could not be parsed.
         """
 
-    def add_linear_exp_val(self, name: str, linear: Dict[int, float]):
+    def add_linear_exp_val(self, name: str, linear: Dict[int, float]) -> None:
         """
         Add linear definition of expectation value to measurement input.
 
@@ -212,7 +355,7 @@ class CheatedPauliZProductInput:
             RuntimeError: Failed to add linear expectation value.
         """
 
-    def add_symbolic_exp_val(self, name: str, symbolic: str):
+    def add_symbolic_exp_val(self, name: str, symbolic: str) -> None:
         """
         Add symbolic definition of expectation value to measurement input.
 
@@ -228,7 +371,43 @@ class CheatedPauliZProductInput:
 
         The i-th PauliProducts are hardcoded as variables pauli_product_i
         in the string expression of CalculatorFloat.
+        """
+
+    def number_qubits(self) -> int:
+        """
+        Return the number of qubits the Pauli products evaluated on complex registers act on.
 
+        Pauli products read out from float registers do not define their qubits,
+        an input containing only those returns 0.
+
+        Returns:
+            int: The number of qubits.
+        """
+
+    def number_pauli_products(self) -> int:
+        """
+        Return the number of registered Pauli products.
+
+        Returns:
+            int: The number of Pauli products.
+        """
+
+    def measured_exp_val_names(self) -> List[str]:
+        """
+        Return the sorted names of the registered expectation values.
+
+        Returns:
+            List[str]: The names of the expectation values.
+        """
+
+    def pauli_products(self) -> List[Tuple[str, List[int]]]:
+        """
+        Return the registered Pauli products ordered by their index.
+
+        The qubits of Pauli products read out from float registers are empty.
+
+        Returns:
+            List[Tuple[str, List[int]]]: The readout register and the qubits of every Pauli product.
         """
 
     def to_json(self) -> str:
@@ -242,7 +421,8 @@ class CheatedPauliZProductInput:
             PyRuntimeError: Unexpected error serializing CheatedPauliZProductInput.
         """
 
-    def from_json(self) -> CheatedPauliZProductInput:
+    @staticmethod
+    def from_json(json_string: str) -> CheatedPauliZProductInput:
         """
         Deserialize the CheatedPauliZProductInput from json form.
 
@@ -264,7 +444,8 @@ class CheatedPauliZProductInput:
             ValueError: Cannot serialize CheatedPauliZProductInput to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> CheatedPauliZProductInput:
+    @staticmethod
+    def from_bincode(input: bytearray) -> CheatedPauliZProductInput:
         """
         Convert the bincode representation of the CheatedPauliZProductInput to a CheatedPauliZProductInput using the [bincode] crate.
 
@@ -279,7 +460,18 @@ class CheatedPauliZProductInput:
             ValueError: Input cannot be deserialized to CheatedPauliZProductInput.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> CheatedPauliZProductInput:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> CheatedPauliZProductInput:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -287,7 +479,8 @@ class CheatedPauliZProductInput:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -320,16 +513,30 @@ class CheatedInput:
                       and an empty dictionay of expectation values.
     """
 
-    def __init__(self, number_qubits: int):
-        return
+    def __init__(self, number_qubits: int) -> None:
+        """
+        Creates a new CheatedInput.
+
+        The CheatedInput stores the number of qubits that are measured
+        and a dictionary mapping expectation value names to operators on the Hilbert space
+        of the qubits. The operators are represented by sparse lists of non-zero entry triples
+        of an operator matrix.
+
+        Args:
+            number_qubits (int): The number of qubits in the PauliZProduct measurement.
 
-    def add_operator_exp_val(
-        self, name: str, operator: int, int, complex, readout: str
-    ):
+        Returns:
+            CheatedInput: The new instance of CheatedInput with the specified number of qubits in input,
+                          and an empty dictionay of expectation values.
+        """
+
+    def add_operator_exp_val(self, name: str, operator: List[Tuple[int, int, complex]], readout: str) -> None:
         """
         Add operator based expectation value to measurement input.
 
         Adds an expectation value that is defined by an operator on the Hilbert space.
+        The operator is stored and evaluated in sparse form, only the non-zero entries need to be provided
+        and entries with the same (row, col) are summed up (as for scipy.sparse.coo_matrix).
 
         Args:
             name (str): The name of the expectation value.
@@ -341,6 +548,30 @@ class CheatedInput:
             RuntimeError: Failed to add operator based expectation value.
         """
 
+    def number_qubits(self) -> int:
+        """
+        Return the number of qubits that defines the dimension of the Hilbert space.
+
+        Returns:
+            int: The number of qubits.
+        """
+
+    def measured_exp_val_names(self) -> List[str]:
+        """
+        Return the sorted names of the registered expectation values.
+
+        Returns:
+            List[str]: The names of the expectation values.
+        """
+
+    def operator_dimensions(self) -> List[Tuple[str, int]]:
+        """
+        Return the names of the measured operators with the dimension of their matrices, sorted by name.
+
+        Returns:
+            List[Tuple[str, int]]: The name and dimension of every measured operator.
+        """
+
     def to_json(self) -> str:
         """
         Serialize the CheatedInput to json form.
@@ -352,7 +583,8 @@ class CheatedInput:
             PyRuntimeError: Unexpected error serializing CheatedInput.
         """
 
-    def from_json(self) -> CheatedInput:
+    @staticmethod
+    def from_json(json_string: str) -> CheatedInput:
         """
         Deserialize the CheatedInput from json form.
 
@@ -374,7 +606,8 @@ class CheatedInput:
             ValueError: Cannot serialize CheatedInput to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> CheatedInput:
+    @staticmethod
+    def from_bincode(input: bytearray) -> CheatedInput:
         """
         Convert the bincode representation of the CheatedInput to a CheatedInput using the [bincode] crate.
 
@@ -389,7 +622,18 @@ class CheatedInput:
             ValueError: Input cannot be deserialized to CheatedInput.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> CheatedInput:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> CheatedInput:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -397,7 +641,8 @@ class CheatedInput:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -421,25 +666,65 @@ class PauliZProduct:
         constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
         circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations.
         input (PauliZProductInput): The additional input information required for measurement.
+        check_registers (bool): Whether to check the readout registers of the input against the circuits. Defaults to True.
 
     Returns:
         PauliZProduct: The PauliZProduct containing the new PauliZ product measurement.
     """
 
-    def __init__(
-        self,
-        constant_circuit: Optional[Circuit],
-        circuits: List[Circuit],
-        input: PauliZProductInput,
-    ):
-        return
-
-    def evaluate(
-        self,
-        input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]],
-        float_registers: Dict[str, List[List[float]]],
-        complex_registers: Dict[str, List[List[complex]]],
-    ) -> Optional[Dict[str, float]]:
+    def __init__(self, constant_circuit: Optional[Circuit], circuits: List[Circuit], input: PauliZProductInput, check_registers: bool = ...) -> None:
+        """
+        Create a new PauliZProduct measurement.
+
+        Args:
+            constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
+            circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations.
+            input (PauliZProductInput): The additional input information required for measurement.
+            check_registers (bool): Whether to check that every readout register of the input is written by
+                                    the circuits and defined long enough for its Pauli products. Defaults to True.
+
+        Returns:
+            PauliZProduct: The PauliZProduct containing the new PauliZ product measurement.
+
+        Raises:
+            ValueError: A readout register is not written by any circuit, not defined or too short.
+        """
+
+    @staticmethod
+    def with_flipped_circuits(constant_circuit: Optional[Circuit], circuits: List[Circuit], input: PauliZProductInput) -> PauliZProduct:
+        """
+        Create a new PauliZProduct measurement, adding the flipped measurement circuits when required.
+
+        When the input uses flipped measurements, a flipped copy of every circuit is appended to circuits.
+        In the flipped copy all measured qubits are flipped with a PauliX gate before their first measurement
+        and every readout register `<register>` of the input is replaced by `<register>_flipped`.
+
+        Args:
+            constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
+            circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations without flipped readout.
+            input (PauliZProductInput): The additional input information required for measurement.
+
+        Returns:
+            PauliZProduct: The PauliZProduct including the flipped circuits.
+
+        Raises:
+            TypeError: The arguments are not qoqo Circuits or a PauliZProductInput.
+            ValueError: A flipped readout register name is already used in the circuits.
+        """
+
+    def deduplicate_circuits(self) -> Tuple[PauliZProduct, List[int]]:
+        """
+        Return a copy of the measurement in which identical circuits are only kept once.
+
+        The first occurrence of every circuit is kept and the order of the kept circuits is unchanged.
+        The input refers to the readout registers and is valid for the deduplicated circuits as well.
+
+        Returns:
+            Tuple[PauliZProduct, List[int]]: The measurement without duplicate circuits and the index
+                of the kept circuit for every circuit of the original measurement.
+        """
+
+    def evaluate(self, input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]], float_registers: Dict[str, List[List[float]]], complex_registers: Dict[str, List[List[complex]]]) -> Optional[Dict[str, float]]:
         """
         Execute the PauliZ product measurement.
 
@@ -456,6 +741,31 @@ class PauliZProduct:
             RuntimeError: Error evaluating PauliZ product measurement.
         """
 
+    def evaluate_weighted(self, input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]], float_registers: Dict[str, List[List[float]]], complex_registers: Dict[str, List[List[complex]]], weights: Dict[str, float], pauli_product_weights: Optional[Dict[int, float]] = ...) -> Optional[Dict[str, float]]:
+        """
+        Execute the PauliZ product measurement with weighted readout registers.
+
+        The expectation values of the Pauli products measured in a readout register are multiplied
+        by the weight of the register. Registers without weight have weight 1.0.
+        When flipped measurements are used, the register `<register>_flipped` uses the weight
+        of `<register>` unless its own weight is given.
+        The expectation value of each Pauli product is additionally multiplied by its entry in
+        `pauli_product_weights`. Pauli products without weight have weight 1.0.
+
+        Args:
+            input_bit_registers (Dict[str, Union[List[List[int]], List[List[bool]]]]): The classical bit registers with the register name as key
+            float_registers (Dict[str, List[List[float]]]): The classical float registers as a dictionary with the register name as key
+            complex_registers (Dict[str, List[List[complex]]]): The classical complex registers as a dictionary with the register name as key
+            weights (Dict[str, float]): The weights of the readout registers with the register name as key
+            pauli_product_weights (Optional[Dict[int, float]]): The weights of the measured Pauli products with the Pauli product index as key
+
+        Returns:
+            Optional[Dict[str, float]]: The evaluated measurement.
+
+        Raises:
+            RuntimeError: Error evaluating PauliZ product measurement.
+        """
+
     def circuits(self) -> List[Circuit]:
         """
         Return the collection of quantum circuits for the separate basis rotations.
@@ -488,7 +798,15 @@ class PauliZProduct:
            str: The type of the measurement.
         """
 
-    def substitute_parameters(self, substituted_parameters: Dict[str, float]):
+    def validate(self) -> None:
+        """
+        Check that all classical registers used by the circuits are defined and large enough.
+
+        Raises:
+            ValueError: A classical register is missing or too small.
+        """
+
+    def substitute_parameters(self, substituted_parameters: Dict[str, float]) -> PauliZProduct:
         """
         Return clone of Measurement with symbolic parameters replaced.
 
@@ -496,7 +814,7 @@ class PauliZProduct:
             substituted_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the Circuit.
         """
 
-    def _internal_to_bincode(self):
+    def _internal_to_bincode(self) -> Tuple[str, bytearray]:
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
 
@@ -518,7 +836,8 @@ class PauliZProduct:
             ValueError: Cannot serialize PauliZProduct to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> PauliZProduct:
+    @staticmethod
+    def from_bincode(input: bytearray) -> PauliZProduct:
         """
         Convert the bincode representation of the PauliZProduct to a PauliZProduct using the [bincode] crate.
 
@@ -544,7 +863,8 @@ class PauliZProduct:
             RuntimeError: Unexpected error serializing PauliZProduct.
         """
 
-    def from_json(self) -> PauliZProduct:
+    @staticmethod
+    def from_json(json_string: str) -> PauliZProduct:
         """
         Deserialize the PauliZProduct from json form using the [serde_json] crate.
 
@@ -555,7 +875,18 @@ class PauliZProduct:
             RuntimeError: Cannot deserialize string to PauliZProduct.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> PauliZProduct:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> PauliZProduct:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -563,7 +894,8 @@ class PauliZProduct:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -592,20 +924,20 @@ class CheatedPauliZProduct:
         self: The CheatedPauliZProduct containing the new cheated PauliZ product measurement.
     """
 
-    def __init__(
-        self,
-        constant_circuit: Optional[Circuit],
-        circuits: List[Circuit],
-        input: CheatedPauliZProductInput,
-    ):
-        return
-
-    def evaluate(
-        self,
-        input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]],
-        float_registers: Dict[str, List[List[float]]],
-        complex_registers: Dict[str, List[List[complex]]],
-    ) -> Optional[Dict[str, float]]:
+    def __init__(self, constant_circuit: Optional[Circuit], circuits: List[Circuit], input: CheatedPauliZProductInput) -> None:
+        """
+        Creates an new PauliZProduct measurement.
+
+        Args:
+            constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
+            circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations.
+            input (CheatedPauliZProductInput): The additional input information required for measurement.
+
+        Returns:
+            self: The CheatedPauliZProduct containing the new cheated PauliZ product measurement.
+        """
+
+    def evaluate(self, input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]], float_registers: Dict[str, List[List[float]]], complex_registers: Dict[str, List[List[complex]]]) -> Optional[Dict[str, float]]:
         """
         Executes the cheated PauliZ product measurement.
 
@@ -654,7 +986,15 @@ class CheatedPauliZProduct:
            str: The type of the measurement.
         """
 
-    def substitute_parameters(self, substituted_parameters: Dict[str, float]):
+    def validate(self) -> None:
+        """
+        Check that all classical registers used by the circuits are defined and large enough.
+
+        Raises:
+            ValueError: A classical register is missing or too small.
+        """
+
+    def substitute_parameters(self, substituted_parameters: Dict[str, float]) -> CheatedPauliZProduct:
         """
         Returns clone of Measurement with symbolic parameters replaced
 
@@ -662,7 +1002,7 @@ class CheatedPauliZProduct:
             substituted_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the Circuit.
         """
 
-    def _internal_to_bincode(self):
+    def _internal_to_bincode(self) -> Tuple[str, bytearray]:
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
 
@@ -684,7 +1024,8 @@ class CheatedPauliZProduct:
             ValueError: Cannot serialize CheatedPauliZProduct to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> CheatedPauliZProduct:
+    @staticmethod
+    def from_bincode(input: bytearray) -> CheatedPauliZProduct:
         """
         Convert the bincode representation of the CheatedPauliZProduct to a CheatedPauliZProduct using the [bincode] crate.
 
@@ -710,7 +1051,8 @@ class CheatedPauliZProduct:
             RuntimeError: Unexpected error serializing CheatedPauliZProduct.
         """
 
-    def from_json(self) -> CheatedPauliZProduct:
+    @staticmethod
+    def from_json(json_string: str) -> CheatedPauliZProduct:
         """
         Deserialize the CheatedPauliZProduct from json form using the [serde_json] crate.
 
@@ -721,7 +1063,18 @@ class CheatedPauliZProduct:
             RuntimeError: Cannot deserialize string to CheatedPauliZProduct.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> CheatedPauliZProduct:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> CheatedPauliZProduct:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -729,7 +1082,8 @@ class CheatedPauliZProduct:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -758,20 +1112,20 @@ class Cheated:
         Cheated: The new measurement.
     """
 
-    def __init__(
-        self,
-        constant_circuit: Optional[Circuit],
-        circuits: List[Circuit],
-        input: CheatedInput,
-    ):
-        return
-
-    def evaluate(
-        self,
-        input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]],
-        float_registers: Dict[str, List[List[float]]],
-        complex_registers: Dict[str, List[List[complex]]],
-    ) -> Optional[Dict[str, float]]:
+    def __init__(self, constant_circuit: Optional[Circuit], circuits: List[Circuit], input: CheatedInput) -> None:
+        """
+        Create an new Cheated measurement
+
+        Args:
+            constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
+            circuits (List[Circuit]): The collection of quantum circuits executed for the measurement.
+            input (CheatedInput): The additional input information required for measurement.
+
+        Returns:
+            Cheated: The new measurement.
+        """
+
+    def evaluate(self, input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]], float_registers: Dict[str, List[List[float]]], complex_registers: Dict[str, List[List[complex]]]) -> Optional[Dict[str, float]]:
         """
         Execute the cheated measurement.
 
@@ -820,7 +1174,15 @@ class Cheated:
            str: The type of the measurement.
         """
 
-    def substitute_parameters(self):
+    def validate(self) -> None:
+        """
+        Check that all classical registers used by the circuits are defined and large enough.
+
+        Raises:
+            ValueError: A classical register is missing or too small.
+        """
+
+    def substitute_parameters(self, substituted_parameters: Dict[str, float]) -> Cheated:
         """
         Return copy of Measurement with symbolic parameters replaced.
 
@@ -831,7 +1193,7 @@ class Cheated:
             RuntimeError: Error substituting symbolic parameters.
         """
 
-    def _internal_to_bincode(self):
+    def _internal_to_bincode(self) -> Tuple[str, bytearray]:
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
 
@@ -853,7 +1215,8 @@ class Cheated:
             ValueError: Cannot serialize Cheated to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> Cheated:
+    @staticmethod
+    def from_bincode(input: bytearray) -> Cheated:
         """
         Convert the bincode representation of the Cheated to a Cheated using the [bincode] crate.
 
@@ -879,7 +1242,8 @@ class Cheated:
             RuntimeError: Unexpected error serializing Cheated.
         """
 
-    def from_json(self) -> Cheated:
+    @staticmethod
+    def from_json(json_string: str) -> Cheated:
         """
         Deserialize the Cheated measurement from json form.
 
@@ -890,7 +1254,18 @@ class Cheated:
             RuntimeError: Cannot deserialize string to Cheated.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> Cheated:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> Cheated:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -898,7 +1273,8 @@ class Cheated:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -926,8 +1302,17 @@ class ClassicalRegister:
         ClassicalRegister: The new register.
     """
 
-    def __init__(self, constant_circuit: Optional[Circuit], circuits: List[Circuit]):
-        return
+    def __init__(self, constant_circuit: Optional[Circuit], circuits: List[Circuit]) -> None:
+        """
+        Create an new ClassicalRegister measurement.
+
+        Args:
+            constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
+            circuits (List[Circuit]): The collection of quantum circuits executed for the measurement.
+
+        Returns:
+            ClassicalRegister: The new register.
+        """
 
     def circuits(self) -> List[Circuit]:
         """
@@ -953,7 +1338,15 @@ class ClassicalRegister:
            str: The type of the measurement.
         """
 
-    def substitute_parameters(self, substituted_parameters: Dict[str, float]):
+    def validate(self) -> None:
+        """
+        Check that all classical registers used by the circuits are defined and large enough.
+
+        Raises:
+            ValueError: A classical register is missing or too small.
+        """
+
+    def substitute_parameters(self, substituted_parameters: Dict[str, float]) -> ClassicalRegister:
         """
         Return copy of Measurement with symbolic parameters replaced.
 
@@ -964,7 +1357,7 @@ class ClassicalRegister:
             RuntimeError: Error substituting symbolic parameters.
         """
 
-    def _internal_to_bincode(self):
+    def _internal_to_bincode(self) -> Tuple[str, bytearray]:
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
 
@@ -986,7 +1379,8 @@ class ClassicalRegister:
             ValueError: Cannot serialize ClassicalRegister to bytes.
         """
 
-    def from_bincode(self, input: bytearray) -> ClassicalRegister:
+    @staticmethod
+    def from_bincode(input: bytearray) -> ClassicalRegister:
         """
         Convert the bincode representation of the ClassicalRegister to a ClassicalRegister using the [bincode] crate.
 
@@ -1012,7 +1406,8 @@ class ClassicalRegister:
             PyRuntimeError: Unexpected error serializing ClassicalRegister.
         """
 
-    def from_json(self) -> ClassicalRegister:
+    @staticmethod
+    def from_json(json_string: str) -> ClassicalRegister:
         """
         Deserialize the ClassicalRegister measurement from json form.
 
@@ -1023,7 +1418,18 @@ class ClassicalRegister:
             PyRuntimeError: Cannot deserialize string to ClassicalRegister.
         """
 
-    def json_schema(self) -> str:
+    def __copy__(self) -> ClassicalRegister:
+        """
+        Return a copy of the Object (copy here produces a deepcopy).
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> ClassicalRegister:
+        """
+        Return a deep copy of the Object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -1031,7 +1437,8 @@ class ClassicalRegister:
             str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -1046,3 +1453,47 @@ class ClassicalRegister:
         Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
+
+def registers_to_records(bit_registers: Dict[str, List[List[bool]]], float_registers: Dict[str, List[List[float]]], complex_registers: Dict[str, List[List[complex]]]) -> List[Dict[str, Union[str, int, bool, float, complex]]]:
+    """
+    Flatten the output registers of a circuit run into records.
+
+    Every value of the registers becomes one record, a dictionary with the keys
+    `register` (the name of the register), `shot_index` (the index of the measurement record),
+    `position` (the index of the value in the measurement record) and `value`.
+    The records of the bit, float and complex registers follow each other, the registers of each type
+    are sorted by name. The list can be passed directly to `pandas.DataFrame(records)`.
+
+    Args:
+        bit_registers (Dict[str, List[List[bool]]]): The bit registers.
+        float_registers (Dict[str, List[List[float]]]): The float registers.
+        complex_registers (Dict[str, List[List[complex]]]): The complex registers.
+
+    Returns:
+        List[Dict[str, Union[str, int, bool, float, complex]]]: The records of all register values.
+
+    Raises:
+        TypeError: The input cannot be converted to registers.
+    """
+
+def expectation_values_to_records(results: List[Optional[Dict[str, float]]], parameter_sets: List[Dict[str, float]]) -> List[Dict[str, Union[str, int, float]]]:
+    """
+    Flatten the expectation values of a parameter sweep into records.
+
+    Every expectation value becomes one record, a dictionary containing the parameters of
+    the run followed by the keys `sweep_index` (the index of the run), `name` (the name of the
+    expectation value) and `value`. The expectation values of each run are sorted by name,
+    runs returning None are skipped. The list can be passed directly to `pandas.DataFrame(records)`.
+
+    Args:
+        results (List[Optional[Dict[str, float]]]): The expectation values returned by `QuantumProgram.run` for each run.
+        parameter_sets (List[Dict[str, float]]): The parameters of each run,
+                                                 e.g. `dict(zip(program.input_parameter_names(), parameters))`.
+
+    Returns:
+        List[Dict[str, Union[str, int, float]]]: The records of all expectation values.
+
+    Raises:
+        TypeError: The input cannot be converted to expectation values or parameters.
+        ValueError: The number of results and parameter sets differ or a parameter is named like a record key.
+    """
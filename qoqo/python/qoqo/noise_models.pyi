@@ -1,6 +1,5 @@
-# This is an auto generated file containing only the documentation.
-# You can find the full implementation on this page:
-# https://github.com/HQSquantumsimulations/qoqo
+# This file is generated by qoqo-stubgen from the Rust sources of qoqo.
+# Do not edit it by hand, regenerate it with `cargo run -p qoqo-stubgen` instead.
 
 """
 A collection of noise models that represent different types of noise that can be present in Quantum Computing hardware.
@@ -14,10 +13,14 @@ A collection of noise models that represent different types of noise that can be
     SingleQubitOverrotationDescription
     SingleQubitOverrotationOnGate
     DecoherenceOnIdleModel
+    idle_noise_circuit
 """
 
-from typing import Optional, List, Tuple
+import numpy
+from typing import Any, List, Optional, Tuple, Union
+from qoqo_calculator_pyo3 import CalculatorFloat
 from struqture_py.spins import PlusMinusLindbladNoiseOperator
+from qoqo import Circuit
 
 class ContinuousDecoherenceModel:
     """
@@ -49,8 +52,10 @@ class ContinuousDecoherenceModel:
         noise_operator (struqture_py.spins.PlusMinusLindbladNoiseOperator): Optional initialisation of Noise Model with given Lindblad operator.
     """
 
-    def __init__(self, noise_operator: PlusMinusLindbladNoiseOperator):
-        return
+    def __init__(self, noise_operator: PlusMinusLindbladNoiseOperator) -> None:
+        """
+        Create a new ContinuousDecoherenceModel
+        """
 
     def get_noise_operator(self) -> PlusMinusLindbladNoiseOperator:
         """
@@ -60,7 +65,8 @@ class ContinuousDecoherenceModel:
             PlusMinusLindbladNoiseOperator: The internal Lindblad noise operator of the continuous noise
         """
 
-    def from_bincode(self, input: bytearray):
+    @staticmethod
+    def from_bincode(input: bytearray) -> ContinuousDecoherenceModel:
         """
         Convert the bincode representation of the Noise-Model to a device using the bincode crate.
 
@@ -75,7 +81,8 @@ class ContinuousDecoherenceModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def from_json(self, input: str):
+    @staticmethod
+    def from_json(input: str) -> ContinuousDecoherenceModel:
         """
         Convert the json representation of a device to a Noise-Model.
 
@@ -89,7 +96,8 @@ class ContinuousDecoherenceModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -97,9 +105,7 @@ class ContinuousDecoherenceModel:
             str: The json schema serialized to json
         """
 
-    def add_damping_rate(
-        self, qubits: List[int], rate: float
-    ) -> ContinuousDecoherenceModel:
+    def add_damping_rate(self, qubits: List[int], rate: float) -> ContinuousDecoherenceModel:
         """
         Convenience function to add damping to several qubits
 
@@ -111,9 +117,7 @@ class ContinuousDecoherenceModel:
             ContinuousDecoherenceModel: The model with the damping added.
         """
 
-    def add_dephasing_rate(
-        self, qubits: List[int], rate: float
-    ) -> ContinuousDecoherenceModel:
+    def add_dephasing_rate(self, qubits: List[int], rate: float) -> ContinuousDecoherenceModel:
         """
         Convenience function to add dephasing to several qubits
 
@@ -125,9 +129,7 @@ class ContinuousDecoherenceModel:
             ContinuousDecoherenceModel: The model with the dephasing added.
         """
 
-    def add_depolarising_rate(
-        self, qubits: List[int], rate: float
-    ) -> ContinuousDecoherenceModel:
+    def add_depolarising_rate(self, qubits: List[int], rate: float) -> ContinuousDecoherenceModel:
         """
         Convenience function to add depolarising to several qubits
 
@@ -139,9 +141,7 @@ class ContinuousDecoherenceModel:
             ContinuousDecoherenceModel: The model with the depolarising added.
         """
 
-    def add_excitation_rate(
-        self, qubits: List[int], rate: float
-    ) -> ContinuousDecoherenceModel:
+    def add_excitation_rate(self, qubits: List[int], rate: float) -> ContinuousDecoherenceModel:
         """
         Convenience function to add excitation to several qubits
 
@@ -153,6 +153,25 @@ class ContinuousDecoherenceModel:
             ContinuousDecoherenceModel: The model with the excitation added.
         """
 
+    def __copy__(self) -> ContinuousDecoherenceModel:
+        """
+        Return a copy of the noise model (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> ContinuousDecoherenceModel:
+        """
+        Return a deep copy of the noise model.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
     def to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the Noise-Model using the bincode crate.
@@ -162,7 +181,6 @@ class ContinuousDecoherenceModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -174,10 +192,10 @@ class ContinuousDecoherenceModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to json.
-
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -217,12 +235,13 @@ class ImperfectReadoutModel:
     ```
     """
 
-    def __init__(self):
-        return
+    def __init__(self) -> None:
+        """
+        Create a new ContinuousDecoherenceModel
+        """
 
-    def new_with_uniform_error(
-        self, number_qubits: int, prob_detect_0_as_1: float, prob_detect_1_as_0: float
-    ) -> ImperfectReadoutModel:
+    @staticmethod
+    def new_with_uniform_error(number_qubits: int, prob_detect_0_as_1: float, prob_detect_1_as_0: float) -> ImperfectReadoutModel:
         """
         Convenience function to create uniform error probabilities
 
@@ -238,7 +257,8 @@ class ImperfectReadoutModel:
             ValueError: Raised if the error probabilities are not valid (< 0 or > 1)
         """
 
-    def from_bincode(self, input: bytearray):
+    @staticmethod
+    def from_bincode(input: bytearray) -> ImperfectReadoutModel:
         """
         Convert the bincode representation of the Noise-Model to a device using the bincode crate.
 
@@ -253,7 +273,8 @@ class ImperfectReadoutModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def from_json(self, input: str):
+    @staticmethod
+    def from_json(input: str) -> ImperfectReadoutModel:
         """
         Convert the json representation of a device to a Noise-Model.
 
@@ -267,7 +288,8 @@ class ImperfectReadoutModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -275,9 +297,7 @@ class ImperfectReadoutModel:
             str: The json schema serialized to json
         """
 
-    def set_error_probabilites(
-        self, qubit: int, prob_detect_0_as_1: float, prob_detect_1_as_0: float
-    ) -> ImperfectReadoutModel:
+    def set_error_probabilites(self, qubit: int, prob_detect_0_as_1: float, prob_detect_1_as_0: float) -> ImperfectReadoutModel:
         """
         Set and overwrite the measurement error probabilities
 
@@ -315,6 +335,57 @@ class ImperfectReadoutModel:
             float: The probability to detect 1 as 0 for the qubit
         """
 
+    def flip_probabilities(self, qubit: int) -> Tuple[float, float]:
+        """
+        Return both readout error probabilities for a qubit
+
+        Args:
+            qubit (int): The qubit for which the probabilities are returned.
+
+        Returns:
+            Tuple[float, float]: The probabilities to detect 0 as 1 and to detect 1 as 0 for the qubit
+        """
+
+    def apply_to_register(self, register: Union[List[List[bool]], List[List[int]], numpy.ndarray], qubits: List[int], seed: int) -> List[List[bool]]:
+        """
+        Apply the readout errors to an ideal bit register
+
+        Every bit is flipped with the probability to detect 0 as 1 when it is 0
+        and with the probability to detect 1 as 0 when it is 1.
+        The bit in column i of every measurement is the readout of qubit qubits[i].
+        Bits in columns without an entry in qubits are not changed.
+
+        Args:
+            register (Union[List[List[bool]], List[List[int]], np.ndarray]): The ideal bit register, one row per measurement.
+            qubits (List[int]): The qubit read out into each column of the register.
+            seed (int): The seed of the random number generator.
+
+        Returns:
+            List[List[bool]]: The bit register with readout errors
+
+        Raises:
+            TypeError: The register cannot be converted to a bit register.
+        """
+
+    def __copy__(self) -> ImperfectReadoutModel:
+        """
+        Return a copy of the noise model (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> ImperfectReadoutModel:
+        """
+        Return a deep copy of the noise model.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
     def to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the Noise-Model using the bincode crate.
@@ -324,7 +395,6 @@ class ImperfectReadoutModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -336,10 +406,10 @@ class ImperfectReadoutModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to json.
-
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -385,12 +455,12 @@ class DecoherenceOnGateModel:
     ```
     """
 
-    def __init__(self):
-        return
+    def __init__(self) -> None:
+        """
+        Create a new DecoherenceOnGateModel.
+        """
 
-    def set_single_qubit_gate_error(
-        self, gate: str, qubit: int, noise_operator: PlusMinusLindbladNoiseOperator
-    ) -> DecoherenceOnGateModel:
+    def set_single_qubit_gate_error(self, gate: str, qubit: int, noise_operator: PlusMinusLindbladNoiseOperator) -> DecoherenceOnGateModel:
         """
         Set extra noise for a single qubit gate.
 
@@ -406,9 +476,7 @@ class DecoherenceOnGateModel:
             PyTypeError: Noise operator is not a struqture.spins.PlusMinusLindbladNoiseOperator.
         """
 
-    def get_single_qubit_gate_error(
-        self, gate: str, qubit: int
-    ) -> Optional[PlusMinusLindbladNoiseOperator]:
+    def get_single_qubit_gate_error(self, gate: str, qubit: int) -> Optional[PlusMinusLindbladNoiseOperator]:
         """
         Return the extra noise for a single qubit gate, if it exists.
 
@@ -420,13 +488,7 @@ class DecoherenceOnGateModel:
             Optional[struqture_py.spins.PlusMinusLindbladNoiseOperator]: The error model applied when gate is applied.
         """
 
-    def set_two_qubit_gate_error(
-        self,
-        gate: str,
-        control: int,
-        target: int,
-        noise_operator: PlusMinusLindbladNoiseOperator,
-    ) -> DecoherenceOnGateModel:
+    def set_two_qubit_gate_error(self, gate: str, control: int, target: int, noise_operator: PlusMinusLindbladNoiseOperator) -> DecoherenceOnGateModel:
         """
         Set extra noise for a two qubit gate.
 
@@ -443,9 +505,7 @@ class DecoherenceOnGateModel:
             PyTypeError: Noise operator is not a struqture.spins.PlusMinusLindbladNoiseOperator.
         """
 
-    def get_two_qubit_gate_error(
-        self, gate: str, control: int, target: int
-    ) -> Optional[PlusMinusLindbladNoiseOperator]:
+    def get_two_qubit_gate_error(self, gate: str, control: int, target: int) -> Optional[PlusMinusLindbladNoiseOperator]:
         """
         Return the extra noise for a single qubit gate, if it exists.
 
@@ -458,14 +518,32 @@ class DecoherenceOnGateModel:
             Optional[struqture_py.spins.PlusMinusLindbladNoiseOperator]: The error model applied when gate is applied.
         """
 
-    def set_three_qubit_gate_error(
-        self,
-        gate: str,
-        control0: int,
-        control1: int,
-        target: int,
-        noise_operator: PlusMinusLindbladNoiseOperator,
-    ) -> DecoherenceOnGateModel:
+    def set_two_qubit_gate_error_symmetric(self, gate: str, qubit_a: int, qubit_b: int, noise_operator: PlusMinusLindbladNoiseOperator) -> DecoherenceOnGateModel:
+        """
+        Set the same extra noise for a two qubit gate in both orientations.
+
+        Args:
+            gate (str): The name of the gate.
+            qubit_a (int): The first qubit the gate acts on.
+            qubit_b (int): The second qubit the gate acts on.
+            noise_operator (struqture_py.spins.PlusMinusLindbladNoiseOperator): The noise affecting system when gate is applied.
+
+        Returns:
+            Self: The error model with the new noise on gate set for both orientations.
+
+        Raises:
+            PyTypeError: Noise operator is not a struqture.spins.PlusMinusLindbladNoiseOperator.
+        """
+
+    def get_all_two_qubit_gate_errors(self) -> List[Tuple[str, int, int]]:
+        """
+        Return all two qubit gates with extra noise.
+
+        Returns:
+            List[Tuple[str, int, int]]: The name, control and target qubit of the gates, sorted.
+        """
+
+    def set_three_qubit_gate_error(self, gate: str, control0: int, control1: int, target: int, noise_operator: PlusMinusLindbladNoiseOperator) -> DecoherenceOnGateModel:
         """
         Set extra noise for a single qubit gate.
 
@@ -483,9 +561,7 @@ class DecoherenceOnGateModel:
             PyTypeError: Noise operator is not a struqture.spins.PlusMinusLindbladNoiseOperator.
         """
 
-    def get_three_qubit_gate_error(
-        self, gate: str, control0: int, control1: int, target: int
-    ) -> Optional[PlusMinusLindbladNoiseOperator]:
+    def get_three_qubit_gate_error(self, gate: str, control0: int, control1: int, target: int) -> Optional[PlusMinusLindbladNoiseOperator]:
         """
         Return the extra noise for a three qubit gate, if it exists.
 
@@ -499,9 +575,7 @@ class DecoherenceOnGateModel:
             Optional[struqture_py.spins.PlusMinusLindbladNoiseOperator]: The error model applied when gate is applied.
         """
 
-    def set_multi_qubit_gate_error(
-        self, gate: str, qubits: list, noise_operator: PlusMinusLindbladNoiseOperator
-    ) -> DecoherenceOnGateModel:
+    def set_multi_qubit_gate_error(self, gate: str, qubits: list, noise_operator: PlusMinusLindbladNoiseOperator) -> DecoherenceOnGateModel:
         """
         Set extra noise for a multi qubit gate.
 
@@ -517,9 +591,7 @@ class DecoherenceOnGateModel:
             PyTypeError: Noise operator is not a struqture.spins.PlusMinusLindbladNoiseOperator.
         """
 
-    def get_multi_qubit_gate_error(
-        self, gate: str, qubits: List[int]
-    ) -> Optional[PlusMinusLindbladNoiseOperator]:
+    def get_multi_qubit_gate_error(self, gate: str, qubits: List[int]) -> Optional[PlusMinusLindbladNoiseOperator]:
         """
         Return the extra noise for a multi qubit gate, if it exists.
 
@@ -531,7 +603,59 @@ class DecoherenceOnGateModel:
             Optional[struqture_py.spins.PlusMinusLindbladNoiseOperator]: The error model applied when gate is applied.
         """
 
-    def from_bincode(self, input: bytearray):
+    def remove_single_qubit_gate_error(self, gate: str, qubit: int) -> DecoherenceOnGateModel:
+        """
+        Remove the extra noise for a single qubit gate.
+
+        Args:
+            gate (str): The name of the gate.
+            qubit (int): The qubit the gate acts on.
+
+        Returns:
+            Self: The error model without noise on the gate.
+        """
+
+    def remove_two_qubit_gate_error(self, gate: str, control: int, target: int) -> DecoherenceOnGateModel:
+        """
+        Remove the extra noise for a two qubit gate in the given orientation.
+
+        Args:
+            gate (str): The name of the gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Self: The error model without noise on the gate.
+        """
+
+    def remove_three_qubit_gate_error(self, gate: str, control0: int, control1: int, target: int) -> DecoherenceOnGateModel:
+        """
+        Remove the extra noise for a three qubit gate.
+
+        Args:
+            gate (str): The name of the gate.
+            control0 (int): The first control qubit the gate acts on.
+            control1 (int): The second control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Self: The error model without noise on the gate.
+        """
+
+    def remove_multi_qubit_gate_error(self, gate: str, qubits: List[int]) -> DecoherenceOnGateModel:
+        """
+        Remove the extra noise for a multi qubit gate.
+
+        Args:
+            gate (str): The name of the gate.
+            qubits (List[int]): The qubits the gate acts on.
+
+        Returns:
+            Self: The error model without noise on the gate.
+        """
+
+    @staticmethod
+    def from_bincode(input: bytearray) -> DecoherenceOnGateModel:
         """
         Convert the bincode representation of the Noise-Model to a device using the bincode crate.
 
@@ -546,7 +670,8 @@ class DecoherenceOnGateModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def from_json(self, input: str):
+    @staticmethod
+    def from_json(input: str) -> DecoherenceOnGateModel:
         """
         Convert the json representation of a device to a Noise-Model.
 
@@ -560,7 +685,8 @@ class DecoherenceOnGateModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -568,6 +694,25 @@ class DecoherenceOnGateModel:
             str: The json schema serialized to json
         """
 
+    def __copy__(self) -> DecoherenceOnGateModel:
+        """
+        Return a copy of the noise model (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> DecoherenceOnGateModel:
+        """
+        Return a deep copy of the noise model.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
     def to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the Noise-Model using the bincode crate.
@@ -577,7 +722,6 @@ class DecoherenceOnGateModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -589,10 +733,10 @@ class DecoherenceOnGateModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to json.
-
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -626,8 +770,34 @@ class SingleQubitOverrotationDescription:
     ```
     """
 
-    def __init__(self):
-        return
+    def __init__(self, gate: str, theta_mean: float, theta_std: float) -> None:
+        """
+        Return description to generate single qubit overrotation noise
+
+        Args:
+            gate: The name qubit gate.
+            theta_mean: The mean of Gaussian distrbution from which overrotation angle is sampled.
+            theta_std: The standard deviation of Gaussian distrbution from which overrotation angle is sampled.
+
+        Returns:
+            `self`.
+        """
+
+    def __copy__(self) -> SingleQubitOverrotationDescription:
+        """
+        Return a copy of the device (copy here produces a deepcopy).
+
+        Returns:
+            A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: Any) -> SingleQubitOverrotationDescription:
+        """
+        Create deep copy of Noise-Model.
+
+        Returns:
+            A deep copy of self.
+        """
 
     def to_bincode(self) -> bytearray:
         """
@@ -651,7 +821,8 @@ class SingleQubitOverrotationDescription:
             ValueError: Cannot serialize SingleQubitOverrotationDescription.
         """
 
-    def from_bincode(self, input: bytearray):
+    @staticmethod
+    def from_bincode(input: bytearray) -> SingleQubitOverrotationDescription:
         """
         Convert the bincode representation of the overotation description to a device using the bincode crate.
 
@@ -666,7 +837,8 @@ class SingleQubitOverrotationDescription:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def from_json(self, input: str):
+    @staticmethod
+    def from_json(input: str) -> SingleQubitOverrotationDescription:
         """
         Convert the json representation of a device to a overotation description.
 
@@ -688,7 +860,8 @@ class SingleQubitOverrotationDescription:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Return the current version of the qoqo library.
 
@@ -696,7 +869,8 @@ class SingleQubitOverrotationDescription:
             str: The current version of the library.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -726,12 +900,12 @@ class SingleQubitOverrotationOnGate:
     ```
     """
 
-    def __init__(self):
-        return
+    def __init__(self) -> None:
+        """
+        Create a new SingleQubitOverrotationOnGate.
+        """
 
-    def set_single_qubit_overrotation(
-        self, gate: str, qubit: int
-    ) -> SingleQubitOverrotationOnGate:
+    def set_single_qubit_overrotation(self, gate: str, qubit: int, noise_description: SingleQubitOverrotationDescription) -> SingleQubitOverrotationOnGate:
         """
         Set overrotation for a single qubit gate.
 
@@ -747,9 +921,7 @@ class SingleQubitOverrotationOnGate:
             PyTypeError: Noise description is not a SingleQubitOverrotationDescription.
         """
 
-    def get_single_qubit_overrotation(
-        self, gate: str, qubit: int
-    ) -> Optional[SingleQubitOverrotationDescription]:
+    def get_single_qubit_overrotation(self, gate: str, qubit: int) -> Optional[SingleQubitOverrotationDescription]:
         """
         Return the overrotation description for a single qubit gate, if it exists.
 
@@ -761,9 +933,7 @@ class SingleQubitOverrotationOnGate:
             Optional[SingleQubitOverrotationDescription]: The overrotation applied when gate is applied.
         """
 
-    def set_two_qubit_overrotation(
-        self, gate: str, control: int, target: int
-    ) -> SingleQubitOverrotationOnGate:
+    def set_two_qubit_overrotation(self, gate: str, control: int, target: int, noise_operator: Tuple[Any, Any]) -> SingleQubitOverrotationOnGate:
         """
         Set extra noise for a two qubit gate.
 
@@ -780,11 +950,7 @@ class SingleQubitOverrotationOnGate:
             PyTypeError: Noise description is not a (SingleQubitOverrotationDescription, SingleQubitOverrotationDescription).
         """
 
-    def get_two_qubit_overrotation(
-        self, gate: str, control: int, target: int
-    ) -> Optional[
-        Tuple[SingleQubitOverrotationDescription, SingleQubitOverrotationDescription]
-    ]:
+    def get_two_qubit_overrotation(self, gate: str, control: int, target: int) -> Optional[Tuple[SingleQubitOverrotationDescription, SingleQubitOverrotationDescription]]:
         """
         Return the extra noise for a single qubit gate, if it exists.
 
@@ -797,7 +963,68 @@ class SingleQubitOverrotationOnGate:
             Optional[Tuple[SingleQubitOverrotationDescription, SingleQubitOverrotationDescription]]: The overrotation applied when gate is applied.
         """
 
-    def from_bincode(self, input: bytearray):
+    def set_single_qubit_overrotation_for_all_qubits(self, gate: str, number_qubits: int, noise_description: SingleQubitOverrotationDescription) -> SingleQubitOverrotationOnGate:
+        """
+        Set the same overrotation for a single qubit gate on all qubits.
+
+        Args:
+            gate (str): The name of the gate.
+            number_qubits (int): The number of qubits, the overrotation is set for qubits 0 to number_qubits - 1.
+            noise_description (SingleQubitOverrotationDescription) - overrotation description for gate.
+
+        Returns:
+            Self: The overrotation model with the new overrotation on gate set.
+
+        Raises:
+            PyTypeError: Noise description is not a SingleQubitOverrotationDescription.
+        """
+
+    def set_two_qubit_overrotation_for_all_qubits(self, gate: str, number_qubits: int, noise_description: Tuple[Any, Any]) -> SingleQubitOverrotationOnGate:
+        """
+        Set the same overrotation for a two qubit gate on all pairs of different qubits in both orientations.
+
+        Args:
+            gate (str): The name of the gate.
+            number_qubits (int): The number of qubits, the overrotation is set for qubits 0 to number_qubits - 1.
+            noise_description ((SingleQubitOverrotationDescription, SingleQubitOverrotationDescription)) - overrotation description for gate.
+
+        Returns:
+            Self: The overrotation model with the new overrotation on gate set.
+
+        Raises:
+            PyTypeError: Noise description is not a (SingleQubitOverrotationDescription, SingleQubitOverrotationDescription).
+        """
+
+    def get_all_single_qubit_overrotations(self) -> List[Tuple[str, int, SingleQubitOverrotationDescription]]:
+        """
+        Return all single qubit gates with overrotation.
+
+        Returns:
+            List[Tuple[str, int, SingleQubitOverrotationDescription]]: The name, qubit and overrotation of the gates, sorted by name and qubit.
+        """
+
+    def get_all_two_qubit_overrotations(self) -> List[Tuple[str, int, int, Tuple[SingleQubitOverrotationDescription, SingleQubitOverrotationDescription]]]:
+        """
+        Return all two qubit gates with overrotation.
+
+        Returns:
+            List[Tuple[str, int, int, Tuple[SingleQubitOverrotationDescription, SingleQubitOverrotationDescription]]]: The name, control, target and overrotation of the gates, sorted by name and qubits.
+        """
+
+    def to_table_json(self) -> str:
+        """
+        Return the overrotations as a table in json form.
+
+        The table is a list of rows with the columns gate, qubits, qubit, rotation_gate, theta_mean and theta_std.
+        Two qubit gates have one row for the control qubit (first description)
+        and one row for the target qubit (second description).
+
+        Returns:
+            str: The overrotation table in json form.
+        """
+
+    @staticmethod
+    def from_bincode(input: bytearray) -> SingleQubitOverrotationOnGate:
         """
         Convert the bincode representation of the Noise-Model to a device using the bincode crate.
 
@@ -812,7 +1039,8 @@ class SingleQubitOverrotationOnGate:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def from_json(self, input: str):
+    @staticmethod
+    def from_json(input: str) -> SingleQubitOverrotationOnGate:
         """
         Convert the json representation of a device to a Noise-Model.
 
@@ -826,7 +1054,8 @@ class SingleQubitOverrotationOnGate:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -834,6 +1063,25 @@ class SingleQubitOverrotationOnGate:
             str: The json schema serialized to json
         """
 
+    def __copy__(self) -> SingleQubitOverrotationOnGate:
+        """
+        Return a copy of the noise model (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> SingleQubitOverrotationOnGate:
+        """
+        Return a deep copy of the noise model.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
     def to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the Noise-Model using the bincode crate.
@@ -843,7 +1091,6 @@ class SingleQubitOverrotationOnGate:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -855,10 +1102,10 @@ class SingleQubitOverrotationOnGate:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to json.
-
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -903,8 +1150,10 @@ class DecoherenceOnIdleModel:
         noise_operator (struqture_py.spins.PlusMinusLindbladNoiseOperator): Optional initialisation of Noise Model with given Lindblad operator.
     """
 
-    def __init__(self, noise_operator: PlusMinusLindbladNoiseOperator):
-        return
+    def __init__(self, noise_operator: PlusMinusLindbladNoiseOperator) -> None:
+        """
+        Create a new DecoherenceOnIdleModel
+        """
 
     def get_noise_operator(self) -> PlusMinusLindbladNoiseOperator:
         """
@@ -914,7 +1163,8 @@ class DecoherenceOnIdleModel:
             PlusMinusLindbladNoiseOperator: The internal Lindblad noise operator of the continuous noise
         """
 
-    def from_bincode(self, input: bytearray):
+    @staticmethod
+    def from_bincode(input: bytearray) -> DecoherenceOnIdleModel:
         """
         Convert the bincode representation of the Noise-Model to a device using the bincode crate.
 
@@ -929,7 +1179,8 @@ class DecoherenceOnIdleModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def from_json(self, input: str):
+    @staticmethod
+    def from_json(input: str) -> DecoherenceOnIdleModel:
         """
         Convert the json representation of a device to a Noise-Model.
 
@@ -943,7 +1194,8 @@ class DecoherenceOnIdleModel:
             ValueError: Input cannot be deserialized to selected Noise-Model.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -951,9 +1203,7 @@ class DecoherenceOnIdleModel:
             str: The json schema serialized to json
         """
 
-    def add_damping_rate(
-        self, qubits: List[int], rate: float
-    ) -> DecoherenceOnIdleModel:
+    def add_damping_rate(self, qubits: List[int], rate: float) -> DecoherenceOnIdleModel:
         """
         Convenience function to add damping to several qubits
 
@@ -965,9 +1215,7 @@ class DecoherenceOnIdleModel:
             DecoherenceOnIdleModel: The model with the damping added.
         """
 
-    def add_dephasing_rate(
-        self, qubits: List[int], rate: float
-    ) -> DecoherenceOnIdleModel:
+    def add_dephasing_rate(self, qubits: List[int], rate: float) -> DecoherenceOnIdleModel:
         """
         Convenience function to add dephasing to several qubits
 
@@ -979,9 +1227,7 @@ class DecoherenceOnIdleModel:
             DecoherenceOnIdleModel: The model with the dephasing added.
         """
 
-    def add_depolarising_rate(
-        self, qubits: List[int], rate: float
-    ) -> DecoherenceOnIdleModel:
+    def add_depolarising_rate(self, qubits: List[int], rate: float) -> DecoherenceOnIdleModel:
         """
         Convenience function to add depolarising to several qubits
 
@@ -993,9 +1239,7 @@ class DecoherenceOnIdleModel:
             DecoherenceOnIdleModel: The model with the depolarising added.
         """
 
-    def add_excitation_rate(
-        self, qubits: List[int], rate: float
-    ) -> DecoherenceOnIdleModel:
+    def add_excitation_rate(self, qubits: List[int], rate: float) -> DecoherenceOnIdleModel:
         """
         Convenience function to add excitation to several qubits
 
@@ -1007,6 +1251,25 @@ class DecoherenceOnIdleModel:
             DecoherenceOnIdleModel: The model with the excitation added.
         """
 
+    def __copy__(self) -> DecoherenceOnIdleModel:
+        """
+        Return a copy of the noise model (copy here produces a deepcopy).
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> DecoherenceOnIdleModel:
+        """
+        Return a deep copy of the noise model.
+
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
+        """
+
     def to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the Noise-Model using the bincode crate.
@@ -1016,7 +1279,6 @@ class DecoherenceOnIdleModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to bytes.
-
         """
 
     def to_json(self) -> str:
@@ -1028,10 +1290,10 @@ class DecoherenceOnIdleModel:
 
         Raises:
             ValueError: Cannot serialize Noise-Model to json.
-
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -1046,3 +1308,23 @@ class DecoherenceOnIdleModel:
         Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
+
+def idle_noise_circuit(qubits: List[int], duration: CalculatorFloat, model: ContinuousDecoherenceModel) -> Circuit:
+    """
+    Create a circuit applying the noise of a ContinuousDecoherenceModel to idling qubits.
+
+    The noise of each qubit is applied with one PragmaGeneralNoise containing the rates of the model
+    in the basis σ+, σ-, σz. Qubits without noise in the model are skipped.
+
+    Args:
+        qubits (List[int]): The idling qubits.
+        duration (CalculatorFloat): The time (in seconds) the qubits are idling.
+        model (ContinuousDecoherenceModel): The noise model containing the decoherence rates.
+
+    Returns:
+        Circuit: The circuit containing one PragmaGeneralNoise per noisy qubit.
+
+    Raises:
+        TypeError: Input cannot be converted to CalculatorFloat or ContinuousDecoherenceModel.
+        ValueError: The model contains noise acting on several qubits or a rate that is not a real number.
+    """
@@ -1,6 +1,5 @@
-# This is an auto generated file containing only the documentation.
-# You can find the full implementation on this page:
-# https://github.com/HQSquantumsimulations/qoqo
+# This file is generated by qoqo-stubgen from the Rust sources of qoqo.
+# Do not edit it by hand, regenerate it with `cargo run -p qoqo-stubgen` instead.
 
 """
 Operations are the atomic instructions in any quantum program that can be represented by qoqo.
@@ -14,43 +13,29 @@ Operations:
     PRAGMAs are operations that can be used when running a simulation of a quantum computing program.
     Measurement Operations are operations that perform a measurement either on a quantum computing device (MeasureQubit)
     or on a simulation of a quantum computing program (PRAGMA measurement operations).
-
 """
 
 import numpy
-from typing import Optional, List, Dict, Set, Union
-from .qoqo import Circuit, Operation
+from typing import Any, Dict, List, Mapping, Optional, Set, Tuple, Union
+from qoqo_calculator_pyo3 import CalculatorFloat
+from struqture_py.spins import PlusMinusLindbladNoiseOperator, SpinHamiltonianSystem
+from qoqo import Circuit
+from qoqo.noise_models import ContinuousDecoherenceModel
 
-class SingleQubitGate(Operation):
+class Operation:
     """
-The general single qubit unitary gate.
-
-.. math::
-    U =\begin{pmatrix}
-        \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-        \beta_r+i \beta_i & \alpha_r-i\alpha_i
-        \end{pmatrix}
+    Operations are the atomic instructions in any quantum program that can be represented by qoqo.
 
-Args:
-    qubit (int): The qubit that the unitary gate is applied to.
-    alpha_r (CalculatorFloat): The real part of the on-diagonal elements of the single-qubit unitary.
-    alpha_i (CalculatorFloat): The imaginary part of the on-diagonal elements of the single-qubit unitary.
-    beta_r (CalculatorFloat): The real part of the off-diagonal elements of the single-qubit unitary.
-    beta_i (CalculatorFloat): The imaginary part of the off-diagonal elements of the single-qubit unitary.
-    global_phase (CalculatorFloat): The global phase of the single-qubit unitary.
+    Operations can be of various kinds: Definitions, GateOperations, PRAGMAs or measurement Operations.
 
-"""
-
-    def __init__(
-        self,
-        qubit: int,
-        alpha_r: Union[float, str],
-        alpha_i: Union[float, str],
-        beta_r: Union[float, str],
-        beta_i: Union[float, str],
-        global_phase: Union[float, str],
-    ):
-        return
+    Operations:
+        Definition operations define the classical registers and variables in the Circuit.
+        GateOperations are single-, two- or multi-qubit gate operations that act on a set of qubits
+        and can be executed on a quantum computing device.
+        PRAGMAs are operations that can be used when running a simulation of a quantum computing program.
+        Measurement Operations are operations that perform a measurement either on a quantum computing device (MeasureQubit)
+        or on a simulation of a quantum computing program (PRAGMA measurement operations).
+    """
 
     def is_parametrized(self) -> bool:
         """
@@ -60,6 +45,30 @@ Args:
             bool: Whether or not the operation contains symbolic parameters.
         """
 
+    def is_gate_operation(self) -> bool:
+        """
+        Returns true if the operation is a gate operation
+
+        Returns:
+            bool: Whether or not the operation has the tag `GateOperation`.
+        """
+
+    def is_pragma(self) -> bool:
+        """
+        Returns true if the operation is a PRAGMA operation
+
+        Returns:
+            bool: Whether or not the operation has the tag `PragmaOperation`.
+        """
+
+    def is_measurement(self) -> bool:
+        """
+        Returns true if the operation is a measurement
+
+        Returns:
+            bool: Whether or not the operation has the tag `Measurement`.
+        """
+
     def tags(self) -> List[str]:
         """
         Returns tags identifying the Operation
@@ -76,9 +85,7 @@ Args:
             str: The name
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def substitute_parameters(self, substitution_parameters: Dict[str, float]) -> Operation:
         """
         Substitutes internal symbolic parameters with float values
 
@@ -117,94 +124,150 @@ Args:
             Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
         """
 
-    def qubit(self):
+    def __copy__(self) -> Operation:
         """
-        Return the qubit the operation acts on
+        Return a copy of the Operation (copy here produces a deepcopy).
 
         Returns:
-            int
+            Self: A deep copy of self.
+        """
+
+    def __deepcopy__(self, _memodict: dict) -> Operation:
         """
+        Return a deep copy of the Operation.
 
-    def global_phase(self):
+        Args:
+            _memodict (dict): The memo dictionary of the deepcopy, it is ignored.
+
+        Returns:
+            Self: A deep copy of self.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+class SingleQubitGate(Operation):
+    r"""
+    The general single qubit unitary gate.
+
+    .. math::
+        U =\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    Args:
+        qubit (int): The qubit that the unitary gate is applied to.
+        alpha_r (CalculatorFloat): The real part of the on-diagonal elements of the single-qubit unitary.
+        alpha_i (CalculatorFloat): The imaginary part of the on-diagonal elements of the single-qubit unitary.
+        beta_r (CalculatorFloat): The real part of the off-diagonal elements of the single-qubit unitary.
+        beta_i (CalculatorFloat): The imaginary part of the off-diagonal elements of the single-qubit unitary.
+        global_phase (CalculatorFloat): The global phase of the single-qubit unitary.
+    """
 
-Returns:
-    CalculatorFloat
-"""
+    def __init__(self, qubit: int, alpha_r: Union[CalculatorFloat, float, str], alpha_i: Union[CalculatorFloat, float, str], beta_r: Union[CalculatorFloat, float, str], beta_i: Union[CalculatorFloat, float, str], global_phase: Union[CalculatorFloat, float, str]) -> None:
+        """
+        Create a new SingleQubitGate.
+
+        Args:
+            qubit (int): The qubit that the unitary gate is applied to.
+            alpha_r (CalculatorFloat): The real part of the on-diagonal elements of the single-qubit unitary.
+            alpha_i (CalculatorFloat): The imaginary part of the on-diagonal elements of the single-qubit unitary.
+            beta_r (CalculatorFloat): The real part of the off-diagonal elements of the single-qubit unitary.
+            beta_i (CalculatorFloat): The imaginary part of the off-diagonal elements of the single-qubit unitary.
+            global_phase (CalculatorFloat): The global phase of the single-qubit unitary.
+
+        Returns:
+            SingleQubitGate: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
+        """
 
-    def alpha_r(self):
+    def qubit(self) -> int:
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
+        Return the qubit the operation acts on.
 
-Here alpha_r is defined by
+        Returns:
+            int: The qubit the operation acts on.
+        """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        Here global_phase is defined by
 
-    def alpha_i(self):
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        Here alpha_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
+        """
 
-    def beta_i(self):
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
+
+        Here beta_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -212,10 +275,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -226,225 +294,257 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def min_supported_version(self) -> str:
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Return the minimum version of qoqo that supports this object.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def json_schema(self) -> str:
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
-        Return the JsonSchema for the json serialisation of the class.
 
-        Returns:
-            str: The json schema serialized to json
+    def unitary_matrix(self) -> numpy.ndarray:
         """
+        Return the unitary matrix of the gate.
 
-class RotateZ(Operation):
-    """
-The ZPower gate :math:`e^{-i \frac{\theta}{2} \sigma^z}`.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\frac{\theta}{2}) & 0 \\\\
-        0 & \cos(\frac{\theta}{2})
-        \end{pmatrix}
-        + \begin{pmatrix}
-        - i \sin(\frac{\theta}{2}) & 0 \\\\
-        0 & i \sin(\frac{\theta}{2})
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
-
-"""
-
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the current version of the qoqo library.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns hqslang name of Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The name
+            str: The json schema serialized to json.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+    @staticmethod
+    def from_unitary_matrix(matrix: Union[numpy.ndarray, List[List[complex]]], qubit: int, tolerance: float = ...) -> SingleQubitGate:
+        r"""
+        Create a SingleQubitGate from its unitary matrix.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        The matrix is decomposed as :math:`e^{i \varphi} [[\alpha, -\beta^*], [\beta, \alpha^*]]`
+        with the global phase :math:`\varphi` in the interval :math:`(-\pi/2, \pi/2]`.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            matrix (Union[np.ndarray, List[List[complex]]]): The 2x2 complex unitary matrix of the gate.
+            qubit (int): The qubit that the unitary gate is applied to.
+            tolerance (float): The tolerance of the unitarity check.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            SingleQubitGate: The gate with the given unitary matrix.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: Matrix cannot be converted to a complex 2d array.
+            ValueError: Matrix is not a 2x2 unitary matrix.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class RotateZ(Operation):
+    r"""
+    The ZPower gate :math:`e^{-i \frac{\theta}{2} \sigma^z}`.
+
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\frac{\theta}{2}) & 0 \\\\
+            0 & \cos(\frac{\theta}{2})
+            \end{pmatrix}
+            + \begin{pmatrix}
+            - i \sin(\frac{\theta}{2}) & 0 \\\\
+            0 & i \sin(\frac{\theta}{2})
+            \end{pmatrix}
+
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
+
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new RotateZ.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            RotateZ: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
+
+        Returns:
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
-        List all involved Qubits
+
+    def powercf(self, power: CalculatorFloat) -> RotateZ:
+        """
+        Return the rotation gate raised to a power.
+
+        Args:
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The gate raised to the power of `power`.
         """
 
-    def qubit(self):
+    def overrotate(self, amplitude: float, variance: float) -> RotateZ:
         """
-        Return the qubit the operation acts on
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            int
+            Self: The overrotated gate.
+        """
+
+    def qubit(self) -> int:
         """
+        Return the qubit the operation acts on.
 
-    def global_phase(self):
+        Returns:
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -452,10 +552,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -466,237 +571,236 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
-        """
-        Return unitary matrix of gate.
-
-        Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-    def powercf(self) -> RotateZ:
-        """
-        Returns Rotated gate raised to power
+        Alias for `mul`, allowing the use of the `*` operator.
 
         Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def min_supported_version(self) -> str:
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Return the minimum version of qoqo that supports this object.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def json_schema(self) -> str:
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
-        Return the JsonSchema for the json serialisation of the class.
 
-        Returns:
-            str: The json schema serialized to json
+    def unitary_matrix(self) -> numpy.ndarray:
         """
+        Return the unitary matrix of the gate.
 
-class RotateY(Operation):
-    """
-The YPower gate :math:`e^{-i \frac{\theta}{2} \sigma^y}`.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\frac{\theta}{2}) & 0 \\\\
-        0 & \cos(\frac{\theta}{2})
-        \end{pmatrix}
-        + \begin{pmatrix}
-        0 & - \sin(\frac{\theta}{2}) \\\\
-        \sin(\frac{\theta}{2}) & 0
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
-
-"""
-
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the current version of the qoqo library.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns hqslang name of Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The name
+            str: The json schema serialized to json.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+class RotateY(Operation):
+    r"""
+    The YPower gate :math:`e^{-i \frac{\theta}{2} \sigma^y}`.
+
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\frac{\theta}{2}) & 0 \\\\
+            0 & \cos(\frac{\theta}{2})
+            \end{pmatrix}
+            + \begin{pmatrix}
+            0 & - \sin(\frac{\theta}{2}) \\\\
+            \sin(\frac{\theta}{2}) & 0
+            \end{pmatrix}
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
+
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new RotateY.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            RotateY: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
+
+        Returns:
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
-        Remap qubits
+
+    def powercf(self, power: CalculatorFloat) -> RotateY:
+        """
+        Return the rotation gate raised to a power.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def overrotate(self, amplitude: float, variance: float) -> RotateY:
         """
-        List all involved Qubits
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -704,10 +808,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -718,237 +827,236 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def powercf(self) -> RotateY:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            Self: gate raised to the power of `power`
-
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def current_version(self) -> str:
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Returns the current version of the qoqo library .
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The current version of the library.
+            Circuit: The rotations followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the unitary matrix of the gate.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class RotateX(Operation):
-    """
-The XPower gate :math:`e^{-i \frac{\theta}{2} \sigma^x}`.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\frac{\theta}{2}) & 0 \\\\
-        0 & \cos(\frac{\theta}{2})
-        \end{pmatrix}
-        + \begin{pmatrix}
-        0  &  -i \sin(\frac{\theta}{2}) \\\\
-        -i \sin(\frac{\theta}{2})  & 0
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
-
-"""
-
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+            np.ndarray: The unitary matrix of the gate.
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the current version of the qoqo library.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns hqslang name of Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The name
+            str: The json schema serialized to json.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+class RotateX(Operation):
+    r"""
+    The XPower gate :math:`e^{-i \frac{\theta}{2} \sigma^x}`.
+
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\frac{\theta}{2}) & 0 \\\\
+            0 & \cos(\frac{\theta}{2})
+            \end{pmatrix}
+            + \begin{pmatrix}
+            0  &  -i \sin(\frac{\theta}{2}) \\\\
+            -i \sin(\frac{\theta}{2})  & 0
+            \end{pmatrix}
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
+
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new RotateX.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            RotateX: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
+
+        Returns:
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
-        Remap qubits
+
+    def powercf(self, power: CalculatorFloat) -> RotateX:
+        """
+        Return the rotation gate raised to a power.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def overrotate(self, amplitude: float, variance: float) -> RotateX:
         """
-        List all involved Qubits
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -956,10 +1064,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -970,35 +1083,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def powercf(self) -> RotateX:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns Rotated gate raised to power
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Self: gate raised to the power of `power`
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def current_version(self) -> str:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns the current version of the qoqo library .
+        Return the unitary matrix of the gate.
+
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -1012,197 +1152,173 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class RotateXY(Operation):
-    """
-Implements a rotation around an axis in the x-y plane in spherical coordinates.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\frac{\theta}{2}) & -i e^{-i \phi} \sin(\frac{\theta}{2}) \\\\
-        -i e^{i \phi} \sin(\frac{\theta}{2}) & \cos(\frac{\theta}{2})
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
-    phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
+    r"""
+    Implements a rotation around an axis in the x-y plane in spherical coordinates.
 
-"""
-
-    def __init__(self, qubit: int, theta: Union[float, str], phi: Union[float, str]):
-        return
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\frac{\theta}{2}) & -i e^{-i \phi} \sin(\frac{\theta}{2}) \\\\
+            -i e^{i \phi} \sin(\frac{\theta}{2}) & \cos(\frac{\theta}{2})
+            \end{pmatrix}
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+        phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
+    """
 
-    def phi(self):
-        """
-        Returns value of attribute phi
-        """
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new RotateXY.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+            phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
+            RotateXY: The new operation.
 
-    def tags(self) -> List[str]:
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns tags identifying the Operation
+
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `phi` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> RotateXY:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> RotateXY:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
+            int: The qubit the operation acts on.
         """
 
-    def global_phase(self):
-        """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -1210,10 +1326,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -1224,35 +1345,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def powercf(self) -> RotateXY:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns Rotated gate raised to power
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Self: gate raised to the power of `power`
+            Circuit: The rotations followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
+    def unitary_matrix(self) -> numpy.ndarray:
         """
+        Return the unitary matrix of the gate.
+
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-    def current_version(self) -> str:
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
-        Returns the current version of the qoqo library .
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -1266,220 +1414,194 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class RotateAroundSphericalAxis(Operation):
-    """
-Implements a rotation around an axis in the x-y plane in spherical coordinates.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\frac{\theta}{2}) & 0 \\\\
-        0 & \cos(\frac{\theta}{2})
-        \end{pmatrix}
-        + \begin{pmatrix}
-        -i \sin(\frac{\theta}{2}) v_z  &  \sin(\frac{\theta}{2}) \left(-i v_x - v_y \right) \\\\
-        \sin(\frac{\theta}{2}) \left(-i v_x + v_y \right) & i \sin(\frac{\theta}{2}) v_z
-        \end{pmatrix}
-
-with
-
-.. math::
- v_x = \sin(\theta_{sph}) \cos(\phi_{sph}) \ , \\
- v_y = \sin(\theta_{sph}) \sin(\phi_{sph}) \ , \\
- v_z = \cos(\theta_{sph}) \ .
+    r"""
+    Implements a rotation around an axis in the x-y plane in spherical coordinates.
+
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\frac{\theta}{2}) & 0 \\\\
+            0 & \cos(\frac{\theta}{2})
+            \end{pmatrix}
+            + \begin{pmatrix}
+            -i \sin(\frac{\theta}{2}) v_z  &  \sin(\frac{\theta}{2}) \left(-i v_x - v_y \right) \\\\
+            \sin(\frac{\theta}{2}) \left(-i v_x + v_y \right) & i \sin(\frac{\theta}{2}) v_z
+            \end{pmatrix}
+
+    with
+
+    .. math::
+     v_x = \sin(\theta_{sph}) \cos(\phi_{sph}) \ , \\
+     v_y = \sin(\theta_{sph}) \sin(\phi_{sph}) \ , \\
+     v_z = \cos(\theta_{sph}) \ .
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
-    spherical_theta (CalculatorFloat): The rotation axis, unit-vector spherical coordinates :math:`\theta_{sph}`.
-    spherical_phi (CalculatorFloat): The rotation axis, unit-vector spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
-
-"""
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+        spherical_theta (CalculatorFloat): The rotation axis, unit-vector spherical coordinates :math:`\theta_{sph}`.
+        spherical_phi (CalculatorFloat): The rotation axis, unit-vector spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
+    """
 
-    def __init__(
-        self,
-        qubit: int,
-        theta: Union[float, str],
-        spherical_theta: Union[float, str],
-        spherical_phi: Union[float, str],
-    ):
-        return
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str], spherical_theta: Union[CalculatorFloat, float, str], spherical_phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new RotateAroundSphericalAxis.
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+            spherical_theta (CalculatorFloat): The rotation axis, unit-vector spherical coordinates :math:`\theta_{sph}`.
+            spherical_phi (CalculatorFloat): The rotation axis, unit-vector spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
 
-    def spherical_theta(self):
-        """
-        Returns value of attribute spherical_theta
-        """
+        Returns:
+            RotateAroundSphericalAxis: The new operation.
 
-    def spherical_phi(self):
-        """
-        Returns value of attribute spherical_phi
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+    def spherical_theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `spherical_theta` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The rotation axis, unit-vector spherical coordinates :math:`\theta_{sph}`.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def spherical_phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `spherical_phi` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The rotation axis, unit-vector spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> RotateAroundSphericalAxis:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> RotateAroundSphericalAxis:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def qubit(self) -> int:
         """
-        List all involved Qubits
+        Return the qubit the operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The qubit the operation acts on.
         """
 
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-        Returns:
-            int
-        """
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def global_phase(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
-
-Here alpha_r is defined by
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        Here beta_r is defined by
 
-    def beta_r(self):
-        """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Here beta_r is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -1487,10 +1609,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -1501,227 +1628,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
-        """
-
-    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
-
-        Returns:
-            np.ndarray: matrix of gate.
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-    def powercf(self) -> RotateAroundSphericalAxis:
-        """
-        Returns Rotated gate raised to power
+        Alias for `mul`, allowing the use of the `*` operator.
 
         Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
-        Return the minimum version of qoqo that supports this object.
 
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The json schema serialized to json
-        """
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-class PauliZ(Operation):
-    """
-The Pauli Z gate.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 \\\\
-        0 & -1
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-
-"""
-
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class PauliZ(Operation):
+    r"""
+    The Pauli Z gate.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 \\\\
+            0 & -1
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new PauliZ.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            PauliZ: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
+            int: The qubit the operation acts on.
         """
 
-    def global_phase(self):
-        """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -1729,10 +1825,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -1743,215 +1844,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
-        Return the minimum version of qoqo that supports this object.
 
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class PauliY(Operation):
-    """
-The Pauli Y gate.
-
-.. math::
-    U = \begin{pmatrix}
-        0 & -i \\\\
-        i & 0
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-
-"""
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class PauliY(Operation):
+    r"""
+    The Pauli Y gate.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \begin{pmatrix}
+            0 & -i \\\\
+            i & 0
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new PauliY.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            PauliY: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -1959,10 +2041,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -1973,23 +2060,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
+        """
+
+    def __mul__(self, other: Operation) -> SingleQubitGate:
+        """
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
+
+        Returns:
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+        """
+
+    def to_single_qubit_gate(self) -> SingleQubitGate:
+        """
+        Return the equivalent SingleQubitGate of the single qubit gate.
+
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
+        Returns:
+            Circuit: The rotations followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -2003,185 +2129,127 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class PauliX(Operation):
-    """
-The Pauli X gate.
-
-.. math::
-    U = \begin{pmatrix}
-        0 & 1 \\\\
-        1 & 0
-        \end{pmatrix}
+    r"""
+    The Pauli X gate.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+    .. math::
+        U = \begin{pmatrix}
+            0 & 1 \\\\
+            1 & 0
+            \end{pmatrix}
 
-"""
-
-    def __init__(self, qubit: int):
-        return
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-    def is_parametrized(self) -> bool:
+    def __init__(self, qubit: int) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new PauliX.
+
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            PauliX: The new operation.
         """
 
-    def tags(self) -> List[str]:
+    def qubit(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The qubit the operation acts on.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
+
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Here alpha_r is defined by
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the remapped qubits
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
+        """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+        Here beta_r is defined by
 
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
-        """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def alpha_r(self):
-        """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
-
-Here alpha_r is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def alpha_i(self):
-        """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def beta_r(self):
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-
-Returns:
-    CalculatorFloat
-"""
-
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -2189,10 +2257,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -2203,215 +2276,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
-        Return the minimum version of qoqo that supports this object.
 
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class SqrtPauliX(Operation):
-    """
-The square root of the XPower gate :math:`e^{-i \frac{\pi}{4} \sigma^x}`.
-
-.. math::
-    U = \frac{1}{\sqrt(2)} \begin{pmatrix}
-        1 & -i \\\\
-        -i & 1
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-
-"""
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class SqrtPauliX(Operation):
+    r"""
+    The square root of the XPower gate :math:`e^{-i \frac{\pi}{4} \sigma^x}`.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \frac{1}{\sqrt(2)} \begin{pmatrix}
+            1 & -i \\\\
+            -i & 1
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new SqrtPauliX.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SqrtPauliX: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -2419,10 +2473,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -2433,215 +2492,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class InvSqrtPauliX(Operation):
-    """
-The inverse square root XPower gate :math:`e^{i \frac{\pi}{4} \sigma^x}`.
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & i \\\\
-        i & 1
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-"""
-
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class InvSqrtPauliX(Operation):
+    r"""
+    The inverse square root XPower gate :math:`e^{i \frac{\pi}{4} \sigma^x}`.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & i \\\\
+            i & 1
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new InvSqrtPauliX.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            InvSqrtPauliX: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -2649,10 +2689,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -2663,23 +2708,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
+        """
+
+    def __mul__(self, other: Operation) -> SingleQubitGate:
+        """
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
+        Returns:
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+        """
+
+    def to_single_qubit_gate(self) -> SingleQubitGate:
+        """
+        Return the equivalent SingleQubitGate of the single qubit gate.
+
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The rotations followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -2693,185 +2777,127 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class Hadamard(Operation):
-    """
-The Hadamard gate.
+    r"""
+    The Hadamard gate.
 
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 1 \\\\
-        1 & -1
-        \end{pmatrix}
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 1 \\\\
+            1 & -1
+            \end{pmatrix}
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-"""
+    def __init__(self, qubit: int) -> None:
+        """
+        Create a new Hadamard.
 
-    def __init__(self, qubit: int):
-        return
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
-    def is_parametrized(self) -> bool:
+        Returns:
+            Hadamard: The new operation.
         """
-        Returns true if operation contains symbolic parameters
+
+    def qubit(self) -> int:
+        """
+        Return the qubit the operation acts on.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            int: The qubit the operation acts on.
         """
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-        Returns:
-            List[str]: The tags identifying the operation
-        """
+        Here global_phase is defined by
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Here alpha_r is defined by
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+        Here beta_r is defined by
 
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
-        """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def alpha_r(self):
-        """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
-
-Here alpha_r is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def alpha_i(self):
-        """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
-
-    def beta_r(self):
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
-
-Here beta_i is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
 
-Returns:
-    CalculatorFloat
-"""
-
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -2879,10 +2905,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -2893,215 +2924,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class TGate(Operation):
-    """
-The T gate.
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 0 \\\\
-        0 & e^{i \frac{\pi}{4}}
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-"""
-
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class TGate(Operation):
+    r"""
+    The T gate.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 0 \\\\
+            0 & e^{i \frac{\pi}{4}}
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new TGate.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            TGate: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -3109,10 +3121,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -3123,215 +3140,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
-        Return the minimum version of qoqo that supports this object.
 
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class SGate(Operation):
-    """
-The S gate.
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 0 \\\\
-        0 & i
-        \end{pmatrix}
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-
-"""
-
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class SGate(Operation):
+    r"""
+    The S gate.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 0 \\\\
+            0 & i
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new SGate.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SGate: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -3339,10 +3337,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -3353,143 +3356,135 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def current_version(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The current version of the library.
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def min_supported_version(self) -> str:
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Return the minimum version of qoqo that supports this object.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def json_schema(self) -> str:
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
-        Return the JsonSchema for the json serialisation of the class.
 
-        Returns:
-            str: The json schema serialized to json
+    def unitary_matrix(self) -> numpy.ndarray:
         """
+        Return the unitary matrix of the gate.
 
-class DefinitionUsize(Operation):
-    """
-    DefinitionUsize is the Definition for an Integer type register.
-
-    Args:
-        name (string): The name of the register that is defined.
-        length (int): The length of the register that is defined, usually the number of qubits to be measured.
-        is_output (bool): True/False if the variable is an output to the program.
-    """
-
-    def __init__(self, name: str, length: int, is_output: bool):
-        return
-
-    def length(self):
-        """
-        Get value of struct field length
-        """
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-    def is_output(self):
-        """
-        Get value of struct field is_output
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the current version of the qoqo library.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns hqslang name of Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The name
+            str: The json schema serialized to json.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+class DefinitionUsize(Operation):
+    """
+    DefinitionUsize is the Definition for an Integer type register.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    Args:
+        name (string): The name of the register that is defined.
+        length (int): The length of the register that is defined, usually the number of qubits to be measured.
+        is_output (bool): True/False if the variable is an output to the program.
+    """
+
+    def __init__(self, name: str, length: int, is_output: bool) -> None:
+        """
+        Create a new DefinitionUsize.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            name (string): The name of the register that is defined.
+            length (int): The length of the register that is defined, usually the number of qubits to be measured.
+            is_output (bool): True/False if the variable is an output to the program.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            DefinitionUsize: The new operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def length(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `length` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The length of the register that is defined, usually the number of qubits to be measured.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def is_output(self) -> bool:
         """
-        List all involved Qubits
+        Return the value of the `is_output` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            bool: True/False if the variable is an output to the program.
         """
 
-    def name(self):
+    def name(self) -> str:
         """
-        Return name of definition operation.
+        Return the name of the definition operation.
 
         Returns:
-            str
+            str: The name of the defined register or variable.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -3503,12 +3498,13 @@ class DefinitionUsize(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class DefinitionBit(Operation):
@@ -3521,95 +3517,120 @@ class DefinitionBit(Operation):
         is_output (bool): True/False if the variable is an output to the program.
     """
 
-    def __init__(self, name: str, length: int, is_output: bool):
-        return
-
-    def length(self):
+    def __init__(self, name: str, length: int, is_output: bool) -> None:
         """
-        Get value of struct field length
+        Create a new DefinitionBit.
+
+        Args:
+            name (string): The name of the register that is defined.
+            length (int): The length of the register that is defined, usually the number of qubits to be measured.
+            is_output (bool): True/False if the variable is an output to the program.
+
+        Returns:
+            DefinitionBit: The new operation.
         """
 
-    def is_output(self):
+    def length(self) -> int:
         """
-        Get value of struct field is_output
+        Return the value of the `length` field.
+
+        Returns:
+            int: The length of the register that is defined, usually the number of qubits to be measured.
         """
 
-    def is_parametrized(self) -> bool:
+    def is_output(self) -> bool:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `is_output` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            bool: True/False if the variable is an output to the program.
         """
 
-    def tags(self) -> List[str]:
+    def name(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the name of the definition operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The name of the defined register or variable.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+class DefinitionFloat(Operation):
+    """
+    DefinitionFloat is the Definition for a Float type register.
+
+    Args:
+        name (string): The name of the register that is defined.
+        length (int): The length of the register that is defined, usually the number of qubits to be measured.
+        is_output (bool): True/False if the variable is an output to the program.
+    """
+
+    def __init__(self, name: str, length: int, is_output: bool) -> None:
         """
-        Remap qubits
+        Create a new DefinitionFloat.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            name (string): The name of the register that is defined.
+            length (int): The length of the register that is defined, usually the number of qubits to be measured.
+            is_output (bool): True/False if the variable is an output to the program.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            DefinitionFloat: The new operation.
+        """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def length(self) -> int:
         """
+        Return the value of the `length` field.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+        Returns:
+            int: The length of the register that is defined, usually the number of qubits to be measured.
         """
-        List all involved Qubits
+
+    def is_output(self) -> bool:
+        """
+        Return the value of the `is_output` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            bool: True/False if the variable is an output to the program.
         """
 
-    def name(self):
+    def name(self) -> str:
         """
-        Return name of definition operation.
+        Return the name of the definition operation.
 
         Returns:
-            str
+            str: The name of the defined register or variable.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -3623,17 +3644,18 @@ class DefinitionBit(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class DefinitionFloat(Operation):
+class DefinitionComplex(Operation):
     """
-    DefinitionFloat is the Definition for a Float type register.
+    DefinitionComplex is the Definition for a Complex type register.
 
     Args:
         name (string): The name of the register that is defined.
@@ -3641,95 +3663,110 @@ class DefinitionFloat(Operation):
         is_output (bool): True/False if the variable is an output to the program.
     """
 
-    def __init__(self, name: str, length: int, is_output: bool):
-        return
-
-    def length(self):
+    def __init__(self, name: str, length: int, is_output: bool) -> None:
         """
-        Get value of struct field length
+        Create a new DefinitionComplex.
+
+        Args:
+            name (string): The name of the register that is defined.
+            length (int): The length of the register that is defined, usually the number of qubits to be measured.
+            is_output (bool): True/False if the variable is an output to the program.
+
+        Returns:
+            DefinitionComplex: The new operation.
         """
 
-    def is_output(self):
+    def length(self) -> int:
         """
-        Get value of struct field is_output
+        Return the value of the `length` field.
+
+        Returns:
+            int: The length of the register that is defined, usually the number of qubits to be measured.
         """
 
-    def is_parametrized(self) -> bool:
+    def is_output(self) -> bool:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `is_output` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            bool: True/False if the variable is an output to the program.
         """
 
-    def tags(self) -> List[str]:
+    def name(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the name of the definition operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The name of the defined register or variable.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+class InputSymbolic(Operation):
+    """
+    InputSymbolic is the Definition for a Float which will replace a certain symbolic parameter.
+
+    Args:
+        name (string): The name of the register that is defined.
+        input (float): The float by which to replace the quantities marked as "name".
+    """
+
+    def __init__(self, name: str, input: float) -> None:
         """
-        Remap qubits
+        Create a new InputSymbolic.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            name (string): The name of the register that is defined.
+            input (float): The float by which to replace the quantities marked as "name".
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            InputSymbolic: The new operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def input(self) -> float:
         """
-        List all involved Qubits
+        Return the value of the `input` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            float: The float by which to replace the quantities marked as "name".
         """
 
-    def name(self):
+    def name(self) -> str:
         """
-        Return name of definition operation.
+        Return the name of the definition operation.
 
         Returns:
-            str
+            str: The name of the defined register or variable.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -3743,113 +3780,135 @@ class DefinitionFloat(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class DefinitionComplex(Operation):
+class MeasureQubit(Operation):
     """
-    DefinitionComplex is the Definition for a Complex type register.
+    Measurement gate operation.
+
+    This Operation acts on one qubit writing the result of the measurement into a readout.
+    The classical register for the readout needs to be defined in advance by using a Definition operation.
 
     Args:
-        name (string): The name of the register that is defined.
-        length (int): The length of the register that is defined, usually the number of qubits to be measured.
-        is_output (bool): True/False if the variable is an output to the program.
+        qubit (int): The measured qubit.
+        readout (string): The classical register for the readout.
+        readout_index (int): The index in the readout the result is saved to.
     """
 
-    def __init__(self, name: str, length: int, is_output: bool):
-        return
-
-    def length(self):
+    def __init__(self, qubit: int, readout: str, readout_index: int) -> None:
         """
-        Get value of struct field length
+        Create a new MeasureQubit.
+
+        Args:
+            qubit (int): The measured qubit.
+            readout (string): The classical register for the readout.
+            readout_index (int): The index in the readout the result is saved to.
+
+        Returns:
+            MeasureQubit: The new operation.
         """
 
-    def is_output(self):
+    def readout(self) -> str:
         """
-        Get value of struct field is_output
+        Return the value of the `readout` field.
+
+        Returns:
+            str: The classical register for the readout.
         """
 
-    def is_parametrized(self) -> bool:
+    def readout_index(self) -> int:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `readout_index` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            int: The index in the readout the result is saved to.
         """
 
-    def tags(self) -> List[str]:
+    def qubit(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The qubit the operation acts on.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+class PragmaGetStateVector(Operation):
+    """
+    This PRAGMA measurement operation returns the statevector of a quantum register.
+
+    Args:
+        readout (string): The name of the classical readout register.
+        circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
+    """
+
+    def __init__(self, readout: str, circuit: Optional[Circuit]) -> None:
         """
-        Remap qubits
+        Create a new PragmaGetStateVector.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            readout (string): The name of the classical readout register.
+            circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaGetStateVector: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def readout(self) -> str:
         """
-        List all involved Qubits
+        Return the value of the `readout` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The name of the classical readout register.
         """
 
-    def name(self):
+    def circuit(self) -> Optional[Circuit]:
         """
-        Return name of definition operation.
+        Return the value of the `circuit` field.
 
         Returns:
-            str
+            Optional[Circuit]: The measurement preparation Circuit, applied on a copy of the register before measurement.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -3863,2060 +3922,62 @@ class DefinitionComplex(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class InputSymbolic(Operation):
+class PragmaGetDensityMatrix(Operation):
     """
-    InputSymbolic is the Definition for a Float which will replace a certain symbolic parameter.
+    This PRAGMA measurement operation returns the density matrix of a quantum register.
+
+    The density matrix of N qubits is written into the readout register as a flattened 2^N x 2^N matrix
+    in row-major order, with qubit 0 corresponding to the least significant bit of the index.
 
     Args:
-        name (string): The name of the register that is defined.
-        input (float): The float by which to replace the quantities marked as "name".
-    """
-
-    def __init__(self, name: str, input: float):
-        return
-
-    def input(self):
-        """
-        Get value of struct field input
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def name(self):
-        """
-        Return name of definition operation.
-
-        Returns:
-            str
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class MeasureQubit(Operation):
-    """
-    Measurement gate operation.
-
-    This Operation acts on one qubit writing the result of the measurement into a readout.
-    The classical register for the readout needs to be defined in advance by using a Definition operation.
-
-    Args:
-        qubit (int): The measured qubit.
-        readout (string): The classical register for the readout.
-        readout_index (int): The index in the readout the result is saved to.
-
-    """
-
-    def __init__(self, qubit: int, readout: str, readout_index: int):
-        return
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def readout_index(self):
-        """
-        Get value of struct field readout_index
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
-
-        Returns:
-            int
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaGetStateVector(Operation):
-    """
-    This PRAGMA measurement operation returns the statevector of a quantum register.
-
-    Args:
-        readout (string): The name of the classical readout register.
-        circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
-
-    """
-
-    def __init__(self, readout: str, circuit: Optional[Circuit]):
-        return
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def circuit(self):
-        """
-        Get value of struct field circuit
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaGetDensityMatrix(Operation):
-    """
-    This PRAGMA measurement operation returns the density matrix of a quantum register.
-
-    Args:
-        readout (string): The name of the classical readout register.
-        circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
-
-    """
-
-    def __init__(self, readout: str, circuit: Optional[Circuit]):
-        return
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def circuit(self):
-        """
-        Get value of struct field circuit
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaGetOccupationProbability(Operation):
-    """
-    This PRAGMA measurement operation returns the vector of the occupation probabilities.
-
-    Occupation probabilities in the context of this PRAGMA operation are probabilities of finding the quantum
-    register in each :math:`\sigma_z` basis state. The quantum register remains unchanged by this PRAGMA measurement operation.
-
-    Args:
-        readout (string): The name of the classical readout register.
-        circuit (Optional[Circuit]): The Circuit used to rotate the qureg.
-
-    """
-
-    def __init__(self, readout: str, circuit: Optional[Circuit]):
-        return
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def circuit(self):
-        """
-        Get value of struct field circuit
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaGetPauliProduct(Operation):
-    """
-    This PRAGMA measurement operation returns a Pauli product expectation value.
-
-    This PRAGMA operation returns a Pauli product expectation value after applying
-    a Rotate to another basis. It performs all of the operation on a clone of the quantum register,
-    sothat the actual quantum register remains unchanged.
-
-    Args:
-        qubit_paulis (Dict[int, int]): The dictionary of the pauli matrix to apply to each qubit in the form
-                                       {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
-        readout (string): The name of the classical readout register.
-        circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
-
-    """
-
-    def __init__(self, qubit_paulis: Dict[int, int], readout: str, circuit: Circuit):
-        return
-
-    def qubit_paulis(self):
-        """
-        Get value of struct field qubit_paulis
-        """
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def circuit(self):
-        """
-        Get value of struct field circuit
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaRepeatedMeasurement(Operation):
-    """
-    This PRAGMA measurement operation returns a measurement record for N repeated measurements.
-
-    Args:
-        readout (string): The name of the classical readout register.
-        qubit_mapping (Dict[int, int]): The mapping of qubits to indices in readout register.
-        number_measurements (int): The number of times to repeat the measurement.
-
-    """
-
-    def __init__(
-        self, readout: str, qubit_mapping: Dict[int, int], number_measurements: int
-    ):
-        return
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def number_measurements(self):
-        """
-        Get value of struct field number_measurements
-        """
-
-    def qubit_mapping(self):
-        """
-        Get value of struct field qubit_mapping
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaSetNumberOfMeasurements(Operation):
-    """
-    Wrap function automatically generates functions in these traits.
-    This PRAGMA operation sets the number of measurements of the circuit.
-
-    This is used for backends that allow setting the number of tries. However, setting the number of
-    measurements does not allow access to the underlying wavefunction or density matrix.
-
-    Args:
-        number_measurements (uint): The number of measurements.
-        readout (string): The register for the readout.
-    """
-
-    def __init__(self, number_measurements: int, readout: str):
-        return
-
-    def number_measurements(self):
-        """
-        Get value of struct field number_measurements
-        """
-
-    def readout(self):
-        """
-        Get value of struct field readout
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaSetStateVector(Operation):
-    """
-    This PRAGMA operation sets the statevector of a quantum register.
-
-    The Circuit() module automatically initializes the qubits in the |0> state, so this PRAGMA
-    operation allows you to set the state of the qubits to a state of your choosing.
-    For instance, to initialize the psi-minus Bell state, we pass the following vector to
-    the PRAGMA:
-        vector = np.array([0, 1 / np.sqrt(2), -1 / np.sqrt(2), 0])
-
-    Args:
-        internal (PragmaSetStateVector): The statevector that is initialized.
-    """
-
-    def __init__(self, internal: Operation):
-        return
-
-    def statevector(self) -> numpy.ndarray:
-        """
-        Return the statevector.
-
-        Returns:
-            np.ndarray: The statevector representing the qubit register.
-        """
-
-    def involved_qubits(self) -> Set[int]:
-        """
-        List all involved qubits (here, all).
-
-        Returns:
-            Set[int]: The involved qubits of the PRAGMA operation.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Return tags classifying the type of the operation.
-
-        Used for the type based dispatch in ffi interfaces.
-
-        Returns:
-            List[str]: The tags of the operation.
-        """
-
-    def hqslang(self) -> str:
-        """
-        Return hqslang name of the operation.
-
-        Returns:
-            str: The hqslang name of the operation.
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Return true when the operation has symbolic parameters.
-
-        Returns:
-            bool: True if the operation contains symbolic parameters, False if it does not.
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> PragmaSetStateVector:
-        """
-        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
-
-        Returns:
-            self: The PRAGMA operation operation with the parameters substituted.
-
-        Raises:
-            RuntimeError: The parameter substitution failed.
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaSetStateVector:
-        """
-        Remap qubits in a clone of the PRAGMA operation.
-
-        Args:
-            mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
-
-        Returns:
-            self: The PRAGMA operation with the qubits remapped.
-
-        Raises:
-            RuntimeError: The qubit remapping failed.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-class PragmaSetDensityMatrix(Operation):
-    """
-    This PRAGMA operation sets the density matrix of a quantum register.
-
-    The Circuit() module automatically initializes the qubits in the |0> state, so this PRAGMA
-    operation allows you to set the state of the qubits by setting a density matrix of your choosing.
-
-    Args:
-        density_matrix (a 2d array of complex numbers): The density matrix that is initialized.
-
-    """
-
-    def __init__(self, density_matrix):
-        return
-
-    def density_matrix(self) -> numpy.ndarray:
-        """
-        Return the set density matrix.
-
-        Returns:
-            np.ndarray: The density matrix (2d array) representing the qubit register.
-        """
-
-    def involved_qubits(self) -> Set[int]:
-        """
-        List all involved qubits (here, all).
-
-        Returns:
-            Set[int]: The involved qubits of the PRAGMA operation.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Return tags classifying the type of the operation.
-
-        Used for type based dispatch in ffi interfaces.
-
-        Returns:
-            List[str]: The tags of the Operation.
-        """
-
-    def hqslang(self) -> str:
-        """
-        Return hqslang name of the operation.
-
-        Returns:
-            str: The hqslang name of the operation.
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Return true when the operation has symbolic parameters.
-
-        Returns:
-            bool: True if the operation contains symbolic parameters, False if it does not.
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> PragmaSetDensityMatrix:
-        """
-        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
-
-        Returns:
-            self: The PRAGMA operation with the parameters substituted.
-
-        Raises:
-            RuntimeError: The parameter substitution failed.
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaSetDensityMatrix:
-        """
-        Remap qubits in a clone of the PRAGMA operation.
-
-        Args:
-            mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
-
-        Returns:
-            self: The PRAGMA operation with the qubits remapped.
-
-        Raises:
-            RuntimeError: The qubit remapping failed.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-class PragmaRepeatGate(Operation):
-    """
-    The repeated gate PRAGMA operation.
-
-    This PRAGMA operation repeats the next gate in the circuit the given number of times
-    to increase the rate for error mitigation.
-
-    Args:
-        repetition_coefficient (int): The number of times the following gate is repeated.
-    """
-
-    def __init__(self, repetition_coefficient: int):
-        return
-
-    def repetition_coefficient(self):
-        """
-        Get value of struct field repetition_coefficient
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaOverrotation(Operation):
-    """
-    The statistical overrotation PRAGMA operation.
-
-    This PRAGMA applies a statistical overrotation to the next rotation gate in the circuit, which
-    matches the hqslang name in the `gate` parameter of PragmaOverrotation and the involved qubits in `qubits`.
-
-    The applied overrotation corresponds to adding a random number to the rotation angle.
-    The random number is drawn from a normal distribution with mean `0`
-    and standard deviation `variance` and is multiplied by the `amplitude`.
-
-    Args:
-        gate (str): The unique hqslang name of the gate to overrotate.
-        qubits (List[int]): The qubits of the gate to overrotate.
-        amplitude (float): The amplitude the random number is multiplied by.
-        variance (float): The standard deviation of the normal distribution the random number is drawn from.
-
-    """
-
-    def __init__(self, gate: str, qubits: List[int], amplitude: float, variance: float):
-        return
-
-    def gate_hqslang(self):
-        """
-        Get value of struct field gate_hqslang
-        """
-
-    def amplitude(self):
-        """
-        Get value of struct field amplitude
-        """
-
-    def variance(self):
-        """
-        Get value of struct field variance
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def qubits(self):
-        """
-        Return list of qubits of the multi qubit operation in order of descending significance
-
-        Returns:
-            List[int]
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaBoostNoise(Operation):
-    """
-    This PRAGMA operation boosts noise and overrotations in the circuit.
-
-    Args:
-        noise_coefficient (CalculatorFloat): The coefficient by which the noise is boosted.
-    """
-
-    def __init__(self, noise_coefficient: Union[float, str]):
-        return
-
-    def noise_coefficient(self):
-        """
-        Returns value of attribute noise_coefficient
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaStopParallelBlock(Operation):
-    """
-    This PRAGMA operation signals the STOP of a parallel execution block.
-
-    Args:
-        qubits (List[int]): The qubits involved in parallel execution block.
-        execution_time (CalculatorFloat): The time for the execution of the block in seconds.
-    """
-
-    def __init__(self, qubits: List[int], execution_time: Union[float, str]):
-        return
-
-    def execution_time(self):
-        """
-        Returns value of attribute execution_time
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def qubits(self):
-        """
-        Return list of qubits of the multi qubit operation in order of descending significance
-
-        Returns:
-            List[int]
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaGlobalPhase(Operation):
-    """
-    The global phase PRAGMA operation.
-
-    This PRAGMA operation signals that the quantum register picks up a global phase,
-    i.e. it provides information that there is a global phase to be considered.
-
-    Args:
-        phase (CalculatorFloat): The picked up global phase.
-    """
-
-    def __init__(self, phase: Union[float, str]):
-        return
-
-    def phase(self):
-        """
-        Returns value of attribute phase
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaSleep(Operation):
-    """
-    This PRAGMA operation makes the quantum hardware wait a given amount of time.
-
-    This PRAGMA operation is used for error mitigation reasons, for instance.
-    It can be used to boost the noise on the qubits since it gets worse with time.
-
-    Args:
-        qubits (List[int]): The qubits involved in the sleep block.
-        sleep_time (CalculatorFloat): The time for the execution of the block in seconds.
-    """
-
-    def __init__(self, qubits: List[int], sleep_time: Union[float, str]):
-        return
-
-    def sleep_time(self):
-        """
-        Returns value of attribute sleep_time
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def qubits(self):
-        """
-        Return list of qubits of the multi qubit operation in order of descending significance
-
-        Returns:
-            List[int]
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaActiveReset(Operation):
-    """
-    This PRAGMA operation resets the chosen qubit to the zero state.
-
-    Args:
-        qubit (int): The qubit to be reset.
-    """
-
-    def __init__(self, qubit: int):
-        return
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
-
-        Returns:
-            int
-        """
-
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
-
-        Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
-
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
-
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class PragmaStartDecompositionBlock(Operation):
-    """
-    This PRAGMA operation signals the START of a decomposition block.
-
-    Args:
-        qubits (List[int]): The qubits involved in the decomposition block.
-        reordering_dictionary (Dict[int, int]): The reordering dictionary of the block.
+        readout (string): The name of the classical readout register.
+        circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
     """
 
-    def __init__(self, qubits: List[int], reordering_dictionary: Dict[int, int]):
-        return
-
-    def reordering_dictionary(self):
-        """
-        Get value of struct field reordering_dictionary
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def __init__(self, readout: str, circuit: Optional[Circuit]) -> None:
         """
-        Remap qubits
+        Create a new PragmaGetDensityMatrix.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            readout (string): The name of the classical readout register.
+            circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaGetDensityMatrix: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def readout(self) -> str:
         """
-        List all involved Qubits
+        Return the value of the `readout` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The name of the classical readout register.
         """
 
-    def qubits(self):
+    def circuit(self) -> Optional[Circuit]:
         """
-        Return list of qubits of the multi qubit operation in order of descending significance
+        Return the value of the `circuit` field.
 
         Returns:
-            List[int]
+            Optional[Circuit]: The measurement preparation Circuit, applied on a copy of the register before measurement.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -5930,101 +3991,62 @@ class PragmaStartDecompositionBlock(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaStopDecompositionBlock(Operation):
-    """
-    This PRAGMA operation signals the STOP of a decomposition block.
+class PragmaGetOccupationProbability(Operation):
+    r"""
+    This PRAGMA measurement operation returns the vector of the occupation probabilities.
+
+    Occupation probabilities in the context of this PRAGMA operation are probabilities of finding the quantum
+    register in each :math:`\sigma_z` basis state. The quantum register remains unchanged by this PRAGMA measurement operation.
 
     Args:
-        qubits (List[int]): The qubits involved in the decomposition block.
+        readout (string): The name of the classical readout register.
+        circuit (Optional[Circuit]): The Circuit used to rotate the qureg.
     """
 
-    def __init__(self, qubits: List[int]):
-        return
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def __init__(self, readout: str, circuit: Optional[Circuit]) -> None:
         """
-        Remap qubits
+        Create a new PragmaGetOccupationProbability.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            readout (string): The name of the classical readout register.
+            circuit (Optional[Circuit]): The Circuit used to rotate the qureg.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaGetOccupationProbability: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def readout(self) -> str:
         """
-        List all involved Qubits
+        Return the value of the `readout` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The name of the classical readout register.
         """
 
-    def qubits(self):
+    def circuit(self) -> Optional[Circuit]:
         """
-        Return list of qubits of the multi qubit operation in order of descending significance
+        Return the value of the `circuit` field.
 
         Returns:
-            List[int]
+            Optional[Circuit]: The Circuit used to rotate the qureg.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6038,153 +4060,74 @@ class PragmaStopDecompositionBlock(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaDamping(Operation):
+class PragmaGetPauliProduct(Operation):
     """
-    The damping PRAGMA noise operation.
-
-    This PRAGMA operation applies a pure damping error corresponding to zero temperature environments.
-
-    Note
+    This PRAGMA measurement operation returns a Pauli product expectation value.
 
-    Damping means going from state `|1>` to `|0>` and corresponds to zero-temperature in a physical
-    device where `|0>` is the ground state.
-    With respect to the definition of the Pauli operator `Z`, `|0>` is the excited state and damping leads to
-    an increase in energy.
+    This PRAGMA operation returns a Pauli product expectation value after applying
+    a Rotate to another basis. It performs all of the operation on a clone of the quantum register,
+    sothat the actual quantum register remains unchanged.
 
     Args:
-        qubit (int): The qubit on which to apply the damping.
-        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
-        rate (CalculatorFloat): The error rate of the damping (in 1/second).
+        qubit_paulis (Dict[int, int]): The dictionary of the pauli matrix to apply to each qubit in the form
+                                       {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
+        readout (string): The name of the classical readout register.
+        circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
     """
 
-    def __init__(
-        self, qubit: int, gate_time: Union[float, str], rate: Union[float, str]
-    ):
-        return
-
-    def gate_time(self):
-        """
-        Returns value of attribute gate_time
-        """
-
-    def rate(self):
-        """
-        Returns value of attribute rate
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
-
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def __init__(self, qubit_paulis: Dict[int, int], readout: str, circuit: Circuit) -> None:
         """
-        Remap qubits
+        Create a new PragmaGetPauliProduct.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            qubit_paulis (Dict[int, int]): The dictionary of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
+            readout (string): The name of the classical readout register.
+            circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaGetPauliProduct: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def qubit(self):
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Return the qubit the operation acts on
 
-        Returns:
-            int
-        """
-
-    def superoperator(self) -> numpy.ndarray:
+    def qubit_paulis(self) -> Dict[int, int]:
         """
-        Return the superoperator defining the evolution of the density matrix under the noise gate
+        Return the value of the `qubit_paulis` field.
 
         Returns:
-            np.ndarray: superoperator of gate.
-
+            Dict[int, int]: The dictionary of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
         """
 
-    def powercf(self):
+    def readout(self) -> str:
         """
-        Return the power of the noise gate
-
-        Args:
-            `power` (CalculatorFloat): exponent in the power operation of the noise gate
+        Return the value of the `readout` field.
 
         Returns:
-            Self
-
+            str: The name of the classical readout register.
         """
 
-    def probability(self):
+    def circuit(self) -> Circuit:
         """
-        Returns the probability associated with the noise operation
+        Return the value of the `circuit` field.
 
         Returns:
-            CalculatorFloat
+            Circuit: The measurement preparation Circuit, applied on a copy of the register before measurement.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6198,146 +4141,164 @@ class PragmaDamping(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaDepolarising(Operation):
+class PragmaRepeatedMeasurement(Operation):
     """
-    The depolarising PRAGMA noise operation.
-
-    This PRAGMA operation applies a depolarising error corresponding to infinite temperature environments.
+    This PRAGMA measurement operation returns a measurement record for N repeated measurements.
 
     Args:
-        qubit (int): The qubit on which to apply the depolarising.
-        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
-        rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
+        readout (string): The name of the classical readout register.
+        qubit_mapping (Dict[int, int]): The mapping of qubits to indices in readout register.
+        number_measurements (int): The number of times to repeat the measurement.
     """
 
-    def __init__(
-        self, qubit: int, gate_time: Union[float, str], rate: Union[float, str]
-    ):
-        return
-
-    def gate_time(self):
+    def __init__(self, readout: str, number_measurements: int, qubit_mapping: Dict[int, int]) -> None:
         """
-        Returns value of attribute gate_time
+        Create a new PragmaRepeatedMeasurement.
+
+        Args:
+            readout (string): The name of the classical readout register.
+            number_measurements (int): The number of times to repeat the measurement.
+            qubit_mapping (Dict[int, int]): The mapping of qubits to indices in readout register.
+
+        Returns:
+            PragmaRepeatedMeasurement: The new operation.
         """
 
-    def rate(self):
+    def readout(self) -> str:
         """
-        Returns value of attribute rate
+        Return the value of the `readout` field.
+
+        Returns:
+            str: The name of the classical readout register.
         """
 
-    def is_parametrized(self) -> bool:
+    def number_measurements(self) -> int:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `number_measurements` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            int: The number of times to repeat the measurement.
         """
 
-    def tags(self) -> List[str]:
+    def qubit_mapping(self) -> Optional[Dict[int, int]]:
         """
-        Returns tags identifying the Operation
+        Return the value of the `qubit_mapping` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Optional[Dict[int, int]]: The mapping of qubits to indices in readout register.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    @staticmethod
+    def with_qubit_readout_pairs(readout: str, number_measurements: int, pairs: List[Tuple[int, int]]) -> PragmaRepeatedMeasurement:
         """
-        Remap qubits
+        Create a PragmaRepeatedMeasurement from pairs of qubits and readout indices.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            readout (string): The name of the classical readout register.
+            number_measurements (int): The number of times to repeat the measurement.
+            pairs (List[Tuple[int, int]]): The pairs (qubit, readout_index) of the qubit mapping.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaRepeatedMeasurement: The PragmaRepeatedMeasurement with the qubit mapping of the pairs.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: A qubit appears more than once or two qubits are mapped to the same readout index.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def effective_readout_index(self, qubit: int) -> int:
         """
-        List all involved Qubits
+        Return the index in the readout register the measurement of a qubit is written to.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+        Qubits without an entry in the qubit mapping, and all qubits when no mapping is set,
+        are written to the readout index equal to the qubit index.
 
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
+        Args:
+            qubit (int): The measured qubit.
 
         Returns:
-            int
+            int: The readout index of the qubit.
         """
 
-    def superoperator(self) -> numpy.ndarray:
-        """
-        Return the superoperator defining the evolution of the density matrix under the noise gate
+class PragmaSetNumberOfMeasurements(Operation):
+    """
+    Wrap function automatically generates functions in these traits.
+    This PRAGMA operation sets the number of measurements of the circuit.
 
-        Returns:
-            np.ndarray: superoperator of gate.
+    This is used for backends that allow setting the number of tries. However, setting the number of
+    measurements does not allow access to the underlying wavefunction or density matrix.
 
-        """
+    Args:
+        number_measurements (uint): The number of measurements.
+        readout (string): The register for the readout.
+    """
 
-    def powercf(self):
+    def __init__(self, number_measurements: int, readout: str) -> None:
         """
-        Return the power of the noise gate
+        Create a new PragmaSetNumberOfMeasurements.
 
         Args:
-            `power` (CalculatorFloat): exponent in the power operation of the noise gate
+            number_measurements (uint): The number of measurements.
+            readout (string): The register for the readout.
 
         Returns:
-            Self
+            PragmaSetNumberOfMeasurements: The new operation.
+        """
+
+    def number_measurements(self) -> int:
+        """
+        Return the value of the `number_measurements` field.
 
+        Returns:
+            int: The number of measurements.
         """
 
-    def probability(self):
+    def readout(self) -> str:
         """
-        Returns the probability associated with the noise operation
+        Return the value of the `readout` field.
 
         Returns:
-            CalculatorFloat
+            str: The register for the readout.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6351,146 +4312,185 @@ class PragmaDepolarising(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaDephasing(Operation):
+class PragmaSetStateVector(Operation):
     """
-    The dephasing PRAGMA noise operation.
+    This PRAGMA operation sets the statevector of a quantum register.
 
-    This PRAGMA operation applies a pure dephasing error.
+    The Circuit() module automatically initializes the qubits in the |0> state, so this PRAGMA
+    operation allows you to set the state of the qubits to a state of your choosing.
+    For instance, to initialize the psi-minus Bell state, we pass the following vector to
+    the PRAGMA:
+        vector = np.array([0, 1 / np.sqrt(2), -1 / np.sqrt(2), 0])
 
     Args:
-        qubit (int): The qubit on which to apply the dephasing.
-        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
-        rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+        internal (PragmaSetStateVector): The statevector that is initialized.
     """
 
-    def __init__(
-        self, qubit: int, gate_time: Union[float, str], rate: Union[float, str]
-    ):
-        return
+    def __init__(self, statevector: List[complex]) -> None:
+        """
+        Create a PragmaSetStateVector.
+
+        Args:
+            statevector (List[complex]): The statevector representing the qubit register.
 
-    def gate_time(self):
+        Returns:
+            self: The new PragmaSetStateVector.
         """
-        Returns value of attribute gate_time
+
+    def statevector(self) -> numpy.ndarray:
         """
+        Return the statevector.
 
-    def rate(self):
+        Returns:
+            np.ndarray: The statevector representing the qubit register.
         """
-        Returns value of attribute rate
+
+    def validate(self) -> None:
         """
+        Validate that the statevector describes a quantum state.
 
-    def is_parametrized(self) -> bool:
+        The statevector is not checked when the PRAGMA is created or deserialized.
+
+        Raises:
+            ValueError: The length of the statevector is not a power of two or the statevector is not normalized.
         """
-        Returns true if operation contains symbolic parameters
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The json schema serialized to json
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Returns the current version of the qoqo library .
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+class PragmaSetDensityMatrix(Operation):
+    """
+    This PRAGMA operation sets the density matrix of a quantum register.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    The Circuit() module automatically initializes the qubits in the |0> state, so this PRAGMA
+    operation allows you to set the state of the qubits by setting a density matrix of your choosing.
+
+    Args:
+        density_matrix (a 2d array of complex numbers): The density matrix that is initialized.
+    """
+
+    def __init__(self, density_matrix: Any) -> None:
+        """
+        Create a PragmaSetDensityMatrix.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            density_matrix (Array2[complex]): The density matrix representing the qubit register.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            self: The new PragmaSetDensityMatrix.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def density_matrix(self) -> numpy.ndarray:
         """
+        Return the set density matrix.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            np.ndarray: The density matrix (2d array) representing the qubit register.
         """
-        Remap qubits
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    def validate(self) -> None:
+        """
+        Validate that the density matrix describes a quantum state.
 
-        Returns:
-            Operation: The operation with the remapped qubits
+        The density matrix is not checked when the PRAGMA is created or deserialized.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: The density matrix is not square, its dimension is not a power of two,
+                        it is not Hermitian or its trace is not one.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def json_schema() -> str:
         """
-        List all involved Qubits
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The json schema serialized to json
         """
 
-    def qubit(self):
+    @staticmethod
+    def current_version() -> str:
         """
-        Return the qubit the operation acts on
+        Returns the current version of the qoqo library .
 
         Returns:
-            int
+            str: The current version of the library.
         """
 
-    def superoperator(self) -> numpy.ndarray:
+    def min_supported_version(self) -> str:
         """
-        Return the superoperator defining the evolution of the density matrix under the noise gate
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            np.ndarray: superoperator of gate.
-
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def powercf(self):
+class PragmaRepeatGate(Operation):
+    """
+    The repeated gate PRAGMA operation.
+
+    This PRAGMA operation repeats the next gate in the circuit the given number of times
+    to increase the rate for error mitigation.
+
+    Args:
+        repetition_coefficient (int): The number of times the following gate is repeated.
+    """
+
+    def __init__(self, repetition_coefficient: int) -> None:
         """
-        Return the power of the noise gate
+        Create a new PragmaRepeatGate.
 
         Args:
-            `power` (CalculatorFloat): exponent in the power operation of the noise gate
+            repetition_coefficient (int): The number of times the following gate is repeated.
 
         Returns:
-            Self
-
+            PragmaRepeatGate: The new operation.
         """
 
-    def probability(self):
+    def repetition_coefficient(self) -> int:
         """
-        Returns the probability associated with the noise operation
+        Return the value of the `repetition_coefficient` field.
 
         Returns:
-            CalculatorFloat
+            int: The number of times the following gate is repeated.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6504,156 +4504,205 @@ class PragmaDephasing(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaRandomNoise(Operation):
+class PragmaOverrotation(Operation):
     """
-    The random noise PRAGMA operation.
+    The statistical overrotation PRAGMA operation.
 
-    This PRAGMA operation applies a pure damping error corresponding to zero temperature environments.
+    This PRAGMA applies a statistical overrotation to the next rotation gate in the circuit, which
+    matches the hqslang name in the `gate` parameter of PragmaOverrotation and the involved qubits in `qubits`.
+
+    The applied overrotation corresponds to adding a random number to the rotation angle.
+    The random number is drawn from a normal distribution with mean `0`
+    and standard deviation `variance` and is multiplied by the `amplitude`.
 
     Args:
-        qubit (int): The qubit on which to apply the damping.
-        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
-        depolarising_rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
-        dephasing_rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+        gate (str): The unique hqslang name of the gate to overrotate.
+        qubits (List[int]): The qubits of the gate to overrotate.
+        amplitude (float): The amplitude the random number is multiplied by.
+        variance (float): The standard deviation of the normal distribution the random number is drawn from.
     """
 
-    def __init__(
-        self,
-        qubit: int,
-        gate_time: Union[float, str],
-        depolarising_rate: Union[float, str],
-        dephasing_rate: Union[float, str],
-    ):
-        return
+    def __init__(self, gate_hqslang: str, qubits: List[int], amplitude: float, variance: float) -> None:
+        """
+        Create a new PragmaOverrotation.
+
+        Args:
+            gate_hqslang (str): The value of the field `gate_hqslang`.
+            qubits (List[int]): The qubits of the gate to overrotate.
+            amplitude (float): The amplitude the random number is multiplied by.
+            variance (float): The standard deviation of the normal distribution the random number is drawn from.
+
+        Returns:
+            PragmaOverrotation: The new operation.
+        """
 
-    def gate_time(self):
+    def gate_hqslang(self) -> str:
         """
-        Returns value of attribute gate_time
+        Return the value of the `gate_hqslang` field.
+
+        Returns:
+            str: The value of the field `gate_hqslang`.
         """
 
-    def depolarising_rate(self):
+    def amplitude(self) -> float:
         """
-        Returns value of attribute depolarising_rate
+        Return the value of the `amplitude` field.
+
+        Returns:
+            float: The amplitude the random number is multiplied by.
         """
 
-    def dephasing_rate(self):
+    def variance(self) -> float:
         """
-        Returns value of attribute dephasing_rate
+        Return the value of the `variance` field.
+
+        Returns:
+            float: The standard deviation of the normal distribution the random number is drawn from.
         """
 
-    def is_parametrized(self) -> bool:
+    def qubits(self) -> List[int]:
         """
-        Returns true if operation contains symbolic parameters
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            List[int]: The qubits the operation acts on.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the JsonSchema for the json serialisation of the class.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The json schema serialized to json.
+        """
+
+class PragmaBoostNoise(Operation):
+    """
+    This PRAGMA operation boosts noise and overrotations in the circuit.
+
+    Args:
+        noise_coefficient (CalculatorFloat): The coefficient by which the noise is boosted.
+    """
+
+    def __init__(self, noise_coefficient: Union[CalculatorFloat, float, str]) -> None:
+        """
+        Create a new PragmaBoostNoise.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            noise_coefficient (CalculatorFloat): The coefficient by which the noise is boosted.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            PragmaBoostNoise: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def noise_coefficient(self) -> CalculatorFloat:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `noise_coefficient` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            CalculatorFloat: The coefficient by which the noise is boosted.
+        """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    @staticmethod
+    def current_version() -> str:
         """
+        Return the current version of the qoqo library.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+        Returns:
+            str: The current version of the library.
         """
-        List all involved Qubits
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def qubit(self):
+    @staticmethod
+    def json_schema() -> str:
         """
-        Return the qubit the operation acts on
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            int
+            str: The json schema serialized to json.
         """
 
-    def superoperator(self) -> numpy.ndarray:
+class PragmaStopParallelBlock(Operation):
+    """
+    This PRAGMA operation signals the STOP of a parallel execution block.
+
+    Args:
+        qubits (List[int]): The qubits involved in parallel execution block.
+        execution_time (CalculatorFloat): The time for the execution of the block in seconds.
+    """
+
+    def __init__(self, qubits: List[int], execution_time: Union[CalculatorFloat, float, str]) -> None:
         """
-        Return the superoperator defining the evolution of the density matrix under the noise gate
+        Create a new PragmaStopParallelBlock.
+
+        Args:
+            qubits (List[int]): The qubits involved in parallel execution block.
+            execution_time (CalculatorFloat): The time for the execution of the block in seconds.
 
         Returns:
-            np.ndarray: superoperator of gate.
+            PragmaStopParallelBlock: The new operation.
 
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def powercf(self):
+    def execution_time(self) -> CalculatorFloat:
         """
-        Return the power of the noise gate
-
-        Args:
-            `power` (CalculatorFloat): exponent in the power operation of the noise gate
+        Return the value of the `execution_time` field.
 
         Returns:
-            Self
-
+            CalculatorFloat: The time for the execution of the block in seconds.
         """
 
-    def probability(self):
+    def qubits(self) -> List[int]:
         """
-        Returns the probability associated with the noise operation
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            CalculatorFloat
+            List[int]: The qubits the operation acts on.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6667,137 +4716,121 @@ class PragmaRandomNoise(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaGeneralNoise(Operation):
+class PragmaGlobalPhase(Operation):
     """
-    The general noise PRAGMA operation.
+    The global phase PRAGMA operation.
 
-    This PRAGMA operation applies a noise term according to the given operators.
+    This PRAGMA operation signals that the quantum register picks up a global phase,
+    i.e. it provides information that there is a global phase to be considered.
 
     Args:
-        qubit (int): The qubit the PRAGMA operation is applied to.
-        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
-        Rates: The rates representing the general noise matrix M (a 3x3 matrix as 2d array).
-
+        phase (CalculatorFloat): The picked up global phase.
     """
 
-    def __init__(self, qubit: int, gate_time: Union[float, str], Rates):
-        return
-
-    def qubit(self) -> int:
-        """
-        Return the qubit on which the PRAGMA operation is applied.
-
-        Returns:
-            int: The qubit of the PRAGMA operation.
+    def __init__(self, phase: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new PragmaGlobalPhase.
 
-    def gate_time(self) -> Union[float, str]:
-        """
-        Return the `gate_time` of the PRAGMA operation.
+        Args:
+            phase (CalculatorFloat): The picked up global phase.
 
         Returns:
-            CalculatorFloat: The gate time of the PRAGMA operation.
-        """
-
-    def rates(self) -> numpy.ndarray:
-        """
-        Return the rates of the PRAGMA operation.
+            PragmaGlobalPhase: The new operation.
 
-        Returns:
-            np.ndarray: The rates of the PRAGMA operation.
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def superoperator(self) -> numpy.ndarray:
+    def phase(self) -> CalculatorFloat:
         """
-        Return the superoperator of the PRAGMA operation.
+        Return the value of the `phase` field.
 
         Returns:
-            np.ndarray: The matrix form of the superoperator of the PRAGMA operation.
+            CalculatorFloat: The picked up global phase.
         """
 
-    def involved_qubits(self) -> Set[int]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved qubits.
+        Return the current version of the qoqo library.
 
         Returns:
-            Set[int]: The involved qubits of the PRAGMA operation.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Return tags classifying the type of the operation.
-
-        Used for the type based dispatch in ffi interfaces.
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags of the Operation.
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Return hqslang name of the operation.
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The hqslang name of the operation.
+            str: The json schema serialized to json.
         """
 
-    def is_parametrized(self) -> bool:
-        """
-        Return true when the operation has symbolic parameters.
+class PragmaSleep(Operation):
+    """
+    This PRAGMA operation makes the quantum hardware wait a given amount of time.
 
-        Returns:
-            bool: True if the operation contains symbolic parameters, False if it does not.
-        """
+    This PRAGMA operation is used for error mitigation reasons, for instance.
+    It can be used to boost the noise on the qubits since it gets worse with time.
+
+    Args:
+        qubits (List[int]): The qubits involved in the sleep block.
+        sleep_time (CalculatorFloat): The time for the execution of the block in seconds.
+    """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> PragmaGeneralNoise:
+    def __init__(self, qubits: List[int], sleep_time: Union[CalculatorFloat, float, str]) -> None:
         """
-        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
+        Create a new PragmaSleep.
 
         Args:
-            substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+            qubits (List[int]): The qubits involved in the sleep block.
+            sleep_time (CalculatorFloat): The time for the execution of the block in seconds.
 
         Returns:
-            self: The PRAGMA operation with the parameters substituted.
+            PragmaSleep: The new operation.
 
         Raises:
-            RuntimeError: The parameter substitution failed.
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaGeneralNoise:
+    def sleep_time(self) -> CalculatorFloat:
         """
-        Remap qubits in a clone of the PRAGMA operation.
-
-        Args:
-            mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+        Return the value of the `sleep_time` field.
 
         Returns:
-            self: The PRAGMA operation with the qubits remapped.
-
-        Raises:
-            RuntimeError: The qubit remapping failed.
+            CalculatorFloat: The time for the execution of the block in seconds.
         """
 
-    def json_schema(self) -> str:
+    def qubits(self) -> List[int]:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            str: The json schema serialized to json
+            List[int]: The qubits the operation acts on.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6811,104 +4844,109 @@ class PragmaGeneralNoise(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-class PragmaConditional(Operation):
-    """
-    The conditional PRAGMA operation.
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
-    This PRAGMA executes a circuit when the condition bit/bool stored in a classical bit register is true.
+        Returns:
+            str: The json schema serialized to json.
+        """
 
-    Args:
-        condition_register (str): The name of the bit register containting the condition bool value.
-        condition_index (int): - The index in the bit register containting the condition bool value.
-        circuit (Circuit): - The circuit executed if the condition is met.
+class PragmaActiveReset(Operation):
     """
+    This PRAGMA operation resets the chosen qubit to the zero state.
 
-    def __init__(self, condition_register: str, condition_index: int, circuit: Circuit):
-        return
+    Args:
+        qubit (int): The qubit to be reset.
+    """
 
-    def condition_register(self):
-        """
-        Get value of struct field condition_register
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new PragmaActiveReset.
 
-    def condition_index(self):
-        """
-        Get value of struct field condition_index
+        Args:
+            qubit (int): The qubit to be reset.
+
+        Returns:
+            PragmaActiveReset: The new operation.
         """
 
-    def circuit(self):
+    def qubit(self) -> int:
         """
-        Get value of struct field circuit
+        Return the qubit the operation acts on.
+
+        Returns:
+            int: The qubit the operation acts on.
         """
 
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the current version of the qoqo library.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns hqslang name of Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The name
+            str: The json schema serialized to json.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+class PragmaStartDecompositionBlock(Operation):
+    """
+    This PRAGMA operation signals the START of a decomposition block.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    Args:
+        qubits (List[int]): The qubits involved in the decomposition block.
+        reordering_dictionary (Dict[int, int]): The reordering dictionary of the block.
+    """
+
+    def __init__(self, qubits: List[int], reordering_dictionary: Dict[int, int]) -> None:
+        """
+        Create a new PragmaStartDecompositionBlock.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            qubits (List[int]): The qubits involved in the decomposition block.
+            reordering_dictionary (Dict[int, int]): The reordering dictionary of the block.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            PragmaStartDecompositionBlock: The new operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def reordering_dictionary(self) -> Dict[int, int]:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `reordering_dictionary` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Dict[int, int]: The reordering dictionary of the block.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def qubits(self) -> List[int]:
         """
-        List all involved Qubits
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            List[int]: The qubits the operation acts on.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -6922,124 +4960,158 @@ class PragmaConditional(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaChangeDevice(Operation):
+class PragmaStopDecompositionBlock(Operation):
     """
-    A wrapper around backend specific PRAGMA operations capable of changing a device.
+    This PRAGMA operation signals the STOP of a decomposition block.
 
-    This PRAGMA is a thin wrapper around device specific operations that can change
-    device properties.
+    Args:
+        qubits (List[int]): The qubits involved in the decomposition block.
     """
 
-    def __init__(self):
-        return
+    def __init__(self, qubits: List[int]) -> None:
+        """
+        Create a new PragmaStopDecompositionBlock.
 
-    def wrapped_tags(self) -> List[str]:
+        Args:
+            qubits (List[int]): The qubits involved in the decomposition block.
+
+        Returns:
+            PragmaStopDecompositionBlock: The new operation.
         """
-        Return the tags of the wrapped operations.
+
+    def qubits(self) -> List[int]:
+        """
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            List[str]: The list of tags.
+            List[int]: The qubits the operation acts on.
         """
 
-    def wrapped_hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Return the hqslang name of the wrapped operations.
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name of the wrapped operation.
+            str: The current version of the library.
         """
 
-    def wrapped_operation(self) -> bytearray:
+    def min_supported_version(self) -> str:
         """
-        Return the binary representation of the wrapped operations.
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            ByteArray: The the binary representation of the wrapped operation.
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def involved_qubits(self) -> Set[int]:
+    @staticmethod
+    def json_schema() -> str:
         """
-        List all involved qubits.
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Set[int]: The involved qubits of the PRAGMA operation.
+            str: The json schema serialized to json.
         """
 
-    def tags(self) -> List[str]:
+class PragmaDamping(Operation):
+    """
+    The damping PRAGMA noise operation.
+
+    This PRAGMA operation applies a pure damping error corresponding to zero temperature environments.
+
+    Note
+
+    Damping means going from state `|1>` to `|0>` and corresponds to zero-temperature in a physical
+    device where `|0>` is the ground state.
+    With respect to the definition of the Pauli operator `Z`, `|0>` is the excited state and damping leads to
+    an increase in energy.
+
+    Args:
+        qubit (int): The qubit on which to apply the damping.
+        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+        rate (CalculatorFloat): The error rate of the damping (in 1/second).
+    """
+
+    def __init__(self, qubit: int, gate_time: Union[CalculatorFloat, float, str], rate: Union[CalculatorFloat, float, str]) -> None:
         """
-        Return tags classifying the type of the operation.
+        Create a new PragmaDamping.
 
-        Used for the type based dispatch in ffi interfaces.
+        Args:
+            qubit (int): The qubit on which to apply the damping.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            rate (CalculatorFloat): The error rate of the damping (in 1/second).
 
         Returns:
-            List[str]: The tags of the Operation.
+            PragmaDamping: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
+    def gate_time(self) -> CalculatorFloat:
         """
-        Return hqslang name of the operation.
+        Return the value of the `gate_time` field.
 
         Returns:
-            str: The hqslang name of the operation.
+            CalculatorFloat: The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
         """
 
-    def is_parametrized(self) -> bool:
+    def rate(self) -> CalculatorFloat:
         """
-        Return true when the operation has symbolic parameters.
+        Return the value of the `rate` field.
 
         Returns:
-            bool: True if the operation contains symbolic parameters, False if it does not.
+            CalculatorFloat: The error rate of the damping (in 1/second).
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> PragmaChangeDevice:
+    def superoperator(self) -> numpy.ndarray:
         """
-        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+        Return the superoperator defining the evolution of the density matrix under the noise gate.
 
         Returns:
-            self: The PRAGMA operation with the parameters substituted.
-
-        Raises:
-            RuntimeError: The parameter substitution failed.
+            np.ndarray: The superoperator of the gate.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaChangeDevice:
+    def powercf(self, power: CalculatorFloat) -> PragmaDamping:
         """
-        Remap qubits in a clone of the PRAGMA operation.
+        Return the noise gate raised to a power.
 
         Args:
-            mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+            power (CalculatorFloat): The exponent in the power operation of the noise gate.
 
         Returns:
-            self: The PRAGMA operation with the qubits remapped.
+            Self: The noise gate raised to the power of `power`.
+        """
 
-        Raises:
-            RuntimeError: The qubit remapping failed.
+    def probability(self) -> CalculatorFloat:
         """
+        Return the probability associated with the noise operation.
 
-    def json_schema(self) -> str:
+        Returns:
+            CalculatorFloat: The probability of the noise operation.
         """
-        Return the JsonSchema for the json serialisation of the class.
+
+    def qubit(self) -> int:
+        """
+        Return the qubit the operation acts on.
 
         Returns:
-            str: The json schema serialized to json
+            int: The qubit the operation acts on.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -7053,116 +5125,115 @@ class PragmaChangeDevice(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-class CNOT(Operation):
-    """
-The controlled NOT quantum operation.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & 1 \\\\
-        0 & 0 & 1 & 0
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of NOT on the target qubit.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit NOT is applied to.
-
-"""
-
-    def __init__(self, control: int, target: int):
-        return
-
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The json schema serialized to json.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def for_qubits(qubits: List[int], gate_time: CalculatorFloat, rate: CalculatorFloat) -> Circuit:
         """
-        Returns tags identifying the Operation
+        Create a circuit applying the damping PRAGMA to each of the given qubits.
+
+        Args:
+            qubits (List[int]): The qubits the PRAGMA operations are applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+            rate (CalculatorFloat): The error rate of the damping (in 1/second).
 
         Returns:
-            List[str]: The tags identifying the operation
-        """
+            Circuit: The circuit containing one PragmaDamping per qubit.
 
-    def hqslang(self) -> str:
+        Raises:
+            TypeError: An argument cannot be converted to CalculatorFloat.
         """
-        Returns hqslang name of Operation
 
-        Returns:
-            str: The name
-        """
+class PragmaDepolarising(Operation):
+    """
+    The depolarising PRAGMA noise operation.
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+    This PRAGMA operation applies a depolarising error corresponding to infinite temperature environments.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    Args:
+        qubit (int): The qubit on which to apply the depolarising.
+        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+        rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
+    """
+
+    def __init__(self, qubit: int, gate_time: Union[CalculatorFloat, float, str], rate: Union[CalculatorFloat, float, str]) -> None:
+        """
+        Create a new PragmaDepolarising.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            qubit (int): The qubit on which to apply the depolarising.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
 
         Returns:
-            Operation: The operation with the parameters substituted
+            PragmaDepolarising: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def gate_time(self) -> CalculatorFloat:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `gate_time` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            CalculatorFloat: The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def rate(self) -> CalculatorFloat:
         """
-        List all involved Qubits
+        Return the value of the `rate` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            CalculatorFloat: The error rate of the depolarisation (in 1/second).
         """
 
-    def control(self):
+    def superoperator(self) -> numpy.ndarray:
         """
-        Returns control qubit of the two-qubit operation
+        Return the superoperator defining the evolution of the density matrix under the noise gate.
+
+        Returns:
+            np.ndarray: The superoperator of the gate.
         """
 
-    def target(self):
+    def powercf(self, power: CalculatorFloat) -> PragmaDepolarising:
         """
-        Returns target qubit of the two-qubit operation
+        Return the noise gate raised to a power.
+
+        Args:
+            power (CalculatorFloat): The exponent in the power operation of the noise gate.
+
+        Returns:
+            Self: The noise gate raised to the power of `power`.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def probability(self) -> CalculatorFloat:
         """
-        Return unitary matrix of gate.
+        Return the probability associated with the noise operation.
 
         Returns:
-            np.ndarray: matrix of gate.
+            CalculatorFloat: The probability of the noise operation.
+        """
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+    def qubit(self) -> int:
         """
+        Return the qubit the operation acts on.
 
-    def current_version(self) -> str:
+        Returns:
+            int: The qubit the operation acts on.
         """
-        Returns the current version of the qoqo library .
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -7176,124 +5247,115 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class SWAP(Operation):
-    """
-The controlled SWAP quantum operation.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & 1
-        \end{pmatrix}
+    @staticmethod
+    def for_qubits(qubits: List[int], gate_time: CalculatorFloat, rate: CalculatorFloat) -> Circuit:
+        """
+        Create a circuit applying the depolarising PRAGMA to each of the given qubits.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
+        Args:
+            qubits (List[int]): The qubits the PRAGMA operations are applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+            rate (CalculatorFloat): The error rate of the depolarising (in 1/second).
 
-"""
+        Returns:
+            Circuit: The circuit containing one PragmaDepolarising per qubit.
 
-    def __init__(self, control: int, target: int):
-        return
+        Raises:
+            TypeError: An argument cannot be converted to CalculatorFloat.
+        """
 
-    def is_parametrized(self) -> bool:
+class PragmaDephasing(Operation):
+    """
+    The dephasing PRAGMA noise operation.
+
+    This PRAGMA operation applies a pure dephasing error.
+
+    Args:
+        qubit (int): The qubit on which to apply the dephasing.
+        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+        rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+    """
+
+    def __init__(self, qubit: int, gate_time: Union[CalculatorFloat, float, str], rate: Union[CalculatorFloat, float, str]) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new PragmaDephasing.
+
+        Args:
+            qubit (int): The qubit on which to apply the dephasing.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            PragmaDephasing: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def tags(self) -> List[str]:
+    def gate_time(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `gate_time` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
         """
 
-    def hqslang(self) -> str:
+    def rate(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `rate` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The error rate of the dephasing (in 1/second).
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def superoperator(self) -> numpy.ndarray:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the superoperator defining the evolution of the density matrix under the noise gate.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            np.ndarray: The superoperator of the gate.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> PragmaDephasing:
         """
-        Remap qubits
+        Return the noise gate raised to a power.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            power (CalculatorFloat): The exponent in the power operation of the noise gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The noise gate raised to the power of `power`.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def probability(self) -> CalculatorFloat:
         """
-        List all involved Qubits
+        Return the probability associated with the noise operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+            CalculatorFloat: The probability of the noise operation.
         """
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
-        """
-
-    def unitary_matrix(self) -> numpy.ndarray:
+    def qubit(self) -> int:
         """
-        Return unitary matrix of gate.
+        Return the qubit the operation acts on.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            int: The qubit the operation acts on.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -7307,124 +5369,125 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class FSwap(Operation):
-    """
-The controlled fermionic SWAP gate.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & -1
-        \end{pmatrix}
+    @staticmethod
+    def for_qubits(qubits: List[int], gate_time: CalculatorFloat, rate: CalculatorFloat) -> Circuit:
+        """
+        Create a circuit applying the dephasing PRAGMA to each of the given qubits.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
+        Args:
+            qubits (List[int]): The qubits the PRAGMA operations are applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+            rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
 
-"""
+        Returns:
+            Circuit: The circuit containing one PragmaDephasing per qubit.
 
-    def __init__(self, control: int, target: int):
-        return
+        Raises:
+            TypeError: An argument cannot be converted to CalculatorFloat.
+        """
 
-    def is_parametrized(self) -> bool:
+class PragmaRandomNoise(Operation):
+    """
+    The random noise PRAGMA operation.
+
+    This PRAGMA operation applies a pure damping error corresponding to zero temperature environments.
+
+    Args:
+        qubit (int): The qubit on which to apply the damping.
+        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+        depolarising_rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
+        dephasing_rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+    """
+
+    def __init__(self, qubit: int, gate_time: Union[CalculatorFloat, float, str], depolarising_rate: Union[CalculatorFloat, float, str], dephasing_rate: Union[CalculatorFloat, float, str]) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new PragmaRandomNoise.
+
+        Args:
+            qubit (int): The qubit on which to apply the damping.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            depolarising_rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
+            dephasing_rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            PragmaRandomNoise: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def tags(self) -> List[str]:
+    def gate_time(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `gate_time` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
         """
 
-    def hqslang(self) -> str:
+    def depolarising_rate(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `depolarising_rate` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The error rate of the depolarisation (in 1/second).
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def dephasing_rate(self) -> CalculatorFloat:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the value of the `dephasing_rate` field.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            CalculatorFloat: The error rate of the dephasing (in 1/second).
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def superoperator(self) -> numpy.ndarray:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the superoperator defining the evolution of the density matrix under the noise gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            np.ndarray: The superoperator of the gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def powercf(self, power: CalculatorFloat) -> PragmaRandomNoise:
         """
-        List all involved Qubits
+        Return the noise gate raised to a power.
+
+        Args:
+            power (CalculatorFloat): The exponent in the power operation of the noise gate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The noise gate raised to the power of `power`.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def probability(self) -> CalculatorFloat:
         """
+        Return the probability associated with the noise operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            CalculatorFloat: The probability of the noise operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def qubit(self) -> int:
         """
-        Return unitary matrix of gate.
+        Return the qubit the operation acts on.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            int: The qubit the operation acts on.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -7438,255 +5501,280 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class ISwap(Operation):
+    @staticmethod
+    def for_qubits(qubits: List[int], gate_time: CalculatorFloat, depolarising_rate: CalculatorFloat, dephasing_rate: CalculatorFloat) -> Circuit:
+        """
+        Create a circuit applying the random noise PRAGMA to each of the given qubits.
+
+        Args:
+            qubits (List[int]): The qubits the PRAGMA operations are applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+            depolarising_rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
+            dephasing_rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+
+        Returns:
+            Circuit: The circuit containing one PragmaRandomNoise per qubit.
+
+        Raises:
+            TypeError: An argument cannot be converted to CalculatorFloat.
+        """
+
+class PragmaGeneralNoise(Operation):
     """
-The controlled ISwap quantum operation.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 0 & i & 0 \\\\
-        0 & i & 0 & 0 \\\\
-        0 & 0 & 0 & 1
-        \end{pmatrix}
+    The general noise PRAGMA operation.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
+    This PRAGMA operation applies a noise term according to the given operators.
 
-"""
+    Args:
+        qubit (int): The qubit the PRAGMA operation is applied to.
+        gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+        Rates: The rates representing the general noise matrix M (a 3x3 matrix as 2d array).
+    """
 
-    def __init__(self, control: int, target: int):
-        return
+    def __init__(self, qubit: int, gate_time: CalculatorFloat, rates: Any) -> None:
+        r"""
+        Create a PragmaGeneralNoise.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        This PRAGMA operation applies a noise term according to the given operators.
+        The operators are represented by a 3x3 matrix:
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
+        .. math ::
+        M = \begin{pmatrix}
+        a & b & c \\
+        d & e & f \\
+        g & h & j \\
+        \end{pmatrix}
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        where the coefficients correspond to the following summands
+        expanded from the first term of the non-coherent part of the Lindblad equation:
 
-        Returns:
-            List[str]: The tags identifying the operation
-        """
+            .. math::
+            \frac{d}{dt}\rho = \sum_{i,j=0}^{2} M_{i,j} L_{i} \rho L_{j}^{\dagger} - \frac{1}{2} \{ L_{j}^{\dagger} L_i, \rho \} \\
+                L_0 = \sigma^{+} \\
+                L_1 = \sigma^{-} \\
+                L_3 = \sigma^{z}
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+        Applying the Pragma with a given `gate_time` corresponds to applying the full time-evolution under the Lindblad equation for `gate_time` time.
+
+        Args:
+            qubit (int): The qubit the PRAGMA operation is applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            rates (Array2[float]): The rate matrix M.
 
         Returns:
-            str: The name
+            self: The new PragmaGeneralNoise.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def from_t1_t2(qubit: int, gate_time: CalculatorFloat, t1: float, t2: float) -> PragmaGeneralNoise:
         """
-        Substitutes internal symbolic parameters with float values
+        Create a PragmaGeneralNoise from the T1 and T2 times of a qubit.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        The damping rate 1/T1 is set in the entry M[0, 0] (equivalent to PragmaDamping)
+        and the pure dephasing rate (1/T2 - 1/(2 T1)) / 2 in the entry M[2, 2] (equivalent to PragmaDephasing).
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            qubit (int): The qubit the PRAGMA operation is applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            t1 (float): The relaxation time T1.
+            t2 (float): The coherence time T2.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            PragmaGeneralNoise: The new PragmaGeneralNoise.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: Argument gate time cannot be converted to CalculatorFloat.
+            ValueError: T1 or T2 are not positive or T2 is larger than 2 T1.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    @staticmethod
+    def from_lindblad_operator(qubit: int, gate_time: CalculatorFloat, operator: PlusMinusLindbladNoiseOperator) -> PragmaGeneralNoise:
         """
-        Remap qubits
+        Create a PragmaGeneralNoise from the single qubit terms of a struqture Lindblad noise operator.
+
+        The entry M[i, j] of the rate matrix is the coefficient of the operator product (Li, Lj) acting on the qubit,
+        with L0 = σ+ (Plus), L1 = σ- (Minus) and L2 = σz (Z) of struqture. Terms not acting on the qubit are ignored.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            qubit (int): The qubit the PRAGMA operation is applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+            operator (struqture_py.spins.PlusMinusLindbladNoiseOperator): The Lindblad noise operator containing the rates.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaGeneralNoise: The new PragmaGeneralNoise.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: Argument gate time cannot be converted to CalculatorFloat.
+            ValueError: The operator cannot be converted or a term acting on the qubit also acts on other qubits or has a rate that is not real.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def to_lindblad_operator(self) -> PlusMinusLindbladNoiseOperator:
         """
-        List all involved Qubits
+        Return the rates of the PRAGMA as a struqture Lindblad noise operator.
+
+        The entry M[i, j] of the rate matrix becomes the coefficient of the operator product (Li, Lj)
+        with L0 = σ+ (Plus), L1 = σ- (Minus) and L2 = σz (Z). The gate time is not part of the operator.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            struqture_py.spins.PlusMinusLindbladNoiseOperator: The Lindblad noise operator with the rates of the PRAGMA.
         """
 
-    def control(self):
+    def effective_t1(self) -> float:
         """
-        Returns control qubit of the two-qubit operation
+        Return the effective T1 time defined by the diagonal rates.
+
+        Returns:
+            float: The effective T1 time, infinite if the populations do not decay.
         """
 
-    def target(self):
+    def effective_t2(self) -> float:
         """
-        Returns target qubit of the two-qubit operation
+        Return the effective T2 time defined by the diagonal rates.
+
+        Returns:
+            float: The effective T2 time, infinite if the coherences do not decay.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    @staticmethod
+    def for_qubits(qubits: List[int], gate_time: CalculatorFloat, rates: Any) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Create a circuit applying the general noise PRAGMA to each of the given qubits.
+
+        Args:
+            qubits (List[int]): The qubits the PRAGMA operations are applied to.
+            gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+            rates (Array2[float]): The rate matrix M.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The circuit containing one PragmaGeneralNoise per qubit.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Argument gate time cannot be converted to CalculatorFloat.
         """
 
-    def current_version(self) -> str:
+    def qubit(self) -> int:
         """
-        Returns the current version of the qoqo library .
+        Return the qubit on which the PRAGMA operation is applied.
 
         Returns:
-            str: The current version of the library.
+            int: The qubit of the PRAGMA operation.
         """
 
-    def min_supported_version(self) -> str:
+    def gate_time(self) -> CalculatorFloat:
         """
-        Return the minimum version of qoqo that supports this object.
+        Return the `gate_time` of the PRAGMA operation.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            CalculatorFloat: The gate time of the PRAGMA operation.
         """
 
-    def json_schema(self) -> str:
+    def rates(self) -> numpy.ndarray:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the rates of the PRAGMA operation.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class SqrtISwap(Operation):
-    """
-The controlled square root ISwap quantum operation.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & \frac{1}{\sqrt{2}} & \frac{i}{\sqrt{2}} & 0 \\\\
-        0 & \frac{i}{\sqrt{2}} & \frac{1}{\sqrt{2}} & 0 \\\\
-        0 & 0 & 0 & 1
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
-
-"""
-
-    def __init__(self, control: int, target: int):
-        return
+            np.ndarray: The rates of the PRAGMA operation.
+        """
 
-    def is_parametrized(self) -> bool:
+    def superoperator(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the superoperator of the PRAGMA operation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The matrix form of the superoperator of the PRAGMA operation.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns tags identifying the Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The json schema serialized to json
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Returns the current version of the qoqo library .
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+class PragmaConditional(Operation):
+    """
+    The conditional PRAGMA operation.
 
-        Returns:
-            Operation: The operation with the parameters substituted
+    This PRAGMA executes a circuit when the condition bit/bool stored in a classical bit register is true.
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
+    Args:
+        condition_register (str): The name of the bit register containting the condition bool value.
+        condition_index (int): - The index in the bit register containting the condition bool value.
+        circuit (Circuit): - The circuit executed if the condition is met.
+    """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def __init__(self, condition_register: str, condition_index: int, circuit: Circuit) -> None:
         """
-        Remap qubits
+        Create a new PragmaConditional.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            condition_register (str): The name of the bit register containting the condition bool value.
+            condition_index (int): - The index in the bit register containting the condition bool value.
+            circuit (Circuit): - The circuit executed if the condition is met.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaConditional: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def condition_register(self) -> str:
         """
-        List all involved Qubits
+        Return the value of the `condition_register` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The name of the bit register containting the condition bool value.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def condition_index(self) -> int:
         """
+        Return the value of the `condition_index` field.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: - The index in the bit register containting the condition bool value.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Return the value of the `circuit` field.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            Circuit: - The circuit executed if the condition is met.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -7700,124 +5788,155 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class InvSqrtISwap(Operation):
+class PragmaChangeDevice(Operation):
     """
-The controlled inverse square root ISwap quantum operation.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & \frac{1}{\sqrt{2}} & \frac{-i}{\sqrt{2}} & 0 \\\\
-        0 & \frac{-i}{\sqrt{2}} & \frac{1}{\sqrt{2}} & 0 \\\\
-        0 & 0 & 0 & 1
-        \end{pmatrix}
+    A wrapper around backend specific PRAGMA operations capable of changing a device.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
+    This PRAGMA is a thin wrapper around device specific operations that can change
+    device properties.
+    """
 
-"""
+    def __init__(self) -> None:
+        """
+        A PragmaChangeDevice cannot be created directly.
 
-    def __init__(self, control: int, target: int):
-        return
+        The intended mechanism for the creation of PragmaChangeDevice is to create a device specific Pragma
+        and call the .to_pragma_change_device() function.
+        """
 
-    def is_parametrized(self) -> bool:
+    def wrapped_tags(self) -> List[str]:
         """
-        Returns true if operation contains symbolic parameters
+        Return the tags of the wrapped operations.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            List[str]: The list of tags.
         """
 
-    def tags(self) -> List[str]:
+    def wrapped_hqslang(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the hqslang name of the wrapped operations.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The name of the wrapped operation.
         """
 
-    def hqslang(self) -> str:
+    def wrapped_operation(self) -> bytearray:
         """
-        Returns hqslang name of Operation
+        Return the binary representation of the wrapped operations.
 
         Returns:
-            str: The name
+            ByteArray: The the binary representation of the wrapped operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the JsonSchema for the json serialisation of the class.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The json schema serialized to json
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def current_version() -> str:
+        """
+        Returns the current version of the qoqo library .
 
         Returns:
-            Operation: The operation with the parameters substituted
+            str: The current version of the library.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
-        Remap qubits
+
+class CNOT(Operation):
+    r"""
+    The controlled NOT quantum operation.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 1 \\\\
+            0 & 0 & 1 & 0
+            \end{pmatrix}
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of NOT on the target qubit.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit NOT is applied to.
+    """
+
+    def __init__(self, control: int, target: int) -> None:
+        """
+        Create a new CNOT.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of NOT on the target qubit.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit NOT is applied to.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            CNOT: The new operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
+    def target(self) -> int:
         """
-        Returns control qubit of the two-qubit operation
+        Return the target qubit of the two-qubit operation.
+
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def target(self):
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Returns target qubit of the two-qubit operation
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -7831,291 +5950,281 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class XY(Operation):
-    """
-The controlled XY quantum operation
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\
-        0 & \cos(\theta/2) & i \sin(\theta/2) & 0 \\\
-        0 & i \sin(\theta/2) & \cos(\theta/2) & 0 \\\
-        0 & 0 & 0 & 1
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
-    theta (CalculatorFloat): The rotation angle :math:`\theta`.
+class SWAP(Operation):
+    r"""
+    The controlled SWAP quantum operation.
 
-"""
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 1
+            \end{pmatrix}
 
-    def __init__(self, control: int, target: int, theta: Union[float, str]):
-        return
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+    """
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+    def __init__(self, control: int, target: int) -> None:
         """
+        Create a new SWAP.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            SWAP: The new operation.
         """
 
-    def tags(self) -> List[str]:
+    def control(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The control qubit of the operation.
         """
 
-    def hqslang(self) -> str:
+    def target(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            str: The name
+            int: The target qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved Qubits
+        Return the current version of the qoqo library.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The current version of the library.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Return unitary matrix of gate.
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            str: The json schema serialized to json.
         """
 
-    def powercf(self) -> XY:
-        """
-        Returns Rotated gate raised to power
+class FSwap(Operation):
+    r"""
+    The controlled fermionic SWAP gate.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & -1
+            \end{pmatrix}
 
-        Returns:
-            Self: gate raised to the power of `power`
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+    """
 
+    def __init__(self, control: int, target: int) -> None:
         """
+        Create a new FSwap.
 
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
 
         Returns:
-            str: The current version of the library.
+            FSwap: The new operation.
         """
 
-    def min_supported_version(self) -> str:
+    def control(self) -> int:
         """
-        Return the minimum version of qoqo that supports this object.
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            int: The control qubit of the operation.
         """
 
-    def json_schema(self) -> str:
+    def target(self) -> int:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            str: The json schema serialized to json
+            int: The target qubit of the operation.
         """
 
-class ControlledPhaseShift(Operation):
-    """
-The controlled-PhaseShift quantum operation.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 \\\\
-        0 & 0 & 0 & e^{i \theta}
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
-    theta (CalculatorFloat): The rotation angle :math:`\theta`.
+    def to_cnot_circuit(self) -> Circuit:
+        """
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-"""
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
-    def __init__(self, control: int, target: int, theta: Union[float, str]):
-        return
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the JsonSchema for the json serialisation of the class.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The json schema serialized to json.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+class ISwap(Operation):
+    r"""
+    The controlled ISwap quantum operation.
 
-        Returns:
-            Operation: The operation with the parameters substituted
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 0 & i & 0 \\\\
+            0 & i & 0 & 0 \\\\
+            0 & 0 & 0 & 1
+            \end{pmatrix}
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
-        """
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+    """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def __init__(self, control: int, target: int) -> None:
         """
-        Remap qubits
+        Create a new ISwap.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            ISwap: The new operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> ControlledPhaseShift:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8129,124 +6238,89 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class ControlledPauliY(Operation):
-    """
-The controlled PauliY quantum operation
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & -i \\\\
-        0 & 0 & i & 0
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of PauliY gate on the target qubit.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliY is applied to.
-
-"""
+class SqrtISwap(Operation):
+    r"""
+    The controlled square root ISwap quantum operation.
 
-    def __init__(self, control: int, target: int):
-        return
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & \frac{1}{\sqrt{2}} & \frac{i}{\sqrt{2}} & 0 \\\\
+            0 & \frac{i}{\sqrt{2}} & \frac{1}{\sqrt{2}} & 0 \\\\
+            0 & 0 & 0 & 1
+            \end{pmatrix}
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+    """
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+    def __init__(self, control: int, target: int) -> None:
         """
+        Create a new SqrtISwap.
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            SqrtISwap: The new operation.
         """
 
-    def hqslang(self) -> str:
+    def control(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            str: The name
+            int: The control qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def target(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            int: The target qubit of the operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Remap qubits
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
-        """
-
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8260,124 +6334,89 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class ControlledPauliZ(Operation):
-    """
-The controlled PauliZ quantum operation
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 \\\\
-        0 & 0 & 0 & -1
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of PauliZ gate on the target qubit.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliZ is applied to.
-
-"""
+class InvSqrtISwap(Operation):
+    r"""
+    The controlled inverse square root ISwap quantum operation.
 
-    def __init__(self, control: int, target: int):
-        return
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & \frac{1}{\sqrt{2}} & \frac{-i}{\sqrt{2}} & 0 \\\\
+            0 & \frac{-i}{\sqrt{2}} & \frac{1}{\sqrt{2}} & 0 \\\\
+            0 & 0 & 0 & 1
+            \end{pmatrix}
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+    """
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+    def __init__(self, control: int, target: int) -> None:
         """
+        Create a new InvSqrtISwap.
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            InvSqrtISwap: The new operation.
         """
 
-    def hqslang(self) -> str:
+    def control(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            str: The name
+            int: The control qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def target(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            int: The target qubit of the operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Remap qubits
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
-        """
-
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8391,124 +6430,125 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class MolmerSorensenXX(Operation):
-    """
-The fixed phase MolmerSorensen XX gate. <http://arxiv.org/abs/1705.02771>
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 0 & 0 & -i \\\\
-        0 & 1 & -i & 0 \\\\
-        0 & -i & 1 & 0 \\\\
-        -i & 0 & 0 & 1
-        \end{pmatrix}
+class XY(Operation):
+    r"""
+    The controlled XY quantum operation
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
-    target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\
+            0 & \cos(\theta/2) & i \sin(\theta/2) & 0 \\\
+            0 & i \sin(\theta/2) & \cos(\theta/2) & 0 \\\
+            0 & 0 & 0 & 1
+            \end{pmatrix}
 
-"""
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+        theta (CalculatorFloat): The rotation angle :math:`\theta`.
+    """
 
-    def __init__(self, control: int, target: int):
-        return
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new XY.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
+            theta (CalculatorFloat): The rotation angle :math:`\theta`.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            XY: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The rotation angle :math:`\theta`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> XY:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> XY:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
+    def target(self) -> int:
         """
-        Returns control qubit of the two-qubit operation
+        Return the target qubit of the two-qubit operation.
+
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def target(self):
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Returns target qubit of the two-qubit operation
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8522,142 +6562,221 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class VariableMSXX(Operation):
+class ControlledPhaseShift(Operation):
+    r"""
+    The controlled-PhaseShift quantum operation.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 \\\\
+            0 & 0 & 0 & e^{i \theta}
+            \end{pmatrix}
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+        theta (CalculatorFloat): The rotation angle :math:`\theta`.
     """
-The variable-angle MolmerSorensen XX gate.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\theta/2) & 0 & 0 & -i \sin(\theta/2) \\\\
-        0 & \cos(\theta/2) & -i \sin(\theta/2) & 0 \\\\
-        0 & -i \sin(\theta/2) & \cos(\theta/2) & 0 \\\\
-        -i \sin(\theta/2) & 0 & 0 & \cos(\theta/2)
-        \end{pmatrix}
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
-    target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
-    theta (CalculatorFloat): The rotation angle :math:`\theta`.
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new ControlledPhaseShift.
 
-"""
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+            theta (CalculatorFloat): The rotation angle :math:`\theta`.
 
-    def __init__(self, control: int, target: int, theta: Union[float, str]):
-        return
+        Returns:
+            ControlledPhaseShift: The new operation.
 
-    def theta(self):
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns value of attribute theta
+
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
+
+        Returns:
+            CalculatorFloat: The rotation angle :math:`\theta`.
         """
 
-    def is_parametrized(self) -> bool:
+    def powercf(self, power: CalculatorFloat) -> ControlledPhaseShift:
         """
-        Returns true if operation contains symbolic parameters
+        Return the rotation gate raised to a power.
+
+        Args:
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Self: The gate raised to the power of `power`.
         """
 
-    def tags(self) -> List[str]:
+    def overrotate(self, amplitude: float, variance: float) -> ControlledPhaseShift:
         """
-        Returns tags identifying the Operation
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Self: The overrotated gate.
         """
 
-    def hqslang(self) -> str:
+    def control(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            str: The name
+            int: The control qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def target(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the target qubit of the two-qubit operation.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            int: The target qubit of the operation.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def to_cnot_circuit(self) -> Circuit:
+        """
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved Qubits
+        Return the current version of the qoqo library.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def control(self):
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
-        Returns control qubit of the two-qubit operation
+
+    @staticmethod
+    def json_schema() -> str:
         """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def target(self):
+        Returns:
+            str: The json schema serialized to json.
         """
-        Returns target qubit of the two-qubit operation
+
+class ControlledPauliY(Operation):
+    r"""
+    The controlled PauliY quantum operation
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & -i \\\\
+            0 & 0 & i & 0
+            \end{pmatrix}
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of PauliY gate on the target qubit.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliY is applied to.
+    """
+
+    def __init__(self, control: int, target: int) -> None:
         """
+        Create a new ControlledPauliY.
 
-    def unitary_matrix(self) -> numpy.ndarray:
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of PauliY gate on the target qubit.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliY is applied to.
+
+        Returns:
+            ControlledPauliY: The new operation.
+        """
+
+    def control(self) -> int:
         """
-        Return unitary matrix of gate.
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            np.ndarray: matrix of gate.
+            int: The control qubit of the operation.
+        """
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def powercf(self) -> VariableMSXX:
+        Returns:
+            int: The target qubit of the operation.
         """
-        Returns Rotated gate raised to power
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+    def to_cnot_circuit(self) -> Circuit:
+        """
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
+
+    def unitary_matrix(self) -> numpy.ndarray:
+        """
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8671,157 +6790,185 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class GivensRotation(Operation):
-    """
-The Givens rotation interaction gate in big endian notation: :math:`e^{-\mathrm{i} \theta (X_c Y_t - Y_c X_t)}`.
+class ControlledPauliZ(Operation):
+    r"""
+    The controlled PauliZ quantum operation
 
-Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
-and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 \\\\
+            0 & 0 & 0 & -1
+            \end{pmatrix}
 
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & \cos(\theta) \cdot e^{i \phi} & \sin(\theta) & 0 \\\\
-        0 & -\sin(\theta) \cdot e^{i \phi} & \cos(\theta) & 0 \\\\
-        0 & 0 & 0 & e^{i \phi}
-        \end{pmatrix}
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of PauliZ gate on the target qubit.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliZ is applied to.
+    """
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
-    theta (CalculatorFloat): The rotation angle :math:`\theta`.
-    phase (CalculatorFloat): The phase :math:`\phi` of the rotation.
+    def __init__(self, control: int, target: int) -> None:
+        """
+        Create a new ControlledPauliZ.
 
-"""
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of PauliZ gate on the target qubit.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliZ is applied to.
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        theta: Union[float, str],
-        phase: Union[float, str],
-    ):
-        return
+        Returns:
+            ControlledPauliZ: The new operation.
+        """
 
-    def theta(self):
+    def control(self) -> int:
         """
-        Returns value of attribute theta
+        Return the control qubit of the two-qubit operation.
+
+        Returns:
+            int: The control qubit of the operation.
         """
 
-    def phi(self):
+    def target(self) -> int:
         """
-        Returns value of attribute phi
+        Return the target qubit of the two-qubit operation.
+
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def is_parametrized(self) -> bool:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Returns true if operation contains symbolic parameters
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def tags(self) -> List[str]:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns tags identifying the Operation
+        Return the unitary matrix of the gate.
 
         Returns:
-            List[str]: The tags identifying the operation
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+class MolmerSorensenXX(Operation):
+    r"""
+    The fixed phase MolmerSorensen XX gate. <http://arxiv.org/abs/1705.02771>
+
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 0 & 0 & -i \\\\
+            0 & 1 & -i & 0 \\\\
+            0 & -i & 1 & 0 \\\\
+            -i & 0 & 0 & 1
+            \end{pmatrix}
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+        target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    """
+
+    def __init__(self, control: int, target: int) -> None:
         """
-        Remap qubits
+        Create a new MolmerSorensenXX.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+            target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            MolmerSorensenXX: The new operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> GivensRotation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8835,157 +6982,125 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class GivensRotationLittleEndian(Operation):
-    """
-The Givens rotation interaction gate in little endian notation: :math:`e^{-\mathrm{i} \theta (X_c Y_t - Y_c X_t)}`.
-
-Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
-and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & \cos(\theta) & \sin(\theta) & 0 \\\\
-        0 & -\sin(\theta) \cdot e^{i \phi} & \cos(\theta) \cdot e^{i \phi} & 0 \\\\
-        0 & 0 & 0 & e^{i \phi}
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int): The index of the least significant qubit in the unitary representation.
-    theta (CalculatorFloat): The rotation angle :math:`\theta`.
-    phase (CalculatorFloat): The phase :math:`\phi` of the rotation.
-
-"""
+class VariableMSXX(Operation):
+    r"""
+    The variable-angle MolmerSorensen XX gate.
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        theta: Union[float, str],
-        phase: Union[float, str],
-    ):
-        return
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\theta/2) & 0 & 0 & -i \sin(\theta/2) \\\\
+            0 & \cos(\theta/2) & -i \sin(\theta/2) & 0 \\\\
+            0 & -i \sin(\theta/2) & \cos(\theta/2) & 0 \\\\
+            -i \sin(\theta/2) & 0 & 0 & \cos(\theta/2)
+            \end{pmatrix}
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+        target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+        theta (CalculatorFloat): The rotation angle :math:`\theta`.
+    """
 
-    def phi(self):
-        """
-        Returns value of attribute phi
-        """
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new VariableMSXX.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+            target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+            theta (CalculatorFloat): The rotation angle :math:`\theta`.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            VariableMSXX: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The rotation angle :math:`\theta`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> VariableMSXX:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> VariableMSXX:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> GivensRotationLittleEndian:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -8999,149 +7114,138 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class Qsim(Operation):
-    """
-The qubit simulation (Qsim) gate.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(x-y) \cdot e^{-i z} & 0 & 0 & -i\sin(x-y)\cdot e^{-i z} \\\\
-        0 & -i \sin(x+y)\cdot e^{i z} & \cos(x+y)\cdot e^{i z} & 0 \\\\
-        0 & \cos(x+y)\cdot e^{i z}& -i \sin(x+y)\cdot e^{i z} & 0 \\\\
-        -\sin(x-y)\cdot e^{-i z} & 0 & 0 & \cos(x-y)\cdot e^{-i z}
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int):: The index of the least significant qubit in the unitary representation.
-    x (CalculatorFloat): The prefactor of the XX interaction.
-    y (CalculatorFloat): The prefactor of the YY interaction.
-    z (CalculatorFloat): The prefactor of the ZZ interaction.
-
-"""
+class GivensRotation(Operation):
+    r"""
+    The Givens rotation interaction gate in big endian notation: :math:`e^{-\mathrm{i} \theta (X_c Y_t - Y_c X_t)}`.
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        x: Union[float, str],
-        y: Union[float, str],
-        z: Union[float, str],
-    ):
-        return
+    Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
+    and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
 
-    def x(self):
-        """
-        Returns value of attribute x
-        """
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & \cos(\theta) \cdot e^{i \phi} & \sin(\theta) & 0 \\\\
+            0 & -\sin(\theta) \cdot e^{i \phi} & \cos(\theta) & 0 \\\\
+            0 & 0 & 0 & e^{i \phi}
+            \end{pmatrix}
 
-    def y(self):
-        """
-        Returns value of attribute y
-        """
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+        theta (CalculatorFloat): The rotation angle :math:`\theta`.
+        phase (CalculatorFloat): The phase :math:`\phi` of the rotation.
+    """
 
-    def z(self):
-        """
-        Returns value of attribute z
-        """
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new GivensRotation.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
+            theta (CalculatorFloat): The rotation angle :math:`\theta`.
+            phi (CalculatorFloat): The value of the field `phi`.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
+            GivensRotation: The new operation.
 
-    def tags(self) -> List[str]:
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns tags identifying the Operation
+
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The rotation angle :math:`\theta`.
         """
 
-    def hqslang(self) -> str:
+    def phi(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `phi` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The value of the field `phi`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> GivensRotation:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> GivensRotation:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
+    def target(self) -> int:
         """
-        Returns control qubit of the two-qubit operation
+        Return the target qubit of the two-qubit operation.
+
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def target(self):
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Returns target qubit of the two-qubit operation
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -9155,153 +7259,138 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class Fsim(Operation):
-    """
-The fermionic qubit simulation (Fsim) gate.
-
-.. math::
-    U = \begin{pmatrix}
-        \cos(\Delta) & 0 & 0 & i \sin(\Delta) \\\\
-        0 & -i \sin(t) & \cos(t) & 0 \\\\
-        0 & \cos(t) & -i \sin(t) & 0 \\\\
-        -\sin(\Delta) \cdot e^{-i U} & 0 & 0 & -\cos(\Delta) \cdot e^{-i U}
-        \end{pmatrix}
+class GivensRotationLittleEndian(Operation):
+    r"""
+    The Givens rotation interaction gate in little endian notation: :math:`e^{-\mathrm{i} \theta (X_c Y_t - Y_c X_t)}`.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int):: The index of the least significant qubit in the unitary representation.
-    t (CalculatorFloat): The hopping strength.
-    u (CalculatorFloat): The interaction strength.
-    delta (CalculatorFloat): The Bogoliubov interaction strength :math:`\Delta`.
+    Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
+    and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
 
-Note:
-The qubits have to be adjacent, i.e., :math:`|i-j|=1` has to hold. This is the only case
-in which the gate is valid as a two-qubit gate (due to the Jordan-Wigner transformation).
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & \cos(\theta) & \sin(\theta) & 0 \\\\
+            0 & -\sin(\theta) \cdot e^{i \phi} & \cos(\theta) \cdot e^{i \phi} & 0 \\\\
+            0 & 0 & 0 & e^{i \phi}
+            \end{pmatrix}
 
-"""
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int): The index of the least significant qubit in the unitary representation.
+        theta (CalculatorFloat): The rotation angle :math:`\theta`.
+        phase (CalculatorFloat): The phase :math:`\phi` of the rotation.
+    """
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        t: Union[float, str],
-        u: Union[float, str],
-        delta: Union[float, str],
-    ):
-        return
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new GivensRotationLittleEndian.
 
-    def t(self):
-        """
-        Returns value of attribute t
-        """
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): The index of the least significant qubit in the unitary representation.
+            theta (CalculatorFloat): The rotation angle :math:`\theta`.
+            phi (CalculatorFloat): The value of the field `phi`.
 
-    def u(self):
-        """
-        Returns value of attribute u
-        """
+        Returns:
+            GivensRotationLittleEndian: The new operation.
 
-    def delta(self):
-        """
-        Returns value of attribute delta
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The rotation angle :math:`\theta`.
         """
 
-    def tags(self) -> List[str]:
+    def phi(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `phi` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The value of the field `phi`.
         """
 
-    def hqslang(self) -> str:
+    def powercf(self, power: CalculatorFloat) -> GivensRotationLittleEndian:
         """
-        Returns hqslang name of Operation
+        Return the rotation gate raised to a power.
+
+        Args:
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            str: The name
+            Self: The gate raised to the power of `power`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> GivensRotationLittleEndian:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The overrotated gate.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def control(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The control qubit of the operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def target(self) -> int:
         """
-        List all involved Qubits
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The target qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def to_cnot_circuit(self) -> Circuit:
         """
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -9315,22 +7404,26 @@ in which the gate is valid as a two-qubit gate (due to the Jordan-Wigner transfo
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class SpinInteraction(Operation):
-    """
-    The generalized, anisotropic XYZ Heisenberg interaction between spins.
-
-    :math:`e^{-\mathrm{i} (x \cdot X_c X_t + y \cdot Y_c Y_t + z \cdot Z_c Z_t)}`
+class Qsim(Operation):
+    r"""
+    The qubit simulation (Qsim) gate.
 
-    Where x, y, z are prefactors of the :math:`X_c X_t`, :math:`Y_c Y_t`, :math:`Z_c Z_t` Pauliproducts acting on control and target qubit,
-    with :math:`XX \equiv \sigma_x \sigma_x`, :math:`YY \equiv \sigma_y \sigma_y` and :math:`ZZ \equiv \sigma_z \sigma_z`.
+    .. math::
+        U = \begin{pmatrix}
+            \cos(x-y) \cdot e^{-i z} & 0 & 0 & -i\sin(x-y)\cdot e^{-i z} \\\\
+            0 & -i \sin(x+y)\cdot e^{i z} & \cos(x+y)\cdot e^{i z} & 0 \\\\
+            0 & \cos(x+y)\cdot e^{i z}& -i \sin(x+y)\cdot e^{i z} & 0 \\\\
+            -\sin(x-y)\cdot e^{-i z} & 0 & 0 & \cos(x-y)\cdot e^{-i z}
+            \end{pmatrix}
 
     Args:
         control (int): The index of the most significant qubit in the unitary representation.
@@ -9338,123 +7431,95 @@ class SpinInteraction(Operation):
         x (CalculatorFloat): The prefactor of the XX interaction.
         y (CalculatorFloat): The prefactor of the YY interaction.
         z (CalculatorFloat): The prefactor of the ZZ interaction.
-
     """
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        x: Union[float, str],
-        y: Union[float, str],
-        z: Union[float, str],
-    ):
-        return
-
-    def x(self):
-        """
-        Returns value of attribute x
+    def __init__(self, control: int, target: int, x: Union[CalculatorFloat, float, str], y: Union[CalculatorFloat, float, str], z: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new Qsim.
 
-    def y(self):
-        """
-        Returns value of attribute y
-        """
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): : The index of the least significant qubit in the unitary representation.
+            x (CalculatorFloat): The prefactor of the XX interaction.
+            y (CalculatorFloat): The prefactor of the YY interaction.
+            z (CalculatorFloat): The prefactor of the ZZ interaction.
 
-    def z(self):
-        """
-        Returns value of attribute z
+        Returns:
+            Qsim: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def x(self) -> CalculatorFloat:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `x` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The prefactor of the XX interaction.
         """
 
-    def tags(self) -> List[str]:
+    def y(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `y` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The prefactor of the YY interaction.
         """
 
-    def hqslang(self) -> str:
+    def z(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `z` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The prefactor of the ZZ interaction.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def control(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            int: The control qubit of the operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def target(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The target qubit of the operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        List all involved Qubits
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
-        """
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -9468,149 +7533,126 @@ class SpinInteraction(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class Bogoliubov(Operation):
-    """
-The Bogoliubov DeGennes interaction gate.
-
-:math:`e^{-\mathrm{i} Re(\Delta) (X_c X_t - Y_c Y_t)/2 + Im(\Delta) (X_c Y_t+Y_c X_t)/2}`
-
-Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
-and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
+class Fsim(Operation):
+    r"""
+    The fermionic qubit simulation (Fsim) gate.
 
-The unitary matrix representation is:
+    .. math::
+        U = \begin{pmatrix}
+            \cos(\Delta) & 0 & 0 & i \sin(\Delta) \\\\
+            0 & -i \sin(t) & \cos(t) & 0 \\\\
+            0 & \cos(t) & -i \sin(t) & 0 \\\\
+            -\sin(\Delta) \cdot e^{-i U} & 0 & 0 & -\cos(\Delta) \cdot e^{-i U}
+            \end{pmatrix}
 
-.. math::
-    U = \begin{pmatrix}
-        \cos(|\Delta|) & 0 & 0 & \mathrm{i} \sin(|\Delta|) e^{\mathrm{i} \cdot \mathrm{angle}(\Delta)} \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 \\\\
-        \mathrm{i} \sin(|\Delta|) e^{-\mathrm{i} \cdot \mathrm{angle}(\Delta)} & 0 & 0 & \cos(|\Delta|)
-        \end{pmatrix}
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int):: The index of the least significant qubit in the unitary representation.
+        t (CalculatorFloat): The hopping strength.
+        u (CalculatorFloat): The interaction strength.
+        delta (CalculatorFloat): The Bogoliubov interaction strength :math:`\Delta`.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation.
-    target (int):: The index of the least significant qubit in the unitary representation.
-    delta_real (CalculatorFloat): The real part of the complex Bogoliubov interaction strength :math:`Re(\Delta)`.
-    delta_imag (CalculatorFloat): The imaginary part of the complex Bogoliubov interaction strength :math:`Im(\Delta)`.
+    Note:
+    The qubits have to be adjacent, i.e., :math:`|i-j|=1` has to hold. This is the only case
+    in which the gate is valid as a two-qubit gate (due to the Jordan-Wigner transformation).
+    """
 
-"""
+    def __init__(self, control: int, target: int, t: Union[CalculatorFloat, float, str], u: Union[CalculatorFloat, float, str], delta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new Fsim.
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        delta_real: Union[float, str],
-        delta_imag: Union[float, str],
-    ):
-        return
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): : The index of the least significant qubit in the unitary representation.
+            t (CalculatorFloat): The hopping strength.
+            u (CalculatorFloat): The interaction strength.
+            delta (CalculatorFloat): The Bogoliubov interaction strength :math:`\Delta`.
 
-    def delta_real(self):
-        """
-        Returns value of attribute delta_real
-        """
+        Returns:
+            Fsim: The new operation.
 
-    def delta_imag(self):
-        """
-        Returns value of attribute delta_imag
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def t(self) -> CalculatorFloat:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `t` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The hopping strength.
         """
 
-    def tags(self) -> List[str]:
+    def u(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `u` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The interaction strength.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def delta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `delta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The Bogoliubov interaction strength :math:`\Delta`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def control(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            int: The control qubit of the operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def target(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The target qubit of the operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        List all involved Qubits
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
-        """
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -9624,127 +7666,119 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PMInteraction(Operation):
-    """
-    The transversal interaction gate.
+class SpinInteraction(Operation):
+    r"""
+    The generalized, anisotropic XYZ Heisenberg interaction between spins.
 
-    :math:`e^{-\mathrm{i} \theta (X_c X_t + Y_c Y_t)} = e^{-\mathrm{i} \theta (\sigma^+_c \sigma^-_t + \sigma^-_c \sigma^+_t)}`
+    :math:`e^{-\mathrm{i} (x \cdot X_c X_t + y \cdot Y_c Y_t + z \cdot Z_c Z_t)}`
 
-    Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
-    and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
+    Where x, y, z are prefactors of the :math:`X_c X_t`, :math:`Y_c Y_t`, :math:`Z_c Z_t` Pauliproducts acting on control and target qubit,
+    with :math:`XX \equiv \sigma_x \sigma_x`, :math:`YY \equiv \sigma_y \sigma_y` and :math:`ZZ \equiv \sigma_z \sigma_z`.
 
     Args:
         control (int): The index of the most significant qubit in the unitary representation.
         target (int):: The index of the least significant qubit in the unitary representation.
-        t (CalculatorFloat): The strength of the rotation :math:`\theta`.
-
+        x (CalculatorFloat): The prefactor of the XX interaction.
+        y (CalculatorFloat): The prefactor of the YY interaction.
+        z (CalculatorFloat): The prefactor of the ZZ interaction.
     """
 
-    def __init__(self, control: int, target: int, t: Union[float, str]):
-        return
-
-    def t(self):
-        """
-        Returns value of attribute t
+    def __init__(self, control: int, target: int, x: Union[CalculatorFloat, float, str], y: Union[CalculatorFloat, float, str], z: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new SpinInteraction.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): : The index of the least significant qubit in the unitary representation.
+            x (CalculatorFloat): The prefactor of the XX interaction.
+            y (CalculatorFloat): The prefactor of the YY interaction.
+            z (CalculatorFloat): The prefactor of the ZZ interaction.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            SpinInteraction: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def tags(self) -> List[str]:
+    def x(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `x` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The prefactor of the XX interaction.
         """
 
-    def hqslang(self) -> str:
+    def y(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `y` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The prefactor of the YY interaction.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def z(self) -> CalculatorFloat:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the value of the `z` field.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            CalculatorFloat: The prefactor of the ZZ interaction.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def control(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The control qubit of the operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def target(self) -> int:
         """
-        List all involved Qubits
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The target qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def to_cnot_circuit(self) -> Circuit:
         """
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -9758,139 +7792,225 @@ class PMInteraction(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class ComplexPMInteraction(Operation):
-    """
-    The complex hopping gate.
+class Bogoliubov(Operation):
+    r"""
+    The Bogoliubov DeGennes interaction gate.
 
-    :math:`e^{-\mathrm{i} \left[ Re(\theta) \cdot (X_c X_t + Y_c Y_t) - Im(\theta) \cdot (X_c Y_t - Y_c X_t) \right] }`
+    :math:`e^{-\mathrm{i} Re(\Delta) (X_c X_t - Y_c Y_t)/2 + Im(\Delta) (X_c Y_t+Y_c X_t)/2}`
 
     Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
     and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
 
+    The unitary matrix representation is:
+
+    .. math::
+        U = \begin{pmatrix}
+            \cos(|\Delta|) & 0 & 0 & \mathrm{i} \sin(|\Delta|) e^{\mathrm{i} \cdot \mathrm{angle}(\Delta)} \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 \\\\
+            \mathrm{i} \sin(|\Delta|) e^{-\mathrm{i} \cdot \mathrm{angle}(\Delta)} & 0 & 0 & \cos(|\Delta|)
+            \end{pmatrix}
+
     Args:
         control (int): The index of the most significant qubit in the unitary representation.
         target (int):: The index of the least significant qubit in the unitary representation.
-        t_real (CalculatorFloat): The real part of the strength of the rotation :math:`Re(\theta)`.
-        t_imag (CalculatorFloat): The imaginary part of the strength of the rotation :math:`Im(\theta)`.
-
+        delta_real (CalculatorFloat): The real part of the complex Bogoliubov interaction strength :math:`Re(\Delta)`.
+        delta_imag (CalculatorFloat): The imaginary part of the complex Bogoliubov interaction strength :math:`Im(\Delta)`.
     """
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        t_real: Union[float, str],
-        t_imag: Union[float, str],
-    ):
-        return
+    def __init__(self, control: int, target: int, delta_real: Union[CalculatorFloat, float, str], delta_imag: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new Bogoliubov.
+
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): : The index of the least significant qubit in the unitary representation.
+            delta_real (CalculatorFloat): The real part of the complex Bogoliubov interaction strength :math:`Re(\Delta)`.
+            delta_imag (CalculatorFloat): The imaginary part of the complex Bogoliubov interaction strength :math:`Im(\Delta)`.
+
+        Returns:
+            Bogoliubov: The new operation.
 
-    def t_real(self):
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns value of attribute t_real
+
+    def delta_real(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `delta_real` field.
+
+        Returns:
+            CalculatorFloat: The real part of the complex Bogoliubov interaction strength :math:`Re(\Delta)`.
         """
 
-    def t_imag(self):
+    def delta_imag(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `delta_imag` field.
+
+        Returns:
+            CalculatorFloat: The imaginary part of the complex Bogoliubov interaction strength :math:`Im(\Delta)`.
         """
-        Returns value of attribute t_imag
+
+    def control(self) -> int:
         """
+        Return the control qubit of the two-qubit operation.
 
-    def is_parametrized(self) -> bool:
+        Returns:
+            int: The control qubit of the operation.
         """
-        Returns true if operation contains symbolic parameters
+
+    def target(self) -> int:
+        """
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            int: The target qubit of the operation.
         """
 
-    def tags(self) -> List[str]:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Returns tags identifying the Operation
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def hqslang(self) -> str:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns hqslang name of Operation
+        Return the unitary matrix of the gate.
 
         Returns:
-            str: The name
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def current_version() -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the current version of the qoqo library.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The current version of the library.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class PMInteraction(Operation):
+    r"""
+    The transversal interaction gate.
+
+    :math:`e^{-\mathrm{i} \theta (X_c X_t + Y_c Y_t)} = e^{-\mathrm{i} \theta (\sigma^+_c \sigma^-_t + \sigma^-_c \sigma^+_t)}`
+
+    Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
+    and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int):: The index of the least significant qubit in the unitary representation.
+        t (CalculatorFloat): The strength of the rotation :math:`\theta`.
+    """
+
+    def __init__(self, control: int, target: int, t: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PMInteraction.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): : The index of the least significant qubit in the unitary representation.
+            t (CalculatorFloat): The strength of the rotation :math:`\theta`.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PMInteraction: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def t(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `t` field.
+
+        Returns:
+            CalculatorFloat: The strength of the rotation :math:`\theta`.
         """
-        List all involved Qubits
+
+    def control(self) -> int:
+        """
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
+    def target(self) -> int:
         """
-        Returns control qubit of the two-qubit operation
+        Return the target qubit of the two-qubit operation.
+
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def target(self):
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Returns target qubit of the two-qubit operation
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
+
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -9904,334 +8024,394 @@ class ComplexPMInteraction(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PhaseShiftedControlledZ(Operation):
-    """
-The phased-shifted controlled-Z gate.
+class ComplexPMInteraction(Operation):
+    r"""
+    The complex hopping gate.
 
-Modified, i.e. phase-shifted ControlledPauliZ two-qubit gate. <https://arxiv.org/pdf/1908.06101.pdf eq.(1)>
+    :math:`e^{-\mathrm{i} \left[ Re(\theta) \cdot (X_c X_t + Y_c Y_t) - Im(\theta) \cdot (X_c Y_t - Y_c X_t) \right] }`
 
-The unitary matrix representation is:
+    Where :math:`X_c` is the Pauli matrix :math:`\sigma^x` acting on the control qubit
+    and :math:`Y_t` is the Pauli matrix :math:`\sigma^y` acting on the target qubit.
 
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & e^{i \phi} & 0 & 0 \\\\
-        0 & 0 & e^{i \phi} & 0 \\\\
-        0 & 0 & 0 & e^{i (2\cdot\phi + \pi)}
-        \end{pmatrix}
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation.
+        target (int):: The index of the least significant qubit in the unitary representation.
+        t_real (CalculatorFloat): The real part of the strength of the rotation :math:`Re(\theta)`.
+        t_imag (CalculatorFloat): The imaginary part of the strength of the rotation :math:`Im(\theta)`.
+    """
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
-    phi (CalculatorFloat): The single qubit phase $\phi$.
+    def __init__(self, control: int, target: int, t_real: Union[CalculatorFloat, float, str], t_imag: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new ComplexPMInteraction.
 
-"""
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation.
+            target (int): : The index of the least significant qubit in the unitary representation.
+            t_real (CalculatorFloat): The real part of the strength of the rotation :math:`Re(\theta)`.
+            t_imag (CalculatorFloat): The imaginary part of the strength of the rotation :math:`Im(\theta)`.
 
-    def __init__(self, control: int, target: int, phi: Union[float, str]):
-        return
+        Returns:
+            ComplexPMInteraction: The new operation.
 
-    def phi(self):
-        """
-        Returns value of attribute phi
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def t_real(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `t_real` field.
+
+        Returns:
+            CalculatorFloat: The real part of the strength of the rotation :math:`Re(\theta)`.
         """
-        Returns true if operation contains symbolic parameters
+
+    def t_imag(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `t_imag` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The imaginary part of the strength of the rotation :math:`Im(\theta)`.
         """
 
-    def tags(self) -> List[str]:
+    def control(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The control qubit of the operation.
         """
 
-    def hqslang(self) -> str:
+    def target(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            str: The name
+            int: The target qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved Qubits
+        Return the current version of the qoqo library.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The current version of the library.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def target(self):
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
-        Returns target qubit of the two-qubit operation
+
+    @staticmethod
+    def json_schema() -> str:
         """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def unitary_matrix(self) -> numpy.ndarray:
+        Returns:
+            str: The json schema serialized to json.
         """
-        Return unitary matrix of gate.
+
+class PhaseShiftedControlledZ(Operation):
+    r"""
+    The phased-shifted controlled-Z gate.
+
+    Modified, i.e. phase-shifted ControlledPauliZ two-qubit gate. <https://arxiv.org/pdf/1908.06101.pdf eq.(1)>
+
+    The unitary matrix representation is:
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & e^{i \phi} & 0 & 0 \\\\
+            0 & 0 & e^{i \phi} & 0 \\\\
+            0 & 0 & 0 & e^{i (2\cdot\phi + \pi)}
+            \end{pmatrix}
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+        phi (CalculatorFloat): The single qubit phase $\phi$.
+    """
+
+    def __init__(self, control: int, target: int, phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PhaseShiftedControlledZ.
+
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+            phi (CalculatorFloat): The single qubit phase $\phi$.
 
         Returns:
-            np.ndarray: matrix of gate.
+            PhaseShiftedControlledZ: The new operation.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
+    def phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `phi` field.
 
         Returns:
-            str: The current version of the library.
+            CalculatorFloat: The single qubit phase $\phi$.
         """
 
-    def min_supported_version(self) -> str:
+    def control(self) -> int:
         """
-        Return the minimum version of qoqo that supports this object.
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            int: The control qubit of the operation.
         """
 
-    def json_schema(self) -> str:
+    def target(self) -> int:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the target qubit of the two-qubit operation.
 
         Returns:
-            str: The json schema serialized to json
+            int: The target qubit of the operation.
         """
 
-class PhaseShiftState0(Operation):
-    """
-The phase shift gate applied on state |0>.
+    def to_cnot_circuit(self) -> Circuit:
+        """
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-Rotation around Z-axis by an arbitrary angle $\theta$ (AC Stark shift of the state |0>).
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
-.. math::
-    U = \begin{pmatrix}
-        e^{i \theta} & 0 \\\\
-        0 & 1
-        \end{pmatrix}
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-"""
+    def unitary_matrix(self) -> numpy.ndarray:
+        """
+        Return the unitary matrix of the gate.
 
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def is_parametrized(self) -> bool:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the current version of the qoqo library.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The current version of the library.
         """
 
-    def tags(self) -> List[str]:
+    def min_supported_version(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns hqslang name of Operation
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The name
+            str: The json schema serialized to json.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+class PhaseShiftState0(Operation):
+    r"""
+    The phase shift gate applied on state |0>.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    Rotation around Z-axis by an arbitrary angle $\theta$ (AC Stark shift of the state |0>).
+
+    .. math::
+        U = \begin{pmatrix}
+            e^{i \theta} & 0 \\\\
+            0 & 1
+            \end{pmatrix}
+
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
+
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PhaseShiftState0.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            PhaseShiftState0: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
+
+        Returns:
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
-        Remap qubits
+
+    def powercf(self, power: CalculatorFloat) -> PhaseShiftState0:
+        """
+        Return the rotation gate raised to a power.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def overrotate(self, amplitude: float, variance: float) -> PhaseShiftState0:
         """
-        List all involved Qubits
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -10239,10 +8419,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -10253,35 +8438,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def powercf(self) -> PhaseShiftState0:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns Rotated gate raised to power
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Self: gate raised to the power of `power`
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def current_version(self) -> str:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns the current version of the qoqo library .
+        Return the unitary matrix of the gate.
+
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -10295,193 +8507,165 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class PhaseShiftState1(Operation):
-    """
-The phase shift gate applied on state |1>.
-
-Rotation around Z-axis by an arbitrary angle $\theta$ (AC Stark shift of the state |1>).
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 \\\\
-        0 & e^{i \theta}
-        \end{pmatrix}
+    r"""
+    The phase shift gate applied on state |1>.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    Rotation around Z-axis by an arbitrary angle $\theta$ (AC Stark shift of the state |1>).
 
-"""
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 \\\\
+            0 & e^{i \theta}
+            \end{pmatrix}
 
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PhaseShiftState1.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            PhaseShiftState1: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> PhaseShiftState1:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> PhaseShiftState1:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -10489,10 +8673,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -10503,35 +8692,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def powercf(self) -> PhaseShiftState1:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns Rotated gate raised to power
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Self: gate raised to the power of `power`
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def current_version(self) -> str:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns the current version of the qoqo library .
+        Return the unitary matrix of the gate.
+
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -10545,12 +8761,13 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class MultiQubitMS(Operation):
@@ -10561,121 +8778,83 @@ class MultiQubitMS(Operation):
     In mathematical terms the gate applies exp(-i * theta/2 * X_i0 * X_i1 * ... * X_in).
     """
 
-    def __init__(self):
-        return
-
-    def theta(self):
-        """
-        Returns value of attribute theta
+    def __init__(self, qubits: List[int], theta: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new MultiQubitMS.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            qubits (List[int]): The value of the field `qubits`.
+            theta (CalculatorFloat): The value of the field `theta`.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            MultiQubitMS: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
+    def theta(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The value of the field `theta`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> MultiQubitMS:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> MultiQubitMS:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubits(self):
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return list of qubits of the multi qubit operation in order of descending significance
+        Return the unitary matrix of the gate.
 
         Returns:
-            List[int]
-        """
-
-    def circuit(self):
-        """
-        Return circuit implementing MultiQubitGateOperation
+            np.ndarray: The unitary matrix of the gate.
 
-        Returns:
-            Circuit
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def qubits(self) -> List[int]:
         """
-        Return unitary matrix of gate.
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            List[int]: The qubits the operation acts on.
         """
 
-    def powercf(self) -> MultiQubitMS:
+    def circuit(self) -> Circuit:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the circuit implementing the MultiQubitGateOperation.
 
         Returns:
-            Self: gate raised to the power of `power`
-
+            Circuit: The circuit implementing the gate.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -10689,12 +8868,13 @@ class MultiQubitMS(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class MultiQubitZZ(Operation):
@@ -10705,121 +8885,83 @@ class MultiQubitZZ(Operation):
     In mathematical terms the gate applies exp(-i * theta/2 * Z_i0 * Z_i1 * ... * Z_in).
     """
 
-    def __init__(self):
-        return
-
-    def theta(self):
-        """
-        Returns value of attribute theta
+    def __init__(self, qubits: List[int], theta: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new MultiQubitZZ.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            qubits (List[int]): The value of the field `qubits`.
+            theta (CalculatorFloat): The value of the field `theta`.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            MultiQubitZZ: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
+    def theta(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The value of the field `theta`.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> MultiQubitZZ:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> MultiQubitZZ:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubits(self):
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return list of qubits of the multi qubit operation in order of descending significance
+        Return the unitary matrix of the gate.
 
         Returns:
-            List[int]
-        """
-
-    def circuit(self):
-        """
-        Return circuit implementing MultiQubitGateOperation
+            np.ndarray: The unitary matrix of the gate.
 
-        Returns:
-            Circuit
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def qubits(self) -> List[int]:
         """
-        Return unitary matrix of gate.
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            List[int]: The qubits the operation acts on.
         """
 
-    def powercf(self) -> MultiQubitZZ:
+    def circuit(self) -> Circuit:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the circuit implementing the MultiQubitGateOperation.
 
         Returns:
-            Self: gate raised to the power of `power`
-
+            Circuit: The circuit implementing the gate.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -10833,12 +8975,13 @@ class MultiQubitZZ(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class InputBit(Operation):
@@ -10851,95 +8994,47 @@ class InputBit(Operation):
         value (int): The value the bit is set to.
     """
 
-    def __init__(self, name: str, index: int, value: int):
-        return
-
-    def index(self):
-        """
-        Get value of struct field index
-        """
-
-    def value(self):
-        """
-        Get value of struct field value
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def __init__(self, name: str, index: int, value: int) -> None:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Create a new InputBit.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            name (string): The name of the register that is defined.
+            index (int): The index in the register that is set.
+            value (int): The value the bit is set to.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            InputBit: The new operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def index(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `index` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The index in the register that is set.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def value(self) -> bool:
         """
-        List all involved Qubits
+        Return the value of the `value` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            bool: The value the bit is set to.
         """
 
-    def name(self):
+    def name(self) -> str:
         """
-        Return name of definition operation.
+        Return the name of the definition operation.
 
         Returns:
-            str
+            str: The name of the defined register or variable.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -10953,12 +9048,13 @@ class InputBit(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class PragmaLoop(Operation):
@@ -10968,90 +9064,43 @@ class PragmaLoop(Operation):
     Args:
         repetitions (CalculatorFloat): The number of repetitions as a symbolic float. At evaluation the floor of any float value is taken
         circuit (Circuit): The Circuit that is looped.
-
     """
 
-    def __init__(self, repetitions: Union[float, str], circuit: Circuit):
-        return
-
-    def repetitions(self):
-        """
-        Returns value of attribute repetitions
-        """
-
-    def circuit(self):
-        """
-        Get value of struct field circuit
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def __init__(self, repetitions: Union[CalculatorFloat, float, str], circuit: Circuit) -> None:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Create a new PragmaLoop.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            repetitions (CalculatorFloat): The number of repetitions as a symbolic float. At evaluation the floor of any float value is taken
+            circuit (Circuit): The Circuit that is looped.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            PragmaLoop: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def repetitions(self) -> CalculatorFloat:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `repetitions` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            CalculatorFloat: The number of repetitions as a symbolic float. At evaluation the floor of any float value is taken
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def circuit(self) -> Circuit:
         """
-        List all involved Qubits
+        Return the value of the `circuit` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Circuit: The Circuit that is looped.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -11065,156 +9114,137 @@ class PragmaLoop(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class PhaseShiftedControlledPhase(Operation):
-    """
-Implements the phase-shifted controlled PhaseShift gate.
-
-The unitary matrix representation is:
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & e^{i \phi} & 0 & 0 \\\\
-        0 & 0 & e^{i \phi} & 0 \\\\
-        0 & 0 & 0 & e^{i(2\cdot\phi + \theta)}
-        \end{pmatrix}
+    r"""
+    Implements the phase-shifted controlled PhaseShift gate.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
-    theta (CalculatorFloat): The phase rotation $\theta$.
-    phi (CalculatorFloat): The single qubit phase $\phi$.
+    The unitary matrix representation is:
 
-"""
-
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        theta: Union[float, str],
-        phi: Union[float, str],
-    ):
-        return
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & e^{i \phi} & 0 & 0 \\\\
+            0 & 0 & e^{i \phi} & 0 \\\\
+            0 & 0 & 0 & e^{i(2\cdot\phi + \theta)}
+            \end{pmatrix}
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+        theta (CalculatorFloat): The phase rotation $\theta$.
+        phi (CalculatorFloat): The single qubit phase $\phi$.
+    """
 
-    def phi(self):
-        """
-        Returns value of attribute phi
-        """
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PhaseShiftedControlledPhase.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the phase-shift on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+            theta (CalculatorFloat): The phase rotation $\theta$.
+            phi (CalculatorFloat): The single qubit phase $\phi$.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
+            PhaseShiftedControlledPhase: The new operation.
 
-    def tags(self) -> List[str]:
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns tags identifying the Operation
+
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The phase rotation $\theta$.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `phi` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The single qubit phase $\phi$.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> PhaseShiftedControlledPhase:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> PhaseShiftedControlledPhase:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> PhaseShiftedControlledPhase:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -11228,143 +9258,127 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class ControlledRotateX(Operation):
-    """
-Implements the controlled RotateX operation.
+    r"""
+    Implements the controlled RotateX operation.
 
-The unitary matrix representation is:
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & \cos(\frac{\theta}{2}) & -i \sin(\frac{\theta}{2}) \\\\
-        0 & 0 & -i \sin(\frac{\theta}{2}) & \cos(\frac{\theta}{2})
-        \end{pmatrix}
+    The unitary matrix representation is:
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
-    theta (CalculatorFloat): The angle $\theta$ of the rotation.
-"""
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & \cos(\frac{\theta}{2}) & -i \sin(\frac{\theta}{2}) \\\\
+            0 & 0 & -i \sin(\frac{\theta}{2}) & \cos(\frac{\theta}{2})
+            \end{pmatrix}
 
-    def __init__(self, control: int, target: int, theta: Union[float, str]):
-        return
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
+        theta (CalculatorFloat): The angle $\theta$ of the rotation.
+    """
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new ControlledRotateX.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
+            theta (CalculatorFloat): The angle $\theta$ of the rotation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            ControlledRotateX: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The angle $\theta$ of the rotation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> ControlledRotateX:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> ControlledRotateX:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> ControlledRotateX:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -11378,155 +9392,137 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class ControlledRotateXY(Operation):
-    """
-Implements the controlled RotateXY operation.
-
-The unitary matrix representation is:
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 \\\\
-        0 & 0 & \cos(\frac{\theta}{2}) & -i e^{-i \phi} \sin(\frac{\theta}{2}) \\\\
-        0 & 0 & -i e^{-i \phi} \sin(\frac{\theta}{2}) & \cos(\frac{\theta}{2})
-        \end{pmatrix}
+    r"""
+    Implements the controlled RotateXY operation.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
-    theta (CalculatorFloat): The angle $\theta$ of the rotation.
-    phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
-"""
+    The unitary matrix representation is:
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        theta: Union[float, str],
-        phi: Union[float, str],
-    ):
-        return
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 \\\\
+            0 & 0 & \cos(\frac{\theta}{2}) & -i e^{-i \phi} \sin(\frac{\theta}{2}) \\\\
+            0 & 0 & -i e^{-i \phi} \sin(\frac{\theta}{2}) & \cos(\frac{\theta}{2})
+            \end{pmatrix}
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
+        theta (CalculatorFloat): The angle $\theta$ of the rotation.
+        phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
+    """
 
-    def phi(self):
-        """
-        Returns value of attribute phi
-        """
+    def __init__(self, control: int, target: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new ControlledRotateXY.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
+            theta (CalculatorFloat): The angle $\theta$ of the rotation.
+            phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
+            ControlledRotateXY: The new operation.
 
-    def tags(self) -> List[str]:
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns tags identifying the Operation
+
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The angle $\theta$ of the rotation.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `phi` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> ControlledRotateXY:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> ControlledRotateXY:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def control(self) -> int:
         """
-        List all involved Qubits
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The control qubit of the operation.
         """
 
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+    def target(self) -> int:
         """
+        Return the target qubit of the two-qubit operation.
 
-    def target(self):
-        """
-        Returns target qubit of the two-qubit operation
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_cnot_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into CNOT gates and single-qubit gates.
+
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> ControlledRotateXY:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -11540,141 +9536,111 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class ControlledControlledPauliZ(Operation):
-    """
-Implements the double-controlled PauliZ gate.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 0 & -1
-        \end{pmatrix}
-
-Args:
-    control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
-    control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliZ is applied to.
-"""
+    r"""
+    Implements the double-controlled PauliZ gate.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & -1
+            \end{pmatrix}
 
-    def __init__(self, control_0: int, control_1: int, target: int):
-        return
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    Args:
+        control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
+        control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliZ is applied to.
+    """
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+    def __init__(self, control_0: int, control_1: int, target: int) -> None:
         """
+        Create a new ControlledControlledPauliZ.
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        Args:
+            control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
+            control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit PauliZ is applied to.
 
         Returns:
-            List[str]: The tags identifying the operation
+            ControlledControlledPauliZ: The new operation.
         """
 
-    def hqslang(self) -> str:
+    def control_0(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control_0 qubit of the three-qubit operation.
 
         Returns:
-            str: The name
+            int: The control_0 qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def control_1(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the control_1 qubit of the three-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            int: The control_1 qubit of the operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def target(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the target qubit of the three-qubit operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The target qubit of the operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def circuit(self) -> Circuit:
         """
-        List all involved Qubits
+        Return the circuit implementing the ThreeQubitGateOperation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def control_0(self):
-        """
-        Returns control_0 qubit of the three-qubit operation
-        """
-
-    def control_1(self):
-        """
-        Returns control_1 qubit of the three-qubit operation
+            Circuit: The circuit implementing the gate.
         """
 
-    def target(self):
-        """
-        Returns target qubit of the three-qubit operation
+    def circuit_with_basis(self, basis: str) -> Circuit:
         """
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
 
-    def circuit(self):
-        """
-        Returns circuit implementing the ThreeQubitGateOperation
+        Args:
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            Circuit
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
+
+        Raises:
+            ValueError: The basis is not supported.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -11688,159 +9654,147 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class ControlledControlledPhaseShift(Operation):
-    """
-Implements the double-controlled PhaseShift gate.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 0 & e^{i \theta}
-        \end{pmatrix}
-
-Args:
-    control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
-    control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the phase-shift is applied to.
-    theta (float): The rotation angle θ.
-"""
-
-    def __init__(self, control_0: int, control_1: int, target: int, theta: float):
-        return
-
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    r"""
+    Implements the double-controlled PhaseShift gate.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & e^{i \theta}
+            \end{pmatrix}
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    Args:
+        control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
+        control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the phase-shift is applied to.
+        theta (float): The rotation angle θ.
+    """
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+    def __init__(self, control_0: int, control_1: int, target: int, theta: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new ControlledControlledPhaseShift.
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        Args:
+            control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
+            control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the phase-shift is applied to.
+            theta (float): The rotation angle θ.
 
         Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+            ControlledControlledPhaseShift: The new operation.
 
-        Returns:
-            str: The name
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def theta(self) -> CalculatorFloat:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the value of the `theta` field.
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
-
-        Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+        Returns:
+            CalculatorFloat: The rotation angle θ.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> ControlledControlledPhaseShift:
         """
-        Remap qubits
+        Return the rotation gate raised to a power.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def overrotate(self, amplitude: float, variance: float) -> ControlledControlledPhaseShift:
         """
-        List all involved Qubits
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def control_0(self):
+    def control_0(self) -> int:
         """
-        Returns control_0 qubit of the three-qubit operation
+        Return the control_0 qubit of the three-qubit operation.
+
+        Returns:
+            int: The control_0 qubit of the operation.
         """
 
-    def control_1(self):
+    def control_1(self) -> int:
         """
-        Returns control_1 qubit of the three-qubit operation
+        Return the control_1 qubit of the three-qubit operation.
+
+        Returns:
+            int: The control_1 qubit of the operation.
         """
 
-    def target(self):
+    def target(self) -> int:
         """
-        Returns target qubit of the three-qubit operation
+        Return the target qubit of the three-qubit operation.
+
+        Returns:
+            int: The target qubit of the operation.
         """
 
-    def circuit(self):
+    def circuit(self) -> Circuit:
         """
-        Returns circuit implementing the ThreeQubitGateOperation
+        Return the circuit implementing the ThreeQubitGateOperation.
 
         Returns:
-            Circuit
+            Circuit: The circuit implementing the gate.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def circuit_with_basis(self, basis: str) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
+
+        Args:
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The basis is not supported.
         """
 
-    def powercf(self) -> ControlledControlledPhaseShift:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -11854,141 +9808,111 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class Toffoli(Operation):
-    """
-Implements Toffoli gate.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 0 & 1 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 1 & 0
-        \end{pmatrix}
-
-Args:
-    control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
-    control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
-    target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the PauliX gate is applied to.
-"""
+    r"""
+    Implements Toffoli gate.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & 1 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 1 & 0
+            \end{pmatrix}
 
-    def __init__(self, control_0: int, control_1: int, target: int):
-        return
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+    Args:
+        control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
+        control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
+        target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the PauliX gate is applied to.
+    """
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+    def __init__(self, control_0: int, control_1: int, target: int) -> None:
         """
+        Create a new Toffoli.
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        Args:
+            control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first controlling qubit of the operation.
+            control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second controlling qubit of the operation.
+            target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the PauliX gate is applied to.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Toffoli: The new operation.
         """
 
-    def hqslang(self) -> str:
+    def control_0(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control_0 qubit of the three-qubit operation.
 
         Returns:
-            str: The name
+            int: The control_0 qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def control_1(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the control_1 qubit of the three-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            int: The control_1 qubit of the operation.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def target(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the target qubit of the three-qubit operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: The target qubit of the operation.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def circuit(self) -> Circuit:
         """
-        List all involved Qubits
+        Return the circuit implementing the ThreeQubitGateOperation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def control_0(self):
-        """
-        Returns control_0 qubit of the three-qubit operation
-        """
-
-    def control_1(self):
-        """
-        Returns control_1 qubit of the three-qubit operation
+            Circuit: The circuit implementing the gate.
         """
 
-    def target(self):
-        """
-        Returns target qubit of the three-qubit operation
+    def circuit_with_basis(self, basis: str) -> Circuit:
         """
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
 
-    def circuit(self):
-        """
-        Returns circuit implementing the ThreeQubitGateOperation
+        Args:
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            Circuit
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
+
+        Raises:
+            ValueError: The basis is not supported.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -12002,191 +9926,163 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class GPi(Operation):
-    """
-Implements a pi-rotation with an embedded phase.
-
-.. math::
-    U = \begin{pmatrix}
-        0 & e^{-i \theta} \\\\
-        e^{i \theta} & 0
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    r"""
+    Implements a pi-rotation with an embedded phase.
 
-"""
+    .. math::
+        U = \begin{pmatrix}
+            0 & e^{-i \theta} \\\\
+            e^{i \theta} & 0
+            \end{pmatrix}
 
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new GPi.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            GPi: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> GPi:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> GPi:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
-
-        Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Self: The overrotated gate.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
-        """
-
-    def global_phase(self):
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -12194,10 +10090,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -12208,35 +10109,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def powercf(self) -> GPi:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Returns Rotated gate raised to power
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            Self: gate raised to the power of `power`
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def current_version(self) -> str:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns the current version of the qoqo library .
+        Return the unitary matrix of the gate.
+
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -12250,241 +10178,245 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class GPi2(Operation):
-    """
-Implements a pi/2-rotation with an embedded phase.
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & -i e^{-i \theta} \\\\
-        -i e^{i \theta} & 1
-        \end{pmatrix}
+    r"""
+    Implements a pi/2-rotation with an embedded phase.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-    theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & -i e^{-i \theta} \\\\
+            -i e^{i \theta} & 1
+            \end{pmatrix}
 
-"""
-
-    def __init__(self, qubit: int, theta: Union[float, str]):
-        return
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+        theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    """
 
-    def theta(self):
-        """
-        Returns value of attribute theta
-        """
+    def __init__(self, qubit: int, theta: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new GPi2.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
+            theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            GPi2: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The angle :math:`\theta` of the rotation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def powercf(self, power: CalculatorFloat) -> GPi2:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the rotation gate raised to a power.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Self: The gate raised to the power of `power`.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def overrotate(self, amplitude: float, variance: float) -> GPi2:
         """
-        Remap qubits
+        Return a clone of the gate with one parameter statistically overrotated.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Self: The overrotated gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def qubit(self) -> int:
         """
-        List all involved Qubits
+        Return the qubit the operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The qubit the operation acts on.
         """
 
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-        Returns:
-            int
-        """
+        Here global_phase is defined by
 
-    def global_phase(self):
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        Here beta_r is defined by
 
-    def beta_r(self):
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def mul(self, other: Operation) -> SingleQubitGate:
+        """
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Does not consume the two operations being multiplied.
+        Only Operations
 
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+
+        Example:
+        ```
+        from qoqo.operations import RotateZ, RotateX
+
+        gate1 =  RotateZ(qubit=0, theta=1)
+        gate2 = RotateX(qubit=0, theta=1)
+        multiplied = gate1.mul(gate2)
+        print("Multiplied gate: ", multiplied)
+        ```
+        """
 
-    def mul(self) -> Operation:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-        Does not consume the two operations being multiplied.
-        Only Operations
+        Alias for `mul`, allowing the use of the `*` operator.
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
-        Example:
-        ```
-        from qoqo.operations import RotateZ, RotateX
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+        """
 
-        gate1 =  RotateZ(qubit=0, theta=1)
-        gate2 = RotateX(qubit=0, theta=1)
-        multiplied = gate1.mul(gate2)
-        print("Multiplied gate: ", multiplied)
-        ```
+    def to_single_qubit_gate(self) -> SingleQubitGate:
+        """
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Return unitary matrix of gate.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def powercf(self) -> GPi2:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns Rotated gate raised to power
-
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+        Return the unitary matrix of the gate.
 
         Returns:
-            Self: gate raised to the power of `power`
+            np.ndarray: The unitary matrix of the gate.
 
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -12498,12 +10430,13 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class PragmaControlledCircuit(Operation):
@@ -12520,87 +10453,41 @@ class PragmaControlledCircuit(Operation):
         circuit (Circuit): - The circuit executed if the condition is met.
     """
 
-    def __init__(self, controlling_qubit: int, circuit: Circuit):
-        return
-
-    def controlling_qubit(self):
-        """
-        Get value of struct field controlling_qubit
-        """
-
-    def circuit(self):
-        """
-        Get value of struct field circuit
-        """
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
-
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
-        """
-
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
-        """
-
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def __init__(self, controlling_qubit: int, circuit: Circuit) -> None:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Create a new PragmaControlledCircuit.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            controlling_qubit (int): - The qubit controlling circuit application.
+            circuit (Circuit): - The circuit executed if the condition is met.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            PragmaControlledCircuit: The new operation.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def controlling_qubit(self) -> int:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the value of the `controlling_qubit` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            int: - The qubit controlling circuit application.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def circuit(self) -> Circuit:
         """
-        List all involved Qubits
+        Return the value of the `circuit` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Circuit: - The circuit executed if the condition is met.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -12614,12 +10501,13 @@ class PragmaControlledCircuit(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class Squeezing(Operation):
@@ -12636,119 +10524,89 @@ class Squeezing(Operation):
         phase (CalculatorFloat): The squeezing phase angle of the squeezing operation.
     """
 
-    def __init__(
-        self, mode: int, squeezing: Union[float, str], phase: Union[float, str]
-    ):
-        return
-
-    def squeezing(self):
-        """
-        Returns value of attribute squeezing
-        """
-
-    def phase(self):
-        """
-        Returns value of attribute phase
+    def __init__(self, mode: int, squeezing: Union[CalculatorFloat, float, str], phase: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new Squeezing.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            mode (int): The mode the squeezing gate is applied to.
+            squeezing (CalculatorFloat): The coefficient of the squeezing operation.
+            phase (CalculatorFloat): The squeezing phase angle of the squeezing operation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Squeezing: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def tags(self) -> List[str]:
+    def squeezing(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `squeezing` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The coefficient of the squeezing operation.
         """
 
-    def hqslang(self) -> str:
+    def phase(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `phase` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The squeezing phase angle of the squeezing operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the set of modes the operation acts on.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def remap_modes(self, mapping: Dict[int, int]) -> Squeezing:
         """
-        Remap qubits
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            Self: The operation with the remapped modes.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Remapping could not be performed.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def mode(self) -> int:
         """
-        List all involved Qubits
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def involved_modes(self) -> Union[Set[int], str]:
+            int: The bosonic mode of the operation.
         """
-        List of modes the operation acts on.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+    def fock_matrix(self, cutoff: int) -> numpy.ndarray:
         """
+        Return the matrix of the gate in the truncated Fock space.
 
-    def remap_modes(self, mapping: Dict[int, int]):
-        """
-        Remap the bosonic modes in copy of the operation.
+        The Fock space of each mode is truncated to the photon numbers 0 to cutoff - 1.
+        For two-mode gates the basis state |n_0, n_1> has the index n_0 * cutoff + n_1.
 
         Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+            cutoff (int): The number of Fock states per mode.
 
         Returns:
-            self
+            np.ndarray: The unitary matrix of the gate in the truncated Fock space.
 
         Raises:
-            PyValueError: Remapping could not be performed
-        """
-
-    def mode(self):
+            ValueError: The cutoff is zero or the operation is symbolic.
         """
-        Return `mode` the bosonic Operation acts on.
 
-        Returns:
-            int
-        """
-
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -12762,12 +10620,13 @@ class Squeezing(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class PhaseShift(Operation):
@@ -12781,112 +10640,80 @@ class PhaseShift(Operation):
         phase (CalculatorFloat): The phase by which to shift the mode.
     """
 
-    def __init__(self, mode: int, phase: Union[float, str]):
-        return
-
-    def phase(self):
-        """
-        Returns value of attribute phase
+    def __init__(self, mode: int, phase: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new PhaseShift.
 
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+        Args:
+            mode (int): The mode the phase-shift gate is applied to.
+            phase (CalculatorFloat): The phase by which to shift the mode.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
-
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+            PhaseShift: The new operation.
 
-        Returns:
-            List[str]: The tags identifying the operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
+    def phase(self) -> CalculatorFloat:
         """
-        Returns hqslang name of Operation
+        Return the value of the `phase` field.
 
         Returns:
-            str: The name
+            CalculatorFloat: The phase by which to shift the mode.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the set of modes the operation acts on.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def remap_modes(self, mapping: Dict[int, int]) -> PhaseShift:
         """
-        Remap qubits
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            Self: The operation with the remapped modes.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Remapping could not be performed.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def mode(self) -> int:
         """
-        List all involved Qubits
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The bosonic mode of the operation.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
-        """
-        List of modes the operation acts on.
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+    def fock_matrix(self, cutoff: int) -> numpy.ndarray:
         """
+        Return the matrix of the gate in the truncated Fock space.
 
-    def remap_modes(self, mapping: Dict[int, int]):
-        """
-        Remap the bosonic modes in copy of the operation.
+        The Fock space of each mode is truncated to the photon numbers 0 to cutoff - 1.
+        For two-mode gates the basis state |n_0, n_1> has the index n_0 * cutoff + n_1.
 
         Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+            cutoff (int): The number of Fock states per mode.
 
         Returns:
-            self
+            np.ndarray: The unitary matrix of the gate in the truncated Fock space.
 
         Raises:
-            PyValueError: Remapping could not be performed
-        """
-
-    def mode(self):
-        """
-        Return `mode` the bosonic Operation acts on.
-
-        Returns:
-            int
+            ValueError: The cutoff is zero or the operation is symbolic.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -12900,12 +10727,13 @@ class PhaseShift(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
 class BeamSplitter(Operation):
@@ -12919,127 +10747,196 @@ class BeamSplitter(Operation):
         phi (CalculatorFloat): The phase angle of the beam-splitter.
     """
 
-    def __init__(
-        self, mode_0: int, mode_1: int, theta: Union[float, str], phi: Union[float, str]
-    ):
-        return
+    def __init__(self, mode_0: int, mode_1: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        """
+        Create a new BeamSplitter.
+
+        Args:
+            mode_0 (int): The first mode the beam-splitter is applied to.
+            mode_1 (int): The second mode the beam-splitter is applied to.
+            theta (CalculatorFloat): The transmittivity angle of the beam-splitter.
+            phi (CalculatorFloat): The phase angle of the beam-splitter.
+
+        Returns:
+            BeamSplitter: The new operation.
 
-    def theta(self):
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns value of attribute theta
+
+    def theta(self) -> CalculatorFloat:
         """
+        Return the value of the `theta` field.
 
-    def phi(self):
+        Returns:
+            CalculatorFloat: The transmittivity angle of the beam-splitter.
         """
-        Returns value of attribute phi
+
+    def phi(self) -> CalculatorFloat:
         """
+        Return the value of the `phi` field.
 
-    def is_parametrized(self) -> bool:
+        Returns:
+            CalculatorFloat: The phase angle of the beam-splitter.
         """
-        Returns true if operation contains symbolic parameters
+
+    def involved_modes(self) -> Union[Set[int], str]:
+        """
+        Return the set of modes the operation acts on.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def tags(self) -> List[str]:
+    def remap_modes(self, mapping: Dict[int, int]) -> BeamSplitter:
         """
-        Returns tags identifying the Operation
+        Remap the bosonic modes in a copy of the operation.
+
+        Args:
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Self: The operation with the remapped modes.
+
+        Raises:
+            ValueError: Remapping could not be performed.
         """
 
-    def hqslang(self) -> str:
+    def mode_0(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the `mode_0` bosonic mode of the two bosonic mode Operation.
 
         Returns:
-            str: The name
+            int: The mode_0 bosonic mode of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def mode_1(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the `mode_1` bosonic mode of the two bosonic mode Operation.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            int: The mode_1 bosonic mode of the operation.
+        """
+
+    def fock_matrix(self, cutoff: int) -> numpy.ndarray:
+        """
+        Return the matrix of the gate in the truncated Fock space.
+
+        The Fock space of each mode is truncated to the photon numbers 0 to cutoff - 1.
+        For two-mode gates the basis state |n_0, n_1> has the index n_0 * cutoff + n_1.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            cutoff (int): The number of Fock states per mode.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            np.ndarray: The unitary matrix of the gate in the truncated Fock space.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: The cutoff is zero or the operation is symbolic.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    @staticmethod
+    def current_version() -> str:
         """
-        Remap qubits
+        Return the current version of the qoqo library.
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json.
+        """
+
+class PhotonDetection(Operation):
+    """
+    The photon number-resolving detector measurement for bosons.
+
+    This can be used as a single-shot measurement of the photon number.
+    https://arxiv.org/pdf/0902.4824.pdf
+
+    Args:
+        mode (int): The mode the detector (measurement) is applied to.
+        readout (str): The register for the readout.
+        readout_index (int): The index in the readout the result is saved to.
+    """
+
+    def __init__(self, mode: int, readout: str, readout_index: int) -> None:
+        """
+        Create a new PhotonDetection.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            mode (int): The mode the detector (measurement) is applied to.
+            readout (str): The register for the readout.
+            readout_index (int): The index in the readout the result is saved to.
+
+        Returns:
+            PhotonDetection: The new operation.
+        """
+
+    def readout(self) -> str:
+        """
+        Return the value of the `readout` field.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            str: The register for the readout.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def readout_index(self) -> int:
         """
-        List all involved Qubits
+        Return the value of the `readout_index` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            int: The index in the readout the result is saved to.
         """
 
     def involved_modes(self) -> Union[Set[int], str]:
         """
-        List of modes the operation acts on.
+        Return the set of modes the operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def remap_modes(self, mapping: Dict[int, int]) -> PhotonDetection:
         """
-        Remap the bosonic modes in copy of the operation.
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
             mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            self
+            Self: The operation with the remapped modes.
 
         Raises:
-            PyValueError: Remapping could not be performed
-        """
-
-    def mode_0(self):
-        """
-        Return `mode_0` bosonic mode of two bosonic mode Operation.
-
-        Returns:
-            int
+            ValueError: Remapping could not be performed.
         """
 
-    def mode_1(self):
+    def mode(self) -> int:
         """
-        Return `mode_1` bosonic mode of two bosonic mode Operation.
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            int
+            int: The bosonic mode of the operation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -13053,138 +10950,209 @@ class BeamSplitter(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PhotonDetection(Operation):
-    """
-    The photon number-resolving detector measurement for bosons.
+class Identity(Operation):
+    r"""
+    The Identity gate.
 
-    This can be used as a single-shot measurement of the photon number.
-    https://arxiv.org/pdf/0902.4824.pdf
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 \\\\
+            0 & 1
+            \end{pmatrix}
 
     Args:
-        mode (int): The mode the detector (measurement) is applied to.
-        readout (str): The register for the readout.
-        readout_index (int): The index in the readout the result is saved to.
+        qubit (int): The qubit the unitary gate is applied to.
     """
 
-    def __init__(self, mode: int, readout: str, readout_index: int):
-        return
-
-    def readout(self):
-        """
-        Get value of struct field readout
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new Identity.
 
-    def readout_index(self):
-        """
-        Get value of struct field readout_index
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
+
+        Returns:
+            Identity: The new operation.
         """
 
-    def is_parametrized(self) -> bool:
+    def qubit(self) -> int:
         """
-        Returns true if operation contains symbolic parameters
+        Return the qubit the operation acts on.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            int: The qubit the operation acts on.
         """
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
+
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
+
+        Here alpha_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-        Substitutes internal symbolic parameters with float values
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Here beta_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the parameters substituted
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
+
+        Here beta_i is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
-        Remap qubits
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Does not consume the two operations being multiplied.
+        Only Operations
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+
+        Example:
+        ```
+        from qoqo.operations import RotateZ, RotateX
+
+        gate1 =  RotateZ(qubit=0, theta=1)
+        gate2 = RotateX(qubit=0, theta=1)
+        multiplied = gate1.mul(gate2)
+        print("Multiplied gate: ", multiplied)
+        ```
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        List all involved Qubits
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        List of modes the operation acts on.
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Remap the bosonic modes in copy of the operation.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-        Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            self
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
         Raises:
-            PyValueError: Remapping could not be performed
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def mode(self):
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the unitary matrix of the gate.
 
         Returns:
-            int
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -13198,366 +11166,426 @@ class PhotonDetection(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class Identity(Operation):
+class PhaseDisplacement(Operation):
     """
-The Identity gate.
+    The single-mode phase-displacement gate with variable magnitude and phase.
 
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 \\\\
-        0 & 1
-        \end{pmatrix}
+    Args:
+        mode (int): The mode the phase-shift gate is applied to.
+        displacement (CalculatorFloat): The magnitude by which to displace the mode.
+        phase (CalculatorFloat): The angle by which to displace the mode.
+    """
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-"""
+    def __init__(self, mode: int, displacement: Union[CalculatorFloat, float, str], phase: Union[CalculatorFloat, float, str]) -> None:
+        """
+        Create a new PhaseDisplacement.
 
-    def __init__(self, qubit: int):
-        return
+        Args:
+            mode (int): The mode the phase-shift gate is applied to.
+            displacement (CalculatorFloat): The magnitude by which to displace the mode.
+            phase (CalculatorFloat): The angle by which to displace the mode.
 
-    def is_parametrized(self) -> bool:
+        Returns:
+            PhaseDisplacement: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-        Returns true if operation contains symbolic parameters
+
+    def displacement(self) -> CalculatorFloat:
+        """
+        Return the value of the `displacement` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The magnitude by which to displace the mode.
         """
 
-    def tags(self) -> List[str]:
+    def phase(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `phase` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The angle by which to displace the mode.
         """
 
-    def hqslang(self) -> str:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Returns hqslang name of Operation
+        Return the set of modes the operation acts on.
 
         Returns:
-            str: The name
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def remap_modes(self, mapping: Dict[int, int]) -> PhaseDisplacement:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            Self: The operation with the remapped modes.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: Remapping could not be performed.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def mode(self) -> int:
         """
-        Remap qubits
+        Return the `mode` the bosonic Operation acts on.
+
+        Returns:
+            int: The bosonic mode of the operation.
+        """
+
+    def fock_matrix(self, cutoff: int) -> numpy.ndarray:
+        """
+        Return the matrix of the gate in the truncated Fock space.
+
+        The Fock space of each mode is truncated to the photon numbers 0 to cutoff - 1.
+        For two-mode gates the basis state |n_0, n_1> has the index n_0 * cutoff + n_1.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            cutoff (int): The number of Fock states per mode.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            np.ndarray: The unitary matrix of the gate in the truncated Fock space.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: The cutoff is zero or the operation is symbolic.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved Qubits
+        Return the current version of the qoqo library.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The current version of the library.
         """
 
-    def qubit(self):
+    def min_supported_version(self) -> str:
         """
-        Return the qubit the operation acts on
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            int
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
         """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def global_phase(self):
+        Returns:
+            str: The json schema serialized to json.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+class EchoCrossResonance(Operation):
+    r"""
+    Implements the controlled RotateXY operation.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    The unitary matrix representation is:
 
-Returns:
-    CalculatorFloat
-"""
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            0 & 1 & 0 & i \\\\
+            1 & 0 & -i & 0 \\\\
+            0 & i & 0 & 1 \\\\
+            -i & 0 & 1 & 0
+            \end{pmatrix}
 
-    def alpha_r(self):
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
+        theta (CalculatorFloat): The angle $\theta$ of the rotation.
+        phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
+    """
+
+    def __init__(self, control: int, target: int) -> None:
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
+        Create a new EchoCrossResonance.
 
-Here alpha_r is defined by
+        Args:
+            control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            EchoCrossResonance: The new operation.
+        """
 
-Returns:
-    CalculatorFloat
-"""
+    def control(self) -> int:
+        """
+        Return the control qubit of the two-qubit operation.
 
-    def alpha_i(self):
+        Returns:
+            int: The control qubit of the operation.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def target(self) -> int:
+        """
+        Return the target qubit of the two-qubit operation.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            int: The target qubit of the operation.
+        """
 
-    def beta_r(self):
+    def to_cnot_circuit(self) -> Circuit:
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
+        Decompose the gate into CNOT gates and single-qubit gates.
 
-Here beta_r is defined by
+        The decomposition uses at most three CNOT gates. The global phase of the gate
+        is added as a PragmaGlobalPhase at the end of the circuit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            Circuit: The CNOT and single-qubit gates followed by a PragmaGlobalPhase.
 
-Returns:
-    CalculatorFloat
-"""
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def beta_i(self):
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
+        Return the unitary matrix of the gate.
 
-Here beta_i is defined by
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
+        """
 
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            str: The current version of the library.
+        """
 
-    def mul(self) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Multiplies two compatible operations implementing OperateSingleQubitGate.
+        Return the minimum version of qoqo that supports this object.
 
-        Does not consume the two operations being multiplied.
-        Only Operations
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            str: The json schema serialized to json.
+        """
 
-        Example:
-        ```
-        from qoqo.operations import RotateZ, RotateX
+class PragmaAnnotatedOp(Operation):
+    """
+    An annotated Operation.
 
-        gate1 =  RotateZ(qubit=0, theta=1)
-        gate2 = RotateX(qubit=0, theta=1)
-        multiplied = gate1.mul(gate2)
-        print("Multiplied gate: ", multiplied)
-        ```
+    Args:
+        operation (Operation): - The Operation to be annotated.
+        annotation (str): - The annotation.
+    """
 
+    def __init__(self, operation: Operation, annotation: str) -> None:
         """
+        Create a PragmaAnnotatedOp instance.
 
-    def unitary_matrix(self) -> numpy.ndarray:
+        Args:
+            operation (Operation): - The Operation to be annotated.
+            annotation (str): - The annotation.
+        """
+
+    def operation(self) -> Operation:
         """
-        Return unitary matrix of gate.
+        Return the internal Operation.
 
         Returns:
-            np.ndarray: matrix of gate.
+            Operation: The annotated Operation.
+        """
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+    def annotation(self) -> str:
+        """
+        Return the annotation.
+
+        Returns:
+            str: The annotation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The current version of the library.
+            str: The json schema serialized to json
         """
 
-    def min_supported_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Return the minimum version of qoqo that supports this object.
+        Returns the current version of the qoqo library .
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            str: The current version of the library.
         """
 
-    def json_schema(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The json schema serialized to json
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-class PhaseDisplacement(Operation):
+class ApplyConstantSpinHamiltonian(Operation):
     """
-    The single-mode phase-displacement gate with variable magnitude and phase.
+     Constant Hamiltonian operation on analog quantum device (PASQAL)
 
     Args:
-        mode (int): The mode the phase-shift gate is applied to.
-        displacement (CalculatorFloat): The magnitude by which to displace the mode.
-        phase (CalculatorFloat): The angle by which to displace the mode.
+        hamiltonian (SpinHamiltonian): The hamiltonian that is to be simulated.
+        time (CalculatorFloat): The duration for which the state evolution takes place.
     """
 
-    def __init__(
-        self, mode: int, displacement: Union[float, str], phase: Union[float, str]
-    ):
-        return
-
-    def displacement(self):
+    def __init__(self, hamiltonian: SpinHamiltonianSystem, time: Union[CalculatorFloat, float, str]) -> None:
         """
-        Returns value of attribute displacement
+        Create a new ApplyConstantSpinHamiltonian.
+
+        Args:
+            hamiltonian (SpinHamiltonian): The hamiltonian that is to be simulated.
+            time (CalculatorFloat): The duration for which the state evolution takes place.
+
+        Returns:
+            ApplyConstantSpinHamiltonian: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def phase(self):
+    def hamiltonian(self) -> SpinHamiltonianSystem:
         """
-        Returns value of attribute phase
+        Return the value of the `hamiltonian` field.
+
+        Returns:
+            SpinHamiltonianSystem: The hamiltonian that is to be simulated.
         """
 
-    def is_parametrized(self) -> bool:
+    def time(self) -> CalculatorFloat:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `time` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The duration for which the state evolution takes place.
         """
 
-    def tags(self) -> List[str]:
+    def spin(self) -> List[int]:
         """
-        Returns tags identifying the Operation
+        Return the spins the analog operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            List[int]: All the spins present in the Hamiltonian of the operation.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+class ApplyTimeDependentSpinHamiltonian(Operation):
+    """
+     Constant Hamiltonian operation on analog quantum device (PASCAL)
+
+    Args:
+        hamiltonian (SpinHamiltonian): The hamiltonian that is to be simulated.
+        time (List[float]): Range of time stored as a vector. The total duration of the simulations is given by the last value in the range.
+        values (Mapping[str, List[float]]): /// Values of time-dependent parameters, appearing in `hamiltonian`, at instances given by the vector `time`.
+    """
+
+    def __init__(self, hamiltonian: SpinHamiltonianSystem, time: List[float], values: Mapping[str, List[float]]) -> None:
         """
-        Remap qubits
+        Create a new ApplyTimeDependentSpinHamiltonian.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            hamiltonian (SpinHamiltonian): The hamiltonian that is to be simulated.
+            time (List[float]): Range of time stored as a vector. The total duration of the simulations is given by the last value in the range.
+            values (Mapping[str, List[float]]): /// Values of time-dependent parameters, appearing in `hamiltonian`, at instances given by the vector `time`.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            ApplyTimeDependentSpinHamiltonian: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def hamiltonian(self) -> SpinHamiltonianSystem:
         """
-        List all involved Qubits
+        Return the value of the `hamiltonian` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SpinHamiltonianSystem: The hamiltonian that is to be simulated.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
+    def time(self) -> List[float]:
         """
-        List of modes the operation acts on.
+        Return the value of the `time` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            List[float]: Range of time stored as a vector. The total duration of the simulations is given by the last value in the range.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def values(self) -> Dict[str, List[float]]:
         """
-        Remap the bosonic modes in copy of the operation.
-
-        Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+        Return the value of the `values` field.
 
         Returns:
-            self
-
-        Raises:
-            PyValueError: Remapping could not be performed
+            Dict[str, List[float]]: /// Values of time-dependent parameters, appearing in `hamiltonian`, at instances given by the vector `time`.
         """
 
-    def mode(self):
+    def spin(self) -> List[int]:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the spins the analog operation acts on.
 
         Returns:
-            int
+            List[int]: All the spins present in the Hamiltonian of the operation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -13571,133 +11599,91 @@ class PhaseDisplacement(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class EchoCrossResonance(Operation):
+class QuantumRabi(Operation):
     """
-Implements the controlled RotateXY operation.
-
-The unitary matrix representation is:
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        0 & 1 & 0 & i \\\\
-        1 & 0 & -i & 0 \\\\
-        0 & i & 0 & 1 \\\\
-        -i & 0 & 1 & 0
-        \end{pmatrix}
-
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Rotatex Operation on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit RotateX Operation is applied to.
-    theta (CalculatorFloat): The angle $\theta$ of the rotation.
-    phi (CalculatorFloat): The rotation axis, in spherical coordinates :math:`\phi_{sph}`  gives the angle in the x-y plane.
-"""
+    The quantum Rabi interaction exp(-i * θ * X * (b^† + b))
 
-    def __init__(
-        self,
-        control: int,
-        target: int,
-        theta: Union[float, str],
-        phi: Union[float, str],
-    ):
-        return
+    Args:
+        qubit (int): The qubit the gate is applied to.
+        mode (int): The mode the gate is applied to.
+        theta (CalculatorFloat): The strength of the interaction.
+    """
 
-    def is_parametrized(self) -> bool:
+    def __init__(self, qubit: int, mode: int, theta: Union[CalculatorFloat, float, str]) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new QuantumRabi.
+
+        Args:
+            qubit (int): The qubit the gate is applied to.
+            mode (int): The mode the gate is applied to.
+            theta (CalculatorFloat): The strength of the interaction.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            QuantumRabi: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def tags(self) -> List[str]:
+    def theta(self) -> CalculatorFloat:
         """
-        Returns tags identifying the Operation
+        Return the value of the `theta` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The strength of the interaction.
         """
 
-    def hqslang(self) -> str:
+    def qubit(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            str: The name
+            int: The qubit the operation acts on.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the set of modes the operation acts on.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def remap_modes(self, mapping: Dict[int, int]) -> QuantumRabi:
         """
-        Remap qubits
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            Self: The operation with the remapped modes.
 
         Raises:
-            RuntimeError: Qubit remapping failed
-        """
-
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
-
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
-
-    def control(self):
-        """
-        Returns control qubit of the two-qubit operation
+            ValueError: Remapping could not be performed.
         """
 
-    def target(self):
+    def mode(self) -> int:
         """
-        Returns target qubit of the two-qubit operation
-        """
-
-    def unitary_matrix(self) -> numpy.ndarray:
-        """
-        Return unitary matrix of gate.
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            int: The bosonic mode of the operation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -13711,133 +11697,116 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PragmaAnnotatedOp(Operation):
+class LongitudinalCoupling(Operation):
     """
-    An annotated Operation.
+    Longitudinal coupling gate exp(-i * θ * Z * (b^† + b))
 
     Args:
-        operation (Operation): - The Operation to be annotated.
-        annotation (str): - The annotation.
+        qubit (int): The qubit the gate is applied to.
+        mode (int): The mode the gate is applied to.
+        theta (CalculatorFloat): The strength of the interaction.
     """
 
-    def __init__(self, operation: Operation, annotation: str):
-        return
-
-    def operation(self) -> Operation:
-        """
-        Return the internal Operation.
-
-        Returns:
-            Operation: The annotated Operation.
+    def __init__(self, qubit: int, mode: int, theta: Union[CalculatorFloat, float, str]) -> None:
         """
+        Create a new LongitudinalCoupling.
 
-    def annotation(self) -> str:
-        """
-        Return the annotation.
+        Args:
+            qubit (int): The qubit the gate is applied to.
+            mode (int): The mode the gate is applied to.
+            theta (CalculatorFloat): The strength of the interaction.
 
         Returns:
-            str: The annotation.
-        """
-
-    def involved_qubits(self) -> Set[int]:
-        """
-        List all involved qubits.
+            LongitudinalCoupling: The new operation.
 
-        Returns:
-            Set[int]: The involved qubits of the PRAGMA operation.
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def tags(self) -> List[str]:
+    def theta(self) -> CalculatorFloat:
         """
-        Return tags classifying the type of the operation.
-
-        Used for the type based dispatch in ffi interfaces.
+        Return the value of the `theta` field.
 
         Returns:
-            List[str]: The tags of the Operation.
+            CalculatorFloat: The strength of the interaction.
         """
 
-    def hqslang(self) -> str:
+    def qubit(self) -> int:
         """
-        Return hqslang name of the operation.
+        Return the qubit the operation acts on.
 
         Returns:
-            str: The hqslang name of the operation.
+            int: The qubit the operation acts on.
         """
 
-    def is_parametrized(self) -> bool:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Return true when the operation has symbolic parameters.
+        Return the set of modes the operation acts on.
 
         Returns:
-            bool: True if the operation contains symbolic parameters, False if it does not.
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> PragmaAnnotatedOp:
+    def remap_modes(self, mapping: Dict[int, int]) -> LongitudinalCoupling:
         """
-        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
-            substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            self: The PRAGMA operation with the parameters substituted.
+            Self: The operation with the remapped modes.
 
         Raises:
-            RuntimeError: The parameter substitution failed.
+            ValueError: Remapping could not be performed.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaAnnotatedOp:
+    def mode(self) -> int:
         """
-        Remap qubits in a clone of the PRAGMA operation.
-
-        Args:
-            mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            self: The PRAGMA operation with the qubits remapped.
-
-        Raises:
-            RuntimeError: The qubit remapping failed.
+            int: The bosonic mode of the operation.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The json schema serialized to json
+            str: The current version of the library.
         """
 
-    def current_version(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The current version of the library.
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def min_supported_version(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Return the minimum version of qoqo that supports this object.
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            str: The json schema serialized to json.
         """
 
-class QuantumRabi(Operation):
+class JaynesCummings(Operation):
     """
-    The quantum Rabi interaction exp(-i * θ * X * (b^† + b))
+    The Jaynes-Cummings gate exp(-i * θ * (σ^- * b^† + σ^+ * b))
 
     Args:
         qubit (int): The qubit the gate is applied to.
@@ -13845,120 +11814,160 @@ class QuantumRabi(Operation):
         theta (CalculatorFloat): The strength of the interaction.
     """
 
-    def __init__(self, qubit: int, mode: int, theta: Union[float, str]):
-        return
-
-    def theta(self):
+    def __init__(self, qubit: int, mode: int, theta: Union[CalculatorFloat, float, str]) -> None:
         """
-        Returns value of attribute theta
+        Create a new JaynesCummings.
+
+        Args:
+            qubit (int): The qubit the gate is applied to.
+            mode (int): The mode the gate is applied to.
+            theta (CalculatorFloat): The strength of the interaction.
+
+        Returns:
+            JaynesCummings: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def theta(self) -> CalculatorFloat:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `theta` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The strength of the interaction.
         """
 
-    def tags(self) -> List[str]:
+    def qubit(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The qubit the operation acts on.
         """
 
-    def hqslang(self) -> str:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Returns hqslang name of Operation
+        Return the set of modes the operation acts on.
 
         Returns:
-            str: The name
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def remap_modes(self, mapping: Dict[int, int]) -> JaynesCummings:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            Self: The operation with the remapped modes.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: Remapping could not be performed.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def mode(self) -> int:
         """
-        Remap qubits
+        Return the `mode` the bosonic Operation acts on.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Returns:
+            int: The bosonic mode of the operation.
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            str: The current version of the library.
+        """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
-        List all involved Qubits
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The json schema serialized to json.
+        """
+
+class SingleExcitationStore(Operation):
+    """
+    Stores a single excitation from the involved qubit into the involved bosonic mode as follows
+    |0⟩_B ⨂ (a |0⟩_Q + b |1⟩_Q) -> (a|0⟩_B + b |1⟩_B ) ⨂ |0⟩_Q
+
+    Note: not defined if the bosonic mode is in a state |n⟩ with n != 0
+
+    Args:
+        qubit (int): The qubit the gate is applied to.
+        mode (int): The mode the gate is applied to.
+    """
+
+    def __init__(self, qubit: int, mode: int) -> None:
+        """
+        Create a new SingleExcitationStore.
+
+        Args:
+            qubit (int): The qubit the gate is applied to.
+            mode (int): The mode the gate is applied to.
+
+        Returns:
+            SingleExcitationStore: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
+            int: The qubit the operation acts on.
         """
 
     def involved_modes(self) -> Union[Set[int], str]:
         """
-        List of modes the operation acts on.
+        Return the set of modes the operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def remap_modes(self, mapping: Dict[int, int]) -> SingleExcitationStore:
         """
-        Remap the bosonic modes in copy of the operation.
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
             mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            self
+            Self: The operation with the remapped modes.
 
         Raises:
-            PyValueError: Remapping could not be performed
+            ValueError: Remapping could not be performed.
         """
 
-    def mode(self):
+    def mode(self) -> int:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            int
+            int: The bosonic mode of the operation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -13972,138 +11981,168 @@ class QuantumRabi(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class LongitudinalCoupling(Operation):
+class SingleExcitationLoad(Operation):
     """
-    Longitudinal coupling gate exp(-i * θ * Z * (b^† + b))
+    Loads a single excitation from a bosonic mode into a qubit as follows
+    (c1 |0⟩_B + c2 |1⟩_B) ⨂ |0⟩_Q -> |0⟩_B ⨂ (c1 |0⟩_Q + c2 |1⟩_Q)
+
+    Note: if the initial qubit state is |1⟩_Q the operation is only defined if c2 = 0
 
     Args:
         qubit (int): The qubit the gate is applied to.
         mode (int): The mode the gate is applied to.
-        theta (CalculatorFloat): The strength of the interaction.
     """
 
-    def __init__(self, qubit: int, mode: int, theta: Union[float, str]):
-        return
+    def __init__(self, qubit: int, mode: int) -> None:
+        """
+        Create a new SingleExcitationLoad.
+
+        Args:
+            qubit (int): The qubit the gate is applied to.
+            mode (int): The mode the gate is applied to.
+
+        Returns:
+            SingleExcitationLoad: The new operation.
+        """
 
-    def theta(self):
+    def qubit(self) -> int:
         """
-        Returns value of attribute theta
+        Return the qubit the operation acts on.
+
+        Returns:
+            int: The qubit the operation acts on.
         """
 
-    def is_parametrized(self) -> bool:
+    def involved_modes(self) -> Union[Set[int], str]:
         """
-        Returns true if operation contains symbolic parameters
+        Return the set of modes the operation acts on.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def tags(self) -> List[str]:
+    def remap_modes(self, mapping: Dict[int, int]) -> SingleExcitationLoad:
         """
-        Returns tags identifying the Operation
+        Remap the bosonic modes in a copy of the operation.
+
+        Args:
+            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Self: The operation with the remapped modes.
+
+        Raises:
+            ValueError: Remapping could not be performed.
         """
 
-    def hqslang(self) -> str:
+    def mode(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            str: The name
+            int: The bosonic mode of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def current_version() -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the current version of the qoqo library.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The current version of the library.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    @staticmethod
+    def json_schema() -> str:
         """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            str: The json schema serialized to json.
         """
-        Remap qubits
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+class CZQubitResonator(Operation):
+    """
+    Controlled-Z operation between a qubit and a bosonic mode, where the two-dimensional subspace of
+    the bosonic mode spanned by the occupation number states |0⟩_B and |1⟩_B is considered
+    as the second qubit involved in the CZ operation.
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the gate is applied to.
+        mode (int): The mode the gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int, mode: int) -> None:
         """
+        Create a new CZQubitResonator.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the gate is applied to.
+            mode (int): The mode the gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            CZQubitResonator: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
+            int: The qubit the operation acts on.
         """
 
     def involved_modes(self) -> Union[Set[int], str]:
         """
-        List of modes the operation acts on.
+        Return the set of modes the operation acts on.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            Union[Set[int], str]: The involved modes as a set or 'ALL' if all modes are involved.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def remap_modes(self, mapping: Dict[int, int]) -> CZQubitResonator:
         """
-        Remap the bosonic modes in copy of the operation.
+        Remap the bosonic modes in a copy of the operation.
 
         Args:
             mapping (Dict[int, int]): Mapping for bosonic modes in operation.
 
         Returns:
-            self
+            Self: The operation with the remapped modes.
 
         Raises:
-            PyValueError: Remapping could not be performed
+            ValueError: Remapping could not be performed.
         """
 
-    def mode(self):
+    def mode(self) -> int:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the `mode` the bosonic Operation acts on.
 
         Returns:
-            int
+            int: The bosonic mode of the operation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -14117,136 +12156,161 @@ class LongitudinalCoupling(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class JaynesCummings(Operation):
+class GateDefinition(Operation):
     """
-    The Jaynes-Cummings gate exp(-i * θ * (σ^- * b^† + σ^+ * b))
+    GateDefinition is the Definition of a new custom gate.
 
     Args:
-        qubit (int): The qubit the gate is applied to.
-        mode (int): The mode the gate is applied to.
-        theta (CalculatorFloat): The strength of the interaction.
+        circuit (Circuit): The circuit where the definition is stored.
+        name (str): The name of the gate that is defined.
+        qubits (List[int]): The indices of the qubits used in the internal definition.
+        free_parameter (List[str]): Names of the free CalculatorFloat variables in the internal definition.
     """
 
-    def __init__(self, qubit: int, mode: int, theta: Union[float, str]):
-        return
-
-    def theta(self):
+    def __init__(self, circuit: Circuit, name: str, qubits: List[int], free_parameters: List[str]) -> None:
         """
-        Returns value of attribute theta
+        Create a new GateDefinition.
+
+        Args:
+            circuit (Circuit): The circuit where the definition is stored.
+            name (str): The name of the gate that is defined.
+            qubits (List[int]): The indices of the qubits used in the internal definition.
+            free_parameters (List[str]): The value of the field `free_parameters`.
+
+        Returns:
+            GateDefinition: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def circuit(self) -> Circuit:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `circuit` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Circuit: The circuit where the definition is stored.
         """
 
-    def tags(self) -> List[str]:
+    def free_parameters(self) -> List[str]:
         """
-        Returns tags identifying the Operation
+        Return the value of the `free_parameters` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            List[str]: The value of the field `free_parameters`.
         """
 
-    def hqslang(self) -> str:
+    def qubits(self) -> List[int]:
         """
-        Returns hqslang name of Operation
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            str: The name
+            List[int]: The qubits the operation acts on.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def name(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the name of the definition operation.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The name of the defined register or variable.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            str: The current version of the library.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
-        Remap qubits
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            str: The json schema serialized to json.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+class CallDefinedGate(Operation):
+    """
+    The gate to be replaced by a gate defined with GateDefinition gate.
+    The gate applies a gate previously defined by GateDefinition with the name gate_name.
+
+    Args:
+        gate_name (str) : The name of the called defined operations.
+        qubits (List[int]) : The qubits that for this call replace the qubits in the internal definition of the called gate
+                              (get replaced in order of apppearance in gate defintion).
+        free_parameters (List[CalculatorFloat]) : List of float values that replace the free parameters in the internal definition of the called gate
+                                                (get replaced in order of apppearance in gate defintion).
+    """
+
+    def __init__(self, gate_name: str, qubits: List[int], free_parameters: List[CalculatorFloat]) -> None:
         """
-        List all involved Qubits
+        Create a new CallDefinedGate.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        Args:
+            gate_name (str) : The name of the called defined operations.
+            qubits (List[int]) : The qubits that for this call replace the qubits in the internal definition of the called gate
+                                  (get replaced in order of apppearance in gate defintion).
+            free_parameters (List[CalculatorFloat]) : List of float values that replace the free parameters in the internal defintion of the called gate
+                                                    (get replaced in order of apppearance in gate defintion).
         """
 
-    def qubit(self):
+    def gate_name(self) -> str:
         """
-        Return the qubit the operation acts on
+        Return the name of the gate to apply.
 
         Returns:
-            int
+            str: The name of the gate.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
+    def qubits(self) -> List[int]:
         """
-        List of modes the operation acts on.
+        Return the qubits on which the Gate operation is applied.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            List[int]: The qubits of the operation.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def free_parameters(self) -> List[CalculatorFloat]:
         """
-        Remap the bosonic modes in copy of the operation.
-
-        Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+        Return the qubits on which the Gate operation is applied.
 
         Returns:
-            self
-
-        Raises:
-            PyValueError: Remapping could not be performed
+            List[CalculatorFloat]: The qubits of the operation.
         """
 
-    def mode(self):
+    @staticmethod
+    def json_schema() -> str:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            int
+            str: The json schema serialized to json
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
         Returns the current version of the qoqo library .
 
@@ -14262,135 +12326,200 @@ class JaynesCummings(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
-
-        Returns:
-            str: The json schema serialized to json
-        """
-
-class SingleExcitationStore(Operation):
-    """
-    Stores a single excitation from the involved qubit into the involved bosonic mode as follows
-    |0⟩_B ⨂ (a |0⟩_Q + b |1⟩_Q) -> (a|0⟩_B + b |1⟩_B ) ⨂ |0⟩_Q
+class SqrtPauliY(Operation):
+    r"""
+    The square root of the YPower gate :math:`e^{-i \frac{\pi}{4} \sigma^y}`.
 
-    Note: not defined if the bosonic mode is in a state |n⟩ with n != 0
+    .. math::
+        U = \frac{1}{\sqrt(2)} \begin{pmatrix}
+            1 & -1 \\\\
+            1 & 1
+            \end{pmatrix}
 
     Args:
-        qubit (int): The qubit the gate is applied to.
-        mode (int): The mode the gate is applied to.
+        qubit (int): The qubit the unitary gate is applied to.
     """
 
-    def __init__(self, qubit: int, mode: int):
-        return
-
-    def is_parametrized(self) -> bool:
+    def __init__(self, qubit: int) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new SqrtPauliY.
+
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            SqrtPauliY: The new operation.
         """
 
-    def tags(self) -> List[str]:
+    def qubit(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The qubit the operation acts on.
         """
 
-    def hqslang(self) -> str:
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
+
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-        Returns hqslang name of Operation
+
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
+
+        Here alpha_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-        Substitutes internal symbolic parameters with float values
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Here beta_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the parameters substituted
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
+
+        Here beta_i is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
-        Remap qubits
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Does not consume the two operations being multiplied.
+        Only Operations
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
-        """
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Example:
+        ```
+        from qoqo.operations import RotateZ, RotateX
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        gate1 =  RotateZ(qubit=0, theta=1)
+        gate2 = RotateX(qubit=0, theta=1)
+        multiplied = gate1.mul(gate2)
+        print("Multiplied gate: ", multiplied)
+        ```
         """
 
-    def qubit(self):
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return the qubit the operation acts on
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            int
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        List of modes the operation acts on.
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Remap the bosonic modes in copy of the operation.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-        Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            self
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
         Raises:
-            PyValueError: Remapping could not be performed
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def mode(self):
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the unitary matrix of the gate.
 
         Returns:
-            int
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -14404,135 +12533,209 @@ class SingleExcitationStore(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class SingleExcitationLoad(Operation):
-    """
-    Loads a single excitation from a bosonic mode into a qubit as follows
-    (c1 |0⟩_B + c2 |1⟩_B) ⨂ |0⟩_Q -> |0⟩_B ⨂ (c1 |0⟩_Q + c2 |1⟩_Q)
+class InvSqrtPauliY(Operation):
+    r"""
+    The inverse square root YPower gate :math:`e^{i \frac{\pi}{4} \sigma^y}`.
 
-    Note: if the initial qubit state is |1⟩_Q the operation is only defined if c2 = 0
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 1 \\\\
+            -1 & 1
+            \end{pmatrix}
 
     Args:
-        qubit (int): The qubit the gate is applied to.
-        mode (int): The mode the gate is applied to.
+        qubit (int): The qubit the unitary gate is applied to.
     """
 
-    def __init__(self, qubit: int, mode: int):
-        return
-
-    def is_parametrized(self) -> bool:
+    def __init__(self, qubit: int) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new InvSqrtPauliY.
+
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            InvSqrtPauliY: The new operation.
         """
 
-    def tags(self) -> List[str]:
+    def qubit(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The qubit the operation acts on.
         """
 
-    def hqslang(self) -> str:
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
+
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-        Returns hqslang name of Operation
+
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
+
+        Here alpha_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-        Substitutes internal symbolic parameters with float values
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Here beta_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
+        """
+
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
+
+        Here beta_i is defined by
 
-        Returns:
-            Operation: The operation with the parameters substituted
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
-        Remap qubits
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Does not consume the two operations being multiplied.
+        Only Operations
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
-        """
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Example:
+        ```
+        from qoqo.operations import RotateZ, RotateX
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        gate1 =  RotateZ(qubit=0, theta=1)
+        gate2 = RotateX(qubit=0, theta=1)
+        multiplied = gate1.mul(gate2)
+        print("Multiplied gate: ", multiplied)
+        ```
         """
 
-    def qubit(self):
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return the qubit the operation acts on
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            int
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        List of modes the operation acts on.
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Remap the bosonic modes in copy of the operation.
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-        Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            self
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
         Raises:
-            PyValueError: Remapping could not be performed
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def mode(self):
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return `mode` the bosonic Operation acts on.
+        Return the unitary matrix of the gate.
 
         Returns:
-            int
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -14546,326 +12749,343 @@ class SingleExcitationLoad(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class CZQubitResonator(Operation):
-    """
-    Controlled-Z operation between a qubit and a bosonic mode, where the two-dimensional subspace of
-    the bosonic mode spanned by the occupation number states |0⟩_B and |1⟩_B is considered
-    as the second qubit involved in the CZ operation.
+class InvSGate(Operation):
+    r"""
+    The InvS gate.
+
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 0 \\\\
+            0 & -i
+            \end{pmatrix}
 
     Args:
-        qubit (int): The qubit the gate is applied to.
-        mode (int): The mode the gate is applied to.
+        qubit (int): The qubit the unitary gate is applied to.
     """
 
-    def __init__(self, qubit: int, mode: int):
-        return
-
-    def is_parametrized(self) -> bool:
+    def __init__(self, qubit: int) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new InvSGate.
+
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            InvSGate: The new operation.
         """
 
-    def tags(self) -> List[str]:
+    def qubit(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the qubit the operation acts on.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The qubit the operation acts on.
         """
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
+
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
-        """
-        Substitutes internal symbolic parameters with float values
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Here alpha_r is defined by
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-        Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+        Here beta_r is defined by
 
-    def qubit(self):
-        """
-        Return the qubit the operation acts on
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            int
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
 
-    def involved_modes(self) -> Union[Set[int], str]:
-        """
-        List of modes the operation acts on.
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
+
+        Here beta_i is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
 
-    def remap_modes(self, mapping: Dict[int, int]):
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
-        Remap the bosonic modes in copy of the operation.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Does not consume the two operations being multiplied.
+        Only Operations
 
         Args:
-            mapping (Dict[int, int]): Mapping for bosonic modes in operation.
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            self
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            PyValueError: Remapping could not be performed
-        """
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
-    def mode(self):
-        """
-        Return `mode` the bosonic Operation acts on.
+        Example:
+        ```
+        from qoqo.operations import RotateZ, RotateX
 
-        Returns:
-            int
+        gate1 =  RotateZ(qubit=0, theta=1)
+        gate2 = RotateX(qubit=0, theta=1)
+        multiplied = gate1.mul(gate2)
+        print("Multiplied gate: ", multiplied)
+        ```
         """
 
-    def current_version(self) -> str:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Returns the current version of the qoqo library .
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-        Returns:
-            str: The current version of the library.
-        """
+        Alias for `mul`, allowing the use of the `*` operator.
 
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def json_schema(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The json schema serialized to json
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-class SqrtPauliY(Operation):
-    """
-The square root of the YPower gate :math:`e^{-i \frac{\pi}{4} \sigma^y}`.
-
-.. math::
-    U = \frac{1}{\sqrt(2)} \begin{pmatrix}
-        1 & -1 \\\\
-        1 & 1
-        \end{pmatrix}
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
-"""
+        Returns:
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def __init__(self, qubit: int):
-        return
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
+        """
 
-    def is_parametrized(self) -> bool:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Returns true if operation contains symbolic parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def tags(self) -> List[str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns tags identifying the Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The current version of the library.
         """
 
-    def hqslang(self) -> str:
+    def min_supported_version(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            str: The name
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def json_schema() -> str:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            str: The json schema serialized to json.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
-        """
-        Remap qubits
+class InvTGate(Operation):
+    r"""
+    The InvT gate.
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    .. math::
+        U = \frac{1}{\sqrt{2}} \begin{pmatrix}
+            1 & 0 \\\\
+            0 & e^{-i \frac{\pi}{4}}
+            \end{pmatrix}
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def __init__(self, qubit: int) -> None:
         """
+        Create a new InvTGate.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
-        """
-        List all involved Qubits
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            InvTGate: The new operation.
         """
 
-    def qubit(self):
+    def qubit(self) -> int:
         """
-        Return the qubit the operation acts on
+        Return the qubit the operation acts on.
 
         Returns:
-            int
+            int: The qubit the operation acts on.
         """
 
-    def global_phase(self):
-        """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -14873,10 +13093,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -14887,215 +13112,196 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
-
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Return unitary matrix of gate.
-
-        Returns:
-            np.ndarray: matrix of gate.
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
-        """
+        Alias for `mul`, allowing the use of the `*` operator.
 
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            str: The current version of the library.
-        """
-
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def json_schema(self) -> str:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            str: The json schema serialized to json
-        """
-
-class InvSqrtPauliY(Operation):
-    """
-The inverse square root YPower gate :math:`e^{i \frac{\pi}{4} \sigma^y}`.
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 1 \\\\
-        -1 & 1
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-
-"""
-
-    def __init__(self, qubit: int):
-        return
-
-    def is_parametrized(self) -> bool:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
-        Returns true if operation contains symbolic parameters
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+    def to_zyz_circuit(self) -> Circuit:
         """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
 
-    def tags(self) -> List[str]:
-        """
-        Returns tags identifying the Operation
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            List[str]: The tags identifying the operation
-        """
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def hqslang(self) -> str:
-        """
-        Returns hqslang name of Operation
-
-        Returns:
-            str: The name
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    @staticmethod
+    def current_version() -> str:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the current version of the qoqo library.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            str: The current version of the library.
+        """
 
-        Raises:
-            RuntimeError: Qubit remapping failed
+    def min_supported_version(self) -> str:
         """
+        Return the minimum version of qoqo that supports this object.
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
-        List all involved Qubits
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The json schema serialized to json.
         """
 
-    def qubit(self):
+class SXGate(Operation):
+    r"""
+    The SX gate.
+
+    .. math::
+        U = \frac{1}{\sqrt(2)} \begin{pmatrix}
+            1+i & 1-i \\\\
+            1-i & 1+i
+            \end{pmatrix}
+
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
+    """
+
+    def __init__(self, qubit: int) -> None:
         """
-        Return the qubit the operation acts on
+        Create a new SXGate.
+
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
         Returns:
-            int
+            SXGate: The new operation.
+        """
+
+    def qubit(self) -> int:
         """
+        Return the qubit the operation acts on.
 
-    def global_phase(self):
+        Returns:
+            int: The qubit the operation acts on.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here global_phase is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_r(self):
+        Returns:
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here alpha_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def alpha_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_r(self):
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_r is defined by
 
-Returns:
-    CalculatorFloat
-"""
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Here beta_i is defined by
 
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
+        """
 
-    def mul(self) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
         Multiplies two compatible operations implementing OperateSingleQubitGate.
 
@@ -15103,10 +13309,15 @@ Returns:
         Only Operations
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
 
         Example:
         ```
@@ -15117,23 +13328,62 @@ Returns:
         multiplied = gate1.mul(gate2)
         print("Multiplied gate: ", multiplied)
         ```
+        """
+
+    def __mul__(self, other: Operation) -> SingleQubitGate:
+        """
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
+
+        Args:
+            other (Operation): An Operation implementing OperateSingleQubitGate.
+
+        Returns:
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
+
+        Raises:
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+        """
+
+    def to_single_qubit_gate(self) -> SingleQubitGate:
+        """
+        Return the equivalent SingleQubitGate of the single qubit gate.
+
+        Returns:
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
+        """
+
+    def to_zyz_circuit(self) -> Circuit:
+        """
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
+
+        Returns:
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -15147,223 +13397,305 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class InvSGate(Operation):
+class InvSXGate(Operation):
+    r"""
+    The InvSX gate.
+
+    .. math::
+        U = \frac{1}{\sqrt(2)} \begin{pmatrix}
+            1-i & 1+i \\\\
+            1+i & 1-i
+            \end{pmatrix}
+
+    Args:
+        qubit (int): The qubit the unitary gate is applied to.
     """
-The InvS gate.
 
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 0 \\\\
-        0 & -i
-        \end{pmatrix}
+    def __init__(self, qubit: int) -> None:
+        """
+        Create a new InvSXGate.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+        Args:
+            qubit (int): The qubit the unitary gate is applied to.
 
-"""
+        Returns:
+            InvSXGate: The new operation.
+        """
 
-    def __init__(self, qubit: int):
-        return
+    def qubit(self) -> int:
+        """
+        Return the qubit the operation acts on.
 
-    def is_parametrized(self) -> bool:
+        Returns:
+            int: The qubit the operation acts on.
         """
-        Returns true if operation contains symbolic parameters
+
+    def global_phase(self) -> CalculatorFloat:
+        r"""
+        Return the global phase :math:`g` of a unitary gate acting on one qubit.
+
+        Here global_phase is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The global phase :math:`g` of the gate.
         """
 
-    def tags(self) -> List[str]:
+    def alpha_r(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit.
+
+        Here alpha_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The real part :math:`\alpha_r` of the on-diagonal elements.
         """
-        Returns tags identifying the Operation
+
+    def alpha_i(self) -> CalculatorFloat:
+        r"""
+        Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit.
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            List[str]: The tags identifying the operation
+            CalculatorFloat: The imaginary part :math:`\alpha_i` of the on-diagonal elements.
         """
 
-    def hqslang(self) -> str:
+    def beta_r(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit.
+
+        Here beta_r is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
+
+        Returns:
+            CalculatorFloat: The real part :math:`\beta_r` of the off-diagonal elements.
         """
-        Returns hqslang name of Operation
+
+    def beta_i(self) -> CalculatorFloat:
+        r"""
+        Return the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit.
+
+        Here beta_i is defined by
+
+        .. math::
+            U =e^{i \cdot g}\begin{pmatrix}
+            \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
+            \beta_r+i \beta_i & \alpha_r-i\alpha_i
+            \end{pmatrix}
 
         Returns:
-            str: The name
+            CalculatorFloat: The imaginary part :math:`\beta_i` of the off-diagonal elements.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def mul(self, other: Operation) -> SingleQubitGate:
         """
-        Substitutes internal symbolic parameters with float values
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Does not consume the two operations being multiplied.
+        Only Operations
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
+
+        Example:
+        ```
+        from qoqo.operations import RotateZ, RotateX
+
+        gate1 =  RotateZ(qubit=0, theta=1)
+        gate2 = RotateX(qubit=0, theta=1)
+        multiplied = gate1.mul(gate2)
+        print("Multiplied gate: ", multiplied)
+        ```
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def __mul__(self, other: Operation) -> SingleQubitGate:
         """
-        Remap qubits
+        Multiplies two compatible operations implementing OperateSingleQubitGate.
+
+        Alias for `mul`, allowing the use of the `*` operator.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            other (Operation): An Operation implementing OperateSingleQubitGate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            SingleQubitGate: Result of the multiplication, i.e. the multiplied single qubit gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: Right hand side cannot be converted to Operation.
+            RuntimeError: Right hand side is not a single qubit gate.
+            ValueError: The two gates act on different qubits or their product is not unitary.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def to_single_qubit_gate(self) -> SingleQubitGate:
         """
-        List all involved Qubits
+        Return the equivalent SingleQubitGate of the single qubit gate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            SingleQubitGate: The gate in the canonical SingleQubitGate representation.
         """
 
-    def qubit(self):
+    def to_zyz_circuit(self) -> Circuit:
         """
-        Return the qubit the operation acts on
+        Decompose the gate into a RotateZ, RotateY, RotateZ sequence.
+
+        The global phase of the gate is added as a PragmaGlobalPhase at the end of the circuit.
 
         Returns:
-            int
-        """
+            Circuit: The rotations followed by a PragmaGlobalPhase.
 
-    def global_phase(self):
+        Raises:
+            ValueError: The parameters of the gate are symbolic.
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
 
-Here global_phase is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def unitary_matrix(self) -> numpy.ndarray:
+        """
+        Return the unitary matrix of the gate.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            np.ndarray: The unitary matrix of the gate.
 
-    def alpha_r(self):
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            str: The current version of the library.
+        """
 
-    def alpha_i(self):
+    def min_supported_version(self) -> str:
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
+        Return the minimum version of qoqo that supports this object.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-Returns:
-    CalculatorFloat
-"""
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def beta_r(self):
+        Returns:
+            str: The json schema serialized to json.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+class TripleControlledPauliX(Operation):
+    """
+    The triple-controlled PauliX gate.
+    """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def __init__(self, control_0: int, control_1: int, control_2: int, target: int) -> None:
+        """
+        Create a new TripleControlledPauliX.
 
-Returns:
-    CalculatorFloat
-"""
+        Args:
+            control_0 (int): The value of the field `control_0`.
+            control_1 (int): The value of the field `control_1`.
+            control_2 (int): The value of the field `control_2`.
+            target (int): The value of the field `target`.
 
-    def beta_i(self):
+        Returns:
+            TripleControlledPauliX: The new operation.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def control_0(self) -> int:
+        """
+        Return the control_0 qubit of the four-qubit operation.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            int: The control_0 qubit of the operation.
+        """
 
+    def control_1(self) -> int:
+        """
+        Return the control_1 qubit of the four-qubit operation.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            int: The control_1 qubit of the operation.
+        """
 
-    def mul(self) -> Operation:
+    def control_2(self) -> int:
         """
-        Multiplies two compatible operations implementing OperateSingleQubitGate.
+        Return the control_2 qubit of the four-qubit operation.
 
-        Does not consume the two operations being multiplied.
-        Only Operations
+        Returns:
+            int: The control_2 qubit of the operation.
+        """
 
-        Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+    def target(self) -> int:
+        """
+        Return the target qubit of the four-qubit operation.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
-
-        Example:
-        ```
-        from qoqo.operations import RotateZ, RotateX
+            int: The target qubit of the operation.
+        """
 
-        gate1 =  RotateZ(qubit=0, theta=1)
-        gate2 = RotateX(qubit=0, theta=1)
-        multiplied = gate1.mul(gate2)
-        print("Multiplied gate: ", multiplied)
-        ```
+    def circuit(self) -> Circuit:
+        """
+        Return the circuit implementing the FourQubitGateOperation.
 
+        Returns:
+            Circuit: The circuit implementing the gate.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -15377,223 +13709,197 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class InvTGate(Operation):
+class TripleControlledPauliZ(Operation):
+    """
+    The triple-controlled PauliZ gate.
     """
-The InvT gate.
-
-.. math::
-    U = \frac{1}{\sqrt{2}} \begin{pmatrix}
-        1 & 0 \\\\
-        0 & e^{-i \frac{\pi}{4}}
-        \end{pmatrix}
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+    def __init__(self, control_0: int, control_1: int, control_2: int, target: int) -> None:
+        """
+        Create a new TripleControlledPauliZ.
 
-"""
+        Args:
+            control_0 (int): The value of the field `control_0`.
+            control_1 (int): The value of the field `control_1`.
+            control_2 (int): The value of the field `control_2`.
+            target (int): The value of the field `target`.
 
-    def __init__(self, qubit: int):
-        return
+        Returns:
+            TripleControlledPauliZ: The new operation.
+        """
 
-    def is_parametrized(self) -> bool:
+    def control_0(self) -> int:
         """
-        Returns true if operation contains symbolic parameters
+        Return the control_0 qubit of the four-qubit operation.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            int: The control_0 qubit of the operation.
         """
 
-    def tags(self) -> List[str]:
+    def control_1(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the control_1 qubit of the four-qubit operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The control_1 qubit of the operation.
         """
 
-    def hqslang(self) -> str:
+    def control_2(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control_2 qubit of the four-qubit operation.
 
         Returns:
-            str: The name
+            int: The control_2 qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def target(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the target qubit of the four-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            int: The target qubit of the operation.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def circuit(self) -> Circuit:
         """
+        Return the circuit implementing the FourQubitGateOperation.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            Circuit: The circuit implementing the gate.
         """
-        Remap qubits
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    def unitary_matrix(self) -> numpy.ndarray:
+        """
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved Qubits
+        Return the current version of the qoqo library.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The current version of the library.
         """
 
-    def qubit(self):
+    def min_supported_version(self) -> str:
         """
-        Return the qubit the operation acts on
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            int
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def global_phase(self):
+    @staticmethod
+    def json_schema() -> str:
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
+        Return the JsonSchema for the json serialisation of the class.
 
-    def alpha_r(self):
+        Returns:
+            str: The json schema serialized to json.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
-
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
+class TripleControlledPhaseShift(Operation):
+    """
+    The triple-controlled PhaseShift gate.
+    """
 
-    def alpha_i(self):
+    def __init__(self, control_0: int, control_1: int, control_2: int, target: int, theta: Union[CalculatorFloat, float, str]) -> None:
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
+        Create a new TripleControlledPhaseShift.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Args:
+            control_0 (int): The value of the field `control_0`.
+            control_1 (int): The value of the field `control_1`.
+            control_2 (int): The value of the field `control_2`.
+            target (int): The value of the field `target`.
+            theta (CalculatorFloat): The value of the field `theta`.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            TripleControlledPhaseShift: The new operation.
 
-    def beta_r(self):
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
-
-Here beta_r is defined by
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
-
-Returns:
-    CalculatorFloat
-"""
+    def theta(self) -> CalculatorFloat:
+        """
+        Return the value of the `theta` field.
 
-    def beta_i(self):
+        Returns:
+            CalculatorFloat: The value of the field `theta`.
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
 
-Here beta_i is defined by
+    def control_0(self) -> int:
+        """
+        Return the control_0 qubit of the four-qubit operation.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            int: The control_0 qubit of the operation.
+        """
 
+    def control_1(self) -> int:
+        """
+        Return the control_1 qubit of the four-qubit operation.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            int: The control_1 qubit of the operation.
+        """
 
-    def mul(self) -> Operation:
+    def control_2(self) -> int:
         """
-        Multiplies two compatible operations implementing OperateSingleQubitGate.
+        Return the control_2 qubit of the four-qubit operation.
 
-        Does not consume the two operations being multiplied.
-        Only Operations
+        Returns:
+            int: The control_2 qubit of the operation.
+        """
 
-        Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+    def target(self) -> int:
+        """
+        Return the target qubit of the four-qubit operation.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
-
-        Example:
-        ```
-        from qoqo.operations import RotateZ, RotateX
+            int: The target qubit of the operation.
+        """
 
-        gate1 =  RotateZ(qubit=0, theta=1)
-        gate2 = RotateX(qubit=0, theta=1)
-        multiplied = gate1.mul(gate2)
-        print("Multiplied gate: ", multiplied)
-        ```
+    def circuit(self) -> Circuit:
+        """
+        Return the circuit implementing the FourQubitGateOperation.
 
+        Returns:
+            Circuit: The circuit implementing the gate.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -15607,223 +13913,227 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class SXGate(Operation):
-    """
-The SX gate.
-
-.. math::
-    U = \frac{1}{\sqrt(2)} \begin{pmatrix}
-        1+i & 1-i \\\\
-        1-i & 1+i
-        \end{pmatrix}
-
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
-
-"""
-
-    def __init__(self, qubit: int):
-        return
-
-    def is_parametrized(self) -> bool:
-        """
-        Returns true if operation contains symbolic parameters
+class ControlledSWAP(Operation):
+    r"""
+    Implements ControlledSWAP gate.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & 1
+            \end{pmatrix}
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
-        """
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the controlling qubit of the operation.
+        target_0 (int): The index of the second most significant qubit in the unitary representation. Here, the first targeting qubit of the operation.
+        target_1 (int): The index of the least significant qubit in the unitary representation. Here, the second targeting qubit of the operation.
+    """
 
-    def tags(self) -> List[str]:
+    def __init__(self, control: int, target_0: int, target_1: int) -> None:
         """
-        Returns tags identifying the Operation
-
-        Returns:
-            List[str]: The tags identifying the operation
+        Creates new instance of Operation ControlledSWAP
         """
 
-    def hqslang(self) -> str:
+    def circuit(self) -> Circuit:
         """
-        Returns hqslang name of Operation
+        Return the circuit implementing the ThreeQubitGateOperation.
 
         Returns:
-            str: The name
+            Circuit: The circuit implementing the gate.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def circuit_with_basis(self, basis: str) -> Circuit:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
 
         Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            Operation: The operation with the parameters substituted
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: The basis is not supported.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def current_version() -> str:
         """
-        List all involved Qubits
+        Return the current version of the qoqo library.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The current version of the library.
         """
 
-    def qubit(self):
+    def min_supported_version(self) -> str:
         """
-        Return the qubit the operation acts on
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            int
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def global_phase(self):
+    @staticmethod
+    def json_schema() -> str:
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
+        Return the JsonSchema for the json serialisation of the class.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            str: The json schema serialized to json.
+        """
 
-Returns:
-    CalculatorFloat
-"""
+    def control(self) -> int:
+        """
+        Returns control qubit of the three-qubit operation
+        """
 
-    def alpha_r(self):
+    def target_0(self) -> int:
+        """
+        Returns target_0 qubit of the three-qubit operation
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+    def target_1(self) -> int:
+        """
+        Returns target_1 qubit of the three-qubit operation
+        """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+class PhaseShiftedControlledControlledZ(Operation):
+    r"""
+    The phased-shifted double-controlled-Z gate.
+
+    The unitary matrix representation is:
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & e^{i \phi} & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & e^{i (3\cdot\phi + \pi)}
+            \end{pmatrix}
 
-Returns:
-    CalculatorFloat
-"""
+    Args:
+        control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first qubit that controls the application of the phase-shift on the target qubit.
+        control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second qubit that controls the application of the phase-shift on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+        phi (CalculatorFloat): The single qubit phase $\phi$.
+    """
 
-    def alpha_i(self):
-        """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
+    def __init__(self, control_0: int, control_1: int, target: int, phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PhaseShiftedControlledControlledZ.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Args:
+            control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first qubit that controls the application of the phase-shift on the target qubit.
+            control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second qubit that controls the application of the phase-shift on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+            phi (CalculatorFloat): The single qubit phase $\phi$.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            PhaseShiftedControlledControlledZ: The new operation.
 
-    def beta_r(self):
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
 
-Here beta_r is defined by
+    def phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `phi` field.
+
+        Returns:
+            CalculatorFloat: The single qubit phase $\phi$.
+        """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def control_0(self) -> int:
+        """
+        Return the control_0 qubit of the three-qubit operation.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            int: The control_0 qubit of the operation.
+        """
 
-    def beta_i(self):
+    def control_1(self) -> int:
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
+        Return the control_1 qubit of the three-qubit operation.
 
-Here beta_i is defined by
+        Returns:
+            int: The control_1 qubit of the operation.
+        """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    def target(self) -> int:
+        """
+        Return the target qubit of the three-qubit operation.
 
+        Returns:
+            int: The target qubit of the operation.
+        """
 
-Returns:
-    CalculatorFloat
-"""
+    def circuit(self) -> Circuit:
+        """
+        Return the circuit implementing the ThreeQubitGateOperation.
 
-    def mul(self) -> Operation:
+        Returns:
+            Circuit: The circuit implementing the gate.
         """
-        Multiplies two compatible operations implementing OperateSingleQubitGate.
 
-        Does not consume the two operations being multiplied.
-        Only Operations
+    def circuit_with_basis(self, basis: str) -> Circuit:
+        """
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
-
-        Example:
-        ```
-        from qoqo.operations import RotateZ, RotateX
-
-        gate1 =  RotateZ(qubit=0, theta=1)
-        gate2 = RotateX(qubit=0, theta=1)
-        multiplied = gate1.mul(gate2)
-        print("Multiplied gate: ", multiplied)
-        ```
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
 
+        Raises:
+            ValueError: The basis is not supported.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -15837,223 +14147,300 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class InvSXGate(Operation):
+class PhaseShiftedControlledControlledPhase(Operation):
+    r"""
+    The phased-shifted double-controlled-Z gate.
+
+    The unitary matrix representation is:
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & e^{i \phi} & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & e^{i (3\cdot\phi + \theta)}
+            \end{pmatrix}
+
+    Args:
+        control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first qubit that controls the application of the phase-shift on the target qubit.
+        control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second qubit that controls the application of the phase-shift on the target qubit.
+        target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+        phi (CalculatorFloat): The single qubit phase $\phi$.
+        theta (CalculatorFloat): The phase rotation $\theta$.
     """
-The InvSX gate.
 
-.. math::
-    U = \frac{1}{\sqrt(2)} \begin{pmatrix}
-        1-i & 1+i \\\\
-        1+i & 1-i
-        \end{pmatrix}
+    def __init__(self, control_0: int, control_1: int, target: int, theta: Union[CalculatorFloat, float, str], phi: Union[CalculatorFloat, float, str]) -> None:
+        r"""
+        Create a new PhaseShiftedControlledControlledPhase.
+
+        Args:
+            control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first qubit that controls the application of the phase-shift on the target qubit.
+            control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second qubit that controls the application of the phase-shift on the target qubit.
+            target (int): : The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
+            theta (CalculatorFloat): The phase rotation $\theta$.
+            phi (CalculatorFloat): The single qubit phase $\phi$.
 
-Args:
-    qubit (int): The qubit the unitary gate is applied to.
+        Returns:
+            PhaseShiftedControlledControlledPhase: The new operation.
 
-"""
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
+        """
 
-    def __init__(self, qubit: int):
-        return
+    def theta(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `theta` field.
 
-    def is_parametrized(self) -> bool:
+        Returns:
+            CalculatorFloat: The phase rotation $\theta$.
         """
-        Returns true if operation contains symbolic parameters
+
+    def phi(self) -> CalculatorFloat:
+        r"""
+        Return the value of the `phi` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            CalculatorFloat: The single qubit phase $\phi$.
         """
 
-    def tags(self) -> List[str]:
+    def powercf(self, power: CalculatorFloat) -> PhaseShiftedControlledControlledPhase:
         """
-        Returns tags identifying the Operation
+        Return the rotation gate raised to a power.
+
+        Args:
+            power (CalculatorFloat): The exponent of the power operation.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Self: The gate raised to the power of `power`.
         """
 
-    def hqslang(self) -> str:
+    def overrotate(self, amplitude: float, variance: float) -> PhaseShiftedControlledControlledPhase:
         """
-        Returns hqslang name of Operation
+        Return a clone of the gate with one parameter statistically overrotated.
+
+        Args:
+            amplitude (float): The amplitude of the overrotation.
+            variance (float): The variance of the overrotation.
 
         Returns:
-            str: The name
+            Self: The overrotated gate.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def control_0(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the control_0 qubit of the three-qubit operation.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            int: The control_0 qubit of the operation.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def control_1(self) -> int:
+        """
+        Return the control_1 qubit of the three-qubit operation.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            int: The control_1 qubit of the operation.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def target(self) -> int:
         """
+        Return the target qubit of the three-qubit operation.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            int: The target qubit of the operation.
+        """
+
+    def circuit(self) -> Circuit:
+        """
+        Return the circuit implementing the ThreeQubitGateOperation.
+
+        Returns:
+            Circuit: The circuit implementing the gate.
         """
-        Remap qubits
+
+    def circuit_with_basis(self, basis: str) -> Circuit:
+        """
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            Operation: The operation with the remapped qubits
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            ValueError: The basis is not supported.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        List all involved Qubits
+        Return the unitary matrix of the gate.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            np.ndarray: The unitary matrix of the gate.
+
+        Raises:
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def qubit(self):
+    @staticmethod
+    def current_version() -> str:
         """
-        Return the qubit the operation acts on
+        Return the current version of the qoqo library.
 
         Returns:
-            int
+            str: The current version of the library.
         """
 
-    def global_phase(self):
+    def min_supported_version(self) -> str:
         """
-Return the global phase :math:`g` of a unitary gate acting on one qubit
-
-Here global_phase is defined by
+        Return the minimum version of qoqo that supports this object.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-Returns:
-    CalculatorFloat
-"""
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def alpha_r(self):
+        Returns:
+            str: The json schema serialized to json.
         """
-Return the property alpha_r :math:`\alpha_r` of a unitary gate acting on one qubit
 
-Here alpha_r is defined by
+class PragmaSimulationRepetitions(Operation):
+    """
+    Wrap function automatically generates functions in these traits.
+    This PRAGMA sets the number of repetitions for stochastic simulations of the quantum circuit.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    This is different from the number of measurements, which is set either with
+    PragmaSetNumberOfMeasurements of with PragmaRepeatedMeasurement. PragmaSimulationRepetitions
+    only applies to stochastic simulations, i.e. simulations of quantum circuits that involve either
+    multiple subsequent measurements on the same qubits, or operations on qubits that have already
+    been measured, and sets the number of times that the whole circuit is simulated in order to obtain
+    sufficient statistics.
 
-Returns:
-    CalculatorFloat
-"""
+    Args:
+        repetitions (int): Number of simulation repetitions.
+    """
 
-    def alpha_i(self):
+    def __init__(self, repetitions: int) -> None:
         """
-Return the property alpha_i :math:`\alpha_i` of a unitary gate acting on one qubit
+        Create a new PragmaSimulationRepetitions.
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+        Args:
+            repetitions (int): Number of simulation repetitions.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            PragmaSimulationRepetitions: The new operation.
+        """
 
-    def beta_r(self):
+    def repetitions(self) -> int:
         """
-Return the property beta_r :math:`\beta_r` of a unitary gate acting on one qubit
+        Return the value of the `repetitions` field.
 
-Here beta_r is defined by
+        Returns:
+            int: Number of simulation repetitions.
+        """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
-Returns:
-    CalculatorFloat
-"""
+        Returns:
+            str: The current version of the library.
+        """
 
-    def beta_i(self):
+    def min_supported_version(self) -> str:
         """
-Returns the property beta_i :math:`\beta_i` of a unitary gate acting on one qubit
+        Return the minimum version of qoqo that supports this object.
 
-Here beta_i is defined by
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-.. math::
-    U =e^{i \cdot g}\begin{pmatrix}
-    \alpha_r+i \alpha_i & -\beta_r+i \beta_i \\\\
-    \beta_r+i \beta_i & \alpha_r-i\alpha_i
-    \end{pmatrix}
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
+        Returns:
+            str: The json schema serialized to json.
+        """
 
-Returns:
-    CalculatorFloat
-"""
+class PragmaGetOperatorExpectation(Operation):
+    """
+    This PRAGMA measurement operation returns the expectation value of an operator.
 
-    def mul(self) -> Operation:
-        """
-        Multiplies two compatible operations implementing OperateSingleQubitGate.
+    The measurement preparation circuit is applied on a copy of the quantum register,
+    so that the actual quantum register remains unchanged.
+    The expectation value is written into the first entry of the float readout register.
 
-        Does not consume the two operations being multiplied.
-        Only Operations
+    Args:
+        operator (SpinHamiltonianSystem): The operator whose expectation value is measured (struqture 1.x or 2.x).
+        readout (string): The name of the classical float readout register.
+        circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
+    """
+
+    def __init__(self, operator: SpinHamiltonianSystem, readout: str, circuit: Circuit) -> None:
+        """
+        Create a new PragmaGetOperatorExpectation.
 
         Args:
-            `other` - An Operation implementing [OperateSingleQubitGate].
+            operator (SpinHamiltonianSystem): The operator whose expectation value is measured (struqture 1.x or 2.x).
+            readout (string): The name of the classical float readout register.
+            circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
 
         Returns:
-            Operation: Result of the multiplication, i.e. the multiplied single qubit gate.
-
-        Example:
-        ```
-        from qoqo.operations import RotateZ, RotateX
-
-        gate1 =  RotateZ(qubit=0, theta=1)
-        gate2 = RotateX(qubit=0, theta=1)
-        multiplied = gate1.mul(gate2)
-        print("Multiplied gate: ", multiplied)
-        ```
+            PragmaGetOperatorExpectation: The new operation.
 
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def operator(self) -> SpinHamiltonianSystem:
         """
-        Return unitary matrix of gate.
+        Return the value of the `operator` field.
 
         Returns:
-            np.ndarray: matrix of gate.
+            SpinHamiltonianSystem: The operator whose expectation value is measured (struqture 1.x or 2.x).
+        """
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+    def readout(self) -> str:
         """
+        Return the value of the `readout` field.
 
-    def current_version(self) -> str:
+        Returns:
+            str: The name of the classical float readout register.
         """
-        Returns the current version of the qoqo library .
+
+    def circuit(self) -> Circuit:
+        """
+        Return the value of the `circuit` field.
+
+        Returns:
+            Circuit: The measurement preparation Circuit, applied on a copy of the register before measurement.
+        """
+
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -16067,144 +14454,222 @@ Returns:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class TripleControlledPauliX(Operation):
+class PragmaSimulateShotNoise(Operation):
     """
-    The triple-controlled PauliX gate.
+    This PRAGMA operation instructs simulation backends to emulate the shot noise of a finite number of measurements.
 
+    Instead of writing exact values, simulation backends sample `number_shots` measurement records
+    of the bit register `readout` from the exact probability distribution of the quantum register.
+    Setting the seed makes the sampled records reproducible.
+
+    Args:
+        readout (str): The name of the classical bit register the sampled measurement records are written to.
+        number_shots (int): The number of sampled measurement records.
+        seed (Optional[int]): The seed of the random number generator, the sampling is not reproducible when None.
     """
 
-    def __init__(self):
-        return
+    def __init__(self, readout: str, number_shots: int, seed: Optional[int]) -> None:
+        """
+        Create a new PragmaSimulateShotNoise.
 
-    def is_parametrized(self) -> bool:
+        Args:
+            readout (str): The name of the classical bit register the sampled measurement records are written to.
+            number_shots (int): The number of sampled measurement records.
+            seed (Optional[int]): The seed of the random number generator, the sampling is not reproducible when None.
+
+        Returns:
+            PragmaSimulateShotNoise: The new operation.
         """
-        Returns true if operation contains symbolic parameters
+
+    def readout(self) -> str:
+        """
+        Return the value of the `readout` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The name of the classical bit register the sampled measurement records are written to.
         """
 
-    def tags(self) -> List[str]:
+    def number_shots(self) -> int:
         """
-        Returns tags identifying the Operation
+        Return the value of the `number_shots` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            int: The number of sampled measurement records.
         """
 
-    def hqslang(self) -> str:
+    def seed(self) -> Optional[int]:
         """
-        Returns hqslang name of Operation
+        Return the value of the `seed` field.
 
         Returns:
-            str: The name
+            Optional[int]: The seed of the random number generator, the sampling is not reproducible when None.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    @staticmethod
+    def current_version() -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the current version of the qoqo library.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The current version of the library.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    @staticmethod
+    def json_schema() -> str:
         """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            str: The json schema serialized to json.
         """
-        Remap qubits
+
+class PragmaSetGateTime(Operation):
+    """
+    This PRAGMA operation sets the gate time of a gate on specific qubits for the device of a circuit.
+
+    The PRAGMA does not act on the quantum state. It is applied to a device by wrapping it
+    in a PragmaChangeDevice, for example to change the gate times of a GenericDevice.
+
+    Args:
+        gate_hqslang (str): The hqslang name of the gate.
+        qubits (List[int]): The qubits the gate acts on, in the order of the qubit arguments of the gate.
+        gate_time (CalculatorFloat): The gate time of the gate on the qubits.
+    """
+
+    def __init__(self, gate_hqslang: str, qubits: List[int], gate_time: Union[CalculatorFloat, float, str]) -> None:
+        """
+        Create a new PragmaSetGateTime.
 
         Args:
-            mapping (Dict[int, int]): The mapping
+            gate_hqslang (str): The hqslang name of the gate.
+            qubits (List[int]): The qubits the gate acts on, in the order of the qubit arguments of the gate.
+            gate_time (CalculatorFloat): The gate time of the gate on the qubits.
 
         Returns:
-            Operation: The operation with the remapped qubits
+            PragmaSetGateTime: The new operation.
 
         Raises:
-            RuntimeError: Qubit remapping failed
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def gate_hqslang(self) -> str:
         """
-        List all involved Qubits
+        Return the value of the `gate_hqslang` field.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The hqslang name of the gate.
         """
 
-    def control_0(self):
+    def gate_time(self) -> CalculatorFloat:
         """
-        Returns control_0 qubit of the four-qubit operation
+        Return the value of the `gate_time` field.
+
+        Returns:
+            CalculatorFloat: The gate time of the gate on the qubits.
         """
 
-    def control_1(self):
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns control_1 qubit of the four-qubit operation
+        Return the current version of the qoqo library.
+
+        Returns:
+            str: The current version of the library.
         """
 
-    def control_2(self):
+    def min_supported_version(self) -> str:
         """
-        Returns control_2 qubit of the four-qubit operation
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def target(self):
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns target qubit of the four-qubit operation
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json.
         """
 
-    def circuit(self):
+    def qubits(self) -> List[int]:
         """
-        Returns circuit implementing the FourQubitGateOperation
+        Return the qubits the gate acts on.
 
         Returns:
-            Circuit
+            List[int]: The qubits in the order of the qubit arguments of the gate.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+class PragmaParallelBlockTiming(Operation):
+    """
+    This PRAGMA closes a parallel block with individual durations of the qubits.
+
+    It is an alternative to PragmaStopParallelBlock for blocks in which the qubits are busy for
+    different times. The PRAGMA does not act on the quantum state. Noise models can use the durations
+    to insert the decoherence of the idling qubits, see `idle_noise_circuit`.
+
+    Args:
+        qubit_durations (Dict[int, CalculatorFloat]): The durations (in seconds) of the parallel block for each involved qubit.
+    """
+
+    def __init__(self, qubit_durations: Dict[int, CalculatorFloat]) -> None:
         """
-        Return unitary matrix of gate.
+        Create a PragmaParallelBlockTiming.
+
+        Args:
+            qubit_durations (Dict[int, CalculatorFloat]): The durations (in seconds) of the parallel block for each involved qubit.
 
         Returns:
-            np.ndarray: matrix of gate.
+            self: The new PragmaParallelBlockTiming.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            TypeError: A duration cannot be converted to CalculatorFloat.
         """
 
-    def current_version(self) -> str:
+    def qubit_durations(self) -> Dict[int, CalculatorFloat]:
         """
-        Returns the current version of the qoqo library .
+        Return the durations of the parallel block for each involved qubit.
 
         Returns:
-            str: The current version of the library.
+            Dict[int, CalculatorFloat]: The durations (in seconds) by qubit.
         """
 
-    def min_supported_version(self) -> str:
+    def idle_noise_circuit(self, model: ContinuousDecoherenceModel) -> Circuit:
         """
-        Return the minimum version of qoqo that supports this object.
+        Create the circuit applying the idle decoherence of a noise model for the durations of the qubits.
+
+        Args:
+            model (ContinuousDecoherenceModel): The noise model containing the decoherence rates.
 
         Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
+            Circuit: The circuit containing one PragmaGeneralNoise per noisy qubit, ordered by qubit.
+
+        Raises:
+            TypeError: Input cannot be converted to ContinuousDecoherenceModel.
+            ValueError: The model contains noise that cannot be applied with single qubit PRAGMAs.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
@@ -16212,122 +14677,190 @@ class TripleControlledPauliX(Operation):
             str: The json schema serialized to json
         """
 
-class TripleControlledPauliZ(Operation):
-    """
-    The triple-controlled PauliZ gate.
-
-    """
+    @staticmethod
+    def current_version() -> str:
+        """
+        Returns the current version of the qoqo library .
 
-    def __init__(self):
-        return
+        Returns:
+            str: The current version of the library.
+        """
 
-    def is_parametrized(self) -> bool:
+    def min_supported_version(self) -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def tags(self) -> List[str]:
+class PragmaConditionalLoop(Operation):
+    """
+    This PRAGMA repeats a circuit while a classical condition bit is true.
+
+    The circuit is executed once and then repeated as long as the condition bit is true after
+    the execution, at most max_repetitions times in total.
+    A repeat-until-success protocol writes a failure flag to the condition bit in the circuit.
+
+    Args:
+        condition_register (str): The name of the bit register containting the condition bool value.
+        condition_index (int): The index in the bit register containting the condition bool value.
+        max_repetitions (int): The maximal number of executions of the circuit.
+        circuit (Circuit): The circuit that is repeated.
+    """
+
+    def __init__(self, condition_register: str, condition_index: int, max_repetitions: int, circuit: Circuit) -> None:
         """
-        Returns tags identifying the Operation
+        Create a new PragmaConditionalLoop.
+
+        Args:
+            condition_register (str): The name of the bit register containting the condition bool value.
+            condition_index (int): The index in the bit register containting the condition bool value.
+            max_repetitions (int): The maximal number of executions of the circuit.
+            circuit (Circuit): The circuit that is repeated.
 
         Returns:
-            List[str]: The tags identifying the operation
+            PragmaConditionalLoop: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def hqslang(self) -> str:
+    def condition_register(self) -> str:
         """
-        Returns hqslang name of Operation
+        Return the value of the `condition_register` field.
 
         Returns:
-            str: The name
+            str: The name of the bit register containting the condition bool value.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def condition_index(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the value of the `condition_index` field.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            int: The index in the bit register containting the condition bool value.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def max_repetitions(self) -> int:
+        """
+        Return the value of the `max_repetitions` field.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            int: The maximal number of executions of the circuit.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def circuit(self) -> Circuit:
         """
+        Return the value of the `circuit` field.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            Circuit: The circuit that is repeated.
         """
-        Remap qubits
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+    @staticmethod
+    def current_version() -> str:
+        """
+        Return the current version of the qoqo library.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            str: The current version of the library.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def min_supported_version(self) -> str:
         """
-        List all involved Qubits
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def control_0(self):
+    @staticmethod
+    def json_schema() -> str:
         """
-        Returns control_0 qubit of the four-qubit operation
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json.
         """
 
-    def control_1(self):
+    def unroll(self) -> Circuit:
         """
-        Returns control_1 qubit of the four-qubit operation
+        Lower the PRAGMA to nested PragmaConditional operations.
+
+        The circuit is executed once unconditionally, every further repetition is wrapped in a
+        PragmaConditional on the condition bit inside the previous one.
+
+        Returns:
+            Circuit: The unrolled circuit.
         """
 
-    def control_2(self):
+class Fredkin(Operation):
+    r"""
+    Implements the Fredkin gate.
+
+    The Fredkin gate is the textbook name of the ControlledSWAP gate and has the same unitary matrix.
+
+    .. math::
+        U = \begin{pmatrix}
+            1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
+            0 & 0 & 0 & 0 & 0 & 0 & 0 & 1
+            \end{pmatrix}
+
+    Args:
+        control (int): The index of the most significant qubit in the unitary representation. Here, the controlling qubit of the operation.
+        target_0 (int): The index of the second most significant qubit in the unitary representation. Here, the first targeting qubit of the operation.
+        target_1 (int): The index of the least significant qubit in the unitary representation. Here, the second targeting qubit of the operation.
+    """
+
+    def __init__(self, control: int, target_0: int, target_1: int) -> None:
         """
-        Returns control_2 qubit of the four-qubit operation
+        Creates new instance of Operation Fredkin
         """
 
-    def target(self):
+    def circuit(self) -> Circuit:
         """
-        Returns target qubit of the four-qubit operation
+        Return the circuit implementing the ThreeQubitGateOperation.
+
+        Returns:
+            Circuit: The circuit implementing the gate.
         """
 
-    def circuit(self):
+    def circuit_with_basis(self, basis: str) -> Circuit:
         """
-        Returns circuit implementing the FourQubitGateOperation
+        Return the circuit implementing the ThreeQubitGateOperation with the entangling gates of a basis.
+
+        Args:
+            basis (str): The entangling gate of the decomposition, one of "CNOT", "CZ" and "ISwap".
 
         Returns:
-            Circuit
+            Circuit: The circuit implementing the gate with the entangling gates of the basis.
+
+        Raises:
+            ValueError: The basis is not supported.
         """
 
     def unitary_matrix(self) -> numpy.ndarray:
         """
-        Return unitary matrix of gate.
+        Return the unitary matrix of the gate.
 
         Returns:
-            np.ndarray: matrix of gate.
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -16341,135 +14874,161 @@ class TripleControlledPauliZ(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class TripleControlledPhaseShift(Operation):
-    """
-    The triple-controlled PhaseShift gate.
-
-    """
+    def control(self) -> int:
+        """
+        Returns control qubit of the three-qubit operation
+        """
 
-    def __init__(self):
-        return
+    def target_0(self) -> int:
+        """
+        Returns target_0 qubit of the three-qubit operation
+        """
 
-    def theta(self):
+    def target_1(self) -> int:
         """
-        Returns value of attribute theta
+        Returns target_1 qubit of the three-qubit operation
         """
 
-    def is_parametrized(self) -> bool:
+class MultiQubitMSWithCouplings(Operation):
+    """
+    The Molmer-Sorensen gate between multiple qubits with individual coupling strengths for each pair of qubits.
+
+    The gate applies the rotation under the sum of products of Pauli X operators on all pairs of qubits.
+    In mathematical terms the gate applies exp(-i * sum_{j<k} theta_jk/2 * X_ij * X_ik).
+
+    Args:
+        qubits (List[int]): The qubits involved in the multi qubit Molmer-Sorensen gate.
+        couplings (List[CalculatorFloat]): The coupling strengths of all n(n-1)/2 pairs of the n qubits,
+                                           ordered by pairs (0, 1), (0, 2), ..., (n-2, n-1) of the positions in qubits.
+    """
+
+    def __init__(self, qubits: List[int], couplings: List[CalculatorFloat]) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a new MultiQubitMSWithCouplings.
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+        Args:
+            qubits (List[int]): The qubits involved in the multi qubit Molmer-Sorensen gate.
+            couplings (List[CalculatorFloat]): The coupling strengths of all n(n-1)/2 pairs of the n qubits.
+
+        Raises:
+            TypeError: A coupling cannot be converted to CalculatorFloat.
+            ValueError: The number of couplings does not match the number of pairs of qubits.
         """
 
-    def tags(self) -> List[str]:
+    def qubits(self) -> List[int]:
         """
-        Returns tags identifying the Operation
+        Return the list of qubits of the multi qubit operation in order of descending significance.
 
         Returns:
-            List[str]: The tags identifying the operation
+            List[int]: The qubits the operation acts on.
         """
 
-    def hqslang(self) -> str:
+    def couplings(self) -> List[CalculatorFloat]:
         """
-        Returns hqslang name of Operation
+        Return the coupling strengths of all pairs of qubits.
 
         Returns:
-            str: The name
+            List[CalculatorFloat]: The couplings ordered by pairs (0, 1), (0, 2), ..., (n-2, n-1).
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def unitary_matrix(self) -> numpy.ndarray:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def circuit(self) -> Circuit:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the circuit implementing the MultiQubitGateOperation.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Circuit: The circuit implementing the gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def json_schema() -> str:
         """
-        List all involved Qubits
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The json schema serialized to json
         """
 
-    def control_0(self):
-        """
-        Returns control_0 qubit of the four-qubit operation
+    @staticmethod
+    def current_version() -> str:
         """
+        Returns the current version of the qoqo library .
 
-    def control_1(self):
-        """
-        Returns control_1 qubit of the four-qubit operation
+        Returns:
+            str: The current version of the library.
         """
 
-    def control_2(self):
+    def min_supported_version(self) -> str:
         """
-        Returns control_2 qubit of the four-qubit operation
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def target(self):
+class PragmaSnapshotRegisters(Operation):
+    """
+    This PRAGMA operation takes a snapshot of classical registers for debugging.
+
+    Evaluating backends that encounter the PRAGMA copy the current content of each register in
+    readout_names into an output register of the same type named "{label}_{name}".
+
+    Args:
+        readout_names (List[str]): The names of the classical registers that are copied.
+        label (str): The label prefixed to the names of the snapshot registers.
+    """
+
+    def __init__(self, readout_names: List[str], label: str) -> None:
         """
-        Returns target qubit of the four-qubit operation
+        Create a new PragmaSnapshotRegisters.
+
+        Args:
+            readout_names (List[str]): The names of the classical registers that are copied.
+            label (str): The label prefixed to the names of the snapshot registers.
+
+        Returns:
+            PragmaSnapshotRegisters: The new operation.
         """
 
-    def circuit(self):
+    def readout_names(self) -> List[str]:
         """
-        Returns circuit implementing the FourQubitGateOperation
+        Return the value of the `readout_names` field.
 
         Returns:
-            Circuit
+            List[str]: The names of the classical registers that are copied.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def label(self) -> str:
         """
-        Return unitary matrix of gate.
+        Return the value of the `label` field.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            str: The label prefixed to the names of the snapshot registers.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -16483,141 +15042,163 @@ class TripleControlledPhaseShift(Operation):
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class ControlledSWAP(Operation):
+    def snapshot_name(self, readout_name: str) -> str:
+        """
+        Return the name of the snapshot register of a copied register.
+
+        Args:
+            readout_name (str): The name of the copied register.
+
+        Returns:
+            str: The name "{label}_{readout_name}".
+        """
+
+class NegativeControl(Operation):
     """
-Implements ControlledSWAP gate.
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 0 & 1
-        \end{pmatrix}
+    Negative control (anti-control) version of a two-qubit gate.
 
-Args:
-    control (int): The index of the most significant qubit in the unitary representation. Here, the controlling qubit of the operation.
-    target_0 (int): The index of the second most significant qubit in the unitary representation. Here, the first targeting qubit of the operation.
-    target_1 (int): The index of the least significant qubit in the unitary representation. Here, the second targeting qubit of the operation.
-"""
+    Applies the inner gate when the control qubit is in state |0> instead of |1>.
+    The unitary matrix is the unitary matrix of the inner gate conjugated with PauliX on the control qubit.
 
-    def __init__(self, control: int, target_0: int, target_1: int):
-        return
+    Args:
+        inner (Operation): The two-qubit gate applied when the control qubit is in state |0>.
+    """
 
-    def is_parametrized(self) -> bool:
+    def __init__(self, inner: Operation) -> None:
         """
-        Returns true if operation contains symbolic parameters
+        Create a NegativeControl instance.
 
-        Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+        Args:
+            inner (Operation): The two-qubit gate applied when the control qubit is in state |0>.
+
+        Raises:
+            TypeError: Input cannot be converted to a two-qubit gate operation.
         """
 
-    def tags(self) -> List[str]:
+    def inner(self) -> Operation:
         """
-        Returns tags identifying the Operation
+        Return the inner two-qubit gate.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Operation: The two-qubit gate applied when the control qubit is in state |0>.
         """
 
-    def hqslang(self) -> str:
+    def control(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the control qubit of the two-qubit operation.
 
         Returns:
-            str: The name
+            int: The control qubit of the operation.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def target(self) -> int:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the target qubit of the two-qubit operation.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            int: The target qubit of the operation.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    def unitary_matrix(self) -> numpy.ndarray:
+        """
+        Return the unitary matrix of the gate.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            np.ndarray: The unitary matrix of the gate.
 
         Raises:
-            RuntimeError: Parameter Substitution failed
+            ValueError: Error symbolic operation cannot return float unitary matrix.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    def circuit(self) -> Circuit:
         """
-        Remap qubits in the ControlledSWAP operation
+        Return the circuit implementing the gate with positive control.
 
-        Args:
-            mapping (Dict[int, int]): The mapping to be used in the remapping.
+        The inner gate is applied between two PauliX gates on the control qubit.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            Circuit: The circuit implementing the gate.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    @staticmethod
+    def json_schema() -> str:
         """
-        List all involved qubits in the ControlledSWAP operation.
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The json schema serialized to json
         """
 
-    def control(self):
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns control qubit of the three-qubit operation
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
         """
 
-    def target_0(self):
+    def min_supported_version(self) -> str:
         """
-        Returns target_0 qubit of the three-qubit operation
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def target_1(self):
+class PragmaAnnotation(Operation):
+    """
+    This PRAGMA operation annotates the circuit with a key-value pair of metadata.
+
+    The annotation records provenance information like the compiler version or the applied passes
+    and survives serialization. It has no effect on the quantum state and involves no qubits.
+
+    Args:
+        key (str): The key of the annotation.
+        value (str): The value of the annotation.
+    """
+
+    def __init__(self, key: str, value: str) -> None:
         """
-        Returns target_1 qubit of the three-qubit operation
+        Create a new PragmaAnnotation.
+
+        Args:
+            key (str): The key of the annotation.
+            value (str): The value of the annotation.
+
+        Returns:
+            PragmaAnnotation: The new operation.
         """
 
-    def circuit(self):
+    def key(self) -> str:
         """
-        Returns circuit implementing the ThreeQubitGateOperation
+        Return the value of the `key` field.
 
         Returns:
-            Circuit
+            str: The key of the annotation.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def value(self) -> str:
         """
-        Return unitary matrix of gate.
+        Return the value of the `value` field.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            str: The value of the annotation.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -16631,152 +15212,151 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PhaseShiftedControlledControlledZ(Operation):
+class PragmaGetReducedDensityMatrix(Operation):
     """
-The phased-shifted double-controlled-Z gate.
-
-The unitary matrix representation is:
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & e^{i \phi} & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 0 & e^{i (3\cdot\phi + \pi)}
-        \end{pmatrix}
-
-Args:
-    control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first qubit that controls the application of the phase-shift on the target qubit.
-    control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second qubit that controls the application of the phase-shift on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
-    phi (CalculatorFloat): The single qubit phase $\phi$.
+    This PRAGMA measurement operation returns the reduced density matrix of a subset of qubits.
 
-"""
+    All other qubits are traced out. The reduced density matrix of k qubits is written into the readout register
+    as a flattened 2^k x 2^k matrix in row-major order, with qubits[0] corresponding to the least significant bit of the index.
 
-    def __init__(
-        self, control_0: int, control_1: int, target: int, phi: Union[float, str]
-    ):
-        return
+    Args:
+        readout (string): The name of the classical readout register.
+        qubits (List[int]): The qubits of the returned reduced density matrix.
+        circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
+    """
 
-    def phi(self):
+    def __init__(self, readout: str, qubits: List[int], circuit: Optional[Circuit]) -> None:
         """
-        Returns value of attribute phi
+        Create a new PragmaGetReducedDensityMatrix.
+
+        Args:
+            readout (string): The name of the classical readout register.
+            qubits (List[int]): The qubits of the returned reduced density matrix.
+            circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
+
+        Returns:
+            PragmaGetReducedDensityMatrix: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def readout(self) -> str:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `readout` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            str: The name of the classical readout register.
         """
 
-    def tags(self) -> List[str]:
+    def circuit(self) -> Optional[Circuit]:
         """
-        Returns tags identifying the Operation
+        Return the value of the `circuit` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            Optional[Circuit]: The measurement preparation Circuit, applied on a copy of the register before measurement.
         """
 
-    def hqslang(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns hqslang name of Operation
+        Return the current version of the qoqo library.
 
         Returns:
-            str: The name
+            str: The current version of the library.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def min_supported_version(self) -> str:
         """
-        Substitutes internal symbolic parameters with float values
+        Return the minimum version of qoqo that supports this object.
 
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
 
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            Operation: The operation with the parameters substituted
+            str: The json schema serialized to json.
+        """
 
-        Raises:
-            RuntimeError: Parameter Substitution failed
+    def qubits(self) -> List[int]:
         """
+        Return the qubits of the returned reduced density matrix.
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        Returns:
+            List[int]: The kept qubits.
         """
-        Remap qubits
 
-        Args:
-            mapping (Dict[int, int]): The mapping
+class PragmaGetIndexedOccupationProbability(Operation):
+    """
+    This PRAGMA measurement operation returns the vector of the occupation probabilities at an offset in the readout register.
 
-        Returns:
-            Operation: The operation with the remapped qubits
+    Acts like PragmaGetOccupationProbability but the occupation probabilities are written into the readout register
+    starting at the entry `readout_index`, so several measurements can share one register.
 
-        Raises:
-            RuntimeError: Qubit remapping failed
-        """
+    Args:
+        readout (string): The name of the classical readout register.
+        readout_index (int): The index of the register entry the first occupation probability is written to.
+        circuit (Optional[Circuit]): The Circuit used to rotate the qureg.
+    """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def __init__(self, readout: str, readout_index: int, circuit: Optional[Circuit]) -> None:
         """
-        List all involved Qubits
+        Create a new PragmaGetIndexedOccupationProbability.
+
+        Args:
+            readout (string): The name of the classical readout register.
+            readout_index (int): The index of the register entry the first occupation probability is written to.
+            circuit (Optional[Circuit]): The Circuit used to rotate the qureg.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
-        """
+            PragmaGetIndexedOccupationProbability: The new operation.
 
-    def control_0(self):
-        """
-        Returns control_0 qubit of the three-qubit operation
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def control_1(self):
-        """
-        Returns control_1 qubit of the three-qubit operation
+    def readout(self) -> str:
         """
+        Return the value of the `readout` field.
 
-    def target(self):
-        """
-        Returns target qubit of the three-qubit operation
+        Returns:
+            str: The name of the classical readout register.
         """
 
-    def circuit(self):
+    def readout_index(self) -> int:
         """
-        Returns circuit implementing the ThreeQubitGateOperation
+        Return the value of the `readout_index` field.
 
         Returns:
-            Circuit
+            int: The index of the register entry the first occupation probability is written to.
         """
 
-    def unitary_matrix(self) -> numpy.ndarray:
+    def circuit(self) -> Optional[Circuit]:
         """
-        Return unitary matrix of gate.
+        Return the value of the `circuit` field.
 
         Returns:
-            np.ndarray: matrix of gate.
-
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
+            Optional[Circuit]: The Circuit used to rotate the qureg.
         """
 
-    def current_version(self) -> str:
+    @staticmethod
+    def current_version() -> str:
         """
-        Returns the current version of the qoqo library .
+        Return the current version of the qoqo library.
 
         Returns:
             str: The current version of the library.
@@ -16790,192 +15370,176 @@ Args:
             str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def json_schema(self) -> str:
+    @staticmethod
+    def json_schema() -> str:
         """
         Return the JsonSchema for the json serialisation of the class.
 
         Returns:
-            str: The json schema serialized to json
+            str: The json schema serialized to json.
         """
 
-class PhaseShiftedControlledControlledPhase(Operation):
+class PragmaGetIndexedPauliProduct(Operation):
     """
-The phased-shifted double-controlled-Z gate.
-
-The unitary matrix representation is:
-
-.. math::
-    U = \begin{pmatrix}
-        1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & e^{i \phi} & 0 & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & e^{i \phi} & 0 & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & e^{i (2\cdot\phi)} & 0 \\\\
-        0 & 0 & 0 & 0 & 0 & 0 & 0 & e^{i (3\cdot\phi + \theta)}
-        \end{pmatrix}
+    This PRAGMA measurement operation returns a Pauli product expectation value at an entry of the readout register.
 
-Args:
-    control_0 (int): The index of the most significant qubit in the unitary representation. Here, the first qubit that controls the application of the phase-shift on the target qubit.
-    control_1 (int): The index of the second most significant qubit in the unitary representation. Here, the second qubit that controls the application of the phase-shift on the target qubit.
-    target (int):: The index of the least significant qubit in the unitary representation. Here, the qubit phase-shift is applied to.
-    phi (CalculatorFloat): The single qubit phase $\phi$.
-    theta (CalculatorFloat): The phase rotation $\theta$.
-
-"""
+    Acts like PragmaGetPauliProduct but the expectation value is written into the entry `readout_index`
+    of the readout register, so several Pauli products can be measured into one register.
 
-    def __init__(
-        self,
-        control_0: int,
-        control_1: int,
-        target: int,
-        phi: Union[float, str],
-        theta: Union[float, str],
-    ):
-        return
+    Args:
+        qubit_paulis (Dict[int, int]): The dictionary of the pauli matrix to apply to each qubit in the form
+                                       {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
+        readout (string): The name of the classical readout register.
+        readout_index (int): The index of the register entry the expectation value is written to.
+        circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
+    """
 
-    def theta(self):
-        """
-        Returns value of attribute theta
+    def __init__(self, qubit_paulis: Dict[int, int], readout: str, readout_index: int, circuit: Circuit) -> None:
         """
+        Create a new PragmaGetIndexedPauliProduct.
 
-    def phi(self):
-        """
-        Returns value of attribute phi
+        Args:
+            qubit_paulis (Dict[int, int]): The dictionary of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
+            readout (string): The name of the classical readout register.
+            readout_index (int): The index of the register entry the expectation value is written to.
+            circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
+
+        Returns:
+            PragmaGetIndexedPauliProduct: The new operation.
+
+        Raises:
+            TypeError: An argument cannot be converted to the type of its field.
         """
 
-    def is_parametrized(self) -> bool:
+    def qubit_paulis(self) -> Dict[int, int]:
         """
-        Returns true if operation contains symbolic parameters
+        Return the value of the `qubit_paulis` field.
 
         Returns:
-            bool: Whether or not the operation contains symbolic parameters.
+            Dict[int, int]: The dictionary of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
         """
 
-    def tags(self) -> List[str]:
+    def readout(self) -> str:
         """
-        Returns tags identifying the Operation
+        Return the value of the `readout` field.
 
         Returns:
-            List[str]: The tags identifying the operation
+            str: The name of the classical readout register.
         """
 
-    def hqslang(self) -> str:
+    def readout_index(self) -> int:
         """
-        Returns hqslang name of Operation
+        Return the value of the `readout_index` field.
 
         Returns:
-            str: The name
+            int: The index of the register entry the expectation value is written to.
         """
 
-    def substitute_parameters(
-        self, substitution_parameters: Dict[str, float]
-    ) -> Operation:
+    def circuit(self) -> Circuit:
         """
-        Substitutes internal symbolic parameters with float values
-
-        Only available when all symbolic expressions can be evaluated to float with the
-        provided parameters.
-
-        Args:
-            substitution_parameters (Dict[str, float]): The substituted free parameters
+        Return the value of the `circuit` field.
 
         Returns:
-            Operation: The operation with the parameters substituted
-
-        Raises:
-            RuntimeError: Parameter Substitution failed
+            Circuit: The measurement preparation Circuit, applied on a copy of the register before measurement.
         """
 
-    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+    @staticmethod
+    def current_version() -> str:
         """
-        Remap qubits
-
-        Args:
-            mapping (Dict[int, int]): The mapping
+        Return the current version of the qoqo library.
 
         Returns:
-            Operation: The operation with the remapped qubits
-
-        Raises:
-            RuntimeError: Qubit remapping failed
+            str: The current version of the library.
         """
 
-    def involved_qubits(self) -> Union[Set[int], str]:
+    def min_supported_version(self) -> str:
         """
-        List all involved Qubits
+        Return the minimum version of qoqo that supports this object.
 
         Returns:
-            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+            str: The minimum version of the qoqo library to deserialize this object.
         """
 
-    def control_0(self):
-        """
-        Returns control_0 qubit of the three-qubit operation
+    @staticmethod
+    def json_schema() -> str:
         """
+        Return the JsonSchema for the json serialisation of the class.
 
-    def control_1(self):
-        """
-        Returns control_1 qubit of the three-qubit operation
+        Returns:
+            str: The json schema serialized to json.
         """
 
-    def target(self):
-        """
-        Returns target qubit of the three-qubit operation
-        """
+def to_tagged_json(operation: Operation) -> str:
+    """
+    Serialize an Operation to a json object tagged with its hqslang name.
 
-    def circuit(self):
-        """
-        Returns circuit implementing the ThreeQubitGateOperation
+    The json object contains the fields of the operation and an additional `hqslang` entry,
+    e.g. `{"hqslang": "RotateZ", "qubit": 0, "theta": 0.5}`.
 
-        Returns:
-            Circuit
-        """
+    Args:
+        operation (Operation): The operation to serialize.
 
-    def unitary_matrix(self) -> numpy.ndarray:
-        """
-        Return unitary matrix of gate.
+    Returns:
+        str: The hqslang-tagged json representation of the operation.
 
-        Returns:
-            np.ndarray: matrix of gate.
+    Raises:
+        TypeError: Input cannot be converted to Operation.
+    """
 
-        Raises:
-            ValueError: Error symbolic operation cannot return float unitary matrix
-        """
+def from_tagged_json(input: str) -> Operation:
+    """
+    Deserialize an Operation from a json object tagged with its hqslang name.
 
-    def powercf(self) -> PhaseShiftedControlledControlledPhase:
-        """
-        Returns Rotated gate raised to power
+    Inverse of `to_tagged_json`.
 
-        Args:
-            `power`(CalculatorFloat): exponent of the power operation.
+    Args:
+        input (str): The hqslang-tagged json representation of the operation.
 
-        Returns:
-            Self: gate raised to the power of `power`
+    Returns:
+        Operation: The deserialized operation.
 
-        """
+    Raises:
+        ValueError: Input cannot be deserialized to Operation.
+    """
 
-    def current_version(self) -> str:
-        """
-        Returns the current version of the qoqo library .
+def try_into_single_qubit_gate_operation(operation: Operation) -> SingleQubitGate:
+    """
+    Convert an Operation to a single-qubit gate operation.
 
-        Returns:
-            str: The current version of the library.
-        """
+    Args:
+        operation (Operation): The operation to convert.
 
-    def min_supported_version(self) -> str:
-        """
-        Return the minimum version of qoqo that supports this object.
+    Returns:
+        SingleQubitGate: The equivalent general single-qubit gate.
 
-        Returns:
-            str: The minimum version of the qoqo library to deserialize this object.
-        """
+    Raises:
+        TypeError: Input cannot be converted to Operation or is not a single-qubit gate operation.
+    """
 
-    def json_schema(self) -> str:
-        """
-        Return the JsonSchema for the json serialisation of the class.
+def try_into_two_qubit_gate_operation(operation: Operation) -> Operation:
+    """
+    Convert an Operation to a two-qubit gate operation.
 
-        Returns:
-            str: The json schema serialized to json
-        """
+    Args:
+        operation (Operation): The operation to convert.
+
+    Returns:
+        Operation: The two-qubit gate operation.
+
+    Raises:
+        TypeError: Input cannot be converted to Operation or is not a two-qubit gate operation.
+    """
+
+def try_into_gate_operation(operation: Operation) -> Operation:
+    """
+    Convert an Operation to a gate operation.
+
+    Args:
+        operation (Operation): The operation to convert.
+
+    Returns:
+        Operation: The gate operation.
+
+    Raises:
+        TypeError: Input cannot be converted to Operation or is not a gate operation.
+    """
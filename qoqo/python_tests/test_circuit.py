@@ -46,5 +46,24 @@ def test_circuit(measurement):
     assert len(circuit) > 0
 
 
+
+def test_circuit_annotations():
+    """Test annotating a circuit and comparing annotated circuits"""
+    circuit = Circuit()
+    circuit.annotate("compiler", "1.0")
+    circuit += ops.PauliX(qubit=0)
+    circuit += ops.PragmaAnnotation(key="author", value="someone")
+    assert circuit.annotations() == {"compiler": "1.0", "author": "someone"}
+
+    deserialized = Circuit.from_json(circuit.to_json())
+    assert deserialized == circuit
+    assert deserialized.annotations() == circuit.annotations()
+
+    other = Circuit()
+    other += ops.PauliX(qubit=0)
+    assert circuit != other
+    assert circuit.semantically_equal(other)
+    assert not circuit.semantically_equal(other, compare_annotations=True)
+
 if __name__ == "__main__":
     pytest.main(sys.argv)
@@ -0,0 +1,69 @@
+"""Test that qoqo submodules can be imported in any order"""
+
+# Copyright © 2019-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+#
+# Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+# in compliance with the License. You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software distributed under the License
+# is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+# or implied. See the License for the specific language governing permissions and limitations under
+# the License.
+import pytest
+import subprocess
+import sys
+
+SUBMODULES = ["operations", "measurements", "devices", "noise_models", "passes", "testing", "backends"]
+
+
+def run_in_fresh_interpreter(code: str) -> None:
+    """Run code in a new interpreter so that no qoqo module has been imported before."""
+    subprocess.run([sys.executable, "-c", code], check=True)
+
+
+@pytest.mark.parametrize("submodule", SUBMODULES)
+def test_import_submodule_before_qoqo(submodule: str):
+    run_in_fresh_interpreter(
+        "import sys\n"
+        f"import qoqo.{submodule}\n"
+        "import qoqo\n"
+        f"assert qoqo.{submodule} is sys.modules['qoqo.{submodule}']\n"
+    )
+
+
+def test_from_import_before_qoqo():
+    run_in_fresh_interpreter(
+        "from qoqo.operations import RotateZ\n"
+        "from qoqo.measurements import PauliZProduct\n"
+        "import qoqo\n"
+        "assert RotateZ is qoqo.operations.RotateZ\n"
+        "assert PauliZProduct is qoqo.measurements.PauliZProduct\n"
+    )
+
+
+@pytest.mark.parametrize("submodule", SUBMODULES)
+def test_submodule_all(submodule: str):
+    import importlib
+
+    module = importlib.import_module(f"qoqo.{submodule}")
+    for name in module.__all__:
+        assert hasattr(module, name)
+    public_classes = [
+        name for name in dir(module) if not name.startswith("_") and isinstance(getattr(module, name), type)
+    ]
+    for name in public_classes:
+        assert name in module.__all__
+
+
+def test_star_import():
+    namespace: dict = {}
+    exec("from qoqo.operations import *", namespace)
+    assert "RotateZ" in namespace
+    assert "PragmaSetStateVector" in namespace
+
+
+if __name__ == "__main__":
+    """The main if called as script."""
+    pytest.main(sys.argv)
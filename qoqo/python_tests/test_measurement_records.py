@@ -0,0 +1,82 @@
+"""Test the conversion of measurement results to records for pandas"""
+
+# Copyright © 2019-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+#
+# Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+# in compliance with the License. You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software distributed under the License
+# is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+# or implied. See the License for the specific language governing permissions and limitations under
+# the License.
+import time
+
+import pytest
+from qoqo.measurements import expectation_values_to_records, registers_to_records
+
+BIT_REGISTERS = {"ro": [[True, False], [False, False]], "a": [[True]]}
+FLOAT_REGISTERS = {"f": [[0.5, 1.5, -2.0]]}
+COMPLEX_REGISTERS = {"c": [[1.0 + 2.0j], [-1.0j]]}
+
+
+def reference_registers_to_records(bit_registers, float_registers, complex_registers):
+    records = []
+    for registers in (bit_registers, float_registers, complex_registers):
+        for name in sorted(registers):
+            for shot_index, shot in enumerate(registers[name]):
+                for position, value in enumerate(shot):
+                    records.append(
+                        {
+                            "register": name,
+                            "shot_index": shot_index,
+                            "position": position,
+                            "value": value,
+                        }
+                    )
+    return records
+
+
+def test_registers_to_records():
+    records = registers_to_records(BIT_REGISTERS, FLOAT_REGISTERS, COMPLEX_REGISTERS)
+    assert records == reference_registers_to_records(
+        BIT_REGISTERS, FLOAT_REGISTERS, COMPLEX_REGISTERS
+    )
+    with pytest.raises(TypeError):
+        registers_to_records({"ro": [["a"]]}, {}, {})
+
+
+def test_expectation_values_to_records():
+    results = [{"z": 0.5, "x": -0.25}, None, {"z": 1.0, "x": 0.0}]
+    parameter_sets = [{"theta": theta} for theta in (0.0, 0.5, 1.0)]
+    records = expectation_values_to_records(results, parameter_sets)
+    assert records == [
+        {"theta": 0.0, "sweep_index": 0, "name": "x", "value": -0.25},
+        {"theta": 0.0, "sweep_index": 0, "name": "z", "value": 0.5},
+        {"theta": 1.0, "sweep_index": 2, "name": "x", "value": 0.0},
+        {"theta": 1.0, "sweep_index": 2, "name": "z", "value": 1.0},
+    ]
+    with pytest.raises(ValueError):
+        expectation_values_to_records(results, parameter_sets[:2])
+
+
+def test_records_dataframe():
+    pandas = pytest.importorskip("pandas")
+    records = registers_to_records(BIT_REGISTERS, FLOAT_REGISTERS, COMPLEX_REGISTERS)
+    frame = pandas.DataFrame(records)
+    assert list(frame.columns) == ["register", "shot_index", "position", "value"]
+    assert len(frame) == 10
+
+
+def test_registers_to_records_size():
+    bit_registers = {"ro": [[True] * 1000 for _ in range(1000)]}
+    start = time.perf_counter()
+    records = registers_to_records(bit_registers, {}, {})
+    rust_time = time.perf_counter() - start
+    start = time.perf_counter()
+    expected = reference_registers_to_records(bit_registers, {}, {})
+    python_time = time.perf_counter() - start
+    assert len(records) == 1_000_000
+    assert records[-1] == expected[-1]
+    print(f"registers_to_records: {rust_time:.3f}s, python reference: {python_time:.3f}s")
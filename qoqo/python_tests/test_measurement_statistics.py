@@ -0,0 +1,45 @@
+"""Test the bit register statistics functions with numpy arrays"""
+
+# Copyright © 2019-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+#
+# Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+# in compliance with the License. You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software distributed under the License
+# is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+# or implied. See the License for the specific language governing permissions and limitations under
+# the License.
+import numpy as np
+import pytest
+from qoqo.measurements import statistics
+
+# Records of two qubits with p(00) = 1/2, p(11) = 1/4 and p(10) = 1/4
+REGISTER = [[False, False], [False, False], [True, True], [True, False]]
+
+
+@pytest.mark.parametrize(
+    "register",
+    [REGISTER, np.array(REGISTER), np.array(REGISTER, dtype=np.int64)],
+)
+def test_statistics(register):
+    assert statistics.bit_frequencies(register) == [0.5, 0.25]
+    assert statistics.bit_correlation(register, 0, 1) == pytest.approx(0.125)
+    assert statistics.joint_histogram(register, [0, 1]) == {
+        (False, False): 2,
+        (True, True): 1,
+        (True, False): 1,
+    }
+    # H(b1) - H(b1 | b0) = H(1/4) - 1/2 H(1/2)
+    expected = -(0.25 * np.log2(0.25) + 0.75 * np.log2(0.75)) - 0.5
+    assert statistics.mutual_information(register, 0, 1) == pytest.approx(expected)
+
+
+def test_statistics_errors():
+    with pytest.raises(ValueError):
+        statistics.bit_frequencies([])
+    with pytest.raises(ValueError):
+        statistics.bit_correlation(REGISTER, 0, 2)
+    with pytest.raises(TypeError):
+        statistics.bit_frequencies(np.zeros(3))
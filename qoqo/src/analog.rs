@@ -0,0 +1,55 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions acting on the analog operations of qoqo Circuits.
+
+use crate::struqture_bridge::spin_hamiltonian_to_python;
+use crate::CircuitWrapper;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+/// Return the effective Hamiltonian of the analog block of a circuit.
+///
+/// The effective Hamiltonian is the sum of the Hamiltonians of all ApplyConstantSpinHamiltonian
+/// operations, each weighted by its evolution time.
+/// Gate operations before the first or after the last analog operation and all other operations are ignored.
+///
+/// Args:
+///     circuit (Circuit): The circuit containing the analog block.
+///
+/// Returns:
+///     SpinHamiltonianSystem: The effective Hamiltonian, empty if the circuit contains no analog operations.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit.
+///     ValueError: A gate operation is interleaved with the analog operations or the circuit contains an ApplyTimeDependentSpinHamiltonian.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, /)")]
+pub fn effective_hamiltonian(py: Python, circuit: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let hamiltonian = roqoqo::analog::effective_hamiltonian(&circuit)
+        .map_err(|err| PyValueError::new_err(format!("Effective Hamiltonian failed: {}", err)))?;
+    spin_hamiltonian_to_python(py, &hamiltonian)
+}
+
+/// Functions acting on the analog operations of qoqo Circuits.
+///
+/// .. autosummary::
+///     :toctree: generated/
+///
+///     effective_hamiltonian
+#[pymodule]
+pub fn analog(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(effective_hamiltonian, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,251 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wrappers adding functionality to Python backends.
+
+use crate::{convert_into_circuit, CircuitWrapper, QoqoError};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use roqoqo::backends::CircuitCache;
+use roqoqo::operations::Operation;
+use roqoqo::registers::Registers;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Backend wrapper memoizing the output registers of circuits run on another backend.
+///
+/// In variational algorithms different parameter sets often produce identical circuits
+/// after symbolic parameters have been substituted.
+/// CachingBackend runs every distinct circuit only once on the wrapped backend
+/// and returns copies of the stored registers when the circuit is run again.
+/// When the cache is full the least recently used circuit is evicted.
+/// For stochastic backends repeated runs of a cached circuit return identical samples.
+///
+/// Args:
+///     backend: The backend running the circuits, needs to provide a `run_circuit` method.
+///     max_size (int): The maximum number of cached circuits. A size of zero disables caching.
+///
+/// Returns:
+///     CachingBackend: The new caching backend.
+#[pyclass(name = "CachingBackend", module = "qoqo.backends")]
+#[derive(Debug)]
+pub struct CachingBackendWrapper {
+    /// The wrapped Python backend.
+    pub backend: Py<PyAny>,
+    /// Cache of the output registers of the circuits run so far.
+    pub cache: CircuitCache,
+}
+
+#[pymethods]
+impl CachingBackendWrapper {
+    /// Create a new CachingBackend.
+    ///
+    /// Args:
+    ///     backend: The backend running the circuits, needs to provide a `run_circuit` method.
+    ///     max_size (int): The maximum number of cached circuits. A size of zero disables caching.
+    ///
+    /// Returns:
+    ///     CachingBackend: The new caching backend.
+    #[new]
+    #[pyo3(signature = (backend, max_size = 128))]
+    pub fn new(backend: Py<PyAny>, max_size: usize) -> Self {
+        Self {
+            backend,
+            cache: CircuitCache::new(max_size),
+        }
+    }
+
+    /// Return the wrapped backend.
+    ///
+    /// Returns:
+    ///     The backend running the circuits that are not in the cache.
+    pub fn backend(&self, py: Python) -> Py<PyAny> {
+        self.backend.clone_ref(py)
+    }
+
+    /// Run a circuit, using the cached registers if the circuit has been run before.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit that is run.
+    ///
+    /// Returns:
+    ///     Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]: The bit, float and complex output registers.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    ///     RuntimeError: The wrapped backend failed to run the circuit.
+    ///     ValueError: The registers returned by the wrapped backend could not be extracted.
+    pub fn run_circuit(&mut self, py: Python, circuit: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        let operations: Vec<Operation> = circuit.iter().cloned().collect();
+        Ok(self.run_operations(py, operations)?.into_py(py))
+    }
+
+    /// Run all circuits of a measurement, using the cached registers of circuits that have been run before.
+    ///
+    /// The constant circuit of the measurement is run before each circuit.
+    ///
+    /// Args:
+    ///     measurement: The measurement that is run.
+    ///
+    /// Returns:
+    ///     Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]: The bit, float and complex output registers.
+    ///
+    /// Raises:
+    ///     TypeError: The circuits of the measurement cannot be extracted.
+    ///     RuntimeError: The wrapped backend failed to run a circuit.
+    ///     ValueError: The registers returned by the wrapped backend could not be extracted.
+    pub fn run_measurement_registers(
+        &mut self,
+        py: Python,
+        measurement: &Bound<PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        Ok(measurement_registers(measurement, |operations| {
+            self.run_operations(py, operations)
+        })?
+        .into_py(py))
+    }
+
+    /// Evaluate the expectation values of a measurement, using the cached registers of circuits that have been run before.
+    ///
+    /// Args:
+    ///     measurement: The measurement that is run.
+    ///
+    /// Returns:
+    ///     Optional[Dict[str, float]]: The evaluated expectation values.
+    ///
+    /// Raises:
+    ///     TypeError: The circuits of the measurement cannot be extracted.
+    ///     RuntimeError: The wrapped backend failed to run a circuit.
+    ///     ValueError: The registers returned by the wrapped backend could not be extracted.
+    pub fn run_measurement(
+        &mut self,
+        py: Python,
+        measurement: &Bound<PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let (bit_registers, float_registers, complex_registers) =
+            measurement_registers(measurement, |operations| {
+                self.run_operations(py, operations)
+            })?;
+        measurement
+            .call_method1(
+                "evaluate",
+                (bit_registers, float_registers, complex_registers),
+            )
+            .map(|result| result.unbind())
+    }
+
+    /// Return the hit and miss counts of the cache.
+    ///
+    /// Returns:
+    ///     Dict[str, int]: The number of `hits`, `misses`, the current `size` and the `max_size` of the cache.
+    pub fn cache_statistics(&self) -> HashMap<String, usize> {
+        let statistics = self.cache.statistics();
+        HashMap::from([
+            ("hits".to_string(), statistics.hits),
+            ("misses".to_string(), statistics.misses),
+            ("size".to_string(), statistics.size),
+            ("max_size".to_string(), statistics.max_size),
+        ])
+    }
+
+    /// Remove all circuits from the cache and reset the statistics.
+    pub fn clear_cache(&mut self) {
+        self.cache.clear()
+    }
+}
+
+impl CachingBackendWrapper {
+    // Returns the registers of a circuit from the cache or runs it on the wrapped backend.
+    fn run_operations(&mut self, py: Python, operations: Vec<Operation>) -> PyResult<Registers> {
+        if let Some(registers) = self.cache.get(&operations) {
+            return Ok(registers);
+        }
+        let circuit = CircuitWrapper {
+            internal: operations.iter().cloned().collect::<Circuit>(),
+        };
+        let registers = self
+            .backend
+            .call_method1(py, "run_circuit", (circuit,))?
+            .extract::<Registers>(py)
+            .map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Could not extract output registers returned by backend: {:?}",
+                    err
+                ))
+            })?;
+        self.cache.insert(operations, registers.clone());
+        Ok(registers)
+    }
+}
+
+// Runs all circuits of a measurement and combines the output registers.
+pub(crate) fn measurement_registers(
+    measurement: &Bound<PyAny>,
+    mut run_operations: impl FnMut(Vec<Operation>) -> PyResult<Registers>,
+) -> PyResult<Registers> {
+    let extraction_error = |err: QoqoError| {
+        PyTypeError::new_err(format!(
+            "Cannot extract circuits from measurement {:?}",
+            err
+        ))
+    };
+    let constant_circuit = measurement.call_method0("constant_circuit")?;
+    let constant_circuit = if constant_circuit.is_none() {
+        None
+    } else {
+        Some(convert_into_circuit(&constant_circuit).map_err(extraction_error)?)
+    };
+    let circuits = measurement
+        .call_method0("circuits")?
+        .extract::<Vec<Bound<PyAny>>>()?;
+
+    let (mut bit_registers, mut float_registers, mut complex_registers): Registers =
+        (HashMap::new(), HashMap::new(), HashMap::new());
+    for circuit in circuits {
+        let circuit = convert_into_circuit(&circuit).map_err(extraction_error)?;
+        let operations: Vec<Operation> = constant_circuit
+            .iter()
+            .flat_map(|constant| constant.iter())
+            .chain(circuit.iter())
+            .cloned()
+            .collect();
+        let (tmp_bit_reg, tmp_float_reg, tmp_complex_reg) = run_operations(operations)?;
+        for (key, mut val) in tmp_bit_reg.into_iter() {
+            bit_registers.entry(key).or_default().append(&mut val);
+        }
+        for (key, mut val) in tmp_float_reg.into_iter() {
+            float_registers.entry(key).or_default().append(&mut val);
+        }
+        for (key, mut val) in tmp_complex_reg.into_iter() {
+            complex_registers.entry(key).or_default().append(&mut val);
+        }
+    }
+    Ok((bit_registers, float_registers, complex_registers))
+}
+
+/// Wrappers adding functionality to Python backends.
+///
+/// .. autosummary::
+///     :toctree: generated/
+///
+///     CachingBackend
+#[pymodule]
+pub fn backends(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_class::<CachingBackendWrapper>()?;
+    Ok(())
+}
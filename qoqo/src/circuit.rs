@@ -18,14 +18,19 @@
 //! functions of a Vec<Operation>, such as len(), is_empty(), get(), iter() and into_iter().
 //!
 
-use crate::{QoqoError, QOQO_VERSION};
+use crate::conversions::convert_into_circuit;
+use crate::QOQO_VERSION;
 use bincode::{deserialize, serialize};
 use pyo3::exceptions::{PyIndexError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
+use pyo3::types::{PyByteArray, PySet};
+use qoqo_calculator_pyo3::CalculatorFloatWrapper;
+use roqoqo::operations::InvolvedClassical;
 use roqoqo::prelude::*;
-use roqoqo::{Circuit, OperationIterator, ROQOQO_VERSION};
-use std::collections::HashSet;
+use roqoqo::{
+    Circuit, CircuitSizeReport, EqualityOptions, LatexOptions, OperationIterator, ROQOQO_VERSION,
+};
+use std::collections::{HashMap, HashSet};
 
 use crate::operations::{convert_operation_to_pyobject, convert_pyany_to_operation};
 
@@ -151,6 +156,189 @@ impl CircuitWrapper {
         })
     }
 
+    /// Rename the classical registers in a clone of the Circuit.
+    ///
+    /// The registers are renamed consistently in definitions, InputBit operations, measurement operations,
+    /// PRAGMA measurement operations and the condition registers of conditional PRAGMAs.
+    /// Registers that are not in the keys of the mapping keep their name.
+    ///
+    /// Args:
+    ///     mapping (Dict[str, str]): The dictionary containing the {old_name: new_name} mapping of the registers.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit with the registers renamed.
+    ///
+    /// Raises:
+    ///     RuntimeError: A register is mapped onto an existing register or two registers are mapped onto the same name.
+    pub fn remap_registers(
+        &self,
+        mapping: std::collections::HashMap<String, String>,
+    ) -> PyResult<Self> {
+        let new_internal = self.internal.remap_registers(&mapping).map_err(|err| {
+            PyRuntimeError::new_err(format!("Register remapping failed: {}", err))
+        })?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Validate the PRAGMA operations setting the quantum state in the Circuit.
+    ///
+    /// Every PragmaSetStateVector and PragmaSetDensityMatrix, including those in the circuits
+    /// of operations like PragmaConditional or PragmaLoop, is checked with its `validate` method.
+    ///
+    /// Raises:
+    ///     ValueError: A PRAGMA operation sets an invalid statevector or density matrix.
+    pub fn validate_pragmas(&self) -> PyResult<()> {
+        self.internal
+            .validate_pragmas()
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Check that the classical registers of the Circuit are not defined in conflicting ways.
+    ///
+    /// Definitions with the same type, name and length are merged when they are added to the Circuit.
+    /// Two definitions of a register with different lengths or different types are a conflict.
+    ///
+    /// Raises:
+    ///     ValueError: A register is defined in conflicting ways, the message names the register, both lengths and indices.
+    pub fn check_definitions(&self) -> PyResult<()> {
+        self.internal
+            .check_definitions()
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Return the classical registers defined in the Circuit.
+    ///
+    /// For registers with conflicting definitions the first definition is used.
+    ///
+    /// Returns:
+    ///     Dict[str, Tuple[int, bool]]: The length and output flag of each register by name.
+    pub fn definitions_map(&self) -> HashMap<String, (usize, bool)> {
+        self.internal.definitions_map()
+    }
+
+    /// Return the qubits the operations of the Circuit act on.
+    ///
+    /// Operations acting on all qubits, like PragmaGetStateVector, do not add qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The qubits used in the Circuit.
+    pub fn used_qubits(&self) -> std::collections::BTreeSet<usize> {
+        self.internal.used_qubits()
+    }
+
+    /// Renumber the used qubits of a clone of the Circuit to 0..n preserving their order.
+    ///
+    /// When all MeasureQubit operations writing to a readout register use the measured qubit as readout index,
+    /// the readout indices are renumbered together with the qubits.
+    ///
+    /// Returns:
+    ///     Tuple[Circuit, Dict[int, int]]: The compacted Circuit and the applied {old_qubit: new_qubit} mapping.
+    ///
+    /// Raises:
+    ///     RuntimeError: Remapping the qubits of an operation failed.
+    pub fn compactify_qubits(&self) -> PyResult<(Self, std::collections::HashMap<usize, usize>)> {
+        let (new_internal, mapping) = self.internal.compactify_qubits().map_err(|err| {
+            PyRuntimeError::new_err(format!("Qubit compactification failed: {:?}", err))
+        })?;
+        Ok((
+            Self {
+                internal: new_internal,
+            },
+            mapping,
+        ))
+    }
+
+    /// Append another circuit acting on a disjoint set of qubits.
+    ///
+    /// All qubits of the other circuit are shifted by qubit_offset before it is appended.
+    ///
+    /// Args:
+    ///     other (Circuit): The circuit that is appended.
+    ///     qubit_offset (int): The offset added to all qubits of other.
+    ///
+    /// Returns:
+    ///     Circuit: The combined circuit.
+    ///
+    /// Raises:
+    ///     TypeError: Other cannot be converted to Circuit.
+    ///     RuntimeError: Register names clash or the shifted qubits overlap with the qubits of self.
+    pub fn tensor(&self, other: &Bound<PyAny>, qubit_offset: usize) -> PyResult<Self> {
+        let other = Self::from_pyany(other)
+            .map_err(|_| PyTypeError::new_err("Other cannot be converted to Circuit"))?;
+        let new_internal = self
+            .internal
+            .tensor(&other, qubit_offset)
+            .map_err(|err| PyRuntimeError::new_err(format!("Tensoring failed: {}", err)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Append another circuit acting on the same qubits.
+    ///
+    /// Definitions that are identical in both circuits are only kept once.
+    ///
+    /// Args:
+    ///     other (Circuit): The circuit that is appended.
+    ///
+    /// Returns:
+    ///     Circuit: The concatenated circuit.
+    ///
+    /// Raises:
+    ///     TypeError: Other cannot be converted to Circuit.
+    ///     RuntimeError: The circuits contain different definitions with the same name.
+    pub fn compose(&self, other: &Bound<PyAny>) -> PyResult<Self> {
+        let other = Self::from_pyany(other)
+            .map_err(|_| PyTypeError::new_err("Other cannot be converted to Circuit"))?;
+        let new_internal = self
+            .internal
+            .compose(&other)
+            .map_err(|err| PyRuntimeError::new_err(format!("Composing failed: {}", err)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Export the circuit to a LaTeX circuit diagram using the quantikz package.
+    ///
+    /// Every qubit is drawn as one wire. Common gates use their standard symbols, measurements are
+    /// drawn as meters and all other operations as boxes labelled with their hqslang name.
+    ///
+    /// Args:
+    ///     hide_pragmas (bool): Do not draw PRAGMA operations. Defaults to False.
+    ///
+    /// Returns:
+    ///     str: The quantikz environment drawing the circuit.
+    ///
+    /// Raises:
+    ///     RuntimeError: The circuit could not be drawn.
+    #[pyo3(signature = (hide_pragmas = false))]
+    pub fn to_latex(&self, hide_pragmas: bool) -> PyResult<String> {
+        self.internal
+            .to_latex_with_options(&LatexOptions::new().hide_pragmas(hide_pragmas))
+            .map_err(|err| PyRuntimeError::new_err(format!("LaTeX export failed: {}", err)))
+    }
+
+    /// Export the circuit to the JSON serialization format of Cirq.
+    ///
+    /// Operations are grouped into Cirq Moments using the parallel blocks of the CircuitDag.
+    /// Supported operations are PauliX, PauliY, PauliZ, Hadamard, SGate, TGate, RotateX, RotateY, RotateZ,
+    /// CNOT, ControlledPauliZ, SWAP, ISwap and MeasureQubit, definitions are skipped.
+    ///
+    /// Returns:
+    ///     str: The Cirq JSON representation of the circuit, can be loaded with `cirq.read_json(json_text=...)`.
+    ///
+    /// Raises:
+    ///     RuntimeError: The circuit contains operations that cannot be exported to Cirq.
+    #[cfg(feature = "circuitdag")]
+    pub fn to_cirq_json(&self) -> PyResult<String> {
+        self.internal
+            .to_cirq_json()
+            .map_err(|err| PyRuntimeError::new_err(format!("Cirq export failed: {}", err)))
+    }
+
     /// Return clone of the circuit with all overrotation Pragmas applied.
     ///
     /// Returns:
@@ -179,6 +367,89 @@ impl CircuitWrapper {
         })
     }
 
+    /// Compare the circuit to another circuit up to harmless differences.
+    ///
+    /// Without any option set, the comparison is identical to `==` apart from ignoring PragmaAnnotation operations.
+    ///
+    /// Args:
+    ///     other (Circuit): The circuit self is compared to.
+    ///     ignore_definition_order (bool): Compare the definitions independent of their order.
+    ///     ignore_pragmas (bool): Ignore PragmaStopParallelBlock and PragmaSleep operations.
+    ///     angle_tolerance (Optional[float]): Treat rotation angles as equal when they agree modulo 2π within this tolerance.
+    ///     compare_annotations (bool): Compare the PragmaAnnotation operations.
+    ///
+    /// Returns:
+    ///     bool: Whether the two circuits are semantically equal.
+    ///
+    /// Raises:
+    ///     TypeError: Other cannot be converted to Circuit.
+    #[pyo3(signature = (other, ignore_definition_order=false, ignore_pragmas=false, angle_tolerance=None, compare_annotations=false))]
+    pub fn semantically_equal(
+        &self,
+        other: &Bound<PyAny>,
+        ignore_definition_order: bool,
+        ignore_pragmas: bool,
+        angle_tolerance: Option<f64>,
+        compare_annotations: bool,
+    ) -> PyResult<bool> {
+        let other = Self::from_pyany(other)
+            .map_err(|_| PyTypeError::new_err("Other cannot be converted to Circuit"))?;
+        let options = EqualityOptions::new()
+            .ignore_definition_order(ignore_definition_order)
+            .ignore_pragmas(ignore_pragmas)
+            .angle_tolerance(angle_tolerance)
+            .compare_annotations(compare_annotations);
+        Ok(self.internal.semantically_equal(&other, options))
+    }
+
+    /// Return the sum of the phases of all PragmaGlobalPhase operations in the circuit.
+    ///
+    /// Symbolic phases are summed symbolically. PragmaGlobalPhase operations in nested
+    /// circuits (for example in a PragmaLoop) are not included.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The total global phase, zero if the circuit contains no PragmaGlobalPhase.
+    pub fn total_global_phase(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.total_global_phase(),
+        }
+    }
+
+    /// Return a copy of the circuit with all global phases combined into a single PragmaGlobalPhase.
+    ///
+    /// All PragmaGlobalPhase operations are removed and, if there were any,
+    /// a single PragmaGlobalPhase with the total global phase is appended at the end.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit with the consolidated global phase.
+    pub fn consolidate_global_phase(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.consolidate_global_phase(),
+        }
+    }
+
+    /// Return the annotations of the circuit.
+    ///
+    /// Collects the keys and values of all PragmaAnnotation operations in the circuit.
+    /// When a key is annotated several times, the last value is returned.
+    ///
+    /// Returns:
+    ///     Dict[str, str]: The annotations of the circuit.
+    pub fn annotations(&self) -> HashMap<String, String> {
+        self.internal.annotations()
+    }
+
+    /// Annotate the circuit with a key-value pair of metadata.
+    ///
+    /// Appends a PragmaAnnotation to the circuit.
+    ///
+    /// Args:
+    ///     key (str): The key of the annotation.
+    ///     value (str): The value of the annotation.
+    pub fn annotate(&mut self, key: String, value: String) {
+        self.internal.annotate(key, value)
+    }
+
     /// Count the number of occurences of a set of operation tags in the circuit.
     ///
     /// Args:
@@ -196,6 +467,19 @@ impl CircuitWrapper {
         counter
     }
 
+    /// Return statistics about the size of the circuit.
+    ///
+    /// The statistics include operations in nested circuits (e.g. the body of a PragmaLoop).
+    ///
+    /// Returns:
+    ///     Dict[str, int]: The total number of operations ("number_operations"),
+    ///                     the size of the bincode serialization in bytes ("serialized_bytes"),
+    ///                     the largest dimension of a set statevector or density matrix ("max_state_dimension")
+    ///                     and the maximal nesting depth of circuits in the circuit ("max_nesting_depth").
+    pub fn size_report(&self) -> HashMap<String, usize> {
+        size_report_to_dict(self.internal.size_report())
+    }
+
     /// Return a list of the hqslang names of all operations occuring in the circuit.
     ///
     /// Returns:
@@ -208,6 +492,57 @@ impl CircuitWrapper {
         operations
     }
 
+    /// Return the set of bosonic modes the circuit acts on.
+    ///
+    /// Operations not acting on bosonic modes (e.g. pure qubit operations) do not contribute.
+    ///
+    /// Returns:
+    ///     Union[Set[int], str]: The involved modes as a set or 'All' if all modes are involved.
+    pub fn involved_modes(&self, py: Python) -> PyResult<PyObject> {
+        let set = match self.internal.involved_modes() {
+            InvolvedModes::All => PySet::new_bound(py, &["All"])?,
+            InvolvedModes::None => PySet::empty_bound(py)?,
+            InvolvedModes::Set(modes) => {
+                PySet::new_bound(py, &modes.into_iter().collect::<Vec<usize>>())?
+            }
+        };
+        Ok(set.into_any().unbind())
+    }
+
+    /// Return the classical register entries the circuit involves.
+    ///
+    /// Operations containing circuits (e.g. PragmaConditional or PragmaLoop) contribute the entries
+    /// of their inner circuits. If an operation involves all entries of a register, the first such
+    /// register is returned, with registers involved up to the number of qubits only returned
+    /// when no register is involved completely.
+    ///
+    /// Returns:
+    ///     Union[Set[Tuple[str, int]], Tuple[str, str]]: The involved (register name, index) entries as a set,
+    ///         ('All', name) if all entries of a register are involved or ('AllQubits', name)
+    ///         if the entries of a register up to the number of qubits are involved.
+    pub fn involved_classical(&self, py: Python) -> PyResult<PyObject> {
+        Ok(match self.internal.involved_classical() {
+            InvolvedClassical::All(name) => ("All", name).into_py(py),
+            InvolvedClassical::AllQubits(name) => ("AllQubits", name).into_py(py),
+            InvolvedClassical::None => PySet::empty_bound(py)?.into_any().unbind(),
+            InvolvedClassical::Set(entries) => {
+                PySet::new_bound(py, &entries.into_iter().collect::<Vec<(String, usize)>>())?
+                    .into_any()
+                    .unbind()
+            }
+        })
+    }
+
+    /// Return the number of bosonic modes needed to run the circuit.
+    ///
+    /// The number of modes is the largest mode index involved in an operation plus one.
+    ///
+    /// Returns:
+    ///     int: The number of bosonic modes, zero if the circuit acts on no bosonic modes.
+    pub fn number_of_modes(&self) -> usize {
+        self.internal.number_of_modes()
+    }
+
     /// Return a copy of the Circuit (copy here produces a deepcopy).
     ///
     /// Returns:
@@ -308,7 +643,6 @@ impl CircuitWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -318,7 +652,6 @@ impl CircuitWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -333,18 +666,25 @@ impl CircuitWrapper {
     ///
     /// Args:
     ///     input (str): The serialized Circuit in json form.
+    ///     strict (bool): Whether fields unknown to the Circuit or its operations raise an error instead of being ignored.
     ///
     /// Returns:
     ///     Circuit: The deserialized Circuit.
     ///
     /// Raises:
-    ///     ValueError: Input cannot be deserialized to Circuit.
+    ///     ValueError: Input cannot be deserialized to Circuit or contains unknown fields in strict mode.
     #[staticmethod]
-    pub fn from_json(json_string: &str) -> PyResult<Self> {
-        Ok(Self {
-            internal: serde_json::from_str(json_string)
-                .map_err(|_| PyValueError::new_err("Input cannot be deserialized to Circuit"))?,
-        })
+    #[pyo3(signature = (json_string, strict = false))]
+    pub fn from_json(json_string: &str, strict: bool) -> PyResult<Self> {
+        let internal = if strict {
+            Circuit::from_json_strict(json_string).map_err(|err| {
+                PyValueError::new_err(format!("Input cannot be deserialized to Circuit: {}", err))
+            })?
+        } else {
+            serde_json::from_str(json_string)
+                .map_err(|_| PyValueError::new_err("Input cannot be deserialized to Circuit"))?
+        };
+        Ok(Self { internal })
     }
 
     /// Return a copy of the Operation at a certain index of the Circuit.
@@ -660,47 +1000,6 @@ impl CircuitWrapper {
     }
 }
 
-/// Convert generic python object to [roqoqo::Circuit].
-///
-/// Fallible conversion of generic python object to [roqoqo::Circuit].
-pub fn convert_into_circuit(input: &Bound<PyAny>) -> Result<Circuit, QoqoError> {
-    if let Ok(try_downcast) = input.extract::<CircuitWrapper>() {
-        return Ok(try_downcast.internal);
-    }
-    // Everything that follows tries to extract the circuit when two separately
-    // compiled python packages are involved
-    // let get_version = input
-    //     .call_method0("_qoqo_versions")
-    //     .map_err(|_| QoqoError::CannotExtractObject)?;
-    // let version = get_version
-    //     .extract::<(&str, &str)>()
-    //     .map_err(|_| QoqoError::CannotExtractObject)?;
-    // let mut rsplit = ROQOQO_VERSION.split('.').take(2);
-    // let mut qsplit = QOQO_VERSION.split('.').take(2);
-    // let rver = format!(
-    //     "{}.{}",
-    //     rsplit.next().expect("ROQOQO_VERSION badly formatted"),
-    //     rsplit.next().expect("ROQOQO_VERSION badly formatted")
-    // );
-    // let qver = format!(
-    //     "{}.{}",
-    //     qsplit.next().expect("QOQO_VERSION badly formatted"),
-    //     qsplit.next().expect("QOQO_VERSION badly formatted")
-    // );
-    // let test_version: (&str, &str) = (rver.as_str(), qver.as_str());
-    // if version == test_version {
-    let get_bytes = input
-        .call_method0("to_bincode")
-        .map_err(|_| QoqoError::CannotExtractObject)?;
-    let bytes = get_bytes
-        .extract::<Vec<u8>>()
-        .map_err(|_| QoqoError::CannotExtractObject)?;
-    deserialize(&bytes[..]).map_err(|_| QoqoError::CannotExtractObject)
-    // } else {
-    //     Err(QoqoError::VersionMismatch)
-    // }
-}
-
 /// Iterator for iterating over Operations in a Circuit.
 #[pyclass(name = "OperationIterator", module = "qoqo")]
 #[derive(Debug)]
@@ -731,3 +1030,16 @@ impl OperationIteratorWrapper {
             .map(|op| convert_operation_to_pyobject(op).unwrap())
     }
 }
+
+/// Convert a size report of a circuit or quantum program into a python dictionary.
+pub(crate) fn size_report_to_dict(report: CircuitSizeReport) -> HashMap<String, usize> {
+    HashMap::from([
+        ("number_operations".to_string(), report.number_operations),
+        ("serialized_bytes".to_string(), report.serialized_bytes),
+        (
+            "max_state_dimension".to_string(),
+            report.max_state_dimension,
+        ),
+        ("max_nesting_depth".to_string(), report.max_nesting_depth),
+    ])
+}
@@ -13,15 +13,17 @@
 //! Module containing the CircuitDag class that represents the Directed Acyclic Graph (DAG)
 //! of a quantum circuit in qoqo.
 //!
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::{QoqoError, QOQO_VERSION};
 use bincode::{deserialize, serialize};
 use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
+use roqoqo::operations::Operation;
 use roqoqo::{Circuit, CircuitDag, ROQOQO_VERSION};
 
+use crate::devices::GenericDeviceWrapper;
 use crate::operations::{convert_operation_to_pyobject, convert_pyany_to_operation};
 use crate::CircuitWrapper;
 
@@ -98,16 +100,26 @@ impl CircuitDagWrapper {
         }
     }
 
-    /// Create a CircuitDag from a given Circuit;
+    /// Create a CircuitDag from a given Circuit.
     ///
     /// Args:
     ///     circuit (Circuit): The Circuit to build the new CircuitDag from.
     ///
     /// Returns:
     ///     self: The new CircuitDag.
+    ///
+    /// Raises:
+    ///     TypeError: The Python Object cannot be converted to Circuit.
+    #[staticmethod]
     #[pyo3(text_signature = "(circuit)")]
-    pub fn from_circuit(&self, circuit: &Bound<PyAny>) -> PyResult<Self> {
-        let circuit = crate::convert_into_circuit(circuit).unwrap();
+    pub fn from_circuit(circuit: &Bound<PyAny>) -> PyResult<Self> {
+        let circuit = crate::convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Cannot convert python object of type {} to Circuit: {:?}",
+                type_name(circuit),
+                err
+            ))
+        })?;
         Ok(Self {
             internal: CircuitDag::from(circuit),
         })
@@ -115,6 +127,8 @@ impl CircuitDagWrapper {
 
     /// Transforms the CircuitDag into a Circuit.
     ///
+    /// Returns:
+    ///     Circuit: The Circuit containing the operations of the CircuitDag.
     #[pyo3(text_signature = "($self)")]
     pub fn to_circuit(&self) -> PyResult<CircuitWrapper> {
         Ok(CircuitWrapper {
@@ -127,13 +141,14 @@ impl CircuitDagWrapper {
     /// Args:
     ///     op (Operation): The Operation to add to the back of the CircuitDag.
     ///
+    /// Returns:
+    ///     Optional[int]: The NodeIndex of the added operation, None if the operation was not added.
+    ///
     /// Raises:
     ///     TypeError: The Python Object cannot be converted to Operation.
     #[pyo3(text_signature = "($self, op)")]
     pub fn add_to_back(&mut self, op: &Bound<PyAny>) -> PyResult<Option<usize>> {
-        let operation = convert_pyany_to_operation(op).map_err(|x| {
-            PyTypeError::new_err(format!("Cannot convert python object to Operation {:?}", x))
-        })?;
+        let operation = extract_operation(op)?;
         Ok(self.internal.add_to_back(operation))
     }
 
@@ -142,16 +157,41 @@ impl CircuitDagWrapper {
     /// Args:
     ///     op (Operation): The Operation to add to the front of the CircuitDag.
     ///
+    /// Returns:
+    ///     Optional[int]: The NodeIndex of the added operation, None if the operation was not added.
+    ///
     /// Raises:
     ///     TypeError: The Python Object cannot be converted to Operation.
     #[pyo3(text_signature = "($self, op)")]
     pub fn add_to_front(&mut self, op: &Bound<PyAny>) -> PyResult<Option<usize>> {
-        let operation = convert_pyany_to_operation(op).map_err(|x| {
-            PyTypeError::new_err(format!("Cannot convert python object to Operation {:?}", x))
-        })?;
+        let operation = extract_operation(op)?;
         Ok(self.internal.add_to_front(operation))
     }
 
+    /// Add a list of Operations to the back of the CircuitDag in order.
+    ///
+    /// The operations are only added if all of them can be converted.
+    ///
+    /// Args:
+    ///     ops (List[Operation]): The Operations to add to the back of the CircuitDag.
+    ///
+    /// Returns:
+    ///     List[Optional[int]]: The NodeIndices of the added operations, None for operations that were not added.
+    ///
+    /// Raises:
+    ///     TypeError: A Python Object in the list cannot be converted to Operation.
+    #[pyo3(text_signature = "($self, ops)")]
+    pub fn extend_back(&mut self, ops: Vec<Bound<PyAny>>) -> PyResult<Vec<Option<usize>>> {
+        let operations = ops
+            .iter()
+            .map(extract_operation)
+            .collect::<PyResult<Vec<Operation>>>()?;
+        Ok(operations
+            .into_iter()
+            .map(|operation| self.internal.add_to_back(operation))
+            .collect())
+    }
+
     /// Checks if executing an operation is blocked by any not-yet executed operation.
     ///
     /// Args:
@@ -368,6 +408,32 @@ impl CircuitDagWrapper {
         self.internal.successors(node).map(|n| n.index()).collect()
     }
 
+    /// Returns the direct predecessors in the CircuitDag of a given node.
+    ///
+    /// Args:
+    ///     node (int): The NodeIndex of the operation whose predecessors are returned.
+    ///
+    /// Returns:
+    ///     List[int]: The sorted list of the direct predecessors.
+    #[pyo3(text_signature = "($self, node)")]
+    pub fn predecessors(&self, node: usize) -> Vec<usize> {
+        self.internal.predecessors(node)
+    }
+
+    /// Returns the nodes that can be executed once the given nodes have been executed.
+    ///
+    /// A node is ready when it has not been executed and all its direct predecessors have been executed.
+    ///
+    /// Args:
+    ///     already_executed (List[int]): List of NodeIndices of Nodes that have already been executed in the Circuit.
+    ///
+    /// Returns:
+    ///     List[int]: The sorted list of NodeIndices of the operations ready to be executed.
+    #[pyo3(text_signature = "($self, already_executed)")]
+    pub fn ready_after(&self, already_executed: Vec<usize>) -> Vec<usize> {
+        self.internal.ready_after(already_executed.as_slice())
+    }
+
     /// Returns the list of nodes of commuting operations in CircuitDag.
     ///
     /// Returns:
@@ -450,6 +516,106 @@ impl CircuitDagWrapper {
                 .to_object(py)
         })
     }
+
+    /// Returns the lifetime of every qubit in the CircuitDag.
+    ///
+    /// The lifetime is given by the positions of the first and the last operation involving the qubit
+    /// in the topological order of the CircuitDag. For a CircuitDag created from a Circuit, the positions
+    /// are the indices of the operations in the Circuit.
+    ///
+    /// Returns:
+    ///     Dict[int, Tuple[int, int]]: The dictionary of {qubit: (first, last)} elements.
+    #[pyo3(text_signature = "($self)")]
+    pub fn qubit_lifetimes(&self) -> HashMap<usize, (usize, usize)> {
+        self.internal.qubit_lifetimes()
+    }
+
+    /// Returns the maximal number of qubits that are alive at the same time.
+    ///
+    /// Returns:
+    ///     int: The maximal number of concurrently alive qubits.
+    #[pyo3(text_signature = "($self)")]
+    pub fn max_concurrent_qubits(&self) -> usize {
+        self.internal.max_concurrent_qubits()
+    }
+
+    /// Returns how often the operation of a node is executed.
+    ///
+    /// The circuit of a PragmaLoop is executed the number of repetitions of the loop,
+    /// all other operations are executed once.
+    ///
+    /// Args:
+    ///     index (int): The index of the node in the CircuitDag.
+    ///
+    /// Returns:
+    ///     int: The number of executions of the operation.
+    ///
+    /// Raises:
+    ///     ValueError: The node is not in the CircuitDag or is a PragmaLoop with symbolic repetitions.
+    #[pyo3(text_signature = "($self, index)")]
+    pub fn repetitions(&self, index: usize) -> PyResult<usize> {
+        self.internal
+            .repetitions(index)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Returns the depth of the CircuitDag.
+    ///
+    /// A PragmaLoop contributes the depth of its circuit times its number of repetitions.
+    ///
+    /// Returns:
+    ///     int: The number of operations on the longest path through the CircuitDag.
+    ///
+    /// Raises:
+    ///     ValueError: A PragmaLoop has symbolic repetitions.
+    #[pyo3(text_signature = "($self)")]
+    pub fn depth(&self) -> PyResult<usize> {
+        self.internal
+            .depth()
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Returns the time needed to execute the CircuitDag on a device.
+    ///
+    /// A PragmaLoop takes the execution time of its circuit times its number of repetitions.
+    ///
+    /// Args:
+    ///     device: The device providing the gate times.
+    ///
+    /// Returns:
+    ///     float: The execution time of the CircuitDag.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to a device.
+    ///     ValueError: A gate is not available on the device or a PragmaLoop has symbolic repetitions.
+    #[pyo3(text_signature = "($self, device)")]
+    pub fn execution_time(&self, device: &Bound<PyAny>) -> PyResult<f64> {
+        let device = GenericDeviceWrapper::from_pyany(device)
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to a device"))?;
+        self.internal
+            .execution_time(&device)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+}
+
+/// Returns the name of the type of a Python object for error messages.
+fn type_name(input: &Bound<PyAny>) -> String {
+    input
+        .get_type()
+        .name()
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Converts a Python object to an Operation with a TypeError naming the type of the object.
+fn extract_operation(op: &Bound<PyAny>) -> PyResult<Operation> {
+    convert_pyany_to_operation(op).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Cannot convert python object of type {} to Operation: {:?}",
+            type_name(op),
+            err
+        ))
+    })
 }
 
 /// Convert generic python object to [roqoqo::CircuitDag].
@@ -0,0 +1,165 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions from python objects to roqoqo objects.
+//!
+//! Python packages building on qoqo (e.g. backends) are compiled separately from qoqo.
+//! A qoqo object created in one python package can therefore not be downcast to the
+//! qoqo wrapper type of another package. The functions in this module first try the direct
+//! downcast and fall back to transferring the object in its serialized [bincode] form.
+//! [QoqoError::VersionMismatch] is only returned when the transfer fails and the roqoqo
+//! version of the python object differs from the roqoqo version of this package.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use pyo3::prelude::*;
+//! use qoqo::conversions::convert_into_circuit;
+//!
+//! #[pyfunction]
+//! fn number_of_operations(circuit: &Bound<PyAny>) -> PyResult<usize> {
+//!     let circuit = convert_into_circuit(circuit).map_err(|err| {
+//!         pyo3::exceptions::PyTypeError::new_err(format!("Cannot convert to Circuit: {}", err))
+//!     })?;
+//!     Ok(circuit.len())
+//! }
+//! ```
+
+use crate::operations::convert_pyany_to_operation;
+use crate::{CircuitWrapper, QoqoError, QuantumProgramWrapper};
+use bincode::deserialize;
+use pyo3::prelude::*;
+use roqoqo::operations::Operation;
+use roqoqo::{Circuit, QuantumProgram, ROQOQO_VERSION};
+
+/// Convert generic python object to [roqoqo::Circuit].
+///
+/// Fallible conversion of generic python object to [roqoqo::Circuit].
+/// Circuits created by a separately compiled python package are transferred via `to_bincode`.
+///
+/// # Arguments
+///
+/// * `input` - The python object that is converted.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The converted Circuit.
+/// * `Err(QoqoError::VersionMismatch)` - The object cannot be transferred and was created with a different roqoqo version.
+/// * `Err(QoqoError::CannotExtractObject)` - The object cannot be converted to a Circuit.
+pub fn convert_into_circuit(input: &Bound<PyAny>) -> Result<Circuit, QoqoError> {
+    if let Ok(try_downcast) = input.extract::<CircuitWrapper>() {
+        return Ok(try_downcast.internal);
+    }
+    deserialize_from_bincode(input)
+}
+
+/// Convert generic python object to [roqoqo::QuantumProgram].
+///
+/// Fallible conversion of generic python object to [roqoqo::QuantumProgram].
+/// QuantumPrograms created by a separately compiled python package are transferred via `to_bincode`.
+///
+/// # Arguments
+///
+/// * `input` - The python object that is converted.
+///
+/// # Returns
+///
+/// * `Ok(QuantumProgram)` - The converted QuantumProgram.
+/// * `Err(QoqoError::VersionMismatch)` - The object cannot be transferred and was created with a different roqoqo version.
+/// * `Err(QoqoError::CannotExtractObject)` - The object cannot be converted to a QuantumProgram.
+pub fn convert_into_quantum_program(input: &Bound<PyAny>) -> Result<QuantumProgram, QoqoError> {
+    if let Ok(try_downcast) = input.extract::<QuantumProgramWrapper>() {
+        return Ok(try_downcast.internal);
+    }
+    deserialize_from_bincode(input)
+}
+
+/// Convert generic python object to [roqoqo::operations::Operation].
+///
+/// Fallible conversion of generic python object to [roqoqo::operations::Operation].
+/// The operation is rebuilt from its `hqslang` name and the getters of its fields,
+/// which also works for operations created by a separately compiled python package.
+///
+/// # Arguments
+///
+/// * `input` - The python object that is converted.
+///
+/// # Returns
+///
+/// * `Ok(Operation)` - The converted Operation.
+/// * `Err(QoqoError::VersionMismatch)` - The operation is not known and was created with a different roqoqo version.
+/// * `Err(QoqoError::ConversionError)` - The object cannot be converted to an Operation.
+pub fn convert_into_operation(input: &Bound<PyAny>) -> Result<Operation, QoqoError> {
+    convert_pyany_to_operation(input).map_err(|err| {
+        if roqoqo_version_differs(input) {
+            QoqoError::VersionMismatch
+        } else {
+            err
+        }
+    })
+}
+
+/// Deserializes the bincode representation of a python object.
+///
+/// The bytes are obtained with `to_bincode` or, for measurements, with `_internal_to_bincode`.
+fn deserialize_from_bincode<T>(input: &Bound<PyAny>) -> Result<T, QoqoError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let bytes = input
+        .call_method0("to_bincode")
+        .and_then(|bytes| bytes.extract::<Vec<u8>>())
+        .or_else(|_| {
+            input
+                .call_method0("_internal_to_bincode")
+                .and_then(|tagged_bytes| tagged_bytes.extract::<(String, Vec<u8>)>())
+                .map(|(_, bytes)| bytes)
+        })
+        .map_err(|_| QoqoError::CannotExtractObject)?;
+    deserialize(&bytes[..]).map_err(|_| {
+        if roqoqo_version_differs(input) {
+            QoqoError::VersionMismatch
+        } else {
+            QoqoError::CannotExtractObject
+        }
+    })
+}
+
+/// Returns the major and minor version of a semver version string.
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut split = version.split('.');
+    let major = split.next()?.trim().parse().ok()?;
+    let minor = split.next()?.trim().parse().ok()?;
+    Some((major, minor))
+}
+
+/// Checks whether a python object was created with a different roqoqo version.
+///
+/// The roqoqo version is read from `_qoqo_versions` or, if not available,
+/// from the minimum roqoqo version required by the object (`min_supported_version`).
+/// Objects that report no version are assumed to use the same version.
+fn roqoqo_version_differs(input: &Bound<PyAny>) -> bool {
+    let own_version = major_minor(ROQOQO_VERSION);
+    if let Ok((roqoqo_version, _)) = input
+        .call_method0("_qoqo_versions")
+        .and_then(|versions| versions.extract::<(String, String)>())
+    {
+        return major_minor(&roqoqo_version) != own_version;
+    }
+    if let Ok(min_version) = input
+        .call_method0("min_supported_version")
+        .and_then(|version| version.extract::<String>())
+    {
+        return major_minor(&min_version) > own_version;
+    }
+    false
+}
@@ -0,0 +1,290 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use ndarray::Array2;
+use numpy::PyReadonlyArray2;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
+use roqoqo::devices::{Device, GenericDevice};
+use roqoqo::RoqoqoError;
+
+/// Methods a python object needs to provide to be used as a device by roqoqo routines.
+const DEVICE_PROTOCOL_METHODS: [&str; 7] = [
+    "single_qubit_gate_time",
+    "two_qubit_gate_time",
+    "three_qubit_gate_time",
+    "multi_qubit_gate_time",
+    "number_qubits",
+    "two_qubit_edges",
+    "qubit_decoherence_rates",
+];
+
+/// A wrapper around a python object that implements the device protocol.
+///
+/// Allows pure python devices to be passed to rust functions that take an `impl Device`.
+/// The python object needs to provide the methods
+///
+/// * `single_qubit_gate_time(hqslang: str, qubit: int) -> Optional[float]`
+/// * `two_qubit_gate_time(hqslang: str, control: int, target: int) -> Optional[float]`
+/// * `three_qubit_gate_time(hqslang: str, control_0: int, control_1: int, target: int) -> Optional[float]`
+/// * `multi_qubit_gate_time(hqslang: str, qubits: List[int]) -> Optional[float]`
+/// * `number_qubits() -> int`
+/// * `two_qubit_edges() -> List[Tuple[int, int]]`
+/// * `qubit_decoherence_rates(qubit: int) -> Optional[np.ndarray]` (nested lists are also accepted)
+///
+/// The methods `single_qubit_gate_names`, `two_qubit_gate_names` and `multi_qubit_gate_names`
+/// are optional. When they are not provided the device reports no available gate names.
+///
+/// All qoqo devices implement the protocol.
+#[derive(Clone, Debug)]
+pub struct DeviceCapsule {
+    internal: Py<PyAny>,
+}
+
+impl DeviceCapsule {
+    /// Creates a new DeviceCapsule for a Python object.
+    ///
+    /// # Arguments
+    ///
+    /// * `python_device` - The python object that should implement the device protocol.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The python object wrapped in a DeviceCapsule.
+    /// * `Err(RoqoqoError)` - The python object is missing a method of the device protocol.
+    pub fn new(python_device: &Bound<PyAny>) -> Result<Self, RoqoqoError> {
+        for method in DEVICE_PROTOCOL_METHODS {
+            if !python_device.hasattr(method).unwrap_or(false) {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Python device does not implement `{}` method of the device protocol.",
+                        method
+                    ),
+                });
+            }
+        }
+        Ok(Self {
+            internal: python_device.clone().unbind(),
+        })
+    }
+
+    /// Calls an optional `*_gate_names` method on the python device.
+    fn gate_names(&self, method: &str) -> Vec<String> {
+        Python::with_gil(|py| -> Vec<String> {
+            let device = self.internal.bind(py);
+            if !device.hasattr(method).unwrap_or(false) {
+                return Vec::new();
+            }
+            device
+                .call_method0(method)
+                .and_then(|names| names.extract::<Vec<String>>())
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `{}` on python device failed: {}",
+                        method, err
+                    )
+                })
+        })
+    }
+}
+
+impl Device for DeviceCapsule {
+    fn single_qubit_gate_time(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        Python::with_gil(|py| -> Option<f64> {
+            self.internal
+                .call_method1(py, "single_qubit_gate_time", (hqslang, *qubit))
+                .and_then(|time| time.extract::<Option<f64>>(py))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `single_qubit_gate_time` on python device failed: {}",
+                        err
+                    )
+                })
+        })
+    }
+
+    fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        Python::with_gil(|py| -> Option<f64> {
+            self.internal
+                .call_method1(py, "two_qubit_gate_time", (hqslang, *control, *target))
+                .and_then(|time| time.extract::<Option<f64>>(py))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `two_qubit_gate_time` on python device failed: {}",
+                        err
+                    )
+                })
+        })
+    }
+
+    fn three_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_0: &usize,
+        control_1: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        Python::with_gil(|py| -> Option<f64> {
+            self.internal
+                .call_method1(
+                    py,
+                    "three_qubit_gate_time",
+                    (hqslang, *control_0, *control_1, *target),
+                )
+                .and_then(|time| time.extract::<Option<f64>>(py))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `three_qubit_gate_time` on python device failed: {}",
+                        err
+                    )
+                })
+        })
+    }
+
+    fn multi_qubit_gate_time(&self, hqslang: &str, qubits: &[usize]) -> Option<f64> {
+        Python::with_gil(|py| -> Option<f64> {
+            self.internal
+                .call_method1(py, "multi_qubit_gate_time", (hqslang, qubits.to_vec()))
+                .and_then(|time| time.extract::<Option<f64>>(py))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `multi_qubit_gate_time` on python device failed: {}",
+                        err
+                    )
+                })
+        })
+    }
+
+    fn qubit_decoherence_rates(&self, qubit: &usize) -> Option<Array2<f64>> {
+        Python::with_gil(|py| -> Option<Array2<f64>> {
+            let rates = self
+                .internal
+                .call_method1(py, "qubit_decoherence_rates", (*qubit,))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `qubit_decoherence_rates` on python device failed: {}",
+                        err
+                    )
+                });
+            let rates = rates.bind(py);
+            if rates.is_none() {
+                return None;
+            }
+            if !rates.is_instance_of::<PyList>() && !rates.is_instance_of::<PyTuple>() {
+                let array = rates.extract::<PyReadonlyArray2<f64>>().unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `qubit_decoherence_rates` on python device does not return a 2d array: {}",
+                        err
+                    )
+                });
+                return Some(array.as_array().to_owned());
+            }
+            let nested = rates.extract::<Vec<Vec<f64>>>().unwrap_or_else(|err| {
+                panic!(
+                    "Internal error `qubit_decoherence_rates` on python device does not return a 2d array: {}",
+                    err
+                )
+            });
+            let number_rows = nested.len();
+            let number_columns = nested.first().map(|row| row.len()).unwrap_or(0);
+            Some(
+                Array2::from_shape_vec(
+                    (number_rows, number_columns),
+                    nested.into_iter().flatten().collect(),
+                )
+                .expect("Internal error `qubit_decoherence_rates` on python device returns rows of different length."),
+            )
+        })
+    }
+
+    fn number_qubits(&self) -> usize {
+        Python::with_gil(|py| -> usize {
+            self.internal
+                .call_method0(py, "number_qubits")
+                .and_then(|number| number.extract::<usize>(py))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `number_qubits` on python device failed: {}",
+                        err
+                    )
+                })
+        })
+    }
+
+    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
+        Python::with_gil(|py| -> Vec<(usize, usize)> {
+            self.internal
+                .call_method0(py, "two_qubit_edges")
+                .and_then(|edges| edges.extract::<Vec<(usize, usize)>>(py))
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Internal error `two_qubit_edges` on python device failed: {}",
+                        err
+                    )
+                })
+        })
+    }
+
+    fn single_qubit_gate_names(&self) -> Vec<String> {
+        self.gate_names("single_qubit_gate_names")
+    }
+
+    fn two_qubit_gate_names(&self) -> Vec<String> {
+        self.gate_names("two_qubit_gate_names")
+    }
+
+    fn multi_qubit_gate_names(&self) -> Vec<String> {
+        self.gate_names("multi_qubit_gate_names")
+    }
+
+    /// Builds a GenericDevice by querying the python device.
+    ///
+    /// Single-qubit gate times are queried for all qubits and two-qubit gate times
+    /// for both directions of all `two_qubit_edges`.
+    /// Three- and multi-qubit gate times cannot be enumerated through the protocol and are not included.
+    fn to_generic_device(&self) -> GenericDevice {
+        let number_qubits = self.number_qubits();
+        let mut generic_device = GenericDevice::new(number_qubits);
+        for gate in self.single_qubit_gate_names() {
+            for qubit in 0..number_qubits {
+                if let Some(time) = self.single_qubit_gate_time(&gate, &qubit) {
+                    generic_device
+                        .set_single_qubit_gate_time(&gate, qubit, time)
+                        .expect("Internal error `number_qubits` on python device is inconsistent.");
+                }
+            }
+        }
+        let edges = self.two_qubit_edges();
+        for gate in self.two_qubit_gate_names() {
+            for (first, second) in edges.iter() {
+                for (control, target) in [(*first, *second), (*second, *first)] {
+                    if let Some(time) = self.two_qubit_gate_time(&gate, &control, &target) {
+                        generic_device
+                            .set_two_qubit_gate_time(&gate, control, target, time)
+                            .expect(
+                                "Internal error `two_qubit_edges` on python device exceed number of qubits.",
+                            );
+                    }
+                }
+            }
+        }
+        for qubit in 0..number_qubits {
+            if let Some(rates) = self.qubit_decoherence_rates(&qubit) {
+                generic_device
+                    .set_qubit_decoherence_rates(qubit, rates)
+                    .expect("Internal error `qubit_decoherence_rates` on python device is not a (3x3)-matrix.");
+            }
+        }
+        generic_device
+    }
+}
@@ -48,6 +48,53 @@ impl GenericDeviceWrapper {
         })
     }
 
+    /// Create a GenericDevice from a human-editable json config.
+    ///
+    /// The config contains default gate times per gate, overrides of the gate times on single qubits,
+    /// edges or sets of qubits, and damping, dephasing and depolarising rates with per-qubit overrides.
+    /// Calibrated gate errors are not part of the config.
+    ///
+    /// Args:
+    ///     config (str): The json config of the device.
+    ///
+    /// Returns:
+    ///     GenericDevice: The device described by the config.
+    ///
+    /// Raises:
+    ///     ValueError: The config cannot be deserialized or contains invalid qubits.
+    #[staticmethod]
+    #[pyo3(text_signature = "(config)")]
+    pub fn from_config_json(config: &str) -> PyResult<GenericDeviceWrapper> {
+        Ok(GenericDeviceWrapper {
+            internal: GenericDevice::from_config_json(config)
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+        })
+    }
+
+    /// Return the human-editable json config of the device.
+    ///
+    /// Returns:
+    ///     str: The json config of the device.
+    ///
+    /// Raises:
+    ///     ValueError: The decoherence rates of a qubit cannot be expressed in the config.
+    pub fn to_config_json(&self) -> PyResult<String> {
+        self.internal
+            .to_config_json()
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema of the json config of the device.
+    ///
+    /// Returns:
+    ///     str: The json schema of the config serialized to json
+    #[staticmethod]
+    pub fn config_json_schema() -> String {
+        let schema = schemars::schema_for!(roqoqo::devices::GenericDeviceConfig);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
     #[cfg(feature = "json_schema")]
     /// Return the JsonSchema for the json serialisation of the class.
     ///
@@ -21,8 +21,11 @@
 #[cfg(feature = "unstable_chain_with_environment")]
 use std::collections::HashMap;
 
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
+use crate::CircuitWrapper;
+
 mod square_lattice;
 #[cfg(feature = "unstable_chain_with_environment")]
 use roqoqo::{devices::ChainWithEnvironmentDevice, RoqoqoError};
@@ -31,6 +34,8 @@ mod generic_device;
 pub use generic_device::GenericDeviceWrapper;
 mod all_to_all;
 pub use all_to_all::AllToAllDeviceWrapper;
+mod device_capsule;
+pub use device_capsule::DeviceCapsule;
 
 #[cfg(feature = "unstable_chain_with_environment")]
 /// A wrapper around a python object that implements the ChainWithEnvironment trait.
@@ -83,6 +88,64 @@ impl ChainWithEnvironmentDevice for ChainWithEnvironmentCapsule {
     }
 }
 
+/// Return the gate time of an operation on a device.
+///
+/// Gates are looked up in the device with their hqslang name and qubits.
+/// Noise PRAGMAs return their gate_time, PragmaSleep returns its sleep_time
+/// and definitions return 0.0.
+///
+/// Args:
+///     device (Device): The device providing the gate times.
+///     operation (Operation): The operation for which the gate time is returned.
+///
+/// Returns:
+///     Optional[float]: The gate time, None if the operation is not available on the device,
+///                      has no gate time or the time is symbolic.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to a device or Operation.
+#[pyfunction]
+#[pyo3(text_signature = "(device, operation, /)")]
+pub fn gate_time(device: &Bound<PyAny>, operation: &Bound<PyAny>) -> PyResult<Option<f64>> {
+    let device = GenericDeviceWrapper::from_pyany(device)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to a device"))?;
+    let operation = crate::operations::convert_pyany_to_operation(operation)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Operation"))?;
+    Ok(roqoqo::devices::gate_time(&device, &operation))
+}
+
+/// Add the noise acting on the qubits of each gate while the gate is applied.
+///
+/// The circuit is a set of operations applied in parallel, so no two gates may act on the same qubit.
+/// After each gate the decoherence rates of each qubit of the gate are applied for the gate time
+/// of the gate on the device, as PragmaDamping, PragmaDephasing and PragmaDepolarising
+/// or as PragmaGeneralNoise for rates that are not a combination of those.
+///
+/// Args:
+///     device (Device): The device providing the gate times and decoherence rates.
+///     circuit (Circuit): The circuit of parallel native operations for which the noise is inserted.
+///
+/// Returns:
+///     Circuit: The circuit containing the noise PRAGMAs.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to a device or Circuit.
+///     ValueError: Several gates act on the same qubit or a gate is not available on the device.
+#[pyfunction]
+#[pyo3(text_signature = "(device, circuit, /)")]
+pub fn add_active_gate_noise(
+    device: &Bound<PyAny>,
+    circuit: &Bound<PyAny>,
+) -> PyResult<CircuitWrapper> {
+    let device = GenericDeviceWrapper::from_pyany(device)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to a device"))?;
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let internal = roqoqo::devices::add_active_gate_noise(&device, &circuit)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(CircuitWrapper { internal })
+}
+
 /// Devices in qoqo have two use cases:
 ///
 /// * Abstract devices: Contain abstract information for the model of a quantum computer and its parameters.
@@ -115,11 +178,15 @@ impl ChainWithEnvironmentDevice for ChainWithEnvironmentCapsule {
 ///     AllToAllDevice
 ///     GenericDevice
 ///     SquareLatticeDevice
+///     gate_time
+///     add_active_gate_noise
 
 #[pymodule]
 pub fn devices(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<AllToAllDeviceWrapper>()?;
     module.add_class::<GenericDeviceWrapper>()?;
     module.add_class::<SquareLatticeDeviceWrapper>()?;
+    module.add_function(wrap_pyfunction!(gate_time, module)?)?;
+    module.add_function(wrap_pyfunction!(add_active_gate_noise, module)?)?;
     Ok(())
 }
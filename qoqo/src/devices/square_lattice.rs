@@ -91,6 +91,67 @@ impl SquareLatticeDeviceWrapper {
         self.internal.number_columns()
     }
 
+    /// Return the qubit index at a position in the lattice.
+    ///
+    /// Args:
+    ///     row (int): The row of the qubit.
+    ///     column (int): The column of the qubit.
+    ///
+    /// Returns:
+    ///     Optional[int]: The index of the qubit (row-major) or None if the position lies outside of the lattice.
+    ///
+    pub fn qubit_index(&self, row: usize, column: usize) -> Option<usize> {
+        self.internal.qubit_index(row, column)
+    }
+
+    /// Return the position of a qubit in the lattice.
+    ///
+    /// Args:
+    ///     qubit (int): The index of the qubit.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[int, int]]: The (row, column) position of the qubit or None if the qubit is not part of the device.
+    ///
+    pub fn qubit_position(&self, qubit: usize) -> Option<(usize, usize)> {
+        self.internal.qubit_position(qubit)
+    }
+
+    /// Return the qubits in a row of the lattice.
+    ///
+    /// Args:
+    ///     row (int): The row of the lattice.
+    ///
+    /// Returns:
+    ///     List[int]: The qubit indices in the row ordered by column.
+    ///
+    pub fn row(&self, row: usize) -> Vec<usize> {
+        self.internal.row(row)
+    }
+
+    /// Return the qubits in a column of the lattice.
+    ///
+    /// Args:
+    ///     column (int): The column of the lattice.
+    ///
+    /// Returns:
+    ///     List[int]: The qubit indices in the column ordered by row.
+    ///
+    pub fn column(&self, column: usize) -> Vec<usize> {
+        self.internal.column(column)
+    }
+
+    /// Return the nearest neighbours of a qubit in the lattice.
+    ///
+    /// Args:
+    ///     qubit (int): The index of the qubit.
+    ///
+    /// Returns:
+    ///     List[int]: The sorted indices of the qubits connected to `qubit` by an edge of the lattice.
+    ///
+    pub fn neighbours(&self, qubit: usize) -> Vec<usize> {
+        self.internal.neighbours(qubit)
+    }
+
     /// Set gate time of all two-qubit gates of specific type
     ///
     /// Args:
@@ -195,6 +256,159 @@ impl SquareLatticeDeviceWrapper {
         }
     }
 
+    /// Set a uniform gate time for a single-qubit-gate, adding the gate if it is not available yet.
+    ///
+    /// Args:
+    ///     gate (str): The hqslang name of the single-qubit-gate.
+    ///     gate_time (float): Gate time for the given gate, valid for all qubits in the device.
+    ///
+    /// Returns:
+    ///     Self: The new device with the new properties
+    #[pyo3(text_signature = "(gate, gate_time, /)")]
+    pub fn with_single_qubit_gate_time(&self, gate: &str, gate_time: f64) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .with_single_qubit_gate_time(gate, gate_time),
+        }
+    }
+
+    /// Set a uniform gate time for a two-qubit-gate, adding the gate if it is not available yet.
+    ///
+    /// Args:
+    ///     gate (str): The hqslang name of the two-qubit-gate.
+    ///     gate_time (float): Gate time for the given gate, valid for all edges in the device.
+    ///
+    /// Returns:
+    ///     Self: The new device with the new properties
+    #[pyo3(text_signature = "(gate, gate_time, /)")]
+    pub fn with_two_qubit_gate_time(&self, gate: &str, gate_time: f64) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .with_two_qubit_gate_time(gate, gate_time),
+        }
+    }
+
+    /// Remove a single-qubit-gate from the available gates of the device.
+    ///
+    /// Args:
+    ///     gate (str): The hqslang name of the single-qubit-gate.
+    ///
+    /// Returns:
+    ///     Self: The new device without the gate
+    #[pyo3(text_signature = "(gate, /)")]
+    pub fn without_single_qubit_gate(&self, gate: &str) -> Self {
+        Self {
+            internal: self.internal.clone().without_single_qubit_gate(gate),
+        }
+    }
+
+    /// Remove a two-qubit-gate from the available gates of the device.
+    ///
+    /// Args:
+    ///     gate (str): The hqslang name of the two-qubit-gate.
+    ///
+    /// Returns:
+    ///     Self: The new device without the gate
+    #[pyo3(text_signature = "(gate, /)")]
+    pub fn without_two_qubit_gate(&self, gate: &str) -> Self {
+        Self {
+            internal: self.internal.clone().without_two_qubit_gate(gate),
+        }
+    }
+
+    /// Add damping to the noise rates of all qubits.
+    ///
+    /// Args:
+    ///     damping (float): The damping rate.
+    ///
+    /// Returns:
+    ///     Self: The new device with the new properties
+    #[pyo3(text_signature = "(damping, /)")]
+    pub fn with_damping_rates(&self, damping: f64) -> Self {
+        Self {
+            internal: self.internal.clone().with_damping_rates(damping),
+        }
+    }
+
+    /// Add dephasing to the noise rates of all qubits.
+    ///
+    /// Args:
+    ///     dephasing (float): The dephasing rate.
+    ///
+    /// Returns:
+    ///     Self: The new device with the new properties
+    #[pyo3(text_signature = "(dephasing, /)")]
+    pub fn with_dephasing_rates(&self, dephasing: f64) -> Self {
+        Self {
+            internal: self.internal.clone().with_dephasing_rates(dephasing),
+        }
+    }
+
+    /// Add depolarising noise to the noise rates of all qubits.
+    ///
+    /// Args:
+    ///     depolarising (float): The depolarising rate.
+    ///
+    /// Returns:
+    ///     Self: The new device with the new properties
+    #[pyo3(text_signature = "(depolarising, /)")]
+    pub fn with_depolarising_rates(&self, depolarising: f64) -> Self {
+        Self {
+            internal: self.internal.clone().with_depolarising_rates(depolarising),
+        }
+    }
+
+    /// Create a SquareLatticeDevice from a human-editable json config.
+    ///
+    /// The config contains default gate times per gate, overrides of the gate times on single qubits,
+    /// edges or sets of qubits, and damping, dephasing and depolarising rates with per-qubit overrides.
+    /// Calibrated gate errors are not part of the config.
+    ///
+    /// Args:
+    ///     config (str): The json config of the device.
+    ///
+    /// Returns:
+    ///     SquareLatticeDevice: The device described by the config.
+    ///
+    /// Raises:
+    ///     ValueError: The config cannot be deserialized or contains invalid qubits.
+    #[staticmethod]
+    #[pyo3(text_signature = "(config)")]
+    pub fn from_config_json(config: &str) -> PyResult<SquareLatticeDeviceWrapper> {
+        Ok(SquareLatticeDeviceWrapper {
+            internal: SquareLatticeDevice::from_config_json(config)
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+        })
+    }
+
+    /// Return the human-editable json config of the device.
+    ///
+    /// Returns:
+    ///     str: The json config of the device.
+    ///
+    /// Raises:
+    ///     ValueError: The decoherence rates of a qubit cannot be expressed in the config.
+    pub fn to_config_json(&self) -> PyResult<String> {
+        self.internal
+            .to_config_json()
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema of the json config of the device.
+    ///
+    /// Returns:
+    ///     str: The json schema of the config serialized to json
+    #[staticmethod]
+    pub fn config_json_schema() -> String {
+        let schema = schemars::schema_for!(roqoqo::devices::SquareLatticeDeviceConfig);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
     #[cfg(feature = "json_schema")]
     /// Return the JsonSchema for the json serialisation of the class.
     ///
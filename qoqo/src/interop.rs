@@ -0,0 +1,154 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interoperability of qoqo with other quantum computing python packages.
+//!
+//! The conversions only access the python objects of the other package via their attributes,
+//! the package itself is never imported by qoqo.
+
+use crate::CircuitWrapper;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+
+/// Convert a qiskit QuantumCircuit to a qoqo Circuit.
+///
+/// Every classical register of the qiskit circuit is defined as an output bit register
+/// with the same name and length. Supported instructions are
+/// h, x, y, z, s, t, rx, ry, rz, cx, cz, swap, ccx, measure and barrier.
+/// Barriers are converted to PragmaStopParallelBlock operations and a non-zero global phase
+/// of the qiskit circuit to a PragmaGlobalPhase operation.
+///
+/// Args:
+///     circuit (qiskit.QuantumCircuit): The qiskit circuit to convert.
+///
+/// Returns:
+///     Circuit: The converted qoqo circuit.
+///
+/// Raises:
+///     TypeError: Input is not a qiskit QuantumCircuit.
+///     ValueError: Circuit contains unsupported instructions or measurements into bits outside of a classical register.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, /)")]
+pub fn convert_qiskit_circuit(py_obj: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
+    let data = py_obj.getattr("data").map_err(|_| {
+        PyTypeError::new_err("Input is not a qiskit QuantumCircuit, `data` attribute is missing")
+    })?;
+    let mut circuit = Circuit::new();
+    for register in py_obj.getattr("cregs")?.iter()? {
+        let register = register?;
+        let name: String = register.getattr("name")?.extract()?;
+        let size: usize = register.getattr("size")?.extract()?;
+        circuit += DefinitionBit::new(name, size, true);
+    }
+    let mut unsupported: Vec<String> = Vec::new();
+    for instruction in data.iter()? {
+        let instruction = instruction?;
+        let operation = instruction.getattr("operation")?;
+        let name: String = operation.getattr("name")?.extract()?;
+        let qubits: Vec<usize> = instruction
+            .getattr("qubits")?
+            .iter()?
+            .map(|qubit| qubit_index(py_obj, &qubit?))
+            .collect::<PyResult<Vec<usize>>>()?;
+        match name.as_str() {
+            "h" => circuit += Hadamard::new(qubits[0]),
+            "x" => circuit += PauliX::new(qubits[0]),
+            "y" => circuit += PauliY::new(qubits[0]),
+            "z" => circuit += PauliZ::new(qubits[0]),
+            "s" => circuit += SGate::new(qubits[0]),
+            "t" => circuit += TGate::new(qubits[0]),
+            "rx" => circuit += RotateX::new(qubits[0], parameter(&operation, 0)?),
+            "ry" => circuit += RotateY::new(qubits[0], parameter(&operation, 0)?),
+            "rz" => circuit += RotateZ::new(qubits[0], parameter(&operation, 0)?),
+            "cx" => circuit += CNOT::new(qubits[0], qubits[1]),
+            "cz" => circuit += ControlledPauliZ::new(qubits[0], qubits[1]),
+            "swap" => circuit += SWAP::new(qubits[0], qubits[1]),
+            "ccx" => circuit += Toffoli::new(qubits[0], qubits[1], qubits[2]),
+            "measure" => {
+                let clbit = instruction.getattr("clbits")?.get_item(0)?;
+                let (readout, readout_index) = clbit_location(py_obj, &clbit)?;
+                circuit += MeasureQubit::new(qubits[0], readout, readout_index)
+            }
+            "barrier" => circuit += PragmaStopParallelBlock::new(qubits, CalculatorFloat::ZERO),
+            _ => {
+                if !unsupported.contains(&name) {
+                    unsupported.push(name)
+                }
+            }
+        }
+    }
+    if !unsupported.is_empty() {
+        return Err(PyValueError::new_err(format!(
+            "Qiskit circuit contains instructions not supported by qoqo: {}",
+            unsupported.join(", ")
+        )));
+    }
+    let global_phase = convert_parameter(&py_obj.getattr("global_phase")?)?;
+    if global_phase != CalculatorFloat::ZERO {
+        circuit += PragmaGlobalPhase::new(global_phase);
+    }
+    Ok(CircuitWrapper { internal: circuit })
+}
+
+/// Returns the index of a qiskit Qubit in the circuit.
+fn qubit_index(py_obj: &Bound<PyAny>, qubit: &Bound<PyAny>) -> PyResult<usize> {
+    py_obj
+        .call_method1("find_bit", (qubit,))?
+        .getattr("index")?
+        .extract()
+}
+
+/// Returns the name of the classical register and the index in the register of a qiskit Clbit.
+fn clbit_location(py_obj: &Bound<PyAny>, clbit: &Bound<PyAny>) -> PyResult<(String, usize)> {
+    let registers = py_obj
+        .call_method1("find_bit", (clbit,))?
+        .getattr("registers")?;
+    if registers.len()? == 0 {
+        return Err(PyValueError::new_err(
+            "Measured classical bit is not part of a classical register",
+        ));
+    }
+    let location = registers.get_item(0)?;
+    let name: String = location.get_item(0)?.getattr("name")?.extract()?;
+    let index: usize = location.get_item(1)?.extract()?;
+    Ok((name, index))
+}
+
+/// Returns the parameter of a qiskit instruction at the given position.
+fn parameter(operation: &Bound<PyAny>, index: usize) -> PyResult<CalculatorFloat> {
+    convert_parameter(&operation.getattr("params")?.get_item(index)?)
+}
+
+/// Converts a float or a qiskit ParameterExpression to a CalculatorFloat.
+fn convert_parameter(parameter: &Bound<PyAny>) -> PyResult<CalculatorFloat> {
+    match parameter.extract::<f64>() {
+        Ok(value) => Ok(CalculatorFloat::Float(value)),
+        Err(_) => Ok(CalculatorFloat::Str(parameter.str()?.to_string())),
+    }
+}
+
+/// Conversions between qoqo and other quantum computing python packages.
+///
+/// The other packages are only needed at runtime when the conversions are used.
+///
+/// .. autosummary::
+///     :toctree: generated/
+///
+///     convert_qiskit_circuit
+#[pymodule]
+pub fn interop(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(convert_qiskit_circuit, module)?)?;
+    Ok(())
+}
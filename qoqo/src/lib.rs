@@ -32,18 +32,42 @@ pub mod measurements;
 pub mod devices;
 
 mod circuit;
-pub use circuit::{convert_into_circuit, CircuitWrapper, OperationIteratorWrapper};
+pub use circuit::{CircuitWrapper, OperationIteratorWrapper};
 
 mod quantum_program;
-pub use quantum_program::{convert_into_quantum_program, QuantumProgramWrapper};
+pub use quantum_program::QuantumProgramWrapper;
+
+pub mod conversions;
+pub use conversions::{convert_into_circuit, convert_into_quantum_program};
 
 pub mod noise_models;
 
+#[cfg(feature = "interop")]
+pub mod interop;
+
+pub mod passes;
+
+#[cfg(feature = "unstable_analog_operations")]
+pub mod analog;
+
+pub mod backends;
+
+pub mod struqture_bridge;
+
+pub mod testing;
+
+pub mod registers;
+
 #[cfg(feature = "circuitdag")]
 mod circuitdag;
 #[cfg(feature = "circuitdag")]
 pub use circuitdag::{convert_into_circuitdag, CircuitDagWrapper};
 
+#[cfg(feature = "circuitdag")]
+mod schedule;
+#[cfg(feature = "circuitdag")]
+pub use schedule::{schedule_asap, ScheduledCircuitWrapper};
+
 /// qoqo version information, used for qoqo import/export checks
 pub const QOQO_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -114,7 +138,16 @@ pub fn available_gates_hqslang() -> Vec<String> {
 ///     measurements
 ///     devices
 ///     noise_models
+///     interop
+///     passes
+///     analog
+///     backends
+///     testing
 ///     available_gates_hqslang
+///     counts_from_registers
+///     register_from_counts
+///     registers_to_json
+///     registers_from_json
 ///
 
 #[pymodule]
@@ -123,7 +156,15 @@ fn qoqo(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<QuantumProgramWrapper>()?;
     #[cfg(feature = "circuitdag")]
     module.add_class::<CircuitDagWrapper>()?;
+    #[cfg(feature = "circuitdag")]
+    module.add_class::<ScheduledCircuitWrapper>()?;
+    #[cfg(feature = "circuitdag")]
+    module.add_function(wrap_pyfunction!(schedule_asap, module)?)?;
     module.add_function(wrap_pyfunction!(available_gates_hqslang, module)?)?;
+    module.add_function(wrap_pyfunction!(registers::counts_from_registers, module)?)?;
+    module.add_function(wrap_pyfunction!(registers::register_from_counts, module)?)?;
+    module.add_function(wrap_pyfunction!(registers::registers_to_json, module)?)?;
+    module.add_function(wrap_pyfunction!(registers::registers_from_json, module)?)?;
     let wrapper = wrap_pymodule!(operations::operations);
     module.add_wrapped(wrapper)?;
     let wrapper2 = wrap_pymodule!(measurements::measurements);
@@ -132,13 +173,31 @@ fn qoqo(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_wrapped(wrapper3)?;
     let wrapper4 = wrap_pymodule!(noise_models::noise_models);
     module.add_wrapped(wrapper4)?;
+    #[cfg(feature = "interop")]
+    module.add_wrapped(wrap_pymodule!(interop::interop))?;
+    module.add_wrapped(wrap_pymodule!(passes::passes))?;
+    #[cfg(feature = "unstable_analog_operations")]
+    module.add_wrapped(wrap_pymodule!(analog::analog))?;
+    module.add_wrapped(wrap_pymodule!(backends::backends))?;
+    module.add_wrapped(wrap_pymodule!(testing::testing))?;
     // Adding nice imports corresponding to maturin example
     let system = PyModule::import_bound(_py, "sys")?;
     let binding = system.getattr("modules")?;
     let system_modules: &Bound<PyDict> = binding.downcast()?;
     system_modules.set_item("qoqo.operations", module.getattr("operations")?)?;
     system_modules.set_item("qoqo.measurements", module.getattr("measurements")?)?;
+    system_modules.set_item(
+        "qoqo.measurements.statistics",
+        module.getattr("measurements")?.getattr("statistics")?,
+    )?;
     system_modules.set_item("qoqo.devices", module.getattr("devices")?)?;
     system_modules.set_item("qoqo.noise_models", module.getattr("noise_models")?)?;
+    #[cfg(feature = "interop")]
+    system_modules.set_item("qoqo.interop", module.getattr("interop")?)?;
+    system_modules.set_item("qoqo.passes", module.getattr("passes")?)?;
+    #[cfg(feature = "unstable_analog_operations")]
+    system_modules.set_item("qoqo.analog", module.getattr("analog")?)?;
+    system_modules.set_item("qoqo.backends", module.getattr("backends")?)?;
+    system_modules.set_item("qoqo.testing", module.getattr("testing")?)?;
     Ok(())
 }
@@ -22,7 +22,6 @@ use roqoqo::measurements::PauliZProduct;
 use roqoqo::prelude::*;
 use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use roqoqo::Circuit;
-#[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
 use std::collections::HashMap;
 
@@ -34,6 +33,7 @@ use std::collections::HashMap;
 ///     constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
 ///     circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations.
 ///     input (PauliZProductInput): The additional input information required for measurement.
+///     check_registers (bool): Whether to check the readout registers of the input against the circuits. Defaults to True.
 ///
 /// Returns:
 ///     PauliZProduct: The PauliZProduct containing the new PauliZ product measurement.
@@ -50,15 +50,21 @@ impl PauliZProductWrapper {
     ///     constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
     ///     circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations.
     ///     input (PauliZProductInput): The additional input information required for measurement.
+    ///     check_registers (bool): Whether to check that every readout register of the input is written by
+    ///                             the circuits and defined long enough for its Pauli products. Defaults to True.
     ///
     /// Returns:
     ///     PauliZProduct: The PauliZProduct containing the new PauliZ product measurement.
+    ///
+    /// Raises:
+    ///     ValueError: A readout register is not written by any circuit, not defined or too short.
     #[new]
-    #[pyo3(signature=(constant_circuit, circuits, input))]
+    #[pyo3(signature=(constant_circuit, circuits, input, check_registers=true))]
     pub fn new(
         constant_circuit: Option<Py<PyAny>>,
         circuits: Vec<Py<PyAny>>,
         input: Py<PyAny>,
+        check_registers: bool,
     ) -> PyResult<Self> {
         Python::with_gil(|py| -> PyResult<Self> {
             let mut new_circuits: Vec<Circuit> = Vec::new();
@@ -89,16 +95,67 @@ impl PauliZProductWrapper {
                     err
                 ))
             })?;
-            Ok(Self {
-                internal: PauliZProduct {
+            let internal = if check_registers {
+                PauliZProduct::new_checked(new_constant, new_circuits, input)
+                    .map_err(|err| PyValueError::new_err(format!("{}", err)))?
+            } else {
+                PauliZProduct {
                     input,
                     constant_circuit: new_constant,
                     circuits: new_circuits,
-                },
-            })
+                }
+            };
+            Ok(Self { internal })
         })
     }
 
+    /// Create a new PauliZProduct measurement, adding the flipped measurement circuits when required.
+    ///
+    /// When the input uses flipped measurements, a flipped copy of every circuit is appended to circuits.
+    /// In the flipped copy all measured qubits are flipped with a PauliX gate before their first measurement
+    /// and every readout register `<register>` of the input is replaced by `<register>_flipped`.
+    ///
+    /// Args:
+    ///     constant_circuit (Optional[Circuit]): The constant Circuit that is executed before each Circuit in circuits.
+    ///     circuits (List[Circuit]): The collection of quantum circuits for the separate basis rotations without flipped readout.
+    ///     input (PauliZProductInput): The additional input information required for measurement.
+    ///
+    /// Returns:
+    ///     PauliZProduct: The PauliZProduct including the flipped circuits.
+    ///
+    /// Raises:
+    ///     TypeError: The arguments are not qoqo Circuits or a PauliZProductInput.
+    ///     ValueError: A flipped readout register name is already used in the circuits.
+    #[staticmethod]
+    #[pyo3(signature=(constant_circuit, circuits, input))]
+    pub fn with_flipped_circuits(
+        constant_circuit: Option<Py<PyAny>>,
+        circuits: Vec<Py<PyAny>>,
+        input: Py<PyAny>,
+    ) -> PyResult<Self> {
+        let measurement = Self::new(constant_circuit, circuits, input, false)?.internal;
+        let internal = PauliZProduct::with_flipped_circuits(
+            measurement.constant_circuit,
+            measurement.circuits,
+            measurement.input,
+        )
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Return a copy of the measurement in which identical circuits are only kept once.
+    ///
+    /// The first occurrence of every circuit is kept and the order of the kept circuits is unchanged.
+    /// The input refers to the readout registers and is valid for the deduplicated circuits as well.
+    ///
+    /// Returns:
+    ///     Tuple[PauliZProduct, List[int]]: The measurement without duplicate circuits and the index
+    ///         of the kept circuit for every circuit of the original measurement.
+    pub fn deduplicate_circuits(&self) -> (Self, Vec<usize>) {
+        let (internal, mapping) = self.internal.deduplicate_circuits();
+        (Self { internal }, mapping)
+    }
+
     /// Execute the PauliZ product measurement.
     ///
     /// Args:
@@ -118,27 +175,7 @@ impl PauliZProductWrapper {
         float_registers: HashMap<String, FloatOutputRegister>,
         complex_registers: HashMap<String, ComplexOutputRegister>,
     ) -> PyResult<Option<HashMap<String, f64>>> {
-        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
-        let bit_registers_bool: PyResult<HashMap<String, Vec<Vec<bool>>>> =
-            input_bit_registers.extract::<HashMap<String, BitOutputRegister>>();
-        if let Ok(try_downcast) = bit_registers_bool {
-            bit_registers = try_downcast
-        } else {
-            let tmp_bit_registers =
-                input_bit_registers.extract::<HashMap<String, Vec<Vec<usize>>>>()?;
-            for (name, output_reg) in tmp_bit_registers {
-                let mut tmp_output_reg: Vec<Vec<bool>> = Vec::with_capacity(output_reg.len());
-                for reg in output_reg {
-                    tmp_output_reg.push(reg.into_iter().map(|x| !matches!(x, 0)).collect());
-                }
-                bit_registers.insert(name, tmp_output_reg);
-                // if t.is_some() {
-                //     return Err(PyRuntimeError::new_err(
-                //         "Unexpected repetition of key in bit_registers",
-                //     ));
-                // }
-            }
-        }
+        let bit_registers = extract_bit_registers(input_bit_registers)?;
         self.internal
             .evaluate(bit_registers, float_registers, complex_registers)
             .map_err(|x| {
@@ -149,6 +186,53 @@ impl PauliZProductWrapper {
             })
     }
 
+    /// Execute the PauliZ product measurement with weighted readout registers.
+    ///
+    /// The expectation values of the Pauli products measured in a readout register are multiplied
+    /// by the weight of the register. Registers without weight have weight 1.0.
+    /// When flipped measurements are used, the register `<register>_flipped` uses the weight
+    /// of `<register>` unless its own weight is given.
+    /// The expectation value of each Pauli product is additionally multiplied by its entry in
+    /// `pauli_product_weights`. Pauli products without weight have weight 1.0.
+    ///
+    /// Args:
+    ///     input_bit_registers (Dict[str, Union[List[List[int]], List[List[bool]]]]): The classical bit registers with the register name as key
+    ///     float_registers (Dict[str, List[List[float]]]): The classical float registers as a dictionary with the register name as key
+    ///     complex_registers (Dict[str, List[List[complex]]]): The classical complex registers as a dictionary with the register name as key
+    ///     weights (Dict[str, float]): The weights of the readout registers with the register name as key
+    ///     pauli_product_weights (Optional[Dict[int, float]]): The weights of the measured Pauli products with the Pauli product index as key
+    ///
+    /// Returns:
+    ///     Optional[Dict[str, float]]: The evaluated measurement.
+    ///
+    /// Raises:
+    ///     RuntimeError: Error evaluating PauliZ product measurement.
+    #[pyo3(signature=(input_bit_registers, float_registers, complex_registers, weights, pauli_product_weights=None))]
+    pub fn evaluate_weighted(
+        &mut self,
+        input_bit_registers: &Bound<PyAny>,
+        float_registers: HashMap<String, FloatOutputRegister>,
+        complex_registers: HashMap<String, ComplexOutputRegister>,
+        weights: HashMap<String, f64>,
+        pauli_product_weights: Option<HashMap<usize, f64>>,
+    ) -> PyResult<Option<HashMap<String, f64>>> {
+        let bit_registers = extract_bit_registers(input_bit_registers)?;
+        self.internal
+            .evaluate_weighted(
+                bit_registers,
+                float_registers,
+                complex_registers,
+                weights,
+                pauli_product_weights.unwrap_or_default(),
+            )
+            .map_err(|x| {
+                PyRuntimeError::new_err(format!(
+                    "Error evaluating PauliZ product measurement {:?}",
+                    x
+                ))
+            })
+    }
+
     /// Return the collection of quantum circuits for the separate basis rotations.
     ///
     /// Returns:
@@ -190,6 +274,16 @@ impl PauliZProductWrapper {
         "PauliZProduct"
     }
 
+    /// Check that all classical registers used by the circuits are defined and large enough.
+    ///
+    /// Raises:
+    ///     ValueError: A classical register is missing or too small.
+    pub fn validate(&self) -> PyResult<()> {
+        self.internal
+            .validate()
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))
+    }
+
     /// Return clone of Measurement with symbolic parameters replaced.
     ///
     /// Args:
@@ -347,7 +441,6 @@ impl PauliZProductWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -357,7 +450,6 @@ impl PauliZProductWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -398,3 +490,26 @@ impl PauliZProductWrapper {
         }
     }
 }
+
+/// Extracts bit registers given either as booleans or as integers.
+fn extract_bit_registers(
+    input_bit_registers: &Bound<PyAny>,
+) -> PyResult<HashMap<String, BitOutputRegister>> {
+    let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+    let bit_registers_bool: PyResult<HashMap<String, Vec<Vec<bool>>>> =
+        input_bit_registers.extract::<HashMap<String, BitOutputRegister>>();
+    if let Ok(try_downcast) = bit_registers_bool {
+        bit_registers = try_downcast
+    } else {
+        let tmp_bit_registers =
+            input_bit_registers.extract::<HashMap<String, Vec<Vec<usize>>>>()?;
+        for (name, output_reg) in tmp_bit_registers {
+            let mut tmp_output_reg: Vec<Vec<bool>> = Vec::with_capacity(output_reg.len());
+            for reg in output_reg {
+                tmp_output_reg.push(reg.into_iter().map(|x| !matches!(x, 0)).collect());
+            }
+            bit_registers.insert(name, tmp_output_reg);
+        }
+    }
+    Ok(bit_registers)
+}
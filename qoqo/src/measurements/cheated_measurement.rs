@@ -22,7 +22,6 @@ use roqoqo::measurements::Cheated;
 use roqoqo::prelude::*;
 use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use roqoqo::Circuit;
-#[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
 use std::collections::HashMap;
 
@@ -187,6 +186,16 @@ impl CheatedWrapper {
         "Cheated"
     }
 
+    /// Check that all classical registers used by the circuits are defined and large enough.
+    ///
+    /// Raises:
+    ///     ValueError: A classical register is missing or too small.
+    pub fn validate(&self) -> PyResult<()> {
+        self.internal
+            .validate()
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))
+    }
+
     /// Return copy of Measurement with symbolic parameters replaced.
     ///
     /// Arguments:
@@ -345,7 +354,6 @@ impl CheatedWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -355,7 +363,6 @@ impl CheatedWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -20,7 +20,6 @@ use pyo3::types::PyByteArray;
 use roqoqo::measurements::ClassicalRegister;
 use roqoqo::prelude::*;
 use roqoqo::Circuit;
-#[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
 use std::collections::HashMap;
 
@@ -119,6 +118,16 @@ impl ClassicalRegisterWrapper {
         "ClassicalRegister"
     }
 
+    /// Check that all classical registers used by the circuits are defined and large enough.
+    ///
+    /// Raises:
+    ///     ValueError: A classical register is missing or too small.
+    pub fn validate(&self) -> PyResult<()> {
+        self.internal
+            .validate()
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))
+    }
+
     /// Return copy of Measurement with symbolic parameters replaced.
     ///
     /// Args:
@@ -278,7 +287,6 @@ impl ClassicalRegisterWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -288,7 +296,6 @@ impl ClassicalRegisterWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -20,9 +20,10 @@ use pyo3::types::PyByteArray;
 use roqoqo::measurements::{
     CheatedInput, CheatedPauliZProductInput, PauliProductMask, PauliZProductInput,
 };
-#[cfg(feature = "json_schema")]
 use roqoqo::{operations::SupportedVersion, ROQOQO_VERSION};
 use std::collections::HashMap;
+use struqture::spins::{PauliProduct, SingleSpinOperator};
+use struqture::SpinIndex;
 
 #[pyclass(name = "PauliZProductInput", module = "qoqo.measurements")]
 #[derive(Clone, Debug)]
@@ -94,6 +95,30 @@ impl PauliZProductInputWrapper {
             .map_err(|_| PyRuntimeError::new_err("Failed to add pauli product"))
     }
 
+    /// Add many measured Pauli products on the same readout register at once.
+    ///
+    /// Equivalent to calling add_pauliz_product for every product in order.
+    /// When a Pauli product is already in the measurement input its existing index is returned.
+    ///
+    /// Args:
+    ///     readout (str): The name of the readout register the Pauli products are defined on.
+    ///     products (List[List[int]]): The lists of the qubits involved in each Pauli product measurement.
+    ///
+    /// Returns:
+    ///     List[int]: The indices of the Pauli products in the order of products.
+    ///
+    /// Raises:
+    ///     RuntimeError: Failed to add pauli products.
+    pub fn add_pauliz_products(
+        &mut self,
+        readout: &str,
+        products: Vec<PauliProductMask>,
+    ) -> PyResult<Vec<usize>> {
+        self.internal
+            .add_pauliz_products(readout, products)
+            .map_err(|x| PyRuntimeError::new_err(format!("Failed to add pauli products {:?}", x)))
+    }
+
     /// Add linear definition of expectation value to measurement input.
     ///
     /// Adds an expectation value that is defined by a linear combination
@@ -115,6 +140,74 @@ impl PauliZProductInputWrapper {
         })
     }
 
+    /// Add many linear definitions of expectation values at once.
+    ///
+    /// Args:
+    ///     entries (List[Tuple[str, Dict[int, float]]]): The names of the expectation values and their linear combinations.
+    ///
+    /// Raises:
+    ///     RuntimeError: Failed to add linear expectation values.
+    pub fn add_linear_exp_vals(
+        &mut self,
+        entries: Vec<(String, HashMap<usize, f64>)>,
+    ) -> PyResult<()> {
+        self.internal.add_linear_exp_vals(entries).map_err(|x| {
+            PyRuntimeError::new_err(format!("Failed to add linear expectation values {:?}", x))
+        })
+    }
+
+    /// Return the number of qubits that are measured.
+    ///
+    /// Returns:
+    ///     int: The number of qubits.
+    pub fn number_qubits(&self) -> usize {
+        self.internal.number_qubits()
+    }
+
+    /// Return the number of registered Pauli products.
+    ///
+    /// Returns:
+    ///     int: The number of Pauli products.
+    pub fn number_pauli_products(&self) -> usize {
+        self.internal.number_pauli_products()
+    }
+
+    /// Return the sorted names of the registered expectation values.
+    ///
+    /// Returns:
+    ///     List[str]: The names of the expectation values.
+    pub fn measured_exp_val_names(&self) -> Vec<String> {
+        self.internal.measured_exp_val_names()
+    }
+
+    /// Return the registered Pauli products ordered by their index.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, List[int]]]: The readout register and qubit mask of every Pauli product.
+    pub fn pauli_products(&self) -> Vec<(String, PauliProductMask)> {
+        self.internal.pauli_products()
+    }
+
+    /// Rename the readout registers in a clone of the PauliZProductInput.
+    ///
+    /// Readout registers that are not in the keys of the mapping keep their name.
+    ///
+    /// Args:
+    ///     mapping (Dict[str, str]): The dictionary containing the {old_name: new_name} mapping of the readout registers.
+    ///
+    /// Returns:
+    ///     PauliZProductInput: The PauliZProductInput with the readout registers renamed.
+    ///
+    /// Raises:
+    ///     RuntimeError: A readout register is mapped onto an existing readout register or two readout registers are mapped onto the same name.
+    pub fn remap_registers(&self, mapping: HashMap<String, String>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.remap_registers(&mapping).map_err(|x| {
+                PyRuntimeError::new_err(format!("Register remapping failed: {}", x))
+            })?,
+        })
+    }
+
     /// Add symbolic definition of expectation value to measurement input.
     ///
     /// Adds an expectation value that is defined by a symbolic combination
@@ -246,7 +339,6 @@ impl PauliZProductInputWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -256,7 +348,6 @@ impl PauliZProductInputWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -313,15 +404,64 @@ impl CheatedPauliZProductInputWrapper {
     /// Add measured Pauli product to CheatedPauliZProductInput and returns index of Pauli product.
     ///
     /// When the pauli product is already in the measurement input the function only returns
-    /// its index.
+    /// its index. Pauli products measured with PragmaGetIndexedPauliProduct are read from the
+    /// register entry `readout_index`. Inputs with a `readout_index` other than 0 can not be
+    /// serialized with to_bincode, use to_json instead.
     ///
     /// Args:
     ///     readout (str): The name of the readout register containing the pauli_product expectation value.
+    ///     readout_index (int): The index of the register entry containing the pauli_product expectation value. Defaults to 0.
+    ///
+    /// Returns:
+    ///     int: The index of the added Pauli product in the list of all Pauli products.
+    #[pyo3(signature=(readout, readout_index=0))]
+    pub fn add_pauliz_product(&mut self, readout: String, readout_index: usize) -> usize {
+        self.internal.add_pauliz_product_at(readout, readout_index)
+    }
+
+    /// Add a Pauli product with X, Y and Z factors and return its index.
+    ///
+    /// The expectation value of the Pauli product is evaluated directly on the statevector or
+    /// density matrix in the complex readout register. When the Pauli product is already in the
+    /// measurement input for the same readout the function only returns its index.
+    /// Inputs with these Pauli products can not be serialized with to_bincode, use to_json instead.
+    ///
+    /// Args:
+    ///     readout (str): The name of the complex readout register containing the statevector or density matrix.
+    ///     pauli_product (str): The Pauli product as space separated factors, e.g. "X0 Y2 Z3". The empty string and "I" are the identity.
     ///
     /// Returns:
     ///     int: The index of the added Pauli product in the list of all Pauli products.
-    pub fn add_pauliz_product(&mut self, readout: String) -> usize {
-        self.internal.add_pauliz_product(readout)
+    ///
+    /// Raises:
+    ///     ValueError: The Pauli product could not be parsed.
+    pub fn add_pauli_product(&mut self, readout: String, pauli_product: &str) -> PyResult<usize> {
+        let mut product = PauliProduct::new();
+        for factor in pauli_product.split_whitespace() {
+            if factor == "I" {
+                continue;
+            }
+            let mut chars = factor.chars();
+            let pauli = match chars.next() {
+                Some('X') => SingleSpinOperator::X,
+                Some('Y') => SingleSpinOperator::Y,
+                Some('Z') => SingleSpinOperator::Z,
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Invalid Pauli factor {} in {}",
+                        factor, pauli_product
+                    )))
+                }
+            };
+            let qubit: usize = chars.as_str().parse().map_err(|_| {
+                PyValueError::new_err(format!(
+                    "Invalid qubit in Pauli factor {} in {}",
+                    factor, pauli_product
+                ))
+            })?;
+            product = product.set_pauli(qubit, pauli);
+        }
+        Ok(self.internal.add_pauli_product(readout, product))
     }
 
     /// Add linear definition of expectation value to measurement input.
@@ -368,6 +508,43 @@ impl CheatedPauliZProductInputWrapper {
             })
     }
 
+    /// Return the number of qubits the Pauli products evaluated on complex registers act on.
+    ///
+    /// Pauli products read out from float registers do not define their qubits,
+    /// an input containing only those returns 0.
+    ///
+    /// Returns:
+    ///     int: The number of qubits.
+    pub fn number_qubits(&self) -> usize {
+        self.internal.number_qubits()
+    }
+
+    /// Return the number of registered Pauli products.
+    ///
+    /// Returns:
+    ///     int: The number of Pauli products.
+    pub fn number_pauli_products(&self) -> usize {
+        self.internal.number_pauli_products()
+    }
+
+    /// Return the sorted names of the registered expectation values.
+    ///
+    /// Returns:
+    ///     List[str]: The names of the expectation values.
+    pub fn measured_exp_val_names(&self) -> Vec<String> {
+        self.internal.measured_exp_val_names()
+    }
+
+    /// Return the registered Pauli products ordered by their index.
+    ///
+    /// The qubits of Pauli products read out from float registers are empty.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, List[int]]]: The readout register and the qubits of every Pauli product.
+    pub fn pauli_products(&self) -> Vec<(String, Vec<usize>)> {
+        self.internal.pauli_products()
+    }
+
     /// Serialize the CheatedPauliZProductInput to json form.
     ///
     /// Returns:
@@ -478,7 +655,6 @@ impl CheatedPauliZProductInputWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -488,7 +664,6 @@ impl CheatedPauliZProductInputWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -545,6 +720,8 @@ impl CheatedInputWrapper {
     /// Add operator based expectation value to measurement input.
     ///
     /// Adds an expectation value that is defined by an operator on the Hilbert space.
+    /// The operator is stored and evaluated in sparse form, only the non-zero entries need to be provided
+    /// and entries with the same (row, col) are summed up (as for scipy.sparse.coo_matrix).
     ///
     /// Args:
     ///     name (str): The name of the expectation value.
@@ -570,6 +747,30 @@ impl CheatedInputWrapper {
             })
     }
 
+    /// Return the number of qubits that defines the dimension of the Hilbert space.
+    ///
+    /// Returns:
+    ///     int: The number of qubits.
+    pub fn number_qubits(&self) -> usize {
+        self.internal.number_qubits()
+    }
+
+    /// Return the sorted names of the registered expectation values.
+    ///
+    /// Returns:
+    ///     List[str]: The names of the expectation values.
+    pub fn measured_exp_val_names(&self) -> Vec<String> {
+        self.internal.measured_exp_val_names()
+    }
+
+    /// Return the names of the measured operators with the dimension of their matrices, sorted by name.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, int]]: The name and dimension of every measured operator.
+    pub fn operator_dimensions(&self) -> Vec<(String, usize)> {
+        self.internal.operator_dimensions()
+    }
+
     /// Serialize the CheatedInput to json form.
     ///
     /// Returns:
@@ -678,7 +879,6 @@ impl CheatedInputWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -688,7 +888,6 @@ impl CheatedInputWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -13,6 +13,7 @@
 //! Qoqo measurements
 #[allow(unused_imports)] // reported unused import is wrong, compilation fails without import
 use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
 mod measurement_auxiliary_data_input;
 pub use measurement_auxiliary_data_input::{
     CheatedInputWrapper, CheatedPauliZProductInputWrapper, PauliZProductInputWrapper,
@@ -25,6 +26,9 @@ mod cheated_measurement;
 pub use cheated_measurement::CheatedWrapper;
 mod classical_register_measurement;
 pub use classical_register_measurement::ClassicalRegisterWrapper;
+mod records;
+pub mod statistics;
+pub use records::{expectation_values_to_records, registers_to_records};
 
 /// Measurements
 ///     
@@ -39,6 +43,9 @@ pub use classical_register_measurement::ClassicalRegisterWrapper;
 ///     DecoherenceOnIdleModel
 ///     Cheated
 ///     ClassicalRegister
+///     registers_to_records
+///     expectation_values_to_records
+///     statistics
 #[pymodule]
 pub fn measurements(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PauliZProductInputWrapper>()?;
@@ -48,6 +55,9 @@ pub fn measurements(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<CheatedPauliZProductWrapper>()?;
     m.add_class::<CheatedWrapper>()?;
     m.add_class::<ClassicalRegisterWrapper>()?;
+    m.add_function(wrap_pyfunction!(registers_to_records, m)?)?;
+    m.add_function(wrap_pyfunction!(expectation_values_to_records, m)?)?;
+    m.add_wrapped(wrap_pymodule!(statistics::statistics))?;
 
     Ok(())
 }
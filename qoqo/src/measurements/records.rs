@@ -0,0 +1,151 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of measurement results to records, one dictionary per value, for tables like pandas DataFrames.
+
+use std::collections::{BTreeMap, HashMap};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
+
+/// Keys of the records of expectation values that cannot be used as parameter names.
+const EXPECTATION_VALUE_KEYS: [&str; 3] = ["sweep_index", "name", "value"];
+
+/// Flatten the output registers of a circuit run into records.
+///
+/// Every value of the registers becomes one record, a dictionary with the keys
+/// `register` (the name of the register), `shot_index` (the index of the measurement record),
+/// `position` (the index of the value in the measurement record) and `value`.
+/// The records of the bit, float and complex registers follow each other, the registers of each type
+/// are sorted by name. The list can be passed directly to `pandas.DataFrame(records)`.
+///
+/// Args:
+///     bit_registers (Dict[str, List[List[bool]]]): The bit registers.
+///     float_registers (Dict[str, List[List[float]]]): The float registers.
+///     complex_registers (Dict[str, List[List[complex]]]): The complex registers.
+///
+/// Returns:
+///     List[Dict[str, Union[str, int, bool, float, complex]]]: The records of all register values.
+///
+/// Raises:
+///     TypeError: The input cannot be converted to registers.
+#[pyfunction]
+#[pyo3(text_signature = "(bit_registers, float_registers, complex_registers, /)")]
+pub fn registers_to_records(
+    py: Python,
+    bit_registers: HashMap<String, BitOutputRegister>,
+    float_registers: HashMap<String, FloatOutputRegister>,
+    complex_registers: HashMap<String, ComplexOutputRegister>,
+) -> PyResult<Py<PyList>> {
+    let number_records = count_values(&bit_registers)
+        + count_values(&float_registers)
+        + count_values(&complex_registers);
+    let mut records: Vec<PyObject> = Vec::with_capacity(number_records);
+    append_records(py, &mut records, bit_registers)?;
+    append_records(py, &mut records, float_registers)?;
+    append_records(py, &mut records, complex_registers)?;
+    Ok(PyList::new_bound(py, records).unbind())
+}
+
+/// Flatten the expectation values of a parameter sweep into records.
+///
+/// Every expectation value becomes one record, a dictionary containing the parameters of
+/// the run followed by the keys `sweep_index` (the index of the run), `name` (the name of the
+/// expectation value) and `value`. The expectation values of each run are sorted by name,
+/// runs returning None are skipped. The list can be passed directly to `pandas.DataFrame(records)`.
+///
+/// Args:
+///     results (List[Optional[Dict[str, float]]]): The expectation values returned by `QuantumProgram.run` for each run.
+///     parameter_sets (List[Dict[str, float]]): The parameters of each run,
+///                                              e.g. `dict(zip(program.input_parameter_names(), parameters))`.
+///
+/// Returns:
+///     List[Dict[str, Union[str, int, float]]]: The records of all expectation values.
+///
+/// Raises:
+///     TypeError: The input cannot be converted to expectation values or parameters.
+///     ValueError: The number of results and parameter sets differ or a parameter is named like a record key.
+#[pyfunction]
+#[pyo3(text_signature = "(results, parameter_sets, /)")]
+pub fn expectation_values_to_records(
+    py: Python,
+    results: Vec<Option<HashMap<String, f64>>>,
+    parameter_sets: Vec<Bound<PyDict>>,
+) -> PyResult<Py<PyList>> {
+    if results.len() != parameter_sets.len() {
+        return Err(PyValueError::new_err(format!(
+            "Got {} results but {} parameter sets",
+            results.len(),
+            parameter_sets.len()
+        )));
+    }
+    for parameters in parameter_sets.iter() {
+        for key in EXPECTATION_VALUE_KEYS {
+            if parameters.contains(key)? {
+                return Err(PyValueError::new_err(format!(
+                    "Parameter name {} is used as a key of the records",
+                    key
+                )));
+            }
+        }
+    }
+    let number_records = results.iter().flatten().map(|result| result.len()).sum();
+    let mut records: Vec<PyObject> = Vec::with_capacity(number_records);
+    for (sweep_index, (result, parameters)) in results.into_iter().zip(parameter_sets).enumerate() {
+        let Some(result) = result else {
+            continue;
+        };
+        for (name, value) in result.into_iter().collect::<BTreeMap<String, f64>>() {
+            let record = parameters.copy()?;
+            record.set_item(intern!(py, "sweep_index"), sweep_index)?;
+            record.set_item(intern!(py, "name"), name)?;
+            record.set_item(intern!(py, "value"), value)?;
+            records.push(record.into_any().unbind());
+        }
+    }
+    Ok(PyList::new_bound(py, records).unbind())
+}
+
+/// Returns the number of values in all registers.
+fn count_values<T>(registers: &HashMap<String, Vec<Vec<T>>>) -> usize {
+    registers
+        .values()
+        .flat_map(|register| register.iter().map(|shot| shot.len()))
+        .sum()
+}
+
+/// Appends one record per value of the registers, sorted by register name.
+fn append_records<T: ToPyObject>(
+    py: Python,
+    records: &mut Vec<PyObject>,
+    registers: HashMap<String, Vec<Vec<T>>>,
+) -> PyResult<()> {
+    let mut names: Vec<&String> = registers.keys().collect();
+    names.sort();
+    for name in names {
+        let register_name = name.to_object(py);
+        for (shot_index, shot) in registers[name].iter().enumerate() {
+            for (position, value) in shot.iter().enumerate() {
+                let record = PyDict::new_bound(py);
+                record.set_item(intern!(py, "register"), &register_name)?;
+                record.set_item(intern!(py, "shot_index"), shot_index)?;
+                record.set_item(intern!(py, "position"), position)?;
+                record.set_item(intern!(py, "value"), value.to_object(py))?;
+                records.push(record.into_any().unbind());
+            }
+        }
+    }
+    Ok(())
+}
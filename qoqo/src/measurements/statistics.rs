@@ -0,0 +1,161 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics of the measurement records in bit registers.
+
+use numpy::PyReadonlyArray2;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use roqoqo::measurements::statistics as roqoqo_statistics;
+use roqoqo::registers::BitOutputRegister;
+
+/// Return the frequency of measuring 1 for each qubit.
+///
+/// Args:
+///     register (Union[np.ndarray, List[List[bool]]]): The measurement records, one row per record.
+///
+/// Returns:
+///     List[float]: The frequency of True for each entry of the records.
+///
+/// Raises:
+///     TypeError: The register cannot be converted to a bit register.
+///     ValueError: The register is empty or the records have different lengths.
+#[pyfunction]
+#[pyo3(text_signature = "(register, /)")]
+pub fn bit_frequencies(register: &Bound<PyAny>) -> PyResult<Vec<f64>> {
+    roqoqo_statistics::bit_frequencies(&extract_register(register)?)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+/// Return the connected correlation <b_i b_j> - <b_i><b_j> of the bits of two qubits.
+///
+/// Args:
+///     register (Union[np.ndarray, List[List[bool]]]): The measurement records, one row per record.
+///     i (int): The first qubit.
+///     j (int): The second qubit.
+///
+/// Returns:
+///     float: The correlation of the two bits.
+///
+/// Raises:
+///     TypeError: The register cannot be converted to a bit register.
+///     ValueError: The register is empty or a record is too short for the qubits.
+#[pyfunction]
+#[pyo3(text_signature = "(register, i, j, /)")]
+pub fn bit_correlation(register: &Bound<PyAny>, i: usize, j: usize) -> PyResult<f64> {
+    roqoqo_statistics::bit_correlation(&extract_register(register)?, i, j)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+/// Return the histogram of the joint outcomes of a set of qubits.
+///
+/// Args:
+///     register (Union[np.ndarray, List[List[bool]]]): The measurement records, one row per record.
+///     qubits (List[int]): The qubits, the outcomes list the bits in this order.
+///
+/// Returns:
+///     Dict[Tuple[bool, ...], int]: The number of records for each measured outcome of the qubits.
+///
+/// Raises:
+///     TypeError: The register cannot be converted to a bit register.
+///     ValueError: The register is empty or a record is too short for the qubits.
+#[pyfunction]
+#[pyo3(text_signature = "(register, qubits, /)")]
+pub fn joint_histogram(
+    py: Python,
+    register: &Bound<PyAny>,
+    qubits: Vec<usize>,
+) -> PyResult<Py<PyDict>> {
+    let histogram = roqoqo_statistics::joint_histogram(&extract_register(register)?, &qubits)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    let dict = PyDict::new_bound(py);
+    for (outcome, count) in histogram.into_iter() {
+        dict.set_item(PyTuple::new_bound(py, outcome), count)?;
+    }
+    Ok(dict.unbind())
+}
+
+/// Return the mutual information of the bits of two qubits in bits.
+///
+/// Args:
+///     register (Union[np.ndarray, List[List[bool]]]): The measurement records, one row per record.
+///     i (int): The first qubit.
+///     j (int): The second qubit.
+///
+/// Returns:
+///     float: The mutual information, between 0 and 1.
+///
+/// Raises:
+///     TypeError: The register cannot be converted to a bit register.
+///     ValueError: The register is empty or a record is too short for the qubits.
+#[pyfunction]
+#[pyo3(text_signature = "(register, i, j, /)")]
+pub fn mutual_information(register: &Bound<PyAny>, i: usize, j: usize) -> PyResult<f64> {
+    roqoqo_statistics::mutual_information(&extract_register(register)?, i, j)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+/// Extracts a bit register given as a numpy array or nested list of booleans or integers.
+fn extract_register(register: &Bound<PyAny>) -> PyResult<BitOutputRegister> {
+    // Lists are handled first, so the NumPy API is only used for arrays
+    if register.is_instance_of::<PyList>() {
+        if let Ok(records) = register.extract::<BitOutputRegister>() {
+            return Ok(records);
+        }
+        if let Ok(records) = register.extract::<Vec<Vec<usize>>>() {
+            return Ok(records
+                .into_iter()
+                .map(|record| record.into_iter().map(|bit| bit != 0).collect())
+                .collect());
+        }
+    } else if let Ok(array) = register.extract::<PyReadonlyArray2<bool>>() {
+        return Ok(array
+            .as_array()
+            .rows()
+            .into_iter()
+            .map(|row| row.to_vec())
+            .collect());
+    } else if let Ok(array) = register.extract::<PyReadonlyArray2<i64>>() {
+        return Ok(array
+            .as_array()
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(|bit| *bit != 0).collect())
+            .collect());
+    }
+    Err(PyTypeError::new_err(
+        "Register cannot be converted to a bit register".to_string(),
+    ))
+}
+
+/// Statistics of the measurement records in bit registers.
+///
+/// The functions evaluate the raw registers returned by ClassicalRegister measurements.
+/// Registers can be given as numpy arrays or nested lists of booleans or integers
+/// with one row per measurement record.
+///
+/// .. autosummary::
+///
+///     bit_frequencies
+///     bit_correlation
+///     joint_histogram
+///     mutual_information
+///
+#[pymodule]
+pub fn statistics(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(bit_frequencies, module)?)?;
+    module.add_function(wrap_pyfunction!(bit_correlation, module)?)?;
+    module.add_function(wrap_pyfunction!(joint_histogram, module)?)?;
+    module.add_function(wrap_pyfunction!(mutual_information, module)?)?;
+    Ok(())
+}
@@ -10,7 +10,11 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pyo3::{exceptions::PyValueError, prelude::*};
+use crate::struqture_bridge::noise_operator_from_python;
+use crate::CircuitWrapper;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo_calculator_pyo3::convert_into_calculator_float;
 use qoqo_macros::noise_model_wrapper;
 use roqoqo::noise_models::{ContinuousDecoherenceModel, NoiseModel};
 #[cfg(feature = "json_schema")]
@@ -56,16 +60,8 @@ impl ContinuousDecoherenceModelWrapper {
     #[new]
     pub fn new(noise_operator: Option<&Bound<PyAny>>) -> PyResult<Self> {
         if let Some(lindblad_operator) = noise_operator {
-            let noise_operator: struqture::spins::PlusMinusLindbladNoiseOperator =
-                match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_pyany(
-                    lindblad_operator,
-                ) {
-                    Ok(x) => x,
-                    Err(_) => match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_struqture_2(lindblad_operator) {
-                        Ok(x) => x.internal,
-                        Err(err) => return Err(PyValueError::new_err(format!("Could not convert input noise_operator from either struqture 1.x or struqture 2.x: {:?}", err))),
-                    }
-                };
+            let noise_operator =
+                noise_operator_from_python(lindblad_operator.py(), lindblad_operator)?;
             Ok(Self {
                 internal: ContinuousDecoherenceModel::from(noise_operator),
             })
@@ -209,3 +205,42 @@ impl ContinuousDecoherenceModelWrapper {
         }
     }
 }
+
+/// Create a circuit applying the noise of a ContinuousDecoherenceModel to idling qubits.
+///
+/// The noise of each qubit is applied with one PragmaGeneralNoise containing the rates of the model
+/// in the basis σ+, σ-, σz. Qubits without noise in the model are skipped.
+///
+/// Args:
+///     qubits (List[int]): The idling qubits.
+///     duration (CalculatorFloat): The time (in seconds) the qubits are idling.
+///     model (ContinuousDecoherenceModel): The noise model containing the decoherence rates.
+///
+/// Returns:
+///     Circuit: The circuit containing one PragmaGeneralNoise per noisy qubit.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to CalculatorFloat or ContinuousDecoherenceModel.
+///     ValueError: The model contains noise acting on several qubits or a rate that is not a real number.
+#[pyfunction]
+#[pyo3(text_signature = "(qubits, duration, model, /)")]
+pub fn idle_noise_circuit(
+    qubits: Vec<usize>,
+    duration: &Bound<PyAny>,
+    model: &Bound<PyAny>,
+) -> PyResult<CircuitWrapper> {
+    let duration = convert_into_calculator_float(duration).map_err(|_| {
+        PyTypeError::new_err("Argument duration cannot be converted to CalculatorFloat")
+    })?;
+    let model = match ContinuousDecoherenceModelWrapper::from_pyany(model) {
+        Ok(NoiseModel::ContinuousDecoherenceModel(model)) => model,
+        _ => {
+            return Err(PyTypeError::new_err(
+                "Input cannot be converted to ContinuousDecoherenceModel",
+            ))
+        }
+    };
+    let circuit = roqoqo::noise_models::idle_noise_circuit(&qubits, duration, &model)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    Ok(CircuitWrapper { internal: circuit })
+}
@@ -10,7 +10,8 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pyo3::{exceptions::PyValueError, prelude::*};
+use crate::struqture_bridge::noise_operator_from_python;
+use pyo3::prelude::*;
 use qoqo_macros::noise_model_wrapper;
 use roqoqo::noise_models::{DecoherenceOnGateModel, NoiseModel};
 #[cfg(feature = "json_schema")]
@@ -77,16 +78,7 @@ impl DecoherenceOnGateModelWrapper {
         qubit: usize,
         noise_operator: &Bound<PyAny>,
     ) -> PyResult<Self> {
-        let noise_operator: struqture::spins::PlusMinusLindbladNoiseOperator =
-            match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_pyany(
-                noise_operator,
-            ) {
-                Ok(x) => x,
-                Err(_) => match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_struqture_2(noise_operator) {
-                    Ok(x) => x.internal,
-                    Err(err) => return Err(PyValueError::new_err(format!("Could not convert input noise_operator from either struqture 1.x or struqture 2.x: {:?}", err))),
-                }
-            };
+        let noise_operator = noise_operator_from_python(noise_operator.py(), noise_operator)?;
         Ok(Self {
             internal: self.internal.clone().set_single_qubit_gate_error(
                 gate,
@@ -138,16 +130,7 @@ impl DecoherenceOnGateModelWrapper {
         target: usize,
         noise_operator: &Bound<PyAny>,
     ) -> PyResult<Self> {
-        let noise_operator: struqture::spins::PlusMinusLindbladNoiseOperator =
-            match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_pyany(
-                noise_operator,
-            ) {
-                Ok(x) => x,
-                Err(_) => match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_struqture_2(noise_operator) {
-                    Ok(x) => x.internal,
-                    Err(err) => return Err(PyValueError::new_err(format!("Could not convert input noise_operator from either struqture 1.x or struqture 2.x: {:?}", err))),
-                }
-            };
+        let noise_operator = noise_operator_from_python(noise_operator.py(), noise_operator)?;
         Ok(Self {
             internal: self.internal.clone().set_two_qubit_gate_error(
                 gate,
@@ -182,6 +165,45 @@ impl DecoherenceOnGateModelWrapper {
             )
     }
 
+    /// Set the same extra noise for a two qubit gate in both orientations.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     qubit_a (int): The first qubit the gate acts on.
+    ///     qubit_b (int): The second qubit the gate acts on.
+    ///     noise_operator (struqture_py.spins.PlusMinusLindbladNoiseOperator): The noise affecting system when gate is applied.
+    ///
+    /// Returns:
+    ///     Self: The error model with the new noise on gate set for both orientations.
+    ///
+    /// Raises:
+    ///     PyTypeError: Noise operator is not a struqture.spins.PlusMinusLindbladNoiseOperator.
+    pub fn set_two_qubit_gate_error_symmetric(
+        &self,
+        gate: &str,
+        qubit_a: usize,
+        qubit_b: usize,
+        noise_operator: &Bound<PyAny>,
+    ) -> PyResult<Self> {
+        let noise_operator = noise_operator_from_python(noise_operator.py(), noise_operator)?;
+        Ok(Self {
+            internal: self.internal.clone().set_two_qubit_gate_error_symmetric(
+                gate,
+                qubit_a,
+                qubit_b,
+                noise_operator,
+            ),
+        })
+    }
+
+    /// Return all two qubit gates with extra noise.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, int, int]]: The name, control and target qubit of the gates, sorted.
+    pub fn get_all_two_qubit_gate_errors(&self) -> Vec<(String, usize, usize)> {
+        self.internal.get_all_two_qubit_gate_errors()
+    }
+
     /// Set extra noise for a single qubit gate.
     ///
     /// Args:
@@ -204,16 +226,7 @@ impl DecoherenceOnGateModelWrapper {
         target: usize,
         noise_operator: &Bound<PyAny>,
     ) -> PyResult<Self> {
-        let noise_operator: struqture::spins::PlusMinusLindbladNoiseOperator =
-            match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_pyany(
-                noise_operator,
-            ) {
-                Ok(x) => x,
-                Err(_) => match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_struqture_2(noise_operator) {
-                    Ok(x) => x.internal,
-                    Err(err) => return Err(PyValueError::new_err(format!("Could not convert input noise_operator from either struqture 1.x or struqture 2.x: {:?}", err))),
-                }
-            };
+        let noise_operator = noise_operator_from_python(noise_operator.py(), noise_operator)?;
         Ok(Self {
             internal: self.internal.clone().set_three_qubit_gate_error(
                 gate,
@@ -269,16 +282,7 @@ impl DecoherenceOnGateModelWrapper {
         qubits: Vec<usize>,
         noise_operator: &Bound<PyAny>,
     ) -> PyResult<Self> {
-        let noise_operator: struqture::spins::PlusMinusLindbladNoiseOperator =
-            match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_pyany(
-                noise_operator,
-            ) {
-                Ok(x) => x,
-                Err(_) => match struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_struqture_2(noise_operator) {
-                    Ok(x) => x.internal,
-                    Err(err) => return Err(PyValueError::new_err(format!("Could not convert input noise_operator from either struqture 1.x or struqture 2.x: {:?}", err))),
-                }
-            };
+        let noise_operator = noise_operator_from_python(noise_operator.py(), noise_operator)?;
         Ok(Self {
             internal: self.internal.clone().set_multi_qubit_gate_error(
                 gate,
@@ -310,6 +314,83 @@ impl DecoherenceOnGateModelWrapper {
             )
     }
 
+    /// Remove the extra noise for a single qubit gate.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     qubit (int): The qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     Self: The error model without noise on the gate.
+    pub fn remove_single_qubit_gate_error(&self, gate: &str, qubit: usize) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .remove_single_qubit_gate_error(gate, qubit),
+        }
+    }
+
+    /// Remove the extra noise for a two qubit gate in the given orientation.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     control (int): The control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     Self: The error model without noise on the gate.
+    pub fn remove_two_qubit_gate_error(&self, gate: &str, control: usize, target: usize) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .remove_two_qubit_gate_error(gate, control, target),
+        }
+    }
+
+    /// Remove the extra noise for a three qubit gate.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     control0 (int): The first control qubit the gate acts on.
+    ///     control1 (int): The second control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     Self: The error model without noise on the gate.
+    pub fn remove_three_qubit_gate_error(
+        &self,
+        gate: &str,
+        control0: usize,
+        control1: usize,
+        target: usize,
+    ) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .remove_three_qubit_gate_error(gate, control0, control1, target),
+        }
+    }
+
+    /// Remove the extra noise for a multi qubit gate.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     qubits (List[int]): The qubits the gate acts on.
+    ///
+    /// Returns:
+    ///     Self: The error model without noise on the gate.
+    pub fn remove_multi_qubit_gate_error(&self, gate: &str, qubits: Vec<usize>) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .remove_multi_qubit_gate_error(gate, qubits),
+        }
+    }
+
     /// Convert the bincode representation of the Noise-Model to a device using the bincode crate.
     ///
     /// Args:
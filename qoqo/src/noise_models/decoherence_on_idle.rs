@@ -10,6 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::struqture_bridge::noise_operator_from_python;
 use pyo3::prelude::*;
 use qoqo_macros::noise_model_wrapper;
 use roqoqo::noise_models::{DecoherenceOnIdleModel, NoiseModel};
@@ -56,9 +57,7 @@ impl DecoherenceOnIdleModelWrapper {
     pub fn new(noise_operator: Option<&Bound<PyAny>>) -> PyResult<Self> {
         if let Some(lindblad_operator) = noise_operator {
             let noise_operator =
-                struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper::from_pyany(
-                    lindblad_operator,
-                )?;
+                noise_operator_from_python(lindblad_operator.py(), lindblad_operator)?;
             Ok(Self {
                 internal: DecoherenceOnIdleModel::from(noise_operator),
             })
@@ -194,4 +194,56 @@ impl ImperfectReadoutModelWrapper {
     pub fn prob_detect_1_as_0(&self, qubit: usize) -> f64 {
         self.internal.prob_detect_1_as_0(&qubit)
     }
+
+    /// Return both readout error probabilities for a qubit
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the probabilities are returned.
+    ///
+    /// Returns:
+    ///     Tuple[float, float]: The probabilities to detect 0 as 1 and to detect 1 as 0 for the qubit
+    pub fn flip_probabilities(&self, qubit: usize) -> (f64, f64) {
+        self.internal.flip_probabilities(&qubit)
+    }
+
+    /// Apply the readout errors to an ideal bit register
+    ///
+    /// Every bit is flipped with the probability to detect 0 as 1 when it is 0
+    /// and with the probability to detect 1 as 0 when it is 1.
+    /// The bit in column i of every measurement is the readout of qubit qubits[i].
+    /// Bits in columns without an entry in qubits are not changed.
+    ///
+    /// Args:
+    ///     register (Union[List[List[bool]], List[List[int]], np.ndarray]): The ideal bit register, one row per measurement.
+    ///     qubits (List[int]): The qubit read out into each column of the register.
+    ///     seed (int): The seed of the random number generator.
+    ///
+    /// Returns:
+    ///     List[List[bool]]: The bit register with readout errors
+    ///
+    /// Raises:
+    ///     TypeError: The register cannot be converted to a bit register.
+    pub fn apply_to_register(
+        &self,
+        register: &Bound<PyAny>,
+        qubits: Vec<usize>,
+        seed: u64,
+    ) -> PyResult<Vec<Vec<bool>>> {
+        let register: Vec<Vec<bool>> = match register.extract::<Vec<Vec<bool>>>() {
+            Ok(register) => register,
+            Err(_) => register
+                .extract::<Vec<Vec<usize>>>()
+                .map_err(|_| {
+                    pyo3::exceptions::PyTypeError::new_err(
+                        "Register cannot be converted to a list of lists of bits",
+                    )
+                })?
+                .into_iter()
+                .map(|row| row.into_iter().map(|bit| bit != 0).collect())
+                .collect(),
+        };
+        Ok(self
+            .internal
+            .apply_to_register_with_seed(&register, &qubits, seed))
+    }
 }
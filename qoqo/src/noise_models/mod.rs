@@ -15,7 +15,7 @@
 //! Collection of pyo3 wrappers around roqoqo noise models.
 
 mod continuous_decoherence;
-pub use continuous_decoherence::ContinuousDecoherenceModelWrapper;
+pub use continuous_decoherence::{idle_noise_circuit, ContinuousDecoherenceModelWrapper};
 mod imperfect_readout;
 pub use imperfect_readout::ImperfectReadoutModelWrapper;
 mod decoherence_on_gate;
@@ -39,6 +39,7 @@ use pyo3::prelude::*;
 ///     SingleQubitOverrotationDescription
 ///     SingleQubitOverrotationOnGate
 ///     DecoherenceOnIdleModel
+///     idle_noise_circuit
 #[pymodule]
 pub fn noise_models(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<ContinuousDecoherenceModelWrapper>()?;
@@ -47,5 +48,6 @@ pub fn noise_models(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<SingleQubitOverrotationDescriptionWrapper>()?;
     module.add_class::<SingleQubitOverrotationOnGateWrapper>()?;
     module.add_class::<DecoherenceOnIdleModelWrapper>()?;
+    module.add_function(wrap_pyfunction!(idle_noise_circuit, module)?)?;
     Ok(())
 }
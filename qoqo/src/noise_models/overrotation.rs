@@ -429,6 +429,131 @@ impl SingleQubitOverrotationOnGateWrapper {
             })
     }
 
+    /// Set the same overrotation for a single qubit gate on all qubits.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     number_qubits (int): The number of qubits, the overrotation is set for qubits 0 to number_qubits - 1.
+    ///     noise_description (SingleQubitOverrotationDescription) - overrotation description for gate.
+    ///
+    /// Returns:
+    ///     Self: The overrotation model with the new overrotation on gate set.
+    ///
+    /// Raises:
+    ///     PyTypeError: Noise description is not a SingleQubitOverrotationDescription.
+    pub fn set_single_qubit_overrotation_for_all_qubits(
+        &self,
+        gate: &str,
+        number_qubits: usize,
+        noise_description: &Bound<PyAny>,
+    ) -> PyResult<Self> {
+        let noise_description =
+            SingleQubitOverrotationDescriptionWrapper::from_pyany(noise_description)?;
+        Ok(Self {
+            internal: self
+                .internal
+                .clone()
+                .set_single_qubit_overrotation_for_all_qubits(
+                    gate,
+                    number_qubits,
+                    noise_description,
+                ),
+        })
+    }
+
+    /// Set the same overrotation for a two qubit gate on all pairs of different qubits in both orientations.
+    ///
+    /// Args:
+    ///     gate (str): The name of the gate.
+    ///     number_qubits (int): The number of qubits, the overrotation is set for qubits 0 to number_qubits - 1.
+    ///     noise_description ((SingleQubitOverrotationDescription, SingleQubitOverrotationDescription)) - overrotation description for gate.
+    ///
+    /// Returns:
+    ///     Self: The overrotation model with the new overrotation on gate set.
+    ///
+    /// Raises:
+    ///     PyTypeError: Noise description is not a (SingleQubitOverrotationDescription, SingleQubitOverrotationDescription).
+    pub fn set_two_qubit_overrotation_for_all_qubits(
+        &self,
+        gate: &str,
+        number_qubits: usize,
+        noise_description: (Bound<PyAny>, Bound<PyAny>),
+    ) -> PyResult<Self> {
+        let noise1 = SingleQubitOverrotationDescriptionWrapper::from_pyany(&noise_description.0)?;
+        let noise2 = SingleQubitOverrotationDescriptionWrapper::from_pyany(&noise_description.1)?;
+        Ok(Self {
+            internal: self
+                .internal
+                .clone()
+                .set_two_qubit_overrotation_for_all_qubits(gate, number_qubits, (noise1, noise2)),
+        })
+    }
+
+    /// Return all single qubit gates with overrotation.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, int, SingleQubitOverrotationDescription]]: The name, qubit and overrotation of the gates, sorted by name and qubit.
+    pub fn get_all_single_qubit_overrotations(
+        &self,
+    ) -> Vec<(String, usize, SingleQubitOverrotationDescriptionWrapper)> {
+        self.internal
+            .get_all_single_qubit_overrotations()
+            .into_iter()
+            .map(|(gate, qubit, internal)| {
+                (
+                    gate,
+                    qubit,
+                    SingleQubitOverrotationDescriptionWrapper { internal },
+                )
+            })
+            .collect()
+    }
+
+    /// Return all two qubit gates with overrotation.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, int, int, Tuple[SingleQubitOverrotationDescription, SingleQubitOverrotationDescription]]]: The name, control, target and overrotation of the gates, sorted by name and qubits.
+    #[allow(clippy::type_complexity)]
+    pub fn get_all_two_qubit_overrotations(
+        &self,
+    ) -> Vec<(
+        String,
+        usize,
+        usize,
+        (
+            SingleQubitOverrotationDescriptionWrapper,
+            SingleQubitOverrotationDescriptionWrapper,
+        ),
+    )> {
+        self.internal
+            .get_all_two_qubit_overrotations()
+            .into_iter()
+            .map(|(gate, control, target, (noise1, noise2))| {
+                (
+                    gate,
+                    control,
+                    target,
+                    (
+                        SingleQubitOverrotationDescriptionWrapper { internal: noise1 },
+                        SingleQubitOverrotationDescriptionWrapper { internal: noise2 },
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Return the overrotations as a table in json form.
+    ///
+    /// The table is a list of rows with the columns gate, qubits, qubit, rotation_gate, theta_mean and theta_std.
+    /// Two qubit gates have one row for the control qubit (first description)
+    /// and one row for the target qubit (second description).
+    ///
+    /// Returns:
+    ///     str: The overrotation table in json form.
+    pub fn to_table_json(&self) -> String {
+        self.internal.to_table_json()
+    }
+
     /// Convert the bincode representation of the Noise-Model to a device using the bincode crate.
     ///
     /// Args:
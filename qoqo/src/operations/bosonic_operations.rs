@@ -10,7 +10,13 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "unstable_fock_matrix")]
+use num_complex::Complex64;
+#[cfg(feature = "unstable_fock_matrix")]
+use numpy::{PyArray2, ToPyArray};
 use pyo3::exceptions::PyRuntimeError;
+#[cfg(feature = "unstable_fock_matrix")]
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PySet;
 use qoqo_calculator::CalculatorFloat;
@@ -30,6 +36,7 @@ use std::collections::HashMap;
     OperateSingleMode,
     InvolveQubits,
     OperateSingleModeGate,
+    OperateFockMatrix,
     JsonSchema
 )]
 /// The single-mode squeezing gate with tunable squeezing.
@@ -57,6 +64,7 @@ pub struct Squeezing {
     OperateSingleMode,
     InvolveQubits,
     OperateSingleModeGate,
+    OperateFockMatrix,
     JsonSchema
 )]
 /// The single-mode phase-displacement gate with variable magnitude and phase.
@@ -80,6 +88,7 @@ pub struct PhaseDisplacement {
     OperateSingleMode,
     InvolveQubits,
     OperateSingleModeGate,
+    OperateFockMatrix,
     JsonSchema
 )]
 /// The single-mode phase-shift gate with variable phase, given by R(θ) = eexp(i * θ * 𝑁̂).
@@ -102,6 +111,7 @@ pub struct PhaseShift {
     InvolveModes,
     SubstituteModes,
     OperateTwoMode,
+    OperateFockMatrix,
     JsonSchema
 )]
 /// The 2-mode beam splitter which splits a beam with a transmission amplitude cos(θ) and a reflection amplitude exp(i * φ) * sin(θ).
@@ -10,7 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{convert_into_circuit, CircuitWrapper};
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PySet;
 use qoqo_macros::*;
@@ -19,6 +19,8 @@ use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
 use std::collections::HashMap;
+use struqture::spins::SpinHamiltonian;
+use struqture_py::spins::SpinHamiltonianSystemWrapper;
 
 #[wrap(Operate, OperateSingleQubit, JsonSchema)]
 #[derive(Eq)]
@@ -53,6 +55,9 @@ pub struct PragmaGetStateVector {
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// This PRAGMA measurement operation returns the density matrix of a quantum register.
 ///
+/// The density matrix of N qubits is written into the readout register as a flattened 2^N x 2^N matrix
+/// in row-major order, with qubit 0 corresponding to the least significant bit of the index.
+///
 /// Args:
 ///     readout (string): The name of the classical readout register.
 ///     circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
@@ -62,6 +67,34 @@ struct PragmaGetDensityMatrix {
     circuit: Option<Circuit>,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA measurement operation returns the reduced density matrix of a subset of qubits.
+///
+/// All other qubits are traced out. The reduced density matrix of k qubits is written into the readout register
+/// as a flattened 2^k x 2^k matrix in row-major order, with qubits[0] corresponding to the least significant bit of the index.
+///
+/// Args:
+///     readout (string): The name of the classical readout register.
+///     qubits (List[int]): The qubits of the returned reduced density matrix.
+///     circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
+///
+struct PragmaGetReducedDensityMatrix {
+    readout: String,
+    qubits: Vec<usize>,
+    circuit: Option<Circuit>,
+}
+
+#[pymethods]
+impl PragmaGetReducedDensityMatrixWrapper {
+    /// Return the qubits of the returned reduced density matrix.
+    ///
+    /// Returns:
+    ///     List[int]: The kept qubits.
+    pub fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().clone()
+    }
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// This PRAGMA measurement operation returns the vector of the occupation probabilities.
 ///
@@ -96,6 +129,61 @@ struct PragmaGetPauliProduct {
     circuit: Circuit,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA measurement operation returns the vector of the occupation probabilities at an offset in the readout register.
+///
+/// Acts like PragmaGetOccupationProbability but the occupation probabilities are written into the readout register
+/// starting at the entry `readout_index`, so several measurements can share one register.
+///
+/// Args:
+///     readout (string): The name of the classical readout register.
+///     readout_index (int): The index of the register entry the first occupation probability is written to.
+///     circuit (Optional[Circuit]): The Circuit used to rotate the qureg.
+///
+struct PragmaGetIndexedOccupationProbability {
+    readout: String,
+    readout_index: usize,
+    circuit: Option<Circuit>,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA measurement operation returns a Pauli product expectation value at an entry of the readout register.
+///
+/// Acts like PragmaGetPauliProduct but the expectation value is written into the entry `readout_index`
+/// of the readout register, so several Pauli products can be measured into one register.
+///
+/// Args:
+///     qubit_paulis (Dict[int, int]): The dictionary of the pauli matrix to apply to each qubit in the form
+///                                    {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
+///     readout (string): The name of the classical readout register.
+///     readout_index (int): The index of the register entry the expectation value is written to.
+///     circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
+///
+struct PragmaGetIndexedPauliProduct {
+    qubit_paulis: std::collections::HashMap<usize, usize>,
+    readout: String,
+    readout_index: usize,
+    circuit: Circuit,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA measurement operation returns the expectation value of an operator.
+///
+/// The measurement preparation circuit is applied on a copy of the quantum register,
+/// so that the actual quantum register remains unchanged.
+/// The expectation value is written into the first entry of the float readout register.
+///
+/// Args:
+///     operator (SpinHamiltonianSystem): The operator whose expectation value is measured (struqture 1.x or 2.x).
+///     readout (string): The name of the classical float readout register.
+///     circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
+///
+struct PragmaGetOperatorExpectation {
+    operator: SpinHamiltonian,
+    readout: String,
+    circuit: Circuit,
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 #[derive(Eq)]
 /// This PRAGMA measurement operation returns a measurement record for N repeated measurements.
@@ -110,3 +198,48 @@ struct PragmaRepeatedMeasurement {
     number_measurements: usize,
     qubit_mapping: Option<std::collections::HashMap<usize, usize>>,
 }
+
+#[pymethods]
+impl PragmaRepeatedMeasurementWrapper {
+    /// Create a PragmaRepeatedMeasurement from pairs of qubits and readout indices.
+    ///
+    /// Args:
+    ///     readout (string): The name of the classical readout register.
+    ///     number_measurements (int): The number of times to repeat the measurement.
+    ///     pairs (List[Tuple[int, int]]): The pairs (qubit, readout_index) of the qubit mapping.
+    ///
+    /// Returns:
+    ///     PragmaRepeatedMeasurement: The PragmaRepeatedMeasurement with the qubit mapping of the pairs.
+    ///
+    /// Raises:
+    ///     ValueError: A qubit appears more than once or two qubits are mapped to the same readout index.
+    #[staticmethod]
+    pub fn with_qubit_readout_pairs(
+        readout: String,
+        number_measurements: usize,
+        pairs: Vec<(usize, usize)>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            internal: PragmaRepeatedMeasurement::with_qubit_readout_pairs(
+                readout,
+                number_measurements,
+                &pairs,
+            )
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))?,
+        })
+    }
+
+    /// Return the index in the readout register the measurement of a qubit is written to.
+    ///
+    /// Qubits without an entry in the qubit mapping, and all qubits when no mapping is set,
+    /// are written to the readout index equal to the qubit index.
+    ///
+    /// Args:
+    ///     qubit (int): The measured qubit.
+    ///
+    /// Returns:
+    ///     int: The readout index of the qubit.
+    pub fn effective_readout_index(&self, qubit: usize) -> usize {
+        self.internal.effective_readout_index(qubit)
+    }
+}
@@ -203,5 +203,138 @@ pub fn operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "unstable_simulation_repetitions")]
     m.add_class::<PragmaSimulationRepetitionsWrapper>()?;
 
+    // 1.18
+    m.add_class::<PragmaGetOperatorExpectationWrapper>()?;
+    m.add_class::<PragmaSimulateShotNoiseWrapper>()?;
+    m.add_class::<PragmaSetGateTimeWrapper>()?;
+    m.add_class::<PragmaParallelBlockTimingWrapper>()?;
+    m.add_class::<PragmaConditionalLoopWrapper>()?;
+    m.add_class::<FredkinWrapper>()?;
+    m.add_class::<MultiQubitMSWithCouplingsWrapper>()?;
+    m.add_class::<PragmaSnapshotRegistersWrapper>()?;
+    m.add_class::<NegativeControlWrapper>()?;
+    m.add_class::<PragmaAnnotationWrapper>()?;
+    m.add_class::<PragmaGetReducedDensityMatrixWrapper>()?;
+    m.add_class::<PragmaGetIndexedOccupationProbabilityWrapper>()?;
+    m.add_class::<PragmaGetIndexedPauliProductWrapper>()?;
+    m.add_function(wrap_pyfunction!(to_tagged_json, m)?)?;
+    m.add_function(wrap_pyfunction!(from_tagged_json, m)?)?;
+    m.add_function(wrap_pyfunction!(try_into_single_qubit_gate_operation, m)?)?;
+    m.add_function(wrap_pyfunction!(try_into_two_qubit_gate_operation, m)?)?;
+    m.add_function(wrap_pyfunction!(try_into_gate_operation, m)?)?;
+
     Ok(())
 }
+
+/// Serialize an Operation to a json object tagged with its hqslang name.
+///
+/// The json object contains the fields of the operation and an additional `hqslang` entry,
+/// e.g. `{"hqslang": "RotateZ", "qubit": 0, "theta": 0.5}`.
+///
+/// Args:
+///     operation (Operation): The operation to serialize.
+///
+/// Returns:
+///     str: The hqslang-tagged json representation of the operation.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Operation.
+#[pyfunction]
+#[pyo3(text_signature = "(operation, /)")]
+pub fn to_tagged_json(operation: &Bound<PyAny>) -> PyResult<String> {
+    let operation = convert_pyany_to_operation(operation).map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("Input cannot be converted to Operation")
+    })?;
+    Ok(operation.to_tagged_json())
+}
+
+/// Deserialize an Operation from a json object tagged with its hqslang name.
+///
+/// Inverse of `to_tagged_json`.
+///
+/// Args:
+///     input (str): The hqslang-tagged json representation of the operation.
+///
+/// Returns:
+///     Operation: The deserialized operation.
+///
+/// Raises:
+///     ValueError: Input cannot be deserialized to Operation.
+#[pyfunction]
+#[pyo3(text_signature = "(input, /)")]
+pub fn from_tagged_json(input: &str) -> PyResult<PyObject> {
+    let operation = Operation::from_tagged_json(input).map_err(|err| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Input cannot be deserialized to Operation: {}",
+            err
+        ))
+    })?;
+    convert_operation_to_pyobject(operation)
+}
+
+/// Convert an Operation to a single-qubit gate operation.
+///
+/// Args:
+///     operation (Operation): The operation to convert.
+///
+/// Returns:
+///     SingleQubitGate: The equivalent general single-qubit gate.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Operation or is not a single-qubit gate operation.
+#[pyfunction]
+#[pyo3(text_signature = "(operation, /)")]
+pub fn try_into_single_qubit_gate_operation(
+    operation: &Bound<PyAny>,
+) -> PyResult<SingleQubitGateWrapper> {
+    let operation = convert_pyany_to_operation(operation).map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("Input cannot be converted to Operation")
+    })?;
+    let gate = roqoqo::operations::SingleQubitGateOperation::try_from(operation)
+        .map_err(|err| pyo3::exceptions::PyTypeError::new_err(err.to_string()))?;
+    Ok(SingleQubitGateWrapper {
+        internal: gate.to_single_qubit_gate(),
+    })
+}
+
+/// Convert an Operation to a two-qubit gate operation.
+///
+/// Args:
+///     operation (Operation): The operation to convert.
+///
+/// Returns:
+///     Operation: The two-qubit gate operation.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Operation or is not a two-qubit gate operation.
+#[pyfunction]
+#[pyo3(text_signature = "(operation, /)")]
+pub fn try_into_two_qubit_gate_operation(operation: &Bound<PyAny>) -> PyResult<PyObject> {
+    let operation = convert_pyany_to_operation(operation).map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("Input cannot be converted to Operation")
+    })?;
+    let gate = roqoqo::operations::TwoQubitGateOperation::try_from(operation)
+        .map_err(|err| pyo3::exceptions::PyTypeError::new_err(err.to_string()))?;
+    convert_operation_to_pyobject(gate.into())
+}
+
+/// Convert an Operation to a gate operation.
+///
+/// Args:
+///     operation (Operation): The operation to convert.
+///
+/// Returns:
+///     Operation: The gate operation.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Operation or is not a gate operation.
+#[pyfunction]
+#[pyo3(text_signature = "(operation, /)")]
+pub fn try_into_gate_operation(operation: &Bound<PyAny>) -> PyResult<PyObject> {
+    let operation = convert_pyany_to_operation(operation).map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("Input cannot be converted to Operation")
+    })?;
+    let gate = roqoqo::operations::GateOperation::try_from(operation)
+        .map_err(|err| pyo3::exceptions::PyTypeError::new_err(err.to_string()))?;
+    convert_operation_to_pyobject(gate.into())
+}
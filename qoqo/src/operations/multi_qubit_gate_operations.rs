@@ -65,6 +65,348 @@ pub struct MultiQubitZZ {
     theta: CalculatorFloat,
 }
 
+/// The Molmer-Sorensen gate between multiple qubits with individual coupling strengths for each pair of qubits.
+///
+/// The gate applies the rotation under the sum of products of Pauli X operators on all pairs of qubits.
+/// In mathematical terms the gate applies exp(-i * sum_{j<k} theta_jk/2 * X_ij * X_ik).
+///
+/// Args:
+///     qubits (List[int]): The qubits involved in the multi qubit Molmer-Sorensen gate.
+///     couplings (List[CalculatorFloat]): The coupling strengths of all n(n-1)/2 pairs of the n qubits,
+///                                        ordered by pairs (0, 1), (0, 2), ..., (n-2, n-1) of the positions in qubits.
+#[pyclass(name = "MultiQubitMSWithCouplings", module = "qoqo")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiQubitMSWithCouplingsWrapper {
+    /// Internal storage of [roqoqo::MultiQubitMSWithCouplings]
+    pub internal: MultiQubitMSWithCouplings,
+}
+
+insert_pyany_to_operation!(
+    "MultiQubitMSWithCouplings" =>{
+        let qbts = op.call_method0("qubits")
+                    .map_err(|_| QoqoError::ConversionError)?;
+        let qubits: Vec<usize> = qbts.extract()
+                .map_err(|_| QoqoError::ConversionError)?;
+
+        let cplngs = op.call_method0("couplings")
+                        .map_err(|_| QoqoError::ConversionError)?;
+        let mut couplings: Vec<CalculatorFloat> = vec![];
+            for coupling in cplngs.iter().map_err(|_| QoqoError::ConversionError)? {
+                let coupling = coupling.map_err(|_| QoqoError::ConversionError)?;
+                couplings.push(convert_into_calculator_float(&coupling).map_err(|_| QoqoError::ConversionError)?);
+            }
+        Ok(MultiQubitMSWithCouplings::new(qubits, couplings).map_err(|_| QoqoError::ConversionError)?.into())
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::MultiQubitMSWithCouplings(internal) => {
+        {
+            let pyref: Py<MultiQubitMSWithCouplingsWrapper> =
+                Py::new(py, MultiQubitMSWithCouplingsWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl MultiQubitMSWithCouplingsWrapper {
+    /// Create a new MultiQubitMSWithCouplings.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits involved in the multi qubit Molmer-Sorensen gate.
+    ///     couplings (List[CalculatorFloat]): The coupling strengths of all n(n-1)/2 pairs of the n qubits.
+    ///
+    /// Raises:
+    ///     TypeError: A coupling cannot be converted to CalculatorFloat.
+    ///     ValueError: The number of couplings does not match the number of pairs of qubits.
+    #[new]
+    fn new(qubits: Vec<usize>, couplings: Vec<Py<PyAny>>) -> PyResult<Self> {
+        let couplings_cf: Vec<CalculatorFloat> =
+            Python::with_gil(|py| -> PyResult<Vec<CalculatorFloat>> {
+                let mut a = vec![];
+                for coupling in couplings {
+                    a.push(
+                        convert_into_calculator_float(coupling.bind(py)).map_err(|_| {
+                            pyo3::exceptions::PyTypeError::new_err(
+                                "Argument couplings cannot be converted to CalculatorFloat",
+                            )
+                        })?,
+                    )
+                }
+                Ok(a)
+            })?;
+
+        Ok(Self {
+            internal: MultiQubitMSWithCouplings::new(qubits, couplings_cf)
+                .map_err(|err| PyValueError::new_err(format!("{}", err)))?,
+        })
+    }
+
+    /// Return the list of qubits of the multi qubit operation in order of descending significance.
+    ///
+    /// Returns:
+    ///     List[int]: The qubits the operation acts on.
+    fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().clone()
+    }
+
+    /// Return the coupling strengths of all pairs of qubits.
+    ///
+    /// Returns:
+    ///     List[CalculatorFloat]: The couplings ordered by pairs (0, 1), (0, 2), ..., (n-2, n-1).
+    fn couplings(&self) -> Vec<CalculatorFloatWrapper> {
+        self.internal
+            .couplings()
+            .iter()
+            .map(|coupling| CalculatorFloatWrapper {
+                internal: coupling.clone(),
+            })
+            .collect::<Vec<CalculatorFloatWrapper>>()
+    }
+
+    /// Return the unitary matrix of the gate.
+    ///
+    /// Returns:
+    ///     np.ndarray: The unitary matrix of the gate.
+    ///
+    /// Raises:
+    ///     ValueError: Error symbolic operation cannot return float unitary matrix.
+    fn unitary_matrix(&self) -> PyResult<Py<PyArray2<Complex64>>> {
+        Python::with_gil(|py| -> PyResult<Py<PyArray2<Complex64>>> {
+            Ok(self
+                .internal
+                .unitary_matrix()
+                .map_err(|x| {
+                    PyValueError::new_err(format!(
+                        "Error symbolic operation cannot return float unitary matrix {:?}",
+                        x
+                    ))
+                })?
+                .to_pyarray_bound(py)
+                .as_gil_ref()
+                .into())
+        })
+    }
+
+    /// Return the circuit implementing the MultiQubitGateOperation.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit implementing the gate.
+    fn circuit(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.circuit(),
+        }
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, self.internal.qubits())
+                .unwrap()
+                .to_object(py)
+        })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the Operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
+    /// Substitute the symbolic parameters in a clone of the operation according to the input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    PyRuntimeError::new_err(format!("Parameter Substitution failed: {:?}", x))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the MultiQubitMSWithCouplings operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     MultiQubitMSWithCouplings: A deep copy of self.
+    fn __copy__(&self) -> MultiQubitMSWithCouplingsWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the operation.
+    ///
+    /// Returns:
+    ///     MultiQubitMSWithCouplings: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: Py<PyAny>) -> MultiQubitMSWithCouplingsWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on MultiQubitMSWithCouplings.
+    ///
+    /// Args:
+    ///     self: The MultiQubitMSWithCouplings object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other: Operation =
+            crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "Right hand side cannot be converted to Operation",
+                )
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(MultiQubitMSWithCouplings);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            MultiQubitMSWithCouplings::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
 /// The gate to be replaced by a gate defined with GateDefinition gate.
 /// The gate applies a gate previously defined by GateDefinition with the name gate_name.
 ///
@@ -219,6 +561,30 @@ impl CallDefinedGateWrapper {
         self.internal.is_parametrized()
     }
 
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
     /// Substitute the symbolic parameters in a clone of the operation according to the input.
     ///
     /// Args:
@@ -10,18 +10,21 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::noise_models::ContinuousDecoherenceModelWrapper;
 use crate::operations::convert_operation_to_pyobject;
+use crate::struqture_bridge::{noise_operator_from_python, noise_operator_to_python};
 use crate::{convert_into_circuit, CircuitWrapper};
 use ndarray::{Array1, Array2};
 use num_complex::Complex64;
 use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use pyo3::types::PySet;
 use qoqo_calculator::CalculatorFloat;
 use qoqo_calculator_pyo3::{convert_into_calculator_float, CalculatorFloatWrapper};
 use qoqo_macros::*;
+use roqoqo::noise_models::NoiseModel;
 use roqoqo::operations::*;
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
@@ -44,6 +47,365 @@ struct PragmaSetNumberOfMeasurements {
     readout: String,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+#[derive(Eq)]
+/// This PRAGMA operation instructs simulation backends to emulate the shot noise of a finite number of measurements.
+///
+/// Instead of writing exact values, simulation backends sample `number_shots` measurement records
+/// of the bit register `readout` from the exact probability distribution of the quantum register.
+/// Setting the seed makes the sampled records reproducible.
+///
+/// Args:
+///     readout (str): The name of the classical bit register the sampled measurement records are written to.
+///     number_shots (int): The number of sampled measurement records.
+///     seed (Optional[int]): The seed of the random number generator, the sampling is not reproducible when None.
+struct PragmaSimulateShotNoise {
+    readout: String,
+    number_shots: usize,
+    seed: Option<u64>,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA operation sets the gate time of a gate on specific qubits for the device of a circuit.
+///
+/// The PRAGMA does not act on the quantum state. It is applied to a device by wrapping it
+/// in a PragmaChangeDevice, for example to change the gate times of a GenericDevice.
+///
+/// Args:
+///     gate_hqslang (str): The hqslang name of the gate.
+///     qubits (List[int]): The qubits the gate acts on, in the order of the qubit arguments of the gate.
+///     gate_time (CalculatorFloat): The gate time of the gate on the qubits.
+struct PragmaSetGateTime {
+    gate_hqslang: String,
+    qubits: Vec<usize>,
+    gate_time: CalculatorFloat,
+}
+
+#[pymethods]
+impl PragmaSetGateTimeWrapper {
+    /// Return the qubits the gate acts on.
+    ///
+    /// Returns:
+    ///     List[int]: The qubits in the order of the qubit arguments of the gate.
+    fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().clone()
+    }
+}
+
+#[pyclass(name = "PragmaParallelBlockTiming", module = "qoqo.operations")]
+#[derive(Clone, Debug, PartialEq)]
+/// This PRAGMA closes a parallel block with individual durations of the qubits.
+///
+/// It is an alternative to PragmaStopParallelBlock for blocks in which the qubits are busy for
+/// different times. The PRAGMA does not act on the quantum state. Noise models can use the durations
+/// to insert the decoherence of the idling qubits, see `idle_noise_circuit`.
+///
+/// Args:
+///     qubit_durations (Dict[int, CalculatorFloat]): The durations (in seconds) of the parallel block for each involved qubit.
+pub struct PragmaParallelBlockTimingWrapper {
+    /// PragmaParallelBlockTiming to be wrapped and converted to Python.
+    pub internal: PragmaParallelBlockTiming,
+}
+
+insert_pyany_to_operation!(
+    "PragmaParallelBlockTiming" =>{
+        let drtns = op.call_method0("qubit_durations")
+                    .map_err(|_| QoqoError::ConversionError)?;
+        let durations: HashMap<usize, Bound<PyAny>> = drtns.extract()
+                    .map_err(|_| QoqoError::ConversionError)?;
+        let mut qubit_durations: HashMap<usize, CalculatorFloat> = HashMap::new();
+        for (qubit, duration) in durations.iter() {
+            qubit_durations.insert(*qubit, convert_into_calculator_float(duration).map_err(|_| QoqoError::ConversionError)?);
+        }
+        Ok(PragmaParallelBlockTiming::new(qubit_durations).into())
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::PragmaParallelBlockTiming(internal) => {
+        {
+            let pyref: Py<PragmaParallelBlockTimingWrapper> =
+                Py::new(py, PragmaParallelBlockTimingWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl PragmaParallelBlockTimingWrapper {
+    /// Create a PragmaParallelBlockTiming.
+    ///
+    /// Args:
+    ///     qubit_durations (Dict[int, CalculatorFloat]): The durations (in seconds) of the parallel block for each involved qubit.
+    ///
+    /// Returns:
+    ///     self: The new PragmaParallelBlockTiming.
+    ///
+    /// Raises:
+    ///     TypeError: A duration cannot be converted to CalculatorFloat.
+    #[new]
+    fn new(qubit_durations: HashMap<usize, Bound<PyAny>>) -> PyResult<Self> {
+        let mut durations: HashMap<usize, CalculatorFloat> = HashMap::new();
+        for (qubit, duration) in qubit_durations.iter() {
+            durations.insert(*qubit, calculator_float_argument(duration, "duration")?);
+        }
+        Ok(Self {
+            internal: PragmaParallelBlockTiming::new(durations),
+        })
+    }
+
+    /// Return the durations of the parallel block for each involved qubit.
+    ///
+    /// Returns:
+    ///     Dict[int, CalculatorFloat]: The durations (in seconds) by qubit.
+    fn qubit_durations(&self) -> HashMap<usize, CalculatorFloatWrapper> {
+        self.internal
+            .qubit_durations()
+            .iter()
+            .map(|(qubit, duration)| {
+                (
+                    *qubit,
+                    CalculatorFloatWrapper {
+                        internal: duration.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Create the circuit applying the idle decoherence of a noise model for the durations of the qubits.
+    ///
+    /// Args:
+    ///     model (ContinuousDecoherenceModel): The noise model containing the decoherence rates.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit containing one PragmaGeneralNoise per noisy qubit, ordered by qubit.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to ContinuousDecoherenceModel.
+    ///     ValueError: The model contains noise that cannot be applied with single qubit PRAGMAs.
+    fn idle_noise_circuit(&self, model: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
+        let model = match ContinuousDecoherenceModelWrapper::from_pyany(model) {
+            Ok(NoiseModel::ContinuousDecoherenceModel(model)) => model,
+            _ => {
+                return Err(PyTypeError::new_err(
+                    "Input cannot be converted to ContinuousDecoherenceModel",
+                ))
+            }
+        };
+        Ok(CircuitWrapper {
+            internal: self
+                .internal
+                .idle_noise_circuit(&model)
+                .map_err(|err| PyValueError::new_err(format!("{}", err)))?,
+        })
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, self.internal.qubit_durations().keys())
+                .unwrap()
+                .to_object(py)
+        })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the Operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    PyRuntimeError::new_err(format!("Parameter Substitution failed: {:?}", x))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaParallelBlockTiming: A deep copy of self.
+    fn __copy__(&self) -> PragmaParallelBlockTimingWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaParallelBlockTiming: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> PragmaParallelBlockTimingWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaParallelBlockTiming.
+    ///
+    /// Args:
+    ///     self: The PragmaParallelBlockTiming object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other = crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+            PyTypeError::new_err("Right hand side cannot be converted to Operation")
+        })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(PragmaParallelBlockTiming);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            PragmaParallelBlockTiming::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// This PRAGMA measurement operation returns the statevector of a quantum register.
 ///
@@ -158,6 +520,18 @@ impl PragmaSetStateVectorWrapper {
         })
     }
 
+    /// Validate that the statevector describes a quantum state.
+    ///
+    /// The statevector is not checked when the PRAGMA is created or deserialized.
+    ///
+    /// Raises:
+    ///     ValueError: The length of the statevector is not a power of two or the statevector is not normalized.
+    fn validate(&self) -> PyResult<()> {
+        self.internal
+            .validate()
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
     /// List all involved qubits (here, all).
     ///
     /// Returns:
@@ -195,6 +569,30 @@ impl PragmaSetStateVectorWrapper {
         self.internal.is_parametrized()
     }
 
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
     /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
     ///
     /// Args:
@@ -446,6 +844,19 @@ impl PragmaSetDensityMatrixWrapper {
         })
     }
 
+    /// Validate that the density matrix describes a quantum state.
+    ///
+    /// The density matrix is not checked when the PRAGMA is created or deserialized.
+    ///
+    /// Raises:
+    ///     ValueError: The density matrix is not square, its dimension is not a power of two,
+    ///                 it is not Hermitian or its trace is not one.
+    fn validate(&self) -> PyResult<()> {
+        self.internal
+            .validate()
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
     /// List all involved qubits (here, all).
     ///
     /// Returns:
@@ -483,6 +894,30 @@ impl PragmaSetDensityMatrixWrapper {
         self.internal.is_parametrized()
     }
 
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
     /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
     ///
     /// Args:
@@ -775,6 +1210,34 @@ pub struct PragmaDamping {
     rate: CalculatorFloat,
 }
 
+#[pymethods]
+impl PragmaDampingWrapper {
+    /// Create a circuit applying the damping PRAGMA to each of the given qubits.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits the PRAGMA operations are applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+    ///     rate (CalculatorFloat): The error rate of the damping (in 1/second).
+    ///
+    /// Returns:
+    ///     Circuit: The circuit containing one PragmaDamping per qubit.
+    ///
+    /// Raises:
+    ///     TypeError: An argument cannot be converted to CalculatorFloat.
+    #[staticmethod]
+    fn for_qubits(
+        qubits: Vec<usize>,
+        gate_time: &Bound<PyAny>,
+        rate: &Bound<PyAny>,
+    ) -> PyResult<CircuitWrapper> {
+        let gate_time = calculator_float_argument(gate_time, "gate time")?;
+        let rate = calculator_float_argument(rate, "rate")?;
+        Ok(CircuitWrapper {
+            internal: PragmaDamping::for_qubits(&qubits, gate_time, rate),
+        })
+    }
+}
+
 // #[pymethods]
 // impl PragmaDampingWrapper {
 //     /// Return the superoperator defining the evolution of the density matrix under the noise gate.
@@ -835,6 +1298,34 @@ pub struct PragmaDepolarising {
     rate: CalculatorFloat,
 }
 
+#[pymethods]
+impl PragmaDepolarisingWrapper {
+    /// Create a circuit applying the depolarising PRAGMA to each of the given qubits.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits the PRAGMA operations are applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+    ///     rate (CalculatorFloat): The error rate of the depolarising (in 1/second).
+    ///
+    /// Returns:
+    ///     Circuit: The circuit containing one PragmaDepolarising per qubit.
+    ///
+    /// Raises:
+    ///     TypeError: An argument cannot be converted to CalculatorFloat.
+    #[staticmethod]
+    fn for_qubits(
+        qubits: Vec<usize>,
+        gate_time: &Bound<PyAny>,
+        rate: &Bound<PyAny>,
+    ) -> PyResult<CircuitWrapper> {
+        let gate_time = calculator_float_argument(gate_time, "gate time")?;
+        let rate = calculator_float_argument(rate, "rate")?;
+        Ok(CircuitWrapper {
+            internal: PragmaDepolarising::for_qubits(&qubits, gate_time, rate),
+        })
+    }
+}
+
 // #[pymethods]
 // impl PragmaDepolarisingWrapper {
 //     /// Return the superoperator defining the evolution of the density matrix under the noise gate.
@@ -895,6 +1386,34 @@ pub struct PragmaDephasing {
     rate: CalculatorFloat,
 }
 
+#[pymethods]
+impl PragmaDephasingWrapper {
+    /// Create a circuit applying the dephasing PRAGMA to each of the given qubits.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits the PRAGMA operations are applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+    ///     rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+    ///
+    /// Returns:
+    ///     Circuit: The circuit containing one PragmaDephasing per qubit.
+    ///
+    /// Raises:
+    ///     TypeError: An argument cannot be converted to CalculatorFloat.
+    #[staticmethod]
+    fn for_qubits(
+        qubits: Vec<usize>,
+        gate_time: &Bound<PyAny>,
+        rate: &Bound<PyAny>,
+    ) -> PyResult<CircuitWrapper> {
+        let gate_time = calculator_float_argument(gate_time, "gate time")?;
+        let rate = calculator_float_argument(rate, "rate")?;
+        Ok(CircuitWrapper {
+            internal: PragmaDephasing::for_qubits(&qubits, gate_time, rate),
+        })
+    }
+}
+
 // #[pymethods]
 // impl PragmaDephasingWrapper {
 //     /// Return the superoperator defining the evolution of the density matrix under the noise gate.
@@ -957,6 +1476,68 @@ pub struct PragmaRandomNoise {
     dephasing_rate: CalculatorFloat,
 }
 
+#[pymethods]
+impl PragmaRandomNoiseWrapper {
+    /// Create a circuit applying the random noise PRAGMA to each of the given qubits.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits the PRAGMA operations are applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+    ///     depolarising_rate (CalculatorFloat): The error rate of the depolarisation (in 1/second).
+    ///     dephasing_rate (CalculatorFloat): The error rate of the dephasing (in 1/second).
+    ///
+    /// Returns:
+    ///     Circuit: The circuit containing one PragmaRandomNoise per qubit.
+    ///
+    /// Raises:
+    ///     TypeError: An argument cannot be converted to CalculatorFloat.
+    #[staticmethod]
+    fn for_qubits(
+        qubits: Vec<usize>,
+        gate_time: &Bound<PyAny>,
+        depolarising_rate: &Bound<PyAny>,
+        dephasing_rate: &Bound<PyAny>,
+    ) -> PyResult<CircuitWrapper> {
+        let gate_time = calculator_float_argument(gate_time, "gate time")?;
+        let depolarising_rate = calculator_float_argument(depolarising_rate, "depolarising_rate")?;
+        let dephasing_rate = calculator_float_argument(dephasing_rate, "dephasing_rate")?;
+        Ok(CircuitWrapper {
+            internal: PragmaRandomNoise::for_qubits(
+                &qubits,
+                gate_time,
+                depolarising_rate,
+                dephasing_rate,
+            ),
+        })
+    }
+}
+
+/// Extracts the rate matrix of a PragmaGeneralNoise from a numpy array or nested lists.
+fn general_noise_rates(rates: &Bound<PyAny>) -> PyResult<Array2<f64>> {
+    if let Ok(rates_pyarray) = rates.extract::<PyReadonlyArray2<f64>>() {
+        Ok(rates_pyarray.as_array().to_owned())
+    } else {
+        let rates_casted: Vec<Vec<f64>> = Vec::extract_bound(rates)?;
+        let ncol = rates_casted.first().map_or(0, |row| row.len());
+        let mut rates_array2: Array2<f64> = Array2::zeros((0, ncol));
+        for subvec in rates_casted {
+            let int_array1: Array1<f64> = Array1::from(subvec);
+            rates_array2.push_row((&int_array1).into()).unwrap();
+        }
+        Ok(rates_array2)
+    }
+}
+
+/// Converts a python argument of a PRAGMA operation to CalculatorFloat.
+fn calculator_float_argument(value: &Bound<PyAny>, name: &str) -> PyResult<CalculatorFloat> {
+    convert_into_calculator_float(value).map_err(|_| {
+        PyTypeError::new_err(format!(
+            "Argument {} cannot be converted to CalculatorFloat",
+            name
+        ))
+    })
+}
+
 // #[pymethods]
 // impl PragmaRandomNoiseWrapper {
 //     /// Return the superoperator defining the evolution of the density matrix under the noise gate.
@@ -1084,19 +1665,7 @@ impl PragmaGeneralNoiseWrapper {
     ///     self: The new PragmaGeneralNoise.
     #[new]
     fn new(qubit: usize, gate_time: &Bound<PyAny>, rates: &Bound<PyAny>) -> PyResult<Self> {
-        let rates_array: Array2<f64> =
-            if let Ok(rates_pyarray) = rates.extract::<PyReadonlyArray2<f64>>() {
-                rates_pyarray.as_array().to_owned()
-            } else {
-                let rates_casted: Vec<Vec<f64>> = Vec::extract_bound(rates)?;
-                let ncol = rates_casted.first().map_or(0, |row| row.len());
-                let mut rates_array2: Array2<f64> = Array2::zeros((0, ncol));
-                for subvec in rates_casted {
-                    let int_array1: Array1<f64> = Array1::from(subvec);
-                    rates_array2.push_row((&int_array1).into()).unwrap();
-                }
-                rates_array2
-            };
+        let rates_array = general_noise_rates(rates)?;
         let gate_time_cf = convert_into_calculator_float(gate_time).map_err(|_| {
             pyo3::exceptions::PyTypeError::new_err(
                 "Argument gate time cannot be converted to CalculatorFloat",
@@ -1108,6 +1677,121 @@ impl PragmaGeneralNoiseWrapper {
         })
     }
 
+    /// Create a PragmaGeneralNoise from the T1 and T2 times of a qubit.
+    ///
+    /// The damping rate 1/T1 is set in the entry M[0, 0] (equivalent to PragmaDamping)
+    /// and the pure dephasing rate (1/T2 - 1/(2 T1)) / 2 in the entry M[2, 2] (equivalent to PragmaDephasing).
+    ///
+    /// Args:
+    ///     qubit (int): The qubit the PRAGMA operation is applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+    ///     t1 (float): The relaxation time T1.
+    ///     t2 (float): The coherence time T2.
+    ///
+    /// Returns:
+    ///     PragmaGeneralNoise: The new PragmaGeneralNoise.
+    ///
+    /// Raises:
+    ///     TypeError: Argument gate time cannot be converted to CalculatorFloat.
+    ///     ValueError: T1 or T2 are not positive or T2 is larger than 2 T1.
+    #[staticmethod]
+    fn from_t1_t2(qubit: usize, gate_time: &Bound<PyAny>, t1: f64, t2: f64) -> PyResult<Self> {
+        let gate_time_cf = convert_into_calculator_float(gate_time).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "Argument gate time cannot be converted to CalculatorFloat",
+            )
+        })?;
+        Ok(Self {
+            internal: PragmaGeneralNoise::from_t1_t2(qubit, gate_time_cf, t1, t2)
+                .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("{:?}", err)))?,
+        })
+    }
+
+    /// Create a PragmaGeneralNoise from the single qubit terms of a struqture Lindblad noise operator.
+    ///
+    /// The entry M[i, j] of the rate matrix is the coefficient of the operator product (Li, Lj) acting on the qubit,
+    /// with L0 = σ+ (Plus), L1 = σ- (Minus) and L2 = σz (Z) of struqture. Terms not acting on the qubit are ignored.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit the PRAGMA operation is applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
+    ///     operator (struqture_py.spins.PlusMinusLindbladNoiseOperator): The Lindblad noise operator containing the rates.
+    ///
+    /// Returns:
+    ///     PragmaGeneralNoise: The new PragmaGeneralNoise.
+    ///
+    /// Raises:
+    ///     TypeError: Argument gate time cannot be converted to CalculatorFloat.
+    ///     ValueError: The operator cannot be converted or a term acting on the qubit also acts on other qubits or has a rate that is not real.
+    #[staticmethod]
+    fn from_lindblad_operator(
+        qubit: usize,
+        gate_time: &Bound<PyAny>,
+        operator: &Bound<PyAny>,
+    ) -> PyResult<Self> {
+        let gate_time = calculator_float_argument(gate_time, "gate time")?;
+        let operator = noise_operator_from_python(operator.py(), operator)?;
+        Ok(Self {
+            internal: PragmaGeneralNoise::from_lindblad_operator(qubit, gate_time, &operator)
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+        })
+    }
+
+    /// Return the rates of the PRAGMA as a struqture Lindblad noise operator.
+    ///
+    /// The entry M[i, j] of the rate matrix becomes the coefficient of the operator product (Li, Lj)
+    /// with L0 = σ+ (Plus), L1 = σ- (Minus) and L2 = σz (Z). The gate time is not part of the operator.
+    ///
+    /// Returns:
+    ///     struqture_py.spins.PlusMinusLindbladNoiseOperator: The Lindblad noise operator with the rates of the PRAGMA.
+    fn to_lindblad_operator(&self, py: Python) -> PyResult<Py<PyAny>> {
+        noise_operator_to_python(py, &self.internal.to_lindblad_operator())
+    }
+
+    /// Return the effective T1 time defined by the diagonal rates.
+    ///
+    /// Returns:
+    ///     float: The effective T1 time, infinite if the populations do not decay.
+    fn effective_t1(&self) -> f64 {
+        self.internal.effective_t1()
+    }
+
+    /// Return the effective T2 time defined by the diagonal rates.
+    ///
+    /// Returns:
+    ///     float: The effective T2 time, infinite if the coherences do not decay.
+    fn effective_t2(&self) -> f64 {
+        self.internal.effective_t2()
+    }
+
+    /// Create a circuit applying the general noise PRAGMA to each of the given qubits.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits the PRAGMA operations are applied to.
+    ///     gate_time (CalculatorFloat): The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware
+    ///     rates (Array2[float]): The rate matrix M.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit containing one PragmaGeneralNoise per qubit.
+    ///
+    /// Raises:
+    ///     TypeError: Argument gate time cannot be converted to CalculatorFloat.
+    #[staticmethod]
+    fn for_qubits(
+        qubits: Vec<usize>,
+        gate_time: &Bound<PyAny>,
+        rates: &Bound<PyAny>,
+    ) -> PyResult<CircuitWrapper> {
+        let gate_time = calculator_float_argument(gate_time, "gate time")?;
+        Ok(CircuitWrapper {
+            internal: PragmaGeneralNoise::for_qubits(
+                &qubits,
+                gate_time,
+                general_noise_rates(rates)?,
+            ),
+        })
+    }
+
     /// Return the qubit on which the PRAGMA operation is applied.
     ///
     /// Returns:
@@ -1188,6 +1872,30 @@ impl PragmaGeneralNoiseWrapper {
         self.internal.is_parametrized()
     }
 
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
     /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
     ///
     /// Args:
@@ -1351,6 +2059,85 @@ pub struct PragmaConditional {
     circuit: Circuit,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA repeats a circuit while a classical condition bit is true.
+///
+/// The circuit is executed once and then repeated as long as the condition bit is true after
+/// the execution, at most max_repetitions times in total.
+/// A repeat-until-success protocol writes a failure flag to the condition bit in the circuit.
+///
+/// Args:
+///     condition_register (str): The name of the bit register containting the condition bool value.
+///     condition_index (int): The index in the bit register containting the condition bool value.
+///     max_repetitions (int): The maximal number of executions of the circuit.
+///     circuit (Circuit): The circuit that is repeated.
+pub struct PragmaConditionalLoop {
+    condition_register: String,
+    condition_index: usize,
+    max_repetitions: usize,
+    circuit: Circuit,
+}
+
+#[pymethods]
+impl PragmaConditionalLoopWrapper {
+    /// Lower the PRAGMA to nested PragmaConditional operations.
+    ///
+    /// The circuit is executed once unconditionally, every further repetition is wrapped in a
+    /// PragmaConditional on the condition bit inside the previous one.
+    ///
+    /// Returns:
+    ///     Circuit: The unrolled circuit.
+    fn unroll(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.unroll(),
+        }
+    }
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+#[derive(Eq)]
+/// This PRAGMA operation takes a snapshot of classical registers for debugging.
+///
+/// Evaluating backends that encounter the PRAGMA copy the current content of each register in
+/// readout_names into an output register of the same type named "{label}_{name}".
+///
+/// Args:
+///     readout_names (List[str]): The names of the classical registers that are copied.
+///     label (str): The label prefixed to the names of the snapshot registers.
+pub struct PragmaSnapshotRegisters {
+    readout_names: Vec<String>,
+    label: String,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+#[derive(Eq)]
+/// This PRAGMA operation annotates the circuit with a key-value pair of metadata.
+///
+/// The annotation records provenance information like the compiler version or the applied passes
+/// and survives serialization. It has no effect on the quantum state and involves no qubits.
+///
+/// Args:
+///     key (str): The key of the annotation.
+///     value (str): The value of the annotation.
+pub struct PragmaAnnotation {
+    key: String,
+    value: String,
+}
+
+#[pymethods]
+impl PragmaSnapshotRegistersWrapper {
+    /// Return the name of the snapshot register of a copied register.
+    ///
+    /// Args:
+    ///     readout_name (str): The name of the copied register.
+    ///
+    /// Returns:
+    ///     str: The name "{label}_{readout_name}".
+    fn snapshot_name(&self, readout_name: &str) -> String {
+        self.internal.snapshot_name(readout_name)
+    }
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// A circuit controlled by a qubit.
 ///
@@ -1483,6 +2270,30 @@ impl PragmaChangeDeviceWrapper {
         self.internal.is_parametrized()
     }
 
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
     /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
     ///
     /// Args:
@@ -1766,6 +2577,30 @@ impl PragmaAnnotatedOpWrapper {
         self.internal.is_parametrized()
     }
 
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
     /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
     ///
     /// Args:
@@ -10,11 +10,12 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use ndarray::Array2;
 use num_complex::Complex64;
-use numpy::{PyArray2, ToPyArray};
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PySet;
+use pyo3::types::{PyList, PySet};
 use qoqo_calculator::CalculatorFloat;
 use qoqo_calculator_pyo3::{convert_into_calculator_float, CalculatorFloatWrapper};
 use qoqo_macros::*;
@@ -55,6 +56,51 @@ struct SingleQubitGate {
     global_phase: CalculatorFloat,
 }
 
+#[pymethods]
+impl SingleQubitGateWrapper {
+    /// Create a SingleQubitGate from its unitary matrix.
+    ///
+    /// The matrix is decomposed as :math:`e^{i \varphi} [[\alpha, -\beta^*], [\beta, \alpha^*]]`
+    /// with the global phase :math:`\varphi` in the interval :math:`(-\pi/2, \pi/2]`.
+    ///
+    /// Args:
+    ///     matrix (Union[np.ndarray, List[List[complex]]]): The 2x2 complex unitary matrix of the gate.
+    ///     qubit (int): The qubit that the unitary gate is applied to.
+    ///     tolerance (float): The tolerance of the unitarity check.
+    ///
+    /// Returns:
+    ///     SingleQubitGate: The gate with the given unitary matrix.
+    ///
+    /// Raises:
+    ///     TypeError: Matrix cannot be converted to a complex 2d array.
+    ///     ValueError: Matrix is not a 2x2 unitary matrix.
+    #[staticmethod]
+    #[pyo3(signature = (matrix, qubit, tolerance = 1e-6))]
+    fn from_unitary_matrix(matrix: &Bound<PyAny>, qubit: usize, tolerance: f64) -> PyResult<Self> {
+        let conversion_error = || {
+            pyo3::exceptions::PyTypeError::new_err(
+                "Matrix cannot be converted to a complex 2d array",
+            )
+        };
+        let matrix_array: Array2<Complex64> = if matrix.is_instance_of::<PyList>() {
+            let rows: Vec<Vec<Complex64>> = matrix.extract().map_err(|_| conversion_error())?;
+            let ncol = rows.first().map_or(0, |row| row.len());
+            Array2::from_shape_vec((rows.len(), ncol), rows.into_iter().flatten().collect())
+                .map_err(|_| conversion_error())?
+        } else {
+            matrix
+                .extract::<PyReadonlyArray2<Complex64>>()
+                .map_err(|_| conversion_error())?
+                .as_array()
+                .to_owned()
+        };
+        Ok(Self {
+            internal: SingleQubitGate::from_unitary_matrix(&matrix_array, qubit, tolerance)
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+        })
+    }
+}
+
 #[wrap(
     Operate,
     OperateSingleQubit,
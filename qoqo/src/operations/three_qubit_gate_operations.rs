@@ -36,6 +36,7 @@ use qoqo_macros::*;
     OperateThreeQubit,
     OperateGate,
     OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
     JsonSchema
 )]
 #[derive(Eq)]
@@ -70,6 +71,7 @@ pub struct ControlledControlledPauliZ {
     Rotate,
     OperateGate,
     OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
     JsonSchema
 )]
 /// Implements the double-controlled PhaseShift gate.
@@ -104,6 +106,7 @@ pub struct ControlledControlledPhaseShift {
     OperateThreeQubit,
     OperateGate,
     OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
     JsonSchema
 )]
 /// Implements Toffoli gate.
@@ -131,7 +134,12 @@ pub struct Toffoli {
 }
 
 #[allow(clippy::upper_case_acronyms)]
-#[wrap(OperateGate, OperateThreeQubitGate, JsonSchema)]
+#[wrap(
+    OperateGate,
+    OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
+    JsonSchema
+)]
 /// Implements ControlledSWAP gate.
 ///
 /// .. math::
@@ -172,6 +180,30 @@ impl ControlledSWAPWrapper {
     fn is_parametrized(&self) -> bool {
         self.internal.is_parametrized()
     }
+
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
     /// Returns tags identifying the Operation
     ///
     /// Returns:
@@ -292,12 +324,205 @@ impl ControlledSWAPWrapper {
     }
 }
 
+#[wrap(
+    OperateGate,
+    OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
+    JsonSchema
+)]
+/// Implements the Fredkin gate.
+///
+/// The Fredkin gate is the textbook name of the ControlledSWAP gate and has the same unitary matrix.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         1 & 0 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+///         0 & 1 & 0 & 0 & 0 & 0 & 0 & 0 \\\\
+///         0 & 0 & 1 & 0 & 0 & 0 & 0 & 0 \\\\
+///         0 & 0 & 0 & 1 & 0 & 0 & 0 & 0 \\\\
+///         0 & 0 & 0 & 0 & 1 & 0 & 0 & 0 \\\\
+///         0 & 0 & 0 & 0 & 0 & 0 & 1 & 0 \\\\
+///         0 & 0 & 0 & 0 & 0 & 1 & 0 & 0 \\\\
+///         0 & 0 & 0 & 0 & 0 & 0 & 0 & 1
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. Here, the controlling qubit of the operation.
+///     target_0 (int): The index of the second most significant qubit in the unitary representation. Here, the first targeting qubit of the operation.
+///     target_1 (int): The index of the least significant qubit in the unitary representation. Here, the second targeting qubit of the operation.
+pub struct Fredkin {
+    control: usize,
+    target_0: usize,
+    target_1: usize,
+}
+
+#[pymethods]
+impl FredkinWrapper {
+    #[new]
+    /// Creates new instance of Operation Fredkin
+    fn new(control: usize, target_0: usize, target_1: usize) -> PyResult<Self> {
+        Ok(Self {
+            internal: Fredkin::new(control, target_0, target_1),
+        })
+    }
+    /// Returns true if operation contains symbolic parameters
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation contains symbolic parameters.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+    /// Returns tags identifying the Operation
+    ///
+    /// Returns:
+    ///     List[str]: The tags identifying the operation
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+    /// Returns hqslang name of Operation
+    ///
+    /// Returns:
+    ///     str: The name
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+    /// Substitutes internal symbolic parameters with float values
+    ///
+    /// Only available when all symbolic expressions can be evaluated to float with the
+    /// provided parameters.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The substituted free parameters
+    ///
+    /// Returns:
+    ///     Operation: The operation with the parameters substituted
+    ///
+    /// Raises:
+    ///     RuntimeError: Parameter Substitution failed
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+    /// Remap qubits in the Fredkin operation
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The mapping to be used in the remapping.
+    ///
+    /// Returns:
+    ///     Operation: The operation with the remapped qubits
+    ///
+    /// Raises:
+    ///     RuntimeError: Qubit remapping failed
+    fn remap_qubits(&self, mapping: HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|x| PyRuntimeError::new_err(format!("Qubit remapping failed: {:?}", x)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+    /// List all involved qubits in the Fredkin operation.
+    ///
+    /// Returns:
+    ///     Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject {
+            let involved = self.internal.involved_qubits();
+            match involved {
+                InvolvedQubits::All => {
+                    let pyref: &Bound<PySet> = &PySet::new_bound(py, &["All"]).unwrap();
+                    let pyobject: PyObject = pyref.to_object(py);
+                    pyobject
+                }
+                InvolvedQubits::None => {
+                    let pyref: &Bound<PySet> = &PySet::empty_bound(py).unwrap();
+                    let pyobject: PyObject = pyref.to_object(py);
+                    pyobject
+                }
+                InvolvedQubits::Set(x) => {
+                    let mut vector: Vec<usize> = Vec::new();
+                    for qubit in x {
+                        vector.push(qubit)
+                    }
+                    let pyref: &Bound<PySet> = &PySet::new_bound(py, &vector[..]).unwrap();
+                    let pyobject: PyObject = pyref.to_object(py);
+                    pyobject
+                }
+            }
+        })
+    }
+    /// Copies Operation
+    ///
+    /// For qoqo operations copy is always a deep copy
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+    /// Creates deep copy of Operation
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
+        self.clone()
+    }
+    /// Returns control qubit of the three-qubit operation
+    pub fn control(&self) -> usize {
+        *self.internal.control_0()
+    }
+    /// Returns target_0 qubit of the three-qubit operation
+    pub fn target_0(&self) -> usize {
+        *self.internal.control_1()
+    }
+    /// Returns target_1 qubit of the three-qubit operation
+    pub fn target_1(&self) -> usize {
+        *self.internal.target()
+    }
+}
+
 #[allow(clippy::upper_case_acronyms)]
 #[wrap(
     Operate,
     OperateThreeQubit,
     OperateGate,
     OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
     JsonSchema
 )]
 /// The phased-shifted double-controlled-Z gate.
@@ -336,6 +561,7 @@ pub struct PhaseShiftedControlledControlledZ {
     OperateGate,
     Rotate,
     OperateThreeQubitGate,
+    OperateThreeQubitGateBasis,
     JsonSchema
 )]
 /// The phased-shifted double-controlled-Z gate.
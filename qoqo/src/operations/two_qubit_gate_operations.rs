@@ -685,3 +685,340 @@ pub struct EchoCrossResonance {
     control: usize,
     target: usize,
 }
+
+#[pyclass(name = "NegativeControl", module = "qoqo.operations")]
+#[derive(Clone, Debug, PartialEq)]
+/// Negative control (anti-control) version of a two-qubit gate.
+///
+/// Applies the inner gate when the control qubit is in state |0> instead of |1>.
+/// The unitary matrix is the unitary matrix of the inner gate conjugated with PauliX on the control qubit.
+///
+/// Args:
+///     inner (Operation): The two-qubit gate applied when the control qubit is in state |0>.
+pub struct NegativeControlWrapper {
+    /// NegativeControl to be wrapped and converted to Python.
+    pub internal: NegativeControl,
+}
+
+insert_pyany_to_operation!(
+    "NegativeControl" =>{
+        let inner = op.call_method0("inner").map_err(|_| QoqoError::ConversionError)?;
+        let inner: Operation = convert_pyany_to_operation(&inner)
+                                  .map_err(|_| QoqoError::ConversionError)?;
+        let inner = TwoQubitGateOperation::try_from(inner)
+                                  .map_err(|_| QoqoError::ConversionError)?;
+        Ok(NegativeControl::new(inner).into())
+    }
+);
+
+insert_operation_to_pyobject!(
+    Operation::NegativeControl(internal) => {
+        {
+            let pyref: Py<NegativeControlWrapper> =
+                Py::new(py, NegativeControlWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl NegativeControlWrapper {
+    /// Create a NegativeControl instance.
+    ///
+    /// Args:
+    ///     inner (Operation): The two-qubit gate applied when the control qubit is in state |0>.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to a two-qubit gate operation.
+    #[new]
+    fn new(inner: &Bound<PyAny>) -> PyResult<Self> {
+        let operation = crate::operations::convert_pyany_to_operation(inner).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err("Input inner cannot be converted to Operation")
+        })?;
+        let inner = TwoQubitGateOperation::try_from(operation)
+            .map_err(|err| pyo3::exceptions::PyTypeError::new_err(err.to_string()))?;
+        Ok(Self {
+            internal: NegativeControl::new(inner),
+        })
+    }
+
+    /// Return the inner two-qubit gate.
+    ///
+    /// Returns:
+    ///     Operation: The two-qubit gate applied when the control qubit is in state |0>.
+    fn inner(&self) -> PyResult<PyObject> {
+        crate::operations::convert_operation_to_pyobject(Operation::from(
+            self.internal.inner().clone(),
+        ))
+    }
+
+    /// Return the control qubit of the two-qubit operation.
+    ///
+    /// Returns:
+    ///     int: The control qubit of the operation.
+    fn control(&self) -> usize {
+        *self.internal.control()
+    }
+
+    /// Return the target qubit of the two-qubit operation.
+    ///
+    /// Returns:
+    ///     int: The target qubit of the operation.
+    fn target(&self) -> usize {
+        *self.internal.target()
+    }
+
+    /// Return the unitary matrix of the gate.
+    ///
+    /// Returns:
+    ///     np.ndarray: The unitary matrix of the gate.
+    ///
+    /// Raises:
+    ///     ValueError: Error symbolic operation cannot return float unitary matrix.
+    fn unitary_matrix(&self) -> PyResult<Py<PyArray2<Complex64>>> {
+        Python::with_gil(|py| -> PyResult<Py<PyArray2<Complex64>>> {
+            Ok(self
+                .internal
+                .unitary_matrix()
+                .map_err(|x| {
+                    PyValueError::new_err(format!(
+                        "Error symbolic operation cannot return float unitary matrix {:?}",
+                        x
+                    ))
+                })?
+                .to_pyarray_bound(py)
+                .unbind())
+        })
+    }
+
+    /// Return the circuit implementing the gate with positive control.
+    ///
+    /// The inner gate is applied between two PauliX gates on the control qubit.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit implementing the gate.
+    fn circuit(&self) -> crate::CircuitWrapper {
+        crate::CircuitWrapper {
+            internal: self.internal.circuit(),
+        }
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject {
+            match self.internal.involved_qubits() {
+                InvolvedQubits::All => {
+                    let pyref: &Bound<PySet> = &PySet::new_bound(py, &["All"]).unwrap();
+                    pyref.as_any().to_object(py)
+                }
+                InvolvedQubits::None => {
+                    let pyref: &Bound<PySet> = &PySet::empty_bound(py).unwrap();
+                    pyref.as_any().to_object(py)
+                }
+                InvolvedQubits::Set(x) => {
+                    let vector: Vec<usize> = x.into_iter().collect();
+                    let pyref: &Bound<PySet> = &PySet::new_bound(py, &vector[..]).unwrap();
+                    pyref.as_any().to_object(py)
+                }
+            }
+        })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the Operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Returns true if the operation is a gate operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `GateOperation`.
+    fn is_gate_operation(&self) -> bool {
+        self.internal.tags().contains(&"GateOperation")
+    }
+
+    /// Returns true if the operation is a PRAGMA operation
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `PragmaOperation`.
+    fn is_pragma(&self) -> bool {
+        self.internal.tags().contains(&"PragmaOperation")
+    }
+
+    /// Returns true if the operation is a measurement
+    ///
+    /// Returns:
+    ///     bool: Whether or not the operation has the tag `Measurement`.
+    fn is_measurement(&self) -> bool {
+        self.internal.tags().contains(&"Measurement")
+    }
+
+    /// Substitute the symbolic parameters in a clone of the operation according to the input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    PyRuntimeError::new_err(format!("Parameter Substitution failed: {:?}", x))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|x| PyRuntimeError::new_err(format!("Qubit remapping failed: {:?}", x)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     NegativeControl: A deep copy of self.
+    fn __copy__(&self) -> NegativeControlWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the operation.
+    ///
+    /// Returns:
+    ///     NegativeControl: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> NegativeControlWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on NegativeControl.
+    ///
+    /// Args:
+    ///     self: The NegativeControl object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other = crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "Right hand side cannot be converted to Operation",
+            )
+        })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(NegativeControl);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            NegativeControl::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transformation passes rewriting qoqo Circuits.
+
+use crate::devices::GenericDeviceWrapper;
+use crate::noise_models::ContinuousDecoherenceModelWrapper;
+use crate::CircuitWrapper;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use roqoqo::devices::GenericDevice;
+use roqoqo::noise_models::{ContinuousDecoherenceModel, NoiseModel};
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Reuse qubits after their terminal measurement.
+///
+/// When the last operation on a logical qubit is a MeasureQubit, the physical qubit is freed
+/// after the measurement and logical qubits starting later are mapped onto it.
+/// A PragmaActiveReset is inserted before the first operation of the reusing qubit.
+/// The readout registers are unchanged.
+///
+/// Args:
+///     circuit (Circuit): The circuit that is rewritten.
+///
+/// Returns:
+///     Tuple[Circuit, Dict[int, int]]: The rewritten circuit and the mapping of logical to physical qubits.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit.
+///     ValueError: An operation acting on all qubits or a PragmaRepeatedMeasurement follows the first reuse of a qubit.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, /)")]
+pub fn reuse_qubits(circuit: &Bound<PyAny>) -> PyResult<(CircuitWrapper, HashMap<usize, usize>)> {
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let (new_circuit, mapping) = roqoqo::passes::reuse_qubits(&circuit)
+        .map_err(|err| PyValueError::new_err(format!("Qubit reuse failed: {}", err)))?;
+    Ok((
+        CircuitWrapper {
+            internal: new_circuit,
+        },
+        mapping,
+    ))
+}
+
+/// Lower all PragmaConditionalLoop operations to nested PragmaConditional operations.
+///
+/// Backends without native support for conditional loops can use this pass before execution.
+///
+/// Args:
+///     circuit (Circuit): The circuit that is rewritten.
+///
+/// Returns:
+///     Circuit: The circuit without PragmaConditionalLoop operations at the top level.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, /)")]
+pub fn unroll_conditional_loops(circuit: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    Ok(CircuitWrapper {
+        internal: roqoqo::passes::unroll_conditional_loops(&circuit),
+    })
+}
+
+/// Estimate the fidelity of a circuit executed on a device.
+///
+/// The estimate is the product of the survival probabilities of all qubits
+/// returned by estimate_fidelity_per_qubit.
+///
+/// Args:
+///     circuit (Circuit): The circuit that is estimated.
+///     device (Device): The device providing the gate times and qubit decoherence rates.
+///     noise (ContinuousDecoherenceModel): The decoherence in addition to the decoherence rates of the device.
+///
+/// Returns:
+///     float: The estimated fidelity between 0 and 1.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit, device or ContinuousDecoherenceModel.
+///     ValueError: A gate is not available on the device, a noise PRAGMA has no probability or a value is symbolic.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, device, noise, /)")]
+pub fn estimate_fidelity(
+    circuit: &Bound<PyAny>,
+    device: &Bound<PyAny>,
+    noise: &Bound<PyAny>,
+) -> PyResult<f64> {
+    let (circuit, device, noise) = extract_fidelity_inputs(circuit, device, noise)?;
+    roqoqo::passes::estimate_fidelity(&circuit, &device, &noise)
+        .map_err(|err| PyValueError::new_err(format!("Fidelity estimation failed: {}", err)))
+}
+
+/// Estimate the survival probability of each qubit of a circuit executed on a device.
+///
+/// Each gate with gate time t multiplies the survival probability of each of its qubits by exp(-t * rate),
+/// where the rate sums the diagonal decoherence rates of the qubit in the device and the noise model.
+/// Noise PRAGMAs multiply the survival probability of their qubits by 1 - probability.
+///
+/// Args:
+///     circuit (Circuit): The circuit that is estimated.
+///     device (Device): The device providing the gate times and qubit decoherence rates.
+///     noise (ContinuousDecoherenceModel): The decoherence in addition to the decoherence rates of the device.
+///
+/// Returns:
+///     Dict[int, float]: The survival probability of each qubit involved in a gate or noise PRAGMA.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit, device or ContinuousDecoherenceModel.
+///     ValueError: A gate is not available on the device, a noise PRAGMA has no probability or a value is symbolic.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, device, noise, /)")]
+pub fn estimate_fidelity_per_qubit(
+    circuit: &Bound<PyAny>,
+    device: &Bound<PyAny>,
+    noise: &Bound<PyAny>,
+) -> PyResult<HashMap<usize, f64>> {
+    let (circuit, device, noise) = extract_fidelity_inputs(circuit, device, noise)?;
+    roqoqo::passes::estimate_fidelity_per_qubit(&circuit, &device, &noise)
+        .map_err(|err| PyValueError::new_err(format!("Fidelity estimation failed: {}", err)))
+}
+
+/// Rewrite a circuit into the native gates of a device.
+///
+/// Three-qubit, four-qubit and multi-qubit gates are decomposed into two-qubit gates,
+/// two-qubit gates are rewritten with the CNOT, ControlledPauliZ or ISwap gate of the device and
+/// single-qubit gates are fused and re-expressed with RotateZ and RotateY, RotateX or SqrtPauliX.
+/// Gates available on the device are not changed and qubits are not routed.
+///
+/// Args:
+///     circuit (Circuit): The circuit that is rewritten.
+///     device (Device): The device providing the native gates.
+///
+/// Returns:
+///     Circuit: The circuit using only gates available on the device.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit or device.
+///     ValueError: The device does not offer the gates needed for a decomposition, a gate is not available on the device or a value is symbolic.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, device, /)")]
+pub fn transpile_to_device(
+    circuit: &Bound<PyAny>,
+    device: &Bound<PyAny>,
+) -> PyResult<CircuitWrapper> {
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let device = GenericDeviceWrapper::from_pyany(device)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to a device"))?;
+    let transpiled = roqoqo::passes::transpile_to_device(&circuit, &device)
+        .map_err(|err| PyValueError::new_err(format!("Transpilation failed: {}", err)))?;
+    Ok(CircuitWrapper {
+        internal: transpiled,
+    })
+}
+
+/// Extracts the circuit, device and noise model of the fidelity estimates.
+fn extract_fidelity_inputs(
+    circuit: &Bound<PyAny>,
+    device: &Bound<PyAny>,
+    noise: &Bound<PyAny>,
+) -> PyResult<(Circuit, GenericDevice, ContinuousDecoherenceModel)> {
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let device = GenericDeviceWrapper::from_pyany(device)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to a device"))?;
+    let noise = match ContinuousDecoherenceModelWrapper::from_pyany(noise) {
+        Ok(NoiseModel::ContinuousDecoherenceModel(noise)) => noise,
+        _ => {
+            return Err(PyTypeError::new_err(
+                "Input cannot be converted to ContinuousDecoherenceModel",
+            ))
+        }
+    };
+    Ok((circuit, device, noise))
+}
+
+/// Transformation passes rewriting qoqo Circuits.
+///
+/// .. autosummary::
+///     :toctree: generated/
+///
+///     reuse_qubits
+///     unroll_conditional_loops
+///     estimate_fidelity
+///     estimate_fidelity_per_qubit
+///     transpile_to_device
+#[pymodule]
+pub fn passes(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(reuse_qubits, module)?)?;
+    module.add_function(wrap_pyfunction!(unroll_conditional_loops, module)?)?;
+    module.add_function(wrap_pyfunction!(estimate_fidelity, module)?)?;
+    module.add_function(wrap_pyfunction!(estimate_fidelity_per_qubit, module)?)?;
+    module.add_function(wrap_pyfunction!(transpile_to_device, module)?)?;
+    Ok(())
+}
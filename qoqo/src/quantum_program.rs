@@ -12,16 +12,17 @@
 
 use std::collections::HashMap;
 
+use crate::circuit::size_report_to_dict;
+use crate::conversions::convert_into_quantum_program;
 use crate::measurements::{
     CheatedPauliZProductWrapper, CheatedWrapper, ClassicalRegisterWrapper, PauliZProductWrapper,
 };
-use crate::{QoqoError, QOQO_VERSION};
+use crate::QOQO_VERSION;
 use bincode::{deserialize, serialize};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use roqoqo::measurements::Measure;
-#[cfg(feature = "json_schema")]
 use roqoqo::operations::SupportedVersion;
 use roqoqo::QuantumProgram;
 use roqoqo::ROQOQO_VERSION;
@@ -126,6 +127,14 @@ impl QuantumProgramWrapper {
         ))
     }
 
+    /// Returns the name of the measurement type of the QuantumProgram.
+    ///
+    /// Returns:
+    ///     str: The measurement type, i.e. PauliZProduct, CheatedPauliZProduct, Cheated or ClassicalRegister.
+    pub fn measurement_type(&self) -> &'static str {
+        self.internal.measurement_type()
+    }
+
     /// Returns the measurement attribute of the QuantumProgram as Python object.
     ///
     /// Returns:
@@ -215,6 +224,18 @@ impl QuantumProgramWrapper {
         }
     }
 
+    /// Check that all classical registers used by the measurement are defined and large enough.
+    ///
+    /// Called automatically by `run` and `run_registers` before the program is sent to the backend.
+    ///
+    /// Raises:
+    ///     ValueError: A classical register is missing or too small.
+    pub fn validate(&self) -> PyResult<()> {
+        self.internal
+            .validate()
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))
+    }
+
     /// Runs the QuantumProgram and returns expectation values.
     ///
     /// Runs the quantum programm for a given set of parameters passed in the same order as the parameters
@@ -229,6 +250,7 @@ impl QuantumProgramWrapper {
             QuantumProgram::PauliZProduct{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(PyValueError::new_err( format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())))};
                 let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
+                measurement.validate().map_err(|err| PyValueError::new_err(format!("{}", err)))?;
                 let substituted_measurement = measurement.substitute_parameters(
                     substituted_parameters
                 ).map_err(|err| PyRuntimeError::new_err(format!("Applying parameters failed {:?}", err)))?;
@@ -237,6 +259,7 @@ impl QuantumProgramWrapper {
             QuantumProgram::CheatedPauliZProduct{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(PyValueError::new_err( format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())))};
                 let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
+                measurement.validate().map_err(|err| PyValueError::new_err(format!("{}", err)))?;
                 let substituted_measurement = measurement.substitute_parameters(
                     substituted_parameters
                 ).map_err(|err| PyRuntimeError::new_err(format!("Applying parameters failed {:?}", err)))?;
@@ -245,6 +268,7 @@ impl QuantumProgramWrapper {
             QuantumProgram::Cheated{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(PyValueError::new_err( format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())))};
                 let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
+                measurement.validate().map_err(|err| PyValueError::new_err(format!("{}", err)))?;
                 let substituted_measurement = measurement.substitute_parameters(
                     substituted_parameters
                 ).map_err(|err| PyRuntimeError::new_err(format!("Applying parameters failed {:?}", err)))?;
@@ -275,6 +299,7 @@ impl QuantumProgramWrapper {
             QuantumProgram::ClassicalRegister{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(PyValueError::new_err( format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())))};
                 let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
+                measurement.validate().map_err(|err| PyValueError::new_err(format!("{}", err)))?;
                 let substituted_measurement = measurement.substitute_parameters(
                     substituted_parameters
                 ).map_err(|err| PyRuntimeError::new_err(format!("Applying parameters failed {:?}", err)))?;
@@ -320,6 +345,20 @@ impl QuantumProgramWrapper {
         (rver, qver)
     }
 
+    /// Return statistics about the size of the QuantumProgram.
+    ///
+    /// The statistics are aggregated over all circuits of the measurement,
+    /// the byte size is the size of the bincode serialization of the whole QuantumProgram.
+    ///
+    /// Returns:
+    ///     Dict[str, int]: The total number of operations ("number_operations"),
+    ///                     the size of the bincode serialization in bytes ("serialized_bytes"),
+    ///                     the largest dimension of a set statevector or density matrix ("max_state_dimension")
+    ///                     and the maximal nesting depth of circuits ("max_nesting_depth").
+    pub fn size_report(&self) -> HashMap<String, usize> {
+        size_report_to_dict(self.internal.size_report())
+    }
+
     /// Return the bincode representation of the QuantumProgram using the [bincode] crate.
     ///
     /// Returns:
@@ -355,8 +394,11 @@ impl QuantumProgramWrapper {
             .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
         Ok(Self {
-            internal: deserialize(&bytes[..]).map_err(|_| {
-                PyValueError::new_err("Input cannot be deserialized to QuantumProgram")
+            internal: deserialize(&bytes[..]).map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Input cannot be deserialized to QuantumProgram: {}",
+                    err
+                ))
             })?,
         })
     }
@@ -378,19 +420,28 @@ impl QuantumProgramWrapper {
     ///
     /// Args:
     ///     input (str): The serialized QuantumProgram in json form.
+    ///     strict (bool): Whether fields unknown to the QuantumProgram, its measurement or circuits raise an error instead of being ignored.
     ///
     /// Returns:
     ///     QuantumProgram: The deserialized QuantumProgram.
     ///
     /// Raises:
-    ///     ValueError: Input cannot be deserialized to QuantumProgram.
+    ///     ValueError: Input cannot be deserialized to QuantumProgram or contains unknown fields in strict mode.
     #[staticmethod]
-    fn from_json(input: &str) -> PyResult<Self> {
-        Ok(Self {
-            internal: serde_json::from_str(input).map_err(|_| {
-                PyValueError::new_err("Input cannot be deserialized to QuantumProgram")
-            })?,
-        })
+    #[pyo3(signature = (input, strict = false))]
+    fn from_json(input: &str, strict: bool) -> PyResult<Self> {
+        let internal = if strict {
+            QuantumProgram::from_json_strict(input).map_err(|err| err.to_string())
+        } else {
+            serde_json::from_str(input).map_err(|err| err.to_string())
+        }
+        .map_err(|err| {
+            PyValueError::new_err(format!(
+                "Input cannot be deserialized to QuantumProgram: {}",
+                err
+            ))
+        })?;
+        Ok(Self { internal })
     }
 
     #[cfg(feature = "json_schema")]
@@ -404,7 +455,6 @@ impl QuantumProgramWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
-    #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
     /// Returns:
@@ -414,7 +464,6 @@ impl QuantumProgramWrapper {
         ROQOQO_VERSION.to_string()
     }
 
-    #[cfg(feature = "json_schema")]
     /// Return the minimum version of qoqo that supports this object.
     ///
     /// Returns:
@@ -457,44 +506,3 @@ impl QuantumProgramWrapper {
         }
     }
 }
-
-/// Convert generic python object to [roqoqo::QuantumProgram].
-///
-/// Fallible conversion of generic python object to [roqoqo::QuantumProgram].
-pub fn convert_into_quantum_program(input: &Bound<PyAny>) -> Result<QuantumProgram, QoqoError> {
-    if let Ok(try_downcast) = input.as_gil_ref().extract::<QuantumProgramWrapper>() {
-        return Ok(try_downcast.internal);
-    }
-    // Everything that follows tries to extract the quantum program when two separately
-    // compiled python packages are involved
-    // let get_version = input
-    //     .call_method0("_qoqo_versions")
-    //     .map_err(|_| QoqoError::CannotExtractObject)?;
-    // let version = get_version
-    //     .extract::<(&str, &str)>()
-    //     .map_err(|_| QoqoError::CannotExtractObject)?;
-    // let mut rsplit = ROQOQO_VERSION.split('.').take(2);
-    // let mut qsplit = QOQO_VERSION.split('.').take(2);
-    // let rver = format!(
-    //     "{}.{}",
-    //     rsplit.next().expect("ROQOQO_VERSION badly formatted"),
-    //     rsplit.next().expect("ROQOQO_VERSION badly formatted")
-    // );
-    // let qver = format!(
-    //     "{}.{}",
-    //     qsplit.next().expect("QOQO_VERSION badly formatted"),
-    //     qsplit.next().expect("QOQO_VERSION badly formatted")
-    // );
-    // let test_version: (&str, &str) = (rver.as_str(), qver.as_str());
-    // if version == test_version {
-    let get_bytes = input
-        .call_method0("to_bincode")
-        .map_err(|_| QoqoError::CannotExtractObject)?;
-    let bytes = get_bytes
-        .extract::<Vec<u8>>()
-        .map_err(|_| QoqoError::CannotExtractObject)?;
-    deserialize(&bytes[..]).map_err(|_| QoqoError::CannotExtractObject)
-    // } else {
-    //     Err(QoqoError::VersionMismatch)
-    // }
-}
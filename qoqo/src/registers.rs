@@ -0,0 +1,142 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Counts dictionaries of the bit registers returned by backends and json serialization of registers.
+
+use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use roqoqo::registers::{self as roqoqo_registers, BitOrder, BitOutputRegister, Registers};
+use std::collections::HashMap;
+
+/// Return the counts dictionary of a bit register returned by a backend.
+///
+/// Args:
+///     registers (Union[Dict[str, List[List[bool]]], Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]]): The bit registers or the registers returned by running a circuit.
+///     readout (str): The name of the bit register.
+///     bit_order (str): "msb" to write the bit of qubit 0 last, "lsb" to write it first. Defaults to "msb".
+///
+/// Returns:
+///     Dict[str, int]: The number of measurement records for each measured bitstring.
+///
+/// Raises:
+///     TypeError: The registers cannot be converted to bit registers.
+///     KeyError: The registers contain no bit register with the name readout.
+///     ValueError: The bit order is neither "msb" nor "lsb".
+#[pyfunction]
+#[pyo3(signature = (registers, readout, bit_order = "msb"))]
+pub fn counts_from_registers(
+    registers: &Bound<PyAny>,
+    readout: &str,
+    bit_order: &str,
+) -> PyResult<HashMap<String, usize>> {
+    let bit_order = extract_bit_order(bit_order)?;
+    // The output of running a circuit is a tuple of bit, float and complex registers
+    let bit_registers = match registers.downcast::<PyTuple>() {
+        Ok(tuple) => tuple.get_item(0)?,
+        Err(_) => registers.clone(),
+    };
+    let bit_registers: HashMap<String, BitOutputRegister> =
+        bit_registers.extract().map_err(|_| {
+            PyTypeError::new_err("Registers cannot be converted to bit registers".to_string())
+        })?;
+    let register = bit_registers.get(readout).ok_or_else(|| {
+        PyKeyError::new_err(format!("No bit register with name {} found", readout))
+    })?;
+    Ok(roqoqo_registers::counts(register, bit_order))
+}
+
+/// Construct a bit register from a counts dictionary.
+///
+/// When number_shots equals the total of the counts, each bitstring appears as often as it is counted.
+/// Otherwise the counts are scaled to number_shots measurement records.
+///
+/// Args:
+///     counts (Dict[str, int]): The number of measurement records for each bitstring.
+///     number_shots (int): The number of measurement records in the returned register.
+///     bit_order (str): "msb" if the bit of qubit 0 is written last, "lsb" if it is written first. Defaults to "msb".
+///
+/// Returns:
+///     List[List[bool]]: The bit register sorted by bitstring.
+///
+/// Raises:
+///     ValueError: The bit order or a bitstring is invalid, or all counts are zero.
+#[pyfunction]
+#[pyo3(signature = (counts, number_shots, bit_order = "msb"))]
+pub fn register_from_counts(
+    counts: HashMap<String, usize>,
+    number_shots: usize,
+    bit_order: &str,
+) -> PyResult<BitOutputRegister> {
+    roqoqo_registers::register_from_counts(&counts, number_shots, extract_bit_order(bit_order)?)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+/// Serialize the registers returned by running a circuit to json.
+///
+/// The registers are written as a list of the bit, float and complex registers, each a dictionary sorted by register name.
+/// JSON has no representation of NaN and infinite values. With lossy=True they are written as the
+/// strings "NaN", "Infinity" and "-Infinity", otherwise they raise a ValueError.
+///
+/// Args:
+///     registers (Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]): The registers returned by running a circuit.
+///     lossy (bool): Whether values that are not finite are written as strings. Defaults to False.
+///
+/// Returns:
+///     str: The serialized registers.
+///
+/// Raises:
+///     TypeError: The input cannot be converted to registers.
+///     ValueError: A register contains a value that is not finite and lossy is False.
+#[pyfunction]
+#[pyo3(signature = (registers, lossy = false))]
+pub fn registers_to_json(registers: &Bound<PyAny>, lossy: bool) -> PyResult<String> {
+    let registers: Registers = registers
+        .extract()
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to registers".to_string()))?;
+    let json = if lossy {
+        roqoqo_registers::registers_to_json_lossy(&registers)
+    } else {
+        roqoqo_registers::registers_to_json(&registers)
+    };
+    json.map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+/// Deserialize registers from json.
+///
+/// Reads values that are not finite written as the strings "NaN", "Infinity" and "-Infinity".
+///
+/// Args:
+///     input (str): The serialized registers.
+///
+/// Returns:
+///     Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]: The bit, float and complex registers.
+///
+/// Raises:
+///     ValueError: The input is not valid json of registers.
+#[pyfunction]
+pub fn registers_from_json(input: &str) -> PyResult<Registers> {
+    roqoqo_registers::registers_from_json(input)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+// Converts the Python name of a bit order.
+fn extract_bit_order(bit_order: &str) -> PyResult<BitOrder> {
+    match bit_order {
+        "msb" => Ok(BitOrder::MsbFirst),
+        "lsb" => Ok(BitOrder::LsbFirst),
+        _ => Err(PyValueError::new_err(format!(
+            "Bit order {} is neither msb nor lsb",
+            bit_order
+        ))),
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of qoqo Circuits on devices.
+
+use std::collections::HashMap;
+
+use crate::devices::GenericDeviceWrapper;
+use crate::CircuitWrapper;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use roqoqo::schedule::ScheduledCircuit;
+
+/// A Circuit together with the start times of its operations.
+///
+/// The start times are given in the order of the operations when iterating over the Circuit,
+/// i.e. the definitions first, followed by the other operations.
+/// A ScheduledCircuit is created by scheduling a Circuit on a device with schedule_asap.
+#[pyclass(name = "ScheduledCircuit", module = "qoqo")]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ScheduledCircuitWrapper {
+    /// Internal storage of [roqoqo::schedule::ScheduledCircuit]
+    pub internal: ScheduledCircuit,
+}
+
+#[pymethods]
+impl ScheduledCircuitWrapper {
+    /// Return the scheduled Circuit.
+    ///
+    /// Returns:
+    ///     Circuit: The scheduled Circuit.
+    pub fn circuit(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.circuit.clone(),
+        }
+    }
+
+    /// Return the start times of the operations of the scheduled Circuit.
+    ///
+    /// Returns:
+    ///     List[float]: The start time of each operation in the order of the Circuit.
+    pub fn start_times(&self) -> Vec<f64> {
+        self.internal.start_times().to_vec()
+    }
+
+    /// Return the durations of the operations of the scheduled Circuit.
+    ///
+    /// Returns:
+    ///     List[float]: The time each operation takes in the order of the Circuit.
+    pub fn durations(&self) -> Vec<f64> {
+        self.internal.durations.clone()
+    }
+
+    /// Return the total duration of the scheduled Circuit.
+    ///
+    /// Returns:
+    ///     float: The latest end time of all operations, zero for an empty Circuit.
+    pub fn total_duration(&self) -> f64 {
+        self.internal.total_duration()
+    }
+
+    /// Return the mapping of the qubits of the Circuit to the qubits of the device.
+    ///
+    /// Returns:
+    ///     Optional[Dict[int, int]]: The qubit assignment, None if the qubits are not remapped.
+    pub fn qubit_assignment(&self) -> Option<HashMap<usize, usize>> {
+        self.internal.qubit_assignment.clone()
+    }
+
+    /// Export the timeline of the scheduled Circuit to json for rendering a Gantt chart.
+    ///
+    /// Returns:
+    ///     str: A json list with the fields `qubits`, `hqslang`, `start`, `duration` and `index` for each operation.
+    pub fn to_timeline_json(&self) -> String {
+        self.internal.to_timeline_json()
+    }
+
+    /// Return the timeline entries grouped by qubit and sorted by start time.
+    ///
+    /// Each entry is a dict with the fields `qubits`, `hqslang`, `start`, `duration` and `index`.
+    /// Operations without qubits are not part of any qubit row.
+    ///
+    /// Returns:
+    ///     Dict[int, List[Dict[str, Any]]]: The timeline entries of each qubit.
+    ///
+    /// Raises:
+    ///     ValueError: Two operations on the same qubit overlap in time.
+    pub fn per_qubit_timeline(&self, py: Python) -> PyResult<HashMap<usize, Vec<Py<PyDict>>>> {
+        let rows = self
+            .internal
+            .per_qubit_timeline()
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+        let mut timeline: HashMap<usize, Vec<Py<PyDict>>> = HashMap::new();
+        for (qubit, row) in rows {
+            let mut entries: Vec<Py<PyDict>> = Vec::with_capacity(row.len());
+            for entry in row {
+                let dict = PyDict::new_bound(py);
+                dict.set_item("qubits", entry.qubits)?;
+                dict.set_item("hqslang", entry.hqslang)?;
+                dict.set_item("start", entry.start)?;
+                dict.set_item("duration", entry.duration)?;
+                dict.set_item("index", entry.index)?;
+                entries.push(dict.unbind());
+            }
+            timeline.insert(qubit, entries);
+        }
+        Ok(timeline)
+    }
+
+    /// Return a copy of the ScheduledCircuit (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     ScheduledCircuit: A deep copy of self.
+    pub fn __copy__(&self) -> ScheduledCircuitWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the ScheduledCircuit.
+    ///
+    /// Returns:
+    ///     ScheduledCircuit: A deep copy of self.
+    pub fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> ScheduledCircuitWrapper {
+        self.clone()
+    }
+
+    /// Return the json representation of the ScheduledCircuit.
+    ///
+    /// Returns:
+    ///     str: The serialized form of ScheduledCircuit.
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize ScheduledCircuit to json.
+    pub fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize ScheduledCircuit to json"))
+    }
+
+    /// Convert the json representation of a ScheduledCircuit to a ScheduledCircuit.
+    ///
+    /// Args:
+    ///     input (str): The serialized ScheduledCircuit in json form.
+    ///
+    /// Returns:
+    ///     ScheduledCircuit: The deserialized ScheduledCircuit.
+    ///
+    /// Raises:
+    ///     ValueError: Input cannot be deserialized to ScheduledCircuit.
+    #[staticmethod]
+    #[pyo3(text_signature = "(input)")]
+    pub fn from_json(input: &str) -> PyResult<Self> {
+        Ok(Self {
+            internal: serde_json::from_str(input).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to ScheduledCircuit")
+            })?,
+        })
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on ScheduledCircuit.
+    ///
+    /// Args:
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two ScheduledCircuits compared evaluated to True or False.
+    ///
+    /// Raises:
+    ///     NotImplementedError: Other comparison not implemented.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other = other.extract::<ScheduledCircuitWrapper>();
+        match op {
+            pyo3::class::basic::CompareOp::Eq => match other {
+                Ok(scheduled) => Ok(self.internal == scheduled.internal),
+                _ => Ok(false),
+            },
+            pyo3::class::basic::CompareOp::Ne => match other {
+                Ok(scheduled) => Ok(self.internal != scheduled.internal),
+                _ => Ok(true),
+            },
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented",
+            )),
+        }
+    }
+}
+
+/// Schedule the operations of a Circuit as soon as possible on a device.
+///
+/// Every operation starts as soon as all operations it depends on in the CircuitDag of the Circuit
+/// have finished. Gates take the gate time of the device, noise PRAGMAs their gate time and
+/// PragmaSleep its sleep time. Operations without a defined time, like measurements, take no time.
+///
+/// Args:
+///     circuit (Circuit): The Circuit that is scheduled.
+///     device (Device): The device providing the gate times.
+///
+/// Returns:
+///     ScheduledCircuit: The Circuit with the start times of its operations.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit or device.
+///     ValueError: A gate of the Circuit is not available on the device.
+#[pyfunction]
+#[pyo3(text_signature = "(circuit, device, /)")]
+pub fn schedule_asap(
+    circuit: &Bound<PyAny>,
+    device: &Bound<PyAny>,
+) -> PyResult<ScheduledCircuitWrapper> {
+    let circuit = CircuitWrapper::from_pyany(circuit)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let device = GenericDeviceWrapper::from_pyany(device)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to a device"))?;
+    let internal = roqoqo::schedule::schedule_asap(&circuit, &device)
+        .map_err(|err| PyValueError::new_err(format!("Scheduling failed: {}", err)))?;
+    Ok(ScheduledCircuitWrapper { internal })
+}
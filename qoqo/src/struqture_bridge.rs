@@ -0,0 +1,100 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of struqture objects between Rust and Python.
+//!
+//! qoqo is built against struqture 1.x while users may pass objects created with struqture-py 2.x.
+//! The functions in this module accept both versions and can be reused by qoqo backends
+//! facing the same problem.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use struqture::spins::PlusMinusLindbladNoiseOperator;
+#[cfg(feature = "unstable_analog_operations")]
+use struqture::spins::{SpinHamiltonian, SpinHamiltonianSystem};
+use struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper;
+#[cfg(feature = "unstable_analog_operations")]
+use struqture_py::spins::SpinHamiltonianSystemWrapper;
+
+/// Converts a PlusMinusLindbladNoiseOperator to a struqture-py Python object.
+///
+/// # Arguments
+///
+/// * `py` - The Python GIL token.
+/// * `operator` - The noise operator that is converted.
+///
+/// # Returns
+///
+/// * `Ok(Py<PyAny>)` - The struqture_py.spins.PlusMinusLindbladNoiseOperator.
+/// * `Err(PyErr)` - The Python object could not be created.
+pub fn noise_operator_to_python(
+    py: Python,
+    operator: &PlusMinusLindbladNoiseOperator,
+) -> PyResult<Py<PyAny>> {
+    let wrapper = PlusMinusLindbladNoiseOperatorWrapper {
+        internal: operator.clone(),
+    };
+    Ok(Py::new(py, wrapper)?.into_any())
+}
+
+/// Extracts a PlusMinusLindbladNoiseOperator from a struqture-py 1.x or 2.x Python object.
+///
+/// # Arguments
+///
+/// * `_py` - The Python GIL token.
+/// * `input` - The struqture_py.spins.PlusMinusLindbladNoiseOperator of either struqture version.
+///
+/// # Returns
+///
+/// * `Ok(PlusMinusLindbladNoiseOperator)` - The extracted noise operator.
+/// * `Err(PyValueError)` - The input could not be converted from either struqture version.
+pub fn noise_operator_from_python(
+    _py: Python,
+    input: &Bound<PyAny>,
+) -> PyResult<PlusMinusLindbladNoiseOperator> {
+    match PlusMinusLindbladNoiseOperatorWrapper::from_pyany(input) {
+        Ok(operator) => Ok(operator),
+        Err(_) => PlusMinusLindbladNoiseOperatorWrapper::from_struqture_2(input)
+            .map(|wrapper| wrapper.internal)
+            .map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Could not convert input noise_operator from either struqture 1.x or struqture 2.x: {:?}",
+                    err
+                ))
+            }),
+    }
+}
+
+/// Converts a SpinHamiltonian to a struqture-py Python object.
+///
+/// The Hamiltonian is wrapped in a SpinHamiltonianSystem without a fixed number of spins,
+/// the same Python type returned by the hamiltonian getter of ApplyConstantSpinHamiltonian.
+///
+/// # Arguments
+///
+/// * `py` - The Python GIL token.
+/// * `hamiltonian` - The Hamiltonian that is converted.
+///
+/// # Returns
+///
+/// * `Ok(Py<PyAny>)` - The struqture_py.spins.SpinHamiltonianSystem.
+/// * `Err(PyErr)` - The Python object could not be created.
+#[cfg(feature = "unstable_analog_operations")]
+pub fn spin_hamiltonian_to_python(
+    py: Python,
+    hamiltonian: &SpinHamiltonian,
+) -> PyResult<Py<PyAny>> {
+    let system = SpinHamiltonianSystem::from_hamiltonian(hamiltonian.clone(), None)
+        .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+    let wrapper = SpinHamiltonianSystemWrapper { internal: system };
+    Ok(Py::new(py, wrapper)?.into_any())
+}
@@ -0,0 +1,310 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Random circuit generation, circuit comparison and mock sampling for tests and benchmarks.
+
+use crate::backends::measurement_registers;
+use crate::measurements::PauliZProductInputWrapper;
+use crate::{convert_into_circuit, CircuitWrapper};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use roqoqo::backends::{circuit_fingerprint, EvaluatingBackend, MockSamplingBackend};
+use roqoqo::operations::Operation;
+use roqoqo::registers::Registers;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Create a random circuit from a set of gates.
+///
+/// The circuit consists of `depth` layers. In every layer every qubit takes part in at most one gate,
+/// drawn uniformly from the gates of `gate_set`. Rotation angles are drawn uniformly from [0, 2π).
+/// The circuit is deterministic for a given seed.
+///
+/// Args:
+///     number_qubits (int): The number of qubits of the circuit.
+///     depth (int): The number of layers of the circuit.
+///     seed (int): The seed of the random number generator.
+///     gate_set (List[str]): The hqslang names of the gates.
+///
+/// Returns:
+///     Circuit: The random circuit.
+///
+/// Raises:
+///     ValueError: A gate is not supported, the gate set is empty or no gate fits on number_qubits qubits.
+#[pyfunction]
+#[pyo3(text_signature = "(number_qubits, depth, seed, gate_set, /)")]
+pub fn random_circuit(
+    number_qubits: usize,
+    depth: usize,
+    seed: u64,
+    gate_set: Vec<String>,
+) -> PyResult<CircuitWrapper> {
+    let gate_set: Vec<&str> = gate_set.iter().map(|name| name.as_str()).collect();
+    let circuit = roqoqo::testing::random_circuit(number_qubits, depth, seed, &gate_set)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    Ok(CircuitWrapper { internal: circuit })
+}
+
+/// Create a random circuit of Clifford gates.
+///
+/// The circuit is deterministic for a given seed.
+///
+/// Args:
+///     number_qubits (int): The number of qubits of the circuit.
+///     depth (int): The number of layers of the circuit.
+///     seed (int): The seed of the random number generator.
+///
+/// Returns:
+///     Circuit: The random Clifford circuit.
+#[pyfunction]
+#[pyo3(text_signature = "(number_qubits, depth, seed, /)")]
+pub fn random_clifford_circuit(number_qubits: usize, depth: usize, seed: u64) -> CircuitWrapper {
+    CircuitWrapper {
+        internal: roqoqo::testing::random_clifford_circuit(number_qubits, depth, seed),
+    }
+}
+
+/// Create a random PauliZProductInput.
+///
+/// The PauliZ products are read out from the register `ro` and combined
+/// in the linear expectation value `random`. The input is deterministic for a given seed.
+///
+/// Args:
+///     number_qubits (int): The number of qubits of the measured circuits.
+///     n_products (int): The number of PauliZ products.
+///     seed (int): The seed of the random number generator.
+///
+/// Returns:
+///     PauliZProductInput: The random measurement input.
+///
+/// Raises:
+///     ValueError: PauliZ products are requested on zero qubits.
+#[pyfunction]
+#[pyo3(text_signature = "(number_qubits, n_products, seed, /)")]
+pub fn random_pauli_measurement_input(
+    number_qubits: usize,
+    n_products: usize,
+    seed: u64,
+) -> PyResult<PauliZProductInputWrapper> {
+    let input = roqoqo::testing::random_pauli_measurement_input(number_qubits, n_products, seed)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    Ok(PauliZProductInputWrapper { internal: input })
+}
+
+/// Check if two circuits implement the same unitary up to a global phase.
+///
+/// Parametrized circuits are compared for `samples` random values of the union of their free
+/// parameters, drawn uniformly from [0, 2π). The same values are substituted into both circuits.
+/// Circuits without free parameters are compared once.
+/// The circuits can act on at most 10 qubits.
+///
+/// Args:
+///     a (Circuit): The first circuit.
+///     b (Circuit): The second circuit.
+///     samples (int): The number of random parameter values compared for parametrized circuits.
+///     seed (int): The seed of the random parameter values.
+///     tolerance (float): The largest allowed absolute difference of the entries of the unitaries.
+///
+/// Returns:
+///     bool: True if the unitaries agree up to a global phase for all samples.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to Circuit.
+///     ValueError: A circuit contains a non-unitary operation, acts on too many qubits or a parameter cannot be evaluated.
+#[pyfunction]
+#[pyo3(signature = (a, b, samples = 10, seed = 0, tolerance = 1e-10))]
+pub fn circuits_equivalent(
+    a: &Bound<PyAny>,
+    b: &Bound<PyAny>,
+    samples: usize,
+    seed: u64,
+    tolerance: f64,
+) -> PyResult<bool> {
+    let a = CircuitWrapper::from_pyany(a)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    let b = CircuitWrapper::from_pyany(b)
+        .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
+    roqoqo::passes::circuits_equivalent(&a, &b, samples, seed, tolerance)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+/// Backend sampling the measurement outcomes of circuits from configured probability tables.
+///
+/// Intended for testing the post-processing of measurements without a simulator.
+/// The distribution of a circuit is looked up by its fingerprint written as a decimal number and,
+/// if no distribution is configured for the fingerprint, by the index of the run,
+/// counting all circuits run on the backend since its creation starting from zero.
+/// Fingerprints are reproducible, so the distributions of a test can be stored with its data.
+/// Each outcome of a distribution gives the measured value of every qubit.
+/// For every output bit register measured by MeasureQubit or PragmaRepeatedMeasurement,
+/// the number of measurements set by PragmaSetNumberOfMeasurements or PragmaRepeatedMeasurement
+/// (one otherwise) of outcomes is sampled. Gates and other PRAGMAs are ignored.
+///
+/// Args:
+///     distributions (Dict[str, List[Tuple[List[bool], float]]]): The outcomes and their probabilities, by circuit fingerprint or run index.
+///     seed (int): The seed of the random number generator.
+///
+/// Returns:
+///     MockSamplingBackend: The new backend.
+///
+/// Raises:
+///     ValueError: A distribution is empty or has negative, non-finite or only zero probabilities.
+#[pyclass(name = "MockSamplingBackend", module = "qoqo.testing")]
+#[derive(Debug)]
+pub struct MockSamplingBackendWrapper {
+    /// Internal storage of [roqoqo::backends::MockSamplingBackend].
+    pub internal: MockSamplingBackend,
+}
+
+#[pymethods]
+impl MockSamplingBackendWrapper {
+    /// Create a new MockSamplingBackend.
+    ///
+    /// Args:
+    ///     distributions (Dict[str, List[Tuple[List[bool], float]]]): The outcomes and their probabilities, by circuit fingerprint or run index.
+    ///     seed (int): The seed of the random number generator.
+    ///
+    /// Returns:
+    ///     MockSamplingBackend: The new backend.
+    ///
+    /// Raises:
+    ///     ValueError: A distribution is empty or has negative, non-finite or only zero probabilities.
+    #[new]
+    #[pyo3(signature = (distributions, seed = 0))]
+    pub fn new(distributions: HashMap<String, Vec<(Vec<bool>, f64)>>, seed: u64) -> PyResult<Self> {
+        Ok(Self {
+            internal: MockSamplingBackend::new(distributions, seed)
+                .map_err(|err| PyValueError::new_err(format!("{}", err)))?,
+        })
+    }
+
+    /// Return the fingerprint of a circuit used to look up its distribution.
+    ///
+    /// The fingerprint only depends on the operations of the circuit and the qoqo version.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit.
+    ///
+    /// Returns:
+    ///     str: The fingerprint of the circuit as a decimal number.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    #[staticmethod]
+    pub fn fingerprint(circuit: &Bound<PyAny>) -> PyResult<String> {
+        let circuit = convert_circuit(circuit)?;
+        Ok(circuit_fingerprint(circuit.iter()).to_string())
+    }
+
+    /// Sample the measurement outcomes of a circuit.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit that is run.
+    ///
+    /// Returns:
+    ///     Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]: The bit, float and complex output registers.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    ///     RuntimeError: No distribution is configured for the circuit or the circuit cannot be sampled.
+    pub fn run_circuit(&self, py: Python, circuit: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+        let circuit = convert_circuit(circuit)?;
+        Ok(self
+            .run_operations(circuit.iter().cloned().collect())?
+            .into_py(py))
+    }
+
+    /// Sample the measurement outcomes of all circuits of a measurement.
+    ///
+    /// The constant circuit of the measurement is run before each circuit.
+    ///
+    /// Args:
+    ///     measurement: The measurement that is run.
+    ///
+    /// Returns:
+    ///     Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]: The bit, float and complex output registers.
+    ///
+    /// Raises:
+    ///     TypeError: The circuits of the measurement cannot be extracted.
+    ///     RuntimeError: No distribution is configured for a circuit or a circuit cannot be sampled.
+    pub fn run_measurement_registers(
+        &self,
+        py: Python,
+        measurement: &Bound<PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        Ok(
+            measurement_registers(measurement, |operations| self.run_operations(operations))?
+                .into_py(py),
+        )
+    }
+
+    /// Evaluate the expectation values of a measurement from sampled measurement outcomes.
+    ///
+    /// Args:
+    ///     measurement: The measurement that is run.
+    ///
+    /// Returns:
+    ///     Optional[Dict[str, float]]: The evaluated expectation values.
+    ///
+    /// Raises:
+    ///     TypeError: The circuits of the measurement cannot be extracted.
+    ///     RuntimeError: No distribution is configured for a circuit or a circuit cannot be sampled.
+    pub fn run_measurement(&self, measurement: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+        let (bit_registers, float_registers, complex_registers) =
+            measurement_registers(measurement, |operations| self.run_operations(operations))?;
+        measurement
+            .call_method1(
+                "evaluate",
+                (bit_registers, float_registers, complex_registers),
+            )
+            .map(|result| result.unbind())
+    }
+}
+
+impl MockSamplingBackendWrapper {
+    // Samples the measurement outcomes of the operations of a circuit.
+    fn run_operations(&self, operations: Vec<Operation>) -> PyResult<Registers> {
+        self.internal
+            .run_circuit_iterator(operations.iter())
+            .map_err(|err| PyRuntimeError::new_err(format!("{}", err)))
+    }
+}
+
+// Converts a Python object to a roqoqo Circuit.
+fn convert_circuit(circuit: &Bound<PyAny>) -> PyResult<Circuit> {
+    convert_into_circuit(circuit).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Circuit argument cannot be converted to qoqo Circuit {:?}",
+            err
+        ))
+    })
+}
+
+/// Random circuit generation, circuit comparison and mock sampling for tests and benchmarks.
+///
+/// .. autosummary::
+///     :toctree: generated/
+///
+///     random_circuit
+///     random_clifford_circuit
+///     random_pauli_measurement_input
+///     circuits_equivalent
+///     MockSamplingBackend
+#[pymodule]
+pub fn testing(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(random_circuit, module)?)?;
+    module.add_function(wrap_pyfunction!(random_clifford_circuit, module)?)?;
+    module.add_function(wrap_pyfunction!(random_pauli_measurement_input, module)?)?;
+    module.add_function(wrap_pyfunction!(circuits_equivalent, module)?)?;
+    module.add_class::<MockSamplingBackendWrapper>()?;
+    Ok(())
+}
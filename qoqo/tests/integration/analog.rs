@@ -0,0 +1,74 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo::analog::effective_hamiltonian;
+use qoqo::CircuitWrapper;
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
+use struqture_py::spins::SpinHamiltonianSystemWrapper;
+
+/// Creates a Hamiltonian with a single PauliProduct
+fn hamiltonian(pp: PauliProduct, value: f64) -> SpinHamiltonian {
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian.add_operator_product(pp, value.into()).unwrap();
+    hamiltonian
+}
+
+/// Test effective_hamiltonian of a two-term Hamiltonian against the roqoqo function
+#[test]
+fn test_effective_hamiltonian() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit +=
+        ApplyConstantSpinHamiltonian::new(hamiltonian(PauliProduct::new().z(0), 1.0), 0.5.into());
+    circuit += ApplyConstantSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().x(0).x(1), 2.0),
+        1.5.into(),
+    );
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let result = effective_hamiltonian(py, circuit_py.as_any()).unwrap();
+        let wrapper = result
+            .bind(py)
+            .extract::<SpinHamiltonianSystemWrapper>()
+            .unwrap();
+        let expected = roqoqo::analog::effective_hamiltonian(&circuit).unwrap();
+        assert_eq!(wrapper.internal.hamiltonian(), &expected);
+        assert_eq!(wrapper.internal.len(), 2);
+        assert_eq!(
+            wrapper.internal.get(&PauliProduct::new().x(0).x(1)),
+            &3.0.into()
+        );
+
+        let error = effective_hamiltonian(py, &0_usize.into_py(py).into_bound(py)).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+
+        circuit += PauliX::new(0);
+        circuit += ApplyConstantSpinHamiltonian::new(
+            hamiltonian(PauliProduct::new().z(0), 1.0),
+            1.0.into(),
+        );
+        let circuit_py = Bound::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let error = effective_hamiltonian(py, circuit_py.as_any()).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
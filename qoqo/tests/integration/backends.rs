@@ -0,0 +1,177 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use qoqo::backends::CachingBackendWrapper;
+use qoqo::measurements::PauliZProductWrapper;
+use qoqo::CircuitWrapper;
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::operations;
+use roqoqo::registers::Registers;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Python backend counting its runs and returning the run number in the float register "runs"
+#[pyclass]
+struct CountingBackend {
+    #[pyo3(get)]
+    runs: usize,
+}
+
+#[pymethods]
+impl CountingBackend {
+    fn run_circuit(&mut self, circuit: CircuitWrapper) -> PyResult<Registers> {
+        if circuit.internal.is_empty() {
+            return Err(PyValueError::new_err("Empty circuit"));
+        }
+        self.runs += 1;
+        Ok((
+            HashMap::from([("ro".to_string(), vec![vec![true]])]),
+            HashMap::from([("runs".to_string(), vec![vec![self.runs as f64]])]),
+            HashMap::new(),
+        ))
+    }
+}
+
+fn circuit(angle: f64) -> CircuitWrapper {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, angle.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    CircuitWrapper { internal: circuit }
+}
+
+fn runs(py: Python, backend: &Py<CountingBackend>) -> usize {
+    backend.borrow(py).runs
+}
+
+/// Test that repeated circuits are only run once on the wrapped Python backend
+#[test]
+fn test_caching_backend_run_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let counting = Py::new(py, CountingBackend { runs: 0 }).unwrap();
+        let backend = Py::new(
+            py,
+            CachingBackendWrapper::new(counting.clone_ref(py).into_any(), 2),
+        )
+        .unwrap();
+        let backend = backend.bind(py);
+
+        let first = backend
+            .call_method1("run_circuit", (circuit(0.5),))
+            .unwrap();
+        // Modifying the returned registers must not change the cache
+        first
+            .get_item(1)
+            .unwrap()
+            .get_item("runs")
+            .unwrap()
+            .call_method1("append", (vec![5.0],))
+            .unwrap();
+        let second = backend
+            .call_method1("run_circuit", (circuit(0.5),))
+            .unwrap()
+            .extract::<Registers>()
+            .unwrap();
+        assert_eq!(second.1.get("runs").unwrap(), &vec![vec![1.0]]);
+        assert_eq!(runs(py, &counting), 1);
+
+        let _ = backend
+            .call_method1("run_circuit", (circuit(1.0),))
+            .unwrap();
+        let _ = backend
+            .call_method1("run_circuit", (circuit(2.0),))
+            .unwrap();
+        // circuit(0.5) has been evicted
+        let _ = backend
+            .call_method1("run_circuit", (circuit(0.5),))
+            .unwrap();
+        assert_eq!(runs(py, &counting), 4);
+
+        let statistics = backend
+            .call_method0("cache_statistics")
+            .unwrap()
+            .extract::<HashMap<String, usize>>()
+            .unwrap();
+        assert_eq!(statistics.get("hits"), Some(&1));
+        assert_eq!(statistics.get("misses"), Some(&4));
+        assert_eq!(statistics.get("size"), Some(&2));
+        assert_eq!(statistics.get("max_size"), Some(&2));
+
+        backend.call_method0("clear_cache").unwrap();
+        let statistics = backend
+            .call_method0("cache_statistics")
+            .unwrap()
+            .extract::<HashMap<String, usize>>()
+            .unwrap();
+        assert_eq!(statistics.get("size"), Some(&0));
+
+        // Errors of the wrapped backend are raised and not cached
+        let empty = CircuitWrapper {
+            internal: Circuit::new(),
+        };
+        assert!(backend.call_method1("run_circuit", (empty,)).is_err());
+        assert!(backend.call_method1("run_circuit", (3,)).is_err());
+    })
+}
+
+/// Test that measurements are evaluated with cached circuits
+#[test]
+fn test_caching_backend_run_measurement() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let counting = Py::new(py, CountingBackend { runs: 0 }).unwrap();
+        let backend = Py::new(
+            py,
+            CachingBackendWrapper::new(counting.clone_ref(py).into_any(), 10),
+        )
+        .unwrap();
+        let backend = backend.bind(py);
+
+        let mut input = PauliZProductInput::new(1, false);
+        let index = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+        input
+            .add_linear_exp_val("z0".to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += operations::RotateY::new(0, 0.1.into());
+        let measurement = PauliZProductWrapper {
+            internal: PauliZProduct {
+                constant_circuit: Some(constant_circuit),
+                circuits: vec![circuit(0.0).internal, circuit(0.0).internal],
+                input,
+            },
+        };
+
+        let registers = backend
+            .call_method1("run_measurement_registers", (measurement.clone(),))
+            .unwrap()
+            .extract::<Registers>()
+            .unwrap();
+        assert_eq!(
+            registers.0.get("ro").unwrap(),
+            &vec![vec![true], vec![true]]
+        );
+        assert_eq!(runs(py, &counting), 1);
+
+        let result = backend
+            .call_method1("run_measurement", (measurement,))
+            .unwrap()
+            .extract::<Option<HashMap<String, f64>>>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.get("z0"), Some(&-1.0));
+        assert_eq!(runs(py, &counting), 1);
+    })
+}
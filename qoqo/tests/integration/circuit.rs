@@ -12,6 +12,7 @@
 
 use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 use qoqo::measurements::{PauliZProductInputWrapper, PauliZProductWrapper};
 use qoqo::operations::{
     convert_operation_to_pyobject, PragmaOverrotationWrapper, RotateXWrapper, RotateYWrapper,
@@ -20,10 +21,9 @@ use qoqo::{CircuitWrapper, OperationIteratorWrapper, QOQO_VERSION};
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations::Operation;
 use roqoqo::operations::*;
-#[cfg(feature = "json_schema")]
 use roqoqo::Circuit;
 use roqoqo::ROQOQO_VERSION;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use test_case::test_case;
 
 // helper functions
@@ -156,6 +156,160 @@ fn test_remap_qubits() {
     })
 }
 
+/// Test remap_registers function of Circuit composing two library circuits that both define "ro"
+#[test]
+fn test_remap_registers() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut library = Circuit::new();
+        library += DefinitionBit::new("ro".to_string(), 1, true);
+        library += MeasureQubit::new(0, "ro".to_string(), 0);
+        let first = Py::new(
+            py,
+            CircuitWrapper {
+                internal: library.clone(),
+            },
+        )
+        .unwrap();
+        let mut second = library.clone();
+        second += DefinitionBit::new("rb".to_string(), 1, true);
+
+        let remapped = first
+            .call_method1(py, "remap_registers", (HashMap::from([("ro", "ro_b")]),))
+            .unwrap();
+        let composed = first
+            .call_method1(py, "compose", (remapped,))
+            .unwrap()
+            .extract::<CircuitWrapper>(py)
+            .unwrap();
+        let mut expected = library.clone();
+        expected += DefinitionBit::new("ro_b".to_string(), 1, true);
+        expected += MeasureQubit::new(0, "ro_b".to_string(), 0);
+        assert_eq!(composed.internal, expected);
+
+        let second = Py::new(py, CircuitWrapper { internal: second }).unwrap();
+        let result = second.call_method1(py, "remap_registers", (HashMap::from([("ro", "rb")]),));
+        assert!(result.is_err());
+    })
+}
+
+/// Test validate_pragmas function of Circuit
+#[test]
+fn test_validate_pragmas() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let statevector: ndarray::Array1<num_complex::Complex64> = ndarray::array![
+            num_complex::Complex64::new(1.0, 0.0),
+            num_complex::Complex64::new(0.0, 0.0)
+        ];
+        let mut circuit = Circuit::new();
+        circuit += PragmaSetStateVector::new(statevector.clone());
+        let valid = Py::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        assert!(valid.call_method0(py, "validate_pragmas").is_ok());
+
+        let mut inner = Circuit::new();
+        inner += PragmaSetStateVector::new(statevector * 2.0);
+        circuit += PragmaLoop::new(2.into(), inner);
+        let invalid = Py::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let error = invalid.call_method0(py, "validate_pragmas").unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
+/// Test check_definitions and definitions_map functions of Circuit
+#[test]
+fn test_check_definitions() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = Circuit::new();
+        circuit += DefinitionBit::new("ro".to_string(), 2, true);
+        circuit += DefinitionFloat::new("ro_float".to_string(), 1, false);
+        let circuit = Py::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        assert!(circuit.call_method0(py, "check_definitions").is_ok());
+
+        // Duplicate definitions with the same length are merged
+        let duplicate = convert_operation_to_pyobject(Operation::from(DefinitionBit::new(
+            "ro".to_string(),
+            2,
+            false,
+        )))
+        .unwrap();
+        circuit.call_method1(py, "add", (duplicate,)).unwrap();
+        let length: usize = circuit
+            .call_method0(py, "__len__")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(length, 2);
+
+        let conflicting = convert_operation_to_pyobject(Operation::from(DefinitionBit::new(
+            "ro".to_string(),
+            3,
+            true,
+        )))
+        .unwrap();
+        circuit.call_method1(py, "add", (conflicting,)).unwrap();
+        let error = circuit.call_method0(py, "check_definitions").unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert!(error
+            .to_string()
+            .contains("ro is defined with length 2 at index 0"));
+
+        let map: HashMap<String, (usize, bool)> = circuit
+            .call_method0(py, "definitions_map")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        let expected: HashMap<String, (usize, bool)> = [
+            ("ro".to_string(), (2, true)),
+            ("ro_float".to_string(), (1, false)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map, expected);
+    })
+}
+
+/// Test used_qubits and compactify_qubits functions of Circuit
+#[test]
+fn test_compactify_qubits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = Circuit::new();
+        circuit += DefinitionBit::new("ro".to_string(), 18, true);
+        circuit += CNOT::new(17, 3);
+        circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+        circuit += MeasureQubit::new(17, "ro".to_string(), 17);
+        let circuit = Py::new(py, CircuitWrapper { internal: circuit }).unwrap();
+
+        let used: BTreeSet<usize> = circuit
+            .call_method0(py, "used_qubits")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(used, BTreeSet::from([3, 17]));
+
+        let (compacted, mapping): (CircuitWrapper, HashMap<usize, usize>) = circuit
+            .call_method0(py, "compactify_qubits")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(mapping, HashMap::from([(3, 0), (17, 1)]));
+        let mut expected = Circuit::new();
+        expected += DefinitionBit::new("ro".to_string(), 18, true);
+        expected += CNOT::new(1, 0);
+        expected += MeasureQubit::new(0, "ro".to_string(), 0);
+        expected += MeasureQubit::new(1, "ro".to_string(), 1);
+        assert_eq!(compacted.internal, expected);
+    })
+}
+
 /// Test count_occurences function of Circuit
 #[test]
 fn test_count_occurences() {
@@ -210,7 +364,289 @@ fn test_count_occurences() {
     })
 }
 
+/// Test semantically_equal function of Circuit
+#[test]
+fn test_semantically_equal() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += DefinitionFloat::new("rf".to_string(), 1, false);
+    circuit += RotateX::new(0, CalculatorFloat::from(0.5));
+    let mut other = Circuit::new();
+    other += DefinitionFloat::new("rf".to_string(), 1, false);
+    other += DefinitionBit::new("ro".to_string(), 1, true);
+    other += PragmaSleep::new(vec![0], CalculatorFloat::from(1.0));
+    other += RotateX::new(0, CalculatorFloat::from(0.5 + 2.0 * std::f64::consts::PI));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = Bound::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let other = Bound::new(py, CircuitWrapper { internal: other }).unwrap();
+
+        let strict = bool::extract_bound(
+            &circuit
+                .call_method1("semantically_equal", (other.clone(),))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!strict);
+        let same = bool::extract_bound(
+            &circuit
+                .call_method1("semantically_equal", (circuit.clone(),))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(same);
+        let partially_relaxed = bool::extract_bound(
+            &circuit
+                .call_method1("semantically_equal", (other.clone(), true, true))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!partially_relaxed);
+        let relaxed = bool::extract_bound(
+            &circuit
+                .call_method1("semantically_equal", (other.clone(), true, true, 1e-9))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(relaxed);
+
+        let comparison = circuit.call_method1("semantically_equal", (vec!["fails"],));
+        assert!(comparison.is_err());
+    })
+}
+
+/// Test annotate, annotations and the comparison of annotations in semantically_equal
+#[test]
+fn test_annotations() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        circuit
+            .call_method1("annotate", ("compiler", "1.0"))
+            .unwrap();
+        circuit
+            .call_method1(
+                "__iadd__",
+                (convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap(),),
+            )
+            .unwrap();
+        circuit
+            .call_method1("annotate", ("author", "someone"))
+            .unwrap();
+
+        let annotations: HashMap<String, String> = circuit
+            .call_method0("annotations")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            annotations,
+            HashMap::from([
+                ("compiler".to_string(), "1.0".to_string()),
+                ("author".to_string(), "someone".to_string()),
+            ])
+        );
+
+        let mut other = Circuit::new();
+        other += PauliX::new(0);
+        let other = Bound::new(py, CircuitWrapper { internal: other }).unwrap();
+        let default = bool::extract_bound(
+            &circuit
+                .call_method1("semantically_equal", (other.clone(),))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(default);
+        let kwargs = [("compare_annotations", true)].into_py_dict_bound(py);
+        let with_annotations = bool::extract_bound(
+            &circuit
+                .call_method("semantically_equal", (other.clone(),), Some(&kwargs))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!with_annotations);
+        let strict =
+            bool::extract_bound(&circuit.call_method1("__eq__", (other,)).unwrap()).unwrap();
+        assert!(!strict);
+
+        // The annotations survive the json serialization
+        let json = circuit.call_method0("to_json").unwrap();
+        let deserialized = circuit.call_method1("from_json", (json,)).unwrap();
+        let annotations: HashMap<String, String> = deserialized
+            .call_method0("annotations")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(annotations.len(), 2);
+    })
+}
+
+/// Test tensor and compose functions of Circuit
+#[test]
+fn test_tensor_compose() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    let mut other = Circuit::new();
+    other += DefinitionBit::new("ro".to_string(), 1, true);
+    other += PauliX::new(0);
+    other += MeasureQubit::new(0, "ro".to_string(), 0);
+    let mut renamed = Circuit::new();
+    renamed += DefinitionBit::new("ro_other".to_string(), 1, true);
+    renamed += PauliX::new(0);
+    renamed += MeasureQubit::new(0, "ro_other".to_string(), 0);
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let other_py = Bound::new(py, CircuitWrapper { internal: other }).unwrap();
+        let renamed_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: renamed.clone(),
+            },
+        )
+        .unwrap();
+
+        // Clashing register names
+        let result = circuit_py.call_method1("tensor", (other_py.clone(), 2));
+        assert!(result.is_err());
+        let result = circuit_py.call_method1("compose", (other_py,));
+        assert!(result.is_err());
+
+        let tensored = circuit_py
+            .call_method1("tensor", (renamed_py.clone(), 2))
+            .unwrap();
+        let tensored = tensored.downcast::<CircuitWrapper>().unwrap().borrow();
+        assert_eq!(tensored.internal, circuit.tensor(&renamed, 2).unwrap());
+        assert_eq!(
+            tensored.internal.get(4),
+            Some(&Operation::from(PauliX::new(2)))
+        );
+
+        // Overlapping qubits
+        let result = circuit_py.call_method1("tensor", (renamed_py.clone(), 1));
+        assert!(result.is_err());
+
+        let composed = circuit_py.call_method1("compose", (renamed_py,)).unwrap();
+        let composed = composed.downcast::<CircuitWrapper>().unwrap().borrow();
+        assert_eq!(composed.internal, circuit.clone() + renamed);
+
+        let result = circuit_py.call_method1("tensor", (vec!["fails"], 2));
+        assert!(result.is_err());
+        let result = circuit_py.call_method1("compose", (vec!["fails"],));
+        assert!(result.is_err());
+    })
+}
+
+/// Test to_cirq_json function of Circuit
+#[cfg(feature = "circuitdag")]
+#[test]
+fn test_to_cirq_json() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += Hadamard::new(0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    let mut unsupported = circuit.clone();
+    unsupported += PragmaActiveReset::new(0);
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let cirq_json: String = circuit_py
+            .call_method0("to_cirq_json")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(cirq_json, circuit.to_cirq_json().unwrap());
+
+        let unsupported_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: unsupported,
+            },
+        )
+        .unwrap();
+        let result = unsupported_py.call_method0("to_cirq_json");
+        assert!(result.is_err());
+    })
+}
+
+/// Test to_latex function of Circuit
+#[test]
+fn test_to_latex() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += PragmaActiveReset::new(0);
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let latex: String = circuit_py
+            .call_method0("to_latex")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(latex, circuit.to_latex().unwrap());
+
+        let latex: String = circuit_py
+            .call_method1("to_latex", (true,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            latex,
+            "\\begin{quantikz}\n\\lstick{$q_{0}$} & \\gate{H} & \\qw\n\\end{quantikz}"
+        );
+    })
+}
+
 /// Test get_operation_types function of Circuit
+/// Test size_report function of Circuit
+#[test]
+fn test_size_report() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut inner = Circuit::new();
+        inner += PragmaLoop::new(2.into(), Circuit::new() + RotateX::new(0, 1.0.into()));
+        let mut circuit = CircuitWrapper::new();
+        circuit.internal += DefinitionBit::new("ro".to_string(), 1, false);
+        circuit.internal += PragmaLoop::new(3.into(), inner);
+        let circuit = Bound::new(py, circuit).unwrap();
+
+        let report = circuit
+            .call_method0("size_report")
+            .unwrap()
+            .extract::<HashMap<String, usize>>()
+            .unwrap();
+        let serialized = circuit
+            .call_method0("to_bincode")
+            .unwrap()
+            .extract::<Vec<u8>>()
+            .unwrap();
+        assert_eq!(report["serialized_bytes"], serialized.len());
+        assert_eq!(report["max_nesting_depth"], 2);
+        assert_eq!(report["number_operations"], 4);
+        assert_eq!(report["max_state_dimension"], 0);
+    })
+}
+
 #[test]
 fn test_get_operation_types() {
     let added_op1 = Operation::from(DefinitionBit::new("ro".to_string(), 1, false));
@@ -237,6 +673,90 @@ fn test_get_operation_types() {
     })
 }
 
+/// Test involved_modes and number_of_modes functions of Circuit
+#[test]
+fn test_involved_modes() {
+    pyo3::prepare_freethreaded_python();
+    let operation1 = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+    let operation2 =
+        convert_operation_to_pyobject(Operation::from(Squeezing::new(2, 0.1.into(), 0.0.into())))
+            .unwrap();
+    let operation3 =
+        convert_operation_to_pyobject(Operation::from(QuantumRabi::new(0, 1, 0.1.into()))).unwrap();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        circuit.call_method1("add", (operation1.clone(),)).unwrap();
+        let modes: HashSet<usize> =
+            HashSet::extract_bound(&circuit.call_method0("involved_modes").unwrap()).unwrap();
+        assert!(modes.is_empty());
+        let number_modes =
+            usize::extract_bound(&circuit.call_method0("number_of_modes").unwrap()).unwrap();
+        assert_eq!(number_modes, 0);
+
+        circuit.call_method1("add", (operation2.clone(),)).unwrap();
+        circuit.call_method1("add", (operation3.clone(),)).unwrap();
+        let modes: HashSet<usize> =
+            HashSet::extract_bound(&circuit.call_method0("involved_modes").unwrap()).unwrap();
+        assert_eq!(modes, HashSet::from([1, 2]));
+        let number_modes =
+            usize::extract_bound(&circuit.call_method0("number_of_modes").unwrap()).unwrap();
+        assert_eq!(number_modes, 3);
+    })
+}
+
+/// Test involved_classical function of Circuit
+#[test]
+fn test_involved_classical() {
+    pyo3::prepare_freethreaded_python();
+    let mut inner_circuit = Circuit::new();
+    inner_circuit += MeasureQubit::new(1, "aux".to_string(), 2);
+    let operation1 =
+        convert_operation_to_pyobject(Operation::from(MeasureQubit::new(0, "ro".to_string(), 0)))
+            .unwrap();
+    let operation2 = convert_operation_to_pyobject(Operation::from(PragmaConditional::new(
+        "ro".to_string(),
+        0,
+        inner_circuit,
+    )))
+    .unwrap();
+    let operation3 = convert_operation_to_pyobject(Operation::from(
+        PragmaRepeatedMeasurement::new("rep".to_string(), 10, None),
+    ))
+    .unwrap();
+    let operation4 = convert_operation_to_pyobject(Operation::from(PragmaGetStateVector::new(
+        "state".to_string(),
+        None,
+    )))
+    .unwrap();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let entries: HashSet<(String, usize)> =
+            HashSet::extract_bound(&circuit.call_method0("involved_classical").unwrap()).unwrap();
+        assert!(entries.is_empty());
+
+        circuit.call_method1("add", (operation1.clone(),)).unwrap();
+        circuit.call_method1("add", (operation2.clone(),)).unwrap();
+        let entries: HashSet<(String, usize)> =
+            HashSet::extract_bound(&circuit.call_method0("involved_classical").unwrap()).unwrap();
+        assert_eq!(
+            entries,
+            HashSet::from([("ro".to_string(), 0), ("aux".to_string(), 2)])
+        );
+
+        circuit.call_method1("add", (operation3.clone(),)).unwrap();
+        let involved: (String, String) =
+            <(String, String)>::extract_bound(&circuit.call_method0("involved_classical").unwrap())
+                .unwrap();
+        assert_eq!(involved, ("AllQubits".to_string(), "rep".to_string()));
+
+        circuit.call_method1("add", (operation4.clone(),)).unwrap();
+        let involved: (String, String) =
+            <(String, String)>::extract_bound(&circuit.call_method0("involved_classical").unwrap())
+                .unwrap();
+        assert_eq!(involved, ("All".to_string(), "state".to_string()));
+    })
+}
+
 /// Test copy and deepcopy functions of Circuit
 #[test]
 fn test_copy_deepcopy() {
@@ -352,7 +872,7 @@ fn test_value_error_bincode() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -413,6 +933,68 @@ fn test_to_from_json() {
     })
 }
 
+/// Test from_json function of Circuit in strict mode
+#[test]
+fn test_from_json_strict() {
+    pyo3::prepare_freethreaded_python();
+    let mut inner_circuit = Circuit::new();
+    inner_circuit += RotateZ::new(0, CalculatorFloat::from(0.5));
+    let mut circuit = Circuit::new();
+    circuit += PragmaLoop::new(CalculatorFloat::from(2), inner_circuit);
+    let json = serde_json::to_string(&circuit).unwrap();
+    let misspelled = json.replacen('{', r#"{"operationss":[],"#, 1);
+    let nested = json.replace(r#""theta":0.5"#, r#""theta":0.5,"thetaa":0.5"#);
+    Python::with_gil(|py| {
+        let circuit_type = py.get_type_bound::<CircuitWrapper>();
+        let deserialised = circuit_type
+            .call_method1("from_json", (&json, true))
+            .unwrap();
+        let deserialised = deserialised.downcast::<CircuitWrapper>().unwrap();
+        assert_eq!(deserialised.borrow().internal, circuit);
+
+        // Unknown fields are ignored by default
+        assert!(circuit_type
+            .call_method1("from_json", (&misspelled,))
+            .is_ok());
+        let error = circuit_type
+            .call_method1("from_json", (&misspelled, true))
+            .unwrap_err();
+        assert!(error.to_string().contains("$.operationss"));
+        let error = circuit_type
+            .call_method1("from_json", (&nested, true))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("$.operations[0].PragmaLoop.circuit.operations[0].RotateZ.thetaa"));
+    })
+}
+
+/// Test min_supported_version and current_version functions of Circuit
+#[test_case(vec![], "1.0.0"; "empty")]
+#[test_case(vec![Operation::from(PauliX::new(0))], "1.0.0"; "PauliX")]
+#[test_case(vec![
+    Operation::from(PauliX::new(0)),
+    Operation::from(TripleControlledPauliX::new(0, 1, 2, 3)),
+], "1.16.0"; "TripleControlledPauliX")]
+fn test_min_supported_version(operations: Vec<Operation>, version: &str) {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in operations {
+            let operation = convert_operation_to_pyobject(operation).unwrap();
+            circuit.call_method1("add", (operation,)).unwrap();
+        }
+
+        let current_version_string =
+            String::extract_bound(&circuit.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&circuit.call_method0("min_supported_version").unwrap()).unwrap();
+
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, version);
+    });
+}
+
 /// Test json_schema function of Circuit
 #[cfg(feature = "json_schema")]
 #[test]
@@ -632,6 +1214,89 @@ fn test_filter_by_tag() {
     })
 }
 
+/// Test tags, hqslang and the category predicates of the operations of a mixed circuit
+#[test]
+fn test_operation_category_predicates() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += RotateX::new(0, "theta".into());
+    circuit += ControlledSWAP::new(0, 1, 2);
+    circuit += PragmaAnnotatedOp::new(PauliX::new(1).into(), "annotation".to_string());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    // (hqslang, is_gate_operation, is_pragma, is_measurement, is_parametrized)
+    let expected = [
+        ("DefinitionBit", false, false, false, false),
+        ("RotateX", true, false, false, true),
+        ("ControlledSWAP", true, false, false, false),
+        ("PragmaAnnotatedOp", false, true, false, false),
+        ("PragmaRepeatedMeasurement", false, true, true, false),
+        ("MeasureQubit", false, false, true, false),
+    ];
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = Py::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let circuit = circuit.bind(py);
+        for (index, (hqslang, gate, pragma, measurement, parametrized)) in
+            expected.iter().enumerate()
+        {
+            let operation = circuit.call_method1("__getitem__", (index,)).unwrap();
+            let call = |method: &str| -> bool {
+                operation
+                    .call_method0(method)
+                    .unwrap()
+                    .extract::<bool>()
+                    .unwrap()
+            };
+            assert_eq!(
+                operation
+                    .call_method0("hqslang")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap(),
+                *hqslang
+            );
+            let tags = operation
+                .call_method0("tags")
+                .unwrap()
+                .extract::<Vec<String>>()
+                .unwrap();
+            assert!(tags.contains(&hqslang.to_string()));
+            assert_eq!(call("is_gate_operation"), *gate, "{}", hqslang);
+            assert_eq!(call("is_pragma"), *pragma, "{}", hqslang);
+            assert_eq!(call("is_measurement"), *measurement, "{}", hqslang);
+            assert_eq!(call("is_parametrized"), *parametrized, "{}", hqslang);
+        }
+
+        let filter = |tag: &str| -> Vec<String> {
+            circuit
+                .call_method1("filter_by_tag", (tag,))
+                .unwrap()
+                .extract::<Vec<Bound<PyAny>>>()
+                .unwrap()
+                .iter()
+                .map(|operation| {
+                    operation
+                        .call_method0("hqslang")
+                        .unwrap()
+                        .extract::<String>()
+                        .unwrap()
+                })
+                .collect()
+        };
+        assert_eq!(filter("GateOperation"), vec!["RotateX", "ControlledSWAP"]);
+        assert_eq!(
+            filter("PragmaOperation"),
+            vec!["PragmaAnnotatedOp", "PragmaRepeatedMeasurement"]
+        );
+        assert_eq!(
+            filter("Measurement"),
+            vec!["PragmaRepeatedMeasurement", "MeasureQubit"]
+        );
+        assert!(filter("NotATag").is_empty());
+    })
+}
+
 /// Test add function
 #[test_case(Operation::from(RotateX::new(0, CalculatorFloat::from(0))); "RotateX float")]
 #[test_case(Operation::from(RotateZ::new(1, CalculatorFloat::from(1.3))); "RotateZ float")]
@@ -973,3 +1638,33 @@ fn test_circuit_overrotate() {
         assert!(comparison);
     })
 }
+
+/// Test total_global_phase and consolidate_global_phase
+#[test]
+fn test_global_phase_consolidation() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += PragmaGlobalPhase::new(0.5.into());
+    circuit += PauliX::new(0);
+    circuit += PragmaGlobalPhase::new("theta".into());
+    circuit += PragmaGlobalPhase::new(0.25.into());
+    let total = circuit.total_global_phase();
+    let consolidated = circuit.consolidate_global_phase();
+    Python::with_gil(|py| {
+        let circuit = Bound::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let phase = circuit
+            .call_method0("total_global_phase")
+            .unwrap()
+            .extract::<qoqo_calculator_pyo3::CalculatorFloatWrapper>()
+            .unwrap();
+        assert_eq!(phase.internal, total);
+
+        let result = circuit
+            .call_method0("consolidate_global_phase")
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(result.internal, consolidated);
+        assert_eq!(result.internal.count_occurences(&["PragmaGlobalPhase"]), 1);
+    })
+}
@@ -10,13 +10,18 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
+use qoqo::devices::AllToAllDeviceWrapper;
 use qoqo::operations::convert_operation_to_pyobject;
 use qoqo::{CircuitDagWrapper, CircuitWrapper, QOQO_VERSION};
 
+use roqoqo::devices::AllToAllDevice;
 use roqoqo::operations::*;
-use roqoqo::ROQOQO_VERSION;
+use roqoqo::{Circuit, CircuitDag, ROQOQO_VERSION};
 
 // Helper functions
 fn new_circuitdag(py: Python) -> Bound<CircuitDagWrapper> {
@@ -681,6 +686,109 @@ fn test_getters_operations_involving_classical() {
     })
 }
 
+/// Test qubit_lifetimes and max_concurrent_qubits on a circuit where qubit 0 is measured early and qubit 3 only appears late
+#[test]
+fn test_qubit_lifetimes() {
+    pyo3::prepare_freethreaded_python();
+    let operations = vec![
+        Operation::from(Hadamard::new(0)),
+        Operation::from(CNOT::new(0, 1)),
+        Operation::from(MeasureQubit::new(0, "ro".to_string(), 0)),
+        Operation::from(CNOT::new(1, 2)),
+        Operation::from(CNOT::new(2, 3)),
+    ];
+    Python::with_gil(|py| {
+        let dag = new_circuitdag(py);
+        for operation in operations {
+            dag.call_method1(
+                "add_to_back",
+                (convert_operation_to_pyobject(operation).unwrap(),),
+            )
+            .unwrap();
+        }
+
+        let lifetimes: HashMap<usize, (usize, usize)> = dag
+            .call_method0("qubit_lifetimes")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let expected: HashMap<usize, (usize, usize)> =
+            [(0, (0, 2)), (1, (1, 3)), (2, (3, 4)), (3, (4, 4))]
+                .into_iter()
+                .collect();
+        assert_eq!(lifetimes, expected);
+
+        let max_concurrent: usize = dag
+            .call_method0("max_concurrent_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(max_concurrent, 2);
+    })
+}
+
+/// Test repetitions, depth and execution_time of a CircuitDag containing a loop
+#[test]
+fn test_loop_repetitions() {
+    pyo3::prepare_freethreaded_python();
+    let mut inner = Circuit::new();
+    inner += Hadamard::new(0);
+    inner += CNOT::new(0, 1);
+    let mut circuit = Circuit::new();
+    circuit += PragmaLoop::new(3.0.into(), inner.clone());
+    circuit += Hadamard::new(1);
+    let mut symbolic_circuit = Circuit::new();
+    symbolic_circuit += PragmaLoop::new("n".into(), inner);
+    let device = AllToAllDevice::new(2, &["Hadamard".to_string()], &["CNOT".to_string()], 1.0)
+        .with_two_qubit_gate_time("CNOT", 2.0);
+    Python::with_gil(|py| {
+        let device = Bound::new(
+            py,
+            AllToAllDeviceWrapper {
+                internal: device.clone(),
+            },
+        )
+        .unwrap();
+        let dag = Bound::new(
+            py,
+            CircuitDagWrapper {
+                internal: CircuitDag::from(circuit),
+            },
+        )
+        .unwrap();
+        let repetitions: usize = dag
+            .call_method1("repetitions", (0,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(repetitions, 3);
+        let depth: usize = dag.call_method0("depth").unwrap().extract().unwrap();
+        assert_eq!(depth, 7);
+        let execution_time: f64 = dag
+            .call_method1("execution_time", (device.clone(),))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(execution_time, 10.0);
+        let error = dag.call_method1("execution_time", (0,));
+        assert!(error.unwrap_err().is_instance_of::<PyTypeError>(py));
+
+        let dag = Bound::new(
+            py,
+            CircuitDagWrapper {
+                internal: CircuitDag::from(symbolic_circuit),
+            },
+        )
+        .unwrap();
+        let error = dag.call_method1("repetitions", (0,));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+        let error = dag.call_method0("depth");
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+        let error = dag.call_method1("execution_time", (device,));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
+
 #[test]
 fn test_convert_into_circuitdag() {
     pyo3::prepare_freethreaded_python();
@@ -693,3 +801,119 @@ fn test_convert_into_circuitdag() {
         assert!(comparison.is_err());
     })
 }
+
+/// Test building a CircuitDag from scratch and comparing to one created from a Circuit
+#[test]
+fn test_build_from_scratch() {
+    pyo3::prepare_freethreaded_python();
+    let paulix_0 = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+    let pauliy_1 = convert_operation_to_pyobject(Operation::from(PauliY::new(1))).unwrap();
+    let cnot_01 = convert_operation_to_pyobject(Operation::from(CNOT::new(0, 1))).unwrap();
+    let hadamard_0 = convert_operation_to_pyobject(Operation::from(Hadamard::new(0))).unwrap();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        circuit.call_method1("add", (hadamard_0.clone(),)).unwrap();
+        circuit.call_method1("add", (paulix_0.clone(),)).unwrap();
+        circuit.call_method1("add", (pauliy_1.clone(),)).unwrap();
+        circuit.call_method1("add", (cnot_01.clone(),)).unwrap();
+
+        let dag_type = py.get_type_bound::<CircuitDagWrapper>();
+        let dag_from_circuit = dag_type.call_method1("from_circuit", (&circuit,)).unwrap();
+
+        let dag = new_circuitdag(py);
+        let indices = dag
+            .call_method1(
+                "extend_back",
+                (vec![paulix_0.clone(), pauliy_1.clone(), cnot_01.clone()],),
+            )
+            .unwrap()
+            .extract::<Vec<Option<usize>>>()
+            .unwrap();
+        assert_eq!(indices, vec![Some(0), Some(1), Some(2)]);
+        assert!(!dag.eq(&dag_from_circuit).unwrap());
+        let index = dag
+            .call_method1("add_to_front", (hadamard_0.clone(),))
+            .unwrap()
+            .extract::<Option<usize>>()
+            .unwrap();
+        assert_eq!(index, Some(3));
+        assert!(dag.eq(&dag_from_circuit).unwrap());
+        let round_trip = dag_type
+            .call_method1("from_circuit", (dag.call_method0("to_circuit").unwrap(),))
+            .unwrap();
+        assert!(round_trip.eq(&dag_from_circuit).unwrap());
+    })
+}
+
+/// Test that adding objects that are not operations raises a TypeError naming the type
+#[test]
+fn test_add_errors() {
+    pyo3::prepare_freethreaded_python();
+    let paulix_0 = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+    Python::with_gil(|py| {
+        let dag = new_circuitdag(py);
+        for method in ["add_to_back", "add_to_front"] {
+            let error = dag.call_method1(method, ("PauliX",)).unwrap_err();
+            assert!(error.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+            assert!(error.to_string().contains("of type str"));
+        }
+
+        let error = dag
+            .call_method1("extend_back", (vec![paulix_0.clone(), 1_i64.into_py(py)],))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert!(error.to_string().contains("of type int"));
+        // No operation is added when one of them cannot be converted
+        assert!(dag.eq(new_circuitdag(py)).unwrap());
+
+        let dag_type = py.get_type_bound::<CircuitDagWrapper>();
+        let error = dag_type
+            .call_method1("from_circuit", (paulix_0,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert!(error.to_string().contains("to Circuit"));
+    })
+}
+
+/// Test scheduling a CircuitDag greedily with ready_after and predecessors
+#[test]
+fn test_ready_after_predecessors() {
+    pyo3::prepare_freethreaded_python();
+    let operations: Vec<Operation> = vec![
+        Hadamard::new(0).into(),
+        Hadamard::new(1).into(),
+        CNOT::new(0, 1).into(),
+        PauliX::new(2).into(),
+        CNOT::new(1, 2).into(),
+        PauliZ::new(0).into(),
+    ];
+    let operations: Vec<Py<PyAny>> = operations
+        .into_iter()
+        .map(|operation| convert_operation_to_pyobject(operation).unwrap())
+        .collect();
+    Python::with_gil(|py| {
+        let dag = new_circuitdag(py);
+        dag.call_method1("extend_back", (operations,)).unwrap();
+
+        let predecessors = dag
+            .call_method1("predecessors", (4,))
+            .unwrap()
+            .extract::<Vec<usize>>()
+            .unwrap();
+        assert_eq!(predecessors, vec![2, 3]);
+
+        let mut executed: Vec<usize> = Vec::new();
+        loop {
+            let ready = dag
+                .call_method1("ready_after", (executed.clone(),))
+                .unwrap()
+                .extract::<Vec<usize>>()
+                .unwrap();
+            if ready.is_empty() {
+                break;
+            }
+            executed.push(ready[0]);
+        }
+        assert_eq!(executed, vec![0, 1, 2, 3, 4, 5]);
+    })
+}
@@ -0,0 +1,219 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the conversions of objects from separately compiled python packages
+
+use pyo3::prelude::*;
+use qoqo::conversions::{
+    convert_into_circuit, convert_into_operation, convert_into_quantum_program,
+};
+use qoqo::measurements::ClassicalRegisterWrapper;
+use qoqo::{CircuitWrapper, QoqoError, QuantumProgramWrapper};
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations::*;
+use roqoqo::{Circuit, QuantumProgram, ROQOQO_VERSION};
+
+/// Python classes imitating qoqo objects created by a separately compiled python package
+const FOREIGN_OBJECTS: &str = r#"
+class ForeignObject:
+    def __init__(self, data, roqoqo_version):
+        self.data = data
+        self.roqoqo_version = roqoqo_version
+
+    def to_bincode(self):
+        return bytearray(self.data)
+
+    def _qoqo_versions(self):
+        return (self.roqoqo_version, self.roqoqo_version)
+
+class ForeignMeasurement:
+    def __init__(self, data):
+        self.data = data
+
+    def _internal_to_bincode(self):
+        return ("ClassicalRegister", bytearray(self.data))
+
+class ForeignRotateX:
+    def __init__(self, hqslang, min_version):
+        self._hqslang = hqslang
+        self.min_version = min_version
+
+    def hqslang(self):
+        return self._hqslang
+
+    def qubit(self):
+        return 1
+
+    def theta(self):
+        return 0.5
+
+    def min_supported_version(self):
+        return self.min_version
+"#;
+
+fn foreign_objects(py: Python) -> Bound<PyModule> {
+    PyModule::from_code_bound(py, FOREIGN_OBJECTS, "foreign_objects.py", "foreign_objects").unwrap()
+}
+
+/// Returns the major and minor roqoqo version of this package
+fn own_version() -> String {
+    ROQOQO_VERSION
+        .split('.')
+        .take(2)
+        .collect::<Vec<&str>>()
+        .join(".")
+}
+
+fn test_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += RotateX::new(0, 0.5.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit
+}
+
+/// Test conversion of Circuits transferred via their bincode representation
+#[test]
+fn test_convert_into_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = test_circuit();
+        let own = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(convert_into_circuit(own.as_any()), Ok(circuit.clone()));
+
+        let module = foreign_objects(py);
+        let foreign_type = module.getattr("ForeignObject").unwrap();
+        let bytes = bincode::serialize(&circuit).unwrap();
+        let foreign = foreign_type.call1((bytes, own_version())).unwrap();
+        assert_eq!(convert_into_circuit(&foreign), Ok(circuit.clone()));
+
+        // Data that can be transferred is accepted from other versions as well
+        let bytes = bincode::serialize(&circuit).unwrap();
+        let foreign = foreign_type.call1((bytes, "1.0")).unwrap();
+        assert_eq!(convert_into_circuit(&foreign), Ok(circuit));
+
+        // Invalid data from the same version cannot be extracted
+        let foreign = foreign_type
+            .call1((vec![1_u8, 2, 3], own_version()))
+            .unwrap();
+        assert_eq!(
+            convert_into_circuit(&foreign),
+            Err(QoqoError::CannotExtractObject)
+        );
+
+        // Invalid data from a different version is a version mismatch
+        let foreign = foreign_type.call1((vec![1_u8, 2, 3], "1.99")).unwrap();
+        assert_eq!(
+            convert_into_circuit(&foreign),
+            Err(QoqoError::VersionMismatch)
+        );
+
+        let not_circuit = 0_usize.into_py(py).into_bound(py);
+        assert_eq!(
+            convert_into_circuit(&not_circuit),
+            Err(QoqoError::CannotExtractObject)
+        );
+    })
+}
+
+/// Test conversion of QuantumPrograms transferred via their bincode representation
+#[test]
+fn test_convert_into_quantum_program() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let measurement = ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![test_circuit()],
+        };
+        let program = QuantumProgram::ClassicalRegister {
+            measurement: measurement.clone(),
+            input_parameter_names: vec![],
+        };
+        let own = Bound::new(
+            py,
+            QuantumProgramWrapper {
+                internal: program.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            convert_into_quantum_program(own.as_any()),
+            Ok(program.clone())
+        );
+
+        let module = foreign_objects(py);
+        let foreign_type = module.getattr("ForeignObject").unwrap();
+        let bytes = bincode::serialize(&program).unwrap();
+        let foreign = foreign_type.call1((bytes, own_version())).unwrap();
+        assert_eq!(convert_into_quantum_program(&foreign), Ok(program));
+
+        let foreign = foreign_type.call1((vec![7_u8], "1.99")).unwrap();
+        assert_eq!(
+            convert_into_quantum_program(&foreign),
+            Err(QoqoError::VersionMismatch)
+        );
+
+        // A measurement is transferred but is not a QuantumProgram
+        let measurement_type = module.getattr("ForeignMeasurement").unwrap();
+        let bytes = bincode::serialize(&measurement).unwrap();
+        let foreign = measurement_type.call1((bytes,)).unwrap();
+        assert_eq!(
+            convert_into_quantum_program(&foreign),
+            Err(QoqoError::CannotExtractObject)
+        );
+        let own_measurement = Bound::new(
+            py,
+            ClassicalRegisterWrapper {
+                internal: measurement,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            convert_into_quantum_program(own_measurement.as_any()),
+            Err(QoqoError::CannotExtractObject)
+        );
+    })
+}
+
+/// Test conversion of Operations rebuilt from their fields
+#[test]
+fn test_convert_into_operation() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = foreign_objects(py);
+        let foreign_type = module.getattr("ForeignRotateX").unwrap();
+
+        let foreign = foreign_type.call1(("RotateX", "1.0.0")).unwrap();
+        assert_eq!(
+            convert_into_operation(&foreign),
+            Ok(Operation::from(RotateX::new(1, 0.5.into())))
+        );
+
+        let foreign = foreign_type.call1(("FutureGate", "1.0.0")).unwrap();
+        assert_eq!(
+            convert_into_operation(&foreign),
+            Err(QoqoError::ConversionError)
+        );
+
+        let foreign = foreign_type.call1(("FutureGate", "1.99.0")).unwrap();
+        assert_eq!(
+            convert_into_operation(&foreign),
+            Err(QoqoError::VersionMismatch)
+        );
+    })
+}
@@ -11,12 +11,23 @@
 // limitations under the License.
 
 use ndarray::{array, Array2};
-use numpy::{pyarray_bound, PyArray2};
+use numpy::{pyarray_bound, PyArray1, PyArray2, PyArray3, PyArrayMethods};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use qoqo::devices::{AllToAllDeviceWrapper, GenericDeviceWrapper, SquareLatticeDeviceWrapper};
-use roqoqo::devices::{AllToAllDevice, GenericDevice, SquareLatticeDevice};
+use qoqo::devices::{
+    add_active_gate_noise, gate_time, AllToAllDeviceWrapper, DeviceCapsule, GenericDeviceWrapper,
+    SquareLatticeDeviceWrapper,
+};
+use qoqo::operations::convert_operation_to_pyobject;
+use qoqo::CircuitWrapper;
+use roqoqo::devices::{AllToAllDevice, Device, GenericDevice, SquareLatticeDevice};
+use roqoqo::operations::{
+    DefinitionBit, MultiQubitMS, Operation, PauliX, PragmaDamping, PragmaSleep, RotateX, Toffoli,
+    CNOT,
+};
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
+use roqoqo::{Circuit, RoqoqoError};
 use test_case::test_case;
 
 fn new_alltoalldevice() -> Py<PyAny> {
@@ -474,6 +485,86 @@ fn test_decoherence_rates(device: Py<PyAny>) {
 }
 
 // Test gate_times for AllToAllDevice
+/// Test the gate time matrices and decoherence rates of all qubits against the individual queries
+#[test_case(new_alltoalldevice(); "all_to_all")]
+#[test_case(new_genericdevice(); "generic")]
+#[test_case(new_genericlattice(); "lattice")]
+fn test_gate_time_matrices(device: Py<PyAny>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        device
+            .call_method1(py, "set_single_qubit_gate_time", ("RotateX", 0, 0.5))
+            .unwrap();
+        device
+            .call_method1(py, "set_two_qubit_gate_time", ("CNOT", 0, 1, 0.7))
+            .unwrap();
+        device.call_method1(py, "add_damping", (1, 0.1)).unwrap();
+
+        let single_qubit_matrix = device
+            .call_method1(py, "single_qubit_gate_time_matrix", ("RotateX",))
+            .unwrap()
+            .downcast_bound::<PyArray1<f64>>(py)
+            .unwrap()
+            .readonly()
+            .as_array()
+            .to_owned();
+        let two_qubit_matrix = device
+            .call_method1(py, "two_qubit_gate_time_matrix", ("CNOT",))
+            .unwrap()
+            .downcast_bound::<PyArray2<f64>>(py)
+            .unwrap()
+            .readonly()
+            .as_array()
+            .to_owned();
+        let rates = device
+            .call_method0(py, "qubit_decoherence_rates_all")
+            .unwrap()
+            .downcast_bound::<PyArray3<f64>>(py)
+            .unwrap()
+            .readonly()
+            .as_array()
+            .to_owned();
+        assert_eq!(single_qubit_matrix.len(), 4);
+        assert_eq!(two_qubit_matrix.shape(), [4, 4]);
+        assert_eq!(rates.shape(), [4, 3, 3]);
+        assert_eq!(single_qubit_matrix[0], 0.5);
+        assert_eq!(two_qubit_matrix[(0, 1)], 0.7);
+        assert_eq!(rates[(1, 0, 0)], 0.1);
+
+        for qubit in 0..4_usize {
+            let time: Option<f64> = device
+                .call_method1(py, "single_qubit_gate_time", ("RotateX", qubit))
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            match time {
+                Some(time) => assert_eq!(single_qubit_matrix[qubit], time),
+                None => assert!(single_qubit_matrix[qubit].is_nan()),
+            }
+            for target in 0..4_usize {
+                let time: Option<f64> = device
+                    .call_method1(py, "two_qubit_gate_time", ("CNOT", qubit, target))
+                    .unwrap()
+                    .extract(py)
+                    .unwrap();
+                match time {
+                    Some(time) => assert_eq!(two_qubit_matrix[(qubit, target)], time),
+                    None => assert!(two_qubit_matrix[(qubit, target)].is_nan()),
+                }
+            }
+            let qubit_rates = device
+                .call_method1(py, "qubit_decoherence_rates", (qubit,))
+                .unwrap()
+                .downcast_bound::<PyArray2<f64>>(py)
+                .unwrap()
+                .readonly()
+                .as_array()
+                .to_owned();
+            assert_eq!(rates.slice(ndarray::s![qubit, .., ..]), qubit_rates);
+        }
+    })
+}
+
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericdevice(); "generic")]
 #[test_case(new_genericlattice(); "lattice")]
@@ -578,6 +669,67 @@ fn test_gatetimes(device: Py<PyAny>) {
     })
 }
 
+// Test gate errors for all devices
+#[test_case(new_alltoalldevice(); "all_to_all")]
+#[test_case(new_genericdevice(); "generic")]
+#[test_case(new_genericlattice(); "lattice")]
+fn test_gate_errors(device: Py<PyAny>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let error_none = device
+            .call_method1(py, "single_qubit_gate_error", ("RotateZ", 0_i64))
+            .unwrap()
+            .extract::<Option<f64>>(py)
+            .unwrap();
+        assert_eq!(error_none, None);
+
+        device
+            .call_method1(py, "set_single_qubit_gate_error", ("RotateZ", 0, 0.01))
+            .unwrap();
+        let error_rotatez = device
+            .call_method1(py, "single_qubit_gate_error", ("RotateZ", 0_i64))
+            .unwrap()
+            .extract::<Option<f64>>(py)
+            .unwrap();
+        assert_eq!(error_rotatez, Some(0.01));
+
+        device
+            .call_method1(py, "set_two_qubit_gate_error", ("CNOT", 0, 1, 0.02))
+            .unwrap();
+        let error_cnot = device
+            .call_method1(py, "two_qubit_gate_error", ("CNOT", 0_i64, 1_i64))
+            .unwrap()
+            .extract::<Option<f64>>(py)
+            .unwrap();
+        assert_eq!(error_cnot, Some(0.02));
+        let error_none2 = device
+            .call_method1(py, "two_qubit_gate_error", ("CNOT", 1_i64, 0_i64))
+            .unwrap()
+            .extract::<Option<f64>>(py)
+            .unwrap();
+        assert_eq!(error_none2, None);
+
+        assert!(device
+            .call_method1(py, "set_single_qubit_gate_error", ("RotateZ", 0, 2.0))
+            .is_err());
+        assert!(device
+            .call_method1(py, "set_two_qubit_gate_error", ("CNOT", 0, 100, 0.02))
+            .is_err());
+
+        // Gate errors are only contained in the json serialization
+        let error = device.call_method0(py, "to_bincode").unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+        let serialised = device.call_method0(py, "to_json").unwrap();
+        let deserialised = device.call_method1(py, "from_json", (serialised,)).unwrap();
+        let error_cnot = deserialised
+            .call_method1(py, "two_qubit_gate_error", ("CNOT", 0_i64, 1_i64))
+            .unwrap()
+            .extract::<Option<f64>>(py)
+            .unwrap();
+        assert_eq!(error_cnot, Some(0.02));
+    })
+}
+
 // Test gate_times for AllToAllDevice
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericlattice(); "lattice")]
@@ -666,6 +818,72 @@ fn test_derive_square_lattice() {
     assert!(wrapper == wrapper);
 }
 
+#[test]
+fn test_square_lattice_coordinates() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let single_qubit_gates = ["RotateX".to_string()];
+        let two_qubit_gates = ["CNOT".to_string()];
+        let device = py
+            .get_type_bound::<SquareLatticeDeviceWrapper>()
+            .call1((3, 4, single_qubit_gates, two_qubit_gates, 1.0))
+            .unwrap();
+
+        let index = device
+            .call_method1("qubit_index", (1, 2))
+            .unwrap()
+            .extract::<Option<usize>>()
+            .unwrap();
+        assert_eq!(index, Some(6));
+        let index = device
+            .call_method1("qubit_index", (3, 0))
+            .unwrap()
+            .extract::<Option<usize>>()
+            .unwrap();
+        assert_eq!(index, None);
+
+        let position = device
+            .call_method1("qubit_position", (11,))
+            .unwrap()
+            .extract::<Option<(usize, usize)>>()
+            .unwrap();
+        assert_eq!(position, Some((2, 3)));
+        let position = device
+            .call_method1("qubit_position", (12,))
+            .unwrap()
+            .extract::<Option<(usize, usize)>>()
+            .unwrap();
+        assert_eq!(position, None);
+
+        let row = device
+            .call_method1("row", (1,))
+            .unwrap()
+            .extract::<Vec<usize>>()
+            .unwrap();
+        assert_eq!(row, vec![4, 5, 6, 7]);
+        let column = device
+            .call_method1("column", (3,))
+            .unwrap()
+            .extract::<Vec<usize>>()
+            .unwrap();
+        assert_eq!(column, vec![3, 7, 11]);
+
+        for (qubit, neighbours) in [
+            (0, vec![1, 4]),
+            (11, vec![7, 10]),
+            (4, vec![0, 5, 8]),
+            (5, vec![1, 4, 6, 9]),
+        ] {
+            let test_neighbours = device
+                .call_method1("neighbours", (qubit,))
+                .unwrap()
+                .extract::<Vec<usize>>()
+                .unwrap();
+            assert_eq!(test_neighbours, neighbours);
+        }
+    })
+}
+
 #[test_case(new_alltoalldevice(), vec![(0,1), (0,2), (0,3), (1,2), (1,3), (2,3)]; "all_to_all")]
 #[test_case(new_genericdevice(), vec![]; "generic")]
 #[test_case(new_genericlattice(), vec![(0,1), (2,3) ,(0,2), (1,3)]; "lattice")]
@@ -684,6 +902,131 @@ fn test_edges(device: Py<PyAny>, test_edges: Vec<(usize, usize)>) {
     })
 }
 
+/// Python implementation of the device protocol with a linear chain of three qubits
+const PYTHON_DEVICE: &str = r#"
+class PythonDevice:
+    def number_qubits(self):
+        return 3
+
+    def single_qubit_gate_names(self):
+        return ["RotateZ"]
+
+    def two_qubit_gate_names(self):
+        return ["CNOT"]
+
+    def single_qubit_gate_time(self, hqslang, qubit):
+        if hqslang == "RotateZ" and qubit < 3:
+            return 0.5
+        return None
+
+    def two_qubit_gate_time(self, hqslang, control, target):
+        if hqslang == "CNOT" and abs(control - target) == 1 and max(control, target) < 3:
+            return 1.0
+        return None
+
+    def three_qubit_gate_time(self, hqslang, control_0, control_1, target):
+        return None
+
+    def multi_qubit_gate_time(self, hqslang, qubits):
+        if hqslang == "MultiQubitMS" and qubits == [0, 1, 2]:
+            return 2.0
+        return None
+
+    def two_qubit_edges(self):
+        return [(0, 1), (1, 2)]
+
+    def qubit_decoherence_rates(self, qubit):
+        if qubit < 3:
+            return [[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]
+        return None
+
+class IncompleteDevice:
+    def number_qubits(self):
+        return 3
+"#;
+
+fn new_python_device(class_name: &str) -> Py<PyAny> {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| -> Py<PyAny> {
+        let module =
+            PyModule::from_code_bound(py, PYTHON_DEVICE, "python_device.py", "python_device")
+                .unwrap();
+        module.getattr(class_name).unwrap().call0().unwrap().into()
+    })
+}
+
+#[test]
+fn test_device_capsule() {
+    let device = new_python_device("PythonDevice");
+    let capsule = Python::with_gil(|py| DeviceCapsule::new(device.bind(py)).unwrap());
+
+    assert_eq!(capsule.number_qubits(), 3);
+    assert_eq!(capsule.single_qubit_gate_time("RotateZ", &1), Some(0.5));
+    assert_eq!(capsule.single_qubit_gate_time("RotateX", &1), None);
+    assert_eq!(capsule.two_qubit_gate_time("CNOT", &1, &0), Some(1.0));
+    assert_eq!(capsule.two_qubit_gate_time("CNOT", &0, &2), None);
+    assert_eq!(
+        capsule.three_qubit_gate_time("ControlledControlledPauliZ", &0, &1, &2),
+        None
+    );
+    assert_eq!(
+        capsule.multi_qubit_gate_time("MultiQubitMS", &[0, 1, 2]),
+        Some(2.0)
+    );
+    assert_eq!(capsule.two_qubit_edges(), vec![(0, 1), (1, 2)]);
+    assert_eq!(
+        capsule.qubit_decoherence_rates(&0),
+        Some(array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+    assert_eq!(capsule.qubit_decoherence_rates(&3), None);
+    assert_eq!(
+        capsule.single_qubit_gate_names(),
+        vec!["RotateZ".to_string()]
+    );
+    assert_eq!(capsule.two_qubit_gate_names(), vec!["CNOT".to_string()]);
+    assert_eq!(capsule.multi_qubit_gate_names(), Vec::<String>::new());
+
+    let generic_device = capsule.to_generic_device();
+    assert_eq!(generic_device.number_qubits(), 3);
+    assert_eq!(
+        generic_device.single_qubit_gate_time("RotateZ", &2),
+        Some(0.5)
+    );
+    assert_eq!(
+        generic_device.two_qubit_gate_time("CNOT", &2, &1),
+        Some(1.0)
+    );
+    assert_eq!(generic_device.two_qubit_gate_time("CNOT", &0, &2), None);
+    assert_eq!(
+        generic_device.qubit_decoherence_rates(&1),
+        Some(array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+}
+
+#[test]
+fn test_device_capsule_qoqo_device() {
+    let device = new_alltoalldevice();
+    let capsule = Python::with_gil(|py| DeviceCapsule::new(device.bind(py)).unwrap());
+
+    assert_eq!(capsule.number_qubits(), 4);
+    assert_eq!(capsule.single_qubit_gate_time("RotateX", &3), Some(1.0));
+    assert_eq!(capsule.two_qubit_gate_time("CNOT", &0, &3), Some(1.0));
+    assert_eq!(capsule.two_qubit_edges().len(), 6);
+    assert_eq!(capsule.two_qubit_gate_names(), vec!["CNOT".to_string()]);
+}
+
+#[test]
+fn test_device_capsule_missing_method() {
+    let device = new_python_device("IncompleteDevice");
+    let capsule = Python::with_gil(|py| DeviceCapsule::new(device.bind(py)));
+    assert_eq!(
+        capsule.unwrap_err(),
+        RoqoqoError::GenericError {
+            msg: "Python device does not implement `single_qubit_gate_time` method of the device protocol.".to_string()
+        }
+    );
+}
+
 #[cfg(feature = "unstable_chain_with_environment")]
 mod test_chain_with_environment {
     use std::collections::HashMap;
@@ -778,6 +1121,25 @@ mod test_chain_with_environment {
             Ok(())
         }
 
+        pub fn set_single_qubit_gate_error(
+            &mut self,
+            _gate: &str,
+            _qubit: usize,
+            _error: f64,
+        ) -> Result<(), RoqoqoError> {
+            Ok(())
+        }
+
+        pub fn set_two_qubit_gate_error(
+            &mut self,
+            _gate: &str,
+            _control: usize,
+            _target: usize,
+            _error: f64,
+        ) -> Result<(), RoqoqoError> {
+            Ok(())
+        }
+
         pub fn set_multi_qubit_gate_time(
             &mut self,
             _gate: &str,
@@ -912,3 +1274,276 @@ mod test_chain_with_environment {
         assert_eq!(chains_with_environment, comparison);
     }
 }
+
+// Test that chained builder methods equal the equivalent imperative setup
+#[test_case(new_alltoalldevice(); "all_to_all")]
+#[test_case(new_genericlattice(); "lattice")]
+fn test_builder_methods(device: Py<PyAny>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = device.bind(py);
+        let chained = device
+            .call_method1("with_single_qubit_gate_time", ("PauliX", 0.5))
+            .unwrap()
+            .call_method1("with_two_qubit_gate_time", ("ISwap", 2.0))
+            .unwrap()
+            .call_method1("with_damping_rates", (0.001,))
+            .unwrap()
+            .call_method1("with_dephasing_rates", (0.002,))
+            .unwrap()
+            .call_method1("with_depolarising_rates", (0.003,))
+            .unwrap();
+
+        let imperative = device
+            .call_method1("set_all_single_qubit_gate_times", ("PauliX", 0.5))
+            .unwrap()
+            .call_method1("set_all_two_qubit_gate_times", ("ISwap", 2.0))
+            .unwrap()
+            .call_method1("add_damping_all", (0.001,))
+            .unwrap()
+            .call_method1("add_dephasing_all", (0.002,))
+            .unwrap()
+            .call_method1("add_depolarising_all", (0.003,))
+            .unwrap();
+        assert!(chained.eq(&imperative).unwrap());
+
+        let reduced = chained
+            .call_method1("without_single_qubit_gate", ("RotateX",))
+            .unwrap()
+            .call_method1("without_two_qubit_gate", ("CNOT",))
+            .unwrap();
+        let single_qubit_gates = reduced
+            .call_method0("single_qubit_gate_names")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert!(single_qubit_gates.contains(&"PauliX".to_string()));
+        assert!(!single_qubit_gates.contains(&"RotateX".to_string()));
+        let two_qubit_gates = reduced
+            .call_method0("two_qubit_gate_names")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert_eq!(two_qubit_gates, vec!["ISwap".to_string()]);
+
+        // The original device is left unchanged
+        let cnot_time = device
+            .call_method1("two_qubit_gate_time", ("CNOT", 0_i64, 1_i64))
+            .unwrap()
+            .extract::<Option<f64>>()
+            .unwrap();
+        assert_eq!(cnot_time, Some(1.0));
+    })
+}
+
+/// Test gate_time function for operations of different arities, PRAGMAs and definitions
+#[test]
+fn test_gate_time() {
+    pyo3::prepare_freethreaded_python();
+    let mut device = GenericDevice::new(4);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 0.5)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 1.0).unwrap();
+    device
+        .set_three_qubit_gate_time("Toffoli", 0, 1, 2, 1.5)
+        .unwrap();
+    device
+        .set_multi_qubit_gate_time("MultiQubitMS", vec![0, 1, 2, 3], 2.0)
+        .unwrap();
+    let operations: Vec<(Operation, Option<f64>)> = vec![
+        (RotateX::new(0, 1.0.into()).into(), Some(0.5)),
+        (RotateX::new(1, 1.0.into()).into(), None),
+        (CNOT::new(0, 1).into(), Some(1.0)),
+        (Toffoli::new(0, 1, 2).into(), Some(1.5)),
+        (
+            MultiQubitMS::new(vec![0, 1, 2, 3], 1.0.into()).into(),
+            Some(2.0),
+        ),
+        (PauliX::new(0).into(), None),
+        (PragmaSleep::new(vec![0], 3.0.into()).into(), Some(3.0)),
+        (
+            PragmaDamping::new(0, 4.0.into(), 0.01.into()).into(),
+            Some(4.0),
+        ),
+        (
+            DefinitionBit::new("ro".to_string(), 2, true).into(),
+            Some(0.0),
+        ),
+    ];
+    Python::with_gil(|py| {
+        let device = Py::new(py, GenericDeviceWrapper { internal: device })
+            .unwrap()
+            .into_bound(py);
+        for (operation, time) in operations {
+            let pyoperation = convert_operation_to_pyobject(operation).unwrap();
+            assert_eq!(gate_time(&device, pyoperation.bind(py)).unwrap(), time);
+        }
+
+        let pyoperation = convert_operation_to_pyobject(CNOT::new(0, 1).into()).unwrap();
+        let not_a_device = 2_i64.into_py(py);
+        assert!(gate_time(not_a_device.bind(py), pyoperation.bind(py)).is_err());
+        assert!(gate_time(&device, not_a_device.bind(py)).is_err());
+    })
+}
+
+/// Test add_active_gate_noise inserting damping after the gates of a parallel circuit
+#[test]
+fn test_add_active_gate_noise() {
+    pyo3::prepare_freethreaded_python();
+    let device = AllToAllDevice::new(3, &["RotateX".to_string()], &["CNOT".to_string()], 2.0)
+        .add_damping_all(0.5);
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(2, 1.0.into());
+    circuit += CNOT::new(0, 1);
+    let mut expected = Circuit::new();
+    expected += RotateX::new(2, 1.0.into());
+    expected += PragmaDamping::new(2, 2.0.into(), 0.5.into());
+    expected += CNOT::new(0, 1);
+    expected += PragmaDamping::new(0, 2.0.into(), 0.5.into());
+    expected += PragmaDamping::new(1, 2.0.into(), 0.5.into());
+    let mut conflicting = circuit.clone();
+    conflicting += RotateX::new(1, 1.0.into());
+    Python::with_gil(|py| {
+        let device = Py::new(py, AllToAllDeviceWrapper { internal: device })
+            .unwrap()
+            .into_bound(py);
+        let pycircuit = Py::new(py, CircuitWrapper { internal: circuit })
+            .unwrap()
+            .into_bound(py);
+        let noisy = add_active_gate_noise(&device, &pycircuit).unwrap();
+        assert_eq!(noisy.internal, expected);
+
+        let conflicting = Py::new(
+            py,
+            CircuitWrapper {
+                internal: conflicting,
+            },
+        )
+        .unwrap()
+        .into_bound(py);
+        assert!(add_active_gate_noise(&device, &conflicting).is_err());
+        let not_a_device = 2_i64.into_py(py);
+        assert!(add_active_gate_noise(not_a_device.bind(py), &pycircuit).is_err());
+        assert!(add_active_gate_noise(&device, not_a_device.bind(py)).is_err());
+    })
+}
+
+/// Test from_config_json and to_config_json on a config with overrides
+#[test]
+fn test_config_json() {
+    pyo3::prepare_freethreaded_python();
+    let config = r#"{
+        "number_qubits": 3,
+        "single_qubit_gates": {"RotateX": 2.0},
+        "two_qubit_gates": {"CNOT": 10.0},
+        "single_qubit_gate_times": [{"gate": "RotateX", "qubit": 2, "time": 3.0}],
+        "two_qubit_gate_times": [{"gate": "CNOT", "control": 0, "target": 1, "time": 12.0}],
+        "decoherence_rates": {"damping": 0.5},
+        "qubit_decoherence_rates": [{"qubit": 1, "dephasing": 0.25}]
+    }"#;
+    let lattice_config = r#"{
+        "number_rows": 2,
+        "number_columns": 2,
+        "two_qubit_gates": {"CNOT": 10.0},
+        "two_qubit_gate_times": [{"gate": "CNOT", "control": 2, "target": 3, "time": 8.0}]
+    }"#;
+    Python::with_gil(|py| {
+        let all_to_all_type = py.get_type_bound::<AllToAllDeviceWrapper>();
+        let generic_type = py.get_type_bound::<GenericDeviceWrapper>();
+        let lattice_type = py.get_type_bound::<SquareLatticeDeviceWrapper>();
+        for device_type in [&all_to_all_type, &generic_type] {
+            let device = device_type
+                .call_method1("from_config_json", (config,))
+                .unwrap();
+            let time: Option<f64> = device
+                .call_method1("single_qubit_gate_time", ("RotateX", 2))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(time, Some(3.0));
+            let time: Option<f64> = device
+                .call_method1("two_qubit_gate_time", ("CNOT", 1, 0))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(time, Some(10.0));
+            let config_json: String = device
+                .call_method0("to_config_json")
+                .unwrap()
+                .extract()
+                .unwrap();
+            let config_value: serde_json::Value = serde_json::from_str(&config_json).unwrap();
+            assert_eq!(
+                config_value["qubit_decoherence_rates"],
+                serde_json::json!([
+                    {"qubit": 1, "damping": 0.0, "dephasing": 0.25, "depolarising": 0.0}
+                ])
+            );
+            let round_trip = device_type
+                .call_method1("from_config_json", (config_json,))
+                .unwrap();
+            assert!(round_trip.eq(&device).unwrap());
+        }
+
+        let device = lattice_type
+            .call_method1("from_config_json", (lattice_config,))
+            .unwrap();
+        let time: Option<f64> = device
+            .call_method1("two_qubit_gate_time", ("CNOT", 2, 3))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(time, Some(8.0));
+        let config_json: String = device
+            .call_method0("to_config_json")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let round_trip = lattice_type
+            .call_method1("from_config_json", (config_json,))
+            .unwrap();
+        assert!(round_trip.eq(&device).unwrap());
+
+        assert!(all_to_all_type
+            .call_method1("from_config_json", ("{\"number_qubits\": \"three\"}",))
+            .is_err());
+        assert!(lattice_type
+            .call_method1("from_config_json", (config,))
+            .is_err());
+    });
+}
+
+/// Test the JsonSchema of the device configs
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_config_json_schema() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let schema: String = py
+            .get_type_bound::<AllToAllDeviceWrapper>()
+            .call_method0("config_json_schema")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let rust_schema = serde_json::to_string_pretty(&schemars::schema_for!(
+            roqoqo::devices::AllToAllDeviceConfig
+        ))
+        .unwrap();
+        assert_eq!(schema, rust_schema);
+        let schema: String = py
+            .get_type_bound::<SquareLatticeDeviceWrapper>()
+            .call_method0("config_json_schema")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(schema.contains("number_columns"));
+        let schema: String = py
+            .get_type_bound::<GenericDeviceWrapper>()
+            .call_method0("config_json_schema")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(schema.contains("qubit_decoherence_rates"));
+    });
+}
@@ -0,0 +1,290 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo::interop::convert_qiskit_circuit;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+use test_case::test_case;
+
+// Minimal stand-in for the parts of the qiskit QuantumCircuit interface used by the conversion
+const MOCK_QISKIT: &str = r#"
+class Register:
+    def __init__(self, name, size):
+        self.name = name
+        self.size = size
+
+class Bit:
+    pass
+
+class BitLocations:
+    def __init__(self, index, registers):
+        self.index = index
+        self.registers = registers
+
+class Parameter:
+    def __init__(self, name):
+        self.name = name
+
+    def __str__(self):
+        return self.name
+
+class Operation:
+    def __init__(self, name, params):
+        self.name = name
+        self.params = list(params)
+
+class Instruction:
+    def __init__(self, operation, qubits, clbits):
+        self.operation = operation
+        self.qubits = tuple(qubits)
+        self.clbits = tuple(clbits)
+
+class QuantumCircuit:
+    def __init__(self, number_qubits, cregs):
+        self.data = []
+        self.global_phase = 0.0
+        self.qubits = [Bit() for _ in range(number_qubits)]
+        self.cregs = [Register(name, size) for (name, size) in cregs]
+        self.clbits = []
+        self._locations = {}
+        for index, qubit in enumerate(self.qubits):
+            self._locations[qubit] = BitLocations(index, [])
+        for register in self.cregs:
+            for index in range(register.size):
+                clbit = Bit()
+                self._locations[clbit] = BitLocations(len(self.clbits), [(register, index)])
+                self.clbits.append(clbit)
+
+    def find_bit(self, bit):
+        return self._locations[bit]
+
+    def append(self, name, qubits, clbits=(), params=()):
+        self.data.append(
+            Instruction(
+                Operation(name, params),
+                [self.qubits[q] for q in qubits],
+                [self.clbits[c] for c in clbits],
+            )
+        )
+"#;
+
+fn mock_module(py: Python) -> Bound<PyModule> {
+    PyModule::from_code_bound(py, MOCK_QISKIT, "mock_qiskit.py", "mock_qiskit").unwrap()
+}
+
+/// Test conversion of all supported instructions, registers and the global phase
+#[test]
+fn test_convert_mock_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mock = mock_module(py);
+        let qc = mock
+            .getattr("QuantumCircuit")
+            .unwrap()
+            .call1((3, vec![("ro", 2), ("aux", 1)]))
+            .unwrap();
+        let theta = mock
+            .getattr("Parameter")
+            .unwrap()
+            .call1(("theta",))
+            .unwrap();
+        for (name, qubits) in [
+            ("h", vec![0]),
+            ("x", vec![1]),
+            ("y", vec![2]),
+            ("z", vec![0]),
+            ("s", vec![1]),
+            ("t", vec![2]),
+            ("cx", vec![0, 1]),
+            ("cz", vec![1, 2]),
+            ("swap", vec![0, 2]),
+            ("ccx", vec![0, 1, 2]),
+            ("barrier", vec![0, 1, 2]),
+        ] {
+            qc.call_method1("append", (name, qubits)).unwrap();
+        }
+        qc.call_method1("append", ("rx", vec![0], Vec::<usize>::new(), vec![0.5]))
+            .unwrap();
+        qc.call_method1("append", ("ry", vec![1], Vec::<usize>::new(), vec![1]))
+            .unwrap();
+        qc.call_method1("append", ("rz", vec![2], Vec::<usize>::new(), vec![theta]))
+            .unwrap();
+        qc.call_method1("append", ("measure", vec![0], vec![1]))
+            .unwrap();
+        qc.call_method1("append", ("measure", vec![2], vec![2]))
+            .unwrap();
+        qc.setattr("global_phase", 0.25).unwrap();
+
+        let converted = convert_qiskit_circuit(&qc).unwrap();
+
+        let mut expected = Circuit::new();
+        expected += DefinitionBit::new("ro".to_string(), 2, true);
+        expected += DefinitionBit::new("aux".to_string(), 1, true);
+        expected += Hadamard::new(0);
+        expected += PauliX::new(1);
+        expected += PauliY::new(2);
+        expected += PauliZ::new(0);
+        expected += SGate::new(1);
+        expected += TGate::new(2);
+        expected += CNOT::new(0, 1);
+        expected += ControlledPauliZ::new(1, 2);
+        expected += SWAP::new(0, 2);
+        expected += Toffoli::new(0, 1, 2);
+        expected += PragmaStopParallelBlock::new(vec![0, 1, 2], CalculatorFloat::ZERO);
+        expected += RotateX::new(0, 0.5.into());
+        expected += RotateY::new(1, 1.0.into());
+        expected += RotateZ::new(2, "theta".into());
+        expected += MeasureQubit::new(0, "ro".to_string(), 1);
+        expected += MeasureQubit::new(2, "aux".to_string(), 0);
+        expected += PragmaGlobalPhase::new(0.25.into());
+        assert_eq!(converted.internal, expected);
+    })
+}
+
+/// Test that unsupported instructions are all listed in the error
+#[test]
+fn test_convert_unsupported_instructions() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mock = mock_module(py);
+        let qc = mock
+            .getattr("QuantumCircuit")
+            .unwrap()
+            .call1((3, Vec::<(String, usize)>::new()))
+            .unwrap();
+        qc.call_method1("append", ("h", vec![0])).unwrap();
+        qc.call_method1("append", ("u3", vec![0])).unwrap();
+        qc.call_method1("append", ("cswap", vec![0, 1, 2])).unwrap();
+        qc.call_method1("append", ("u3", vec![1])).unwrap();
+
+        let err = convert_qiskit_circuit(&qc).unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        assert_eq!(
+            err.value_bound(py).to_string(),
+            "Qiskit circuit contains instructions not supported by qoqo: u3, cswap"
+        );
+    })
+}
+
+/// Test error handling for inputs that are not circuits
+#[test]
+fn test_convert_invalid_input() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let err = convert_qiskit_circuit(&1_usize.into_py(py).into_bound(py)).unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+    })
+}
+
+/// Test conversion of an actual qiskit circuit, only runs when qiskit is installed
+#[test]
+fn test_convert_qiskit_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let qiskit = match py.import_bound("qiskit") {
+            Ok(qiskit) => qiskit,
+            Err(_) => return,
+        };
+        let qc = qiskit
+            .getattr("QuantumCircuit")
+            .unwrap()
+            .call1((3, 3))
+            .unwrap();
+        qc.call_method1("h", (0,)).unwrap();
+        qc.call_method1("cx", (0, 1)).unwrap();
+        qc.call_method1("ccx", (0, 1, 2)).unwrap();
+        qc.call_method1("rz", (0.3, 2)).unwrap();
+        qc.call_method0("barrier").unwrap();
+        qc.call_method1("measure", (vec![0, 1, 2], vec![0, 1, 2]))
+            .unwrap();
+
+        let converted = convert_qiskit_circuit(&qc).unwrap().internal;
+        let instructions: usize = qc.getattr("data").unwrap().len().unwrap();
+        // Additional DefinitionBit for the classical register
+        assert_eq!(converted.len(), instructions + 1);
+        assert_eq!(
+            converted.get(0),
+            Some(&Operation::from(DefinitionBit::new(
+                "c".to_string(),
+                3,
+                true
+            )))
+        );
+        assert_eq!(
+            converted.get(3),
+            Some(&Operation::from(Toffoli::new(0, 1, 2)))
+        );
+        assert_eq!(
+            converted.get(converted.len() - 1),
+            Some(&Operation::from(MeasureQubit::new(2, "c".to_string(), 2)))
+        );
+    })
+}
+
+/// Test that converted gates have the same unitary as in qiskit, only runs when qiskit is installed
+#[test_case("h", vec![0], vec![]; "h")]
+#[test_case("s", vec![0], vec![]; "s")]
+#[test_case("t", vec![0], vec![]; "t")]
+#[test_case("rx", vec![0], vec![0.3]; "rx")]
+#[test_case("ry", vec![0], vec![-1.2]; "ry")]
+#[test_case("rz", vec![0], vec![2.1]; "rz")]
+#[test_case("cx", vec![0, 1], vec![]; "cx")]
+#[test_case("cz", vec![0, 1], vec![]; "cz")]
+#[test_case("swap", vec![0, 1], vec![]; "swap")]
+fn test_convert_qiskit_unitary(name: &str, qubits: Vec<usize>, params: Vec<f64>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let qiskit = match py.import_bound("qiskit") {
+            Ok(qiskit) => qiskit,
+            Err(_) => return,
+        };
+        let qc = qiskit
+            .getattr("QuantumCircuit")
+            .unwrap()
+            .call1((qubits.len(),))
+            .unwrap();
+        let mut args: Vec<PyObject> = params.iter().map(|p| p.into_py(py)).collect();
+        args.extend(qubits.iter().map(|q| q.into_py(py)));
+        qc.call_method1(name, pyo3::types::PyTuple::new_bound(py, args))
+            .unwrap();
+
+        let converted = convert_qiskit_circuit(&qc).unwrap().internal;
+        let gate = GateOperation::try_from(converted.get(0).unwrap().clone()).unwrap();
+        let roqoqo_unitary = gate.unitary_matrix().unwrap();
+
+        // qiskit orders qubits little-endian, roqoqo big-endian
+        let qiskit_unitary: Vec<Vec<Complex64>> = py
+            .import_bound("qiskit.quantum_info")
+            .unwrap()
+            .getattr("Operator")
+            .unwrap()
+            .call1((qc,))
+            .unwrap()
+            .call_method0("reverse_qargs")
+            .unwrap()
+            .getattr("data")
+            .unwrap()
+            .call_method0("tolist")
+            .unwrap()
+            .extract()
+            .unwrap();
+        for (row_index, row) in qiskit_unitary.iter().enumerate() {
+            for (column_index, value) in row.iter().enumerate() {
+                assert!((roqoqo_unitary[(row_index, column_index)] - value).norm() < 1e-10);
+            }
+        }
+    })
+}
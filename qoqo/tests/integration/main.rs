@@ -26,8 +26,41 @@ mod circuitdag;
 #[cfg(test)]
 mod quantum_program;
 
+#[cfg(test)]
+mod conversions;
+
 #[cfg(test)]
 mod measurements;
 
 #[cfg(test)]
 mod noise_models;
+
+#[cfg(test)]
+#[cfg(feature = "interop")]
+mod interop;
+
+#[cfg(test)]
+mod passes;
+
+#[cfg(test)]
+#[cfg(feature = "circuitdag")]
+mod schedule;
+
+#[cfg(test)]
+#[cfg(feature = "unstable_analog_operations")]
+mod analog;
+
+#[cfg(test)]
+mod testing;
+
+#[cfg(test)]
+mod struqture_bridge;
+
+#[cfg(test)]
+mod backends;
+
+#[cfg(test)]
+mod modules;
+
+#[cfg(test)]
+mod registers;
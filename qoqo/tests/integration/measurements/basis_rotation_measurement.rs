@@ -22,7 +22,7 @@ use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputReg
 use roqoqo::ROQOQO_VERSION;
 use roqoqo::{
     measurements::{PauliZProduct, PauliZProductInput},
-    Circuit,
+    operations, Circuit,
 };
 use std::collections::HashMap;
 use test_case::test_case;
@@ -51,7 +51,7 @@ fn test_returning_circuits() {
         circs.push(circ1);
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input))
+            .call1((Some(CircuitWrapper::new()), circs.clone(), input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -156,7 +156,7 @@ fn test_py03_evaluate_bool(
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -267,7 +267,7 @@ fn test_py03_evaluate_usize(
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -346,7 +346,7 @@ fn test_evaluate_symbolic(register: Vec<Vec<bool>>, constant: f64) {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -402,7 +402,7 @@ fn test_py03_evaluate_error0() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -450,7 +450,7 @@ fn test_pyo3_copy() {
         circs.push(circ1);
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input))
+            .call1((Some(CircuitWrapper::new()), circs.clone(), input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
         let br_clone = br;
@@ -495,7 +495,7 @@ fn test_pyo3_debug() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
         let br_wrapper = br.extract::<PauliZProductWrapper>().unwrap();
@@ -548,7 +548,7 @@ fn test_internal_to_bincode() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -599,7 +599,7 @@ fn test_to_from_bincode() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -648,7 +648,7 @@ fn test_to_from_json() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -700,7 +700,7 @@ fn test_substitute_parameters() {
         circs.push(circ1);
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input))
+            .call1((Some(CircuitWrapper::new()), circs.clone(), input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -741,7 +741,7 @@ fn test_substitute_parameters_error() {
         circs.push(circ1);
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input))
+            .call1((Some(CircuitWrapper::new()), circs.clone(), input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -767,7 +767,7 @@ fn test_measurement_type() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -792,7 +792,7 @@ fn test_return_input() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -820,7 +820,7 @@ fn test_pyo3_format_repr() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
         let to_format = br.call_method1("__format__", ("",)).unwrap();
@@ -848,7 +848,7 @@ fn test_pyo3_copy_deepcopy() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let br = binding.downcast::<PauliZProductWrapper>().unwrap();
         let copy_op = br.call_method0("__copy__").unwrap();
@@ -888,12 +888,12 @@ fn test_pyo3_richcmp() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input))
+            .call1((Some(CircuitWrapper::new()), circs.clone(), input, false))
             .unwrap();
         let br_one = binding.downcast::<PauliZProductWrapper>().unwrap();
 
         let arg: Option<CircuitWrapper> = None;
-        let binding = br_type.call1((arg, circs, input)).unwrap();
+        let binding = br_type.call1((arg, circs, input, false)).unwrap();
         let br_two = binding.downcast::<PauliZProductWrapper>().unwrap();
         let comparison =
             bool::extract_bound(&br_one.call_method1("__eq__", (br_two,)).unwrap()).unwrap();
@@ -930,7 +930,7 @@ fn test_pyo3_json_schema() {
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         #[allow(clippy::redundant_clone)]
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input))
+            .call1((Some(CircuitWrapper::new()), circs.clone(), input, false))
             .unwrap();
         let br_one = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -957,3 +957,461 @@ fn test_pyo3_json_schema() {
         assert_eq!(minimum_supported_version_string_input, "1.0.0");
     });
 }
+
+/// Test that bulk registration produces the same evaluation results as one-by-one registration
+#[test]
+fn test_pyo3_bulk_registration() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let products: Vec<Vec<usize>> = vec![vec![], vec![0], vec![1, 2], vec![0]];
+        let entries: Vec<(String, HashMap<usize, f64>)> = vec![
+            ("constant".to_string(), HashMap::from([(0, 3.0)])),
+            ("mixed".to_string(), HashMap::from([(1, 4.0), (2, -1.5)])),
+        ];
+        let input_type = py.get_type_bound::<PauliZProductInputWrapper>();
+
+        let single = input_type.call1((3, false)).unwrap();
+        for mask in products.clone() {
+            single
+                .call_method1("add_pauliz_product", ("ro", mask))
+                .unwrap();
+        }
+        for (name, linear) in entries.clone() {
+            single
+                .call_method1("add_linear_exp_val", (name, linear))
+                .unwrap();
+        }
+
+        let bulk = input_type.call1((3, false)).unwrap();
+        let indices: Vec<usize> = bulk
+            .call_method1("add_pauliz_products", ("ro", products))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(indices, vec![0, 1, 2, 1]);
+        bulk.call_method1("add_linear_exp_vals", (entries,))
+            .unwrap();
+        let number: usize = bulk
+            .call_method0("number_pauli_products")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(number, 3);
+        let registered: Vec<(String, Vec<usize>)> = bulk
+            .call_method0("pauli_products")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(registered[2], ("ro".to_string(), vec![1, 2]));
+        assert!(bulk
+            .call_method1("add_pauliz_products", ("ro", vec![vec![3]]))
+            .is_err());
+        assert!(bulk
+            .call_method1(
+                "add_linear_exp_vals",
+                (vec![("constant", HashMap::<usize, f64>::new())],)
+            )
+            .is_err());
+
+        let registers: HashMap<String, BitOutputRegister> = HashMap::from([(
+            "ro".to_string(),
+            vec![vec![true, false, true], vec![false, true, true]],
+        )]);
+        let br_type = py.get_type_bound::<PauliZProductWrapper>();
+        let evaluate = |input: &Bound<PyAny>| -> HashMap<String, f64> {
+            br_type
+                .call1((
+                    Some(CircuitWrapper::new()),
+                    vec![CircuitWrapper::new()],
+                    input,
+                    false,
+                ))
+                .unwrap()
+                .call_method1(
+                    "evaluate",
+                    (
+                        registers.clone(),
+                        HashMap::<String, FloatOutputRegister>::new(),
+                        HashMap::<String, ComplexOutputRegister>::new(),
+                    ),
+                )
+                .unwrap()
+                .extract()
+                .unwrap()
+        };
+        assert_eq!(evaluate(&bulk), evaluate(&single));
+    })
+}
+
+/// Test weighted evaluation against a manual reweighting of the unweighted evaluation
+#[test]
+fn test_pyo3_evaluate_weighted() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<PauliZProductInputWrapper>();
+        let input = input_type.call1((3, false)).unwrap();
+        input
+            .call_method1("add_pauliz_products", ("ro", vec![vec![0], vec![1, 2]]))
+            .unwrap();
+        input
+            .call_method1("add_pauliz_product", ("rx", vec![1, 2]))
+            .unwrap();
+        input
+            .call_method1(
+                "add_linear_exp_val",
+                ("sum", HashMap::from([(0, 2.0), (1, -1.0), (2, 0.5)])),
+            )
+            .unwrap();
+        let br_type = py.get_type_bound::<PauliZProductWrapper>();
+        let br = br_type
+            .call1((
+                Some(CircuitWrapper::new()),
+                vec![CircuitWrapper::new()],
+                input,
+                false,
+            ))
+            .unwrap();
+        // Integer registers are accepted like in evaluate
+        let registers: HashMap<String, Vec<Vec<usize>>> = HashMap::from([
+            (
+                "ro".to_string(),
+                vec![vec![1, 0, 1], vec![0, 1, 1], vec![0, 0, 0]],
+            ),
+            ("rx".to_string(), vec![vec![0, 1, 0]]),
+        ]);
+        let float_registers = HashMap::<String, FloatOutputRegister>::new();
+        let complex_registers = HashMap::<String, ComplexOutputRegister>::new();
+        let evaluate_weighted = |weights: HashMap<String, f64>,
+                                 pauli_product_weights: HashMap<usize, f64>|
+         -> PyResult<f64> {
+            let result: HashMap<String, f64> = br
+                .call_method1(
+                    "evaluate_weighted",
+                    (
+                        registers.clone(),
+                        float_registers.clone(),
+                        complex_registers.clone(),
+                        weights,
+                        pauli_product_weights,
+                    ),
+                )?
+                .extract()?;
+            Ok(result["sum"])
+        };
+        let plain: HashMap<String, f64> = br
+            .call_method1(
+                "evaluate",
+                (
+                    registers.clone(),
+                    float_registers.clone(),
+                    complex_registers.clone(),
+                ),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        // Pauli product weights are optional
+        let result: HashMap<String, f64> = br
+            .call_method1(
+                "evaluate_weighted",
+                (
+                    registers.clone(),
+                    float_registers.clone(),
+                    complex_registers.clone(),
+                    HashMap::<String, f64>::new(),
+                ),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(result["sum"], plain["sum"]);
+        assert_eq!(
+            evaluate_weighted(HashMap::new(), HashMap::new()).unwrap(),
+            plain["sum"]
+        );
+
+        // ro: Z0 = 1/3, Z1Z2 = 1/3, rx: Z1Z2 = -1 with Pauli product weight 0.5
+        let weighted = evaluate_weighted(
+            HashMap::from([("ro".to_string(), 3.0), ("rx".to_string(), 2.0)]),
+            HashMap::from([(2, 0.5)]),
+        )
+        .unwrap();
+        let expected = 2.0 * (3.0 / 3.0) - (3.0 / 3.0) - 0.5 * (2.0 * 0.5);
+        assert!((weighted - expected).abs() < 1e-12);
+
+        assert!(evaluate_weighted(
+            HashMap::from([("unknown".to_string(), 1.0)]),
+            HashMap::new()
+        )
+        .is_err());
+        assert!(evaluate_weighted(HashMap::new(), HashMap::from([(3, 0.5)])).is_err());
+    })
+}
+
+/// Test that with_flipped_circuits appends flipped circuits whose ideal evaluation matches the unflipped one
+#[test]
+fn test_pyo3_with_flipped_circuits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut constant_circuit = CircuitWrapper::new();
+        constant_circuit.internal += operations::DefinitionBit::new("ro".to_string(), 2, true);
+        constant_circuit.internal += operations::PauliX::new(1);
+        let mut circuit = CircuitWrapper::new();
+        circuit.internal += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 2, None);
+
+        let input_type = py.get_type_bound::<PauliZProductInputWrapper>();
+        let br_type = py.get_type_bound::<PauliZProductWrapper>();
+        let create = |flipped: bool| {
+            let input = input_type.call1((2, flipped)).unwrap();
+            input
+                .call_method1(
+                    "add_pauliz_products",
+                    ("ro", vec![vec![0], vec![1], vec![0, 1]]),
+                )
+                .unwrap();
+            input
+                .call_method1(
+                    "add_linear_exp_val",
+                    ("energy", HashMap::from([(0, 1.0), (1, 2.0), (2, -0.5)])),
+                )
+                .unwrap();
+            br_type
+                .call_method1(
+                    "with_flipped_circuits",
+                    (Some(constant_circuit.clone()), vec![circuit.clone()], input),
+                )
+                .unwrap()
+        };
+        let unflipped = create(false);
+        let flipped = create(true);
+        let circuits: Vec<CircuitWrapper> =
+            flipped.call_method0("circuits").unwrap().extract().unwrap();
+        let mut expected = CircuitWrapper::new();
+        expected.internal += operations::DefinitionBit::new("ro_flipped".to_string(), 2, true);
+        expected.internal += operations::PauliX::new(0);
+        expected.internal += operations::PauliX::new(1);
+        expected.internal +=
+            operations::PragmaRepeatedMeasurement::new("ro_flipped".to_string(), 2, None);
+        assert_eq!(circuits[0], circuit);
+        assert_eq!(circuits[1], expected);
+
+        // Ideal data for the state |10> (qubit 1 excited), flipped readout measures |01>
+        let registers: HashMap<String, BitOutputRegister> = HashMap::from([
+            ("ro".to_string(), vec![vec![false, true]; 2]),
+            ("ro_flipped".to_string(), vec![vec![true, false]; 2]),
+        ]);
+        let evaluate = |measurement: &Bound<PyAny>| -> f64 {
+            let result: HashMap<String, f64> = measurement
+                .call_method1(
+                    "evaluate",
+                    (
+                        registers.clone(),
+                        HashMap::<String, FloatOutputRegister>::new(),
+                        HashMap::<String, ComplexOutputRegister>::new(),
+                    ),
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            result["energy"]
+        };
+        assert_eq!(evaluate(&flipped), evaluate(&unflipped));
+        assert_eq!(evaluate(&unflipped), 1.0 - 2.0 + 0.5);
+
+        // Flipped register names already used in the circuits raise a ValueError
+        let mut clashing = circuit.clone();
+        clashing.internal += operations::DefinitionBit::new("ro_flipped".to_string(), 2, true);
+        let input = input_type.call1((2, true)).unwrap();
+        input
+            .call_method1("add_pauliz_product", ("ro", vec![0]))
+            .unwrap();
+        let error = br_type
+            .call_method1(
+                "with_flipped_circuits",
+                (None::<CircuitWrapper>, vec![clashing], input),
+            )
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
+/// Test that deduplicate_circuits keeps one copy of every distinct circuit
+#[test]
+fn test_pyo3_deduplicate_circuits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut input = PauliZProductInput::new(2, false);
+        input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+        let distinct: Vec<Circuit> = (0..3)
+            .map(|index| {
+                let mut circuit = Circuit::new();
+                circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+                circuit += operations::RotateX::new(0, (index as f64).into());
+                circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+                circuit
+            })
+            .collect();
+        let order = vec![2, 2, 0, 1, 0, 2, 1, 1, 0, 2];
+        let measurement = PauliZProduct {
+            constant_circuit: None,
+            circuits: order.iter().map(|index| distinct[*index].clone()).collect(),
+            input,
+        };
+        let br = Bound::new(
+            py,
+            PauliZProductWrapper {
+                internal: measurement,
+            },
+        )
+        .unwrap();
+
+        let (deduplicated, mapping): (PauliZProductWrapper, Vec<usize>) = br
+            .call_method0("deduplicate_circuits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            deduplicated.internal.circuits,
+            vec![
+                distinct[2].clone(),
+                distinct[0].clone(),
+                distinct[1].clone()
+            ]
+        );
+        assert_eq!(mapping, vec![0, 0, 1, 2, 1, 0, 2, 2, 1, 0]);
+    })
+}
+
+/// Test remap_registers of PauliZProductInput
+#[test]
+fn test_pyo3_input_remap_registers() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<PauliZProductInputWrapper>();
+        let input = input_type.call1((3, false)).unwrap();
+        input
+            .call_method1("add_pauliz_product", ("ro", vec![0]))
+            .unwrap();
+        input
+            .call_method1("add_pauliz_product", ("rx", vec![1, 2]))
+            .unwrap();
+
+        let remapped = input
+            .call_method1("remap_registers", (HashMap::from([("ro", "ro_b")]),))
+            .unwrap();
+        let pauli_products: Vec<(String, Vec<usize>)> = remapped
+            .call_method0("pauli_products")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            pauli_products,
+            vec![
+                ("ro_b".to_string(), vec![0]),
+                ("rx".to_string(), vec![1, 2])
+            ]
+        );
+
+        let result = input.call_method1("remap_registers", (HashMap::from([("ro", "rx")]),));
+        assert!(result.is_err());
+    })
+}
+
+/// Test the getters of PauliZProductInput after a json round-trip
+#[test]
+fn test_pyo3_input_getters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<PauliZProductInputWrapper>();
+        let input = input_type.call1((3, false)).unwrap();
+        input
+            .call_method1("add_pauliz_product", ("ro", vec![0]))
+            .unwrap();
+        input
+            .call_method1("add_pauliz_product", ("rx", vec![1, 2]))
+            .unwrap();
+        input
+            .call_method1("add_linear_exp_val", ("b", HashMap::from([(0, 1.0)])))
+            .unwrap();
+        input
+            .call_method1("add_symbolic_exp_val", ("a", "pauli_product_1"))
+            .unwrap();
+        let json: String = input.call_method0("to_json").unwrap().extract().unwrap();
+        let input = input_type.call_method1("from_json", (json,)).unwrap();
+
+        let number_qubits: usize = input
+            .call_method0("number_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(number_qubits, 3);
+        let names: Vec<String> = input
+            .call_method0("measured_exp_val_names")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+        let pauli_products: Vec<(String, Vec<usize>)> = input
+            .call_method0("pauli_products")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            pauli_products,
+            vec![("ro".to_string(), vec![0]), ("rx".to_string(), vec![1, 2])]
+        );
+    })
+}
+
+/// Test that the constructor checks the readout registers unless check_registers is False
+#[test]
+fn test_pyo3_new_check_registers() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut input = PauliZProductInputWrapper::new(3, false);
+        input
+            .add_pauliz_product("ro".to_string(), vec![0, 2])
+            .unwrap();
+        let mut circuit = CircuitWrapper::new();
+        circuit.internal += operations::DefinitionBit::new("ro".to_string(), 3, true);
+        circuit.internal += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+        let mut short_circuit = CircuitWrapper::new();
+        short_circuit.internal += operations::DefinitionBit::new("ro".to_string(), 2, true);
+        short_circuit.internal +=
+            operations::PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+        let br_type = py.get_type_bound::<PauliZProductWrapper>();
+
+        let binding = br_type
+            .call1((None::<CircuitWrapper>, vec![circuit.clone()], input.clone()))
+            .unwrap();
+        let br = binding.downcast::<PauliZProductWrapper>().unwrap();
+        assert_eq!(
+            br.borrow().internal,
+            PauliZProduct {
+                constant_circuit: None,
+                circuits: vec![circuit.internal],
+                input: input.internal.clone(),
+            }
+        );
+
+        // Register not defined by any circuit
+        let error = br_type.call1((
+            None::<CircuitWrapper>,
+            vec![CircuitWrapper::new()],
+            input.clone(),
+        ));
+        assert!(error.is_err());
+        // Register too short for the qubits of the product
+        let error = br_type.call1((
+            None::<CircuitWrapper>,
+            vec![short_circuit.clone()],
+            input.clone(),
+        ));
+        assert!(error.is_err());
+        // Permissive path
+        let binding = br_type
+            .call1((None::<CircuitWrapper>, vec![short_circuit], input, false))
+            .unwrap();
+        assert!(binding.downcast::<PauliZProductWrapper>().is_ok());
+    })
+}
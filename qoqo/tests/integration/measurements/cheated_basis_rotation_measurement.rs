@@ -13,6 +13,7 @@
 //! Integration test for public API of Basis rotation measurement
 
 use bincode::serialize;
+use num_complex::Complex64;
 use pyo3::prelude::*;
 use pyo3::Python;
 use qoqo::measurements::{CheatedPauliZProductInputWrapper, CheatedPauliZProductWrapper};
@@ -22,7 +23,7 @@ use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputReg
 use roqoqo::ROQOQO_VERSION;
 use roqoqo::{
     measurements::{CheatedPauliZProduct, CheatedPauliZProductInput},
-    Circuit,
+    operations, Circuit,
 };
 use std::collections::HashMap;
 
@@ -296,11 +297,11 @@ fn test_pyo3_debug() {
         let br_clone = br_wrapper.clone();
         assert_eq!(format!("{:?}", br_wrapper), format!("{:?}", br_clone));
 
-        let debug_string = "RefCell { value: CheatedPauliZProductWrapper { internal: CheatedPauliZProduct { constant_circuit: Some(Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }), circuits: [Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }], input: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0} } } } }";
+        let debug_string = "RefCell { value: CheatedPauliZProductWrapper { internal: CheatedPauliZProduct { constant_circuit: Some(Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }), circuits: [Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }], input: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0}, pauli_product_operators: {}, pauli_product_readout_indices: {} } } } }";
         assert_eq!(format!("{:?}", br.as_gil_ref()), debug_string);
 
         let debug_input = input;
-        let debug_input_string = "RefCell { value: CheatedPauliZProductInputWrapper { internal: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0} } } }";
+        let debug_input_string = "RefCell { value: CheatedPauliZProductInputWrapper { internal: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0}, pauli_product_operators: {}, pauli_product_readout_indices: {} } } }";
         assert_eq!(format!("{:?}", input.as_gil_ref()), debug_input_string);
         assert_eq!(
             CheatedPauliZProductInputWrapper::default().internal,
@@ -416,6 +417,51 @@ fn test_to_from_bincode() {
     })
 }
 
+/// Test that inputs with Pauli products not fitting the bincode layout only serialize to json
+#[test]
+fn test_to_bincode_pauli_products() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<CheatedPauliZProductInputWrapper>();
+        let operator_input = input_type.call0().unwrap();
+        operator_input
+            .call_method1("add_pauli_product", ("psi", "X0"))
+            .unwrap();
+        let index_input = input_type.call0().unwrap();
+        index_input
+            .call_method1("add_pauliz_product", ("ro", 1))
+            .unwrap();
+        for input in [operator_input, index_input] {
+            let error = input.call_method0("to_bincode").unwrap_err();
+            assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+
+            let br_type = py.get_type_bound::<CheatedPauliZProductWrapper>();
+            let br = br_type
+                .call1((None::<CircuitWrapper>, vec![CircuitWrapper::new()], &input))
+                .unwrap();
+            let error = br.call_method0("to_bincode").unwrap_err();
+            assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+            let error = br.call_method0("_internal_to_bincode").unwrap_err();
+            assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+
+            let serialised = br.call_method0("to_json").unwrap();
+            let deserialised = br.call_method1("from_json", (&serialised,)).unwrap();
+            assert_eq!(
+                format!(
+                    "{:?}",
+                    br.downcast::<CheatedPauliZProductWrapper>().unwrap()
+                ),
+                format!(
+                    "{:?}",
+                    deserialised
+                        .downcast::<CheatedPauliZProductWrapper>()
+                        .unwrap()
+                )
+            );
+        }
+    })
+}
+
 /// Test to_json and from_json functions
 #[test]
 fn test_to_from_json() {
@@ -587,7 +633,7 @@ fn test_return_input() {
 fn test_pyo3_format_repr() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
-        let format_repr = "CheatedPauliZProduct { constant_circuit: Some(Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }), circuits: [Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }], input: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0} } }";
+        let format_repr = "CheatedPauliZProduct { constant_circuit: Some(Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }), circuits: [Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }], input: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0}, pauli_product_operators: {}, pauli_product_readout_indices: {} } }";
         let input_type = py.get_type_bound::<CheatedPauliZProductInputWrapper>();
         let binding = input_type.call0().unwrap();
         let input = binding
@@ -737,3 +783,186 @@ fn test_pyo3_json_schema() {
         assert_eq!(minimum_supported_version_string_input, "1.0.0");
     });
 }
+
+/// Test add_pauli_product against analytic expectation values of a known statevector
+#[test]
+fn test_pyo3_add_pauli_product() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<CheatedPauliZProductInputWrapper>();
+        let input = input_type.call0().unwrap();
+        // Expectation values for qubit 0 in |+i> and qubit 1 in |1>
+        let products = [
+            ("Y0", 1.0),
+            ("Z1", -1.0),
+            ("X0", 0.0),
+            ("Y0 Z1", -1.0),
+            ("", 1.0),
+            ("I", 1.0),
+        ];
+        for (index, (product, _)) in products.iter().enumerate() {
+            let added: usize = input
+                .call_method1("add_pauli_product", ("psi", *product))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(added, index.min(4));
+            input
+                .call_method1(
+                    "add_linear_exp_val",
+                    (format!("pp{}", index), HashMap::from([(added, 1.0)])),
+                )
+                .unwrap();
+        }
+        for invalid in ["A0", "X", "Xa", "X0 Q1"] {
+            let error = input
+                .call_method1("add_pauli_product", ("psi", invalid))
+                .unwrap_err();
+            assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        }
+
+        let mut circuit = CircuitWrapper::new();
+        circuit.internal += operations::PragmaGetStateVector::new("psi".to_string(), None);
+        let br_type = py.get_type_bound::<CheatedPauliZProductWrapper>();
+        let br = br_type
+            .call1((None::<CircuitWrapper>, vec![circuit], input))
+            .unwrap();
+        let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+        let statevector = vec![
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(amplitude, 0.0),
+            Complex64::new(0.0, amplitude),
+        ];
+        let complex_registers: HashMap<String, ComplexOutputRegister> =
+            HashMap::from([("psi".to_string(), vec![statevector])]);
+        let result: HashMap<String, f64> = br
+            .call_method1(
+                "evaluate",
+                (
+                    HashMap::<String, BitOutputRegister>::new(),
+                    HashMap::<String, FloatOutputRegister>::new(),
+                    complex_registers,
+                ),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        for (index, (_, expected)) in products.iter().enumerate() {
+            assert!((result[&format!("pp{}", index)] - expected).abs() < 1e-12);
+        }
+    })
+}
+
+/// Test add_pauliz_product with Pauli products sharing one float readout register
+#[test]
+fn test_pyo3_add_pauliz_product_readout_index() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<CheatedPauliZProductInputWrapper>();
+        let input = input_type.call0().unwrap();
+        for readout_index in 0..3_usize {
+            let added: usize = input
+                .call_method1("add_pauliz_product", ("ro", readout_index))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(added, readout_index);
+        }
+        let added: usize = input
+            .call_method1("add_pauliz_product", ("ro",))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(added, 0);
+        input
+            .call_method1(
+                "add_linear_exp_val",
+                ("exp", HashMap::from([(0, 1.0), (1, 2.0), (2, 4.0)])),
+            )
+            .unwrap();
+
+        let mut circuit = CircuitWrapper::new();
+        circuit.internal += operations::DefinitionFloat::new("ro".to_string(), 3, true);
+        for readout_index in 0..3 {
+            circuit.internal += operations::PragmaGetIndexedPauliProduct::new(
+                HashMap::from([(readout_index, 3)]),
+                "ro".to_string(),
+                readout_index,
+                Circuit::new(),
+            );
+        }
+        let br_type = py.get_type_bound::<CheatedPauliZProductWrapper>();
+        let br = br_type
+            .call1((None::<CircuitWrapper>, vec![circuit], input))
+            .unwrap();
+        let float_registers: HashMap<String, FloatOutputRegister> =
+            HashMap::from([("ro".to_string(), vec![vec![1.0, 0.5, -0.25]])]);
+        let result: HashMap<String, f64> = br
+            .call_method1(
+                "evaluate",
+                (
+                    HashMap::<String, BitOutputRegister>::new(),
+                    float_registers,
+                    HashMap::<String, ComplexOutputRegister>::new(),
+                ),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(result["exp"], 1.0);
+    })
+}
+
+/// Test the getters of CheatedPauliZProductInput after a json round-trip
+#[test]
+fn test_pyo3_input_getters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<CheatedPauliZProductInputWrapper>();
+        let input = input_type.call0().unwrap();
+        input.call_method1("add_pauliz_product", ("ro_a",)).unwrap();
+        input
+            .call_method1("add_pauli_product", ("ro_c", "X3 Z1"))
+            .unwrap();
+        input
+            .call_method1(
+                "add_linear_exp_val",
+                ("exp", HashMap::from([(0, 1.0), (1, 2.0)])),
+            )
+            .unwrap();
+        let json: String = input.call_method0("to_json").unwrap().extract().unwrap();
+        let input = input_type.call_method1("from_json", (json,)).unwrap();
+
+        let number_qubits: usize = input
+            .call_method0("number_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(number_qubits, 4);
+        let number_pauli_products: usize = input
+            .call_method0("number_pauli_products")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(number_pauli_products, 2);
+        let names: Vec<String> = input
+            .call_method0("measured_exp_val_names")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(names, vec!["exp".to_string()]);
+        let pauli_products: Vec<(String, Vec<usize>)> = input
+            .call_method0("pauli_products")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            pauli_products,
+            vec![
+                ("ro_a".to_string(), vec![]),
+                ("ro_c".to_string(), vec![1, 3])
+            ]
+        );
+    })
+}
@@ -128,6 +128,63 @@ fn test_py03_evaluate_bool(
     })
 }
 
+/// Test evaluate() function for a sparse operator on many qubits
+#[test]
+fn test_py03_evaluate_sparse_operator() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let number_qubits = 10;
+        let dimension = 2_usize.pow(number_qubits);
+        let input_type = py.get_type_bound::<CheatedInputWrapper>();
+        let binding = input_type.call1((number_qubits,)).unwrap();
+        let input = binding.downcast::<CheatedInputWrapper>().unwrap();
+        // Only the non-zero entries are given, duplicated entries are summed up
+        let sparse_operator = vec![
+            (0, 0, Complex64::new(0.25, 0.0)),
+            (0, 0, Complex64::new(0.75, 0.0)),
+            (0, dimension - 1, Complex64::new(0.0, -1.0)),
+            (dimension - 1, 0, Complex64::new(0.0, 1.0)),
+        ];
+        let _ = input
+            .call_method1("add_operator_exp_val", ("sparse", sparse_operator, "ro"))
+            .unwrap();
+        let error = input.call_method1(
+            "add_operator_exp_val",
+            (
+                "too_large",
+                vec![(dimension, 0, Complex64::new(1.0, 0.0))],
+                "ro",
+            ),
+        );
+        assert!(error.is_err());
+
+        let br_type = py.get_type_bound::<CheatedWrapper>();
+        let binding = br_type
+            .call1((None::<CircuitWrapper>, vec![CircuitWrapper::new()], input))
+            .unwrap();
+        let br = binding.downcast::<CheatedWrapper>().unwrap();
+
+        // (|0> + i|dimension - 1>) / sqrt(2)
+        let mut state = vec![Complex64::new(0.0, 0.0); dimension];
+        state[0] = Complex64::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        state[dimension - 1] = Complex64::new(0.0, std::f64::consts::FRAC_1_SQRT_2);
+        let mut measured_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let _ = measured_registers.insert("ro".to_string(), vec![state]);
+        let result = br
+            .call_method1(
+                "evaluate",
+                (
+                    HashMap::<String, BitOutputRegister>::new(),
+                    HashMap::<String, FloatOutputRegister>::new(),
+                    measured_registers,
+                ),
+            )
+            .unwrap();
+        let value = f64::extract_bound(&result.get_item("sparse").unwrap()).unwrap();
+        assert!((value - 1.5).abs() < 1e-10);
+    })
+}
+
 /// Test evaluate failure
 #[test]
 fn test_py03_evaluate_error0() {
@@ -768,3 +825,49 @@ fn test_pyo3_json_schema() {
         assert_eq!(minimum_supported_version_string_input, "1.0.0");
     });
 }
+
+/// Test the getters of CheatedInput after a json round-trip
+#[test]
+fn test_pyo3_input_getters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<CheatedInputWrapper>();
+        let input = input_type.call1((2,)).unwrap();
+        input
+            .call_method1(
+                "add_operator_exp_val",
+                ("z1", vec![(0, 0, Complex64::new(1.0, 0.0))], "ro"),
+            )
+            .unwrap();
+        input
+            .call_method1(
+                "add_operator_exp_val",
+                ("x0", vec![(0, 1, Complex64::new(1.0, 0.0))], "ro"),
+            )
+            .unwrap();
+        let json: String = input.call_method0("to_json").unwrap().extract().unwrap();
+        let input = input_type.call_method1("from_json", (json,)).unwrap();
+
+        let number_qubits: usize = input
+            .call_method0("number_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(number_qubits, 2);
+        let names: Vec<String> = input
+            .call_method0("measured_exp_val_names")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(names, vec!["x0".to_string(), "z1".to_string()]);
+        let dimensions: Vec<(String, usize)> = input
+            .call_method0("operator_dimensions")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            dimensions,
+            vec![("x0".to_string(), 4), ("z1".to_string(), 4)]
+        );
+    })
+}
@@ -17,3 +17,5 @@ mod cheated_basis_rotation_measurement;
 mod cheated_measurement;
 
 mod classical_register_measurement;
+mod records;
+mod statistics;
@@ -0,0 +1,228 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the conversion of measurement results to records
+
+use num_complex::Complex64;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::wrap_pymodule;
+use std::collections::HashMap;
+
+fn measurements_module(py: Python) -> Bound<PyModule> {
+    wrap_pymodule!(qoqo::measurements::measurements)(py).into_bound(py)
+}
+
+/// Pure python implementation of the conversions used as reference
+const REFERENCE: &str = r#"
+def registers_to_records(bit_registers, float_registers, complex_registers):
+    records = []
+    for registers in (bit_registers, float_registers, complex_registers):
+        for name in sorted(registers):
+            for shot_index, shot in enumerate(registers[name]):
+                for position, value in enumerate(shot):
+                    records.append(
+                        {"register": name, "shot_index": shot_index, "position": position, "value": value}
+                    )
+    return records
+
+def expectation_values_to_records(results, parameter_sets):
+    records = []
+    for sweep_index, (result, parameters) in enumerate(zip(results, parameter_sets)):
+        if result is None:
+            continue
+        for name in sorted(result):
+            record = dict(parameters)
+            record.update({"sweep_index": sweep_index, "name": name, "value": result[name]})
+            records.append(record)
+    return records
+"#;
+
+fn reference_module(py: Python) -> Bound<PyDict> {
+    let globals = PyDict::new_bound(py);
+    py.run_bound(REFERENCE, Some(&globals), None).unwrap();
+    globals
+}
+
+/// Test registers_to_records against the python reference
+#[test]
+fn test_registers_to_records() {
+    pyo3::prepare_freethreaded_python();
+    let bit_registers: HashMap<String, Vec<Vec<bool>>> = HashMap::from([
+        (
+            "ro".to_string(),
+            vec![vec![true, false], vec![false, false]],
+        ),
+        ("a".to_string(), vec![vec![true]]),
+    ]);
+    let float_registers: HashMap<String, Vec<Vec<f64>>> =
+        HashMap::from([("f".to_string(), vec![vec![0.5, 1.5, -2.0]])]);
+    let complex_registers: HashMap<String, Vec<Vec<Complex64>>> = HashMap::from([(
+        "c".to_string(),
+        vec![
+            vec![Complex64::new(1.0, 2.0)],
+            vec![Complex64::new(0.0, -1.0)],
+        ],
+    )]);
+    Python::with_gil(|py| {
+        let arguments = (
+            bit_registers.clone(),
+            float_registers.clone(),
+            complex_registers.clone(),
+        );
+        let records = measurements_module(py)
+            .call_method1("registers_to_records", arguments.clone())
+            .unwrap();
+        let expected = reference_module(py)
+            .get_item("registers_to_records")
+            .unwrap()
+            .unwrap()
+            .call1(arguments)
+            .unwrap();
+        assert_eq!(records.len().unwrap(), 10);
+        assert!(records.eq(expected).unwrap());
+
+        let first = records.get_item(0).unwrap();
+        assert_eq!(
+            first
+                .get_item("register")
+                .unwrap()
+                .extract::<String>()
+                .unwrap(),
+            "a"
+        );
+        assert!(first.get_item("value").unwrap().extract::<bool>().unwrap());
+
+        let empty = measurements_module(py)
+            .call_method1(
+                "registers_to_records",
+                (
+                    HashMap::<String, Vec<Vec<bool>>>::new(),
+                    HashMap::<String, Vec<Vec<f64>>>::new(),
+                    HashMap::<String, Vec<Vec<Complex64>>>::new(),
+                ),
+            )
+            .unwrap();
+        assert_eq!(empty.len().unwrap(), 0);
+
+        let error = measurements_module(py).call_method1(
+            "registers_to_records",
+            (
+                HashMap::from([("ro".to_string(), vec![vec!["a"]])]),
+                HashMap::<String, Vec<Vec<f64>>>::new(),
+                HashMap::<String, Vec<Vec<Complex64>>>::new(),
+            ),
+        );
+        assert!(error.unwrap_err().is_instance_of::<PyTypeError>(py));
+    })
+}
+
+/// Test expectation_values_to_records against the python reference
+#[test]
+fn test_expectation_values_to_records() {
+    pyo3::prepare_freethreaded_python();
+    let results: Vec<Option<HashMap<String, f64>>> = vec![
+        Some(HashMap::from([
+            ("z".to_string(), 0.5),
+            ("x".to_string(), -0.25),
+        ])),
+        None,
+        Some(HashMap::from([
+            ("z".to_string(), 1.0),
+            ("x".to_string(), 0.0),
+        ])),
+    ];
+    Python::with_gil(|py| {
+        let parameter_sets = PyList::empty_bound(py);
+        for theta in [0.0, 0.5, 1.0] {
+            let parameters = PyDict::new_bound(py);
+            parameters.set_item("theta", theta).unwrap();
+            parameters.set_item("phi", 2.0 * theta).unwrap();
+            parameter_sets.append(parameters).unwrap();
+        }
+        let arguments = (results.clone(), parameter_sets.clone());
+        let records = measurements_module(py)
+            .call_method1("expectation_values_to_records", arguments.clone())
+            .unwrap();
+        let expected = reference_module(py)
+            .get_item("expectation_values_to_records")
+            .unwrap()
+            .unwrap()
+            .call1(arguments)
+            .unwrap();
+        assert_eq!(records.len().unwrap(), 4);
+        assert!(records.eq(expected).unwrap());
+        let last = records.get_item(3).unwrap();
+        assert_eq!(
+            last.get_item("sweep_index")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            last.get_item("theta").unwrap().extract::<f64>().unwrap(),
+            1.0
+        );
+
+        let error = measurements_module(py).call_method1(
+            "expectation_values_to_records",
+            (results.clone(), PyList::empty_bound(py)),
+        );
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+
+        let parameters = PyDict::new_bound(py);
+        parameters.set_item("value", 1.0).unwrap();
+        let error = measurements_module(py).call_method1(
+            "expectation_values_to_records",
+            (vec![results[0].clone()], vec![parameters]),
+        );
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test registers_to_records for a register with 10^6 values
+#[test]
+fn test_registers_to_records_size() {
+    pyo3::prepare_freethreaded_python();
+    let bit_registers: HashMap<String, Vec<Vec<bool>>> =
+        HashMap::from([("ro".to_string(), vec![vec![true; 1000]; 1000])]);
+    Python::with_gil(|py| {
+        let records = measurements_module(py)
+            .call_method1(
+                "registers_to_records",
+                (
+                    bit_registers,
+                    HashMap::<String, Vec<Vec<f64>>>::new(),
+                    HashMap::<String, Vec<Vec<Complex64>>>::new(),
+                ),
+            )
+            .unwrap();
+        assert_eq!(records.len().unwrap(), 1_000_000);
+        let last = records.get_item(999_999).unwrap();
+        assert_eq!(
+            last.get_item("shot_index")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            999
+        );
+        assert_eq!(
+            last.get_item("position")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            999
+        );
+    })
+}
@@ -0,0 +1,132 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the bit register statistics functions
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3::wrap_pymodule;
+use std::collections::HashMap;
+
+fn statistics_module(py: Python) -> Bound<PyModule> {
+    wrap_pymodule!(qoqo::measurements::statistics::statistics)(py).into_bound(py)
+}
+
+// Records of two qubits with p(00) = 1/2, p(11) = 1/4 and p(10) = 1/4
+fn register() -> Vec<Vec<bool>> {
+    vec![
+        vec![false, false],
+        vec![false, false],
+        vec![true, true],
+        vec![true, false],
+    ]
+}
+
+/// Test bit_frequencies for boolean and integer records
+#[test]
+fn test_bit_frequencies() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = statistics_module(py);
+        let frequencies: Vec<f64> = module
+            .call_method1("bit_frequencies", (register(),))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(frequencies, vec![0.5, 0.25]);
+
+        let integer_register: Vec<Vec<usize>> = vec![vec![1, 0, 1], vec![1, 1, 0]];
+        let frequencies: Vec<f64> = module
+            .call_method1("bit_frequencies", (integer_register,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(frequencies, vec![1.0, 0.5, 0.5]);
+    })
+}
+
+/// Test bit_correlation and mutual_information on a hand-computed example
+#[test]
+fn test_correlation_mutual_information() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = statistics_module(py);
+        // <b0 b1> - <b0><b1> = 1/4 - 1/2 * 1/4
+        let correlation: f64 = module
+            .call_method1("bit_correlation", (register(), 0, 1))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!((correlation - 0.125).abs() < 1e-12);
+
+        let perfectly_correlated = vec![vec![true, true], vec![false, false]];
+        let information: f64 = module
+            .call_method1("mutual_information", (perfectly_correlated, 0, 1))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!((information - 1.0).abs() < 1e-12);
+    })
+}
+
+/// Test joint_histogram returns tuples of outcomes as keys
+#[test]
+fn test_joint_histogram() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = statistics_module(py);
+        let histogram: HashMap<Vec<bool>, usize> = module
+            .call_method1("joint_histogram", (register(), vec![1, 0]))
+            .unwrap()
+            .extract()
+            .unwrap();
+        let expected: HashMap<Vec<bool>, usize> = [
+            (vec![false, false], 2),
+            (vec![true, true], 1),
+            (vec![false, true], 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(histogram, expected);
+
+        let binding = module
+            .call_method1("joint_histogram", (register(), vec![0]))
+            .unwrap();
+        let key = binding.call_method0("keys").unwrap().iter().unwrap().next();
+        assert!(key.unwrap().unwrap().is_instance_of::<PyTuple>());
+    })
+}
+
+/// Test that invalid registers raise errors
+#[test]
+fn test_statistics_errors() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = statistics_module(py);
+        let empty: Vec<Vec<bool>> = vec![];
+        let error = module
+            .call_method1("bit_frequencies", (empty,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+
+        let error = module
+            .call_method1("bit_correlation", (register(), 0, 2))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+
+        let error = module
+            .call_method1("mutual_information", (vec!["register"], 0, 1))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+    })
+}
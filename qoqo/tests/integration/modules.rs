@@ -0,0 +1,86 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use pyo3::wrap_pymodule;
+
+// Checks that `__all__` of a submodule lists exactly its public classes and functions
+fn check_all(module: &Bound<PyAny>, expected: &[&str]) {
+    let all = module
+        .getattr("__all__")
+        .unwrap()
+        .extract::<Vec<String>>()
+        .unwrap();
+    for name in all.iter() {
+        assert!(module.hasattr(name.as_str()).unwrap(), "{}", name);
+    }
+    for name in module
+        .dir()
+        .extract::<Vec<String>>()
+        .unwrap()
+        .iter()
+        .filter(|name| !name.starts_with('_'))
+    {
+        let attribute = module.getattr(name.as_str()).unwrap();
+        if attribute.is_instance_of::<PyType>() || attribute.is_callable() {
+            assert!(all.contains(name), "{} missing in __all__", name);
+        }
+    }
+    for name in expected {
+        assert!(all.contains(&name.to_string()), "{}", name);
+    }
+}
+
+/// Test that every submodule lists its registered classes in `__all__`
+#[test]
+fn test_submodules_all() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        #[allow(unused_mut)]
+        let mut modules: Vec<(Py<PyModule>, Vec<&str>)> = vec![
+            (
+                wrap_pymodule!(qoqo::operations::operations)(py),
+                vec!["RotateZ", "PragmaSetStateVector", "CallDefinedGate"],
+            ),
+            (
+                wrap_pymodule!(qoqo::measurements::measurements)(py),
+                vec!["PauliZProduct", "PauliZProductInput", "ClassicalRegister"],
+            ),
+            (
+                wrap_pymodule!(qoqo::devices::devices)(py),
+                vec!["GenericDevice", "AllToAllDevice", "SquareLatticeDevice"],
+            ),
+            (
+                wrap_pymodule!(qoqo::noise_models::noise_models)(py),
+                vec!["ContinuousDecoherenceModel", "DecoherenceOnIdleModel"],
+            ),
+            (wrap_pymodule!(qoqo::passes::passes)(py), vec![]),
+            (
+                wrap_pymodule!(qoqo::testing::testing)(py),
+                vec!["random_circuit"],
+            ),
+            (
+                wrap_pymodule!(qoqo::backends::backends)(py),
+                vec!["CachingBackend"],
+            ),
+        ];
+        #[cfg(feature = "unstable_analog_operations")]
+        modules.push((
+            wrap_pymodule!(qoqo::analog::analog)(py),
+            vec!["effective_hamiltonian"],
+        ));
+        for (module, expected) in modules.iter() {
+            check_all(module.bind(py).as_any(), expected);
+        }
+    })
+}
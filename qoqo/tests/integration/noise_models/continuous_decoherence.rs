@@ -305,3 +305,37 @@ fn test_json_schema() {
         assert_eq!(minimum_supported_version_string, "1.6.0");
     });
 }
+
+/// Test idle_noise_circuit function
+#[test]
+fn test_idle_noise_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let model = roqoqo::noise_models::ContinuousDecoherenceModel::new()
+            .add_damping_rate(&[0, 1], 0.1)
+            .add_dephasing_rate(&[1], 0.2);
+        let model_py = py
+            .get_type_bound::<ContinuousDecoherenceModelWrapper>()
+            .call0()
+            .unwrap()
+            .call_method1("add_damping_rate", (vec![0, 1], 0.1))
+            .unwrap()
+            .call_method1("add_dephasing_rate", (vec![1], 0.2))
+            .unwrap();
+        let function = wrap_pyfunction_bound!(idle_noise_circuit, py).unwrap();
+        let circuit = function
+            .call1((vec![0_usize, 1, 2], 0.5, model_py.clone()))
+            .unwrap()
+            .extract::<qoqo::CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            circuit.internal,
+            roqoqo::noise_models::idle_noise_circuit(&[0, 1, 2], 0.5.into(), &model).unwrap()
+        );
+
+        assert!(function.call1((vec![0_usize], 0.5, 1.0)).is_err());
+        assert!(function
+            .call1((vec![0_usize], vec!["fails"], model_py))
+            .is_err());
+    })
+}
@@ -185,6 +185,125 @@ fn test_two_qubit_noise_term() {
     })
 }
 
+#[test]
+fn test_two_qubit_noise_term_symmetric() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<DecoherenceOnGateModelWrapper>();
+        let br = br_type.call0().unwrap();
+
+        let mut internal_plus_minus = struqture::spins::PlusMinusLindbladNoiseOperator::new();
+        let _ = internal_plus_minus.add_operator_product(
+            (
+                struqture::spins::PlusMinusProduct::new().z(0),
+                struqture::spins::PlusMinusProduct::new().z(0),
+            ),
+            0.1.into(),
+        );
+        let plus_minus_operator = spins::PlusMinusLindbladNoiseOperatorWrapper {
+            internal: internal_plus_minus,
+        };
+        let br = br
+            .call_method1(
+                "set_two_qubit_gate_error_symmetric",
+                ("CNOT", 0, 1, plus_minus_operator.clone()),
+            )
+            .unwrap();
+        for (control, target) in [(0, 1), (1, 0)] {
+            let operator = br
+                .call_method1("get_two_qubit_gate_error", ("CNOT", control, target))
+                .unwrap()
+                .extract::<spins::PlusMinusLindbladNoiseOperatorWrapper>()
+                .unwrap();
+            assert_eq!(operator, plus_minus_operator);
+        }
+        let gates: Vec<(String, usize, usize)> = br
+            .call_method0("get_all_two_qubit_gate_errors")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            gates,
+            vec![("CNOT".to_string(), 0, 1), ("CNOT".to_string(), 1, 0)]
+        );
+
+        // Serialization round trip keeps both orientations
+        let serialised = br.call_method0("to_json").unwrap();
+        let deserialised = br.call_method1("from_json", (&serialised,)).unwrap();
+        assert!(br
+            .call_method1("__eq__", (&deserialised,))
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+
+        let br = br
+            .call_method1("remove_two_qubit_gate_error", ("CNOT", 1, 0))
+            .unwrap();
+        assert!(br
+            .call_method1("get_two_qubit_gate_error", ("CNOT", 1, 0))
+            .unwrap()
+            .is_none());
+        let gates: Vec<(String, usize, usize)> = br
+            .call_method0("get_all_two_qubit_gate_errors")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(gates, vec![("CNOT".to_string(), 0, 1)]);
+    })
+}
+
+#[test]
+fn test_remove_noise_terms() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<DecoherenceOnGateModelWrapper>();
+        let empty = br_type.call0().unwrap();
+        let plus_minus_operator = spins::PlusMinusLindbladNoiseOperatorWrapper {
+            internal: struqture::spins::PlusMinusLindbladNoiseOperator::new(),
+        };
+        let br = empty
+            .call_method1(
+                "set_single_qubit_gate_error",
+                ("RotateX", 0, plus_minus_operator.clone()),
+            )
+            .unwrap()
+            .call_method1(
+                "set_three_qubit_gate_error",
+                (
+                    "ControlledControlledPauliZ",
+                    0,
+                    1,
+                    2,
+                    plus_minus_operator.clone(),
+                ),
+            )
+            .unwrap()
+            .call_method1(
+                "set_multi_qubit_gate_error",
+                ("MultiQubitMS", vec![0, 1, 2, 3], plus_minus_operator),
+            )
+            .unwrap();
+        let removed = br
+            .call_method1("remove_single_qubit_gate_error", ("RotateX", 0))
+            .unwrap()
+            .call_method1(
+                "remove_three_qubit_gate_error",
+                ("ControlledControlledPauliZ", 0, 1, 2),
+            )
+            .unwrap()
+            .call_method1(
+                "remove_multi_qubit_gate_error",
+                ("MultiQubitMS", vec![0, 1, 2, 3]),
+            )
+            .unwrap();
+        assert!(removed
+            .call_method1("__eq__", (&empty,))
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+    })
+}
+
 #[test]
 fn test_three_qubit_noise_term() {
     pyo3::prepare_freethreaded_python();
@@ -60,6 +60,49 @@ fn test_new_with_uniform_error_init() {
     })
 }
 
+/// Test flip_probabilities and apply_to_register
+#[test]
+fn test_apply_to_register() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<ImperfectReadoutModelWrapper>();
+        let binding = br_type
+            .call_method1("new_with_uniform_error", (2, 0.2, 0.1))
+            .unwrap();
+        let br = binding.downcast::<ImperfectReadoutModelWrapper>().unwrap();
+        let probabilities: (f64, f64) = br
+            .call_method1("flip_probabilities", (1,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(probabilities, (0.2, 0.1));
+
+        let number_shots = 10000;
+        let register: Vec<Vec<usize>> = vec![vec![0, 1]; number_shots];
+        let noisy: Vec<Vec<bool>> = br
+            .call_method1("apply_to_register", (register, vec![0, 1], 7))
+            .unwrap()
+            .extract()
+            .unwrap();
+        let flip_rate = |column: usize, ideal: bool| -> f64 {
+            noisy.iter().filter(|row| row[column] != ideal).count() as f64 / number_shots as f64
+        };
+        assert!((flip_rate(0, false) - 0.2).abs() < 0.02);
+        assert!((flip_rate(1, true) - 0.1).abs() < 0.02);
+
+        let bool_register: Vec<Vec<bool>> = vec![vec![false, true]; number_shots];
+        let noisy_bool: Vec<Vec<bool>> = br
+            .call_method1("apply_to_register", (bool_register, vec![0, 1], 7))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(noisy_bool, noisy);
+
+        let error = br.call_method1("apply_to_register", ("register", vec![0, 1], 7));
+        assert!(error.is_err());
+    })
+}
+
 /// Test debug
 #[test]
 fn test_pyo3_debug() {
@@ -329,6 +329,92 @@ fn test_two_qubit_noise_term() {
     })
 }
 
+#[test]
+fn test_overrotation_for_all_qubits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<SingleQubitOverrotationOnGateWrapper>();
+        let br = br_type.call0().unwrap();
+
+        let desc1 = SingleQubitOverrotationDescriptionWrapper::new("RotateZ", 0.1, 0.5);
+        let desc2 = SingleQubitOverrotationDescriptionWrapper::new("RotateX", 0.0, 0.2);
+        let br = br
+            .call_method1(
+                "set_single_qubit_overrotation_for_all_qubits",
+                ("RotateX", 2, desc1.clone()),
+            )
+            .unwrap()
+            .call_method1(
+                "set_two_qubit_overrotation_for_all_qubits",
+                ("CNOT", 2, (desc1.clone(), desc2.clone())),
+            )
+            .unwrap();
+
+        let single: Vec<(String, usize, SingleQubitOverrotationDescriptionWrapper)> = br
+            .call_method0("get_all_single_qubit_overrotations")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            single,
+            vec![
+                ("RotateX".to_string(), 0, desc1.clone()),
+                ("RotateX".to_string(), 1, desc1.clone()),
+            ]
+        );
+        let two: Vec<(
+            String,
+            usize,
+            usize,
+            (
+                SingleQubitOverrotationDescriptionWrapper,
+                SingleQubitOverrotationDescriptionWrapper,
+            ),
+        )> = br
+            .call_method0("get_all_two_qubit_overrotations")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            two,
+            vec![
+                ("CNOT".to_string(), 0, 1, (desc1.clone(), desc2.clone())),
+                ("CNOT".to_string(), 1, 0, (desc1.clone(), desc2.clone())),
+            ]
+        );
+
+        // Serialization round trips keep the bulk settings
+        let serialised = br.call_method0("to_json").unwrap();
+        let deserialised = br.call_method1("from_json", (&serialised,)).unwrap();
+        assert!(br
+            .call_method1("__eq__", (&deserialised,))
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        let serialised = br.call_method0("to_bincode").unwrap();
+        let deserialised = br.call_method1("from_bincode", (&serialised,)).unwrap();
+        assert!(br
+            .call_method1("__eq__", (&deserialised,))
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+
+        let table: String = br.call_method0("to_table_json").unwrap().extract().unwrap();
+        let table: serde_json::Value = serde_json::from_str(&table).unwrap();
+        assert_eq!(table.as_array().unwrap().len(), 6);
+        assert_eq!(
+            table[2],
+            serde_json::json!({"gate": "CNOT", "qubits": [0, 1], "qubit": 0, "rotation_gate": "RotateZ", "theta_mean": 0.1, "theta_std": 0.5})
+        );
+
+        let error = br.call_method1(
+            "set_two_qubit_overrotation_for_all_qubits",
+            ("CNOT", 2, (desc1, "desc2")),
+        );
+        assert!(error.is_err());
+    })
+}
+
 /// Test json_schema function of SingleQubitOverrotationOnGate
 #[cfg(feature = "json_schema")]
 #[test]
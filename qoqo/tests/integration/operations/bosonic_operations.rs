@@ -964,3 +964,24 @@ fn test_pyo3_json_schema(operation: Operation) {
         assert_eq!(minimum_supported_version_string, minimum_version);
     });
 }
+
+/// Test that fock_matrix raises ValueError for a zero cutoff and symbolic parameters
+#[cfg(feature = "unstable_fock_matrix")]
+#[test_case(Operation::from(Squeezing::new(0, 0.1.into(), 0.1.into())), Operation::from(Squeezing::new(0, "r".into(), 0.1.into())); "Squeezing")]
+#[test_case(Operation::from(PhaseDisplacement::new(0, 0.1.into(), 0.1.into())), Operation::from(PhaseDisplacement::new(0, 0.1.into(), "phi".into())); "PhaseDisplacement")]
+#[test_case(Operation::from(PhaseShift::new(0, 0.1.into())), Operation::from(PhaseShift::new(0, "phi".into())); "PhaseShift")]
+#[test_case(Operation::from(BeamSplitter::new(0, 1, 0.1.into(), 0.5.into())), Operation::from(BeamSplitter::new(0, 1, "theta".into(), 0.5.into())); "BeamSplitter")]
+fn test_pyo3_fock_matrix_errors(operation: Operation, symbolic_operation: Operation) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(operation).unwrap();
+        let error = operation.call_method1(py, "fock_matrix", (0,)).unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+
+        let symbolic_operation = convert_operation_to_pyobject(symbolic_operation).unwrap();
+        let error = symbolic_operation
+            .call_method1(py, "fock_matrix", (2,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
@@ -18,6 +18,9 @@ use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
 use std::collections::{HashMap, HashSet};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
+use struqture_py::spins::SpinHamiltonianSystemWrapper;
 use test_case::test_case;
 
 fn create_qubit_mapping() -> HashMap<usize, usize> {
@@ -59,6 +62,22 @@ fn circuit_remapped() -> Circuit {
     circuit
 }
 
+fn create_operator() -> SpinHamiltonian {
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    operator
+}
+
+fn operator_remapped() -> SpinHamiltonian {
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(2), 1.0.into())
+        .unwrap();
+    operator
+}
+
 fn new_circuit(py: Python) -> Bound<CircuitWrapper> {
     let circuit_type = py.get_type_bound::<CircuitWrapper>();
     circuit_type
@@ -73,9 +92,13 @@ fn new_circuit(py: Python) -> Bound<CircuitWrapper> {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_readout(input_measurement: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -111,8 +134,12 @@ fn test_pyo3_qubit_mapping(input_measurement: Operation, operation_name: &str) {
 /// Test circuit() input/function
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_circuit(input_measurement: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -187,7 +214,9 @@ fn test_pyo3_input_pragmarepeatedmeasurements_input() {
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_involved_qubits_all(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -203,6 +232,8 @@ fn test_pyo3_involved_qubits_all(input_definition: Operation) {
 /// Test involved_qubits function for pragmas with qubit 0
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 fn test_pyo3_involved_qubits_0(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -239,9 +270,13 @@ fn test_pyo3_format_repr(input_measurement: Operation, format_repr: &str) {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_copy_deepcopy(input_measurement: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -272,9 +307,13 @@ fn test_pyo3_copy_deepcopy(input_measurement: Operation) {
 /// Test tags function
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))), "PragmaGetStateVector"; "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))), "PragmaGetDensityMatrix"; "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))), "PragmaGetReducedDensityMatrix"; "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))), "PragmaGetIndexedOccupationProbability"; "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())), "PragmaGetIndexedPauliProduct"; "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))), "PragmaGetOccupationProbability"; "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())), "PragmaGetPauliProduct"; "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))), "PragmaRepeatedMeasurement"; "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())), "PragmaGetOperatorExpectation"; "PragmaGetOperatorExpectation")]
 fn test_pyo3_tags(input_measurement: Operation, tag_name: &str) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -308,9 +347,13 @@ fn test_pyo3_tags_measure_qubits() {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)), String::from("MeasureQubit"); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))), String::from("PragmaGetStateVector"); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))), String::from("PragmaGetDensityMatrix"); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))), String::from("PragmaGetReducedDensityMatrix"); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))), String::from("PragmaGetIndexedOccupationProbability"); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())), String::from("PragmaGetIndexedPauliProduct"); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))), String::from("PragmaGetOccupationProbability"); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())), String::from("PragmaGetPauliProduct"); "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))), String::from("PragmaRepeatedMeasurement"); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())), String::from("PragmaGetOperatorExpectation"); "PragmaGetOperatorExpectation")]
 fn test_pyo3_hqslang(input_measurement: Operation, hqslang_param: String) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -329,9 +372,13 @@ fn test_pyo3_hqslang(input_measurement: Operation, hqslang_param: String) {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_is_parametrized(input_measurement: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -349,9 +396,13 @@ fn test_pyo3_is_parametrized(input_measurement: Operation) {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 0)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_substitute_parameters(input_measurement: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -384,9 +435,13 @@ fn test_pyo3_substitute_parameters(input_measurement: Operation) {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 0)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 1, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(create_qubit_mapping(), String::from("ro"), 1, create_circuit())); "PragmaGetIndexedPauliProduct")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 fn test_pyo3_substitute_params_error(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -417,6 +472,9 @@ fn test_pyo3_substitute_params_error(input_operation: Operation) {
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))),
             Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubits_remapped())));
             "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())),
+            Operation::from(PragmaGetOperatorExpectation::new(operator_remapped(), String::from("ro"), circuit_remapped()));
+            "PragmaGetOperatorExpectation")]
 fn test_pyo3_remap_qubits(first_op: Operation, second_op: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -476,6 +534,9 @@ fn test_pyo3_remapqubits_error(input_operation: Operation) {
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(create_qubit_mapping()))),
             Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubits_remapped())));
             "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())),
+            Operation::from(PragmaGetOperatorExpectation::new(operator_remapped(), String::from("ro"), circuit_remapped()));
+            "PragmaGetOperatorExpectation")]
 fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -614,6 +675,197 @@ fn test_pyo3_new_get_density_matrix() {
     })
 }
 
+/// Test PragmaGetIndexedOccupationProbability new() function
+#[test]
+fn test_pyo3_new_get_indexed_occupation_proba() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaGetIndexedOccupationProbabilityWrapper>();
+        let binding = operation
+            .call1(("ro".to_string(), 2_usize, Option::<CircuitWrapper>::None))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaGetIndexedOccupationProbabilityWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaGetIndexedOccupationProbability::new(
+            String::from("ro"),
+            2,
+            None,
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition.clone()).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+        assert_eq!(
+            convert_pyany_to_operation(new_op.as_any()).unwrap(),
+            input_definition
+        );
+
+        let readout_index: usize = new_op
+            .call_method0("readout_index")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(readout_index, 2);
+
+        let meas_wrapper = new_op
+            .extract::<PragmaGetIndexedOccupationProbabilityWrapper>()
+            .unwrap();
+        let binding = operation
+            .call1(("ro".to_string(), 0_usize, new_circuit(py)))
+            .unwrap();
+        let new_op_diff = binding
+            .downcast::<PragmaGetIndexedOccupationProbabilityWrapper>()
+            .unwrap();
+        let meas_wrapper_diff = new_op_diff
+            .extract::<PragmaGetIndexedOccupationProbabilityWrapper>()
+            .unwrap();
+        let helper_ne: bool = meas_wrapper_diff != meas_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = meas_wrapper == meas_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", meas_wrapper),
+            "PragmaGetIndexedOccupationProbabilityWrapper { internal: PragmaGetIndexedOccupationProbability { readout: \"ro\", readout_index: 2, circuit: None } }"
+        );
+    })
+}
+
+/// Test PragmaGetIndexedPauliProduct new() function
+#[test]
+fn test_pyo3_new_get_indexed_pauli_product() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaGetIndexedPauliProductWrapper>();
+        let binding = operation
+            .call1((
+                create_qubit_mapping(),
+                "ro".to_string(),
+                2_usize,
+                new_circuit(py),
+            ))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaGetIndexedPauliProductWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaGetIndexedPauliProduct::new(
+            create_qubit_mapping(),
+            String::from("ro"),
+            2,
+            Circuit::default(),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition.clone()).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+        assert_eq!(
+            convert_pyany_to_operation(new_op.as_any()).unwrap(),
+            input_definition
+        );
+
+        let readout_index: usize = new_op
+            .call_method0("readout_index")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(readout_index, 2);
+
+        let meas_wrapper = new_op
+            .extract::<PragmaGetIndexedPauliProductWrapper>()
+            .unwrap();
+        let binding = operation
+            .call1((
+                create_qubit_mapping(),
+                "ro".to_string(),
+                1_usize,
+                new_circuit(py),
+            ))
+            .unwrap();
+        let new_op_diff = binding
+            .downcast::<PragmaGetIndexedPauliProductWrapper>()
+            .unwrap();
+        let meas_wrapper_diff = new_op_diff
+            .extract::<PragmaGetIndexedPauliProductWrapper>()
+            .unwrap();
+        let helper_ne: bool = meas_wrapper_diff != meas_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = meas_wrapper == meas_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", meas_wrapper),
+            "PragmaGetIndexedPauliProductWrapper { internal: PragmaGetIndexedPauliProduct { qubit_paulis: {0: 1}, readout: \"ro\", readout_index: 2, circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } } }"
+        );
+    })
+}
+
+/// Test PragmaGetReducedDensityMatrix new() function
+#[test]
+fn test_pyo3_new_get_reduced_density_matrix() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaGetReducedDensityMatrixWrapper>();
+        let binding = operation
+            .call1((
+                "ro".to_string(),
+                vec![1_usize, 0],
+                Option::<CircuitWrapper>::None,
+            ))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaGetReducedDensityMatrixWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaGetReducedDensityMatrix::new(
+            String::from("ro"),
+            vec![1, 0],
+            None,
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition.clone()).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+        assert_eq!(
+            convert_pyany_to_operation(new_op.as_any()).unwrap(),
+            input_definition
+        );
+
+        let qubits: Vec<usize> = new_op.call_method0("qubits").unwrap().extract().unwrap();
+        assert_eq!(qubits, vec![1, 0]);
+        let involved_qubits: HashSet<usize> = new_op
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(involved_qubits, [0, 1].into_iter().collect());
+
+        let meas_wrapper = new_op
+            .extract::<PragmaGetReducedDensityMatrixWrapper>()
+            .unwrap();
+        let binding = operation
+            .call1(("ro".to_string(), vec![1_usize], new_circuit(py)))
+            .unwrap();
+        let new_op_diff = binding
+            .downcast::<PragmaGetReducedDensityMatrixWrapper>()
+            .unwrap();
+        let meas_wrapper_diff = new_op_diff
+            .extract::<PragmaGetReducedDensityMatrixWrapper>()
+            .unwrap();
+        let helper_ne: bool = meas_wrapper_diff != meas_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = meas_wrapper == meas_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", meas_wrapper),
+            "PragmaGetReducedDensityMatrixWrapper { internal: PragmaGetReducedDensityMatrix { readout: \"ro\", qubits: [1, 0], circuit: None } }"
+        );
+    })
+}
+
 /// Test PragmaGetOccupationProbability new() function
 #[test]
 fn test_pyo3_new_get_occupation_proba() {
@@ -701,6 +953,65 @@ fn test_pyo3_new_get_pauli_product() {
     })
 }
 
+/// Test PragmaGetOperatorExpectation new() function
+#[test]
+fn test_pyo3_new_get_operator_expectation() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let system = py
+            .get_type_bound::<SpinHamiltonianSystemWrapper>()
+            .call1((None::<usize>,))
+            .unwrap();
+        system
+            .call_method1("add_operator_product", ("0Z", 1.0))
+            .unwrap();
+        let operation = py.get_type_bound::<PragmaGetOperatorExpectationWrapper>();
+        let binding = operation
+            .call1((system.clone(), "ro".to_string(), new_circuit(py)))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaGetOperatorExpectationWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaGetOperatorExpectation::new(
+            create_operator(),
+            String::from("ro"),
+            Circuit::default(),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let operator = new_op
+            .call_method0("operator")
+            .unwrap()
+            .extract::<SpinHamiltonianSystemWrapper>()
+            .unwrap();
+        assert_eq!(operator.internal.hamiltonian(), &create_operator());
+
+        let meas_wrapper = new_op
+            .extract::<PragmaGetOperatorExpectationWrapper>()
+            .unwrap();
+        let binding = operation
+            .call1((system, "ro2".to_string(), new_circuit(py)))
+            .unwrap();
+        let new_op_diff = binding
+            .downcast::<PragmaGetOperatorExpectationWrapper>()
+            .unwrap();
+        let meas_wrapper_diff = new_op_diff
+            .extract::<PragmaGetOperatorExpectationWrapper>()
+            .unwrap();
+        let helper_ne: bool = meas_wrapper_diff != meas_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = meas_wrapper == meas_wrapper.clone();
+        assert!(helper_eq);
+
+        let result = operation.call1((vec!["fails"], "ro".to_string(), new_circuit(py)));
+        assert!(result.is_err());
+    })
+}
+
 /// Test PragmaRepeatedMeasurement new() function
 #[test]
 fn test_pyo3_new_repeated_measurement() {
@@ -748,6 +1059,41 @@ fn test_pyo3_new_repeated_measurement() {
     })
 }
 
+/// Test PragmaRepeatedMeasurement with_qubit_readout_pairs and effective_readout_index
+#[test]
+fn test_pyo3_repeated_measurement_qubit_readout_pairs() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaRepeatedMeasurementWrapper>();
+        let binding = operation
+            .call_method1("with_qubit_readout_pairs", ("ro", 2, vec![(0, 1), (2, 0)]))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaRepeatedMeasurementWrapper>()
+            .unwrap();
+        let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+        qubit_mapping.insert(0, 1);
+        qubit_mapping.insert(2, 0);
+        assert_eq!(
+            new_op.borrow().internal,
+            PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping))
+        );
+        for (qubit, index) in [(0, 1), (2, 0), (3, 3)] {
+            let readout_index: usize = new_op
+                .call_method1("effective_readout_index", (qubit,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(readout_index, index);
+        }
+
+        let error = operation
+            .call_method1("with_qubit_readout_pairs", ("ro", 2, vec![(0, 1), (2, 1)]))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
 /// Test json_schema function for all measurement operations
 #[cfg(feature = "json_schema")]
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 0));
@@ -804,3 +1150,135 @@ fn test_pyo3_json_schema(operation: Operation) {
         assert_eq!(minimum_supported_version_string, "1.0.0");
     });
 }
+
+/// Test json_schema function for PragmaGetOperatorExpectation
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_pyo3_json_schema_get_operator_expectation() {
+    let operation = Operation::from(PragmaGetOperatorExpectation::new(
+        create_operator(),
+        String::from("ro"),
+        create_circuit(),
+    ));
+    let rust_schema =
+        serde_json::to_string_pretty(&schemars::schema_for!(PragmaGetOperatorExpectation)).unwrap();
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let pyobject = convert_operation_to_pyobject(operation).unwrap();
+        let operation = pyobject.bind(py);
+
+        let schema: String =
+            String::extract_bound(&operation.call_method0("json_schema").unwrap()).unwrap();
+
+        assert_eq!(schema, rust_schema);
+
+        let current_version_string =
+            String::extract_bound(&operation.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&operation.call_method0("min_supported_version").unwrap())
+                .unwrap();
+
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.18.0");
+    });
+}
+
+/// Test json_schema function for PragmaGetReducedDensityMatrix
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_pyo3_json_schema_get_reduced_density_matrix() {
+    let operation = Operation::from(PragmaGetReducedDensityMatrix::new(
+        String::from("ro"),
+        vec![0],
+        Some(create_circuit()),
+    ));
+    let rust_schema =
+        serde_json::to_string_pretty(&schemars::schema_for!(PragmaGetReducedDensityMatrix))
+            .unwrap();
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let pyobject = convert_operation_to_pyobject(operation).unwrap();
+        let operation = pyobject.bind(py);
+
+        let schema: String =
+            String::extract_bound(&operation.call_method0("json_schema").unwrap()).unwrap();
+
+        assert_eq!(schema, rust_schema);
+
+        let current_version_string =
+            String::extract_bound(&operation.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&operation.call_method0("min_supported_version").unwrap())
+                .unwrap();
+
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.18.0");
+    });
+}
+
+/// Test json_schema function for PragmaGetIndexedOccupationProbability
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_pyo3_json_schema_get_indexed_occupation_probability() {
+    let operation = Operation::from(PragmaGetIndexedOccupationProbability::new(
+        String::from("ro"),
+        2,
+        Some(create_circuit()),
+    ));
+    let rust_schema = serde_json::to_string_pretty(&schemars::schema_for!(
+        PragmaGetIndexedOccupationProbability
+    ))
+    .unwrap();
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let pyobject = convert_operation_to_pyobject(operation).unwrap();
+        let operation = pyobject.bind(py);
+
+        let schema: String =
+            String::extract_bound(&operation.call_method0("json_schema").unwrap()).unwrap();
+
+        assert_eq!(schema, rust_schema);
+
+        let current_version_string =
+            String::extract_bound(&operation.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&operation.call_method0("min_supported_version").unwrap())
+                .unwrap();
+
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.18.0");
+    });
+}
+
+/// Test json_schema function for PragmaGetIndexedPauliProduct
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_pyo3_json_schema_get_indexed_pauli_product() {
+    let operation = Operation::from(PragmaGetIndexedPauliProduct::new(
+        create_qubit_mapping(),
+        String::from("ro"),
+        2,
+        create_circuit(),
+    ));
+    let rust_schema =
+        serde_json::to_string_pretty(&schemars::schema_for!(PragmaGetIndexedPauliProduct)).unwrap();
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let pyobject = convert_operation_to_pyobject(operation).unwrap();
+        let operation = pyobject.bind(py);
+
+        let schema: String =
+            String::extract_bound(&operation.call_method0("json_schema").unwrap()).unwrap();
+
+        assert_eq!(schema, rust_schema);
+
+        let current_version_string =
+            String::extract_bound(&operation.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&operation.call_method0("min_supported_version").unwrap())
+                .unwrap();
+
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.18.0");
+    });
+}
@@ -13,12 +13,16 @@
 use ndarray::Array2;
 use num_complex::Complex64;
 use numpy::PyArray2;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyList;
 use pyo3::Python;
 use qoqo::operations::convert_operation_to_pyobject;
 #[cfg(feature = "unstable_operation_definition")]
 use qoqo::operations::CallDefinedGateWrapper;
-use qoqo::operations::{MultiQubitMSWrapper, MultiQubitZZWrapper};
+use qoqo::operations::{
+    MultiQubitMSWithCouplingsWrapper, MultiQubitMSWrapper, MultiQubitZZWrapper,
+};
 use qoqo::CircuitWrapper;
 use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorFloat;
@@ -164,9 +168,60 @@ fn test_new_call_defined_gate(
     })
 }
 
+/// Test new() function for MultiQubitMSWithCouplings
+#[test]
+fn test_new_multi_qubit_ms_with_couplings() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation_type = py.get_type_bound::<MultiQubitMSWithCouplingsWrapper>();
+        let couplings = PyList::new_bound(
+            py,
+            [
+                0.1_f64.into_py(py),
+                "theta".into_py(py),
+                0.3_f64.into_py(py),
+            ],
+        );
+        let binding = operation_type.call1((vec![0, 1, 2], couplings)).unwrap();
+        let operation_py = binding
+            .downcast::<MultiQubitMSWithCouplingsWrapper>()
+            .unwrap();
+        let expected = MultiQubitMSWithCouplings::new(
+            vec![0, 1, 2],
+            vec![0.1.into(), "theta".into(), 0.3.into()],
+        )
+        .unwrap();
+        let comparison = convert_operation_to_pyobject(Operation::from(expected.clone())).unwrap();
+        assert!(
+            bool::extract_bound(&operation_py.call_method1("__eq__", (comparison,)).unwrap())
+                .unwrap()
+        );
+
+        let couplings: Vec<CalculatorFloatWrapper> = operation_py
+            .call_method0("couplings")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let couplings: Vec<CalculatorFloat> = couplings.into_iter().map(|c| c.internal).collect();
+        assert_eq!(&couplings, expected.couplings());
+
+        // Error initialisation
+        let result = operation_type.call1((vec![0, 1, 2], vec![0.1, 0.2]));
+        assert!(result.unwrap_err().is_instance_of::<PyValueError>(py));
+        let result = operation_type.call1((vec![0, 1], vec![vec![0.1]]));
+        assert!(result.unwrap_err().is_instance_of::<PyTypeError>(py));
+
+        let def_wrapper = operation_py
+            .extract::<MultiQubitMSWithCouplingsWrapper>()
+            .unwrap();
+        assert_eq!(def_wrapper.internal, expected);
+    })
+}
+
 /// Test is_parametrized() function for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1], vec![CalculatorFloat::from("theta"); 1]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_is_parametrized(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -183,6 +238,7 @@ fn test_pyo3_is_parametrized(input_operation: Operation) {
 /// Test is_parametrized = false for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::PI)); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::PI)); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1], vec![CalculatorFloat::PI; 1]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_is_not_parametrized(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -274,6 +330,15 @@ fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
         "MultiQubitZZ",
         ];
     "MultiQubitZZ")]
+#[test_case(
+    Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from(0); 3]).unwrap()),
+    vec![
+        "Operation",
+        "GateOperation",
+        "MultiQubitGateOperation",
+        "MultiQubitMSWithCouplings",
+        ];
+    "MultiQubitMSWithCouplings")]
 fn test_pyo3_tags(input_operation: Operation, tags: Vec<&str>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -369,6 +434,7 @@ fn test_pyo3_call_defined_gate_inputs() {
 /// Test remap_qubits() function for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from(1.3); 3]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_remapqubits(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -446,6 +512,7 @@ fn test_pyo3_remapqubits_call_defined_gate() {
 // test remap_qubits() function returning an error.
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from(1.3); 3]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_remapqubits_error(input_operation: Operation) {
     // preparation
     pyo3::prepare_freethreaded_python();
@@ -483,6 +550,7 @@ fn test_pyo3_remapqubits_error_call_defined_gate() {
 /// Test unitary_matrix() function for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from(1.3); 3]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_unitarymatrix(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -508,6 +576,7 @@ fn test_pyo3_unitarymatrix(input_operation: Operation) {
 /// Test unitary_matrix() function for MultiQubitGate Operations for the error case
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from("PI"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from("PI"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from("PI"); 3]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_unitarymatrix_error(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -545,6 +614,22 @@ fn test_pyo3_circuit_ms() {
     })
 }
 
+/// Test circuit() function for MultiQubitMSWithCouplings
+#[test]
+fn test_pyo3_circuit_ms_with_couplings() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let gate =
+            MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), 0.2.into(), 0.3.into()])
+                .unwrap();
+        let operation = convert_operation_to_pyobject(Operation::from(gate.clone())).unwrap();
+        let py_result = operation.call_method0(py, "circuit").unwrap();
+        let result_circuit: CircuitWrapper = py_result.extract(py).unwrap();
+
+        assert_eq!(result_circuit.internal, gate.circuit());
+    })
+}
+
 /// Test circuit() function for MultiQubitZZ
 #[test]
 fn test_pyo3_circuit_zz() {
@@ -570,6 +655,7 @@ fn test_pyo3_circuit_zz() {
 /// Test copy and deepcopy functions
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from(1.3); 3]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_copy_deepcopy(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -641,6 +727,10 @@ fn test_pyo3_copy_deepcopy_call_defined_gate() {
     "MultiQubitZZ { qubits: [0, 1, 2], theta: Float(0.0) }",
     Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::ZERO));
     "MultiQubitZZ")]
+#[test_case(
+    "MultiQubitMSWithCouplings { qubits: [0, 1], couplings: [Float(0.0)] }",
+    Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1], vec![CalculatorFloat::ZERO]).unwrap());
+    "MultiQubitMSWithCouplings")]
 fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -684,6 +774,7 @@ fn test_pyo3_format_repr_call_defined_gate() {
 /// Test substitute_parameters() function for one parameter
 #[test_case(Operation::from(MultiQubitMS::new(vec![1, 2, 3], CalculatorFloat::from("theta"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![1, 2, 3], CalculatorFloat::from("theta"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![1, 2, 3], vec![CalculatorFloat::from("theta"); 3]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_substitute_params_rotate(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -754,6 +845,7 @@ fn test_pyo3_substitute_call_defined_gate() {
 /// Test substitute_parameters() causing an error `None`
 #[test_case(Operation::from(MultiQubitMS::new(vec![1, 2], CalculatorFloat::from("test"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![1, 2], CalculatorFloat::from("test"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![1, 2], vec![CalculatorFloat::from("test"); 1]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_substitute_params_error(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -813,6 +905,9 @@ fn test_pyo3_rotate_powercf(first_op: Operation, second_op: Operation) {
 #[test_case(
     Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0))),
     Operation::from(MultiQubitZZ::new(vec![1, 2], CalculatorFloat::from(0))); "MultiQubitZZ")]
+#[test_case(
+    Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![CalculatorFloat::from(0); 3]).unwrap()),
+    Operation::from(MultiQubitMSWithCouplings::new(vec![1, 2], vec![CalculatorFloat::from(0)]).unwrap()); "MultiQubitMSWithCouplings")]
 fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -924,6 +1019,33 @@ fn test_pyo3_json_schema(operation: Operation) {
     });
 }
 
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_pyo3_json_schema_ms_with_couplings() {
+    let rust_schema =
+        serde_json::to_string_pretty(&schemars::schema_for!(MultiQubitMSWithCouplings)).unwrap();
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let pyobject = convert_operation_to_pyobject(Operation::from(
+            MultiQubitMSWithCouplings::new(vec![0, 1], vec![CalculatorFloat::from(0)]).unwrap(),
+        ))
+        .unwrap();
+        let operation = pyobject.bind(py);
+
+        let schema: String =
+            String::extract_bound(&operation.call_method0("json_schema").unwrap()).unwrap();
+        assert_eq!(schema, rust_schema);
+
+        let current_version_string =
+            String::extract_bound(&operation.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&operation.call_method0("min_supported_version").unwrap())
+                .unwrap();
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.18.0");
+    });
+}
+
 /// Test the json schema for CallDefinedGate
 #[cfg(feature = "unstable_operation_definition")]
 #[cfg(feature = "json_schema")]
@@ -12,16 +12,15 @@
 
 use ndarray::{array, Array1, Array2};
 use num_complex::Complex64;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::Python;
 use qoqo::operations::*;
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations::*;
-use roqoqo::Circuit;
+use roqoqo::{Circuit, RoqoqoError};
 use std::collections::HashMap;
 use std::f64::consts::PI;
-#[cfg(feature = "unstable_analog_operations")]
 use struqture::prelude::*;
-#[cfg(feature = "unstable_analog_operations")]
 use struqture::spins::*;
 use test_case::test_case;
 
@@ -71,6 +70,7 @@ use test_case::test_case;
 #[test_case(Operation::from(InvSqrtISwap::new(0, 1)); "InvSqrtISwap")]
 #[test_case(Operation::from(XY::new(0, 1, CalculatorFloat::PI)); "XY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(NegativeControl::new(CNOT::new(0, 1).into())); "NegativeControl")]
 #[test_case(Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::FRAC_PI_4)); "ControlledPhaseShift")]
 #[test_case(Operation::from(PhaseShiftedControlledZ::new(0, 1, CalculatorFloat::FRAC_PI_4)); "PhaseShiftedControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledPhase::new(0, 1, CalculatorFloat::FRAC_PI_4, 0.1.into())); "PhaseShiftedControlledPhase")]
@@ -86,6 +86,7 @@ use test_case::test_case;
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], 0.1.into())); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], 0.1.into())); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), "theta".into(), 0.3.into()]).unwrap()); "MultiQubitMSWithCouplings")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
@@ -104,10 +105,21 @@ use test_case::test_case;
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(reordering(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
+#[test_case(Operation::from(PragmaGetIndexedOccupationProbability::new(String::from("ro"), 2, Some(create_circuit()))); "PragmaGetIndexedOccupationProbability")]
+#[test_case(Operation::from(PragmaGetIndexedPauliProduct::new(reordering(), String::from("ro"), 2, create_circuit())); "PragmaGetIndexedPauliProduct")]
+#[test_case(Operation::from(PragmaGetOperatorExpectation::new(create_operator(), String::from("ro"), create_circuit())); "PragmaGetOperatorExpectation")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(reordering()))); "PragmaRepeatedMeasurement")]
 #[test_case(Operation::from(PragmaSetNumberOfMeasurements::new(1, String::from("ro"))); "PragmaSetNumberOfMeasurements")]
+#[test_case(Operation::from(PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42))); "PragmaSimulateShotNoise")]
+#[test_case(Operation::from(PragmaSimulateShotNoise::new(String::from("ro"), 100, None)); "PragmaSimulateShotNoise_no_seed")]
+#[test_case(Operation::from(PragmaSetGateTime::new(String::from("CNOT"), vec![0, 1], CalculatorFloat::from(300.0))); "PragmaSetGateTime")]
+#[test_case(Operation::from(PragmaParallelBlockTiming::new(HashMap::from([(0, CalculatorFloat::from(100.0)), (1, CalculatorFloat::from("t"))]))); "PragmaParallelBlockTiming")]
+#[test_case(Operation::from(PragmaConditionalLoop::new(String::from("ro"), 0, 3, create_circuit())); "PragmaConditionalLoop")]
+#[test_case(Operation::from(PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("first"))); "PragmaSnapshotRegisters")]
+#[test_case(Operation::from(PragmaAnnotation::new(String::from("compiler"), String::from("1.0"))); "PragmaAnnotation")]
 #[test_case(Operation::from(PragmaSetStateVector::new(statevector())); "PragmaSetStateVector")]
 #[test_case(Operation::from(PragmaSetDensityMatrix::new(densitymatrix())); "PragmaSetDensityMatrix")]
 #[test_case(Operation::from(PragmaRepeatGate::new(3)); "PragmaRepeatGate")]
@@ -144,6 +156,7 @@ use test_case::test_case;
 #[test_case(Operation::from(SXGate::new(1)); "SXGate")]
 #[test_case(Operation::from(InvSXGate::new(1)); "InvSXGate")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::PI, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 #[test_case(
@@ -160,10 +173,88 @@ fn test_conversion(input: Operation) {
     Python::with_gil(|py| {
         let operation = convert_operation_to_pyobject(input.clone()).unwrap();
         let output = convert_pyany_to_operation(operation.bind(py)).unwrap();
+        assert_eq!(input, output);
+
+        let tagged_json = to_tagged_json(operation.bind(py)).unwrap();
+        assert_eq!(tagged_json, input.to_tagged_json());
+        let round_trip = from_tagged_json(&tagged_json).unwrap();
+        let output = convert_pyany_to_operation(round_trip.bind(py)).unwrap();
         assert_eq!(input, output)
     })
 }
 
+/// Test to_tagged_json and from_tagged_json error handling
+#[test]
+fn test_tagged_json_errors() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let not_an_operation = py.None();
+        let res = to_tagged_json(not_an_operation.bind(py));
+        assert!(res.unwrap_err().is_instance_of::<PyTypeError>(py));
+
+        let res = from_tagged_json(r#"{"hqslang": "NotAGate", "qubit": 0}"#);
+        assert!(res.unwrap_err().is_instance_of::<PyValueError>(py));
+
+        let res = from_tagged_json(r#"{"hqslang": "RotateZ", "qubit": 0}"#);
+        assert!(res.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test try_into_single_qubit_gate_operation, try_into_two_qubit_gate_operation and try_into_gate_operation
+#[test]
+fn test_try_into_gate_operations() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let rotate_z = RotateZ::new(1, CalculatorFloat::from(1.3));
+        let operation = convert_operation_to_pyobject(rotate_z.clone().into()).unwrap();
+        let single_qubit_gate = try_into_single_qubit_gate_operation(operation.bind(py)).unwrap();
+        assert_eq!(single_qubit_gate.internal, rotate_z.to_single_qubit_gate());
+        let gate = try_into_gate_operation(operation.bind(py)).unwrap();
+        assert_eq!(
+            convert_pyany_to_operation(gate.bind(py)).unwrap(),
+            Operation::from(rotate_z)
+        );
+        let res = try_into_two_qubit_gate_operation(operation.bind(py));
+        let err = res.unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+        assert_eq!(
+            err.value_bound(py).to_string(),
+            RoqoqoError::ConversionError {
+                start_type: "Operation",
+                end_type: "TwoQubitGateOperation"
+            }
+            .to_string()
+        );
+
+        let cnot = Operation::from(CNOT::new(0, 1));
+        let operation = convert_operation_to_pyobject(cnot.clone()).unwrap();
+        let two_qubit_gate = try_into_two_qubit_gate_operation(operation.bind(py)).unwrap();
+        assert_eq!(
+            convert_pyany_to_operation(two_qubit_gate.bind(py)).unwrap(),
+            cnot
+        );
+        let res = try_into_single_qubit_gate_operation(operation.bind(py));
+        assert!(res.unwrap_err().is_instance_of::<PyTypeError>(py));
+
+        let damping = Operation::from(PragmaDamping::new(
+            0,
+            CalculatorFloat::from(1.0),
+            CalculatorFloat::from(0.1),
+        ));
+        let operation = convert_operation_to_pyobject(damping).unwrap();
+        let res = try_into_single_qubit_gate_operation(operation.bind(py));
+        assert!(res.unwrap_err().is_instance_of::<PyTypeError>(py));
+        let res = try_into_two_qubit_gate_operation(operation.bind(py));
+        assert!(res.unwrap_err().is_instance_of::<PyTypeError>(py));
+        let res = try_into_gate_operation(operation.bind(py));
+        assert!(res.unwrap_err().is_instance_of::<PyTypeError>(py));
+
+        let not_an_operation = py.None();
+        let res = try_into_gate_operation(not_an_operation.bind(py));
+        assert!(res.unwrap_err().is_instance_of::<PyTypeError>(py));
+    })
+}
+
 #[cfg(feature = "unstable_simulation_repetitions")]
 #[test_case(
     Operation::from(PragmaSimulationRepetitions::new(100)); "PragmaSimulationRepetitions"
@@ -261,6 +352,14 @@ fn operators() -> Array2<f64> {
     operators
 }
 
+fn create_operator() -> SpinHamiltonian {
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(0).x(1), 0.5.into())
+        .unwrap();
+    operator
+}
+
 fn create_circuit() -> Circuit {
     let mut circuit = Circuit::new();
     circuit.add_operation(PauliX::new(0));
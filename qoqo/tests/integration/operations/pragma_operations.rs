@@ -26,6 +26,7 @@ use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
 use std::collections::{HashMap, HashSet};
+use struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper;
 use test_case::test_case;
 
 use super::convert_cf_to_pyobject;
@@ -199,6 +200,45 @@ fn test_pyo3_inputs_setstatevector() {
     })
 }
 
+/// Test validate of PragmaSetStateVector and PragmaSetDensityMatrix
+#[test]
+fn test_pyo3_validate_set_state() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let valid = convert_operation_to_pyobject(Operation::from(PragmaSetStateVector::new(
+            statevector(),
+        )))
+        .unwrap();
+        assert!(valid.call_method0(py, "validate").is_ok());
+        let valid = convert_operation_to_pyobject(Operation::from(PragmaSetDensityMatrix::new(
+            densitymatrix(),
+        )))
+        .unwrap();
+        assert!(valid.call_method0(py, "validate").is_ok());
+
+        let invalid = convert_operation_to_pyobject(Operation::from(PragmaSetStateVector::new(
+            statevector() * 2.0,
+        )))
+        .unwrap();
+        let error = invalid.call_method0(py, "validate").unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert_eq!(
+            error.value_bound(py).to_string(),
+            roqoqo::RoqoqoError::StateVectorNotNormalized { norm: 2.0 }.to_string()
+        );
+        let invalid = convert_operation_to_pyobject(Operation::from(PragmaSetDensityMatrix::new(
+            densitymatrix() * 2.0,
+        )))
+        .unwrap();
+        let error = invalid.call_method0(py, "validate").unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert_eq!(
+            error.value_bound(py).to_string(),
+            roqoqo::RoqoqoError::DensityMatrixTraceNotOne { trace: 2.0 }.to_string()
+        );
+    })
+}
+
 /// Test inputs of PragmaSetDensityMatrix
 #[test]
 fn test_pyo3_inputs_setdensitymatrix() {
@@ -1833,6 +1873,324 @@ fn test_pyo3_new_set_number_of_measurements() {
     })
 }
 
+/// Test PragmaSimulateShotNoise new() function and inputs
+#[test]
+fn test_pyo3_new_simulate_shot_noise() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaSimulateShotNoiseWrapper>();
+        let binding = operation.call1(("ro".to_string(), 100, Some(42))).unwrap();
+        let new_op = binding
+            .downcast::<PragmaSimulateShotNoiseWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaSimulateShotNoise::new(
+            String::from("ro"),
+            100,
+            Some(42),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let readout: String = new_op.call_method0("readout").unwrap().extract().unwrap();
+        assert_eq!(readout, "ro");
+        let number_shots: usize = new_op
+            .call_method0("number_shots")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(number_shots, 100);
+        let seed: Option<u64> = new_op.call_method0("seed").unwrap().extract().unwrap();
+        assert_eq!(seed, Some(42));
+
+        let binding = operation.call1(("ro".to_string(), 100, py.None())).unwrap();
+        let seed: Option<u64> = binding.call_method0("seed").unwrap().extract().unwrap();
+        assert_eq!(seed, None);
+
+        let pragma_wrapper = new_op.extract::<PragmaSimulateShotNoiseWrapper>().unwrap();
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaSimulateShotNoiseWrapper { internal: PragmaSimulateShotNoise { readout: \"ro\", number_shots: 100, seed: Some(42) } }"
+        );
+    })
+}
+
+/// Test PragmaSetGateTime new() function
+#[test]
+fn test_pyo3_new_set_gate_time() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaSetGateTimeWrapper>();
+        let binding = operation
+            .call1(("CNOT".to_string(), vec![0, 1], 300.0))
+            .unwrap();
+        let new_op = binding.downcast::<PragmaSetGateTimeWrapper>().unwrap();
+
+        let input_definition = Operation::from(PragmaSetGateTime::new(
+            String::from("CNOT"),
+            vec![0, 1],
+            CalculatorFloat::from(300.0),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let gate_hqslang: String = new_op
+            .call_method0("gate_hqslang")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(gate_hqslang, "CNOT");
+        let qubits: Vec<usize> = new_op.call_method0("qubits").unwrap().extract().unwrap();
+        assert_eq!(qubits, vec![0, 1]);
+        let gate_time: f64 = new_op.call_method0("gate_time").unwrap().extract().unwrap();
+        assert_eq!(gate_time, 300.0);
+        let involved_qubits: String = new_op
+            .call_method0("involved_qubits")
+            .unwrap()
+            .str()
+            .unwrap()
+            .to_string();
+        assert_eq!(involved_qubits, "set()");
+
+        let pragma_wrapper = new_op.extract::<PragmaSetGateTimeWrapper>().unwrap();
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaSetGateTimeWrapper { internal: PragmaSetGateTime { gate_hqslang: \"CNOT\", qubits: [0, 1], gate_time: Float(300.0) } }"
+        );
+    })
+}
+
+/// Test PragmaConditionalLoop new() and unroll() functions
+#[test]
+fn test_pyo3_new_conditional_loop() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaConditionalLoopWrapper>();
+        let circuit = CircuitWrapper {
+            internal: create_circuit(),
+        };
+        let binding = operation.call1(("ro".to_string(), 0, 3, circuit)).unwrap();
+        let new_op = binding.downcast::<PragmaConditionalLoopWrapper>().unwrap();
+
+        let input_definition = Operation::from(PragmaConditionalLoop::new(
+            String::from("ro"),
+            0,
+            3,
+            create_circuit(),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let max_repetitions: usize = new_op
+            .call_method0("max_repetitions")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(max_repetitions, 3);
+
+        let unrolled = new_op
+            .call_method0("unroll")
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let pragma = PragmaConditionalLoop::new(String::from("ro"), 0, 3, create_circuit());
+        assert_eq!(unrolled.internal, pragma.unroll());
+    })
+}
+
+/// Test PragmaSnapshotRegisters new() and snapshot_name() functions
+#[test]
+fn test_pyo3_new_snapshot_registers() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaSnapshotRegistersWrapper>();
+        let binding = operation
+            .call1((
+                vec!["ro".to_string(), "rf".to_string()],
+                "first".to_string(),
+            ))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaSnapshotRegistersWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaSnapshotRegisters::new(
+            vec![String::from("ro"), String::from("rf")],
+            String::from("first"),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let readout_names: Vec<String> = new_op
+            .call_method0("readout_names")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(readout_names, vec!["ro".to_string(), "rf".to_string()]);
+        let label: String = new_op.call_method0("label").unwrap().extract().unwrap();
+        assert_eq!(label, "first");
+        let snapshot_name: String = new_op
+            .call_method1("snapshot_name", ("ro",))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(snapshot_name, "first_ro");
+
+        let pragma_wrapper = new_op.extract::<PragmaSnapshotRegistersWrapper>().unwrap();
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaSnapshotRegistersWrapper { internal: PragmaSnapshotRegisters { readout_names: [\"ro\", \"rf\"], label: \"first\" } }"
+        );
+    })
+}
+
+/// Test PragmaAnnotation new() function
+#[test]
+fn test_pyo3_new_annotation() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaAnnotationWrapper>();
+        let binding = operation
+            .call1(("compiler".to_string(), "1.0".to_string()))
+            .unwrap();
+        let new_op = binding.downcast::<PragmaAnnotationWrapper>().unwrap();
+
+        let input_definition = Operation::from(PragmaAnnotation::new(
+            String::from("compiler"),
+            String::from("1.0"),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let key: String = new_op.call_method0("key").unwrap().extract().unwrap();
+        assert_eq!(key, "compiler");
+        let value: String = new_op.call_method0("value").unwrap().extract().unwrap();
+        assert_eq!(value, "1.0");
+        let involved_qubits: HashSet<usize> = new_op
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(involved_qubits.is_empty());
+
+        let pragma_wrapper = new_op.extract::<PragmaAnnotationWrapper>().unwrap();
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaAnnotationWrapper { internal: PragmaAnnotation { key: \"compiler\", value: \"1.0\" } }"
+        );
+        assert!(operation.call1(("compiler".to_string(), 1.0)).is_err());
+    })
+}
+
+/// Test PragmaParallelBlockTiming new() function
+#[test]
+fn test_pyo3_new_parallel_block_timing() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaParallelBlockTimingWrapper>();
+        let durations: HashMap<usize, Py<PyAny>> =
+            HashMap::from([(0, 100.0_f64.into_py(py)), (2, "t".into_py(py))]);
+        let binding = operation.call1((durations,)).unwrap();
+        let new_op = binding
+            .downcast::<PragmaParallelBlockTimingWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaParallelBlockTiming::new(HashMap::from([
+            (0, CalculatorFloat::from(100.0)),
+            (2, CalculatorFloat::from("t")),
+        ])));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let qubit_durations: HashMap<usize, CalculatorFloatWrapper> = new_op
+            .call_method0("qubit_durations")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(qubit_durations[&0].internal, CalculatorFloat::from(100.0));
+        assert_eq!(qubit_durations[&2].internal, CalculatorFloat::from("t"));
+        let involved_qubits: HashSet<usize> = new_op
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(involved_qubits, HashSet::from([0, 2]));
+        let is_parametrized: bool = new_op
+            .call_method0("is_parametrized")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(is_parametrized);
+
+        let substituted = new_op
+            .call_method1(
+                "substitute_parameters",
+                (HashMap::from([("t".to_string(), 300.0)]),),
+            )
+            .unwrap();
+        let substituted = substituted
+            .extract::<PragmaParallelBlockTimingWrapper>()
+            .unwrap();
+        assert_eq!(
+            substituted.internal,
+            PragmaParallelBlockTiming::new(HashMap::from([
+                (0, CalculatorFloat::from(100.0)),
+                (2, CalculatorFloat::from(300.0)),
+            ]))
+        );
+
+        let wrong_durations: HashMap<usize, Vec<usize>> = HashMap::from([(0, vec![0])]);
+        let result = operation.call1((wrong_durations,));
+        assert!(result.is_err());
+    })
+}
+
+/// Test PragmaParallelBlockTiming idle_noise_circuit() with a ContinuousDecoherenceModel
+#[test]
+fn test_pyo3_parallel_block_timing_idle_noise_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let model = py
+            .get_type_bound::<qoqo::noise_models::ContinuousDecoherenceModelWrapper>()
+            .call0()
+            .unwrap()
+            .call_method1("add_damping_rate", (vec![0], 0.001))
+            .unwrap();
+        let operation = Operation::from(PragmaParallelBlockTiming::new(HashMap::from([
+            (0, CalculatorFloat::from(100.0)),
+            (1, CalculatorFloat::from(300.0)),
+        ])));
+        let pragma = convert_operation_to_pyobject(operation).unwrap();
+        let circuit = pragma
+            .call_method1(py, "idle_noise_circuit", (model,))
+            .unwrap()
+            .extract::<CircuitWrapper>(py)
+            .unwrap();
+        let mut expected = Circuit::new();
+        expected += PragmaGeneralNoise::new(
+            0,
+            CalculatorFloat::from(100.0),
+            array![[0.001, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+        );
+        assert_eq!(circuit.internal, expected);
+
+        let result = pragma.call_method1(py, "idle_noise_circuit", (1,));
+        assert!(result.is_err());
+    })
+}
+
 /// Test PragmaSetStateVector new() function
 #[test]
 fn test_pyo3_new_set_statevector() {
@@ -2518,6 +2876,73 @@ fn test_pyo3_new_general_noise() {
     })
 }
 
+/// Test PragmaGeneralNoise from_t1_t2() and effective T1/T2 functions
+#[test]
+fn test_pyo3_from_t1_t2_general_noise() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaGeneralNoiseWrapper>();
+        let binding = operation
+            .call_method1("from_t1_t2", (1, 0.005, 10.0, 15.0))
+            .unwrap();
+        let new_op = binding.downcast::<PragmaGeneralNoiseWrapper>().unwrap();
+
+        let pragma_wrapper = new_op.extract::<PragmaGeneralNoiseWrapper>().unwrap();
+        assert_eq!(
+            pragma_wrapper.internal,
+            PragmaGeneralNoise::from_t1_t2(1, CalculatorFloat::from(0.005), 10.0, 15.0).unwrap()
+        );
+
+        let t1 = f64::extract_bound(&new_op.call_method0("effective_t1").unwrap()).unwrap();
+        let t2 = f64::extract_bound(&new_op.call_method0("effective_t2").unwrap()).unwrap();
+        assert!((t1 - 10.0).abs() < 1e-10);
+        assert!((t2 - 15.0).abs() < 1e-10);
+
+        // Error initialisation
+        let result = operation.call_method1("from_t1_t2", (1, 0.005, 10.0, 25.0));
+        assert!(result.is_err());
+
+        let result = operation.call_method1("from_t1_t2", (1, vec!["fails"], 10.0, 15.0));
+        assert!(result.is_err());
+    })
+}
+
+/// Test PragmaGeneralNoise to_lindblad_operator() and from_lindblad_operator() round trip
+#[test_case(array![[0.3, 0.0, 0.0], [0.0, 0.2, 0.0], [0.0, 0.0, 0.1]]; "diagonal")]
+#[test_case(array![[0.3, 0.05, 0.0], [0.05, 0.2, -0.02], [0.0, -0.02, 0.1]]; "off diagonal")]
+fn test_pyo3_general_noise_lindblad_operator(rates: Array2<f64>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let pragma = PragmaGeneralNoise::new(1, CalculatorFloat::from(0.005), rates);
+        let new_op = Bound::new(
+            py,
+            PragmaGeneralNoiseWrapper {
+                internal: pragma.clone(),
+            },
+        )
+        .unwrap();
+        let operator = new_op.call_method0("to_lindblad_operator").unwrap();
+        let operator_wrapper = operator
+            .extract::<PlusMinusLindbladNoiseOperatorWrapper>()
+            .unwrap();
+        assert_eq!(operator_wrapper.internal, pragma.to_lindblad_operator());
+
+        let operation = py.get_type_bound::<PragmaGeneralNoiseWrapper>();
+        let converted = operation
+            .call_method1("from_lindblad_operator", (1, 0.005, operator.clone()))
+            .unwrap()
+            .extract::<PragmaGeneralNoiseWrapper>()
+            .unwrap();
+        assert_eq!(converted.internal, pragma);
+
+        // Error initialisation
+        let result = operation.call_method1("from_lindblad_operator", (1, 0.005, vec![0.1]));
+        assert!(result.is_err());
+        let result = operation.call_method1("from_lindblad_operator", (1, vec!["fails"], operator));
+        assert!(result.is_err());
+    })
+}
+
 /// Test PragmaConditional new() function
 #[test]
 fn test_pyo3_new_conditional() {
@@ -2813,3 +3238,116 @@ fn test_pyo3_json_schema(operation: PragmaOperation) {
         assert_eq!(minimum_supported_version_string, minimum_version);
     });
 }
+
+/// Test round trip of a typed PRAGMA through the PragmaChangeDevice python wrapper
+#[test]
+fn test_pyo3_change_device_round_trip() {
+    let wrapped = PragmaActiveReset::new(1);
+    let operation: Operation = PragmaChangeDevice::from_operation(&wrapped).unwrap().into();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let pyobject = convert_operation_to_pyobject(operation.clone()).unwrap();
+        let operation_py = pyobject.bind(py);
+
+        let wrapped_hqslang: String = operation_py
+            .call_method0("wrapped_hqslang")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(wrapped_hqslang, "PragmaActiveReset");
+        let wrapped_operation: Vec<u8> = operation_py
+            .call_method0("wrapped_operation")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(wrapped_operation, bincode::serialize(&wrapped).unwrap());
+
+        let converted = convert_pyany_to_operation(operation_py).unwrap();
+        assert_eq!(converted, operation);
+        let pragma = PragmaChangeDevice::try_from(converted).unwrap();
+        let deserialized: PragmaActiveReset = pragma.try_deserialize().unwrap();
+        assert_eq!(deserialized, wrapped);
+        assert!(pragma.try_deserialize::<PragmaDamping>().is_err());
+    })
+}
+
+/// Test for_qubits staticmethods of the single-qubit noise PRAGMAs
+#[test]
+fn test_pyo3_noise_for_qubits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let qubits = vec![0_usize, 2];
+        let damping = py
+            .get_type_bound::<PragmaDampingWrapper>()
+            .call_method1("for_qubits", (qubits.clone(), 0.005, 0.02))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            damping.internal,
+            PragmaDamping::for_qubits(&qubits, 0.005.into(), 0.02.into())
+        );
+
+        let depolarising = py
+            .get_type_bound::<PragmaDepolarisingWrapper>()
+            .call_method1("for_qubits", (qubits.clone(), 0.005, "rate"))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            depolarising.internal,
+            PragmaDepolarising::for_qubits(&qubits, 0.005.into(), "rate".into())
+        );
+
+        let dephasing = py
+            .get_type_bound::<PragmaDephasingWrapper>()
+            .call_method1("for_qubits", (qubits.clone(), 0.005, 0.02))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            dephasing.internal,
+            PragmaDephasing::for_qubits(&qubits, 0.005.into(), 0.02.into())
+        );
+
+        let random_noise = py
+            .get_type_bound::<PragmaRandomNoiseWrapper>()
+            .call_method1("for_qubits", (qubits.clone(), 0.005, 0.02, 0.01))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            random_noise.internal,
+            PragmaRandomNoise::for_qubits(&qubits, 0.005.into(), 0.02.into(), 0.01.into())
+        );
+
+        let error = py
+            .get_type_bound::<PragmaDampingWrapper>()
+            .call_method1("for_qubits", (qubits, vec!["fails"], 0.02));
+        assert!(error.is_err());
+    })
+}
+
+/// Test for_qubits staticmethod of PragmaGeneralNoise
+#[test]
+fn test_pyo3_general_noise_for_qubits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let qubits = vec![0_usize, 2];
+        let rates = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ];
+        let general_noise = py
+            .get_type_bound::<PragmaGeneralNoiseWrapper>()
+            .call_method1("for_qubits", (qubits.clone(), 0.005, rates))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            general_noise.internal,
+            PragmaGeneralNoise::for_qubits(&qubits, 0.005.into(), Array2::eye(3))
+        );
+    })
+}
@@ -24,6 +24,7 @@ use qoqo::operations::{
     RotateZWrapper, SGateWrapper, SXGateWrapper, SingleQubitGateWrapper, SqrtPauliXWrapper,
     SqrtPauliYWrapper, TGateWrapper,
 };
+use qoqo::CircuitWrapper;
 use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorFloat;
 use qoqo_calculator_pyo3::CalculatorFloatWrapper;
@@ -888,6 +889,57 @@ fn test_new_singlequbitgate(
     })
 }
 
+/// Test from_unitary_matrix() function for SingleQubitGate
+#[test]
+fn test_from_unitary_matrix_singlequbitgate() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let gate = SingleQubitGate::new(
+            1,
+            CalculatorFloat::from(0.6),
+            CalculatorFloat::from(0.0),
+            CalculatorFloat::from(0.0),
+            CalculatorFloat::from(0.8),
+            CalculatorFloat::from(0.3),
+        );
+        let matrix: Vec<Vec<Complex64>> = gate
+            .unitary_matrix()
+            .unwrap()
+            .outer_iter()
+            .map(|row| row.to_vec())
+            .collect();
+        let operation_type = py.get_type_bound::<SingleQubitGateWrapper>();
+        let binding = operation_type
+            .call_method1("from_unitary_matrix", (matrix, 1))
+            .unwrap();
+        let new_gate = binding
+            .extract::<SingleQubitGateWrapper>()
+            .unwrap()
+            .internal;
+        assert_eq!(new_gate.qubit(), &1);
+        for (expected, value) in [
+            (0.6, new_gate.alpha_r()),
+            (0.0, new_gate.alpha_i()),
+            (0.0, new_gate.beta_r()),
+            (0.8, new_gate.beta_i()),
+            (0.3, new_gate.global_phase()),
+        ] {
+            assert!((expected - f64::try_from(value).unwrap()).abs() < 1e-10);
+        }
+
+        // Error initialisation
+        let not_unitary = vec![
+            vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            vec![Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+        ];
+        let result = operation_type.call_method1("from_unitary_matrix", (not_unitary, 1, 1e-6));
+        assert!(result.is_err());
+
+        let result = operation_type.call_method1("from_unitary_matrix", (vec!["fails"], 1));
+        assert!(result.is_err());
+    })
+}
+
 /// Test new() function for Identity
 #[test_case(Operation::from(Identity::new(1)), (1,), "__eq__"; "Identity_eq")]
 #[test_case(Operation::from(Identity::new(1)), (0,), "__ne__"; "Identity_ne")]
@@ -2825,6 +2877,126 @@ fn test_pyo3_mul_error3(gate1: Operation) {
         let result = operation.call_method1(py, "mul", (operation2,));
 
         assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
+/// Test multiplying RotateX by Hadamard with the * operator
+#[test]
+fn test_pyo3_mul_operator() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let rotate_x = convert_operation_to_pyobject(Operation::from(RotateX::new(
+            0,
+            CalculatorFloat::from(PI / 2.0),
+        )))
+        .unwrap();
+        let hadamard = convert_operation_to_pyobject(Operation::from(Hadamard::new(0))).unwrap();
+
+        let multiplied = rotate_x.bind(py).mul(hadamard.bind(py)).unwrap();
+        let via_method = rotate_x
+            .call_method1(py, "mul", (hadamard.clone(),))
+            .unwrap();
+        assert!(
+            bool::extract_bound(&multiplied.call_method1("__eq__", (via_method,)).unwrap())
+                .unwrap()
+        );
+        assert_eq!(
+            multiplied
+                .call_method0("hqslang")
+                .unwrap()
+                .extract::<String>()
+                .unwrap(),
+            "SingleQubitGate"
+        );
+
+        let expected = [
+            ("alpha_r", -0.5),
+            ("alpha_i", -0.5),
+            ("beta_r", -0.5),
+            ("beta_i", -0.5),
+            ("global_phase", PI / 2.0),
+        ];
+        for (parameter, value) in expected {
+            let result_py = multiplied.call_method0(parameter).unwrap();
+            let result = *CalculatorFloatWrapper::extract_bound(&result_py)
+                .unwrap()
+                .internal
+                .float()
+                .unwrap();
+            assert!(
+                (result - value).abs() < 1e-10,
+                "{parameter}: {result} != {value}"
+            );
+        }
+
+        let other_qubit = convert_operation_to_pyobject(Operation::from(Hadamard::new(1))).unwrap();
+        let error = rotate_x.bind(py).mul(other_qubit.bind(py)).unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
+/// Test to_single_qubit_gate function for SingleQubitGate Operations
+#[test_case(Operation::from(PauliX::new(1)); "PauliX")]
+#[test_case(Operation::from(Hadamard::new(1)); "Hadamard")]
+#[test_case(Operation::from(SGate::new(1)); "SGate")]
+#[test_case(Operation::from(RotateX::new(1, CalculatorFloat::from(0.3))); "RotateX")]
+#[test_case(Operation::from(RotateZ::new(1, CalculatorFloat::from("theta"))); "RotateZ")]
+#[test_case(Operation::from(SingleQubitGate::new(1, 1.0.into(), 0.0.into(), 0.0.into(), 0.0.into(), 0.0.into())); "SingleQubitGate")]
+fn test_pyo3_to_single_qubit_gate(input_operation: Operation) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_operation.clone()).unwrap();
+        let gate: SingleQubitGateOperation = input_operation.try_into().unwrap();
+        let result = operation.call_method0(py, "to_single_qubit_gate").unwrap();
+        let result = result
+            .bind(py)
+            .downcast::<SingleQubitGateWrapper>()
+            .unwrap()
+            .borrow()
+            .internal
+            .clone();
+        assert_eq!(result, gate.to_single_qubit_gate());
+    })
+}
+
+/// Test to_zyz_circuit function for SingleQubitGate Operations
+#[test_case(Operation::from(PauliX::new(1)); "PauliX")]
+#[test_case(Operation::from(Hadamard::new(1)); "Hadamard")]
+#[test_case(Operation::from(SGate::new(1)); "SGate")]
+#[test_case(Operation::from(RotateX::new(1, CalculatorFloat::from(0.3))); "RotateX")]
+#[test_case(Operation::from(SingleQubitGate::new(1, 0.6.into(), 0.0.into(), 0.0.into(), 0.8.into(), 0.2.into())); "SingleQubitGate")]
+fn test_pyo3_to_zyz_circuit(input_operation: Operation) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_operation.clone()).unwrap();
+        let gate: SingleQubitGateOperation = input_operation.try_into().unwrap();
+        let result = operation.call_method0(py, "to_zyz_circuit").unwrap();
+        let result = result
+            .bind(py)
+            .downcast::<CircuitWrapper>()
+            .unwrap()
+            .borrow()
+            .internal
+            .clone();
+        assert_eq!(result, gate.to_zyz_circuit().unwrap());
+    })
+}
+
+/// Test to_zyz_circuit function for symbolic SingleQubitGate Operations
+#[test]
+fn test_pyo3_to_zyz_circuit_symbolic() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(Operation::from(RotateZ::new(
+            1,
+            CalculatorFloat::from("theta"),
+        )))
+        .unwrap();
+        let error = operation.call_method0(py, "to_zyz_circuit").unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
     })
 }
 
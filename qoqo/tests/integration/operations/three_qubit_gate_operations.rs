@@ -23,7 +23,7 @@ use pyo3::prelude::*;
 use qoqo::{
     operations::{
         convert_operation_to_pyobject, ControlledControlledPauliZWrapper,
-        ControlledControlledPhaseShiftWrapper, ControlledSWAPWrapper,
+        ControlledControlledPhaseShiftWrapper, ControlledSWAPWrapper, FredkinWrapper,
         PhaseShiftedControlledControlledPhaseWrapper, PhaseShiftedControlledControlledZWrapper,
         ToffoliWrapper,
     },
@@ -40,6 +40,7 @@ use test_case::test_case;
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_pyo3_is_not_parametrized(input_operation: Operation) {
@@ -103,6 +104,14 @@ fn test_pyo3_is_parametrized(input_operation: Operation) {
         "ControlledSWAP",
         ],
     Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "ThreeQubitGateOperation",
+        "Fredkin",
+        ],
+    Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(
     vec![
         "Operation",
@@ -140,6 +149,7 @@ fn test_pyo3_tags(tags: Vec<&str>, input_operation: Operation) {
 #[test_case("ControlledControlledPhaseShift", Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case("Toffoli", Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case("ControlledSWAP", Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case("Fredkin", Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case("PhaseShiftedControlledControlledZ", Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::from("theta"))); "PhaseShiftedControlledControlledZ")]
 #[test_case("PhaseShiftedControlledControlledPhase", Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::from("phi"), CalculatorFloat::from("theta"))); "PhaseShiftedControlledControlledPhase")]
 fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
@@ -230,6 +240,7 @@ fn test_pyo3_remapqubits(input_operation: Operation) {
 }
 
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 fn test_pyo3_remapqubits_cswap(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -302,6 +313,7 @@ fn test_pyo3_remapqubits_cswap(input_operation: Operation) {
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_pyo3_remapqubits_error(input_operation: Operation) {
@@ -333,6 +345,7 @@ fn test_pyo3_unitarymatrix_error(input_operation: Operation) {
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_pyo3_unitarymatrix(input_operation: Operation) {
@@ -369,6 +382,9 @@ fn test_pyo3_unitarymatrix(input_operation: Operation) {
 #[test_case(
     "ControlledSWAP { control: 1, target_0: 0, target_1: 2 }",
     Operation::from(ControlledSWAP::new(1, 0, 2)); "ControlledSWAP")]
+#[test_case(
+    "Fredkin { control: 1, target_0: 0, target_1: 2 }",
+    Operation::from(Fredkin::new(1, 0, 2)); "Fredkin")]
 #[test_case(
     "PhaseShiftedControlledControlledZ { control_0: 0, control_1: 1, target: 2, phi: Float(1.5707963267948966) }",
     Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
@@ -392,6 +408,7 @@ fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_pyo3_copy_deepcopy(input_operation: Operation) {
@@ -428,6 +445,8 @@ fn test_pyo3_copy_deepcopy(input_operation: Operation) {
             Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)),
             Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)),
+            Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::from("test"))),
             Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::from(1.0))); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::from("test"), CalculatorFloat::from("test"))),
@@ -686,6 +705,49 @@ fn test_new_controlledswap(input_operation: Operation, arguments: (u32, u32, u32
     })
 }
 
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)), (0, 1, 2), "__eq__"; "Fredkin_eq")]
+#[test_case(Operation::from(Fredkin::new(2, 1, 0)), (0, 1, 2), "__ne__"; "Fredkin_ne")]
+fn test_new_fredkin(input_operation: Operation, arguments: (u32, u32, u32), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<FredkinWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<FredkinWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, vec!["fails"]));
+        assert!(result.is_err());
+
+        let result = operation_type.call1((0, vec!["fails"], 2));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<FredkinWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2, 3)).unwrap();
+        let new_op_diff = binding.downcast::<FredkinWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<FredkinWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "FredkinWrapper { internal: Fredkin { control: 1, target_0: 2, target_1: 3 } }"
+        );
+    })
+}
+
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::from(1.0))), (0, 1, 2, 1.0), "__eq__"; "PhaseShiftedControlledControlledZ_eq")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(2, 1, 0, CalculatorFloat::from(1.0))), (0, 1, 2, 1.0), "__ne__"; "PhaseShiftedControlledControlledZ_ne")]
 fn test_new_phaseshiftedccz(
@@ -984,12 +1046,47 @@ fn test_circuit_pyo3_phaseshiftedccphase() {
     });
 }
 
+/// Test circuit_with_basis function for all three qubit gate operations
+#[test_case(ThreeQubitGateOperation::from(ControlledControlledPauliZ::new(0, 1, 2)); "ControlledControlledPauliZ")]
+#[test_case(ThreeQubitGateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.3))); "ControlledControlledPhaseShift")]
+#[test_case(ThreeQubitGateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
+#[test_case(ThreeQubitGateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(ThreeQubitGateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
+#[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::from(0.3))); "PhaseShiftedControlledControlledZ")]
+#[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::from(0.3), CalculatorFloat::from(0.2))); "PhaseShiftedControlledControlledPhase")]
+fn test_pyo3_circuit_with_basis(input_operation: ThreeQubitGateOperation) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation =
+            convert_operation_to_pyobject(Operation::from(input_operation.clone())).unwrap();
+        for (name, basis) in [
+            ("CNOT", TwoQubitBasis::CNOT),
+            ("CZ", TwoQubitBasis::ControlledPauliZ),
+            ("ControlledPauliZ", TwoQubitBasis::ControlledPauliZ),
+            ("ISwap", TwoQubitBasis::ISwap),
+        ] {
+            let py_result = operation
+                .call_method1(py, "circuit_with_basis", (name,))
+                .unwrap();
+            let result_circuit: CircuitWrapper = py_result.extract(py).unwrap();
+            assert_eq!(
+                result_circuit.internal,
+                input_operation.circuit_with_basis(basis)
+            );
+        }
+
+        let result = operation.call_method1(py, "circuit_with_basis", ("SWAP",));
+        assert!(result.is_err());
+    });
+}
+
 /// Test json_schema function for all three qubit gate operations
 #[cfg(feature = "json_schema")]
 #[test_case(ThreeQubitGateOperation::from(ControlledControlledPauliZ::new(0, 1, 2)); "ControlleControlledPauliZ")]
 #[test_case(ThreeQubitGateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from("test"))); "ControlledControlledPhaseShift")]
 #[test_case(ThreeQubitGateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(ThreeQubitGateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(ThreeQubitGateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_pyo3_json_schema(operation: ThreeQubitGateOperation) {
@@ -1008,6 +1105,9 @@ fn test_pyo3_json_schema(operation: ThreeQubitGateOperation) {
         ThreeQubitGateOperation::ControlledSWAP(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(ControlledSWAP)).unwrap()
         }
+        ThreeQubitGateOperation::Fredkin(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(Fredkin)).unwrap()
+        }
         ThreeQubitGateOperation::PhaseShiftedControlledControlledZ(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(PhaseShiftedControlledControlledZ))
                 .unwrap()
@@ -1050,6 +1150,9 @@ fn test_pyo3_json_schema(operation: ThreeQubitGateOperation) {
             | ThreeQubitGateOperation::PhaseShiftedControlledControlledPhase(_) => {
                 assert_eq!(minimum_supported_version_string, "1.16.0")
             }
+            ThreeQubitGateOperation::Fredkin(_) => {
+                assert_eq!(minimum_supported_version_string, "1.18.0")
+            }
             _ => unreachable!(),
         };
     });
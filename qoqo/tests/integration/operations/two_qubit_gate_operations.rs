@@ -22,10 +22,11 @@ use qoqo::operations::{
     ControlledPauliZWrapper, ControlledPhaseShiftWrapper, ControlledRotateXWrapper,
     ControlledRotateXYWrapper, EchoCrossResonanceWrapper, FSwapWrapper, FsimWrapper,
     GivensRotationLittleEndianWrapper, GivensRotationWrapper, ISwapWrapper, InvSqrtISwapWrapper,
-    MolmerSorensenXXWrapper, PMInteractionWrapper, PhaseShiftedControlledPhaseWrapper,
-    PhaseShiftedControlledZWrapper, QsimWrapper, SWAPWrapper, SpinInteractionWrapper,
-    SqrtISwapWrapper, VariableMSXXWrapper, XYWrapper,
+    MolmerSorensenXXWrapper, NegativeControlWrapper, PMInteractionWrapper,
+    PhaseShiftedControlledPhaseWrapper, PhaseShiftedControlledZWrapper, QsimWrapper, SWAPWrapper,
+    SpinInteractionWrapper, SqrtISwapWrapper, VariableMSXXWrapper, XYWrapper,
 };
+use qoqo::CircuitWrapper;
 
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations::Operation;
@@ -475,6 +476,7 @@ fn test_pyo3_remapqubits_error(input_operation: Operation) {
 #[test_case(Operation::from(PMInteraction::new(0, 1, CalculatorFloat::from("test"))); "PMInteraction")]
 #[test_case(Operation::from(ComplexPMInteraction::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::from(-1.0))); "ComplexPMInteraction")]
 #[test_case(Operation::from(PhaseShiftedControlledZ::new(0, 1, CalculatorFloat::from("test"))); "PhaseShiftedControlledZ")]
+#[test_case(Operation::from(NegativeControl::new(ControlledPhaseShift::new(0, 1, CalculatorFloat::from("test")).into())); "NegativeControl")]
 #[test_case(Operation::from(PhaseShiftedControlledPhase::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledPhase")]
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::from("test"))); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::from("test"))); "ControlledRotateXY")]
@@ -513,6 +515,7 @@ fn test_pyo3_unitarymatrix_error(input_operation: Operation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(NegativeControl::new(CNOT::new(0, 1).into())); "NegativeControl")]
 fn test_pyo3_unitarymatrix(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -1935,6 +1938,46 @@ fn test_new_echocrossresonance(input_operation: Operation, arguments: (u32, u32)
     })
 }
 
+/// Test to_cnot_circuit function for TwoQubitGate Operations
+#[test_case(Operation::from(CNOT::new(0, 1)); "CNOT")]
+#[test_case(Operation::from(ISwap::new(0, 1)); "ISwap")]
+#[test_case(Operation::from(Fsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(2.0), CalculatorFloat::from(-1.0))); "Fsim")]
+#[test_case(Operation::from(Qsim::new(1, 0, CalculatorFloat::from(1.0), CalculatorFloat::from(0.5), CalculatorFloat::from(-0.3))); "Qsim")]
+#[test_case(Operation::from(Bogoliubov::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Bogoliubov")]
+#[test_case(Operation::from(SpinInteraction::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(2.0), CalculatorFloat::from(-1.0))); "SpinInteraction")]
+fn test_pyo3_to_cnot_circuit(input_operation: Operation) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_operation.clone()).unwrap();
+        let gate: TwoQubitGateOperation = input_operation.try_into().unwrap();
+        let result = operation.call_method0(py, "to_cnot_circuit").unwrap();
+        let result = result
+            .bind(py)
+            .downcast::<CircuitWrapper>()
+            .unwrap()
+            .borrow()
+            .internal
+            .clone();
+        assert_eq!(result, gate.to_cnot_circuit().unwrap());
+    })
+}
+
+/// Test to_cnot_circuit function for symbolic TwoQubitGate Operations
+#[test]
+fn test_pyo3_to_cnot_circuit_symbolic() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(Operation::from(XY::new(
+            0,
+            1,
+            CalculatorFloat::from("theta"),
+        )))
+        .unwrap();
+        let error = operation.call_method0(py, "to_cnot_circuit").unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
 /// Test the __richcmp__ function
 #[test_case(
     Operation::from(CNOT::new(0, 1)),
@@ -2179,3 +2222,114 @@ fn test_pyo3_json_schema(operation: TwoQubitGateOperation) {
         assert_eq!(minimum_supported_version_string, minimum_version);
     });
 }
+
+/// Test new() function and the accessors of NegativeControl
+#[test]
+fn test_new_negative_control() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let inner = convert_operation_to_pyobject(Operation::from(CNOT::new(2, 0))).unwrap();
+        let operation_type = py.get_type_bound::<NegativeControlWrapper>();
+        let binding = operation_type.call1((inner.clone_ref(py),)).unwrap();
+        let operation_py = binding.downcast::<NegativeControlWrapper>().unwrap();
+        let wrapper = operation_py.extract::<NegativeControlWrapper>().unwrap();
+        assert_eq!(
+            wrapper.internal,
+            NegativeControl::new(CNOT::new(2, 0).into())
+        );
+
+        let control: usize = operation_py
+            .call_method0("control")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(control, 2);
+        let target: usize = operation_py
+            .call_method0("target")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(target, 0);
+        let comparison = bool::extract_bound(
+            &operation_py
+                .call_method0("inner")
+                .unwrap()
+                .call_method1("__eq__", (inner,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+        let hqslang: String = operation_py
+            .call_method0("hqslang")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(hqslang, "NegativeControl");
+        let predicate = |method: &str| -> bool {
+            operation_py
+                .call_method0(method)
+                .unwrap()
+                .extract()
+                .unwrap()
+        };
+        assert!(predicate("is_gate_operation"));
+        assert!(!predicate("is_pragma"));
+        assert!(!predicate("is_measurement"));
+
+        // Error initialisation
+        let single_qubit_gate =
+            convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+        let result = operation_type.call1((single_qubit_gate,));
+        assert!(result
+            .unwrap_err()
+            .is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        let result = operation_type.call1((0,));
+        assert!(result
+            .unwrap_err()
+            .is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+
+        assert_eq!(
+            format!("{:?}", wrapper),
+            "NegativeControlWrapper { internal: NegativeControl { inner: CNOT(CNOT { control: 2, target: 0 }) } }"
+        );
+    })
+}
+
+/// Test circuit() function of NegativeControl
+#[test]
+fn test_pyo3_negative_control_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let gate = NegativeControl::new(ControlledPhaseShift::new(1, 0, "theta".into()).into());
+        let operation = convert_operation_to_pyobject(Operation::from(gate.clone())).unwrap();
+        let result = operation.call_method0(py, "circuit").unwrap();
+        let result = result
+            .bind(py)
+            .downcast::<CircuitWrapper>()
+            .unwrap()
+            .borrow()
+            .internal
+            .clone();
+        assert_eq!(result, gate.circuit());
+        assert_eq!(result.len(), 3);
+
+        let substituted = operation
+            .call_method1(
+                py,
+                "substitute_parameters",
+                (HashMap::from([("theta", 0.5)]),),
+            )
+            .unwrap();
+        let substituted = substituted
+            .bind(py)
+            .downcast::<NegativeControlWrapper>()
+            .unwrap()
+            .borrow()
+            .internal
+            .clone();
+        assert_eq!(
+            substituted,
+            NegativeControl::new(ControlledPhaseShift::new(1, 0, 0.5.into()).into())
+        );
+    })
+}
@@ -0,0 +1,220 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo::devices::AllToAllDeviceWrapper;
+use qoqo::noise_models::ContinuousDecoherenceModelWrapper;
+use qoqo::passes::{
+    estimate_fidelity, estimate_fidelity_per_qubit, reuse_qubits, transpile_to_device,
+    unroll_conditional_loops,
+};
+use qoqo::CircuitWrapper;
+use roqoqo::devices::AllToAllDevice;
+use roqoqo::noise_models::ContinuousDecoherenceModel;
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Test reuse_qubits against the roqoqo pass
+#[test]
+fn test_reuse_qubits() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += Hadamard::new(0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PauliX::new(1);
+    circuit += CNOT::new(1, 2);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += MeasureQubit::new(2, "ro".to_string(), 2);
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let (reused, mapping) = reuse_qubits(circuit_py.as_any()).unwrap();
+        let (expected, expected_mapping) = roqoqo::passes::reuse_qubits(&circuit).unwrap();
+        assert_eq!(reused.internal, expected);
+        assert_eq!(mapping, expected_mapping);
+        let physical: HashMap<usize, usize> = [(0, 0), (1, 0), (2, 1)].into_iter().collect();
+        assert_eq!(mapping, physical);
+
+        let error = reuse_qubits(&0_usize.into_py(py).into_bound(py)).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+
+        circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+        let circuit_py = Bound::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let error = reuse_qubits(circuit_py.as_any()).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test the fidelity estimates against the roqoqo cost model
+#[test]
+fn test_estimate_fidelity() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    let device = AllToAllDevice::new(2, &["Hadamard".to_string()], &["CNOT".to_string()], 1.0)
+        .add_damping_all(0.01);
+    let noise = ContinuousDecoherenceModel::new().add_dephasing_rate(&[1], 0.02);
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let device_py = py
+            .get_type_bound::<AllToAllDeviceWrapper>()
+            .call1((2, vec!["Hadamard"], vec!["CNOT"], 1.0))
+            .unwrap()
+            .call_method1("add_damping_all", (0.01,))
+            .unwrap();
+        let noise_py = py
+            .get_type_bound::<ContinuousDecoherenceModelWrapper>()
+            .call0()
+            .unwrap()
+            .call_method1("add_dephasing_rate", (vec![1], 0.02))
+            .unwrap();
+
+        let fidelity = estimate_fidelity(circuit_py.as_any(), &device_py, &noise_py).unwrap();
+        let expected = roqoqo::passes::estimate_fidelity(&circuit, &device, &noise).unwrap();
+        assert_eq!(fidelity, expected);
+        let per_qubit =
+            estimate_fidelity_per_qubit(circuit_py.as_any(), &device_py, &noise_py).unwrap();
+        let expected =
+            roqoqo::passes::estimate_fidelity_per_qubit(&circuit, &device, &noise).unwrap();
+        assert_eq!(per_qubit, expected);
+
+        // Adding a gate decreases the estimate
+        circuit += Hadamard::new(1);
+        let circuit_py = Bound::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let decreased = estimate_fidelity(circuit_py.as_any(), &device_py, &noise_py).unwrap();
+        assert!(decreased < fidelity);
+
+        let error = estimate_fidelity(&0_usize.into_py(py).into_bound(py), &device_py, &noise_py)
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+        let error = estimate_fidelity(circuit_py.as_any(), &noise_py, &noise_py).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+        let error = estimate_fidelity(circuit_py.as_any(), &device_py, &device_py).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+
+        let mut unknown_gate = Circuit::new();
+        unknown_gate += PauliX::new(0);
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: unknown_gate,
+            },
+        )
+        .unwrap();
+        let error = estimate_fidelity(circuit_py.as_any(), &device_py, &noise_py).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test unroll_conditional_loops against the roqoqo pass
+#[test]
+fn test_unroll_conditional_loops() {
+    pyo3::prepare_freethreaded_python();
+    let mut body = Circuit::new();
+    body += Hadamard::new(0);
+    body += MeasureQubit::new(0, "ro".to_string(), 0);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += PragmaConditionalLoop::new("ro".to_string(), 0, 3, body);
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let unrolled = unroll_conditional_loops(circuit_py.as_any()).unwrap();
+        assert_eq!(
+            unrolled.internal,
+            roqoqo::passes::unroll_conditional_loops(&circuit)
+        );
+
+        let error = unroll_conditional_loops(&0_usize.into_py(py).into_bound(py)).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+    })
+}
+
+/// Test transpile_to_device against the roqoqo pass
+#[test]
+fn test_transpile_to_device() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += Toffoli::new(0, 1, 2);
+    let device = AllToAllDevice::new(
+        3,
+        &["RotateZ".to_string(), "SqrtPauliX".to_string()],
+        &["ControlledPauliZ".to_string()],
+        1.0,
+    );
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let device_py = py
+            .get_type_bound::<AllToAllDeviceWrapper>()
+            .call1((
+                3,
+                vec!["RotateZ", "SqrtPauliX"],
+                vec!["ControlledPauliZ"],
+                1.0,
+            ))
+            .unwrap();
+
+        let transpiled = transpile_to_device(circuit_py.as_any(), &device_py).unwrap();
+        let expected = roqoqo::passes::transpile_to_device(&circuit, &device).unwrap();
+        assert_eq!(transpiled.internal, expected);
+        for operation in transpiled.internal.iter() {
+            assert!([
+                "RotateZ",
+                "SqrtPauliX",
+                "ControlledPauliZ",
+                "PragmaGlobalPhase"
+            ]
+            .contains(&operation.hqslang()));
+        }
+
+        let error =
+            transpile_to_device(&0_usize.into_py(py).into_bound(py), &device_py).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+        let error = transpile_to_device(circuit_py.as_any(), circuit_py.as_any()).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+
+        let device_py = py
+            .get_type_bound::<AllToAllDeviceWrapper>()
+            .call1((3, vec!["RotateZ", "SqrtPauliX"], vec!["SWAP"], 1.0))
+            .unwrap();
+        let error = transpile_to_device(circuit_py.as_any(), &device_py).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
@@ -89,7 +89,7 @@ fn test_basic_traits() {
 
         assert_eq!(
             format!("{:?}", QuantumProgramWrapper::new(&input, vec!["test".into()]).unwrap()),
-            "QuantumProgramWrapper { internal: CheatedPauliZProduct { measurement: CheatedPauliZProduct { constant_circuit: Some(Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }), circuits: [Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }, Circuit { definitions: [], operations: [RotateX(RotateX { qubit: 0, theta: Float(0.0) })], _roqoqo_version: RoqoqoVersion }], input: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0} } }, input_parameter_names: [\"test\"] } }"
+            "QuantumProgramWrapper { internal: CheatedPauliZProduct { measurement: CheatedPauliZProduct { constant_circuit: Some(Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }), circuits: [Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion }, Circuit { definitions: [], operations: [RotateX(RotateX { qubit: 0, theta: Float(0.0) })], _roqoqo_version: RoqoqoVersion }], input: CheatedPauliZProductInput { measured_exp_vals: {}, pauli_product_keys: {\"ro\": 0}, pauli_product_operators: {}, pauli_product_readout_indices: {} } }, input_parameter_names: [\"test\"] } }"
         );
     })
 }
@@ -112,7 +112,12 @@ fn test_new_run_br() {
         circs.push(circ1.clone());
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs.clone(), input_instance))
+            .call1((
+                Some(CircuitWrapper::new()),
+                circs.clone(),
+                input_instance,
+                false,
+            ))
             .unwrap();
         let input = binding.downcast::<PauliZProductWrapper>().unwrap();
 
@@ -314,6 +319,121 @@ fn test_new_run_classical_register() {
     })
 }
 
+/// Test size_report function of QuantumProgram
+#[test]
+fn test_size_report() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = Circuit::new();
+        circuit += PragmaLoop::new(
+            2.into(),
+            Circuit::new() + PragmaLoop::new(2.into(), Circuit::new() + PauliX::new(0)),
+        );
+        let program = QuantumProgramWrapper {
+            internal: QuantumProgram::ClassicalRegister {
+                measurement: ClassicalRegister {
+                    constant_circuit: None,
+                    circuits: vec![circuit.clone(), circuit],
+                },
+                input_parameter_names: vec![],
+            },
+        };
+        let program = Bound::new(py, program).unwrap();
+
+        let report = program
+            .call_method0("size_report")
+            .unwrap()
+            .extract::<std::collections::HashMap<String, usize>>()
+            .unwrap();
+        let serialized = program
+            .call_method0("to_bincode")
+            .unwrap()
+            .extract::<Vec<u8>>()
+            .unwrap();
+        assert_eq!(report["serialized_bytes"], serialized.len());
+        assert_eq!(report["max_nesting_depth"], 2);
+        assert_eq!(report["number_operations"], 6);
+    })
+}
+
+/// Test that undefined or too small classical registers are rejected before execution
+#[test]
+fn test_validate_classical_register() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += DefinitionBit::new("ro".to_string(), 1, true);
+        let mut circ1 = Circuit::new();
+        circ1 += MeasureQubit::new(0, "ro".to_string(), 0);
+        let valid = ClassicalRegisterWrapper {
+            internal: ClassicalRegister {
+                constant_circuit: Some(constant_circuit.clone()),
+                circuits: vec![circ1.clone()],
+            },
+        };
+        let valid = Bound::new(py, valid).unwrap();
+        valid.call_method0("validate").unwrap();
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let program = program_type.call1((valid, Vec::<String>::new())).unwrap();
+        program.call_method0("validate").unwrap();
+        program
+            .call_method1("run_registers", (TestBackend, None::<Vec<f64>>))
+            .unwrap();
+
+        circ1 += MeasureQubit::new(1, "ro".to_string(), 1);
+        let too_small = ClassicalRegisterWrapper {
+            internal: ClassicalRegister {
+                constant_circuit: Some(constant_circuit),
+                circuits: vec![circ1.clone()],
+            },
+        };
+        let too_small = Bound::new(py, too_small).unwrap();
+        assert!(too_small.call_method0("validate").is_err());
+        let program = program_type
+            .call1((too_small, Vec::<String>::new()))
+            .unwrap();
+        assert!(program.call_method0("validate").is_err());
+        let error = program.call_method1("run_registers", (TestBackend, None::<Vec<f64>>));
+        assert!(error.is_err());
+
+        let missing = ClassicalRegisterWrapper {
+            internal: ClassicalRegister {
+                constant_circuit: None,
+                circuits: vec![circ1],
+            },
+        };
+        let missing = Bound::new(py, missing).unwrap();
+        assert!(missing.call_method0("validate").is_err());
+    })
+}
+
+/// Test min_supported_version function of QuantumProgram
+#[test]
+fn test_min_supported_version() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = Circuit::new();
+        circuit += TripleControlledPauliX::new(0, 1, 2, 3);
+        let cr = ClassicalRegisterWrapper {
+            internal: ClassicalRegister {
+                constant_circuit: Some(circuit),
+                circuits: vec![Circuit::new()],
+            },
+        };
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let program = program_type
+            .call1((Bound::new(py, cr).unwrap(), Vec::<String>::new()))
+            .unwrap();
+
+        let current_version_string =
+            String::extract_bound(&program.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&program.call_method0("min_supported_version").unwrap()).unwrap();
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.16.0");
+    })
+}
+
 /// Test new function of QuantumProgram first error
 #[test]
 fn test_new_error_1() {
@@ -470,6 +590,45 @@ fn test_value_error_bincode() {
     })
 }
 
+/// Test measurement_type and the errors for QuantumPrograms with unknown measurement types
+#[test]
+fn test_unknown_measurement_type() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input = create_measurement(py);
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let binding = program_type
+            .call1((&input, vec!["test".to_string()]))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+        let measurement_type: String = program
+            .call_method0("measurement_type")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(measurement_type, "CheatedPauliZProduct");
+
+        let json: String = program.call_method0("to_json").unwrap().extract().unwrap();
+        let json = json.replacen("\"CheatedPauliZProduct\"", "\"FutureMeasurement\"", 1);
+        let error = program_type.call_method1("from_json", (json,)).unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        let message = error.value_bound(py).to_string();
+        assert!(message.starts_with("Input cannot be deserialized to QuantumProgram: "));
+        assert!(message.contains("Unknown QuantumProgram measurement type FutureMeasurement"));
+
+        let mut bytes = bincode::serialize(&program.borrow().internal).unwrap();
+        bytes[0] = 9;
+        let error = program_type
+            .call_method1("from_bincode", (bytes,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert!(error
+            .value_bound(py)
+            .to_string()
+            .contains("Unknown QuantumProgram measurement type with variant index 9"));
+    })
+}
+
 /// Test to_ and from_json functions of QuantumProgram
 #[test]
 fn test_to_from_json() {
@@ -519,6 +678,43 @@ fn test_to_from_json() {
     })
 }
 
+/// Test from_json function of QuantumProgram in strict mode
+#[test]
+fn test_from_json_strict() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    };
+    let json = serde_json::to_string(&program).unwrap();
+    let nested = json.replace(r#""readout":"ro""#, r#""readout":"ro","readoutt":"ro""#);
+    Python::with_gil(|py| {
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let deserialised = program_type
+            .call_method1("from_json", (&json, true))
+            .unwrap();
+        let deserialised = deserialised.downcast::<QuantumProgramWrapper>().unwrap();
+        assert_eq!(deserialised.borrow().internal, program);
+
+        // Unknown fields are ignored by default
+        assert!(program_type
+            .call_method1("from_json", (&nested, false))
+            .is_ok());
+        let error = program_type
+            .call_method1("from_json", (&nested, true))
+            .unwrap_err();
+        assert!(error.to_string().contains(
+            "$.ClassicalRegister.measurement.circuits[0].operations[0].MeasureQubit.readoutt"
+        ));
+    })
+}
+
 /// Test json_schema function of QuantumProgram
 #[cfg(feature = "json_schema")]
 #[test]
@@ -669,7 +865,7 @@ fn test_return_measurement_paulizproduct() {
 
         let br_type = py.get_type_bound::<PauliZProductWrapper>();
         let binding = br_type
-            .call1((Some(CircuitWrapper::new()), circs, input))
+            .call1((Some(CircuitWrapper::new()), circs, input, false))
             .unwrap();
         let measurement_input = binding.downcast::<PauliZProductWrapper>().unwrap();
 
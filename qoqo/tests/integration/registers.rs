@@ -0,0 +1,157 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the counts dictionaries of bit registers
+
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pyfunction_bound;
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
+use std::collections::HashMap;
+use test_case::test_case;
+
+fn bit_registers() -> HashMap<String, BitOutputRegister> {
+    // Register of length 3, only qubits 0 and 1 were measured
+    let mut bit_registers = HashMap::new();
+    bit_registers.insert(
+        "ro".to_string(),
+        vec![
+            vec![true, false, false],
+            vec![true, false, false],
+            vec![true, true, false],
+        ],
+    );
+    bit_registers
+}
+
+fn counts_map(entries: &[(&str, usize)]) -> HashMap<String, usize> {
+    entries
+        .iter()
+        .map(|(bitstring, count)| (bitstring.to_string(), *count))
+        .collect()
+}
+
+/// Test counts_from_registers for both bit orders
+#[test_case("msb", &[("001", 2), ("011", 1)]; "msb")]
+#[test_case("lsb", &[("100", 2), ("110", 1)]; "lsb")]
+fn test_counts_from_registers(bit_order: &str, expected: &[(&str, usize)]) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let function = wrap_pyfunction_bound!(qoqo::registers::counts_from_registers, py).unwrap();
+        let counts: HashMap<String, usize> = function
+            .call1((bit_registers(), "ro", bit_order))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(counts, counts_map(expected));
+    })
+}
+
+/// Test counts_from_registers accepts the output of running a circuit and defaults to msb
+#[test]
+fn test_counts_from_run_output() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let function = wrap_pyfunction_bound!(qoqo::registers::counts_from_registers, py).unwrap();
+        let output = (
+            bit_registers(),
+            HashMap::<String, FloatOutputRegister>::new(),
+            HashMap::<String, ComplexOutputRegister>::new(),
+        );
+        let counts: HashMap<String, usize> =
+            function.call1((output, "ro")).unwrap().extract().unwrap();
+        assert_eq!(counts, counts_map(&[("001", 2), ("011", 1)]));
+
+        let error = function.call1((bit_registers(), "other")).unwrap_err();
+        assert!(error.is_instance_of::<PyKeyError>(py));
+        let error = function.call1((bit_registers(), "ro", "big")).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test register_from_counts inverts counts_from_registers
+#[test_case("msb"; "msb")]
+#[test_case("lsb"; "lsb")]
+fn test_register_from_counts(bit_order: &str) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let counts_function =
+            wrap_pyfunction_bound!(qoqo::registers::counts_from_registers, py).unwrap();
+        let register_function =
+            wrap_pyfunction_bound!(qoqo::registers::register_from_counts, py).unwrap();
+        let counts = counts_function
+            .call1((bit_registers(), "ro", bit_order))
+            .unwrap();
+        let register: BitOutputRegister = register_function
+            .call1((counts, 3, bit_order))
+            .unwrap()
+            .extract()
+            .unwrap();
+        let mut registers = HashMap::new();
+        registers.insert("ro".to_string(), register);
+        let roundtrip: HashMap<String, usize> = counts_function
+            .call1((registers, "ro", bit_order))
+            .unwrap()
+            .extract()
+            .unwrap();
+        let expected: HashMap<String, usize> = counts_function
+            .call1((bit_registers(), "ro", bit_order))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(roundtrip, expected);
+
+        let invalid = PyDict::new_bound(py);
+        invalid.set_item("0x", 1).unwrap();
+        let error = register_function.call1((invalid, 1)).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test registers_to_json and registers_from_json with values that are not finite
+#[test]
+fn test_registers_json() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let to_json = wrap_pyfunction_bound!(qoqo::registers::registers_to_json, py).unwrap();
+        let from_json = wrap_pyfunction_bound!(qoqo::registers::registers_from_json, py).unwrap();
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        float_registers.insert("ro_float".to_string(), vec![vec![1.0, f64::NAN]]);
+        let complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let registers = (bit_registers(), float_registers, complex_registers);
+
+        let error = to_json.call1((registers.clone(),)).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+        assert!(error
+            .to_string()
+            .contains("Register ro_float contains a value that is not finite in row 0 at index 1"));
+
+        let json: String = to_json
+            .call1((registers.clone(), true))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(json.contains(r#"{"ro_float":[[1.0,"NaN"]]}"#));
+        let deserialized: (
+            HashMap<String, BitOutputRegister>,
+            HashMap<String, FloatOutputRegister>,
+            HashMap<String, ComplexOutputRegister>,
+        ) = from_json.call1((json,)).unwrap().extract().unwrap();
+        assert_eq!(deserialized.0, registers.0);
+        assert!(deserialized.1["ro_float"][0][1].is_nan());
+        assert!(deserialized.2.is_empty());
+
+        let error = from_json.call1(("[1, 2]",)).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
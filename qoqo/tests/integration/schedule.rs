@@ -0,0 +1,164 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo::devices::AllToAllDeviceWrapper;
+use qoqo::{schedule_asap, CircuitWrapper, ScheduledCircuitWrapper};
+use roqoqo::devices::AllToAllDevice;
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Test scheduling a circuit and the methods of ScheduledCircuit
+#[test]
+fn test_schedule_asap() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += Hadamard::new(1);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    let device = AllToAllDevice::new(2, &["Hadamard".to_string()], &["CNOT".to_string()], 1.0)
+        .with_two_qubit_gate_time("CNOT", 2.0);
+    Python::with_gil(|py| {
+        let circuit_py = Bound::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        let device_py = Bound::new(
+            py,
+            AllToAllDeviceWrapper {
+                internal: device.clone(),
+            },
+        )
+        .unwrap();
+        let scheduled = Bound::new(
+            py,
+            schedule_asap(circuit_py.as_any(), device_py.as_any()).unwrap(),
+        )
+        .unwrap();
+        let expected = roqoqo::schedule::schedule_asap(&circuit, &device).unwrap();
+
+        let start_times: Vec<f64> = scheduled
+            .call_method0("start_times")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(start_times, vec![0.0, 0.0, 0.0, 1.0, 3.0]);
+        let total_duration: f64 = scheduled
+            .call_method0("total_duration")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(total_duration, 3.0);
+        let scheduled_circuit: CircuitWrapper = scheduled
+            .call_method0("circuit")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(scheduled_circuit.internal, circuit);
+        let assignment: Option<HashMap<usize, usize>> = scheduled
+            .call_method0("qubit_assignment")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(assignment, None);
+        assert_eq!(
+            scheduled
+                .extract::<ScheduledCircuitWrapper>()
+                .unwrap()
+                .internal,
+            expected
+        );
+
+        let json: String = scheduled
+            .call_method0("to_json")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let deserialized = ScheduledCircuitWrapper::from_json(&json).unwrap();
+        assert_eq!(deserialized.internal, expected);
+        let comparison: bool = scheduled
+            .call_method1("__eq__", (deserialized,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(comparison);
+
+        let error = schedule_asap(&0_usize.into_py(py).into_bound(py), device_py.as_any());
+        assert!(error.unwrap_err().is_instance_of::<PyTypeError>(py));
+
+        circuit += RotateX::new(0, 1.0.into());
+        let circuit_py = Bound::new(py, CircuitWrapper { internal: circuit }).unwrap();
+        let error = schedule_asap(circuit_py.as_any(), device_py.as_any());
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test the timeline export of ScheduledCircuit
+#[test]
+fn test_timeline() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += Hadamard::new(1);
+    circuit += CNOT::new(0, 1);
+    circuit += Hadamard::new(1);
+    let device = AllToAllDevice::new(2, &["Hadamard".to_string()], &["CNOT".to_string()], 1.0);
+    let scheduled = roqoqo::schedule::schedule_asap(&circuit, &device).unwrap();
+    Python::with_gil(|py| {
+        let scheduled_py = Bound::new(
+            py,
+            ScheduledCircuitWrapper {
+                internal: scheduled.clone(),
+            },
+        )
+        .unwrap();
+        let json: String = scheduled_py
+            .call_method0("to_timeline_json")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(json, scheduled.to_timeline_json());
+
+        let rows: HashMap<usize, Vec<HashMap<String, PyObject>>> = scheduled_py
+            .call_method0("per_qubit_timeline")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        let indices: Vec<usize> = rows[&1]
+            .iter()
+            .map(|entry| entry["index"].extract(py).unwrap())
+            .collect();
+        assert_eq!(indices, vec![1, 2, 3]);
+        let start: f64 = rows[&1][2]["start"].extract(py).unwrap();
+        assert_eq!(start, 2.0);
+
+        let mut overlapping = scheduled;
+        overlapping.start_times[3] = 1.5;
+        let overlapping_py = Bound::new(
+            py,
+            ScheduledCircuitWrapper {
+                internal: overlapping,
+            },
+        )
+        .unwrap();
+        let error = overlapping_py.call_method0("per_qubit_timeline");
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
@@ -0,0 +1,127 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use qoqo::noise_models::DecoherenceOnIdleModelWrapper;
+use qoqo::struqture_bridge::{noise_operator_from_python, noise_operator_to_python};
+use struqture::prelude::*;
+use struqture::spins::{PlusMinusLindbladNoiseOperator, PlusMinusProduct};
+use struqture_py::spins::PlusMinusLindbladNoiseOperatorWrapper;
+
+/// Stand-in for a struqture-py 2.x PlusMinusLindbladNoiseOperator
+#[pyclass]
+struct Struqture2NoiseOperator {
+    internal: struqture_2::spins::PlusMinusLindbladNoiseOperator,
+}
+
+#[pymethods]
+impl Struqture2NoiseOperator {
+    fn _get_serialisation_meta(&self) -> String {
+        let meta = struqture_2::SerializationSupport::struqture_serialisation_meta(&self.internal);
+        serde_json::to_string(&meta).unwrap()
+    }
+
+    fn to_bincode(&self) -> Vec<u8> {
+        bincode::serialize(&self.internal).unwrap()
+    }
+}
+
+fn create_operator() -> PlusMinusLindbladNoiseOperator {
+    let mut operator = PlusMinusLindbladNoiseOperator::new();
+    operator
+        .add_operator_product(
+            (PlusMinusProduct::new().z(0), PlusMinusProduct::new().z(0)),
+            0.5.into(),
+        )
+        .unwrap();
+    operator
+        .add_operator_product(
+            (
+                PlusMinusProduct::new().minus(1),
+                PlusMinusProduct::new().minus(1),
+            ),
+            0.1.into(),
+        )
+        .unwrap();
+    operator
+}
+
+/// Test that a noise operator survives the conversion to and from a struqture 1.x Python object
+#[test]
+fn test_round_trip() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operator = create_operator();
+        let python_operator = noise_operator_to_python(py, &operator).unwrap();
+        let wrapper = python_operator
+            .bind(py)
+            .extract::<PlusMinusLindbladNoiseOperatorWrapper>()
+            .unwrap();
+        assert_eq!(wrapper.internal, operator);
+        assert_eq!(
+            noise_operator_from_python(py, python_operator.bind(py)).unwrap(),
+            operator
+        );
+    })
+}
+
+/// Test that struqture 2.x objects are converted to struqture 1.x noise operators
+#[test]
+fn test_from_struqture_2() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut internal = struqture_2::spins::PlusMinusLindbladNoiseOperator::new();
+        struqture_2::OperateOnDensityMatrix::add_operator_product(
+            &mut internal,
+            (
+                struqture_2::spins::PlusMinusProduct::new().z(0),
+                struqture_2::spins::PlusMinusProduct::new().z(0),
+            ),
+            0.5.into(),
+        )
+        .unwrap();
+        struqture_2::OperateOnDensityMatrix::add_operator_product(
+            &mut internal,
+            (
+                struqture_2::spins::PlusMinusProduct::new().minus(1),
+                struqture_2::spins::PlusMinusProduct::new().minus(1),
+            ),
+            0.1.into(),
+        )
+        .unwrap();
+        let python_operator = Bound::new(py, Struqture2NoiseOperator { internal }).unwrap();
+        assert_eq!(
+            noise_operator_from_python(py, python_operator.as_any()).unwrap(),
+            create_operator()
+        );
+
+        // The noise models accept struqture 2.x objects through the bridge
+        let model = DecoherenceOnIdleModelWrapper::new(Some(python_operator.as_any())).unwrap();
+        let model_operator = model.get_noise_operator();
+        assert_eq!(model_operator.internal, create_operator());
+    })
+}
+
+/// Test that inputs that are not noise operators raise a ValueError instead of panicking
+#[test]
+fn test_from_python_error() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input = 3_usize.into_py(py);
+        let error = noise_operator_from_python(py, input.bind(py)).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+
+        let error = DecoherenceOnIdleModelWrapper::new(Some(input.bind(py))).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
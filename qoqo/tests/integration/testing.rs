@@ -0,0 +1,235 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo::measurements::PauliZProductWrapper;
+use qoqo::testing::{
+    circuits_equivalent, random_circuit, random_clifford_circuit, random_pauli_measurement_input,
+    MockSamplingBackendWrapper,
+};
+use qoqo::CircuitWrapper;
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::operations::*;
+use roqoqo::registers::Registers;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+/// Test random_circuit against the roqoqo function
+#[test]
+fn test_random_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let gates = vec!["RotateX".to_string(), "CNOT".to_string()];
+        let circuit = random_circuit(4, 5, 3, gates.clone()).unwrap();
+        let expected = roqoqo::testing::random_circuit(4, 5, 3, &["RotateX", "CNOT"]).unwrap();
+        assert_eq!(circuit.internal, expected);
+        assert_eq!(
+            circuit.internal,
+            random_circuit(4, 5, 3, gates).unwrap().internal
+        );
+
+        let error = random_circuit(4, 5, 3, vec!["NotAGate".to_string()]).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test random_clifford_circuit and random_pauli_measurement_input against the roqoqo functions
+#[test]
+fn test_random_clifford_and_measurement_input() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = random_clifford_circuit(3, 4, 8);
+        assert_eq!(
+            circuit.internal,
+            roqoqo::testing::random_clifford_circuit(3, 4, 8)
+        );
+
+        let input = random_pauli_measurement_input(3, 5, 8).unwrap();
+        assert_eq!(
+            input.internal,
+            roqoqo::testing::random_pauli_measurement_input(3, 5, 8).unwrap()
+        );
+
+        let error = random_pauli_measurement_input(0, 5, 8).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test circuits_equivalent with symbolic parameters and its errors
+#[test]
+fn test_circuits_equivalent() {
+    pyo3::prepare_freethreaded_python();
+    let mut a = Circuit::new();
+    a += RotateX::new(0, "theta".into());
+    let mut b = Circuit::new();
+    b += Hadamard::new(0);
+    b += RotateZ::new(0, "theta".into());
+    b += Hadamard::new(0);
+    let mut c = Circuit::new();
+    c += RotateY::new(0, "theta".into());
+    let mut d = a.clone();
+    d += MeasureQubit::new(0, "ro".to_string(), 0);
+    Python::with_gil(|py| {
+        let [a, b, c, d] = [a, b, c, d].map(|circuit| {
+            Bound::new(py, CircuitWrapper { internal: circuit })
+                .unwrap()
+                .into_any()
+        });
+        assert!(circuits_equivalent(&a, &b, 5, 1, 1e-10).unwrap());
+        assert!(!circuits_equivalent(&a, &c, 5, 1, 1e-10).unwrap());
+
+        let error = circuits_equivalent(&a, &d, 5, 1, 1e-10).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+        let error =
+            circuits_equivalent(&a, &0_usize.into_py(py).into_bound(py), 5, 1, 1e-10).unwrap_err();
+        assert!(error.is_instance_of::<PyTypeError>(py));
+    })
+}
+
+fn sampled_circuit(angle: f64) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, angle.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    circuit
+}
+
+/// Test MockSamplingBackend sampling circuits and measurements
+#[test]
+fn test_mock_sampling_backend() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let fingerprint = MockSamplingBackendWrapper::fingerprint(
+            &CircuitWrapper {
+                internal: sampled_circuit(1.0),
+            }
+            .into_py(py)
+            .into_bound(py),
+        )
+        .unwrap();
+        let distributions = HashMap::from([
+            (fingerprint, vec![(vec![true, false], 1.0)]),
+            ("1".to_string(), vec![(vec![false, true], 1.0)]),
+        ]);
+        let backend = Py::new(
+            py,
+            MockSamplingBackendWrapper::new(distributions, 3).unwrap(),
+        )
+        .unwrap();
+        let backend = backend.bind(py);
+
+        let (bit_registers, _, _): Registers = backend
+            .call_method1(
+                "run_circuit",
+                (CircuitWrapper {
+                    internal: sampled_circuit(1.0),
+                },),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(bit_registers["ro"], vec![vec![true, false]; 10]);
+
+        let mut input = PauliZProductInput::new(2, false);
+        let index = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+        input
+            .add_linear_exp_val("z0".to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+        let measurement = PauliZProductWrapper {
+            internal: PauliZProduct {
+                constant_circuit: None,
+                circuits: vec![sampled_circuit(0.5)],
+                input,
+            },
+        };
+        let result: HashMap<String, f64> = backend
+            .call_method1("run_measurement", (measurement.clone(),))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(result["z0"], 1.0);
+
+        // No distribution is configured for the third run
+        let error = backend
+            .call_method1("run_measurement_registers", (measurement,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyRuntimeError>(py));
+
+        let error = MockSamplingBackendWrapper::new(
+            HashMap::from([("0".to_string(), vec![(vec![true], -1.0)])]),
+            0,
+        )
+        .unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Circuit measuring with a PragmaRepeatedMeasurement whose qubit mapping is built in the given order
+fn repeated_measurement_circuit(qubits: impl Iterator<Item = usize>) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 16, true);
+    circuit += PragmaRepeatedMeasurement::new(
+        "ro".to_string(),
+        10,
+        Some(qubits.map(|qubit| (qubit, 15 - qubit)).collect()),
+    );
+    circuit
+}
+
+/// Test looking up the distribution of a circuit with a qubit mapping built twice
+#[test]
+fn test_mock_sampling_backend_repeated_measurement() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let fingerprint = MockSamplingBackendWrapper::fingerprint(
+            &CircuitWrapper {
+                internal: repeated_measurement_circuit(0..16),
+            }
+            .into_py(py)
+            .into_bound(py),
+        )
+        .unwrap();
+        assert_eq!(
+            fingerprint,
+            roqoqo::backends::circuit_fingerprint(repeated_measurement_circuit(0..16).iter())
+                .to_string()
+        );
+        let mut outcome = vec![false; 16];
+        outcome[3] = true;
+        let backend = Py::new(
+            py,
+            MockSamplingBackendWrapper::new(
+                HashMap::from([(fingerprint, vec![(outcome, 1.0)])]),
+                0,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let (bit_registers, _, _): Registers = backend
+            .bind(py)
+            .call_method1(
+                "run_circuit",
+                (CircuitWrapper {
+                    internal: repeated_measurement_circuit((0..16).rev()),
+                },),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        let mut expected = vec![false; 16];
+        expected[12] = true;
+        assert_eq!(bit_registers["ro"], vec![expected; 10]);
+    })
+}
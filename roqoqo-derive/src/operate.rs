@@ -73,7 +73,7 @@ fn operate_qubits_enum(de: DataEnum, ident: Ident) -> TokenStream {
             }
         }
     });
-    let is_parametrized_quotes = variants_with_type.map(|(vident, _, _)| {
+    let is_parametrized_quotes = variants_with_type.clone().map(|(vident, _, _)| {
         quote! {
             &#ident::#vident(ref inner) => {Operate::is_parametrized(&(*inner))},
         }
@@ -105,6 +105,32 @@ fn operate_qubits_enum(de: DataEnum, ident: Ident) -> TokenStream {
             }
         }
     };
+    let arbitrary_quote = if cfg!(feature = "arbitrary") {
+        let number_variants = variants_with_type.clone().count();
+        let arbitrary_match_quotes =
+            variants_with_type
+                .clone()
+                .enumerate()
+                .map(|(index, (vident, _, _))| {
+                    quote! {
+                        #index => Ok(#ident::#vident(::arbitrary::Arbitrary::arbitrary(u)?)),
+                    }
+                });
+        quote! {
+            #[automatically_derived]
+            /// Implements [arbitrary::Arbitrary] choosing each variant with the same probability.
+            impl<'a> ::arbitrary::Arbitrary<'a> for #ident{
+                fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                    match u.choose_index(#number_variants)? {
+                        #(#arbitrary_match_quotes)*
+                        _ => Err(::arbitrary::Error::IncorrectFormat),
+                    }
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     quote! {
         #[automatically_derived]
         #[cfg_attr(feature = "dynamic", typetag::serde)]
@@ -116,6 +142,7 @@ fn operate_qubits_enum(de: DataEnum, ident: Ident) -> TokenStream {
         }
         #(#from_quotes)*
         #(#try_from_quotes)*
+        #arbitrary_quote
     }
 }
 
@@ -189,6 +216,17 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             .chain(struqture_fields)
             .collect()
     };
+    let arbitrary_quote = if cfg!(feature = "arbitrary") {
+        arbitrary_struct_quote(
+            &ident,
+            fields_with_type
+                .clone()
+                .map(|(id, type_string, _)| (id, type_string))
+                .collect(),
+        )
+    } else {
+        TokenStream::new()
+    };
     let getter_fields = fields_with_type
         .filter(|(id, _, _)| {
             // let id = &field.ident.clone().expect("Struct fields must be named");
@@ -241,6 +279,76 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             }
         }
 
+        #arbitrary_quote
+    }
+}
+
+/// Field names holding a single qubit, filled with distinct qubits in arbitrary Operations
+const QUBIT_FIELDS: &[&str] = &[
+    "qubit",
+    "control",
+    "control_0",
+    "control_1",
+    "control_2",
+    "target",
+    "target_0",
+    "target_1",
+    "controlling_qubit",
+];
+
+/// Field names holding a single mode, filled with distinct modes in arbitrary Operations
+const MODE_FIELDS: &[&str] = &["mode", "mode_0", "mode_1"];
+
+/// Generates the TokenStream of the implementation of arbitrary::Arbitrary for structs
+///
+/// Qubit and mode fields are filled with distinct indices and qubit mappings never map two qubits
+/// to the same readout index, all other fields use the ArbitraryField trait of roqoqo.
+fn arbitrary_struct_quote(ident: &Ident, fields: Vec<(Ident, Option<String>)>) -> TokenStream {
+    let qubit_fields: Vec<&Ident> = fields
+        .iter()
+        .map(|(id, _)| id)
+        .filter(|id| QUBIT_FIELDS.contains(&id.to_string().as_str()))
+        .collect();
+    let mode_fields: Vec<&Ident> = fields
+        .iter()
+        .map(|(id, _)| id)
+        .filter(|id| MODE_FIELDS.contains(&id.to_string().as_str()))
+        .collect();
+    let number_qubits = qubit_fields.len();
+    let number_modes = mode_fields.len();
+    let field_quotes = fields.iter().map(|(id, type_string)| {
+        if let Some(index) = qubit_fields.iter().position(|qid| *qid == id) {
+            quote! {#id: qubit_indices[#index]}
+        } else if let Some(index) = mode_fields.iter().position(|mid| *mid == id) {
+            quote! {#id: mode_indices[#index]}
+        } else if id == "qubits" && type_string.as_deref() == Some("Vec") {
+            quote! {#id: crate::operations::arbitrary_fields::arbitrary_qubits(u)?}
+        } else if id == "qubit_mapping" {
+            quote! {#id: crate::operations::arbitrary_fields::arbitrary_qubit_mapping(u)?}
+        } else {
+            quote! {#id: crate::operations::arbitrary_fields::ArbitraryField::arbitrary_field(u)?}
+        }
+    });
+    let qubit_indices_quote = if number_qubits > 0 {
+        quote! {let qubit_indices = crate::operations::arbitrary_fields::distinct_indices(u, #number_qubits)?;}
+    } else {
+        TokenStream::new()
+    };
+    let mode_indices_quote = if number_modes > 0 {
+        quote! {let mode_indices = crate::operations::arbitrary_fields::distinct_indices(u, #number_modes)?;}
+    } else {
+        TokenStream::new()
+    };
+    quote! {
+        #[automatically_derived]
+        /// Implements [arbitrary::Arbitrary] with small qubit indices and bounded parameters.
+        impl<'a> ::arbitrary::Arbitrary<'a> for #ident{
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                #qubit_indices_quote
+                #mode_indices_quote
+                Ok(Self{#(#field_quotes),*})
+            }
+        }
     }
 }
 
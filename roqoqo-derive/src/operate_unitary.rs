@@ -238,11 +238,17 @@ pub fn dispatch_struct_enum_three_qubit_gate(input: DeriveInput) -> TokenStream
 
 fn three_qubit_gate_enum(de: DataEnum, ident: Ident) -> TokenStream {
     let variants_with_type = extract_variants_with_types(de).into_iter();
-    let match_quotes = variants_with_type.map(|(vident, _, _)| {
+    let variants: Vec<_> = variants_with_type.map(|(vident, _, _)| vident).collect();
+    let match_quotes = variants.iter().map(|vident| {
         quote! {
             &#ident::#vident(ref inner) => {OperateThreeQubitGate::circuit(&(*inner))},
         }
     });
+    let match_basis_quotes = variants.iter().map(|vident| {
+        quote! {
+            &#ident::#vident(ref inner) => {OperateThreeQubitGate::circuit_with_basis(&(*inner), basis)},
+        }
+    });
     quote! {
         #[automatically_derived]
         impl OperateThreeQubitGate for #ident{
@@ -252,6 +258,12 @@ fn three_qubit_gate_enum(de: DataEnum, ident: Ident) -> TokenStream {
                     _ => panic!("Unexpectedly cannot match variant")
                 }
             }
+            fn circuit_with_basis(&self, basis: crate::operations::TwoQubitBasis) -> crate::Circuit {
+                match self{
+                    #(#match_basis_quotes)*
+                    _ => panic!("Unexpectedly cannot match variant")
+                }
+            }
         }
     }
 }
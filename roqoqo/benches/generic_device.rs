@@ -0,0 +1,102 @@
+// Copyright © 2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks of the gate time and edge queries of the GenericDevice.
+//!
+//! Run with `cargo bench -p roqoqo --bench generic_device`. Comparing against an earlier state uses
+//! criterion baselines: `-- --save-baseline before` on the old and `-- --baseline before` on the new code.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use roqoqo::devices::{Device, GenericDevice};
+
+const NUMBER_QUBITS: usize = 20;
+const NUMBER_QUERIES: usize = 1_000_000;
+
+/// Creates a device with CNOT and ControlledPauliZ on a linear chain and RotateX on all qubits.
+fn create_device() -> GenericDevice {
+    let mut device = GenericDevice::new(NUMBER_QUBITS);
+    for qubit in 0..NUMBER_QUBITS {
+        device
+            .set_single_qubit_gate_time("RotateX", qubit, 1.0)
+            .unwrap();
+    }
+    for qubit in 0..NUMBER_QUBITS - 1 {
+        device
+            .set_two_qubit_gate_time("CNOT", qubit, qubit + 1, 2.0)
+            .unwrap();
+        device
+            .set_two_qubit_gate_time("ControlledPauliZ", qubit + 1, qubit, 2.0)
+            .unwrap();
+        device
+            .set_two_qubit_gate_time("SWAP", qubit, qubit + 1, 3.0)
+            .unwrap();
+    }
+    for qubit in 0..NUMBER_QUBITS - 2 {
+        device
+            .set_three_qubit_gate_time("Toffoli", qubit, qubit + 1, qubit + 2, 4.0)
+            .unwrap();
+    }
+    device
+}
+
+fn two_qubit_gate_time(c: &mut Criterion) {
+    let device = create_device();
+    c.bench_function("two_qubit_gate_time 10^6 queries", |b| {
+        b.iter(|| {
+            let mut total = 0.0;
+            for index in 0..NUMBER_QUERIES {
+                let control = index % NUMBER_QUBITS;
+                let target = (index + 1) % NUMBER_QUBITS;
+                total += device
+                    .two_qubit_gate_time(black_box("CNOT"), &control, &target)
+                    .unwrap_or(0.0);
+            }
+            total
+        })
+    });
+}
+
+fn three_qubit_gate_time(c: &mut Criterion) {
+    let device = create_device();
+    c.bench_function("three_qubit_gate_time 10^6 queries", |b| {
+        b.iter(|| {
+            let mut total = 0.0;
+            for index in 0..NUMBER_QUERIES {
+                let control_0 = index % NUMBER_QUBITS;
+                total += device
+                    .three_qubit_gate_time(
+                        black_box("Toffoli"),
+                        &control_0,
+                        &((control_0 + 1) % NUMBER_QUBITS),
+                        &((control_0 + 2) % NUMBER_QUBITS),
+                    )
+                    .unwrap_or(0.0);
+            }
+            total
+        })
+    });
+}
+
+fn two_qubit_edges(c: &mut Criterion) {
+    let device = create_device();
+    c.bench_function("two_qubit_edges", |b| {
+        b.iter(|| black_box(&device).two_qubit_edges())
+    });
+}
+
+criterion_group!(
+    benches,
+    two_qubit_gate_time,
+    three_qubit_gate_time,
+    two_qubit_edges
+);
+criterion_main!(benches);
@@ -22,7 +22,7 @@ use syn::punctuated::Punctuated;
 use syn::visit::{self, Visit};
 use syn::{AttrStyle, File, Ident, ItemImpl, ItemStruct, LitStr, Path, Token, Type, TypePath};
 
-const NUMBER_OF_MINOR_VERSIONS: usize = 18;
+const NUMBER_OF_MINOR_VERSIONS: usize = 19;
 
 static AVAILABLE_GATES: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
 
@@ -426,6 +426,9 @@ impl<'ast> Visit<'ast> for Visitor {
                 if trait_name.as_str() == "ImplementedIn1point17" {
                     self.roqoqo_version_register.insert(id.clone(), 17);
                 }
+                if trait_name.as_str() == "ImplementedIn1point18" {
+                    self.roqoqo_version_register.insert(id.clone(), 18);
+                }
                 if trait_name.as_str() == "OperateSingleQubitGate" {
                     self.single_qubit_gate_operations.push(id.clone());
                 }
@@ -511,6 +514,24 @@ fn main() {
         operations_quotes.extend(res)
     }
 
+    // Construct TokenStreams for the match arms of the hqslang-tagged json registry
+    let mut tagged_json_quotes: Vec<proc_macro2::TokenStream> = Vec::new();
+    for i in 0..NUMBER_OF_MINOR_VERSIONS {
+        let res: Vec<proc_macro2::TokenStream> = vis
+            .operations
+            .clone()
+            .into_iter()
+            .filter(|v| vis.filter_for_version(v, i))
+            .map(|v| {
+                let hqslang = v.to_string();
+                quote! {
+                    #hqslang => Some(serde_json::from_value::<#v>(fields).map(Operation::from))
+                }
+            })
+            .collect();
+        tagged_json_quotes.extend(res)
+    }
+
     // Construct TokenStreams for variants of operation enum
     let mut single_qubit_operations_quotes: Vec<proc_macro2::TokenStream> = Vec::new();
     for i in 0..NUMBER_OF_MINOR_VERSIONS {
@@ -704,6 +725,20 @@ fn main() {
         /// List of hqslang of all available gates
         pub const AVAILABLE_GATES_HQSLANG: [&str; #available_gates_length] = [#(#available_gates),*];
 
+        /// Deserializes the fields of the Operation with the given hqslang name from a json value.
+        ///
+        /// Registry used by [Operation::from_tagged_json], returns `None` if no Operation with the hqslang name exists.
+        #[cfg(feature = "serialize")]
+        pub(crate) fn operation_from_tagged_json_fields(
+            hqslang: &str,
+            fields: serde_json::Value,
+        ) -> Option<Result<Operation, serde_json::Error>> {
+            match hqslang {
+                #(#tagged_json_quotes),* ,
+                _ => None,
+            }
+        }
+
         /// Enum of all Operations implementing [Operate]
         #[derive(Debug, Clone, PartialEq, InvolveQubits, Operate, Substitute, SupportedVersion)]
         #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,78 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions acting on the analog operations of roqoqo Circuits.
+
+use struqture::spins::SpinHamiltonian;
+use struqture::OperateOnDensityMatrix;
+
+use crate::operations::{GateOperation, Operate, Operation};
+use crate::{Circuit, RoqoqoError};
+
+/// Returns the effective Hamiltonian of the analog block of a circuit.
+///
+/// The effective Hamiltonian is the sum of the Hamiltonians of all [crate::operations::ApplyConstantSpinHamiltonian]
+/// operations, each weighted by its evolution time.
+/// The time evolution of the circuit is given by exp(-i H) with the effective Hamiltonian H
+/// as long as the Hamiltonians of the analog operations commute.
+/// Gate operations before the first or after the last analog operation are ignored,
+/// all other operations (e.g. PRAGMAs and definitions) are ignored as well.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit containing the analog block.
+///
+/// # Returns
+///
+/// * `Ok(SpinHamiltonian)` - The effective Hamiltonian, empty if the circuit contains no analog operations.
+/// * `Err(RoqoqoError::GenericError)` - A gate operation is interleaved with the analog operations.
+/// * `Err(RoqoqoError::GenericError)` - The circuit contains an ApplyTimeDependentSpinHamiltonian.
+/// * `Err(RoqoqoError::StruqtureError)` - The Hamiltonians could not be added.
+pub fn effective_hamiltonian(circuit: &Circuit) -> Result<SpinHamiltonian, RoqoqoError> {
+    let mut hamiltonian = SpinHamiltonian::new();
+    let mut analog_block_started = false;
+    let mut interleaved_gate: Option<&str> = None;
+    for operation in circuit.iter() {
+        match operation {
+            Operation::ApplyConstantSpinHamiltonian(op) => {
+                if let Some(hqslang) = interleaved_gate {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "Gate operation {} is interleaved with the analog operations",
+                            hqslang
+                        ),
+                    });
+                }
+                analog_block_started = true;
+                for (pauli_product, value) in op.hamiltonian().iter() {
+                    hamiltonian
+                        .add_operator_product(pauli_product.clone(), value.clone() * op.time())?;
+                }
+            }
+            Operation::ApplyTimeDependentSpinHamiltonian(_) => {
+                return Err(RoqoqoError::GenericError {
+                    msg: "ApplyTimeDependentSpinHamiltonian has no constant effective Hamiltonian"
+                        .to_string(),
+                });
+            }
+            _ => {
+                if analog_block_started
+                    && interleaved_gate.is_none()
+                    && GateOperation::try_from(operation).is_ok()
+                {
+                    interleaved_gate = Some(operation.hqslang());
+                }
+            }
+        }
+    }
+    Ok(hamiltonian)
+}
@@ -27,19 +27,37 @@
 //!
 //! Other backends:
 //! * `qasm` ( <https://github.com/HQSquantumsimulations/qoqo_qasm> ).
+//!
+//! [CachingBackend] wraps any [EvaluatingBackend] and memoizes the results of repeated circuits.
+//! [MockSamplingBackend] samples measurement outcomes from configured probability tables for tests.
 
+use std::borrow::Borrow;
 use std::collections::HashMap;
+#[cfg(feature = "serialize")]
+use std::collections::{BTreeMap, VecDeque};
+#[cfg(feature = "serialize")]
+use std::sync::Mutex;
 
-use crate::operations::Operation;
+use crate::operations::{
+    Define, DefinitionBit, DefinitionComplex, DefinitionFloat, Operation, PragmaSnapshotRegisters,
+};
+#[cfg(feature = "serialize")]
+use crate::operations::{Operate, OperateSingleQubit};
 use crate::registers::Registers;
 use crate::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use crate::Circuit;
 use crate::{
     measurements::{Measure, MeasureExpectationValues},
-    RoqoqoBackendError,
+    RoqoqoBackendError, RoqoqoError,
 };
 #[cfg(feature = "async")]
 use async_trait::async_trait;
+#[cfg(feature = "serialize")]
+use rand::distributions::{Distribution, WeightedIndex};
+#[cfg(feature = "serialize")]
+use rand::rngs::StdRng;
+#[cfg(feature = "serialize")]
+use rand::SeedableRng;
 
 /// Result of functions running a full circuit and producing output registers.
 pub type RegisterResult = Result<Registers, RoqoqoBackendError>;
@@ -64,6 +82,10 @@ pub trait EvaluatingBackend: Sized {
     /// # Returns
     ///
     /// `RegisterResult` - The output registers written by the evaluated circuits.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "info", skip_all, fields(number_operations = circuit.len()))
+    )]
     fn run_circuit(&self, circuit: &Circuit) -> RegisterResult {
         self.run_circuit_iterator(circuit.iter())
     }
@@ -91,6 +113,67 @@ pub trait EvaluatingBackend: Sized {
         circuit: impl Iterator<Item = &'a Operation>,
     ) -> RegisterResult;
 
+    /// Runs a circuit containing [crate::operations::PragmaSnapshotRegisters] on a backend without native support.
+    ///
+    /// The circuit is split at every snapshot PRAGMA. For each snapshot the part of the circuit
+    /// before the PRAGMA is run, with the definitions of the snapshot registers turned into output registers,
+    /// and the output registers in `readout_names` are returned under the snapshot names `"{label}_{name}"`.
+    /// The circuit without the snapshot PRAGMAs is run for the remaining registers.
+    /// As every snapshot is taken in a separate run, the snapshots of stochastic backends
+    /// are samples independent of the final registers.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit that is run on the backend.
+    ///
+    /// # Returns
+    ///
+    /// `RegisterResult` - The output registers of the circuit together with the snapshot registers.
+    fn run_circuit_with_snapshots(&self, circuit: &Circuit) -> RegisterResult {
+        let mut snapshot_bit: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut snapshot_float: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut snapshot_complex: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let mut prefix: Vec<&Operation> = Vec::new();
+        for operation in circuit.iter() {
+            let snapshot = match operation {
+                Operation::PragmaSnapshotRegisters(snapshot) => snapshot,
+                _ => {
+                    prefix.push(operation);
+                    continue;
+                }
+            };
+            let prefix_operations: Vec<Operation> = prefix
+                .iter()
+                .map(|operation| output_definition(operation, snapshot))
+                .collect();
+            let (mut bit, mut float, mut complex) =
+                self.run_circuit_iterator(prefix_operations.iter())?;
+            for name in snapshot.readout_names() {
+                let snapshot_name = snapshot.snapshot_name(name);
+                if let Some(register) = bit.remove(name) {
+                    snapshot_bit.insert(snapshot_name, register);
+                } else if let Some(register) = float.remove(name) {
+                    snapshot_float.insert(snapshot_name, register);
+                } else if let Some(register) = complex.remove(name) {
+                    snapshot_complex.insert(snapshot_name, register);
+                } else {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Register {} of snapshot {} is not an output register before the snapshot",
+                            name,
+                            snapshot.label()
+                        ),
+                    });
+                }
+            }
+        }
+        let (mut bit, mut float, mut complex) = self.run_circuit_iterator(prefix.into_iter())?;
+        bit.extend(snapshot_bit);
+        float.extend(snapshot_float);
+        complex.extend(snapshot_complex);
+        Ok((bit, float, complex))
+    }
+
     /// Runs all circuits corresponding to one measurement with the backend.
     ///
     /// An expectation value measurement in general involves several circuits.
@@ -103,6 +186,8 @@ pub trait EvaluatingBackend: Sized {
     /// ([crate::registers::BitOutputRegister], [crate::registers::FloatOutputRegister] and [crate::registers::ComplexOutputRegister]).  
     /// At the end all OutputRegisters are combined in a single HashMap for each type of register.
     ///
+    /// The provided implementation runs the circuits with [EvaluatingBackend::run_circuits_registers].
+    ///
     /// # Arguments
     ///
     /// * `measurement` - The measurement that is run on the backend.
@@ -110,20 +195,74 @@ pub trait EvaluatingBackend: Sized {
     /// # Returns
     ///
     /// `RegisterResult` - The output registers written by the evaluated measurement circuits.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "info",
+            skip_all,
+            fields(number_circuits = measurement.circuits().count())
+        )
+    )]
     fn run_measurement_registers<T>(&self, measurement: &T) -> RegisterResult
     where
         T: Measure,
+    {
+        self.run_circuits_registers(
+            measurement.constant_circuit().as_ref(),
+            measurement.circuits().map(Ok),
+        )
+    }
+
+    /// Runs circuits obtained from an iterator one after another with the backend.
+    ///
+    /// Each circuit is run after the constant circuit and the output registers of all runs are
+    /// concatenated as in [EvaluatingBackend::run_measurement_registers].
+    /// A circuit is only requested from the iterator when it is run, so an iterator creating
+    /// the circuits lazily keeps a single circuit in memory at a time
+    /// (see [crate::QuantumProgram::substituted_circuits]).
+    ///
+    /// # Arguments
+    ///
+    /// * `constant_circuit` - The circuit that is executed before each circuit.
+    /// * `circuits` - The iterator over the circuits, an error stops the run.
+    ///
+    /// # Returns
+    ///
+    /// `RegisterResult` - The output registers written by the evaluated circuits.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip_all))]
+    fn run_circuits_registers<I, C>(
+        &self,
+        constant_circuit: Option<&Circuit>,
+        circuits: I,
+    ) -> RegisterResult
+    where
+        I: Iterator<Item = Result<C, RoqoqoError>>,
+        C: Borrow<Circuit>,
     {
         let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
         let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
         let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
 
-        for circuit in measurement.circuits() {
-            let (tmp_bit_reg, tmp_float_reg, tmp_complex_reg) = match measurement.constant_circuit()
-            {
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        for (circuit_index, circuit) in circuits.enumerate() {
+            let circuit = circuit?;
+            let circuit: &Circuit = circuit.borrow();
+            #[cfg(feature = "tracing")]
+            let span = tracing::info_span!(
+                "run_circuit_iterator",
+                circuit_index,
+                number_operations = constant_circuit.map_or(0, |x| x.len()) + circuit.len(),
+                elapsed_seconds = tracing::field::Empty,
+            )
+            .entered();
+            #[cfg(feature = "tracing")]
+            let start = std::time::Instant::now();
+            let (tmp_bit_reg, tmp_float_reg, tmp_complex_reg) = match constant_circuit {
                 Some(x) => self.run_circuit_iterator(x.iter().chain(circuit.iter()))?,
                 None => self.run_circuit_iterator(circuit.iter())?,
             };
+            #[cfg(feature = "tracing")]
+            span.record("elapsed_seconds", start.elapsed().as_secs_f64());
 
             for (key, mut val) in tmp_bit_reg.into_iter() {
                 if let Some(x) = bit_registers.get_mut(&key) {
@@ -149,6 +288,7 @@ pub trait EvaluatingBackend: Sized {
         }
         Ok((bit_registers, float_registers, complex_registers))
     }
+
     /// Evaluates expectation values of a measurement with the backend.
     ///
     /// # Arguments
@@ -159,6 +299,7 @@ pub trait EvaluatingBackend: Sized {
     ///
     /// `Ok(Option<HashMap<String, f64>>)` - The HashMap of measurement results.
     /// `Err(RoqoqoBackendError)` - The measurement run failed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip_all))]
     fn run_measurement<T>(
         &self,
         measurement: &T,
@@ -310,3 +451,543 @@ pub trait AsyncEvaluatingBackend: Sized {
         Ok(measurement.evaluate(bit_registers, float_registers, complex_registers)?)
     }
 }
+
+/// Returns the operation with the definition of a register copied by the snapshot turned into an output register.
+fn output_definition(operation: &Operation, snapshot: &PragmaSnapshotRegisters) -> Operation {
+    let copied = |name: &String| snapshot.readout_names().contains(name);
+    match operation {
+        Operation::DefinitionBit(op) if copied(op.name()) => {
+            DefinitionBit::new(op.name().clone(), *op.length(), true).into()
+        }
+        Operation::DefinitionFloat(op) if copied(op.name()) => {
+            DefinitionFloat::new(op.name().clone(), *op.length(), true).into()
+        }
+        Operation::DefinitionComplex(op) if copied(op.name()) => {
+            DefinitionComplex::new(op.name().clone(), *op.length(), true).into()
+        }
+        _ => (*operation).clone(),
+    }
+}
+
+/// Returns a fingerprint of a circuit given by an iterator over its operations.
+///
+/// The fingerprint is computed from the bincode serialization of every operation
+/// with a hash function using fixed keys. HashMap fields of operations are serialized sorted by key,
+/// so circuits with the same operations in the same order have the same fingerprint
+/// independent of how they were constructed, in every process and with every Rust version.
+/// Fingerprints can change between roqoqo versions and enabled features.
+/// Different circuits can share a fingerprint, users of the fingerprint
+/// that require equality have to compare the operations as well.
+///
+/// # Arguments
+///
+/// * `circuit` - The iterator over operations (corresponds to a circuit).
+///
+/// # Returns
+///
+/// `u64` - The fingerprint of the circuit.
+#[cfg(feature = "serialize")]
+pub fn circuit_fingerprint<'a>(circuit: impl Iterator<Item = &'a Operation>) -> u64 {
+    let mut hasher = FingerprintHasher::new();
+    for operation in circuit {
+        // Operations always serialize and writing into the hasher can not fail
+        let _ = bincode::serialize_into(&mut hasher, operation);
+    }
+    hasher.0
+}
+
+// 64-bit FNV-1a hash, fixed keys make the fingerprint reproducible.
+#[cfg(feature = "serialize")]
+struct FingerprintHasher(u64);
+
+#[cfg(feature = "serialize")]
+impl FingerprintHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl std::io::Write for FingerprintHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for byte in buf {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Hit and miss counts of a [CircuitCache].
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStatistics {
+    /// Number of lookups that returned cached registers.
+    pub hits: usize,
+    /// Number of lookups that did not find the circuit in the cache.
+    pub misses: usize,
+    /// Number of circuits currently stored in the cache.
+    pub size: usize,
+    /// Maximum number of circuits stored in the cache.
+    pub max_size: usize,
+}
+
+// A cached circuit with its output registers.
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    operations: Vec<Operation>,
+    registers: Registers,
+    // Time of the last use, matches the most recent record of the entry in the usage order
+    last_used: u64,
+}
+
+/// Least recently used cache of the output registers of circuits.
+///
+/// Circuits are looked up by their [circuit_fingerprint] and compared operation by operation,
+/// so fingerprint collisions never return the registers of a different circuit.
+/// When the cache is full the least recently used circuit is evicted.
+/// Used by [CachingBackend] and by language bindings wrapping backends of other languages.
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone)]
+pub struct CircuitCache {
+    max_size: usize,
+    entries: HashMap<u64, CacheEntry>,
+    // Fingerprints with the time of their use, ordered from least to most recent.
+    // Records of entries that have been used again later are outdated and skipped on eviction.
+    usage_order: VecDeque<(u64, u64)>,
+    time: u64,
+    hits: usize,
+    misses: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl CircuitCache {
+    /// Creates a new empty CircuitCache.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_size` - The maximum number of cached circuits. A size of zero disables caching.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The new CircuitCache.
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            entries: HashMap::new(),
+            usage_order: VecDeque::new(),
+            time: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Looks up the output registers of a circuit.
+    ///
+    /// A successful lookup marks the circuit as most recently used and counts as a hit,
+    /// otherwise a miss is counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `operations` - The operations of the circuit.
+    ///
+    /// # Returns
+    ///
+    /// `Option<Registers>` - A copy of the cached registers if the circuit is in the cache.
+    pub fn get(&mut self, operations: &[Operation]) -> Option<Registers> {
+        let fingerprint = circuit_fingerprint(operations.iter());
+        match self.entries.get_mut(&fingerprint) {
+            Some(entry) if entry.operations.as_slice() == operations => {
+                self.hits += 1;
+                self.time += 1;
+                entry.last_used = self.time;
+                let registers = entry.registers.clone();
+                self.record_use(fingerprint);
+                Some(registers)
+            }
+            _ => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Stores the output registers of a circuit.
+    ///
+    /// Evicts the least recently used circuit when the cache is full.
+    /// A cached circuit with the same fingerprint is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `operations` - The operations of the circuit.
+    /// * `registers` - The output registers produced by running the circuit.
+    pub fn insert(&mut self, operations: Vec<Operation>, registers: Registers) {
+        if self.max_size == 0 {
+            return;
+        }
+        let fingerprint = circuit_fingerprint(operations.iter());
+        if !self.entries.contains_key(&fingerprint) {
+            while self.entries.len() >= self.max_size {
+                self.evict_least_recently_used();
+            }
+        }
+        self.time += 1;
+        self.entries.insert(
+            fingerprint,
+            CacheEntry {
+                operations,
+                registers,
+                last_used: self.time,
+            },
+        );
+        self.record_use(fingerprint);
+    }
+
+    /// Removes all circuits from the cache and resets the statistics.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.usage_order.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Returns the hit and miss counts of the cache.
+    ///
+    /// # Returns
+    ///
+    /// `CacheStatistics` - The statistics of the cache.
+    pub fn statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.entries.len(),
+            max_size: self.max_size,
+        }
+    }
+
+    // Appends the current use of an entry to the usage order.
+    // Outdated records are dropped once they make up more than half of the usage order.
+    fn record_use(&mut self, fingerprint: u64) {
+        self.usage_order.push_back((fingerprint, self.time));
+        if self.usage_order.len() > 2 * self.max_size {
+            let entries = &self.entries;
+            self.usage_order
+                .retain(|(fingerprint, time)| is_current_use(entries, fingerprint, time));
+        }
+    }
+
+    // Removes the entry whose last use is the oldest.
+    fn evict_least_recently_used(&mut self) {
+        while let Some((fingerprint, time)) = self.usage_order.pop_front() {
+            if is_current_use(&self.entries, &fingerprint, &time) {
+                self.entries.remove(&fingerprint);
+                return;
+            }
+        }
+    }
+}
+
+// Checks whether a record of the usage order is the last use of a cached entry.
+#[cfg(feature = "serialize")]
+fn is_current_use(entries: &HashMap<u64, CacheEntry>, fingerprint: &u64, time: &u64) -> bool {
+    entries
+        .get(fingerprint)
+        .is_some_and(|entry| entry.last_used == *time)
+}
+
+/// Backend wrapper memoizing the output registers of circuits run on another backend.
+///
+/// In variational algorithms different parameter sets often produce identical circuits
+/// after symbolic parameters have been substituted.
+/// CachingBackend runs every distinct circuit only once on the wrapped backend
+/// and returns copies of the stored registers when the circuit is run again.
+/// For stochastic backends repeated runs of a cached circuit therefore return identical samples.
+/// Failed runs are not cached.
+#[cfg(feature = "serialize")]
+#[derive(Debug)]
+pub struct CachingBackend<B: EvaluatingBackend> {
+    backend: B,
+    cache: Mutex<CircuitCache>,
+}
+
+#[cfg(feature = "serialize")]
+impl<B: EvaluatingBackend> CachingBackend<B> {
+    /// Creates a new CachingBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The backend running the circuits that are not in the cache.
+    /// * `max_size` - The maximum number of cached circuits. A size of zero disables caching.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The new CachingBackend.
+    pub fn new(backend: B, max_size: usize) -> Self {
+        Self {
+            backend,
+            cache: Mutex::new(CircuitCache::new(max_size)),
+        }
+    }
+
+    /// Returns the wrapped backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns the hit and miss counts of the cache.
+    ///
+    /// # Returns
+    ///
+    /// `CacheStatistics` - The statistics of the cache.
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        match self.cache.lock() {
+            Ok(cache) => cache.statistics(),
+            Err(poisoned) => poisoned.into_inner().statistics(),
+        }
+    }
+
+    /// Removes all circuits from the cache and resets the statistics.
+    pub fn clear_cache(&self) {
+        match self.cache.lock() {
+            Ok(mut cache) => cache.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<B: EvaluatingBackend> EvaluatingBackend for CachingBackend<B> {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let operations: Vec<Operation> = circuit.cloned().collect();
+        let cached = self
+            .cache
+            .lock()
+            .map_err(|_| RoqoqoBackendError::GenericError {
+                msg: "Circuit cache of CachingBackend is poisoned".to_string(),
+            })?
+            .get(&operations);
+        if let Some(registers) = cached {
+            return Ok(registers);
+        }
+        // The lock is not held while the wrapped backend runs the circuit
+        let registers = self.backend.run_circuit_iterator(operations.iter())?;
+        self.cache
+            .lock()
+            .map_err(|_| RoqoqoBackendError::GenericError {
+                msg: "Circuit cache of CachingBackend is poisoned".to_string(),
+            })?
+            .insert(operations, registers.clone());
+        Ok(registers)
+    }
+}
+
+/// Backend sampling the measurement outcomes of circuits from configured probability tables.
+///
+/// Intended for testing the post-processing of measurements without a simulator.
+/// The distribution of a circuit is looked up by its [circuit_fingerprint] written as a decimal number
+/// and, if no distribution is configured for the fingerprint, by the index of the run,
+/// counting all circuits run on the backend since its creation starting from zero.
+/// Fingerprints are reproducible, so the distributions of a test can be stored with its data.
+/// Each outcome of a distribution gives the measured value of every qubit.
+///
+/// Gates and other PRAGMAs are ignored. For every output bit register measured by
+/// [crate::operations::MeasureQubit] or [crate::operations::PragmaRepeatedMeasurement],
+/// `number_measurements` outcomes are sampled independently, as set by
+/// [crate::operations::PragmaSetNumberOfMeasurements] or [crate::operations::PragmaRepeatedMeasurement]
+/// and one otherwise. Output bit registers that are not measured and output float and complex registers
+/// are returned without entries.
+#[cfg(feature = "serialize")]
+#[derive(Debug)]
+pub struct MockSamplingBackend {
+    distributions: HashMap<String, (Vec<Vec<bool>>, WeightedIndex<f64>)>,
+    state: Mutex<MockSamplingState>,
+}
+
+// Mutable state of a MockSamplingBackend changed by each run.
+#[cfg(feature = "serialize")]
+#[derive(Debug)]
+struct MockSamplingState {
+    rng: StdRng,
+    circuit_index: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl MockSamplingBackend {
+    /// Creates a new MockSamplingBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `distributions` - The outcomes and their (not necessarily normalized) probabilities, by circuit fingerprint or run index.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new MockSamplingBackend.
+    /// * `Err(RoqoqoBackendError::GenericError)` - A distribution is empty or has negative, non-finite or only zero probabilities.
+    pub fn new(
+        distributions: HashMap<String, Vec<(Vec<bool>, f64)>>,
+        seed: u64,
+    ) -> Result<Self, RoqoqoBackendError> {
+        let distributions = distributions
+            .into_iter()
+            .map(|(key, distribution)| {
+                let (outcomes, probabilities): (Vec<Vec<bool>>, Vec<f64>) =
+                    distribution.into_iter().unzip();
+                let index = WeightedIndex::new(probabilities).map_err(|err| {
+                    RoqoqoBackendError::GenericError {
+                        msg: format!("Invalid probabilities in distribution {}: {}", key, err),
+                    }
+                })?;
+                Ok((key, (outcomes, index)))
+            })
+            .collect::<Result<_, RoqoqoBackendError>>()?;
+        Ok(Self {
+            distributions,
+            state: Mutex::new(MockSamplingState {
+                rng: StdRng::seed_from_u64(seed),
+                circuit_index: 0,
+            }),
+        })
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl EvaluatingBackend for MockSamplingBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let operations: Vec<&Operation> = circuit.collect();
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| RoqoqoBackendError::GenericError {
+                msg: "State of MockSamplingBackend is poisoned".to_string(),
+            })?;
+        let circuit_index = state.circuit_index;
+        state.circuit_index += 1;
+        let fingerprint = circuit_fingerprint(operations.iter().copied());
+        let (outcomes, index) = self
+            .distributions
+            .get(&fingerprint.to_string())
+            .or_else(|| self.distributions.get(&circuit_index.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "No distribution configured for circuit with fingerprint {} or index {}",
+                    fingerprint, circuit_index
+                ),
+            })?;
+
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        // Length of each bit register and whether it is an output register
+        let mut bit_definitions: HashMap<&String, (usize, bool)> = HashMap::new();
+        // Measured qubit and readout index for each register, sorted so that a seed gives the same samples
+        let mut measured: BTreeMap<&String, Vec<(usize, usize)>> = BTreeMap::new();
+        let mut number_measurements: HashMap<&String, usize> = HashMap::new();
+        for operation in operations {
+            match operation {
+                Operation::DefinitionBit(op) => {
+                    let _ = bit_definitions.insert(op.name(), (*op.length(), *op.is_output()));
+                }
+                Operation::DefinitionFloat(op) if *op.is_output() => {
+                    let _ = float_registers.insert(op.name().clone(), Vec::new());
+                }
+                Operation::DefinitionComplex(op) if *op.is_output() => {
+                    let _ = complex_registers.insert(op.name().clone(), Vec::new());
+                }
+                Operation::MeasureQubit(op) => {
+                    measured
+                        .entry(op.readout())
+                        .or_default()
+                        .push((*op.qubit(), *op.readout_index()));
+                }
+                Operation::PragmaSetNumberOfMeasurements(op) => {
+                    let _ = number_measurements.insert(op.readout(), *op.number_measurements());
+                }
+                Operation::PragmaRepeatedMeasurement(op) => {
+                    let length = bit_definitions
+                        .get(op.readout())
+                        .map_or(0, |(length, _)| *length);
+                    let mapping: Vec<(usize, usize)> = match op.qubit_mapping() {
+                        Some(mapping) => mapping.iter().map(|(q, i)| (*q, *i)).collect(),
+                        None => (0..length).map(|qubit| (qubit, qubit)).collect(),
+                    };
+                    measured.entry(op.readout()).or_default().extend(mapping);
+                    let _ = number_measurements.insert(op.readout(), *op.number_measurements());
+                }
+                Operation::PragmaGetStateVector(_)
+                | Operation::PragmaGetDensityMatrix(_)
+                | Operation::PragmaGetReducedDensityMatrix(_)
+                | Operation::PragmaGetOccupationProbability(_)
+                | Operation::PragmaGetPauliProduct(_)
+                | Operation::PragmaGetIndexedOccupationProbability(_)
+                | Operation::PragmaGetIndexedPauliProduct(_)
+                | Operation::PragmaSnapshotRegisters(_) => {
+                    return Err(RoqoqoBackendError::OperationNotInBackend {
+                        backend: "MockSamplingBackend",
+                        hqslang: operation.hqslang(),
+                    })
+                }
+                _ => (),
+            }
+        }
+
+        for (name, qubits) in measured {
+            let (length, is_output) = bit_definitions.get(name).copied().ok_or_else(|| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!("Measured bit register {} is not defined", name),
+                }
+            })?;
+            if !is_output {
+                continue;
+            }
+            let shots = number_measurements.get(name).copied().unwrap_or(1);
+            let mut register: BitOutputRegister = Vec::with_capacity(shots);
+            for _ in 0..shots {
+                let outcome = &outcomes[index.sample(&mut state.rng)];
+                let mut shot = vec![false; length];
+                for (qubit, readout_index) in qubits.iter() {
+                    let value =
+                        outcome
+                            .get(*qubit)
+                            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                                msg: format!(
+                                    "Qubit {} is not part of the configured outcome {:?}",
+                                    qubit, outcome
+                                ),
+                            })?;
+                    let entry = shot.get_mut(*readout_index).ok_or_else(|| {
+                        RoqoqoBackendError::GenericError {
+                            msg: format!(
+                                "Readout index {} out of range for bit register {} of length {}",
+                                readout_index, name, length
+                            ),
+                        }
+                    })?;
+                    *entry = *value;
+                }
+                register.push(shot);
+            }
+            let _ = bit_registers.insert(name.clone(), register);
+        }
+        for (name, (_, is_output)) in bit_definitions {
+            if is_output {
+                let _ = bit_registers.entry(name.clone()).or_default();
+            }
+        }
+        Ok((bit_registers, float_registers, complex_registers))
+    }
+}
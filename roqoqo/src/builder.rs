@@ -0,0 +1,300 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builder to construct roqoqo Circuits with chained method calls.
+//!
+//! # Example
+//!
+//! ```
+//! use roqoqo::Circuit;
+//!
+//! let circuit: Circuit = Circuit::builder()
+//!     .h(0)
+//!     .cnot(0, 1)
+//!     .rz(1, 0.3)
+//!     .rz_sym(0, "theta")
+//!     .measure_all("ro", 2)
+//!     .build();
+//! assert_eq!(circuit.len(), 7);
+//! ```
+//!
+
+use crate::operations::*;
+use crate::Circuit;
+use qoqo_calculator::CalculatorFloat;
+
+/// Builder for a [crate::Circuit] using chained method calls.
+///
+/// Every method appends the corresponding operation to the circuit under construction.
+/// Gate parameters accept anything that can be converted into a [CalculatorFloat].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CircuitBuilder {
+    circuit: Circuit,
+}
+
+impl CircuitBuilder {
+    /// Creates a new CircuitBuilder starting from an empty Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new CircuitBuilder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an arbitrary Operation to the circuit.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The Operation to add.
+    pub fn add_operation<T>(mut self, op: T) -> Self
+    where
+        T: Into<Operation>,
+    {
+        self.circuit.add_operation(op);
+        self
+    }
+
+    /// Adds a [Hadamard] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn h(self, qubit: usize) -> Self {
+        self.add_operation(Hadamard::new(qubit))
+    }
+
+    /// Adds a [PauliX] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn x(self, qubit: usize) -> Self {
+        self.add_operation(PauliX::new(qubit))
+    }
+
+    /// Adds a [PauliY] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn y(self, qubit: usize) -> Self {
+        self.add_operation(PauliY::new(qubit))
+    }
+
+    /// Adds a [PauliZ] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn z(self, qubit: usize) -> Self {
+        self.add_operation(PauliZ::new(qubit))
+    }
+
+    /// Adds an [SGate].
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn s(self, qubit: usize) -> Self {
+        self.add_operation(SGate::new(qubit))
+    }
+
+    /// Adds a [TGate].
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn t(self, qubit: usize) -> Self {
+        self.add_operation(TGate::new(qubit))
+    }
+
+    /// Adds a [SqrtPauliX] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    pub fn sqrt_x(self, qubit: usize) -> Self {
+        self.add_operation(SqrtPauliX::new(qubit))
+    }
+
+    /// Adds a [RotateX] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    /// * `theta` - The angle of the rotation.
+    pub fn rx(self, qubit: usize, theta: impl Into<CalculatorFloat>) -> Self {
+        self.add_operation(RotateX::new(qubit, theta.into()))
+    }
+
+    /// Adds a [RotateY] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    /// * `theta` - The angle of the rotation.
+    pub fn ry(self, qubit: usize, theta: impl Into<CalculatorFloat>) -> Self {
+        self.add_operation(RotateY::new(qubit, theta.into()))
+    }
+
+    /// Adds a [RotateZ] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    /// * `theta` - The angle of the rotation.
+    pub fn rz(self, qubit: usize, theta: impl Into<CalculatorFloat>) -> Self {
+        self.add_operation(RotateZ::new(qubit, theta.into()))
+    }
+
+    /// Adds a [RotateX] gate with a symbolic angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    /// * `symbol` - The symbolic expression of the angle.
+    pub fn rx_sym(self, qubit: usize, symbol: &str) -> Self {
+        self.rx(qubit, CalculatorFloat::from(symbol))
+    }
+
+    /// Adds a [RotateY] gate with a symbolic angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    /// * `symbol` - The symbolic expression of the angle.
+    pub fn ry_sym(self, qubit: usize, symbol: &str) -> Self {
+        self.ry(qubit, CalculatorFloat::from(symbol))
+    }
+
+    /// Adds a [RotateZ] gate with a symbolic angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate is applied to.
+    /// * `symbol` - The symbolic expression of the angle.
+    pub fn rz_sym(self, qubit: usize, symbol: &str) -> Self {
+        self.rz(qubit, CalculatorFloat::from(symbol))
+    }
+
+    /// Adds a [CNOT] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `control` - The control qubit.
+    /// * `target` - The target qubit.
+    pub fn cnot(self, control: usize, target: usize) -> Self {
+        self.add_operation(CNOT::new(control, target))
+    }
+
+    /// Adds a [ControlledPauliZ] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `control` - The control qubit.
+    /// * `target` - The target qubit.
+    pub fn cz(self, control: usize, target: usize) -> Self {
+        self.add_operation(ControlledPauliZ::new(control, target))
+    }
+
+    /// Adds a [ControlledPhaseShift] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `control` - The control qubit.
+    /// * `target` - The target qubit.
+    /// * `theta` - The phase applied to the |11> state.
+    pub fn cphase(self, control: usize, target: usize, theta: impl Into<CalculatorFloat>) -> Self {
+        self.add_operation(ControlledPhaseShift::new(control, target, theta.into()))
+    }
+
+    /// Adds a [SWAP] gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `control` - The first qubit.
+    /// * `target` - The second qubit.
+    pub fn swap(self, control: usize, target: usize) -> Self {
+        self.add_operation(SWAP::new(control, target))
+    }
+
+    /// Adds a [DefinitionBit] for a bit readout register.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the register.
+    /// * `length` - The length of the register.
+    /// * `is_output` - Whether the register is returned as an output.
+    pub fn bit_register(self, name: &str, length: usize, is_output: bool) -> Self {
+        self.add_operation(DefinitionBit::new(name.to_string(), length, is_output))
+    }
+
+    /// Adds a [DefinitionFloat] for a float readout register.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the register.
+    /// * `length` - The length of the register.
+    /// * `is_output` - Whether the register is returned as an output.
+    pub fn float_register(self, name: &str, length: usize, is_output: bool) -> Self {
+        self.add_operation(DefinitionFloat::new(name.to_string(), length, is_output))
+    }
+
+    /// Adds a [MeasureQubit] operation.
+    ///
+    /// The readout register needs to be defined separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The measured qubit.
+    /// * `readout` - The name of the readout register.
+    /// * `readout_index` - The index in the readout the result is saved to.
+    pub fn measure(self, qubit: usize, readout: &str, readout_index: usize) -> Self {
+        self.add_operation(MeasureQubit::new(qubit, readout.to_string(), readout_index))
+    }
+
+    /// Defines an output bit register and measures the first `number_qubits` qubits into it.
+    ///
+    /// Qubit `i` is measured into index `i` of the register.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout` - The name of the readout register.
+    /// * `number_qubits` - The number of measured qubits (and length of the register).
+    pub fn measure_all(self, readout: &str, number_qubits: usize) -> Self {
+        (0..number_qubits).fold(
+            self.bit_register(readout, number_qubits, true),
+            |builder, qubit| builder.measure(qubit, readout, qubit),
+        )
+    }
+
+    /// Returns the constructed Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit containing all added operations.
+    pub fn build(self) -> Circuit {
+        self.circuit
+    }
+}
+
+impl Circuit {
+    /// Creates a [CircuitBuilder] to construct a Circuit with chained method calls.
+    ///
+    /// # Returns
+    ///
+    /// * `CircuitBuilder` - The builder starting from an empty Circuit.
+    pub fn builder() -> CircuitBuilder {
+        CircuitBuilder::new()
+    }
+}
@@ -11,17 +11,23 @@
 // limitations under the License.
 
 use crate::operations::{
-    Define, InvolveQubits, InvolvedQubits, Operate, Operation, Substitute, SupportedVersion,
+    Define, Definition, DefinitionBit, DefinitionComplex, DefinitionFloat, DefinitionUsize,
+    InputBit, InvolveModes, InvolveQubits, InvolvedClassical, InvolvedModes, InvolvedQubits,
+    MeasureQubit, Operate, OperateSingleMode, OperateSingleQubit, Operation, PhotonDetection,
+    PragmaAnnotatedOp, PragmaAnnotation, PragmaConditional, PragmaConditionalLoop,
+    PragmaControlledCircuit, PragmaGetDensityMatrix, PragmaGetIndexedOccupationProbability,
+    PragmaGetIndexedPauliProduct, PragmaGetOccupationProbability, PragmaGetOperatorExpectation,
+    PragmaGetPauliProduct, PragmaGetReducedDensityMatrix, PragmaGetStateVector, PragmaGlobalPhase,
+    PragmaLoop, PragmaRepeatedMeasurement, PragmaSetNumberOfMeasurements, PragmaSimulateShotNoise,
+    PragmaSnapshotRegisters, Substitute, SupportedVersion,
 };
-#[cfg(feature = "overrotate")]
 use crate::operations::{Rotate, Rotation};
 use crate::RoqoqoError;
 use crate::RoqoqoVersion;
 #[cfg(feature = "serialize")]
 use crate::RoqoqoVersionSerializable;
-use qoqo_calculator::Calculator;
-use std::collections::{HashMap, HashSet};
-#[cfg(feature = "overrotate")]
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::convert::TryFrom;
 use std::ops;
 use std::{
@@ -69,19 +75,27 @@ use std::{
 /// * `len()`: returns the length of the Circuit
 /// * `is_empty()`: returns a boolean of whether the Circuit contains any definitions and operations or not
 /// * `involved_qubits()`: returns the qubits invovlved in the whole Circuit
+/// * `involved_modes()`: returns the bosonic modes involved in the whole Circuit
+/// * `number_of_modes()`: returns the number of bosonic modes needed to run the Circuit
 /// * `definitions()`: returns the definitions in the Circuit
 /// * `operations()`: returns the operations in the Circuit
 /// * `substitute_parameters(calculator)`: substitutes any symbolic parameters in (a copy of) the Circuit according to the specified Calculator
 /// * `remap_qubits(mapping)`: remaps the qubits in (a copy of) the Circuit according to the specified mapping
+/// * `used_qubits()`: returns the sorted qubits the operations of the Circuit act on
+/// * `compactify_qubits()`: renumbers the used qubits of (a copy of) the Circuit to 0..n
+/// * `remap_registers(mapping)`: renames the classical registers in (a copy of) the Circuit according to the specified mapping
+/// * `tensor(other, qubit_offset)`: appends another Circuit with its qubits shifted by the offset
+/// * `compose(other)`: appends another Circuit acting on the same qubits, deduplicating definitions
 /// * `count_occurences(operations)`: returns the number of operations in the Circuit with the specified operation tags
 /// * `get_operation_types()`: returns a list of all of the operations in the Circuit (in hqslang)
+/// * `semantically_equal(other, options)`: compares the Circuit to another Circuit up to harmless differences
 /// * `from_iter(iterator)`: creates a Circuit from the items in the specified iterator
 /// * `extend(iterator)`: adds the operations in the specified iterator to the Circuit
 /// * `default()`: creates an empty Circuit
 /// * `[...]`: gets a slice of the Circuit (returned as a vector)
 /// * `+` and `+=`: add two circuits or an operation to the Circuit
 ///
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Clone)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serialize", serde(try_from = "CircuitSerializable"))]
@@ -93,6 +107,29 @@ pub struct Circuit {
     operations: Vec<Operation>,
     /// The roqoqo version.
     _roqoqo_version: RoqoqoVersion,
+    /// False if no operation in the Circuit is parametrized.
+    ///
+    /// Maintained when operations are added, set to true when an operation is accessed mutably.
+    #[cfg_attr(feature = "json_schema", schemars(skip))]
+    maybe_parametrized: bool,
+}
+
+/// The cached parametrization flag is not part of the equality of two Circuits.
+impl PartialEq for Circuit {
+    fn eq(&self, other: &Self) -> bool {
+        self.definitions == other.definitions && self.operations == other.operations
+    }
+}
+
+/// The cached parametrization flag is not part of the debug output of the Circuit.
+impl std::fmt::Debug for Circuit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Circuit")
+            .field("definitions", &self.definitions)
+            .field("operations", &self.operations)
+            .field("_roqoqo_version", &self._roqoqo_version)
+            .finish()
+    }
 }
 
 #[cfg(feature = "serialize")]
@@ -112,11 +149,7 @@ struct CircuitSerializable {
 impl TryFrom<CircuitSerializable> for Circuit {
     type Error = RoqoqoError;
     fn try_from(value: CircuitSerializable) -> Result<Self, Self::Error> {
-        Ok(Circuit {
-            definitions: value.definitions,
-            operations: value.operations,
-            _roqoqo_version: RoqoqoVersion,
-        })
+        Ok(Circuit::from_parts(value.definitions, value.operations))
     }
 }
 
@@ -136,6 +169,412 @@ impl From<Circuit> for CircuitSerializable {
     }
 }
 
+/// Options for the relaxed comparison of two Circuits with [Circuit::semantically_equal].
+///
+/// The default options only ignore `PragmaAnnotation` operations and do not relax any other part of the comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EqualityOptions {
+    /// Compare the definitions of the Circuits independent of their order.
+    pub ignore_definition_order: bool,
+    /// Ignore `PragmaStopParallelBlock` and `PragmaSleep` operations, which do not change the quantum state.
+    pub ignore_pragmas: bool,
+    /// Treat rotation angles as equal when they agree modulo 2π within the given (absolute) tolerance.
+    pub angle_tolerance: Option<f64>,
+    /// Compare `PragmaAnnotation` operations, which only carry metadata and are ignored otherwise.
+    pub compare_annotations: bool,
+}
+
+impl EqualityOptions {
+    /// Creates new EqualityOptions that only ignore `PragmaAnnotation` operations.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The default EqualityOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the order of the definitions is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `ignore_definition_order` - Whether definitions are compared independent of their order.
+    pub fn ignore_definition_order(mut self, ignore_definition_order: bool) -> Self {
+        self.ignore_definition_order = ignore_definition_order;
+        self
+    }
+
+    /// Sets whether `PragmaStopParallelBlock` and `PragmaSleep` operations are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `ignore_pragmas` - Whether the PRAGMAs are ignored.
+    pub fn ignore_pragmas(mut self, ignore_pragmas: bool) -> Self {
+        self.ignore_pragmas = ignore_pragmas;
+        self
+    }
+
+    /// Sets the tolerance for comparing rotation angles modulo 2π.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle_tolerance` - The absolute tolerance, `None` compares the angles strictly.
+    pub fn angle_tolerance(mut self, angle_tolerance: Option<f64>) -> Self {
+        self.angle_tolerance = angle_tolerance;
+        self
+    }
+
+    /// Sets whether `PragmaAnnotation` operations are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare_annotations` - Whether the annotations are compared.
+    pub fn compare_annotations(mut self, compare_annotations: bool) -> Self {
+        self.compare_annotations = compare_annotations;
+        self
+    }
+}
+
+/// Size statistics of a Circuit or QuantumProgram, see [Circuit::size_report].
+///
+/// The statistics can be used to check whether a program fits into the memory of a simulator before it is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircuitSizeReport {
+    /// Number of operations and definitions, including those in the circuits of nested operations.
+    pub number_operations: usize,
+    /// Size in bytes of the bincode serialization.
+    pub serialized_bytes: usize,
+    /// Largest dimension of a statevector or density matrix set with PragmaSetStateVector or PragmaSetDensityMatrix.
+    pub max_state_dimension: usize,
+    /// Deepest nesting of circuits in operations like PragmaLoop or PragmaConditional, 0 without nested circuits.
+    pub max_nesting_depth: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl CircuitSizeReport {
+    /// Adds the operations of a Circuit at the given nesting depth to the statistics.
+    pub(crate) fn add_circuit(&mut self, circuit: &Circuit, depth: usize) {
+        self.number_operations += circuit.len();
+        self.max_nesting_depth = self.max_nesting_depth.max(depth);
+        for operation in circuit.iter() {
+            self.add_operation(operation, depth);
+        }
+    }
+
+    /// Adds the state dimensions and nested circuits of an operation to the statistics.
+    fn add_operation(&mut self, operation: &Operation, depth: usize) {
+        match operation {
+            Operation::PragmaSetStateVector(op) => {
+                self.max_state_dimension = self.max_state_dimension.max(op.statevector().len())
+            }
+            Operation::PragmaSetDensityMatrix(op) => {
+                self.max_state_dimension = self.max_state_dimension.max(op.density_matrix().nrows())
+            }
+            Operation::PragmaAnnotatedOp(op) => self.add_operation(&op.operation, depth),
+            _ => {
+                for circuit in nested_circuits(operation) {
+                    self.add_circuit(circuit, depth + 1);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the circuits contained in an operation like PragmaLoop or PragmaConditional.
+fn nested_circuits(operation: &Operation) -> Vec<&Circuit> {
+    match operation {
+        Operation::PragmaGetStateVector(op) => op.circuit().iter().collect(),
+        Operation::PragmaGetDensityMatrix(op) => op.circuit().iter().collect(),
+        Operation::PragmaGetReducedDensityMatrix(op) => op.circuit().iter().collect(),
+        Operation::PragmaGetOccupationProbability(op) => op.circuit().iter().collect(),
+        Operation::PragmaGetPauliProduct(op) => vec![op.circuit()],
+        Operation::PragmaGetIndexedOccupationProbability(op) => op.circuit().iter().collect(),
+        Operation::PragmaGetIndexedPauliProduct(op) => vec![op.circuit()],
+        Operation::PragmaGetOperatorExpectation(op) => vec![op.circuit()],
+        Operation::PragmaConditional(op) => vec![op.circuit()],
+        Operation::PragmaConditionalLoop(op) => vec![op.circuit()],
+        Operation::PragmaLoop(op) => vec![op.circuit()],
+        Operation::PragmaControlledCircuit(op) => vec![op.circuit()],
+        _ => Vec::new(),
+    }
+}
+
+/// hqslang names of the PRAGMAs ignored by [Circuit::semantically_equal] when `ignore_pragmas` is set.
+const IGNORABLE_PRAGMAS: [&str; 2] = ["PragmaStopParallelBlock", "PragmaSleep"];
+
+/// Compares two operations, allowing rotation angles to differ by multiples of 2π within a tolerance.
+fn operations_semantically_equal(
+    operation: &Operation,
+    other: &Operation,
+    angle_tolerance: Option<f64>,
+) -> bool {
+    if operation == other {
+        return true;
+    }
+    let tolerance = match angle_tolerance {
+        Some(tolerance) => tolerance,
+        None => return false,
+    };
+    let (rotation, other_rotation) = match (
+        Rotation::try_from(operation.clone()),
+        Rotation::try_from(other.clone()),
+    ) {
+        (Ok(rotation), Ok(other_rotation)) => (rotation, other_rotation),
+        _ => return false,
+    };
+    // All parameters apart from the rotation angle need to agree
+    if rotation.powercf(CalculatorFloat::ZERO) != other_rotation.powercf(CalculatorFloat::ZERO) {
+        return false;
+    }
+    match (
+        f64::try_from(rotation.theta().clone()),
+        f64::try_from(other_rotation.theta().clone()),
+    ) {
+        (Ok(theta), Ok(other_theta)) => {
+            let difference = (theta - other_theta).rem_euclid(2.0 * std::f64::consts::PI);
+            difference <= tolerance || 2.0 * std::f64::consts::PI - difference <= tolerance
+        }
+        _ => false,
+    }
+}
+
+/// Returns the name of a definition operation.
+fn definition_name(operation: &Operation) -> Option<String> {
+    Definition::try_from(operation.clone())
+        .ok()
+        .map(|definition| definition.name().clone())
+}
+
+/// Returns the name, length and output flag of a classical register definition.
+fn register_definition(operation: &Operation) -> Option<(&String, usize, bool)> {
+    match operation {
+        Operation::DefinitionBit(def) => Some((def.name(), *def.length(), *def.is_output())),
+        Operation::DefinitionFloat(def) => Some((def.name(), *def.length(), *def.is_output())),
+        Operation::DefinitionComplex(def) => Some((def.name(), *def.length(), *def.is_output())),
+        Operation::DefinitionUsize(def) => Some((def.name(), *def.length(), *def.is_output())),
+        _ => None,
+    }
+}
+
+/// Checks that no register is renamed to the name of an existing register that is not renamed
+/// and that no two registers are renamed to the same name.
+///
+/// # Arguments
+///
+/// * `existing` - The names of the existing registers.
+/// * `mapping` - The HashMap containing the {old_name: new_name} mapping of the registers.
+pub(crate) fn check_valid_register_mapping(
+    existing: impl IntoIterator<Item = String>,
+    mapping: &HashMap<String, String>,
+) -> Result<(), RoqoqoError> {
+    for name in existing {
+        if !mapping.contains_key(&name) && mapping.values().any(|target| *target == name) {
+            return Err(RoqoqoError::RegisterMappingError { name });
+        }
+    }
+    let mut targets: HashSet<&String> = HashSet::new();
+    for target in mapping.values() {
+        if !targets.insert(target) {
+            return Err(RoqoqoError::RegisterMappingError {
+                name: target.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the new name of a register.
+fn rename_register(name: &str, mapping: &HashMap<String, String>) -> String {
+    mapping.get(name).map_or(name, String::as_str).to_string()
+}
+
+/// Renames the classical registers of an operation without checking the mapping.
+fn rename_operation_registers(
+    operation: &Operation,
+    mapping: &HashMap<String, String>,
+) -> Operation {
+    match operation {
+        Operation::DefinitionBit(op) => DefinitionBit::new(
+            rename_register(op.name(), mapping),
+            *op.length(),
+            *op.is_output(),
+        )
+        .into(),
+        Operation::DefinitionFloat(op) => DefinitionFloat::new(
+            rename_register(op.name(), mapping),
+            *op.length(),
+            *op.is_output(),
+        )
+        .into(),
+        Operation::DefinitionComplex(op) => DefinitionComplex::new(
+            rename_register(op.name(), mapping),
+            *op.length(),
+            *op.is_output(),
+        )
+        .into(),
+        Operation::DefinitionUsize(op) => DefinitionUsize::new(
+            rename_register(op.name(), mapping),
+            *op.length(),
+            *op.is_output(),
+        )
+        .into(),
+        Operation::InputBit(op) => InputBit::new(
+            rename_register(op.name(), mapping),
+            *op.index(),
+            *op.value(),
+        )
+        .into(),
+        Operation::MeasureQubit(op) => MeasureQubit::new(
+            *op.qubit(),
+            rename_register(op.readout(), mapping),
+            *op.readout_index(),
+        )
+        .into(),
+        Operation::PhotonDetection(op) => PhotonDetection::new(
+            *op.mode(),
+            rename_register(op.readout(), mapping),
+            *op.readout_index(),
+        )
+        .into(),
+        Operation::PragmaRepeatedMeasurement(op) => PragmaRepeatedMeasurement::new(
+            rename_register(op.readout(), mapping),
+            *op.number_measurements(),
+            op.qubit_mapping().clone(),
+        )
+        .into(),
+        Operation::PragmaSetNumberOfMeasurements(op) => PragmaSetNumberOfMeasurements::new(
+            *op.number_measurements(),
+            rename_register(op.readout(), mapping),
+        )
+        .into(),
+        Operation::PragmaSimulateShotNoise(op) => PragmaSimulateShotNoise::new(
+            rename_register(op.readout(), mapping),
+            *op.number_shots(),
+            *op.seed(),
+        )
+        .into(),
+        Operation::PragmaSnapshotRegisters(op) => PragmaSnapshotRegisters::new(
+            op.readout_names()
+                .iter()
+                .map(|name| rename_register(name, mapping))
+                .collect(),
+            op.label().clone(),
+        )
+        .into(),
+        Operation::PragmaGetStateVector(op) => PragmaGetStateVector::new(
+            rename_register(op.readout(), mapping),
+            op.circuit()
+                .as_ref()
+                .map(|circuit| circuit.rename_registers(mapping)),
+        )
+        .into(),
+        Operation::PragmaGetDensityMatrix(op) => PragmaGetDensityMatrix::new(
+            rename_register(op.readout(), mapping),
+            op.circuit()
+                .as_ref()
+                .map(|circuit| circuit.rename_registers(mapping)),
+        )
+        .into(),
+        Operation::PragmaGetReducedDensityMatrix(op) => PragmaGetReducedDensityMatrix::new(
+            rename_register(op.readout(), mapping),
+            op.qubits().clone(),
+            op.circuit()
+                .as_ref()
+                .map(|circuit| circuit.rename_registers(mapping)),
+        )
+        .into(),
+        Operation::PragmaGetOccupationProbability(op) => PragmaGetOccupationProbability::new(
+            rename_register(op.readout(), mapping),
+            op.circuit()
+                .as_ref()
+                .map(|circuit| circuit.rename_registers(mapping)),
+        )
+        .into(),
+        Operation::PragmaGetPauliProduct(op) => PragmaGetPauliProduct::new(
+            op.qubit_paulis().clone(),
+            rename_register(op.readout(), mapping),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaGetIndexedOccupationProbability(op) => {
+            PragmaGetIndexedOccupationProbability::new(
+                rename_register(op.readout(), mapping),
+                *op.readout_index(),
+                op.circuit()
+                    .as_ref()
+                    .map(|circuit| circuit.rename_registers(mapping)),
+            )
+            .into()
+        }
+        Operation::PragmaGetIndexedPauliProduct(op) => PragmaGetIndexedPauliProduct::new(
+            op.qubit_paulis().clone(),
+            rename_register(op.readout(), mapping),
+            *op.readout_index(),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaGetOperatorExpectation(op) => PragmaGetOperatorExpectation::new(
+            op.operator().clone(),
+            rename_register(op.readout(), mapping),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaConditional(op) => PragmaConditional::new(
+            rename_register(op.condition_register(), mapping),
+            *op.condition_index(),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaConditionalLoop(op) => PragmaConditionalLoop::new(
+            rename_register(op.condition_register(), mapping),
+            *op.condition_index(),
+            *op.max_repetitions(),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaLoop(op) => PragmaLoop::new(
+            op.repetitions().clone(),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaControlledCircuit(op) => PragmaControlledCircuit::new(
+            *op.controlling_qubit(),
+            op.circuit().rename_registers(mapping),
+        )
+        .into(),
+        Operation::PragmaAnnotatedOp(op) => PragmaAnnotatedOp::new(
+            rename_operation_registers(&op.operation, mapping),
+            op.annotation.clone(),
+        )
+        .into(),
+        _ => operation.clone(),
+    }
+}
+
+/// Validates the state setting PRAGMAs of an operation and of the circuits it contains.
+fn validate_operation_pragmas(operation: &Operation) -> Result<(), RoqoqoError> {
+    match operation {
+        Operation::PragmaSetStateVector(op) => op.validate(),
+        Operation::PragmaSetDensityMatrix(op) => op.validate(),
+        Operation::PragmaAnnotatedOp(op) => validate_operation_pragmas(&op.operation),
+        _ => nested_circuits(operation)
+            .into_iter()
+            .try_for_each(|circuit| circuit.validate_pragmas()),
+    }
+}
+
+/// Returns the qubits explicitly involved in the operations of a Circuit.
+fn involved_qubit_set(circuit: &Circuit) -> HashSet<usize> {
+    let mut qubits: HashSet<usize> = HashSet::new();
+    for op in circuit.iter() {
+        if let InvolvedQubits::Set(op_qubits) = op.involved_qubits() {
+            qubits.extend(op_qubits);
+        }
+    }
+    qubits
+}
+
 impl Circuit {
     /// Creates an empty quantum Circuit.
     ///
@@ -147,8 +586,24 @@ impl Circuit {
             definitions: Vec::new(),
             operations: Vec::new(),
             _roqoqo_version: RoqoqoVersion,
+            maybe_parametrized: false,
+        }
+    }
+
+    /// Creates a Circuit from definitions and operations and computes the parametrization flag.
+    fn from_parts(definitions: Vec<Operation>, operations: Vec<Operation>) -> Self {
+        let maybe_parametrized = definitions
+            .iter()
+            .chain(operations.iter())
+            .any(|o| o.is_parametrized());
+        Circuit {
+            definitions,
+            operations,
+            _roqoqo_version: RoqoqoVersion,
+            maybe_parametrized,
         }
     }
+
     /// Adds an Operation to Circuit (self).
     ///
     /// # Arguments
@@ -159,16 +614,17 @@ impl Circuit {
         T: Into<Operation>,
     {
         let input: Operation = op.into();
+        self.maybe_parametrized |= input.is_parametrized();
         match &input {
-            Operation::DefinitionBit(_) => self.definitions.push(input),
+            Operation::DefinitionBit(_) => self.push_definition(input),
             Operation::DefinitionFloat(_) => {
-                self.definitions.push(input);
+                self.push_definition(input);
             }
             Operation::DefinitionComplex(_) => {
-                self.definitions.push(input);
+                self.push_definition(input);
             }
             Operation::DefinitionUsize(_) => {
-                self.definitions.push(input);
+                self.push_definition(input);
             }
             Operation::InputSymbolic(_) => {
                 self.definitions.push(input);
@@ -181,6 +637,83 @@ impl Circuit {
         }
     }
 
+    /// Adds a definition to the definitions of the Circuit.
+    ///
+    /// A register definition with the same type, name and length as an existing definition is not added again.
+    /// When only the new definition is an output, it replaces the existing definition.
+    fn push_definition(&mut self, definition: Operation) {
+        if let Some((name, length, is_output)) = register_definition(&definition) {
+            let duplicate = self.definitions.iter().position(|existing| {
+                std::mem::discriminant(existing) == std::mem::discriminant(&definition)
+                    && register_definition(existing).is_some_and(|(other_name, other_length, _)| {
+                        other_name == name && other_length == length
+                    })
+            });
+            if let Some(index) = duplicate {
+                if is_output {
+                    self.definitions[index] = definition;
+                }
+                return;
+            }
+        }
+        self.definitions.push(definition);
+    }
+
+    /// Checks that the classical registers of the Circuit are not defined in conflicting ways.
+    ///
+    /// Definitions with the same type, name and length are merged when they are added to the Circuit.
+    /// Two definitions of a register with different lengths or different types are a conflict.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - No register is defined in conflicting ways.
+    /// * `Err(RoqoqoError::ConflictingDefinitions)` - The first two conflicting definitions of a register.
+    pub fn check_definitions(&self) -> Result<(), RoqoqoError> {
+        let mut first_definitions: HashMap<&String, (usize, &Operation)> = HashMap::new();
+        for (index, definition) in self.definitions.iter().enumerate() {
+            if let Some((name, length, _)) = register_definition(definition) {
+                match first_definitions.get(name) {
+                    Some((first_index, first)) => {
+                        let (_, first_length, _) = register_definition(first)
+                            .expect("Only register definitions are stored");
+                        if first_length != length
+                            || std::mem::discriminant(*first) != std::mem::discriminant(definition)
+                        {
+                            return Err(RoqoqoError::ConflictingDefinitions {
+                                name: name.clone(),
+                                first_length,
+                                first_index: *first_index,
+                                second_length: length,
+                                second_index: index,
+                            });
+                        }
+                    }
+                    None => {
+                        first_definitions.insert(name, (index, definition));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the classical registers defined in the Circuit.
+    ///
+    /// For registers with conflicting definitions the first definition is used.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, (usize, bool)>` - The length and output flag of each register by name.
+    pub fn definitions_map(&self) -> HashMap<String, (usize, bool)> {
+        let mut map: HashMap<String, (usize, bool)> = HashMap::new();
+        for definition in self.definitions.iter() {
+            if let Some((name, length, is_output)) = register_definition(definition) {
+                map.entry(name.clone()).or_insert((length, is_output));
+            }
+        }
+        map
+    }
+
     /// Returns a reference to the element at index similar to std::Vec get function.
     ///
     /// Contrary to std::Vec get function not implemented for slices  .
@@ -213,6 +746,7 @@ impl Circuit {
     ///
     /// * `Option<mut &Operation>` - A mutable reference to the operation at the given index (if it exists).
     pub fn get_mut(&mut self, index: usize) -> Option<&mut Operation> {
+        self.maybe_parametrized = true;
         let def_len = self.definitions.len();
         if index >= self.definitions.len() {
             self.operations.get_mut(index - def_len)
@@ -232,12 +766,16 @@ impl Circuit {
 
     /// Returns true if the Circuit contains symbolic variables.
     ///
+    /// Circuits are only scanned when a parametrized operation was added
+    /// or an operation was accessed mutably since the Circuit was created.
+    ///
     /// # Returns
     ///
     /// * `bool` - True if the Circuit contains symbolic values, false if it does not.
     pub fn is_parametrized(&self) -> bool {
-        self.operations.iter().any(|o| o.is_parametrized())
-            || self.definitions.iter().any(|o| o.is_parametrized())
+        self.maybe_parametrized
+            && (self.operations.iter().any(|o| o.is_parametrized())
+                || self.definitions.iter().any(|o| o.is_parametrized()))
     }
 
     /// Returns the length of the Circuit.
@@ -280,6 +818,86 @@ impl Circuit {
         }
     }
 
+    /// Returns the classical register entries the Circuit involves.
+    ///
+    /// The involved entries of all operations and definitions are merged. Operations containing
+    /// circuits (e.g. PragmaConditional or PragmaLoop) contribute the entries of their inner
+    /// circuits. Since `InvolvedClassical` can only name one fully involved register, the
+    /// entries are merged with the following rules:
+    ///
+    /// * If any operation involves all entries of a register (`All`), the first such register is returned.
+    /// * Otherwise, if any operation involves all entries of a register up to the number of qubits
+    ///   (`AllQubits`), the first such register is returned.
+    /// * Otherwise, the union of all involved entries is returned (`Set`), or `None` if there are none.
+    ///
+    /// # Returns
+    ///
+    /// * `InvolvedClassical` - The classical register entries involved in the Circuit.
+    pub fn involved_classical(&self) -> InvolvedClassical {
+        let mut all_qubits: Option<String> = None;
+        let mut temp_involved: HashSet<(String, usize)> = HashSet::new();
+        for op in self.iter() {
+            match op.involved_classical() {
+                InvolvedClassical::All(x) => {
+                    return InvolvedClassical::All(x);
+                }
+                InvolvedClassical::AllQubits(x) => {
+                    all_qubits.get_or_insert(x);
+                }
+                InvolvedClassical::None => (),
+                InvolvedClassical::Set(x) => temp_involved.extend(x),
+            }
+        }
+        match (all_qubits, temp_involved.is_empty()) {
+            (Some(x), _) => InvolvedClassical::AllQubits(x),
+            (None, true) => InvolvedClassical::None,
+            (None, false) => InvolvedClassical::Set(temp_involved),
+        }
+    }
+
+    /// Returns bosonic modes the Circuit acts on.
+    ///
+    /// Operations not acting on bosonic modes (e.g. pure qubit operations) do not contribute.
+    ///
+    /// # Returns
+    ///
+    /// * `InvolvedModes` - The bosonic modes involved in the Circuit.
+    pub fn involved_modes(&self) -> InvolvedModes {
+        let mut temp_involved: HashSet<usize> = HashSet::new();
+        for op in self.operations.iter() {
+            match op.involved_modes() {
+                InvolvedModes::All => {
+                    return InvolvedModes::All;
+                }
+                InvolvedModes::None => (),
+                InvolvedModes::Set(x) => temp_involved.extend(x),
+            }
+        }
+        match temp_involved.is_empty() {
+            true => InvolvedModes::None,
+            false => InvolvedModes::Set(temp_involved),
+        }
+    }
+
+    /// Returns the number of bosonic modes needed to run the Circuit.
+    ///
+    /// The number of modes is the largest mode index involved in an operation plus one.
+    /// Operations acting on all modes do not fix the number of modes and are not counted.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of bosonic modes, zero if the Circuit acts on no bosonic modes.
+    pub fn number_of_modes(&self) -> usize {
+        self.operations
+            .iter()
+            .filter_map(|op| match op.involved_modes() {
+                InvolvedModes::Set(x) => x.into_iter().max(),
+                _ => None,
+            })
+            .max()
+            .map_or(0, |mode| mode + 1)
+    }
+
     /// Returns reference to the vector of definitions in Circuit.
     ///
     /// Definitions need to be unique.
@@ -312,6 +930,15 @@ impl Circuit {
     ///
     /// * `Ok(Self)` -  The Circuit with the parameters substituted.
     /// * `Err(RoqoqoError)` - The subsitution failed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Circuit::substitute_parameters",
+            level = "debug",
+            skip_all,
+            fields(number_operations = self.len())
+        )
+    )]
     pub fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
         let mut tmp_calculator = calculator.clone();
         let mut tmp_def: Vec<Operation> = Vec::new();
@@ -326,12 +953,70 @@ impl Circuit {
         for op in self.operations.iter() {
             tmp_op.push(op.substitute_parameters(&tmp_calculator)?);
         }
-        Ok(Self {
-            definitions: tmp_def,
-            operations: tmp_op,
-            _roqoqo_version: RoqoqoVersion,
-        })
+        Ok(Self::from_parts(tmp_def, tmp_op))
     }
+    /// Renames the classical registers in a clone of the Circuit.
+    ///
+    /// The registers are renamed consistently in the definitions, InputBit operations, measurement
+    /// operations, PRAGMA measurement operations and the condition registers of conditional PRAGMAs,
+    /// including the Circuits nested in PRAGMA operations.
+    /// Registers that are not in the keys of the mapping keep their name.
+    /// InputSymbolic definitions define symbolic parameters and not registers and are not renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The HashMap containing the {old_name: new_name} mapping of the registers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The Circuit with the registers renamed.
+    /// * `Err(RoqoqoError::RegisterMappingError)` - A register is mapped onto a register that is defined and not renamed,
+    ///   or two registers are mapped onto the same name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::Circuit;
+    /// use roqoqo::operations::{DefinitionBit, MeasureQubit};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    /// circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    /// let mapping: HashMap<String, String> = [("ro".to_string(), "ro_a".to_string())].into_iter().collect();
+    ///
+    /// let mut expected = Circuit::new();
+    /// expected += DefinitionBit::new("ro_a".to_string(), 1, true);
+    /// expected += MeasureQubit::new(0, "ro_a".to_string(), 0);
+    /// assert_eq!(circuit.remap_registers(&mapping).unwrap(), expected);
+    /// ```
+    pub fn remap_registers(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        check_valid_register_mapping(
+            self.definitions
+                .iter()
+                .filter_map(|definition| match definition {
+                    Operation::InputSymbolic(_) => None,
+                    _ => definition_name(definition),
+                }),
+            mapping,
+        )?;
+        Ok(self.rename_registers(mapping))
+    }
+
+    /// Renames the classical registers in a clone of the Circuit without checking the mapping.
+    fn rename_registers(&self, mapping: &HashMap<String, String>) -> Self {
+        Self::from_parts(
+            self.definitions
+                .iter()
+                .map(|operation| rename_operation_registers(operation, mapping))
+                .collect(),
+            self.operations
+                .iter()
+                .map(|operation| rename_operation_registers(operation, mapping))
+                .collect(),
+        )
+    }
+
     /// Remaps the qubits in operations in clone of Circuit.
     ///
     /// # Arguments
@@ -342,16 +1027,362 @@ impl Circuit {
     ///
     /// * `Ok(Self)` -  The Circuit with the qubits remapped.
     /// * `Err(RoqoqoError)` - The remapping failed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Circuit::remap_qubits",
+            level = "debug",
+            skip_all,
+            fields(number_operations = self.len())
+        )
+    )]
     pub fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
         let mut tmp_op: Vec<Operation> = Vec::new();
         for op in self.operations.iter() {
             tmp_op.push(op.remap_qubits(mapping)?);
         }
-        Ok(Self {
-            definitions: self.definitions.clone(),
-            operations: tmp_op,
-            _roqoqo_version: RoqoqoVersion,
-        })
+        Ok(Self::from_parts(self.definitions.clone(), tmp_op))
+    }
+
+    /// Validates the PRAGMA operations setting the quantum state in the Circuit.
+    ///
+    /// Every [crate::operations::PragmaSetStateVector] and [crate::operations::PragmaSetDensityMatrix],
+    /// including those in the circuits of operations like PragmaConditional or PragmaLoop,
+    /// is checked with its `validate` method. Deserialization does not validate these PRAGMAs.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All PRAGMA operations set valid quantum states.
+    /// * `Err(RoqoqoError)` - The error of the first invalid PRAGMA operation.
+    pub fn validate_pragmas(&self) -> Result<(), RoqoqoError> {
+        self.iter().try_for_each(validate_operation_pragmas)
+    }
+
+    /// Returns size statistics of the Circuit.
+    ///
+    /// The statistics are computed in one pass over the Circuit and the circuits nested in its operations.
+    ///
+    /// # Returns
+    ///
+    /// * `CircuitSizeReport` - The number of operations, serialized size, largest state dimension and deepest nesting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::Circuit;
+    /// use roqoqo::operations::{PragmaLoop, RotateX};
+    ///
+    /// let mut inner = Circuit::new();
+    /// inner += RotateX::new(0, 0.1.into());
+    /// let mut circuit = Circuit::new();
+    /// circuit += PragmaLoop::new(2.0.into(), inner);
+    /// let report = circuit.size_report();
+    ///
+    /// assert_eq!(report.number_operations, 2);
+    /// assert_eq!(report.max_nesting_depth, 1);
+    /// assert_eq!(report.serialized_bytes, bincode::serialize(&circuit).unwrap().len());
+    /// ```
+    #[cfg(feature = "serialize")]
+    pub fn size_report(&self) -> CircuitSizeReport {
+        let mut report = CircuitSizeReport::default();
+        report.add_circuit(self, 0);
+        report.serialized_bytes = bincode::serialized_size(self)
+            .expect("Unexpected failure to compute the serialized size of the Circuit")
+            as usize;
+        report
+    }
+
+    /// Returns the qubits the operations of the Circuit act on.
+    ///
+    /// Operations acting on all qubits, like PragmaGetStateVector, do not add qubits.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<usize>` - The sorted qubits used in the Circuit.
+    pub fn used_qubits(&self) -> BTreeSet<usize> {
+        involved_qubit_set(self).into_iter().collect()
+    }
+
+    /// Renumbers the used qubits of a clone of the Circuit to 0..n preserving their order.
+    ///
+    /// Circuits with sparse qubit indices are compacted, so that simulators only allocate the used qubits.
+    /// The qubits are remapped with [Circuit::remap_qubits].
+    /// When all [crate::operations::MeasureQubit] operations writing to a readout register use the
+    /// measured qubit as readout index, the readout indices are renumbered together with the qubits.
+    /// The lengths of the register definitions are not changed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Circuit, HashMap<usize, usize>))` - The compacted Circuit and the applied {old_qubit: new_qubit} mapping.
+    /// * `Err(RoqoqoError)` - Remapping the qubits of an operation failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::Circuit;
+    /// use roqoqo::operations::CNOT;
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit += CNOT::new(17, 3);
+    /// let (compacted, mapping) = circuit.compactify_qubits().unwrap();
+    ///
+    /// let mut expected = Circuit::new();
+    /// expected += CNOT::new(1, 0);
+    /// assert_eq!(compacted, expected);
+    /// assert_eq!(mapping[&17], 1);
+    /// ```
+    pub fn compactify_qubits(&self) -> Result<(Self, HashMap<usize, usize>), RoqoqoError> {
+        let used = self.used_qubits();
+        let mapping: HashMap<usize, usize> = used
+            .iter()
+            .enumerate()
+            .map(|(new, old)| (*old, new))
+            .collect();
+        // remap_qubits requires a permutation, qubits freed by the compaction are mapped to the newly occupied ones
+        let targets: BTreeSet<usize> = (0..used.len()).collect();
+        let mut permutation = mapping.clone();
+        permutation.extend(
+            targets
+                .difference(&used)
+                .copied()
+                .zip(used.difference(&targets).copied()),
+        );
+
+        let mut qubit_indexed: HashSet<&String> = HashSet::new();
+        let mut other_indexed: HashSet<&String> = HashSet::new();
+        for operation in self.operations.iter() {
+            if let Operation::MeasureQubit(op) = operation {
+                if op.qubit() == op.readout_index() {
+                    qubit_indexed.insert(op.readout());
+                } else {
+                    other_indexed.insert(op.readout());
+                }
+            }
+        }
+        let mut operations: Vec<Operation> = Vec::with_capacity(self.operations.len());
+        for operation in self.operations.iter() {
+            let operation = match operation {
+                Operation::MeasureQubit(op)
+                    if qubit_indexed.contains(op.readout())
+                        && !other_indexed.contains(op.readout()) =>
+                {
+                    MeasureQubit::new(*op.qubit(), op.readout().clone(), mapping[op.qubit()]).into()
+                }
+                _ => operation.clone(),
+            };
+            operations.push(operation.remap_qubits(&permutation)?);
+        }
+        Ok((
+            Self::from_parts(self.definitions.clone(), operations),
+            mapping,
+        ))
+    }
+
+    /// Appends another Circuit acting on a disjoint set of qubits.
+    ///
+    /// All qubits of `other` are shifted by `qubit_offset` before it is appended to a clone of self.
+    /// The readout registers of the two Circuits must have different names.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The Circuit that is appended.
+    /// * `qubit_offset` - The offset added to all qubits of `other`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The combined Circuit acting on the qubits of both Circuits.
+    /// * `Err(RoqoqoError::RegisterNameClash)` - Both Circuits define a register with the same name.
+    /// * `Err(RoqoqoError::GenericError)` - The shifted qubits of `other` overlap with the qubits of self.
+    /// * `Err(RoqoqoError)` - Shifting the qubits of `other` failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::Circuit;
+    /// use roqoqo::operations::{CNOT, Hadamard};
+    ///
+    /// let mut bell = Circuit::new();
+    /// bell += Hadamard::new(0);
+    /// bell += CNOT::new(0, 1);
+    ///
+    /// let tensored = bell.tensor(&bell, 2).unwrap();
+    /// let mut expected = bell.clone();
+    /// expected += Hadamard::new(2);
+    /// expected += CNOT::new(2, 3);
+    /// assert_eq!(tensored, expected);
+    /// ```
+    pub fn tensor(&self, other: &Circuit, qubit_offset: usize) -> Result<Self, RoqoqoError> {
+        let self_names: HashSet<String> = self
+            .definitions
+            .iter()
+            .filter_map(definition_name)
+            .collect();
+        if let Some(name) = other
+            .definitions
+            .iter()
+            .filter_map(definition_name)
+            .find(|name| self_names.contains(name))
+        {
+            return Err(RoqoqoError::RegisterNameClash { name });
+        }
+        let other_qubits = involved_qubit_set(other);
+        let shifted_qubits: HashSet<usize> =
+            other_qubits.iter().map(|q| q + qubit_offset).collect();
+        if let Some(qubit) = involved_qubit_set(self).intersection(&shifted_qubits).min() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubit {} is used in both circuits after shifting by {}",
+                    qubit, qubit_offset
+                ),
+            });
+        }
+        // remap_qubits requires a permutation, qubits freed by the shift are mapped to the newly occupied ones
+        let mut freed: Vec<usize> = other_qubits.difference(&shifted_qubits).copied().collect();
+        let mut occupied: Vec<usize> = shifted_qubits.difference(&other_qubits).copied().collect();
+        freed.sort_unstable();
+        occupied.sort_unstable();
+        let mut mapping: HashMap<usize, usize> = other_qubits
+            .iter()
+            .map(|q| (*q, q + qubit_offset))
+            .collect();
+        mapping.extend(occupied.into_iter().zip(freed));
+        Ok(self.clone() + other.remap_qubits(&mapping)?)
+    }
+
+    /// Appends another Circuit acting on the same qubits.
+    ///
+    /// Definitions that are identical in both Circuits are only kept once.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The Circuit that is appended.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The concatenated Circuit.
+    /// * `Err(RoqoqoError::RegisterNameClash)` - Both Circuits contain different definitions with the same name.
+    pub fn compose(&self, other: &Circuit) -> Result<Self, RoqoqoError> {
+        let mut definitions = self.definitions.clone();
+        for definition in other.definitions.iter() {
+            if definitions.contains(definition) {
+                continue;
+            }
+            if let Some(name) = definition_name(definition) {
+                if definitions
+                    .iter()
+                    .any(|existing| definition_name(existing).as_ref() == Some(&name))
+                {
+                    return Err(RoqoqoError::RegisterNameClash { name });
+                }
+            }
+            definitions.push(definition.clone());
+        }
+        Ok(Self::from_parts(
+            definitions,
+            self.operations
+                .iter()
+                .chain(other.operations.iter())
+                .cloned()
+                .collect(),
+        ))
+    }
+
+    /// Returns the sum of the phases of all PragmaGlobalPhase operations in the Circuit.
+    ///
+    /// Symbolic phases are summed symbolically.
+    /// Only the top level operations are considered, PragmaGlobalPhase operations in nested
+    /// circuits (for example in a PragmaLoop) are not included.
+    ///
+    /// # Returns
+    ///
+    /// * `CalculatorFloat` - The total global phase, zero if the Circuit contains no PragmaGlobalPhase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::Circuit;
+    /// use roqoqo::operations::{PragmaGlobalPhase, PauliX};
+    /// use qoqo_calculator::CalculatorFloat;
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit += PragmaGlobalPhase::new(0.5.into());
+    /// circuit += PauliX::new(0);
+    /// circuit += PragmaGlobalPhase::new(0.25.into());
+    /// assert_eq!(circuit.total_global_phase(), CalculatorFloat::from(0.75));
+    /// ```
+    pub fn total_global_phase(&self) -> CalculatorFloat {
+        self.operations
+            .iter()
+            .filter_map(|operation| match operation {
+                Operation::PragmaGlobalPhase(pragma) => Some(pragma.phase()),
+                _ => None,
+            })
+            .fold(CalculatorFloat::from(0.0), |total, phase| total + phase)
+    }
+
+    /// Returns a copy of the Circuit with all global phases combined into a single PragmaGlobalPhase.
+    ///
+    /// All top level PragmaGlobalPhase operations are removed and, if there were any,
+    /// a single PragmaGlobalPhase with the [Circuit::total_global_phase] is appended at the end.
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit with the consolidated global phase.
+    pub fn consolidate_global_phase(&self) -> Circuit {
+        let mut operations: Vec<Operation> = self
+            .operations
+            .iter()
+            .filter(|operation| !matches!(operation, Operation::PragmaGlobalPhase(_)))
+            .cloned()
+            .collect();
+        if operations.len() != self.operations.len() {
+            operations.push(PragmaGlobalPhase::new(self.total_global_phase()).into());
+        }
+        Self::from_parts(self.definitions.clone(), operations)
+    }
+
+    /// Returns the annotations of the Circuit.
+    ///
+    /// Collects the keys and values of all top level PragmaAnnotation operations.
+    /// When a key is annotated several times, the last value is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, String>` - The annotations of the Circuit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::Circuit;
+    /// use roqoqo::operations::PauliX;
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit.annotate("compiler".to_string(), "1.0".to_string());
+    /// circuit += PauliX::new(0);
+    /// assert_eq!(circuit.annotations()["compiler"], "1.0");
+    /// ```
+    pub fn annotations(&self) -> HashMap<String, String> {
+        self.operations
+            .iter()
+            .filter_map(|operation| match operation {
+                Operation::PragmaAnnotation(pragma) => {
+                    Some((pragma.key().clone(), pragma.value().clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Annotates the Circuit with a key-value pair of metadata.
+    ///
+    /// Appends a PragmaAnnotation to the operations of the Circuit.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the annotation.
+    /// * `value` - The value of the annotation.
+    pub fn annotate(&mut self, key: String, value: String) {
+        self.add_operation(PragmaAnnotation::new(key, value));
     }
 
     /// Counts the number of occurences of a set of operation tags in the circuit.
@@ -386,6 +1417,85 @@ impl Circuit {
         operations
     }
 
+    /// Compares the Circuit with another Circuit up to harmless differences.
+    ///
+    /// Depending on the options the order of definitions, PRAGMAs that do not change the quantum state
+    /// and rotation angles differing by multiples of 2π are ignored.
+    /// `PragmaAnnotation` operations are ignored unless `compare_annotations` is set.
+    /// The strict comparison, including the annotations, is still available with `==`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The Circuit that is compared to self.
+    /// * `options` - The parts of the comparison that are relaxed.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the Circuits are equal under the relaxed comparison.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use roqoqo::{Circuit, EqualityOptions};
+    /// use roqoqo::operations::{DefinitionBit, DefinitionFloat, PragmaSleep, RotateZ};
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    /// circuit += DefinitionFloat::new("rf".to_string(), 1, true);
+    /// circuit += RotateZ::new(0, 0.5.into());
+    ///
+    /// let mut other = Circuit::new();
+    /// other += DefinitionFloat::new("rf".to_string(), 1, true);
+    /// other += DefinitionBit::new("ro".to_string(), 1, true);
+    /// other += PragmaSleep::new(vec![0], 1.0.into());
+    /// other += RotateZ::new(0, (0.5 + 2.0 * PI).into());
+    ///
+    /// let options = EqualityOptions::new()
+    ///     .ignore_definition_order(true)
+    ///     .ignore_pragmas(true)
+    ///     .angle_tolerance(Some(1e-10));
+    /// assert!(circuit != other);
+    /// assert!(circuit.semantically_equal(&other, options));
+    /// ```
+    pub fn semantically_equal(&self, other: &Circuit, options: EqualityOptions) -> bool {
+        // Comparing definitions
+        if self.definitions.len() != other.definitions.len() {
+            return false;
+        }
+        if options.ignore_definition_order {
+            let mut unmatched: Vec<&Operation> = other.definitions.iter().collect();
+            for definition in self.definitions.iter() {
+                match unmatched.iter().position(|x| *x == definition) {
+                    Some(index) => {
+                        unmatched.swap_remove(index);
+                    }
+                    None => return false,
+                }
+            }
+        } else if self.definitions != other.definitions {
+            return false;
+        }
+        // Comparing operations
+        let is_relevant = |op: &&Operation| {
+            !(options.ignore_pragmas && IGNORABLE_PRAGMAS.contains(&op.hqslang()))
+                && (options.compare_annotations || !matches!(op, Operation::PragmaAnnotation(_)))
+        };
+        let mut operations = self.operations.iter().filter(is_relevant);
+        let mut other_operations = other.operations.iter().filter(is_relevant);
+        loop {
+            match (operations.next(), other_operations.next()) {
+                (None, None) => return true,
+                (Some(op), Some(other_op)) => {
+                    if !operations_semantically_equal(op, other_op, options.angle_tolerance) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
     /// Returns clone of the circuit with all Overrotation Pragmas applied.
     ///
     /// # Returns
@@ -418,11 +1528,7 @@ impl Circuit {
     #[cfg(feature = "overrotate")]
     pub fn overrotate(&self) -> Result<Self, RoqoqoError> {
         let mut tmp_vec = self.operations.clone();
-        let mut return_circuit = Circuit {
-            definitions: self.definitions.clone(),
-            operations: Vec::new(),
-            _roqoqo_version: RoqoqoVersion,
-        };
+        let mut return_operations: Vec<Operation> = Vec::new();
         let mut length = tmp_vec.len();
         while length > 0 {
             match tmp_vec
@@ -471,14 +1577,50 @@ impl Circuit {
                 }
                 _ => {
                     for op in tmp_vec {
-                        return_circuit.operations.push(op)
+                        return_operations.push(op)
                     }
                     tmp_vec = Vec::new();
                 }
             }
             length = tmp_vec.len();
         }
-        Ok(return_circuit)
+        Ok(Self::from_parts(
+            self.definitions.clone(),
+            return_operations,
+        ))
+    }
+
+    /// Deserializes a Circuit from json and rejects fields unknown to the Circuit.
+    ///
+    /// The default deserialization ignores unknown fields, so a misspelled field of a hand-written
+    /// json Circuit (e.g. `"thetaa"` instead of `"theta"`) can go unnoticed.
+    /// The strict deserialization also checks the operations and the circuits nested in them.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The json representation of the Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The deserialized Circuit.
+    /// * `Err(RoqoqoError::SerializationError)` - The input cannot be deserialized or contains unknown fields, which are listed with their json paths.
+    #[cfg(feature = "serialize")]
+    pub fn from_json_strict(input: &str) -> Result<Self, RoqoqoError> {
+        crate::from_json_strict(input)
+    }
+
+    /// Serializes a Circuit to json, writing float values that are not finite as strings.
+    ///
+    /// NaN and infinite values in PragmaOverrotation and PragmaGeneralNoise operations are written
+    /// as "NaN", "Infinity" and "-Infinity" instead of `null`, so they can be deserialized again.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The serialized Circuit.
+    /// * `Err(RoqoqoError::SerializationError)` - The Circuit could not be serialized.
+    #[cfg(feature = "serialize")]
+    pub fn to_json_lossy(&self) -> Result<String, RoqoqoError> {
+        crate::float_serialization::to_json_lossy(self)
     }
 }
 
@@ -522,6 +1664,7 @@ impl ops::IndexMut<usize> for Circuit {
     ///
     /// Panics when index is out of range of operations in circuit.
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.maybe_parametrized = true;
         let def_len = self.definitions.len();
         if index >= def_len {
             &mut self.operations[index - def_len]
@@ -739,20 +1882,9 @@ where
 /// * `other` - The Circuit to be added.
 impl ops::Add<Circuit> for Circuit {
     type Output = Self;
-    fn add(self, other: Circuit) -> Self {
-        Self {
-            definitions: self
-                .definitions
-                .into_iter()
-                .chain(other.definitions)
-                .collect(),
-            operations: self
-                .operations
-                .into_iter()
-                .chain(other.operations)
-                .collect(),
-            _roqoqo_version: RoqoqoVersion,
-        }
+    fn add(mut self, other: Circuit) -> Self {
+        self += other;
+        self
     }
 }
 
@@ -763,20 +1895,9 @@ impl ops::Add<Circuit> for Circuit {
 /// * `other` - The Circuit reference to be added.
 impl ops::Add<&Circuit> for Circuit {
     type Output = Self;
-    fn add(self, other: &Circuit) -> Self {
-        Self {
-            definitions: self
-                .definitions
-                .into_iter()
-                .chain(other.definitions.iter().cloned())
-                .collect(),
-            operations: self
-                .operations
-                .into_iter()
-                .chain(other.operations.iter().cloned())
-                .collect(),
-            _roqoqo_version: RoqoqoVersion,
-        }
+    fn add(mut self, other: &Circuit) -> Self {
+        self += other;
+        self
     }
 }
 
@@ -801,7 +1922,10 @@ where
 /// * `other` - The Circuit to be appended.
 impl ops::AddAssign<Circuit> for Circuit {
     fn add_assign(&mut self, other: Circuit) {
-        self.definitions.extend(other.definitions);
+        self.maybe_parametrized = self.maybe_parametrized || other.maybe_parametrized;
+        for definition in other.definitions {
+            self.push_definition(definition);
+        }
         self.operations.extend(other.operations)
     }
 }
@@ -813,7 +1937,10 @@ impl ops::AddAssign<Circuit> for Circuit {
 /// * `other` - The Circuit to be appended.
 impl ops::AddAssign<&Circuit> for Circuit {
     fn add_assign(&mut self, other: &Circuit) {
-        self.definitions.extend(other.definitions.iter().cloned());
+        self.maybe_parametrized = self.maybe_parametrized || other.maybe_parametrized;
+        for definition in other.definitions.iter() {
+            self.push_definition(definition.clone());
+        }
         self.operations.extend(other.operations.iter().cloned())
     }
 }
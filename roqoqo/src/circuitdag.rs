@@ -10,12 +10,16 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
+use crate::devices::Device;
 use crate::operations::*;
+use crate::schedule::operation_duration;
 use crate::Circuit;
 use crate::RoqoqoVersionSerializable;
-use crate::{RoqoqoError, RoqoqoVersion};
+use crate::{RoqoqoBackendError, RoqoqoError, RoqoqoVersion};
+use qoqo_calculator::CalculatorFloat;
 
 use petgraph::adj::NodeIndex;
 use petgraph::algo;
@@ -40,19 +44,30 @@ use petgraph::Direction::{Incoming, Outgoing};
 /// * `new_front_layer(already_executed_indices, front_layer_indices, index)`: given an Operation index, computes a new front layer when considering that Operation as executed
 /// * `parallel_blocks()`: returns an iterator over the possible parallel blocks in circuit that can be executed simultaneously
 /// * `successors(index)`: returns an iterator over all successors in the CircuitDag of a given node
+/// * `predecessors(index)`: returns the direct predecessors in the CircuitDag of a given node
+/// * `ready_after(already_executed_indices)`: returns the nodes that can be executed once the given nodes have been executed
 /// * `commuting_operators()`: returns a reference to the vector of commuting operations in CircuitDag
 /// * `first_parallel_block()`: returns a reference to the HashSet containing the nodes in the first parallel block
 /// * `last_parallel_block()`: returns a reference to the HashSet containing the nodes in the last parallel block
 /// * `first_operation_involving_qubit()`: returns a reference to the HashMap where a key represents a qubit and its value represents the first node that involves that qubit
 /// * `last_operation_involving_qubit()`: returns a reference to the HashMap where a key represents a qubit and its value represents the last node that involves that qubit
+/// * `first_operation_involving_mode()`: returns a reference to the HashMap where a key represents a bosonic mode and its value represents the first node that involves that mode
+/// * `last_operation_involving_mode()`: returns a reference to the HashMap where a key represents a bosonic mode and its value represents the last node that involves that mode
 /// * `first_operation_involving_classical()`: returns a reference to the HashMap where a key is composed by the name and the size of the classical register and its value represents the first node that involves that register
 /// * `last_operation_involving_classical()`: returns a reference to the HashMap where a key is composed by the name and the size of the classical register and its value represents the last node that involves that register
 /// * `get(index)`: returns a reference to the Operation contained in the indexed CircuitDag's node
+/// * `qubit_lifetimes()`: returns the positions of the first and last operation involving each qubit
+/// * `max_concurrent_qubits()`: returns the maximal number of qubits alive at the same time
+/// * `repetitions(index)`: returns how often the operation of a node is executed, the number of repetitions for a PragmaLoop
+/// * `depth()`: returns the number of layers of the CircuitDag, counting the layers of each loop repetition
+/// * `execution_time(device)`: returns the time needed to run the CircuitDag on a device, counting the time of each loop repetition
 ///
 /// Note: operations PragmaStartDecompositionBlock and PragmaStopDecompositionBlock are considered part of the graph.
+/// Bosonic operations acting on the same bosonic mode are ordered like operations acting on the same qubit.
 ///
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(try_from = "CircuitDagSerializable"))]
 pub struct CircuitDag {
     pub(crate) graph: Graph<Operation, (), Directed, usize>,
     pub(crate) commuting_operations: Vec<NodeIndex<usize>>,
@@ -64,6 +79,11 @@ pub struct CircuitDag {
     pub(crate) last_operation_involving_qubit: HashMap<usize, NodeIndex<usize>>,
     pub(crate) first_operation_involving_classical: HashMap<(String, usize), NodeIndex<usize>>,
     pub(crate) last_operation_involving_classical: HashMap<(String, usize), NodeIndex<usize>>,
+    // Not serialized, rebuilt from the graph when deserializing
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) first_operation_involving_mode: HashMap<usize, NodeIndex<usize>>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) last_operation_involving_mode: HashMap<usize, NodeIndex<usize>>,
     _roqoqo_version: RoqoqoVersion,
 }
 
@@ -90,7 +110,7 @@ struct CircuitDagSerializable {
 impl TryFrom<CircuitDagSerializable> for CircuitDag {
     type Error = RoqoqoError;
     fn try_from(value: CircuitDagSerializable) -> Result<Self, Self::Error> {
-        Ok(CircuitDag {
+        let mut dag = CircuitDag {
             _roqoqo_version: RoqoqoVersion,
             graph: value.graph,
             commuting_operations: value.commuting_operations,
@@ -102,7 +122,26 @@ impl TryFrom<CircuitDagSerializable> for CircuitDag {
             last_operation_involving_qubit: value.last_operation_involving_qubit,
             first_operation_involving_classical: value.first_operation_involving_classical,
             last_operation_involving_classical: value.last_operation_involving_classical,
-        })
+            first_operation_involving_mode: HashMap::new(),
+            last_operation_involving_mode: HashMap::new(),
+        };
+        // Operations acting on the same bosonic mode are connected by edges,
+        // so the topological order visits them in the order of execution.
+        let sorted = toposort(&dag.graph, None).map_err(|_| RoqoqoError::GenericError {
+            msg: "Graph of the deserialized CircuitDag contains a cycle".to_string(),
+        })?;
+        for node in sorted {
+            if let InvolvedModes::Set(modes) = dag.graph[node].involved_modes() {
+                for mode in modes {
+                    let node = node.index();
+                    dag.first_operation_involving_mode
+                        .entry(mode)
+                        .or_insert(node);
+                    dag.last_operation_involving_mode.insert(mode, node);
+                }
+            }
+        }
+        Ok(dag)
     }
 }
 
@@ -163,6 +202,8 @@ impl CircuitDag {
             first_operation_involving_classical: HashMap::<(String, usize), NodeIndex<usize>>::new(
             ),
             last_operation_involving_classical: HashMap::<(String, usize), NodeIndex<usize>>::new(),
+            first_operation_involving_mode: HashMap::<usize, NodeIndex<usize>>::new(),
+            last_operation_involving_mode: HashMap::<usize, NodeIndex<usize>>::new(),
             _roqoqo_version: RoqoqoVersion,
         }
     }
@@ -187,9 +228,11 @@ impl CircuitDag {
             Operation::DefinitionUsize(_) => self.commuting_operations.push(node.index()),
             Operation::DefinitionComplex(_) => self.commuting_operations.push(node.index()),
             _ => {
-                if let (InvolvedQubits::None, InvolvedClassical::None) =
-                    (operation.involved_qubits(), operation.involved_classical())
-                {
+                if let (InvolvedQubits::None, InvolvedClassical::None, InvolvedModes::None) = (
+                    operation.involved_qubits(),
+                    operation.involved_classical(),
+                    operation.involved_modes(),
+                ) {
                     self.commuting_operations.push(node.index());
                 } else {
                     self.add_to_back_involved(node.index());
@@ -212,27 +255,34 @@ impl CircuitDag {
     ///
     /// * 'node' - The NodeIndex<usize> of the node to add to the end of the CircuitDag.
     fn add_to_back_involved(&mut self, node: NodeIndex<usize>) {
-        let node_involved_qubits: InvolvedQubits = self
-            .graph
-            .node_weight(node.into())
-            .unwrap()
-            .involved_qubits();
-        // Calls the proper subfunction depending on the qubits involved
+        let node_operation = self.graph.node_weight(node.into()).unwrap();
+        let node_involved_qubits: InvolvedQubits = node_operation.involved_qubits();
+        let node_involved_modes: InvolvedModes = node_operation.involved_modes();
+        // Calls the proper subfunction depending on the qubits and bosonic modes involved
         //  in the operation
+        let mut involves_set = false;
         if let InvolvedQubits::Set(x) = node_involved_qubits {
             for qubit in x {
                 self.update_from_qubit_back(node, qubit);
             }
-            if self
+            involves_set = true;
+        } else if let InvolvedQubits::All = node_involved_qubits {
+            self.update_from_all_operation_back(node);
+        }
+        if let InvolvedModes::Set(x) = node_involved_modes {
+            for mode in x {
+                self.update_from_mode_back(node, mode);
+            }
+            involves_set = true;
+        }
+        if involves_set
+            && self
                 .graph
                 .neighbors_directed(node.into(), Incoming)
                 .next()
                 .is_none()
-            {
-                self.first_parallel_block.insert(node);
-            }
-        } else if let InvolvedQubits::All = node_involved_qubits {
-            self.update_from_all_operation_back(node);
+        {
+            self.first_parallel_block.insert(node);
         }
     }
 
@@ -269,6 +319,31 @@ impl CircuitDag {
         }
     }
 
+    /// Updates the relevant attributes and the graph of CircuitDag from a single bosonic mode
+    /// involved in an Operation added to the back of the CircuitDag.
+    ///
+    /// Operations acting on the same bosonic mode are ordered by an edge like operations
+    /// acting on the same qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * 'node' - The index of the node whose Operation involves the bosonic mode.
+    /// * 'mode' - The bosonic mode involved in the Operation.
+    fn update_from_mode_back(&mut self, node: NodeIndex<usize>, mode: usize) {
+        if let Some(&i) = self.last_operation_involving_mode.get(&mode) {
+            self.graph.update_edge(i.into(), node.into(), ());
+            self.last_parallel_block.remove(&i);
+        }
+        if self
+            .last_operation_involving_mode
+            .insert(mode, node)
+            .is_none()
+        {
+            self.first_operation_involving_mode.insert(mode, node);
+        }
+        self.last_parallel_block.insert(node);
+    }
+
     /// Updates the relevant attributes and the graph of CircuitDag when an Operation that involves
     /// all qubits is added to the back.
     ///
@@ -329,9 +404,11 @@ impl CircuitDag {
             Operation::DefinitionUsize(_) => self.commuting_operations.push(node.index()),
             Operation::DefinitionComplex(_) => self.commuting_operations.push(node.index()),
             _ => {
-                if let (InvolvedQubits::None, InvolvedClassical::None) =
-                    (operation.involved_qubits(), operation.involved_classical())
-                {
+                if let (InvolvedQubits::None, InvolvedClassical::None, InvolvedModes::None) = (
+                    operation.involved_qubits(),
+                    operation.involved_classical(),
+                    operation.involved_modes(),
+                ) {
                     self.commuting_operations.push(node.index());
                 } else {
                     self.add_to_front_involved(node.index());
@@ -354,27 +431,34 @@ impl CircuitDag {
     ///
     /// * 'node' - The NodeIndex<usize> of the node to add to the end of the CircuitDag.
     fn add_to_front_involved(&mut self, node: NodeIndex<usize>) {
-        let node_involved_qubits: InvolvedQubits = self
-            .graph
-            .node_weight(node.into())
-            .unwrap()
-            .involved_qubits();
-        // Calls the proper subfunction depending on the qubits involved
+        let node_operation = self.graph.node_weight(node.into()).unwrap();
+        let node_involved_qubits: InvolvedQubits = node_operation.involved_qubits();
+        let node_involved_modes: InvolvedModes = node_operation.involved_modes();
+        // Calls the proper subfunction depending on the qubits and bosonic modes involved
         //  in the operation
+        let mut involves_set = false;
         if let InvolvedQubits::Set(x) = node_involved_qubits {
             for qubit in x {
                 self.update_from_qubit_front(node, qubit);
             }
-            if self
+            involves_set = true;
+        } else if let InvolvedQubits::All = node_involved_qubits {
+            self.update_from_all_operation_front(node);
+        }
+        if let InvolvedModes::Set(x) = node_involved_modes {
+            for mode in x {
+                self.update_from_mode_front(node, mode);
+            }
+            involves_set = true;
+        }
+        if involves_set
+            && self
                 .graph
                 .neighbors_directed(node.into(), Outgoing)
                 .next()
                 .is_none()
-            {
-                self.last_parallel_block.insert(node);
-            }
-        } else if let InvolvedQubits::All = node_involved_qubits {
-            self.update_from_all_operation_front(node);
+        {
+            self.last_parallel_block.insert(node);
         }
     }
 
@@ -411,6 +495,28 @@ impl CircuitDag {
         }
     }
 
+    /// Updates the relevant attributes and the graph of CircuitDag from a single bosonic mode
+    /// involved in an Operation added to the front of the CircuitDag.
+    ///
+    /// # Arguments
+    ///
+    /// * 'node' - The index of the node whose Operation involves the bosonic mode.
+    /// * 'mode' - The bosonic mode involved in the Operation.
+    fn update_from_mode_front(&mut self, node: NodeIndex<usize>, mode: usize) {
+        if let Some(&i) = self.first_operation_involving_mode.get(&mode) {
+            self.graph.update_edge(node.into(), i.into(), ());
+            self.first_parallel_block.remove(&i);
+        }
+        if self
+            .first_operation_involving_mode
+            .insert(mode, node)
+            .is_none()
+        {
+            self.last_operation_involving_mode.insert(mode, node);
+        }
+        self.first_parallel_block.insert(node);
+    }
+
     /// Updates the relevant attributes and the graph of CircuitDag when an Operation that involves
     /// all qubits is added to the front.
     ///
@@ -743,6 +849,66 @@ impl CircuitDag {
         self.graph.neighbors_directed(node.into(), Outgoing)
     }
 
+    /// Returns the sorted direct predecessors in the CircuitDag of a given node.
+    ///
+    /// Only the incoming edges of the node are visited.
+    ///
+    /// # Arguments:
+    ///
+    /// * `node` - NodeIndex of the Operation whose predecessors are returned.
+    pub fn predecessors(&self, node: NodeIndex<usize>) -> Vec<NodeIndex<usize>> {
+        let mut predecessors: Vec<NodeIndex<usize>> = self
+            .graph
+            .neighbors_directed(node.into(), Incoming)
+            .map(|predecessor| predecessor.index())
+            .collect();
+        predecessors.sort_unstable();
+        predecessors.dedup();
+        predecessors
+    }
+
+    /// Returns the nodes that can be executed once the given nodes have been executed.
+    ///
+    /// A node is ready when it has not been executed and all its direct predecessors have been executed.
+    /// Only the successors of the executed nodes and the first operations of the CircuitDag are visited,
+    /// so the cost scales with the number of edges touched and not with the size of the graph.
+    ///
+    /// # Arguments:
+    ///
+    /// * `already_executed` - Slice of NodeIndices of Nodes that have already been executed in the Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<NodeIndex<usize>>` - The sorted NodeIndices of the Operations that are ready to be executed.
+    pub fn ready_after(&self, already_executed: &[NodeIndex<usize>]) -> Vec<NodeIndex<usize>> {
+        let executed: HashSet<NodeIndex<usize>> = already_executed.iter().copied().collect();
+        let mut candidates: HashSet<NodeIndex<usize>> = self
+            .first_parallel_block
+            .iter()
+            .chain(self.commuting_operations.iter())
+            .chain(self.first_operation_involving_qubit.values())
+            .chain(self.first_operation_involving_mode.values())
+            .chain(self.first_operation_involving_classical.values())
+            .chain(self.first_all.iter())
+            .copied()
+            .collect();
+        for node in executed.iter() {
+            candidates.extend(self.successors(*node).map(|successor| successor.index()));
+        }
+        let mut ready: Vec<NodeIndex<usize>> = candidates
+            .into_iter()
+            .filter(|node| {
+                !executed.contains(node)
+                    && self
+                        .graph
+                        .neighbors_directed((*node).into(), Incoming)
+                        .all(|predecessor| executed.contains(&predecessor.index()))
+            })
+            .collect();
+        ready.sort_unstable();
+        ready
+    }
+
     /// Returns a reference to the vector of commuting operations in CircuitDag.
     ///
     pub fn commuting_operations(&self) -> &Vec<usize> {
@@ -775,6 +941,20 @@ impl CircuitDag {
         &self.last_operation_involving_qubit
     }
 
+    /// Returns a reference to the HashMap where a key represents a bosonic mode and its value
+    /// represents the first node that involves that mode.
+    ///
+    pub fn first_operation_involving_mode(&self) -> &HashMap<usize, usize> {
+        &self.first_operation_involving_mode
+    }
+
+    /// Returns a reference to the HashMap where a key represents a bosonic mode and its value
+    /// represents the last node that involves that mode.
+    ///
+    pub fn last_operation_involving_mode(&self) -> &HashMap<usize, usize> {
+        &self.last_operation_involving_mode
+    }
+
     /// Returns a reference to the HashMap where a key is composed by the name and the size
     /// of the classical register and its value represents the first node that involves that
     /// register.
@@ -797,10 +977,221 @@ impl CircuitDag {
     pub fn get(&self, node: NodeIndex<usize>) -> Option<&Operation> {
         self.graph.node_weight(node.into())
     }
+
+    /// Returns the lifetime of every qubit in the CircuitDag.
+    ///
+    /// The lifetime of a qubit is given by the positions of the first and the last operation
+    /// involving the qubit in the topological order of the CircuitDag.
+    /// Ties in the topological order are broken by the node index, so that for a CircuitDag
+    /// created from a Circuit the positions are the indices of the operations in the Circuit.
+    /// Operations involving all qubits count as involving every qubit used in the CircuitDag.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<usize, (usize, usize)>` - The positions of the first and the last operation involving each qubit.
+    pub fn qubit_lifetimes(&self) -> HashMap<usize, (usize, usize)> {
+        let mut lifetimes: HashMap<usize, (usize, usize)> = HashMap::new();
+        let mut involving_all: Vec<usize> = Vec::new();
+        for (position, node) in self.ordered_nodes().into_iter().enumerate() {
+            let operation = self.get(node).expect("Node is missing in CircuitDag");
+            match operation.involved_qubits() {
+                InvolvedQubits::Set(qubits) => {
+                    for qubit in qubits {
+                        lifetimes
+                            .entry(qubit)
+                            .and_modify(|(_, last)| *last = position)
+                            .or_insert((position, position));
+                    }
+                }
+                InvolvedQubits::All => involving_all.push(position),
+                InvolvedQubits::None => (),
+            }
+        }
+        for position in involving_all {
+            for (first, last) in lifetimes.values_mut() {
+                *first = (*first).min(position);
+                *last = (*last).max(position);
+            }
+        }
+        lifetimes
+    }
+
+    /// Returns the maximal number of qubits that are alive at the same time.
+    ///
+    /// A qubit is alive from its first to its last involving operation (see [CircuitDag::qubit_lifetimes]).
+    /// Qubits whose lifetimes do not overlap can be mapped onto the same physical qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The maximal number of concurrently alive qubits.
+    pub fn max_concurrent_qubits(&self) -> usize {
+        // Sweep over start (+1) and end (-1) events, ends are placed after the last operation
+        let mut events: Vec<(usize, isize)> = Vec::new();
+        for (first, last) in self.qubit_lifetimes().into_values() {
+            events.push((first, 1));
+            events.push((last + 1, -1));
+        }
+        events.sort_unstable();
+        let mut alive: isize = 0;
+        let mut max_alive: isize = 0;
+        for (_, change) in events {
+            alive += change;
+            max_alive = max_alive.max(alive);
+        }
+        max_alive as usize
+    }
+
+    /// Returns how often the operation of a node is executed.
+    ///
+    /// The circuit of a PragmaLoop is executed the number of repetitions of the loop,
+    /// rounded down to an integer. All other operations are executed once.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The index of the node in the CircuitDag.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of executions of the operation.
+    /// * `Err(RoqoqoError::SymbolicRepetitions)` - The operation is a PragmaLoop with symbolic repetitions.
+    /// * `Err(RoqoqoError::GenericError)` - The node is not in the CircuitDag.
+    pub fn repetitions(&self, node: NodeIndex<usize>) -> Result<usize, RoqoqoError> {
+        match self.get(node) {
+            Some(Operation::PragmaLoop(op)) => loop_repetitions(op, node),
+            Some(_) => Ok(1),
+            None => Err(RoqoqoError::GenericError {
+                msg: format!("Node {} is not in the CircuitDag", node),
+            }),
+        }
+    }
+
+    /// Returns the depth of the CircuitDag.
+    ///
+    /// The depth is the number of operations on the longest path through the CircuitDag.
+    /// A PragmaLoop contributes the depth of its circuit times its number of repetitions.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The depth of the CircuitDag.
+    /// * `Err(RoqoqoError::SymbolicRepetitions)` - A PragmaLoop has symbolic repetitions.
+    pub fn depth(&self) -> Result<usize, RoqoqoError> {
+        let mut ends: HashMap<usize, usize> = HashMap::new();
+        let mut depth: usize = 0;
+        for node in self.ordered_nodes() {
+            let layers = match self.get(node).expect("Node is missing in CircuitDag") {
+                Operation::PragmaLoop(op) => {
+                    loop_repetitions(op, node)? * CircuitDag::from(op.circuit().clone()).depth()?
+                }
+                _ => 1,
+            };
+            let start = self
+                .predecessors(node)
+                .into_iter()
+                .map(|predecessor| ends[&predecessor])
+                .max()
+                .unwrap_or(0);
+            depth = depth.max(start + layers);
+            ends.insert(node, start + layers);
+        }
+        Ok(depth)
+    }
+
+    /// Returns the time needed to execute the CircuitDag on a device.
+    ///
+    /// Every operation starts as soon as its predecessors have finished, with the durations
+    /// used by [crate::schedule::schedule_asap]. A PragmaLoop takes the execution time
+    /// of its circuit times its number of repetitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device providing the gate times.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The execution time of the CircuitDag.
+    /// * `Err(RoqoqoBackendError::RoqoqoError)` - A PragmaLoop has symbolic repetitions.
+    /// * `Err(RoqoqoBackendError::GenericError)` - A gate of the CircuitDag is not available on the device.
+    pub fn execution_time(&self, device: &impl Device) -> Result<f64, RoqoqoBackendError> {
+        let mut ends: HashMap<usize, f64> = HashMap::new();
+        let mut execution_time: f64 = 0.0;
+        for node in self.ordered_nodes() {
+            let operation = self.get(node).expect("Node is missing in CircuitDag");
+            let duration = operation_duration(operation, node, device)?;
+            let start = self
+                .predecessors(node)
+                .into_iter()
+                .map(|predecessor| ends[&predecessor])
+                .fold(0.0, f64::max);
+            execution_time = execution_time.max(start + duration);
+            ends.insert(node, start + duration);
+        }
+        Ok(execution_time)
+    }
+
+    /// Returns the nodes in topological order, ties are broken by the smallest node index.
+    fn ordered_nodes(&self) -> Vec<usize> {
+        let mut in_degrees: HashMap<usize, usize> = self
+            .graph
+            .node_indices()
+            .map(|node| {
+                (
+                    node.index(),
+                    self.graph.neighbors_directed(node, Incoming).count(),
+                )
+            })
+            .collect();
+        let mut ready: BinaryHeap<Reverse<usize>> = in_degrees
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(node, _)| Reverse(*node))
+            .collect();
+        let mut order: Vec<usize> = Vec::with_capacity(in_degrees.len());
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+            for successor in self.successors(node) {
+                let degree = in_degrees
+                    .get_mut(&successor.index())
+                    .expect("Successor is missing in CircuitDag");
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(Reverse(successor.index()));
+                }
+            }
+        }
+        order
+    }
+}
+
+/// Returns the number of repetitions of a loop, rounded down to an integer.
+///
+/// # Arguments
+///
+/// * `operation` - The loop.
+/// * `index` - The index of the loop reported in the error.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number of repetitions.
+/// * `Err(RoqoqoError::SymbolicRepetitions)` - The number of repetitions is symbolic.
+pub(crate) fn loop_repetitions(operation: &PragmaLoop, index: usize) -> Result<usize, RoqoqoError> {
+    match operation.repetitions() {
+        CalculatorFloat::Float(repetitions) => Ok(*repetitions as usize),
+        CalculatorFloat::Str(repetitions) => Err(RoqoqoError::SymbolicRepetitions {
+            repetitions: repetitions.clone(),
+            index,
+        }),
+    }
 }
 
 /// Creates a new CircuitDag from a given Circuit.
 ///
+/// Every operation of the Circuit becomes one node, a PragmaLoop is not expanded.
+/// The repetitions of a loop are a weight of its node (see [CircuitDag::repetitions]) that
+/// [CircuitDag::depth] and [CircuitDag::execution_time] multiply the depth and time of the
+/// looped circuit by. The CircuitDag can be created for loops with symbolic repetitions,
+/// the depth and execution time then return [RoqoqoError::SymbolicRepetitions].
+/// The parallel blocks contain a loop as a single node.
+///
 impl From<Circuit> for CircuitDag {
     fn from(circuit: Circuit) -> Self {
         let mut new_dag = CircuitDag {
@@ -818,6 +1209,8 @@ impl From<Circuit> for CircuitDag {
             first_operation_involving_classical: HashMap::<(String, usize), NodeIndex<usize>>::new(
             ),
             last_operation_involving_classical: HashMap::<(String, usize), NodeIndex<usize>>::new(),
+            first_operation_involving_mode: HashMap::<usize, NodeIndex<usize>>::new(),
+            last_operation_involving_mode: HashMap::<usize, NodeIndex<usize>>::new(),
             _roqoqo_version: RoqoqoVersion,
         };
 
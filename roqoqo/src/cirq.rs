@@ -0,0 +1,156 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export of roqoqo Circuits to the JSON serialization format of Cirq.
+
+use crate::operations::{InvolveQubits, InvolvedQubits, Operate, OperateTwoQubit, Operation};
+use crate::{Circuit, CircuitDag, RoqoqoBackendError};
+use qoqo_calculator::CalculatorFloat;
+use serde_json::{json, Value};
+
+/// Name of the "backend" used in errors of the Cirq export.
+const CIRQ_BACKEND: &str = "Cirq";
+
+impl Circuit {
+    /// Exports the Circuit to the JSON serialization format of Cirq.
+    ///
+    /// The Circuit is converted to a Cirq `Circuit` of `Moment`s containing `GateOperation`s on `LineQubit`s.
+    /// Operations are grouped into moments using the parallel blocks of the [crate::CircuitDag] of the Circuit.
+    ///
+    /// Supported operations are PauliX, PauliY, PauliZ, Hadamard, SGate, TGate, RotateX, RotateY, RotateZ,
+    /// CNOT, ControlledPauliZ, SWAP, ISwap and MeasureQubit.
+    /// MeasureQubit is exported as a Cirq measurement with the key `{readout}[{readout_index}]`.
+    /// Definitions are skipped as Cirq does not declare classical registers.
+    /// Symbolic rotation angles are only supported when they consist of a single symbol,
+    /// which is exported as a `sympy.Symbol`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The Cirq JSON representation of the Circuit.
+    /// * `Err(RoqoqoBackendError::OperationNotInBackend)` - The Circuit contains an operation without Cirq equivalent.
+    /// * `Err(RoqoqoBackendError::GenericError)` - A symbolic rotation angle cannot be exported.
+    pub fn to_cirq_json(&self) -> Result<String, RoqoqoBackendError> {
+        let mut moments: Vec<Value> = Vec::new();
+        let definitions_only = self.definitions().len() == self.len();
+        if !definitions_only {
+            let dag = CircuitDag::from(self.clone());
+            for mut block in dag.parallel_blocks() {
+                block.sort_unstable();
+                let mut operations: Vec<Value> = Vec::new();
+                for node in block {
+                    let operation = dag
+                        .get(node)
+                        .expect("Node of parallel block is missing in CircuitDag");
+                    if let Some(cirq_operation) = cirq_gate_operation(operation)? {
+                        operations.push(cirq_operation);
+                    }
+                }
+                if !operations.is_empty() {
+                    moments.push(json!({"cirq_type": "Moment", "operations": operations}));
+                }
+            }
+        }
+        Ok(json!({"cirq_type": "Circuit", "moments": moments}).to_string())
+    }
+}
+
+/// Converts an Operation to a Cirq GateOperation, returns `None` for definitions.
+fn cirq_gate_operation(operation: &Operation) -> Result<Option<Value>, RoqoqoBackendError> {
+    let gate = match operation {
+        Operation::PauliX(_) => eigen_gate("_PauliX", 1.0),
+        Operation::PauliY(_) => eigen_gate("_PauliY", 1.0),
+        Operation::PauliZ(_) => eigen_gate("_PauliZ", 1.0),
+        Operation::Hadamard(_) => eigen_gate("HPowGate", 1.0),
+        Operation::SGate(_) => eigen_gate("ZPowGate", 0.5),
+        Operation::TGate(_) => eigen_gate("ZPowGate", 0.25),
+        Operation::RotateX(op) => json!({"cirq_type": "Rx", "rads": cirq_parameter(op.theta())?}),
+        Operation::RotateY(op) => json!({"cirq_type": "Ry", "rads": cirq_parameter(op.theta())?}),
+        Operation::RotateZ(op) => json!({"cirq_type": "Rz", "rads": cirq_parameter(op.theta())?}),
+        Operation::CNOT(_) => eigen_gate("CXPowGate", 1.0),
+        Operation::ControlledPauliZ(_) => eigen_gate("CZPowGate", 1.0),
+        Operation::SWAP(_) => eigen_gate("SwapPowGate", 1.0),
+        Operation::ISwap(_) => eigen_gate("ISwapPowGate", 1.0),
+        Operation::MeasureQubit(op) => json!({
+            "cirq_type": "MeasurementGate",
+            "num_qubits": 1,
+            "key": format!("{}[{}]", op.readout(), op.readout_index()),
+            "invert_mask": []
+        }),
+        Operation::DefinitionBit(_)
+        | Operation::DefinitionFloat(_)
+        | Operation::DefinitionComplex(_)
+        | Operation::DefinitionUsize(_)
+        | Operation::InputSymbolic(_)
+        | Operation::InputBit(_) => return Ok(None),
+        _ => {
+            return Err(RoqoqoBackendError::OperationNotInBackend {
+                backend: CIRQ_BACKEND,
+                hqslang: operation.hqslang(),
+            })
+        }
+    };
+    let qubits: Vec<Value> = ordered_qubits(operation)
+        .into_iter()
+        .map(|qubit| json!({"cirq_type": "LineQubit", "x": qubit}))
+        .collect();
+    Ok(Some(json!({
+        "cirq_type": "GateOperation",
+        "gate": gate,
+        "qubits": qubits
+    })))
+}
+
+/// Returns the qubits of a supported operation in the order expected by the Cirq gate.
+fn ordered_qubits(operation: &Operation) -> Vec<usize> {
+    match operation {
+        Operation::CNOT(op) => vec![*op.control(), *op.target()],
+        Operation::ControlledPauliZ(op) => vec![*op.control(), *op.target()],
+        Operation::SWAP(op) => vec![*op.control(), *op.target()],
+        Operation::ISwap(op) => vec![*op.control(), *op.target()],
+        _ => match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => qubits.into_iter().collect(),
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Returns the JSON representation of a Cirq EigenGate without global phase shift.
+fn eigen_gate(cirq_type: &str, exponent: f64) -> Value {
+    json!({"cirq_type": cirq_type, "exponent": exponent, "global_shift": 0.0})
+}
+
+/// Converts a rotation angle to a JSON number or a `sympy.Symbol`.
+fn cirq_parameter(parameter: &CalculatorFloat) -> Result<Value, RoqoqoBackendError> {
+    match parameter {
+        CalculatorFloat::Float(value) => Ok(json!(value)),
+        CalculatorFloat::Str(expression) => {
+            let is_symbol = expression
+                .chars()
+                .next()
+                .map(|first| first.is_ascii_alphabetic() || first == '_')
+                .unwrap_or(false)
+                && expression
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if is_symbol {
+                Ok(json!({"cirq_type": "sympy.Symbol", "name": expression}))
+            } else {
+                Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Symbolic expression {} cannot be exported to Cirq, only single symbols are supported",
+                        expression
+                    ),
+                })
+            }
+        }
+    }
+}
@@ -17,6 +17,7 @@ use crate::RoqoqoError;
 use ndarray::Array2;
 /// A device assuming all-to-all connectivity between all involved qubits.
 ///
+/// As with [GenericDevice], binary serialization fails once calibrated gate errors are set.
 #[derive(Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
@@ -51,6 +52,8 @@ impl AllToAllDevice {
             two_qubit_gates: HashMap::with_capacity(two_qubit_gates.len()),
             multi_qubit_gates: HashMap::new(),
             decoherence_rates: HashMap::with_capacity(number_qubits),
+            single_qubit_gate_errors: HashMap::new(),
+            two_qubit_gate_errors: HashMap::new(),
         };
         let mut new = Self {
             number_qubits,
@@ -175,6 +178,52 @@ impl AllToAllDevice {
             .set_two_qubit_gate_time(gate, control, target, gate_time)
     }
 
+    /// Setting the calibrated error rate of a single qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    /// * `qubit` - The qubit for which the gate error is set.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate error was correctly set.
+    /// * `Err(RoqoqoError::GenericError)` - Qubit is larger than the number of qubits in the device or the error is not between 0 and 1.
+    pub fn set_single_qubit_gate_error(
+        &mut self,
+        gate: &str,
+        qubit: usize,
+        error: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.generic_device
+            .set_single_qubit_gate_error(gate, qubit, error)
+    }
+
+    /// Setting the calibrated error rate of a two qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    /// * `control` - The control qubit for which the gate error is set.
+    /// * `target` - The target qubit for which the gate error is set.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate error was correctly set.
+    /// * `Err(RoqoqoError::GenericError)` - A qubit is larger than the number of qubits in the device or the error is not between 0 and 1.
+    pub fn set_two_qubit_gate_error(
+        &mut self,
+        gate: &str,
+        control: usize,
+        target: usize,
+        error: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.generic_device
+            .set_two_qubit_gate_error(gate, control, target, error)
+    }
+
     /// Setting the gate time of a two qubit gate.
     ///
     /// # Arguments
@@ -372,6 +421,103 @@ impl AllToAllDevice {
         }
         self
     }
+
+    /// Builder method setting a uniform gate time for a single-qubit-gate, adding the gate if it is not available yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    /// * `gate_time` - gate time for the given gate type, valid for all qubits in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice with updated gate times.
+    pub fn with_single_qubit_gate_time(self, gate: &str, gate_time: f64) -> Self {
+        self.set_all_single_qubit_gate_times(gate, gate_time)
+    }
+
+    /// Builder method setting a uniform gate time for a two-qubit-gate, adding the gate if it is not available yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    /// * `gate_time` - gate time for the given gate type, valid for all edges in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice with updated gate times.
+    pub fn with_two_qubit_gate_time(self, gate: &str, gate_time: f64) -> Self {
+        self.set_all_two_qubit_gate_times(gate, gate_time)
+    }
+
+    /// Builder method removing a single-qubit-gate from the available gates of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice without the gate.
+    pub fn without_single_qubit_gate(mut self, gate: &str) -> Self {
+        self.generic_device.single_qubit_gates.remove(gate);
+        self.generic_device.single_qubit_gate_errors.remove(gate);
+        self
+    }
+
+    /// Builder method removing a two-qubit-gate from the available gates of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice without the gate.
+    pub fn without_two_qubit_gate(mut self, gate: &str) -> Self {
+        self.generic_device.two_qubit_gates.remove(gate);
+        self.generic_device.two_qubit_gate_errors.remove(gate);
+        self
+    }
+
+    /// Builder method adding damping to the noise rates of all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `damping` - The damping rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice with updated damping.
+    pub fn with_damping_rates(self, damping: f64) -> Self {
+        self.add_damping_all(damping)
+    }
+
+    /// Builder method adding dephasing to the noise rates of all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `dephasing` - The dephasing rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice with updated dephasing.
+    pub fn with_dephasing_rates(self, dephasing: f64) -> Self {
+        self.add_dephasing_all(dephasing)
+    }
+
+    /// Builder method adding depolarising noise to the noise rates of all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `depolarising` - The depolarising rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The AllToAllDevice with updated depolarising.
+    pub fn with_depolarising_rates(self, depolarising: f64) -> Self {
+        self.add_depolarising_all(depolarising)
+    }
 }
 
 /// Implements Device trait for AllToAllDevice.
@@ -426,6 +572,15 @@ impl Device for AllToAllDevice {
         self.generic_device.qubit_decoherence_rates(qubit)
     }
 
+    fn single_qubit_gate_error(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        self.generic_device.single_qubit_gate_error(hqslang, qubit)
+    }
+
+    fn two_qubit_gate_error(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        self.generic_device
+            .two_qubit_gate_error(hqslang, control, target)
+    }
+
     fn to_generic_device(&self) -> GenericDevice {
         self.generic_device.clone()
     }
@@ -0,0 +1,643 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Human-editable configuration files for the roqoqo devices.
+//!
+//! The config format is independent of the serde representation of the devices.
+//! Gate times are given as default times per gate, that are valid for all qubits (single-qubit gates)
+//! or all edges (two-qubit gates) of the device, and lists of overrides for single qubits, edges or
+//! sets of qubits. Decoherence rates are given as damping, dephasing and depolarising rates,
+//! with a default for all qubits and per-qubit overrides.
+//!
+//! Calibrated gate errors are not part of the config format.
+//!
+//! Example config of an [crate::devices::AllToAllDevice]:
+//!
+//! ```json
+//! {
+//!   "number_qubits": 3,
+//!   "single_qubit_gates": {"RotateZ": 1.0, "RotateX": 2.0},
+//!   "two_qubit_gates": {"CNOT": 10.0},
+//!   "single_qubit_gate_times": [{"gate": "RotateX", "qubit": 2, "time": 3.0}],
+//!   "two_qubit_gate_times": [{"gate": "CNOT", "control": 0, "target": 1, "time": 12.0}],
+//!   "multi_qubit_gate_times": [{"gate": "MultiQubitMS", "qubits": [0, 1, 2], "time": 20.0}],
+//!   "decoherence_rates": {"damping": 0.001},
+//!   "qubit_decoherence_rates": [{"qubit": 1, "damping": 0.002, "dephasing": 0.0005}]
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+
+use ndarray::{array, Array2};
+
+use crate::devices::{AllToAllDevice, Device, GenericDevice, SquareLatticeDevice};
+use crate::{RoqoqoBackendError, RoqoqoError};
+
+/// Config of an [crate::devices::AllToAllDevice].
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct AllToAllDeviceConfig {
+    /// The number of qubits in the device.
+    pub number_qubits: usize,
+    /// The gate times and decoherence rates of the device.
+    #[serde(flatten)]
+    pub settings: DeviceSettingsConfig,
+}
+
+/// Config of a [crate::devices::SquareLatticeDevice].
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct SquareLatticeDeviceConfig {
+    /// The number of rows in the square lattice.
+    pub number_rows: usize,
+    /// The number of columns in the square lattice.
+    pub number_columns: usize,
+    /// The gate times and decoherence rates of the device.
+    #[serde(flatten)]
+    pub settings: DeviceSettingsConfig,
+}
+
+/// Config of a [crate::devices::GenericDevice].
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct GenericDeviceConfig {
+    /// The number of qubits in the device.
+    pub number_qubits: usize,
+    /// The gate times and decoherence rates of the device.
+    #[serde(flatten)]
+    pub settings: DeviceSettingsConfig,
+}
+
+/// Gate times and decoherence rates shared by all device configs.
+///
+/// Default gate times are applied first, overrides replace the default time
+/// of a gate on the given qubits. Per-qubit decoherence rates replace the default rates.
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct DeviceSettingsConfig {
+    /// Default gate times of the single-qubit gates, valid for all qubits of the device.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub single_qubit_gates: BTreeMap<String, f64>,
+    /// Default gate times of the two-qubit gates, valid for all edges of the device in both directions.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub two_qubit_gates: BTreeMap<String, f64>,
+    /// Gate times of single-qubit gates on single qubits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub single_qubit_gate_times: Vec<SingleQubitGateTimeConfig>,
+    /// Gate times of two-qubit gates on single edges.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub two_qubit_gate_times: Vec<TwoQubitGateTimeConfig>,
+    /// Gate times of three-qubit and multi-qubit gates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multi_qubit_gate_times: Vec<MultiQubitGateTimeConfig>,
+    /// Default decoherence rates, valid for all qubits of the device.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoherence_rates: Option<DecoherenceRatesConfig>,
+    /// Decoherence rates of single qubits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub qubit_decoherence_rates: Vec<QubitDecoherenceRatesConfig>,
+}
+
+/// Gate time of a single-qubit gate on one qubit.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct SingleQubitGateTimeConfig {
+    /// The hqslang name of the gate.
+    pub gate: String,
+    /// The qubit the gate acts on.
+    pub qubit: usize,
+    /// The gate time.
+    pub time: f64,
+}
+
+/// Gate time of a two-qubit gate on one edge.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct TwoQubitGateTimeConfig {
+    /// The hqslang name of the gate.
+    pub gate: String,
+    /// The control qubit of the gate.
+    pub control: usize,
+    /// The target qubit of the gate.
+    pub target: usize,
+    /// The gate time.
+    pub time: f64,
+}
+
+/// Gate time of a three-qubit or multi-qubit gate on a list of qubits.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct MultiQubitGateTimeConfig {
+    /// The hqslang name of the gate.
+    pub gate: String,
+    /// The qubits the gate acts on.
+    pub qubits: Vec<usize>,
+    /// The gate time.
+    pub time: f64,
+}
+
+/// Decoherence rates of a qubit.
+///
+/// The rates are combined into the (3x3) decoherence rate matrix in the same way as
+/// `add_damping`, `add_dephasing` and `add_depolarising` of the devices.
+#[derive(Clone, Copy, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct DecoherenceRatesConfig {
+    /// The damping rate.
+    #[serde(default)]
+    pub damping: f64,
+    /// The dephasing rate.
+    #[serde(default)]
+    pub dephasing: f64,
+    /// The depolarising rate.
+    #[serde(default)]
+    pub depolarising: f64,
+}
+
+/// Decoherence rates of one qubit, replacing the default decoherence rates.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct QubitDecoherenceRatesConfig {
+    /// The qubit the rates apply to.
+    pub qubit: usize,
+    /// The decoherence rates of the qubit.
+    #[serde(flatten)]
+    pub rates: DecoherenceRatesConfig,
+}
+
+impl DecoherenceRatesConfig {
+    /// Returns the (3x3) decoherence rate matrix of the rates.
+    pub fn to_matrix(&self) -> Array2<f64> {
+        array![
+            [self.damping + self.depolarising / 2.0, 0.0, 0.0],
+            [0.0, self.depolarising / 2.0, 0.0],
+            [0.0, 0.0, self.dephasing + self.depolarising / 4.0]
+        ]
+    }
+
+    /// Decomposes a (3x3) decoherence rate matrix into damping, dephasing and depolarising rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `rates` - The decoherence rate matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The decomposed rates.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The matrix is not a diagonal (3x3)-matrix.
+    pub fn from_matrix(rates: &Array2<f64>) -> Result<Self, RoqoqoBackendError> {
+        let is_diagonal = rates.shape() == [3, 3]
+            && rates
+                .indexed_iter()
+                .all(|((row, column), value)| row == column || *value == 0.0);
+        if !is_diagonal {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Decoherence rates {} cannot be expressed as damping, dephasing and depolarising rates",
+                    rates
+                ),
+            });
+        }
+        let depolarising = 2.0 * rates[[1, 1]];
+        Ok(Self {
+            damping: rates[[0, 0]] - rates[[1, 1]],
+            dephasing: rates[[2, 2]] - rates[[1, 1]] / 2.0,
+            depolarising,
+        })
+    }
+}
+
+/// Device setters used to apply a config, shared by all devices with a config format.
+trait ConfigurableDevice: Device {
+    /// Returns all directed edges a default two-qubit gate time applies to.
+    fn config_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for (control, target) in self.two_qubit_edges() {
+            edges.push((control, target));
+            edges.push((target, control));
+        }
+        edges
+    }
+
+    fn set_single_qubit_time(
+        &mut self,
+        gate: &str,
+        qubit: usize,
+        time: f64,
+    ) -> Result<(), RoqoqoError>;
+
+    fn set_two_qubit_time(
+        &mut self,
+        gate: &str,
+        control: usize,
+        target: usize,
+        time: f64,
+    ) -> Result<(), RoqoqoError>;
+
+    fn set_multi_qubit_time(
+        &mut self,
+        gate: &str,
+        qubits: Vec<usize>,
+        time: f64,
+    ) -> Result<(), RoqoqoError>;
+
+    fn set_rates(&mut self, qubit: usize, rates: Array2<f64>) -> Result<(), RoqoqoError>;
+}
+
+macro_rules! impl_configurable_device {
+    ($device:ty) => {
+        impl ConfigurableDevice for $device {
+            fn set_single_qubit_time(
+                &mut self,
+                gate: &str,
+                qubit: usize,
+                time: f64,
+            ) -> Result<(), RoqoqoError> {
+                self.set_single_qubit_gate_time(gate, qubit, time)
+            }
+
+            fn set_two_qubit_time(
+                &mut self,
+                gate: &str,
+                control: usize,
+                target: usize,
+                time: f64,
+            ) -> Result<(), RoqoqoError> {
+                self.set_two_qubit_gate_time(gate, control, target, time)
+            }
+
+            fn set_multi_qubit_time(
+                &mut self,
+                gate: &str,
+                qubits: Vec<usize>,
+                time: f64,
+            ) -> Result<(), RoqoqoError> {
+                self.set_multi_qubit_gate_time(gate, qubits, time)
+            }
+
+            fn set_rates(&mut self, qubit: usize, rates: Array2<f64>) -> Result<(), RoqoqoError> {
+                self.set_qubit_decoherence_rates(qubit, rates)
+            }
+        }
+    };
+}
+
+impl_configurable_device!(AllToAllDevice);
+impl_configurable_device!(SquareLatticeDevice);
+
+impl ConfigurableDevice for GenericDevice {
+    fn config_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for control in 0..self.number_qubits {
+            for target in 0..self.number_qubits {
+                if control != target {
+                    edges.push((control, target));
+                }
+            }
+        }
+        edges
+    }
+
+    fn set_single_qubit_time(
+        &mut self,
+        gate: &str,
+        qubit: usize,
+        time: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.set_single_qubit_gate_time(gate, qubit, time)
+    }
+
+    fn set_two_qubit_time(
+        &mut self,
+        gate: &str,
+        control: usize,
+        target: usize,
+        time: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.set_two_qubit_gate_time(gate, control, target, time)
+    }
+
+    fn set_multi_qubit_time(
+        &mut self,
+        gate: &str,
+        qubits: Vec<usize>,
+        time: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.set_multi_qubit_gate_time(gate, qubits, time)
+    }
+
+    fn set_rates(&mut self, qubit: usize, rates: Array2<f64>) -> Result<(), RoqoqoError> {
+        self.set_qubit_decoherence_rates(qubit, rates)
+    }
+}
+
+/// Returns an error when a qubit of the config is outside the device.
+fn check_config_qubit(qubit: usize, number_qubits: usize) -> Result<(), RoqoqoBackendError> {
+    if qubit >= number_qubits {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Qubit {} in device config out of range for device of size {}",
+                qubit, number_qubits
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Applies the gate times and decoherence rates of a config to a device.
+fn apply_settings<T: ConfigurableDevice>(
+    device: &mut T,
+    settings: &DeviceSettingsConfig,
+) -> Result<(), RoqoqoBackendError> {
+    let number_qubits = device.number_qubits();
+    for (gate, time) in settings.single_qubit_gates.iter() {
+        for qubit in 0..number_qubits {
+            device.set_single_qubit_time(gate, qubit, *time)?;
+        }
+    }
+    let edges = device.config_edges();
+    for (gate, time) in settings.two_qubit_gates.iter() {
+        for (control, target) in edges.iter() {
+            device.set_two_qubit_time(gate, *control, *target, *time)?;
+        }
+    }
+    for entry in settings.single_qubit_gate_times.iter() {
+        check_config_qubit(entry.qubit, number_qubits)?;
+        device.set_single_qubit_time(&entry.gate, entry.qubit, entry.time)?;
+    }
+    for entry in settings.two_qubit_gate_times.iter() {
+        check_config_qubit(entry.control, number_qubits)?;
+        check_config_qubit(entry.target, number_qubits)?;
+        device.set_two_qubit_time(&entry.gate, entry.control, entry.target, entry.time)?;
+    }
+    for entry in settings.multi_qubit_gate_times.iter() {
+        for qubit in entry.qubits.iter() {
+            check_config_qubit(*qubit, number_qubits)?;
+        }
+        device.set_multi_qubit_time(&entry.gate, entry.qubits.clone(), entry.time)?;
+    }
+    if let Some(rates) = settings.decoherence_rates {
+        for qubit in 0..number_qubits {
+            device.set_rates(qubit, rates.to_matrix())?;
+        }
+    }
+    for entry in settings.qubit_decoherence_rates.iter() {
+        check_config_qubit(entry.qubit, number_qubits)?;
+        device.set_rates(entry.qubit, entry.rates.to_matrix())?;
+    }
+    Ok(())
+}
+
+/// Returns the most common value, earlier values win ties.
+fn most_common<T: PartialEq + Copy>(values: impl Iterator<Item = T>) -> Option<T> {
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(counted, _)| *counted == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+    let max_count = counts.iter().map(|(_, count)| *count).max()?;
+    counts
+        .into_iter()
+        .find(|(_, count)| *count == max_count)
+        .map(|(value, _)| value)
+}
+
+/// Extracts the gate times and decoherence rates of a device into a config.
+///
+/// The most common time of a gate defined on all qubits (or edges) becomes the default time,
+/// all other times are written as overrides. The same applies to the decoherence rates.
+fn extract_settings<T: ConfigurableDevice>(
+    device: &T,
+) -> Result<DeviceSettingsConfig, RoqoqoBackendError> {
+    let generic = device.to_generic_device();
+    let number_qubits = device.number_qubits();
+    let mut settings = DeviceSettingsConfig::default();
+
+    for (gate, times) in generic.single_qubit_gates.iter() {
+        let mut entries: Vec<(usize, f64)> = times.iter().map(|(q, t)| (*q, *t)).collect();
+        entries.sort_by_key(|(qubit, _)| *qubit);
+        let default = if (0..number_qubits).all(|qubit| times.contains_key(&qubit)) {
+            most_common(entries.iter().map(|(_, time)| *time))
+        } else {
+            None
+        };
+        if let Some(default) = default {
+            settings.single_qubit_gates.insert(gate.clone(), default);
+        }
+        for (qubit, time) in entries {
+            if Some(time) != default {
+                settings
+                    .single_qubit_gate_times
+                    .push(SingleQubitGateTimeConfig {
+                        gate: gate.clone(),
+                        qubit,
+                        time,
+                    });
+            }
+        }
+    }
+
+    let edges = device.config_edges();
+    for (gate, times) in generic.two_qubit_gates.iter() {
+        let mut entries: Vec<((usize, usize), f64)> = times.iter().map(|(e, t)| (*e, *t)).collect();
+        entries.sort_by_key(|(edge, _)| *edge);
+        let default = if edges.iter().all(|edge| times.contains_key(edge)) {
+            most_common(entries.iter().map(|(_, time)| *time))
+        } else {
+            None
+        };
+        if let Some(default) = default {
+            settings.two_qubit_gates.insert(gate.clone(), default);
+        }
+        for ((control, target), time) in entries {
+            if Some(time) != default {
+                settings.two_qubit_gate_times.push(TwoQubitGateTimeConfig {
+                    gate: gate.clone(),
+                    control,
+                    target,
+                    time,
+                });
+            }
+        }
+    }
+
+    for (gate, times) in generic.multi_qubit_gates.iter() {
+        for (qubits, time) in times.iter() {
+            settings
+                .multi_qubit_gate_times
+                .push(MultiQubitGateTimeConfig {
+                    gate: gate.clone(),
+                    qubits: qubits.clone(),
+                    time: *time,
+                });
+        }
+    }
+
+    let mut rates: Vec<(usize, DecoherenceRatesConfig)> = Vec::new();
+    for (qubit, matrix) in generic.decoherence_rates.iter() {
+        rates.push((*qubit, DecoherenceRatesConfig::from_matrix(matrix)?));
+    }
+    rates.sort_by_key(|(qubit, _)| *qubit);
+    let qubits_with_rates: HashSet<usize> = rates.iter().map(|(qubit, _)| *qubit).collect();
+    if (0..number_qubits).all(|qubit| qubits_with_rates.contains(&qubit)) {
+        settings.decoherence_rates = most_common(rates.iter().map(|(_, rates)| *rates));
+    }
+    for (qubit, qubit_rates) in rates {
+        if Some(qubit_rates) != settings.decoherence_rates {
+            settings
+                .qubit_decoherence_rates
+                .push(QubitDecoherenceRatesConfig {
+                    qubit,
+                    rates: qubit_rates,
+                });
+        }
+    }
+
+    settings
+        .single_qubit_gate_times
+        .sort_by(|a, b| (&a.gate, a.qubit).cmp(&(&b.gate, b.qubit)));
+    settings
+        .two_qubit_gate_times
+        .sort_by(|a, b| (&a.gate, a.control, a.target).cmp(&(&b.gate, b.control, b.target)));
+    settings
+        .multi_qubit_gate_times
+        .sort_by(|a, b| (&a.gate, &a.qubits).cmp(&(&b.gate, &b.qubits)));
+    Ok(settings)
+}
+
+/// Deserializes a config from json, mapping the serde error to a RoqoqoBackendError.
+fn config_from_json<'a, T: serde::Deserialize<'a>>(json: &'a str) -> Result<T, RoqoqoBackendError> {
+    serde_json::from_str(json).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!("Cannot deserialize device config from json: {}", err),
+    })
+}
+
+/// Serializes a config to pretty-printed json, mapping the serde error to a RoqoqoBackendError.
+fn config_to_json<T: serde::Serialize>(config: &T) -> Result<String, RoqoqoBackendError> {
+    serde_json::to_string_pretty(config).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!("Cannot serialize device config to json: {}", err),
+    })
+}
+
+impl AllToAllDevice {
+    /// Creates an AllToAllDevice from a json config file.
+    ///
+    /// See [crate::devices::AllToAllDeviceConfig] for the config format.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The json config of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The device described by the config.
+    /// * `Err(RoqoqoBackendError)` - The config cannot be deserialized or contains invalid qubits.
+    pub fn from_config_json(json: &str) -> Result<Self, RoqoqoBackendError> {
+        let config: AllToAllDeviceConfig = config_from_json(json)?;
+        let mut device = AllToAllDevice::new(config.number_qubits, &[], &[], 0.0);
+        apply_settings(&mut device, &config.settings)?;
+        Ok(device)
+    }
+
+    /// Returns the json config of the AllToAllDevice.
+    ///
+    /// Inverse of [AllToAllDevice::from_config_json], calibrated gate errors are not part of the config.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The pretty-printed json config of the device.
+    /// * `Err(RoqoqoBackendError)` - The decoherence rates of a qubit are not diagonal.
+    pub fn to_config_json(&self) -> Result<String, RoqoqoBackendError> {
+        config_to_json(&AllToAllDeviceConfig {
+            number_qubits: self.number_qubits(),
+            settings: extract_settings(self)?,
+        })
+    }
+}
+
+impl SquareLatticeDevice {
+    /// Creates a SquareLatticeDevice from a json config file.
+    ///
+    /// See [crate::devices::SquareLatticeDeviceConfig] for the config format.
+    /// Default two-qubit gate times are set for all edges of the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The json config of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The device described by the config.
+    /// * `Err(RoqoqoBackendError)` - The config cannot be deserialized or contains invalid qubits or edges.
+    pub fn from_config_json(json: &str) -> Result<Self, RoqoqoBackendError> {
+        let config: SquareLatticeDeviceConfig = config_from_json(json)?;
+        let mut device =
+            SquareLatticeDevice::new(config.number_rows, config.number_columns, &[], &[], 0.0);
+        apply_settings(&mut device, &config.settings)?;
+        Ok(device)
+    }
+
+    /// Returns the json config of the SquareLatticeDevice.
+    ///
+    /// Inverse of [SquareLatticeDevice::from_config_json], calibrated gate errors are not part of the config.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The pretty-printed json config of the device.
+    /// * `Err(RoqoqoBackendError)` - The decoherence rates of a qubit are not diagonal.
+    pub fn to_config_json(&self) -> Result<String, RoqoqoBackendError> {
+        config_to_json(&SquareLatticeDeviceConfig {
+            number_rows: self.number_rows(),
+            number_columns: self.number_columns(),
+            settings: extract_settings(self)?,
+        })
+    }
+}
+
+impl GenericDevice {
+    /// Creates a GenericDevice from a json config file.
+    ///
+    /// See [crate::devices::GenericDeviceConfig] for the config format.
+    /// Default two-qubit gate times are set for all pairs of qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The json config of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The device described by the config.
+    /// * `Err(RoqoqoBackendError)` - The config cannot be deserialized or contains invalid qubits.
+    pub fn from_config_json(json: &str) -> Result<Self, RoqoqoBackendError> {
+        let config: GenericDeviceConfig = config_from_json(json)?;
+        let mut device = GenericDevice::new(config.number_qubits);
+        apply_settings(&mut device, &config.settings)?;
+        Ok(device)
+    }
+
+    /// Returns the json config of the GenericDevice.
+    ///
+    /// Inverse of [GenericDevice::from_config_json], calibrated gate errors are not part of the config.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The pretty-printed json config of the device.
+    /// * `Err(RoqoqoBackendError)` - The decoherence rates of a qubit are not diagonal.
+    pub fn to_config_json(&self) -> Result<String, RoqoqoBackendError> {
+        config_to_json(&GenericDeviceConfig {
+            number_qubits: self.number_qubits,
+            settings: extract_settings(self)?,
+        })
+    }
+}
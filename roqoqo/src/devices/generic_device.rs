@@ -10,24 +10,29 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use super::Device;
+#[cfg(feature = "serialize")]
+use crate::operations::PragmaSetGateTime;
 #[cfg(feature = "json_schema")]
 use crate::Array2f64Def;
+#[cfg(feature = "serialize")]
+use crate::RoqoqoBackendError;
 use crate::RoqoqoError;
 use crate::RoqoqoVersionSerializable;
-use ndarray::{array, Array2};
+use ndarray::{array, s, Array1, Array2, Array3};
 /// A generic device assuming all-to-all connectivity between all involved qubits.
 ///
 /// # Note
 ///
 /// GenericDevice uses nested HashMaps to represent the most general device connectivity.
 /// The memory usage will be inefficient for devices with large qubit numbers.
+///
+/// The calibrated gate errors are not part of the binary (bincode) serialization,
+/// which is unchanged since roqoqo 1.0. Serializing a GenericDevice with gate errors
+/// to a binary format fails, it can only be serialized to human readable formats like json.
 #[derive(Clone, Debug, PartialEq, Default)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serialize", serde(from = "GenericDeviceSerialize"))]
-#[cfg_attr(feature = "serialize", serde(into = "GenericDeviceSerialize"))]
 pub struct GenericDevice {
     /// The number of qubits
     pub number_qubits: usize,
@@ -39,6 +44,10 @@ pub struct GenericDevice {
     pub multi_qubit_gates: HashMap<String, HashMap<Vec<usize>, f64>>,
     /// Decoherence rates for all qubits
     pub decoherence_rates: HashMap<usize, Array2<f64>>,
+    /// Calibrated error rates of single qubit gates
+    pub single_qubit_gate_errors: HashMap<String, HashMap<usize, f64>>,
+    /// Calibrated error rates of two qubit gates
+    pub two_qubit_gate_errors: HashMap<String, TwoQubitGates>,
 }
 
 #[cfg(feature = "json_schema")]
@@ -61,16 +70,116 @@ struct GenericDeviceSerialize {
     /// The number of qubits
     number_qubits: usize,
     /// Gate times for all single qubit gates
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
     single_qubit_gates: HashMap<String, Vec<(usize, f64)>>,
     /// Gate times for all two qubit gates
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
     two_qubit_gates: HashMap<String, TwoQubitGatesVec>,
     /// Gate times for all multi qubit gates
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
     multi_qubit_gates: HashMap<String, Vec<(Vec<usize>, f64)>>,
     /// Decoherence rates for all qubits
     decoherence_rates: Vec<(usize, Array2<f64>)>,
+    /// Calibrated error rates of single qubit gates
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            default,
+            serialize_with = "crate::sorted_serialization::serialize_sorted_map"
+        )
+    )]
+    single_qubit_gate_errors: HashMap<String, Vec<(usize, f64)>>,
+    /// Calibrated error rates of two qubit gates
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            default,
+            serialize_with = "crate::sorted_serialization::serialize_sorted_map"
+        )
+    )]
+    two_qubit_gate_errors: HashMap<String, TwoQubitGatesVec>,
+    _roqoqo_version: RoqoqoVersionSerializable,
+}
+
+// Serialized form of GenericDevice in binary formats, unchanged since roqoqo 1.0.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "GenericDeviceSerialize")]
+struct GenericDeviceSerializeCompact {
+    number_qubits: usize,
+    #[serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")]
+    single_qubit_gates: HashMap<String, Vec<(usize, f64)>>,
+    #[serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")]
+    two_qubit_gates: HashMap<String, TwoQubitGatesVec>,
+    #[serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")]
+    multi_qubit_gates: HashMap<String, Vec<(Vec<usize>, f64)>>,
+    decoherence_rates: Vec<(usize, Array2<f64>)>,
     _roqoqo_version: RoqoqoVersionSerializable,
 }
 
+#[cfg(feature = "serialize")]
+impl serde::Serialize for GenericDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let helper = GenericDeviceSerialize::from(self.clone());
+        if serializer.is_human_readable() {
+            helper.serialize(serializer)
+        } else if helper.single_qubit_gate_errors.is_empty()
+            && helper.two_qubit_gate_errors.is_empty()
+        {
+            GenericDeviceSerializeCompact {
+                number_qubits: helper.number_qubits,
+                single_qubit_gates: helper.single_qubit_gates,
+                two_qubit_gates: helper.two_qubit_gates,
+                multi_qubit_gates: helper.multi_qubit_gates,
+                decoherence_rates: helper.decoherence_rates,
+                _roqoqo_version: helper._roqoqo_version,
+            }
+            .serialize(serializer)
+        } else {
+            Err(serde::ser::Error::custom(
+                "GenericDevice with calibrated gate errors can only be serialized to human readable formats",
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for GenericDevice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            Ok(GenericDeviceSerialize::deserialize(deserializer)?.into())
+        } else {
+            let helper = GenericDeviceSerializeCompact::deserialize(deserializer)?;
+            Ok(GenericDeviceSerialize {
+                number_qubits: helper.number_qubits,
+                single_qubit_gates: helper.single_qubit_gates,
+                two_qubit_gates: helper.two_qubit_gates,
+                multi_qubit_gates: helper.multi_qubit_gates,
+                decoherence_rates: helper.decoherence_rates,
+                single_qubit_gate_errors: HashMap::new(),
+                two_qubit_gate_errors: HashMap::new(),
+                _roqoqo_version: helper._roqoqo_version,
+            }
+            .into())
+        }
+    }
+}
+
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[allow(dead_code)]
 struct SchemaHelperGenericDeviceSerialize<Array2f64Def> {
@@ -84,6 +193,12 @@ struct SchemaHelperGenericDeviceSerialize<Array2f64Def> {
     multi_qubit_gates: HashMap<String, Vec<(Vec<usize>, f64)>>,
     /// Decoherence rates for all qubits
     decoherence_rates: Vec<(usize, Array2f64Def)>,
+    /// Calibrated error rates of single qubit gates
+    #[cfg_attr(feature = "json_schema", schemars(default))]
+    single_qubit_gate_errors: HashMap<String, Vec<(usize, f64)>>,
+    /// Calibrated error rates of two qubit gates
+    #[cfg_attr(feature = "json_schema", schemars(default))]
+    two_qubit_gate_errors: HashMap<String, TwoQubitGatesVec>,
     _roqoqo_version: RoqoqoVersionSerializable,
 }
 
@@ -111,6 +226,16 @@ impl From<GenericDeviceSerialize> for GenericDevice {
             let new_map: HashMap<Vec<usize>, f64> = map.into_iter().collect();
             multi_qubit_gates.insert(name, new_map);
         }
+        let single_qubit_gate_errors: HashMap<String, HashMap<usize, f64>> = value
+            .single_qubit_gate_errors
+            .into_iter()
+            .map(|(name, map)| (name, map.into_iter().collect()))
+            .collect();
+        let two_qubit_gate_errors: HashMap<String, TwoQubitGates> = value
+            .two_qubit_gate_errors
+            .into_iter()
+            .map(|(name, map)| (name, map.into_iter().collect()))
+            .collect();
 
         let new_device: GenericDevice = GenericDevice {
             number_qubits: value.number_qubits,
@@ -118,6 +243,8 @@ impl From<GenericDeviceSerialize> for GenericDevice {
             two_qubit_gates,
             multi_qubit_gates,
             decoherence_rates,
+            single_qubit_gate_errors,
+            two_qubit_gate_errors,
         };
         new_device
     }
@@ -132,21 +259,44 @@ impl From<GenericDevice> for GenericDeviceSerialize {
         let mut multi_qubit_gates: HashMap<String, Vec<(Vec<usize>, f64)>> =
             HashMap::with_capacity(value.two_qubit_gates.len());
 
-        let decoherence_rates: Vec<(usize, Array2<f64>)> =
+        // Entries are sorted by key so that the serialized device does not depend on HashMap ordering
+        let mut decoherence_rates: Vec<(usize, Array2<f64>)> =
             value.decoherence_rates.into_iter().collect();
+        decoherence_rates.sort_by_key(|(qubit, _)| *qubit);
 
         for (name, map) in value.two_qubit_gates.into_iter() {
-            let new_map: TwoQubitGatesVec = map.into_iter().collect();
+            let mut new_map: TwoQubitGatesVec = map.into_iter().collect();
+            new_map.sort_by_key(|(qubits, _)| *qubits);
             two_qubit_gates.insert(name, new_map);
         }
         for (name, map) in value.single_qubit_gates.into_iter() {
-            let new_map: Vec<(usize, f64)> = map.into_iter().collect();
+            let mut new_map: Vec<(usize, f64)> = map.into_iter().collect();
+            new_map.sort_by_key(|(qubit, _)| *qubit);
             single_qubit_gates.insert(name, new_map);
         }
         for (name, map) in value.multi_qubit_gates.into_iter() {
-            let new_map: Vec<(Vec<usize>, f64)> = map.into_iter().collect();
+            let mut new_map: Vec<(Vec<usize>, f64)> = map.into_iter().collect();
+            new_map.sort_by(|(qubits_a, _), (qubits_b, _)| qubits_a.cmp(qubits_b));
             multi_qubit_gates.insert(name, new_map);
         }
+        let single_qubit_gate_errors: HashMap<String, Vec<(usize, f64)>> = value
+            .single_qubit_gate_errors
+            .into_iter()
+            .map(|(name, map)| {
+                let mut new_map: Vec<(usize, f64)> = map.into_iter().collect();
+                new_map.sort_by_key(|(qubit, _)| *qubit);
+                (name, new_map)
+            })
+            .collect();
+        let two_qubit_gate_errors: HashMap<String, TwoQubitGatesVec> = value
+            .two_qubit_gate_errors
+            .into_iter()
+            .map(|(name, map)| {
+                let mut new_map: TwoQubitGatesVec = map.into_iter().collect();
+                new_map.sort_by_key(|(qubits, _)| *qubits);
+                (name, new_map)
+            })
+            .collect();
         let current_version = RoqoqoVersionSerializable {
             major_version: 1,
             minor_version: 1,
@@ -158,6 +308,8 @@ impl From<GenericDevice> for GenericDeviceSerialize {
             two_qubit_gates,
             multi_qubit_gates,
             decoherence_rates,
+            single_qubit_gate_errors,
+            two_qubit_gate_errors,
             _roqoqo_version: current_version,
         };
         new_device
@@ -179,6 +331,8 @@ impl GenericDevice {
             two_qubit_gates: HashMap::new(),
             multi_qubit_gates: HashMap::new(),
             decoherence_rates: HashMap::new(),
+            single_qubit_gate_errors: HashMap::new(),
+            two_qubit_gate_errors: HashMap::new(),
         }
     }
 
@@ -357,6 +511,80 @@ impl GenericDevice {
         Ok(())
     }
 
+    /// Setting the calibrated error rate of a single qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    /// * `qubit` - The qubit for which the gate error is set.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate error was correctly set.
+    /// * `Err(RoqoqoError::GenericError)` - Qubit is larger than the number of qubits in the device or the error is not between 0 and 1.
+    pub fn set_single_qubit_gate_error(
+        &mut self,
+        gate: &str,
+        qubit: usize,
+        error: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_gate_error(&[qubit], error)?;
+        self.single_qubit_gate_errors
+            .entry(gate.to_string())
+            .or_default()
+            .insert(qubit, error);
+        Ok(())
+    }
+
+    /// Setting the calibrated error rate of a two qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    /// * `control` - The control qubit for which the gate error is set.
+    /// * `target` - The target qubit for which the gate error is set.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate error was correctly set.
+    /// * `Err(RoqoqoError::GenericError)` - A qubit is larger than the number of qubits in the device or the error is not between 0 and 1.
+    pub fn set_two_qubit_gate_error(
+        &mut self,
+        gate: &str,
+        control: usize,
+        target: usize,
+        error: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_gate_error(&[control, target], error)?;
+        self.two_qubit_gate_errors
+            .entry(gate.to_string())
+            .or_default()
+            .insert((control, target), error);
+        Ok(())
+    }
+
+    /// Checks the qubits and the value of a gate error before it is set.
+    fn check_gate_error(&self, qubits: &[usize], error: f64) -> Result<(), RoqoqoError> {
+        for qubit in qubits {
+            if *qubit >= self.number_qubits {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Qubit {} larger than number qubits {}",
+                        qubit, self.number_qubits
+                    ),
+                });
+            }
+        }
+        if !(0.0..=1.0).contains(&error) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Gate error {} is not between 0 and 1", error),
+            });
+        }
+        Ok(())
+    }
+
     /// Function to set the decoherence rates for all qubits in the device.
     ///
     /// # Arguments
@@ -392,6 +620,57 @@ impl GenericDevice {
         }
     }
 
+    /// Returns the gate times of a single-qubit gate on all qubits of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the single-qubit gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Array1<f64>` - The gate time on each qubit, NaN where the gate is not available.
+    pub fn single_qubit_gate_time_matrix(&self, hqslang: &str) -> Array1<f64> {
+        let gate_times = self.single_qubit_gates.get(hqslang);
+        Array1::from_shape_fn(self.number_qubits, |qubit| {
+            gate_times
+                .and_then(|times| times.get(&qubit).copied())
+                .unwrap_or(f64::NAN)
+        })
+    }
+
+    /// Returns the gate times of a two-qubit gate on all pairs of qubits of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the two-qubit gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Array2<f64>` - The gate time with the control qubit as row and the target qubit as column, NaN where the gate is not available.
+    pub fn two_qubit_gate_time_matrix(&self, hqslang: &str) -> Array2<f64> {
+        let gate_times = self.two_qubit_gates.get(hqslang);
+        Array2::from_shape_fn((self.number_qubits, self.number_qubits), |edge| {
+            gate_times
+                .and_then(|times| times.get(&edge).copied())
+                .unwrap_or(f64::NAN)
+        })
+    }
+
+    /// Returns the decoherence rates of all qubits of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Array3<f64>` - The (3x3)-matrix of decoherence rates of each qubit, zero for qubits without decoherence rates.
+    pub fn qubit_decoherence_rates_all(&self) -> Array3<f64> {
+        let mut rates = Array3::zeros((self.number_qubits, 3, 3));
+        for (qubit, qubit_rates) in self.decoherence_rates.iter() {
+            if *qubit < self.number_qubits {
+                rates.slice_mut(s![*qubit, .., ..]).assign(qubit_rates);
+            }
+        }
+        rates
+    }
+
     /// Adds qubit damping to noise rates.
     ///
     /// # Arguments
@@ -498,10 +777,7 @@ impl Device for GenericDevice {
         target: &usize,
     ) -> Option<f64> {
         match self.multi_qubit_gates.get(hqslang) {
-            Some(x) => {
-                let qubits: Vec<usize> = vec![*control_0, *control_1, *target];
-                x.get(&qubits).copied()
-            }
+            Some(x) => x.get([*control_0, *control_1, *target].as_slice()).copied(),
             None => None,
         }
     }
@@ -510,10 +786,7 @@ impl Device for GenericDevice {
         // variable unused in AllToAllDevice, is kept here for consistency purposes.
 
         match self.multi_qubit_gates.get(hqslang) {
-            Some(x) => {
-                let qubits: Vec<usize> = qubits.to_vec();
-                x.get(&qubits).copied()
-            }
+            Some(x) => x.get(qubits).copied(),
             None => None,
         }
     }
@@ -522,23 +795,30 @@ impl Device for GenericDevice {
         self.decoherence_rates.get(qubit).cloned()
     }
 
+    fn single_qubit_gate_error(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        self.single_qubit_gate_errors
+            .get(hqslang)
+            .and_then(|x| x.get(qubit).copied())
+    }
+
+    fn two_qubit_gate_error(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        self.two_qubit_gate_errors
+            .get(hqslang)
+            .and_then(|x| x.get(&(*control, *target)).copied())
+    }
+
     fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
-        let mut vector: Vec<(usize, usize)> = Vec::new();
-        for row in 0..self.number_qubits() {
-            for column in row + 1..self.number_qubits() {
-                if self
-                    .two_qubit_gates
-                    .iter()
-                    .filter(|(key, _)| key.as_str() != "SWAP")
-                    .any(|(_, val)| {
-                        val.contains_key(&(row, column)) || val.contains_key(&(column, row))
-                    })
-                {
-                    vector.push((row, column))
-                }
-            }
-        }
-        vector
+        // Only the calibrated qubit pairs are visited, the BTreeSet sorts the edges by their qubits.
+        let edges: BTreeSet<(usize, usize)> = self
+            .two_qubit_gates
+            .iter()
+            .filter(|(key, _)| key.as_str() != "SWAP")
+            .flat_map(|(_, val)| val.keys())
+            .filter(|(control, target)| control != target)
+            .map(|(control, target)| (*control.min(target), *control.max(target)))
+            .filter(|(_, larger)| *larger < self.number_qubits)
+            .collect();
+        edges.into_iter().collect()
     }
 
     fn to_generic_device(&self) -> GenericDevice {
@@ -556,6 +836,52 @@ impl Device for GenericDevice {
     fn multi_qubit_gate_names(&self) -> Vec<String> {
         self.multi_qubit_gates.keys().cloned().collect()
     }
+
+    /// Changes the device with a wrapped [crate::operations::PragmaSetGateTime].
+    ///
+    /// The gate time of the gate on the qubits of the PRAGMA is set, depending on the number of qubits
+    /// as a single, two or multi qubit gate time. Other PRAGMAs are not supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the wrapped operation
+    /// * `operation` - The Pragma operation encoded in binary form using the [bincode] crate
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate time has been set.
+    /// * `Err(RoqoqoBackendError)` - The operation is not supported, cannot be deserialized or the gate time cannot be set.
+    #[cfg(feature = "serialize")]
+    fn change_device(&mut self, hqslang: &str, operation: &[u8]) -> Result<(), RoqoqoBackendError> {
+        match hqslang {
+            "PragmaSetGateTime" => {
+                let pragma: PragmaSetGateTime = bincode::deserialize(operation).map_err(|err| {
+                    RoqoqoError::ChangeDeviceDeserializationError {
+                        hqslang: hqslang.to_string(),
+                        msg: format!("{:?}", err),
+                    }
+                })?;
+                let gate_time = f64::try_from(pragma.gate_time().clone())?;
+                let gate = pragma.gate_hqslang().as_str();
+                match pragma.qubits().as_slice() {
+                    [] => Err(RoqoqoBackendError::GenericError {
+                        msg: format!("PragmaSetGateTime for gate {} has no qubits", gate),
+                    }),
+                    [qubit] => Ok(self.set_single_qubit_gate_time(gate, *qubit, gate_time)?),
+                    [control, target] => {
+                        Ok(self.set_two_qubit_gate_time(gate, *control, *target, gate_time)?)
+                    }
+                    qubits => {
+                        Ok(self.set_multi_qubit_gate_time(gate, qubits.to_vec(), gate_time)?)
+                    }
+                }
+            }
+            _ => Err(RoqoqoError::UnknownChangeDevicePragma {
+                hqslang: hqslang.to_string(),
+            }
+            .into()),
+        }
+    }
 }
 
 impl crate::operations::SupportedVersion for GenericDevice {}
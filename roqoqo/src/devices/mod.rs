@@ -33,19 +33,35 @@
 
 #[cfg(feature = "unstable_chain_with_environment")]
 use std::collections::HashMap;
-#[cfg(feature = "unstable_qoqo_devices")]
 use std::collections::HashSet;
 
-use crate::RoqoqoBackendError;
-#[cfg(feature = "unstable_qoqo_devices")]
-use crate::{prelude::InvolveQubits, Circuit};
+#[cfg(feature = "serialize")]
+use crate::operations::PragmaChangeDevice;
+use crate::operations::{
+    Definition, FourQubitGateOperation, GateOperation, InvolveQubits, InvolvedQubits,
+    MultiQubitGateOperation, Operate, OperateFourQubit, OperateMultiQubit, OperateSingleQubit,
+    OperateThreeQubit, OperateTwoQubit, Operation, PragmaDamping, PragmaDephasing,
+    PragmaDepolarising, PragmaGeneralNoise, SingleQubitGateOperation, ThreeQubitGateOperation,
+    TwoQubitGateOperation,
+};
+use crate::{Circuit, RoqoqoBackendError};
 use ndarray::Array2;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
 mod generic_device;
 pub use generic_device::GenericDevice;
 mod all_to_all;
 pub use all_to_all::AllToAllDevice;
 mod square_lattice;
 pub use square_lattice::SquareLatticeDevice;
+#[cfg(feature = "serialize")]
+mod device_config;
+#[cfg(feature = "serialize")]
+pub use device_config::{
+    AllToAllDeviceConfig, DecoherenceRatesConfig, DeviceSettingsConfig, GenericDeviceConfig,
+    MultiQubitGateTimeConfig, QubitDecoherenceRatesConfig, SingleQubitGateTimeConfig,
+    SquareLatticeDeviceConfig, TwoQubitGateTimeConfig,
+};
 // use crate::RoqoqoError;
 // use std::collections::HashMap;
 
@@ -53,6 +69,26 @@ pub use square_lattice::SquareLatticeDevice;
 ///
 /// Defines standard functions available for roqoqo devices.
 ///
+/// The trait is object safe, so devices can be used as trait objects.
+/// References and boxes of devices implement the trait as well:
+///
+/// ```
+/// use roqoqo::devices::{Device, GenericDevice};
+///
+/// fn number_qubits(device: &dyn Device) -> usize {
+///     device.number_qubits()
+/// }
+///
+/// fn generic_number_qubits(device: impl Device) -> usize {
+///     device.number_qubits()
+/// }
+///
+/// let device: Box<dyn Device> = Box::new(GenericDevice::new(3));
+/// assert_eq!(number_qubits(device.as_ref()), 3);
+/// assert_eq!(number_qubits(&device), 3);
+/// assert_eq!(generic_number_qubits(&device), 3);
+/// assert_eq!(generic_number_qubits(device), 3);
+/// ```
 pub trait Device {
     /// Returns the gate time of a single qubit operation if the single qubit operation is available on device.
     ///
@@ -164,6 +200,44 @@ pub trait Device {
     ///
     fn qubit_decoherence_rates(&self, qubit: &usize) -> Option<Array2<f64>>;
 
+    /// Returns the calibrated error rate of a single qubit operation if it is known for the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a single qubit gate.
+    /// * `qubit` - The qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The error rate of the gate.
+    /// * `None` - The device does not provide an error rate for the gate.
+    ///
+    fn single_qubit_gate_error(&self, _hqslang: &str, _qubit: &usize) -> Option<f64> {
+        None
+    }
+
+    /// Returns the calibrated error rate of a two qubit operation if it is known for the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a two qubit gate.
+    /// * `control` - The control qubit the gate acts on.
+    /// * `target` - The target qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The error rate of the gate.
+    /// * `None` - The device does not provide an error rate for the gate.
+    ///
+    fn two_qubit_gate_error(
+        &self,
+        _hqslang: &str,
+        _control: &usize,
+        _target: &usize,
+    ) -> Option<f64> {
+        None
+    }
+
     /// Returns the number of qubits the device supports.
     ///
     /// # Returns
@@ -206,6 +280,10 @@ pub trait Device {
     /// For most devices the default behaviour is that the device cannot be changed
     /// and the function returns a corresponding RoqoqoBackendError
     ///
+    /// The generic [crate::operations::PragmaSetGateTime] can be used by devices with adjustable
+    /// gate times. [GenericDevice] provides a reference implementation that applies it
+    /// to its stored gate times.
+    ///
     /// # Arguments
     ///
     /// * `hqslang` - The hqslang name of the wrapped operation
@@ -225,6 +303,30 @@ pub trait Device {
         })
     }
 
+    /// Changes the device topology based on a typed Pragma operation.
+    ///
+    /// Convenience wrapper around [Device::change_device] that serializes the operation
+    /// internally with the [bincode] crate and passes its hqslang name along.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The device specific Pragma operation
+    ///
+    /// # Returns
+    ///
+    /// Result of changing the device.
+    /// A RoqoqoError::SerializationError is returned if the operation cannot be serialized.
+    ///
+    #[cfg(feature = "serialize")]
+    fn change_device_typed<T>(&mut self, operation: &T) -> Result<(), RoqoqoBackendError>
+    where
+        T: Operate + Serialize,
+        Self: Sized,
+    {
+        let pragma = PragmaChangeDevice::from_operation(operation)?;
+        self.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+    }
+
     /// Turns Device into GenericDevice
     ///
     /// Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
@@ -237,6 +339,377 @@ pub trait Device {
     fn to_generic_device(&self) -> GenericDevice;
 }
 
+/// Implements Device for references to devices.
+///
+/// Allows passing `&T`, `&dyn Device` and `&Box<dyn Device>` to functions taking an `impl Device`.
+/// A shared reference cannot change the device it points to,
+/// so [Device::change_device] always returns the default error.
+impl<T: Device + ?Sized> Device for &T {
+    fn single_qubit_gate_time(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        (**self).single_qubit_gate_time(hqslang, qubit)
+    }
+
+    fn single_qubit_gate_names(&self) -> Vec<String> {
+        (**self).single_qubit_gate_names()
+    }
+
+    fn two_qubit_gate_names(&self) -> Vec<String> {
+        (**self).two_qubit_gate_names()
+    }
+
+    fn multi_qubit_gate_names(&self) -> Vec<String> {
+        (**self).multi_qubit_gate_names()
+    }
+
+    fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        (**self).two_qubit_gate_time(hqslang, control, target)
+    }
+
+    fn three_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_0: &usize,
+        control_1: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        (**self).three_qubit_gate_time(hqslang, control_0, control_1, target)
+    }
+
+    fn multi_qubit_gate_time(&self, hqslang: &str, qubits: &[usize]) -> Option<f64> {
+        (**self).multi_qubit_gate_time(hqslang, qubits)
+    }
+
+    fn qubit_decoherence_rates(&self, qubit: &usize) -> Option<Array2<f64>> {
+        (**self).qubit_decoherence_rates(qubit)
+    }
+
+    fn single_qubit_gate_error(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        (**self).single_qubit_gate_error(hqslang, qubit)
+    }
+
+    fn two_qubit_gate_error(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        (**self).two_qubit_gate_error(hqslang, control, target)
+    }
+
+    fn number_qubits(&self) -> usize {
+        (**self).number_qubits()
+    }
+
+    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
+        (**self).two_qubit_edges()
+    }
+
+    fn to_generic_device(&self) -> GenericDevice {
+        (**self).to_generic_device()
+    }
+}
+
+/// Implements Device for boxed devices, including `Box<dyn Device>`.
+impl<T: Device + ?Sized> Device for Box<T> {
+    fn single_qubit_gate_time(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        (**self).single_qubit_gate_time(hqslang, qubit)
+    }
+
+    fn single_qubit_gate_names(&self) -> Vec<String> {
+        (**self).single_qubit_gate_names()
+    }
+
+    fn two_qubit_gate_names(&self) -> Vec<String> {
+        (**self).two_qubit_gate_names()
+    }
+
+    fn multi_qubit_gate_names(&self) -> Vec<String> {
+        (**self).multi_qubit_gate_names()
+    }
+
+    fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        (**self).two_qubit_gate_time(hqslang, control, target)
+    }
+
+    fn three_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_0: &usize,
+        control_1: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        (**self).three_qubit_gate_time(hqslang, control_0, control_1, target)
+    }
+
+    fn multi_qubit_gate_time(&self, hqslang: &str, qubits: &[usize]) -> Option<f64> {
+        (**self).multi_qubit_gate_time(hqslang, qubits)
+    }
+
+    fn qubit_decoherence_rates(&self, qubit: &usize) -> Option<Array2<f64>> {
+        (**self).qubit_decoherence_rates(qubit)
+    }
+
+    fn single_qubit_gate_error(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        (**self).single_qubit_gate_error(hqslang, qubit)
+    }
+
+    fn two_qubit_gate_error(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        (**self).two_qubit_gate_error(hqslang, control, target)
+    }
+
+    fn number_qubits(&self) -> usize {
+        (**self).number_qubits()
+    }
+
+    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
+        (**self).two_qubit_edges()
+    }
+
+    fn change_device(&mut self, hqslang: &str, operation: &[u8]) -> Result<(), RoqoqoBackendError> {
+        (**self).change_device(hqslang, operation)
+    }
+
+    fn to_generic_device(&self) -> GenericDevice {
+        (**self).to_generic_device()
+    }
+}
+
+/// Returns the time needed to execute an operation on a device.
+///
+/// Gate operations are dispatched to the gate time function of the [Device] matching their number of qubits,
+/// four-qubit gates use [Device::multi_qubit_gate_time].
+/// Noise PRAGMAs return their `gate_time`, [crate::operations::PragmaSleep] returns its `sleep_time`
+/// and definitions take no time.
+///
+/// # Arguments
+///
+/// * `device` - The device the operation is executed on.
+/// * `operation` - The operation that is executed.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The time needed to execute the operation.
+/// * `None` - The operation is not available on the device, has a symbolic time or has no defined time.
+///
+/// # Example
+///
+/// ```
+/// use roqoqo::devices::{gate_time, GenericDevice};
+/// use roqoqo::operations::{Operation, RotateX, CNOT};
+///
+/// let mut device = GenericDevice::new(2);
+/// device.set_single_qubit_gate_time("RotateX", 0, 10.0).unwrap();
+///
+/// assert_eq!(gate_time(&device, &Operation::from(RotateX::new(0, 1.0.into()))), Some(10.0));
+/// assert_eq!(gate_time(&device, &Operation::from(CNOT::new(0, 1))), None);
+/// ```
+pub fn gate_time(device: &impl Device, operation: &Operation) -> Option<f64> {
+    dispatch_gate_time(
+        operation,
+        |hqslang, qubit| device.single_qubit_gate_time(hqslang, qubit),
+        |hqslang, control, target| device.two_qubit_gate_time(hqslang, control, target),
+        |hqslang, control_0, control_1, target| {
+            device.three_qubit_gate_time(hqslang, control_0, control_1, target)
+        },
+        |hqslang, qubits| device.multi_qubit_gate_time(hqslang, qubits),
+    )
+}
+
+/// Returns the time needed to execute an operation using the gate time functions of a device.
+///
+/// Used for [gate_time] and the devices implementing [QoqoDevice].
+fn dispatch_gate_time(
+    operation: &Operation,
+    single_qubit_gate_time: impl Fn(&str, &usize) -> Option<f64>,
+    two_qubit_gate_time: impl Fn(&str, &usize, &usize) -> Option<f64>,
+    three_qubit_gate_time: impl Fn(&str, &usize, &usize, &usize) -> Option<f64>,
+    multi_qubit_gate_time: impl Fn(&str, &[usize]) -> Option<f64>,
+) -> Option<f64> {
+    let hqslang = operation.hqslang();
+    if let Ok(gate) = SingleQubitGateOperation::try_from(operation) {
+        single_qubit_gate_time(hqslang, gate.qubit())
+    } else if let Ok(gate) = TwoQubitGateOperation::try_from(operation) {
+        two_qubit_gate_time(hqslang, gate.control(), gate.target())
+    } else if let Operation::NegativeControl(gate) = operation {
+        two_qubit_gate_time(hqslang, gate.control(), gate.target())
+    } else if let Ok(gate) = ThreeQubitGateOperation::try_from(operation) {
+        three_qubit_gate_time(hqslang, gate.control_0(), gate.control_1(), gate.target())
+    } else if let Ok(gate) = FourQubitGateOperation::try_from(operation) {
+        multi_qubit_gate_time(
+            hqslang,
+            &[
+                *gate.control_0(),
+                *gate.control_1(),
+                *gate.control_2(),
+                *gate.target(),
+            ],
+        )
+    } else if let Ok(gate) = MultiQubitGateOperation::try_from(operation) {
+        multi_qubit_gate_time(hqslang, gate.qubits())
+    } else if Definition::try_from(operation).is_ok() {
+        Some(0.0)
+    } else {
+        let time = match operation {
+            Operation::PragmaSleep(pragma) => pragma.sleep_time(),
+            Operation::PragmaDamping(pragma) => pragma.gate_time(),
+            Operation::PragmaDepolarising(pragma) => pragma.gate_time(),
+            Operation::PragmaDephasing(pragma) => pragma.gate_time(),
+            Operation::PragmaRandomNoise(pragma) => pragma.gate_time(),
+            Operation::PragmaGeneralNoise(pragma) => pragma.gate_time(),
+            _ => return None,
+        };
+        time.float().ok().copied()
+    }
+}
+
+/// Adds the decoherence noise acting on the qubits of each gate while the gate is applied.
+///
+/// The circuit is a set of operations applied in parallel on the device, so no two gates
+/// may act on the same qubit and a gate acting on all qubits may not be combined with other gates.
+/// After each gate the decoherence rates of each qubit of the gate are applied for the gate time
+/// of the gate on the device. Rates that are a combination of damping, dephasing and depolarising
+/// are inserted as [PragmaDamping], [PragmaDephasing] and [PragmaDepolarising], all other rates
+/// as [PragmaGeneralNoise]. Gates acting on all qubits add the noise on all qubits of the device.
+///
+/// # Arguments
+///
+/// * `device` - The device providing the gate times and decoherence rates.
+/// * `circuit` - The circuit of parallel native operations for which the noise is inserted.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit of parallel operations containing the noise PRAGMAs.
+/// * `Err(RoqoqoBackendError::GenericError)` - Several gates act on the same qubit or a gate is not available on the device.
+///
+/// # Example
+///
+/// ```
+/// use roqoqo::devices::{add_active_gate_noise, AllToAllDevice};
+/// use roqoqo::operations::{PragmaDamping, RotateX};
+/// use roqoqo::Circuit;
+///
+/// let device = AllToAllDevice::new(2, &["RotateX".to_string()], &[], 1.0)
+///     .with_single_qubit_gate_time("RotateX", 2.0)
+///     .add_damping_all(0.1);
+/// let mut circuit = Circuit::new();
+/// circuit += RotateX::new(0, 1.0.into());
+///
+/// let noisy = add_active_gate_noise(&device, &circuit).unwrap();
+/// let mut expected = circuit.clone();
+/// expected += PragmaDamping::new(0, 2.0.into(), 0.1.into());
+/// assert_eq!(noisy, expected);
+/// ```
+pub fn add_active_gate_noise(
+    device: &impl Device,
+    circuit: &Circuit,
+) -> Result<Circuit, RoqoqoBackendError> {
+    insert_active_gate_noise(
+        circuit,
+        device.number_qubits(),
+        |operation| gate_time(device, operation),
+        |qubit| device.qubit_decoherence_rates(qubit),
+    )
+}
+
+/// Inserts the decoherence noise of the qubits of each gate after the gate, see [add_active_gate_noise].
+fn insert_active_gate_noise(
+    circuit: &Circuit,
+    number_qubits: usize,
+    gate_time: impl Fn(&Operation) -> Option<f64>,
+    decoherence_rates: impl Fn(&usize) -> Option<Array2<f64>>,
+) -> Result<Circuit, RoqoqoBackendError> {
+    check_parallel_gates(circuit)?;
+    let mut noisy_circuit = Circuit::new();
+    for operation in circuit.iter() {
+        noisy_circuit.add_operation(operation.clone());
+        let gate = match GateOperation::try_from(operation) {
+            Ok(gate) => gate,
+            Err(_) => continue,
+        };
+        let mut qubits: Vec<usize> = match gate.involved_qubits() {
+            InvolvedQubits::Set(qubits) => qubits.into_iter().collect(),
+            InvolvedQubits::All => (0..number_qubits).collect(),
+            InvolvedQubits::None => Vec::new(),
+        };
+        qubits.sort_unstable();
+        let time = gate_time(operation).ok_or_else(|| RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Error add_active_gate_noise: Gate {} on qubits {:?} is not available on the device",
+                operation.hqslang(),
+                qubits
+            ),
+        })?;
+        for qubit in qubits {
+            if let Some(rates) = decoherence_rates(&qubit) {
+                for noise in decoherence_noise(qubit, time, rates) {
+                    noisy_circuit.add_operation(noise);
+                }
+            }
+        }
+    }
+    Ok(noisy_circuit)
+}
+
+/// Checks that no two gates of a parallel set of operations act on the same qubit.
+fn check_parallel_gates(circuit: &Circuit) -> Result<(), RoqoqoBackendError> {
+    let mut involved_qubits = HashSet::<usize>::new();
+    let mut number_gates: usize = 0;
+    let mut gate_on_all_qubits = false;
+    for operation in circuit.iter() {
+        let gate = match GateOperation::try_from(operation) {
+            Ok(gate) => gate,
+            Err(_) => continue,
+        };
+        let conflict = match gate.involved_qubits() {
+            InvolvedQubits::Set(involved_set) => {
+                let conflict = gate_on_all_qubits || !involved_qubits.is_disjoint(&involved_set);
+                involved_qubits.extend(involved_set);
+                conflict
+            }
+            InvolvedQubits::All => {
+                let conflict = number_gates > 0;
+                gate_on_all_qubits = true;
+                conflict
+            }
+            InvolvedQubits::None => false,
+        };
+        if conflict {
+            return Err(RoqoqoBackendError::GenericError { msg: "Error add_active_gate_noise: Several unitary gates operate on same qubit in a parallel set of operations".to_string()});
+        }
+        number_gates += 1;
+    }
+    Ok(())
+}
+
+/// Returns the noise PRAGMAs applying the decoherence rates of a qubit for a time.
+///
+/// Diagonal rates are decomposed into damping, dephasing and depolarising
+/// (see [GenericDevice::add_damping], [GenericDevice::add_dephasing] and [GenericDevice::add_depolarising]),
+/// other rates are applied with a [PragmaGeneralNoise].
+fn decoherence_noise(qubit: usize, time: f64, rates: Array2<f64>) -> Vec<Operation> {
+    if rates.iter().all(|rate| *rate == 0.0) {
+        return Vec::new();
+    }
+    let is_diagonal = rates.dim() == (3, 3)
+        && rates
+            .indexed_iter()
+            .all(|((row, column), rate)| row == column || *rate == 0.0);
+    if is_diagonal {
+        let depolarising = 2.0 * rates[(1, 1)];
+        let damping = rates[(0, 0)] - rates[(1, 1)];
+        let dephasing = rates[(2, 2)] - rates[(1, 1)] / 2.0;
+        if depolarising >= 0.0 && damping >= 0.0 && dephasing >= 0.0 {
+            let mut noise: Vec<Operation> = Vec::new();
+            if damping > 0.0 {
+                noise.push(PragmaDamping::new(qubit, time.into(), damping.into()).into());
+            }
+            if dephasing > 0.0 {
+                noise.push(PragmaDephasing::new(qubit, time.into(), dephasing.into()).into());
+            }
+            if depolarising > 0.0 {
+                noise.push(PragmaDepolarising::new(qubit, time.into(), depolarising.into()).into());
+            }
+            return noise;
+        }
+    }
+    vec![PragmaGeneralNoise::new(qubit, time.into(), rates).into()]
+}
+
 #[cfg(feature = "unstable_qoqo_devices")]
 /// Trait for new qoqo devices.
 ///
@@ -387,11 +860,11 @@ pub trait QoqoDevice {
 
     /// Adds the noise contributions due to qubit gates being applied
     ///
-    /// This functions adds additional noise that occurs in the device,
+    /// This functions adds the noise that occurs in the device,
     /// when one or more unitary gates are applied in parallel.
-    /// This does NOT include the background noise that is defined by the
-    /// gate time and the constant decoherence rates on all qubits.
-    /// The noise is inserted in the form of roqoqo noise Pragmas.
+    /// After each gate the decoherence rates of the qubits of the gate are applied
+    /// for the gate time of the gate in the form of roqoqo noise Pragmas
+    /// (see [add_active_gate_noise] for devices implementing [Device]).
     ///
     /// # Arguments
     ///
@@ -404,21 +877,22 @@ pub trait QoqoDevice {
     /// `Err` - Error applying the noise. Usually occurs when the gates in the Circuit cannot
     ///         be executed or cannot be executed in parallel.
     fn add_active_gate_noise(&self, circuit: &Circuit) -> Result<Circuit, RoqoqoBackendError> {
-        use crate::operations::GateOperation;
-        use crate::operations::InvolvedQubits;
-        let mut invovlved_qubits = HashSet::<usize>::new();
-        for op in circuit.iter() {
-            if let Ok(operation) = GateOperation::try_from(op) {
-                if let InvolvedQubits::Set(involved_set) = operation.involved_qubits() {
-                    if invovlved_qubits.is_disjoint(&involved_set) {
-                        invovlved_qubits.extend(involved_set)
-                    } else {
-                        return Err(RoqoqoBackendError::GenericError { msg: "Error add_active_gate_noise: Several unitary gates operate on same qubit in a parallel set of operations".to_string()});
-                    }
-                }
-            }
-        }
-        Ok(circuit.clone())
+        insert_active_gate_noise(
+            circuit,
+            self.number_qubits(),
+            |operation| {
+                dispatch_gate_time(
+                    operation,
+                    |hqslang, qubit| self.single_qubit_gate_time(hqslang, qubit),
+                    |hqslang, control, target| self.two_qubit_gate_time(hqslang, control, target),
+                    |hqslang, control_0, control_1, target| {
+                        self.three_qubit_gate_time(hqslang, control_0, control_1, target)
+                    },
+                    |hqslang, qubits| self.multi_qubit_gate_time(hqslang, qubits),
+                )
+            },
+            |qubit| self.qubit_decoherence_rates(qubit),
+        )
     }
 
     /// Returns the list of pairs of qubits linked with a native two-qubit-gate in the device.
@@ -18,6 +18,7 @@ use crate::RoqoqoError;
 use ndarray::Array2;
 /// A device assuming all-to-all connectivity between all involved qubits.
 ///
+/// As with [GenericDevice], binary serialization fails once calibrated gate errors are set.
 #[derive(Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
@@ -58,6 +59,8 @@ impl SquareLatticeDevice {
             two_qubit_gates: HashMap::with_capacity(two_qubit_gates.len()),
             multi_qubit_gates: HashMap::new(),
             decoherence_rates: HashMap::with_capacity(number_rows * number_columns),
+            single_qubit_gate_errors: HashMap::new(),
+            two_qubit_gate_errors: HashMap::new(),
         };
         let mut new = Self {
             number_rows,
@@ -86,6 +89,110 @@ impl SquareLatticeDevice {
         self.number_rows
     }
 
+    /// Returns the qubit index at a position in the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the qubit.
+    /// * `column` - The column of the qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The index of the qubit (row-major).
+    /// * `None` - The position lies outside of the lattice.
+    ///
+    pub fn qubit_index(&self, row: usize, column: usize) -> Option<usize> {
+        if row < self.number_rows && column < self.number_columns {
+            Some(row * self.number_columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of a qubit in the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The index of the qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((usize, usize))` - The (row, column) position of the qubit.
+    /// * `None` - The qubit is not part of the device.
+    ///
+    pub fn qubit_position(&self, qubit: usize) -> Option<(usize, usize)> {
+        if qubit < self.number_rows * self.number_columns {
+            Some((qubit / self.number_columns, qubit % self.number_columns))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the qubits in a row of the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the lattice.
+    ///
+    /// # Returns
+    ///
+    /// The qubit indices in the row ordered by column. Empty if the row is not part of the lattice.
+    ///
+    pub fn row(&self, row: usize) -> Vec<usize> {
+        (0..self.number_columns)
+            .filter_map(|column| self.qubit_index(row, column))
+            .collect()
+    }
+
+    /// Returns the qubits in a column of the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The column of the lattice.
+    ///
+    /// # Returns
+    ///
+    /// The qubit indices in the column ordered by row. Empty if the column is not part of the lattice.
+    ///
+    pub fn column(&self, column: usize) -> Vec<usize> {
+        (0..self.number_rows)
+            .filter_map(|row| self.qubit_index(row, column))
+            .collect()
+    }
+
+    /// Returns the nearest neighbours of a qubit in the lattice.
+    ///
+    /// These are the qubits connected to `qubit` by an edge of the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The index of the qubit.
+    ///
+    /// # Returns
+    ///
+    /// The sorted indices of the neighbouring qubits. Empty if the qubit is not part of the device.
+    ///
+    pub fn neighbours(&self, qubit: usize) -> Vec<usize> {
+        let (row, column) = match self.qubit_position(qubit) {
+            Some(position) => position,
+            None => return Vec::new(),
+        };
+        let mut neighbours: Vec<usize> = Vec::with_capacity(4);
+        if row > 0 {
+            neighbours.push(qubit - self.number_columns);
+        }
+        if column > 0 {
+            neighbours.push(qubit - 1);
+        }
+        if column + 1 < self.number_columns {
+            neighbours.push(qubit + 1);
+        }
+        if row + 1 < self.number_rows {
+            neighbours.push(qubit + self.number_columns);
+        }
+        neighbours
+    }
+
     /// Function that allows to set a unifromg gate time per gate type for the single-qubit-gates.
     ///
     /// # Arguments
@@ -222,6 +329,60 @@ impl SquareLatticeDevice {
         target: usize,
         gate_time: f64,
     ) -> Result<(), RoqoqoError> {
+        self.check_lattice_edge(control, target)?;
+        self.generic_device
+            .set_two_qubit_gate_time(gate, control, target, gate_time)
+    }
+
+    /// Setting the calibrated error rate of a single qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    /// * `qubit` - The qubit for which the gate error is set.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate error was correctly set.
+    /// * `Err(RoqoqoError::GenericError)` - Qubit is larger than the number of qubits in the device or the error is not between 0 and 1.
+    pub fn set_single_qubit_gate_error(
+        &mut self,
+        gate: &str,
+        qubit: usize,
+        error: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.generic_device
+            .set_single_qubit_gate_error(gate, qubit, error)
+    }
+
+    /// Setting the calibrated error rate of a two qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    /// * `control` - The control qubit for which the gate error is set.
+    /// * `target` - The target qubit for which the gate error is set.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate error was correctly set.
+    /// * `Err(RoqoqoError::GenericError)` - The qubits are not neighbours on the lattice or the error is not between 0 and 1.
+    pub fn set_two_qubit_gate_error(
+        &mut self,
+        gate: &str,
+        control: usize,
+        target: usize,
+        error: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_lattice_edge(control, target)?;
+        self.generic_device
+            .set_two_qubit_gate_error(gate, control, target, error)
+    }
+
+    /// Checks that two qubits are neighbours on the square lattice.
+    fn check_lattice_edge(&self, control: usize, target: usize) -> Result<(), RoqoqoError> {
         let row_control: i64 = (control / self.number_columns)
             .try_into()
             .expect("Qubit number too large");
@@ -237,8 +398,7 @@ impl SquareLatticeDevice {
         if ((row_control - row_target).abs() == 1 && column_control == column_target)
             || (row_control == row_target && (column_control - column_target).abs() == 1)
         {
-            self.generic_device
-                .set_two_qubit_gate_time(gate, control, target, gate_time)
+            Ok(())
         } else {
             Err(RoqoqoError::GenericError{msg: format!("Two qubit gate between psotions ({}, {}, qubit: {}) and ({}, {}, qubit:{}) not possible on SquareLattice", row_control, column_control, control, row_target, column_target, target)})
         }
@@ -413,6 +573,103 @@ impl SquareLatticeDevice {
         }
         self
     }
+
+    /// Builder method setting a uniform gate time for a single-qubit-gate, adding the gate if it is not available yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    /// * `gate_time` - gate time for the given gate type, valid for all qubits in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice with updated gate times.
+    pub fn with_single_qubit_gate_time(self, gate: &str, gate_time: f64) -> Self {
+        self.set_all_single_qubit_gate_times(gate, gate_time)
+    }
+
+    /// Builder method setting a uniform gate time for a two-qubit-gate, adding the gate if it is not available yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    /// * `gate_time` - gate time for the given gate type, valid for all edges in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice with updated gate times.
+    pub fn with_two_qubit_gate_time(self, gate: &str, gate_time: f64) -> Self {
+        self.set_all_two_qubit_gate_times(gate, gate_time)
+    }
+
+    /// Builder method removing a single-qubit-gate from the available gates of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-qubit-gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice without the gate.
+    pub fn without_single_qubit_gate(mut self, gate: &str) -> Self {
+        self.generic_device.single_qubit_gates.remove(gate);
+        self.generic_device.single_qubit_gate_errors.remove(gate);
+        self
+    }
+
+    /// Builder method removing a two-qubit-gate from the available gates of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-qubit-gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice without the gate.
+    pub fn without_two_qubit_gate(mut self, gate: &str) -> Self {
+        self.generic_device.two_qubit_gates.remove(gate);
+        self.generic_device.two_qubit_gate_errors.remove(gate);
+        self
+    }
+
+    /// Builder method adding damping to the noise rates of all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `damping` - The damping rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice with updated damping.
+    pub fn with_damping_rates(self, damping: f64) -> Self {
+        self.add_damping_all(damping)
+    }
+
+    /// Builder method adding dephasing to the noise rates of all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `dephasing` - The dephasing rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice with updated dephasing.
+    pub fn with_dephasing_rates(self, dephasing: f64) -> Self {
+        self.add_dephasing_all(dephasing)
+    }
+
+    /// Builder method adding depolarising noise to the noise rates of all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `depolarising` - The depolarising rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SquareLatticeDevice with updated depolarising.
+    pub fn with_depolarising_rates(self, depolarising: f64) -> Self {
+        self.add_depolarising_all(depolarising)
+    }
 }
 
 /// Implements Device trait for SquareLatticeDevice.
@@ -469,6 +726,15 @@ impl Device for SquareLatticeDevice {
         self.generic_device.qubit_decoherence_rates(qubit)
     }
 
+    fn single_qubit_gate_error(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        self.generic_device.single_qubit_gate_error(hqslang, qubit)
+    }
+
+    fn two_qubit_gate_error(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        self.generic_device
+            .two_qubit_gate_error(hqslang, control, target)
+    }
+
     fn to_generic_device(&self) -> GenericDevice {
         self.generic_device.clone()
     }
@@ -0,0 +1,199 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialization of float values that are not finite.
+//!
+//! JSON has no representation of NaN and infinite values. `serde_json` writes them as `null`,
+//! which cannot be deserialized into a float again. The lossy json serialization ([to_json_lossy])
+//! writes non-finite values as the strings "NaN", "Infinity" and "-Infinity", the default
+//! serialization keeps writing numbers. In human readable formats both numbers and these strings
+//! are accepted when deserializing. Finite values and binary formats like bincode are unchanged.
+
+use crate::RoqoqoError;
+use ndarray::Array2;
+use serde::de::{Error, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::Cell;
+use std::fmt;
+
+thread_local! {
+    // Set while a value is serialized with to_json_lossy.
+    static LOSSY_JSON: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Serializes a value to json, writing float fields that are not finite as strings.
+///
+/// Only fields using [serialize_float] or [serialize_float_array2] are affected.
+pub(crate) fn to_json_lossy<T>(value: &T) -> Result<String, RoqoqoError>
+where
+    T: Serialize + ?Sized,
+{
+    let previous = LOSSY_JSON.with(|lossy| lossy.replace(true));
+    let serialized = serde_json::to_string(value);
+    LOSSY_JSON.with(|lossy| lossy.set(previous));
+    serialized.map_err(|err| RoqoqoError::SerializationError {
+        msg: err.to_string(),
+    })
+}
+
+// Returns true when non-finite float fields are written as strings.
+fn write_non_finite_strings<S: Serializer>(serializer: &S) -> bool {
+    serializer.is_human_readable() && LOSSY_JSON.with(|lossy| lossy.get())
+}
+
+/// A float value that is written as a string in human readable formats when it is not finite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct JsonFloat(pub(crate) f64);
+
+impl Serialize for JsonFloat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !serializer.is_human_readable() || self.0.is_finite() {
+            serializer.serialize_f64(self.0)
+        } else if self.0.is_nan() {
+            serializer.serialize_str("NaN")
+        } else if self.0 > 0.0 {
+            serializer.serialize_str("Infinity")
+        } else {
+            serializer.serialize_str("-Infinity")
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonFloat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(JsonFloatVisitor)
+        } else {
+            f64::deserialize(deserializer).map(JsonFloat)
+        }
+    }
+}
+
+struct JsonFloatVisitor;
+
+impl<'de> Visitor<'de> for JsonFloatVisitor {
+    type Value = JsonFloat;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a float or one of the strings \"NaN\", \"Infinity\", \"-Infinity\"")
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(JsonFloat(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(JsonFloat(value as f64))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(JsonFloat(value as f64))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match value {
+            "NaN" => Ok(JsonFloat(f64::NAN)),
+            "Infinity" => Ok(JsonFloat(f64::INFINITY)),
+            "-Infinity" => Ok(JsonFloat(f64::NEG_INFINITY)),
+            _ => Err(E::invalid_value(Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+/// Serializes a float field, writing non-finite values as strings in [to_json_lossy].
+pub(crate) fn serialize_float<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if write_non_finite_strings(&serializer) {
+        JsonFloat(*value).serialize(serializer)
+    } else {
+        serializer.serialize_f64(*value)
+    }
+}
+
+/// Deserializes a float field written with [serialize_float].
+pub(crate) fn deserialize_float<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    JsonFloat::deserialize(deserializer).map(|value| value.0)
+}
+
+/// Human readable representation of an Array2 in the format of the ndarray serialization.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Array")]
+struct Array2Json {
+    v: u8,
+    dim: [usize; 2],
+    data: Vec<JsonFloat>,
+}
+
+/// Serializes a float array field, writing non-finite values as strings in [to_json_lossy].
+pub(crate) fn serialize_float_array2<S>(
+    value: &Array2<f64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !write_non_finite_strings(&serializer) {
+        return value.serialize(serializer);
+    }
+    let (rows, columns) = value.dim();
+    Array2Json {
+        v: 1,
+        dim: [rows, columns],
+        data: value.iter().map(|entry| JsonFloat(*entry)).collect(),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a float array field written with [serialize_float_array2].
+pub(crate) fn deserialize_float_array2<'de, D>(deserializer: D) -> Result<Array2<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return Array2::<f64>::deserialize(deserializer);
+    }
+    let array = Array2Json::deserialize(deserializer)?;
+    if array.v != 1 {
+        return Err(D::Error::custom(format!(
+            "unknown array version: {}",
+            array.v
+        )));
+    }
+    Array2::from_shape_vec(
+        (array.dim[0], array.dim[1]),
+        array.data.into_iter().map(|entry| entry.0).collect(),
+    )
+    .map_err(|err| D::Error::custom(format!("data and dimension must match in size: {}", err)))
+}
@@ -0,0 +1,240 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export of roqoqo Circuits to LaTeX circuit diagrams using the quantikz package.
+
+use crate::operations::{
+    InvolveQubits, InvolvedQubits, Operate, OperateSingleQubit, OperateThreeQubit, OperateTwoQubit,
+    Operation,
+};
+use crate::{Circuit, RoqoqoError};
+use qoqo_calculator::CalculatorFloat;
+
+/// Options for the LaTeX export of a Circuit with [Circuit::to_latex_with_options].
+///
+/// The default options draw all operations.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LatexOptions {
+    /// Do not draw PRAGMA operations.
+    pub hide_pragmas: bool,
+}
+
+impl LatexOptions {
+    /// Creates new LatexOptions drawing all operations.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The default LatexOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether PRAGMA operations are hidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_pragmas` - Whether PRAGMA operations are left out of the diagram.
+    pub fn hide_pragmas(mut self, hide_pragmas: bool) -> Self {
+        self.hide_pragmas = hide_pragmas;
+        self
+    }
+}
+
+impl Circuit {
+    /// Exports the Circuit to a LaTeX circuit diagram using the quantikz package.
+    ///
+    /// Equivalent to [Circuit::to_latex_with_options] with the default [LatexOptions].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The `quantikz` environment drawing the Circuit.
+    /// * `Err(RoqoqoError)` - The Circuit could not be drawn.
+    pub fn to_latex(&self) -> Result<String, RoqoqoError> {
+        self.to_latex_with_options(&LatexOptions::default())
+    }
+
+    /// Exports the Circuit to a LaTeX circuit diagram using the quantikz package.
+    ///
+    /// Every qubit is drawn as one wire, operations are placed in the leftmost free column.
+    /// Common gates use the standard symbols (control dots, `\targ{}` for CNOT targets, swap crosses
+    /// and boxed rotations with their angle), measurements are drawn as meters.
+    /// All other operations are drawn as boxes labelled with their hqslang name spanning their qubits.
+    /// Definitions and operations not acting on qubits are not drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The options of the export.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The `quantikz` environment drawing the Circuit.
+    /// * `Err(RoqoqoError)` - The Circuit could not be drawn.
+    pub fn to_latex_with_options(&self, options: &LatexOptions) -> Result<String, RoqoqoError> {
+        let number_qubits = self
+            .iter()
+            .filter_map(|operation| match operation.involved_qubits() {
+                InvolvedQubits::Set(qubits) => qubits.into_iter().max(),
+                _ => None,
+            })
+            .max()
+            .map(|qubit| qubit + 1)
+            .unwrap_or(0);
+        let mut wires: Vec<Vec<String>> = vec![Vec::new(); number_qubits];
+        for operation in self.iter() {
+            if options.hide_pragmas && operation.tags().contains(&"PragmaOperation") {
+                continue;
+            }
+            let cells = latex_cells(operation, number_qubits);
+            let (first, last) = match (
+                cells.iter().map(|(qubit, _)| *qubit).min(),
+                cells.iter().map(|(qubit, _)| *qubit).max(),
+            ) {
+                (Some(first), Some(last)) => (first, last),
+                _ => continue,
+            };
+            let column = wires[first..=last]
+                .iter()
+                .map(|wire| wire.len())
+                .max()
+                .unwrap_or(0);
+            for wire in wires[first..=last].iter_mut() {
+                wire.resize(column + 1, "\\qw".to_string());
+            }
+            for (qubit, cell) in cells {
+                wires[qubit][column] = cell;
+            }
+        }
+        let number_columns = wires.iter().map(|wire| wire.len()).max().unwrap_or(0);
+        let rows: Vec<String> = wires
+            .into_iter()
+            .enumerate()
+            .map(|(qubit, mut wire)| {
+                wire.resize(number_columns + 1, "\\qw".to_string());
+                format!("\\lstick{{$q_{{{}}}$}} & {}", qubit, wire.join(" & "))
+            })
+            .collect();
+        Ok(format!(
+            "\\begin{{quantikz}}\n{}\n\\end{{quantikz}}",
+            rows.join(" \\\\\n")
+        ))
+    }
+}
+
+/// Returns the quantikz cells of an operation for each qubit it is drawn on.
+fn latex_cells(operation: &Operation, number_qubits: usize) -> Vec<(usize, String)> {
+    match operation {
+        Operation::Hadamard(op) => vec![(*op.qubit(), gate("H"))],
+        Operation::PauliX(op) => vec![(*op.qubit(), gate("X"))],
+        Operation::PauliY(op) => vec![(*op.qubit(), gate("Y"))],
+        Operation::PauliZ(op) => vec![(*op.qubit(), gate("Z"))],
+        Operation::SGate(op) => vec![(*op.qubit(), gate("S"))],
+        Operation::TGate(op) => vec![(*op.qubit(), gate("T"))],
+        Operation::InvSGate(op) => vec![(*op.qubit(), gate("S^\\dagger"))],
+        Operation::InvTGate(op) => vec![(*op.qubit(), gate("T^\\dagger"))],
+        Operation::SqrtPauliX(op) => vec![(*op.qubit(), gate("\\sqrt{X}"))],
+        Operation::InvSqrtPauliX(op) => vec![(*op.qubit(), gate("\\sqrt{X}^\\dagger"))],
+        Operation::RotateX(op) => vec![(*op.qubit(), rotation("R_x", op.theta()))],
+        Operation::RotateY(op) => vec![(*op.qubit(), rotation("R_y", op.theta()))],
+        Operation::RotateZ(op) => vec![(*op.qubit(), rotation("R_z", op.theta()))],
+        Operation::CNOT(op) => vec![
+            (*op.control(), control(*op.control(), *op.target())),
+            (*op.target(), "\\targ{}".to_string()),
+        ],
+        Operation::ControlledPauliZ(op) => vec![
+            (*op.control(), control(*op.control(), *op.target())),
+            (*op.target(), "\\control{}".to_string()),
+        ],
+        Operation::ControlledPauliY(op) => vec![
+            (*op.control(), control(*op.control(), *op.target())),
+            (*op.target(), gate("Y")),
+        ],
+        Operation::ControlledPhaseShift(op) => vec![
+            (*op.control(), control(*op.control(), *op.target())),
+            (*op.target(), rotation("P", op.theta())),
+        ],
+        Operation::SWAP(op) => vec![
+            (
+                *op.control(),
+                format!("\\swap{{{}}}", signed_distance(*op.control(), *op.target())),
+            ),
+            (*op.target(), "\\targX{}".to_string()),
+        ],
+        Operation::Toffoli(op) => vec![
+            (*op.control_0(), control(*op.control_0(), *op.target())),
+            (*op.control_1(), control(*op.control_1(), *op.target())),
+            (*op.target(), "\\targ{}".to_string()),
+        ],
+        Operation::MeasureQubit(op) => vec![(*op.qubit(), "\\meter{}".to_string())],
+        _ => match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => labelled_box(operation.hqslang(), qubits.into_iter()),
+            InvolvedQubits::All => labelled_box(operation.hqslang(), 0..number_qubits),
+            InvolvedQubits::None => Vec::new(),
+        },
+    }
+}
+
+/// Returns a boxed single-qubit gate with the given label.
+fn gate(label: &str) -> String {
+    format!("\\gate{{{}}}", label)
+}
+
+/// Returns a boxed rotation gate labelled with its angle.
+fn rotation(label: &str, theta: &CalculatorFloat) -> String {
+    gate(&format!("{}({})", label, latex_angle(theta)))
+}
+
+/// Returns a control dot connected to the target qubit.
+fn control(control: usize, target: usize) -> String {
+    format!("\\ctrl{{{}}}", signed_distance(control, target))
+}
+
+/// Returns the signed number of wires from `from` to `to`.
+fn signed_distance(from: usize, to: usize) -> i64 {
+    to as i64 - from as i64
+}
+
+/// Returns a box labelled with the hqslang name spanning all wires between the first and last qubit.
+fn labelled_box(hqslang: &str, qubits: impl Iterator<Item = usize>) -> Vec<(usize, String)> {
+    let qubits: Vec<usize> = qubits.collect();
+    match (qubits.iter().min(), qubits.iter().max()) {
+        (Some(first), Some(last)) if first == last => {
+            vec![(*first, gate(&format!("\\mathrm{{{}}}", hqslang)))]
+        }
+        (Some(first), Some(last)) => vec![
+            (
+                *first,
+                format!(
+                    "\\gate[wires={}]{{\\mathrm{{{}}}}}",
+                    last - first + 1,
+                    hqslang
+                ),
+            ),
+            (*last, "\\qw".to_string()),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// Formats a rotation angle, floats are rounded to four decimal places.
+fn latex_angle(theta: &CalculatorFloat) -> String {
+    match theta {
+        CalculatorFloat::Float(value) => {
+            let rounded = format!("{:.4}", value);
+            let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+            match trimmed {
+                "-0" => "0".to_string(),
+                _ => trimmed.to_string(),
+            }
+        }
+        CalculatorFloat::Str(expression) => expression.replace('_', "\\_"),
+    }
+}
@@ -134,19 +134,97 @@ impl From<RoqoqoVersion> for RoqoqoVersionSerializable {
     }
 }
 
+// Updates the minimum version to the comparison version when the comparison version is higher.
+// Versions are compared lexicographically: major version first, then minor and patch version.
 #[inline]
 fn update_roqoqo_version(
     current_minimum_version: &mut (u32, u32, u32),
     comparison_version: (u32, u32, u32),
 ) {
-    if current_minimum_version.0 < comparison_version.0
-        || current_minimum_version.1 < comparison_version.1
-        || current_minimum_version.2 < comparison_version.2
-    {
+    if comparison_version > *current_minimum_version {
         *current_minimum_version = comparison_version;
     }
 }
 
+// Deserializes a json string and rejects fields that are not part of the deserialized object.
+//
+// Unknown fields are found by comparing the keys of the input with the keys of the serialized
+// result, all unknown fields are listed in the error with their json path.
+#[cfg(feature = "serialize")]
+pub(crate) fn from_json_strict<T>(input: &str) -> Result<T, RoqoqoError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    from_json_value_strict(parse_json_value(input)?)
+}
+
+// Parses a json string into a json value for the strict deserialization.
+#[cfg(feature = "serialize")]
+pub(crate) fn parse_json_value(input: &str) -> Result<serde_json::Value, RoqoqoError> {
+    serde_json::from_str(input).map_err(|err| RoqoqoError::SerializationError {
+        msg: format!("Input is not valid json: {}", err),
+    })
+}
+
+// Deserializes a json value and rejects fields that are not part of the deserialized object.
+#[cfg(feature = "serialize")]
+pub(crate) fn from_json_value_strict<T>(input_value: serde_json::Value) -> Result<T, RoqoqoError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let deserialized: T = serde_json::from_value(input_value.clone()).map_err(|err| {
+        RoqoqoError::SerializationError {
+            msg: err.to_string(),
+        }
+    })?;
+    let known_value =
+        serde_json::to_value(&deserialized).map_err(|err| RoqoqoError::SerializationError {
+            msg: err.to_string(),
+        })?;
+    let mut unknown_fields: Vec<String> = Vec::new();
+    collect_unknown_fields(&input_value, &known_value, "$", &mut unknown_fields);
+    if unknown_fields.is_empty() {
+        Ok(deserialized)
+    } else {
+        Err(RoqoqoError::SerializationError {
+            msg: format!(
+                "Unknown fields in json input: {}",
+                unknown_fields.join(", ")
+            ),
+        })
+    }
+}
+
+// Adds the json paths of all keys of the input that are missing in the known value.
+#[cfg(feature = "serialize")]
+fn collect_unknown_fields(
+    input: &serde_json::Value,
+    known: &serde_json::Value,
+    path: &str,
+    unknown_fields: &mut Vec<String>,
+) {
+    match (input, known) {
+        (serde_json::Value::Object(input), serde_json::Value::Object(known)) => {
+            for (key, value) in input.iter() {
+                let field_path = format!("{}.{}", path, key);
+                match known.get(key) {
+                    Some(known_value) => {
+                        collect_unknown_fields(value, known_value, &field_path, unknown_fields)
+                    }
+                    None => unknown_fields.push(field_path),
+                }
+            }
+        }
+        (serde_json::Value::Array(input), serde_json::Value::Array(known)) => {
+            for (index, (value, known_value)) in input.iter().zip(known.iter()).enumerate() {
+                let element_path = format!("{}[{}]", path, index);
+                collect_unknown_fields(value, known_value, &element_path, unknown_fields);
+            }
+        }
+        _ => (),
+    }
+}
+
 /// Errors that can occur in roqoqo.
 #[derive(Error, Debug, PartialEq)]
 pub enum RoqoqoError {
@@ -229,6 +307,18 @@ pub enum RoqoqoError {
         /// Name of the missing register.
         name: String,
     },
+    /// Classical register is too small for the readout indices used in a Circuit.
+    #[error("Classical register {name} in circuit {circuit_index} is too small: {required} entries required, {defined} entries defined.")]
+    RegisterTooSmall {
+        /// Name of the classical register.
+        name: String,
+        /// Number of entries required by the operations in the Circuit.
+        required: usize,
+        /// Number of entries defined for the register.
+        defined: usize,
+        /// Index of the Circuit in the circuits of the measurement.
+        circuit_index: usize,
+    },
     /// Error occured in basis rotation measurement.
     #[error("Error occured in basis rotation measurement. {msg}")]
     PauliZProductMeasurementError {
@@ -241,6 +331,118 @@ pub enum RoqoqoError {
         /// Error message
         msg: String,
     },
+    /// Error when two combined circuits define registers with the same name.
+    #[error("Register {name} is defined in both circuits.")]
+    RegisterNameClash {
+        /// Name of the clashing register.
+        name: String,
+    },
+    /// Error when a Circuit contains conflicting definitions of a classical register.
+    #[error("Register {name} is defined with length {first_length} at index {first_index} and with length {second_length} at index {second_index}.")]
+    ConflictingDefinitions {
+        /// Name of the register.
+        name: String,
+        /// Length of the first definition.
+        first_length: usize,
+        /// Index of the first definition in the Circuit.
+        first_index: usize,
+        /// Length of the second definition.
+        second_length: usize,
+        /// Index of the second definition in the Circuit.
+        second_index: usize,
+    },
+    /// Error when remapping registers fails because a register is mapped onto an existing register.
+    #[error("Mapping failed. Register map maps to register {name} that already exists")]
+    RegisterMappingError {
+        /// Name of the target register.
+        name: String,
+    },
+    /// Error when the PRAGMA wrapped in a PragmaChangeDevice is not known to the device.
+    #[error("PRAGMA {hqslang} is not a known device changing PRAGMA.")]
+    UnknownChangeDevicePragma {
+        /// hqslang name of the wrapped PRAGMA.
+        hqslang: String,
+    },
+    /// Error deserializing the PRAGMA wrapped in a PragmaChangeDevice.
+    #[error("Deserializing wrapped PRAGMA {hqslang} failed: {msg}")]
+    ChangeDeviceDeserializationError {
+        /// hqslang name of the wrapped PRAGMA.
+        hqslang: String,
+        /// Error message.
+        msg: String,
+    },
+    /// Error when an hqslang name does not correspond to any Operation.
+    #[error("Operation {hqslang} is not a known roqoqo Operation.")]
+    UnknownOperation {
+        /// hqslang name of the unknown Operation.
+        hqslang: String,
+    },
+    /// Error when the dimension of a statevector or density matrix is not a power of two.
+    #[error("Dimension {dim} of the quantum state is not a power of two.")]
+    StateDimensionNotPowerOfTwo {
+        /// Dimension of the statevector or density matrix.
+        dim: usize,
+    },
+    /// Error when a statevector is not normalized.
+    #[error("Statevector is not normalized, its norm is {norm}.")]
+    StateVectorNotNormalized {
+        /// Norm of the statevector.
+        norm: f64,
+    },
+    /// Error when a density matrix is not square.
+    #[error("Density matrix of shape ({rows}, {columns}) is not square.")]
+    DensityMatrixNotSquare {
+        /// Number of rows of the density matrix.
+        rows: usize,
+        /// Number of columns of the density matrix.
+        columns: usize,
+    },
+    /// Error when a density matrix is not Hermitian.
+    #[error("Density matrix is not Hermitian, entries ({row}, {column}) and ({column}, {row}) are not complex conjugates.")]
+    DensityMatrixNotHermitian {
+        /// Row of the first non-Hermitian entry.
+        row: usize,
+        /// Column of the first non-Hermitian entry.
+        column: usize,
+    },
+    /// Error when the trace of a density matrix is not one.
+    #[error("Trace of the density matrix is {trace} instead of one.")]
+    DensityMatrixTraceNotOne {
+        /// Trace of the density matrix.
+        trace: f64,
+    },
+    /// Error when a register that is serialized to json contains a value that is not finite.
+    #[error("Register {name} contains a value that is not finite in row {row} at index {index}. Use the lossy json serialization to write NaN and infinite values.")]
+    NonFiniteRegisterValue {
+        /// Name of the register.
+        name: String,
+        /// Index of the row (the run of the quantum program) in the register.
+        row: usize,
+        /// Index of the value in the row.
+        index: usize,
+    },
+    /// Error when two scheduled operations acting on the same qubit overlap in time.
+    #[error("Operation {first_hqslang} at index {first_index} and operation {second_hqslang} at index {second_index} overlap on qubit {qubit}.")]
+    OverlappingOperations {
+        /// Qubit on which the operations overlap.
+        qubit: usize,
+        /// hqslang name of the operation starting first.
+        first_hqslang: String,
+        /// Index of the operation starting first in the Circuit.
+        first_index: usize,
+        /// hqslang name of the operation starting second.
+        second_hqslang: String,
+        /// Index of the operation starting second in the Circuit.
+        second_index: usize,
+    },
+    /// Error when the number of repetitions of a loop is symbolic where a number is required.
+    #[error("PragmaLoop at index {index} has the symbolic number of repetitions {repetitions}. Substitute the parameters first.")]
+    SymbolicRepetitions {
+        /// Symbolic number of repetitions of the loop.
+        repetitions: String,
+        /// Index of the loop in the Circuit or CircuitDag.
+        index: usize,
+    },
     /// Generic error that does not fit in other error categories.
     #[error("An error occured in roqoqo: {msg} ")]
     GenericError {
@@ -429,6 +631,8 @@ impl JsonSchema for Array2f64Def {
     }
 }
 
+#[cfg(feature = "unstable_analog_operations")]
+pub mod analog;
 #[doc(hidden)]
 mod circuit;
 pub use circuit::Circuit;
@@ -439,13 +643,57 @@ mod circuitdag;
 #[cfg(feature = "circuitdag")]
 pub use circuitdag::CircuitDag;
 pub mod backends;
+pub mod builder;
+#[cfg(all(feature = "circuitdag", feature = "serialize"))]
+mod cirq;
+#[cfg(feature = "serialize")]
+mod float_serialization;
+mod latex;
+pub use latex::LatexOptions;
 pub mod devices;
 pub mod measurements;
 pub mod operations;
+pub mod passes;
 pub mod prelude;
 #[doc(hidden)]
 mod quantum_program;
 pub mod registers;
+#[cfg(feature = "circuitdag")]
+pub mod schedule;
+#[cfg(feature = "serialize")]
+mod sorted_serialization;
+pub mod testing;
 pub use quantum_program::QuantumProgram;
 
 pub mod noise_models;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn update_roqoqo_version_lexicographic() {
+        let mut version = (1, 10, 0);
+        update_roqoqo_version(&mut version, (1, 9, 5));
+        assert_eq!(version, (1, 10, 0));
+
+        let mut version = (1, 9, 5);
+        update_roqoqo_version(&mut version, (1, 10, 0));
+        assert_eq!(version, (1, 10, 0));
+
+        let mut version = (2, 0, 0);
+        update_roqoqo_version(&mut version, (1, 15, 3));
+        assert_eq!(version, (2, 0, 0));
+
+        let mut version = (1, 15, 3);
+        update_roqoqo_version(&mut version, (2, 0, 0));
+        assert_eq!(version, (2, 0, 0));
+
+        let mut version = (1, 5, 0);
+        update_roqoqo_version(&mut version, (0, 9, 3));
+        assert_eq!(version, (1, 5, 0));
+
+        let mut version = (1, 5, 0);
+        update_roqoqo_version(&mut version, (1, 5, 1));
+        assert_eq!(version, (1, 5, 1));
+    }
+}
@@ -11,9 +11,13 @@
 // limitations under the License.
 
 use super::*;
+use crate::operations::{
+    DefinitionBit, MeasureQubit, OperateSingleQubit, PauliX, PragmaRepeatedMeasurement,
+};
 use ndarray::{Array1, Array2};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Collected information for executing a measurement of PauliZ product.
 #[derive(Debug, PartialEq, Clone)]
@@ -103,11 +107,353 @@ impl MeasureExpectationValues for PauliZProduct {
     /// * `Ok(None)` - The measurement did not fail but is incomplete. A new round of measurements is needed
     /// * `Err([RoqoqoError::PauliZProductMeasurementError])` - An error occured in PauliZ product measurement.
     ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "PauliZProduct::evaluate", level = "info", skip_all)
+    )]
     fn evaluate(
         &self,
         bit_registers: HashMap<String, BitOutputRegister>,
         float_registers: HashMap<String, FloatOutputRegister>,
         complex_registers: HashMap<String, ComplexOutputRegister>,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        self.evaluate_with_register_weights(bit_registers, &HashMap::new(), &HashMap::new())
+    }
+}
+
+impl PauliZProduct {
+    /// Creates a new PauliZProduct measurement, checking the readout registers of the input against the circuits.
+    ///
+    /// For every readout register of the Pauli products in `input` at least one circuit has to write to the
+    /// register (checked with the `involved_classical` of its operations). In every circuit writing to the register,
+    /// the register has to be defined in the circuit or the constant circuit
+    /// with a length covering all qubits of the Pauli products read out from the register.
+    /// Without this check these errors only appear when the measurement is evaluated.
+    ///
+    /// # Arguments
+    ///
+    /// * `constant_circuit` - The constant Circuit that is executed before each Circuit in circuits.
+    /// * `circuits` - The collection of quantum circuits for the separate basis rotations.
+    /// * `input` - The additional input information required for measurement.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new PauliZProduct measurement.
+    /// * `Err([RoqoqoError::MissingRegister])` - No circuit writes to a readout register of the input or the register is not defined.
+    /// * `Err([RoqoqoError::RegisterTooSmall])` - A readout register is too short for the qubits of its Pauli products.
+    pub fn new_checked(
+        constant_circuit: Option<Circuit>,
+        circuits: Vec<Circuit>,
+        input: PauliZProductInput,
+    ) -> Result<Self, RoqoqoError> {
+        for (readout, masks) in input.pauli_product_qubit_masks.iter() {
+            let required = masks
+                .values()
+                .flat_map(|mask| mask.iter())
+                .map(|qubit| qubit + 1)
+                .max()
+                .unwrap_or(0);
+            let mut written = false;
+            for (circuit_index, circuit) in circuits.iter().enumerate() {
+                let mut length: Option<usize> = None;
+                let mut writes_register = false;
+                for operation in constant_circuit
+                    .iter()
+                    .flat_map(|constant| constant.iter())
+                    .chain(circuit.iter())
+                {
+                    match operation {
+                        Operation::DefinitionBit(definition) if definition.name() == readout => {
+                            length = Some(*definition.length())
+                        }
+                        _ => {
+                            writes_register |= match operation.involved_classical() {
+                                InvolvedClassical::Set(entries) => {
+                                    entries.iter().any(|(name, _)| name == readout)
+                                }
+                                InvolvedClassical::All(name)
+                                | InvolvedClassical::AllQubits(name) => &name == readout,
+                                InvolvedClassical::None => false,
+                            }
+                        }
+                    }
+                }
+                if !writes_register {
+                    continue;
+                }
+                written = true;
+                let defined = length.ok_or_else(|| RoqoqoError::MissingRegister {
+                    name: readout.clone(),
+                })?;
+                if defined < required {
+                    return Err(RoqoqoError::RegisterTooSmall {
+                        name: readout.clone(),
+                        required,
+                        defined,
+                        circuit_index,
+                    });
+                }
+            }
+            if !written {
+                return Err(RoqoqoError::MissingRegister {
+                    name: readout.clone(),
+                });
+            }
+        }
+        Ok(Self {
+            constant_circuit,
+            circuits,
+            input,
+        })
+    }
+
+    /// Creates a new PauliZProduct measurement, adding the flipped measurement circuits when required.
+    ///
+    /// When the input uses flipped measurements, a flipped copy of every circuit is appended to
+    /// `circuits` in the same order. In the flipped copy all qubits read out into a register of the input are
+    /// flipped with a PauliX gate directly before their first measurement and the readout register `<register>`
+    /// is replaced by `<register>_flipped`, the naming convention used by [MeasureExpectationValues::evaluate].
+    /// Flipped copies of register definitions are added to the flipped circuits, in front of the circuit
+    /// when the register is defined in the constant circuit.
+    /// When the input does not use flipped measurements the circuits are used unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `constant_circuit` - The constant Circuit that is executed before each Circuit in circuits.
+    /// * `circuits` - The quantum circuits for the separate basis rotations without flipped readout.
+    /// * `input` - The additional input information required for measurement.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PauliZProduct measurement including the flipped circuits.
+    /// * `Err([RoqoqoError::PauliZProductMeasurementError])` - A flipped register name is already used.
+    pub fn with_flipped_circuits(
+        constant_circuit: Option<Circuit>,
+        circuits: Vec<Circuit>,
+        input: PauliZProductInput,
+    ) -> Result<Self, RoqoqoError> {
+        if !input.use_flipped_measurement {
+            return Ok(Self {
+                constant_circuit,
+                circuits,
+                input,
+            });
+        }
+        let readouts: HashMap<&str, String> = input
+            .pauli_product_qubit_masks
+            .keys()
+            .map(|name| (name.as_str(), format!("{}_flipped", name)))
+            .collect();
+        // Definitions of readout registers in the constant circuit are copied into every flipped circuit
+        let mut constant_definitions: Vec<Operation> = Vec::new();
+        for operation in constant_circuit.iter().flat_map(|circuit| circuit.iter()) {
+            check_flipped_name(&readouts, operation)?;
+            if let Operation::DefinitionBit(definition) = operation {
+                if let Some(flipped) = readouts.get(definition.name().as_str()) {
+                    constant_definitions.push(
+                        DefinitionBit::new(
+                            flipped.clone(),
+                            *definition.length(),
+                            *definition.is_output(),
+                        )
+                        .into(),
+                    );
+                }
+            }
+        }
+
+        let mut flipped_circuits: Vec<Circuit> = Vec::with_capacity(circuits.len());
+        for circuit in circuits.iter() {
+            let mut flipped_circuit = Circuit::new();
+            for definition in constant_definitions.iter() {
+                flipped_circuit.add_operation(definition.clone());
+            }
+            let mut register_lengths: HashMap<&str, usize> = HashMap::new();
+            for operation in constant_circuit
+                .iter()
+                .flat_map(|circuit| circuit.iter())
+                .chain(circuit.iter())
+            {
+                if let Operation::DefinitionBit(definition) = operation {
+                    register_lengths.insert(definition.name().as_str(), *definition.length());
+                }
+            }
+            let mut flipped_qubits: HashSet<usize> = HashSet::new();
+            for operation in circuit.iter() {
+                check_flipped_name(&readouts, operation)?;
+                match operation {
+                    Operation::DefinitionBit(definition) => {
+                        match readouts.get(definition.name().as_str()) {
+                            Some(flipped) => flipped_circuit.add_operation(DefinitionBit::new(
+                                flipped.clone(),
+                                *definition.length(),
+                                *definition.is_output(),
+                            )),
+                            None => flipped_circuit.add_operation(operation.clone()),
+                        }
+                    }
+                    Operation::MeasureQubit(measurement) => match readouts
+                        .get(measurement.readout().as_str())
+                    {
+                        Some(flipped) => {
+                            if flipped_qubits.insert(*measurement.qubit()) {
+                                flipped_circuit.add_operation(PauliX::new(*measurement.qubit()));
+                            }
+                            flipped_circuit.add_operation(MeasureQubit::new(
+                                *measurement.qubit(),
+                                flipped.clone(),
+                                *measurement.readout_index(),
+                            ));
+                        }
+                        None => flipped_circuit.add_operation(operation.clone()),
+                    },
+                    Operation::PragmaRepeatedMeasurement(measurement) => {
+                        match readouts.get(measurement.readout().as_str()) {
+                            Some(flipped) => {
+                                let measured_qubits: Vec<usize> = match measurement.qubit_mapping()
+                                {
+                                    Some(mapping) => {
+                                        let mut qubits: Vec<usize> =
+                                            mapping.keys().copied().collect();
+                                        qubits.sort_unstable();
+                                        qubits
+                                    }
+                                    None => (0..register_lengths
+                                        .get(measurement.readout().as_str())
+                                        .copied()
+                                        .unwrap_or(input.number_qubits))
+                                        .collect(),
+                                };
+                                for qubit in measured_qubits {
+                                    if flipped_qubits.insert(qubit) {
+                                        flipped_circuit.add_operation(PauliX::new(qubit));
+                                    }
+                                }
+                                flipped_circuit.add_operation(PragmaRepeatedMeasurement::new(
+                                    flipped.clone(),
+                                    *measurement.number_measurements(),
+                                    measurement.qubit_mapping().clone(),
+                                ));
+                            }
+                            None => flipped_circuit.add_operation(operation.clone()),
+                        }
+                    }
+                    _ => flipped_circuit.add_operation(operation.clone()),
+                }
+            }
+            flipped_circuits.push(flipped_circuit);
+        }
+        let mut circuits = circuits;
+        circuits.extend(flipped_circuits);
+        Ok(Self {
+            constant_circuit,
+            circuits,
+            input,
+        })
+    }
+
+    /// Returns a copy of the measurement in which identical circuits are only kept once.
+    ///
+    /// The first occurrence of every circuit is kept and the order of the kept circuits is unchanged.
+    /// Identical circuits write into the same readout registers, so executing a duplicate only adds
+    /// repeated readouts of the same Pauli products. The [PauliZProductInput] refers to the readout
+    /// registers and not to the circuit indices and is therefore valid for the deduplicated circuits as well.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The measurement without duplicate circuits.
+    /// * `Vec<usize>` - The index of the kept circuit for every circuit of the original measurement.
+    pub fn deduplicate_circuits(&self) -> (Self, Vec<usize>) {
+        let mut circuits: Vec<Circuit> = Vec::new();
+        let mut mapping: Vec<usize> = Vec::with_capacity(self.circuits.len());
+        for circuit in self.circuits.iter() {
+            match circuits.iter().position(|kept| kept == circuit) {
+                Some(index) => mapping.push(index),
+                None => {
+                    mapping.push(circuits.len());
+                    circuits.push(circuit.clone());
+                }
+            }
+        }
+        (
+            Self {
+                constant_circuit: self.constant_circuit.clone(),
+                circuits,
+                input: self.input.clone(),
+            },
+            mapping,
+        )
+    }
+
+    /// Executes the PauliZ product measurement with weighted readout registers.
+    ///
+    /// The expectation values of the Pauli products measured in a readout register are multiplied by the
+    /// weight of the register before the expectation values are evaluated, e.g. to account for
+    /// importance sampling of the measured Pauli product groups. Registers without weight have weight 1.0,
+    /// so evaluating with empty weights is identical to [MeasureExpectationValues::evaluate].
+    ///
+    /// When `use_flipped_measurement` is set, the normal and flipped readout of a register are weighted
+    /// separately before they are averaged. The flipped readout `<register>_flipped` uses the weight of
+    /// `<register>` unless a weight for `<register>_flipped` is given.
+    /// The measured expectation value of each Pauli product is additionally multiplied by its entry in
+    /// `pauli_product_weights`. Pauli products without weight have weight 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The classical bit registers as a HashMap with the register name as key.
+    /// * `float_registers` - The classical float registers as a HashMap with the register name as key.
+    /// * `complex_registers` - The classical complex registers as a HashMap with the register name as key.
+    /// * `weights` - The weights of the readout registers as a HashMap with the register name as key.
+    /// * `pauli_product_weights` - The weights of the measured Pauli products as a HashMap with the Pauli product index as key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(HashMap<String, f64>))` - The measurement has been evaluated successfully. The HashMap contains the measured expectation values.
+    /// * `Ok(None)` - The measurement did not fail but is incomplete. A new round of measurements is needed
+    /// * `Err([RoqoqoError::PauliZProductMeasurementError])` - An error occured in PauliZ product measurement or a weight is given for an unknown register or Pauli product.
+    #[allow(unused_variables)]
+    pub fn evaluate_weighted(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        float_registers: HashMap<String, FloatOutputRegister>,
+        complex_registers: HashMap<String, ComplexOutputRegister>,
+        weights: HashMap<String, f64>,
+        pauli_product_weights: HashMap<usize, f64>,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        for name in weights.keys() {
+            let register_name = match name.strip_suffix("_flipped") {
+                Some(stripped) if self.input.use_flipped_measurement => stripped,
+                _ => name.as_str(),
+            };
+            if !self
+                .input
+                .pauli_product_qubit_masks
+                .contains_key(register_name)
+            {
+                return Err(RoqoqoError::PauliZProductMeasurementError {
+                    msg: format!("Weight given for unknown register {}", name),
+                });
+            }
+        }
+        for index in pauli_product_weights.keys() {
+            if *index >= self.input.number_pauli_products {
+                return Err(RoqoqoError::PauliZProductMeasurementError {
+                    msg: format!(
+                        "Weight given for Pauli product {}, only {} Pauli products have been added",
+                        index, self.input.number_pauli_products
+                    ),
+                });
+            }
+        }
+        self.evaluate_with_register_weights(bit_registers, &weights, &pauli_product_weights)
+    }
+
+    // Evaluates the expectation values, weighting the Pauli products measured in each register.
+    fn evaluate_with_register_weights(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        weights: &HashMap<String, f64>,
+        pauli_product_weights: &HashMap<usize, f64>,
     ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
         // todo replace with actual input
         let measurement_fidelities = vec![1.0; self.input.number_qubits];
@@ -194,34 +540,48 @@ impl MeasureExpectationValues for PauliZProduct {
         let mut pauli_products: Array1<f64> = Array1::zeros(self.input.number_pauli_products);
         for (register_name, _) in self.input.pauli_product_qubit_masks.iter() {
             if !register_name.ends_with("flipped") {
+                let register_weight = weights.get(register_name).copied().unwrap_or(1.0);
                 // Create temporary averaged vector of pauli_products
                 // Averaging between normal and flipped readout when flipped measurement is used
                 if self.input.use_flipped_measurement {
+                    let flipped_name = format!("{}_flipped", register_name);
+                    let flipped_weight = weights
+                        .get(&flipped_name)
+                        .copied()
+                        .unwrap_or(register_weight);
                     let tmp_pauli_products = (&pauli_product_dict
                         .get(register_name.as_str())
                         .ok_or(RoqoqoError::PauliZProductMeasurementError {
                             msg: format!("Register name {} not fount", register_name),
                         })?
                         .view()
+                        * register_weight
                         + &pauli_product_dict
-                            .get(format!("{}_flipped", register_name).as_str())
+                            .get(flipped_name.as_str())
                             .ok_or(RoqoqoError::PauliZProductMeasurementError {
                                 msg: format!("Register name {}_flipped not fount", register_name),
                             })?
-                            .view())
+                            .view()
+                            * flipped_weight)
                         / 2.0;
                     // reinserting in dict of pauli products
                     pauli_products += &tmp_pauli_products.view();
                 } else {
-                    pauli_products += &pauli_product_dict
+                    pauli_products += &(&pauli_product_dict
                         .get(register_name.as_str())
                         .ok_or(RoqoqoError::PauliZProductMeasurementError {
                             msg: format!("Register name {} not fount", register_name),
                         })?
                         .view()
+                        * register_weight)
                 }
             }
         }
+        for (index, pauli_product_weight) in pauli_product_weights.iter() {
+            if let Some(value) = pauli_products.get_mut(*index) {
+                *value *= pauli_product_weight;
+            }
+        }
         // Evaluating expectation values
         let mut results: HashMap<String, f64> = HashMap::new();
 
@@ -267,3 +627,22 @@ impl crate::operations::SupportedVersion for PauliZProduct {
         current_minimum_version
     }
 }
+
+// Returns an error when an operation uses the flipped name of a readout register.
+fn check_flipped_name(
+    readouts: &HashMap<&str, String>,
+    operation: &Operation,
+) -> Result<(), RoqoqoError> {
+    let name = match operation {
+        Operation::DefinitionBit(definition) => definition.name(),
+        Operation::MeasureQubit(measurement) => measurement.readout(),
+        Operation::PragmaRepeatedMeasurement(measurement) => measurement.readout(),
+        _ => return Ok(()),
+    };
+    if readouts.values().any(|flipped| flipped == name) {
+        return Err(RoqoqoError::PauliZProductMeasurementError {
+            msg: format!("Flipped readout register {} is already used", name),
+        });
+    }
+    Ok(())
+}
@@ -15,15 +15,14 @@ use crate::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegi
 use crate::Circuit;
 use crate::RoqoqoError;
 use ndarray::{Array1, ArrayView1, ArrayView2};
-//use ndarray::{Array2};
 use num_complex::Complex64;
-//use sprs::{CsMat, TriMat};
 use std::collections::HashMap;
 
 /// Cheated measurement using state obtained from simulator backend.
 ///
 /// Cheated measurements are only possible witch simulator backends that can return the state vector or the density matrix of the quantum computer.
-/// The expectation values are defined by a matrix representation of the measured observables.
+/// The expectation values are defined by a sparse matrix representation of the measured observables,
+/// the operators are never converted to dense matrices.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
@@ -109,6 +108,10 @@ impl MeasureExpectationValues for Cheated {
     /// * `Err([RoqoqoError::MissingRegister])` - The OutputRegister is missing.
     /// * `Err([RoqoqoError::MismatchedRegisterDimension])` - The dimension of register exceeds Hilbert space dimension of qubits.
     #[allow(unused_variables)]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Cheated::evaluate", level = "info", skip_all)
+    )]
     fn evaluate(
         &self,
         bit_registers: HashMap<String, BitOutputRegister>,
@@ -119,11 +122,6 @@ impl MeasureExpectationValues for Cheated {
         // Evaluating expectation values
         let mut results: HashMap<String, f64> = HashMap::new();
         for (name, (operator, readout)) in self.input.measured_operators.iter() {
-            // let row_inds: Vec<usize> = operator.iter().map(|(row, _, _)| row).cloned().collect();
-            // let col_inds: Vec<usize> = operator.iter().map(|(_, col, _)| col).cloned().collect();
-            // let data: Vec<Complex64> = operator.iter().map(|(_, _, val)| val).cloned().collect();
-            // let tmp_tri = TriMat::from_triplets((dimension, dimension), row_inds, col_inds, data);
-            // let matrix: CsMat<Complex64> = tmp_tri.to_csc();
             let register_vec =
                 complex_registers
                     .get(readout)
@@ -134,11 +132,6 @@ impl MeasureExpectationValues for Cheated {
             for (index, register) in register_vec.iter().enumerate() {
                 if register.len() == dimension {
                     let vector: ArrayView1<Complex64> = ArrayView1::<Complex64>::from(register);
-                    // let complex_conj_vec: Vec<Complex64> =
-                    //     register.iter().map(|x| x.conj()).collect();
-                    // let complex_conj: ArrayView1<Complex64> =
-                    //     ArrayView1::<Complex64>::from(&complex_conj_vec);
-                    // let tmp_val: Complex64 = complex_conj.dot(&(&matrix * &vector));
                     let tmp_val: Complex64 =
                         sparse_matrix_vector_expectation_value(operator, &vector);
                     local_results[index] = tmp_val.re;
@@ -146,15 +139,6 @@ impl MeasureExpectationValues for Cheated {
                     let vector: ArrayView2<Complex64> =
                         ArrayView2::<Complex64>::from_shape((dimension, dimension), register)
                             .expect("Unexpected error reshaping array");
-                    // let complex_conj_vec: Vec<Complex64> =
-                    //     register.iter().map(|x| x.conj()).collect();
-                    // let complex_conj: Array2<Complex64> = Array2::<Complex64>::from_shape_vec(
-                    //     (dimension, dimension),
-                    //     complex_conj_vec,
-                    // )
-                    // .expect("Unexpected error reshaping array");
-                    // let tmp_val: Complex64 =
-                    //     ((complex_conj.t()).dot(&(&matrix * &vector))).diag().sum();
                     let tmp_val =
                         sparse_matrix_matrix_expectation_value(operator, &vector, dimension);
                     local_results[index] = tmp_val.re;
@@ -177,6 +161,7 @@ impl MeasureExpectationValues for Cheated {
     }
 }
 
+/// Computes <psi|O|psi> directly from the sparse (row, col, value) entries of O.
 #[inline]
 fn sparse_matrix_vector_expectation_value(
     matrix: &[(usize, usize, Complex64)],
@@ -189,6 +174,7 @@ fn sparse_matrix_vector_expectation_value(
     val
 }
 
+/// Computes the trace form of the expectation value for a density matrix directly from the sparse entries of O.
 #[inline]
 fn sparse_matrix_matrix_expectation_value(
     matrix: &[(usize, usize, Complex64)],
@@ -15,7 +15,9 @@ use crate::CalculatorFloat;
 use crate::Complex64Def;
 use crate::RoqoqoError;
 use num_complex::Complex64;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use struqture::spins::PauliProduct;
+use struqture::SpinIndex;
 
 /// Provides PauliProductMasks for all Pauli Products measured from one readout register.
 pub type SingleReadoutPauliProductMasks = HashMap<usize, PauliProductMask>;
@@ -137,6 +139,61 @@ impl PauliZProductInput {
         Ok(self.number_pauli_products - 1)
     }
 
+    /// Adds many measured Pauli products on the same readout register at once.
+    ///
+    /// Equivalent to calling [PauliZProductInput::add_pauliz_product] for every product in order
+    /// but runs in linear time in the number of products.
+    /// When a Pauli product is already in the measurement input its existing index is returned.
+    /// No product is added when any product involves a qubit exceeding the number of qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout` - The name of the readout register the Pauli products are defined on.
+    /// * `products` - The qubit masks of the Pauli products.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<usize>)` - The indices of the Pauli products in the order of `products`.
+    /// * `Err([RoqoqoError::PauliProductExceedsQubits])` - A Pauli product involves a qubit exceeding the maximum number of qubits.
+    pub fn add_pauliz_products(
+        &mut self,
+        readout: &str,
+        products: Vec<PauliProductMask>,
+    ) -> Result<Vec<usize>, RoqoqoError> {
+        if let Some(i) = products
+            .iter()
+            .flatten()
+            .find(|i| **i >= self.number_qubits)
+        {
+            return Err(RoqoqoError::PauliProductExceedsQubits {
+                pp_qubit: *i,
+                number_qubits: self.number_qubits,
+            });
+        }
+        let masks = self
+            .pauli_product_qubit_masks
+            .entry(readout.to_string())
+            .or_default();
+        let mut known: HashMap<PauliProductMask, usize> = masks
+            .iter()
+            .map(|(index, mask)| (mask.clone(), *index))
+            .collect();
+        let mut indices = Vec::with_capacity(products.len());
+        for mask in products {
+            match known.get(&mask) {
+                Some(index) => indices.push(*index),
+                None => {
+                    let index = self.number_pauli_products;
+                    known.insert(mask.clone(), index);
+                    masks.insert(index, mask);
+                    self.number_pauli_products += 1;
+                    indices.push(index);
+                }
+            }
+        }
+        Ok(indices)
+    }
+
     /// Adds linear definition of expectation value to measurement input.
     ///
     /// Adds an expectation value that is defined by a linear combination
@@ -166,6 +223,36 @@ impl PauliZProductInput {
         Ok(())
     }
 
+    /// Adds many linear definitions of expectation values at once.
+    ///
+    /// No expectation value is added when any name is already taken or used twice in `entries`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The names of the expectation values and their linear combinations as maps between Pauli product index and coefficient.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok()` - The values were successfully added.
+    /// * `Err([RoqoqoError::ExpValUsedTwice])` - The name of an expectation value is already taken.
+    pub fn add_linear_exp_vals(
+        &mut self,
+        entries: Vec<(String, HashMap<usize, f64>)>,
+    ) -> Result<(), RoqoqoError> {
+        let mut names: HashSet<&String> = HashSet::with_capacity(entries.len());
+        for (name, _) in entries.iter() {
+            if self.measured_exp_vals.contains_key(name) || !names.insert(name) {
+                return Err(RoqoqoError::ExpValUsedTwice { name: name.clone() });
+            }
+        }
+        self.measured_exp_vals.extend(
+            entries
+                .into_iter()
+                .map(|(name, linear)| (name, PauliProductsToExpVal::Linear(linear))),
+        );
+        Ok(())
+    }
+
     /// Adds symbolic definition of expectation value to measurement input.
     ///
     /// Adds an expectation value that is defined by a symbolic combination
@@ -197,13 +284,86 @@ impl PauliZProductInput {
         }
         Ok(())
     }
+
+    /// Returns the number of qubits that are measured.
+    pub fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+
+    /// Returns the number of registered Pauli products.
+    pub fn number_pauli_products(&self) -> usize {
+        self.number_pauli_products
+    }
+
+    /// Returns the sorted names of the registered expectation values.
+    pub fn measured_exp_val_names(&self) -> Vec<String> {
+        sorted_exp_val_names(&self.measured_exp_vals)
+    }
+
+    /// Returns the registered Pauli products ordered by their index.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(String, PauliProductMask)>` - The readout register and qubit mask of every Pauli product.
+    pub fn pauli_products(&self) -> Vec<(String, PauliProductMask)> {
+        let mut products: Vec<(usize, String, PauliProductMask)> = self
+            .pauli_product_qubit_masks
+            .iter()
+            .flat_map(|(readout, masks)| {
+                masks
+                    .iter()
+                    .map(move |(index, mask)| (*index, readout.clone(), mask.clone()))
+            })
+            .collect();
+        products.sort_unstable_by_key(|(index, _, _)| *index);
+        products
+            .into_iter()
+            .map(|(_, readout, mask)| (readout, mask))
+            .collect()
+    }
+
+    /// Renames the readout registers in a clone of the PauliZProductInput.
+    ///
+    /// Readout registers that are not in the keys of the mapping keep their name.
+    /// Use together with [crate::Circuit::remap_registers] on the circuits of the measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The HashMap containing the {old_name: new_name} mapping of the readout registers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PauliZProductInput with the readout registers renamed.
+    /// * `Err(RoqoqoError::RegisterMappingError)` - A readout register is mapped onto a readout register that is not renamed,
+    ///   or two readout registers are mapped onto the same name.
+    pub fn remap_registers(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        crate::circuit::check_valid_register_mapping(
+            self.pauli_product_qubit_masks.keys().cloned(),
+            mapping,
+        )?;
+        let mut new_input = self.clone();
+        new_input.pauli_product_qubit_masks = self
+            .pauli_product_qubit_masks
+            .iter()
+            .map(|(readout, masks)| {
+                (
+                    mapping.get(readout).unwrap_or(readout).clone(),
+                    masks.clone(),
+                )
+            })
+            .collect();
+        Ok(new_input)
+    }
 }
 
 /// Provides necessary information to run a [crate::measurements::CheatedPauliZProduct] measurement.
 ///
 /// Is used by the full measurement struct [crate::measurements::CheatedPauliZProduct].
+///
+/// Pauli products added with [CheatedPauliZProductInput::add_pauli_product] or
+/// [CheatedPauliZProductInput::add_pauliz_product_at] are only serialized in human readable formats like json.
+/// Binary formats like bincode keep the layout of roqoqo 1.0 and fail to serialize these Pauli products.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct CheatedPauliZProductInput {
     /// Collection of names and construction methods of  expectation values.
@@ -212,6 +372,114 @@ pub struct CheatedPauliZProductInput {
     pub measured_exp_vals: HashMap<String, PauliProductsToExpVal>,
     /// Mapping the pauli product indices to the readout keys.
     pub pauli_product_keys: HashMap<String, usize>,
+    /// Pauli products with X, Y and Z factors evaluated directly on a statevector or density matrix.
+    ///
+    /// Maps the pauli product indices to the name of the complex readout register and the Pauli product.
+    #[cfg_attr(feature = "json_schema", schemars(default))]
+    pub pauli_product_operators: HashMap<usize, (String, PauliProduct)>,
+    /// Pauli products read out from an entry of a float register other than the first one.
+    ///
+    /// Maps the pauli product indices to the name of the float readout register and the readout index.
+    #[cfg_attr(feature = "json_schema", schemars(default))]
+    pub pauli_product_readout_indices: HashMap<usize, (String, usize)>,
+}
+
+// Serialized form of CheatedPauliZProductInput in human readable formats.
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "CheatedPauliZProductInput")]
+struct CheatedPauliZProductInputReadable {
+    measured_exp_vals: HashMap<String, PauliProductsToExpVal>,
+    pauli_product_keys: HashMap<String, usize>,
+    #[serde(default)]
+    pauli_product_operators: HashMap<usize, (String, PauliProduct)>,
+    #[serde(default)]
+    pauli_product_readout_indices: HashMap<usize, (String, usize)>,
+}
+
+// Borrowed form of CheatedPauliZProductInputReadable used for serialization.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize)]
+#[serde(rename = "CheatedPauliZProductInput")]
+struct CheatedPauliZProductInputReadableRef<'a> {
+    measured_exp_vals: &'a HashMap<String, PauliProductsToExpVal>,
+    pauli_product_keys: &'a HashMap<String, usize>,
+    pauli_product_operators: &'a HashMap<usize, (String, PauliProduct)>,
+    pauli_product_readout_indices: &'a HashMap<usize, (String, usize)>,
+}
+
+// Serialized form of CheatedPauliZProductInput in binary formats, unchanged since roqoqo 1.0.
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "CheatedPauliZProductInput")]
+struct CheatedPauliZProductInputCompact {
+    measured_exp_vals: HashMap<String, PauliProductsToExpVal>,
+    pauli_product_keys: HashMap<String, usize>,
+}
+
+// Borrowed form of CheatedPauliZProductInputCompact used for serialization.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize)]
+#[serde(rename = "CheatedPauliZProductInput")]
+struct CheatedPauliZProductInputCompactRef<'a> {
+    measured_exp_vals: &'a HashMap<String, PauliProductsToExpVal>,
+    pauli_product_keys: &'a HashMap<String, usize>,
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for CheatedPauliZProductInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            CheatedPauliZProductInputReadableRef {
+                measured_exp_vals: &self.measured_exp_vals,
+                pauli_product_keys: &self.pauli_product_keys,
+                pauli_product_operators: &self.pauli_product_operators,
+                pauli_product_readout_indices: &self.pauli_product_readout_indices,
+            }
+            .serialize(serializer)
+        } else if self.pauli_product_operators.is_empty()
+            && self.pauli_product_readout_indices.is_empty()
+        {
+            CheatedPauliZProductInputCompactRef {
+                measured_exp_vals: &self.measured_exp_vals,
+                pauli_product_keys: &self.pauli_product_keys,
+            }
+            .serialize(serializer)
+        } else {
+            Err(serde::ser::Error::custom(
+                "CheatedPauliZProductInput with Pauli products added by add_pauli_product or add_pauliz_product_at can only be serialized to human readable formats",
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for CheatedPauliZProductInput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let helper = CheatedPauliZProductInputReadable::deserialize(deserializer)?;
+            Ok(Self {
+                measured_exp_vals: helper.measured_exp_vals,
+                pauli_product_keys: helper.pauli_product_keys,
+                pauli_product_operators: helper.pauli_product_operators,
+                pauli_product_readout_indices: helper.pauli_product_readout_indices,
+            })
+        } else {
+            let helper = CheatedPauliZProductInputCompact::deserialize(deserializer)?;
+            Ok(Self {
+                measured_exp_vals: helper.measured_exp_vals,
+                pauli_product_keys: helper.pauli_product_keys,
+                pauli_product_operators: HashMap::new(),
+                pauli_product_readout_indices: HashMap::new(),
+            })
+        }
+    }
 }
 
 impl Default for CheatedPauliZProductInput {
@@ -232,6 +500,8 @@ impl CheatedPauliZProductInput {
         Self {
             measured_exp_vals: HashMap::new(),
             pauli_product_keys: HashMap::new(),
+            pauli_product_operators: HashMap::new(),
+            pauli_product_readout_indices: HashMap::new(),
         }
     }
 
@@ -251,9 +521,126 @@ impl CheatedPauliZProductInput {
         if let Some((_, v)) = self.pauli_product_keys.iter().find(|(k, _)| k == &&readout) {
             return *v;
         }
-        self.pauli_product_keys
-            .insert(readout, self.pauli_product_keys.len());
-        self.pauli_product_keys.len() - 1
+        let index = self.number_pauli_products();
+        self.pauli_product_keys.insert(readout, index);
+        index
+    }
+
+    /// Adds measured Pauli product read out from a given entry of a float register and returns its index.
+    ///
+    /// Allows several Pauli products measured with [crate::operations::PragmaGetIndexedPauliProduct] to share
+    /// one readout register. When the Pauli product is already in the measurement input for the same
+    /// readout and readout index the function only returns its index.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout` - The name of the readout register containing the pauli_product expectation value.
+    /// * `readout_index` - The index of the register entry containing the pauli_product expectation value.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The index of the added Pauli product in the list of all Pauli products.
+    pub fn add_pauliz_product_at(&mut self, readout: String, readout_index: usize) -> usize {
+        if readout_index == 0 {
+            return self.add_pauliz_product(readout);
+        }
+        if let Some((index, _)) = self
+            .pauli_product_readout_indices
+            .iter()
+            .find(|(_, (r, i))| r == &readout && *i == readout_index)
+        {
+            return *index;
+        }
+        let index = self.number_pauli_products();
+        self.pauli_product_readout_indices
+            .insert(index, (readout, readout_index));
+        index
+    }
+
+    /// Adds a Pauli product with X, Y and Z factors to CheatedPauliZProductInput and returns its index.
+    ///
+    /// The expectation value of the Pauli product is evaluated directly on the statevector or
+    /// density matrix in the complex readout register. The qubits of the Pauli product refer to the qubits
+    /// of the register in little endian order. When the Pauli product is already in the measurement input
+    /// for the same readout the function only returns its index.
+    /// Inputs containing these Pauli products can not be serialized to bincode.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout` - The name of the complex readout register containing the statevector or density matrix.
+    /// * `pauli_product` - The measured Pauli product.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The index of the added Pauli product in the list of all Pauli products.
+    pub fn add_pauli_product(&mut self, readout: String, pauli_product: PauliProduct) -> usize {
+        if let Some((index, _)) = self
+            .pauli_product_operators
+            .iter()
+            .find(|(_, (r, p))| r == &readout && p == &pauli_product)
+        {
+            return *index;
+        }
+        let index = self.number_pauli_products();
+        self.pauli_product_operators
+            .insert(index, (readout, pauli_product));
+        index
+    }
+
+    /// Returns the number of Pauli products read out from float registers or evaluated on complex registers.
+    pub fn number_pauli_products(&self) -> usize {
+        self.pauli_product_keys.len()
+            + self.pauli_product_operators.len()
+            + self.pauli_product_readout_indices.len()
+    }
+
+    /// Returns the number of qubits the Pauli products evaluated on complex registers act on.
+    ///
+    /// Pauli products read out from float registers do not define their qubits,
+    /// an input containing only those returns 0.
+    pub fn number_qubits(&self) -> usize {
+        self.pauli_product_operators
+            .values()
+            .flat_map(|(_, pauli_product)| pauli_product.iter().map(|(qubit, _)| qubit + 1))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the sorted names of the registered expectation values.
+    pub fn measured_exp_val_names(&self) -> Vec<String> {
+        sorted_exp_val_names(&self.measured_exp_vals)
+    }
+
+    /// Returns the registered Pauli products ordered by their index.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(String, PauliProductMask)>` - The readout register and the qubits of every Pauli product.
+    ///   The qubits of Pauli products read out from float registers are empty.
+    pub fn pauli_products(&self) -> Vec<(String, PauliProductMask)> {
+        let mut products: Vec<(usize, String, PauliProductMask)> =
+            self.pauli_product_keys
+                .iter()
+                .map(|(readout, index)| (*index, readout.clone(), Vec::new()))
+                .chain(
+                    self.pauli_product_readout_indices
+                        .iter()
+                        .map(|(index, (readout, _))| (*index, readout.clone(), Vec::new())),
+                )
+                .chain(self.pauli_product_operators.iter().map(
+                    |(index, (readout, pauli_product))| {
+                        let mut qubits: PauliProductMask =
+                            pauli_product.iter().map(|(qubit, _)| *qubit).collect();
+                        qubits.sort_unstable();
+                        (*index, readout.clone(), qubits)
+                    },
+                ))
+                .collect();
+        products.sort_unstable_by_key(|(index, _, _)| *index);
+        products
+            .into_iter()
+            .map(|(_, readout, qubits)| (readout, qubits))
+            .collect()
     }
 
     /// Adds linear definition of expectation value to measurement input.
@@ -380,6 +767,8 @@ impl CheatedInput {
     /// Adds expectation value of an operator to measurement input.
     ///
     /// Adds an expectation value of a quantum operator defined by a complex matrix.
+    /// The matrix is stored in sparse form, only the non-zero entries need to be provided
+    /// and entries with the same (row, col) are summed up.
     ///
     /// # Arguments
     ///
@@ -417,10 +806,49 @@ impl CheatedInput {
         }
         Ok(())
     }
+
+    /// Returns the number of qubits that defines the dimension of the Hilbert space.
+    pub fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+
+    /// Returns the sorted names of the registered expectation values.
+    pub fn measured_exp_val_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.measured_operators.keys().cloned().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns the names of the measured operators with the dimension of their matrices, sorted by name.
+    ///
+    /// All operators act on the Hilbert space of the input, their dimension is 2^number_qubits.
+    pub fn operator_dimensions(&self) -> Vec<(String, usize)> {
+        let dimension = 2_usize.pow(self.number_qubits as u32);
+        self.measured_exp_val_names()
+            .into_iter()
+            .map(|name| (name, dimension))
+            .collect()
+    }
+}
+
+// Returns the names of the expectation values in alphabetical order.
+fn sorted_exp_val_names(measured_exp_vals: &HashMap<String, PauliProductsToExpVal>) -> Vec<String> {
+    let mut names: Vec<String> = measured_exp_vals.keys().cloned().collect();
+    names.sort_unstable();
+    names
 }
 
 impl crate::operations::SupportedVersion for CheatedInput {}
 
-impl crate::operations::SupportedVersion for CheatedPauliZProductInput {}
+impl crate::operations::SupportedVersion for CheatedPauliZProductInput {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        if self.pauli_product_operators.is_empty() && self.pauli_product_readout_indices.is_empty()
+        {
+            (1, 0, 0)
+        } else {
+            (1, 18, 0)
+        }
+    }
+}
 
 impl crate::operations::SupportedVersion for PauliZProductInput {}
@@ -20,7 +20,16 @@
 //!
 //! # Note
 //! The functionality to **perform** the actual measurement is provided by the measurement operations [crate::operations].
+//!
+//! # Legacy names
+//! Before roqoqo 0.11 the [PauliZProduct] and [CheatedPauliZProduct] measurements were called
+//! `BasisRotation` and `CheatedBasisRotation` (with inputs `BasisRotationInput` and `CheatedBasisRotationInput`).
+//! The struct and field names of the measurements and their inputs are unchanged in the serialized data,
+//! only the variant names of a serialized [crate::QuantumProgram] differ.
+//! A QuantumProgram deserializes the legacy variant names `BasisRotation` and `CheatedBasisRotation`
+//! to the `PauliZProduct` and `CheatedPauliZProduct` variants and is always serialized with the current names.
 
+use crate::operations::{Define, InvolveQubits, InvolvedClassical, Operation};
 use std::collections::HashMap;
 
 #[doc(hidden)]
@@ -38,6 +47,10 @@ pub use cheated_measurement::*;
 #[doc(hidden)]
 mod classical_register_measurement;
 pub use classical_register_measurement::*;
+#[doc(hidden)]
+mod shot_noise;
+pub use shot_noise::*;
+pub mod statistics;
 
 use crate::registers::BitOutputRegister;
 use crate::{
@@ -54,8 +67,20 @@ use async_trait::async_trait;
 #[cfg(feature = "async")]
 use futures::future::FutureExt;
 #[cfg(feature = "async")]
+use futures::stream::{Stream, StreamExt};
+#[cfg(feature = "async")]
 use std::pin::Pin;
 
+/// Stream of successive expectation value estimates returned by [MeasureExpectationValues::async_evaluate_stream].
+#[cfg(feature = "async")]
+pub type EvaluationStream<'a> = Pin<
+    Box<
+        dyn Stream<Item = Result<Option<HashMap<String, f64>>, RoqoqoBackendError>>
+            + std::marker::Send
+            + 'a,
+    >,
+>;
+
 /// Allows generic interfacing with roqoqo measurements.
 ///
 /// # Example
@@ -134,6 +159,74 @@ pub trait Measure: PartialEq + Clone {
         &self,
         substituted_parameters: HashMap<String, f64>,
     ) -> Result<Self, RoqoqoError>;
+
+    /// Validates that the classical registers of every Circuit are defined and large enough.
+    ///
+    /// Every Circuit in circuits is checked together with the constant Circuit.
+    /// Each classical register used by an operation has to be defined in one of the two Circuits,
+    /// and all readout indices used by operations have to be smaller than the length of the definition.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All classical registers are defined and large enough.
+    /// * `Err(RoqoqoError::MissingRegister)` - A classical register is used but not defined.
+    /// * `Err(RoqoqoError::RegisterTooSmall)` - A readout index is larger than the defined register.
+    /// * `Err(RoqoqoError::ConflictingDefinitions)` - A Circuit defines a register in conflicting ways, see [crate::Circuit::check_definitions].
+    fn validate(&self) -> Result<(), RoqoqoError> {
+        if let Some(constant_circuit) = self.constant_circuit() {
+            constant_circuit.check_definitions()?;
+        }
+        for (circuit_index, circuit) in self.circuits().enumerate() {
+            circuit.check_definitions()?;
+            let operations = self
+                .constant_circuit()
+                .iter()
+                .flat_map(|constant| constant.iter())
+                .chain(circuit.iter());
+            let mut defined: HashMap<String, usize> = HashMap::new();
+            let mut required: Vec<(String, Option<usize>)> = Vec::new();
+            for operation in operations {
+                match operation {
+                    Operation::DefinitionBit(def) => {
+                        defined.insert(def.name().clone(), *def.length());
+                    }
+                    Operation::DefinitionFloat(def) => {
+                        defined.insert(def.name().clone(), *def.length());
+                    }
+                    Operation::DefinitionComplex(def) => {
+                        defined.insert(def.name().clone(), *def.length());
+                    }
+                    Operation::DefinitionUsize(def) => {
+                        defined.insert(def.name().clone(), *def.length());
+                    }
+                    _ => match operation.involved_classical() {
+                        InvolvedClassical::Set(entries) => required
+                            .extend(entries.into_iter().map(|(name, index)| (name, Some(index)))),
+                        InvolvedClassical::All(name) | InvolvedClassical::AllQubits(name) => {
+                            required.push((name, None))
+                        }
+                        InvolvedClassical::None => (),
+                    },
+                }
+            }
+            for (name, index) in required {
+                let length = *defined
+                    .get(&name)
+                    .ok_or_else(|| RoqoqoError::MissingRegister { name: name.clone() })?;
+                if let Some(index) = index {
+                    if index >= length {
+                        return Err(RoqoqoError::RegisterTooSmall {
+                            name,
+                            required: index + 1,
+                            defined: length,
+                            circuit_index,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Allows generic interfacing with roqoqo measurements that evaluate expectation values.
@@ -220,13 +313,13 @@ pub trait MeasureExpectationValues: PartialEq + Clone + Measure {
     ///
     /// Arguments:
     ///
-    /// * `registers` - Future .
+    /// * `registers` - Future returning the classical registers of running the measurement.
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(HashMap<String, f64>))` - The measurement has been evaluated successfully. The HashMap contains the measured expectation values.
-    /// * `Ok(None)` - The measurement did not fail but is incomplete. A new round of measurements is needed.
-    /// * `Err(RoqoqoError)` - The measurement evaluation failed.
+    /// * `Ok(HashMap<String, f64>)` - The measurement has been evaluated successfully. The HashMap contains the measured expectation values.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The measurement is incomplete. Use [MeasureExpectationValues::async_evaluate_checked] to handle incomplete measurements.
+    /// * `Err(RoqoqoBackendError)` - Running or evaluating the measurement failed.
     #[cfg(feature = "async")]
     async fn async_evaluate(
         &self,
@@ -234,9 +327,70 @@ pub trait MeasureExpectationValues: PartialEq + Clone + Measure {
             Box<dyn FutureExt<Output = Result<Registers, RoqoqoBackendError>> + std::marker::Send>,
         >,
     ) -> Result<HashMap<String, f64>, RoqoqoBackendError> {
+        self.async_evaluate_checked(registers)
+            .await?
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "Measurement is incomplete, a new round of measurements is needed".to_string(),
+            })
+    }
+
+    /// Evaluates measurement results based on a [futures::future::Future] of classical registers.
+    ///
+    /// Arguments:
+    ///
+    /// * `registers` - Future returning the classical registers of running the measurement.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(HashMap<String, f64>))` - The measurement has been evaluated successfully. The HashMap contains the measured expectation values.
+    /// * `Ok(None)` - The measurement did not fail but is incomplete. A new round of measurements is needed.
+    /// * `Err(RoqoqoBackendError)` - Running or evaluating the measurement failed.
+    #[cfg(feature = "async")]
+    async fn async_evaluate_checked(
+        &self,
+        registers: Pin<
+            Box<dyn FutureExt<Output = Result<Registers, RoqoqoBackendError>> + std::marker::Send>,
+        >,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoBackendError> {
         let (bit_registers, float_registers, complex_registers) = registers.await?;
-        Ok(self
-            .evaluate(bit_registers, float_registers, complex_registers)?
-            .unwrap())
+        Ok(self.evaluate(bit_registers, float_registers, complex_registers)?)
+    }
+
+    /// Evaluates measurement results based on a [futures::stream::Stream] of partial classical registers.
+    ///
+    /// The records of each partial result are appended to the registers received before,
+    /// and the measurement is evaluated on all records received so far.
+    /// Each evaluation is yielded, so the estimates are refined as more records arrive.
+    /// Errors of the stream or the evaluation are yielded without ending the stream.
+    ///
+    /// Arguments:
+    ///
+    /// * `registers` - Stream of partial classical registers of running the measurement.
+    ///
+    /// # Returns
+    ///
+    /// * `Stream` - Yields the result of [MeasureExpectationValues::evaluate] for the records received so far.
+    #[cfg(feature = "async")]
+    fn async_evaluate_stream<'a, S>(&'a self, registers: S) -> EvaluationStream<'a>
+    where
+        Self: Sync,
+        S: Stream<Item = Result<Registers, RoqoqoBackendError>> + std::marker::Send + 'a,
+    {
+        let mut received: Registers = (HashMap::new(), HashMap::new(), HashMap::new());
+        Box::pin(registers.map(move |partial| {
+            let (bit_registers, float_registers, complex_registers) = partial?;
+            append_records(&mut received.0, bit_registers);
+            append_records(&mut received.1, float_registers);
+            append_records(&mut received.2, complex_registers);
+            Ok(self.evaluate(received.0.clone(), received.1.clone(), received.2.clone())?)
+        }))
+    }
+}
+
+// Appends the records of partial output registers to the registers with the same name.
+#[cfg(feature = "async")]
+fn append_records<T>(registers: &mut HashMap<String, Vec<T>>, partial: HashMap<String, Vec<T>>) {
+    for (name, records) in partial.into_iter() {
+        registers.entry(name).or_default().extend(records);
     }
 }
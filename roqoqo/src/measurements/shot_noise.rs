@@ -0,0 +1,85 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::registers::{BitOutputRegister, FloatOutputRegister};
+use crate::RoqoqoError;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Tolerance of the deviation of the sum of probabilities from one.
+const PROBABILITY_TOLERANCE: f64 = 1e-6;
+
+/// Samples measurement records of a bit register from exact occupation probabilities.
+///
+/// Each entry of the float register is a probability distribution over the `2^N` basis states of
+/// `N` qubits, as written by [crate::operations::PragmaGetOccupationProbability].
+/// For each entry, `number_shots` measurement records are sampled and appended to the returned
+/// register. Qubit `i` of a record is set for basis states with bit `i` set in their index.
+/// Backends emulating [crate::operations::PragmaSimulateShotNoise] can use this function.
+///
+/// # Arguments
+///
+/// * `probabilities` - The exact probabilities of the basis states.
+/// * `number_shots` - The number of records sampled from each entry of the register.
+/// * `seed` - The seed of the random number generator, a random seed is used when None.
+///
+/// # Returns
+///
+/// * `Ok(BitOutputRegister)` - The sampled measurement records.
+/// * `Err(RoqoqoError::GenericError)` - An entry is not a probability distribution over `2^N` basis states.
+pub fn sample_bit_register(
+    probabilities: &FloatOutputRegister,
+    number_shots: usize,
+    seed: Option<u64>,
+) -> Result<BitOutputRegister, RoqoqoError> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut register: BitOutputRegister = Vec::with_capacity(probabilities.len() * number_shots);
+    for distribution in probabilities.iter() {
+        if !distribution.len().is_power_of_two() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Length {} of probability register is not a power of two",
+                    distribution.len()
+                ),
+            });
+        }
+        if distribution.iter().any(|p| !p.is_finite() || *p < 0.0) {
+            return Err(RoqoqoError::GenericError {
+                msg: "Probabilities must be finite and non-negative".to_string(),
+            });
+        }
+        let total: f64 = distribution.iter().sum();
+        if (total - 1.0).abs() > PROBABILITY_TOLERANCE {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Probabilities sum up to {} instead of 1", total),
+            });
+        }
+        let number_qubits = distribution.len().trailing_zeros() as usize;
+        let sampler =
+            WeightedIndex::new(distribution).map_err(|err| RoqoqoError::GenericError {
+                msg: format!("Cannot sample from probabilities: {}", err),
+            })?;
+        for _ in 0..number_shots {
+            let state = sampler.sample(&mut rng);
+            register.push(
+                (0..number_qubits)
+                    .map(|qubit| (state >> qubit) & 1 == 1)
+                    .collect(),
+            );
+        }
+    }
+    Ok(register)
+}
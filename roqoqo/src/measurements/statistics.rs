@@ -0,0 +1,182 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics of the measurement records in bit registers.
+//!
+//! The functions evaluate the raw registers returned by [crate::measurements::ClassicalRegister]
+//! measurements. Each entry of a [BitOutputRegister] is one measurement record (shot), the
+//! entries of a record are the measured bits of the qubits.
+//! All functions return an error for empty registers instead of undefined (NaN) values.
+
+use crate::registers::BitOutputRegister;
+use crate::RoqoqoError;
+use std::collections::HashMap;
+
+/// Returns the frequency of measuring 1 for each qubit.
+///
+/// # Arguments
+///
+/// * `register` - The measurement records, all records need to have the same length.
+///
+/// # Returns
+///
+/// * `Ok(Vec<f64>)` - The frequency of `true` for each entry of the records.
+/// * `Err(RoqoqoError::GenericError)` - The register is empty or the records have different lengths.
+pub fn bit_frequencies(register: &BitOutputRegister) -> Result<Vec<f64>, RoqoqoError> {
+    let number_records = check_register(register, &[])? as f64;
+    let number_qubits = register[0].len();
+    if let Some(record) = register.iter().find(|record| record.len() != number_qubits) {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Measurement records have different lengths {} and {}",
+                number_qubits,
+                record.len()
+            ),
+        });
+    }
+    let mut counts = vec![0_usize; number_qubits];
+    for record in register.iter() {
+        for (count, bit) in counts.iter_mut().zip(record.iter()) {
+            if *bit {
+                *count += 1;
+            }
+        }
+    }
+    Ok(counts
+        .into_iter()
+        .map(|count| count as f64 / number_records)
+        .collect())
+}
+
+/// Returns the connected correlation of the bits of two qubits.
+///
+/// The correlation is `<b_i b_j> - <b_i><b_j>`, the covariance of the measured bits
+/// (`true` is counted as 1). It is zero for independent bits and well-defined for
+/// constant bits.
+///
+/// # Arguments
+///
+/// * `register` - The measurement records.
+/// * `i` - The first qubit.
+/// * `j` - The second qubit.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The correlation of the two bits.
+/// * `Err(RoqoqoError::GenericError)` - The register is empty or a record is too short for the qubits.
+pub fn bit_correlation(
+    register: &BitOutputRegister,
+    i: usize,
+    j: usize,
+) -> Result<f64, RoqoqoError> {
+    let number_records = check_register(register, &[i, j])? as f64;
+    let mut count_i = 0_usize;
+    let mut count_j = 0_usize;
+    let mut count_both = 0_usize;
+    for record in register.iter() {
+        count_i += record[i] as usize;
+        count_j += record[j] as usize;
+        count_both += (record[i] && record[j]) as usize;
+    }
+    let mean_i = count_i as f64 / number_records;
+    let mean_j = count_j as f64 / number_records;
+    Ok(count_both as f64 / number_records - mean_i * mean_j)
+}
+
+/// Returns the histogram of the joint outcomes of a set of qubits.
+///
+/// # Arguments
+///
+/// * `register` - The measurement records.
+/// * `qubits` - The qubits, the outcomes list the bits in this order.
+///
+/// # Returns
+///
+/// * `Ok(HashMap<Vec<bool>, usize>)` - The number of records for each measured outcome of the qubits.
+/// * `Err(RoqoqoError::GenericError)` - The register is empty or a record is too short for the qubits.
+pub fn joint_histogram(
+    register: &BitOutputRegister,
+    qubits: &[usize],
+) -> Result<HashMap<Vec<bool>, usize>, RoqoqoError> {
+    check_register(register, qubits)?;
+    let mut histogram: HashMap<Vec<bool>, usize> = HashMap::new();
+    for record in register.iter() {
+        let outcome: Vec<bool> = qubits.iter().map(|qubit| record[*qubit]).collect();
+        *histogram.entry(outcome).or_insert(0) += 1;
+    }
+    Ok(histogram)
+}
+
+/// Returns the mutual information of the bits of two qubits in bits.
+///
+/// The mutual information `I(i; j) = sum p(x, y) log2(p(x, y) / (p(x) p(y)))` is calculated
+/// from the empirical distribution of the records. Outcomes that were not measured do not
+/// contribute.
+///
+/// # Arguments
+///
+/// * `register` - The measurement records.
+/// * `i` - The first qubit.
+/// * `j` - The second qubit.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The mutual information, between 0 and 1.
+/// * `Err(RoqoqoError::GenericError)` - The register is empty or a record is too short for the qubits.
+pub fn mutual_information(
+    register: &BitOutputRegister,
+    i: usize,
+    j: usize,
+) -> Result<f64, RoqoqoError> {
+    let number_records = check_register(register, &[i, j])? as f64;
+    let mut joint = [[0_usize; 2]; 2];
+    for record in register.iter() {
+        joint[record[i] as usize][record[j] as usize] += 1;
+    }
+    let marginal_i = [joint[0][0] + joint[0][1], joint[1][0] + joint[1][1]];
+    let marginal_j = [joint[0][0] + joint[1][0], joint[0][1] + joint[1][1]];
+    let mut information = 0.0;
+    for x in 0..2 {
+        for y in 0..2 {
+            if joint[x][y] == 0 {
+                continue;
+            }
+            let p_xy = joint[x][y] as f64 / number_records;
+            let p_x = marginal_i[x] as f64 / number_records;
+            let p_y = marginal_j[y] as f64 / number_records;
+            information += p_xy * (p_xy / (p_x * p_y)).log2();
+        }
+    }
+    Ok(information)
+}
+
+// Checks that the register is not empty and all records contain the qubits.
+// Returns the number of records.
+fn check_register(register: &BitOutputRegister, qubits: &[usize]) -> Result<usize, RoqoqoError> {
+    if register.is_empty() {
+        return Err(RoqoqoError::GenericError {
+            msg: "Bit register contains no measurement records".to_string(),
+        });
+    }
+    if let Some(max_qubit) = qubits.iter().max() {
+        if let Some(record) = register.iter().find(|record| record.len() <= *max_qubit) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubit {} exceeds the length {} of a measurement record",
+                    max_qubit,
+                    record.len()
+                ),
+            });
+        }
+    }
+    Ok(register.len())
+}
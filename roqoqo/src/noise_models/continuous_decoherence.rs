@@ -11,6 +11,10 @@
 // limitations under the License.
 
 use super::SupportedVersion;
+use crate::operations::PragmaGeneralNoise;
+use crate::{Circuit, RoqoqoError};
+use qoqo_calculator::CalculatorFloat;
+use std::collections::BTreeSet;
 use struqture::{
     spins::PlusMinusLindbladNoiseOperator, spins::PlusMinusProduct, OperateOnDensityMatrix,
 };
@@ -184,6 +188,42 @@ impl ContinuousDecoherenceModel {
     }
 }
 
+/// Creates a Circuit applying the noise of a ContinuousDecoherenceModel to idling qubits.
+///
+/// The noise of each qubit is applied with one [PragmaGeneralNoise] created with
+/// [PragmaGeneralNoise::from_lindblad_operator], containing the rates of the model in the basis σ+, σ-, σz,
+/// e.g. a damping rate added with [ContinuousDecoherenceModel::add_damping_rate] is the entry M[0, 0].
+/// Qubits without noise in the model are skipped.
+///
+/// # Arguments
+///
+/// * `qubits` - The idling qubits.
+/// * `duration` - The time (in seconds) the qubits are idling.
+/// * `model` - The noise model containing the decoherence rates.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The Circuit containing one PragmaGeneralNoise per noisy qubit.
+/// * `Err(RoqoqoError::GenericError)` - The model contains noise acting on several qubits or a rate that is not a real number.
+pub fn idle_noise_circuit(
+    qubits: &[usize],
+    duration: CalculatorFloat,
+    model: &ContinuousDecoherenceModel,
+) -> Result<Circuit, RoqoqoError> {
+    let mut circuit = Circuit::new();
+    for qubit in qubits.iter().collect::<BTreeSet<_>>() {
+        let pragma = PragmaGeneralNoise::from_lindblad_operator(
+            *qubit,
+            duration.clone(),
+            &model.lindblad_noise,
+        )?;
+        if pragma.rates().iter().any(|rate| *rate != 0.0) {
+            circuit += pragma;
+        }
+    }
+    Ok(circuit)
+}
+
 impl From<PlusMinusLindbladNoiseOperator> for ContinuousDecoherenceModel {
     fn from(value: PlusMinusLindbladNoiseOperator) -> Self {
         Self {
@@ -209,6 +249,70 @@ mod tests {
         assert_eq!(model.lindblad_noise, PlusMinusLindbladNoiseOperator::new());
     }
 
+    #[test]
+    fn test_idle_noise_circuit() {
+        let model = ContinuousDecoherenceModel::new()
+            .add_damping_rate(&[0, 1], 0.1)
+            .add_dephasing_rate(&[1], 0.2)
+            .add_depolarising_rate(&[3], 0.4);
+        let circuit = idle_noise_circuit(&[0, 1, 2], 2.0.into(), &model).unwrap();
+        let mut expected = Circuit::new();
+        expected += PragmaGeneralNoise::new(
+            0,
+            2.0.into(),
+            ndarray::array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+        );
+        expected += PragmaGeneralNoise::new(
+            1,
+            2.0.into(),
+            ndarray::array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.2]],
+        );
+        assert_eq!(circuit, expected);
+
+        let circuit = idle_noise_circuit(&[3], 2.0.into(), &model).unwrap();
+        let mut expected = Circuit::new();
+        expected += PragmaGeneralNoise::new(
+            3,
+            2.0.into(),
+            ndarray::array![[0.2, 0.0, 0.0], [0.0, 0.2, 0.0], [0.0, 0.0, 0.1]],
+        );
+        assert_eq!(circuit, expected);
+        assert!(idle_noise_circuit(&[2], 2.0.into(), &model)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_idle_noise_circuit_error() {
+        let mut lindblad_noise = PlusMinusLindbladNoiseOperator::new();
+        lindblad_noise
+            .add_operator_product(
+                (
+                    PlusMinusProduct::new().plus(0).z(1),
+                    PlusMinusProduct::new().plus(0).z(1),
+                ),
+                0.1.into(),
+            )
+            .unwrap();
+        let model = ContinuousDecoherenceModel::from(lindblad_noise);
+        let result = idle_noise_circuit(&[0, 1], 1.0.into(), &model);
+        assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+
+        let mut lindblad_noise = PlusMinusLindbladNoiseOperator::new();
+        lindblad_noise
+            .add_operator_product(
+                (
+                    PlusMinusProduct::new().plus(0),
+                    PlusMinusProduct::new().plus(0),
+                ),
+                "rate".into(),
+            )
+            .unwrap();
+        let model = ContinuousDecoherenceModel::from(lindblad_noise);
+        let result = idle_noise_circuit(&[0], 1.0.into(), &model);
+        assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+    }
+
     #[test]
     fn test_continuous_decoherence_model_add_damping() {
         let model = ContinuousDecoherenceModel::new();
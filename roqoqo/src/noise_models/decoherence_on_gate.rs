@@ -258,6 +258,51 @@ impl DecoherenceOnGateModel {
             .get(&(gate.to_string(), (control, target)))
     }
 
+    /// Sets the same extra noise for a two qubit gate in both orientations.
+    ///
+    /// The noise is set for `qubit_a` as control and `qubit_b` as target and vice versa.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `qubit_a` - The first qubit the gate acts on.
+    /// * `qubit_b` - The second qubit the gate acts on.
+    /// * `noise_operator` - The noise affecting system when gate is applied.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The error model with the new noise on gate set.
+    pub fn set_two_qubit_gate_error_symmetric(
+        mut self,
+        gate: &str,
+        qubit_a: usize,
+        qubit_b: usize,
+        noise_operator: struqture::spins::PlusMinusLindbladNoiseOperator,
+    ) -> Self {
+        self.two_qubit_gate_errors.insert(
+            (gate.to_string(), (qubit_a, qubit_b)),
+            noise_operator.clone(),
+        );
+        self.two_qubit_gate_errors
+            .insert((gate.to_string(), (qubit_b, qubit_a)), noise_operator);
+        self
+    }
+
+    /// Returns all two qubit gates with extra noise.
+    ///
+    /// # Returns
+    ///
+    /// `Vec<(String, usize, usize)>` - The name, control and target qubit of the gates, sorted.
+    pub fn get_all_two_qubit_gate_errors(&self) -> Vec<(String, usize, usize)> {
+        let mut gates: Vec<(String, usize, usize)> = self
+            .two_qubit_gate_errors
+            .keys()
+            .map(|(gate, (control, target))| (gate.clone(), *control, *target))
+            .collect();
+        gates.sort();
+        gates
+    }
+
     /// Sets extra noise for a three qubit gate.
     ///
     /// # Arguments
@@ -349,6 +394,87 @@ impl DecoherenceOnGateModel {
         self.multi_qubit_gate_errors
             .get(&(gate.to_string(), qubits))
     }
+
+    /// Removes the extra noise for a single qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `qubit` - The qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The error model without noise on the gate.
+    pub fn remove_single_qubit_gate_error(mut self, gate: &str, qubit: usize) -> Self {
+        self.single_qubit_gate_errors
+            .remove(&(gate.to_string(), qubit));
+        self
+    }
+
+    /// Removes the extra noise for a two qubit gate.
+    ///
+    /// Only the given orientation is removed, noise set with [Self::set_two_qubit_gate_error_symmetric]
+    /// has to be removed for both orientations.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `control` - Controlling qubit.
+    /// * `target` - Target qubit.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The error model without noise on the gate.
+    pub fn remove_two_qubit_gate_error(
+        mut self,
+        gate: &str,
+        control: usize,
+        target: usize,
+    ) -> Self {
+        self.two_qubit_gate_errors
+            .remove(&(gate.to_string(), (control, target)));
+        self
+    }
+
+    /// Removes the extra noise for a three qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `control0` - First controlling qubit.
+    /// * `control1` - Second controlling qubit.
+    /// * `target` - Target qubit.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The error model without noise on the gate.
+    pub fn remove_three_qubit_gate_error(
+        mut self,
+        gate: &str,
+        control0: usize,
+        control1: usize,
+        target: usize,
+    ) -> Self {
+        self.three_qubit_gate_errors
+            .remove(&(gate.to_string(), (control0, control1, target)));
+        self
+    }
+
+    /// Removes the extra noise for a multi qubit gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `qubits` - A vector of qubit indices.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The error model without noise on the gate.
+    pub fn remove_multi_qubit_gate_error(mut self, gate: &str, qubits: Vec<usize>) -> Self {
+        self.multi_qubit_gate_errors
+            .remove(&(gate.to_string(), qubits));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -356,7 +482,8 @@ mod tests {
     use super::*;
     #[cfg(feature = "json_schema")]
     use jsonschema::Validator;
-    use struqture::spins::PlusMinusLindbladNoiseOperator;
+    use struqture::prelude::*;
+    use struqture::spins::{PlusMinusLindbladNoiseOperator, PlusMinusProduct};
 
     #[test]
     fn test_decoherence_on_gate_model_single() {
@@ -417,6 +544,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decoherence_on_gate_model_two_symmetric() {
+        let mut lindblad_noise = PlusMinusLindbladNoiseOperator::new();
+        lindblad_noise
+            .add_operator_product(
+                (PlusMinusProduct::new().z(0), PlusMinusProduct::new().z(0)),
+                0.9.into(),
+            )
+            .unwrap();
+        let noise_model = DecoherenceOnGateModel::new()
+            .set_two_qubit_gate_error_symmetric("CNOT", 0, 1, lindblad_noise.clone())
+            .set_two_qubit_gate_error("CNOT", 1, 2, PlusMinusLindbladNoiseOperator::new());
+        assert_eq!(
+            noise_model.get_two_qubit_gate_error("CNOT", 0, 1),
+            Some(&lindblad_noise)
+        );
+        assert_eq!(
+            noise_model.get_two_qubit_gate_error("CNOT", 1, 0),
+            Some(&lindblad_noise)
+        );
+        assert_eq!(noise_model.get_two_qubit_gate_error("CNOT", 2, 1), None);
+        assert_eq!(
+            noise_model.get_all_two_qubit_gate_errors(),
+            vec![
+                ("CNOT".to_string(), 0, 1),
+                ("CNOT".to_string(), 1, 0),
+                ("CNOT".to_string(), 1, 2)
+            ]
+        );
+
+        let noise_model = noise_model.remove_two_qubit_gate_error("CNOT", 1, 0);
+        assert_eq!(noise_model.get_two_qubit_gate_error("CNOT", 1, 0), None);
+        assert_eq!(
+            noise_model.get_two_qubit_gate_error("CNOT", 0, 1),
+            Some(&lindblad_noise)
+        );
+    }
+
+    #[test]
+    fn test_decoherence_on_gate_model_remove() {
+        let noise_model = DecoherenceOnGateModel::new()
+            .set_single_qubit_gate_error("RotateX", 0, PlusMinusLindbladNoiseOperator::new())
+            .set_three_qubit_gate_error(
+                "ControlledControlledPauliZ",
+                0,
+                1,
+                2,
+                PlusMinusLindbladNoiseOperator::new(),
+            )
+            .set_multi_qubit_gate_error(
+                "MultiQubitMS",
+                vec![0, 1, 2, 3],
+                PlusMinusLindbladNoiseOperator::new(),
+            );
+        let removed = noise_model
+            .clone()
+            .remove_single_qubit_gate_error("RotateX", 0)
+            .remove_three_qubit_gate_error("ControlledControlledPauliZ", 0, 1, 2)
+            .remove_multi_qubit_gate_error("MultiQubitMS", vec![0, 1, 2, 3]);
+        assert_eq!(removed, DecoherenceOnGateModel::new());
+        // Removing gates without noise does not change the model
+        let unchanged = noise_model
+            .clone()
+            .remove_single_qubit_gate_error("RotateX", 1)
+            .remove_three_qubit_gate_error("ControlledControlledPauliZ", 1, 0, 2)
+            .remove_multi_qubit_gate_error("MultiQubitMS", vec![0, 1, 2]);
+        assert_eq!(unchanged, noise_model);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn test_json_serialization_symmetric() {
+        let noise_model = DecoherenceOnGateModel::new()
+            .set_two_qubit_gate_error_symmetric("CNOT", 0, 1, PlusMinusLindbladNoiseOperator::new())
+            .set_two_qubit_gate_error_symmetric("CZ", 2, 1, PlusMinusLindbladNoiseOperator::new())
+            .remove_two_qubit_gate_error("CZ", 1, 2);
+        let json_str = serde_json::to_string(&noise_model).unwrap();
+        let deserialized_noise_model: DecoherenceOnGateModel =
+            serde_json::from_str(&json_str).unwrap();
+        assert_eq!(noise_model, deserialized_noise_model);
+        assert_eq!(
+            deserialized_noise_model.get_all_two_qubit_gate_errors(),
+            vec![
+                ("CNOT".to_string(), 0, 1),
+                ("CNOT".to_string(), 1, 0),
+                ("CZ".to_string(), 2, 1)
+            ]
+        );
+        let bincode_serialized = bincode::serialize(&noise_model).unwrap();
+        let deserialized_noise_model: DecoherenceOnGateModel =
+            bincode::deserialize(&bincode_serialized).unwrap();
+        assert_eq!(noise_model, deserialized_noise_model);
+    }
+
     #[cfg(feature = "serialize")]
     #[test]
     fn test_json_serialization() {
@@ -11,7 +11,10 @@
 // limitations under the License.
 
 use super::SupportedVersion;
+use crate::registers::BitOutputRegister;
 use crate::{RoqoqoBackendError, RoqoqoError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
 /// Noise model representing readout errors.
 ///
@@ -135,6 +138,93 @@ impl ImperfectReadoutModel {
     pub fn prob_detect_1_as_0(&self, qubit: &usize) -> f64 {
         *self.prob_detect_1_as_0.get(qubit).unwrap_or(&0.0)
     }
+
+    /// Return both readout error probabilities for a qubit
+    ///
+    /// # Arguments
+    ///
+    /// `qubit` - The qubit for which the probabilities are returned.
+    ///
+    /// # Returns
+    ///
+    /// `(f64, f64)` - The probabilities to detect 0 as 1 and to detect 1 as 0 for the qubit
+    pub fn flip_probabilities(&self, qubit: &usize) -> (f64, f64) {
+        (
+            self.prob_detect_0_as_1(qubit),
+            self.prob_detect_1_as_0(qubit),
+        )
+    }
+
+    /// Apply the readout errors to an ideal bit register
+    ///
+    /// Every bit is flipped with the probability to detect 0 as 1 when it is `false`
+    /// and with the probability to detect 1 as 0 when it is `true`.
+    /// The bit in column `i` of every measurement is the readout of qubit `qubit_order[i]`.
+    /// Bits in columns without an entry in `qubit_order` are not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - The ideal bit register, one row per measurement.
+    /// * `qubit_order` - The qubit read out into each column of the register.
+    /// * `rng` - The random number generator used to sample the readout errors.
+    ///
+    /// # Returns
+    ///
+    /// `BitOutputRegister` - The bit register with readout errors
+    pub fn apply_to_register(
+        &self,
+        register: &BitOutputRegister,
+        qubit_order: &[usize],
+        rng: &mut impl Rng,
+    ) -> BitOutputRegister {
+        let probabilities: Vec<(f64, f64)> = qubit_order
+            .iter()
+            .map(|qubit| self.flip_probabilities(qubit))
+            .collect();
+        register
+            .iter()
+            .map(|measurement| {
+                measurement
+                    .iter()
+                    .enumerate()
+                    .map(|(column, bit)| match probabilities.get(column) {
+                        Some((prob_detect_0_as_1, prob_detect_1_as_0)) => {
+                            let flip_probability = if *bit {
+                                *prob_detect_1_as_0
+                            } else {
+                                *prob_detect_0_as_1
+                            };
+                            *bit ^ rng.gen_bool(flip_probability)
+                        }
+                        None => *bit,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Apply the readout errors to an ideal bit register with a seeded random number generator
+    ///
+    /// See [ImperfectReadoutModel::apply_to_register]. The same seed always produces the same register.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - The ideal bit register, one row per measurement.
+    /// * `qubit_order` - The qubit read out into each column of the register.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// `BitOutputRegister` - The bit register with readout errors
+    pub fn apply_to_register_with_seed(
+        &self,
+        register: &BitOutputRegister,
+        qubit_order: &[usize],
+        seed: u64,
+    ) -> BitOutputRegister {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.apply_to_register(register, qubit_order, &mut rng)
+    }
 }
 
 impl SupportedVersion for ImperfectReadoutModel {
@@ -227,6 +317,57 @@ mod tests {
         assert_eq!(model.prob_detect_1_as_0(&4), 0.0);
     }
 
+    #[test]
+    fn test_imperfect_readout_model_flip_probabilities() {
+        let model = ImperfectReadoutModel::new_with_uniform_error(2, 0.2, 0.8)
+            .unwrap()
+            .set_error_probabilites(1, 0.1, 0.3)
+            .unwrap();
+        assert_eq!(model.flip_probabilities(&0), (0.2, 0.8));
+        assert_eq!(model.flip_probabilities(&1), (0.1, 0.3));
+        assert_eq!(model.flip_probabilities(&4), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_imperfect_readout_model_apply_to_register_statistics() {
+        let model = ImperfectReadoutModel::new()
+            .set_error_probabilites(0, 0.1, 0.3)
+            .unwrap()
+            .set_error_probabilites(2, 0.25, 0.05)
+            .unwrap();
+        let number_shots = 20000;
+        let register: BitOutputRegister = vec![vec![false, true, true, false]; number_shots];
+        // Column 0 reads out qubit 2, column 1 qubit 0, column 2 qubit 1 without errors,
+        // column 3 has no qubit
+        let noisy = model.apply_to_register_with_seed(&register, &[2, 0, 1], 42);
+        assert_eq!(noisy.len(), number_shots);
+
+        let flip_rate = |column: usize| -> f64 {
+            noisy
+                .iter()
+                .zip(register.iter())
+                .filter(|(noisy_row, row)| noisy_row[column] != row[column])
+                .count() as f64
+                / number_shots as f64
+        };
+        // Standard deviation of the rates is below 0.0035
+        assert!((flip_rate(0) - 0.25).abs() < 0.015);
+        assert!((flip_rate(1) - 0.3).abs() < 0.015);
+        assert_eq!(flip_rate(2), 0.0);
+        assert_eq!(flip_rate(3), 0.0);
+
+        // The same seed reproduces the register
+        assert_eq!(
+            model.apply_to_register_with_seed(&register, &[2, 0, 1], 42),
+            noisy
+        );
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(
+            model.apply_to_register(&register, &[2, 0, 1], &mut rng),
+            noisy
+        );
+    }
+
     #[cfg(feature = "json_schema")]
     #[test]
     fn test_json_schema_feature() {
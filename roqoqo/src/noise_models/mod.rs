@@ -16,7 +16,7 @@
 //!
 
 mod continuous_decoherence;
-pub use continuous_decoherence::ContinuousDecoherenceModel;
+pub use continuous_decoherence::{idle_noise_circuit, ContinuousDecoherenceModel};
 mod imperfect_readout;
 pub use imperfect_readout::ImperfectReadoutModel;
 mod decoherence_on_gate;
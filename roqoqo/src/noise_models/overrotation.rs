@@ -313,6 +313,152 @@ impl SingleQubitOverrotationOnGate {
         self.two_qubit_overrotation
             .get(&(gate.to_string(), (control, target)))
     }
+
+    /// Sets the same overrotation for a single qubit gate on all qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `number_qubits` - The number of qubits, the overrotation is set for qubits 0 to `number_qubits - 1`.
+    /// * `noise_description` - overrotation description for gate.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The overrotation model with the new overrotation on gate set.
+    pub fn set_single_qubit_overrotation_for_all_qubits(
+        mut self,
+        gate: &str,
+        number_qubits: usize,
+        noise_description: SingleQubitOverrotationDescription,
+    ) -> Self {
+        for qubit in 0..number_qubits {
+            self.single_qubit_overrotation
+                .insert((gate.to_string(), qubit), noise_description.clone());
+        }
+        self
+    }
+
+    /// Sets the same overrotation for a two qubit gate on all pairs of different qubits.
+    ///
+    /// The overrotation is set for both orientations of each pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The name of the gate.
+    /// * `number_qubits` - The number of qubits, the overrotation is set for qubits 0 to `number_qubits - 1`.
+    /// * `noise_description` - overrotation description for gate.
+    ///
+    /// # Returns
+    ///
+    /// `Self` - The overrotation model with the new overrotation on gate set.
+    pub fn set_two_qubit_overrotation_for_all_qubits(
+        mut self,
+        gate: &str,
+        number_qubits: usize,
+        noise_description: (
+            SingleQubitOverrotationDescription,
+            SingleQubitOverrotationDescription,
+        ),
+    ) -> Self {
+        for control in 0..number_qubits {
+            for target in (0..number_qubits).filter(|target| *target != control) {
+                self.two_qubit_overrotation.insert(
+                    (gate.to_string(), (control, target)),
+                    noise_description.clone(),
+                );
+            }
+        }
+        self
+    }
+
+    /// Returns all single qubit gates with overrotation.
+    ///
+    /// # Returns
+    ///
+    /// `Vec<(String, usize, SingleQubitOverrotationDescription)>` - The name, qubit and overrotation of the gates, sorted by name and qubit.
+    pub fn get_all_single_qubit_overrotations(
+        &self,
+    ) -> Vec<(String, usize, SingleQubitOverrotationDescription)> {
+        let mut overrotations: Vec<(String, usize, SingleQubitOverrotationDescription)> = self
+            .single_qubit_overrotation
+            .iter()
+            .map(|((gate, qubit), description)| (gate.clone(), *qubit, description.clone()))
+            .collect();
+        overrotations.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+        overrotations
+    }
+
+    /// Returns all two qubit gates with overrotation.
+    ///
+    /// # Returns
+    ///
+    /// `Vec<(String, usize, usize, (SingleQubitOverrotationDescription, SingleQubitOverrotationDescription))>` - The name, control, target and overrotation of the gates, sorted by name and qubits.
+    #[allow(clippy::type_complexity)]
+    pub fn get_all_two_qubit_overrotations(
+        &self,
+    ) -> Vec<(
+        String,
+        usize,
+        usize,
+        (
+            SingleQubitOverrotationDescription,
+            SingleQubitOverrotationDescription,
+        ),
+    )> {
+        let mut overrotations: Vec<_> = self
+            .two_qubit_overrotation
+            .iter()
+            .map(|((gate, (control, target)), description)| {
+                (gate.clone(), *control, *target, description.clone())
+            })
+            .collect();
+        overrotations.sort_by(|a, b| (&a.0, a.1, a.2).cmp(&(&b.0, b.1, b.2)));
+        overrotations
+    }
+
+    /// Exports the overrotations as a table in json form.
+    ///
+    /// The table is a list of rows with the columns `gate`, `qubits`, `qubit`, `rotation_gate`,
+    /// `theta_mean` and `theta_std`. Each row describes the overrotation on `qubit` after
+    /// the gate `gate` acting on `qubits`. Two qubit gates have one row for the control qubit
+    /// (first description) and one row for the target qubit (second description).
+    /// Single qubit gates are listed before two qubit gates, both sorted by name and qubits.
+    ///
+    /// # Returns
+    ///
+    /// `String` - The overrotation table in json form.
+    #[cfg(feature = "serialize")]
+    pub fn to_table_json(&self) -> String {
+        let row = |gate: &str,
+                   qubits: &[usize],
+                   qubit: usize,
+                   description: &SingleQubitOverrotationDescription| {
+            serde_json::json!({
+                "gate": gate,
+                "qubits": qubits,
+                "qubit": qubit,
+                "rotation_gate": description.gate(),
+                "theta_mean": description.theta_mean(),
+                "theta_std": description.theta_std(),
+            })
+        };
+        let mut rows: Vec<serde_json::Value> = Vec::new();
+        for (gate, qubit, description) in self.get_all_single_qubit_overrotations() {
+            rows.push(row(&gate, &[qubit], qubit, &description));
+        }
+        for (gate, control, target, (control_description, target_description)) in
+            self.get_all_two_qubit_overrotations()
+        {
+            rows.push(row(
+                &gate,
+                &[control, target],
+                control,
+                &control_description,
+            ));
+            rows.push(row(&gate, &[control, target], target, &target_description));
+        }
+        serde_json::Value::Array(rows).to_string()
+    }
 }
 
 #[cfg(test)]
@@ -348,6 +494,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_overrotation_for_all_qubits() {
+        let noise_descp = SingleQubitOverrotationDescription::new("RotateZ", 0.1, 0.5);
+        let noise_descp_2 = SingleQubitOverrotationDescription::new("RotateX", 0.0, 0.2);
+        let noise_model = SingleQubitOverrotationOnGate::new()
+            .set_single_qubit_overrotation_for_all_qubits("RotateX", 3, noise_descp.clone())
+            .set_two_qubit_overrotation_for_all_qubits(
+                "CNOT",
+                2,
+                (noise_descp.clone(), noise_descp_2.clone()),
+            );
+        assert_eq!(
+            noise_model.get_all_single_qubit_overrotations(),
+            vec![
+                ("RotateX".to_string(), 0, noise_descp.clone()),
+                ("RotateX".to_string(), 1, noise_descp.clone()),
+                ("RotateX".to_string(), 2, noise_descp.clone()),
+            ]
+        );
+        assert_eq!(
+            noise_model.get_single_qubit_overrotation("RotateX", 3),
+            None
+        );
+        assert_eq!(
+            noise_model.get_all_two_qubit_overrotations(),
+            vec![
+                (
+                    "CNOT".to_string(),
+                    0,
+                    1,
+                    (noise_descp.clone(), noise_descp_2.clone())
+                ),
+                (
+                    "CNOT".to_string(),
+                    1,
+                    0,
+                    (noise_descp.clone(), noise_descp_2.clone())
+                ),
+            ]
+        );
+        assert_eq!(noise_model.get_two_qubit_overrotation("CNOT", 0, 0), None);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn test_to_table_json() {
+        let noise_descp = SingleQubitOverrotationDescription::new("RotateZ", 0.1, 0.5);
+        let noise_descp_2 = SingleQubitOverrotationDescription::new("RotateX", 0.0, 0.2);
+        let noise_model = SingleQubitOverrotationOnGate::new()
+            .set_single_qubit_overrotation("RotateX", 1, noise_descp.clone())
+            .set_two_qubit_overrotation("CNOT", 0, 1, (noise_descp, noise_descp_2));
+        let table: serde_json::Value = serde_json::from_str(&noise_model.to_table_json()).unwrap();
+        assert_eq!(
+            table,
+            serde_json::json!([
+                {"gate": "RotateX", "qubits": [1], "qubit": 1, "rotation_gate": "RotateZ", "theta_mean": 0.1, "theta_std": 0.5},
+                {"gate": "CNOT", "qubits": [0, 1], "qubit": 0, "rotation_gate": "RotateZ", "theta_mean": 0.1, "theta_std": 0.5},
+                {"gate": "CNOT", "qubits": [0, 1], "qubit": 1, "rotation_gate": "RotateX", "theta_mean": 0.0, "theta_std": 0.2},
+            ])
+        );
+        assert_eq!(SingleQubitOverrotationOnGate::new().to_table_json(), "[]");
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn test_json_serialization_for_all_qubits() {
+        let noise_descp = SingleQubitOverrotationDescription::new("RotateZ", 1.0, 1.0);
+        let noise_model = SingleQubitOverrotationOnGate::new()
+            .set_single_qubit_overrotation_for_all_qubits("RotateX", 4, noise_descp.clone())
+            .set_two_qubit_overrotation_for_all_qubits(
+                "CNOT",
+                3,
+                (noise_descp.clone(), noise_descp),
+            );
+        let json_str = serde_json::to_string(&noise_model).unwrap();
+        let deserialized_noise_model: SingleQubitOverrotationOnGate =
+            serde_json::from_str(&json_str).unwrap();
+        assert_eq!(noise_model, deserialized_noise_model);
+        assert_eq!(
+            deserialized_noise_model
+                .get_all_two_qubit_overrotations()
+                .len(),
+            6
+        );
+    }
+
     #[cfg(feature = "serialize")]
     #[test]
     fn test_json_serialization() {
@@ -107,6 +107,10 @@ pub struct ApplyTimeDependentSpinHamiltonian {
     /// Range of time stored as a vector. The total duration of the simulations is given by the last value in the range.
     time: Vec<f64>,
     /// Values of time-dependent parameters, appearing in `hamiltonian`, at instances given by the vector `time`.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
     values: HashMap<String, Vec<f64>>,
 }
 
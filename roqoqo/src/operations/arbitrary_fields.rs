@@ -0,0 +1,292 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generation of the fields of arbitrary Operations.
+//!
+//! The [arbitrary::Arbitrary] implementations of the Operations are generated by the Operate derive macro
+//! and fill every field using [ArbitraryField].
+//! Generated values are kept small: qubit and mode indices are drawn from `0..8`,
+//! angles are multiples of 1/16 in [-4, 4] and inner circuits contain at most two gates.
+
+#[cfg(feature = "unstable_simulation_repetitions")]
+use super::PragmaSimulationRepetitions;
+use super::{
+    GateOperation, MultiQubitMSWithCouplings, NegativeControl, Operation, PragmaAnnotatedOp,
+    PragmaChangeDevice, PragmaParallelBlockTiming, TwoQubitGateOperation, AVAILABLE_GATES_HQSLANG,
+};
+use crate::Circuit;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use ndarray::{Array1, Array2};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use std::collections::HashMap;
+use std::hash::Hash;
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
+
+/// Largest qubit or mode index used in arbitrary Operations.
+const MAX_INDEX: usize = 7;
+/// Largest number of elements in arbitrary collections.
+const MAX_LENGTH: usize = 3;
+/// Names used for registers and symbolic parameters.
+const NAMES: &[&str] = &["ro", "ri", "theta", "phi"];
+
+/// Generates a value of a field of an arbitrary Operation.
+pub(crate) trait ArbitraryField<'a>: Sized {
+    /// Generates the value from the unstructured input.
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self>;
+}
+
+/// Returns `number` distinct indices drawn from `0..=MAX_INDEX`.
+///
+/// Used for the qubits and modes of an Operation that must not coincide.
+pub(crate) fn distinct_indices(u: &mut Unstructured, number: usize) -> Result<Vec<usize>> {
+    let mut pool: Vec<usize> = (0..=MAX_INDEX).collect();
+    for i in 0..number {
+        let j = u.int_in_range(i..=MAX_INDEX)?;
+        pool.swap(i, j);
+    }
+    pool.truncate(number);
+    Ok(pool)
+}
+
+/// Returns a list of one to four distinct qubits.
+pub(crate) fn arbitrary_qubits(u: &mut Unstructured) -> Result<Vec<usize>> {
+    let number = u.int_in_range(1..=4)?;
+    distinct_indices(u, number)
+}
+
+/// Returns an optional mapping of qubits to readout indices without two qubits sharing an index.
+pub(crate) fn arbitrary_qubit_mapping(
+    u: &mut Unstructured,
+) -> Result<Option<HashMap<usize, usize>>> {
+    if !bool::arbitrary(u)? {
+        return Ok(None);
+    }
+    let number = arbitrary_length(u)?;
+    let qubits = distinct_indices(u, number)?;
+    let readout_indices = distinct_indices(u, number)?;
+    Ok(Some(qubits.into_iter().zip(readout_indices).collect()))
+}
+
+// Returns the number of elements of an arbitrary collection.
+fn arbitrary_length(u: &mut Unstructured) -> Result<usize> {
+    u.int_in_range(0..=MAX_LENGTH)
+}
+
+impl<'a> ArbitraryField<'a> for usize {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.int_in_range(0..=MAX_INDEX)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for u64 {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        u64::arbitrary(u)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for u8 {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        u8::arbitrary(u)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for bool {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        bool::arbitrary(u)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for f64 {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Multiples of 1/16 are exactly representable and survive text serialization unchanged
+        Ok(u.int_in_range(-64_i32..=64)? as f64 / 16.0)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Complex64 {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Complex64::new(
+            f64::arbitrary_field(u)?,
+            f64::arbitrary_field(u)?,
+        ))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for String {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(u.choose(NAMES)?.to_string())
+    }
+}
+
+impl<'a> ArbitraryField<'a> for CalculatorFloat {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1, 4)? {
+            Ok(CalculatorFloat::from(String::arbitrary_field(u)?))
+        } else {
+            Ok(CalculatorFloat::from(f64::arbitrary_field(u)?))
+        }
+    }
+}
+
+impl<'a, T: ArbitraryField<'a>> ArbitraryField<'a> for Option<T> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        if bool::arbitrary(u)? {
+            Ok(Some(T::arbitrary_field(u)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a, T: ArbitraryField<'a>> ArbitraryField<'a> for Vec<T> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        (0..arbitrary_length(u)?)
+            .map(|_| T::arbitrary_field(u))
+            .collect()
+    }
+}
+
+impl<'a, K: ArbitraryField<'a> + Eq + Hash, V: ArbitraryField<'a>> ArbitraryField<'a>
+    for HashMap<K, V>
+{
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        (0..arbitrary_length(u)?)
+            .map(|_| Ok((K::arbitrary_field(u)?, V::arbitrary_field(u)?)))
+            .collect()
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Array1<Complex64> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dimension = 1 << u.int_in_range(0..=2)?;
+        (0..dimension)
+            .map(|_| Complex64::arbitrary_field(u))
+            .collect::<Result<Vec<Complex64>>>()
+            .map(Array1::from)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Array2<Complex64> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dimension = 1 << u.int_in_range(0..=2)?;
+        let mut matrix = Array2::zeros((dimension, dimension));
+        for value in matrix.iter_mut() {
+            *value = Complex64::arbitrary_field(u)?;
+        }
+        Ok(matrix)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Array2<f64> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Rate matrices of noise PRAGMAs are 3x3
+        let mut matrix = Array2::zeros((3, 3));
+        for value in matrix.iter_mut() {
+            *value = f64::arbitrary_field(u)?;
+        }
+        Ok(matrix)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Circuit {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Inner circuits only contain gates to keep the nesting depth bounded
+        let mut circuit = Circuit::new();
+        for _ in 0..u.int_in_range(0..=2)? {
+            circuit.add_operation(GateOperation::arbitrary(u)?);
+        }
+        Ok(circuit)
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Box<Operation> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Box::new(GateOperation::arbitrary(u)?.into()))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for SpinHamiltonian {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut hamiltonian = SpinHamiltonian::new();
+        for _ in 0..arbitrary_length(u)? {
+            let qubit = usize::arbitrary_field(u)?;
+            let product = match u.int_in_range(0..=2)? {
+                0 => PauliProduct::new().x(qubit),
+                1 => PauliProduct::new().y(qubit),
+                _ => PauliProduct::new().z(qubit),
+            };
+            hamiltonian
+                .add_operator_product(product, CalculatorFloat::arbitrary_field(u)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+        Ok(hamiltonian)
+    }
+}
+
+// The following Operations implement Operate by hand and therefore do not get a generated implementation.
+
+impl<'a> Arbitrary<'a> for PragmaChangeDevice {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PragmaChangeDevice {
+            wrapped_tags: Vec::<String>::arbitrary_field(u)?,
+            wrapped_hqslang: u.choose(&AVAILABLE_GATES_HQSLANG)?.to_string(),
+            wrapped_operation: Vec::<u8>::arbitrary_field(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for PragmaAnnotatedOp {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PragmaAnnotatedOp {
+            operation: Box::<Operation>::arbitrary_field(u)?,
+            annotation: String::arbitrary_field(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for NegativeControl {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(NegativeControl::new(TwoQubitGateOperation::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MultiQubitMSWithCouplings {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let qubits = arbitrary_qubits(u)?;
+        let number_pairs = qubits.len() * (qubits.len() - 1) / 2;
+        let mut couplings = Vec::with_capacity(number_pairs);
+        for _ in 0..number_pairs {
+            couplings.push(CalculatorFloat::arbitrary_field(u)?);
+        }
+        Ok(MultiQubitMSWithCouplings { qubits, couplings })
+    }
+}
+
+impl<'a> Arbitrary<'a> for PragmaParallelBlockTiming {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PragmaParallelBlockTiming::new(HashMap::<
+            usize,
+            CalculatorFloat,
+        >::arbitrary_field(
+            u
+        )?))
+    }
+}
+
+#[cfg(feature = "unstable_simulation_repetitions")]
+impl<'a> Arbitrary<'a> for PragmaSimulationRepetitions {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PragmaSimulationRepetitions::new(usize::arbitrary_field(u)?))
+    }
+}
@@ -12,6 +12,8 @@
 
 use std::collections::HashSet;
 
+#[cfg(feature = "unstable_fock_matrix")]
+use crate::operations::OperateFockMatrix;
 use crate::operations::{
     ImplementedIn1point6, ImplementedIn1point8, InvolveModes, InvolveQubits, InvolvedClassical,
     InvolvedModes, InvolvedQubits, Operate, OperateModeGate, OperateSingleMode,
@@ -19,6 +21,10 @@ use crate::operations::{
     SupportedVersion,
 };
 use crate::RoqoqoError;
+#[cfg(feature = "unstable_fock_matrix")]
+use ndarray::{linalg::kron, Array2};
+#[cfg(feature = "unstable_fock_matrix")]
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 
 /// The single-mode squeezing gate with tunable squeezing.
@@ -73,6 +79,22 @@ impl SupportedVersion for Squeezing {
     }
 }
 
+/// The generator (z^* a^2 - z a^†2) / 2 with z = squeezing * exp(i * phase).
+#[cfg(feature = "unstable_fock_matrix")]
+impl OperateFockMatrix for Squeezing {
+    fn fock_matrix(&self, cutoff: usize) -> Result<Array2<Complex64>, RoqoqoError> {
+        check_cutoff(cutoff)?;
+        let z = Complex64::from_polar(
+            f64::try_from(self.squeezing.clone())?,
+            f64::try_from(self.phase.clone())?,
+        );
+        let a = annihilation_operator(cutoff);
+        let a_dagger = adjoint(&a);
+        let generator = (a.dot(&a) * z.conj() - a_dagger.dot(&a_dagger) * z) * 0.5;
+        Ok(exponential(&generator))
+    }
+}
+
 /// The single-mode phase-displacement gate with variable magnitude and phase.
 ///
 #[derive(
@@ -121,6 +143,21 @@ impl SupportedVersion for PhaseDisplacement {
     }
 }
 
+/// The generator alpha a^† - alpha^* a with alpha = displacement * exp(i * phase).
+#[cfg(feature = "unstable_fock_matrix")]
+impl OperateFockMatrix for PhaseDisplacement {
+    fn fock_matrix(&self, cutoff: usize) -> Result<Array2<Complex64>, RoqoqoError> {
+        check_cutoff(cutoff)?;
+        let alpha = Complex64::from_polar(
+            f64::try_from(self.displacement.clone())?,
+            f64::try_from(self.phase.clone())?,
+        );
+        let a = annihilation_operator(cutoff);
+        let generator = adjoint(&a) * alpha - a * alpha.conj();
+        Ok(exponential(&generator))
+    }
+}
+
 /// The single-mode phase-shift gate with variable phase, given by R(θ) = exp(i * θ * 𝑁̂).
 ///
 /// https://arxiv.org/pdf/2104.03241.pdf
@@ -169,6 +206,18 @@ impl SupportedVersion for PhaseShift {
     }
 }
 
+/// The diagonal matrix exp(i * phase * n) for the photon numbers n.
+#[cfg(feature = "unstable_fock_matrix")]
+impl OperateFockMatrix for PhaseShift {
+    fn fock_matrix(&self, cutoff: usize) -> Result<Array2<Complex64>, RoqoqoError> {
+        check_cutoff(cutoff)?;
+        let phase = f64::try_from(self.phase.clone())?;
+        Ok(Array2::from_diag(&ndarray::Array1::from_iter(
+            (0..cutoff).map(|n| Complex64::from_polar(1.0, phase * n as f64)),
+        )))
+    }
+}
+
 /// The 2-mode beam splitter which splits a beam with a transmission amplitude cos(θ) and a reflection amplitude exp(i * φ) * sin(θ).
 ///
 #[derive(
@@ -219,6 +268,24 @@ impl SupportedVersion for BeamSplitter {
     }
 }
 
+/// The generator theta * (exp(i * phi) a_0 a_1^† - exp(-i * phi) a_0^† a_1),
+/// mapping a single photon in `mode_0` to cos(theta) |1, 0> + exp(i * phi) * sin(theta) |0, 1>.
+#[cfg(feature = "unstable_fock_matrix")]
+impl OperateFockMatrix for BeamSplitter {
+    fn fock_matrix(&self, cutoff: usize) -> Result<Array2<Complex64>, RoqoqoError> {
+        check_cutoff(cutoff)?;
+        let theta = f64::try_from(self.theta.clone())?;
+        let phase = Complex64::from_polar(1.0, f64::try_from(self.phi.clone())?);
+        let a = annihilation_operator(cutoff);
+        let identity: Array2<Complex64> = Array2::eye(cutoff);
+        let a_0 = kron(&a, &identity);
+        let a_1 = kron(&identity, &a);
+        let generator =
+            (a_0.dot(&adjoint(&a_1)) * phase - adjoint(&a_0).dot(&a_1) * phase.conj()) * theta;
+        Ok(exponential(&generator))
+    }
+}
+
 /// The photon number-resolving detector measurement for bosons.
 ///
 /// This can be used as a single-shot measurement of the photon number.
@@ -268,3 +335,59 @@ impl SupportedVersion for PhotonDetection {
         (1, 6, 0)
     }
 }
+
+/// Checks that the Fock space cutoff contains at least the vacuum state.
+#[cfg(feature = "unstable_fock_matrix")]
+fn check_cutoff(cutoff: usize) -> Result<(), RoqoqoError> {
+    if cutoff == 0 {
+        return Err(RoqoqoError::GenericError {
+            msg: "Fock space cutoff must be at least 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the annihilation operator of a single mode in the truncated Fock space.
+#[cfg(feature = "unstable_fock_matrix")]
+fn annihilation_operator(cutoff: usize) -> Array2<Complex64> {
+    let mut a: Array2<Complex64> = Array2::zeros((cutoff, cutoff));
+    for n in 1..cutoff {
+        a[[n - 1, n]] = Complex64::new((n as f64).sqrt(), 0.0);
+    }
+    a
+}
+
+/// Returns the conjugate transpose of a matrix.
+#[cfg(feature = "unstable_fock_matrix")]
+fn adjoint(matrix: &Array2<Complex64>) -> Array2<Complex64> {
+    matrix.t().mapv(|x| x.conj())
+}
+
+/// Returns the matrix exponential using scaling and squaring of the Taylor series.
+#[cfg(feature = "unstable_fock_matrix")]
+fn exponential(matrix: &Array2<Complex64>) -> Array2<Complex64> {
+    let norm = matrix
+        .rows()
+        .into_iter()
+        .map(|row| row.iter().map(|x| x.norm()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let squarings = if norm > 0.5 {
+        (norm / 0.5).log2().ceil() as i32
+    } else {
+        0
+    };
+    let scaled = matrix / Complex64::new(2.0_f64.powi(squarings), 0.0);
+    let mut result: Array2<Complex64> = Array2::eye(matrix.nrows());
+    let mut term: Array2<Complex64> = Array2::eye(matrix.nrows());
+    for k in 1..30 {
+        term = term.dot(&scaled) / Complex64::new(k as f64, 0.0);
+        result += &term;
+        if term.iter().all(|x| x.norm() < f64::EPSILON) {
+            break;
+        }
+    }
+    for _ in 0..squarings {
+        result = result.dot(&result);
+    }
+    result
+}
@@ -22,6 +22,8 @@ use crate::operations::{
     Substitute,
 };
 use crate::Circuit;
+use struqture::spins::SpinHamiltonian;
+use struqture::{OperateOnDensityMatrix, SpinIndex};
 
 /// Measurement gate operation.
 ///
@@ -133,6 +135,10 @@ impl InvolveQubits for PragmaGetStateVector {
 
 /// This PRAGMA measurement operation returns the density matrix of a quantum register.
 ///
+/// The density matrix of the full register with N qubits is written into the complex readout register
+/// as a flattened 2^N x 2^N matrix in row-major order.
+/// The basis states are ordered in little endian notation: qubit 0 corresponds to the least significant bit of the index.
+///
 #[derive(
     Debug,
     Clone,
@@ -197,6 +203,110 @@ impl InvolveQubits for PragmaGetDensityMatrix {
     }
 }
 
+/// This PRAGMA measurement operation returns the reduced density matrix of a subset of qubits.
+///
+/// The reduced density matrix is obtained by tracing out all qubits not in `qubits`
+/// (see [crate::registers::partial_trace]).
+/// It is written into the complex readout register as a flattened 2^k x 2^k matrix in row-major order for k qubits,
+/// with the same little endian ordering as [PragmaGetDensityMatrix]: `qubits[0]` corresponds to the
+/// least significant bit of the reduced index.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGetReducedDensityMatrix {
+    /// The name of the classical readout register.
+    readout: String,
+    /// The qubits of the returned reduced density matrix.
+    qubits: Vec<usize>,
+    /// The measurement preparation Circuit, applied on a copy of the register before measurement (None if not defined, Some(Circuit) otherwise).
+    circuit: Option<Circuit>,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGetReducedDensityMatrix: &[&str; 4] = &[
+    "Operation",
+    "Measurement",
+    "PragmaOperation",
+    "PragmaGetReducedDensityMatrix",
+];
+
+impl super::ImplementedIn1point18 for PragmaGetReducedDensityMatrix {}
+
+impl SupportedVersion for PragmaGetReducedDensityMatrix {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+impl PragmaGetReducedDensityMatrix {
+    /// Returns the qubits of the returned reduced density matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `&Vec<usize>` - The kept qubits.
+    pub fn qubits(&self) -> &Vec<usize> {
+        &self.qubits
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaGetReducedDensityMatrix {
+    /// Remaps qubits in operations in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.remap_qubits(mapping)?),
+            _ => None,
+        };
+        let new_qubits = self
+            .qubits
+            .iter()
+            .map(|qubit| *mapping.get(qubit).unwrap_or(qubit))
+            .collect();
+        Ok(PragmaGetReducedDensityMatrix::new(
+            self.readout.clone(),
+            new_qubits,
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.substitute_parameters(calculator)?),
+            _ => None,
+        };
+        Ok(PragmaGetReducedDensityMatrix::new(
+            self.readout.clone(),
+            self.qubits.clone(),
+            new_circuit,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for PragmaGetReducedDensityMatrix.
+impl InvolveQubits for PragmaGetReducedDensityMatrix {
+    /// Lists all involved qubits (the kept qubits and the qubits of the circuit).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut new_hash_set: HashSet<usize> = self.qubits.iter().cloned().collect();
+        match self
+            .circuit
+            .as_ref()
+            .map(|circuit| circuit.involved_qubits())
+        {
+            Some(InvolvedQubits::All) => return InvolvedQubits::All,
+            Some(InvolvedQubits::Set(tmp_set)) => new_hash_set.extend(tmp_set),
+            _ => (),
+        }
+        InvolvedQubits::Set(new_hash_set)
+    }
+
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::All(self.readout.clone())
+    }
+}
+
 /// This PRAGMA measurement operation returns the vector of the occupation probabilities.
 ///
 /// Occupation probabilities in the context of this PRAGMA Operation are probabilities of finding the quantum
@@ -284,6 +394,10 @@ impl InvolveQubits for PragmaGetOccupationProbability {
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaGetPauliProduct {
     /// The HashMap of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: `0` = identity, `1` = PauliX, `2` = PauliY, `3` = PauliZ.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
     qubit_paulis: HashMap<usize, usize>,
     /// The name of the classical readout register.
     readout: String,
@@ -352,6 +466,255 @@ impl InvolveQubits for PragmaGetPauliProduct {
     }
 }
 
+/// This PRAGMA measurement operation returns the vector of the occupation probabilities at an offset in the readout register.
+///
+/// Acts like [PragmaGetOccupationProbability] but backends write the occupation probabilities into the
+/// readout register starting at the entry `readout_index`, so several measurements can share one register.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGetIndexedOccupationProbability {
+    /// The name of the classical readout register.
+    readout: String,
+    /// The index of the readout register entry the first occupation probability is written to.
+    readout_index: usize,
+    /// The measurement preparation Circuit, applied on a copy of the register before measurement (None if not defined, Some(Circuit) otherwise).
+    circuit: Option<Circuit>,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGetIndexedOccupationProbability: &[&str; 4] = &[
+    "Operation",
+    "Measurement",
+    "PragmaOperation",
+    "PragmaGetIndexedOccupationProbability",
+];
+
+impl super::ImplementedIn1point18 for PragmaGetIndexedOccupationProbability {}
+
+impl SupportedVersion for PragmaGetIndexedOccupationProbability {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaGetIndexedOccupationProbability {
+    /// Remaps qubits in operations in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.remap_qubits(mapping)?),
+            _ => None,
+        };
+        Ok(PragmaGetIndexedOccupationProbability::new(
+            self.readout.clone(),
+            self.readout_index,
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.substitute_parameters(calculator)?),
+            _ => None,
+        };
+        Ok(PragmaGetIndexedOccupationProbability::new(
+            self.readout.clone(),
+            self.readout_index,
+            new_circuit,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for PragmaGetIndexedOccupationProbability.
+impl InvolveQubits for PragmaGetIndexedOccupationProbability {
+    /// Lists all involved qubits (here, all).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::All(self.readout.clone())
+    }
+}
+
+/// This PRAGMA measurement operation returns a Pauli product expectation value at an entry of the readout register.
+///
+/// Acts like [PragmaGetPauliProduct] but backends write the expectation value into the entry `readout_index`
+/// of the readout register, so several Pauli products can be measured into one register.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGetIndexedPauliProduct {
+    /// The HashMap of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: `0` = identity, `1` = PauliX, `2` = PauliY, `3` = PauliZ.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
+    qubit_paulis: HashMap<usize, usize>,
+    /// The name of the classical readout register.
+    readout: String,
+    /// The index of the readout register entry the expectation value is written to.
+    readout_index: usize,
+    /// The measurement preparation Circuit, applied on a copy of the register before measurement.
+    circuit: Circuit,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGetIndexedPauliProduct: &[&str; 4] = &[
+    "Operation",
+    "Measurement",
+    "PragmaOperation",
+    "PragmaGetIndexedPauliProduct",
+];
+
+impl super::ImplementedIn1point18 for PragmaGetIndexedPauliProduct {}
+
+impl SupportedVersion for PragmaGetIndexedPauliProduct {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaGetIndexedPauliProduct {
+    /// Remaps qubits in operations in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_qubit_paulis: HashMap<usize, usize> = self
+            .qubit_paulis
+            .iter()
+            .map(|(qubit, pauli)| (*mapping.get(qubit).unwrap_or(qubit), *pauli))
+            .collect();
+        let new_circuit = self.circuit.remap_qubits(mapping)?;
+        Ok(PragmaGetIndexedPauliProduct::new(
+            new_qubit_paulis,
+            self.readout.clone(),
+            self.readout_index,
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
+        Ok(PragmaGetIndexedPauliProduct::new(
+            self.qubit_paulis.clone(),
+            self.readout.clone(),
+            self.readout_index,
+            new_circuit,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for PragmaGetIndexedPauliProduct.
+impl InvolveQubits for PragmaGetIndexedPauliProduct {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut new_hash_set: HashSet<usize> = self.qubit_paulis.keys().cloned().collect();
+        if let InvolvedQubits::Set(tmp_set) = &self.circuit.involved_qubits() {
+            new_hash_set.extend(tmp_set);
+        }
+        InvolvedQubits::Set(new_hash_set)
+    }
+
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::Set(HashSet::from([(self.readout.clone(), self.readout_index)]))
+    }
+}
+
+/// This PRAGMA measurement operation returns the expectation value of an operator.
+///
+/// The operator is given as a spin Hamiltonian. The measurement preparation circuit is applied
+/// on a copy of the quantum register, so that the actual quantum register remains unchanged.
+/// The (real) expectation value is written into the first entry of a float register.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGetOperatorExpectation {
+    /// The operator (as a spin Hamiltonian) whose expectation value is measured.
+    operator: SpinHamiltonian,
+    /// The name of the classical float readout register.
+    readout: String,
+    /// The measurement preparation Circuit, applied on a copy of the register before measurement.
+    circuit: Circuit,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGetOperatorExpectation: &[&str; 4] = &[
+    "Operation",
+    "Measurement",
+    "PragmaOperation",
+    "PragmaGetOperatorExpectation",
+];
+
+impl super::ImplementedIn1point18 for PragmaGetOperatorExpectation {}
+
+impl SupportedVersion for PragmaGetOperatorExpectation {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaGetOperatorExpectation {
+    /// Remaps qubits in operations in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let mut new_operator = SpinHamiltonian::new();
+        for (pp, value) in &self.operator {
+            new_operator
+                .add_operator_product(pp.remap_qubits(mapping), value.clone())
+                .map_err(|err| RoqoqoError::GenericError {
+                    msg: err.to_string(),
+                })?;
+        }
+        let new_circuit = self.circuit.remap_qubits(mapping)?;
+        Ok(PragmaGetOperatorExpectation::new(
+            new_operator,
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let mut new_operator = self.operator.clone();
+        for (key, value) in &self.operator {
+            let new_value = calculator.parse_get(value.clone())?;
+            new_operator
+                .set(key.clone(), new_value.into())
+                .map_err(|err| RoqoqoError::GenericError {
+                    msg: err.to_string(),
+                })?;
+        }
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
+        Ok(PragmaGetOperatorExpectation::new(
+            new_operator,
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for PragmaGetOperatorExpectation.
+impl InvolveQubits for PragmaGetOperatorExpectation {
+    /// Lists all involved qubits (here, all).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+
+    fn involved_classical(&self) -> InvolvedClassical {
+        let mut new_set: HashSet<(String, usize)> = HashSet::new();
+        new_set.insert((self.readout.clone(), 0));
+        InvolvedClassical::Set(new_set)
+    }
+}
+
 /// This PRAGMA measurement operation returns a measurement record for N repeated measurements.
 ///
 #[derive(
@@ -364,6 +727,10 @@ impl InvolveQubits for PragmaGetPauliProduct {
     roqoqo_derive::OperatePragma,
 )]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(try_from = "PragmaRepeatedMeasurementSerialize")
+)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaRepeatedMeasurement {
     /// The name of the classical readout register.
@@ -371,9 +738,113 @@ pub struct PragmaRepeatedMeasurement {
     /// The number of times N to repeat the measurement.
     number_measurements: usize,
     /// The mapping of qubits to indices in the readout register.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_optional_map")
+    )]
     qubit_mapping: Option<HashMap<usize, usize>>,
 }
 
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "PragmaRepeatedMeasurement")]
+struct PragmaRepeatedMeasurementSerialize {
+    readout: String,
+    number_measurements: usize,
+    qubit_mapping: Option<HashMap<usize, usize>>,
+}
+
+#[cfg(feature = "serialize")]
+impl TryFrom<PragmaRepeatedMeasurementSerialize> for PragmaRepeatedMeasurement {
+    type Error = RoqoqoError;
+
+    fn try_from(value: PragmaRepeatedMeasurementSerialize) -> Result<Self, Self::Error> {
+        if let Some(mapping) = value.qubit_mapping.as_ref() {
+            check_injective_readout_mapping(mapping.iter().map(|(qubit, index)| (*qubit, *index)))?;
+        }
+        Ok(PragmaRepeatedMeasurement::new(
+            value.readout,
+            value.number_measurements,
+            value.qubit_mapping,
+        ))
+    }
+}
+
+impl PragmaRepeatedMeasurement {
+    /// Creates a new PragmaRepeatedMeasurement from pairs of qubits and readout indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout` - The name of the classical readout register.
+    /// * `number_measurements` - The number of times N to repeat the measurement.
+    /// * `pairs` - The pairs `(qubit, readout_index)` of the qubit mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PragmaRepeatedMeasurement with the qubit mapping of the pairs.
+    /// * `Err(RoqoqoError::GenericError)` - A qubit appears more than once or two qubits are mapped to the same readout index.
+    pub fn with_qubit_readout_pairs(
+        readout: String,
+        number_measurements: usize,
+        pairs: &[(usize, usize)],
+    ) -> Result<Self, RoqoqoError> {
+        check_injective_readout_mapping(pairs.iter().copied())?;
+        Ok(PragmaRepeatedMeasurement::new(
+            readout,
+            number_measurements,
+            Some(pairs.iter().copied().collect()),
+        ))
+    }
+
+    /// Returns the index in the readout register the measurement of a qubit is written to.
+    ///
+    /// Qubits without an entry in the qubit mapping, and all qubits when no mapping is set,
+    /// are written to the readout index equal to the qubit index.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The measured qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The readout index of the qubit.
+    pub fn effective_readout_index(&self, qubit: usize) -> usize {
+        self.qubit_mapping
+            .as_ref()
+            .and_then(|mapping| mapping.get(&qubit))
+            .copied()
+            .unwrap_or(qubit)
+    }
+}
+
+// Checks that each qubit appears once and no two qubits are mapped to the same readout index.
+fn check_injective_readout_mapping(
+    pairs: impl Iterator<Item = (usize, usize)>,
+) -> Result<(), RoqoqoError> {
+    let mut pairs: Vec<(usize, usize)> = pairs.collect();
+    pairs.sort_unstable();
+    let mut readout_qubits: HashMap<usize, usize> = HashMap::new();
+    for (position, (qubit, index)) in pairs.iter().enumerate() {
+        if position > 0 && pairs[position - 1].0 == *qubit {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubit {} appears more than once in the qubit mapping",
+                    qubit
+                ),
+            });
+        }
+        if let Some(other) = readout_qubits.insert(*index, *qubit) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubits {} and {} are both mapped to readout index {}",
+                    other, qubit, index
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
 #[allow(non_upper_case_globals)]
 const TAGS_PragmaRepeatedMeasurement: &[&str; 4] = &[
     "Operation",
@@ -402,6 +873,8 @@ impl Substitute for PragmaRepeatedMeasurement {
                 }
                 Some(mutable_mapping)
             }
+            // An empty mapping keeps all qubits, measuring without a mapping stays unchanged
+            None if mapping.is_empty() => None,
             None => Some(mapping.clone()),
         };
         Ok(PragmaRepeatedMeasurement::new(
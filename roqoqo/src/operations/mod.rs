@@ -20,7 +20,7 @@
 //! * Measurement Operations are operations that perform a measurement either on a quantum computing device (MeasureQubit)
 //!   or on a simulation of a quantum computing program (PRAGMA measurement operations).
 
-use crate::RoqoqoError;
+use crate::{Circuit, RoqoqoError};
 #[cfg(feature = "dynamic")]
 use dyn_clone::DynClone;
 use ndarray::Array2;
@@ -72,6 +72,9 @@ pub use spin_boson_operations::*;
 mod analog_operations;
 #[cfg(feature = "unstable_analog_operations")]
 pub use analog_operations::*;
+/// Generation of arbitrary operation fields for property-based tests.
+#[cfg(feature = "arbitrary")]
+pub(crate) mod arbitrary_fields;
 
 include!(concat!(env!("OUT_DIR"), "/_auto_generated_operations.rs"));
 
@@ -569,6 +572,15 @@ pub trait OperateConstantGate:
     fn inverse(&self) -> GateOperation;
 }
 
+/// Default tolerance of [OperateSingleQubitGate::mul] for the deviation of the norm of a product from one
+/// before the product is renormalized.
+pub const MUL_NORM_TOLERANCE: f64 = 1e-10;
+
+/// Largest deviation of the norm of a product of single qubit gates from one that is accepted as rounding error.
+///
+/// Larger deviations indicate a gate that is not unitary and [OperateSingleQubitGate::mul_with_tolerance] returns an error.
+pub const MUL_MAX_NORM_DEVIATION: f64 = 1e-6;
+
 /// Trait for unitary operations acting on exactly one qubit.
 ///
 /// Implements the general single qubit unitary gates  that can be brought into the form:
@@ -651,12 +663,18 @@ pub trait OperateSingleQubitGate:
     /// Multiplies two compatible operations implementing OperateSingleQubitGate.
     ///
     /// Does not consume the two operations being multiplied.
-    /// Only Operations
+    /// Equivalent to [OperateSingleQubitGate::mul_with_tolerance] with the tolerance [MUL_NORM_TOLERANCE].
     ///
     /// # Arguments:
     ///
     /// * `other` - An Operation implementing [OperateSingleQubitGate].
     ///
+    /// # Returns
+    ///
+    /// * `Ok(SingleQubitGate)` - The product of the two gates.
+    /// * `Err(RoqoqoError::MultiplicationIncompatibleQubits)` - The gates act on different qubits.
+    /// * `Err(RoqoqoError::UnitaryMatrixErrror)` - The norm of the product deviates from one by more than [MUL_MAX_NORM_DEVIATION].
+    ///
     /// # Example
     /// ```
     /// use roqoqo::operations::{RotateZ, RotateX};
@@ -668,6 +686,47 @@ pub trait OperateSingleQubitGate:
     /// let multiplied = gate1.mul(&gate2).unwrap();
     /// ```
     fn mul<T>(&self, other: &T) -> Result<SingleQubitGate, RoqoqoError>
+    where
+        T: OperateSingleQubitGate,
+    {
+        self.mul_with_tolerance(other, MUL_NORM_TOLERANCE)
+    }
+
+    /// Multiplies two compatible operations implementing OperateSingleQubitGate with a normalization tolerance.
+    ///
+    /// Does not consume the two operations being multiplied.
+    /// For gates with float parameters the norm sqrt(|α|² + |β|²) of the product is checked.
+    /// The product is renormalized when the norm deviates from one by more than `tolerance`,
+    /// which removes the rounding errors accumulated over long products.
+    /// A deviation larger than [MUL_MAX_NORM_DEVIATION] indicates a gate that is not unitary
+    /// and returns an error. Products of symbolic gates are not normalized.
+    ///
+    /// # Arguments:
+    ///
+    /// * `other` - An Operation implementing [OperateSingleQubitGate].
+    /// * `tolerance` - The largest deviation of the norm from one that is not renormalized.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SingleQubitGate)` - The product of the two gates.
+    /// * `Err(RoqoqoError::MultiplicationIncompatibleQubits)` - The gates act on different qubits.
+    /// * `Err(RoqoqoError::UnitaryMatrixErrror)` - The norm of the product deviates from one by more than [MUL_MAX_NORM_DEVIATION].
+    ///
+    /// # Example
+    /// ```
+    /// use roqoqo::operations::{RotateZ, RotateX};
+    /// use roqoqo::prelude::*;
+    /// use qoqo_calculator::CalculatorFloat;
+    ///
+    /// let gate1 =  RotateZ::new(0, CalculatorFloat::from(1));
+    /// let gate2 =  RotateX::new(0, CalculatorFloat::from(1));
+    /// let multiplied = gate1.mul_with_tolerance(&gate2, 0.0).unwrap();
+    /// ```
+    fn mul_with_tolerance<T>(
+        &self,
+        other: &T,
+        tolerance: f64,
+    ) -> Result<SingleQubitGate, RoqoqoError>
     where
         T: OperateSingleQubitGate,
     {
@@ -681,50 +740,42 @@ pub trait OperateSingleQubitGate:
         let beta = qoqo_calculator::CalculatorComplex::new(self.beta_r(), self.beta_i());
         let oalpha = qoqo_calculator::CalculatorComplex::new(other.alpha_r(), other.alpha_i());
         let obeta = qoqo_calculator::CalculatorComplex::new(other.beta_r(), other.beta_i());
-        let new_alpha = alpha.clone() * &oalpha - beta.conj() * &obeta;
-        let new_beta = beta * oalpha + obeta * alpha.conj();
-
-        if new_alpha.re.is_float()
-            && new_alpha.im.is_float()
-            && new_beta.re.is_float()
-            && new_beta.im.is_float()
+        let mut new_alpha = alpha.clone() * &oalpha - beta.conj() * &obeta;
+        let mut new_beta = beta * oalpha + obeta * alpha.conj();
+
+        if let (
+            CalculatorFloat::Float(alpha_r),
+            CalculatorFloat::Float(alpha_i),
+            CalculatorFloat::Float(beta_r),
+            CalculatorFloat::Float(beta_i),
+        ) = (&new_alpha.re, &new_alpha.im, &new_beta.re, &new_beta.im)
         {
-            let norm = (new_alpha.re.float().unwrap().powf(2.0)
-                + new_alpha.im.float().unwrap().powf(2.0)
-                + new_beta.re.float().unwrap().powf(2.0)
-                + new_beta.im.float().unwrap().powf(2.0))
-            .sqrt();
-
-            if (norm - 1.0).abs() > f64::EPSILON {
-                Ok(SingleQubitGate::new(
-                    *other.qubit(),
-                    new_alpha.re / norm,
-                    new_alpha.im / norm,
-                    new_beta.re / norm,
-                    new_beta.im / norm,
-                    self.global_phase() + other.global_phase(),
-                ))
-            } else {
-                Ok(SingleQubitGate::new(
-                    *other.qubit(),
-                    new_alpha.re,
-                    new_alpha.im,
-                    new_beta.re,
-                    new_beta.im,
-                    self.global_phase() + other.global_phase(),
-                ))
+            let norm = (alpha_r.powi(2) + alpha_i.powi(2) + beta_r.powi(2) + beta_i.powi(2)).sqrt();
+            let deviation = (norm - 1.0).abs();
+            if deviation.is_nan() || deviation > MUL_MAX_NORM_DEVIATION {
+                return Err(RoqoqoError::UnitaryMatrixErrror {
+                    alpha_r: *alpha_r,
+                    alpha_i: *alpha_i,
+                    beta_r: *beta_r,
+                    beta_i: *beta_i,
+                    norm,
+                });
+            }
+            if deviation > tolerance {
+                new_alpha /= norm;
+                new_beta /= norm;
             }
-        } else {
-            Ok(SingleQubitGate::new(
-                *other.qubit(),
-                new_alpha.re,
-                new_alpha.im,
-                new_beta.re,
-                new_beta.im,
-                self.global_phase() + other.global_phase(),
-            ))
         }
+        Ok(SingleQubitGate::new(
+            *other.qubit(),
+            new_alpha.re,
+            new_alpha.im,
+            new_beta.re,
+            new_beta.im,
+            self.global_phase() + other.global_phase(),
+        ))
     }
+
     /// Returns equivalent SingleQubitGate.
     ///
     /// Converts Operation implementing OperateSingleQubitGate Trait into SingleQubitGate.
@@ -738,6 +789,76 @@ pub trait OperateSingleQubitGate:
             self.global_phase(),
         )
     }
+
+    /// Decomposes the gate into a RotateZ, RotateY, RotateZ sequence.
+    ///
+    /// Equivalent to [OperateSingleQubitGate::to_native_circuit] with [SingleQubitBasis::ZYZ].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The rotations followed by a PragmaGlobalPhase.
+    /// * `Err(RoqoqoError::CalculatorError)` - The parameters of the gate are symbolic.
+    ///
+    /// # Example
+    /// ```
+    /// use roqoqo::operations::{Hadamard, OperateSingleQubitGate};
+    ///
+    /// let circuit = Hadamard::new(0).to_zyz_circuit().unwrap();
+    /// assert_eq!(circuit.len(), 4);
+    /// ```
+    fn to_zyz_circuit(&self) -> Result<Circuit, RoqoqoError> {
+        self.to_native_circuit(SingleQubitBasis::ZYZ)
+    }
+
+    /// Decomposes the gate into a sequence of gates from a native gate set.
+    ///
+    /// The gate is written as e^{iφ} RotateZ(a) RotateY(b) RotateZ(c) with b in [0, π]
+    /// and converted to the requested basis. The decomposition reproduces the unitary matrix
+    /// including the global phase φ, which is added as a PragmaGlobalPhase at the end of the Circuit.
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The native gate set of the decomposition.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The native gates followed by a PragmaGlobalPhase.
+    /// * `Err(RoqoqoError::CalculatorError)` - The parameters of the gate are symbolic.
+    fn to_native_circuit(&self, basis: SingleQubitBasis) -> Result<Circuit, RoqoqoError> {
+        let alpha = Complex64::new(
+            f64::try_from(self.alpha_r())?,
+            f64::try_from(self.alpha_i())?,
+        );
+        let beta = Complex64::new(f64::try_from(self.beta_r())?, f64::try_from(self.beta_i())?);
+        let global_phase = f64::try_from(self.global_phase())?;
+        // alpha = e^{-i(a+c)/2} cos(b/2) and beta = e^{i(a-c)/2} sin(b/2)
+        let theta = 2.0 * beta.norm().atan2(alpha.norm());
+        let phi_first = -beta.arg() - alpha.arg();
+        let phi_last = beta.arg() - alpha.arg();
+        let qubit = *self.qubit();
+        let mut circuit = Circuit::new();
+        match basis {
+            SingleQubitBasis::ZYZ => {
+                circuit += RotateZ::new(qubit, phi_first.into());
+                circuit += RotateY::new(qubit, theta.into());
+                circuit += RotateZ::new(qubit, phi_last.into());
+            }
+            SingleQubitBasis::ZXZ => {
+                circuit += RotateZ::new(qubit, (phi_first - std::f64::consts::FRAC_PI_2).into());
+                circuit += RotateX::new(qubit, theta.into());
+                circuit += RotateZ::new(qubit, (phi_last + std::f64::consts::FRAC_PI_2).into());
+            }
+            SingleQubitBasis::ZSX => {
+                circuit += RotateZ::new(qubit, phi_first.into());
+                circuit += SqrtPauliX::new(qubit);
+                circuit += RotateZ::new(qubit, (theta - std::f64::consts::PI).into());
+                circuit += SqrtPauliX::new(qubit);
+                circuit += RotateZ::new(qubit, (phi_last + std::f64::consts::PI).into());
+            }
+        }
+        circuit += PragmaGlobalPhase::new(global_phase.into());
+        Ok(circuit)
+    }
 }
 
 /// Trait for all Operations operating on or affecting exactly two qubits.
@@ -766,6 +887,52 @@ pub trait OperateTwoQubitGate:
 {
     /// Returns [KakDecomposition] of two qubit gate.
     fn kak_decomposition(&self) -> KakDecomposition;
+
+    /// Decomposes the gate into CNOT gates and single-qubit gates.
+    ///
+    /// Equivalent to [OperateTwoQubitGate::to_basis_circuit] with [TwoQubitBasis::CNOT].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - At most three CNOT gates with SingleQubitGates, followed by a PragmaGlobalPhase.
+    /// * `Err(RoqoqoError::CalculatorError)` - The parameters of the gate are symbolic.
+    ///
+    /// # Example
+    /// ```
+    /// use roqoqo::operations::{ISwap, Operate, OperateTwoQubitGate};
+    ///
+    /// let circuit = ISwap::new(0, 1).to_cnot_circuit().unwrap();
+    /// assert_eq!(circuit.iter().filter(|op| op.hqslang() == "CNOT").count(), 2);
+    /// ```
+    fn to_cnot_circuit(&self) -> Result<Circuit, RoqoqoError> {
+        self.to_basis_circuit(TwoQubitBasis::CNOT)
+    }
+
+    /// Decomposes the gate into entangling gates of a basis and single-qubit gates.
+    ///
+    /// The decomposition is built from the [KakDecomposition] of the gate. Depending on the k-vector
+    /// the canonical part needs zero to three CNOT gates, which are replaced by the entangling gates of the basis.
+    /// All single-qubit gates between entangling gates are merged into one SingleQubitGate per qubit.
+    /// The global phase is added as a PragmaGlobalPhase at the end of the Circuit,
+    /// so the Circuit reproduces the unitary matrix of the gate exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The entangling gate of the decomposition.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The entangling and single-qubit gates followed by a PragmaGlobalPhase.
+    /// * `Err(RoqoqoError::CalculatorError)` - The parameters of the gate are symbolic.
+    fn to_basis_circuit(&self, basis: TwoQubitBasis) -> Result<Circuit, RoqoqoError> {
+        self.unitary_matrix()?;
+        kak_to_basis_circuit(
+            &self.kak_decomposition(),
+            *self.control(),
+            *self.target(),
+            basis,
+        )
+    }
 }
 
 /// Trait for all Operations operating on or affecting exactly three qubits.
@@ -798,6 +965,33 @@ pub trait OperateThreeQubitGate:
 {
     /// Returns a decomposition of the three-qubit operation using a circuit with two-qubit-operations.
     fn circuit(&self) -> crate::Circuit;
+
+    /// Returns a decomposition of the three-qubit operation using only the entangling gates of a basis.
+    ///
+    /// Allows devices to use their preferred two-qubit gate instead of the CNOT and controlled-phase gates
+    /// used by [OperateThreeQubitGate::circuit].
+    /// The default implementation returns [OperateThreeQubitGate::circuit].
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The entangling gate of the decomposition.
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The entangling gates of the basis together with single-qubit gates.
+    ///
+    /// # Example
+    /// ```
+    /// use roqoqo::operations::{Operate, OperateThreeQubitGate, Toffoli, TwoQubitBasis};
+    ///
+    /// let circuit = Toffoli::new(0, 1, 2).circuit_with_basis(TwoQubitBasis::ControlledPauliZ);
+    /// assert_eq!(circuit.iter().filter(|op| op.hqslang() == "ControlledPauliZ").count(), 6);
+    /// assert_eq!(circuit.iter().filter(|op| op.hqslang() == "CNOT").count(), 0);
+    /// ```
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> crate::Circuit {
+        let _ = basis;
+        self.circuit()
+    }
 }
 
 /// Trait for all Operations operating on or affecting exactly three qubits.
@@ -926,6 +1120,9 @@ pub trait ImplementedIn1point16: Operate {}
 /// Marker trait to show that some operation has been implemented in roqoqo 1.17.0
 pub trait ImplementedIn1point17: Operate {}
 
+/// Marker trait to show that some operation has been implemented in roqoqo 1.18.0
+pub trait ImplementedIn1point18: Operate {}
+
 #[cfg(feature = "dynamic")]
 /// A wrapper for Operate trait objects.
 ///
@@ -983,6 +1180,102 @@ impl PartialEq for DynOperation {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl Operation {
+    /// Serializes the Operation to a self-describing json object tagged with its hqslang name.
+    ///
+    /// The json object contains the fields of the wrapped operation struct and an additional
+    /// `hqslang` entry, e.g. `{"hqslang": "RotateZ", "qubit": 0, "theta": 0.5}`.
+    /// In contrast to the serde representation of [Operation] the format does not depend on the
+    /// layout of the Operation enum.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The hqslang-tagged json representation of the Operation.
+    pub fn to_tagged_json(&self) -> String {
+        let mut fields = match serde_json::to_value(self)
+            .expect("Unexpected error serializing Operation to json")
+        {
+            serde_json::Value::Object(mut variant) => match variant.remove(self.hqslang()) {
+                Some(serde_json::Value::Object(fields)) => fields,
+                _ => serde_json::Map::new(),
+            },
+            _ => serde_json::Map::new(),
+        };
+        fields.insert(
+            "hqslang".to_string(),
+            serde_json::Value::String(self.hqslang().to_string()),
+        );
+        serde_json::Value::Object(fields).to_string()
+    }
+
+    /// Deserializes an Operation from a json object tagged with its hqslang name.
+    ///
+    /// Inverse of [Operation::to_tagged_json].
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The hqslang-tagged json representation of the Operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Operation)` - The deserialized Operation.
+    /// * `Err(RoqoqoError::UnknownOperation)` - The hqslang tag does not correspond to any Operation.
+    /// * `Err(RoqoqoError::SerializationError)` - The input is not a tagged json object or the fields do not match the Operation.
+    pub fn from_tagged_json(input: &str) -> Result<Self, RoqoqoError> {
+        let mut fields: serde_json::Map<String, serde_json::Value> = serde_json::from_str(input)
+            .map_err(|err| RoqoqoError::SerializationError {
+                msg: format!("Input is not a json object: {}", err),
+            })?;
+        let hqslang = match fields.remove("hqslang") {
+            Some(serde_json::Value::String(hqslang)) => hqslang,
+            _ => {
+                return Err(RoqoqoError::SerializationError {
+                    msg: "Json object does not contain an hqslang string entry".to_string(),
+                })
+            }
+        };
+        operation_from_tagged_json_fields(&hqslang, serde_json::Value::Object(fields))
+            .ok_or_else(|| RoqoqoError::UnknownOperation {
+                hqslang: hqslang.clone(),
+            })?
+            .map_err(|err| RoqoqoError::SerializationError {
+                msg: format!("Fields do not match Operation {}: {}", hqslang, err),
+            })
+    }
+
+    /// Deserializes an Operation from json and rejects fields unknown to the Operation.
+    ///
+    /// The default deserialization ignores unknown fields, so a misspelled field of a hand-written
+    /// json Operation can go unnoticed. The strict deserialization returns an error instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The json representation of the Operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Operation)` - The deserialized Operation.
+    /// * `Err(RoqoqoError::SerializationError)` - The input cannot be deserialized or contains unknown fields, which are listed with their json paths.
+    pub fn from_json_strict(input: &str) -> Result<Self, RoqoqoError> {
+        crate::from_json_strict(input)
+    }
+
+    /// Serializes an Operation to json, writing float values that are not finite as strings.
+    ///
+    /// The default json serialization writes the NaN and infinite amplitudes, variances and rates of
+    /// PragmaOverrotation and PragmaGeneralNoise as `null`, which cannot be deserialized.
+    /// The lossy serialization writes them as "NaN", "Infinity" and "-Infinity" instead.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The serialized Operation.
+    /// * `Err(RoqoqoError::SerializationError)` - The Operation could not be serialized.
+    pub fn to_json_lossy(&self) -> Result<String, RoqoqoError> {
+        crate::float_serialization::to_json_lossy(self)
+    }
+}
+
 /// Check if a HashMap is a valid mapping for remapping_qubits
 #[inline]
 pub(crate) fn check_valid_mapping(mapping: &HashMap<usize, usize>) -> Result<(), RoqoqoError> {
@@ -1032,6 +1325,22 @@ pub trait InvolveModes {
     }
 }
 
+/// Implements [InvolveModes] for all Operations.
+///
+/// Operations acting on bosonic modes (bosonic and spin-boson operations) return their modes,
+/// all other Operations return InvolvedModes::None.
+impl InvolveModes for Operation {
+    fn involved_modes(&self) -> InvolvedModes {
+        if let Ok(operation) = SingleModeOperation::try_from(self) {
+            operation.involved_modes()
+        } else if let Ok(operation) = TwoModeOperation::try_from(self) {
+            operation.involved_modes()
+        } else {
+            InvolvedModes::None
+        }
+    }
+}
+
 /// SubstituteModes trait allowing to perform bosonic mode mappings.
 ///
 /// # Example
@@ -1148,6 +1457,37 @@ pub trait OperateTwoModeGate:
 {
 }
 
+/// Trait for bosonic gates with a matrix representation in the truncated Fock space.
+///
+/// The Fock space of each mode is truncated to the photon numbers 0 to `cutoff - 1`.
+/// The matrix is the exponential of the truncated generator of the gate and is therefore unitary.
+/// For two-mode gates the basis state |n_0, n_1> has the index `n_0 * cutoff + n_1`,
+/// where n_0 is the photon number of `mode_0`.
+///
+/// # Example
+/// ```
+/// use roqoqo::operations::{OperateFockMatrix, PhaseShift};
+///
+/// let phaseshift = PhaseShift::new(0, 0.5.into());
+/// assert_eq!(phaseshift.fock_matrix(3).unwrap().dim(), (3, 3));
+/// ```
+///
+#[cfg(feature = "unstable_fock_matrix")]
+pub trait OperateFockMatrix: OperateModeGate {
+    /// Returns the matrix of the gate in the truncated Fock space.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - The number of Fock states per mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix of dimension cutoff (single-mode) or cutoff^2 (two-mode gates).
+    /// * `Err(RoqoqoError::GenericError)` - The cutoff is zero.
+    /// * `Err(RoqoqoError::CalculatorError)` - A parameter of the gate is symbolic.
+    fn fock_matrix(&self, cutoff: usize) -> Result<Array2<Complex64>, RoqoqoError>;
+}
+
 #[cfg(feature = "unstable_analog_operations")]
 /// Trait for all continuous time spin operations
 ///
@@ -161,6 +161,220 @@ impl OperateMultiQubitGate for MultiQubitZZ {
     }
 }
 
+/// The Molmer-Sorensen gate between multiple qubits with individual coupling strengths for each pair of qubits.
+///
+/// The gate applies the rotation under the sum of products of Pauli X operators on all pairs of qubits.
+/// In mathematical terms the gate applies exp(-i * Σ_{j<k} θ_jk/2 * X_ij * X_ik).
+/// The couplings θ_jk are ordered by pairs (0, 1), (0, 2), ..., (0, n-1), (1, 2), ..., (n-2, n-1)
+/// of the positions of the qubits in `qubits`.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug, Clone, PartialEq, roqoqo_derive::InvolveQubits, roqoqo_derive::OperateMultiQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct MultiQubitMSWithCouplings {
+    /// The qubits involved in the multi qubit Molmer-Sorensen gate.
+    pub(crate) qubits: Vec<usize>,
+    /// The coupling strengths of all pairs of qubits.
+    pub(crate) couplings: Vec<CalculatorFloat>,
+}
+
+impl MultiQubitMSWithCouplings {
+    /// Creates a new instance of `MultiQubitMSWithCouplings`.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits involved in the multi qubit Molmer-Sorensen gate.
+    /// * `couplings` - The coupling strengths of all n(n-1)/2 pairs of the n qubits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new MultiQubitMSWithCouplings.
+    /// * `Err(RoqoqoError::GenericError)` - The number of couplings does not match the number of pairs of qubits.
+    pub fn new(qubits: Vec<usize>, couplings: Vec<CalculatorFloat>) -> Result<Self, RoqoqoError> {
+        check_number_of_couplings(qubits.len(), couplings.len())?;
+        Ok(Self { qubits, couplings })
+    }
+
+    /// Returns the value of the field `couplings`.
+    #[inline]
+    pub fn couplings(&self) -> &Vec<CalculatorFloat> {
+        &self.couplings
+    }
+
+    /// Returns the pairs of qubits together with their coupling strength.
+    fn coupled_pairs(&self) -> impl Iterator<Item = (usize, usize, &CalculatorFloat)> {
+        let number_qubits = self.qubits.len();
+        (0..number_qubits)
+            .flat_map(move |j| ((j + 1)..number_qubits).map(move |k| (j, k)))
+            .zip(self.couplings.iter())
+            .map(|((j, k), coupling)| (j, k, coupling))
+    }
+}
+
+/// Checks that the number of couplings matches the number of pairs of qubits.
+fn check_number_of_couplings(
+    number_qubits: usize,
+    number_couplings: usize,
+) -> Result<(), RoqoqoError> {
+    let number_pairs = number_qubits * number_qubits.saturating_sub(1) / 2;
+    if number_couplings != number_pairs {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "MultiQubitMSWithCouplings on {} qubits requires {} couplings, got {}",
+                number_qubits, number_pairs, number_couplings
+            ),
+        });
+    }
+    Ok(())
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_MultiQubitMSWithCouplings: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "MultiQubitGateOperation",
+    "MultiQubitMSWithCouplings",
+];
+
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for MultiQubitMSWithCouplings {
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_MultiQubitMSWithCouplings
+    }
+
+    fn hqslang(&self) -> &'static str {
+        "MultiQubitMSWithCouplings"
+    }
+
+    fn is_parametrized(&self) -> bool {
+        self.couplings.iter().any(|coupling| !coupling.is_float())
+    }
+}
+
+impl Substitute for MultiQubitMSWithCouplings {
+    fn substitute_parameters(
+        &self,
+        calculator: &qoqo_calculator::Calculator,
+    ) -> Result<Self, RoqoqoError> {
+        let mut new_couplings: Vec<CalculatorFloat> = Vec::with_capacity(self.couplings.len());
+        for coupling in self.couplings.iter() {
+            new_couplings.push(CalculatorFloat::from(
+                calculator
+                    .parse_get(coupling.clone())
+                    .map_err(RoqoqoError::CalculatorError)?,
+            ));
+        }
+        Ok(Self {
+            qubits: self.qubits.clone(),
+            couplings: new_couplings,
+        })
+    }
+
+    fn remap_qubits(
+        &self,
+        mapping: &std::collections::HashMap<usize, usize>,
+    ) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_qubits: Vec<usize> = self
+            .qubits
+            .iter()
+            .map(|q| *mapping.get(q).unwrap_or(q))
+            .collect();
+        Ok(Self {
+            qubits: new_qubits,
+            couplings: self.couplings.clone(),
+        })
+    }
+}
+
+impl super::ImplementedIn1point18 for MultiQubitMSWithCouplings {}
+
+impl SupportedVersion for MultiQubitMSWithCouplings {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+impl OperateGate for MultiQubitMSWithCouplings {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// The first qubit in `qubits` corresponds to the most significant bit of the basis states.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - A coupling is symbolic or the number of couplings does not match the number of qubits.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let number_qubits = self.qubits.len();
+        check_number_of_couplings(number_qubits, self.couplings.len())?;
+        let mut pairs: Vec<(usize, usize, f64)> = Vec::with_capacity(self.couplings.len());
+        for (j, k, coupling) in self.coupled_pairs() {
+            pairs.push((j, k, *coupling.float()?));
+        }
+        let dim = 2_usize.pow(number_qubits as u32);
+        let bit = |state: usize, position: usize| (state >> (number_qubits - 1 - position)) & 1;
+        // All XX terms commute and are diagonal in the Hadamard-transformed basis,
+        // where each pair contributes the phase -θ_jk/2 * z_j * z_k with z = ±1.
+        let diagonal: Vec<Complex64> = (0..dim)
+            .map(|state| {
+                let phase: f64 = pairs
+                    .iter()
+                    .map(|(j, k, coupling)| {
+                        let parity = if bit(state, *j) == bit(state, *k) {
+                            1.0
+                        } else {
+                            -1.0
+                        };
+                        -coupling / 2.0 * parity
+                    })
+                    .sum();
+                Complex64::new(phase.cos(), phase.sin())
+            })
+            .collect();
+        let hadamard_sign = |row: usize, column: usize| {
+            if (row & column).count_ones() % 2 == 0 {
+                1.0
+            } else {
+                -1.0
+            }
+        };
+        let mut array: Array2<Complex64> = Array2::zeros((dim, dim));
+        for row in 0..dim {
+            for column in 0..dim {
+                array[(row, column)] = diagonal
+                    .iter()
+                    .enumerate()
+                    .map(|(state, value)| {
+                        value * hadamard_sign(row, state) * hadamard_sign(state, column)
+                    })
+                    .sum::<Complex64>()
+                    / dim as f64;
+            }
+        }
+        Ok(array)
+    }
+}
+
+impl OperateMultiQubitGate for MultiQubitMSWithCouplings {
+    fn circuit(&self) -> Circuit {
+        let mut circuit = Circuit::new();
+        for q in self.qubits.iter() {
+            circuit += operations::Hadamard::new(*q);
+        }
+        for (j, k, coupling) in self.coupled_pairs() {
+            circuit += operations::CNOT::new(self.qubits[j], self.qubits[k]);
+            circuit += operations::RotateZ::new(self.qubits[k], coupling.clone());
+            circuit += operations::CNOT::new(self.qubits[j], self.qubits[k]);
+        }
+        for q in self.qubits.iter() {
+            circuit += operations::Hadamard::new(*q);
+        }
+        circuit
+    }
+}
+
 /// The gate to be replaced by a gate defined with GateDefinition gate.
 ///
 /// The gate applies a gate previously defined by GateDefinition with the name gate_name.
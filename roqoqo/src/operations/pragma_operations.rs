@@ -22,15 +22,17 @@ use crate::Circuit;
 #[cfg(feature = "json_schema")]
 use crate::{Array1C64Def, Array2C64Def, Array2f64Def};
 #[cfg(feature = "serialize")]
-use bincode::serialize;
+use bincode::{deserialize, serialize};
 use nalgebra::{matrix, Matrix4};
 use ndarray::{array, Array, Array1, Array2};
 use num_complex::Complex64;
 use qoqo_calculator::{Calculator, CalculatorFloat};
 #[cfg(feature = "serialize")]
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
+use struqture::spins::{PlusMinusLindbladNoiseOperator, PlusMinusProduct, SinglePlusMinusOperator};
+use struqture::OperateOnDensityMatrix;
 
 use super::InvolvedClassical;
 
@@ -137,6 +139,37 @@ struct SchemaHelperPragmaSetStateVector {
 const TAGS_PragmaSetStateVector: &[&str; 3] =
     &["Operation", "PragmaOperation", "PragmaSetStateVector"];
 
+/// Tolerance of the norm of statevectors and the Hermiticity and trace of density matrices.
+const STATE_TOLERANCE: f64 = 1e-6;
+
+impl PragmaSetStateVector {
+    /// Validates that the statevector describes a quantum state.
+    ///
+    /// The statevector is not checked on construction or deserialization.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The statevector has a power of two dimension and is normalized.
+    /// * `Err(RoqoqoError::StateDimensionNotPowerOfTwo)` - The length of the statevector is not a power of two.
+    /// * `Err(RoqoqoError::StateVectorNotNormalized)` - The norm of the statevector is not one.
+    pub fn validate(&self) -> Result<(), RoqoqoError> {
+        let dim = self.statevector.len();
+        if !dim.is_power_of_two() {
+            return Err(RoqoqoError::StateDimensionNotPowerOfTwo { dim });
+        }
+        let norm = self
+            .statevector
+            .iter()
+            .map(|value| value.norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        if (norm - 1.0).abs() > STATE_TOLERANCE {
+            return Err(RoqoqoError::StateVectorNotNormalized { norm });
+        }
+        Ok(())
+    }
+}
+
 // Implementing the InvolveQubits trait for PragmaSetStateVector.
 impl InvolveQubits for PragmaSetStateVector {
     /// Lists all involved qubits (here, all).
@@ -204,6 +237,44 @@ struct SchemaHelperPragmaSetDensityMatrix {
 const TAGS_PragmaSetDensityMatrix: &[&str; 3] =
     &["Operation", "PragmaOperation", "PragmaSetDensityMatrix"];
 
+impl PragmaSetDensityMatrix {
+    /// Validates that the density matrix describes a quantum state.
+    ///
+    /// The density matrix is not checked on construction or deserialization.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The density matrix is square with a power of two dimension, Hermitian and has trace one.
+    /// * `Err(RoqoqoError::DensityMatrixNotSquare)` - The density matrix is not square.
+    /// * `Err(RoqoqoError::StateDimensionNotPowerOfTwo)` - The dimension of the density matrix is not a power of two.
+    /// * `Err(RoqoqoError::DensityMatrixNotHermitian)` - The density matrix is not Hermitian.
+    /// * `Err(RoqoqoError::DensityMatrixTraceNotOne)` - The trace of the density matrix is not one.
+    pub fn validate(&self) -> Result<(), RoqoqoError> {
+        let (rows, columns) = self.density_matrix.dim();
+        if rows != columns {
+            return Err(RoqoqoError::DensityMatrixNotSquare { rows, columns });
+        }
+        if !rows.is_power_of_two() {
+            return Err(RoqoqoError::StateDimensionNotPowerOfTwo { dim: rows });
+        }
+        for ((row, column), value) in self.density_matrix.indexed_iter() {
+            if (value - self.density_matrix[(column, row)].conj()).norm() > STATE_TOLERANCE {
+                return Err(RoqoqoError::DensityMatrixNotHermitian { row, column });
+            }
+        }
+        let trace: f64 = self
+            .density_matrix
+            .diag()
+            .iter()
+            .map(|value| value.re)
+            .sum();
+        if (trace - 1.0).abs() > STATE_TOLERANCE {
+            return Err(RoqoqoError::DensityMatrixTraceNotOne { trace });
+        }
+        Ok(())
+    }
+}
+
 // Implementing the InvolveQubits trait for PragmaSetDensityMatrix.
 impl InvolveQubits for PragmaSetDensityMatrix {
     /// Lists all involved qubits (here, all).
@@ -273,8 +344,22 @@ pub struct PragmaOverrotation {
     /// The qubits of the gate to overrotate.
     qubits: Vec<usize>,
     /// The amplitude the random number is multiplied by.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            serialize_with = "crate::float_serialization::serialize_float",
+            deserialize_with = "crate::float_serialization::deserialize_float"
+        )
+    )]
     amplitude: f64,
     /// The standard deviation of the normal distribution the random number is drawn from.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            serialize_with = "crate::float_serialization::serialize_float",
+            deserialize_with = "crate::float_serialization::deserialize_float"
+        )
+    )]
     variance: f64,
 }
 
@@ -458,6 +543,10 @@ pub struct PragmaStartDecompositionBlock {
     /// The qubits involved in the decomposition block.
     qubits: Vec<usize>,
     /// The reordering dictionary of the block.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
     reordering_dictionary: HashMap<usize, usize>,
 }
 
@@ -573,6 +662,30 @@ const TAGS_PragmaDamping: &[&str; 6] = &[
     "PragmaDamping",
 ];
 
+impl PragmaDamping {
+    /// Creates a Circuit applying the damping PRAGMA to each of the given qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits the PRAGMA Operations are applied to.
+    /// * `gate_time` - The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware.
+    /// * `rate` - The error rate of the damping (in 1/second).
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit containing one PragmaDamping per qubit.
+    pub fn for_qubits(
+        qubits: &[usize],
+        gate_time: CalculatorFloat,
+        rate: CalculatorFloat,
+    ) -> Circuit {
+        qubits
+            .iter()
+            .map(|qubit| PragmaDamping::new(*qubit, gate_time.clone(), rate.clone()))
+            .collect()
+    }
+}
+
 /// OperatePragmaNoise trait creating necessary functions for a PRAGMA noise Operation.
 impl OperatePragmaNoise for PragmaDamping {
     /// Returns the superoperator matrix of the operation.
@@ -644,6 +757,30 @@ const TAGS_PragmaDepolarising: &[&str; 6] = &[
     "PragmaDepolarising",
 ];
 
+impl PragmaDepolarising {
+    /// Creates a Circuit applying the depolarising PRAGMA to each of the given qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits the PRAGMA Operations are applied to.
+    /// * `gate_time` - The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware.
+    /// * `rate` - The error rate of the depolarising (in 1/second).
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit containing one PragmaDepolarising per qubit.
+    pub fn for_qubits(
+        qubits: &[usize],
+        gate_time: CalculatorFloat,
+        rate: CalculatorFloat,
+    ) -> Circuit {
+        qubits
+            .iter()
+            .map(|qubit| PragmaDepolarising::new(*qubit, gate_time.clone(), rate.clone()))
+            .collect()
+    }
+}
+
 /// OperatePragmaNoise trait creating necessary functions for a PRAGMA noise Operation.
 impl OperatePragmaNoise for PragmaDepolarising {
     /// Returns the superoperator matrix of the operation.
@@ -712,6 +849,30 @@ const TAGS_PragmaDephasing: &[&str; 6] = &[
     "PragmaDephasing",
 ];
 
+impl PragmaDephasing {
+    /// Creates a Circuit applying the dephasing PRAGMA to each of the given qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits the PRAGMA Operations are applied to.
+    /// * `gate_time` - The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware.
+    /// * `rate` - The error rate of the dephasing (in 1/second).
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit containing one PragmaDephasing per qubit.
+    pub fn for_qubits(
+        qubits: &[usize],
+        gate_time: CalculatorFloat,
+        rate: CalculatorFloat,
+    ) -> Circuit {
+        qubits
+            .iter()
+            .map(|qubit| PragmaDephasing::new(*qubit, gate_time.clone(), rate.clone()))
+            .collect()
+    }
+}
+
 /// OperatePragmaNoise trait creating necessary functions for a PRAGMA noise Operation.
 impl OperatePragmaNoise for PragmaDephasing {
     /// Returns the superoperator matrix of the operation.
@@ -786,6 +947,39 @@ const TAGS_PragmaRandomNoise: &[&str; 6] = &[
     "PragmaRandomNoise",
 ];
 
+impl PragmaRandomNoise {
+    /// Creates a Circuit applying the random noise PRAGMA to each of the given qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits the PRAGMA Operations are applied to.
+    /// * `gate_time` - The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware.
+    /// * `depolarising_rate` - The error rate of the depolarisation (in 1/second).
+    /// * `dephasing_rate` - The error rate of the dephasing (in 1/second).
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit containing one PragmaRandomNoise per qubit.
+    pub fn for_qubits(
+        qubits: &[usize],
+        gate_time: CalculatorFloat,
+        depolarising_rate: CalculatorFloat,
+        dephasing_rate: CalculatorFloat,
+    ) -> Circuit {
+        qubits
+            .iter()
+            .map(|qubit| {
+                PragmaRandomNoise::new(
+                    *qubit,
+                    gate_time.clone(),
+                    depolarising_rate.clone(),
+                    dephasing_rate.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
 /// OperatePragmaNoise trait creating necessary functions for a PRAGMA noise Operation.
 impl OperatePragmaNoise for PragmaRandomNoise {
     /// Returns the superoperator matrix of the operation. For the RandomNoise pragma, the superoperator
@@ -892,6 +1086,13 @@ pub struct PragmaGeneralNoise {
     /// The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware
     gate_time: CalculatorFloat,
     /// The rates representing the general noise matrix M (a 3x3 matrix).
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            serialize_with = "crate::float_serialization::serialize_float_array2",
+            deserialize_with = "crate::float_serialization::deserialize_float_array2"
+        )
+    )]
     rates: Array2<f64>,
 }
 
@@ -928,6 +1129,187 @@ const TAGS_PragmaGeneralNoise: &[&str; 5] = &[
     "PragmaGeneralNoise",
 ];
 
+impl PragmaGeneralNoise {
+    /// Creates a PragmaGeneralNoise from the T1 and T2 times of a qubit.
+    ///
+    /// The rate matrix combines amplitude damping with rate 1/T1 in the entry M[0, 0]
+    /// (equivalent to [PragmaDamping]) and pure dephasing with rate (1/T2 - 1/(2 T1)) / 2
+    /// in the entry M[2, 2] (equivalent to [PragmaDephasing]), so that populations decay with T1
+    /// and coherences decay with T2.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the PRAGMA Operation is applied to.
+    /// * `gate_time` - The time the gate takes to be applied to the qubit.
+    /// * `t1` - The relaxation time T1, in the same units as the gate time.
+    /// * `t2` - The coherence time T2, in the same units as the gate time.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new PragmaGeneralNoise.
+    /// * `Err(RoqoqoError::GenericError)` - T1 or T2 are not positive or T2 is larger than 2 T1.
+    pub fn from_t1_t2(
+        qubit: usize,
+        gate_time: CalculatorFloat,
+        t1: f64,
+        t2: f64,
+    ) -> Result<Self, RoqoqoError> {
+        if !(t1 > 0.0 && t2 > 0.0) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("T1 and T2 need to be positive, got T1={} and T2={}", t1, t2),
+            });
+        }
+        if t2 > 2.0 * t1 {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("T2 needs to be <= 2 T1, got T1={} and T2={}", t1, t2),
+            });
+        }
+        let damping_rate = 1.0 / t1;
+        let dephasing_rate = (1.0 / t2 - 0.5 * damping_rate) / 2.0;
+        let mut rates: Array2<f64> = Array2::zeros((3, 3));
+        rates[(0, 0)] = damping_rate;
+        rates[(2, 2)] = dephasing_rate;
+        Ok(Self::new(qubit, gate_time, rates))
+    }
+
+    /// Returns the effective T1 time defined by the diagonal rates.
+    ///
+    /// Populations decay with the sum of the σ+ and σ- rates M[0, 0] + M[1, 1].
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The effective T1 time, infinite if the populations do not decay.
+    pub fn effective_t1(&self) -> f64 {
+        1.0 / (self.rates[(0, 0)] + self.rates[(1, 1)])
+    }
+
+    /// Returns the effective T2 time defined by the diagonal rates.
+    ///
+    /// Coherences decay with half the population decay rate plus twice the σz rate M[2, 2].
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The effective T2 time, infinite if the coherences do not decay.
+    pub fn effective_t2(&self) -> f64 {
+        1.0 / (0.5 * (self.rates[(0, 0)] + self.rates[(1, 1)]) + 2.0 * self.rates[(2, 2)])
+    }
+
+    /// Creates a PragmaGeneralNoise from the single qubit terms of a struqture Lindblad noise operator.
+    ///
+    /// The rate matrix and the [PlusMinusLindbladNoiseOperator] use the same form of the Lindblad equation.
+    /// The basis L0 = σ+, L1 = σ-, L2 = σz of the rate matrix corresponds to the struqture single qubit
+    /// operators [SinglePlusMinusOperator::Plus], [SinglePlusMinusOperator::Minus] and [SinglePlusMinusOperator::Z],
+    /// so the entry M[i, j] is the coefficient of the operator product (Li, Lj) acting on `qubit`.
+    /// Terms of the noise operator not acting on `qubit` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the PRAGMA Operation is applied to.
+    /// * `gate_time` - The time (in seconds) the gate takes to be applied to the qubit on the (simulated) hardware.
+    /// * `operator` - The Lindblad noise operator containing the rates.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new PragmaGeneralNoise.
+    /// * `Err(RoqoqoError::GenericError)` - A term acting on `qubit` also acts on other qubits or its rate is not a real number.
+    pub fn from_lindblad_operator(
+        qubit: usize,
+        gate_time: CalculatorFloat,
+        operator: &PlusMinusLindbladNoiseOperator,
+    ) -> Result<Self, RoqoqoError> {
+        let mut rates: Array2<f64> = Array2::zeros((3, 3));
+        for ((left, right), value) in operator.iter() {
+            let acts_on_qubit =
+                |product: &PlusMinusProduct| product.iter().any(|(q, _)| *q == qubit);
+            if !acts_on_qubit(left) && !acts_on_qubit(right) {
+                continue;
+            }
+            let (left_index, right_index) = match (
+                single_qubit_lindblad_index(left, qubit),
+                single_qubit_lindblad_index(right, qubit),
+            ) {
+                (Some(left_index), Some(right_index)) => (left_index, right_index),
+                _ => {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "Noise term ({}, {}) acts on other qubits than qubit {} and cannot be applied with a single qubit PRAGMA",
+                            left, right, qubit
+                        ),
+                    })
+                }
+            };
+            let rate = match (f64::try_from(value.re.clone()), value.im.clone()) {
+                (Ok(rate), CalculatorFloat::Float(0.0)) => rate,
+                _ => {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!("Rate {} of qubit {} is not a real number", value, qubit),
+                    })
+                }
+            };
+            rates[(left_index, right_index)] += rate;
+        }
+        Ok(Self::new(qubit, gate_time, rates))
+    }
+
+    /// Returns the rates of the PRAGMA as a struqture Lindblad noise operator.
+    ///
+    /// The entry M[i, j] of the rate matrix becomes the coefficient of the operator product (Li, Lj)
+    /// acting on the qubit of the PRAGMA, with L0 = σ+ ([SinglePlusMinusOperator::Plus]),
+    /// L1 = σ- ([SinglePlusMinusOperator::Minus]) and L2 = σz ([SinglePlusMinusOperator::Z]).
+    /// Entries equal to zero are omitted. The gate time is not part of the noise operator.
+    ///
+    /// # Returns
+    ///
+    /// * `PlusMinusLindbladNoiseOperator` - The Lindblad noise operator with the rates of the PRAGMA.
+    pub fn to_lindblad_operator(&self) -> PlusMinusLindbladNoiseOperator {
+        let products = [
+            PlusMinusProduct::new().plus(self.qubit),
+            PlusMinusProduct::new().minus(self.qubit),
+            PlusMinusProduct::new().z(self.qubit),
+        ];
+        let mut operator = PlusMinusLindbladNoiseOperator::new();
+        for ((left_index, right_index), rate) in self.rates.indexed_iter() {
+            if *rate != 0.0 {
+                operator
+                    .add_operator_product(
+                        (products[left_index].clone(), products[right_index].clone()),
+                        (*rate).into(),
+                    )
+                    .expect("Internal struqture bug.");
+            }
+        }
+        operator
+    }
+
+    /// Creates a Circuit applying the general noise PRAGMA to each of the given qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits the PRAGMA Operations are applied to.
+    /// * `gate_time` - The time (in seconds) the gate takes to be applied to the qubits on the (simulated) hardware.
+    /// * `rates` - The rates representing the general noise matrix M (a 3x3 matrix).
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The Circuit containing one PragmaGeneralNoise per qubit.
+    pub fn for_qubits(qubits: &[usize], gate_time: CalculatorFloat, rates: Array2<f64>) -> Circuit {
+        qubits
+            .iter()
+            .map(|qubit| PragmaGeneralNoise::new(*qubit, gate_time.clone(), rates.clone()))
+            .collect()
+    }
+}
+
+// Returns the index of the Lindblad operator of a product acting only on the given qubit.
+fn single_qubit_lindblad_index(product: &PlusMinusProduct, qubit: usize) -> Option<usize> {
+    match product.iter().as_slice() {
+        [(q, SinglePlusMinusOperator::Plus)] if *q == qubit => Some(0),
+        [(q, SinglePlusMinusOperator::Minus)] if *q == qubit => Some(1),
+        [(q, SinglePlusMinusOperator::Z)] if *q == qubit => Some(2),
+        _ => None,
+    }
+}
+
 // Collection of superoperators that appear in the Lindblad equation for a single qubit/spin with
 // a basis of the form 0: sigma+ 1:sigma- 2: sigmaz
 const PGN_SUPEROP: [[Matrix4<f64>; 3]; 3] = [
@@ -1038,14 +1420,7 @@ impl OperatePragmaNoise for PragmaGeneralNoise {
 ///
 /// This PRAGMA executes a circuit when the condition bit/bool stored in a [crate::registers::BitRegister] is true.
 ///
-#[derive(
-    Debug,
-    Clone,
-    PartialEq,
-    roqoqo_derive::SupportedVersion,
-    roqoqo_derive::Operate,
-    roqoqo_derive::OperatePragma,
-)]
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaConditional {
@@ -1057,6 +1432,15 @@ pub struct PragmaConditional {
     circuit: Circuit,
 }
 
+impl SupportedVersion for PragmaConditional {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        let mut current_minimum_version = (1, 0, 0);
+        let comparison_version = self.circuit.minimum_supported_roqoqo_version();
+        crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+        current_minimum_version
+    }
+}
+
 #[allow(non_upper_case_globals)]
 const TAGS_PragmaConditional: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaConditional"];
 
@@ -1067,10 +1451,17 @@ impl InvolveQubits for PragmaConditional {
         self.circuit.involved_qubits()
     }
 
-    fn involved_classical(&self) -> super::InvolvedClassical {
-        let mut new_set: HashSet<(String, usize)> = HashSet::new();
-        new_set.insert((self.condition_register.clone(), self.condition_index));
-        super::InvolvedClassical::Set(new_set)
+    /// Lists the condition entry and all classical entries involved in the circuit.
+    fn involved_classical(&self) -> InvolvedClassical {
+        let condition = (self.condition_register.clone(), self.condition_index);
+        match self.circuit.involved_classical() {
+            InvolvedClassical::None => InvolvedClassical::Set(HashSet::from([condition])),
+            InvolvedClassical::Set(mut x) => {
+                x.insert(condition);
+                InvolvedClassical::Set(x)
+            }
+            involved => involved,
+        }
     }
 }
 
@@ -1212,6 +1603,16 @@ impl Operate for PragmaChangeDevice {
     }
 }
 impl PragmaChangeDevice {
+    /// Creates a new PragmaChangeDevice wrapping a device specific PRAGMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `wrapped_pragma` - The device specific PRAGMA that is wrapped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PragmaChangeDevice wrapping the serialized PRAGMA.
+    /// * `Err(RoqoqoError::SerializationError)` - The PRAGMA could not be serialized.
     #[cfg(feature = "serialize")]
     pub fn new<T>(wrapped_pragma: &T) -> Result<Self, RoqoqoError>
     where
@@ -1232,6 +1633,46 @@ impl PragmaChangeDevice {
             })?,
         })
     }
+
+    /// Creates a new PragmaChangeDevice from a device specific PRAGMA.
+    ///
+    /// The hqslang name and tags are taken from the wrapped operation so they always match the payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The device specific PRAGMA that is wrapped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PragmaChangeDevice wrapping the serialized PRAGMA.
+    /// * `Err(RoqoqoError::SerializationError)` - The PRAGMA could not be serialized.
+    #[cfg(feature = "serialize")]
+    pub fn from_operation<T>(operation: &T) -> Result<Self, RoqoqoError>
+    where
+        T: Operate,
+        T: Serialize,
+    {
+        Self::new(operation)
+    }
+
+    /// Deserializes the wrapped PRAGMA into a typed operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The deserialized PRAGMA.
+    /// * `Err(RoqoqoError::ChangeDeviceDeserializationError)` - The payload could not be deserialized into `T`.
+    #[cfg(feature = "serialize")]
+    pub fn try_deserialize<T>(&self) -> Result<T, RoqoqoError>
+    where
+        T: DeserializeOwned,
+    {
+        deserialize(&self.wrapped_operation[..]).map_err(|err| {
+            RoqoqoError::ChangeDeviceDeserializationError {
+                hqslang: self.wrapped_hqslang.clone(),
+                msg: format!("{:?}", err),
+            }
+        })
+    }
 }
 #[allow(non_upper_case_globals)]
 const TAGS_PragmaChangeDevice: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaChangeDevice"];
@@ -1279,7 +1720,10 @@ impl super::ImplementedIn1point1 for PragmaLoop {}
 
 impl SupportedVersion for PragmaLoop {
     fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
-        (1, 1, 0)
+        let mut current_minimum_version = (1, 1, 0);
+        let comparison_version = self.circuit.minimum_supported_roqoqo_version();
+        crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+        current_minimum_version
     }
 }
 
@@ -1309,35 +1753,9 @@ impl InvolveQubits for PragmaLoop {
         self.circuit.involved_qubits()
     }
 
+    /// Lists all classical entries involved in the circuit.
     fn involved_classical(&self) -> InvolvedClassical {
-        let mut involved = InvolvedClassical::None;
-        for op in self.circuit.iter() {
-            let tmp_involved = op.involved_classical();
-            match &tmp_involved {
-                InvolvedClassical::All(x) => {
-                    return InvolvedClassical::All(x.clone());
-                }
-                InvolvedClassical::AllQubits(x) => {
-                    return InvolvedClassical::AllQubits(x.clone());
-                }
-                InvolvedClassical::None => (),
-                InvolvedClassical::Set(x) => match involved {
-                    InvolvedClassical::All(y) => {
-                        return InvolvedClassical::All(y);
-                    }
-                    InvolvedClassical::AllQubits(y) => {
-                        return InvolvedClassical::AllQubits(y);
-                    }
-                    InvolvedClassical::None => involved = tmp_involved,
-                    InvolvedClassical::Set(y) => {
-                        let mut combined = x.clone();
-                        combined.extend(y);
-                        involved = InvolvedClassical::Set(combined)
-                    }
-                },
-            }
-        }
-        involved
+        self.circuit.involved_classical()
     }
 }
 
@@ -1385,7 +1803,10 @@ impl super::ImplementedIn1point8 for PragmaAnnotatedOp {}
 
 impl SupportedVersion for PragmaAnnotatedOp {
     fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
-        (1, 8, 0)
+        let mut current_minimum_version = (1, 8, 0);
+        let comparison_version = self.operation.minimum_supported_roqoqo_version();
+        crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+        current_minimum_version
     }
 }
 
@@ -1492,3 +1913,460 @@ impl SupportedVersion for PragmaSimulationRepetitions {
         (1, 17, 0)
     }
 }
+
+/// This PRAGMA Operation instructs simulation backends to emulate the shot noise of a finite number of measurements.
+///
+/// Instead of writing exact values, simulation backends sample `number_shots` measurement records
+/// of the bit register `readout` from the exact probability distribution of the quantum register.
+/// Setting the seed makes the sampled records reproducible.
+/// Backends can use [crate::measurements::sample_bit_register] for the sampling.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperatePragma,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSimulateShotNoise {
+    /// The name of the classical bit register the sampled measurement records are written to.
+    readout: String,
+    /// The number of sampled measurement records.
+    number_shots: usize,
+    /// The seed of the random number generator, the sampling is not reproducible when None.
+    seed: Option<u64>,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSimulateShotNoise: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaSimulateShotNoise"];
+
+// Implementing the InvolveQubits trait for PragmaSimulateShotNoise.
+impl InvolveQubits for PragmaSimulateShotNoise {
+    /// Lists all involved qubits (here, all).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+
+    /// Lists the involved classical registers (here, one entry per qubit of the readout register).
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::AllQubits(self.readout.clone())
+    }
+}
+
+impl super::ImplementedIn1point18 for PragmaSimulateShotNoise {}
+
+impl SupportedVersion for PragmaSimulateShotNoise {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// This PRAGMA Operation sets the gate time of a gate on specific qubits for the device of a circuit.
+///
+/// Pulse-level experiments can use it to override the timing of the device, for example to treat
+/// a CNOT on qubits 0 and 1 as taking 300 ns. The PRAGMA does not act on the quantum state.
+/// It is applied to a device by wrapping it in a [PragmaChangeDevice], see
+/// [crate::devices::GenericDevice] for a device consuming it.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperatePragma,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSetGateTime {
+    /// The hqslang name of the gate.
+    gate_hqslang: String,
+    /// The qubits the gate acts on, in the order of the qubit arguments of the gate.
+    qubits: Vec<usize>,
+    /// The gate time of the gate on the qubits.
+    gate_time: CalculatorFloat,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSetGateTime: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSetGateTime"];
+
+impl PragmaSetGateTime {
+    /// Returns the qubits the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `&Vec<usize>` - The qubits in the order of the qubit arguments of the gate.
+    pub fn qubits(&self) -> &Vec<usize> {
+        &self.qubits
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaSetGateTime.
+impl InvolveQubits for PragmaSetGateTime {
+    /// Lists all involved qubits (here, none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+}
+
+impl super::ImplementedIn1point18 for PragmaSetGateTime {}
+
+impl SupportedVersion for PragmaSetGateTime {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// This PRAGMA closes a parallel block with individual durations of the qubits.
+///
+/// It is an alternative to [PragmaStopParallelBlock] for blocks in which the qubits are busy for
+/// different times, for example when operations of different latency are executed in parallel.
+/// The PRAGMA does not act on the quantum state. Noise models can use the durations to insert the
+/// decoherence of the idling qubits, see [PragmaParallelBlockTiming::idle_noise_circuit].
+///
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaParallelBlockTiming {
+    /// The durations (in seconds) of the parallel block for each involved qubit.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_map")
+    )]
+    qubit_durations: HashMap<usize, CalculatorFloat>,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaParallelBlockTiming: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaParallelBlockTiming"];
+
+impl PragmaParallelBlockTiming {
+    /// Creates a new instance of `PragmaParallelBlockTiming`.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit_durations` - The durations (in seconds) of the parallel block for each involved qubit.
+    pub fn new(qubit_durations: HashMap<usize, CalculatorFloat>) -> Self {
+        Self { qubit_durations }
+    }
+
+    /// Returns the value of the field `qubit_durations`.
+    #[inline]
+    pub fn qubit_durations(&self) -> &HashMap<usize, CalculatorFloat> {
+        &self.qubit_durations
+    }
+
+    /// Creates the Circuit applying the idle decoherence of a noise model for the durations of the qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The noise model containing the decoherence rates.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The Circuit containing one PragmaGeneralNoise per noisy qubit, ordered by qubit.
+    /// * `Err(RoqoqoError::GenericError)` - The model contains noise that cannot be applied with single qubit PRAGMAs.
+    pub fn idle_noise_circuit(
+        &self,
+        model: &crate::noise_models::ContinuousDecoherenceModel,
+    ) -> Result<Circuit, RoqoqoError> {
+        let mut qubits: Vec<&usize> = self.qubit_durations.keys().collect();
+        qubits.sort();
+        let mut circuit = Circuit::new();
+        for qubit in qubits {
+            circuit += crate::noise_models::idle_noise_circuit(
+                &[*qubit],
+                self.qubit_durations[qubit].clone(),
+                model,
+            )?;
+        }
+        Ok(circuit)
+    }
+}
+
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for PragmaParallelBlockTiming {
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_PragmaParallelBlockTiming
+    }
+
+    fn hqslang(&self) -> &'static str {
+        "PragmaParallelBlockTiming"
+    }
+
+    fn is_parametrized(&self) -> bool {
+        self.qubit_durations
+            .values()
+            .any(|duration| !duration.is_float())
+    }
+}
+
+impl OperatePragma for PragmaParallelBlockTiming {}
+
+// Implementing the InvolveQubits trait for PragmaParallelBlockTiming.
+impl InvolveQubits for PragmaParallelBlockTiming {
+    /// Lists all involved qubits (the qubits with a duration).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::Set(self.qubit_durations.keys().copied().collect())
+    }
+}
+
+impl Substitute for PragmaParallelBlockTiming {
+    /// Substitutes symbolic parameters in the durations of the PragmaParallelBlockTiming.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let mut new_durations: HashMap<usize, CalculatorFloat> =
+            HashMap::with_capacity(self.qubit_durations.len());
+        for (qubit, duration) in self.qubit_durations.iter() {
+            new_durations.insert(
+                *qubit,
+                CalculatorFloat::from(
+                    calculator
+                        .parse_get(duration.clone())
+                        .map_err(RoqoqoError::CalculatorError)?,
+                ),
+            );
+        }
+        Ok(Self::new(new_durations))
+    }
+
+    /// Remaps qubits in the durations of the PragmaParallelBlockTiming.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_durations: HashMap<usize, CalculatorFloat> = self
+            .qubit_durations
+            .iter()
+            .map(|(qubit, duration)| (*mapping.get(qubit).unwrap_or(qubit), duration.clone()))
+            .collect();
+        Ok(Self::new(new_durations))
+    }
+}
+
+impl super::ImplementedIn1point18 for PragmaParallelBlockTiming {}
+
+impl SupportedVersion for PragmaParallelBlockTiming {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// This PRAGMA repeats a circuit while a classical condition bit is true.
+///
+/// The circuit is executed once and then repeated as long as the condition bit stored in a
+/// [crate::registers::BitRegister] is true after the execution, at most `max_repetitions` times in total.
+/// A repeat-until-success protocol writes a failure flag to the condition bit in the circuit.
+///
+/// Backends that cannot execute the PRAGMA natively can detect it by its `PragmaConditionalLoop` tag
+/// and lower it to nested [PragmaConditional] operations with [PragmaConditionalLoop::unroll].
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaConditionalLoop {
+    /// The name of the [crate::registers::BitRegister] containting the condition bool value.
+    condition_register: String,
+    /// The index in the [crate::registers::BitRegister] containting the condition bool value.
+    condition_index: usize,
+    /// The maximal number of executions of the circuit.
+    max_repetitions: usize,
+    /// The circuit that is repeated.
+    circuit: Circuit,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaConditionalLoop: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaConditionalLoop"];
+
+impl PragmaConditionalLoop {
+    /// Lowers the PRAGMA to nested [PragmaConditional] operations.
+    ///
+    /// The circuit is executed once unconditionally, every further repetition is wrapped in a
+    /// PragmaConditional on the condition bit inside the previous one.
+    /// For `max_repetitions` equal to 0 the returned circuit is empty.
+    ///
+    /// # Returns
+    ///
+    /// * `Circuit` - The unrolled circuit.
+    pub fn unroll(&self) -> Circuit {
+        let mut unrolled = Circuit::new();
+        for repetition in 0..self.max_repetitions {
+            let mut outer = self.circuit.clone();
+            if repetition > 0 {
+                outer += PragmaConditional::new(
+                    self.condition_register.clone(),
+                    self.condition_index,
+                    unrolled,
+                );
+            }
+            unrolled = outer;
+        }
+        unrolled
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaConditionalLoop {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        let new_circuit = self.circuit.remap_qubits(mapping)?;
+        Ok(PragmaConditionalLoop::new(
+            self.condition_register.clone(),
+            self.condition_index,
+            self.max_repetitions,
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
+        Ok(PragmaConditionalLoop::new(
+            self.condition_register.clone(),
+            self.condition_index,
+            self.max_repetitions,
+            new_circuit,
+        ))
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaConditionalLoop.
+impl InvolveQubits for PragmaConditionalLoop {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        self.circuit.involved_qubits()
+    }
+
+    /// Lists the condition entry and all classical entries involved in the circuit.
+    fn involved_classical(&self) -> InvolvedClassical {
+        let condition = (self.condition_register.clone(), self.condition_index);
+        match self.circuit.involved_classical() {
+            InvolvedClassical::None => InvolvedClassical::Set(HashSet::from([condition])),
+            InvolvedClassical::Set(mut x) => {
+                x.insert(condition);
+                InvolvedClassical::Set(x)
+            }
+            involved => involved,
+        }
+    }
+}
+
+impl super::ImplementedIn1point18 for PragmaConditionalLoop {}
+
+impl SupportedVersion for PragmaConditionalLoop {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        if self.circuit.minimum_supported_roqoqo_version() > (1, 18, 0) {
+            return self.circuit.minimum_supported_roqoqo_version();
+        }
+        (1, 18, 0)
+    }
+}
+
+/// This PRAGMA Operation takes a snapshot of classical registers for debugging.
+///
+/// Evaluating backends that encounter the PRAGMA copy the current content of each register in
+/// `readout_names` into an output register of the same type named `"{label}_{name}"`.
+/// Backends without native support can run circuits containing the PRAGMA with
+/// [crate::backends::EvaluatingBackend::run_circuit_with_snapshots].
+///
+/// The PRAGMA involves all qubits, so that it is not reordered with the operations
+/// writing to the registers before or after it.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperatePragma,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSnapshotRegisters {
+    /// The names of the classical registers that are copied.
+    readout_names: Vec<String>,
+    /// The label prefixed to the names of the snapshot registers.
+    label: String,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSnapshotRegisters: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaSnapshotRegisters"];
+
+impl PragmaSnapshotRegisters {
+    /// Returns the name of the snapshot register of a copied register.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout_name` - The name of the copied register.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The name `"{label}_{readout_name}"`.
+    pub fn snapshot_name(&self, readout_name: &str) -> String {
+        format!("{}_{}", self.label, readout_name)
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaSnapshotRegisters.
+impl InvolveQubits for PragmaSnapshotRegisters {
+    /// Lists all involved qubits (here, all).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+impl super::ImplementedIn1point18 for PragmaSnapshotRegisters {}
+
+impl SupportedVersion for PragmaSnapshotRegisters {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+/// This PRAGMA Operation annotates the circuit with a key-value pair of metadata.
+///
+/// The annotation records provenance information like the compiler version or the applied passes
+/// and survives serialization. It has no effect on the quantum state and involves no qubits.
+/// The annotations of a circuit are collected by [crate::Circuit::annotations].
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperatePragma,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaAnnotation {
+    /// The key of the annotation.
+    key: String,
+    /// The value of the annotation.
+    value: String,
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaAnnotation: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaAnnotation"];
+
+// Implementing the InvolveQubits trait for PragmaAnnotation.
+impl InvolveQubits for PragmaAnnotation {
+    /// Lists all involved qubits (here, none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+}
+
+impl super::ImplementedIn1point18 for PragmaAnnotation {}
+
+impl SupportedVersion for PragmaAnnotation {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
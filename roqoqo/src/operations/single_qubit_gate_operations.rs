@@ -24,6 +24,21 @@ use crate::RoqoqoError;
 #[cfg(feature = "overrotate")]
 use rand_distr::{Distribution, Normal};
 
+/// Native gate sets used to decompose single-qubit gates.
+///
+/// Every decomposition is exact including the global phase, which is added as a [PragmaGlobalPhase].
+/// The rotations are listed in the order in which they are applied.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SingleQubitBasis {
+    /// RotateZ, RotateY, RotateZ Euler decomposition.
+    ZYZ,
+    /// RotateZ, RotateX, RotateZ Euler decomposition.
+    ZXZ,
+    /// RotateZ, SqrtPauliX, RotateZ, SqrtPauliX, RotateZ decomposition used by transmon hardware.
+    ZSX,
+}
+
 /// The most general unitary operation acting on one qubit.
 ///
 /// # Warning
@@ -155,6 +170,72 @@ impl OperateSingleQubitGate for SingleQubitGate {
     }
 }
 
+impl SingleQubitGate {
+    /// Creates a SingleQubitGate from its unitary matrix.
+    ///
+    /// The matrix is decomposed as e^{iφ} [[α, -β*], [β, α*]] with |α|^2 + |β|^2 = 1.
+    /// The global phase φ is chosen as half the argument of the determinant, in the interval (-π/2, π/2].
+    /// As φ is only defined up to π, `from_unitary_matrix(gate.unitary_matrix())` returns a gate
+    /// with the same unitary matrix as `gate`, with α, β and φ possibly shifted by the sign convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The 2x2 unitary matrix of the gate.
+    /// * `qubit` - The qubit the unitary gate is applied to.
+    /// * `tolerance` - The tolerance of the unitarity check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SingleQubitGate with the given unitary matrix.
+    /// * `Err(RoqoqoError::GenericError)` - The matrix is not a 2x2 matrix.
+    /// * `Err(RoqoqoError::UnitaryMatrixErrror)` - The matrix is not unitary within the tolerance.
+    pub fn from_unitary_matrix(
+        matrix: &Array2<Complex64>,
+        qubit: usize,
+        tolerance: f64,
+    ) -> Result<Self, RoqoqoError> {
+        if matrix.shape() != [2, 2] {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Unitary matrix of a SingleQubitGate needs to be of shape [2, 2], got {:?}",
+                    matrix.shape()
+                ),
+            });
+        }
+        let determinant = matrix[(0, 0)] * matrix[(1, 1)] - matrix[(0, 1)] * matrix[(1, 0)];
+        let global_phase = determinant.arg() / 2.0;
+        let pref = Complex64::new(0.0, -global_phase).exp();
+        let alpha = pref * matrix[(0, 0)];
+        let beta = pref * matrix[(1, 0)];
+        let norm = alpha.norm_sqr() + beta.norm_sqr();
+        let deviation = matrix
+            .dot(&matrix.t().mapv(|x| x.conj()))
+            .indexed_iter()
+            .map(|((row, column), value)| {
+                let identity = if row == column { 1.0 } else { 0.0 };
+                (value - identity).norm()
+            })
+            .fold(0.0, f64::max);
+        if deviation > tolerance || (norm - 1.0).abs() > tolerance {
+            return Err(RoqoqoError::UnitaryMatrixErrror {
+                alpha_r: alpha.re,
+                alpha_i: alpha.im,
+                beta_r: beta.re,
+                beta_i: beta.im,
+                norm,
+            });
+        }
+        Ok(Self::new(
+            qubit,
+            alpha.re.into(),
+            alpha.im.into(),
+            beta.re.into(),
+            beta.im.into(),
+            global_phase.into(),
+        ))
+    }
+}
+
 /// The ZPower gate exp(-i * θ/2 * σ^z).
 ///
 #[derive(
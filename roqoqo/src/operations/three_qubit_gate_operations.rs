@@ -10,8 +10,9 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::two_qubit_gate_operations::cnot_to_basis_circuit;
 use super::{ControlledPhaseShift, Hadamard, PhaseShiftedControlledPhase, CNOT};
-use super::{RotateZ, TGate};
+use super::{Operation, PhaseShiftState1, RotateZ, TGate, TwoQubitBasis};
 use crate::prelude::*;
 use crate::Circuit;
 use ndarray::{array, Array2};
@@ -22,6 +23,58 @@ use qoqo_calculator::CalculatorFloat;
 use rand_distr::{Distribution, Normal};
 use std::f64::consts::PI;
 
+/// Replaces the two-qubit gates of a three-qubit gate decomposition by entangling gates of the basis.
+///
+/// ControlledPhaseShift and PhaseShiftedControlledPhase gates are first decomposed into two CNOT gates
+/// and PhaseShiftState1 gates, every CNOT gate is then replaced by the entangling gates of the basis.
+/// All replacements are exact including the global phase.
+fn circuit_to_basis(circuit: Circuit, basis: TwoQubitBasis) -> Circuit {
+    let mut basis_circuit = Circuit::new();
+    for operation in circuit.iter() {
+        match operation {
+            Operation::CNOT(cnot) => {
+                basis_circuit += cnot_to_basis_circuit(*cnot.control(), *cnot.target(), basis)
+            }
+            Operation::ControlledPhaseShift(cphase) => {
+                basis_circuit += controlled_phase_to_basis_circuit(
+                    *cphase.control(),
+                    *cphase.target(),
+                    cphase.theta().clone(),
+                    basis,
+                )
+            }
+            Operation::PhaseShiftedControlledPhase(cphase) => {
+                basis_circuit += PhaseShiftState1::new(*cphase.control(), cphase.phi().clone());
+                basis_circuit += PhaseShiftState1::new(*cphase.target(), cphase.phi().clone());
+                basis_circuit += controlled_phase_to_basis_circuit(
+                    *cphase.control(),
+                    *cphase.target(),
+                    cphase.theta().clone(),
+                    basis,
+                )
+            }
+            _ => basis_circuit.add_operation(operation.clone()),
+        }
+    }
+    basis_circuit
+}
+
+/// Returns the circuit of ControlledPhaseShift(control, target, θ) with the entangling gates of the basis.
+fn controlled_phase_to_basis_circuit(
+    control: usize,
+    target: usize,
+    theta: CalculatorFloat,
+    basis: TwoQubitBasis,
+) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += PhaseShiftState1::new(control, theta.clone() / 2.0);
+    circuit += PhaseShiftState1::new(target, theta.clone() / 2.0);
+    circuit += cnot_to_basis_circuit(control, target, basis);
+    circuit += PhaseShiftState1::new(target, -theta / 2.0);
+    circuit += cnot_to_basis_circuit(control, target, basis);
+    circuit
+}
+
 /// Implements the double-controlled PauliZ gate.
 ///
 /// The double-controlled PauliZ applies a PauliZ unitary to the `target` qubit
@@ -171,6 +224,10 @@ impl OperateThreeQubitGate for ControlledControlledPauliZ {
             ControlledPhaseShift::new(self.control_0, self.target, CalculatorFloat::FRAC_PI_2);
         circuit
     }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        circuit_to_basis(self.circuit(), basis)
+    }
 }
 
 /// Implements the double-controlled PhaseShift gate.
@@ -325,6 +382,10 @@ impl OperateThreeQubitGate for ControlledControlledPhaseShift {
         circuit += ControlledPhaseShift::new(self.control_0, self.target, self.theta.clone() / 2.0);
         circuit
     }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        circuit_to_basis(self.circuit(), basis)
+    }
 }
 
 /// Implements the Toffoli gate.
@@ -483,6 +544,10 @@ impl OperateThreeQubitGate for Toffoli {
         circuit += CNOT::new(self.control_0, self.control_1);
         circuit
     }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        circuit_to_basis(self.circuit(), basis)
+    }
 }
 
 /// Implements the controlled SWAP gate.
@@ -637,6 +702,10 @@ impl OperateThreeQubitGate for ControlledSWAP {
         circuit += CNOT::new(self.target_1, self.target_0);
         circuit
     }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        circuit_to_basis(self.circuit(), basis)
+    }
 }
 
 impl OperateThreeQubit for ControlledSWAP {
@@ -687,6 +756,128 @@ impl InvolveQubits for ControlledSWAP {
     }
 }
 
+/// Implements the Fredkin gate.
+///
+/// The Fredkin gate is the textbook name of the [ControlledSWAP] gate and has the same unitary matrix.
+/// It swaps the `target_0` and `target_1` qubits when the `control` qubit is in state 1.
+/// The two gates can be converted into each other with [From].
+///
+/// NOTE: for compatibility reasons, the OperateThreeQubit trait is implemented, but
+/// the "control" qubit of the operation can be accessed via the "control_0()" method,
+/// the "target_0" qubit of the operation can be accessed via the "control_1()" method and
+/// the "target_1" qubit of the operation can be accessed via the "target()" method.
+#[derive(Debug, Clone, PartialEq, Eq, roqoqo_derive::Operate)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct Fredkin {
+    /// The index of the most significant qubit in the unitary representation. Here, the controlling qubit of the operation.
+    control: usize,
+    /// The index of the second most significant qubit in the unitary representation. Here, the first targeting qubit of the operation.
+    target_0: usize,
+    /// The index of the least significant qubit in the unitary representation. Here, the second targeting qubit of the operation.
+    target_1: usize,
+}
+
+impl super::ImplementedIn1point18 for Fredkin {}
+
+impl SupportedVersion for Fredkin {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 18, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_Fredkin: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "ThreeQubitGateOperation",
+    "Fredkin",
+];
+
+impl From<ControlledSWAP> for Fredkin {
+    fn from(value: ControlledSWAP) -> Self {
+        Self::new(value.control, value.target_0, value.target_1)
+    }
+}
+
+impl From<Fredkin> for ControlledSWAP {
+    fn from(value: Fredkin) -> Self {
+        Self::new(value.control, value.target_0, value.target_1)
+    }
+}
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for Fredkin {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed (here, not possible).
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        ControlledSWAP::from(self.clone()).unitary_matrix()
+    }
+}
+
+/// Trait for all gate operations acting on exactly three qubits.
+impl OperateThreeQubitGate for Fredkin {
+    fn circuit(&self) -> Circuit {
+        ControlledSWAP::from(self.clone()).circuit()
+    }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        ControlledSWAP::from(self.clone()).circuit_with_basis(basis)
+    }
+}
+
+impl OperateThreeQubit for Fredkin {
+    /// Returns `target_1` qubit of the three qubit Operation.
+    fn target(&self) -> &usize {
+        &self.target_1
+    }
+
+    /// Returns `control` qubit of the three qubit Operation.
+    fn control_0(&self) -> &usize {
+        &self.control
+    }
+
+    /// Returns `target_0` qubit of the three qubit Operation.
+    fn control_1(&self) -> &usize {
+        &self.target_0
+    }
+}
+
+impl Substitute for Fredkin {
+    fn substitute_parameters(
+        &self,
+        _calculator: &qoqo_calculator::Calculator,
+    ) -> Result<Self, RoqoqoError> {
+        Ok(Self::new(self.control, self.target_0, self.target_1))
+    }
+
+    fn remap_qubits(
+        &self,
+        mapping: &std::collections::HashMap<usize, usize>,
+    ) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        Ok(Self::new(
+            *mapping.get(&self.control).unwrap_or(&self.control),
+            *mapping.get(&self.target_0).unwrap_or(&self.target_0),
+            *mapping.get(&self.target_1).unwrap_or(&self.target_1),
+        ))
+    }
+}
+
+impl InvolveQubits for Fredkin {
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut new_hash_set: std::collections::HashSet<usize> = std::collections::HashSet::new();
+        new_hash_set.insert(self.control);
+        new_hash_set.insert(self.target_0);
+        new_hash_set.insert(self.target_1);
+        InvolvedQubits::Set(new_hash_set)
+    }
+}
+
 /// Implements the double-controlled phase-shifted PauliZ gate.
 ///
 #[derive(
@@ -855,6 +1046,17 @@ impl OperateThreeQubitGate for PhaseShiftedControlledControlledZ {
         );
         circuit
     }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        // The gate is the double-controlled gate followed by a phase shift φ on every qubit
+        let mut circuit =
+            ControlledControlledPauliZ::new(self.control_0, self.control_1, self.target)
+                .circuit_with_basis(basis);
+        circuit += PhaseShiftState1::new(self.control_0, self.phi.clone());
+        circuit += PhaseShiftState1::new(self.control_1, self.phi.clone());
+        circuit += PhaseShiftState1::new(self.target, self.phi.clone());
+        circuit
+    }
 }
 
 /// Implements the double-controlled phase-shifted PhaseShift gate.
@@ -1029,4 +1231,19 @@ impl OperateThreeQubitGate for PhaseShiftedControlledControlledPhase {
         );
         circuit
     }
+
+    fn circuit_with_basis(&self, basis: TwoQubitBasis) -> Circuit {
+        // The gate is the double-controlled gate followed by a phase shift φ on every qubit
+        let mut circuit = ControlledControlledPhaseShift::new(
+            self.control_0,
+            self.control_1,
+            self.target,
+            self.theta.clone(),
+        )
+        .circuit_with_basis(basis);
+        circuit += PhaseShiftState1::new(self.control_0, self.phi.clone());
+        circuit += PhaseShiftState1::new(self.control_1, self.phi.clone());
+        circuit += PhaseShiftState1::new(self.target, self.phi.clone());
+        circuit
+    }
 }
@@ -11,16 +11,20 @@
 // limitations under the License.
 
 use crate::operations::single_qubit_gate_operations::*;
+use crate::operations::{
+    Operation, PragmaGlobalPhase, SingleQubitGateOperation, TwoQubitGateOperation,
+};
 use crate::prelude::*;
 use crate::Circuit;
 use crate::RoqoqoError;
 use ndarray::{array, Array2};
 use num_complex::Complex64;
-use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use qoqo_calculator::{Calculator, CalculatorComplex, CalculatorFloat};
 #[cfg(feature = "overrotate")]
 use rand_distr::{Distribution, Normal};
+use std::collections::HashMap;
 use std::convert::TryFrom;
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
 use super::SupportedVersion;
 
@@ -46,6 +50,250 @@ pub struct KakDecomposition {
     pub circuit_after: Option<Circuit>,
 }
 
+/// Entangling gates used to decompose two-qubit gates.
+///
+/// See [OperateTwoQubitGate::to_basis_circuit] and [crate::operations::OperateThreeQubitGate::circuit_with_basis].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoQubitBasis {
+    /// Decomposition using at most three CNOT gates.
+    CNOT,
+    /// Decomposition using at most three ControlledPauliZ gates.
+    ControlledPauliZ,
+    /// Decomposition using at most six ISwap gates, two for every CNOT of the CNOT decomposition.
+    ISwap,
+}
+
+/// Tolerance below which a component of the k-vector is treated as zero.
+const KAK_TOLERANCE: f64 = 1e-10;
+
+/// Decomposes a two-qubit gate into entangling gates of the basis and single-qubit gates.
+///
+/// The gate is given by its KAK decomposition U = e^{iφ} A exp(i (k_0 XX + k_1 YY + k_2 ZZ)) B.
+/// The canonical part is implemented with zero to three CNOT gates depending on the k-vector,
+/// the CNOT gates are replaced by the entangling gates of the basis and all single-qubit gates
+/// between entangling gates are merged into SingleQubitGates.
+/// The global phase is added as a PragmaGlobalPhase, so the decomposition reproduces the unitary exactly.
+pub(crate) fn kak_to_basis_circuit(
+    kak: &KakDecomposition,
+    control: usize,
+    target: usize,
+    basis: TwoQubitBasis,
+) -> Result<Circuit, RoqoqoError> {
+    let k_vector = [
+        f64::try_from(kak.k_vector[0].clone())?,
+        f64::try_from(kak.k_vector[1].clone())?,
+        f64::try_from(kak.k_vector[2].clone())?,
+    ];
+    let mut circuit = Circuit::new();
+    circuit += PragmaGlobalPhase::new(kak.global_phase.clone());
+    if let Some(circuit_before) = &kak.circuit_before {
+        circuit += circuit_before.clone();
+    }
+    circuit += canonical_cnot_circuit(control, target, k_vector);
+    if let Some(circuit_after) = &kak.circuit_after {
+        circuit += circuit_after.clone();
+    }
+
+    let mut basis_circuit = Circuit::new();
+    for operation in circuit.iter() {
+        match operation {
+            Operation::CNOT(cnot) => {
+                basis_circuit += cnot_to_basis_circuit(cnot.control, cnot.target, basis)
+            }
+            _ => basis_circuit.add_operation(operation.clone()),
+        }
+    }
+    merge_single_qubit_gates(&basis_circuit, control, target)
+}
+
+/// Returns the circuit implementing a CNOT gate with the entangling gates of the basis.
+///
+/// The replacement reproduces the unitary matrix of the CNOT exactly, including the global phase.
+pub(crate) fn cnot_to_basis_circuit(
+    control: usize,
+    target: usize,
+    basis: TwoQubitBasis,
+) -> Circuit {
+    let mut circuit = Circuit::new();
+    match basis {
+        TwoQubitBasis::CNOT => circuit += CNOT::new(control, target),
+        TwoQubitBasis::ControlledPauliZ => {
+            circuit += Hadamard::new(target);
+            circuit += ControlledPauliZ::new(control, target);
+            circuit += Hadamard::new(target);
+        }
+        TwoQubitBasis::ISwap => {
+            circuit += SGate::new(target);
+            circuit += ISwap::new(control, target);
+            circuit += RotateX::new(control, CalculatorFloat::FRAC_PI_2);
+            circuit += ISwap::new(control, target);
+            circuit += Hadamard::new(target);
+            circuit += InvSGate::new(control);
+            circuit += InvSGate::new(target);
+        }
+    }
+    circuit
+}
+
+/// Returns the circuit of exp(i (k_0 XX + k_1 YY + k_2 ZZ)) using as few CNOT gates as possible.
+///
+/// The k-vector is first permuted with local basis changes so that a single non-zero component is in the
+/// ZZ position and a single zero component is in the YY position.
+fn canonical_cnot_circuit(control: usize, target: usize, k_vector: [f64; 3]) -> Circuit {
+    let [kx, ky, kz] = k_vector;
+    let non_zero = k_vector.iter().filter(|k| k.abs() > KAK_TOLERANCE).count();
+    let mut circuit = Circuit::new();
+    if non_zero == 0 {
+        return circuit;
+    }
+    if non_zero == 1 {
+        let k = kx + ky + kz;
+        if (k.abs() - FRAC_PI_4).abs() < KAK_TOLERANCE {
+            // Move the non-zero component to the ZZ position
+            let (before, after): (Vec<SingleQubitGateOperation>, Vec<SingleQubitGateOperation>) =
+                if kx.abs() > KAK_TOLERANCE {
+                    (vec![Hadamard::new(0).into()], vec![Hadamard::new(0).into()])
+                } else if ky.abs() > KAK_TOLERANCE {
+                    (
+                        vec![RotateX::new(0, (-FRAC_PI_2).into()).into()],
+                        vec![RotateX::new(0, FRAC_PI_2.into()).into()],
+                    )
+                } else {
+                    (vec![], vec![])
+                };
+            let sign = k.signum();
+            add_local_gates(&mut circuit, &before, control, target);
+            // exp(i s π/4 ZZ) = e^{-i s π/4} RotateZ(-s π/2) ⊗ RotateZ(-s π/2) CZ
+            circuit += Hadamard::new(target);
+            circuit += CNOT::new(control, target);
+            circuit += Hadamard::new(target);
+            circuit += RotateZ::new(control, (-sign * FRAC_PI_2).into());
+            circuit += RotateZ::new(target, (-sign * FRAC_PI_2).into());
+            circuit += PragmaGlobalPhase::new((-sign * FRAC_PI_4).into());
+            add_local_gates(&mut circuit, &after, control, target);
+            return circuit;
+        }
+    }
+    if non_zero <= 2 {
+        // Move a zero component to the YY position
+        let (before, after, [a, c]): (
+            Vec<SingleQubitGateOperation>,
+            Vec<SingleQubitGateOperation>,
+            [f64; 2],
+        ) = if ky.abs() <= KAK_TOLERANCE {
+            (vec![], vec![], [kx, kz])
+        } else if kz.abs() <= KAK_TOLERANCE {
+            (
+                vec![RotateX::new(0, (-FRAC_PI_2).into()).into()],
+                vec![RotateX::new(0, FRAC_PI_2.into()).into()],
+                [kx, ky],
+            )
+        } else {
+            (
+                vec![RotateZ::new(0, (-FRAC_PI_2).into()).into()],
+                vec![RotateZ::new(0, FRAC_PI_2.into()).into()],
+                [ky, kz],
+            )
+        };
+        add_local_gates(&mut circuit, &before, control, target);
+        circuit += CNOT::new(control, target);
+        circuit += RotateX::new(control, (-2.0 * a).into());
+        circuit += RotateZ::new(target, (-2.0 * c).into());
+        circuit += CNOT::new(control, target);
+        add_local_gates(&mut circuit, &after, control, target);
+        return circuit;
+    }
+    // General case following Vatan and Williams, Phys. Rev. A 69, 032315 (2004)
+    circuit += RotateZ::new(target, (-FRAC_PI_2).into());
+    circuit += CNOT::new(target, control);
+    circuit += RotateZ::new(control, (-FRAC_PI_2 - 2.0 * kz).into());
+    circuit += RotateY::new(target, (FRAC_PI_2 + 2.0 * kx).into());
+    circuit += CNOT::new(control, target);
+    circuit += RotateY::new(target, (-FRAC_PI_2 - 2.0 * ky).into());
+    circuit += CNOT::new(target, control);
+    circuit += RotateZ::new(control, FRAC_PI_2.into());
+    circuit += PragmaGlobalPhase::new((-FRAC_PI_4).into());
+    circuit
+}
+
+/// Adds the same single-qubit gates to the control and the target qubit.
+fn add_local_gates(
+    circuit: &mut Circuit,
+    gates: &[SingleQubitGateOperation],
+    control: usize,
+    target: usize,
+) {
+    for qubit in [control, target] {
+        let mapping = std::collections::HashMap::from([(0, qubit), (qubit, 0)]);
+        for gate in gates {
+            circuit.add_operation(
+                gate.remap_qubits(&mapping)
+                    .expect("Remapping single-qubit gate cannot fail"),
+            );
+        }
+    }
+}
+
+/// Merges all single-qubit gates between two-qubit gates into one SingleQubitGate per qubit.
+///
+/// Single-qubit gates that are equal to the identity up to a phase and PragmaGlobalPhase operations
+/// are collected into one PragmaGlobalPhase at the end of the Circuit.
+fn merge_single_qubit_gates(
+    circuit: &Circuit,
+    control: usize,
+    target: usize,
+) -> Result<Circuit, RoqoqoError> {
+    let identity = |qubit: usize| {
+        SingleQubitGate::new(
+            qubit,
+            CalculatorFloat::from(1.0),
+            CalculatorFloat::ZERO,
+            CalculatorFloat::ZERO,
+            CalculatorFloat::ZERO,
+            CalculatorFloat::ZERO,
+        )
+    };
+    let mut pending = [identity(control), identity(target)];
+    let mut global_phase = CalculatorFloat::ZERO;
+    let mut merged = Circuit::new();
+    let flush = |pending: &mut [SingleQubitGate; 2],
+                 global_phase: &mut CalculatorFloat,
+                 merged: &mut Circuit|
+     -> Result<(), RoqoqoError> {
+        for gate in pending.iter_mut() {
+            let alpha_r = f64::try_from(gate.alpha_r())?;
+            if f64::try_from(gate.alpha_i())?.abs() < KAK_TOLERANCE
+                && f64::try_from(gate.beta_r())?.abs() < KAK_TOLERANCE
+                && f64::try_from(gate.beta_i())?.abs() < KAK_TOLERANCE
+            {
+                *global_phase += gate.global_phase();
+                if alpha_r < 0.0 {
+                    *global_phase += CalculatorFloat::PI;
+                }
+            } else {
+                merged.add_operation(gate.clone());
+            }
+            *gate = identity(*gate.qubit());
+        }
+        Ok(())
+    };
+    for operation in circuit.iter() {
+        if let Operation::PragmaGlobalPhase(phase) = operation {
+            global_phase += phase.phase();
+        } else if let Ok(gate) = SingleQubitGateOperation::try_from(operation.clone()) {
+            let index = if *gate.qubit() == control { 0 } else { 1 };
+            pending[index] = gate.mul(&pending[index])?;
+        } else {
+            flush(&mut pending, &mut global_phase, &mut merged)?;
+            merged.add_operation(operation.clone());
+        }
+    }
+    flush(&mut pending, &mut global_phase, &mut merged)?;
+    merged += PragmaGlobalPhase::new(global_phase);
+    Ok(merged)
+}
+
 /// The CNOT controlled not gate.
 ///
 /// Flips the state of a `target` qubit based on the `control` qubit.
@@ -2614,3 +2862,121 @@ impl OperateTwoQubitGate for EchoCrossResonance {
         }
     }
 }
+
+/// Negative control (anti-control) version of a two-qubit gate.
+///
+/// Applies the inner gate with the control convention flipped: the gate acts on the `target` qubit
+/// when the `control` qubit is in state |0> instead of |1>.
+/// The unitary matrix is the unitary matrix of the inner gate conjugated with PauliX on the `control` qubit.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct NegativeControl {
+    /// The two-qubit gate applied when the control qubit is in state |0>.
+    inner: TwoQubitGateOperation,
+}
+
+impl NegativeControl {
+    /// Creates a new instance of NegativeControl.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The two-qubit gate applied when the control qubit is in state |0>.
+    pub fn new(inner: TwoQubitGateOperation) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the two-qubit gate applied when the control qubit is in state |0>.
+    pub fn inner(&self) -> &TwoQubitGateOperation {
+        &self.inner
+    }
+
+    /// Returns the circuit implementing the gate with positive control.
+    ///
+    /// The inner gate is applied between two PauliX gates on the `control` qubit.
+    pub fn circuit(&self) -> Circuit {
+        let mut circuit = Circuit::new();
+        circuit += PauliX::new(*self.control());
+        circuit.add_operation(Operation::from(self.inner.clone()));
+        circuit += PauliX::new(*self.control());
+        circuit
+    }
+}
+
+impl super::ImplementedIn1point18 for NegativeControl {}
+
+impl SupportedVersion for NegativeControl {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        let mut current_minimum_version = (1, 18, 0);
+        let comparison_version = self.inner.minimum_supported_roqoqo_version();
+        crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+        current_minimum_version
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_NegativeControl: &[&str; 3] = &["Operation", "GateOperation", "NegativeControl"];
+
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for NegativeControl {
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_NegativeControl
+    }
+    fn hqslang(&self) -> &'static str {
+        "NegativeControl"
+    }
+    fn is_parametrized(&self) -> bool {
+        self.inner.is_parametrized()
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for NegativeControl {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(NegativeControl::new(self.inner.remap_qubits(mapping)?))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(NegativeControl::new(
+            self.inner.substitute_parameters(calculator)?,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for NegativeControl.
+impl InvolveQubits for NegativeControl {
+    fn involved_qubits(&self) -> InvolvedQubits {
+        self.inner.involved_qubits()
+    }
+}
+
+impl OperateTwoQubit for NegativeControl {
+    /// Returns `target` qubit of the inner gate.
+    fn target(&self) -> &usize {
+        self.inner.target()
+    }
+    /// Returns `control` qubit of the inner gate.
+    fn control(&self) -> &usize {
+        self.inner.control()
+    }
+}
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for NegativeControl {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let matrix = self.inner.unitary_matrix()?;
+        // The control qubit is the most significant qubit, conjugating with PauliX on it
+        // exchanges the first and second half of the rows and of the columns.
+        Ok(Array2::from_shape_fn((4, 4), |(row, column)| {
+            matrix[[row ^ 2, column ^ 2]]
+        }))
+    }
+}
@@ -0,0 +1,710 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transformation passes rewriting roqoqo Circuits.
+//!
+//! Passes take a Circuit and return a rewritten Circuit together with the information
+//! needed to interpret the results of the rewritten Circuit.
+//! The cost model [estimate_fidelity] lets passes compare candidate Circuits on a device.
+//! [transpile_to_device] rewrites a Circuit into the native gates of a device.
+//! [circuits_equivalent] checks that a pass does not change the unitary of a small Circuit.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::f64::consts::PI;
+
+use ndarray::Array2;
+use num_complex::Complex64;
+use qoqo_calculator::{Calculator, CalculatorError, CalculatorFloat};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use struqture::OperateOnDensityMatrix;
+
+use crate::devices::{gate_time, Device};
+use crate::noise_models::ContinuousDecoherenceModel;
+use crate::operations::{
+    FourQubitGateOperation, GateOperation, InvolveQubits, InvolvedQubits, MultiQubitGateOperation,
+    Operate, OperateFourQubit, OperateFourQubitGate, OperateGate, OperateMultiQubit,
+    OperateMultiQubitGate, OperatePragmaNoiseProba, OperateSingleQubit, OperateSingleQubitGate,
+    OperateThreeQubit, OperateThreeQubitGate, OperateTwoQubit, OperateTwoQubitGate, Operation,
+    PragmaActiveReset, PragmaConditionalLoop, PragmaGlobalPhase, PragmaNoiseOperation,
+    PragmaNoiseProbaOperation, SingleQubitBasis, SingleQubitGate, SingleQubitGateOperation,
+    Substitute, ThreeQubitGateOperation, TwoQubitBasis, TwoQubitGateOperation,
+};
+use crate::{Circuit, RoqoqoBackendError, RoqoqoError};
+
+/// Reuses qubits after their terminal measurement.
+///
+/// A logical qubit is alive from its first to its last involving operation.
+/// When the last operation involving a logical qubit is a [crate::operations::MeasureQubit],
+/// its physical qubit is freed after the measurement. Logical qubits starting later are mapped onto freed
+/// physical qubits, a [crate::operations::PragmaActiveReset] is inserted before the first operation
+/// of the reusing logical qubit. All other logical qubits are mapped onto new physical qubits,
+/// physical qubits are numbered in order of first use.
+///
+/// The readout registers are not changed, the returned mapping relates every logical qubit
+/// of the input Circuit to the physical qubit it is executed on.
+///
+/// # Arguments
+///
+/// * `circuit` - The Circuit that is rewritten.
+///
+/// # Returns
+///
+/// * `Ok((Circuit, HashMap<usize, usize>))` - The rewritten Circuit and the logical to physical qubit mapping.
+/// * `Err(RoqoqoError::GenericError)` - An operation involving all qubits or a PragmaRepeatedMeasurement follows the first reuse of a qubit.
+pub fn reuse_qubits(circuit: &Circuit) -> Result<(Circuit, HashMap<usize, usize>), RoqoqoError> {
+    let operations: Vec<&Operation> = circuit.iter().collect();
+    // First and last position of the operations involving each logical qubit
+    let mut lifetimes: HashMap<usize, (usize, usize)> = HashMap::new();
+    for (position, operation) in operations.iter().enumerate() {
+        if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+            for qubit in qubits {
+                lifetimes
+                    .entry(qubit)
+                    .and_modify(|(_, last)| *last = position)
+                    .or_insert((position, position));
+            }
+        }
+    }
+
+    let mut mapping: HashMap<usize, usize> = HashMap::new();
+    let mut resets: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut free_physical: BTreeSet<usize> = BTreeSet::new();
+    let mut freed_at: HashMap<usize, usize> = HashMap::new();
+    let mut number_physical: usize = 0;
+    // Position of the first measurement whose qubit is reused
+    let mut reuse_point: usize = operations.len();
+    for (position, operation) in operations.iter().enumerate() {
+        let mut starting: Vec<usize> = lifetimes
+            .iter()
+            .filter(|(_, (first, _))| *first == position)
+            .map(|(qubit, _)| *qubit)
+            .collect();
+        starting.sort_unstable();
+        for logical in starting {
+            let physical = match free_physical.pop_first() {
+                Some(physical) => {
+                    resets.entry(position).or_default().push(physical);
+                    reuse_point = reuse_point.min(freed_at[&physical]);
+                    physical
+                }
+                None => {
+                    number_physical += 1;
+                    number_physical - 1
+                }
+            };
+            mapping.insert(logical, physical);
+        }
+        if let Operation::MeasureQubit(measurement) = operation {
+            let logical = *measurement.qubit();
+            if lifetimes[&logical].1 == position {
+                free_physical.insert(mapping[&logical]);
+                freed_at.insert(mapping[&logical], position);
+            }
+        }
+    }
+
+    for operation in operations.iter().skip(reuse_point) {
+        if operation.involved_qubits() == InvolvedQubits::All
+            || operation.hqslang() == "PragmaRepeatedMeasurement"
+        {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubits cannot be reused, operation {} is not supported after the first measurement of a reused qubit",
+                    operation.hqslang()
+                ),
+            });
+        }
+    }
+
+    // Remapping requires that every target qubit is also a key of the mapping
+    let mut remapping = mapping.clone();
+    for physical in mapping.values() {
+        remapping.entry(*physical).or_insert(*physical);
+    }
+    let mut new_circuit = Circuit::new();
+    for (position, operation) in operations.into_iter().enumerate() {
+        if let Some(physical_qubits) = resets.get(&position) {
+            for physical in physical_qubits {
+                new_circuit.add_operation(PragmaActiveReset::new(*physical));
+            }
+        }
+        new_circuit.add_operation(operation.remap_qubits(&remapping)?);
+    }
+    Ok((new_circuit, mapping))
+}
+
+/// Lowers all [crate::operations::PragmaConditionalLoop] operations to nested PragmaConditional operations.
+///
+/// Backends without native support for conditional loops can use this pass before execution.
+/// Conditional loops nested inside the circuit of a conditional loop are unrolled as well.
+///
+/// # Arguments
+///
+/// * `circuit` - The Circuit that is rewritten.
+///
+/// # Returns
+///
+/// * `Circuit` - The Circuit without PragmaConditionalLoop operations at the top level.
+pub fn unroll_conditional_loops(circuit: &Circuit) -> Circuit {
+    let mut new_circuit = Circuit::new();
+    for operation in circuit.iter() {
+        if let Operation::PragmaConditionalLoop(pragma) = operation {
+            let inner = PragmaConditionalLoop::new(
+                pragma.condition_register().clone(),
+                *pragma.condition_index(),
+                *pragma.max_repetitions(),
+                unroll_conditional_loops(pragma.circuit()),
+            );
+            for unrolled_operation in inner.unroll().iter() {
+                new_circuit.add_operation(unrolled_operation.clone());
+            }
+        } else {
+            new_circuit.add_operation(operation.clone());
+        }
+    }
+    new_circuit
+}
+
+/// Estimates the fidelity of a Circuit executed on a device.
+///
+/// The estimate is the product of the survival probabilities of all qubits
+/// returned by [estimate_fidelity_per_qubit].
+///
+/// # Arguments
+///
+/// * `circuit` - The Circuit that is estimated.
+/// * `device` - The device providing the gate times and qubit decoherence rates.
+/// * `noise` - The decoherence the qubits experience in addition to the decoherence rates of the device.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The estimated fidelity between 0 and 1.
+/// * `Err(RoqoqoBackendError)` - See [estimate_fidelity_per_qubit].
+pub fn estimate_fidelity(
+    circuit: &Circuit,
+    device: &dyn Device,
+    noise: &ContinuousDecoherenceModel,
+) -> Result<f64, RoqoqoBackendError> {
+    Ok(estimate_fidelity_per_qubit(circuit, device, noise)?
+        .values()
+        .product())
+}
+
+/// Estimates the survival probability of each qubit of a Circuit executed on a device.
+///
+/// Each gate with gate time `t` multiplies the survival probability of each of its qubits
+/// by `exp(-t * rate)`. The decoherence rate of a qubit is the sum of the diagonal of
+/// the qubit decoherence rates of the device and the diagonal Lindblad rates of the qubit in the noise model.
+/// Noise PRAGMAs multiply the survival probability of their qubits by `1 - probability()`.
+/// All other operations (measurements, definitions and PRAGMAs) do not change the estimate.
+///
+/// # Arguments
+///
+/// * `circuit` - The Circuit that is estimated.
+/// * `device` - The device providing the gate times and qubit decoherence rates.
+/// * `noise` - The decoherence the qubits experience in addition to the decoherence rates of the device.
+///
+/// # Returns
+///
+/// * `Ok(HashMap<usize, f64>)` - The survival probability of each qubit involved in a gate or noise PRAGMA.
+/// * `Err(RoqoqoBackendError::GenericError)` - A gate is not available on the device or a noise PRAGMA has no probability.
+/// * `Err(RoqoqoBackendError::CalculatorError)` - A rate, gate time or probability is symbolic.
+pub fn estimate_fidelity_per_qubit(
+    circuit: &Circuit,
+    device: &dyn Device,
+    noise: &ContinuousDecoherenceModel,
+) -> Result<HashMap<usize, f64>, RoqoqoBackendError> {
+    let mut noise_rates: HashMap<usize, f64> = HashMap::new();
+    for ((left, right), rate) in noise.lindblad_noise.iter() {
+        if left == right && left.len() == 1 {
+            if let Some((qubit, _)) = left.iter().next() {
+                *noise_rates.entry(*qubit).or_insert(0.0) += f64::try_from(rate.re.clone())?;
+            }
+        }
+    }
+
+    let mut survival: HashMap<usize, f64> = HashMap::new();
+    for operation in circuit.iter() {
+        if let Ok(gate) = GateOperation::try_from(operation) {
+            let qubits: Vec<usize> = match gate.involved_qubits() {
+                InvolvedQubits::Set(qubits) => qubits.into_iter().collect(),
+                _ => Vec::new(),
+            };
+            let gate_time =
+                gate_time(&device, operation).ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Gate {} on qubits {:?} is not available on the device",
+                        gate.hqslang(),
+                        qubits
+                    ),
+                })?;
+            for qubit in qubits {
+                let device_rate = device
+                    .qubit_decoherence_rates(&qubit)
+                    .map(|rates| rates.diag().sum())
+                    .unwrap_or(0.0);
+                let rate = device_rate + noise_rates.get(&qubit).copied().unwrap_or(0.0);
+                *survival.entry(qubit).or_insert(1.0) *= (-gate_time * rate).exp();
+            }
+        } else if let Ok(pragma) = PragmaNoiseProbaOperation::try_from(operation) {
+            let probability = f64::try_from(pragma.probability())?;
+            if let InvolvedQubits::Set(qubits) = pragma.involved_qubits() {
+                for qubit in qubits {
+                    *survival.entry(qubit).or_insert(1.0) *= 1.0 - probability;
+                }
+            }
+        } else if PragmaNoiseOperation::try_from(operation).is_ok() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Noise PRAGMA {} has no probability and cannot be estimated",
+                    operation.hqslang()
+                ),
+            });
+        }
+    }
+    Ok(survival)
+}
+
+/// Tolerance below which a fused single-qubit gate is treated as the identity.
+const IDENTITY_TOLERANCE: f64 = 1e-10;
+
+/// Rewrites a Circuit into the native gates of a device.
+///
+/// The pass runs in four steps:
+///
+/// 1. Three-qubit, four-qubit and multi-qubit gates that are not available on the device
+///    are decomposed into two-qubit and single-qubit gates.
+/// 2. Two-qubit gates that are not available on the device are decomposed with the first entangling gate
+///    of CNOT, ControlledPauliZ and ISwap offered by the device (see [OperateTwoQubitGate::to_basis_circuit]).
+/// 3. Consecutive single-qubit gates on a qubit containing a gate that is not available on the device
+///    are fused into one gate and re-expressed with RotateZ and RotateY, RotateX or SqrtPauliX,
+///    whichever set the device offers first (see [OperateSingleQubitGate::to_native_circuit]).
+/// 4. Every gate of the result is checked to be available on the device.
+///
+/// Gates available on the device are not changed. The global phase of all decompositions
+/// and of the PragmaGlobalPhase operations of the input is collected into one PragmaGlobalPhase at the end,
+/// which is omitted when the phase is a multiple of 2π.
+/// Operations nested in PRAGMAs are not rewritten. Qubits are not routed,
+/// the two-qubit gates of the Circuit have to act on connected qubits of the device.
+///
+/// # Arguments
+///
+/// * `circuit` - The Circuit that is rewritten.
+/// * `device` - The device providing the native gates.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The Circuit using only gates available on the device.
+/// * `Err(RoqoqoBackendError::GenericError)` - The device does not offer the gates needed for a decomposition or a gate of the result is not available on the device.
+/// * `Err(RoqoqoBackendError::RoqoqoError)` - A gate that needs to be decomposed has symbolic parameters.
+pub fn transpile_to_device(
+    circuit: &Circuit,
+    device: &impl Device,
+) -> Result<Circuit, RoqoqoBackendError> {
+    let two_qubit_gate_names = device.two_qubit_gate_names();
+    let two_qubit_basis = [
+        ("CNOT", TwoQubitBasis::CNOT),
+        ("ControlledPauliZ", TwoQubitBasis::ControlledPauliZ),
+        ("ISwap", TwoQubitBasis::ISwap),
+    ]
+    .into_iter()
+    .find(|(name, _)| two_qubit_gate_names.iter().any(|gate| gate == name))
+    .map(|(_, basis)| basis);
+    let single_qubit_gate_names = device.single_qubit_gate_names();
+    let single_qubit_basis = [
+        ("RotateY", SingleQubitBasis::ZYZ),
+        ("RotateX", SingleQubitBasis::ZXZ),
+        ("SqrtPauliX", SingleQubitBasis::ZSX),
+    ]
+    .into_iter()
+    .find(|(name, _)| {
+        single_qubit_gate_names.iter().any(|gate| gate == "RotateZ")
+            && single_qubit_gate_names.iter().any(|gate| gate == name)
+    })
+    .map(|(_, basis)| basis);
+
+    let mut decomposed: Vec<Operation> = Vec::new();
+    for operation in circuit.iter() {
+        decompose_to_two_qubit_gates(operation, device, two_qubit_basis, &mut decomposed)?;
+    }
+
+    let mut transpiled = Circuit::new();
+    let mut global_phase = CalculatorFloat::ZERO;
+    let mut pending: BTreeMap<usize, Vec<SingleQubitGateOperation>> = BTreeMap::new();
+    for operation in decomposed {
+        if let Operation::PragmaGlobalPhase(pragma) = &operation {
+            global_phase += pragma.phase();
+        } else if let Ok(gate) = SingleQubitGateOperation::try_from(&operation) {
+            pending.entry(*gate.qubit()).or_default().push(gate);
+        } else {
+            let qubits: BTreeSet<usize> = match operation.involved_qubits() {
+                InvolvedQubits::All => pending.keys().copied().collect(),
+                InvolvedQubits::Set(qubits) => qubits.into_iter().collect(),
+                InvolvedQubits::None => BTreeSet::new(),
+            };
+            for qubit in qubits {
+                if let Some(gates) = pending.remove(&qubit) {
+                    fuse_single_qubit_gates(
+                        gates,
+                        device,
+                        single_qubit_basis,
+                        &mut transpiled,
+                        &mut global_phase,
+                    )?;
+                }
+            }
+            transpiled.add_operation(operation);
+        }
+    }
+    for gates in pending.into_values() {
+        fuse_single_qubit_gates(
+            gates,
+            device,
+            single_qubit_basis,
+            &mut transpiled,
+            &mut global_phase,
+        )?;
+    }
+    match global_phase.float() {
+        Ok(phase) => {
+            let phase = phase.rem_euclid(2.0 * PI);
+            if phase > IDENTITY_TOLERANCE && 2.0 * PI - phase > IDENTITY_TOLERANCE {
+                transpiled.add_operation(PragmaGlobalPhase::new(phase.into()));
+            }
+        }
+        Err(_) => transpiled.add_operation(PragmaGlobalPhase::new(global_phase)),
+    }
+
+    for operation in transpiled.iter() {
+        if GateOperation::try_from(operation).is_ok() && gate_time(device, operation).is_none() {
+            let qubits: Vec<usize> = match operation.involved_qubits() {
+                InvolvedQubits::Set(qubits) => qubits.into_iter().collect(),
+                _ => Vec::new(),
+            };
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Gate {} on qubits {:?} is not available on the device",
+                    operation.hqslang(),
+                    qubits
+                ),
+            });
+        }
+    }
+    Ok(transpiled)
+}
+
+/// Recursively decomposes an operation until it only contains two-qubit and single-qubit gates.
+///
+/// Operations that are available on the device, single-qubit gates and operations that are not gates
+/// are not decomposed. Two-qubit gates are decomposed with the basis and not decomposed further,
+/// so entangling gates between unconnected qubits are left for the final availability check.
+fn decompose_to_two_qubit_gates(
+    operation: &Operation,
+    device: &impl Device,
+    basis: Option<TwoQubitBasis>,
+    decomposed: &mut Vec<Operation>,
+) -> Result<(), RoqoqoBackendError> {
+    if gate_time(device, operation).is_some()
+        || SingleQubitGateOperation::try_from(operation).is_ok()
+    {
+        decomposed.push(operation.clone());
+        return Ok(());
+    }
+    let required_basis = || {
+        basis.ok_or_else(|| RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Gate {} cannot be decomposed, the device offers none of the two-qubit gates CNOT, ControlledPauliZ and ISwap",
+                operation.hqslang()
+            ),
+        })
+    };
+    let circuit = if let Ok(gate) = TwoQubitGateOperation::try_from(operation) {
+        decomposed.extend(gate.to_basis_circuit(required_basis()?)?.iter().cloned());
+        return Ok(());
+    } else if let Ok(gate) = ThreeQubitGateOperation::try_from(operation) {
+        gate.circuit_with_basis(required_basis()?)
+    } else if let Ok(gate) = FourQubitGateOperation::try_from(operation) {
+        gate.circuit()
+    } else if let Ok(gate) = MultiQubitGateOperation::try_from(operation) {
+        gate.circuit()
+    } else {
+        decomposed.push(operation.clone());
+        return Ok(());
+    };
+    for inner in circuit.iter() {
+        decompose_to_two_qubit_gates(inner, device, basis, decomposed)?;
+    }
+    Ok(())
+}
+
+/// Adds consecutive single-qubit gates on one qubit to the Circuit using the native gates of the device.
+///
+/// When all gates are available on the device they are added unchanged. Otherwise they are fused into one gate,
+/// which is dropped when it is the identity up to a phase and decomposed with the basis otherwise.
+/// The global phase of the fused gate is added to `global_phase`.
+fn fuse_single_qubit_gates(
+    gates: Vec<SingleQubitGateOperation>,
+    device: &impl Device,
+    basis: Option<SingleQubitBasis>,
+    circuit: &mut Circuit,
+    global_phase: &mut CalculatorFloat,
+) -> Result<(), RoqoqoBackendError> {
+    if gates.iter().all(|gate| {
+        device
+            .single_qubit_gate_time(gate.hqslang(), gate.qubit())
+            .is_some()
+    }) {
+        for gate in gates {
+            circuit.add_operation(gate);
+        }
+        return Ok(());
+    }
+    let qubit = match gates.first() {
+        Some(gate) => *gate.qubit(),
+        None => return Ok(()),
+    };
+    let mut fused = SingleQubitGate::new(
+        qubit,
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+    );
+    for gate in gates {
+        fused = gate.mul(&fused)?;
+    }
+    if f64::try_from(fused.alpha_i())?.abs() < IDENTITY_TOLERANCE
+        && f64::try_from(fused.beta_r())?.abs() < IDENTITY_TOLERANCE
+        && f64::try_from(fused.beta_i())?.abs() < IDENTITY_TOLERANCE
+    {
+        *global_phase += fused.global_phase();
+        if f64::try_from(fused.alpha_r())? < 0.0 {
+            *global_phase += CalculatorFloat::PI;
+        }
+        return Ok(());
+    }
+    let basis = basis.ok_or_else(|| RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Single-qubit gates on qubit {} cannot be decomposed, the device offers RotateZ together with none of RotateY, RotateX and SqrtPauliX",
+            qubit
+        ),
+    })?;
+    for operation in fused.to_native_circuit(basis)?.iter() {
+        if let Operation::PragmaGlobalPhase(pragma) = operation {
+            *global_phase += pragma.phase();
+        } else {
+            circuit.add_operation(operation.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Maximal number of qubits of the Circuits compared by [circuits_equivalent].
+pub const EQUIVALENCE_MAX_QUBITS: usize = 10;
+
+/// Checks if two Circuits implement the same unitary up to a global phase.
+///
+/// Parametrized Circuits are compared for `samples` random values of the union of their free
+/// parameters, drawn uniformly from [0, 2π) with a random number generator seeded with `seed`.
+/// The same values are substituted into both Circuits. Circuits without free parameters are compared once.
+/// The unitaries act on all qubits up to the largest qubit used in one of the Circuits,
+/// which must be smaller than [EQUIVALENCE_MAX_QUBITS].
+///
+/// # Arguments
+///
+/// * `a` - The first Circuit.
+/// * `b` - The second Circuit.
+/// * `samples` - The number of random parameter values compared for parametrized Circuits (at least one).
+/// * `seed` - The seed of the random parameter values.
+/// * `tolerance` - The largest allowed absolute difference of the entries of the unitaries.
+///
+/// # Returns
+///
+/// * `Ok(bool)` - True if the unitaries agree up to a global phase for all samples.
+/// * `Err(RoqoqoError::GenericError)` - A Circuit contains a non-unitary operation or acts on too many qubits.
+/// * `Err(RoqoqoError::CalculatorError)` - A parameter cannot be evaluated.
+///
+/// # Example
+///
+/// ```
+/// use roqoqo::operations::{RotateX, RotateZ, Hadamard};
+/// use roqoqo::passes::circuits_equivalent;
+/// use roqoqo::Circuit;
+///
+/// let mut a = Circuit::new();
+/// a += RotateX::new(0, "theta".into());
+/// let mut b = Circuit::new();
+/// b += Hadamard::new(0);
+/// b += RotateZ::new(0, "theta".into());
+/// b += Hadamard::new(0);
+///
+/// assert!(circuits_equivalent(&a, &b, 5, 42, 1e-10).unwrap());
+/// ```
+pub fn circuits_equivalent(
+    a: &Circuit,
+    b: &Circuit,
+    samples: usize,
+    seed: u64,
+    tolerance: f64,
+) -> Result<bool, RoqoqoError> {
+    let number_qubits = a
+        .iter()
+        .chain(b.iter())
+        .filter_map(|operation| match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => qubits.into_iter().max(),
+            _ => None,
+        })
+        .max()
+        .map_or(0, |qubit| qubit + 1);
+    if number_qubits > EQUIVALENCE_MAX_QUBITS {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Circuits act on {} qubits, equivalence is only checked for up to {} qubits",
+                number_qubits, EQUIVALENCE_MAX_QUBITS
+            ),
+        });
+    }
+    if !a.is_parametrized() && !b.is_parametrized() {
+        return Ok(unitaries_equal_up_to_phase(
+            &circuit_unitary(a, number_qubits)?,
+            &circuit_unitary(b, number_qubits)?,
+            tolerance,
+        ));
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+    // Names of the free parameters found so far, in the order in which they were found
+    let mut parameters: Vec<String> = Vec::new();
+    for _ in 0..samples.max(1) {
+        let mut calculator = Calculator::new();
+        for name in parameters.iter() {
+            calculator.set_variable(name, rng.gen_range(0.0..2.0 * PI));
+        }
+        let mut unitaries: Vec<Array2<Complex64>> = Vec::with_capacity(2);
+        for circuit in [a, b] {
+            // Free parameters are found when the substitution fails because they are not set
+            let substituted = loop {
+                match circuit.substitute_parameters(&calculator) {
+                    Ok(substituted) => break substituted,
+                    Err(RoqoqoError::CalculatorError(CalculatorError::VariableNotSet { name })) => {
+                        calculator.set_variable(&name, rng.gen_range(0.0..2.0 * PI));
+                        parameters.push(name);
+                    }
+                    Err(error) => return Err(error),
+                }
+            };
+            unitaries.push(circuit_unitary(&substituted, number_qubits)?);
+        }
+        if !unitaries_equal_up_to_phase(&unitaries[0], &unitaries[1], tolerance) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Computes the unitary of a Circuit without free parameters, qubit q is bit 2^q of the basis state index.
+fn circuit_unitary(
+    circuit: &Circuit,
+    number_qubits: usize,
+) -> Result<Array2<Complex64>, RoqoqoError> {
+    let dimension = 1 << number_qubits;
+    let mut unitary: Array2<Complex64> = Array2::eye(dimension);
+    for operation in circuit.iter() {
+        let (qubits, matrix): (Vec<usize>, Array2<Complex64>) = match operation {
+            Operation::InputSymbolic(_) => continue,
+            Operation::PragmaGlobalPhase(pragma) => {
+                let phase = Complex64::new(0.0, *pragma.phase().float()?).exp();
+                unitary.mapv_inplace(|entry| entry * phase);
+                continue;
+            }
+            _ => {
+                if let Ok(gate) = SingleQubitGateOperation::try_from(operation) {
+                    (vec![*gate.qubit()], gate.unitary_matrix()?)
+                } else if let Ok(gate) = TwoQubitGateOperation::try_from(operation) {
+                    (
+                        vec![*gate.control(), *gate.target()],
+                        gate.unitary_matrix()?,
+                    )
+                } else if let Ok(gate) = ThreeQubitGateOperation::try_from(operation) {
+                    (
+                        vec![*gate.control_0(), *gate.control_1(), *gate.target()],
+                        gate.unitary_matrix()?,
+                    )
+                } else if let Ok(gate) = FourQubitGateOperation::try_from(operation) {
+                    (
+                        vec![
+                            *gate.control_0(),
+                            *gate.control_1(),
+                            *gate.control_2(),
+                            *gate.target(),
+                        ],
+                        gate.unitary_matrix()?,
+                    )
+                } else if let Ok(gate) = MultiQubitGateOperation::try_from(operation) {
+                    (gate.qubits().clone(), gate.unitary_matrix()?)
+                } else {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "Operation {} is not a unitary gate, equivalence cannot be checked",
+                            operation.hqslang()
+                        ),
+                    });
+                }
+            }
+        };
+        // The first qubit of the gate is the most significant qubit of its matrix
+        let size = qubits.len();
+        let mut new_unitary: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        for index in 0..dimension {
+            let local = qubits
+                .iter()
+                .fold(0, |local, qubit| 2 * local + ((index >> qubit) & 1));
+            for row in 0..(1 << size) {
+                let mut target = index;
+                for (position, qubit) in qubits.iter().enumerate() {
+                    let bit = (row >> (size - 1 - position)) & 1;
+                    target = (target & !(1 << qubit)) | (bit << qubit);
+                }
+                let factor = matrix[(row, local)];
+                if factor != Complex64::new(0.0, 0.0) {
+                    for column in 0..dimension {
+                        new_unitary[(target, column)] += factor * unitary[(index, column)];
+                    }
+                }
+            }
+        }
+        unitary = new_unitary;
+    }
+    Ok(unitary)
+}
+
+/// Checks if two unitaries agree up to a global phase within the tolerance.
+fn unitaries_equal_up_to_phase(
+    a: &Array2<Complex64>,
+    b: &Array2<Complex64>,
+    tolerance: f64,
+) -> bool {
+    // The phase is fixed by the entry with the largest absolute value, which is never close to zero
+    let (index, reference) = a
+        .indexed_iter()
+        .max_by(|(_, x), (_, y)| x.norm().total_cmp(&y.norm()))
+        .expect("Unitaries act on at least one basis state");
+    let phase = b[index] / reference;
+    if (phase.norm() - 1.0).abs() > tolerance {
+        return false;
+    }
+    a.iter()
+        .zip(b.iter())
+        .all(|(x, y)| (x * phase - y).norm() <= tolerance)
+}
@@ -12,6 +12,7 @@
 
 //! Represents a quantum program evaluating measurements based on a one or more free float parameters.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 #[cfg(feature = "async")]
@@ -19,7 +20,10 @@ use crate::backends::AsyncEvaluatingBackend;
 use crate::backends::{EvaluatingBackend, RegisterResult};
 use crate::measurements;
 use crate::measurements::Measure;
-use crate::RoqoqoBackendError;
+#[cfg(feature = "serialize")]
+use crate::CircuitSizeReport;
+use crate::{Circuit, RoqoqoBackendError, RoqoqoError};
+use qoqo_calculator::Calculator;
 use std::fmt::{Display, Formatter};
 
 /// Represents a quantum program evaluating measurements based on a one or more free float parameters.
@@ -37,7 +41,7 @@ use std::fmt::{Display, Formatter};
 ///
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum QuantumProgram {
     /// Variant for basis rotation measurement based quantum programs
@@ -71,6 +75,177 @@ pub enum QuantumProgram {
 }
 
 impl QuantumProgram {
+    /// Returns the name of the measurement type of the QuantumProgram.
+    ///
+    /// The name is the name of the QuantumProgram variant used in the serialized form.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The measurement type, e.g. `"PauliZProduct"`.
+    pub fn measurement_type(&self) -> &'static str {
+        match self {
+            QuantumProgram::PauliZProduct { .. } => "PauliZProduct",
+            QuantumProgram::CheatedPauliZProduct { .. } => "CheatedPauliZProduct",
+            QuantumProgram::Cheated { .. } => "Cheated",
+            QuantumProgram::ClassicalRegister { .. } => "ClassicalRegister",
+        }
+    }
+
+    /// Validates the classical registers of the measurement of the QuantumProgram.
+    ///
+    /// See [crate::measurements::Measure::validate]. The validation is also performed
+    /// before the QuantumProgram is executed by one of the `run` methods.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All classical registers are defined and large enough.
+    /// * `Err(RoqoqoError::MissingRegister)` - A classical register is used but not defined.
+    /// * `Err(RoqoqoError::RegisterTooSmall)` - A readout index is larger than the defined register.
+    /// * `Err(RoqoqoError::ConflictingDefinitions)` - A Circuit defines a register in conflicting ways.
+    pub fn validate(&self) -> Result<(), RoqoqoError> {
+        match self {
+            QuantumProgram::PauliZProduct { measurement, .. } => measurement.validate(),
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => measurement.validate(),
+            QuantumProgram::Cheated { measurement, .. } => measurement.validate(),
+            QuantumProgram::ClassicalRegister { measurement, .. } => measurement.validate(),
+        }
+    }
+
+    /// Returns size statistics of the QuantumProgram.
+    ///
+    /// The statistics of the constant circuit and all circuits of the measurement are aggregated,
+    /// see [crate::Circuit::size_report]. The serialized size is the size of the whole QuantumProgram.
+    ///
+    /// # Returns
+    ///
+    /// * `CircuitSizeReport` - The total number of operations, serialized size, largest state dimension and deepest nesting.
+    #[cfg(feature = "serialize")]
+    pub fn size_report(&self) -> CircuitSizeReport {
+        let mut report = CircuitSizeReport::default();
+        match self {
+            QuantumProgram::PauliZProduct { measurement, .. } => {
+                add_measurement_circuits(&mut report, measurement)
+            }
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => {
+                add_measurement_circuits(&mut report, measurement)
+            }
+            QuantumProgram::Cheated { measurement, .. } => {
+                add_measurement_circuits(&mut report, measurement)
+            }
+            QuantumProgram::ClassicalRegister { measurement, .. } => {
+                add_measurement_circuits(&mut report, measurement)
+            }
+        }
+        report.serialized_bytes = bincode::serialized_size(self)
+            .expect("Unexpected failure to compute the serialized size of the QuantumProgram")
+            as usize;
+        report
+    }
+
+    /// Deserializes a QuantumProgram from json and rejects fields unknown to the QuantumProgram.
+    ///
+    /// The default deserialization ignores unknown fields, so a misspelled field in the
+    /// measurement or one of its circuits can go unnoticed. The strict deserialization returns an error instead.
+    /// The legacy variant names `BasisRotation` and `CheatedBasisRotation` are accepted,
+    /// the json paths of unknown fields use the current variant names.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The json representation of the QuantumProgram.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QuantumProgram)` - The deserialized QuantumProgram.
+    /// * `Err(RoqoqoError::SerializationError)` - The input cannot be deserialized or contains unknown fields, which are listed with their json paths.
+    #[cfg(feature = "serialize")]
+    pub fn from_json_strict(input: &str) -> Result<Self, RoqoqoError> {
+        let mut input_value = crate::parse_json_value(input)?;
+        if let serde_json::Value::Object(variants) = &mut input_value {
+            for (legacy_name, index) in LEGACY_QUANTUM_PROGRAM_VARIANTS {
+                if let Some(value) = variants.remove(*legacy_name) {
+                    variants.insert(QUANTUM_PROGRAM_VARIANTS[*index].to_string(), value);
+                }
+            }
+        }
+        crate::from_json_value_strict(input_value)
+    }
+
+    /// Returns an iterator over the Circuits of the QuantumProgram with the parameters substituted.
+    ///
+    /// The constant Circuit of the measurement is substituted once and prepended to each Circuit.
+    /// The Circuits are substituted lazily when they are requested from the iterator,
+    /// so only one substituted Circuit is kept in memory at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - List of float ([f64]) parameters in order of `input_parameter_names`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Iterator<Item = Result<Circuit, RoqoqoError>>)` - The substituted Circuits in the order of the measurement.
+    /// * `Err(RoqoqoError)` - The number of parameters is wrong or the substitution of the constant Circuit failed.
+    pub fn substituted_circuits<'a>(
+        &'a self,
+        parameters: &[f64],
+    ) -> Result<impl Iterator<Item = Result<Circuit, RoqoqoError>> + 'a, RoqoqoError> {
+        let (constant_circuit, circuits, input_parameter_names) = match self {
+            QuantumProgram::PauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => (
+                measurement.constant_circuit(),
+                measurement.circuits(),
+                input_parameter_names,
+            ),
+            QuantumProgram::CheatedPauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => (
+                measurement.constant_circuit(),
+                measurement.circuits(),
+                input_parameter_names,
+            ),
+            QuantumProgram::Cheated {
+                measurement,
+                input_parameter_names,
+            } => (
+                measurement.constant_circuit(),
+                measurement.circuits(),
+                input_parameter_names,
+            ),
+            QuantumProgram::ClassicalRegister {
+                measurement,
+                input_parameter_names,
+            } => (
+                measurement.constant_circuit(),
+                measurement.circuits(),
+                input_parameter_names,
+            ),
+        };
+        if parameters.len() != input_parameter_names.len() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Wrong number of parameters {} parameters expected {} parameters given",
+                    input_parameter_names.len(),
+                    parameters.len()
+                ),
+            });
+        }
+        let (constant_circuit, circuits) = substitute_circuits(
+            constant_circuit,
+            circuits,
+            input_parameter_names,
+            parameters,
+        )?;
+        Ok(circuits.map(move |circuit| {
+            let circuit = circuit?;
+            Ok(match &constant_circuit {
+                Some(constant_circuit) => constant_circuit.as_ref().clone() + circuit.as_ref(),
+                None => circuit.into_owned(),
+            })
+        }))
+    }
+
     /// Runs the QuantumProgram and returns expectation values.
     ///
     /// Runs the quantum programm for a given set of parameters passed in the same order as the parameters
@@ -80,6 +255,15 @@ impl QuantumProgram {
     ///
     /// * `backend` - The backend the program is executed on.
     /// * `parameters` - List of float ([f64]) parameters of the function call in order of `input_parameter_names`
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "QuantumProgram::run",
+            level = "info",
+            skip_all,
+            fields(number_parameters = parameters.len())
+        )
+    )]
     pub fn run<T>(
         &self,
         backend: T,
@@ -91,27 +275,21 @@ impl QuantumProgram {
         match self{
             QuantumProgram::PauliZProduct{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.run_measurement(&substituted_measurement)
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.run_measurement(substituted_measurement.as_ref())
             }
             QuantumProgram::CheatedPauliZProduct{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.run_measurement(&substituted_measurement)
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.run_measurement(substituted_measurement.as_ref())
             }
             QuantumProgram::Cheated{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.run_measurement(&substituted_measurement)
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.run_measurement(substituted_measurement.as_ref())
             }
             _ => Err(RoqoqoBackendError::GenericError{msg: "A quantum programm returning classical registeres cannot be executed by `run` use `run_registers` instead".to_string()})
         }
@@ -129,6 +307,15 @@ impl QuantumProgram {
     ///
     /// * `backend` - The backend the program is executed on.
     /// * `parameters` - List of float ([f64]) parameters of the function call in order of `input_parameter_names`
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "QuantumProgram::run_registers",
+            level = "info",
+            skip_all,
+            fields(number_parameters = parameters.len())
+        )
+    )]
     pub fn run_registers<T>(&self, backend: T, parameters: &[f64]) -> RegisterResult
     where
         T: EvaluatingBackend,
@@ -136,11 +323,9 @@ impl QuantumProgram {
         match self{
             QuantumProgram::ClassicalRegister{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.run_measurement_registers(&substituted_measurement)
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.run_measurement_registers(substituted_measurement.as_ref())
             }
             _ => Err(RoqoqoBackendError::GenericError{msg: "A quantum programm returning expectation values cannot be executed by `run_registers` use `run` instead".to_string()})
         }
@@ -168,27 +353,21 @@ impl QuantumProgram {
         match self{
             QuantumProgram::PauliZProduct{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.async_run_measurement(&substituted_measurement).await
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.async_run_measurement(substituted_measurement.as_ref()).await
             }
             QuantumProgram::CheatedPauliZProduct{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.async_run_measurement(&substituted_measurement).await
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.async_run_measurement(substituted_measurement.as_ref()).await
             }
             QuantumProgram::Cheated{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.async_run_measurement(&substituted_measurement).await
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.async_run_measurement(substituted_measurement.as_ref()).await
             }
             _ => Err(RoqoqoBackendError::GenericError{msg: "A quantum programm returning classical registeres cannot be executed by `run` use `run_registers` instead".to_string()})
         }
@@ -215,38 +394,124 @@ impl QuantumProgram {
         match self{
             QuantumProgram::ClassicalRegister{measurement, input_parameter_names } => {
                 if parameters.len() != input_parameter_names.len() { return Err(RoqoqoBackendError::GenericError{msg: format!("Wrong number of parameters {} parameters expected {} parameters given", input_parameter_names.len(), parameters.len())})};
-                let substituted_parameters: HashMap<String, f64> = input_parameter_names.iter().zip(parameters.iter()).map(|(key, value)| (key.clone(), *value)).collect();
-                let substituted_measurement = measurement.substitute_parameters(
-                    substituted_parameters
-                )?;
-                backend.async_run_measurement_registers(&substituted_measurement).await
+                measurement.validate()?;
+                let substituted_measurement = substitute_measurement(measurement, input_parameter_names, parameters)?;
+                backend.async_run_measurement_registers(substituted_measurement.as_ref()).await
             }
             _ => Err(RoqoqoBackendError::GenericError{msg: "A quantum programm returning expectation values cannot be executed by `run_registers` use `run` instead".to_string()})
         }
     }
 }
 
-/// Implements the Display trait for QuantumProgram.
-impl Display for QuantumProgram {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut s: String = String::new();
+/// Substitutes the input parameters in the Circuits of a measurement.
+///
+/// The substitution is skipped when no parameters are given and no Circuit of the measurement
+/// is parametrized, the measurement is then borrowed instead of cloned.
+///
+/// # Arguments
+///
+/// * `measurement` - The measurement in which the parameters are substituted.
+/// * `input_parameter_names` - The names of the free parameters.
+/// * `parameters` - The values of the free parameters in order of `input_parameter_names`.
+///
+/// # Returns
+///
+/// * `Ok(Cow<T>)` - The measurement with the parameters substituted.
+/// * `Err(RoqoqoError)` - The substitution failed.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(number_circuits = measurement.circuits().count())
+    )
+)]
+fn substitute_measurement<'a, T: Measure>(
+    measurement: &'a T,
+    input_parameter_names: &[String],
+    parameters: &[f64],
+) -> Result<Cow<'a, T>, RoqoqoError> {
+    let is_parametrized = measurement
+        .constant_circuit()
+        .iter()
+        .chain(measurement.circuits())
+        .any(|circuit| circuit.is_parametrized());
+    if parameters.is_empty() && !is_parametrized {
+        return Ok(Cow::Borrowed(measurement));
+    }
+    let substituted_parameters: HashMap<String, f64> = input_parameter_names
+        .iter()
+        .zip(parameters.iter())
+        .map(|(key, value)| (key.clone(), *value))
+        .collect();
+    Ok(Cow::Owned(
+        measurement.substitute_parameters(substituted_parameters)?,
+    ))
+}
 
-        match self {
-            QuantumProgram::PauliZProduct { .. } => {
-                s.push_str("QuantumProgram::PauliZProduct");
-            }
-            QuantumProgram::CheatedPauliZProduct { .. } => {
-                s.push_str("QuantumProgram::CheatedPauliZProduct");
-            }
-            QuantumProgram::Cheated { .. } => {
-                s.push_str("QuantumProgram::Cheated");
-            }
-            QuantumProgram::ClassicalRegister { .. } => {
-                s.push_str("QuantumProgram::ClassicalRegister");
-            }
+/// Substitutes the input parameters in the constant Circuit and lazily in the Circuits of a measurement.
+///
+/// The constant Circuit is substituted directly, the Circuits only when they are requested
+/// from the returned iterator. Circuits are borrowed instead of cloned when no parameters
+/// are given and they are not parametrized.
+///
+/// # Arguments
+///
+/// * `constant_circuit` - The constant Circuit of the measurement.
+/// * `circuits` - The Circuits of the measurement.
+/// * `input_parameter_names` - The names of the free parameters.
+/// * `parameters` - The values of the free parameters in order of `input_parameter_names`.
+///
+/// # Returns
+///
+/// * `Ok((Option<Cow<Circuit>>, impl Iterator))` - The substituted constant Circuit and the iterator over the substituted Circuits.
+/// * `Err(RoqoqoError)` - The substitution of the constant Circuit failed.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+#[allow(clippy::type_complexity)]
+fn substitute_circuits<'a>(
+    constant_circuit: &'a Option<Circuit>,
+    circuits: Box<dyn Iterator<Item = &'a Circuit> + 'a>,
+    input_parameter_names: &[String],
+    parameters: &[f64],
+) -> Result<
+    (
+        Option<Cow<'a, Circuit>>,
+        impl Iterator<Item = Result<Cow<'a, Circuit>, RoqoqoError>> + 'a,
+    ),
+    RoqoqoError,
+> {
+    let mut calculator = Calculator::new();
+    for (name, value) in input_parameter_names.iter().zip(parameters.iter()) {
+        calculator.set_variable(name, *value);
+    }
+    let has_parameters = !parameters.is_empty();
+    let substitute = move |circuit: &'a Circuit| -> Result<Cow<'a, Circuit>, RoqoqoError> {
+        if has_parameters || circuit.is_parametrized() {
+            Ok(Cow::Owned(circuit.substitute_parameters(&calculator)?))
+        } else {
+            Ok(Cow::Borrowed(circuit))
         }
+    };
+    let constant_circuit = constant_circuit.as_ref().map(&substitute).transpose()?;
+    Ok((constant_circuit, circuits.map(substitute)))
+}
 
-        write!(f, "{}", s)
+/// Adds the constant circuit and the circuits of a measurement to the size statistics.
+#[cfg(feature = "serialize")]
+fn add_measurement_circuits<T: Measure>(report: &mut CircuitSizeReport, measurement: &T) {
+    for circuit in measurement
+        .constant_circuit()
+        .iter()
+        .chain(measurement.circuits())
+    {
+        report.add_circuit(circuit, 0);
+    }
+}
+
+/// Implements the Display trait for QuantumProgram.
+impl Display for QuantumProgram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "QuantumProgram::{}", self.measurement_type())
     }
 }
 
@@ -268,3 +533,297 @@ impl crate::operations::SupportedVersion for QuantumProgram {
         }
     }
 }
+
+/// Names of the QuantumProgram variants in the order of their serialized variant index.
+#[cfg(feature = "serialize")]
+const QUANTUM_PROGRAM_VARIANTS: &[&str] = &[
+    "PauliZProduct",
+    "CheatedPauliZProduct",
+    "Cheated",
+    "ClassicalRegister",
+];
+
+/// Legacy names of QuantumProgram variants and the index of the variant they are deserialized to.
+///
+/// The PauliZProduct and CheatedPauliZProduct measurements were called BasisRotation and
+/// CheatedBasisRotation before roqoqo 0.11.
+#[cfg(feature = "serialize")]
+const LEGACY_QUANTUM_PROGRAM_VARIANTS: &[(&str, usize)] =
+    &[("BasisRotation", 0), ("CheatedBasisRotation", 1)];
+
+/// Names of the fields of every QuantumProgram variant.
+#[cfg(feature = "serialize")]
+const QUANTUM_PROGRAM_FIELDS: &[&str] = &["measurement", "input_parameter_names"];
+
+/// Variant tag of a serialized QuantumProgram.
+///
+/// Unknown tags are kept so that a helpful error can be returned
+/// for QuantumPrograms serialized with a newer version of roqoqo.
+#[cfg(feature = "serialize")]
+enum QuantumProgramTag {
+    Known(usize),
+    UnknownName(String),
+    UnknownIndex(u64),
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for QuantumProgramTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct TagVisitor;
+        impl<'de> serde::de::Visitor<'de> for TagVisitor {
+            type Value = QuantumProgramTag;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("QuantumProgram variant")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match usize::try_from(value) {
+                    Ok(index) if index < QUANTUM_PROGRAM_VARIANTS.len() => {
+                        Ok(QuantumProgramTag::Known(index))
+                    }
+                    _ => Ok(QuantumProgramTag::UnknownIndex(value)),
+                }
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let legacy_index = LEGACY_QUANTUM_PROGRAM_VARIANTS
+                    .iter()
+                    .find(|(name, _)| *name == value)
+                    .map(|(_, index)| *index);
+                match QUANTUM_PROGRAM_VARIANTS
+                    .iter()
+                    .position(|name| *name == value)
+                    .or(legacy_index)
+                {
+                    Some(index) => Ok(QuantumProgramTag::Known(index)),
+                    None => Ok(QuantumProgramTag::UnknownName(value.to_string())),
+                }
+            }
+
+            fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_str(&String::from_utf8_lossy(value))
+            }
+        }
+
+        deserializer.deserialize_identifier(TagVisitor)
+    }
+}
+
+/// Visitor for the fields of a QuantumProgram variant with measurement type `M`.
+#[cfg(feature = "serialize")]
+struct QuantumProgramFieldsVisitor<M>(std::marker::PhantomData<M>);
+
+#[cfg(feature = "serialize")]
+impl<'de, M> serde::de::Visitor<'de> for QuantumProgramFieldsVisitor<M>
+where
+    M: serde::Deserialize<'de>,
+{
+    type Value = (M, Vec<String>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("QuantumProgram variant with measurement and input_parameter_names")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let measurement = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+        let input_parameter_names = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        Ok((measurement, input_parameter_names))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut measurement: Option<M> = None;
+        let mut input_parameter_names: Option<Vec<String>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "measurement" => {
+                    if measurement.is_some() {
+                        return Err(serde::de::Error::duplicate_field("measurement"));
+                    }
+                    measurement = Some(map.next_value()?);
+                }
+                "input_parameter_names" => {
+                    if input_parameter_names.is_some() {
+                        return Err(serde::de::Error::duplicate_field("input_parameter_names"));
+                    }
+                    input_parameter_names = Some(map.next_value()?);
+                }
+                _ => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok((
+            measurement.ok_or_else(|| serde::de::Error::missing_field("measurement"))?,
+            input_parameter_names
+                .ok_or_else(|| serde::de::Error::missing_field("input_parameter_names"))?,
+        ))
+    }
+}
+
+/// Returns the largest minimum roqoqo version of the circuits found in a serialized value.
+#[cfg(feature = "serialize")]
+fn highest_circuit_version(value: &serde_json::Value) -> Option<(u64, u64)> {
+    match value {
+        serde_json::Value::Object(map) => {
+            let own_version = map.get("_roqoqo_version").and_then(|version| {
+                Some((
+                    version.get("major_version")?.as_u64()?,
+                    version.get("minor_version")?.as_u64()?,
+                ))
+            });
+            map.values()
+                .filter_map(highest_circuit_version)
+                .chain(own_version)
+                .max()
+        }
+        serde_json::Value::Array(values) => values.iter().filter_map(highest_circuit_version).max(),
+        _ => None,
+    }
+}
+
+/// Returns the error for a QuantumProgram variant that is unknown to this version of roqoqo.
+#[cfg(feature = "serialize")]
+fn unknown_variant_error<E>(variant: &str, required_version: Option<(u64, u64)>) -> E
+where
+    E: serde::de::Error,
+{
+    let mut current_version = crate::ROQOQO_VERSION
+        .split('.')
+        .map(|number| number.parse::<u64>().unwrap_or_default());
+    let current_version = (
+        current_version.next().unwrap_or_default(),
+        current_version.next().unwrap_or_default(),
+    );
+    // Circuits store the minimum version they require, which only helps if it is newer
+    let required = match required_version.filter(|version| *version > current_version) {
+        Some((major, minor)) => format!("roqoqo {}.{} or newer", major, minor),
+        None => "a newer version of roqoqo".to_string(),
+    };
+    E::custom(RoqoqoError::SerializationError {
+        msg: format!(
+            "Unknown QuantumProgram measurement type {}, the QuantumProgram requires {} (this is roqoqo {})",
+            variant,
+            required,
+            crate::ROQOQO_VERSION
+        ),
+    })
+}
+
+/// Deserializes a QuantumProgram.
+///
+/// A QuantumProgram with a measurement type that is not known to this version of roqoqo
+/// returns an error containing a [RoqoqoError::SerializationError] naming the measurement type.
+/// For self-describing formats like json the error also contains the minimum roqoqo version
+/// required by the circuits of the QuantumProgram.
+///
+/// The legacy variant names `BasisRotation` and `CheatedBasisRotation` are accepted
+/// for the PauliZProduct and CheatedPauliZProduct variants.
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for QuantumProgram {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct QuantumProgramVisitor;
+        impl<'de> serde::de::Visitor<'de> for QuantumProgramVisitor {
+            type Value = QuantumProgram;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("enum QuantumProgram")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::EnumAccess<'de>,
+            {
+                use serde::de::VariantAccess;
+                let (tag, variant) = data.variant::<QuantumProgramTag>()?;
+                match tag {
+                    QuantumProgramTag::Known(0) => {
+                        let (measurement, input_parameter_names) = variant.struct_variant(
+                            QUANTUM_PROGRAM_FIELDS,
+                            QuantumProgramFieldsVisitor(std::marker::PhantomData),
+                        )?;
+                        Ok(QuantumProgram::PauliZProduct {
+                            measurement,
+                            input_parameter_names,
+                        })
+                    }
+                    QuantumProgramTag::Known(1) => {
+                        let (measurement, input_parameter_names) = variant.struct_variant(
+                            QUANTUM_PROGRAM_FIELDS,
+                            QuantumProgramFieldsVisitor(std::marker::PhantomData),
+                        )?;
+                        Ok(QuantumProgram::CheatedPauliZProduct {
+                            measurement,
+                            input_parameter_names,
+                        })
+                    }
+                    QuantumProgramTag::Known(2) => {
+                        let (measurement, input_parameter_names) = variant.struct_variant(
+                            QUANTUM_PROGRAM_FIELDS,
+                            QuantumProgramFieldsVisitor(std::marker::PhantomData),
+                        )?;
+                        Ok(QuantumProgram::Cheated {
+                            measurement,
+                            input_parameter_names,
+                        })
+                    }
+                    QuantumProgramTag::Known(_) => {
+                        let (measurement, input_parameter_names) = variant.struct_variant(
+                            QUANTUM_PROGRAM_FIELDS,
+                            QuantumProgramFieldsVisitor(std::marker::PhantomData),
+                        )?;
+                        Ok(QuantumProgram::ClassicalRegister {
+                            measurement,
+                            input_parameter_names,
+                        })
+                    }
+                    QuantumProgramTag::UnknownName(name) => {
+                        // Only self-describing formats use variant names, so the content
+                        // can be inspected for the roqoqo version of its circuits.
+                        let required_version = variant
+                            .newtype_variant::<serde_json::Value>()
+                            .ok()
+                            .as_ref()
+                            .and_then(highest_circuit_version);
+                        Err(unknown_variant_error(&name, required_version))
+                    }
+                    QuantumProgramTag::UnknownIndex(index) => Err(unknown_variant_error(
+                        &format!("with variant index {}", index),
+                        None,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_enum(
+            "QuantumProgram",
+            QUANTUM_PROGRAM_VARIANTS,
+            QuantumProgramVisitor,
+        )
+    }
+}
@@ -15,10 +15,17 @@
 //! Registers are used to store classical information during the execution of a
 //! roqoqo circuit and to provide a unified output interface for the different backends.
 
-use std::collections::HashMap;
+#[cfg(feature = "serialize")]
+use std::collections::BTreeMap;
+use std::collections::{HashMap, HashSet};
 
+use ndarray::Array2;
 use num_complex::Complex64;
 
+#[cfg(feature = "serialize")]
+use crate::float_serialization::JsonFloat;
+use crate::RoqoqoError;
+
 // This could be optimized by using bit-vec or bitvec traits
 // but needs to be tested first.
 /// Represents classical register of bits for computation in qoqo.
@@ -54,3 +61,361 @@ pub type Registers = (
     HashMap<String, FloatOutputRegister>,
     HashMap<String, ComplexOutputRegister>,
 );
+
+/// Json representation of registers with sorted register names and float values that are not finite as strings.
+#[cfg(feature = "serialize")]
+type JsonRegisters = (
+    BTreeMap<String, BitOutputRegister>,
+    BTreeMap<String, Vec<Vec<JsonFloat>>>,
+    BTreeMap<String, Vec<Vec<(JsonFloat, JsonFloat)>>>,
+);
+
+/// Serializes registers to json.
+///
+/// The registers are written as a list of the bit, float and complex registers, each a dictionary
+/// sorted by register name. JSON has no representation of NaN and infinite values, so they are rejected.
+/// Use [registers_to_json_lossy] to export registers containing them.
+///
+/// # Arguments
+///
+/// * `registers` - The bit, float and complex registers returned by running a circuit.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The serialized registers.
+/// * `Err(RoqoqoError::NonFiniteRegisterValue)` - A float or complex register contains a value that is not finite.
+/// * `Err(RoqoqoError::SerializationError)` - The registers could not be serialized.
+#[cfg(feature = "serialize")]
+pub fn registers_to_json(registers: &Registers) -> Result<String, RoqoqoError> {
+    let non_finite = first_non_finite(&registers.1, |value| value.is_finite())
+        .into_iter()
+        .chain(first_non_finite(&registers.2, |value| value.is_finite()))
+        .min();
+    match non_finite {
+        Some((name, row, index)) => Err(RoqoqoError::NonFiniteRegisterValue {
+            name: name.clone(),
+            row,
+            index,
+        }),
+        None => registers_to_json_lossy(registers),
+    }
+}
+
+// Returns the register name, row and index of the first value that is not finite.
+#[cfg(feature = "serialize")]
+fn first_non_finite<T>(
+    registers: &HashMap<String, Vec<Vec<T>>>,
+    is_finite: fn(&T) -> bool,
+) -> Option<(&String, usize, usize)> {
+    registers
+        .iter()
+        .filter_map(|(name, register)| {
+            register.iter().enumerate().find_map(|(row, values)| {
+                values
+                    .iter()
+                    .position(|value| !is_finite(value))
+                    .map(|index| (name, row, index))
+            })
+        })
+        .min()
+}
+
+/// Serializes registers to json, writing values that are not finite as strings.
+///
+/// NaN and infinite values are written as the strings "NaN", "Infinity" and "-Infinity",
+/// also for the real and imaginary parts of complex values. [registers_from_json] reads them back.
+/// The output is identical to [registers_to_json] for registers with finite values only.
+///
+/// # Arguments
+///
+/// * `registers` - The bit, float and complex registers returned by running a circuit.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The serialized registers.
+/// * `Err(RoqoqoError::SerializationError)` - The registers could not be serialized.
+#[cfg(feature = "serialize")]
+pub fn registers_to_json_lossy(registers: &Registers) -> Result<String, RoqoqoError> {
+    let json_registers: JsonRegisters = (
+        registers
+            .0
+            .iter()
+            .map(|(name, register)| (name.clone(), register.clone()))
+            .collect(),
+        registers
+            .1
+            .iter()
+            .map(|(name, register)| {
+                let rows = register
+                    .iter()
+                    .map(|row| row.iter().map(|value| JsonFloat(*value)).collect())
+                    .collect();
+                (name.clone(), rows)
+            })
+            .collect(),
+        registers
+            .2
+            .iter()
+            .map(|(name, register)| {
+                let rows = register
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|value| (JsonFloat(value.re), JsonFloat(value.im)))
+                            .collect()
+                    })
+                    .collect();
+                (name.clone(), rows)
+            })
+            .collect(),
+    );
+    serde_json::to_string(&json_registers).map_err(|err| RoqoqoError::SerializationError {
+        msg: err.to_string(),
+    })
+}
+
+/// Deserializes registers from json.
+///
+/// Reads the output of [registers_to_json] and [registers_to_json_lossy], including values that are
+/// not finite written as the strings "NaN", "Infinity" and "-Infinity".
+///
+/// # Arguments
+///
+/// * `input` - The serialized registers.
+///
+/// # Returns
+///
+/// * `Ok(Registers)` - The deserialized bit, float and complex registers.
+/// * `Err(RoqoqoError::SerializationError)` - The input is not valid json of registers.
+#[cfg(feature = "serialize")]
+pub fn registers_from_json(input: &str) -> Result<Registers, RoqoqoError> {
+    let json_registers: JsonRegisters =
+        serde_json::from_str(input).map_err(|err| RoqoqoError::SerializationError {
+            msg: err.to_string(),
+        })?;
+    Ok((
+        json_registers.0.into_iter().collect(),
+        json_registers
+            .1
+            .into_iter()
+            .map(|(name, register)| {
+                let rows = register
+                    .into_iter()
+                    .map(|row| row.into_iter().map(|value| value.0).collect())
+                    .collect();
+                (name, rows)
+            })
+            .collect(),
+        json_registers
+            .2
+            .into_iter()
+            .map(|(name, register)| {
+                let rows = register
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .map(|(re, im)| Complex64::new(re.0, im.0))
+                            .collect()
+                    })
+                    .collect();
+                (name, rows)
+            })
+            .collect(),
+    ))
+}
+
+/// Computes the reduced density matrix of a subset of qubits by tracing out all other qubits.
+///
+/// The density matrix uses the same layout as the output of [crate::operations::PragmaGetDensityMatrix]:
+/// basis states are ordered in little endian notation, qubit 0 corresponds to the least significant bit of the index.
+/// In the reduced density matrix `keep[0]` corresponds to the least significant bit of the reduced index,
+/// so the order of `keep` determines the order of the qubits in the result.
+/// This is the layout of the output of [crate::operations::PragmaGetReducedDensityMatrix].
+///
+/// # Arguments
+///
+/// * `rho` - The density matrix of the full register with dimension 2^number_qubits x 2^number_qubits.
+/// * `keep` - The qubits that are kept in the reduced density matrix.
+/// * `number_qubits` - The number of qubits of the full register.
+///
+/// # Returns
+///
+/// * `Ok(Array2<Complex64>)` - The reduced density matrix with dimension 2^k x 2^k for k kept qubits.
+/// * `Err(RoqoqoError::MismatchedRegisterDimension)` - The dimension of rho does not match the number of qubits.
+/// * `Err(RoqoqoError::GenericError)` - A kept qubit is out of range or appears more than once.
+pub fn partial_trace(
+    rho: &Array2<Complex64>,
+    keep: &[usize],
+    number_qubits: usize,
+) -> Result<Array2<Complex64>, RoqoqoError> {
+    let dimension = 1_usize << number_qubits;
+    if rho.dim() != (dimension, dimension) {
+        return Err(RoqoqoError::MismatchedRegisterDimension {
+            dim: rho.len(),
+            number_qubits,
+        });
+    }
+    let mut unique_qubits: HashSet<usize> = HashSet::new();
+    for qubit in keep {
+        if *qubit >= number_qubits {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Kept qubit {} is out of range for a register with {} qubits",
+                    qubit, number_qubits
+                ),
+            });
+        }
+        if !unique_qubits.insert(*qubit) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Kept qubit {} appears more than once", qubit),
+            });
+        }
+    }
+    let traced: Vec<usize> = (0..number_qubits)
+        .filter(|qubit| !unique_qubits.contains(qubit))
+        .collect();
+    // Maps the bits of a reduced index onto the given qubits of the full register
+    let full_index = |index: usize, qubits: &[usize]| -> usize {
+        qubits
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| index & (1 << bit) != 0)
+            .fold(0, |acc, (_, qubit)| acc | (1 << qubit))
+    };
+    let reduced_dimension = 1_usize << keep.len();
+    let mut reduced: Array2<Complex64> = Array2::zeros((reduced_dimension, reduced_dimension));
+    for row in 0..reduced_dimension {
+        let full_row = full_index(row, keep);
+        for column in 0..reduced_dimension {
+            let full_column = full_index(column, keep);
+            for environment in 0..(1_usize << traced.len()) {
+                let full_environment = full_index(environment, &traced);
+                reduced[(row, column)] +=
+                    rho[(full_row | full_environment, full_column | full_environment)];
+            }
+        }
+    }
+    Ok(reduced)
+}
+
+/// Order of the bits in the bitstrings of a counts dictionary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// The most significant bit is the first character, the bit of qubit 0 is the last character.
+    MsbFirst,
+    /// The least significant bit is the first character, the bit of qubit 0 is the first character.
+    LsbFirst,
+}
+
+/// Counts how often each bitstring was measured in a bit register.
+///
+/// Each measurement record is formatted as a string of `0` and `1` characters containing all
+/// bits of the record, including entries of the register that were not written by a measurement.
+///
+/// # Arguments
+///
+/// * `register` - The measurement records.
+/// * `bit_order` - The order of the bits in the bitstrings.
+///
+/// # Returns
+///
+/// * `HashMap<String, usize>` - The number of records for each measured bitstring.
+pub fn counts(register: &BitOutputRegister, bit_order: BitOrder) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for record in register.iter() {
+        let bits = record.iter().map(|bit| if *bit { '1' } else { '0' });
+        let bitstring: String = match bit_order {
+            BitOrder::MsbFirst => bits.rev().collect(),
+            BitOrder::LsbFirst => bits.collect(),
+        };
+        *counts.entry(bitstring).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Constructs a bit register from a counts dictionary.
+///
+/// The inverse of [counts]. When `number_shots` equals the total of the counts, each bitstring
+/// appears in the register as often as it is counted. Otherwise the counts are scaled to
+/// `number_shots` records, with rounding distributed by the largest remainders.
+/// The records are sorted by bitstring.
+///
+/// # Arguments
+///
+/// * `counts` - The number of records for each bitstring.
+/// * `number_shots` - The number of records in the returned register.
+/// * `bit_order` - The order of the bits in the bitstrings.
+///
+/// # Returns
+///
+/// * `Ok(BitOutputRegister)` - The register with `number_shots` measurement records.
+/// * `Err(RoqoqoError::GenericError)` - A bitstring is invalid, the bitstrings have different lengths or all counts are zero.
+pub fn register_from_counts(
+    counts: &HashMap<String, usize>,
+    number_shots: usize,
+    bit_order: BitOrder,
+) -> Result<BitOutputRegister, RoqoqoError> {
+    let mut bitstrings: Vec<(&String, usize)> = counts
+        .iter()
+        .map(|(bitstring, count)| (bitstring, *count))
+        .collect();
+    bitstrings.sort();
+    let mut records: Vec<(BitRegister, usize)> = Vec::with_capacity(bitstrings.len());
+    for (bitstring, count) in bitstrings.iter() {
+        let mut record = bitstring
+            .chars()
+            .map(|character| match character {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Bitstring {} contains characters other than 0 and 1",
+                        bitstring
+                    ),
+                }),
+            })
+            .collect::<Result<BitRegister, RoqoqoError>>()?;
+        if let Some((first, _)) = records.first() {
+            if first.len() != record.len() {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Bitstrings have different lengths {} and {}",
+                        first.len(),
+                        record.len()
+                    ),
+                });
+            }
+        }
+        if bit_order == BitOrder::MsbFirst {
+            record.reverse();
+        }
+        records.push((record, *count));
+    }
+    let total: usize = records.iter().map(|(_, count)| count).sum();
+    if total == 0 {
+        if number_shots == 0 {
+            return Ok(Vec::new());
+        }
+        return Err(RoqoqoError::GenericError {
+            msg: "Cannot construct register with measurement records from zero counts".to_string(),
+        });
+    }
+    // Largest remainder method, the scaled counts sum up to number_shots
+    let mut shots: Vec<usize> = Vec::with_capacity(records.len());
+    let mut remainders: Vec<(usize, usize)> = Vec::with_capacity(records.len());
+    for (index, (_, count)) in records.iter().enumerate() {
+        let scaled = count * number_shots;
+        shots.push(scaled / total);
+        remainders.push((scaled % total, index));
+    }
+    let missing = number_shots - shots.iter().sum::<usize>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, index) in remainders.into_iter().take(missing) {
+        shots[index] += 1;
+    }
+    Ok(records
+        .into_iter()
+        .zip(shots)
+        .flat_map(|((record, _), shots)| std::iter::repeat(record).take(shots))
+        .collect())
+}
@@ -0,0 +1,281 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of roqoqo Circuits on devices.
+//!
+//! A [ScheduledCircuit] keeps the start time of every operation of a Circuit computed by a scheduler,
+//! e.g. [schedule_asap], for visualization and export.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::circuitdag::loop_repetitions;
+use crate::devices::{gate_time, Device};
+use crate::operations::{GateOperation, InvolveQubits, InvolvedQubits, Operate, Operation};
+use crate::{Circuit, CircuitDag, RoqoqoBackendError, RoqoqoError};
+
+/// The timing of a single scheduled operation, e.g. one bar of a Gantt chart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimelineEntry {
+    /// The qubits the operation acts on, empty for operations without qubits like definitions.
+    pub qubits: Vec<usize>,
+    /// The hqslang name of the operation.
+    pub hqslang: String,
+    /// The start time of the operation.
+    pub start: f64,
+    /// The time the operation takes.
+    pub duration: f64,
+    /// The index of the operation in the Circuit.
+    pub index: usize,
+}
+
+/// A Circuit together with the start times of its operations.
+///
+/// The start times and durations are given in the order of the operations returned by
+/// [Circuit::iter], i.e. the definitions first, followed by the other operations.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct ScheduledCircuit {
+    /// The scheduled Circuit.
+    pub circuit: Circuit,
+    /// The start time of each operation of the Circuit.
+    pub start_times: Vec<f64>,
+    /// The time each operation of the Circuit takes.
+    pub durations: Vec<f64>,
+    /// The optional mapping of the qubits of the Circuit to the qubits of the device.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::sorted_serialization::serialize_sorted_optional_map")
+    )]
+    pub qubit_assignment: Option<HashMap<usize, usize>>,
+}
+
+impl ScheduledCircuit {
+    /// Returns the start times of the operations of the scheduled Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `&[f64]` - The start time of each operation in the order of [Circuit::iter].
+    pub fn start_times(&self) -> &[f64] {
+        &self.start_times
+    }
+
+    /// Returns the total duration of the scheduled Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The latest end time of all operations, zero for an empty Circuit.
+    pub fn total_duration(&self) -> f64 {
+        self.start_times
+            .iter()
+            .zip(self.durations.iter())
+            .map(|(start, duration)| start + duration)
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the timeline of the scheduled Circuit with one entry per operation.
+    ///
+    /// Operations acting on all qubits are listed with all qubits used in the Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<TimelineEntry>` - The timing of each operation in the order of [Circuit::iter].
+    pub fn timeline(&self) -> Vec<TimelineEntry> {
+        let all_qubits: Vec<usize> = self
+            .circuit
+            .iter()
+            .filter_map(|operation| match operation.involved_qubits() {
+                InvolvedQubits::Set(qubits) => Some(qubits),
+                _ => None,
+            })
+            .flatten()
+            .collect::<BTreeSet<usize>>()
+            .into_iter()
+            .collect();
+        self.circuit
+            .iter()
+            .zip(self.start_times.iter().zip(self.durations.iter()))
+            .enumerate()
+            .map(|(index, (operation, (start, duration)))| TimelineEntry {
+                qubits: match operation.involved_qubits() {
+                    InvolvedQubits::Set(qubits) => qubits
+                        .into_iter()
+                        .collect::<BTreeSet<usize>>()
+                        .into_iter()
+                        .collect(),
+                    InvolvedQubits::All => all_qubits.clone(),
+                    InvolvedQubits::None => Vec::new(),
+                },
+                hqslang: operation.hqslang().to_string(),
+                start: *start,
+                duration: *duration,
+                index,
+            })
+            .collect()
+    }
+
+    /// Exports the timeline of the scheduled Circuit to json for rendering a Gantt chart.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - A json list with the fields `qubits`, `hqslang`, `start`, `duration` and `index` for each operation.
+    #[cfg(feature = "serialize")]
+    pub fn to_timeline_json(&self) -> String {
+        serde_json::to_string(&self.timeline()).expect("Unexpected failure to serialize timeline")
+    }
+
+    /// Returns the timeline entries grouped by qubit and sorted by start time.
+    ///
+    /// Operations without qubits are not part of any qubit row.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BTreeMap<usize, Vec<TimelineEntry>>)` - The timeline entries of each qubit.
+    /// * `Err(RoqoqoError::OverlappingOperations)` - Two operations on the same qubit overlap in time.
+    pub fn per_qubit_timeline(&self) -> Result<BTreeMap<usize, Vec<TimelineEntry>>, RoqoqoError> {
+        let mut rows: BTreeMap<usize, Vec<TimelineEntry>> = BTreeMap::new();
+        for entry in self.timeline() {
+            for qubit in entry.qubits.iter() {
+                rows.entry(*qubit).or_default().push(entry.clone());
+            }
+        }
+        for (qubit, row) in rows.iter_mut() {
+            row.sort_by(|a, b| a.start.total_cmp(&b.start).then(a.index.cmp(&b.index)));
+            // The operation ending last among the operations already checked in the row
+            let mut latest: Option<&TimelineEntry> = None;
+            for entry in row.iter() {
+                if let Some(previous) = latest {
+                    if entry.start < previous.start + previous.duration {
+                        return Err(RoqoqoError::OverlappingOperations {
+                            qubit: *qubit,
+                            first_hqslang: previous.hqslang.clone(),
+                            first_index: previous.index,
+                            second_hqslang: entry.hqslang.clone(),
+                            second_index: entry.index,
+                        });
+                    }
+                }
+                if latest.map_or(true, |previous| {
+                    entry.start + entry.duration > previous.start + previous.duration
+                }) {
+                    latest = Some(entry);
+                }
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// Schedules the operations of a Circuit as soon as possible on a device.
+///
+/// Every operation starts as soon as all operations it depends on in the [CircuitDag] of the Circuit
+/// have finished. The durations of the operations are given by [gate_time]: gates take the gate time
+/// of the device, noise PRAGMAs their gate time and PragmaSleep its sleep time.
+/// A PragmaLoop takes the execution time of its circuit times its number of repetitions.
+/// Operations without a defined time, like measurements, take no time.
+///
+/// # Arguments
+///
+/// * `circuit` - The Circuit that is scheduled.
+/// * `device` - The device providing the gate times.
+///
+/// # Returns
+///
+/// * `Ok(ScheduledCircuit)` - The Circuit with the start times of its operations.
+/// * `Err(RoqoqoBackendError::GenericError)` - A gate of the Circuit is not available on the device.
+/// * `Err(RoqoqoBackendError::RoqoqoError)` - A PragmaLoop has symbolic repetitions.
+///
+/// # Example
+///
+/// ```
+/// use roqoqo::devices::AllToAllDevice;
+/// use roqoqo::operations::{RotateX, CNOT};
+/// use roqoqo::schedule::schedule_asap;
+/// use roqoqo::Circuit;
+///
+/// let device = AllToAllDevice::new(3, &["RotateX".to_string()], &["CNOT".to_string()], 1.0)
+///     .with_two_qubit_gate_time("CNOT", 2.0);
+/// let mut circuit = Circuit::new();
+/// circuit += RotateX::new(0, 1.0.into());
+/// circuit += RotateX::new(2, 1.0.into());
+/// circuit += CNOT::new(0, 1);
+///
+/// let scheduled = schedule_asap(&circuit, &device).unwrap();
+/// assert_eq!(scheduled.start_times(), &[0.0, 0.0, 1.0]);
+/// assert_eq!(scheduled.total_duration(), 3.0);
+/// ```
+pub fn schedule_asap(
+    circuit: &Circuit,
+    device: &impl Device,
+) -> Result<ScheduledCircuit, RoqoqoBackendError> {
+    let durations = circuit
+        .iter()
+        .enumerate()
+        .map(|(index, operation)| operation_duration(operation, index, device))
+        .collect::<Result<Vec<f64>, RoqoqoBackendError>>()?;
+    // The nodes of the dag are added in the order of the operations of the Circuit,
+    // so all predecessors of an operation are scheduled before the operation itself.
+    let dag = CircuitDag::from(circuit.clone());
+    let mut start_times: Vec<f64> = Vec::with_capacity(durations.len());
+    for node in 0..durations.len() {
+        let start = dag
+            .predecessors(node)
+            .into_iter()
+            .map(|predecessor| start_times[predecessor] + durations[predecessor])
+            .fold(0.0, f64::max);
+        start_times.push(start);
+    }
+    Ok(ScheduledCircuit {
+        circuit: circuit.clone(),
+        start_times,
+        durations,
+        qubit_assignment: None,
+    })
+}
+
+/// Returns the duration of an operation, failing for gates not available on the device.
+///
+/// The index of the operation is reported when a PragmaLoop has symbolic repetitions.
+pub(crate) fn operation_duration(
+    operation: &Operation,
+    index: usize,
+    device: &impl Device,
+) -> Result<f64, RoqoqoBackendError> {
+    if let Operation::PragmaLoop(op) = operation {
+        let repetitions = loop_repetitions(op, index)?;
+        return Ok(
+            repetitions as f64 * CircuitDag::from(op.circuit().clone()).execution_time(device)?
+        );
+    }
+    match gate_time(device, operation) {
+        Some(time) => Ok(time),
+        None if GateOperation::try_from(operation).is_ok() => {
+            let qubits: Vec<usize> = match operation.involved_qubits() {
+                InvolvedQubits::Set(qubits) => {
+                    let mut qubits: Vec<usize> = qubits.into_iter().collect();
+                    qubits.sort_unstable();
+                    qubits
+                }
+                _ => Vec::new(),
+            };
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Gate {} on qubits {:?} is not available on the device",
+                    operation.hqslang(),
+                    qubits
+                ),
+            })
+        }
+        None => Ok(0.0),
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialization of HashMap fields with entries sorted by key.
+//!
+//! The iteration order of a HashMap depends on its random hasher state,
+//! so serializing a HashMap directly produces different output for equal values.
+//! The functions are used with `#[serde(serialize_with = ...)]` and produce the same format
+//! as the default serialization, so deserialization accepts entries in any order.
+
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// Serializes a HashMap with its entries sorted by key.
+pub(crate) fn serialize_sorted_map<K, V, S>(
+    map: &HashMap<K, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Ord + Hash,
+    V: Serialize,
+    S: Serializer,
+{
+    map.iter()
+        .collect::<BTreeMap<&K, &V>>()
+        .serialize(serializer)
+}
+
+/// Serializes an optional HashMap with its entries sorted by key.
+pub(crate) fn serialize_sorted_optional_map<K, V, S>(
+    map: &Option<HashMap<K, V>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Ord + Hash,
+    V: Serialize,
+    S: Serializer,
+{
+    map.as_ref()
+        .map(|map| map.iter().collect::<BTreeMap<&K, &V>>())
+        .serialize(serializer)
+}
@@ -0,0 +1,311 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Random circuit generation for tests and benchmarks.
+//!
+//! All functions are deterministic: calling them twice with the same seed returns the same result.
+
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use qoqo_calculator::CalculatorFloat;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::measurements::PauliZProductInput;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+
+/// Hqslang names and numbers of qubits of the gates supported by [random_circuit].
+pub const RANDOM_CIRCUIT_GATES: &[(&str, usize)] = &[
+    ("PauliX", 1),
+    ("PauliY", 1),
+    ("PauliZ", 1),
+    ("Hadamard", 1),
+    ("SGate", 1),
+    ("InvSGate", 1),
+    ("TGate", 1),
+    ("InvTGate", 1),
+    ("SqrtPauliX", 1),
+    ("InvSqrtPauliX", 1),
+    ("SqrtPauliY", 1),
+    ("InvSqrtPauliY", 1),
+    ("SXGate", 1),
+    ("Identity", 1),
+    ("RotateX", 1),
+    ("RotateY", 1),
+    ("RotateZ", 1),
+    ("PhaseShiftState0", 1),
+    ("PhaseShiftState1", 1),
+    ("RotateXY", 1),
+    ("RotateAroundSphericalAxis", 1),
+    ("GPi", 1),
+    ("GPi2", 1),
+    ("CNOT", 2),
+    ("ControlledPauliY", 2),
+    ("ControlledPauliZ", 2),
+    ("SWAP", 2),
+    ("ISwap", 2),
+    ("SqrtISwap", 2),
+    ("InvSqrtISwap", 2),
+    ("FSwap", 2),
+    ("MolmerSorensenXX", 2),
+    ("EchoCrossResonance", 2),
+    ("VariableMSXX", 2),
+    ("XY", 2),
+    ("ControlledPhaseShift", 2),
+    ("PMInteraction", 2),
+    ("ComplexPMInteraction", 2),
+    ("GivensRotation", 2),
+    ("GivensRotationLittleEndian", 2),
+    ("Qsim", 2),
+    ("Fsim", 2),
+    ("SpinInteraction", 2),
+    ("Bogoliubov", 2),
+    ("PhaseShiftedControlledZ", 2),
+    ("PhaseShiftedControlledPhase", 2),
+    ("ControlledRotateX", 2),
+    ("ControlledRotateXY", 2),
+    ("Toffoli", 3),
+    ("ControlledControlledPauliZ", 3),
+    ("ControlledSWAP", 3),
+    ("ControlledControlledPhaseShift", 3),
+    ("PhaseShiftedControlledControlledZ", 3),
+    ("PhaseShiftedControlledControlledPhase", 3),
+];
+
+/// Hqslang names of the Clifford gates used by [random_clifford_circuit].
+pub const CLIFFORD_GATES: &[&str] = &[
+    "PauliX",
+    "PauliY",
+    "PauliZ",
+    "Hadamard",
+    "SGate",
+    "InvSGate",
+    "SqrtPauliX",
+    "InvSqrtPauliX",
+    "SqrtPauliY",
+    "InvSqrtPauliY",
+    "CNOT",
+    "ControlledPauliY",
+    "ControlledPauliZ",
+    "SWAP",
+    "ISwap",
+];
+
+/// Creates a random circuit from a set of gates.
+///
+/// The circuit consists of `depth` layers. In every layer the qubits are visited in random order
+/// and every qubit not yet used in the layer starts a gate drawn uniformly from the gates of `gate_set`
+/// that fit on the remaining unused qubits. The additional qubits of multi-qubit gates are drawn
+/// from the unused qubits of the layer. Rotation angles are drawn uniformly from [0, 2π).
+///
+/// # Arguments
+///
+/// * `number_qubits` - The number of qubits of the circuit.
+/// * `depth` - The number of layers of the circuit.
+/// * `seed` - The seed of the random number generator.
+/// * `gate_set` - The hqslang names of the gates, see [RANDOM_CIRCUIT_GATES].
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The random circuit.
+/// * `Err(RoqoqoError::UnknownOperation)` - A gate name is not supported.
+/// * `Err(RoqoqoError::GenericError)` - The gate set is empty or no gate fits on `number_qubits` qubits.
+pub fn random_circuit(
+    number_qubits: usize,
+    depth: usize,
+    seed: u64,
+    gate_set: &[&str],
+) -> Result<Circuit, RoqoqoError> {
+    let mut gates: Vec<(&str, usize)> = Vec::with_capacity(gate_set.len());
+    for name in gate_set {
+        let gate = RANDOM_CIRCUIT_GATES
+            .iter()
+            .find(|(hqslang, _)| hqslang == name)
+            .ok_or_else(|| RoqoqoError::UnknownOperation {
+                hqslang: name.to_string(),
+            })?;
+        gates.push(*gate);
+    }
+    if gates.is_empty() {
+        return Err(RoqoqoError::GenericError {
+            msg: "Gate set of random circuit is empty".to_string(),
+        });
+    }
+    if number_qubits > 0 && gates.iter().all(|(_, n)| *n > number_qubits) {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "No gate of the gate set acts on {} or fewer qubits",
+                number_qubits
+            ),
+        });
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut circuit = Circuit::new();
+    for _ in 0..depth {
+        let mut free: Vec<usize> = (0..number_qubits).collect();
+        free.shuffle(&mut rng);
+        while let Some(qubit) = free.pop() {
+            let fitting: Vec<&(&str, usize)> =
+                gates.iter().filter(|(_, n)| *n <= free.len() + 1).collect();
+            let (name, n) = match fitting.choose(&mut rng) {
+                Some(gate) => **gate,
+                None => continue,
+            };
+            let mut qubits = vec![qubit];
+            for _ in 1..n {
+                let index = rng.gen_range(0..free.len());
+                qubits.push(free.swap_remove(index));
+            }
+            circuit += random_gate(name, &qubits, &mut rng);
+        }
+    }
+    Ok(circuit)
+}
+
+/// Creates a random circuit of Clifford gates.
+///
+/// Uses the layered construction of [random_circuit] with the gates of [CLIFFORD_GATES].
+///
+/// # Arguments
+///
+/// * `number_qubits` - The number of qubits of the circuit.
+/// * `depth` - The number of layers of the circuit.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Returns
+///
+/// * `Circuit` - The random Clifford circuit.
+pub fn random_clifford_circuit(number_qubits: usize, depth: usize, seed: u64) -> Circuit {
+    random_circuit(number_qubits, depth, seed, CLIFFORD_GATES)
+        .expect("Clifford gate set contains single-qubit gates known to random_circuit")
+}
+
+/// Creates a random PauliZProductInput.
+///
+/// Draws `n_products` PauliZ products on random non-empty subsets of the qubits, read out from the
+/// register `ro`, and adds one linear expectation value `random` combining all products with
+/// coefficients drawn uniformly from [-1, 1). A product drawn repeatedly is measured once and
+/// its coefficients are summed.
+///
+/// # Arguments
+///
+/// * `number_qubits` - The number of qubits of the measured circuits.
+/// * `n_products` - The number of PauliZ products.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Returns
+///
+/// * `Ok(PauliZProductInput)` - The random measurement input.
+/// * `Err(RoqoqoError::GenericError)` - PauliZ products are requested on zero qubits.
+pub fn random_pauli_measurement_input(
+    number_qubits: usize,
+    n_products: usize,
+    seed: u64,
+) -> Result<PauliZProductInput, RoqoqoError> {
+    if number_qubits == 0 && n_products > 0 {
+        return Err(RoqoqoError::GenericError {
+            msg: "PauliZ products require at least one qubit".to_string(),
+        });
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = PauliZProductInput::new(number_qubits, false);
+    let mut linear: HashMap<usize, f64> = HashMap::new();
+    for _ in 0..n_products {
+        let size = rng.gen_range(1..=number_qubits);
+        let mut mask: Vec<usize> = (0..number_qubits).collect();
+        mask.shuffle(&mut rng);
+        mask.truncate(size);
+        mask.sort_unstable();
+        let index = input.add_pauliz_product("ro".to_string(), mask)?;
+        *linear.entry(index).or_insert(0.0) += rng.gen_range(-1.0..1.0);
+    }
+    input.add_linear_exp_val("random".to_string(), linear)?;
+    Ok(input)
+}
+
+// Creates the gate with the hqslang name `name` acting on `qubits` with random angles.
+fn random_gate(name: &str, qubits: &[usize], rng: &mut StdRng) -> Operation {
+    let mut angle = || -> CalculatorFloat { rng.gen_range(0.0..(2.0 * PI)).into() };
+    let q = qubits;
+    match name {
+        "PauliX" => PauliX::new(q[0]).into(),
+        "PauliY" => PauliY::new(q[0]).into(),
+        "PauliZ" => PauliZ::new(q[0]).into(),
+        "Hadamard" => Hadamard::new(q[0]).into(),
+        "SGate" => SGate::new(q[0]).into(),
+        "InvSGate" => InvSGate::new(q[0]).into(),
+        "TGate" => TGate::new(q[0]).into(),
+        "InvTGate" => InvTGate::new(q[0]).into(),
+        "SqrtPauliX" => SqrtPauliX::new(q[0]).into(),
+        "InvSqrtPauliX" => InvSqrtPauliX::new(q[0]).into(),
+        "SqrtPauliY" => SqrtPauliY::new(q[0]).into(),
+        "InvSqrtPauliY" => InvSqrtPauliY::new(q[0]).into(),
+        "SXGate" => SXGate::new(q[0]).into(),
+        "Identity" => Identity::new(q[0]).into(),
+        "RotateX" => RotateX::new(q[0], angle()).into(),
+        "RotateY" => RotateY::new(q[0], angle()).into(),
+        "RotateZ" => RotateZ::new(q[0], angle()).into(),
+        "PhaseShiftState0" => PhaseShiftState0::new(q[0], angle()).into(),
+        "PhaseShiftState1" => PhaseShiftState1::new(q[0], angle()).into(),
+        "RotateXY" => RotateXY::new(q[0], angle(), angle()).into(),
+        "RotateAroundSphericalAxis" => {
+            RotateAroundSphericalAxis::new(q[0], angle(), angle(), angle()).into()
+        }
+        "GPi" => GPi::new(q[0], angle()).into(),
+        "GPi2" => GPi2::new(q[0], angle()).into(),
+        "CNOT" => CNOT::new(q[0], q[1]).into(),
+        "ControlledPauliY" => ControlledPauliY::new(q[0], q[1]).into(),
+        "ControlledPauliZ" => ControlledPauliZ::new(q[0], q[1]).into(),
+        "SWAP" => SWAP::new(q[0], q[1]).into(),
+        "ISwap" => ISwap::new(q[0], q[1]).into(),
+        "SqrtISwap" => SqrtISwap::new(q[0], q[1]).into(),
+        "InvSqrtISwap" => InvSqrtISwap::new(q[0], q[1]).into(),
+        "FSwap" => FSwap::new(q[0], q[1]).into(),
+        "MolmerSorensenXX" => MolmerSorensenXX::new(q[0], q[1]).into(),
+        "EchoCrossResonance" => EchoCrossResonance::new(q[0], q[1]).into(),
+        "VariableMSXX" => VariableMSXX::new(q[0], q[1], angle()).into(),
+        "XY" => XY::new(q[0], q[1], angle()).into(),
+        "ControlledPhaseShift" => ControlledPhaseShift::new(q[0], q[1], angle()).into(),
+        "PMInteraction" => PMInteraction::new(q[0], q[1], angle()).into(),
+        "ComplexPMInteraction" => ComplexPMInteraction::new(q[0], q[1], angle(), angle()).into(),
+        "GivensRotation" => GivensRotation::new(q[0], q[1], angle(), angle()).into(),
+        "GivensRotationLittleEndian" => {
+            GivensRotationLittleEndian::new(q[0], q[1], angle(), angle()).into()
+        }
+        "Qsim" => Qsim::new(q[0], q[1], angle(), angle(), angle()).into(),
+        "Fsim" => Fsim::new(q[0], q[1], angle(), angle(), angle()).into(),
+        "SpinInteraction" => SpinInteraction::new(q[0], q[1], angle(), angle(), angle()).into(),
+        "Bogoliubov" => Bogoliubov::new(q[0], q[1], angle(), angle()).into(),
+        "PhaseShiftedControlledZ" => PhaseShiftedControlledZ::new(q[0], q[1], angle()).into(),
+        "PhaseShiftedControlledPhase" => {
+            PhaseShiftedControlledPhase::new(q[0], q[1], angle(), angle()).into()
+        }
+        "ControlledRotateX" => ControlledRotateX::new(q[0], q[1], angle()).into(),
+        "ControlledRotateXY" => ControlledRotateXY::new(q[0], q[1], angle(), angle()).into(),
+        "Toffoli" => Toffoli::new(q[0], q[1], q[2]).into(),
+        "ControlledControlledPauliZ" => ControlledControlledPauliZ::new(q[0], q[1], q[2]).into(),
+        "ControlledSWAP" => ControlledSWAP::new(q[0], q[1], q[2]).into(),
+        "ControlledControlledPhaseShift" => {
+            ControlledControlledPhaseShift::new(q[0], q[1], q[2], angle()).into()
+        }
+        "PhaseShiftedControlledControlledZ" => {
+            PhaseShiftedControlledControlledZ::new(q[0], q[1], q[2], angle()).into()
+        }
+        "PhaseShiftedControlledControlledPhase" => {
+            PhaseShiftedControlledControlledPhase::new(q[0], q[1], q[2], angle(), angle()).into()
+        }
+        _ => unreachable!("Gate names are checked against RANDOM_CIRCUIT_GATES"),
+    }
+}
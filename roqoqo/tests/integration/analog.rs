@@ -0,0 +1,104 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for functions acting on analog operations
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::analog::effective_hamiltonian;
+use roqoqo::operations::*;
+use roqoqo::{Circuit, RoqoqoError};
+use std::collections::HashMap;
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
+
+/// Creates a Hamiltonian with a single PauliProduct
+fn hamiltonian(pp: PauliProduct, value: CalculatorFloat) -> SpinHamiltonian {
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian.add_operator_product(pp, value).unwrap();
+    hamiltonian
+}
+
+/// Test the effective Hamiltonian of two analog operations with different terms
+#[test]
+fn effective_hamiltonian_two_terms() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += ApplyConstantSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().z(0), 1.0.into()),
+        0.5.into(),
+    );
+    circuit += PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    circuit += ApplyConstantSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().x(0).x(1), 2.0.into()),
+        1.5.into(),
+    );
+    circuit += ApplyConstantSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().z(0), 1.0.into()),
+        "t".into(),
+    );
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    let effective = effective_hamiltonian(&circuit).unwrap();
+    assert_eq!(
+        effective.get(&PauliProduct::new().z(0)),
+        &(CalculatorFloat::from("t") + 0.5)
+    );
+    assert_eq!(effective.get(&PauliProduct::new().x(0).x(1)), &3.0.into());
+    assert_eq!(effective.len(), 2);
+}
+
+/// Test the effective Hamiltonian of a circuit without analog operations is empty
+#[test]
+fn effective_hamiltonian_empty() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    assert_eq!(
+        effective_hamiltonian(&circuit).unwrap(),
+        SpinHamiltonian::new()
+    );
+}
+
+/// Test that gates interleaved with analog operations and time-dependent Hamiltonians are rejected
+#[test]
+fn effective_hamiltonian_errors() {
+    let mut circuit = Circuit::new();
+    circuit += ApplyConstantSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().z(0), 1.0.into()),
+        1.0.into(),
+    );
+    circuit += PauliX::new(0);
+    circuit += ApplyConstantSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().z(0), 1.0.into()),
+        1.0.into(),
+    );
+    assert_eq!(
+        effective_hamiltonian(&circuit),
+        Err(RoqoqoError::GenericError {
+            msg: "Gate operation PauliX is interleaved with the analog operations".to_string()
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += ApplyTimeDependentSpinHamiltonian::new(
+        hamiltonian(PauliProduct::new().z(0), "omega".into()),
+        vec![1.0],
+        HashMap::from([("omega".to_string(), vec![1.0])]),
+    );
+    assert_eq!(
+        effective_hamiltonian(&circuit),
+        Err(RoqoqoError::GenericError {
+            msg: "ApplyTimeDependentSpinHamiltonian has no constant effective Hamiltonian"
+                .to_string()
+        })
+    );
+}
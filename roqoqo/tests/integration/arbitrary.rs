@@ -0,0 +1,87 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Property tests for arbitrary operations
+
+use arbitrary::{Arbitrary, Unstructured};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use roqoqo::operations::*;
+use std::collections::{HashMap, HashSet};
+
+/// Number of operations generated by each property test
+const NUMBER_SAMPLES: u64 = 3000;
+
+/// Generates the arbitrary operations from seeded random bytes
+fn arbitrary_operations() -> Vec<Operation> {
+    let mut operations = Vec::new();
+    for seed in 0..NUMBER_SAMPLES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut bytes = vec![0u8; 256];
+        rng.fill_bytes(&mut bytes);
+        let mut unstructured = Unstructured::new(&bytes);
+        if let Ok(operation) = Operation::arbitrary(&mut unstructured) {
+            operations.push(operation);
+        }
+    }
+    operations
+}
+
+/// Test that the generated operations cover most operations and use distinct qubits
+#[test]
+fn test_arbitrary_operations() {
+    let operations = arbitrary_operations();
+    assert!(operations.len() as u64 > NUMBER_SAMPLES * 9 / 10);
+    let hqslang: HashSet<&str> = operations.iter().map(|op| op.hqslang()).collect();
+    assert!(hqslang.len() > 100);
+    for operation in operations.iter() {
+        if let Ok(gate) = GateOperation::try_from(operation) {
+            let qubits = match gate.involved_qubits() {
+                InvolvedQubits::Set(qubits) => qubits,
+                _ => continue,
+            };
+            assert!(qubits.iter().all(|qubit| *qubit < 8));
+        }
+    }
+}
+
+/// Test that bincode serialization round trips are lossless
+#[test]
+fn test_bincode_round_trip() {
+    for operation in arbitrary_operations() {
+        let serialized = bincode::serialize(&operation).unwrap();
+        let deserialized: Operation = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, operation);
+    }
+}
+
+/// Test that json serialization round trips are lossless
+#[test]
+fn test_json_round_trip() {
+    for operation in arbitrary_operations() {
+        let serialized = serde_json::to_string(&operation).unwrap();
+        let deserialized: Operation = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, operation);
+    }
+}
+
+/// Test that remapping with the identity map does not change operations
+#[test]
+fn test_remap_qubits_identity() {
+    for operation in arbitrary_operations() {
+        let mapping: HashMap<usize, usize> = match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => qubits.into_iter().map(|q| (q, q)).collect(),
+            _ => HashMap::new(),
+        };
+        assert_eq!(operation.remap_qubits(&mapping), Ok(operation.clone()));
+    }
+}
@@ -0,0 +1,559 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::{
+    circuit_fingerprint, CacheStatistics, CachingBackend, CircuitCache, MockSamplingBackend,
+    RegisterResult,
+};
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::operations::{self, Operation};
+use roqoqo::prelude::*;
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+/// Backend counting its runs and returning the run number in the float register "runs"
+#[derive(Debug, Default)]
+struct CountingBackend {
+    runs: Cell<usize>,
+}
+
+impl EvaluatingBackend for CountingBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let operations: Vec<&Operation> = circuit.collect();
+        if operations.iter().any(|op| op.hqslang() == "PauliY") {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "PauliY is not supported".to_string(),
+            });
+        }
+        self.runs.set(self.runs.get() + 1);
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        let _ = bit_registers.insert("ro".to_string(), vec![vec![true, false]]);
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let _ = float_registers.insert("runs".to_string(), vec![vec![self.runs.get() as f64]]);
+        let complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        Ok((bit_registers, float_registers, complex_registers))
+    }
+}
+
+fn circuit(angle: f64) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, angle.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit
+}
+
+/// Test that a repeated circuit is only run once on the wrapped backend
+#[test]
+fn test_cache_hit() {
+    let backend = CachingBackend::new(CountingBackend::default(), 10);
+    let first = backend.run_circuit(&circuit(0.5)).unwrap();
+    let second = backend.run_circuit(&circuit(0.5)).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(backend.backend().runs.get(), 1);
+    assert_eq!(
+        backend.cache_statistics(),
+        CacheStatistics {
+            hits: 1,
+            misses: 1,
+            size: 1,
+            max_size: 10
+        }
+    );
+
+    let third = backend.run_circuit(&circuit(1.0)).unwrap();
+    assert_eq!(third.1.get("runs").unwrap(), &vec![vec![2.0]]);
+    assert_eq!(backend.backend().runs.get(), 2);
+    assert_eq!(backend.cache_statistics().misses, 2);
+
+    backend.clear_cache();
+    assert_eq!(
+        backend.cache_statistics(),
+        CacheStatistics {
+            hits: 0,
+            misses: 0,
+            size: 0,
+            max_size: 10
+        }
+    );
+    let _ = backend.run_circuit(&circuit(0.5)).unwrap();
+    assert_eq!(backend.backend().runs.get(), 3);
+}
+
+/// Test that modifying returned registers does not change the cached registers
+#[test]
+fn test_cache_hit_deep_copy() {
+    let backend = CachingBackend::new(CountingBackend::default(), 10);
+    let mut first = backend.run_circuit(&circuit(0.5)).unwrap();
+    first.0.get_mut("ro").unwrap()[0][0] = false;
+    first.1.get_mut("runs").unwrap().push(vec![5.0]);
+    let second = backend.run_circuit(&circuit(0.5)).unwrap();
+    assert_eq!(second.0.get("ro").unwrap(), &vec![vec![true, false]]);
+    assert_eq!(second.1.get("runs").unwrap(), &vec![vec![1.0]]);
+}
+
+/// Test that the least recently used circuit is evicted from a full cache
+#[test]
+fn test_cache_lru_eviction() {
+    let backend = CachingBackend::new(CountingBackend::default(), 2);
+    let _ = backend.run_circuit(&circuit(0.0)).unwrap();
+    let _ = backend.run_circuit(&circuit(1.0)).unwrap();
+    // Marks circuit(0.0) as most recently used
+    let _ = backend.run_circuit(&circuit(0.0)).unwrap();
+    // Evicts circuit(1.0)
+    let _ = backend.run_circuit(&circuit(2.0)).unwrap();
+    assert_eq!(backend.backend().runs.get(), 3);
+    assert_eq!(backend.cache_statistics().size, 2);
+
+    let _ = backend.run_circuit(&circuit(0.0)).unwrap();
+    assert_eq!(backend.backend().runs.get(), 3);
+    let _ = backend.run_circuit(&circuit(1.0)).unwrap();
+    assert_eq!(backend.backend().runs.get(), 4);
+    assert_eq!(
+        backend.cache_statistics(),
+        CacheStatistics {
+            hits: 2,
+            misses: 4,
+            size: 2,
+            max_size: 2
+        }
+    );
+}
+
+/// Test that a cache of size zero runs every circuit
+#[test]
+fn test_cache_disabled() {
+    let backend = CachingBackend::new(CountingBackend::default(), 0);
+    let _ = backend.run_circuit(&circuit(0.5)).unwrap();
+    let _ = backend.run_circuit(&circuit(0.5)).unwrap();
+    assert_eq!(backend.backend().runs.get(), 2);
+    assert_eq!(backend.cache_statistics().size, 0);
+}
+
+/// Test that failed runs are not cached
+#[test]
+fn test_cache_error() {
+    let backend = CachingBackend::new(CountingBackend::default(), 10);
+    let mut failing = circuit(0.5);
+    failing += operations::PauliY::new(0);
+    assert!(backend.run_circuit(&failing).is_err());
+    assert!(backend.run_circuit(&failing).is_err());
+    assert_eq!(backend.cache_statistics().hits, 0);
+    assert_eq!(backend.cache_statistics().size, 0);
+}
+
+/// Test that measurement circuits including the constant circuit are cached
+#[test]
+fn test_cache_measurement() {
+    let mut input = PauliZProductInput::new(2, false);
+    let index = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    let mut linear: HashMap<usize, f64> = HashMap::new();
+    let _ = linear.insert(index, 1.0);
+    input.add_linear_exp_val("z0".to_string(), linear).unwrap();
+    let measurement = |angle: f64| {
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += operations::RotateY::new(0, angle.into());
+        PauliZProduct {
+            constant_circuit: Some(constant_circuit),
+            circuits: vec![circuit(0.0)],
+            input: input.clone(),
+        }
+    };
+    let backend = CachingBackend::new(CountingBackend::default(), 10);
+    let first = backend.run_measurement(&measurement(0.1)).unwrap();
+    let second = backend.run_measurement(&measurement(0.1)).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(backend.backend().runs.get(), 1);
+    let _ = backend.run_measurement(&measurement(0.2)).unwrap();
+    assert_eq!(backend.backend().runs.get(), 2);
+}
+
+/// Test the circuit fingerprint and the standalone cache
+#[test]
+fn test_circuit_fingerprint() {
+    assert_eq!(
+        circuit_fingerprint(circuit(0.5).iter()),
+        circuit_fingerprint(circuit(0.5).iter())
+    );
+    assert_ne!(
+        circuit_fingerprint(circuit(0.5).iter()),
+        circuit_fingerprint(circuit(1.5).iter())
+    );
+
+    let mut cache = CircuitCache::new(1);
+    let operations: Vec<Operation> = circuit(0.5).iter().cloned().collect();
+    assert_eq!(cache.get(&operations), None);
+    let registers = (HashMap::new(), HashMap::new(), HashMap::new());
+    cache.insert(operations.clone(), registers.clone());
+    // Inserting a cached circuit again does not duplicate it
+    cache.insert(operations.clone(), registers.clone());
+    assert_eq!(cache.get(&operations), Some(registers));
+    assert_eq!(
+        cache.statistics(),
+        CacheStatistics {
+            hits: 1,
+            misses: 1,
+            size: 1,
+            max_size: 1
+        }
+    );
+}
+
+/// Circuit measuring with a PragmaRepeatedMeasurement whose qubit mapping is built in the given order
+fn repeated_measurement_circuit(qubits: impl Iterator<Item = usize>) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 16, true);
+    circuit += operations::PragmaRepeatedMeasurement::new(
+        "ro".to_string(),
+        10,
+        Some(qubits.map(|qubit| (qubit, 15 - qubit)).collect()),
+    );
+    circuit
+}
+
+/// Test that the fingerprint does not depend on the HashMap order of operations
+#[test]
+fn test_circuit_fingerprint_hashmap_order() {
+    let forward = repeated_measurement_circuit(0..16);
+    let reversed = repeated_measurement_circuit((0..16).rev());
+    assert_eq!(forward, reversed);
+    assert_eq!(
+        circuit_fingerprint(forward.iter()),
+        circuit_fingerprint(reversed.iter())
+    );
+
+    let mut cache = CircuitCache::new(1);
+    let registers = (HashMap::new(), HashMap::new(), HashMap::new());
+    cache.insert(forward.iter().cloned().collect(), registers.clone());
+    let operations: Vec<Operation> = reversed.iter().cloned().collect();
+    assert_eq!(cache.get(&operations), Some(registers));
+}
+
+/// Test that the fingerprint uses a hash function with fixed keys
+#[test]
+fn test_circuit_fingerprint_fixed() {
+    // 64-bit FNV-1a of the bincode serialization of the operations
+    let mut expected: u64 = 0xcbf2_9ce4_8422_2325;
+    assert_eq!(circuit_fingerprint(Circuit::new().iter()), expected);
+    for operation in circuit(0.5).iter() {
+        for byte in bincode::serialize(operation).unwrap() {
+            expected ^= u64::from(byte);
+            expected = expected.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    assert_eq!(circuit_fingerprint(circuit(0.5).iter()), expected);
+}
+
+/// Test that the least recently used circuit is evicted after many lookups
+#[test]
+fn test_cache_lru_eviction_many_lookups() {
+    let mut cache = CircuitCache::new(2);
+    let registers = (HashMap::new(), HashMap::new(), HashMap::new());
+    let first: Vec<Operation> = circuit(0.0).iter().cloned().collect();
+    let second: Vec<Operation> = circuit(1.0).iter().cloned().collect();
+    let third: Vec<Operation> = circuit(2.0).iter().cloned().collect();
+    cache.insert(first.clone(), registers.clone());
+    cache.insert(second.clone(), registers.clone());
+    for _ in 0..100 {
+        assert!(cache.get(&second).is_some());
+        assert!(cache.get(&first).is_some());
+    }
+    // Evicts second
+    cache.insert(third.clone(), registers.clone());
+    assert!(cache.get(&second).is_none());
+    assert!(cache.get(&first).is_some());
+    assert!(cache.get(&third).is_some());
+    assert_eq!(cache.statistics().size, 2);
+}
+
+/// Backend evaluating the classical bit operations of a circuit in a single run
+///
+/// With native snapshots PragmaSnapshotRegisters copies the bit registers into the snapshot registers,
+/// otherwise the PRAGMA is rejected.
+#[derive(Debug, Default)]
+struct BitRegisterBackend {
+    native_snapshots: bool,
+}
+
+impl EvaluatingBackend for BitRegisterBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let mut registers: HashMap<String, Vec<bool>> = HashMap::new();
+        let mut output_names: Vec<String> = Vec::new();
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        for operation in circuit {
+            match operation {
+                Operation::DefinitionBit(op) => {
+                    let _ = registers.insert(op.name().clone(), vec![false; *op.length()]);
+                    if *op.is_output() {
+                        output_names.push(op.name().clone());
+                    }
+                }
+                Operation::InputBit(op) => {
+                    registers.get_mut(op.name()).unwrap()[*op.index()] = *op.value();
+                }
+                Operation::PragmaSnapshotRegisters(op) if self.native_snapshots => {
+                    for name in op.readout_names() {
+                        let _ = bit_registers
+                            .insert(op.snapshot_name(name), vec![registers[name].clone()]);
+                    }
+                }
+                _ => {
+                    return Err(RoqoqoBackendError::OperationNotInBackend {
+                        backend: "BitRegisterBackend",
+                        hqslang: operation.hqslang(),
+                    })
+                }
+            }
+        }
+        for name in output_names {
+            let _ = bit_registers.insert(name.clone(), vec![registers[&name].clone()]);
+        }
+        Ok((bit_registers, HashMap::new(), HashMap::new()))
+    }
+}
+
+fn snapshot_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::DefinitionBit::new("flags".to_string(), 1, false);
+    circuit += operations::InputBit::new("ro".to_string(), 0, true);
+    circuit += operations::PragmaSnapshotRegisters::new(
+        vec!["ro".to_string(), "flags".to_string()],
+        "first".to_string(),
+    );
+    circuit += operations::InputBit::new("ro".to_string(), 1, true);
+    circuit += operations::InputBit::new("flags".to_string(), 0, true);
+    circuit +=
+        operations::PragmaSnapshotRegisters::new(vec!["ro".to_string()], "second".to_string());
+    circuit
+}
+
+/// Test snapshot registers are written by a backend with native support and by run_circuit_with_snapshots
+#[test]
+fn test_snapshot_registers() {
+    let mut expected: HashMap<String, BitOutputRegister> = HashMap::new();
+    let _ = expected.insert("ro".to_string(), vec![vec![true, true]]);
+    let _ = expected.insert("first_ro".to_string(), vec![vec![true, false]]);
+    let _ = expected.insert("first_flags".to_string(), vec![vec![false]]);
+    let _ = expected.insert("second_ro".to_string(), vec![vec![true, true]]);
+
+    let native = BitRegisterBackend {
+        native_snapshots: true,
+    };
+    let (bit_registers, float_registers, complex_registers) =
+        native.run_circuit(&snapshot_circuit()).unwrap();
+    assert_eq!(bit_registers, expected);
+    assert!(float_registers.is_empty());
+    assert!(complex_registers.is_empty());
+
+    let backend = BitRegisterBackend::default();
+    assert!(backend.run_circuit(&snapshot_circuit()).is_err());
+    let (bit_registers, _, _) = backend
+        .run_circuit_with_snapshots(&snapshot_circuit())
+        .unwrap();
+    assert_eq!(bit_registers, expected);
+
+    let mut circuit = circuit(0.5);
+    circuit += operations::PragmaSnapshotRegisters::new(vec!["ro".to_string()], "last".to_string());
+    let (bit_registers, _, _) = CountingBackend::default()
+        .run_circuit_with_snapshots(&circuit)
+        .unwrap();
+    assert_eq!(bit_registers.get("last_ro"), Some(&vec![vec![true, false]]));
+}
+
+/// Test run_circuit_with_snapshots fails when a snapshot register is not available
+#[test]
+fn test_snapshot_registers_missing() {
+    let mut circuit = Circuit::new();
+    circuit +=
+        operations::PragmaSnapshotRegisters::new(vec!["rf".to_string()], "first".to_string());
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    let result = BitRegisterBackend::default().run_circuit_with_snapshots(&circuit);
+    assert_eq!(
+        result,
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Register rf of snapshot first is not an output register before the snapshot"
+                .to_string()
+        })
+    );
+}
+
+fn sampled_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::DefinitionBit::new("rr".to_string(), 3, true);
+    circuit += operations::DefinitionBit::new("hidden".to_string(), 1, false);
+    circuit += operations::DefinitionFloat::new("rf".to_string(), 1, true);
+    circuit += operations::Hadamard::new(0);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 1);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(0, "hidden".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(100, "ro".to_string());
+    circuit += operations::PragmaRepeatedMeasurement::new(
+        "rr".to_string(),
+        20,
+        Some(HashMap::from([(0, 2), (1, 0)])),
+    );
+    circuit
+}
+
+/// Test sampling the configured distribution into the measured registers
+#[test]
+fn test_mock_sampling() {
+    let distributions = HashMap::from([(
+        "0".to_string(),
+        vec![(vec![false, true], 0.25), (vec![true, false], 0.75)],
+    )]);
+    let backend = MockSamplingBackend::new(distributions.clone(), 42).unwrap();
+    let (bit_registers, float_registers, complex_registers) =
+        backend.run_circuit(&sampled_circuit()).unwrap();
+    assert_eq!(bit_registers.len(), 2);
+    assert_eq!(float_registers, HashMap::from([("rf".to_string(), vec![])]));
+    assert!(complex_registers.is_empty());
+
+    let ro = &bit_registers["ro"];
+    assert_eq!(ro.len(), 100);
+    assert!(ro
+        .iter()
+        .all(|shot| shot == &vec![true, false] || shot == &vec![false, true]));
+    let ones = ro.iter().filter(|shot| shot[1]).count();
+    assert!(ones > 55 && ones < 95);
+    let rr = &bit_registers["rr"];
+    assert_eq!(rr.len(), 20);
+    assert!(rr
+        .iter()
+        .all(|shot| shot == &vec![true, false, false] || shot == &vec![false, false, true]));
+
+    // The same seed reproduces the samples
+    let again = MockSamplingBackend::new(distributions, 42).unwrap();
+    assert_eq!(
+        again.run_circuit(&sampled_circuit()).unwrap().0,
+        bit_registers
+    );
+}
+
+/// Test looking up distributions by fingerprint before the index of the run
+#[test]
+fn test_mock_sampling_lookup() {
+    let circuit = circuit(0.5);
+    let distributions = HashMap::from([
+        (
+            circuit_fingerprint(circuit.iter()).to_string(),
+            vec![(vec![true], 1.0)],
+        ),
+        ("1".to_string(), vec![(vec![false], 1.0)]),
+    ]);
+    let backend = MockSamplingBackend::new(distributions, 0).unwrap();
+    let (bit_registers, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bit_registers["ro"], vec![vec![true, false]]);
+    let (bit_registers, _, _) = backend.run_circuit(&self::circuit(1.0)).unwrap();
+    assert_eq!(bit_registers["ro"], vec![vec![false, false]]);
+    let (bit_registers, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bit_registers["ro"], vec![vec![true, false]]);
+
+    let error = backend.run_circuit(&self::circuit(1.0));
+    assert_eq!(
+        error,
+        Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "No distribution configured for circuit with fingerprint {} or index 3",
+                circuit_fingerprint(self::circuit(1.0).iter())
+            )
+        })
+    );
+}
+
+/// Test looking up the distribution of a circuit with a qubit mapping built independently
+#[test]
+fn test_mock_sampling_lookup_repeated_measurement() {
+    let fingerprint = circuit_fingerprint(repeated_measurement_circuit(0..16).iter());
+    let mut outcome = vec![false; 16];
+    outcome[3] = true;
+    let distributions = HashMap::from([(fingerprint.to_string(), vec![(outcome, 1.0)])]);
+    let backend = MockSamplingBackend::new(distributions, 0).unwrap();
+    let (bit_registers, _, _) = backend
+        .run_circuit(&repeated_measurement_circuit((0..16).rev()))
+        .unwrap();
+    let mut expected = vec![false; 16];
+    expected[12] = true;
+    assert_eq!(bit_registers["ro"], vec![expected; 10]);
+}
+
+/// Test errors of invalid distributions and circuits
+#[test]
+fn test_mock_sampling_errors() {
+    for distribution in [
+        vec![],
+        vec![(vec![true], -1.0)],
+        vec![(vec![true], 0.0)],
+        vec![(vec![true], f64::NAN)],
+    ] {
+        let result = MockSamplingBackend::new(HashMap::from([("0".to_string(), distribution)]), 0);
+        assert!(matches!(
+            result,
+            Err(RoqoqoBackendError::GenericError { .. })
+        ));
+    }
+
+    let distributions = HashMap::from([("0".to_string(), vec![(vec![true], 1.0)])]);
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 0);
+    let backend = MockSamplingBackend::new(distributions.clone(), 0).unwrap();
+    assert_eq!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 1 is not part of the configured outcome [true]".to_string()
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 1);
+    let backend = MockSamplingBackend::new(distributions.clone(), 0).unwrap();
+    assert_eq!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Readout index 1 out of range for bit register ro of length 1".to_string()
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    let backend = MockSamplingBackend::new(distributions.clone(), 0).unwrap();
+    assert_eq!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Measured bit register ro is not defined".to_string()
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionComplex::new("rc".to_string(), 2, true);
+    circuit += operations::PragmaGetStateVector::new("rc".to_string(), None);
+    let backend = MockSamplingBackend::new(distributions, 0).unwrap();
+    assert_eq!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::OperationNotInBackend {
+            backend: "MockSamplingBackend",
+            hqslang: "PragmaGetStateVector"
+        })
+    );
+}
@@ -0,0 +1,100 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for public API of CircuitBuilder
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::builder::CircuitBuilder;
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+use std::f64::consts::PI;
+use test_case::test_case;
+
+/// Test that an empty builder creates an empty circuit
+#[test]
+fn empty_builder() {
+    assert_eq!(Circuit::builder().build(), Circuit::new());
+    assert_eq!(CircuitBuilder::new(), Circuit::builder());
+    assert_eq!(CircuitBuilder::default().build(), Circuit::default());
+}
+
+/// Test that single builder methods add the same operation as the manual construction
+#[test_case(Circuit::builder().h(1), Operation::from(Hadamard::new(1)); "h")]
+#[test_case(Circuit::builder().x(1), Operation::from(PauliX::new(1)); "x")]
+#[test_case(Circuit::builder().y(1), Operation::from(PauliY::new(1)); "y")]
+#[test_case(Circuit::builder().z(1), Operation::from(PauliZ::new(1)); "z")]
+#[test_case(Circuit::builder().s(1), Operation::from(SGate::new(1)); "s")]
+#[test_case(Circuit::builder().t(1), Operation::from(TGate::new(1)); "t")]
+#[test_case(Circuit::builder().sqrt_x(1), Operation::from(SqrtPauliX::new(1)); "sqrt_x")]
+#[test_case(Circuit::builder().rx(1, PI), Operation::from(RotateX::new(1, CalculatorFloat::PI)); "rx")]
+#[test_case(Circuit::builder().ry(1, 0.3), Operation::from(RotateY::new(1, CalculatorFloat::from(0.3))); "ry")]
+#[test_case(Circuit::builder().rz(1, CalculatorFloat::from(0.3)), Operation::from(RotateZ::new(1, CalculatorFloat::from(0.3))); "rz")]
+#[test_case(Circuit::builder().rz(1, 2), Operation::from(RotateZ::new(1, CalculatorFloat::from(2))); "rz integer")]
+#[test_case(Circuit::builder().rx_sym(1, "theta"), Operation::from(RotateX::new(1, CalculatorFloat::from("theta"))); "rx_sym")]
+#[test_case(Circuit::builder().ry_sym(1, "theta"), Operation::from(RotateY::new(1, CalculatorFloat::from("theta"))); "ry_sym")]
+#[test_case(Circuit::builder().rz_sym(1, "2*theta"), Operation::from(RotateZ::new(1, CalculatorFloat::from("2*theta"))); "rz_sym")]
+#[test_case(Circuit::builder().cnot(0, 1), Operation::from(CNOT::new(0, 1)); "cnot")]
+#[test_case(Circuit::builder().cz(0, 1), Operation::from(ControlledPauliZ::new(0, 1)); "cz")]
+#[test_case(Circuit::builder().cphase(0, 1, "phi"), Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::from("phi"))); "cphase")]
+#[test_case(Circuit::builder().swap(0, 1), Operation::from(SWAP::new(0, 1)); "swap")]
+#[test_case(Circuit::builder().measure(0, "ro", 1), Operation::from(MeasureQubit::new(0, "ro".to_string(), 1)); "measure")]
+#[test_case(Circuit::builder().bit_register("ro", 2, true), Operation::from(DefinitionBit::new("ro".to_string(), 2, true)); "bit_register")]
+#[test_case(Circuit::builder().float_register("ro", 2, false), Operation::from(DefinitionFloat::new("ro".to_string(), 2, false)); "float_register")]
+#[test_case(Circuit::builder().add_operation(PragmaActiveReset::new(0)), Operation::from(PragmaActiveReset::new(0)); "add_operation")]
+fn single_operation(builder: CircuitBuilder, operation: Operation) {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(operation);
+    assert_eq!(builder.build(), circuit);
+}
+
+/// Test that a chained builder creates the same circuit as the manual construction
+#[test]
+fn chained_builder() {
+    let circuit = Circuit::builder()
+        .h(0)
+        .cnot(0, 1)
+        .rz(1, 0.3)
+        .rz_sym(0, "theta")
+        .measure_all("ro", 3)
+        .build();
+
+    let mut test_circuit = Circuit::new();
+    test_circuit += Hadamard::new(0);
+    test_circuit += CNOT::new(0, 1);
+    test_circuit += RotateZ::new(1, CalculatorFloat::from(0.3));
+    test_circuit += RotateZ::new(0, CalculatorFloat::from("theta"));
+    test_circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    test_circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    test_circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    test_circuit += MeasureQubit::new(2, "ro".to_string(), 2);
+
+    assert_eq!(circuit, test_circuit);
+    assert_eq!(
+        circuit.definitions(),
+        &vec![Operation::from(DefinitionBit::new(
+            "ro".to_string(),
+            3,
+            true
+        ))]
+    );
+}
+
+/// Test that measure_all with zero qubits only defines the register
+#[test]
+fn measure_all_empty() {
+    let mut test_circuit = Circuit::new();
+    test_circuit += DefinitionBit::new("ro".to_string(), 0, true);
+    assert_eq!(
+        Circuit::builder().measure_all("ro", 0).build(),
+        test_circuit
+    );
+}
@@ -12,11 +12,13 @@
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, Validator};
 use qoqo_calculator::{Calculator, CalculatorFloat};
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
 use roqoqo::operations::*;
-use roqoqo::{AsVec, Circuit};
+use roqoqo::prelude::*;
+use roqoqo::{AsVec, Circuit, EqualityOptions, RoqoqoError};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 #[cfg(feature = "overrotate")]
 use std::convert::TryInto;
 use std::iter::FromIterator;
@@ -97,6 +99,98 @@ fn is_parametrized(operation: Operation, parametrized: bool) {
     }
 }
 
+/// Test is_parametrized stays correct when the Circuit is edited
+#[test]
+fn is_parametrized_after_editing() {
+    let symbolic = Operation::from(RotateX::new(0, CalculatorFloat::from("theta")));
+    let constant = Operation::from(RotateX::new(0, CalculatorFloat::from(0.5)));
+
+    let mut circuit = Circuit::new();
+    assert!(!circuit.is_parametrized());
+    circuit += constant.clone();
+    circuit.add_operation(DefinitionBit::new("ro".to_string(), 1, true));
+    assert!(!circuit.is_parametrized());
+    circuit += symbolic.clone();
+    assert!(circuit.is_parametrized());
+
+    // Mutable access
+    *circuit.get_mut(2).unwrap() = constant.clone();
+    assert!(!circuit.is_parametrized());
+    circuit[2] = symbolic.clone();
+    assert!(circuit.is_parametrized());
+    circuit[2] = constant.clone();
+    assert!(!circuit.is_parametrized());
+
+    // Definitions
+    let mut definition_circuit = Circuit::new();
+    definition_circuit += InputSymbolic::new("theta".to_string(), 1.0);
+    assert!(!definition_circuit.is_parametrized());
+    definition_circuit += DefinitionFloat::new("ro".to_string(), 1, false);
+    assert!(!definition_circuit.is_parametrized());
+
+    // Adding circuits
+    let mut symbolic_circuit = Circuit::new();
+    symbolic_circuit += symbolic.clone();
+    assert!((circuit.clone() + symbolic_circuit.clone()).is_parametrized());
+    assert!((circuit.clone() + &symbolic_circuit).is_parametrized());
+    assert!(!(circuit.clone() + circuit.clone()).is_parametrized());
+    let mut added = circuit.clone();
+    added += &circuit;
+    assert!(!added.is_parametrized());
+    added += &symbolic_circuit;
+    assert!(added.is_parametrized());
+    let mut added = circuit.clone();
+    added += symbolic_circuit.clone();
+    assert!(added.is_parametrized());
+    let mut extended = circuit.clone();
+    extended.extend(vec![symbolic.clone()]);
+    assert!(extended.is_parametrized());
+    assert!(Circuit::from_iter(vec![symbolic.clone()]).is_parametrized());
+    assert!(!Circuit::from_iter(vec![constant.clone()]).is_parametrized());
+
+    // Circuits returned by transformations
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 0.1);
+    assert!(!symbolic_circuit
+        .substitute_parameters(&calculator)
+        .unwrap()
+        .is_parametrized());
+    let mapping: HashMap<usize, usize> = [(0, 1), (1, 0)].into_iter().collect();
+    assert!(symbolic_circuit
+        .remap_qubits(&mapping)
+        .unwrap()
+        .is_parametrized());
+    assert!(circuit
+        .compose(&symbolic_circuit)
+        .unwrap()
+        .is_parametrized());
+    assert!(circuit
+        .tensor(&symbolic_circuit, 1)
+        .unwrap()
+        .is_parametrized());
+    assert!(symbolic_circuit
+        .consolidate_global_phase()
+        .is_parametrized());
+}
+
+/// Test is_parametrized is recomputed after deserialization
+#[cfg(feature = "serialize")]
+#[test]
+fn is_parametrized_after_deserialization() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from("theta"));
+    let deserialized: Circuit =
+        serde_json::from_str(&serde_json::to_string(&circuit).unwrap()).unwrap();
+    assert!(deserialized.is_parametrized());
+    assert_eq!(deserialized, circuit);
+
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from(0.5));
+    let deserialized: Circuit =
+        bincode::deserialize(&bincode::serialize(&circuit).unwrap()).unwrap();
+    assert!(!deserialized.is_parametrized());
+}
+
 /// Test len function
 #[test]
 fn length() {
@@ -132,6 +226,110 @@ fn involved_qubits(operation: Operation, qubits: InvolvedQubits) {
     assert_eq!(circuit.involved_qubits(), qubits)
 }
 
+/// Test involved bosonic modes and number of modes
+#[test]
+fn involved_modes() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, CalculatorFloat::from(0.5)));
+    assert_eq!(circuit.involved_modes(), InvolvedModes::None);
+    assert_eq!(circuit.number_of_modes(), 0);
+
+    circuit.add_operation(Squeezing::new(1, 0.1.into(), 0.0.into()));
+    circuit.add_operation(BeamSplitter::new(1, 3, 0.1.into(), 0.2.into()));
+    circuit.add_operation(QuantumRabi::new(0, 4, 0.1.into()));
+    assert_eq!(
+        circuit.involved_modes(),
+        InvolvedModes::Set([1, 3, 4].iter().cloned().collect())
+    );
+    assert_eq!(circuit.number_of_modes(), 5);
+    assert_eq!(
+        Operation::from(RotateX::new(0, CalculatorFloat::from(0.5))).involved_modes(),
+        InvolvedModes::None
+    );
+}
+
+/// Test involved classical register entries of Circuit and their merge rules
+#[test]
+fn involved_classical() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from(0.5));
+    assert_eq!(circuit.involved_classical(), InvolvedClassical::None);
+
+    let mut inner_circuit = Circuit::new();
+    inner_circuit += MeasureQubit::new(1, "aux".to_string(), 2);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaConditional::new("ro".to_string(), 0, inner_circuit);
+    assert_eq!(
+        circuit.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("ro".to_string(), 0),
+            ("aux".to_string(), 2)
+        ]))
+    );
+
+    // Registers involved up to the number of qubits take precedence over sets
+    circuit += PragmaRepeatedMeasurement::new("rep".to_string(), 10, None);
+    circuit += PragmaRepeatedMeasurement::new("rep2".to_string(), 10, None);
+    assert_eq!(
+        circuit.involved_classical(),
+        InvolvedClassical::AllQubits("rep".to_string())
+    );
+
+    // Completely involved registers take precedence over all other entries
+    circuit += PragmaGetStateVector::new("state".to_string(), None);
+    assert_eq!(
+        circuit.involved_classical(),
+        InvolvedClassical::All("state".to_string())
+    );
+}
+
+/// Test strict json deserialization of Circuit rejecting unknown fields
+#[cfg(feature = "serialize")]
+#[test]
+fn from_json_strict() {
+    let mut inner_circuit = Circuit::new();
+    inner_circuit += RotateZ::new(0, CalculatorFloat::from(0.5));
+    let mut measurement_circuit = Circuit::new();
+    measurement_circuit += PauliX::new(0);
+    let mut circuit = Circuit::new();
+    circuit += PragmaGetDensityMatrix::new("ro".to_string(), Some(measurement_circuit));
+    circuit += PragmaLoop::new(CalculatorFloat::from(2), inner_circuit);
+    let json = serde_json::to_string(&circuit).unwrap();
+    assert_eq!(Circuit::from_json_strict(&json).unwrap(), circuit);
+
+    // A misspelled optional field is silently ignored by the default deserialization
+    let misspelled = json.replacen(r#""circuit":{"#, r#""circuitt":{"#, 1);
+    let deserialized: Circuit = serde_json::from_str(&misspelled).unwrap();
+    assert_ne!(deserialized, circuit);
+    assert_eq!(
+        Circuit::from_json_strict(&misspelled),
+        Err(RoqoqoError::SerializationError {
+            msg: "Unknown fields in json input: $.operations[0].PragmaGetDensityMatrix.circuitt"
+                .to_string()
+        })
+    );
+
+    // Misspelled field in the circuit of a PragmaLoop
+    let nested = json.replace(r#""theta":0.5"#, r#""theta":0.5,"thetaa":0.5"#);
+    assert_eq!(
+        Circuit::from_json_strict(&nested),
+        Err(RoqoqoError::SerializationError {
+            msg: "Unknown fields in json input: $.operations[1].PragmaLoop.circuit.operations[0].RotateZ.thetaa"
+                .to_string()
+        })
+    );
+
+    // All unknown fields are listed
+    let top_level = misspelled.replacen('{', r#"{"extra":1,"#, 1);
+    match Circuit::from_json_strict(&top_level) {
+        Err(RoqoqoError::SerializationError { msg }) => {
+            assert!(msg.contains("$.extra"));
+            assert!(msg.contains("$.operations[0].PragmaGetDensityMatrix.circuitt"));
+        }
+        _ => panic!("Unknown fields not detected"),
+    }
+}
+
 /// Test definitions and operations functions
 #[test]
 fn definitions_operations() {
@@ -427,6 +625,47 @@ fn simple_traits() {
     assert!(circuit != circuit_1);
 }
 
+/// Test that the minimum supported version of a circuit is the maximum over its operations
+#[test_case(vec![], (1, 0, 0); "empty")]
+#[test_case(vec![Operation::from(RotateX::new(0, 1.0.into()))], (1, 0, 0); "RotateX")]
+#[test_case(vec![Operation::from(InputBit::new("ro".to_string(), 0, true))], (1, 1, 0); "InputBit")]
+#[test_case(vec![
+    Operation::from(TripleControlledPauliX::new(0, 1, 2, 3)),
+    Operation::from(InputBit::new("ro".to_string(), 0, true)),
+    Operation::from(PauliX::new(0)),
+], (1, 16, 0); "TripleControlledPauliX")]
+#[test_case(vec![
+    Operation::from(InputBit::new("ro".to_string(), 0, true)),
+    Operation::from(TripleControlledPauliX::new(0, 1, 2, 3)),
+    Operation::from(PragmaAnnotatedOp::new(PauliX::new(0).into(), "annotation".to_string())),
+], (1, 16, 0); "increasing and decreasing")]
+#[test_case(vec![
+    Operation::from(PragmaLoop::new(2.into(), triple_controlled_circuit())),
+], (1, 16, 0); "PragmaLoop")]
+#[test_case(vec![
+    Operation::from(PragmaConditional::new("ro".to_string(), 0, triple_controlled_circuit())),
+], (1, 16, 0); "PragmaConditional")]
+#[test_case(vec![
+    Operation::from(PragmaAnnotatedOp::new(
+        TripleControlledPauliX::new(0, 1, 2, 3).into(),
+        "annotation".to_string(),
+    )),
+], (1, 16, 0); "PragmaAnnotatedOp")]
+fn test_minimum_supported_version(operations: Vec<Operation>, version: (u32, u32, u32)) {
+    let mut circuit = Circuit::new();
+    for operation in operations {
+        circuit.add_operation(operation);
+    }
+    assert_eq!(circuit.minimum_supported_roqoqo_version(), version);
+}
+
+// Returns a circuit with a minimum supported version of 1.16.0
+fn triple_controlled_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += TripleControlledPauliX::new(0, 1, 2, 3);
+    circuit
+}
+
 /// Test overrotate circuit
 #[test]
 #[cfg(feature = "overrotate")]
@@ -471,3 +710,719 @@ fn test_json_schema() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Test semantically_equal with reordered definitions
+#[test]
+fn semantically_equal_definition_order() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += DefinitionFloat::new("rf".to_string(), 1, false);
+    circuit += PauliX::new(0);
+    let mut other = Circuit::new();
+    other += DefinitionFloat::new("rf".to_string(), 1, false);
+    other += DefinitionBit::new("ro".to_string(), 2, true);
+    other += PauliX::new(0);
+
+    assert!(circuit != other);
+    assert!(circuit.semantically_equal(&circuit, EqualityOptions::default()));
+    assert!(!circuit.semantically_equal(&other, EqualityOptions::new()));
+    let options = EqualityOptions::new().ignore_definition_order(true);
+    assert!(circuit.semantically_equal(&other, options));
+    assert!(other.semantically_equal(&circuit, options));
+
+    // Different definitions are not equal in any order
+    let mut different = Circuit::new();
+    different += DefinitionFloat::new("rf".to_string(), 1, false);
+    different += DefinitionBit::new("ro".to_string(), 3, true);
+    different += PauliX::new(0);
+    assert!(!circuit.semantically_equal(&different, options));
+    let mut duplicated = Circuit::new();
+    duplicated += DefinitionBit::new("ro".to_string(), 2, true);
+    duplicated += DefinitionBit::new("ro".to_string(), 2, true);
+    duplicated += PauliX::new(0);
+    assert!(!circuit.semantically_equal(&duplicated, options));
+    assert!(!duplicated.semantically_equal(&circuit, options));
+}
+
+/// Test semantically_equal with interleaved PRAGMAs
+#[test]
+fn semantically_equal_ignore_pragmas() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    circuit += CNOT::new(0, 1);
+    let mut other = Circuit::new();
+    other += PragmaSleep::new(vec![0, 1], 1.0.into());
+    other += PauliX::new(0);
+    other += PragmaStopParallelBlock::new(vec![0, 1], 0.1.into());
+    other += CNOT::new(0, 1);
+    other += PragmaSleep::new(vec![0], 1.0.into());
+
+    assert!(!circuit.semantically_equal(&other, EqualityOptions::new()));
+    let options = EqualityOptions::new().ignore_pragmas(true);
+    assert!(circuit.semantically_equal(&other, options));
+    assert!(other.semantically_equal(&circuit, options));
+
+    // Other PRAGMAs are not ignored
+    let mut with_reset = circuit.clone();
+    with_reset += PragmaActiveReset::new(0);
+    assert!(!circuit.semantically_equal(&with_reset, options));
+    // The order of the remaining operations matters
+    let mut reordered = Circuit::new();
+    reordered += CNOT::new(0, 1);
+    reordered += PauliX::new(0);
+    assert!(!circuit.semantically_equal(&reordered, options));
+}
+
+/// Test semantically_equal ignores annotations unless they are compared
+#[test]
+fn semantically_equal_annotations() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    circuit.annotate("compiler".to_string(), "1.0".to_string());
+    let mut other = Circuit::new();
+    other.annotate("author".to_string(), "someone".to_string());
+    other += PauliX::new(0);
+
+    assert!(circuit != other);
+    assert!(circuit.semantically_equal(&other, EqualityOptions::new()));
+    assert!(other.semantically_equal(&circuit, EqualityOptions::default()));
+    let options = EqualityOptions::new().compare_annotations(true);
+    assert!(!circuit.semantically_equal(&other, options));
+    assert!(circuit.semantically_equal(&circuit.clone(), options));
+
+    // Annotations do not hide differences in the other operations
+    let mut different = Circuit::new();
+    different += PauliY::new(0);
+    different.annotate("compiler".to_string(), "1.0".to_string());
+    assert!(!circuit.semantically_equal(&different, EqualityOptions::new()));
+}
+
+/// Test semantically_equal with rotation angles differing by multiples of 2π
+#[test_case(0.5, 0.5 + 2.0 * std::f64::consts::PI, true; "plus 2pi")]
+#[test_case(0.5, 0.5 - 4.0 * std::f64::consts::PI, true; "minus 4pi")]
+#[test_case(0.0, 2.0 * std::f64::consts::PI - 1e-12, true; "below 2pi")]
+#[test_case(0.5, 0.5 + 1e-3, false; "outside tolerance")]
+#[test_case(0.5, 0.5 + std::f64::consts::PI, false; "plus pi")]
+fn semantically_equal_angle_modulo(theta: f64, other_theta: f64, equal: bool) {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, theta.into());
+    circuit += RotateXY::new(1, theta.into(), 0.3.into());
+    circuit += ControlledPhaseShift::new(0, 1, theta.into());
+    let mut other = Circuit::new();
+    other += RotateX::new(0, other_theta.into());
+    other += RotateXY::new(1, other_theta.into(), 0.3.into());
+    other += ControlledPhaseShift::new(0, 1, other_theta.into());
+
+    let options = EqualityOptions::new().angle_tolerance(Some(1e-9));
+    assert_eq!(circuit.semantically_equal(&other, options), equal);
+    assert_eq!(other.semantically_equal(&circuit, options), equal);
+    assert!(!circuit.semantically_equal(&other, EqualityOptions::new()));
+}
+
+/// Test semantically_equal does not relax anything but the rotation angle
+#[test]
+fn semantically_equal_angle_other_parameters() {
+    let options = EqualityOptions::new().angle_tolerance(Some(1e-9));
+    let two_pi = 2.0 * std::f64::consts::PI;
+    let mut circuit = Circuit::new();
+    circuit += RotateXY::new(1, 0.5.into(), 0.3.into());
+    let mut other = Circuit::new();
+    other += RotateXY::new(1, (0.5 + two_pi).into(), (0.3 + two_pi).into());
+    assert!(!circuit.semantically_equal(&other, options));
+
+    let mut other = Circuit::new();
+    other += RotateXY::new(0, (0.5 + two_pi).into(), 0.3.into());
+    assert!(!circuit.semantically_equal(&other, options));
+
+    let mut circuit = Circuit::new();
+    circuit += RotateZ::new(0, "theta".into());
+    let mut other = Circuit::new();
+    other += RotateZ::new(0, "theta".into());
+    assert!(circuit.semantically_equal(&other, options));
+    let mut other = Circuit::new();
+    other += RotateZ::new(0, "theta + 2*pi".into());
+    assert!(!circuit.semantically_equal(&other, options));
+}
+
+/// Test tensor function of Circuit
+#[test]
+fn tensor() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    let mut other = Circuit::new();
+    other += DefinitionFloat::new("rf".to_string(), 1, true);
+    other += PauliX::new(0);
+    other += CNOT::new(1, 2);
+    other += PragmaGetPauliProduct::new(HashMap::from([(2, 1)]), "rf".to_string(), Circuit::new());
+
+    let tensored = circuit.tensor(&other, 2).unwrap();
+    let mut expected = circuit.clone();
+    expected += DefinitionFloat::new("rf".to_string(), 1, true);
+    expected += PauliX::new(2);
+    expected += CNOT::new(3, 4);
+    expected +=
+        PragmaGetPauliProduct::new(HashMap::from([(4, 1)]), "rf".to_string(), Circuit::new());
+    assert_eq!(tensored, expected);
+
+    // Shifting by less than the number of qubits in self is fine as long as no qubit is shared
+    let mut only_qubit_zero = Circuit::new();
+    only_qubit_zero += PauliZ::new(0);
+    let tensored = only_qubit_zero.tensor(&other, 1).unwrap();
+    let mut expected = only_qubit_zero.clone();
+    expected += DefinitionFloat::new("rf".to_string(), 1, true);
+    expected += PauliX::new(1);
+    expected += CNOT::new(2, 3);
+    expected +=
+        PragmaGetPauliProduct::new(HashMap::from([(3, 1)]), "rf".to_string(), Circuit::new());
+    assert_eq!(tensored, expected);
+
+    // No offset for a circuit on disjoint qubits
+    let mut high_qubits = Circuit::new();
+    high_qubits += PauliY::new(5);
+    let tensored = circuit.tensor(&high_qubits, 0).unwrap();
+    assert_eq!(tensored, circuit.clone() + high_qubits);
+}
+
+/// Test tensor function of Circuit with overlapping qubits and clashing register names
+#[test]
+fn tensor_errors() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += CNOT::new(0, 1);
+    let mut other = Circuit::new();
+    other += PauliX::new(0);
+
+    assert_eq!(
+        circuit.tensor(&other, 1),
+        Err(RoqoqoError::GenericError {
+            msg: "Qubit 1 is used in both circuits after shifting by 1".to_string()
+        })
+    );
+
+    let mut clashing = Circuit::new();
+    clashing += DefinitionFloat::new("ro".to_string(), 1, false);
+    clashing += PauliX::new(0);
+    assert_eq!(
+        circuit.tensor(&clashing, 2),
+        Err(RoqoqoError::RegisterNameClash {
+            name: "ro".to_string()
+        })
+    );
+    // Identical definitions clash as well, the registers would be written by both circuits
+    assert_eq!(
+        circuit.tensor(&circuit, 2),
+        Err(RoqoqoError::RegisterNameClash {
+            name: "ro".to_string()
+        })
+    );
+}
+
+/// Test compose function of Circuit
+#[test]
+fn compose() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    let mut other = Circuit::new();
+    other += DefinitionBit::new("ro".to_string(), 2, true);
+    other += DefinitionFloat::new("rf".to_string(), 1, true);
+    other += CNOT::new(0, 1);
+    other += MeasureQubit::new(1, "ro".to_string(), 1);
+
+    let composed = circuit.compose(&other).unwrap();
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 2, true);
+    expected += DefinitionFloat::new("rf".to_string(), 1, true);
+    expected += Hadamard::new(0);
+    expected += CNOT::new(0, 1);
+    expected += MeasureQubit::new(1, "ro".to_string(), 1);
+    assert_eq!(composed, expected);
+
+    let mut clashing = Circuit::new();
+    clashing += DefinitionBit::new("ro".to_string(), 3, true);
+    assert_eq!(
+        circuit.compose(&clashing),
+        Err(RoqoqoError::RegisterNameClash {
+            name: "ro".to_string()
+        })
+    );
+}
+
+/// Returns a library Circuit measuring into a register "ro" with a conditional and a nested measurement
+fn library_circuit(length: usize) -> Circuit {
+    let mut conditional = Circuit::new();
+    conditional += PauliX::new(0);
+    conditional += MeasureQubit::new(0, "ro".to_string(), 1);
+    let mut basis_rotation = Circuit::new();
+    basis_rotation += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), length, true);
+    circuit += DefinitionFloat::new("rf".to_string(), 1, true);
+    circuit += InputSymbolic::new("ro".to_string(), 1.0);
+    circuit += InputBit::new("ro".to_string(), 0, true);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaConditional::new("ro".to_string(), 0, conditional);
+    circuit += PragmaGetPauliProduct::new(
+        [(0, 3)].into_iter().collect(),
+        "rf".to_string(),
+        basis_rotation,
+    );
+    circuit
+}
+
+/// Test remap_registers function of Circuit
+#[test]
+fn remap_registers() {
+    let mapping: HashMap<String, String> = [("ro".to_string(), "ro_b".to_string())]
+        .into_iter()
+        .collect();
+    let remapped = library_circuit(2).remap_registers(&mapping).unwrap();
+
+    let mut conditional = Circuit::new();
+    conditional += PauliX::new(0);
+    conditional += MeasureQubit::new(0, "ro_b".to_string(), 1);
+    let mut basis_rotation = Circuit::new();
+    basis_rotation += PragmaRepeatedMeasurement::new("ro_b".to_string(), 10, None);
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro_b".to_string(), 2, true);
+    expected += DefinitionFloat::new("rf".to_string(), 1, true);
+    expected += InputSymbolic::new("ro".to_string(), 1.0);
+    expected += InputBit::new("ro_b".to_string(), 0, true);
+    expected += MeasureQubit::new(0, "ro_b".to_string(), 0);
+    expected += PragmaConditional::new("ro_b".to_string(), 0, conditional);
+    expected += PragmaGetPauliProduct::new(
+        [(0, 3)].into_iter().collect(),
+        "rf".to_string(),
+        basis_rotation,
+    );
+    assert_eq!(remapped, expected);
+
+    // Swapping two registers is allowed
+    let swap: HashMap<String, String> = [
+        ("ro".to_string(), "rf".to_string()),
+        ("rf".to_string(), "ro".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    let swapped = library_circuit(2).remap_registers(&swap).unwrap();
+    assert_eq!(swapped.remap_registers(&swap).unwrap(), library_circuit(2));
+
+    // Mapping onto an existing register that is not renamed fails
+    let clash: HashMap<String, String> =
+        [("ro".to_string(), "rf".to_string())].into_iter().collect();
+    assert_eq!(
+        library_circuit(2).remap_registers(&clash),
+        Err(RoqoqoError::RegisterMappingError {
+            name: "rf".to_string()
+        })
+    );
+    let merge: HashMap<String, String> = [
+        ("ro".to_string(), "new".to_string()),
+        ("rf".to_string(), "new".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        library_circuit(2).remap_registers(&merge),
+        Err(RoqoqoError::RegisterMappingError {
+            name: "new".to_string()
+        })
+    );
+}
+
+/// Test composing two library Circuits that both define "ro" after remapping the registers
+#[test]
+fn compose_remapped_library_circuits() {
+    let first = library_circuit(2);
+    let second = library_circuit(3);
+    assert_eq!(
+        first.compose(&second),
+        Err(RoqoqoError::RegisterNameClash {
+            name: "ro".to_string()
+        })
+    );
+
+    let mapping: HashMap<String, String> = [
+        ("ro".to_string(), "ro_b".to_string()),
+        ("rf".to_string(), "rf_b".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    let composed = first
+        .compose(&second.remap_registers(&mapping).unwrap())
+        .unwrap();
+    let names: Vec<String> = composed
+        .definitions()
+        .iter()
+        .filter_map(|definition| Definition::try_from(definition.clone()).ok())
+        .map(|definition| definition.name().clone())
+        .collect();
+    // The identical InputSymbolic definition is only kept once
+    assert_eq!(names, vec!["ro", "rf", "ro", "ro_b", "rf_b"]);
+    assert_eq!(composed.len(), first.len() + second.len() - 1);
+    assert!(composed.iter().any(
+        |operation| operation == &Operation::from(MeasureQubit::new(0, "ro_b".to_string(), 0))
+    ));
+}
+
+/// Test total_global_phase and consolidate_global_phase with numeric and symbolic phases
+#[test]
+fn global_phase_consolidation() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += PragmaGlobalPhase::new(0.5.into());
+    circuit += PauliX::new(0);
+    circuit += PragmaGlobalPhase::new("theta".into());
+    circuit += RotateZ::new(0, "theta".into());
+    circuit += PragmaGlobalPhase::new(0.25.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    let total = circuit.total_global_phase();
+    let mut substituted = Calculator::new();
+    substituted.set_variable("theta", 1.0);
+    assert!(!total.is_float());
+    assert!(
+        (substituted.parse_get(total.clone()).unwrap() - 1.75).abs() < 1e-12,
+        "{}",
+        total
+    );
+
+    let consolidated = circuit.consolidate_global_phase();
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 1, true);
+    expected += PauliX::new(0);
+    expected += RotateZ::new(0, "theta".into());
+    expected += MeasureQubit::new(0, "ro".to_string(), 0);
+    expected += PragmaGlobalPhase::new(total.clone());
+    assert_eq!(consolidated, expected);
+    assert_eq!(consolidated.count_occurences(&["PragmaGlobalPhase"]), 1);
+    assert_eq!(consolidated.total_global_phase(), total);
+
+    // Substituting the consolidated circuit gives the same numeric phase
+    let substituted_circuit = consolidated.substitute_parameters(&substituted).unwrap();
+    let phase = substituted_circuit.total_global_phase();
+    assert!((f64::try_from(phase).unwrap() - 1.75).abs() < 1e-12);
+
+    // Numeric phases are summed numerically
+    let mut numeric = Circuit::new();
+    numeric += PragmaGlobalPhase::new(0.5.into());
+    numeric += PauliX::new(0);
+    numeric += PragmaGlobalPhase::new((-0.2).into());
+    assert_eq!(numeric.total_global_phase(), CalculatorFloat::from(0.3));
+
+    // Circuits without a global phase are not changed
+    let mut no_phase = Circuit::new();
+    no_phase += PauliX::new(0);
+    assert_eq!(no_phase.total_global_phase(), CalculatorFloat::from(0.0));
+    assert_eq!(no_phase.consolidate_global_phase(), no_phase);
+}
+
+/// Returns a Circuit on the sparse qubits 3 and 17 measuring into the registers "ro" and "aux"
+fn sparse_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 18, true);
+    circuit += DefinitionBit::new("aux".to_string(), 1, true);
+    circuit += Hadamard::new(3);
+    circuit += CNOT::new(3, 17);
+    circuit += PragmaGetStateVector::new("state".to_string(), None);
+    circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+    circuit += MeasureQubit::new(17, "ro".to_string(), 17);
+    circuit += MeasureQubit::new(17, "aux".to_string(), 0);
+    circuit
+}
+
+/// Test used_qubits function of Circuit
+#[test]
+fn used_qubits() {
+    assert_eq!(Circuit::new().used_qubits(), BTreeSet::new());
+    assert_eq!(sparse_circuit().used_qubits(), BTreeSet::from([3, 17]));
+}
+
+/// Test compactify_qubits function of Circuit
+#[test]
+fn compactify_qubits() {
+    let (compacted, mapping) = sparse_circuit().compactify_qubits().unwrap();
+    assert_eq!(mapping, HashMap::from([(3, 0), (17, 1)]));
+    assert_eq!(compacted.used_qubits(), BTreeSet::from([0, 1]));
+
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 18, true);
+    expected += DefinitionBit::new("aux".to_string(), 1, true);
+    expected += Hadamard::new(0);
+    expected += CNOT::new(0, 1);
+    expected += PragmaGetStateVector::new("state".to_string(), None);
+    expected += MeasureQubit::new(0, "ro".to_string(), 0);
+    expected += MeasureQubit::new(1, "ro".to_string(), 1);
+    expected += MeasureQubit::new(1, "aux".to_string(), 0);
+    assert_eq!(compacted, expected);
+
+    // A compact Circuit is not changed
+    let (recompacted, mapping) = compacted.compactify_qubits().unwrap();
+    assert_eq!(recompacted, compacted);
+    assert_eq!(mapping, HashMap::from([(0, 0), (1, 1)]));
+
+    // Readout indices are kept when a register is not indexed by the measured qubits
+    let mut circuit = Circuit::new();
+    circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+    circuit += MeasureQubit::new(5, "ro".to_string(), 0);
+    let (compacted, _) = circuit.compactify_qubits().unwrap();
+    let mut expected = Circuit::new();
+    expected += MeasureQubit::new(0, "ro".to_string(), 3);
+    expected += MeasureQubit::new(1, "ro".to_string(), 0);
+    assert_eq!(compacted, expected);
+}
+
+/// Test the compacted Circuit produces the same measurement statistics after relabelling the readouts
+#[test]
+fn compactify_qubits_measurement_statistics() {
+    let (compacted, mapping) = sparse_circuit().compactify_qubits().unwrap();
+
+    let measurement = |qubits: Vec<usize>, circuit: Circuit| {
+        let mut input = PauliZProductInput::new(18, false);
+        let index = input.add_pauliz_product("ro".to_string(), qubits).unwrap();
+        input
+            .add_linear_exp_val("parity".to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+        PauliZProduct {
+            constant_circuit: None,
+            circuits: vec![circuit],
+            input,
+        }
+    };
+    let original = measurement(vec![3, 17], sparse_circuit());
+    let compact = measurement(vec![0, 1], compacted);
+
+    let records: Vec<Vec<bool>> = [(true, true), (false, false), (true, false)]
+        .into_iter()
+        .map(|(first, second)| {
+            let mut record = vec![false; 18];
+            record[3] = first;
+            record[17] = second;
+            record
+        })
+        .collect();
+    let relabelled: Vec<Vec<bool>> = records
+        .iter()
+        .map(|record| {
+            let mut new_record = vec![false; 18];
+            for (old, new) in mapping.iter() {
+                new_record[*new] = record[*old];
+            }
+            new_record
+        })
+        .collect();
+
+    let original_result = original
+        .evaluate(
+            HashMap::from([("ro".to_string(), records)]),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap();
+    let compact_result = compact
+        .evaluate(
+            HashMap::from([("ro".to_string(), relabelled)]),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap();
+    assert_eq!(original_result, compact_result);
+    assert!(original_result.is_some());
+}
+
+/// Test validate_pragmas finding invalid state setting PRAGMAs in nested circuits
+#[test]
+fn validate_pragmas() {
+    let statevector: ndarray::Array1<num_complex::Complex64> = ndarray::array![
+        num_complex::Complex64::new(1.0, 0.0),
+        num_complex::Complex64::new(0.0, 0.0)
+    ];
+    let mut circuit = Circuit::new();
+    circuit += PragmaSetStateVector::new(statevector.clone());
+    circuit += RotateX::new(0, "theta".into());
+    assert_eq!(circuit.validate_pragmas(), Ok(()));
+
+    let mut inner = Circuit::new();
+    inner += PragmaSetStateVector::new(statevector * 2.0);
+    let mut nested = circuit.clone();
+    nested += PragmaLoop::new(2.into(), inner.clone());
+    assert_eq!(
+        nested.validate_pragmas(),
+        Err(RoqoqoError::StateVectorNotNormalized { norm: 2.0 })
+    );
+
+    let mut nested = circuit.clone();
+    nested += PragmaConditional::new("ro".to_string(), 0, inner);
+    assert!(nested.validate_pragmas().is_err());
+
+    let mut nested = circuit;
+    nested += PragmaSetDensityMatrix::new(ndarray::Array2::zeros((2, 3)));
+    assert_eq!(
+        nested.validate_pragmas(),
+        Err(RoqoqoError::DensityMatrixNotSquare {
+            rows: 2,
+            columns: 3
+        })
+    );
+}
+
+/// Test that duplicate register definitions with the same length are merged
+#[test]
+fn duplicate_definitions() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, false);
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += DefinitionFloat::new("ro_float".to_string(), 2, true);
+    assert_eq!(circuit.len(), 2);
+    assert_eq!(
+        circuit[0],
+        Operation::from(DefinitionBit::new("ro".to_string(), 2, true))
+    );
+    assert_eq!(circuit.check_definitions(), Ok(()));
+
+    let mut other = Circuit::new();
+    other += DefinitionBit::new("ro".to_string(), 2, false);
+    other += MeasureQubit::new(0, "ro".to_string(), 0);
+    let combined = circuit.clone() + other;
+    assert_eq!(combined.len(), 3);
+    assert_eq!(combined.check_definitions(), Ok(()));
+
+    let map = combined.definitions_map();
+    let expected: HashMap<String, (usize, bool)> = [
+        ("ro".to_string(), (2, true)),
+        ("ro_float".to_string(), (2, true)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(map, expected);
+}
+
+/// Test check_definitions reporting conflicting register definitions
+#[test]
+fn conflicting_definitions() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += DefinitionFloat::new("ro_float".to_string(), 1, true);
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    assert_eq!(circuit.len(), 4);
+    assert_eq!(
+        circuit.check_definitions(),
+        Err(RoqoqoError::ConflictingDefinitions {
+            name: "ro".to_string(),
+            first_length: 2,
+            first_index: 0,
+            second_length: 3,
+            second_index: 2,
+        })
+    );
+    assert_eq!(circuit.definitions_map()["ro"], (2, true));
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += DefinitionFloat::new("ro".to_string(), 2, true);
+    assert!(matches!(
+        circuit.check_definitions(),
+        Err(RoqoqoError::ConflictingDefinitions { .. })
+    ));
+
+    let mut input = PauliZProductInput::new(1, false);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    let measurement = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![circuit],
+        input,
+    };
+    assert!(matches!(
+        measurement.validate(),
+        Err(RoqoqoError::ConflictingDefinitions { .. })
+    ));
+}
+
+/// Test size_report counting nested operations, state dimensions and nesting depth
+#[cfg(feature = "serialize")]
+#[test]
+fn size_report() {
+    let empty = Circuit::new().size_report();
+    assert_eq!(empty.number_operations, 0);
+    assert_eq!(empty.max_nesting_depth, 0);
+    assert_eq!(empty.max_state_dimension, 0);
+
+    let mut innermost = Circuit::new();
+    innermost += RotateX::new(0, 0.1.into());
+    innermost += PragmaSetDensityMatrix::new(ndarray::Array2::zeros((4, 4)));
+    let mut inner = Circuit::new();
+    inner += PragmaLoop::new(3.into(), innermost);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += PragmaSetStateVector::new(ndarray::Array1::zeros(2));
+    circuit += PragmaLoop::new(2.into(), inner);
+    circuit += PragmaConditional::new("ro".to_string(), 0, Circuit::new());
+
+    let report = circuit.size_report();
+    assert_eq!(report.number_operations, 7);
+    assert_eq!(report.max_nesting_depth, 2);
+    assert_eq!(report.max_state_dimension, 4);
+    assert_eq!(
+        report.serialized_bytes,
+        bincode::serialize(&circuit).unwrap().len()
+    );
+
+    let serialized = serde_json::to_string(&report).unwrap();
+    let deserialized: roqoqo::CircuitSizeReport = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, report);
+}
+
+/// Test annotating a circuit and collecting the annotations
+#[test]
+fn circuit_annotations() {
+    let mut circuit = Circuit::new();
+    assert!(circuit.annotations().is_empty());
+    circuit.annotate("compiler".to_string(), "1.0".to_string());
+    circuit += PauliX::new(0);
+    circuit += PragmaAnnotation::new("passes".to_string(), "optimize".to_string());
+    circuit.annotate("compiler".to_string(), "1.1".to_string());
+
+    assert_eq!(
+        circuit.annotations(),
+        HashMap::from([
+            ("compiler".to_string(), "1.1".to_string()),
+            ("passes".to_string(), "optimize".to_string()),
+        ])
+    );
+    assert_eq!(circuit.len(), 4);
+    assert_eq!(
+        circuit.get(0),
+        Some(&Operation::from(PragmaAnnotation::new(
+            "compiler".to_string(),
+            "1.0".to_string()
+        )))
+    );
+    assert_eq!(
+        circuit.involved_qubits(),
+        InvolvedQubits::Set(HashSet::from([0]))
+    );
+}
+
+/// Test the annotations of a circuit survive serialization
+#[cfg(feature = "serialize")]
+#[test]
+fn circuit_annotations_serde() {
+    let mut circuit = Circuit::new();
+    circuit.annotate("compiler".to_string(), "1.0".to_string());
+    circuit.annotate("author".to_string(), "someone".to_string());
+    circuit += PauliX::new(0);
+
+    let json = serde_json::to_string(&circuit).unwrap();
+    let deserialized: Circuit = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, circuit);
+    assert_eq!(deserialized.annotations(), circuit.annotations());
+
+    let encoded = bincode::serialize(&circuit).unwrap();
+    let decoded: Circuit = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded.annotations(), circuit.annotations());
+}
@@ -12,9 +12,11 @@
 
 use std::collections::HashSet;
 
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::devices::AllToAllDevice;
 use roqoqo::{
     operations::{self, *},
-    RoqoqoError,
+    RoqoqoBackendError, RoqoqoError,
 };
 use roqoqo::{Circuit, CircuitDag};
 
@@ -48,6 +50,131 @@ fn test_conversion() {
     }
 }
 
+/// Test that bosonic operations acting on the same mode are ordered.
+#[test]
+fn bosonic_operations_same_mode() {
+    let first = Operation::from(Squeezing::new(0, 0.1.into(), 0.0.into()));
+    let second = Operation::from(Squeezing::new(0, 0.2.into(), 0.5.into()));
+    let other_mode = Operation::from(Squeezing::new(1, 0.3.into(), 0.0.into()));
+
+    let mut dag: CircuitDag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    let index_first = dag.add_to_back(first.clone()).unwrap();
+    let index_second = dag.add_to_back(second.clone()).unwrap();
+    let index_other = dag.add_to_back(other_mode.clone()).unwrap();
+
+    assert!(dag.commuting_operations().is_empty());
+    assert_eq!(
+        dag.successors(index_first).collect::<Vec<_>>(),
+        vec![index_second.into()]
+    );
+    assert_eq!(
+        dag.first_parallel_block(),
+        &HashSet::from([index_first, index_other])
+    );
+    assert_eq!(
+        dag.last_parallel_block(),
+        &HashSet::from([index_second, index_other])
+    );
+    assert_eq!(
+        dag.first_operation_involving_mode().get(&0),
+        Some(&index_first)
+    );
+    assert_eq!(
+        dag.last_operation_involving_mode().get(&0),
+        Some(&index_second)
+    );
+
+    let index_front = dag.add_to_front(second.clone()).unwrap();
+    assert_eq!(
+        dag.successors(index_front).collect::<Vec<_>>(),
+        vec![index_first.into()]
+    );
+    assert_eq!(
+        dag.first_operation_involving_mode().get(&0),
+        Some(&index_front)
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += second.clone();
+    circuit += first.clone();
+    let circuit_from_dag: Circuit = CircuitDag::from(circuit.clone()).into();
+    assert_eq!(circuit_from_dag, circuit);
+}
+
+/// Test that the bosonic mode maps are rebuilt when deserializing a CircuitDag.
+#[cfg(feature = "serialize")]
+#[test]
+fn bosonic_modes_serialization() {
+    let mut dag: CircuitDag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    let _ = dag.add_to_back(Squeezing::new(0, 0.1.into(), 0.0.into()).into());
+    let last = dag
+        .add_to_back(Squeezing::new(0, 0.2.into(), 0.5.into()).into())
+        .unwrap();
+    let other_mode = dag
+        .add_to_back(PhaseShift::new(1, 0.3.into()).into())
+        .unwrap();
+    let first = dag
+        .add_to_front(Squeezing::new(0, 0.4.into(), 0.0.into()).into())
+        .unwrap();
+
+    let serialized = bincode::serialize(&dag).unwrap();
+    let deserialized: CircuitDag = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, dag);
+    assert_eq!(
+        deserialized.first_operation_involving_mode(),
+        dag.first_operation_involving_mode()
+    );
+    assert_eq!(
+        deserialized.last_operation_involving_mode(),
+        dag.last_operation_involving_mode()
+    );
+    assert_eq!(
+        deserialized.first_operation_involving_mode().get(&0),
+        Some(&first)
+    );
+    assert_eq!(
+        deserialized.last_operation_involving_mode().get(&0),
+        Some(&last)
+    );
+    assert_eq!(
+        deserialized.first_operation_involving_mode().get(&1),
+        Some(&other_mode)
+    );
+}
+
+/// Test deserializing a CircuitDag serialized by roqoqo 1.17.
+///
+/// The CircuitDag contains RotateX(0), Squeezing(0) and PhaseShift(1).
+#[cfg(feature = "serialize")]
+#[test]
+fn bosonic_modes_deserialize_1_17() {
+    let dag: CircuitDag = bincode::deserialize(&CIRCUIT_DAG_1_17).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 0.5.into());
+    circuit += Squeezing::new(0, 0.1.into(), 0.0.into());
+    circuit += PhaseShift::new(1, 0.2.into());
+    assert_eq!(dag, CircuitDag::from(circuit));
+    assert_eq!(dag.first_operation_involving_mode().get(&0), Some(&1));
+    assert_eq!(dag.last_operation_involving_mode().get(&0), Some(&1));
+    assert_eq!(dag.first_operation_involving_mode().get(&1), Some(&2));
+    assert_eq!(dag.last_operation_involving_mode().get(&1), Some(&2));
+}
+
+// Bincode serialization of a CircuitDag written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const CIRCUIT_DAG_1_17: [u8; 242] = [
+    3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    224, 63, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 153, 153, 153, 153, 153, 185,
+    63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    154, 153, 153, 153, 153, 153, 201, 63, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 17, 0, 0, 0
+];
+
 /// Test adding an operation that doesn't involve qubits.
 ///
 #[test_case(
@@ -508,3 +635,201 @@ fn test_successors() {
     assert_eq!(iter.next().unwrap(), d.into());
     assert!(iter.next().is_none());
 }
+
+/// Circuit where qubit 0 is measured early and qubit 3 only appears late
+fn qubit_reuse_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 4, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += RotateX::new(2, 1.0.into());
+    circuit += CNOT::new(1, 2);
+    circuit += CNOT::new(2, 3);
+    circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+    circuit
+}
+
+/// Test qubit_lifetimes and max_concurrent_qubits
+#[test]
+fn test_qubit_lifetimes() {
+    let dag = CircuitDag::from(qubit_reuse_circuit());
+
+    let lifetimes = dag.qubit_lifetimes();
+    assert_eq!(lifetimes.len(), 4);
+    assert_eq!(lifetimes[&0], (1, 3));
+    assert_eq!(lifetimes[&1], (2, 5));
+    assert_eq!(lifetimes[&2], (4, 6));
+    assert_eq!(lifetimes[&3], (6, 7));
+    assert_eq!(dag.max_concurrent_qubits(), 2);
+
+    // An operation involving all qubits keeps every qubit alive until the end
+    let mut circuit = qubit_reuse_circuit();
+    circuit += PragmaGetStateVector::new("ro".to_string(), None);
+    let dag = CircuitDag::from(circuit);
+    let lifetimes = dag.qubit_lifetimes();
+    assert_eq!(lifetimes[&0], (1, 8));
+    assert_eq!(lifetimes[&3], (6, 8));
+    assert_eq!(dag.max_concurrent_qubits(), 4);
+
+    // Ties are broken by the node index, so operations added to the front can be placed late
+    let mut dag = CircuitDag::from(qubit_reuse_circuit());
+    dag.add_to_front(PauliX::new(3).into());
+    let lifetimes = dag.qubit_lifetimes();
+    assert_eq!(lifetimes[&2], (4, 7));
+    assert_eq!(lifetimes[&3], (6, 8));
+    assert_eq!(dag.max_concurrent_qubits(), 2);
+
+    let dag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    assert!(dag.qubit_lifetimes().is_empty());
+    assert_eq!(dag.max_concurrent_qubits(), 0);
+}
+
+fn scheduling_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 4, true);
+    circuit += Hadamard::new(0);
+    circuit += Hadamard::new(1);
+    circuit += PauliX::new(2);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateZ::new(3, 0.5.into());
+    circuit += CNOT::new(1, 2);
+    circuit += PauliY::new(0);
+    circuit += ControlledPauliZ::new(2, 3);
+    circuit += RotateX::new(1, 0.1.into());
+    circuit += PragmaGlobalPhase::new(0.3.into());
+    circuit += SWAP::new(0, 3);
+    circuit += PauliZ::new(2);
+    circuit += CNOT::new(3, 1);
+    circuit += Hadamard::new(2);
+    circuit += PragmaStopParallelBlock::new(vec![0, 1], 0.0.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += MeasureQubit::new(2, "ro".to_string(), 2);
+    circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+    circuit
+}
+
+/// Test predecessors
+#[test]
+fn test_predecessors() {
+    let dag = CircuitDag::from(scheduling_circuit());
+    assert_eq!(dag.predecessors(1), Vec::<usize>::new());
+    assert_eq!(dag.predecessors(4), vec![1, 2]);
+    assert_eq!(dag.predecessors(6), vec![3, 4]);
+    for node in 0..20 {
+        for predecessor in dag.predecessors(node) {
+            assert!(dag.successors(predecessor).any(|n| n.index() == node));
+        }
+    }
+}
+
+/// Test scheduling a circuit greedily with ready_after
+#[test]
+fn test_ready_after_greedy_schedule() {
+    let dag = CircuitDag::from(scheduling_circuit());
+    let mut executed: Vec<usize> = Vec::new();
+    loop {
+        let ready = dag.ready_after(&executed);
+        if ready.is_empty() {
+            break;
+        }
+        for node in ready.iter() {
+            assert!(!executed.contains(node));
+            assert!(dag.blocking_predecessors(&executed, node).is_empty());
+        }
+        // Greedily execute the first ready operation
+        executed.push(ready[0]);
+    }
+
+    assert_eq!(executed.len(), 20);
+    let unique: HashSet<usize> = executed.iter().copied().collect();
+    assert_eq!(unique.len(), 20);
+    for (position, node) in executed.iter().enumerate() {
+        for predecessor in dag.predecessors(*node) {
+            let predecessor_position = executed.iter().position(|n| *n == predecessor).unwrap();
+            assert!(predecessor_position < position);
+        }
+    }
+
+    // Executing all ready operations at once also schedules every operation exactly once
+    let mut executed: Vec<usize> = Vec::new();
+    let mut ready = dag.ready_after(&executed);
+    while !ready.is_empty() {
+        executed.extend(ready.iter());
+        ready = dag.ready_after(&executed);
+    }
+    let unique: HashSet<usize> = executed.iter().copied().collect();
+    assert_eq!(executed.len(), 20);
+    assert_eq!(unique.len(), 20);
+}
+
+fn loop_device() -> AllToAllDevice {
+    AllToAllDevice::new(
+        3,
+        &["Hadamard".to_string(), "RotateX".to_string()],
+        &["CNOT".to_string()],
+        1.0,
+    )
+    .with_two_qubit_gate_time("CNOT", 2.0)
+}
+
+fn loop_circuit(repetitions: CalculatorFloat) -> Circuit {
+    let mut inner = Circuit::new();
+    inner += Hadamard::new(0);
+    inner += CNOT::new(0, 1);
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(2, 0.1.into());
+    circuit += PragmaLoop::new(repetitions, inner);
+    circuit += Hadamard::new(1);
+    circuit
+}
+
+/// Test that depth and execution_time scale with the repetitions of an integer loop
+#[test]
+fn test_loop_repetitions() {
+    let device = loop_device();
+    for repetitions in [0, 1, 2, 5] {
+        let dag = CircuitDag::from(loop_circuit((repetitions as f64).into()));
+        assert_eq!(dag.repetitions(0), Ok(1));
+        assert_eq!(dag.repetitions(1), Ok(repetitions));
+        assert_eq!(dag.depth(), Ok(2 * repetitions + 1));
+        assert_eq!(
+            dag.execution_time(&device),
+            Ok((3 * repetitions + 1) as f64)
+        );
+    }
+    // The RotateX on qubit 2 runs in parallel to the loop
+    let dag = CircuitDag::from(loop_circuit(0.0.into()));
+    assert_eq!(dag.execution_time(&device), Ok(1.0));
+
+    // Nested loops multiply their repetitions
+    let mut circuit = Circuit::new();
+    circuit += PragmaLoop::new(3.0.into(), loop_circuit(2.0.into()));
+    let dag = CircuitDag::from(circuit);
+    assert_eq!(dag.depth(), Ok(15));
+    assert_eq!(dag.execution_time(&device), Ok(21.0));
+
+    let dag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    assert_eq!(dag.depth(), Ok(0));
+    assert_eq!(dag.execution_time(&device), Ok(0.0));
+    assert!(dag.repetitions(0).is_err());
+}
+
+/// Test that depth and execution_time fail for a loop with symbolic repetitions
+#[test]
+fn test_symbolic_loop_repetitions() {
+    let error = || RoqoqoError::SymbolicRepetitions {
+        repetitions: "n".to_string(),
+        index: 1,
+    };
+    let dag = CircuitDag::from(loop_circuit("n".into()));
+    assert!(matches!(dag.get(1), Some(Operation::PragmaLoop(_))));
+    assert_eq!(dag.repetitions(0), Ok(1));
+    assert_eq!(dag.repetitions(1), Err(error()));
+    assert_eq!(dag.depth(), Err(error()));
+    assert_eq!(
+        dag.execution_time(&loop_device()),
+        Err(RoqoqoBackendError::RoqoqoError(error()))
+    );
+}
@@ -0,0 +1,169 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the Cirq JSON export of circuits
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations::*;
+use roqoqo::{Circuit, RoqoqoBackendError};
+use test_case::test_case;
+
+fn parse(input: &str) -> serde_json::Value {
+    serde_json::from_str(input).unwrap()
+}
+
+/// Test export of a Bell state preparation with measurements
+#[test]
+fn to_cirq_json_bell_state() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+
+    let reference = r#"{
+  "cirq_type": "Circuit",
+  "moments": [
+    {
+      "cirq_type": "Moment",
+      "operations": [
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "HPowGate", "exponent": 1.0, "global_shift": 0.0},
+          "qubits": [{"cirq_type": "LineQubit", "x": 0}]
+        }
+      ]
+    },
+    {
+      "cirq_type": "Moment",
+      "operations": [
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "CXPowGate", "exponent": 1.0, "global_shift": 0.0},
+          "qubits": [{"cirq_type": "LineQubit", "x": 0}, {"cirq_type": "LineQubit", "x": 1}]
+        }
+      ]
+    },
+    {
+      "cirq_type": "Moment",
+      "operations": [
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "MeasurementGate", "num_qubits": 1, "key": "ro[0]", "invert_mask": []},
+          "qubits": [{"cirq_type": "LineQubit", "x": 0}]
+        },
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "MeasurementGate", "num_qubits": 1, "key": "ro[1]", "invert_mask": []},
+          "qubits": [{"cirq_type": "LineQubit", "x": 1}]
+        }
+      ]
+    }
+  ]
+}"#;
+    assert_eq!(parse(&circuit.to_cirq_json().unwrap()), parse(reference));
+}
+
+/// Test that operations on disjoint qubits share a moment
+#[test]
+fn to_cirq_json_moments() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    circuit += RotateZ::new(1, 0.5.into());
+    circuit += ISwap::new(1, 0);
+    circuit += RotateX::new(2, "theta".into());
+
+    let reference = r#"{
+  "cirq_type": "Circuit",
+  "moments": [
+    {
+      "cirq_type": "Moment",
+      "operations": [
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "_PauliX", "exponent": 1.0, "global_shift": 0.0},
+          "qubits": [{"cirq_type": "LineQubit", "x": 0}]
+        },
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "Rz", "rads": 0.5},
+          "qubits": [{"cirq_type": "LineQubit", "x": 1}]
+        },
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "Rx", "rads": {"cirq_type": "sympy.Symbol", "name": "theta"}},
+          "qubits": [{"cirq_type": "LineQubit", "x": 2}]
+        }
+      ]
+    },
+    {
+      "cirq_type": "Moment",
+      "operations": [
+        {
+          "cirq_type": "GateOperation",
+          "gate": {"cirq_type": "ISwapPowGate", "exponent": 1.0, "global_shift": 0.0},
+          "qubits": [{"cirq_type": "LineQubit", "x": 1}, {"cirq_type": "LineQubit", "x": 0}]
+        }
+      ]
+    }
+  ]
+}"#;
+    assert_eq!(parse(&circuit.to_cirq_json().unwrap()), parse(reference));
+}
+
+/// Test the gate representation of all supported single gates
+#[test_case(Operation::from(PauliY::new(0)), r#"{"cirq_type": "_PauliY", "exponent": 1.0, "global_shift": 0.0}"#; "PauliY")]
+#[test_case(Operation::from(PauliZ::new(0)), r#"{"cirq_type": "_PauliZ", "exponent": 1.0, "global_shift": 0.0}"#; "PauliZ")]
+#[test_case(Operation::from(SGate::new(0)), r#"{"cirq_type": "ZPowGate", "exponent": 0.5, "global_shift": 0.0}"#; "SGate")]
+#[test_case(Operation::from(TGate::new(0)), r#"{"cirq_type": "ZPowGate", "exponent": 0.25, "global_shift": 0.0}"#; "TGate")]
+#[test_case(Operation::from(RotateY::new(0, 1.5.into())), r#"{"cirq_type": "Ry", "rads": 1.5}"#; "RotateY")]
+#[test_case(Operation::from(ControlledPauliZ::new(0, 1)), r#"{"cirq_type": "CZPowGate", "exponent": 1.0, "global_shift": 0.0}"#; "ControlledPauliZ")]
+#[test_case(Operation::from(SWAP::new(0, 1)), r#"{"cirq_type": "SwapPowGate", "exponent": 1.0, "global_shift": 0.0}"#; "SWAP")]
+fn to_cirq_json_gates(operation: Operation, gate: &str) {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(operation);
+    let exported = parse(&circuit.to_cirq_json().unwrap());
+    assert_eq!(exported["moments"][0]["operations"][0]["gate"], parse(gate));
+}
+
+/// Test export of circuits without operations
+#[test]
+fn to_cirq_json_empty() {
+    let reference = parse(r#"{"cirq_type": "Circuit", "moments": []}"#);
+    assert_eq!(parse(&Circuit::new().to_cirq_json().unwrap()), reference);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionFloat::new("ro".to_string(), 1, true);
+    assert_eq!(parse(&circuit.to_cirq_json().unwrap()), reference);
+}
+
+/// Test errors for operations without Cirq equivalent
+#[test]
+fn to_cirq_json_errors() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += PragmaDamping::new(0, 1.0.into(), 0.1.into());
+    assert_eq!(
+        circuit.to_cirq_json(),
+        Err(RoqoqoBackendError::OperationNotInBackend {
+            backend: "Cirq",
+            hqslang: "PragmaDamping"
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += RotateZ::new(0, CalculatorFloat::from("2 * theta"));
+    assert!(matches!(
+        circuit.to_cirq_json(),
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+}
@@ -12,13 +12,34 @@
 
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, Validator};
-use ndarray::array;
+#[cfg(feature = "serialize")]
+use ndarray::Array2;
+use ndarray::{array, Array1, Array3};
+#[cfg(feature = "serialize")]
+use qoqo_calculator::Calculator;
+#[cfg(feature = "serialize")]
+use roqoqo::devices::AllToAllDeviceConfig;
+use roqoqo::operations::{
+    DefinitionBit, MeasureQubit, MultiQubitMS, NegativeControl, Operation, PragmaDamping,
+    PragmaDephasing, PragmaDepolarising, PragmaGeneralNoise, PragmaSleep, RotateX, RotateZ,
+    Toffoli, TripleControlledPauliX, CNOT,
+};
+#[cfg(feature = "serialize")]
+use roqoqo::operations::{
+    InvolveQubits, InvolvedQubits, Operate, PragmaActiveReset, PragmaChangeDevice,
+    PragmaSetGateTime, Substitute, SupportedVersion,
+};
 use roqoqo::{
-    devices::{AllToAllDevice, Device, GenericDevice, SquareLatticeDevice},
-    RoqoqoError,
+    devices::{
+        add_active_gate_noise, gate_time, AllToAllDevice, Device, GenericDevice,
+        SquareLatticeDevice,
+    },
+    Circuit, RoqoqoBackendError, RoqoqoError,
 };
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
+#[cfg(feature = "serialize")]
+use std::collections::HashMap;
 // use test_case::test_case;
 
 #[test]
@@ -254,6 +275,12 @@ fn generic_device_works() {
 
     let change_device_error = device.change_device("PragmaTest", Vec::<u8>::new().as_ref());
     assert!(change_device_error.is_err());
+    #[cfg(feature = "serialize")]
+    assert!(change_device_error
+        .unwrap_err()
+        .to_string()
+        .contains("PRAGMA PragmaTest is not a known device changing PRAGMA."));
+    #[cfg(not(feature = "serialize"))]
     assert!(change_device_error
         .unwrap_err()
         .to_string()
@@ -272,6 +299,102 @@ fn change_device_test() {
     let result = device.change_device("", &empty_serialisation);
     assert!(result.is_err());
 }
+
+/// Test changing the gate times of a GenericDevice with a wrapped PragmaSetGateTime
+#[cfg(feature = "serialize")]
+#[test]
+fn change_device_set_gate_time() {
+    let mut device = GenericDevice::new(3);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 0.1)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 0.5).unwrap();
+
+    let pragma = PragmaChangeDevice::new(&PragmaSetGateTime::new(
+        "CNOT".to_string(),
+        vec![0, 1],
+        300.0.into(),
+    ))
+    .unwrap();
+    device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .unwrap();
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &1), Some(300.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &1, &0), None);
+
+    let pragma = PragmaChangeDevice::new(&PragmaSetGateTime::new(
+        "RotateX".to_string(),
+        vec![2],
+        20.0.into(),
+    ))
+    .unwrap();
+    device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &2), Some(20.0));
+
+    let pragma = PragmaChangeDevice::new(&PragmaSetGateTime::new(
+        "MultiQubitMS".to_string(),
+        vec![0, 1, 2],
+        1000.0.into(),
+    ))
+    .unwrap();
+    device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .unwrap();
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitMS", &[0, 1, 2]),
+        Some(1000.0)
+    );
+
+    // Symbolic gate times and PRAGMAs without qubits cannot be applied
+    let pragma = PragmaChangeDevice::new(&PragmaSetGateTime::new(
+        "CNOT".to_string(),
+        vec![0, 1],
+        "t".into(),
+    ))
+    .unwrap();
+    assert!(device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .is_err());
+    let pragma = PragmaChangeDevice::new(&PragmaSetGateTime::new(
+        "CNOT".to_string(),
+        vec![],
+        300.0.into(),
+    ))
+    .unwrap();
+    assert!(device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .is_err());
+
+    // Qubits outside of the device are rejected
+    let pragma = PragmaChangeDevice::new(&PragmaSetGateTime::new(
+        "CNOT".to_string(),
+        vec![0, 3],
+        300.0.into(),
+    ))
+    .unwrap();
+    assert!(device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .is_err());
+
+    // Other PRAGMAs and corrupted serialisations are rejected
+    let pragma = PragmaChangeDevice::new(&PragmaActiveReset::new(0)).unwrap();
+    assert!(matches!(
+        device.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation),
+        Err(RoqoqoBackendError::RoqoqoError(
+            RoqoqoError::UnknownChangeDevicePragma { hqslang }
+        )) if hqslang == "PragmaActiveReset"
+    ));
+    let result = device.change_device("PragmaSetGateTime", &[0, 1]);
+    assert!(matches!(
+        result,
+        Err(RoqoqoBackendError::RoqoqoError(
+            RoqoqoError::ChangeDeviceDeserializationError { .. }
+        ))
+    ));
+}
 #[test]
 fn all_to_all_generic() {
     let mut generic_device = GenericDevice::new(2);
@@ -327,6 +450,12 @@ fn all_to_all_generic() {
 
     let change_device_error = generic_device.change_device("PragmaTest", Vec::<u8>::new().as_ref());
     assert!(change_device_error.is_err());
+    #[cfg(feature = "serialize")]
+    assert!(change_device_error
+        .unwrap_err()
+        .to_string()
+        .contains("PRAGMA PragmaTest is not a known device changing PRAGMA."));
+    #[cfg(not(feature = "serialize"))]
     assert!(change_device_error
         .unwrap_err()
         .to_string()
@@ -363,6 +492,12 @@ fn square_lattice_generic() {
 
     let change_device_error = generic_device.change_device("PragmaTest", Vec::<u8>::new().as_ref());
     assert!(change_device_error.is_err());
+    #[cfg(feature = "serialize")]
+    assert!(change_device_error
+        .unwrap_err()
+        .to_string()
+        .contains("PRAGMA PragmaTest is not a known device changing PRAGMA."));
+    #[cfg(not(feature = "serialize"))]
     assert!(change_device_error
         .unwrap_err()
         .to_string()
@@ -494,6 +629,68 @@ fn test_square_lattice() {
         .contains("The `change_device()` method has not been implemented."));
 }
 
+#[test]
+fn test_square_lattice_coordinates() {
+    // 3x4 lattice:
+    //  0  1  2  3
+    //  4  5  6  7
+    //  8  9 10 11
+    let device =
+        SquareLatticeDevice::new(3, 4, &["RotateX".to_string()], &["CNOT".to_string()], 0.1);
+
+    assert_eq!(device.qubit_index(0, 0), Some(0));
+    assert_eq!(device.qubit_index(1, 2), Some(6));
+    assert_eq!(device.qubit_index(2, 3), Some(11));
+    assert_eq!(device.qubit_index(3, 0), None);
+    assert_eq!(device.qubit_index(0, 4), None);
+
+    assert_eq!(device.qubit_position(0), Some((0, 0)));
+    assert_eq!(device.qubit_position(6), Some((1, 2)));
+    assert_eq!(device.qubit_position(11), Some((2, 3)));
+    assert_eq!(device.qubit_position(12), None);
+    for qubit in 0..12 {
+        let (row, column) = device.qubit_position(qubit).unwrap();
+        assert_eq!(device.qubit_index(row, column), Some(qubit));
+    }
+
+    assert_eq!(device.row(0), vec![0, 1, 2, 3]);
+    assert_eq!(device.row(2), vec![8, 9, 10, 11]);
+    assert_eq!(device.row(3), Vec::<usize>::new());
+    assert_eq!(device.column(0), vec![0, 4, 8]);
+    assert_eq!(device.column(3), vec![3, 7, 11]);
+    assert_eq!(device.column(4), Vec::<usize>::new());
+
+    // corners
+    assert_eq!(device.neighbours(0), vec![1, 4]);
+    assert_eq!(device.neighbours(3), vec![2, 7]);
+    assert_eq!(device.neighbours(8), vec![4, 9]);
+    assert_eq!(device.neighbours(11), vec![7, 10]);
+    // edges
+    assert_eq!(device.neighbours(1), vec![0, 2, 5]);
+    assert_eq!(device.neighbours(4), vec![0, 5, 8]);
+    assert_eq!(device.neighbours(7), vec![3, 6, 11]);
+    assert_eq!(device.neighbours(9), vec![5, 8, 10]);
+    // interior
+    assert_eq!(device.neighbours(5), vec![1, 4, 6, 9]);
+    assert_eq!(device.neighbours(6), vec![2, 5, 7, 10]);
+    // outside of the lattice
+    assert_eq!(device.neighbours(12), Vec::<usize>::new());
+
+    // consistent with the edges of the device
+    let edges = device.two_qubit_edges();
+    for qubit in 0..12 {
+        for neighbour in device.neighbours(qubit) {
+            assert!(edges.contains(&(qubit, neighbour)) || edges.contains(&(neighbour, qubit)));
+            assert_eq!(
+                device.two_qubit_gate_time("CNOT", &qubit, &neighbour),
+                Some(0.1)
+            );
+        }
+    }
+    let number_neighbours: usize = (0..12).map(|qubit| device.neighbours(qubit).len()).sum();
+    assert_eq!(number_neighbours, 2 * edges.len());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_json_schema() {
@@ -544,3 +741,1176 @@ fn test_json_schema() {
     assert!(validation_result_squared.is_ok());
     assert!(validation_result_all_to_all.is_ok());
 }
+
+/// Toy device specific PRAGMA setting the number of qubits of the ToyDevice.
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct PragmaToyNumberQubits {
+    number_qubits: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl InvolveQubits for PragmaToyNumberQubits {
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Substitute for PragmaToyNumberQubits {
+    fn substitute_parameters(&self, _calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl SupportedVersion for PragmaToyNumberQubits {}
+
+#[cfg(feature = "serialize")]
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for PragmaToyNumberQubits {
+    fn tags(&self) -> &'static [&'static str] {
+        &["Operation", "PragmaOperation", "PragmaToyNumberQubits"]
+    }
+    fn hqslang(&self) -> &'static str {
+        "PragmaToyNumberQubits"
+    }
+    fn is_parametrized(&self) -> bool {
+        false
+    }
+}
+
+/// Toy device that can be changed with a PragmaToyNumberQubits.
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone, PartialEq)]
+struct ToyDevice {
+    number_qubits: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl Device for ToyDevice {
+    fn single_qubit_gate_time(&self, _hqslang: &str, _qubit: &usize) -> Option<f64> {
+        None
+    }
+    fn two_qubit_gate_time(
+        &self,
+        _hqslang: &str,
+        _control: &usize,
+        _target: &usize,
+    ) -> Option<f64> {
+        None
+    }
+    fn three_qubit_gate_time(
+        &self,
+        _hqslang: &str,
+        _control_0: &usize,
+        _control_1: &usize,
+        _target: &usize,
+    ) -> Option<f64> {
+        None
+    }
+    fn multi_qubit_gate_time(&self, _hqslang: &str, _qubits: &[usize]) -> Option<f64> {
+        None
+    }
+    fn qubit_decoherence_rates(&self, _qubit: &usize) -> Option<ndarray::Array2<f64>> {
+        None
+    }
+    fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
+        Vec::new()
+    }
+    fn change_device(&mut self, hqslang: &str, operation: &[u8]) -> Result<(), RoqoqoBackendError> {
+        match hqslang {
+            "PragmaToyNumberQubits" => {
+                let pragma: PragmaToyNumberQubits =
+                    bincode::deserialize(operation).map_err(|err| {
+                        RoqoqoError::ChangeDeviceDeserializationError {
+                            hqslang: hqslang.to_string(),
+                            msg: format!("{:?}", err),
+                        }
+                    })?;
+                self.number_qubits = pragma.number_qubits;
+                Ok(())
+            }
+            _ => Err(RoqoqoError::UnknownChangeDevicePragma {
+                hqslang: hqslang.to_string(),
+            }
+            .into()),
+        }
+    }
+    fn to_generic_device(&self) -> GenericDevice {
+        GenericDevice::new(self.number_qubits)
+    }
+}
+
+/// Test round trip of a typed PRAGMA through PragmaChangeDevice
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_change_device_typed_round_trip() {
+    let toy = PragmaToyNumberQubits { number_qubits: 4 };
+    let pragma = PragmaChangeDevice::from_operation(&toy).unwrap();
+    assert_eq!(pragma, PragmaChangeDevice::new(&toy).unwrap());
+    assert_eq!(pragma.wrapped_hqslang, "PragmaToyNumberQubits");
+    assert_eq!(
+        pragma.wrapped_tags,
+        vec!["Operation", "PragmaOperation", "PragmaToyNumberQubits"]
+    );
+    let deserialized: PragmaToyNumberQubits = pragma.try_deserialize().unwrap();
+    assert_eq!(deserialized, toy);
+
+    let mut broken = pragma.clone();
+    broken.wrapped_operation = vec![1];
+    assert_eq!(
+        broken.try_deserialize::<PragmaToyNumberQubits>(),
+        Err(RoqoqoError::ChangeDeviceDeserializationError {
+            hqslang: "PragmaToyNumberQubits".to_string(),
+            msg: "Io(Kind(UnexpectedEof))".to_string(),
+        })
+    );
+}
+
+/// Test changing a device with typed PRAGMAs
+#[cfg(feature = "serialize")]
+#[test]
+fn change_device_typed() {
+    let mut device = ToyDevice { number_qubits: 2 };
+    let toy = PragmaToyNumberQubits { number_qubits: 4 };
+    device.change_device_typed(&toy).unwrap();
+    assert_eq!(device.number_qubits(), 4);
+
+    let pragma =
+        PragmaChangeDevice::from_operation(&PragmaToyNumberQubits { number_qubits: 3 }).unwrap();
+    device
+        .change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)
+        .unwrap();
+    assert_eq!(device.number_qubits(), 3);
+
+    // Unknown PRAGMA name
+    let error = device.change_device_typed(&PragmaActiveReset::new(0));
+    assert!(matches!(
+        error,
+        Err(RoqoqoBackendError::RoqoqoError(
+            RoqoqoError::UnknownChangeDevicePragma { hqslang }
+        )) if hqslang == "PragmaActiveReset"
+    ));
+    // Known PRAGMA name, broken payload
+    let error = device.change_device("PragmaToyNumberQubits", &[1]);
+    assert!(matches!(
+        error,
+        Err(RoqoqoBackendError::RoqoqoError(
+            RoqoqoError::ChangeDeviceDeserializationError { hqslang, .. }
+        )) if hqslang == "PragmaToyNumberQubits"
+    ));
+    assert_eq!(device.number_qubits(), 3);
+
+    // Devices that cannot be changed still return the default error
+    let mut all_to_all_device = AllToAllDevice::new(2, &[], &[], 1.0);
+    let error = all_to_all_device.change_device_typed(&toy).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("The `change_device()` method has not been implemented."));
+}
+
+/// Test that GenericDevice serializes identically independent of the HashMap ordering
+#[cfg(feature = "serialize")]
+#[test]
+fn generic_device_serde_stable() {
+    let gates = ["RotateX", "RotateY", "RotateZ", "PauliX", "Hadamard"];
+    let two_qubit_gates = ["CNOT", "ControlledPauliZ", "ISwap", "SWAP"];
+    let number_qubits = 8;
+
+    let mut device = GenericDevice::new(number_qubits);
+    for gate in gates.iter() {
+        for qubit in 0..number_qubits {
+            device
+                .set_single_qubit_gate_time(gate, qubit, 0.1 * qubit as f64)
+                .unwrap();
+        }
+    }
+    for gate in two_qubit_gates.iter() {
+        for control in 0..number_qubits {
+            for target in 0..number_qubits {
+                device
+                    .set_two_qubit_gate_time(gate, control, target, 0.5)
+                    .unwrap();
+            }
+        }
+    }
+    for qubit in 0..number_qubits {
+        device
+            .set_multi_qubit_gate_time("MultiQubitMS", vec![qubit, (qubit + 1) % 8], 0.3)
+            .unwrap();
+        device
+            .set_qubit_decoherence_rates(
+                qubit,
+                array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+            )
+            .unwrap();
+    }
+
+    // The same device built in reverse order
+    let mut device_reversed = GenericDevice::new(number_qubits);
+    for qubit in (0..number_qubits).rev() {
+        device_reversed
+            .set_qubit_decoherence_rates(
+                qubit,
+                array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+            )
+            .unwrap();
+        device_reversed
+            .set_multi_qubit_gate_time("MultiQubitMS", vec![qubit, (qubit + 1) % 8], 0.3)
+            .unwrap();
+    }
+    for gate in two_qubit_gates.iter().rev() {
+        for control in (0..number_qubits).rev() {
+            for target in (0..number_qubits).rev() {
+                device_reversed
+                    .set_two_qubit_gate_time(gate, control, target, 0.5)
+                    .unwrap();
+            }
+        }
+    }
+    for gate in gates.iter().rev() {
+        for qubit in (0..number_qubits).rev() {
+            device_reversed
+                .set_single_qubit_gate_time(gate, qubit, 0.1 * qubit as f64)
+                .unwrap();
+        }
+    }
+    assert_eq!(device, device_reversed);
+
+    let json = serde_json::to_string(&device).unwrap();
+    assert_eq!(json, serde_json::to_string(&device_reversed).unwrap());
+    assert_eq!(
+        bincode::serialize(&device).unwrap(),
+        bincode::serialize(&device_reversed).unwrap()
+    );
+    let deserialized: GenericDevice = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, device_reversed);
+}
+
+/// Test that gate errors default to None and can be set on all devices
+#[test]
+fn gate_errors() {
+    let mut device = GenericDevice::new(3);
+    assert_eq!(device.single_qubit_gate_error("RotateX", &0), None);
+    assert_eq!(device.two_qubit_gate_error("CNOT", &0, &1), None);
+
+    device
+        .set_single_qubit_gate_error("RotateX", 0, 0.01)
+        .unwrap();
+    device.set_two_qubit_gate_error("CNOT", 0, 1, 0.02).unwrap();
+    assert_eq!(device.single_qubit_gate_error("RotateX", &0), Some(0.01));
+    assert_eq!(device.single_qubit_gate_error("RotateX", &1), None);
+    assert_eq!(device.two_qubit_gate_error("CNOT", &0, &1), Some(0.02));
+    assert_eq!(device.two_qubit_gate_error("CNOT", &1, &0), None);
+
+    assert!(device
+        .set_single_qubit_gate_error("RotateX", 3, 0.01)
+        .is_err());
+    assert!(device.set_two_qubit_gate_error("CNOT", 0, 3, 0.01).is_err());
+    assert!(device
+        .set_single_qubit_gate_error("RotateX", 0, 1.5)
+        .is_err());
+    assert!(device.set_two_qubit_gate_error("CNOT", 0, 1, -0.1).is_err());
+
+    let mut all_to_all =
+        AllToAllDevice::new(3, &["RotateX".to_string()], &["CNOT".to_string()], 1.0);
+    assert_eq!(all_to_all.single_qubit_gate_error("RotateX", &0), None);
+    all_to_all
+        .set_single_qubit_gate_error("RotateX", 1, 0.01)
+        .unwrap();
+    all_to_all
+        .set_two_qubit_gate_error("CNOT", 0, 2, 0.02)
+        .unwrap();
+    assert_eq!(
+        all_to_all.single_qubit_gate_error("RotateX", &1),
+        Some(0.01)
+    );
+    assert_eq!(all_to_all.two_qubit_gate_error("CNOT", &0, &2), Some(0.02));
+    let generic = all_to_all.to_generic_device();
+    assert_eq!(generic.single_qubit_gate_error("RotateX", &1), Some(0.01));
+    assert_eq!(generic.two_qubit_gate_error("CNOT", &0, &2), Some(0.02));
+
+    let mut lattice =
+        SquareLatticeDevice::new(2, 2, &["RotateX".to_string()], &["CNOT".to_string()], 1.0);
+    assert_eq!(lattice.two_qubit_gate_error("CNOT", &0, &1), None);
+    lattice
+        .set_single_qubit_gate_error("RotateX", 3, 0.01)
+        .unwrap();
+    lattice
+        .set_two_qubit_gate_error("CNOT", 0, 1, 0.02)
+        .unwrap();
+    assert!(lattice
+        .set_two_qubit_gate_error("CNOT", 0, 3, 0.02)
+        .is_err());
+    assert_eq!(lattice.single_qubit_gate_error("RotateX", &3), Some(0.01));
+    assert_eq!(lattice.two_qubit_gate_error("CNOT", &0, &1), Some(0.02));
+    let generic = lattice.to_generic_device();
+    assert_eq!(generic.two_qubit_gate_error("CNOT", &0, &1), Some(0.02));
+}
+
+/// Test that gate errors survive serialization and are optional when deserializing
+#[cfg(feature = "serialize")]
+#[test]
+fn gate_errors_serde() {
+    let mut device = GenericDevice::new(2);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 1.0)
+        .unwrap();
+    device
+        .set_single_qubit_gate_error("RotateX", 0, 0.01)
+        .unwrap();
+    device.set_two_qubit_gate_error("CNOT", 0, 1, 0.02).unwrap();
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: GenericDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, device);
+    assert_eq!(
+        deserialized.single_qubit_gate_error("RotateX", &0),
+        Some(0.01)
+    );
+    assert_eq!(
+        deserialized.two_qubit_gate_error("CNOT", &0, &1),
+        Some(0.02)
+    );
+
+    // The binary serialization is unchanged since roqoqo 1.0 and does not contain gate errors
+    let error = bincode::serialize(&device).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "GenericDevice with calibrated gate errors can only be serialized to human readable formats"
+    );
+    let mut all_to_all = AllToAllDevice::new(2, &["RotateX".to_string()], &[], 1.0);
+    all_to_all
+        .set_single_qubit_gate_error("RotateX", 0, 0.01)
+        .unwrap();
+    assert!(bincode::serialize(&all_to_all).is_err());
+    device.single_qubit_gate_errors.clear();
+    device.two_qubit_gate_errors.clear();
+    let encoded = bincode::serialize(&device).unwrap();
+    let decoded: GenericDevice = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, device);
+
+    // Devices serialized before gate errors were added deserialize without errors
+    let mut value: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+    let object = value.as_object_mut().unwrap();
+    object.remove("single_qubit_gate_errors");
+    object.remove("two_qubit_gate_errors");
+    let deserialized: GenericDevice = serde_json::from_value(value).unwrap();
+    assert_eq!(deserialized.single_qubit_gate_error("RotateX", &0), None);
+    assert_eq!(
+        deserialized.single_qubit_gate_time("RotateX", &0),
+        Some(1.0)
+    );
+}
+
+/// Test deserializing devices serialized with roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[test]
+fn devices_deserialize_1_17() {
+    let mut device = GenericDevice::new(2);
+    device
+        .set_single_qubit_gate_time("RotateZ", 0, 1.0)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 2.0).unwrap();
+    device
+        .set_qubit_decoherence_rates(0, array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+        .unwrap();
+    let deserialized: GenericDevice = bincode::deserialize(&GENERIC_DEVICE_1_17).unwrap();
+    assert_eq!(deserialized, device);
+    assert_eq!(bincode::serialize(&device).unwrap(), GENERIC_DEVICE_1_17);
+
+    let device = AllToAllDevice::new(2, &["RotateZ".to_string()], &["CNOT".to_string()], 1.0);
+    let deserialized: AllToAllDevice = bincode::deserialize(&ALL_TO_ALL_DEVICE_1_17).unwrap();
+    assert_eq!(deserialized, device);
+    // Gate times are serialized sorted by qubits, so only the layout matches roqoqo 1.17
+    assert_eq!(
+        bincode::serialize(&device).unwrap().len(),
+        ALL_TO_ALL_DEVICE_1_17.len()
+    );
+
+    let device =
+        SquareLatticeDevice::new(1, 2, &["RotateZ".to_string()], &["CNOT".to_string()], 1.0);
+    let deserialized: SquareLatticeDevice =
+        bincode::deserialize(&SQUARE_LATTICE_DEVICE_1_17).unwrap();
+    assert_eq!(deserialized, device);
+    assert_eq!(
+        bincode::serialize(&device).unwrap().len(),
+        SQUARE_LATTICE_DEVICE_1_17.len()
+    );
+}
+
+// Bincode serialization of a GenericDevice written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const GENERIC_DEVICE_1_17: [u8; 236] = [
+    2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 82, 111, 116, 97, 116, 101,
+    90, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 1, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 67, 78, 79, 84, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 9, 0,
+    0, 0, 0, 0, 0, 0, 154, 153, 153, 153, 153, 153, 185, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0
+];
+
+// Bincode serialization of an AllToAllDevice written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const ALL_TO_ALL_DEVICE_1_17: [u8; 389] = [
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0,
+    0, 0, 82, 111, 116, 97, 116, 101, 90, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 240, 63, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 0, 67, 78, 79, 84, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+    0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0
+];
+
+// Bincode serialization of a SquareLatticeDevice written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const SQUARE_LATTICE_DEVICE_1_17: [u8; 397] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 82, 111, 116, 97, 116, 101, 90, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 1, 0, 0,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 67, 78, 79, 84, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 1, 0, 0, 0
+];
+
+/// Test that chained builder methods equal the equivalent imperative setup
+#[test]
+fn builder_methods() {
+    let chained = AllToAllDevice::new(3, &["RotateX".to_string()], &["CNOT".to_string()], 1.0)
+        .with_single_qubit_gate_time("RotateZ", 0.5)
+        .with_two_qubit_gate_time("ISwap", 2.0)
+        .without_single_qubit_gate("RotateX")
+        .without_two_qubit_gate("CNOT")
+        .with_damping_rates(0.001)
+        .with_dephasing_rates(0.002)
+        .with_depolarising_rates(0.003);
+    let mut imperative = AllToAllDevice::new(3, &["RotateZ".to_string()], &[], 0.5);
+    for control in 0..3 {
+        for target in 0..3 {
+            if control != target {
+                imperative
+                    .set_two_qubit_gate_time("ISwap", control, target, 2.0)
+                    .unwrap();
+            }
+        }
+    }
+    for qubit in 0..3 {
+        imperative.add_damping(qubit, 0.001).unwrap();
+        imperative.add_dephasing(qubit, 0.002).unwrap();
+        imperative.add_depolarising(qubit, 0.003).unwrap();
+    }
+    assert_eq!(chained, imperative);
+    assert_eq!(chained.single_qubit_gate_time("RotateX", &0), None);
+    assert_eq!(chained.two_qubit_gate_time("CNOT", &0, &1), None);
+
+    let chained =
+        SquareLatticeDevice::new(2, 2, &["RotateX".to_string()], &["CNOT".to_string()], 1.0)
+            .with_single_qubit_gate_time("RotateZ", 0.5)
+            .with_two_qubit_gate_time("ISwap", 2.0)
+            .without_single_qubit_gate("RotateX")
+            .without_two_qubit_gate("CNOT")
+            .with_damping_rates(0.001)
+            .with_dephasing_rates(0.002)
+            .with_depolarising_rates(0.003);
+    let mut imperative = SquareLatticeDevice::new(2, 2, &["RotateZ".to_string()], &[], 0.5);
+    for (control, target) in imperative.two_qubit_edges() {
+        imperative
+            .set_two_qubit_gate_time("ISwap", control, target, 2.0)
+            .unwrap();
+        imperative
+            .set_two_qubit_gate_time("ISwap", target, control, 2.0)
+            .unwrap();
+    }
+    for qubit in 0..4 {
+        imperative.add_damping(qubit, 0.001).unwrap();
+        imperative.add_dephasing(qubit, 0.002).unwrap();
+        imperative.add_depolarising(qubit, 0.003).unwrap();
+    }
+    assert_eq!(chained, imperative);
+    assert_eq!(
+        chained.single_qubit_gate_names(),
+        vec!["RotateZ".to_string()]
+    );
+    assert_eq!(chained.two_qubit_gate_names(), vec!["ISwap".to_string()]);
+}
+
+fn dyn_device_gate_time(device: &dyn Device) -> Option<f64> {
+    device.single_qubit_gate_time("RotateX", &0)
+}
+
+fn impl_device_gate_time(device: impl Device) -> Option<f64> {
+    device.single_qubit_gate_time("RotateX", &0)
+}
+
+/// Test that boxed devices and references to devices can be used as devices
+#[test]
+fn boxed_and_referenced_devices() {
+    let mut generic = GenericDevice::new(2);
+    generic
+        .set_single_qubit_gate_time("RotateX", 0, 0.5)
+        .unwrap();
+    generic
+        .set_two_qubit_gate_error("CNOT", 0, 1, 0.01)
+        .unwrap();
+    let boxed: Box<dyn Device> = Box::new(generic.clone());
+
+    assert_eq!(dyn_device_gate_time(&boxed), Some(0.5));
+    assert_eq!(dyn_device_gate_time(boxed.as_ref()), Some(0.5));
+    assert_eq!(impl_device_gate_time(&generic), Some(0.5));
+    assert_eq!(impl_device_gate_time(&boxed), Some(0.5));
+    assert_eq!(impl_device_gate_time(boxed.as_ref()), Some(0.5));
+
+    let reference: &dyn Device = &generic;
+    assert_eq!(reference.number_qubits(), 2);
+    assert_eq!(
+        (&reference).single_qubit_gate_names(),
+        vec!["RotateX".to_string()]
+    );
+    assert_eq!(
+        (&reference).two_qubit_gate_error("CNOT", &0, &1),
+        Some(0.01)
+    );
+    assert_eq!(boxed.to_generic_device(), generic);
+    assert_eq!(boxed.two_qubit_edges(), generic.two_qubit_edges());
+
+    let devices: Vec<Box<dyn Device>> = vec![
+        Box::new(generic.clone()),
+        Box::new(AllToAllDevice::new(3, &["RotateX".to_string()], &[], 0.5)),
+        Box::new(SquareLatticeDevice::new(
+            2,
+            2,
+            &["RotateX".to_string()],
+            &[],
+            0.5,
+        )),
+    ];
+    let number_qubits: Vec<usize> = devices
+        .iter()
+        .map(|device| device.number_qubits())
+        .collect();
+    assert_eq!(number_qubits, vec![2, 3, 4]);
+    for device in devices.iter() {
+        assert_eq!(dyn_device_gate_time(device), Some(0.5));
+    }
+}
+
+/// Test that changing a boxed device is forwarded and a referenced device cannot be changed
+#[cfg(feature = "serialize")]
+#[test]
+fn boxed_device_change_device() {
+    let mut generic = GenericDevice::new(2);
+    generic
+        .set_single_qubit_gate_time("RotateX", 0, 0.5)
+        .unwrap();
+    let pragma = PragmaSetGateTime::new("RotateX".to_string(), vec![0], 0.7.into());
+
+    let mut boxed: Box<dyn Device> = Box::new(generic.clone());
+    let pragma_change = PragmaChangeDevice::new(&pragma).unwrap();
+    boxed
+        .change_device(
+            &pragma_change.wrapped_hqslang,
+            &pragma_change.wrapped_operation,
+        )
+        .unwrap();
+    assert_eq!(boxed.single_qubit_gate_time("RotateX", &0), Some(0.7));
+
+    let mut reference = &generic;
+    assert!(reference.change_device_typed(&pragma).is_err());
+    assert_eq!(generic.single_qubit_gate_time("RotateX", &0), Some(0.5));
+}
+
+/// Returns the gate names of a device in sorted order
+fn sorted_gate_names(device: &dyn Device) -> [Vec<String>; 3] {
+    let mut names = [
+        device.single_qubit_gate_names(),
+        device.two_qubit_gate_names(),
+        device.multi_qubit_gate_names(),
+    ];
+    for list in names.iter_mut() {
+        list.sort();
+    }
+    names
+}
+
+/// Test that the gate names of the devices match the names of their generic devices
+#[test]
+fn gate_names_match_generic_device() {
+    let single_qubit_gates = ["RotateX".to_string(), "RotateZ".to_string()];
+    let two_qubit_gates = ["CNOT".to_string(), "ISwap".to_string()];
+
+    let all_to_all = AllToAllDevice::new(4, &single_qubit_gates, &two_qubit_gates, 1.0)
+        .without_single_qubit_gate("RotateZ")
+        .with_two_qubit_gate_time("ControlledPauliZ", 0.5);
+    let square_lattice =
+        SquareLatticeDevice::new(10, 10, &single_qubit_gates, &two_qubit_gates, 1.0)
+            .without_two_qubit_gate("ISwap")
+            .with_single_qubit_gate_time("Hadamard", 0.5);
+    let mut generic = GenericDevice::new(3);
+    generic
+        .set_multi_qubit_gate_time("MultiQubitMS", vec![0, 1, 2], 1.0)
+        .unwrap();
+
+    let devices: Vec<Box<dyn Device>> = vec![
+        Box::new(all_to_all),
+        Box::new(square_lattice),
+        Box::new(generic),
+    ];
+    for device in devices.iter() {
+        assert_eq!(
+            sorted_gate_names(device.as_ref()),
+            sorted_gate_names(&device.to_generic_device())
+        );
+    }
+    assert_eq!(
+        sorted_gate_names(devices[1].as_ref()),
+        [
+            vec![
+                "Hadamard".to_string(),
+                "RotateX".to_string(),
+                "RotateZ".to_string()
+            ],
+            vec!["CNOT".to_string()],
+            Vec::new(),
+        ]
+    );
+}
+
+/// Test gate_time for operations of all gate arities, PRAGMAs and definitions
+#[test]
+fn gate_time_of_operations() {
+    let mut device = GenericDevice::new(4);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 0.5)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 1.0).unwrap();
+    device
+        .set_two_qubit_gate_time("NegativeControl", 1, 0, 1.25)
+        .unwrap();
+    device
+        .set_three_qubit_gate_time("Toffoli", 0, 1, 2, 1.5)
+        .unwrap();
+    device
+        .set_multi_qubit_gate_time("TripleControlledPauliX", vec![0, 1, 2, 3], 2.0)
+        .unwrap();
+    device
+        .set_multi_qubit_gate_time("MultiQubitMS", vec![0, 1, 2], 2.5)
+        .unwrap();
+
+    let operations: Vec<(Operation, Option<f64>)> = vec![
+        (RotateX::new(0, 1.0.into()).into(), Some(0.5)),
+        (RotateX::new(1, 1.0.into()).into(), None),
+        (RotateZ::new(0, 1.0.into()).into(), None),
+        (CNOT::new(0, 1).into(), Some(1.0)),
+        (CNOT::new(1, 0).into(), None),
+        (
+            NegativeControl::new(CNOT::new(1, 0).into()).into(),
+            Some(1.25),
+        ),
+        (NegativeControl::new(CNOT::new(0, 1).into()).into(), None),
+        (Toffoli::new(0, 1, 2).into(), Some(1.5)),
+        (TripleControlledPauliX::new(0, 1, 2, 3).into(), Some(2.0)),
+        (
+            MultiQubitMS::new(vec![0, 1, 2], 1.0.into()).into(),
+            Some(2.5),
+        ),
+        (MultiQubitMS::new(vec![0, 1], 1.0.into()).into(), None),
+        (PragmaSleep::new(vec![0], 3.0.into()).into(), Some(3.0)),
+        (
+            PragmaDamping::new(0, 4.0.into(), 0.01.into()).into(),
+            Some(4.0),
+        ),
+        (
+            PragmaDephasing::new(0, "t".into(), 0.01.into()).into(),
+            None,
+        ),
+        (
+            DefinitionBit::new("ro".to_string(), 2, true).into(),
+            Some(0.0),
+        ),
+        (MeasureQubit::new(0, "ro".to_string(), 0).into(), None),
+    ];
+    for (operation, time) in operations {
+        assert_eq!(gate_time(&device, &operation), time, "{:?}", operation);
+    }
+
+    let boxed: Box<dyn Device> = Box::new(device);
+    assert_eq!(
+        gate_time(&boxed, &Operation::from(CNOT::new(0, 1))),
+        Some(1.0)
+    );
+}
+
+#[cfg(feature = "serialize")]
+const ALL_TO_ALL_CONFIG: &str = r#"{
+  "number_qubits": 3,
+  "single_qubit_gates": {"RotateX": 2.0, "RotateZ": 1.0},
+  "two_qubit_gates": {"CNOT": 10.0},
+  "single_qubit_gate_times": [{"gate": "RotateX", "qubit": 2, "time": 3.0}],
+  "two_qubit_gate_times": [{"gate": "CNOT", "control": 0, "target": 1, "time": 12.0}],
+  "multi_qubit_gate_times": [{"gate": "MultiQubitMS", "qubits": [0, 1, 2], "time": 20.0}],
+  "decoherence_rates": {"damping": 0.5},
+  "qubit_decoherence_rates": [{"qubit": 1, "damping": 0.25, "dephasing": 0.125, "depolarising": 0.5}]
+}"#;
+
+/// Test loading an AllToAllDevice from a config with overrides and writing it back
+#[cfg(feature = "serialize")]
+#[test]
+fn all_to_all_config_json() {
+    let device = AllToAllDevice::from_config_json(ALL_TO_ALL_CONFIG).unwrap();
+    assert_eq!(device.number_qubits(), 3);
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(2.0));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &2), Some(3.0));
+    assert_eq!(device.single_qubit_gate_time("RotateZ", &2), Some(1.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &1), Some(12.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &1, &0), Some(10.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &2, &1), Some(10.0));
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitMS", &[0, 1, 2]),
+        Some(20.0)
+    );
+    assert_eq!(
+        device.qubit_decoherence_rates(&0),
+        Some(array![[0.5, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+    assert_eq!(
+        device.qubit_decoherence_rates(&1),
+        Some(array![[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 0.25]])
+    );
+
+    let mut manual = AllToAllDevice::new(3, &["RotateX".to_string()], &["CNOT".to_string()], 10.0)
+        .set_all_single_qubit_gate_times("RotateX", 2.0)
+        .set_all_single_qubit_gate_times("RotateZ", 1.0)
+        .add_damping_all(0.5);
+    manual
+        .set_single_qubit_gate_time("RotateX", 2, 3.0)
+        .unwrap();
+    manual.set_two_qubit_gate_time("CNOT", 0, 1, 12.0).unwrap();
+    manual
+        .set_multi_qubit_gate_time("MultiQubitMS", vec![0, 1, 2], 20.0)
+        .unwrap();
+    manual
+        .set_qubit_decoherence_rates(1, Array2::zeros((3, 3)))
+        .unwrap();
+    manual.add_damping(1, 0.25).unwrap();
+    manual.add_dephasing(1, 0.125).unwrap();
+    manual.add_depolarising(1, 0.5).unwrap();
+    assert_eq!(device, manual);
+
+    let config_json = device.to_config_json().unwrap();
+    let config: AllToAllDeviceConfig = serde_json::from_str(&config_json).unwrap();
+    let expected: AllToAllDeviceConfig = serde_json::from_str(ALL_TO_ALL_CONFIG).unwrap();
+    assert_eq!(config, expected);
+    assert_eq!(
+        AllToAllDevice::from_config_json(&config_json).unwrap(),
+        device
+    );
+}
+
+/// Test the config of a SquareLatticeDevice is restricted to the edges of the lattice
+#[cfg(feature = "serialize")]
+#[test]
+fn square_lattice_config_json() {
+    let config = r#"{
+        "number_rows": 2,
+        "number_columns": 2,
+        "single_qubit_gates": {"RotateZ": 1.0},
+        "two_qubit_gates": {"CNOT": 10.0},
+        "two_qubit_gate_times": [{"gate": "CNOT", "control": 2, "target": 3, "time": 8.0}]
+    }"#;
+    let device = SquareLatticeDevice::from_config_json(config).unwrap();
+    assert_eq!(device.number_rows(), 2);
+    assert_eq!(device.number_columns(), 2);
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &1), Some(10.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &2, &0), Some(10.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &2, &3), Some(8.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &3), None);
+    assert_eq!(
+        device.qubit_decoherence_rates(&3),
+        Some(Array2::zeros((3, 3)))
+    );
+    assert_eq!(
+        SquareLatticeDevice::from_config_json(&device.to_config_json().unwrap()).unwrap(),
+        device
+    );
+
+    let invalid_edge = r#"{
+        "number_rows": 2,
+        "number_columns": 2,
+        "two_qubit_gate_times": [{"gate": "CNOT", "control": 0, "target": 3, "time": 8.0}]
+    }"#;
+    assert!(SquareLatticeDevice::from_config_json(invalid_edge).is_err());
+}
+
+/// Test gates that are only defined on some qubits are written as overrides
+#[cfg(feature = "serialize")]
+#[test]
+fn generic_device_config_json() {
+    let mut device = GenericDevice::new(3);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 1.0)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 1, 2, 5.0).unwrap();
+    device.add_dephasing(2, 0.5).unwrap();
+
+    let config_json = device.to_config_json().unwrap();
+    let config: serde_json::Value = serde_json::from_str(&config_json).unwrap();
+    assert_eq!(
+        config,
+        serde_json::json!({
+            "number_qubits": 3,
+            "single_qubit_gate_times": [{"gate": "RotateX", "qubit": 0, "time": 1.0}],
+            "two_qubit_gate_times": [{"gate": "CNOT", "control": 1, "target": 2, "time": 5.0}],
+            "qubit_decoherence_rates": [
+                {"qubit": 2, "damping": 0.0, "dephasing": 0.5, "depolarising": 0.0}
+            ]
+        })
+    );
+    assert_eq!(
+        GenericDevice::from_config_json(&config_json).unwrap(),
+        device
+    );
+}
+
+/// Test errors of invalid configs and of devices without a config representation
+#[cfg(feature = "serialize")]
+#[test]
+fn config_json_errors() {
+    assert!(AllToAllDevice::from_config_json("{\"number_rows\": 2}").is_err());
+    let out_of_range = r#"{
+        "number_qubits": 2,
+        "single_qubit_gate_times": [{"gate": "RotateX", "qubit": 2, "time": 3.0}]
+    }"#;
+    assert_eq!(
+        GenericDevice::from_config_json(out_of_range),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 2 in device config out of range for device of size 2".to_string()
+        })
+    );
+
+    let mut device = AllToAllDevice::new(2, &[], &[], 1.0);
+    device
+        .set_qubit_decoherence_rates(0, array![[0.0, 0.1, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+        .unwrap();
+    assert!(device.to_config_json().is_err());
+}
+
+/// Test the JsonSchema of the device configs validates a config
+#[cfg(feature = "json_schema")]
+#[test]
+fn config_json_schema() {
+    let schema = serde_json::to_value(schema_for!(AllToAllDeviceConfig)).unwrap();
+    let validator = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema)
+        .unwrap();
+    let config: serde_json::Value = serde_json::from_str(ALL_TO_ALL_CONFIG).unwrap();
+    assert!(validator.is_valid(&config));
+    let invalid: serde_json::Value =
+        serde_json::json!({"number_qubits": 2, "single_qubit_gates": {"RotateX": "fast"}});
+    assert!(!validator.is_valid(&invalid));
+}
+
+/// Compares gate time matrices and decoherence rates of a device to the individual queries
+fn check_gate_time_matrices(
+    device: &impl Device,
+    hqslang: (&str, &str),
+    single_qubit_matrix: Array1<f64>,
+    two_qubit_matrix: ndarray::Array2<f64>,
+    rates: Array3<f64>,
+) {
+    let number_qubits = device.number_qubits();
+    assert_eq!(single_qubit_matrix.len(), number_qubits);
+    assert_eq!(two_qubit_matrix.shape(), [number_qubits, number_qubits]);
+    assert_eq!(rates.shape(), [number_qubits, 3, 3]);
+    for qubit in 0..number_qubits {
+        match device.single_qubit_gate_time(hqslang.0, &qubit) {
+            Some(time) => assert_eq!(single_qubit_matrix[qubit], time),
+            None => assert!(single_qubit_matrix[qubit].is_nan()),
+        }
+        for target in 0..number_qubits {
+            match device.two_qubit_gate_time(hqslang.1, &qubit, &target) {
+                Some(time) => assert_eq!(two_qubit_matrix[(qubit, target)], time),
+                None => assert!(two_qubit_matrix[(qubit, target)].is_nan()),
+            }
+        }
+        let qubit_rates = device
+            .qubit_decoherence_rates(&qubit)
+            .unwrap_or_else(|| ndarray::Array2::zeros((3, 3)));
+        assert_eq!(rates.slice(ndarray::s![qubit, .., ..]), qubit_rates);
+    }
+}
+
+/// Test gate time matrices and decoherence rates of all qubits against the individual queries
+#[test]
+fn gate_time_matrices() {
+    let mut device = GenericDevice::new(3);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 1.0)
+        .unwrap();
+    device
+        .set_single_qubit_gate_time("RotateX", 2, 2.0)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 3.0).unwrap();
+    device.set_two_qubit_gate_time("CNOT", 2, 1, 4.0).unwrap();
+    device.add_damping(1, 0.1).unwrap();
+    check_gate_time_matrices(
+        &device,
+        ("RotateX", "CNOT"),
+        device.single_qubit_gate_time_matrix("RotateX"),
+        device.two_qubit_gate_time_matrix("CNOT"),
+        device.qubit_decoherence_rates_all(),
+    );
+    assert_eq!(device.single_qubit_gate_time_matrix("RotateX")[2], 2.0);
+    assert!(device.single_qubit_gate_time_matrix("RotateX")[1].is_nan());
+    assert_eq!(device.two_qubit_gate_time_matrix("CNOT")[(2, 1)], 4.0);
+    assert!(device.two_qubit_gate_time_matrix("CNOT")[(1, 2)].is_nan());
+    assert_eq!(device.qubit_decoherence_rates_all()[(1, 0, 0)], 0.1);
+    assert!(device
+        .single_qubit_gate_time_matrix("RotateZ")
+        .iter()
+        .all(|time| time.is_nan()));
+
+    let mut device = AllToAllDevice::new(3, &["RotateZ".to_string()], &["CNOT".to_string()], 1.0)
+        .add_dephasing_all(0.2);
+    device
+        .set_single_qubit_gate_time("RotateZ", 1, 0.5)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 1, 2, 2.5).unwrap();
+    let generic = device.to_generic_device();
+    check_gate_time_matrices(
+        &device,
+        ("RotateZ", "CNOT"),
+        generic.single_qubit_gate_time_matrix("RotateZ"),
+        generic.two_qubit_gate_time_matrix("CNOT"),
+        generic.qubit_decoherence_rates_all(),
+    );
+    assert_eq!(generic.two_qubit_gate_time_matrix("CNOT")[(1, 2)], 2.5);
+
+    let device =
+        SquareLatticeDevice::new(2, 3, &["RotateX".to_string()], &["CNOT".to_string()], 0.1)
+            .add_damping_all(0.3);
+    let generic = device.to_generic_device();
+    check_gate_time_matrices(
+        &device,
+        ("RotateX", "CNOT"),
+        generic.single_qubit_gate_time_matrix("RotateX"),
+        generic.two_qubit_gate_time_matrix("CNOT"),
+        generic.qubit_decoherence_rates_all(),
+    );
+    assert!(generic.two_qubit_gate_time_matrix("CNOT")[(0, 4)].is_nan());
+}
+
+fn active_noise_device() -> AllToAllDevice {
+    let mut device = AllToAllDevice::new(4, &["RotateX".to_string()], &["CNOT".to_string()], 2.0)
+        .add_damping_all(0.5);
+    device.add_dephasing(1, 0.25).unwrap();
+    device.add_depolarising(3, 0.25).unwrap();
+    device
+        .set_three_qubit_gate_time("Toffoli", 0, 1, 2, 3.0)
+        .unwrap();
+    device
+        .set_multi_qubit_gate_time("MultiQubitMS", vec![0, 1, 2, 3], 4.0)
+        .unwrap();
+    device
+}
+
+/// Test add_active_gate_noise inserting the decoherence noise of single- and two-qubit gates
+#[test]
+fn test_add_active_gate_noise() {
+    let device = active_noise_device();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += RotateX::new(3, 1.0.into());
+    circuit += CNOT::new(1, 0);
+    circuit += MeasureQubit::new(2, "ro".to_string(), 0);
+
+    let noisy = add_active_gate_noise(&device, &circuit).unwrap();
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 1, true);
+    expected += RotateX::new(3, 1.0.into());
+    expected += PragmaDamping::new(3, 2.0.into(), 0.5.into());
+    expected += PragmaDepolarising::new(3, 2.0.into(), 0.25.into());
+    expected += CNOT::new(1, 0);
+    expected += PragmaDamping::new(0, 2.0.into(), 0.5.into());
+    expected += PragmaDamping::new(1, 2.0.into(), 0.5.into());
+    expected += PragmaDephasing::new(1, 2.0.into(), 0.25.into());
+    expected += MeasureQubit::new(2, "ro".to_string(), 0);
+    assert_eq!(noisy, expected);
+
+    // Qubits without decoherence rates get no noise
+    let device = AllToAllDevice::new(2, &["RotateX".to_string()], &[], 2.0);
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 1.0.into());
+    assert_eq!(add_active_gate_noise(&device, &circuit).unwrap(), circuit);
+}
+
+/// Test add_active_gate_noise for three- and multi-qubit gates and rates that are not diagonal
+#[test]
+fn test_add_active_gate_noise_multi_qubit() {
+    let mut device = active_noise_device();
+    let mut circuit = Circuit::new();
+    circuit += Toffoli::new(0, 1, 2);
+    let noisy = add_active_gate_noise(&device, &circuit).unwrap();
+    let mut expected = circuit.clone();
+    expected += PragmaDamping::new(0, 3.0.into(), 0.5.into());
+    expected += PragmaDamping::new(1, 3.0.into(), 0.5.into());
+    expected += PragmaDephasing::new(1, 3.0.into(), 0.25.into());
+    expected += PragmaDamping::new(2, 3.0.into(), 0.5.into());
+    assert_eq!(noisy, expected);
+
+    let mut circuit = Circuit::new();
+    circuit += MultiQubitMS::new(vec![0, 1, 2, 3], 1.0.into());
+    let noisy = add_active_gate_noise(&device, &circuit).unwrap();
+    assert_eq!(noisy.len(), 7);
+    assert_eq!(
+        noisy.get(1),
+        Some(&Operation::from(PragmaDamping::new(
+            0,
+            4.0.into(),
+            0.5.into()
+        )))
+    );
+
+    let rates = array![[0.1, 0.2, 0.0], [0.2, 0.1, 0.0], [0.0, 0.0, 0.0]];
+    device
+        .set_qubit_decoherence_rates(0, rates.clone())
+        .unwrap();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 1.0.into());
+    let noisy = add_active_gate_noise(&device, &circuit).unwrap();
+    let mut expected = circuit.clone();
+    expected += PragmaGeneralNoise::new(0, 2.0.into(), rates);
+    assert_eq!(noisy, expected);
+}
+
+/// Test add_active_gate_noise failing for gates on the same qubit and unavailable gates
+#[test]
+fn test_add_active_gate_noise_errors() {
+    let device = active_noise_device();
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 1);
+    circuit += RotateX::new(1, 1.0.into());
+    assert_eq!(
+        add_active_gate_noise(&device, &circuit),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Error add_active_gate_noise: Several unitary gates operate on same qubit in a parallel set of operations".to_string()
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += Toffoli::new(1, 2, 3);
+    assert_eq!(
+        add_active_gate_noise(&device, &circuit),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Error add_active_gate_noise: Gate Toffoli on qubits [1, 2, 3] is not available on the device".to_string()
+        })
+    );
+}
+
+/// Reference implementation of GenericDevice::two_qubit_edges checking every pair of qubits
+fn reference_two_qubit_edges(device: &GenericDevice) -> Vec<(usize, usize)> {
+    let mut vector: Vec<(usize, usize)> = Vec::new();
+    for row in 0..device.number_qubits {
+        for column in row + 1..device.number_qubits {
+            if device
+                .two_qubit_gates
+                .iter()
+                .filter(|(key, _)| key.as_str() != "SWAP")
+                .any(|(_, val)| {
+                    val.contains_key(&(row, column)) || val.contains_key(&(column, row))
+                })
+            {
+                vector.push((row, column))
+            }
+        }
+    }
+    vector
+}
+
+/// Test the edges and gate times of random GenericDevices against the reference implementations
+#[test]
+fn generic_device_queries_match_reference() {
+    use rand::prelude::*;
+    use rand::rngs::StdRng;
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for number_qubits in [0, 1, 2, 5, 12] {
+        for _ in 0..20 {
+            let mut device = GenericDevice::new(number_qubits);
+            for gate in ["CNOT", "ControlledPauliZ", "SWAP"] {
+                for _ in 0..rng.gen_range(0..2 * number_qubits + 1) {
+                    let control = rng.gen_range(0..number_qubits);
+                    let target = rng.gen_range(0..number_qubits);
+                    device
+                        .set_two_qubit_gate_time(gate, control, target, rng.gen())
+                        .unwrap();
+                }
+                // Entries outside of the device can only be added through the public fields
+                device
+                    .two_qubit_gates
+                    .entry(gate.to_string())
+                    .or_default()
+                    .insert((number_qubits, 0), 1.0);
+            }
+            for _ in 0..rng.gen_range(0..number_qubits + 1) {
+                let qubits: Vec<usize> = (0..3).map(|_| rng.gen_range(0..number_qubits)).collect();
+                device
+                    .set_multi_qubit_gate_time("Toffoli", qubits, rng.gen())
+                    .unwrap();
+            }
+
+            assert_eq!(device.two_qubit_edges(), reference_two_qubit_edges(&device));
+            for control in 0..number_qubits + 1 {
+                for target in 0..number_qubits + 1 {
+                    for gate in ["CNOT", "SWAP", "ISwap"] {
+                        assert_eq!(
+                            device.two_qubit_gate_time(gate, &control, &target),
+                            device
+                                .two_qubit_gates
+                                .get(gate)
+                                .and_then(|times| times.get(&(control, target)).copied())
+                        );
+                    }
+                    for qubit in 0..number_qubits + 1 {
+                        let qubits = vec![control, target, qubit];
+                        let expected = device
+                            .multi_qubit_gates
+                            .get("Toffoli")
+                            .and_then(|times| times.get(&qubits).copied());
+                        assert_eq!(
+                            device.three_qubit_gate_time("Toffoli", &control, &target, &qubit),
+                            expected
+                        );
+                        assert_eq!(device.multi_qubit_gate_time("Toffoli", &qubits), expected);
+                    }
+                }
+            }
+        }
+    }
+}
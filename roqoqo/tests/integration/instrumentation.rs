@@ -0,0 +1,140 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::operations;
+use roqoqo::prelude::*;
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
+use roqoqo::{Circuit, QuantumProgram};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+/// Backend returning a fixed bit register for every circuit
+#[derive(Debug, Clone, Copy)]
+struct MockBackend;
+
+impl EvaluatingBackend for MockBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        _circuit: impl Iterator<Item = &'a operations::Operation>,
+    ) -> roqoqo::backends::RegisterResult {
+        let mut result_bit: HashMap<String, BitOutputRegister> = HashMap::new();
+        result_bit.insert("ro".to_string(), vec![vec![false], vec![true]]);
+        let result_float: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let result_complex: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        Ok((result_bit, result_float, result_complex))
+    }
+}
+
+/// A span recorded by the RecordingLayer with its name and fields
+#[derive(Debug, Clone, Default)]
+struct RecordedSpan {
+    name: String,
+    fields: HashMap<String, String>,
+}
+
+impl Visit for RecordedSpan {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.fields
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+/// Layer recording all spans in the order they are created
+#[derive(Debug, Clone, Default)]
+struct RecordingLayer {
+    spans: Arc<Mutex<Vec<(Id, RecordedSpan)>>>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for RecordingLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut span = RecordedSpan {
+            name: attrs.metadata().name().to_string(),
+            fields: HashMap::new(),
+        };
+        attrs.record(&mut span);
+        self.spans.lock().unwrap().push((id.clone(), span));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some((_, span)) = spans.iter_mut().rev().find(|(span_id, _)| span_id == id) {
+            values.record(span);
+        }
+    }
+}
+
+/// Test that running a QuantumProgram emits spans for substitution, backend runs and evaluation
+#[test]
+fn quantum_program_run_spans() {
+    let mut input = PauliZProductInput::new(1, false);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_linear_exp_val("z".to_string(), HashMap::from([(0, 1.0)]))
+        .unwrap();
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, "theta".into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    let measurement = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![circuit.clone(), circuit],
+        input,
+    };
+    let program = QuantumProgram::PauliZProduct {
+        measurement,
+        input_parameter_names: vec!["theta".to_string()],
+    };
+
+    let layer = RecordingLayer::default();
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    let result = tracing::subscriber::with_default(subscriber, || {
+        program.run(MockBackend, &[0.5]).unwrap().unwrap()
+    });
+    assert_eq!(result["z"], 0.0);
+
+    let spans: Vec<RecordedSpan> = layer
+        .spans
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, span)| span.clone())
+        .collect();
+    let names: Vec<&str> = spans.iter().map(|span| span.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "QuantumProgram::run",
+            "substitute_measurement",
+            "Circuit::substitute_parameters",
+            "Circuit::substitute_parameters",
+            "run_measurement",
+            "run_measurement_registers",
+            "run_circuits_registers",
+            "run_circuit_iterator",
+            "run_circuit_iterator",
+            "PauliZProduct::evaluate",
+        ]
+    );
+    assert_eq!(spans[0].fields["number_parameters"], "1");
+    assert_eq!(spans[2].fields["number_operations"], "3");
+    assert_eq!(spans[5].fields["number_circuits"], "2");
+    for (index, span) in spans[7..9].iter().enumerate() {
+        assert_eq!(span.fields["circuit_index"], index.to_string());
+        assert_eq!(span.fields["number_operations"], "3");
+        assert!(span.fields.contains_key("elapsed_seconds"));
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the LaTeX export of circuits
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations::*;
+use roqoqo::{Circuit, LatexOptions};
+use std::f64::consts::PI;
+
+const BELL_PAIR: &str = r"\begin{quantikz}
+\lstick{$q_{0}$} & \gate{H} & \ctrl{1} & \meter{} & \qw \\
+\lstick{$q_{1}$} & \qw & \targ{} & \meter{} & \qw
+\end{quantikz}";
+
+/// Test export of a Bell pair circuit against the reference diagram
+#[test]
+fn to_latex_bell_pair() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    assert_eq!(circuit.to_latex().unwrap(), BELL_PAIR);
+}
+
+/// Test rotation angles, controlled gates pointing upwards and labelled boxes
+#[test]
+fn to_latex_gates() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, (PI / 2.0).into());
+    circuit += RotateZ::new(2, CalculatorFloat::from("theta_1"));
+    circuit += ControlledPauliZ::new(2, 0);
+    circuit += SWAP::new(0, 1);
+    circuit += MultiQubitMS::new(vec![0, 2], 0.5.into());
+    circuit += Toffoli::new(0, 2, 1);
+    let expected = r"\begin{quantikz}
+\lstick{$q_{0}$} & \gate{R_x(1.5708)} & \control{} & \swap{1} & \gate[wires=3]{\mathrm{MultiQubitMS}} & \ctrl{1} & \qw \\
+\lstick{$q_{1}$} & \qw & \qw & \targX{} & \qw & \targ{} & \qw \\
+\lstick{$q_{2}$} & \gate{R_z(theta\_1)} & \ctrl{-2} & \qw & \qw & \ctrl{-1} & \qw
+\end{quantikz}";
+    assert_eq!(circuit.to_latex().unwrap(), expected);
+}
+
+/// Test that PRAGMA operations can be hidden
+#[test]
+fn to_latex_hide_pragmas() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += PragmaDamping::new(1, 1.0.into(), 0.1.into());
+    circuit += PragmaGlobalPhase::new(0.5.into());
+    circuit += PauliX::new(1);
+
+    let expected = r"\begin{quantikz}
+\lstick{$q_{0}$} & \gate{H} & \qw & \qw \\
+\lstick{$q_{1}$} & \gate{\mathrm{PragmaDamping}} & \gate{X} & \qw
+\end{quantikz}";
+    assert_eq!(circuit.to_latex().unwrap(), expected);
+
+    let expected = r"\begin{quantikz}
+\lstick{$q_{0}$} & \gate{H} & \qw \\
+\lstick{$q_{1}$} & \gate{X} & \qw
+\end{quantikz}";
+    assert_eq!(
+        circuit
+            .to_latex_with_options(&LatexOptions::new().hide_pragmas(true))
+            .unwrap(),
+        expected
+    );
+}
+
+/// Test export of a circuit without qubits
+#[test]
+fn to_latex_empty() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    assert_eq!(
+        circuit.to_latex().unwrap(),
+        "\\begin{quantikz}\n\n\\end{quantikz}"
+    );
+}
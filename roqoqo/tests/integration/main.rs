@@ -16,15 +16,53 @@ mod operations;
 #[cfg(test)]
 mod measurements;
 
+#[cfg(test)]
+#[cfg(feature = "unstable_analog_operations")]
+mod analog;
+
 #[cfg(test)]
 mod circuit;
 
+#[cfg(test)]
+mod builder;
+
 #[cfg(test)]
 mod devices;
 
 #[cfg(test)]
 mod quantum_program;
 
+#[cfg(test)]
+mod backends;
+
 #[cfg(test)]
 #[cfg(feature = "circuitdag")]
 mod circuitdag;
+
+#[cfg(test)]
+#[cfg(all(feature = "circuitdag", feature = "serialize"))]
+mod cirq;
+
+#[cfg(test)]
+mod latex;
+
+#[cfg(test)]
+#[cfg(feature = "circuitdag")]
+mod schedule;
+
+#[cfg(test)]
+mod passes;
+
+#[cfg(test)]
+mod testing;
+
+#[cfg(test)]
+mod registers;
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(test)]
+#[cfg(feature = "tracing")]
+mod instrumentation;
@@ -0,0 +1,188 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the asynchronous evaluation of measurements
+
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::stream::{self, StreamExt};
+use roqoqo::measurements::{Measure, MeasureExpectationValues, PauliZProduct, PauliZProductInput};
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister, Registers};
+use roqoqo::{Circuit, RoqoqoBackendError, RoqoqoError};
+use std::collections::HashMap;
+use std::pin::Pin;
+
+// Measurement of <Z0> that is incomplete until a minimum number of records has been measured
+#[derive(Debug, Clone, PartialEq)]
+struct MinimumRecords {
+    minimum_records: usize,
+    constant_circuit: Option<Circuit>,
+    circuits: Vec<Circuit>,
+}
+
+impl Measure for MinimumRecords {
+    fn circuits<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Circuit> + 'a> {
+        Box::new(self.circuits.iter())
+    }
+
+    fn constant_circuit(&self) -> &Option<Circuit> {
+        &self.constant_circuit
+    }
+
+    fn substitute_parameters(
+        &self,
+        _substituted_parameters: HashMap<String, f64>,
+    ) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+impl MeasureExpectationValues for MinimumRecords {
+    fn evaluate(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        _float_registers: HashMap<String, FloatOutputRegister>,
+        _complex_registers: HashMap<String, ComplexOutputRegister>,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        let register = bit_registers
+            .get("ro")
+            .ok_or(RoqoqoError::MissingRegister {
+                name: "ro".to_string(),
+            })?;
+        if register.len() < self.minimum_records {
+            return Ok(None);
+        }
+        let expectation_value = register
+            .iter()
+            .map(|record| if record[0] { -1.0 } else { 1.0 })
+            .sum::<f64>()
+            / register.len() as f64;
+        Ok(Some(HashMap::from([("z0".to_string(), expectation_value)])))
+    }
+}
+
+fn minimum_records(minimum_records: usize) -> MinimumRecords {
+    MinimumRecords {
+        minimum_records,
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+    }
+}
+
+// Boxes the registers in a future as returned by a backend
+fn future(
+    registers: Result<Registers, RoqoqoBackendError>,
+) -> Pin<Box<dyn FutureExt<Output = Result<Registers, RoqoqoBackendError>> + Send>> {
+    Box::pin(async move { registers })
+}
+
+fn registers(records: BitOutputRegister) -> Registers {
+    (
+        HashMap::from([("ro".to_string(), records)]),
+        HashMap::new(),
+        HashMap::new(),
+    )
+}
+
+/// Test async_evaluate of a complete PauliZProduct measurement
+#[test]
+fn test_async_evaluate_pauli_z_product() {
+    let mut input = PauliZProductInput::new(1, false);
+    let index = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_linear_exp_val("z0".to_string(), HashMap::from([(index, 2.0)]))
+        .unwrap();
+    let measurement = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input,
+    };
+    let records = vec![vec![false], vec![true], vec![false], vec![false]];
+    let result = block_on(measurement.async_evaluate(future(Ok(registers(records))))).unwrap();
+    assert_eq!(result.get("z0"), Some(&1.0));
+}
+
+/// Test that incomplete measurements are signalled instead of panicking
+#[test]
+fn test_async_evaluate_incomplete() {
+    let measurement = minimum_records(3);
+    let records = vec![vec![false], vec![true]];
+    let result =
+        block_on(measurement.async_evaluate_checked(future(Ok(registers(records.clone())))));
+    assert_eq!(result, Ok(None));
+
+    let result = block_on(measurement.async_evaluate(future(Ok(registers(records)))));
+    assert_eq!(
+        result,
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Measurement is incomplete, a new round of measurements is needed".to_string()
+        })
+    );
+
+    let records = vec![vec![false], vec![true], vec![false]];
+    let result = block_on(measurement.async_evaluate_checked(future(Ok(registers(records)))));
+    assert_eq!(
+        result,
+        Ok(Some(HashMap::from([("z0".to_string(), 1.0 / 3.0)])))
+    );
+}
+
+/// Test that errors of the backend are propagated by async_evaluate_checked
+#[test]
+fn test_async_evaluate_backend_error() {
+    let measurement = minimum_records(1);
+    let error = || RoqoqoBackendError::Timeout {
+        msg: "test".to_string(),
+    };
+    let result = block_on(measurement.async_evaluate_checked(future(Err(error()))));
+    assert_eq!(result, Err(error()));
+}
+
+/// Test that async_evaluate_stream refines the estimate with each partial result
+#[test]
+fn test_async_evaluate_stream() {
+    let measurement = minimum_records(2);
+    let partial_results: Vec<Result<Registers, RoqoqoBackendError>> = vec![
+        Ok(registers(vec![vec![true]])),
+        Ok(registers(vec![vec![false]])),
+        Err(RoqoqoBackendError::NetworkError {
+            msg: "test".to_string(),
+        }),
+        Ok(registers(vec![vec![false], vec![false]])),
+        Ok((HashMap::new(), HashMap::new(), HashMap::new())),
+    ];
+    let estimates: Vec<Result<Option<HashMap<String, f64>>, RoqoqoBackendError>> = block_on(
+        measurement
+            .async_evaluate_stream(stream::iter(partial_results))
+            .collect(),
+    );
+    assert_eq!(estimates.len(), 5);
+    assert_eq!(estimates[0], Ok(None));
+    assert_eq!(
+        estimates[1],
+        Ok(Some(HashMap::from([("z0".to_string(), 0.0)])))
+    );
+    assert_eq!(
+        estimates[2],
+        Err(RoqoqoBackendError::NetworkError {
+            msg: "test".to_string()
+        })
+    );
+    assert_eq!(
+        estimates[3],
+        Ok(Some(HashMap::from([("z0".to_string(), 0.5)])))
+    );
+    assert_eq!(
+        estimates[4],
+        Ok(Some(HashMap::from([("z0".to_string(), 0.5)])))
+    );
+}
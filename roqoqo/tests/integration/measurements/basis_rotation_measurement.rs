@@ -15,9 +15,11 @@
 #[cfg(feature = "jsonschema")]
 use jsonschema::{Draft, Validator};
 use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::MockSamplingBackend;
 use roqoqo::operations;
 use roqoqo::prelude::*;
 use roqoqo::Circuit;
+use roqoqo::RoqoqoError;
 use roqoqo::{
     measurements::{PauliZProduct, PauliZProductInput},
     registers::BitOutputRegister,
@@ -315,17 +317,19 @@ fn test_evaluate_linear_flipped(
     assert_eq!(result.get("two_pp_exp_val").unwrap(), &two_pp_exp_val);
 }
 
-#[test_case(vec![
-    vec![false, false, false],
-    vec![false, false, false],
-    vec![false, false, false],
-], 3.0_f64.sin()-1.0_f64.sin() ; "All measurements zero")]
-#[test_case(vec![
-    vec![true, true, true],
-    vec![true, true, true],
-    vec![true, true, true],
-], 3.0_f64.sin() + 1.0_f64.sin() ; "All measurements one")]
-fn test_evaluate_symbolic(register: Vec<Vec<bool>>, constant: f64) {
+/// Creates a backend measuring the basis state of each circuit, given in the order the circuits are run
+fn basis_state_backend(states: &[Vec<bool>]) -> MockSamplingBackend {
+    let distributions = states
+        .iter()
+        .enumerate()
+        .map(|(index, state)| (index.to_string(), vec![(state.clone(), 1.0)]))
+        .collect();
+    MockSamplingBackend::new(distributions, 0).unwrap()
+}
+
+#[test_case(vec![false, false, false], 3.0_f64.sin()-1.0_f64.sin() ; "All measurements zero")]
+#[test_case(vec![true, true, true], 3.0_f64.sin() + 1.0_f64.sin() ; "All measurements one")]
+fn test_evaluate_symbolic(state: Vec<bool>, constant: f64) {
     let mut bri = PauliZProductInput::new(3, false);
     let _a = bri.add_pauliz_product("ro".to_string(), vec![]);
     let _b = bri.add_pauliz_product("ro".to_string(), vec![0]);
@@ -336,30 +340,22 @@ fn test_evaluate_symbolic(register: Vec<Vec<bool>>, constant: f64) {
     bri.add_symbolic_exp_val("constant".to_string(), symbolic)
         .unwrap();
 
-    let circs: Vec<Circuit> = vec![Circuit::new()];
+    let mut circs: Vec<Circuit> = Vec::new();
+    for name in ["ro", "rx"] {
+        let mut circ = Circuit::new();
+        circ += operations::DefinitionBit::new(name.to_string(), 3, true);
+        circ += operations::PragmaRepeatedMeasurement::new(name.to_string(), 3, None);
+        circs.push(circ);
+    }
     let br = PauliZProduct {
         constant_circuit: None,
         circuits: circs,
         input: bri,
     };
 
-    let mut measured_registers: HashMap<String, BitOutputRegister> = HashMap::new();
-    let new_output_register: BitOutputRegister = register; // vec![
-                                                           //     vec![false, false, false],
-                                                           //     vec![false, false, false],
-                                                           //     vec![false, false, false],
-                                                           // ];
-    let _ = measured_registers.insert("ro".to_string(), new_output_register);
-    let _ = measured_registers.insert(
-        "rx".to_string(),
-        vec![
-            vec![false, false, false],
-            vec![false, false, false],
-            vec![false, false, false],
-        ],
-    );
-    let result = br
-        .evaluate(measured_registers, HashMap::new(), HashMap::new())
+    // The ro circuit measures the given state, the rx circuit the zero state
+    let result = basis_state_backend(&[state, vec![false, false, false]])
+        .run_measurement(&br)
         .unwrap()
         .unwrap();
     assert!((result.get("constant").unwrap() - constant).abs() < f64::EPSILON);
@@ -402,3 +398,617 @@ fn test_json_schema() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+#[test]
+fn test_evaluate_bulk_registration() {
+    let products = vec![vec![], vec![0], vec![1, 2], vec![0, 1, 2]];
+    let coefficients: Vec<(String, HashMap<usize, f64>)> = vec![
+        ("constant".to_string(), HashMap::from([(0, 3.0)])),
+        ("single".to_string(), HashMap::from([(1, 4.0), (2, -1.5)])),
+        ("all".to_string(), HashMap::from([(1, 0.5), (3, 2.0)])),
+    ];
+
+    let mut single = PauliZProductInput::new(3, false);
+    for mask in products.clone() {
+        single.add_pauliz_product("ro".to_string(), mask).unwrap();
+    }
+    for (name, linear) in coefficients.clone() {
+        single.add_linear_exp_val(name, linear).unwrap();
+    }
+    let mut bulk = PauliZProductInput::new(3, false);
+    bulk.add_pauliz_products("ro", products).unwrap();
+    bulk.add_linear_exp_vals(coefficients).unwrap();
+
+    let registers: HashMap<String, BitOutputRegister> = HashMap::from([(
+        "ro".to_string(),
+        vec![
+            vec![true, false, true],
+            vec![false, true, true],
+            vec![true, true, false],
+        ],
+    )]);
+    let evaluate = |input: PauliZProductInput| {
+        PauliZProduct {
+            constant_circuit: None,
+            circuits: vec![Circuit::new()],
+            input,
+        }
+        .evaluate(registers.clone(), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap()
+    };
+    assert_eq!(evaluate(bulk), evaluate(single));
+}
+
+/// Creates a measurement reading out the Pauli products Z0, Z1Z2 in `ro` and Z1Z2 in `rx`
+fn weighted_measurement(use_flipped_measurement: bool) -> PauliZProduct {
+    let mut input = PauliZProductInput::new(3, use_flipped_measurement);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_pauliz_product("ro".to_string(), vec![1, 2])
+        .unwrap();
+    input
+        .add_pauliz_product("rx".to_string(), vec![1, 2])
+        .unwrap();
+    for (index, name) in ["pp0", "pp1", "pp2"].iter().enumerate() {
+        input
+            .add_linear_exp_val(name.to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+    }
+    input
+        .add_linear_exp_val(
+            "sum".to_string(),
+            HashMap::from([(0, 2.0), (1, -1.0), (2, 0.5)]),
+        )
+        .unwrap();
+    PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input,
+    }
+}
+
+#[test]
+fn test_evaluate_weighted() {
+    let measurement = weighted_measurement(false);
+    let registers: HashMap<String, BitOutputRegister> = HashMap::from([
+        (
+            "ro".to_string(),
+            vec![
+                vec![true, false, true],
+                vec![false, false, true],
+                vec![true, true, true],
+                vec![false, true, true],
+            ],
+        ),
+        (
+            "rx".to_string(),
+            vec![vec![false, true, false], vec![false, false, false]],
+        ),
+    ]);
+    let plain = measurement
+        .evaluate(registers.clone(), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    // Empty weights reproduce the unweighted evaluation
+    let unweighted = measurement
+        .evaluate_weighted(
+            registers.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(plain, unweighted);
+
+    let weights = HashMap::from([("ro".to_string(), 0.25), ("rx".to_string(), 3.0)]);
+    let weighted = measurement
+        .evaluate_weighted(
+            registers.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            weights,
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    // Manual reweighting of the unweighted Pauli product expectation values
+    let pp0 = plain["pp0"] * 0.25;
+    let pp1 = plain["pp1"] * 0.25;
+    let pp2 = plain["pp2"] * 3.0;
+    assert!((weighted["pp0"] - pp0).abs() < 1e-12);
+    assert!((weighted["pp1"] - pp1).abs() < 1e-12);
+    assert!((weighted["pp2"] - pp2).abs() < 1e-12);
+    assert!((weighted["sum"] - (2.0 * pp0 - pp1 + 0.5 * pp2)).abs() < 1e-12);
+
+    // Per Pauli product weights are applied in addition to register weights
+    let weighted = measurement
+        .evaluate_weighted(
+            registers.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::from([("rx".to_string(), 3.0)]),
+            HashMap::from([(2, 0.5)]),
+        )
+        .unwrap()
+        .unwrap();
+    assert!((weighted["pp2"] - plain["pp2"] * 1.5).abs() < 1e-12);
+    assert!((weighted["pp0"] - plain["pp0"]).abs() < 1e-12);
+
+    let error = measurement.evaluate_weighted(
+        registers.clone(),
+        HashMap::new(),
+        HashMap::new(),
+        HashMap::from([("ro_flipped".to_string(), 1.0)]),
+        HashMap::new(),
+    );
+    assert_eq!(
+        error,
+        Err(RoqoqoError::PauliZProductMeasurementError {
+            msg: "Weight given for unknown register ro_flipped".to_string()
+        })
+    );
+    let error = measurement.evaluate_weighted(
+        registers,
+        HashMap::new(),
+        HashMap::new(),
+        HashMap::new(),
+        HashMap::from([(3, 1.0)]),
+    );
+    assert_eq!(
+        error,
+        Err(RoqoqoError::PauliZProductMeasurementError {
+            msg: "Weight given for Pauli product 3, only 3 Pauli products have been added"
+                .to_string()
+        })
+    );
+}
+
+#[test]
+fn test_evaluate_weighted_flipped() {
+    let measurement = weighted_measurement(true);
+    let ro = vec![vec![true, false, true], vec![false, true, true]];
+    let ro_flipped = vec![vec![true, true, true], vec![true, true, false]];
+    let rx = vec![vec![false, true, false]];
+    let rx_flipped = vec![vec![true, true, true]];
+    let registers: HashMap<String, BitOutputRegister> = HashMap::from([
+        ("ro".to_string(), ro.clone()),
+        ("ro_flipped".to_string(), ro_flipped.clone()),
+        ("rx".to_string(), rx.clone()),
+        ("rx_flipped".to_string(), rx_flipped.clone()),
+    ]);
+    // Evaluates the single readouts without flipped measurement
+    let unflipped = weighted_measurement(false);
+    let single = |ro: &BitOutputRegister, rx: &BitOutputRegister| {
+        unflipped
+            .evaluate(
+                HashMap::from([
+                    ("ro".to_string(), ro.clone()),
+                    ("rx".to_string(), rx.clone()),
+                ]),
+                HashMap::new(),
+                HashMap::new(),
+            )
+            .unwrap()
+            .unwrap()
+    };
+    let normal = single(&ro, &rx);
+    // Flipped readouts measure the inverted bits
+    let invert = |register: &BitOutputRegister| -> BitOutputRegister {
+        register
+            .iter()
+            .map(|shot| shot.iter().map(|bit| !bit).collect())
+            .collect()
+    };
+    let flipped = single(&invert(&ro_flipped), &invert(&rx_flipped));
+
+    // The flipped register falls back to the weight of the normal register
+    let weighted = measurement
+        .evaluate_weighted(
+            registers.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::from([("ro".to_string(), 2.0)]),
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    let expected = (normal["pp0"] + flipped["pp0"]) / 2.0 * 2.0;
+    assert!((weighted["pp0"] - expected).abs() < 1e-12);
+    let expected = (normal["pp2"] + flipped["pp2"]) / 2.0;
+    assert!((weighted["pp2"] - expected).abs() < 1e-12);
+
+    // Normal and flipped readout can be weighted separately
+    let weighted = measurement
+        .evaluate_weighted(
+            registers,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::from([("rx".to_string(), 0.5), ("rx_flipped".to_string(), 1.5)]),
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    let expected = (0.5 * normal["pp2"] + 1.5 * flipped["pp2"]) / 2.0;
+    assert!((weighted["pp2"] - expected).abs() < 1e-12);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_deserialize_1_17() {
+    let mut input = PauliZProductInput::new(2, false);
+    let index = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_linear_exp_val("z0".to_string(), HashMap::from([(index, 2.0)]))
+        .unwrap();
+    let deserialized: PauliZProductInput =
+        bincode::deserialize(&PAULI_Z_PRODUCT_INPUT_1_17).unwrap();
+    assert_eq!(deserialized, input);
+    assert_eq!(
+        bincode::serialize(&input).unwrap(),
+        PAULI_Z_PRODUCT_INPUT_1_17
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    let measurement = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![circuit],
+        input,
+    };
+    let deserialized: PauliZProduct = bincode::deserialize(&PAULI_Z_PRODUCT_1_17).unwrap();
+    assert_eq!(deserialized, measurement);
+    assert_eq!(
+        bincode::serialize(&measurement).unwrap(),
+        PAULI_Z_PRODUCT_1_17
+    );
+}
+
+// Bincode serialization of a PauliZProductInput written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const PAULI_Z_PRODUCT_INPUT_1_17: [u8; 113] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 122, 48, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0
+];
+
+// Bincode serialization of a PauliZProduct measurement written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const PAULI_Z_PRODUCT_1_17: [u8; 199] = [
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114,
+    111, 2, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 122, 48, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0
+];
+
+/// Runs circuits that only contain PauliX gates and measurements on the zero state
+fn run_basis_state_circuits(measurement: &PauliZProduct) -> HashMap<String, BitOutputRegister> {
+    let mut registers: HashMap<String, BitOutputRegister> = HashMap::new();
+    for circuit in measurement.circuits.iter() {
+        let mut state = vec![false; measurement.input.number_qubits];
+        let mut outputs: HashMap<String, Vec<bool>> = HashMap::new();
+        let mut repetitions: HashMap<String, usize> = HashMap::new();
+        let operations = measurement
+            .constant_circuit
+            .iter()
+            .flat_map(|c| c.iter())
+            .chain(circuit.iter());
+        for operation in operations {
+            match operation {
+                operations::Operation::DefinitionBit(definition) => {
+                    outputs.insert(definition.name().clone(), vec![false; *definition.length()]);
+                }
+                operations::Operation::PauliX(gate) => {
+                    state[*gate.qubit()] = !state[*gate.qubit()];
+                }
+                operations::Operation::MeasureQubit(measure) => {
+                    outputs.get_mut(measure.readout()).unwrap()[*measure.readout_index()] =
+                        state[*measure.qubit()];
+                    repetitions.insert(measure.readout().clone(), 1);
+                }
+                operations::Operation::PragmaRepeatedMeasurement(measure) => {
+                    let output = outputs.get_mut(measure.readout()).unwrap();
+                    for (qubit, value) in state.iter().enumerate() {
+                        let index = match measure.qubit_mapping() {
+                            Some(mapping) => mapping.get(&qubit).copied(),
+                            None => Some(qubit),
+                        };
+                        if let Some(index) = index {
+                            output[index] = *value;
+                        }
+                    }
+                    repetitions.insert(measure.readout().clone(), *measure.number_measurements());
+                }
+                _ => panic!("Unsupported operation {}", operation.hqslang()),
+            }
+        }
+        for (name, number) in repetitions {
+            registers
+                .entry(name.clone())
+                .or_default()
+                .extend(vec![outputs[&name].clone(); number]);
+        }
+    }
+    registers
+}
+
+/// Creates an input measuring symmetric observables in the registers ro and rx
+fn flipped_input(use_flipped_measurement: bool) -> PauliZProductInput {
+    let mut input = PauliZProductInput::new(3, use_flipped_measurement);
+    let products = input
+        .add_pauliz_products("ro", vec![vec![0], vec![1, 2], vec![0, 1, 2]])
+        .unwrap();
+    let rx = input
+        .add_pauliz_product("rx".to_string(), vec![0, 1])
+        .unwrap();
+    for (index, name) in products
+        .iter()
+        .chain([rx].iter())
+        .zip(["z0", "z12", "z012", "x01"])
+    {
+        input
+            .add_linear_exp_val(name.to_string(), HashMap::from([(*index, 1.0)]))
+            .unwrap();
+    }
+    input
+        .add_linear_exp_val(
+            "energy".to_string(),
+            HashMap::from([(products[0], 0.5), (products[2], -2.0), (rx, 1.5)]),
+        )
+        .unwrap();
+    input
+}
+
+#[test]
+fn test_with_flipped_circuits_evaluation() {
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 3, true);
+    constant_circuit += operations::PauliX::new(0);
+    constant_circuit += operations::PauliX::new(2);
+    let mut circuit_ro = Circuit::new();
+    circuit_ro += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 5, None);
+    let mut circuit_rx = Circuit::new();
+    circuit_rx += operations::DefinitionBit::new("rx".to_string(), 2, true);
+    circuit_rx += operations::PauliX::new(1);
+    circuit_rx += operations::MeasureQubit::new(0, "rx".to_string(), 0);
+    circuit_rx += operations::MeasureQubit::new(1, "rx".to_string(), 1);
+    let circuits = vec![circuit_ro, circuit_rx];
+
+    let unflipped = PauliZProduct::with_flipped_circuits(
+        Some(constant_circuit.clone()),
+        circuits.clone(),
+        flipped_input(false),
+    )
+    .unwrap();
+    assert_eq!(unflipped.circuits, circuits);
+    let flipped = PauliZProduct::with_flipped_circuits(
+        Some(constant_circuit),
+        circuits.clone(),
+        flipped_input(true),
+    )
+    .unwrap();
+    assert_eq!(flipped.circuits.len(), 4);
+    assert_eq!(flipped.circuits[..2], circuits[..]);
+    assert_eq!(flipped.validate(), Ok(()));
+
+    let flipped_registers = run_basis_state_circuits(&flipped);
+    assert_eq!(
+        flipped_registers["ro_flipped"],
+        vec![vec![false, true, false]; 5]
+    );
+    assert_eq!(flipped_registers["rx_flipped"], vec![vec![false, false]]);
+    let unflipped_result = unflipped
+        .evaluate(
+            run_basis_state_circuits(&unflipped),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    let flipped_result = flipped
+        .evaluate(flipped_registers, HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert_eq!(flipped_result, unflipped_result);
+    assert_eq!(unflipped_result["z0"], -1.0);
+    assert_eq!(unflipped_result["z012"], 1.0);
+    assert_eq!(unflipped_result["x01"], 1.0);
+}
+
+#[test]
+fn test_with_flipped_circuits_structure() {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::DefinitionFloat::new("other".to_string(), 1, true);
+    circuit += operations::Hadamard::new(0);
+    circuit += operations::PragmaRepeatedMeasurement::new(
+        "ro".to_string(),
+        10,
+        Some(HashMap::from([(2, 0), (0, 1)])),
+    );
+    let mut input = PauliZProductInput::new(3, true);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    let measurement =
+        PauliZProduct::with_flipped_circuits(None, vec![circuit.clone()], input.clone()).unwrap();
+
+    let mut expected = Circuit::new();
+    expected += operations::DefinitionBit::new("ro_flipped".to_string(), 2, true);
+    expected += operations::DefinitionFloat::new("other".to_string(), 1, true);
+    expected += operations::Hadamard::new(0);
+    expected += operations::PauliX::new(0);
+    expected += operations::PauliX::new(2);
+    expected += operations::PragmaRepeatedMeasurement::new(
+        "ro_flipped".to_string(),
+        10,
+        Some(HashMap::from([(2, 0), (0, 1)])),
+    );
+    assert_eq!(measurement.circuits, vec![circuit.clone(), expected]);
+
+    // Registers named like a flipped readout register are rejected
+    let mut clashing = circuit;
+    clashing += operations::DefinitionBit::new("ro_flipped".to_string(), 2, true);
+    assert_eq!(
+        PauliZProduct::with_flipped_circuits(None, vec![clashing.clone()], input.clone()),
+        Err(RoqoqoError::PauliZProductMeasurementError {
+            msg: "Flipped readout register ro_flipped is already used".to_string()
+        })
+    );
+    assert_eq!(
+        PauliZProduct::with_flipped_circuits(Some(clashing), vec![Circuit::new()], input),
+        Err(RoqoqoError::PauliZProductMeasurementError {
+            msg: "Flipped readout register ro_flipped is already used".to_string()
+        })
+    );
+}
+
+/// Test that new_checked rejects readout registers not written by the circuits or too short for the products
+#[test]
+fn test_new_checked() {
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    let mut circuit = Circuit::new();
+    circuit += operations::Hadamard::new(0);
+    circuit += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let mut input = PauliZProductInput::new(3, false);
+    input
+        .add_pauliz_product("ro".to_string(), vec![0, 1])
+        .unwrap();
+
+    let measurement = PauliZProduct::new_checked(
+        Some(constant_circuit.clone()),
+        vec![Circuit::new(), circuit.clone()],
+        input.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        measurement,
+        PauliZProduct {
+            constant_circuit: Some(constant_circuit.clone()),
+            circuits: vec![Circuit::new(), circuit.clone()],
+            input: input.clone(),
+        }
+    );
+
+    // Product referencing a register none of the circuits define or write
+    let mut missing_input = input.clone();
+    missing_input
+        .add_pauliz_product("ro_other".to_string(), vec![0])
+        .unwrap();
+    assert_eq!(
+        PauliZProduct::new_checked(
+            Some(constant_circuit.clone()),
+            vec![circuit.clone()],
+            missing_input
+        ),
+        Err(RoqoqoError::MissingRegister {
+            name: "ro_other".to_string()
+        })
+    );
+    // Register written but not defined
+    assert_eq!(
+        PauliZProduct::new_checked(None, vec![circuit.clone()], input.clone()),
+        Err(RoqoqoError::MissingRegister {
+            name: "ro".to_string()
+        })
+    );
+
+    // Product referencing an index beyond the register length
+    let mut long_input = PauliZProductInput::new(3, false);
+    long_input
+        .add_pauliz_product("ro".to_string(), vec![0, 2])
+        .unwrap();
+    assert_eq!(
+        PauliZProduct::new_checked(
+            Some(constant_circuit),
+            vec![Circuit::new(), circuit],
+            long_input
+        ),
+        Err(RoqoqoError::RegisterTooSmall {
+            name: "ro".to_string(),
+            required: 3,
+            defined: 2,
+            circuit_index: 1,
+        })
+    );
+}
+
+#[test]
+fn test_deduplicate_circuits() {
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 3, true);
+    constant_circuit += operations::PauliX::new(0);
+    let mut circuit_ro = Circuit::new();
+    circuit_ro += operations::PauliX::new(2);
+    circuit_ro += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 5, None);
+    let mut circuit_rx = Circuit::new();
+    circuit_rx += operations::DefinitionBit::new("rx".to_string(), 2, true);
+    circuit_rx += operations::PauliX::new(1);
+    circuit_rx += operations::MeasureQubit::new(0, "rx".to_string(), 0);
+    circuit_rx += operations::MeasureQubit::new(1, "rx".to_string(), 1);
+    let mut circuit_ry = Circuit::new();
+    circuit_ry += operations::DefinitionBit::new("ry".to_string(), 1, true);
+    circuit_ry += operations::MeasureQubit::new(2, "ry".to_string(), 0);
+
+    let mut input = flipped_input(false);
+    let ry = input.add_pauliz_product("ry".to_string(), vec![0]).unwrap();
+    input
+        .add_linear_exp_val("y2".to_string(), HashMap::from([(ry, 1.0)]))
+        .unwrap();
+
+    let order = [0, 1, 0, 2, 2, 1, 0, 2, 1, 0];
+    let distinct = [circuit_ro, circuit_rx, circuit_ry];
+    let measurement = PauliZProduct {
+        constant_circuit: Some(constant_circuit),
+        circuits: order.iter().map(|index| distinct[*index].clone()).collect(),
+        input,
+    };
+    assert_eq!(measurement.circuits.len(), 10);
+
+    let (deduplicated, mapping) = measurement.deduplicate_circuits();
+    assert_eq!(deduplicated.circuits, distinct.to_vec());
+    assert_eq!(mapping, order.to_vec());
+    assert_eq!(deduplicated.constant_circuit, measurement.constant_circuit);
+    assert_eq!(deduplicated.input, measurement.input);
+    assert_eq!(deduplicated.validate(), Ok(()));
+    for (old_index, new_index) in mapping.iter().enumerate() {
+        assert_eq!(
+            measurement.circuits[old_index],
+            deduplicated.circuits[*new_index]
+        );
+    }
+
+    let result = measurement
+        .evaluate(
+            run_basis_state_circuits(&measurement),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    let deduplicated_result = deduplicated
+        .evaluate(
+            run_basis_state_circuits(&deduplicated),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+    for (name, value) in result.iter() {
+        assert_eq!(value.to_bits(), deduplicated_result[name].to_bits());
+    }
+    assert_eq!(result.len(), deduplicated_result.len());
+
+    // Deduplicating again does not change the measurement
+    let (again, mapping) = deduplicated.deduplicate_circuits();
+    assert_eq!(again, deduplicated);
+    assert_eq!(mapping, vec![0, 1, 2]);
+}
@@ -14,17 +14,21 @@
 
 #[cfg(feature = "jsonschema")]
 use jsonschema::{Draft, Validator};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations;
+use roqoqo::operations::SupportedVersion;
 use roqoqo::prelude::*;
-use roqoqo::Circuit;
 use roqoqo::{
     measurements::{CheatedPauliZProduct, CheatedPauliZProductInput},
     registers::FloatOutputRegister,
 };
+use roqoqo::{Circuit, RoqoqoError};
 #[cfg(feature = "jsonschema")]
 use schemars::schema_for;
 use std::collections::HashMap;
+use struqture::spins::PauliProduct;
+use struqture::SpinIndex;
 
 #[test]
 fn test_returning_circuits() {
@@ -172,6 +176,52 @@ fn test_evaluate_linear() {
     assert_eq!(result.get("multi_pp_val").unwrap(), &1.0);
 }
 
+#[test]
+fn test_evaluate_readout_indices() {
+    let mut bri = CheatedPauliZProductInput::new();
+    assert_eq!(bri.add_pauliz_product_at("ro".to_string(), 0), 0);
+    assert_eq!(bri.add_pauliz_product_at("ro".to_string(), 2), 1);
+    assert_eq!(bri.add_pauliz_product_at("ro".to_string(), 1), 2);
+    assert_eq!(bri.add_pauliz_product_at("ro".to_string(), 2), 1);
+    assert_eq!(bri.number_pauli_products(), 3);
+    assert_eq!(bri.minimum_supported_roqoqo_version(), (1, 18, 0));
+    bri.add_linear_exp_val(
+        "sum".to_string(),
+        HashMap::from([(0, 1.0), (1, 10.0), (2, 100.0)]),
+    )
+    .unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionFloat::new("ro".to_string(), 3, true);
+    for readout_index in 0..3 {
+        circuit += operations::PragmaGetIndexedPauliProduct::new(
+            HashMap::from([(readout_index, 3)]),
+            "ro".to_string(),
+            readout_index,
+            Circuit::new(),
+        );
+    }
+    let br = CheatedPauliZProduct {
+        constant_circuit: None,
+        circuits: vec![circuit],
+        input: bri,
+    };
+    assert!(br.validate().is_ok());
+
+    let registers = HashMap::from([("ro".to_string(), vec![vec![1.0, -0.5, 0.25]])]);
+    let result = br
+        .evaluate(HashMap::new(), registers, HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert_eq!(result.get("sum").unwrap(), &-46.5);
+
+    let registers = HashMap::from([("ro".to_string(), vec![vec![1.0, -0.5]])]);
+    assert!(matches!(
+        br.evaluate(HashMap::new(), registers, HashMap::new()),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+}
+
 #[test]
 fn test_evaluate_symbolic() {
     let mut bri = CheatedPauliZProductInput::new();
@@ -242,3 +292,231 @@ fn test_json_schema() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Returns the statevector of qubit 0 in |+i>, qubit 1 in |1> and qubit 2 in |+> in little endian order
+fn known_statevector() -> Vec<Complex64> {
+    let qubit_0 = [Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)];
+    let qubit_1 = [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)];
+    let qubit_2 = [Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)];
+    (0..8)
+        .map(|b| qubit_0[b & 1] * qubit_1[(b >> 1) & 1] * qubit_2[(b >> 2) & 1] / 2.0)
+        .collect()
+}
+
+/// Creates a measurement of Pauli products with analytic expectation values on the known state
+fn pauli_product_measurement(
+    readout_operation: operations::Operation,
+) -> (CheatedPauliZProduct, Vec<f64>) {
+    let mut input = CheatedPauliZProductInput::new();
+    let products = [
+        (PauliProduct::new().y(0), 1.0),
+        (PauliProduct::new().z(1), -1.0),
+        (PauliProduct::new().x(2), 1.0),
+        (PauliProduct::new().x(0), 0.0),
+        (PauliProduct::new().z(0), 0.0),
+        (PauliProduct::new().y(0).z(1).x(2), -1.0),
+        (PauliProduct::new().x(0).x(2), 0.0),
+        (PauliProduct::new(), 1.0),
+    ];
+    let mut expected = Vec::new();
+    for (index, (product, value)) in products.into_iter().enumerate() {
+        assert_eq!(input.add_pauli_product("psi".to_string(), product), index);
+        input
+            .add_linear_exp_val(format!("pp{}", index), HashMap::from([(index, 1.0)]))
+            .unwrap();
+        expected.push(value);
+    }
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionComplex::new("psi".to_string(), 64, true);
+    circuit += readout_operation;
+    (
+        CheatedPauliZProduct {
+            constant_circuit: None,
+            circuits: vec![circuit],
+            input,
+        },
+        expected,
+    )
+}
+
+#[test]
+fn test_evaluate_pauli_products_statevector() {
+    let (measurement, expected) = pauli_product_measurement(
+        operations::PragmaGetStateVector::new("psi".to_string(), None).into(),
+    );
+    let registers = HashMap::from([("psi".to_string(), vec![known_statevector()])]);
+    let result = measurement
+        .evaluate(HashMap::new(), HashMap::new(), registers)
+        .unwrap()
+        .unwrap();
+    for (index, value) in expected.iter().enumerate() {
+        assert!((result[&format!("pp{}", index)] - value).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_evaluate_pauli_products_density_matrix() {
+    let (measurement, expected) = pauli_product_measurement(
+        operations::PragmaGetDensityMatrix::new("psi".to_string(), None).into(),
+    );
+    let psi = known_statevector();
+    let density_matrix: Vec<Complex64> = (0..64).map(|i| psi[i / 8] * psi[i % 8].conj()).collect();
+    let registers = HashMap::from([("psi".to_string(), vec![density_matrix])]);
+    let result = measurement
+        .evaluate(HashMap::new(), HashMap::new(), registers)
+        .unwrap()
+        .unwrap();
+    for (index, value) in expected.iter().enumerate() {
+        assert!((result[&format!("pp{}", index)] - value).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_pauli_products_input() {
+    let mut input = CheatedPauliZProductInput::new();
+    assert_eq!(input.add_pauliz_product("ro".to_string()), 0);
+    assert_eq!(
+        input.add_pauli_product("psi".to_string(), PauliProduct::new().x(1)),
+        1
+    );
+    assert_eq!(input.add_pauliz_product("ri".to_string()), 2);
+    assert_eq!(
+        input.add_pauli_product("psi".to_string(), PauliProduct::new().x(1)),
+        1
+    );
+    assert_eq!(input.number_pauli_products(), 3);
+    assert_eq!(input.minimum_supported_roqoqo_version(), (1, 18, 0));
+    assert_eq!(
+        CheatedPauliZProductInput::new().minimum_supported_roqoqo_version(),
+        (1, 0, 0)
+    );
+
+    // Inputs serialized without Pauli product operators deserialize with empty operators
+    let serialized = serde_json::to_string(&input).unwrap();
+    assert_eq!(
+        serde_json::from_str::<CheatedPauliZProductInput>(&serialized).unwrap(),
+        input
+    );
+    let legacy = r#"{"measured_exp_vals":{},"pauli_product_keys":{"ro":0}}"#;
+    let deserialized: CheatedPauliZProductInput = serde_json::from_str(legacy).unwrap();
+    assert!(deserialized.pauli_product_operators.is_empty());
+    assert!(deserialized.pauli_product_readout_indices.is_empty());
+    assert_eq!(deserialized.number_pauli_products(), 1);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_pauli_products_input_bincode() {
+    let mut input = CheatedPauliZProductInput::new();
+    input.add_pauliz_product("ro".to_string());
+    let serialized = bincode::serialize(&input).unwrap();
+    assert_eq!(
+        bincode::deserialize::<CheatedPauliZProductInput>(&serialized).unwrap(),
+        input
+    );
+
+    // Pauli products that do not fit the bincode layout of roqoqo 1.17 are not serialized
+    let error = "CheatedPauliZProductInput with Pauli products added by add_pauli_product or add_pauliz_product_at can only be serialized to human readable formats";
+    let mut operator_input = input.clone();
+    operator_input.add_pauli_product("psi".to_string(), PauliProduct::new().x(1));
+    assert_eq!(
+        bincode::serialize(&operator_input).unwrap_err().to_string(),
+        error
+    );
+    let mut index_input = input;
+    index_input.add_pauliz_product_at("ri".to_string(), 2);
+    assert_eq!(
+        bincode::serialize(&index_input).unwrap_err().to_string(),
+        error
+    );
+    let measurement = CheatedPauliZProduct {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input: index_input.clone(),
+    };
+    assert_eq!(
+        bincode::serialize(&measurement).unwrap_err().to_string(),
+        error
+    );
+    // The json serialization keeps the Pauli products
+    let serialized = serde_json::to_string(&index_input).unwrap();
+    assert_eq!(
+        serde_json::from_str::<CheatedPauliZProductInput>(&serialized).unwrap(),
+        index_input
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_deserialize_1_17() {
+    let mut input = CheatedPauliZProductInput::new();
+    let index = input.add_pauliz_product("ro".to_string());
+    input
+        .add_linear_exp_val("z0".to_string(), HashMap::from([(index, 2.0)]))
+        .unwrap();
+    let deserialized: CheatedPauliZProductInput =
+        bincode::deserialize(&CHEATED_PAULI_Z_PRODUCT_INPUT_1_17).unwrap();
+    assert_eq!(deserialized, input);
+    assert_eq!(
+        bincode::serialize(&input).unwrap(),
+        CHEATED_PAULI_Z_PRODUCT_INPUT_1_17
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionFloat::new("ro".to_string(), 1, true);
+    let measurement = CheatedPauliZProduct {
+        constant_circuit: None,
+        circuits: vec![circuit],
+        input,
+    };
+    let deserialized: CheatedPauliZProduct =
+        bincode::deserialize(&CHEATED_PAULI_Z_PRODUCT_1_17).unwrap();
+    assert_eq!(deserialized, measurement);
+}
+
+// Bincode serialization of a CheatedPauliZProductInput written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const CHEATED_PAULI_Z_PRODUCT_INPUT_1_17: [u8; 72] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 122, 48, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 114, 111, 0, 0, 0, 0, 0, 0, 0, 0
+];
+
+// Bincode serialization of a CheatedPauliZProduct measurement written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const CHEATED_PAULI_Z_PRODUCT_1_17: [u8; 128] = [
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114,
+    111, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 122, 48, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111,
+    0, 0, 0, 0, 0, 0, 0, 0
+];
+
+#[test]
+fn test_evaluate_pauli_products_errors() {
+    let (measurement, _) = pauli_product_measurement(
+        operations::PragmaGetStateVector::new("psi".to_string(), None).into(),
+    );
+    // Statevector of two qubits is too small for Pauli products on qubit 2
+    let registers = HashMap::from([("psi".to_string(), vec![vec![Complex64::new(1.0, 0.0); 4]])]);
+    assert_eq!(
+        measurement.evaluate(HashMap::new(), HashMap::new(), registers),
+        Err(RoqoqoError::MismatchedRegisterDimension {
+            dim: 4,
+            number_qubits: 3
+        })
+    );
+    let registers = HashMap::from([("psi".to_string(), vec![vec![Complex64::new(1.0, 0.0); 12]])]);
+    assert!(matches!(
+        measurement.evaluate(HashMap::new(), HashMap::new(), registers),
+        Err(RoqoqoError::MismatchedRegisterDimension { dim: 12, .. })
+    ));
+    assert_eq!(
+        measurement.evaluate(HashMap::new(), HashMap::new(), HashMap::new()),
+        Err(RoqoqoError::MissingRegister {
+            name: "psi".to_string()
+        })
+    );
+}
@@ -14,6 +14,7 @@
 
 #[cfg(feature = "jsonschema")]
 use jsonschema::{Draft, Validator};
+use ndarray::{Array1, Array2};
 use num_complex::Complex64;
 use roqoqo::prelude::*;
 use roqoqo::Circuit;
@@ -181,6 +182,67 @@ fn test_evaluate(register: Vec<Vec<Complex64>>, value_diagonal: f64, value_off_d
     assert!((result.get("test_off_diagonal").unwrap() - value_off_diagonal).abs() < 1e-10);
 }
 
+/// Simple deterministic pseudo-random numbers in [-1, 1) for the sparse operator tests
+fn pseudo_random(seed: &mut u64) -> f64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    ((*seed >> 11) as f64 / (1_u64 << 53) as f64) * 2.0 - 1.0
+}
+
+#[test]
+fn test_evaluate_sparse_operator_against_dense() {
+    let number_qubits = 4;
+    let dimension = 2_usize.pow(number_qubits as u32);
+    let mut seed: u64 = 42;
+    // Random sparse operator with a duplicated entry, duplicated entries are summed up
+    let mut operator: Vec<(usize, usize, Complex64)> = Vec::new();
+    for _ in 0..20 {
+        let row = ((pseudo_random(&mut seed) + 1.0) / 2.0 * dimension as f64) as usize;
+        let col = ((pseudo_random(&mut seed) + 1.0) / 2.0 * dimension as f64) as usize;
+        let value = Complex64::new(pseudo_random(&mut seed), pseudo_random(&mut seed));
+        operator.push((row, col, value));
+    }
+    operator.push((operator[0].0, operator[0].1, Complex64::new(0.5, 0.0)));
+    let mut dense_operator: Array2<Complex64> = Array2::zeros((dimension, dimension));
+    for (row, col, value) in operator.iter() {
+        dense_operator[(*row, *col)] += value;
+    }
+
+    // Random normalized state vector and the corresponding density matrix
+    let mut state: Array1<Complex64> = (0..dimension)
+        .map(|_| Complex64::new(pseudo_random(&mut seed), pseudo_random(&mut seed)))
+        .collect();
+    let norm = state.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+    state.mapv_inplace(|x| x / norm);
+    let density_matrix: Vec<Complex64> = (0..dimension)
+        .flat_map(|row| {
+            let state = state.clone();
+            (0..dimension).map(move |col| state[row] * state[col].conj())
+        })
+        .collect();
+    let dense_value = state.mapv(|x| x.conj()).dot(&dense_operator.dot(&state)).re;
+
+    let mut input = CheatedInput::new(number_qubits);
+    input
+        .add_operator_exp_val("sparse".to_string(), operator, "ro".to_string())
+        .unwrap();
+    let measurement = Cheated {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input,
+    };
+    for register in [state.to_vec(), density_matrix] {
+        let mut measured_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let _ = measured_registers.insert("ro".to_string(), vec![register]);
+        let result = measurement
+            .evaluate(HashMap::new(), HashMap::new(), measured_registers)
+            .unwrap()
+            .unwrap();
+        assert!((result.get("sparse").unwrap() - dense_value).abs() < 1e-10);
+    }
+}
+
 #[test]
 fn test_evaluate_error() {
     let register = vec![vec![
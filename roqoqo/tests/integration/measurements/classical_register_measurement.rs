@@ -20,6 +20,7 @@ use roqoqo::measurements::ClassicalRegister;
 use roqoqo::operations;
 use roqoqo::prelude::*;
 use roqoqo::Circuit;
+use roqoqo::RoqoqoError;
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
 
@@ -39,6 +40,63 @@ fn test_returning_circuits() {
     assert_eq!(&Circuit::new(), br.constant_circuit().as_ref().unwrap());
 }
 
+#[test]
+fn test_validate() {
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionComplex::new("state".to_string(), 4, true);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += operations::PragmaGetStateVector::new("state".to_string(), None);
+    let br = ClassicalRegister {
+        constant_circuit: Some(constant_circuit.clone()),
+        circuits: vec![circuit.clone()],
+    };
+    assert_eq!(br.validate(), Ok(()));
+
+    // Readout index outside of the defined register
+    let mut too_small = circuit.clone();
+    too_small += operations::MeasureQubit::new(2, "ro".to_string(), 2);
+    let br = ClassicalRegister {
+        constant_circuit: Some(constant_circuit.clone()),
+        circuits: vec![circuit.clone(), too_small],
+    };
+    assert_eq!(
+        br.validate(),
+        Err(RoqoqoError::RegisterTooSmall {
+            name: "ro".to_string(),
+            required: 3,
+            defined: 2,
+            circuit_index: 1
+        })
+    );
+
+    // Register defined in neither the constant circuit nor the circuit
+    let br = ClassicalRegister {
+        constant_circuit: None,
+        circuits: vec![circuit.clone()],
+    };
+    assert_eq!(
+        br.validate(),
+        Err(RoqoqoError::MissingRegister {
+            name: "ro".to_string()
+        })
+    );
+    let mut missing_all = Circuit::new();
+    missing_all += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let br = ClassicalRegister {
+        constant_circuit: None,
+        circuits: vec![missing_all],
+    };
+    assert_eq!(
+        br.validate(),
+        Err(RoqoqoError::MissingRegister {
+            name: "ro".to_string()
+        })
+    );
+}
+
 #[test]
 fn test_clone_eq_format() {
     let mut circs: Vec<Circuit> = Vec::new();
@@ -22,6 +22,8 @@ use roqoqo::RoqoqoError;
 use schemars::schema_for;
 use std::collections::HashMap;
 use std::default::Default;
+use struqture::spins::PauliProduct;
+use struqture::SpinIndex;
 
 #[test]
 fn test_pp_to_exp_val() {
@@ -300,3 +302,203 @@ fn test_json_schema_cheated() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+#[test]
+fn test_add_pauliz_products() {
+    let mut single = PauliZProductInput::new(3, false);
+    let mut expected_indices = Vec::new();
+    for mask in [vec![], vec![0], vec![1, 2], vec![0]] {
+        expected_indices.push(single.add_pauliz_product("ro".to_string(), mask).unwrap());
+    }
+    single
+        .add_pauliz_product("rx".to_string(), vec![1, 2])
+        .unwrap();
+
+    let mut bulk = PauliZProductInput::new(3, false);
+    let indices = bulk
+        .add_pauliz_products("ro", vec![vec![], vec![0], vec![1, 2], vec![0]])
+        .unwrap();
+    assert_eq!(indices, expected_indices);
+    assert_eq!(indices, vec![0, 1, 2, 1]);
+    // Already registered products keep their index
+    assert_eq!(
+        bulk.add_pauliz_products("rx", vec![vec![1, 2]]).unwrap(),
+        vec![3]
+    );
+    assert_eq!(
+        bulk.add_pauliz_products("ro", vec![vec![1, 2], vec![]])
+            .unwrap(),
+        vec![2, 0]
+    );
+    assert_eq!(bulk, single);
+
+    assert_eq!(bulk.number_pauli_products(), 4);
+    assert_eq!(
+        bulk.pauli_products(),
+        vec![
+            ("ro".to_string(), vec![]),
+            ("ro".to_string(), vec![0]),
+            ("ro".to_string(), vec![1, 2]),
+            ("rx".to_string(), vec![1, 2]),
+        ]
+    );
+
+    // No product is added when one of them exceeds the number of qubits
+    let error = bulk.add_pauliz_products("ro", vec![vec![2], vec![0, 3]]);
+    assert_eq!(
+        error,
+        Err(RoqoqoError::PauliProductExceedsQubits {
+            pp_qubit: 3,
+            number_qubits: 3
+        })
+    );
+    assert_eq!(bulk, single);
+}
+
+#[test]
+fn test_add_linear_exp_vals() {
+    let mut single = PauliZProductInput::new(3, false);
+    let mut bulk = single.clone();
+    let entries: Vec<(String, HashMap<usize, f64>)> = vec![
+        ("a".to_string(), HashMap::from([(0, 1.0)])),
+        ("b".to_string(), HashMap::from([(0, 2.0), (1, -1.0)])),
+    ];
+    for (name, linear) in entries.clone() {
+        single.add_linear_exp_val(name, linear).unwrap();
+    }
+    bulk.add_linear_exp_vals(entries).unwrap();
+    assert_eq!(bulk, single);
+
+    // No expectation value is added when a name is taken or repeated
+    let error = bulk.add_linear_exp_vals(vec![
+        ("c".to_string(), HashMap::new()),
+        ("a".to_string(), HashMap::new()),
+    ]);
+    assert_eq!(
+        error,
+        Err(RoqoqoError::ExpValUsedTwice {
+            name: "a".to_string()
+        })
+    );
+    let error = bulk.add_linear_exp_vals(vec![
+        ("d".to_string(), HashMap::new()),
+        ("d".to_string(), HashMap::new()),
+    ]);
+    assert_eq!(
+        error,
+        Err(RoqoqoError::ExpValUsedTwice {
+            name: "d".to_string()
+        })
+    );
+    assert_eq!(bulk, single);
+}
+
+/// Test remap_registers of PauliZProductInput
+#[test]
+fn test_remap_registers_br() {
+    let mut input = PauliZProductInput::new(3, false);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_pauliz_product("ro_flipped".to_string(), vec![1])
+        .unwrap();
+
+    let mapping: HashMap<String, String> = [("ro".to_string(), "ro_b".to_string())]
+        .into_iter()
+        .collect();
+    let remapped = input.remap_registers(&mapping).unwrap();
+    assert_eq!(
+        remapped.pauli_products(),
+        vec![
+            ("ro_b".to_string(), vec![0]),
+            ("ro_flipped".to_string(), vec![1])
+        ]
+    );
+    assert_eq!(remapped.number_pauli_products(), 2);
+
+    let clash: HashMap<String, String> = [("ro".to_string(), "ro_flipped".to_string())]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        input.remap_registers(&clash),
+        Err(RoqoqoError::RegisterMappingError {
+            name: "ro_flipped".to_string()
+        })
+    );
+}
+
+/// Test that the getters of the measurement inputs reflect the added entries after a serialization round-trip
+#[test]
+fn test_getters() {
+    let mut input = PauliZProductInput::new(3, false);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_pauliz_product("ro_flipped".to_string(), vec![1, 2])
+        .unwrap();
+    input
+        .add_linear_exp_val("b".to_string(), HashMap::from([(0, 1.0)]))
+        .unwrap();
+    input
+        .add_symbolic_exp_val("a".to_string(), "pauli_product_1".into())
+        .unwrap();
+    let input: PauliZProductInput =
+        serde_json::from_str(&serde_json::to_string(&input).unwrap()).unwrap();
+    assert_eq!(input.number_qubits(), 3);
+    assert_eq!(
+        input.measured_exp_val_names(),
+        vec!["a".to_string(), "b".to_string()]
+    );
+    assert_eq!(
+        input.pauli_products(),
+        vec![
+            ("ro".to_string(), vec![0]),
+            ("ro_flipped".to_string(), vec![1, 2])
+        ]
+    );
+
+    let mut input = CheatedPauliZProductInput::new();
+    assert_eq!(input.number_qubits(), 0);
+    input.add_pauliz_product("ro_a".to_string());
+    assert_eq!(input.number_qubits(), 0);
+    input.add_pauli_product("ro_c".to_string(), PauliProduct::new().x(3).z(1));
+    input
+        .add_linear_exp_val("exp".to_string(), HashMap::from([(0, 1.0), (1, 2.0)]))
+        .unwrap();
+    let input: CheatedPauliZProductInput =
+        serde_json::from_str(&serde_json::to_string(&input).unwrap()).unwrap();
+    assert_eq!(input.number_qubits(), 4);
+    assert_eq!(input.measured_exp_val_names(), vec!["exp".to_string()]);
+    assert_eq!(
+        input.pauli_products(),
+        vec![
+            ("ro_a".to_string(), vec![]),
+            ("ro_c".to_string(), vec![1, 3])
+        ]
+    );
+
+    let mut input = CheatedInput::new(2);
+    input
+        .add_operator_exp_val(
+            "z1".to_string(),
+            vec![(0, 0, Complex64::new(1.0, 0.0))],
+            "ro".to_string(),
+        )
+        .unwrap();
+    input
+        .add_operator_exp_val(
+            "x0".to_string(),
+            vec![(0, 1, Complex64::new(1.0, 0.0))],
+            "ro".to_string(),
+        )
+        .unwrap();
+    let input: CheatedInput =
+        serde_json::from_str(&serde_json::to_string(&input).unwrap()).unwrap();
+    assert_eq!(input.number_qubits(), 2);
+    assert_eq!(
+        input.measured_exp_val_names(),
+        vec!["x0".to_string(), "z1".to_string()]
+    );
+    assert_eq!(
+        input.operator_dimensions(),
+        vec![("x0".to_string(), 4), ("z1".to_string(), 4)]
+    );
+}
@@ -10,8 +10,12 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "async")]
+mod async_evaluate;
 mod basis_rotation_measurement;
 mod cheated_basis_rotation_measurement;
 mod cheated_measurement;
 mod classical_register_measurement;
 mod measurement_auxiliary_data_input;
+mod shot_noise;
+mod statistics;
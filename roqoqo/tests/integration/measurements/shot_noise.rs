@@ -0,0 +1,87 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for sampling bit registers from exact probabilities
+
+use roqoqo::measurements::sample_bit_register;
+use roqoqo::registers::{BitOutputRegister, FloatOutputRegister};
+use roqoqo::RoqoqoError;
+use test_case::test_case;
+
+/// Test that sampling with a fixed seed is deterministic
+#[test]
+fn test_sample_bit_register_fixed_seed() {
+    let probabilities: FloatOutputRegister = vec![vec![0.1, 0.2, 0.3, 0.4]];
+    let first = sample_bit_register(&probabilities, 50, Some(11)).unwrap();
+    let second = sample_bit_register(&probabilities, 50, Some(11)).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first.len(), 50);
+    assert!(first.iter().all(|record| record.len() == 2));
+
+    let other_seed = sample_bit_register(&probabilities, 50, Some(12)).unwrap();
+    assert_ne!(first, other_seed);
+}
+
+/// Test that deterministic distributions always return the same record
+#[test_case(vec![1.0, 0.0, 0.0, 0.0], vec![false, false]; "00")]
+#[test_case(vec![0.0, 1.0, 0.0, 0.0], vec![true, false]; "qubit 0")]
+#[test_case(vec![0.0, 0.0, 1.0, 0.0], vec![false, true]; "qubit 1")]
+#[test_case(vec![0.0, 0.0, 0.0, 1.0], vec![true, true]; "11")]
+fn test_sample_bit_register_basis_state(distribution: Vec<f64>, record: Vec<bool>) {
+    let sampled = sample_bit_register(&vec![distribution], 10, None).unwrap();
+    let expected: BitOutputRegister = vec![record; 10];
+    assert_eq!(sampled, expected);
+}
+
+/// Test that the sampled frequencies follow the probabilities
+#[test]
+fn test_sample_bit_register_frequencies() {
+    let probabilities: FloatOutputRegister = vec![vec![0.25, 0.75]];
+    let sampled = sample_bit_register(&probabilities, 10000, Some(3)).unwrap();
+    let ones = sampled.iter().filter(|record| record[0]).count() as f64;
+    assert!((ones / 10000.0 - 0.75).abs() < 0.02);
+}
+
+/// Test that the records of all register entries are appended
+#[test]
+fn test_sample_bit_register_multiple_entries() {
+    let probabilities: FloatOutputRegister = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+    let sampled = sample_bit_register(&probabilities, 3, Some(0)).unwrap();
+    assert_eq!(
+        sampled,
+        vec![
+            vec![false],
+            vec![false],
+            vec![false],
+            vec![true],
+            vec![true],
+            vec![true]
+        ]
+    );
+    assert!(sample_bit_register(&Vec::new(), 3, Some(0))
+        .unwrap()
+        .is_empty());
+}
+
+/// Test that invalid probabilities are rejected
+#[test_case(vec![0.5, 0.25, 0.25], "Length 3 of probability register is not a power of two"; "length")]
+#[test_case(vec![1.5, -0.5], "Probabilities must be finite and non-negative"; "negative")]
+#[test_case(vec![0.5, f64::NAN], "Probabilities must be finite and non-negative"; "nan")]
+#[test_case(vec![0.5, 0.25], "Probabilities sum up to 0.75 instead of 1"; "normalization")]
+fn test_sample_bit_register_error(distribution: Vec<f64>, msg: &str) {
+    assert_eq!(
+        sample_bit_register(&vec![distribution], 10, Some(0)),
+        Err(RoqoqoError::GenericError {
+            msg: msg.to_string()
+        })
+    );
+}
@@ -0,0 +1,124 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the statistics of bit registers
+
+use roqoqo::measurements::statistics::{
+    bit_correlation, bit_frequencies, joint_histogram, mutual_information,
+};
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::RoqoqoError;
+use std::collections::HashMap;
+
+fn register() -> BitOutputRegister {
+    vec![
+        vec![true, false, true],
+        vec![true, true, false],
+        vec![false, false, true],
+        vec![true, false, true],
+    ]
+}
+
+/// Test bit_frequencies on a hand-computed example
+#[test]
+fn test_bit_frequencies() {
+    assert_eq!(
+        bit_frequencies(&register()).unwrap(),
+        vec![0.75, 0.25, 0.75]
+    );
+    assert_eq!(
+        bit_frequencies(&vec![vec![true], vec![true, false]]),
+        Err(RoqoqoError::GenericError {
+            msg: "Measurement records have different lengths 1 and 2".to_string()
+        })
+    );
+}
+
+/// Test bit_correlation on hand-computed examples
+#[test]
+fn test_bit_correlation() {
+    let register = register();
+    assert!((bit_correlation(&register, 0, 1).unwrap() - 0.0625).abs() < 1e-12);
+    assert!((bit_correlation(&register, 0, 2).unwrap() + 0.0625).abs() < 1e-12);
+    assert!((bit_correlation(&register, 1, 1).unwrap() - 0.1875).abs() < 1e-12);
+
+    // Constant bits have no correlation
+    let constant: BitOutputRegister = vec![vec![true, false]; 3];
+    assert_eq!(bit_correlation(&constant, 0, 1).unwrap(), 0.0);
+}
+
+/// Test joint_histogram on a hand-computed example
+#[test]
+fn test_joint_histogram() {
+    let histogram = joint_histogram(&register(), &[0, 2]).unwrap();
+    let mut expected: HashMap<Vec<bool>, usize> = HashMap::new();
+    expected.insert(vec![true, true], 2);
+    expected.insert(vec![true, false], 1);
+    expected.insert(vec![false, true], 1);
+    assert_eq!(histogram, expected);
+
+    let histogram = joint_histogram(&register(), &[2, 1]).unwrap();
+    let mut expected: HashMap<Vec<bool>, usize> = HashMap::new();
+    expected.insert(vec![true, false], 3);
+    expected.insert(vec![false, true], 1);
+    assert_eq!(histogram, expected);
+
+    let histogram = joint_histogram(&register(), &[]).unwrap();
+    let mut expected: HashMap<Vec<bool>, usize> = HashMap::new();
+    expected.insert(vec![], 4);
+    assert_eq!(histogram, expected);
+}
+
+/// Test mutual_information on hand-computed examples
+#[test]
+fn test_mutual_information() {
+    let correlated: BitOutputRegister = vec![
+        vec![true, true],
+        vec![false, false],
+        vec![true, true],
+        vec![false, false],
+    ];
+    assert!((mutual_information(&correlated, 0, 1).unwrap() - 1.0).abs() < 1e-12);
+
+    let independent: BitOutputRegister = vec![
+        vec![false, false],
+        vec![false, true],
+        vec![true, false],
+        vec![true, true],
+    ];
+    assert!(mutual_information(&independent, 0, 1).unwrap().abs() < 1e-12);
+    assert!(bit_correlation(&independent, 0, 1).unwrap().abs() < 1e-12);
+
+    assert!((mutual_information(&register(), 0, 2).unwrap() - 0.12255624891826565).abs() < 1e-12);
+    let constant: BitOutputRegister = vec![vec![true, false]; 3];
+    assert_eq!(mutual_information(&constant, 0, 1).unwrap(), 0.0);
+}
+
+/// Test that empty registers and missing qubits return errors
+#[test]
+fn test_statistics_errors() {
+    let empty: BitOutputRegister = Vec::new();
+    let empty_error = || RoqoqoError::GenericError {
+        msg: "Bit register contains no measurement records".to_string(),
+    };
+    assert_eq!(bit_frequencies(&empty), Err(empty_error()));
+    assert_eq!(bit_correlation(&empty, 0, 1), Err(empty_error()));
+    assert_eq!(mutual_information(&empty, 0, 1), Err(empty_error()));
+    assert_eq!(joint_histogram(&empty, &[0]), Err(empty_error()));
+
+    let qubit_error = || RoqoqoError::GenericError {
+        msg: "Qubit 3 exceeds the length 3 of a measurement record".to_string(),
+    };
+    assert_eq!(bit_correlation(&register(), 3, 0), Err(qubit_error()));
+    assert_eq!(mutual_information(&register(), 0, 3), Err(qubit_error()));
+    assert_eq!(joint_histogram(&register(), &[0, 3]), Err(qubit_error()));
+}
@@ -559,3 +559,35 @@ fn operate_analog_timedependent_spin() {
     assert!(!unparam_analog.is_parametrized());
     assert!(param_analog.is_parametrized());
 }
+
+/// Test that ApplyTimeDependentSpinHamiltonian serializes identically independent of the HashMap ordering
+#[cfg(feature = "serialize")]
+#[test]
+fn apply_timedependent_spin_hamiltonian_serde_stable() {
+    let names: Vec<String> = (0..16).map(|i| format!("omega_{}", i)).collect();
+    let values: HashMap<String, Vec<f64>> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.clone(), vec![i as f64]))
+        .collect();
+    // Same entries inserted in reverse into a map with a different hasher state and capacity
+    let mut values_reinserted: HashMap<String, Vec<f64>> = HashMap::with_capacity(64);
+    for (i, name) in names.iter().enumerate().rev() {
+        values_reinserted.insert(name.clone(), vec![i as f64]);
+    }
+    let hamiltonian = create_apply_timedependent_spin_hamiltonian("omega_0")
+        .hamiltonian()
+        .clone();
+    let op = ApplyTimeDependentSpinHamiltonian::new(hamiltonian.clone(), vec![1.0], values);
+    let op_reinserted =
+        ApplyTimeDependentSpinHamiltonian::new(hamiltonian, vec![1.0], values_reinserted);
+
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(json, serde_json::to_string(&op_reinserted).unwrap());
+    assert_eq!(
+        bincode::serialize(&op).unwrap(),
+        bincode::serialize(&op_reinserted).unwrap()
+    );
+    let deserialized: ApplyTimeDependentSpinHamiltonian = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, op_reinserted);
+}
@@ -16,6 +16,8 @@
 use jsonschema::{Draft, Validator};
 use qoqo_calculator::{Calculator, CalculatorFloat};
 use roqoqo::operations::*;
+#[cfg(feature = "unstable_fock_matrix")]
+use roqoqo::RoqoqoError;
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
 #[cfg(feature = "serialize")]
@@ -584,3 +586,76 @@ fn photondetection_json_schema() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Checks that a matrix is unitary up to numerical precision
+#[cfg(feature = "unstable_fock_matrix")]
+fn assert_unitary(matrix: &ndarray::Array2<num_complex::Complex64>) {
+    let product = matrix.t().mapv(|x| x.conj()).dot(matrix);
+    let identity: ndarray::Array2<num_complex::Complex64> = ndarray::Array2::eye(matrix.nrows());
+    for (value, expected) in product.iter().zip(identity.iter()) {
+        assert!((value - expected).norm() < 1e-10);
+    }
+}
+
+/// Test that the fock matrices of the single-mode gates are unitary
+#[cfg(feature = "unstable_fock_matrix")]
+#[test_case(SingleModeGateOperation::from(Squeezing::new(0, 0.3.into(), 0.7.into())); "Squeezing")]
+#[test_case(SingleModeGateOperation::from(PhaseDisplacement::new(0, 1.2.into(), 0.4.into())); "PhaseDisplacement")]
+#[test_case(SingleModeGateOperation::from(PhaseShift::new(0, 0.5.into())); "PhaseShift")]
+fn fock_matrix_single_mode_unitary(operation: SingleModeGateOperation) {
+    for cutoff in 1..5 {
+        let matrix = match &operation {
+            SingleModeGateOperation::Squeezing(op) => op.fock_matrix(cutoff),
+            SingleModeGateOperation::PhaseDisplacement(op) => op.fock_matrix(cutoff),
+            SingleModeGateOperation::PhaseShift(op) => op.fock_matrix(cutoff),
+            _ => panic!("Unexpected operation"),
+        }
+        .unwrap();
+        assert_eq!(matrix.dim(), (cutoff, cutoff));
+        assert_unitary(&matrix);
+    }
+}
+
+/// Test the fock matrix of the BeamSplitter
+#[cfg(feature = "unstable_fock_matrix")]
+#[test]
+fn fock_matrix_beam_splitter() {
+    use num_complex::Complex64;
+    let theta = 0.3_f64;
+    let phi = 0.2_f64;
+    let op = BeamSplitter::new(0, 1, theta.into(), phi.into());
+    for cutoff in 1..4 {
+        let matrix = op.fock_matrix(cutoff).unwrap();
+        assert_eq!(matrix.dim(), (cutoff * cutoff, cutoff * cutoff));
+        assert_unitary(&matrix);
+    }
+    // Basis |n_0, n_1> with index n_0 * 2 + n_1: |00>, |01>, |10>, |11>
+    let matrix = op.fock_matrix(2).unwrap();
+    let expected = [
+        ((0, 0), Complex64::new(1.0, 0.0)),
+        ((2, 2), Complex64::new(theta.cos(), 0.0)),
+        ((1, 2), Complex64::from_polar(theta.sin(), phi)),
+        ((1, 1), Complex64::new(theta.cos(), 0.0)),
+        ((2, 1), -Complex64::from_polar(theta.sin(), -phi)),
+        ((3, 3), Complex64::new(1.0, 0.0)),
+        ((0, 3), Complex64::new(0.0, 0.0)),
+    ];
+    for ((row, column), value) in expected {
+        assert!((matrix[[row, column]] - value).norm() < 1e-10);
+    }
+}
+
+/// Test that the fock matrix fails for a zero cutoff and symbolic parameters
+#[cfg(feature = "unstable_fock_matrix")]
+#[test]
+fn fock_matrix_errors() {
+    let op = PhaseShift::new(0, 0.5.into());
+    assert_eq!(
+        op.fock_matrix(0),
+        Err(RoqoqoError::GenericError {
+            msg: "Fock space cutoff must be at least 1".to_string()
+        })
+    );
+    let op = BeamSplitter::new(0, 1, "theta".into(), 0.2.into());
+    assert!(op.fock_matrix(2).is_err());
+}
@@ -143,6 +143,7 @@ fn involved_classical_all_qubits(operation: Operation, involved: InvolvedClassic
 #[test_case(Operation::from( InputSymbolic::new("a".to_string(), 20.0)), vec![("a", 0)]; "InputSymbolic")]
 #[test_case(Operation::from( InputBit::new("ro".to_string(), 2, false)), vec![("ro", 2)]; "InputBit")]
 #[test_case(Operation::from( PragmaConditional::new("ro".to_string(), 3, Circuit::new())), vec![("ro", 3)]; "PragmaConditional")]
+#[test_case(Operation::from( PragmaGetIndexedPauliProduct::new(HashMap::from([(0, 3)]), "ro".to_string(), 2, Circuit::new())), vec![("ro", 2)]; "PragmaGetIndexedPauliProduct")]
 
 fn involved_classical_set(operation: Operation, involved: Vec<(&str, usize)>) {
     let a: HashSet<(String, usize)> = involved
@@ -158,6 +159,8 @@ fn involved_classical_set(operation: Operation, involved: Vec<(&str, usize)>) {
 #[test_case(Operation::from( DefinitionUsize::new("out".to_string(), 20, true)), "out"; "DefinitionUsize")]
 #[test_case(Operation::from( PragmaGetStateVector::new("out".to_string(), None)), "out"; "PragmaGetStateVector")]
 #[test_case(Operation::from( PragmaGetDensityMatrix::new("out".to_string(), None)), "out"; "PragmaGetDensityMatrix")]
+#[test_case(Operation::from( PragmaGetReducedDensityMatrix::new("out".to_string(), vec![0], None)), "out"; "PragmaGetReducedDensityMatrix")]
+#[test_case(Operation::from( PragmaGetIndexedOccupationProbability::new("out".to_string(), 2, None)), "out"; "PragmaGetIndexedOccupationProbability")]
 fn involved_classical_all(operation: Operation, involved: &str) {
     assert_eq!(
         operation.involved_classical(),
@@ -165,6 +168,56 @@ fn involved_classical_all(operation: Operation, involved: &str) {
     );
 }
 
+/// Test that pragmas containing circuits include the entries of nested circuits
+#[test]
+fn involved_classical_nested_circuits() {
+    let mut innermost_circuit = Circuit::new();
+    innermost_circuit += MeasureQubit::new(2, "c".to_string(), 4);
+    let mut inner_circuit = Circuit::new();
+    inner_circuit += MeasureQubit::new(1, "b".to_string(), 1);
+    inner_circuit += PragmaConditional::new("b".to_string(), 1, innermost_circuit);
+    let mut circuit = Circuit::new();
+    circuit += MeasureQubit::new(0, "a".to_string(), 0);
+    circuit += PragmaLoop::new(CalculatorFloat::from(2), inner_circuit.clone());
+
+    let expected: HashSet<(String, usize)> = [("a", 0), ("b", 1), ("c", 4), ("ro", 3)]
+        .into_iter()
+        .map(|(s, i)| (s.to_string(), i))
+        .collect();
+    let conditional = PragmaConditional::new("ro".to_string(), 3, circuit.clone());
+    assert_eq!(
+        conditional.involved_classical(),
+        InvolvedClassical::Set(expected.clone())
+    );
+    let conditional_loop = PragmaConditionalLoop::new("ro".to_string(), 3, 5, circuit);
+    assert_eq!(
+        conditional_loop.involved_classical(),
+        InvolvedClassical::Set(expected)
+    );
+
+    let mut expected: HashSet<(String, usize)> = HashSet::new();
+    expected.insert(("b".to_string(), 1));
+    expected.insert(("c".to_string(), 4));
+    let pragma_loop = PragmaLoop::new(CalculatorFloat::from(2), inner_circuit.clone());
+    assert_eq!(
+        pragma_loop.involved_classical(),
+        InvolvedClassical::Set(expected)
+    );
+
+    // Completely involved registers of the inner circuit are passed on
+    inner_circuit += PragmaGetStateVector::new("state".to_string(), None);
+    let conditional = PragmaConditional::new("ro".to_string(), 3, inner_circuit.clone());
+    assert_eq!(
+        conditional.involved_classical(),
+        InvolvedClassical::All("state".to_string())
+    );
+    let pragma_loop = PragmaLoop::new(CalculatorFloat::from(2), inner_circuit);
+    assert_eq!(
+        pragma_loop.involved_classical(),
+        InvolvedClassical::All("state".to_string())
+    );
+}
+
 fn get_reordering() -> HashMap<usize, usize> {
     let mut map: HashMap<usize, usize> = HashMap::new();
     map.insert(1, 1);
@@ -14,14 +14,20 @@
 
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, Validator};
+use ndarray::{array, Array1, Array2};
+use num_complex::Complex64;
 use qoqo_calculator::{Calculator, CalculatorFloat};
 use roqoqo::operations::*;
-use roqoqo::Circuit;
+use roqoqo::registers::partial_trace;
+use roqoqo::{Circuit, RoqoqoError};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
 #[cfg(feature = "serialize")]
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::{HashMap, HashSet};
+use std::f64::consts::FRAC_1_SQRT_2;
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
 
 /// Test MeasureQubit inputs, involved qubits and OperateSingleQubit qubit
 #[test]
@@ -544,6 +550,311 @@ fn pragma_get_density_matrix_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test PragmaGetReducedDensityMatrix inputs and involved qubits
+#[test]
+fn pragma_get_reduced_density_matrix_inputs_qubits() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(3);
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![2, 0], Some(circuit));
+
+    // Test inputs are correct
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.qubits(), &vec![2, 0]);
+
+    // Test InvolveQubits trait
+    let qubits: HashSet<usize> = [0, 2, 3].into_iter().collect();
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+    let qubits: HashSet<usize> = [1].into_iter().collect();
+    assert_eq!(
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None).involved_qubits(),
+        InvolvedQubits::Set(qubits)
+    );
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::All(String::from("ro"))
+    );
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaGetReducedDensityMatrix standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_get_reduced_density_matrix_simple_traits() {
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None);
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaGetReducedDensityMatrix { readout: \"ro\", qubits: [1], circuit: None }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None);
+    let pragma_1 = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], None);
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaGetReducedDensityMatrix Operate trait
+#[test]
+fn pragma_get_reduced_density_matrix_operate_trait() {
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None);
+
+    // (1) Test tags function
+    let tags: &[&str; 4] = &[
+        "Operation",
+        "Measurement",
+        "PragmaOperation",
+        "PragmaGetReducedDensityMatrix",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaGetReducedDensityMatrix")
+    );
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaGetReducedDensityMatrix Substitute trait
+#[test]
+fn pragma_get_reduced_density_matrix_substitute_trait() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from("theta"));
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 1], Some(circuit));
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(0, CalculatorFloat::from(0.5));
+    let test_gate =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 1], Some(circuit_test));
+    assert_eq!(result, test_gate);
+
+    // (2) Remap qubits function, remapping the kept qubits and the circuit
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = test_gate.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(2, CalculatorFloat::from(0.5));
+    let test_gate =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![2, 1], Some(circuit_test));
+    assert_eq!(result, test_gate);
+}
+
+/// Test PragmaGetReducedDensityMatrix Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_reduced_density_matrix_serde_readable() {
+    let pragma_serialization =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None);
+
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaGetReducedDensityMatrix",
+                len: 3,
+            },
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("qubits"),
+            Token::Seq { len: Some(1) },
+            Token::U64(1),
+            Token::SeqEnd,
+            Token::Str("circuit"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaGetReducedDensityMatrix Serialization and Deserialization traits (compact)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_reduced_density_matrix_serde_compact() {
+    let pragma_serialization =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None);
+
+    assert_tokens(
+        &pragma_serialization.compact(),
+        &[
+            Token::Struct {
+                name: "PragmaGetReducedDensityMatrix",
+                len: 3,
+            },
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("qubits"),
+            Token::Seq { len: Some(1) },
+            Token::U64(1),
+            Token::SeqEnd,
+            Token::Str("circuit"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaGetReducedDensityMatrix JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_get_reduced_density_matrix_json_schema() {
+    let op = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1], None);
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaGetReducedDensityMatrix);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test that a PragmaGetDensityMatrix serialized with roqoqo 1.17 can be deserialized
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_density_matrix_deserialize_1_17() {
+    // Operation::PragmaGetDensityMatrix("ro", None) serialized with bincode by roqoqo 1.17
+    let serialized: [u8; 15] = [60, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 0];
+    let operation: Operation = bincode::deserialize(&serialized).unwrap();
+    let pragma = PragmaGetDensityMatrix::new(String::from("ro"), None);
+    assert_eq!(operation, Operation::from(pragma.clone()));
+    assert_eq!(
+        bincode::serialize(&Operation::from(pragma)).unwrap(),
+        serialized.to_vec()
+    );
+}
+
+/// Test that a Circuit with measurement pragmas serialized with roqoqo 1.17 can be deserialized
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_measurements_deserialize_1_17() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new(String::from("ro"), 16, true);
+    circuit += PragmaGetDensityMatrix::new(String::from("ro"), None);
+    circuit += PragmaGetOccupationProbability::new(String::from("ro"), None);
+    circuit +=
+        PragmaGetPauliProduct::new(HashMap::from([(0, 3)]), String::from("ro"), Circuit::new());
+    let deserialized: Circuit = bincode::deserialize(&CIRCUIT_PRAGMAS_1_17).unwrap();
+    assert_eq!(deserialized, circuit);
+    assert_eq!(
+        bincode::serialize(&circuit).unwrap(),
+        CIRCUIT_PRAGMAS_1_17.to_vec()
+    );
+}
+
+// Bincode serialization of a Circuit with measurement pragmas written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const CIRCUIT_PRAGMAS_1_17: [u8; 139] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 16, 0, 0, 0, 0, 0, 0, 0,
+    1, 3, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 0, 61, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 0, 0, 114, 111, 0, 62, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0
+];
+
+/// Test partial_trace for a product state and an entangled state
+#[test]
+fn partial_trace_reduced_density_matrix() {
+    // |psi> = |0>_0 |1>_1 |+>_2 in little endian ordering
+    let plus = array![
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(FRAC_1_SQRT_2, 0.0)
+    ];
+    let mut statevector: Array1<Complex64> = Array1::zeros(8);
+    for (index, amplitude) in plus.iter().enumerate() {
+        statevector[0b010 | (index << 2)] = *amplitude;
+    }
+    let rho = outer(&statevector);
+
+    let reduced = partial_trace(&rho, &[1], 3).unwrap();
+    let expected = array![
+        [Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]
+    ];
+    assert!(approx_equal(&reduced, &expected));
+
+    let reduced = partial_trace(&rho, &[2], 3).unwrap();
+    assert!(approx_equal(&reduced, &outer(&plus)));
+
+    // The order of the kept qubits determines the ordering of the reduced basis
+    let reduced = partial_trace(&rho, &[2, 1], 3).unwrap();
+    let mut expected_state: Array1<Complex64> = Array1::zeros(4);
+    expected_state[0b10] = plus[0];
+    expected_state[0b11] = plus[1];
+    assert!(approx_equal(&reduced, &outer(&expected_state)));
+
+    // Keeping all qubits returns the full density matrix
+    let reduced = partial_trace(&rho, &[0, 1, 2], 3).unwrap();
+    assert!(approx_equal(&reduced, &rho));
+
+    // Tracing out one qubit of a Bell pair gives the maximally mixed state
+    let mut bell: Array1<Complex64> = Array1::zeros(4);
+    bell[0b00] = Complex64::new(FRAC_1_SQRT_2, 0.0);
+    bell[0b11] = Complex64::new(FRAC_1_SQRT_2, 0.0);
+    let reduced = partial_trace(&outer(&bell), &[0], 2).unwrap();
+    let expected = array![
+        [Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(0.5, 0.0)]
+    ];
+    assert!(approx_equal(&reduced, &expected));
+}
+
+/// Test partial_trace errors
+#[test]
+fn partial_trace_errors() {
+    let rho: Array2<Complex64> = Array2::zeros((4, 4));
+    assert_eq!(
+        partial_trace(&rho, &[0], 3),
+        Err(RoqoqoError::MismatchedRegisterDimension {
+            dim: 16,
+            number_qubits: 3
+        })
+    );
+    assert!(matches!(
+        partial_trace(&rho, &[2], 2),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+    assert!(matches!(
+        partial_trace(&rho, &[1, 1], 2),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+}
+
+fn outer(vector: &Array1<Complex64>) -> Array2<Complex64> {
+    Array2::from_shape_fn((vector.len(), vector.len()), |(row, column)| {
+        vector[row] * vector[column].conj()
+    })
+}
+
+fn approx_equal(left: &Array2<Complex64>, right: &Array2<Complex64>) -> bool {
+    left.dim() == right.dim()
+        && left
+            .iter()
+            .zip(right.iter())
+            .all(|(l, r)| (l - r).norm() < 1e-10)
+}
+
 /// Test PragmaGetOccupationProbability inputs and involved qubits
 #[test]
 fn pragma_get_occupation_probability_inputs_qubits() {
@@ -950,6 +1261,30 @@ fn pragma_get_pauli_product_serde_compact() {
     );
 }
 
+/// Test that PragmaGetPauliProduct serializes identically independent of the HashMap ordering
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_pauli_product_serde_stable() {
+    let qubit_paulis: HashMap<usize, usize> = (0..16).map(|i| (i, i % 4)).collect();
+    // Same entries inserted in reverse into a map with a different hasher state and capacity
+    let mut qubit_paulis_reinserted: HashMap<usize, usize> = HashMap::with_capacity(64);
+    for i in (0..16).rev() {
+        qubit_paulis_reinserted.insert(i, i % 4);
+    }
+    let pragma = PragmaGetPauliProduct::new(qubit_paulis, String::from("ro"), Circuit::new());
+    let pragma_reinserted =
+        PragmaGetPauliProduct::new(qubit_paulis_reinserted, String::from("ro"), Circuit::new());
+
+    let json = serde_json::to_string(&pragma).unwrap();
+    assert_eq!(json, serde_json::to_string(&pragma_reinserted).unwrap());
+    assert_eq!(
+        bincode::serialize(&pragma).unwrap(),
+        bincode::serialize(&pragma_reinserted).unwrap()
+    );
+    let deserialized: PragmaGetPauliProduct = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, pragma_reinserted);
+}
+
 /// Test PragmaGetPauliProduct JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test]
@@ -976,153 +1311,775 @@ fn pragma_get_pauli_product_json_schema() {
     assert!(validation_result.is_ok());
 }
 
-/// Test PragmaRepeatedMeasurement inputs and involved qubits
+/// Test PragmaGetIndexedOccupationProbability inputs and involved qubits
 #[test]
-fn pragma_repeated_measurement_inputs_qubits() {
-    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping.insert(0, 1);
-    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+fn pragma_get_indexed_occupation_probability_inputs_qubits() {
+    let pragma = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, None);
 
     // Test inputs are correct
     assert_eq!(pragma.readout(), &String::from("ro"));
-    assert_eq!(pragma.qubit_mapping(), &Some(qubit_mapping));
-    assert_eq!(pragma.number_measurements(), &2_usize);
+    assert_eq!(pragma.readout_index(), &4);
+    assert_eq!(pragma.circuit(), &None);
 
     // Test InvolveQubits trait
     assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::All(String::from("ro"))
+    );
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
 }
 
-/// Test PragmaRepeatedMeasurement standard derived traits (Debug, Clone, PartialEq)
+/// Test PragmaGetIndexedOccupationProbability standard derived traits (Debug, Clone, PartialEq)
 #[test]
-fn pragma_repeated_measurement_simple_traits() {
-    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping.insert(0, 1);
-    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+fn pragma_get_indexed_occupation_probability_simple_traits() {
+    let pragma = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, None);
 
     // Test Debug trait
     assert_eq!(
         format!("{:?}", pragma),
-        "PragmaRepeatedMeasurement { readout: \"ro\", number_measurements: 2, qubit_mapping: Some({0: 1}) }"
+        "PragmaGetIndexedOccupationProbability { readout: \"ro\", readout_index: 4, circuit: None }"
     );
 
     // Test Clone trait
     assert_eq!(pragma.clone(), pragma);
 
     // Test PartialEq trait
-    let pragma_0 =
-        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
-    let pragma_1 =
-        PragmaRepeatedMeasurement::new(String::from("ro1"), 2, Some(qubit_mapping.clone()));
+    let pragma_0 = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, None);
+    let pragma_1 = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 0, None);
     assert!(pragma_0 == pragma);
     assert!(pragma == pragma_0);
     assert!(pragma_1 != pragma);
     assert!(pragma != pragma_1);
 }
 
-/// Test PragmaRepeatedMeasurement Operate trait
+/// Test PragmaGetIndexedOccupationProbability Operate trait
 #[test]
-fn pragma_repeated_measurement_operate_trait() {
-    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping.insert(0, 1);
-    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+fn pragma_get_indexed_occupation_probability_operate_trait() {
+    let pragma = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, None);
 
     // (1) Test tags function
     let tags: &[&str; 4] = &[
         "Operation",
         "Measurement",
         "PragmaOperation",
-        "PragmaRepeatedMeasurement",
+        "PragmaGetIndexedOccupationProbability",
     ];
     assert_eq!(pragma.tags(), tags);
 
     // (2) Test hqslang function
-    assert_eq!(pragma.hqslang(), String::from("PragmaRepeatedMeasurement"));
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaGetIndexedOccupationProbability")
+    );
 
     // (3) Test is_parametrized function
     assert!(!pragma.is_parametrized());
 }
 
-/// Test PragmaRepeatedMeasurement Substitute trait
+/// Test PragmaGetIndexedOccupationProbability Substitute trait
 #[test]
-fn pragma_repeated_measurement_substitute_trait() {
-    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping.insert(0, 1);
-    qubit_mapping.insert(1, 0);
-    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+fn pragma_get_indexed_occupation_probability_substitute_trait() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from("theta"));
+    let pragma = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, Some(circuit));
 
     // (1) Substitute parameters function
-    let pragma_test =
-        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
     let mut substitution_dict: Calculator = Calculator::new();
-    substitution_dict.set_variable("ro", 0.0);
-    let result = pragma_test
-        .substitute_parameters(&substitution_dict)
-        .unwrap();
-    assert_eq!(pragma, result);
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(0, CalculatorFloat::from(0.5));
+    let test_gate =
+        PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, Some(circuit_test));
+    assert_eq!(result, test_gate);
 
     // (2) Remap qubits function
-    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping_test.insert(0, 2);
-    qubit_mapping_test.insert(2, 0);
-    qubit_mapping_test.insert(3, 4);
-    qubit_mapping_test.insert(4, 5);
-    qubit_mapping_test.insert(5, 3);
-
-    let mut new_qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    new_qubit_mapping.insert(2, 1);
-    new_qubit_mapping.insert(1, 0);
-    new_qubit_mapping.insert(0, 2);
-    new_qubit_mapping.insert(3, 4);
-    new_qubit_mapping.insert(4, 5);
-    new_qubit_mapping.insert(5, 3);
-    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    let qubit_mapping_test: HashMap<usize, usize> = HashMap::from([(0, 2), (2, 0)]);
+    let result = test_gate.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(2, CalculatorFloat::from(0.5));
     let test_gate =
-        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(new_qubit_mapping.clone()));
+        PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, Some(circuit_test));
     assert_eq!(result, test_gate);
 }
 
-/// Test PragmaRepeatedMeasurement Serialization and Deserialization traits (readable)
+/// Test PragmaGetIndexedOccupationProbability Serialization and Deserialization traits (readable)
 #[cfg(feature = "serialize")]
 #[test]
-fn pragma_repeated_measurement_serde_readable() {
-    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping.insert(0, 1);
+fn pragma_get_indexed_occupation_probability_serde_readable() {
     let pragma_serialization =
-        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
-
+        PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, None);
     assert_tokens(
         &pragma_serialization.readable(),
         &[
             Token::Struct {
-                name: "PragmaRepeatedMeasurement",
+                name: "PragmaGetIndexedOccupationProbability",
                 len: 3,
             },
             Token::Str("readout"),
             Token::Str("ro"),
-            Token::Str("number_measurements"),
-            Token::U64(2),
-            Token::Str("qubit_mapping"),
-            Token::Some,
-            Token::Map { len: Some(1) },
-            Token::U64(0),
-            Token::U64(1),
-            Token::MapEnd,
+            Token::Str("readout_index"),
+            Token::U64(4),
+            Token::Str("circuit"),
+            Token::None,
             Token::StructEnd,
         ],
     );
 }
 
-/// Test PragmaRepeatedMeasurement Serialization and Deserialization traits (compact)
-#[cfg(feature = "serialize")]
+/// Test PragmaGetIndexedOccupationProbability JsonSchema trait
+#[cfg(feature = "json_schema")]
 #[test]
-fn pragma_repeated_measurement_serde_compact() {
-    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
-    qubit_mapping.insert(0, 1);
-    let pragma_serialization =
-        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+fn pragma_get_indexed_occupation_probability_json_schema() {
+    let op = PragmaGetIndexedOccupationProbability::new(String::from("ro"), 4, None);
 
-    assert_tokens(
-        &pragma_serialization.compact(),
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaGetIndexedOccupationProbability);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test PragmaGetIndexedPauliProduct inputs and involved qubits
+#[test]
+fn pragma_get_indexed_pauli_product_inputs_qubits() {
+    let qubit_paulis: HashMap<usize, usize> = HashMap::from([(0, 3)]);
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(1);
+    let pragma = PragmaGetIndexedPauliProduct::new(
+        qubit_paulis.clone(),
+        String::from("ro"),
+        2,
+        circuit.clone(),
+    );
+
+    // Test inputs are correct
+    assert_eq!(pragma.qubit_paulis(), &qubit_paulis);
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.readout_index(), &2);
+    assert_eq!(pragma.circuit(), &circuit);
+
+    // Test InvolveQubits trait
+    let qubits: HashSet<usize> = [0, 1].into_iter().collect();
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([(String::from("ro"), 2)]))
+    );
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaGetIndexedPauliProduct standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_get_indexed_pauli_product_simple_traits() {
+    let pragma = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        2,
+        Circuit::default(),
+    );
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaGetIndexedPauliProduct { qubit_paulis: {0: 1}, readout: \"ro\", readout_index: 2, circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        2,
+        Circuit::default(),
+    );
+    let pragma_1 = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        1,
+        Circuit::default(),
+    );
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaGetIndexedPauliProduct Operate trait
+#[test]
+fn pragma_get_indexed_pauli_product_operate_trait() {
+    let pragma = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        2,
+        Circuit::default(),
+    );
+
+    // (1) Test tags function
+    let tags: &[&str; 4] = &[
+        "Operation",
+        "Measurement",
+        "PragmaOperation",
+        "PragmaGetIndexedPauliProduct",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaGetIndexedPauliProduct")
+    );
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaGetIndexedPauliProduct Substitute trait
+#[test]
+fn pragma_get_indexed_pauli_product_substitute_trait() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from("theta"));
+    let pragma =
+        PragmaGetIndexedPauliProduct::new(HashMap::from([(0, 1)]), String::from("ro"), 2, circuit);
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(0, CalculatorFloat::from(0.5));
+    let test_gate = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        2,
+        circuit_test,
+    );
+    assert_eq!(result, test_gate);
+
+    // (2) Remap qubits function, remapping the Pauli product and the circuit
+    let qubit_mapping_test: HashMap<usize, usize> = HashMap::from([(0, 2), (2, 0)]);
+    let result = test_gate.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(2, CalculatorFloat::from(0.5));
+    let test_gate = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(2, 1)]),
+        String::from("ro"),
+        2,
+        circuit_test,
+    );
+    assert_eq!(result, test_gate);
+}
+
+/// Test PragmaGetIndexedPauliProduct Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_indexed_pauli_product_serde_readable() {
+    let pragma_serialization = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        2,
+        Circuit::default(),
+    );
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaGetIndexedPauliProduct",
+                len: 4,
+            },
+            Token::Str("qubit_paulis"),
+            Token::Map { len: Some(1) },
+            Token::U64(0),
+            Token::U64(1),
+            Token::MapEnd,
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("readout_index"),
+            Token::U64(2),
+            Token::Str("circuit"),
+            Token::Struct {
+                name: "Circuit",
+                len: 3,
+            },
+            Token::Str("definitions"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("operations"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("_roqoqo_version"),
+            Token::Struct {
+                name: "RoqoqoVersionSerializable",
+                len: 2,
+            },
+            Token::Str("major_version"),
+            Token::U32(1),
+            Token::Str("minor_version"),
+            Token::U32(0),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaGetIndexedPauliProduct JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_get_indexed_pauli_product_json_schema() {
+    let op = PragmaGetIndexedPauliProduct::new(
+        HashMap::from([(0, 1)]),
+        String::from("ro"),
+        2,
+        Circuit::default(),
+    );
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaGetIndexedPauliProduct);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Create a PragmaGetOperatorExpectation measuring `coefficient * Z_0 + X_1`
+fn create_pragma_get_operator_expectation(
+    coefficient: CalculatorFloat,
+    circuit: Circuit,
+) -> PragmaGetOperatorExpectation {
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(0), coefficient)
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().x(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    PragmaGetOperatorExpectation::new(operator, String::from("ro"), circuit)
+}
+
+/// Test PragmaGetOperatorExpectation inputs and involved qubits
+#[test]
+fn pragma_get_operator_expectation_inputs_qubits() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(0));
+    let pragma = create_pragma_get_operator_expectation(1.0.into(), circuit.clone());
+
+    // Test inputs are correct
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().x(1), 1.0.into())
+        .unwrap();
+    assert_eq!(pragma.operator(), &operator);
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.circuit(), &circuit);
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+    let mut classical: HashSet<(String, usize)> = HashSet::new();
+    classical.insert((String::from("ro"), 0));
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(classical)
+    );
+}
+
+/// Test PragmaGetOperatorExpectation standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_get_operator_expectation_simple_traits() {
+    let pragma = create_pragma_get_operator_expectation(1.0.into(), Circuit::default());
+
+    // Test Debug trait
+    assert!(format!("{:?}", pragma).starts_with("PragmaGetOperatorExpectation { operator: "));
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = create_pragma_get_operator_expectation(1.0.into(), Circuit::default());
+    let pragma_1 = create_pragma_get_operator_expectation(2.0.into(), Circuit::default());
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaGetOperatorExpectation Operate trait
+#[test]
+fn pragma_get_operator_expectation_operate_trait() {
+    let pragma = create_pragma_get_operator_expectation(1.0.into(), Circuit::default());
+
+    // (1) Test tags function
+    let tags: &[&str; 4] = &[
+        "Operation",
+        "Measurement",
+        "PragmaOperation",
+        "PragmaGetOperatorExpectation",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaGetOperatorExpectation")
+    );
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let pragma = create_pragma_get_operator_expectation("theta".into(), Circuit::default());
+    assert!(pragma.is_parametrized());
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, "theta".into()));
+    let pragma = create_pragma_get_operator_expectation(1.0.into(), circuit);
+    assert!(pragma.is_parametrized());
+}
+
+/// Test PragmaGetOperatorExpectation Substitute trait
+#[test]
+fn pragma_get_operator_expectation_substitute_trait() {
+    // (1) Substitute parameters function
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, "theta".into()));
+    let pragma = create_pragma_get_operator_expectation("theta".into(), circuit);
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, 0.5.into()));
+    let test_pragma = create_pragma_get_operator_expectation(0.5.into(), circuit);
+    assert_eq!(result, test_pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 2);
+    qubit_mapping.insert(2, 0);
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(0));
+    let pragma = create_pragma_get_operator_expectation(1.0.into(), circuit);
+    let result = pragma.remap_qubits(&qubit_mapping).unwrap();
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(2), 1.0.into())
+        .unwrap();
+    operator
+        .add_operator_product(PauliProduct::new().x(1), 1.0.into())
+        .unwrap();
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(2));
+    let test_pragma = PragmaGetOperatorExpectation::new(operator, String::from("ro"), circuit);
+    assert_eq!(result, test_pragma);
+
+    // (3) Remap qubits function with an invalid mapping
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 2);
+    assert!(pragma.remap_qubits(&qubit_mapping).is_err());
+}
+
+/// Test PragmaGetOperatorExpectation Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_operator_expectation_serde_readable() {
+    let mut operator = SpinHamiltonian::new();
+    operator
+        .add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    let pragma_serialization =
+        PragmaGetOperatorExpectation::new(operator, String::from("ro"), Circuit::default());
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaGetOperatorExpectation",
+                len: 3,
+            },
+            Token::Str("operator"),
+            Token::Struct {
+                name: "SpinHamiltonianSerialize",
+                len: 2,
+            },
+            Token::Str("items"),
+            Token::Seq { len: Some(1) },
+            Token::Tuple { len: 2 },
+            Token::Str("0Z"),
+            Token::F64(1.0),
+            Token::TupleEnd,
+            Token::SeqEnd,
+            Token::Str("_struqture_version"),
+            Token::Struct {
+                name: "StruqtureVersionSerializable",
+                len: 2,
+            },
+            Token::Str("major_version"),
+            Token::U32(1),
+            Token::Str("minor_version"),
+            Token::U32(0),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("circuit"),
+            Token::Struct {
+                name: "Circuit",
+                len: 3,
+            },
+            Token::Str("definitions"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("operations"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("_roqoqo_version"),
+            Token::Struct {
+                name: "RoqoqoVersionSerializable",
+                len: 2,
+            },
+            Token::Str("major_version"),
+            Token::U32(1),
+            Token::Str("minor_version"),
+            Token::U32(0),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaGetOperatorExpectation JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_get_operator_expectation_json_schema() {
+    let op = create_pragma_get_operator_expectation(1.0.into(), Circuit::default());
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaGetOperatorExpectation);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test PragmaRepeatedMeasurement inputs and involved qubits
+#[test]
+fn pragma_repeated_measurement_inputs_qubits() {
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+
+    // Test inputs are correct
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.qubit_mapping(), &Some(qubit_mapping));
+    assert_eq!(pragma.number_measurements(), &2_usize);
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+}
+
+/// Test PragmaRepeatedMeasurement standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_repeated_measurement_simple_traits() {
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaRepeatedMeasurement { readout: \"ro\", number_measurements: 2, qubit_mapping: Some({0: 1}) }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 =
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+    let pragma_1 =
+        PragmaRepeatedMeasurement::new(String::from("ro1"), 2, Some(qubit_mapping.clone()));
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaRepeatedMeasurement Operate trait
+#[test]
+fn pragma_repeated_measurement_operate_trait() {
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+
+    // (1) Test tags function
+    let tags: &[&str; 4] = &[
+        "Operation",
+        "Measurement",
+        "PragmaOperation",
+        "PragmaRepeatedMeasurement",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaRepeatedMeasurement"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaRepeatedMeasurement Substitute trait
+#[test]
+fn pragma_repeated_measurement_substitute_trait() {
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    qubit_mapping.insert(1, 0);
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+
+    // (1) Substitute parameters function
+    let pragma_test =
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma_test
+        .substitute_parameters(&substitution_dict)
+        .unwrap();
+    assert_eq!(pragma, result);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    qubit_mapping_test.insert(3, 4);
+    qubit_mapping_test.insert(4, 5);
+    qubit_mapping_test.insert(5, 3);
+
+    let mut new_qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    new_qubit_mapping.insert(2, 1);
+    new_qubit_mapping.insert(1, 0);
+    new_qubit_mapping.insert(0, 2);
+    new_qubit_mapping.insert(3, 4);
+    new_qubit_mapping.insert(4, 5);
+    new_qubit_mapping.insert(5, 3);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    let test_gate =
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(new_qubit_mapping.clone()));
+    assert_eq!(result, test_gate);
+
+    // (3) Remapping without qubit mapping
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, None);
+    assert_eq!(pragma.remap_qubits(&HashMap::new()).unwrap(), pragma);
+    let result = pragma.remap_qubits(&qubit_mapping).unwrap();
+    assert_eq!(
+        result,
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping))
+    );
+}
+
+/// Test PragmaRepeatedMeasurement constructor from qubit readout pairs and effective readout index
+#[test]
+fn pragma_repeated_measurement_qubit_readout_pairs() {
+    let pragma = PragmaRepeatedMeasurement::with_qubit_readout_pairs(
+        String::from("ro"),
+        2,
+        &[(0, 1), (2, 0)],
+    )
+    .unwrap();
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    qubit_mapping.insert(2, 0);
+    assert_eq!(
+        pragma,
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping))
+    );
+    assert_eq!(pragma.effective_readout_index(0), 1);
+    assert_eq!(pragma.effective_readout_index(2), 0);
+    assert_eq!(pragma.effective_readout_index(3), 3);
+
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, None);
+    assert_eq!(pragma.effective_readout_index(4), 4);
+}
+
+/// Test PragmaRepeatedMeasurement constructor errors for mappings that are not injective
+#[test]
+fn pragma_repeated_measurement_qubit_readout_pairs_error() {
+    let result = PragmaRepeatedMeasurement::with_qubit_readout_pairs(
+        String::from("ro"),
+        2,
+        &[(0, 1), (2, 1)],
+    );
+    assert_eq!(
+        result,
+        Err(RoqoqoError::GenericError {
+            msg: "Qubits 0 and 2 are both mapped to readout index 1".to_string()
+        })
+    );
+    let result = PragmaRepeatedMeasurement::with_qubit_readout_pairs(
+        String::from("ro"),
+        2,
+        &[(0, 1), (0, 2)],
+    );
+    assert_eq!(
+        result,
+        Err(RoqoqoError::GenericError {
+            msg: "Qubit 0 appears more than once in the qubit mapping".to_string()
+        })
+    );
+}
+
+/// Test that deserializing a PragmaRepeatedMeasurement with a duplicate readout index fails
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_repeated_measurement_deserialize_duplicate_index() {
+    let json = r#"{"readout":"ro","number_measurements":2,"qubit_mapping":{"0":1,"1":1}}"#;
+    let error = serde_json::from_str::<PragmaRepeatedMeasurement>(json).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Qubits 0 and 1 are both mapped to readout index 1"));
+    let json = r#"{"readout":"ro","number_measurements":2,"qubit_mapping":{"0":1,"1":0}}"#;
+    assert!(serde_json::from_str::<PragmaRepeatedMeasurement>(json).is_ok());
+}
+
+/// Test PragmaRepeatedMeasurement Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_repeated_measurement_serde_readable() {
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    let pragma_serialization =
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+
+    assert_tokens(
+        &pragma_serialization.readable(),
         &[
             Token::Struct {
                 name: "PragmaRepeatedMeasurement",
@@ -1143,6 +2100,69 @@ fn pragma_repeated_measurement_serde_compact() {
     );
 }
 
+/// Test PragmaRepeatedMeasurement Serialization and Deserialization traits (compact)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_repeated_measurement_serde_compact() {
+    let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping.insert(0, 1);
+    let pragma_serialization =
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping.clone()));
+
+    assert_tokens(
+        &pragma_serialization.compact(),
+        &[
+            Token::Struct {
+                name: "PragmaRepeatedMeasurement",
+                len: 3,
+            },
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("number_measurements"),
+            Token::U64(2),
+            Token::Str("qubit_mapping"),
+            Token::Some,
+            Token::Map { len: Some(1) },
+            Token::U64(0),
+            Token::U64(1),
+            Token::MapEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test that PragmaRepeatedMeasurement serializes identically independent of the HashMap ordering
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_repeated_measurement_serde_stable() {
+    let qubit_mapping: HashMap<usize, usize> = (0..16).map(|i| (i, 15 - i)).collect();
+    // Same entries inserted in reverse into a map with a different hasher state and capacity,
+    // with entries overwritten and additional entries removed again
+    let mut qubit_mapping_reinserted: HashMap<usize, usize> = HashMap::with_capacity(64);
+    for i in (0..20).rev() {
+        qubit_mapping_reinserted.insert(i, 0);
+    }
+    for i in 0..16 {
+        qubit_mapping_reinserted.insert(i, 15 - i);
+    }
+    for i in 16..20 {
+        qubit_mapping_reinserted.remove(&i);
+    }
+    let pragma = PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping));
+    let pragma_reinserted =
+        PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(qubit_mapping_reinserted));
+
+    let json = serde_json::to_string(&pragma).unwrap();
+    assert_eq!(json, serde_json::to_string(&pragma_reinserted).unwrap());
+    assert!(json.contains(r#""qubit_mapping":{"0":15,"1":14,"2":13,"#));
+    assert_eq!(
+        bincode::serialize(&pragma).unwrap(),
+        bincode::serialize(&pragma_reinserted).unwrap()
+    );
+    let deserialized: PragmaRepeatedMeasurement = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, pragma_reinserted);
+}
+
 /// Test PragmaRepeatedMeasurement JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test]
@@ -30,6 +30,9 @@ mod involved_classical;
 
 mod supported_version;
 
+#[cfg(feature = "serialize")]
+mod tagged_json;
+
 mod bosonic_operations;
 
 mod spin_boson_operations;
@@ -14,12 +14,13 @@
 
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, Validator};
-use ndarray::array;
+use ndarray::linalg::kron;
+use ndarray::{array, Array2};
 use num_complex::Complex64;
 use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations::*;
-use roqoqo::Circuit;
+use roqoqo::{Circuit, RoqoqoError};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
 use std::collections::{HashMap, HashSet};
@@ -572,15 +573,191 @@ fn test_rotatex_powercf_multi_qubit_zz(theta: CalculatorFloat, power: Calculator
     assert_eq!(power_gate.theta(), test_gate.theta());
 }
 
+#[test]
+fn test_new_multi_ms_with_couplings() {
+    let gate =
+        MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), 0.2.into(), 0.3.into()]);
+    assert!(gate.is_ok());
+    let gate = MultiQubitMSWithCouplings::new(vec![0, 1], vec![]);
+    assert_eq!(
+        gate,
+        Err(RoqoqoError::GenericError {
+            msg: "MultiQubitMSWithCouplings on 2 qubits requires 1 couplings, got 0".to_string()
+        })
+    );
+    let gate = MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), 0.2.into()]);
+    assert!(gate.is_err());
+}
+
+/// Test that the two qubit gate with couplings agrees with MultiQubitMS
+#[test]
+fn test_matrix_output_two_multi_ms_with_couplings() {
+    let gate =
+        MultiQubitMSWithCouplings::new(vec![0, 1], vec![CalculatorFloat::FRAC_PI_4]).unwrap();
+    let reference = MultiQubitMS::new(vec![0, 1], CalculatorFloat::FRAC_PI_4);
+    let difference = gate.unitary_matrix().unwrap() - reference.unitary_matrix().unwrap();
+    assert!(difference.iter().all(|x| x.norm() < 1e-12));
+}
+
+/// Test the three qubit unitary matrix against the product of the exponentiated XX terms
+#[test]
+fn test_matrix_output_three_multi_ms_with_couplings() {
+    let couplings = [0.3, -0.7, 1.1];
+    let gate = MultiQubitMSWithCouplings::new(
+        vec![0, 1, 2],
+        couplings
+            .iter()
+            .map(|c| CalculatorFloat::from(*c))
+            .collect(),
+    )
+    .unwrap();
+
+    let identity: Array2<Complex64> = Array2::eye(2);
+    let pauli_x: Array2<Complex64> = array![
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]
+    ];
+    // The first qubit is the most significant one
+    let xx_01 = kron(&kron(&pauli_x, &pauli_x), &identity);
+    let xx_02 = kron(&kron(&pauli_x, &identity), &pauli_x);
+    let xx_12 = kron(&kron(&identity, &pauli_x), &pauli_x);
+    let mut manual: Array2<Complex64> = Array2::eye(8);
+    for (xx, coupling) in [xx_01, xx_02, xx_12].iter().zip(couplings.iter()) {
+        // exp(-i θ/2 XX) = cos(θ/2) - i sin(θ/2) XX because XX squares to the identity
+        let exponential = Array2::<Complex64>::eye(8) * Complex64::new((coupling / 2.0).cos(), 0.0)
+            + xx * Complex64::new(0.0, -(coupling / 2.0).sin());
+        manual = manual.dot(&exponential);
+    }
+
+    let difference = gate.unitary_matrix().unwrap() - manual;
+    assert!(difference.iter().all(|x| x.norm() < 1e-12));
+}
+
+#[test]
+fn test_matrix_output_symbolic_multi_ms_with_couplings() {
+    let gate = MultiQubitMSWithCouplings::new(vec![0, 1], vec!["theta".into()]).unwrap();
+    assert!(gate.unitary_matrix().is_err());
+}
+
+#[test]
+fn test_circuit_three_multi_ms_with_couplings() {
+    let gate =
+        MultiQubitMSWithCouplings::new(vec![3, 1, 2], vec![0.1.into(), "theta".into(), 0.3.into()])
+            .unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(3);
+    circuit += Hadamard::new(1);
+    circuit += Hadamard::new(2);
+    circuit += CNOT::new(3, 1);
+    circuit += RotateZ::new(1, 0.1.into());
+    circuit += CNOT::new(3, 1);
+    circuit += CNOT::new(3, 2);
+    circuit += RotateZ::new(2, "theta".into());
+    circuit += CNOT::new(3, 2);
+    circuit += CNOT::new(1, 2);
+    circuit += RotateZ::new(2, 0.3.into());
+    circuit += CNOT::new(1, 2);
+    circuit += Hadamard::new(3);
+    circuit += Hadamard::new(1);
+    circuit += Hadamard::new(2);
+
+    assert_eq!(gate.circuit(), circuit);
+}
+
+#[test]
+fn test_operate_multi_ms_with_couplings() {
+    let gate =
+        MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), 0.2.into(), 0.3.into()])
+            .unwrap();
+    assert_eq!(gate.hqslang(), "MultiQubitMSWithCouplings");
+    assert_eq!(
+        gate.tags(),
+        &[
+            "Operation",
+            "GateOperation",
+            "MultiQubitGateOperation",
+            "MultiQubitMSWithCouplings",
+        ]
+    );
+    assert_eq!(gate.qubits(), &vec![0, 1, 2]);
+    assert_eq!(
+        gate.couplings(),
+        &vec![
+            CalculatorFloat::from(0.1),
+            CalculatorFloat::from(0.2),
+            CalculatorFloat::from(0.3)
+        ]
+    );
+    assert!(!gate.is_parametrized());
+    let gate1 =
+        MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), "theta".into(), 0.3.into()])
+            .unwrap();
+    assert!(gate1.is_parametrized());
+    assert_ne!(gate, gate1);
+    assert_eq!(gate1.clone(), gate1);
+    assert_eq!(gate.minimum_supported_roqoqo_version(), (1, 18, 0));
+
+    let mut comp_set: HashSet<usize> = HashSet::new();
+    let _ = comp_set.insert(0);
+    let _ = comp_set.insert(1);
+    let _ = comp_set.insert(2);
+    assert_eq!(gate.involved_qubits(), InvolvedQubits::Set(comp_set));
+}
+
+#[test]
+fn test_substitute_multi_ms_with_couplings() {
+    let gate1 = MultiQubitMSWithCouplings::new(
+        vec![0, 1, 2],
+        vec![0.1.into(), "theta".into(), "2 * theta".into()],
+    )
+    .unwrap();
+    let gate =
+        MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), 0.5.into(), 1.0.into()])
+            .unwrap();
+    let mut calc = Calculator::new();
+    calc.set_variable("theta", 0.5);
+    assert_eq!(gate1.substitute_parameters(&calc).unwrap(), gate);
+    assert!(gate1.substitute_parameters(&Calculator::new()).is_err());
+
+    let mut mapping: HashMap<usize, usize> = std::collections::HashMap::new();
+    let _ = mapping.insert(0, 1);
+    let _ = mapping.insert(1, 2);
+    let _ = mapping.insert(2, 0);
+    let remapped = gate1.remap_qubits(&mapping).unwrap();
+    assert_eq!(remapped.qubits(), &vec![1, 2, 0]);
+    assert_eq!(remapped.couplings(), gate1.couplings());
+
+    let _ = mapping.remove(&0);
+    assert!(gate1.remap_qubits(&mapping).is_err());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serde_multi_ms_with_couplings() {
+    let gate =
+        MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), "theta".into(), 0.3.into()])
+            .unwrap();
+    let operation = Operation::from(gate);
+    let serialized = serde_json::to_string(&operation).unwrap();
+    let deserialized: Operation = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, operation);
+    assert_eq!(deserialized.hqslang(), "MultiQubitMSWithCouplings");
+}
+
 /// Test JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test_case(MultiQubitGateOperation::from(MultiQubitZZ::new(vec![0, 1, 2, 3], 0.23.into())); "MultiQubitZZ")]
 #[test_case(MultiQubitGateOperation::from(MultiQubitMS::new(vec![0, 1, 2], 0.45.into())); "MultiQubitMS")]
+#[test_case(MultiQubitGateOperation::from(MultiQubitMSWithCouplings::new(vec![0, 1, 2], vec![0.1.into(), "theta".into(), 0.3.into()]).unwrap()); "MultiQubitMSWithCouplings")]
 pub fn test_json_schema_multi_qubit_gate_operations(gate: MultiQubitGateOperation) {
     // Serialize
     let test_json = match gate.clone() {
         MultiQubitGateOperation::MultiQubitMS(op) => serde_json::to_string(&op).unwrap(),
         MultiQubitGateOperation::MultiQubitZZ(op) => serde_json::to_string(&op).unwrap(),
+        MultiQubitGateOperation::MultiQubitMSWithCouplings(op) => {
+            serde_json::to_string(&op).unwrap()
+        }
         _ => unreachable!(),
     };
     let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
@@ -589,6 +766,9 @@ pub fn test_json_schema_multi_qubit_gate_operations(gate: MultiQubitGateOperatio
     let test_schema = match gate {
         MultiQubitGateOperation::MultiQubitMS(_) => schema_for!(MultiQubitMS),
         MultiQubitGateOperation::MultiQubitZZ(_) => schema_for!(MultiQubitZZ),
+        MultiQubitGateOperation::MultiQubitMSWithCouplings(_) => {
+            schema_for!(MultiQubitMSWithCouplings)
+        }
         _ => unreachable!(),
     };
     let schema = serde_json::to_string(&test_schema).unwrap();
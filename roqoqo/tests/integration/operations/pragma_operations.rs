@@ -19,7 +19,7 @@ use jsonschema::{Draft, Validator};
 use nalgebra::{matrix, Matrix4};
 use ndarray::{array, Array, Array1, Array2};
 use num_complex::Complex64;
-use qoqo_calculator::{Calculator, CalculatorFloat};
+use qoqo_calculator::{Calculator, CalculatorComplex, CalculatorFloat};
 use roqoqo::operations::*;
 use roqoqo::prelude::RoqoqoError;
 use roqoqo::Circuit;
@@ -28,6 +28,9 @@ use schemars::schema_for;
 #[cfg(feature = "serialize")]
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::{HashMap, HashSet};
+use std::f64::consts::FRAC_1_SQRT_2;
+use struqture::prelude::*;
+use struqture::spins::{PlusMinusLindbladNoiseOperator, PlusMinusProduct};
 use test_case::test_case;
 
 /// Test PragmaLoop inputs and involved qubits
@@ -381,6 +384,41 @@ fn pragma_set_statevector_inputs_qubits() {
     assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
 }
 
+/// Test validation of PragmaSetStateVector
+#[test]
+fn pragma_set_statevector_validate() {
+    let pragma = PragmaSetStateVector::new(array![
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(0.0, -FRAC_1_SQRT_2)
+    ]);
+    assert_eq!(pragma.validate(), Ok(()));
+
+    let pragma = PragmaSetStateVector::new(array![
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 0.0)
+    ]);
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::StateDimensionNotPowerOfTwo { dim: 3 })
+    );
+    let pragma = PragmaSetStateVector::new(Array1::zeros(0));
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::StateDimensionNotPowerOfTwo { dim: 0 })
+    );
+
+    let pragma =
+        PragmaSetStateVector::new(array![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)]);
+    let error = pragma.validate().unwrap_err();
+    assert!(
+        matches!(error, RoqoqoError::StateVectorNotNormalized { norm } if (norm - 2.0_f64.sqrt()).abs() < 1e-10)
+    );
+    assert!(error
+        .to_string()
+        .starts_with("Statevector is not normalized"));
+}
+
 /// Test PragmaSetStateVector standard derived traits (Debug, Clone, PartialEq)
 #[test]
 fn pragma_set_statevector_simple_traits() {
@@ -612,6 +650,59 @@ fn pragma_set_density_matrix_inputs_qubits() {
     assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
 }
 
+/// Test validation of PragmaSetDensityMatrix
+#[test]
+fn pragma_set_density_matrix_validate() {
+    let pragma = PragmaSetDensityMatrix::new(array![
+        [Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.5)],
+        [Complex64::new(0.0, -0.5), Complex64::new(0.5, 0.0)],
+    ]);
+    assert_eq!(pragma.validate(), Ok(()));
+
+    let pragma = PragmaSetDensityMatrix::new(Array2::zeros((2, 4)));
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::DensityMatrixNotSquare {
+            rows: 2,
+            columns: 4
+        })
+    );
+
+    let mut matrix: Array2<Complex64> = Array2::zeros((3, 3));
+    matrix[(0, 0)] = Complex64::new(1.0, 0.0);
+    let pragma = PragmaSetDensityMatrix::new(matrix);
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::StateDimensionNotPowerOfTwo { dim: 3 })
+    );
+
+    let pragma = PragmaSetDensityMatrix::new(array![
+        [Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.5)],
+        [Complex64::new(0.0, 0.5), Complex64::new(0.5, 0.0)],
+    ]);
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::DensityMatrixNotHermitian { row: 0, column: 1 })
+    );
+    let pragma = PragmaSetDensityMatrix::new(array![
+        [Complex64::new(0.5, 0.1), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(0.5, 0.0)],
+    ]);
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::DensityMatrixNotHermitian { row: 0, column: 0 })
+    );
+
+    let pragma = PragmaSetDensityMatrix::new(array![
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+    ]);
+    assert_eq!(
+        pragma.validate(),
+        Err(RoqoqoError::DensityMatrixTraceNotOne { trace: 2.0 })
+    );
+}
+
 /// Test PragmaSetDensityMatrix standard derived traits (Debug, Clone, PartialEq)
 #[test]
 fn pragma_set_density_matrix_simple_traits() {
@@ -1090,6 +1181,39 @@ fn pragma_overrotation_serde_compact() {
     );
 }
 
+/// Test PragmaOverrotation json serialization of values that are not finite
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_overrotation_serde_non_finite() {
+    let pragma = PragmaOverrotation::new("RotateX".to_string(), vec![0], f64::NAN, f64::INFINITY);
+    // The default serialization writes numbers, not finite values become null
+    let json = serde_json::to_string(&pragma).unwrap();
+    assert_eq!(
+        json,
+        r#"{"gate_hqslang":"RotateX","qubits":[0],"amplitude":null,"variance":null}"#
+    );
+    let json = Operation::from(pragma.clone()).to_json_lossy().unwrap();
+    assert_eq!(
+        json,
+        r#"{"PragmaOverrotation":{"gate_hqslang":"RotateX","qubits":[0],"amplitude":"NaN","variance":"Infinity"}}"#
+    );
+    let deserialized: Operation = serde_json::from_str(&json).unwrap();
+    let deserialized = PragmaOverrotation::try_from(deserialized).unwrap();
+    assert!(deserialized.amplitude().is_nan());
+    assert_eq!(deserialized.variance(), &f64::INFINITY);
+    // Finite values are written as numbers by the lossy serialization
+    let finite = PragmaOverrotation::new("RotateX".to_string(), vec![0], 0.5, 1.0);
+    assert_eq!(
+        Operation::from(finite.clone()).to_json_lossy().unwrap(),
+        serde_json::to_string(&Operation::from(finite)).unwrap()
+    );
+
+    // Binary serialization is unchanged
+    let bytes = bincode::serialize(&pragma).unwrap();
+    let deserialized: PragmaOverrotation = bincode::deserialize(&bytes).unwrap();
+    assert!(deserialized.amplitude().is_nan());
+}
+
 /// Test PragmaOverrotation JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test]
@@ -2019,6 +2143,34 @@ fn pragma_start_decomp_block_serde_compact() {
     );
 }
 
+/// Test that PragmaStartDecompositionBlock serializes identically independent of the HashMap ordering
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_start_decomp_block_serde_stable() {
+    let reordering: HashMap<usize, usize> = (0..16).map(|i| (i, i + 1)).collect();
+    // Same entries inserted in reverse into a map with a different hasher state and capacity,
+    // with additional entries removed again
+    let mut reordering_reinserted: HashMap<usize, usize> = HashMap::with_capacity(64);
+    for i in (0..20).rev() {
+        reordering_reinserted.insert(i, i + 1);
+    }
+    for i in 16..20 {
+        reordering_reinserted.remove(&i);
+    }
+    let pragma = PragmaStartDecompositionBlock::new(vec![0, 1], reordering);
+    let pragma_reinserted = PragmaStartDecompositionBlock::new(vec![0, 1], reordering_reinserted);
+
+    let json = serde_json::to_string(&pragma).unwrap();
+    assert_eq!(json, serde_json::to_string(&pragma_reinserted).unwrap());
+    assert!(json.contains(r#""reordering_dictionary":{"0":1,"1":2,"2":3,"#));
+    assert_eq!(
+        serialize(&pragma).unwrap(),
+        serialize(&pragma_reinserted).unwrap()
+    );
+    let deserialized: PragmaStartDecompositionBlock = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, pragma_reinserted);
+}
+
 /// Test PragmaStartDecompositionBlock JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test]
@@ -3201,6 +3353,193 @@ fn pragma_general_noise_pragmanoise_trait() {
     }
 }
 
+/// Test PragmaGeneralNoise from_t1_t2 against the composition of damping and dephasing
+#[test_case(10.0, 15.0; "t2 smaller than 2 t1")]
+#[test_case(10.0, 20.0; "t2 equal to 2 t1")]
+#[test_case(4.0, 1.5; "t2 smaller than t1")]
+fn pragma_general_noise_from_t1_t2(t1: f64, t2: f64) {
+    let gate_time = 0.7;
+    let pragma =
+        PragmaGeneralNoise::from_t1_t2(1, CalculatorFloat::from(gate_time), t1, t2).unwrap();
+    assert_eq!(pragma.qubit(), &1);
+    assert_eq!(pragma.gate_time(), &CalculatorFloat::from(gate_time));
+
+    let dephasing_rate = (1.0 / t2 - 1.0 / (2.0 * t1)) / 2.0;
+    let damping = PragmaDamping::new(
+        1,
+        CalculatorFloat::from(gate_time),
+        CalculatorFloat::from(1.0 / t1),
+    );
+    let dephasing = PragmaDephasing::new(
+        1,
+        CalculatorFloat::from(gate_time),
+        CalculatorFloat::from(dephasing_rate),
+    );
+    let composed: Array2<f64> = dephasing
+        .superoperator()
+        .unwrap()
+        .dot(&damping.superoperator().unwrap());
+    let result: Array2<f64> = composed - pragma.superoperator().unwrap();
+    for item in result.iter() {
+        assert!(item.abs() <= 1e-10);
+    }
+
+    assert!((pragma.effective_t1() - t1).abs() < 1e-10);
+    assert!((pragma.effective_t2() - t2).abs() < 1e-10);
+}
+
+/// Test PragmaGeneralNoise from_t1_t2 with invalid times
+#[test_case(10.0, 20.5; "t2 larger than 2 t1")]
+#[test_case(0.0, 1.0; "t1 zero")]
+#[test_case(1.0, -1.0; "t2 negative")]
+fn pragma_general_noise_from_t1_t2_error(t1: f64, t2: f64) {
+    let result = PragmaGeneralNoise::from_t1_t2(0, CalculatorFloat::from(0.1), t1, t2);
+    assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+}
+
+/// Test the round trip of PragmaGeneralNoise rates through a struqture Lindblad noise operator
+#[test_case(array![[0.3, 0.0, 0.0], [0.0, 0.2, 0.0], [0.0, 0.0, 0.1]]; "diagonal")]
+#[test_case(array![[0.3, 0.05, 0.0], [0.05, 0.2, -0.02], [0.0, -0.02, 0.1]]; "off diagonal")]
+fn pragma_general_noise_lindblad_operator(rates: Array2<f64>) {
+    let pragma = PragmaGeneralNoise::new(2, CalculatorFloat::from(0.005), rates.clone());
+    let operator = pragma.to_lindblad_operator();
+    let products = [
+        PlusMinusProduct::new().plus(2),
+        PlusMinusProduct::new().minus(2),
+        PlusMinusProduct::new().z(2),
+    ];
+    for ((i, j), rate) in rates.indexed_iter() {
+        assert_eq!(
+            operator.get(&(products[i].clone(), products[j].clone())),
+            &CalculatorComplex::from(*rate)
+        );
+    }
+    assert_eq!(
+        operator.len(),
+        rates.iter().filter(|rate| **rate != 0.0).count()
+    );
+    let converted =
+        PragmaGeneralNoise::from_lindblad_operator(2, CalculatorFloat::from(0.005), &operator)
+            .unwrap();
+    assert_eq!(converted, pragma);
+
+    // Terms of other qubits are ignored
+    let mut operator = operator;
+    operator
+        .add_operator_product(
+            (PlusMinusProduct::new().z(0), PlusMinusProduct::new().z(0)),
+            1.0.into(),
+        )
+        .unwrap();
+    let converted =
+        PragmaGeneralNoise::from_lindblad_operator(2, CalculatorFloat::from(0.005), &operator)
+            .unwrap();
+    assert_eq!(converted, pragma);
+    let converted =
+        PragmaGeneralNoise::from_lindblad_operator(1, CalculatorFloat::from(0.005), &operator)
+            .unwrap();
+    assert_eq!(converted.rates(), &Array2::<f64>::zeros((3, 3)));
+}
+
+/// Test from_lindblad_operator with noise terms that cannot be represented by a single qubit PRAGMA
+#[test]
+fn pragma_general_noise_from_lindblad_operator_error() {
+    let mut operator = PlusMinusLindbladNoiseOperator::new();
+    operator
+        .add_operator_product(
+            (
+                PlusMinusProduct::new().plus(0).z(1),
+                PlusMinusProduct::new().plus(0).z(1),
+            ),
+            0.1.into(),
+        )
+        .unwrap();
+    let result = PragmaGeneralNoise::from_lindblad_operator(0, 1.0.into(), &operator);
+    assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+
+    let mut operator = PlusMinusLindbladNoiseOperator::new();
+    operator
+        .add_operator_product(
+            (
+                PlusMinusProduct::new().plus(0),
+                PlusMinusProduct::new().z(1),
+            ),
+            0.1.into(),
+        )
+        .unwrap();
+    let result = PragmaGeneralNoise::from_lindblad_operator(1, 1.0.into(), &operator);
+    assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+
+    let mut operator = PlusMinusLindbladNoiseOperator::new();
+    operator
+        .add_operator_product(
+            (
+                PlusMinusProduct::new().plus(0),
+                PlusMinusProduct::new().plus(0),
+            ),
+            CalculatorComplex::new(0.1, 0.2),
+        )
+        .unwrap();
+    let result = PragmaGeneralNoise::from_lindblad_operator(0, 1.0.into(), &operator);
+    assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+}
+
+/// Test for_qubits of the noise PRAGMAs creating one operation per qubit
+#[test]
+fn pragma_noise_for_qubits() {
+    let qubits = [0, 2, 5];
+    let gate_time = CalculatorFloat::from(0.005);
+    let rate = CalculatorFloat::from("rate");
+    let rates: Array2<f64> = array![[0.3, 0.0, 0.0], [0.0, 0.2, 0.0], [0.0, 0.0, 0.1]];
+
+    let mut damping = Circuit::new();
+    let mut depolarising = Circuit::new();
+    let mut dephasing = Circuit::new();
+    let mut random_noise = Circuit::new();
+    let mut general_noise = Circuit::new();
+    for qubit in qubits {
+        damping += PragmaDamping::new(qubit, gate_time.clone(), rate.clone());
+        depolarising += PragmaDepolarising::new(qubit, gate_time.clone(), rate.clone());
+        dephasing += PragmaDephasing::new(qubit, gate_time.clone(), rate.clone());
+        random_noise += PragmaRandomNoise::new(qubit, gate_time.clone(), rate.clone(), 0.1.into());
+        general_noise += PragmaGeneralNoise::new(qubit, gate_time.clone(), rates.clone());
+    }
+    assert_eq!(
+        PragmaDamping::for_qubits(&qubits, gate_time.clone(), rate.clone()),
+        damping
+    );
+    assert_eq!(
+        PragmaDepolarising::for_qubits(&qubits, gate_time.clone(), rate.clone()),
+        depolarising
+    );
+    assert_eq!(
+        PragmaDephasing::for_qubits(&qubits, gate_time.clone(), rate.clone()),
+        dephasing
+    );
+    assert_eq!(
+        PragmaRandomNoise::for_qubits(&qubits, gate_time.clone(), rate.clone(), 0.1.into()),
+        random_noise
+    );
+    assert_eq!(
+        PragmaGeneralNoise::for_qubits(&qubits, gate_time.clone(), rates),
+        general_noise
+    );
+    assert!(PragmaDamping::for_qubits(&[], gate_time, rate).is_empty());
+}
+
+/// Test PragmaGeneralNoise effective T1 and T2 times
+#[test]
+fn pragma_general_noise_effective_times() {
+    let rates: Array2<f64> = array![[0.3, 0.0, 0.0], [0.0, 0.2, 0.0], [0.0, 0.0, 0.1]];
+    let pragma = PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005), rates);
+    assert!((pragma.effective_t1() - 2.0).abs() < 1e-10);
+    assert!((pragma.effective_t2() - 1.0 / 0.45).abs() < 1e-10);
+
+    let pragma = PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005), Array2::zeros((3, 3)));
+    assert!(pragma.effective_t1().is_infinite());
+    assert!(pragma.effective_t2().is_infinite());
+}
+
 /// Test PragmaGeneralNoise Serialization and Deserialization traits (readable)
 #[cfg(feature = "serialize")]
 #[test]
@@ -3299,6 +3638,34 @@ fn pragma_general_noise_serde_compact() {
     );
 }
 
+/// Test PragmaGeneralNoise json serialization of rates that are not finite
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_general_noise_serde_non_finite() {
+    let rates: Array2<f64> = array![[f64::NEG_INFINITY, 0.0], [0.0, 1.0]];
+    let pragma = PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005), rates.clone());
+    let json = serde_json::to_string(&pragma).unwrap();
+    assert_eq!(
+        json,
+        r#"{"qubit":0,"gate_time":0.005,"rates":{"v":1,"dim":[2,2],"data":[null,0.0,0.0,1.0]}}"#
+    );
+    let mut circuit = Circuit::new();
+    circuit += pragma.clone();
+    let json = circuit.to_json_lossy().unwrap();
+    assert!(json.contains(
+        r#"{"PragmaGeneralNoise":{"qubit":0,"gate_time":0.005,"rates":{"v":1,"dim":[2,2],"data":["-Infinity",0.0,0.0,1.0]}}}"#
+    ));
+    let deserialized: Circuit = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, circuit);
+
+    // Binary serialization is unchanged
+    let bytes = bincode::serialize(&pragma).unwrap();
+    assert_eq!(
+        bytes,
+        bincode::serialize(&(0_usize, CalculatorFloat::from(0.005), rates)).unwrap()
+    );
+}
+
 /// Test PragmaGeneralNoise JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test]
@@ -4120,3 +4487,957 @@ fn pragma_simulation_repetitions_json_schema() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Test PragmaSimulateShotNoise inputs and involved qubits
+#[test]
+fn pragma_simulate_shot_noise_inputs_qubits() {
+    let pragma = PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42));
+
+    // Test inputs are correct
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.number_shots(), &100_usize);
+    assert_eq!(pragma.seed(), &Some(42_u64));
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::AllQubits(String::from("ro"))
+    );
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaSimulateShotNoise standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_simulate_shot_noise_simple_traits() {
+    let pragma = PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42));
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSimulateShotNoise { readout: \"ro\", number_shots: 100, seed: Some(42) }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42));
+    let pragma_1 = PragmaSimulateShotNoise::new(String::from("ro"), 100, None);
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSimulateShotNoise Operate trait
+#[test]
+fn pragma_simulate_shot_noise_operate_trait() {
+    let pragma = PragmaSimulateShotNoise::new(String::from("ro"), 100, None);
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSimulateShotNoise"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaSimulateShotNoise"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaSimulateShotNoise Substitute trait
+#[test]
+fn pragma_simulate_shot_noise_substitute_trait() {
+    let pragma = PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42));
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaSimulateShotNoise Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_simulate_shot_noise_serde_readable() {
+    let pragma_serialization = PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42));
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSimulateShotNoise",
+                len: 3,
+            },
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("number_shots"),
+            Token::U64(100),
+            Token::Str("seed"),
+            Token::Some,
+            Token::U64(42),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSimulateShotNoise Serialization and Deserialization traits (compact)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_simulate_shot_noise_serde_compact() {
+    let pragma_serialization = PragmaSimulateShotNoise::new(String::from("ro"), 100, None);
+    assert_tokens(
+        &pragma_serialization.compact(),
+        &[
+            Token::Struct {
+                name: "PragmaSimulateShotNoise",
+                len: 3,
+            },
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("number_shots"),
+            Token::U64(100),
+            Token::Str("seed"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSimulateShotNoise JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_simulate_shot_noise_json_schema() {
+    let op = PragmaSimulateShotNoise::new(String::from("ro"), 100, Some(42));
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaSimulateShotNoise);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test PragmaSetGateTime inputs and involved qubits
+#[test]
+fn pragma_set_gate_time_inputs_qubits() {
+    let pragma = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+
+    // Test inputs are correct
+    assert_eq!(pragma.gate_hqslang(), &String::from("CNOT"));
+    assert_eq!(pragma.qubits(), &vec![0, 1]);
+    assert_eq!(pragma.gate_time(), &CalculatorFloat::from(300.0));
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaSetGateTime standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_set_gate_time_simple_traits() {
+    let pragma = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSetGateTime { gate_hqslang: \"CNOT\", qubits: [0, 1], gate_time: Float(300.0) }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+    let pragma_1 = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![1, 0],
+        CalculatorFloat::from(300.0),
+    );
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSetGateTime Operate trait
+#[test]
+fn pragma_set_gate_time_operate_trait() {
+    let pragma = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSetGateTime"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaSetGateTime"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let pragma_param =
+        PragmaSetGateTime::new(String::from("CNOT"), vec![0, 1], CalculatorFloat::from("t"));
+    assert!(pragma_param.is_parametrized());
+}
+
+/// Test PragmaSetGateTime Substitute trait
+#[test]
+fn pragma_set_gate_time_substitute_trait() {
+    let pragma = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+
+    // (1) Substitute parameters function
+    let pragma_test =
+        PragmaSetGateTime::new(String::from("CNOT"), vec![0, 1], CalculatorFloat::from("t"));
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("t", 300.0);
+    let result = pragma_test
+        .substitute_parameters(&substitution_dict)
+        .unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let pragma_test = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![2, 1],
+        CalculatorFloat::from(300.0),
+    );
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(2, 0);
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(1, 1);
+    let result = pragma_test.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaSetGateTime Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_set_gate_time_serde_readable() {
+    let pragma_serialization = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSetGateTime",
+                len: 3,
+            },
+            Token::Str("gate_hqslang"),
+            Token::Str("CNOT"),
+            Token::Str("qubits"),
+            Token::Seq { len: Some(2) },
+            Token::U64(0),
+            Token::U64(1),
+            Token::SeqEnd,
+            Token::Str("gate_time"),
+            Token::F64(300.0),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSetGateTime Serialization and Deserialization traits (compact)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_set_gate_time_serde_compact() {
+    let pragma_serialization = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+    assert_tokens(
+        &pragma_serialization.compact(),
+        &[
+            Token::Struct {
+                name: "PragmaSetGateTime",
+                len: 3,
+            },
+            Token::Str("gate_hqslang"),
+            Token::Str("CNOT"),
+            Token::Str("qubits"),
+            Token::Seq { len: Some(2) },
+            Token::U64(0),
+            Token::U64(1),
+            Token::SeqEnd,
+            Token::Str("gate_time"),
+            Token::NewtypeVariant {
+                name: "CalculatorFloat",
+                variant: "Float",
+            },
+            Token::F64(300.0),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSetGateTime JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_set_gate_time_json_schema() {
+    let op = PragmaSetGateTime::new(
+        String::from("CNOT"),
+        vec![0, 1],
+        CalculatorFloat::from(300.0),
+    );
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaSetGateTime);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+fn conditional_loop_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(1, String::from("flag"), 0);
+    circuit
+}
+
+/// Test PragmaConditionalLoop inputs and involved qubits
+#[test]
+fn pragma_conditional_loop_inputs_qubits() {
+    let circuit = conditional_loop_circuit();
+    let pragma = PragmaConditionalLoop::new(String::from("ro"), 1, 3, circuit.clone());
+
+    // Test inputs are correct
+    assert_eq!(pragma.condition_register(), &String::from("ro"));
+    assert_eq!(pragma.condition_index(), &1_usize);
+    assert_eq!(pragma.max_repetitions(), &3_usize);
+    assert_eq!(pragma.circuit(), &circuit);
+
+    // Test InvolveQubits trait
+    let mut qubits: HashSet<usize> = HashSet::new();
+    qubits.insert(0);
+    qubits.insert(1);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+    let mut classical: HashSet<(String, usize)> = HashSet::new();
+    classical.insert((String::from("ro"), 1));
+    classical.insert((String::from("flag"), 0));
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(classical)
+    );
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaConditionalLoop standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_conditional_loop_simple_traits() {
+    let pragma = PragmaConditionalLoop::new(String::from("ro"), 1, 3, Circuit::default());
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaConditionalLoop { condition_register: \"ro\", condition_index: 1, max_repetitions: 3, circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaConditionalLoop::new(String::from("ro"), 1, 3, Circuit::default());
+    let pragma_1 = PragmaConditionalLoop::new(String::from("ro"), 1, 4, Circuit::default());
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaConditionalLoop Operate trait
+#[test]
+fn pragma_conditional_loop_operate_trait() {
+    let pragma = PragmaConditionalLoop::new(String::from("ro"), 1, 3, Circuit::default());
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaConditionalLoop"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaConditionalLoop"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from("theta"));
+    let pragma_param = PragmaConditionalLoop::new(String::from("ro"), 1, 3, circuit);
+    assert!(pragma_param.is_parametrized());
+}
+
+/// Test PragmaConditionalLoop Substitute trait
+#[test]
+fn pragma_conditional_loop_substitute_trait() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, CalculatorFloat::from(1.0));
+    let pragma = PragmaConditionalLoop::new(String::from("ro"), 1, 3, circuit);
+
+    // (1) Substitute parameters function
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(0, CalculatorFloat::from("theta"));
+    let pragma_test = PragmaConditionalLoop::new(String::from("ro"), 1, 3, circuit_test);
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 1.0);
+    let result = pragma_test
+        .substitute_parameters(&substitution_dict)
+        .unwrap();
+    assert_eq!(result, pragma);
+    assert!(pragma_test
+        .substitute_parameters(&Calculator::new())
+        .is_err());
+
+    // (2) Remap qubits function
+    let mut circuit_test = Circuit::new();
+    circuit_test += RotateX::new(2, CalculatorFloat::from(1.0));
+    let pragma_test = PragmaConditionalLoop::new(String::from("ro"), 1, 3, circuit_test);
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(2, 0);
+    qubit_mapping_test.insert(0, 2);
+    let result = pragma_test.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaConditionalLoop unrolling to nested PragmaConditional operations
+#[test]
+fn pragma_conditional_loop_unroll() {
+    let circuit = conditional_loop_circuit();
+    let pragma = PragmaConditionalLoop::new(String::from("flag"), 0, 3, circuit.clone());
+
+    let mut innermost = Circuit::new();
+    innermost += circuit.clone();
+    let mut middle = circuit.clone();
+    middle += PragmaConditional::new(String::from("flag"), 0, innermost);
+    let mut expected = circuit.clone();
+    expected += PragmaConditional::new(String::from("flag"), 0, middle);
+    assert_eq!(pragma.unroll(), expected);
+
+    let single = PragmaConditionalLoop::new(String::from("flag"), 0, 1, circuit.clone());
+    assert_eq!(single.unroll(), circuit);
+    let empty = PragmaConditionalLoop::new(String::from("flag"), 0, 0, circuit);
+    assert_eq!(empty.unroll(), Circuit::new());
+}
+
+/// Test PragmaConditionalLoop Serialization and Deserialization traits
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_conditional_loop_serde() {
+    let pragma = PragmaConditionalLoop::new(String::from("flag"), 0, 3, conditional_loop_circuit());
+    let serialized = serde_json::to_string(&pragma).unwrap();
+    let deserialized: PragmaConditionalLoop = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, pragma);
+
+    let operation = Operation::from(pragma.clone());
+    let encoded = serialize(&operation).unwrap();
+    let decoded: Operation = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, operation);
+}
+
+/// Test PragmaConditionalLoop JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_conditional_loop_json_schema() {
+    let op = PragmaConditionalLoop::new(String::from("flag"), 0, 3, conditional_loop_circuit());
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaConditionalLoop);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test PragmaSnapshotRegisters inputs and involved qubits
+#[test]
+fn pragma_snapshot_registers_inputs_qubits() {
+    let pragma = PragmaSnapshotRegisters::new(
+        vec![String::from("ro"), String::from("rf")],
+        String::from("first"),
+    );
+
+    // Test inputs are correct
+    assert_eq!(
+        pragma.readout_names(),
+        &vec![String::from("ro"), String::from("rf")]
+    );
+    assert_eq!(pragma.label(), &String::from("first"));
+    assert_eq!(pragma.snapshot_name("ro"), String::from("first_ro"));
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+    assert_eq!(pragma.involved_classical(), InvolvedClassical::None);
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaSnapshotRegisters standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_snapshot_registers_simple_traits() {
+    let pragma = PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("first"));
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSnapshotRegisters { readout_names: [\"ro\"], label: \"first\" }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("first"));
+    let pragma_1 = PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("second"));
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSnapshotRegisters Operate and Substitute traits
+#[test]
+fn pragma_snapshot_registers_operate_substitute() {
+    let pragma = PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("first"));
+
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSnapshotRegisters"];
+    assert_eq!(pragma.tags(), tags);
+    assert_eq!(pragma.hqslang(), String::from("PragmaSnapshotRegisters"));
+    assert!(!pragma.is_parametrized());
+
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+
+    // Remapping the registers of a circuit renames the copied registers but not the label
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new(String::from("ro"), 1, true);
+    circuit += pragma;
+    let mapping: HashMap<String, String> = [(String::from("ro"), String::from("ro_a"))]
+        .into_iter()
+        .collect();
+    let remapped = circuit.remap_registers(&mapping).unwrap();
+    assert_eq!(
+        remapped.get(1),
+        Some(&Operation::from(PragmaSnapshotRegisters::new(
+            vec![String::from("ro_a")],
+            String::from("first")
+        )))
+    );
+}
+
+/// Test PragmaSnapshotRegisters Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_snapshot_registers_serde_readable() {
+    let pragma_serialization =
+        PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("first"));
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSnapshotRegisters",
+                len: 2,
+            },
+            Token::Str("readout_names"),
+            Token::Seq { len: Some(1) },
+            Token::Str("ro"),
+            Token::SeqEnd,
+            Token::Str("label"),
+            Token::Str("first"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSnapshotRegisters JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_snapshot_registers_json_schema() {
+    let op = PragmaSnapshotRegisters::new(vec![String::from("ro")], String::from("first"));
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaSnapshotRegisters);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test PragmaParallelBlockTiming inputs and involved qubits
+#[test]
+fn pragma_parallel_block_timing_inputs_qubits() {
+    let durations: HashMap<usize, CalculatorFloat> = HashMap::from([
+        (0, CalculatorFloat::from(100.0)),
+        (2, CalculatorFloat::from(300.0)),
+    ]);
+    let pragma = PragmaParallelBlockTiming::new(durations.clone());
+
+    // Test inputs are correct
+    assert_eq!(pragma.qubit_durations(), &durations);
+
+    // Test InvolveQubits trait
+    let mut qubits: HashSet<usize> = HashSet::new();
+    qubits.insert(0);
+    qubits.insert(2);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaParallelBlockTiming standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_parallel_block_timing_simple_traits() {
+    let pragma = PragmaParallelBlockTiming::new(HashMap::from([(0, CalculatorFloat::from(100.0))]));
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaParallelBlockTiming { qubit_durations: {0: Float(100.0)} }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 =
+        PragmaParallelBlockTiming::new(HashMap::from([(0, CalculatorFloat::from(100.0))]));
+    let pragma_1 =
+        PragmaParallelBlockTiming::new(HashMap::from([(1, CalculatorFloat::from(100.0))]));
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaParallelBlockTiming Operate trait
+#[test]
+fn pragma_parallel_block_timing_operate_trait() {
+    let pragma = PragmaParallelBlockTiming::new(HashMap::from([(0, CalculatorFloat::from(100.0))]));
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaParallelBlockTiming"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaParallelBlockTiming"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let pragma_param = PragmaParallelBlockTiming::new(HashMap::from([
+        (0, CalculatorFloat::from(100.0)),
+        (1, CalculatorFloat::from("t")),
+    ]));
+    assert!(pragma_param.is_parametrized());
+}
+
+/// Test PragmaParallelBlockTiming Substitute trait
+#[test]
+fn pragma_parallel_block_timing_substitute_trait() {
+    let pragma = PragmaParallelBlockTiming::new(HashMap::from([
+        (0, CalculatorFloat::from(100.0)),
+        (1, CalculatorFloat::from(300.0)),
+    ]));
+
+    // (1) Substitute parameters function
+    let pragma_test = PragmaParallelBlockTiming::new(HashMap::from([
+        (0, CalculatorFloat::from(100.0)),
+        (1, CalculatorFloat::from("3 * t")),
+    ]));
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("t", 100.0);
+    let result = pragma_test
+        .substitute_parameters(&substitution_dict)
+        .unwrap();
+    assert_eq!(result, pragma);
+    assert!(pragma_test
+        .substitute_parameters(&Calculator::new())
+        .is_err());
+
+    // (2) Remap qubits function
+    let pragma_test = PragmaParallelBlockTiming::new(HashMap::from([
+        (2, CalculatorFloat::from(100.0)),
+        (1, CalculatorFloat::from(300.0)),
+    ]));
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(2, 0);
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(1, 1);
+    let result = pragma_test.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaParallelBlockTiming Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_parallel_block_timing_serde_readable() {
+    let pragma_serialization = PragmaParallelBlockTiming::new(HashMap::from([
+        (2, CalculatorFloat::from(300.0)),
+        (0, CalculatorFloat::from(100.0)),
+    ]));
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaParallelBlockTiming",
+                len: 1,
+            },
+            Token::Str("qubit_durations"),
+            Token::Map { len: Some(2) },
+            Token::U64(0),
+            Token::F64(100.0),
+            Token::U64(2),
+            Token::F64(300.0),
+            Token::MapEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaParallelBlockTiming Serialization and Deserialization traits (compact)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_parallel_block_timing_serde_compact() {
+    let pragma_serialization =
+        PragmaParallelBlockTiming::new(HashMap::from([(0, CalculatorFloat::from("t"))]));
+    assert_tokens(
+        &pragma_serialization.compact(),
+        &[
+            Token::Struct {
+                name: "PragmaParallelBlockTiming",
+                len: 1,
+            },
+            Token::Str("qubit_durations"),
+            Token::Map { len: Some(1) },
+            Token::U64(0),
+            Token::NewtypeVariant {
+                name: "CalculatorFloat",
+                variant: "Str",
+            },
+            Token::Str("t"),
+            Token::MapEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaParallelBlockTiming JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_parallel_block_timing_json_schema() {
+    let op = PragmaParallelBlockTiming::new(HashMap::from([
+        (0, CalculatorFloat::from(100.0)),
+        (1, CalculatorFloat::from("t")),
+    ]));
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaParallelBlockTiming);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+/// Test PragmaParallelBlockTiming idle noise with a ContinuousDecoherenceModel
+#[test]
+fn pragma_parallel_block_timing_idle_noise_circuit() {
+    let model = roqoqo::noise_models::ContinuousDecoherenceModel::new()
+        .add_damping_rate(&[0, 1], 0.001)
+        .add_dephasing_rate(&[1], 0.002);
+    let pragma = PragmaParallelBlockTiming::new(HashMap::from([
+        (2, CalculatorFloat::from(50.0)),
+        (1, CalculatorFloat::from(300.0)),
+        (0, CalculatorFloat::from(100.0)),
+    ]));
+
+    let mut expected = Circuit::new();
+    expected += PragmaGeneralNoise::new(
+        0,
+        CalculatorFloat::from(100.0),
+        array![[0.001, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+    );
+    expected += PragmaGeneralNoise::new(
+        1,
+        CalculatorFloat::from(300.0),
+        array![[0.001, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.002]],
+    );
+    assert_eq!(pragma.idle_noise_circuit(&model).unwrap(), expected);
+
+    let no_noise = PragmaParallelBlockTiming::new(HashMap::from([(2, 50.0.into())]));
+    assert_eq!(no_noise.idle_noise_circuit(&model).unwrap(), Circuit::new());
+}
+
+/// Test PragmaAnnotation inputs and involved qubits
+#[test]
+fn pragma_annotation_inputs_qubits() {
+    let pragma = PragmaAnnotation::new(String::from("compiler"), String::from("1.0"));
+
+    // Test inputs are correct
+    assert_eq!(pragma.key(), &String::from("compiler"));
+    assert_eq!(pragma.value(), &String::from("1.0"));
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(pragma.involved_classical(), InvolvedClassical::None);
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+/// Test PragmaAnnotation standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_annotation_simple_traits() {
+    let pragma = PragmaAnnotation::new(String::from("compiler"), String::from("1.0"));
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaAnnotation { key: \"compiler\", value: \"1.0\" }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaAnnotation::new(String::from("compiler"), String::from("1.0"));
+    let pragma_1 = PragmaAnnotation::new(String::from("compiler"), String::from("1.1"));
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaAnnotation Operate and Substitute traits
+#[test]
+fn pragma_annotation_operate_substitute() {
+    let pragma = PragmaAnnotation::new(String::from("compiler"), String::from("theta"));
+
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaAnnotation"];
+    assert_eq!(pragma.tags(), tags);
+    assert_eq!(pragma.hqslang(), String::from("PragmaAnnotation"));
+    assert!(!pragma.is_parametrized());
+
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaAnnotation Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_annotation_serde_readable() {
+    let pragma_serialization = PragmaAnnotation::new(String::from("compiler"), String::from("1.0"));
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaAnnotation",
+                len: 2,
+            },
+            Token::Str("key"),
+            Token::Str("compiler"),
+            Token::Str("value"),
+            Token::Str("1.0"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaAnnotation JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_annotation_json_schema() {
+    let op = PragmaAnnotation::new(String::from("compiler"), String::from("1.0"));
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaAnnotation);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
@@ -1925,6 +1925,211 @@ fn test_general_multiplication(seed: u64) {
     }
 }
 
+/// Test SingleQubitGate from_unitary_matrix for random SU(2) matrices with a global phase
+#[test_case(0_u64; "seed0")]
+#[test_case(1_u64; "seed1")]
+#[test_case(2_u64; "seed2")]
+#[test_case(3_u64; "seed3")]
+#[test_case(4_u64; "seed4")]
+#[test_case(5_u64; "seed5")]
+#[test_case(6_u64; "seed6")]
+#[test_case(7_u64; "seed7")]
+#[test_case(8_u64; "seed8")]
+#[test_case(9_u64; "seed9")]
+fn test_from_unitary_matrix_random(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let angle_phi_alpha: f64 = rng.sample::<f64, _>(Standard) * 2.0 * PI;
+    let angle_phi_beta: f64 = rng.sample::<f64, _>(Standard) * 2.0 * PI;
+    let angle_theta: f64 = rng.sample::<f64, _>(Standard) * PI;
+    // Phase inside the interval (-π/2, π/2) chosen by from_unitary_matrix
+    let phase: f64 = (rng.sample::<f64, _>(Standard) - 0.5) * 0.99 * PI;
+
+    let gate = SingleQubitGate::new(
+        3,
+        (angle_theta.cos() * angle_phi_alpha.cos()).into(),
+        (angle_theta.cos() * angle_phi_alpha.sin()).into(),
+        (angle_theta.sin() * angle_phi_beta.cos()).into(),
+        (angle_theta.sin() * angle_phi_beta.sin()).into(),
+        phase.into(),
+    );
+    let matrix = gate.unitary_matrix().unwrap();
+
+    let new_gate = SingleQubitGate::from_unitary_matrix(&matrix, 3, 1e-10).unwrap();
+    assert_eq!(new_gate.qubit(), &3);
+    for (old, new) in [
+        (gate.alpha_r(), new_gate.alpha_r()),
+        (gate.alpha_i(), new_gate.alpha_i()),
+        (gate.beta_r(), new_gate.beta_r()),
+        (gate.beta_i(), new_gate.beta_i()),
+        (gate.global_phase(), new_gate.global_phase()),
+    ] {
+        assert!((f64::try_from(old).unwrap() - f64::try_from(new).unwrap()).abs() < 1e-9);
+    }
+
+    // Phases outside of (-π/2, π/2] give the same unitary matrix
+    let shifted_gate = SingleQubitGate::new(
+        3,
+        gate.alpha_r(),
+        gate.alpha_i(),
+        gate.beta_r(),
+        gate.beta_i(),
+        (phase + PI).into(),
+    );
+    let shifted_matrix = shifted_gate.unitary_matrix().unwrap();
+    let new_matrix = SingleQubitGate::from_unitary_matrix(&shifted_matrix, 3, 1e-10)
+        .unwrap()
+        .unitary_matrix()
+        .unwrap();
+    for (direct_val, new_val) in shifted_matrix.iter().zip(new_matrix.iter()) {
+        assert!((direct_val - new_val).norm() < 1e-9);
+    }
+}
+
+/// Test SingleQubitGate from_unitary_matrix for invalid matrices
+#[test]
+fn test_from_unitary_matrix_error() {
+    let not_unitary: Array2<Complex64> = ndarray::array![
+        [Complex64::new(1.0, 0.0), Complex64::new(0.5, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]
+    ];
+    let result = SingleQubitGate::from_unitary_matrix(&not_unitary, 0, 1e-6);
+    assert!(matches!(result, Err(UnitaryMatrixErrror { .. })));
+
+    let scaled: Array2<Complex64> = ndarray::array![
+        [Complex64::new(2.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(2.0, 0.0)]
+    ];
+    let result = SingleQubitGate::from_unitary_matrix(&scaled, 0, 1e-6);
+    assert!(matches!(result, Err(UnitaryMatrixErrror { norm, .. }) if (norm - 4.0).abs() < 1e-10));
+
+    let wrong_shape: Array2<Complex64> = Array2::zeros((4, 4));
+    let result = SingleQubitGate::from_unitary_matrix(&wrong_shape, 0, 1e-6);
+    assert!(matches!(result, Err(RoqoqoError::GenericError { .. })));
+}
+
+/// Returns the unitary matrix of a Circuit of single-qubit gates and global phases on one qubit
+fn single_qubit_circuit_unitary(circuit: &roqoqo::Circuit) -> Array2<Complex64> {
+    let mut unitary: Array2<Complex64> = Array2::eye(2);
+    for operation in circuit.iter() {
+        match operation {
+            Operation::PragmaGlobalPhase(op) => {
+                let phase = *op.phase().float().unwrap();
+                unitary *= Complex64::new(0.0, phase).exp();
+            }
+            _ => {
+                let gate = SingleQubitGateOperation::try_from(operation.clone()).unwrap();
+                unitary = gate.unitary_matrix().unwrap().dot(&unitary);
+            }
+        }
+    }
+    unitary
+}
+
+/// Test decomposition of constant single-qubit gates into native gate sets
+#[test_case(SingleQubitGateOperation::from(RotateX::new(0, CalculatorFloat::from(0.7))); "RotateX")]
+#[test_case(SingleQubitGateOperation::from(RotateY::new(0, CalculatorFloat::from(-2.1))); "RotateY")]
+#[test_case(SingleQubitGateOperation::from(RotateZ::new(0, CalculatorFloat::from(5.3))); "RotateZ")]
+#[test_case(SingleQubitGateOperation::from(PauliX::new(0)); "PauliX")]
+#[test_case(SingleQubitGateOperation::from(PauliY::new(0)); "PauliY")]
+#[test_case(SingleQubitGateOperation::from(PauliZ::new(0)); "PauliZ")]
+#[test_case(SingleQubitGateOperation::from(SqrtPauliX::new(0)); "SqrtPauliX")]
+#[test_case(SingleQubitGateOperation::from(InvSqrtPauliX::new(0)); "InvSqrtPauliX")]
+#[test_case(SingleQubitGateOperation::from(SqrtPauliY::new(0)); "SqrtPauliY")]
+#[test_case(SingleQubitGateOperation::from(InvSqrtPauliY::new(0)); "InvSqrtPauliY")]
+#[test_case(SingleQubitGateOperation::from(Hadamard::new(0)); "Hadamard")]
+#[test_case(SingleQubitGateOperation::from(SGate::new(0)); "SGate")]
+#[test_case(SingleQubitGateOperation::from(InvSGate::new(0)); "InvSGate")]
+#[test_case(SingleQubitGateOperation::from(TGate::new(0)); "TGate")]
+#[test_case(SingleQubitGateOperation::from(InvTGate::new(0)); "InvTGate")]
+#[test_case(SingleQubitGateOperation::from(SXGate::new(0)); "SXGate")]
+#[test_case(SingleQubitGateOperation::from(InvSXGate::new(0)); "InvSXGate")]
+#[test_case(SingleQubitGateOperation::from(Identity::new(0)); "Identity")]
+#[test_case(SingleQubitGateOperation::from(PhaseShiftState0::new(0, CalculatorFloat::from(1.2))); "PhaseShiftState0")]
+#[test_case(SingleQubitGateOperation::from(PhaseShiftState1::new(0, CalculatorFloat::from(-0.4))); "PhaseShiftState1")]
+#[test_case(SingleQubitGateOperation::from(GPi::new(0, CalculatorFloat::from(0.3))); "GPi")]
+#[test_case(SingleQubitGateOperation::from(GPi2::new(0, CalculatorFloat::from(2.5))); "GPi2")]
+#[test_case(SingleQubitGateOperation::from(RotateXY::new(0, CalculatorFloat::from(1.1), CalculatorFloat::from(0.2))); "RotateXY")]
+#[test_case(SingleQubitGateOperation::from(RotateAroundSphericalAxis::new(0, CalculatorFloat::from(0.9), CalculatorFloat::from(0.4), CalculatorFloat::from(1.7))); "RotateAroundSphericalAxis")]
+#[test_case(SingleQubitGateOperation::from(SingleQubitGate::new(0, CalculatorFloat::from(0.0), CalculatorFloat::from(0.6), CalculatorFloat::from(-0.8), CalculatorFloat::from(0.0), CalculatorFloat::from(3.0))); "SingleQubitGate")]
+fn test_single_qubit_gate_native_decomposition(gate: SingleQubitGateOperation) {
+    let unitary = gate.unitary_matrix().unwrap();
+    for basis in [
+        SingleQubitBasis::ZYZ,
+        SingleQubitBasis::ZXZ,
+        SingleQubitBasis::ZSX,
+    ] {
+        let circuit = gate.to_native_circuit(basis).unwrap();
+        for operation in circuit.iter() {
+            let allowed = match basis {
+                SingleQubitBasis::ZYZ => ["RotateZ", "RotateY", "PragmaGlobalPhase"],
+                SingleQubitBasis::ZXZ => ["RotateZ", "RotateX", "PragmaGlobalPhase"],
+                SingleQubitBasis::ZSX => ["RotateZ", "SqrtPauliX", "PragmaGlobalPhase"],
+            };
+            assert!(allowed.contains(&operation.hqslang()));
+        }
+        let reconstructed = single_qubit_circuit_unitary(&circuit);
+        for (original, new) in unitary.iter().zip(reconstructed.iter()) {
+            assert!((original - new).norm() < 1e-10);
+        }
+    }
+    assert_eq!(
+        gate.to_zyz_circuit().unwrap(),
+        gate.to_native_circuit(SingleQubitBasis::ZYZ).unwrap()
+    );
+}
+
+/// Test decomposition of random single-qubit gates into native gate sets
+#[test_case(0_u64; "seed0")]
+#[test_case(1_u64; "seed1")]
+#[test_case(2_u64; "seed2")]
+#[test_case(3_u64; "seed3")]
+#[test_case(4_u64; "seed4")]
+fn test_single_qubit_gate_native_decomposition_random(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let angle_phi_alpha: f64 = rng.sample::<f64, _>(Standard) * 2.0 * PI;
+    let angle_phi_beta: f64 = rng.sample::<f64, _>(Standard) * 2.0 * PI;
+    let angle_theta: f64 = rng.sample::<f64, _>(Standard) * PI;
+    let phase: f64 = rng.sample::<f64, _>(Standard) * 2.0 * PI;
+    let gate = SingleQubitGate::new(
+        2,
+        (angle_theta.cos() * angle_phi_alpha.cos()).into(),
+        (angle_theta.cos() * angle_phi_alpha.sin()).into(),
+        (angle_theta.sin() * angle_phi_beta.cos()).into(),
+        (angle_theta.sin() * angle_phi_beta.sin()).into(),
+        phase.into(),
+    );
+    let unitary = gate.unitary_matrix().unwrap();
+    for basis in [
+        SingleQubitBasis::ZYZ,
+        SingleQubitBasis::ZXZ,
+        SingleQubitBasis::ZSX,
+    ] {
+        let circuit = gate.to_native_circuit(basis).unwrap();
+        for operation in circuit.iter() {
+            assert!(operation.involved_qubits() != InvolvedQubits::All);
+            if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+                assert_eq!(qubits, HashSet::from([2]));
+            }
+        }
+        let reconstructed = single_qubit_circuit_unitary(&circuit);
+        for (original, new) in unitary.iter().zip(reconstructed.iter()) {
+            assert!((original - new).norm() < 1e-10);
+        }
+    }
+}
+
+/// Test decomposition of symbolic single-qubit gates
+#[test]
+fn test_single_qubit_gate_native_decomposition_symbolic() {
+    let gate = RotateX::new(0, CalculatorFloat::from("theta"));
+    assert!(matches!(gate.to_zyz_circuit(), Err(CalculatorError(_))));
+    assert!(matches!(
+        gate.to_native_circuit(SingleQubitBasis::ZSX),
+        Err(CalculatorError(_))
+    ));
+}
+
 /// Test SingleQubitGate multiplication for RotateXYZ
 #[test_case(
     SingleQubitGateOperation::from(RotateX::new(0, CalculatorFloat::from(0))),
@@ -1956,38 +2161,84 @@ fn test_rotatexyz_multiplication(
     }
 }
 
-/// Test that multiplication of two SingleQubitGateOperations returns a normalized SingleQubitGate
+/// Test that multiplication renormalizes small deviations of the norm and rejects non-unitary products
 #[test]
 fn test_singlequbitgate_mul_norm() {
-    let gate1 = SingleQubitGate::new(
+    let identity = SingleQubitGate::new(
         0,
         1.0.into(),
-        0.5.into(),
-        2.0.into(),
-        0.75.into(),
+        0.0.into(),
+        0.0.into(),
+        0.0.into(),
         0.0.into(),
     );
-    let gate2 = SingleQubitGate::new(
+    let scale = 1.0 + 1e-8;
+    let gate1 = SingleQubitGate::new(
         0,
-        1.0.into(),
+        (0.6 * scale).into(),
         0.0.into(),
+        (0.8 * scale).into(),
         0.0.into(),
         0.0.into(),
+    );
+    // The default tolerance renormalizes the product
+    let multiplied = gate1.mul(&identity).unwrap();
+    assert!((f64::try_from(multiplied.alpha_r()).unwrap() - 0.6).abs() < 1e-15);
+    assert!((f64::try_from(multiplied.beta_r()).unwrap() - 0.8).abs() < 1e-15);
+    // A larger tolerance keeps the product unchanged
+    let multiplied = gate1.mul_with_tolerance(&identity, 1e-7).unwrap();
+    assert_eq!(multiplied.alpha_r(), gate1.alpha_r());
+    assert_eq!(multiplied.beta_r(), gate1.beta_r());
+
+    let gate1 = SingleQubitGate::new(
+        0,
+        1.0.into(),
+        0.5.into(),
+        2.0.into(),
+        0.75.into(),
         0.0.into(),
     );
-    let result = gate1.mul(&gate2);
-    let multiplied = result.unwrap();
-    let alpha_r: f64 = f64::try_from(gate1.alpha_r()).unwrap();
-    let alpha_i: f64 = f64::try_from(gate1.alpha_i()).unwrap();
-    let beta_r: f64 = f64::try_from(gate1.beta_r()).unwrap();
-    let beta_i: f64 = f64::try_from(gate1.beta_i()).unwrap();
-    let norm: f64 =
-        (alpha_r.powf(2.0) + alpha_i.powf(2.0) + beta_r.powf(2.0) + beta_i.powf(2.0)).sqrt();
-    assert_eq!(multiplied.alpha_r(), gate1.alpha_r() / norm);
-    assert_eq!(multiplied.alpha_i(), gate1.alpha_i() / norm);
-    assert_eq!(multiplied.beta_r(), gate1.beta_r() / norm);
-    assert_eq!(multiplied.beta_i(), gate1.beta_i() / norm);
-    assert_eq!(multiplied.global_phase(), gate1.global_phase());
+    let norm: f64 = (1.0_f64 + 0.25 + 4.0 + 0.5625).sqrt();
+    assert_eq!(
+        gate1.mul(&identity),
+        Err(UnitaryMatrixErrror {
+            alpha_r: 1.0,
+            alpha_i: 0.5,
+            beta_r: 2.0,
+            beta_i: 0.75,
+            norm,
+        })
+    );
+    assert!(gate1.mul_with_tolerance(&identity, 10.0).is_err());
+}
+
+/// Test that a long product of small rotations stays unitary and matches the matrix product
+#[test]
+fn test_singlequbitgate_mul_long_chain() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut product = Identity::new(0).to_single_qubit_gate();
+    let mut matrix: Array2<Complex64> = Array2::eye(2).mapv(|x: f64| Complex64::new(x, 0.0));
+    for index in 0..10_000 {
+        let theta: f64 = rng.gen_range(-1e-3..1e-3);
+        let gate: SingleQubitGateOperation = match index % 3 {
+            0 => RotateX::new(0, theta.into()).into(),
+            1 => RotateY::new(0, theta.into()).into(),
+            _ => RotateZ::new(0, theta.into()).into(),
+        };
+        matrix = gate.unitary_matrix().unwrap().dot(&matrix);
+        product = gate.mul(&product).unwrap();
+
+        let alpha_r = f64::try_from(product.alpha_r()).unwrap();
+        let alpha_i = f64::try_from(product.alpha_i()).unwrap();
+        let beta_r = f64::try_from(product.beta_r()).unwrap();
+        let beta_i = f64::try_from(product.beta_i()).unwrap();
+        let norm = (alpha_r.powi(2) + alpha_i.powi(2) + beta_r.powi(2) + beta_i.powi(2)).sqrt();
+        assert!((norm - 1.0).abs() <= MUL_NORM_TOLERANCE);
+    }
+    let product_matrix = product.unitary_matrix().unwrap();
+    for (entry, expected) in product_matrix.iter().zip(matrix.iter()) {
+        assert!((entry - expected).norm() < 1e-10);
+    }
 }
 
 /// Test symbolic multiplication for SingleQubitGates
@@ -397,3 +397,38 @@ fn test_version_1_16_0_four_qubit_gate(operation: operations::FourQubitGateOpera
 fn test_version_1_17_0_pragmas(operation: operations::Operation) {
     assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 17, 0));
 }
+
+#[test_case(operations::Operation::from(operations::PragmaGetOperatorExpectation::new(struqture::spins::SpinHamiltonian::new(), "ro".into(), roqoqo::Circuit::new())); "PragmaGetOperatorExpectation")]
+#[test_case(operations::Operation::from(operations::PragmaGetReducedDensityMatrix::new("ro".into(), vec![0], None)); "PragmaGetReducedDensityMatrix")]
+#[test_case(operations::Operation::from(operations::PragmaGetIndexedOccupationProbability::new("ro".into(), 1, None)); "PragmaGetIndexedOccupationProbability")]
+#[test_case(operations::Operation::from(operations::PragmaGetIndexedPauliProduct::new(HashMap::from([(0, 3)]), "ro".into(), 1, roqoqo::Circuit::new())); "PragmaGetIndexedPauliProduct")]
+#[test_case(operations::Operation::from(operations::PragmaSimulateShotNoise::new("ro".into(), 100, Some(42))); "PragmaSimulateShotNoise")]
+#[test_case(operations::Operation::from(operations::PragmaSetGateTime::new("CNOT".into(), vec![0, 1], 300.0.into())); "PragmaSetGateTime")]
+#[test_case(operations::Operation::from(operations::PragmaParallelBlockTiming::new(std::collections::HashMap::from([(0, 300.0.into())]))); "PragmaParallelBlockTiming")]
+#[test_case(operations::Operation::from(operations::PragmaConditionalLoop::new("ro".into(), 0, 3, roqoqo::Circuit::new())); "PragmaConditionalLoop")]
+#[test_case(operations::Operation::from(operations::PragmaSnapshotRegisters::new(vec!["ro".into()], "first".into())); "PragmaSnapshotRegisters")]
+#[test_case(operations::Operation::from(operations::PragmaAnnotation::new("compiler".into(), "1.0".into())); "PragmaAnnotation")]
+fn test_version_1_18_0_pragmas(operation: operations::Operation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+#[test_case(operations::ThreeQubitGateOperation::from(operations::Fredkin::new(0, 1, 2)); "Fredkin")]
+fn test_version_1_18_0_three_qubit_gate(operation: operations::ThreeQubitGateOperation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+#[test]
+fn test_version_1_18_0_multi_qubit_gate() {
+    let operation = operations::MultiQubitGateOperation::from(
+        operations::MultiQubitMSWithCouplings::new(vec![0, 1], vec![CalculatorFloat::PI]).unwrap(),
+    );
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
+
+#[test]
+fn test_version_1_18_0_negative_control() {
+    let operation = operations::Operation::from(operations::NegativeControl::new(
+        operations::EchoCrossResonance::new(0, 1).into(),
+    ));
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 18, 0));
+}
@@ -0,0 +1,225 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the hqslang-tagged json serialization of operations
+
+use ndarray::array;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations;
+use roqoqo::prelude::*;
+use roqoqo::RoqoqoError;
+use std::collections::HashMap;
+use test_case::test_case;
+
+#[test_case(operations::Operation::from(operations::CNOT::new(1,0)); "CNOT")]
+#[test_case(operations::Operation::from(operations::SWAP::new(1,0)); "SWAP")]
+#[test_case(operations::Operation::from(operations::FSwap::new(1,0)); "FSwap")]
+#[test_case(operations::Operation::from(operations::ISwap::new(1,0)); "ISwap")]
+#[test_case(operations::Operation::from(operations::SqrtISwap::new(1,0)); "SqrtISwap")]
+#[test_case(operations::Operation::from(operations::InvSqrtISwap::new(1,0)); "InvSqrtISwap")]
+#[test_case(operations::Operation::from(operations::XY::new(1,0, 1.0.into())); "XY")]
+#[test_case(operations::Operation::from(operations::ControlledPauliY::new(1,0)); "ControlledPauliY")]
+#[test_case(operations::Operation::from(operations::ControlledPauliZ::new(1,0)); "ControlledPauliZ")]
+#[test_case(operations::Operation::from(operations::ControlledPhaseShift::new(1,0, 1.0.into())); "ControlledPhaseShift")]
+#[test_case(operations::Operation::from(operations::PMInteraction::new(1,0, 1.0.into())); "PMInteraction")]
+#[test_case(operations::Operation::from(operations::ComplexPMInteraction::new(1,0, 1.0.into(), 2.0.into())); "ComplexPMInteraction")]
+#[test_case(operations::Operation::from(operations::MolmerSorensenXX::new(1,0,)); "MolmerSorensenXX")]
+#[test_case(operations::Operation::from(operations::VariableMSXX::new(1,0, 1.0.into())); "VariableMSXX")]
+#[test_case(operations::Operation::from(operations::GivensRotation::new(1,0, 1.0.into(), 2.0.into())); "GivensRotation")]
+#[test_case(operations::Operation::from(operations::GivensRotationLittleEndian::new(1,0, 1.0.into(), 2.0.into())); "GivensRotationLittleEndian")]
+#[test_case(operations::Operation::from(operations::Qsim::new(1,0, 0.5.into(), 1.0.into(), 0.5.into())); "Qsim")]
+#[test_case(operations::Operation::from(operations::Fsim::new(1,0, 0.5.into(), 1.0.into(), 0.5.into())); "Fsim")]
+#[test_case(operations::Operation::from(operations::SpinInteraction::new(1,0, 1.0.into(), 2.0.into(), 3.0.into())); "SpinInteraction")]
+#[test_case(operations::Operation::from(operations::Bogoliubov::new(1,0, 1.0.into(), 2.0.into())); "Bogoliubov")]
+#[test_case(operations::Operation::from(operations::PhaseShiftedControlledZ::new(1,0, 3.0.into())); "PhaseShiftedControlledZ")]
+#[test_case(operations::Operation::from(operations::PhaseShiftedControlledPhase::new(1,0, 3.0.into(), 2.0.into())); "PhaseShiftedControlledPhase")]
+#[test_case(operations::Operation::from(operations::ControlledRotateX::new(0, 1, 0.1.into())); "ControlledRotateX")]
+#[test_case(operations::Operation::from(operations::ControlledRotateXY::new(0, 1, 0.1.into(), 0.2.into())); "ControlledRotateXY")]
+#[test_case(operations::Operation::from(operations::EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(operations::Operation::from(operations::NegativeControl::new(operations::CNOT::new(0, 1).into())); "NegativeControl")]
+#[test_case(operations::Operation::from(operations::ControlledControlledPauliZ::new(0, 1, 2)); "ControlledControlledPauliZ")]
+#[test_case(operations::Operation::from(operations::ControlledControlledPhaseShift::new(0, 1, 2, 0.1.into())); "ControlledControlledPhaseShift")]
+#[test_case(operations::Operation::from(operations::Toffoli::new(0, 1, 2)); "Toffoli")]
+#[test_case(operations::Operation::from(operations::Hadamard::new(0)); "Hadamard")]
+#[test_case(operations::Operation::from(operations::PauliX::new(0));"PauliX")]
+#[test_case(operations::Operation::from(operations::PauliY::new(0));"PauliY")]
+#[test_case(operations::Operation::from(operations::PauliZ::new(0));"PauliZ")]
+#[test_case(operations::Operation::from(operations::RotateX::new(0, 0.0.into()));"RotateX")]
+#[test_case(operations::Operation::from(operations::RotateY::new(0, 1.0.into()));"RotateY")]
+#[test_case(operations::Operation::from(operations::RotateZ::new(0, 2.0.into()));"RotateZ")]
+#[test_case(operations::Operation::from(operations::PhaseShiftState0::new(0, 3.0.into()));"PhaseShiftState0")]
+#[test_case(operations::Operation::from(operations::PhaseShiftState1::new(0, 4.0.into()));"PhaseShiftState1")]
+#[test_case(operations::Operation::from(operations::SGate::new(0)); "SGate")]
+#[test_case(operations::Operation::from(operations::TGate::new(0)); "TGate")]
+#[test_case(operations::Operation::from(operations::SqrtPauliX::new(0)); "SqrtPauliX")]
+#[test_case(operations::Operation::from(operations::InvSqrtPauliX::new(0)); "InvSqrtPauliX")]
+#[test_case(operations::Operation::from(operations::RotateAroundSphericalAxis::new(0, 1.0.into(), 0.5.into(), 1.0.into())); "RotateAroundSphericalAxis")]
+#[test_case(operations::Operation::from(operations::SingleQubitGate::new(0,0.5.into(),  0.5.into(), 0.5.into(), 0.5.into(), 0.5.into()));"SingleQubitGate")]
+#[test_case(operations::Operation::from(operations::GPi::new(0, 0.1.into()));"GPi")]
+#[test_case(operations::Operation::from(operations::GPi2::new(0, 0.1.into()));"GPi2")]
+#[test_case(operations::Operation::from(operations::MultiQubitMS::new(vec![0,1,2,3], 1.0.into())); "MultiQubitMS")]
+#[test_case(operations::Operation::from(operations::Squeezing::new(0, 1.0.into(), 0.0.into())); "Squeezing")]
+#[test_case(operations::Operation::from(operations::PhaseShift::new(0, 1.0.into())); "PhaseShift")]
+#[test_case(operations::Operation::from(operations::PhaseDisplacement::new(0, 1.0.into(), 0.1.into())); "PhaseDisplacement")]
+#[test_case(operations::Operation::from(operations::QuantumRabi::new(1, 0, 1.0.into()));"QuantumRabi")]
+#[test_case(operations::Operation::from(operations::LongitudinalCoupling::new(1, 0, 1.0.into()));"LongitudinalCoupling")]
+#[test_case(operations::Operation::from(operations::JaynesCummings::new(1, 0, 1.0.into()));"JaynesCummings")]
+#[test_case(operations::Operation::from(operations::SingleExcitationLoad::new(1, 0));"SingleExcitationLoad")]
+#[test_case(operations::Operation::from(operations::SingleExcitationStore::new(1, 0));"SingleExcitationStore")]
+#[test_case(operations::Operation::from(operations::CZQubitResonator::new(1, 0));"CZQubitResonator")]
+#[test_case(operations::Operation::from(operations::BeamSplitter::new(0, 1, 0.5.into(), 1.0.into())); "BeamSplitter")]
+#[test_case(operations::Operation::from(operations::PhotonDetection::new(0, "ro".into(), 1)); "PhotonDetection")]
+#[test_case(operations::Operation::from(operations::PragmaSetNumberOfMeasurements::new(3, "ro".into())); "PragmaSetNumberOfMeasurements")]
+#[test_case(operations::Operation::from(operations::PragmaRepeatGate::new(3)); "PragmaRepeatGate")]
+#[test_case(operations::Operation::from(operations::PragmaGeneralNoise::new(0, 1.0.into(),  array![[0.1, 0.0, 0.0],[0.0, 0.0, 0.0],[0.0, 0.0, 0.0]])); "PragmaGeneralNoise")]
+#[test_case(operations::Operation::from(operations::PragmaBoostNoise::new(0.5.into())); "PragmaBoostNoise")]
+#[test_case(operations::Operation::from(operations::PragmaStopParallelBlock::new(vec![0, 1], 0.5.into())); "PragmaStopParallelBlock")]
+#[test_case(operations::Operation::from(operations::PragmaGlobalPhase::new(0.5.into())); "PragmaGlobalPhase")]
+#[test_case(operations::Operation::from(operations::PragmaStartDecompositionBlock::new(vec![0, 1], HashMap::new())); "PragmaStartDecompositionBlock")]
+#[test_case(operations::Operation::from(operations::PragmaStopDecompositionBlock::new(vec![0, 1])); "PragmaStopDecompositionBlock")]
+#[test_case(operations::Operation::from(operations::DefinitionUsize::new("ro".into(), 2, false)); "DefinitionUsize")]
+#[test_case(operations::Operation::from(operations::InputSymbolic::new("ro".into(), 2.0)); "InputSymbolic")]
+#[test_case(operations::Operation::from(operations::PragmaDamping::new(0, 0.01.into(),  2.0.into())); "PragmaDamping001")]
+#[test_case(operations::Operation::from(operations::PragmaDephasing::new(0, 0.01.into(),  2.0.into())); "PragmaDephasing")]
+#[test_case(operations::Operation::from(operations::PragmaGetPauliProduct::new(HashMap::from([(0, 0)]), "ro".into(), roqoqo::Circuit::new(),)); "PragmaGetPauliProduct")]
+#[test_case(operations::Operation::from(operations::PragmaActiveReset::new(0)); "PragmaActiveReset")]
+#[test_case(operations::Operation::from(operations::PragmaSleep::new(vec![0],0.0.into())); "PragmaSleep")]
+#[test_case(operations::Operation::from(operations::PragmaRepeatedMeasurement::new( "ro".to_string(), 10, None)); "PragmaRepeatedMeasurement")]
+#[test_case(operations::Operation::from(operations::DefinitionBit::new("ro".into(), 2, false)); "DefinitionBit")]
+#[test_case(operations::Operation::from(operations::DefinitionFloat::new("ro".into(), 2, false)); "DefinitionFloat")]
+#[test_case(operations::Operation::from(operations::DefinitionComplex::new("ro".into(), 2, false)); "DefinitionComplex")]
+#[test_case(operations::Operation::from(operations::PragmaGetOccupationProbability::new("ro".into(), None)); "PragmaGetOccupationProbability")]
+#[test_case(operations::Operation::from(operations::PragmaLoop::new(10.into(), roqoqo::Circuit::new())); "PragmaLoop")]
+#[test_case(operations::Operation::from(operations::InputBit::new(String::from("test"), 1, false)); "InputBit")]
+#[test_case(operations::Operation::from(operations::PragmaControlledCircuit::new(10, roqoqo::Circuit::new())); "PragmaControlledCircuit")]
+#[test_case(operations::Operation::from(operations::PragmaAnnotatedOp::new(operations::PauliX::new(0).into(), "test".to_string())); "PragmaAnnotatedOp")]
+#[test_case(operations::Operation::from(operations::PragmaAnnotation::new("compiler".into(), "1.0".into())); "PragmaAnnotation")]
+#[test_case(operations::Operation::from(operations::SqrtPauliY::new(0)); "SqrtPauliY")]
+#[test_case(operations::Operation::from(operations::InvSqrtPauliY::new(0)); "InvSqrtPauliY")]
+#[test_case(operations::Operation::from(operations::InvTGate::new(0)); "InvTGate")]
+#[test_case(operations::Operation::from(operations::InvSGate::new(0)); "InvSGate")]
+#[test_case(operations::Operation::from(operations::SXGate::new(0)); "SXGate")]
+#[test_case(operations::Operation::from(operations::InvSXGate::new(0)); "InvSXGate")]
+#[test_case(operations::Operation::from(operations::ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(operations::Operation::from(operations::PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledZ")]
+#[test_case(operations::Operation::from(operations::PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::PI, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
+#[test_case(operations::Operation::from(operations::PragmaGetOperatorExpectation::new(struqture::spins::SpinHamiltonian::new(), "ro".into(), roqoqo::Circuit::new())); "PragmaGetOperatorExpectation")]
+#[test_case(operations::Operation::from(operations::PragmaGetIndexedOccupationProbability::new("ro".into(), 2, None)); "PragmaGetIndexedOccupationProbability")]
+#[test_case(operations::Operation::from(operations::PragmaGetIndexedPauliProduct::new(HashMap::from([(0, 3)]), "ro".into(), 2, roqoqo::Circuit::new())); "PragmaGetIndexedPauliProduct")]
+#[test_case(operations::Operation::from(operations::PragmaSetStateVector::new(array![Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.5)])); "PragmaSetStateVector")]
+#[test_case(operations::Operation::from(operations::PragmaSetDensityMatrix::new(array![[Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.5)], [Complex64::new(0.0, -0.5), Complex64::new(0.0, 0.0)]])); "PragmaSetDensityMatrix")]
+#[test_case(operations::Operation::from(operations::PragmaChangeDevice::from_operation(&operations::PragmaActiveReset::new(0)).unwrap()); "PragmaChangeDevice")]
+fn test_tagged_json_round_trip(operation: operations::Operation) {
+    let tagged_json = operation.to_tagged_json();
+    let value: serde_json::Value = serde_json::from_str(&tagged_json).unwrap();
+    assert_eq!(value["hqslang"], operation.hqslang());
+    assert_eq!(
+        operations::Operation::from_tagged_json(&tagged_json).unwrap(),
+        operation
+    );
+}
+
+#[test]
+fn test_tagged_json_format() {
+    let operation = operations::Operation::from(operations::RotateZ::new(0, 0.5.into()));
+    let value: serde_json::Value = serde_json::from_str(&operation.to_tagged_json()).unwrap();
+    let expected: serde_json::Value = serde_json::json!({
+        "hqslang": "RotateZ",
+        "qubit": 0,
+        "theta": 0.5
+    });
+    assert_eq!(value, expected);
+
+    let operation = operations::Operation::from(operations::CNOT::new(0, 1));
+    let value: serde_json::Value = serde_json::from_str(&operation.to_tagged_json()).unwrap();
+    let expected: serde_json::Value = serde_json::json!({
+        "hqslang": "CNOT",
+        "control": 0,
+        "target": 1
+    });
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn test_tagged_json_key_order_independent() {
+    let operation = operations::Operation::from_tagged_json(
+        r#"{"theta": 0.5, "qubit": 2, "hqslang": "RotateX"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        operation,
+        operations::Operation::from(operations::RotateX::new(2, 0.5.into()))
+    );
+}
+
+#[test]
+fn test_tagged_json_errors() {
+    let res = operations::Operation::from_tagged_json(r#"{"hqslang": "NotAGate", "qubit": 0}"#);
+    assert_eq!(
+        res,
+        Err(RoqoqoError::UnknownOperation {
+            hqslang: "NotAGate".to_string()
+        })
+    );
+
+    let res = operations::Operation::from_tagged_json(r#"{"qubit": 0, "theta": 0.5}"#);
+    assert!(matches!(res, Err(RoqoqoError::SerializationError { .. })));
+
+    let res = operations::Operation::from_tagged_json(r#"{"hqslang": "RotateZ", "qubit": 0}"#);
+    assert!(matches!(res, Err(RoqoqoError::SerializationError { .. })));
+
+    let res = operations::Operation::from_tagged_json("[1, 2]");
+    assert!(matches!(res, Err(RoqoqoError::SerializationError { .. })));
+}
+
+#[test]
+fn test_tagged_json_registry_covers_available_gates() {
+    for hqslang in operations::AVAILABLE_GATES_HQSLANG {
+        let res = operations::Operation::from_tagged_json(&format!(
+            "{{\"hqslang\": \"{}\", \"unknown_field\": []}}",
+            hqslang
+        ));
+        assert!(
+            matches!(res, Err(RoqoqoError::SerializationError { .. })),
+            "{} is missing from the tagged json registry",
+            hqslang
+        );
+    }
+}
+
+#[test]
+fn test_from_json_strict() {
+    let operation = operations::Operation::from(operations::RotateZ::new(0, 0.5.into()));
+    let json = serde_json::to_string(&operation).unwrap();
+    assert_eq!(
+        operations::Operation::from_json_strict(&json).unwrap(),
+        operation
+    );
+
+    let misspelled = r#"{"RotateZ": {"qubit": 0, "theta": 0.5, "thetaa": 1.0}}"#;
+    assert_eq!(
+        serde_json::from_str::<operations::Operation>(misspelled).unwrap(),
+        operation
+    );
+    assert_eq!(
+        operations::Operation::from_json_strict(misspelled),
+        Err(RoqoqoError::SerializationError {
+            msg: "Unknown fields in json input: $.RotateZ.thetaa".to_string()
+        })
+    );
+
+    let res = operations::Operation::from_json_strict(r#"{"RotateZ": {"qubit": 0}}"#);
+    assert!(matches!(res, Err(RoqoqoError::SerializationError { .. })));
+    let res = operations::Operation::from_json_strict("{");
+    assert!(matches!(res, Err(RoqoqoError::SerializationError { .. })));
+}
@@ -176,6 +176,93 @@ fn test_circuit_phaseshiftedcontrolledcontrolledphase() {
     assert_eq!(c, circuit);
 }
 
+// helper function returning the unitary of a circuit on qubits 0, 1 and 2, qubit 0 is the most significant qubit
+fn three_qubit_circuit_unitary(circuit: &Circuit) -> Array2<Complex64> {
+    let bit = |index: usize, qubit: usize| (index >> (2 - qubit)) & 1;
+    let mut unitary: Array2<Complex64> = Array2::eye(8);
+    for operation in circuit.iter() {
+        let (qubits, matrix): (Vec<usize>, Array2<Complex64>) =
+            match SingleQubitGateOperation::try_from(operation.clone()) {
+                Ok(gate) => (vec![*gate.qubit()], gate.unitary_matrix().unwrap()),
+                Err(_) => {
+                    let gate = TwoQubitGateOperation::try_from(operation.clone()).unwrap();
+                    (
+                        vec![*gate.control(), *gate.target()],
+                        gate.unitary_matrix().unwrap(),
+                    )
+                }
+            };
+        let local_index = |index: usize| {
+            qubits
+                .iter()
+                .fold(0, |local, qubit| 2 * local + bit(index, *qubit))
+        };
+        let full = Array2::from_shape_fn((8, 8), |(i, j)| {
+            if (0..3)
+                .filter(|qubit| !qubits.contains(qubit))
+                .all(|qubit| bit(i, qubit) == bit(j, qubit))
+            {
+                matrix[(local_index(i), local_index(j))]
+            } else {
+                Complex64::new(0.0, 0.0)
+            }
+        });
+        unitary = full.dot(&unitary);
+    }
+    unitary
+}
+
+//
+// Test decomposition of ThreeQubit Gates into CNOT, ControlledPauliZ and ISwap bases
+//
+#[test_case(ThreeQubitGateOperation::from(ControlledControlledPauliZ::new(0, 1, 2)); "ControlledControlledPauliZ")]
+#[test_case(ThreeQubitGateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
+#[test_case(ThreeQubitGateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
+#[test_case(ThreeQubitGateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(ThreeQubitGateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
+#[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
+#[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::from(0.7), CalculatorFloat::from(-0.4))); "PhaseShiftedControlledControlledPhase")]
+fn test_three_qubit_gate_circuit_with_basis(gate: ThreeQubitGateOperation) {
+    let expected_unitary = gate.unitary_matrix().unwrap();
+    let number_cnots = gate
+        .circuit_with_basis(TwoQubitBasis::CNOT)
+        .iter()
+        .filter(|operation| operation.hqslang() == "CNOT")
+        .count();
+    assert!(number_cnots > 0);
+    for (basis, entangler, number_entanglers) in [
+        (TwoQubitBasis::CNOT, "CNOT", number_cnots),
+        (
+            TwoQubitBasis::ControlledPauliZ,
+            "ControlledPauliZ",
+            number_cnots,
+        ),
+        (TwoQubitBasis::ISwap, "ISwap", 2 * number_cnots),
+    ] {
+        let circuit = gate.circuit_with_basis(basis);
+        let mut entanglers = 0;
+        for operation in circuit.iter() {
+            if TwoQubitGateOperation::try_from(operation.clone()).is_ok() {
+                assert_eq!(operation.hqslang(), entangler);
+                entanglers += 1;
+            } else {
+                assert!(SingleQubitGateOperation::try_from(operation.clone()).is_ok());
+            }
+        }
+        assert_eq!(entanglers, number_entanglers);
+        // The decomposition reproduces the unitary matrix up to a global phase
+        let decomposed = three_qubit_circuit_unitary(&circuit);
+        let phase = (0..8)
+            .map(|i| decomposed[(i, i)] * expected_unitary[(i, i)].conj())
+            .find(|product| product.norm() > 1e-6)
+            .map(|product| product / product.norm())
+            .unwrap_or_else(|| decomposed[(0, 7)] * expected_unitary[(0, 7)].conj());
+        for (original, new) in expected_unitary.iter().zip(decomposed.iter()) {
+            assert!((original * phase - new).norm() < 1e-10);
+        }
+    }
+}
+
 //
 // Test Unitary Matrix for ThreeQubit Gates
 //
@@ -185,6 +272,7 @@ fn test_circuit_phaseshiftedcontrolledcontrolledphase() {
 #[test_case(GateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(GateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(GateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(GateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(GateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(GateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_three_qubit_gate_unitarity(gate: GateOperation) {
@@ -211,6 +299,7 @@ fn test_three_qubit_gate_unitarity(gate: GateOperation) {
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_threequbitgates_clone(gate1: Operation) {
@@ -223,6 +312,7 @@ fn test_threequbitgates_clone(gate1: Operation) {
 #[test_case(ThreeQubitGateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(ThreeQubitGateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(ThreeQubitGateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(ThreeQubitGateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(ThreeQubitGateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_qubits_threequbitgates(gate: ThreeQubitGateOperation) {
@@ -244,6 +334,7 @@ fn test_qubits_threequbitgates(gate: ThreeQubitGateOperation) {
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_is_parametrized_false(gate: Operation) {
@@ -263,6 +354,7 @@ fn test_is_parametrized_true(gate: Operation) {
 #[test_case("ControlledControlledPhaseShift", Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case("Toffoli", Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case("ControlledSWAP", Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case("Fredkin", Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case("PhaseShiftedControlledControlledZ", Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case("PhaseShiftedControlledControlledPhase", Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_threequbitgateoperations_hqslang(name: &'static str, gate: Operation) {
@@ -282,6 +374,9 @@ fn test_threequbitgateoperations_hqslang(name: &'static str, gate: Operation) {
 #[test_case(
     GateOperation::from(ControlledSWAP::new(0, 1, 2)),
     GateOperation::from(ControlledSWAP::new(1, 2, 0)); "ControlledSWAP")]
+#[test_case(
+    GateOperation::from(Fredkin::new(0, 1, 2)),
+    GateOperation::from(Fredkin::new(1, 2, 0)); "Fredkin")]
 #[test_case(
     GateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)),
     GateOperation::from(PhaseShiftedControlledControlledZ::new(1, 2, 0, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
@@ -301,6 +396,7 @@ fn remap_qubits_result(gate: GateOperation, test_gate: GateOperation) {
 #[test_case(GateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(GateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(GateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(GateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(GateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(GateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn remap_qubits_error0(gate: GateOperation) {
@@ -314,6 +410,7 @@ fn remap_qubits_error0(gate: GateOperation) {
 #[test_case(GateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(GateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(GateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(GateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(GateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(GateOperation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn remap_qubits_error1(gate: GateOperation) {
@@ -356,6 +453,14 @@ fn remap_qubits_error1(gate: GateOperation) {
         "ControlledSWAP",
         ],
     Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "ThreeQubitGateOperation",
+        "Fredkin",
+        ],
+    Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(
     vec![
         "Operation",
@@ -391,6 +496,9 @@ pub fn test_tags(tags: Vec<&str>, gate: Operation) {
 #[test_case(
     "ControlledSWAP(ControlledSWAP { control: 1, target_0: 0, target_1: 2 })",
     Operation::from(ControlledSWAP::new(1, 0, 2)); "ControlledSWAP")]
+#[test_case(
+    "Fredkin(Fredkin { control: 1, target_0: 0, target_1: 2 })",
+    Operation::from(Fredkin::new(1, 0, 2)); "Fredkin")]
 #[test_case(
     "PhaseShiftedControlledControlledZ(PhaseShiftedControlledControlledZ { control_0: 1, control_1: 0, target: 2, phi: Float(3.141592653589793) })",
     Operation::from(PhaseShiftedControlledControlledZ::new(1, 0, 2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledZ")]
@@ -413,6 +521,9 @@ fn test_three_qubitgates_debug(message: &'static str, gate: Operation) {
 #[test_case(
     Operation::from(ControlledSWAP::new(0, 1, 2)),
     Operation::from(ControlledSWAP::new(1, 2, 0)); "ControlledSWAP")]
+#[test_case(
+    Operation::from(Fredkin::new(0, 1, 2)),
+    Operation::from(Fredkin::new(1, 2, 0)); "Fredkin")]
 #[test_case(
     Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::PI)),
     Operation::from(PhaseShiftedControlledControlledZ::new(1, 2, 0, CalculatorFloat::PI)); "PhaseShiftedControlledControlledZ")]
@@ -442,6 +553,7 @@ fn test_rotate_powercf(gate: Rotation, gate2: Rotation) {
 #[test_case(Operation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(Operation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSwap")]
+#[test_case(Operation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::FRAC_PI_2)); "PhaseShiftedControlledControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledControlledPhase::new(0, 1, 2, CalculatorFloat::FRAC_PI_2, CalculatorFloat::PI)); "PhaseShiftedControlledControlledPhase")]
 fn test_ineffective_substitute_parameters(gate: Operation) {
@@ -513,6 +625,29 @@ fn test_inputs_cswap() {
     assert_eq!(gate.target(), &2);
 }
 
+#[test]
+fn test_inputs_fredkin() {
+    let gate = Fredkin::new(0, 1, 2);
+    assert_eq!(gate.control_0(), &0);
+    assert_eq!(gate.control_1(), &1);
+    assert_eq!(gate.target(), &2);
+}
+
+#[test]
+fn test_fredkin_controlledswap_equivalence() {
+    let fredkin = Fredkin::new(0, 1, 2);
+    let cswap = ControlledSWAP::new(0, 1, 2);
+    assert_eq!(ControlledSWAP::from(fredkin.clone()), cswap);
+    assert_eq!(Fredkin::from(cswap.clone()), fredkin);
+    assert_eq!(fredkin.unitary_matrix(), cswap.unitary_matrix());
+    assert_eq!(fredkin.circuit(), cswap.circuit());
+
+    let operation: Operation =
+        serde_json::from_str(r#"{"Fredkin":{"control":0,"target_0":1,"target_1":2}}"#).unwrap();
+    assert_eq!(operation, Operation::from(fredkin));
+    assert_eq!(operation.hqslang(), "Fredkin");
+}
+
 #[test]
 fn test_inputs_phaseshiftedccz() {
     let gate = PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::PI);
@@ -535,12 +670,43 @@ fn test_inputs_phaseshiftedccps() {
     assert_eq!(gate.target(), &2);
 }
 
+#[test]
+fn test_unitary_matrix_phaseshiftedccps() {
+    let theta = 0.3;
+    let phi = 0.7;
+    let gate = PhaseShiftedControlledControlledPhase::new(
+        0,
+        1,
+        2,
+        CalculatorFloat::from(theta),
+        CalculatorFloat::from(phi),
+    );
+    let matrix = gate.unitary_matrix().unwrap();
+    for row in 0..8 {
+        for column in 0..8 {
+            if row != column {
+                assert_eq!(matrix[[row, column]], Complex64::new(0.0, 0.0));
+            }
+        }
+        // each excited qubit picks up phi, the fully excited state additionally picks up theta
+        let excitations = (row as u32).count_ones() as f64;
+        let phase = if row == 7 {
+            excitations * phi + theta
+        } else {
+            excitations * phi
+        };
+        let expected = Complex64::new(phase.cos(), phase.sin());
+        assert!((matrix[[row, row]] - expected).norm() < 1e-12);
+    }
+}
+
 /// Test JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test_case(ThreeQubitGateOperation::from(ControlledControlledPauliZ::new(0, 1, 2)); "ControlledControlledPauliZ")]
 #[test_case(ThreeQubitGateOperation::from(ControlledControlledPhaseShift::new(0, 1, 2, CalculatorFloat::from(0.2))); "ControlledControlledPhaseShift")]
 #[test_case(ThreeQubitGateOperation::from(Toffoli::new(0, 1, 2)); "Toffoli")]
 #[test_case(ThreeQubitGateOperation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+#[test_case(ThreeQubitGateOperation::from(Fredkin::new(0, 1, 2)); "Fredkin")]
 #[test_case(
     ThreeQubitGateOperation::from(PhaseShiftedControlledControlledZ::new(0, 1, 2, CalculatorFloat::from("theta"))); "PhaseShiftedControlledControlledZ")]
 #[test_case(
@@ -556,6 +722,7 @@ pub fn test_json_schema_three_qubit_gate_operations(gate: ThreeQubitGateOperatio
         }
         ThreeQubitGateOperation::Toffoli(op) => serde_json::to_string(&op).unwrap(),
         ThreeQubitGateOperation::ControlledSWAP(op) => serde_json::to_string(&op).unwrap(),
+        ThreeQubitGateOperation::Fredkin(op) => serde_json::to_string(&op).unwrap(),
         ThreeQubitGateOperation::PhaseShiftedControlledControlledZ(op) => {
             serde_json::to_string(&op).unwrap()
         }
@@ -576,6 +743,7 @@ pub fn test_json_schema_three_qubit_gate_operations(gate: ThreeQubitGateOperatio
         }
         ThreeQubitGateOperation::Toffoli(_) => schema_for!(Toffoli),
         ThreeQubitGateOperation::ControlledSWAP(_) => schema_for!(ControlledSWAP),
+        ThreeQubitGateOperation::Fredkin(_) => schema_for!(Fredkin),
         ThreeQubitGateOperation::PhaseShiftedControlledControlledZ(_) => {
             schema_for!(PhaseShiftedControlledControlledZ)
         }
@@ -238,6 +238,135 @@ fn test_kakdecomposition(gate: TwoQubitGateOperation) {
     }
 }
 
+// helper function returning the unitary of a circuit on qubits 0 and 1, qubit 0 is the most significant qubit
+fn two_qubit_circuit_unitary(circuit: &Circuit) -> Array2<Complex64> {
+    let swap: Array2<Complex64> = SWAP::new(0, 1).unitary_matrix().unwrap();
+    let identity: Array2<Complex64> = Array2::eye(2);
+    let mut unitary: Array2<Complex64> = Array2::eye(4);
+    for operation in circuit.iter() {
+        let matrix: Array2<Complex64> = match operation {
+            Operation::PragmaGlobalPhase(op) => {
+                Array2::eye(4) * Complex64::new(0.0, *op.phase().float().unwrap()).exp()
+            }
+            _ => match SingleQubitGateOperation::try_from(operation.clone()) {
+                Ok(gate) => {
+                    let single = convert_matrix(gate.unitary_matrix().unwrap());
+                    let identity = convert_matrix(identity.clone());
+                    let full = if *gate.qubit() == 0 {
+                        single.kronecker(&identity)
+                    } else {
+                        identity.kronecker(&single)
+                    };
+                    Array2::from_shape_fn((4, 4), |(i, j)| full[(i, j)])
+                }
+                Err(_) => {
+                    let gate = TwoQubitGateOperation::try_from(operation.clone()).unwrap();
+                    let matrix = gate.unitary_matrix().unwrap();
+                    if *gate.control() == 0 {
+                        matrix
+                    } else {
+                        swap.dot(&matrix).dot(&swap)
+                    }
+                }
+            },
+        };
+        unitary = matrix.dot(&unitary);
+    }
+    unitary
+}
+
+//
+// Test decomposition of TwoQubit Gates into CNOT, ControlledPauliZ and ISwap bases
+//
+#[test_case(TwoQubitGateOperation::from(CNOT::new(0, 1)), 1; "CNOT")]
+#[test_case(TwoQubitGateOperation::from(CNOT::new(1, 0)), 1; "CNOT_reversed")]
+#[test_case(TwoQubitGateOperation::from(SWAP::new(0, 1)), 3; "SWAP")]
+#[test_case(TwoQubitGateOperation::from(ISwap::new(0, 1)), 2; "ISwap")]
+#[test_case(TwoQubitGateOperation::from(FSwap::new(0, 1)), 2; "FSwap")]
+#[test_case(TwoQubitGateOperation::from(SqrtISwap::new(0, 1)), 2; "SqrtISwap")]
+#[test_case(TwoQubitGateOperation::from(InvSqrtISwap::new(0, 1)), 2; "InvSqrtISwap")]
+#[test_case(TwoQubitGateOperation::from(XY::new(0, 1, CalculatorFloat::from(0.7))), 2; "XY")]
+#[test_case(TwoQubitGateOperation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::from(0.3))), 2; "ControlledPhaseShift")]
+#[test_case(TwoQubitGateOperation::from(ControlledPauliY::new(0, 1)), 1; "ControlledPauliY")]
+#[test_case(TwoQubitGateOperation::from(ControlledPauliZ::new(0, 1)), 1; "ControlledPauliZ")]
+#[test_case(TwoQubitGateOperation::from(MolmerSorensenXX::new(0, 1)), 1; "MolmerSorensenXX")]
+#[test_case(TwoQubitGateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::from(1.3))), 2; "VariableMSXX")]
+#[test_case(TwoQubitGateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::from(0.4), CalculatorFloat::from(1.1))), 2; "GivensRotation")]
+#[test_case(TwoQubitGateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from(0.4), CalculatorFloat::from(1.1))), 2; "GivensRotationLittleEndian")]
+#[test_case(TwoQubitGateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(0.5), CalculatorFloat::from(-0.3))), 3; "Qsim")]
+#[test_case(TwoQubitGateOperation::from(Fsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(2.0), CalculatorFloat::from(-1.0))), 3; "Fsim")]
+#[test_case(TwoQubitGateOperation::from(Fsim::new(1, 0, CalculatorFloat::from(0.2), CalculatorFloat::from(-0.6), CalculatorFloat::from(0.4))), 3; "Fsim_reversed")]
+#[test_case(TwoQubitGateOperation::from(SpinInteraction::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(2.0), CalculatorFloat::from(-1.0))), 3; "SpinInteraction")]
+#[test_case(TwoQubitGateOperation::from(Bogoliubov::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))), 2; "Bogoliubov")]
+#[test_case(TwoQubitGateOperation::from(PMInteraction::new(0, 1, CalculatorFloat::from(0.8))), 2; "PMInteraction")]
+#[test_case(TwoQubitGateOperation::from(ComplexPMInteraction::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))), 2; "ComplexPMInteraction")]
+#[test_case(TwoQubitGateOperation::from(PhaseShiftedControlledZ::new(0, 1, CalculatorFloat::from(PI/(-3.0)))), 1; "PhaseShiftedControlledZ")]
+#[test_case(TwoQubitGateOperation::from(PhaseShiftedControlledPhase::new(0, 1, CalculatorFloat::from(0.9), CalculatorFloat::from(0.2))), 2; "PhaseShiftedControlledPhase")]
+#[test_case(TwoQubitGateOperation::from(ControlledRotateX::new(0, 1, CalculatorFloat::from(0.6))), 2; "ControlledRotateX")]
+#[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::from(0.6), CalculatorFloat::from(1.4))), 2; "ControlledRotateXY")]
+#[test_case(TwoQubitGateOperation::from(EchoCrossResonance::new(0, 1)), 1; "EchoCrossResonance")]
+fn test_two_qubit_gate_basis_decomposition(gate: TwoQubitGateOperation, number_cnots: usize) {
+    let unitary = gate.unitary_matrix().unwrap();
+    let mut expected_unitary = unitary.clone();
+    if *gate.control() == 1 {
+        let swap: Array2<Complex64> = SWAP::new(0, 1).unitary_matrix().unwrap();
+        expected_unitary = swap.dot(&unitary).dot(&swap);
+    }
+    for (basis, entangler, number_entanglers) in [
+        (TwoQubitBasis::CNOT, "CNOT", number_cnots),
+        (
+            TwoQubitBasis::ControlledPauliZ,
+            "ControlledPauliZ",
+            number_cnots,
+        ),
+        (TwoQubitBasis::ISwap, "ISwap", 2 * number_cnots),
+    ] {
+        let circuit = gate.to_basis_circuit(basis).unwrap();
+        assert_eq!(
+            circuit
+                .iter()
+                .filter(|operation| operation.hqslang() == entangler)
+                .count(),
+            number_entanglers
+        );
+        for operation in circuit.iter() {
+            assert!(
+                operation.hqslang() == entangler
+                    || operation.hqslang() == "SingleQubitGate"
+                    || operation.hqslang() == "PragmaGlobalPhase"
+            );
+        }
+        let decomposed = two_qubit_circuit_unitary(&circuit);
+        for (original, new) in expected_unitary.iter().zip(decomposed.iter()) {
+            assert!((original - new).norm() < 1e-10);
+        }
+    }
+    assert_eq!(
+        gate.to_cnot_circuit().unwrap(),
+        gate.to_basis_circuit(TwoQubitBasis::CNOT).unwrap()
+    );
+}
+
+/// Test decomposition of symbolic TwoQubit Gates
+#[test]
+fn test_two_qubit_gate_basis_decomposition_symbolic() {
+    let gate = Fsim::new(
+        0,
+        1,
+        CalculatorFloat::from("t"),
+        CalculatorFloat::from(2.0),
+        CalculatorFloat::from(-1.0),
+    );
+    assert!(matches!(
+        gate.to_cnot_circuit(),
+        Err(RoqoqoError::CalculatorError(_))
+    ));
+    assert!(matches!(
+        gate.to_basis_circuit(TwoQubitBasis::ISwap),
+        Err(RoqoqoError::CalculatorError(_))
+    ));
+}
+
 //
 // Test Unitary Matrix for TwoQubit Gates
 //
@@ -1348,3 +1477,148 @@ pub fn test_json_schema_two_qubit_gate_operations(gate: TwoQubitGateOperation) {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Test NegativeControl unitary matrix against the conjugation with PauliX on the control qubit
+#[test_case(TwoQubitGateOperation::from(CNOT::new(0, 1)); "CNOT")]
+#[test_case(TwoQubitGateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
+#[test_case(TwoQubitGateOperation::from(ControlledPhaseShift::new(1, 0, CalculatorFloat::from(0.3))); "ControlledPhaseShift")]
+#[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::from(0.3), CalculatorFloat::from(0.7))); "ControlledRotateXY")]
+#[test_case(TwoQubitGateOperation::from(EchoCrossResonance::new(1, 0)); "EchoCrossResonance")]
+fn test_negative_control_unitary(inner: TwoQubitGateOperation) {
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let flip_control: Array2<Complex64> = array![
+        [zero, zero, one, zero],
+        [zero, zero, zero, one],
+        [one, zero, zero, zero],
+        [zero, one, zero, zero],
+    ];
+    let gate = NegativeControl::new(inner.clone());
+    let unitary = gate.unitary_matrix().unwrap();
+    let expected = flip_control
+        .dot(&inner.unitary_matrix().unwrap())
+        .dot(&flip_control);
+    assert_eq!(unitary, expected);
+}
+
+/// Test NegativeControl of CNOT flips the target when the control is in state |0>
+#[test]
+fn test_negative_control_cnot_unitary() {
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let gate = NegativeControl::new(CNOT::new(0, 1).into());
+    let expected: Array2<Complex64> = array![
+        [zero, one, zero, zero],
+        [one, zero, zero, zero],
+        [zero, zero, one, zero],
+        [zero, zero, zero, one],
+    ];
+    assert_eq!(gate.unitary_matrix().unwrap(), expected);
+
+    let symbolic = NegativeControl::new(ControlledPhaseShift::new(0, 1, "theta".into()).into());
+    assert!(symbolic.unitary_matrix().is_err());
+}
+
+/// Test NegativeControl lowering to the inner gate between PauliX gates
+#[test]
+fn test_negative_control_circuit() {
+    let inner = TwoQubitGateOperation::from(ControlledPhaseShift::new(2, 0, "theta".into()));
+    let gate = NegativeControl::new(inner.clone());
+    let mut expected = Circuit::new();
+    expected += PauliX::new(2);
+    expected.add_operation(Operation::from(inner));
+    expected += PauliX::new(2);
+    assert_eq!(gate.circuit(), expected);
+}
+
+/// Test NegativeControl Operate, OperateTwoQubit and InvolveQubits traits
+#[test]
+fn test_negative_control_operate() {
+    let gate = NegativeControl::new(ControlledPhaseShift::new(2, 0, "theta".into()).into());
+    assert_eq!(gate.hqslang(), "NegativeControl");
+    assert_eq!(
+        gate.tags(),
+        &["Operation", "GateOperation", "NegativeControl"]
+    );
+    assert!(gate.is_parametrized());
+    assert!(!NegativeControl::new(CNOT::new(0, 1).into()).is_parametrized());
+    assert_eq!(gate.control(), &2);
+    assert_eq!(gate.target(), &0);
+    assert_eq!(
+        gate.inner(),
+        &TwoQubitGateOperation::from(ControlledPhaseShift::new(2, 0, "theta".into()))
+    );
+    assert_eq!(
+        gate.involved_qubits(),
+        InvolvedQubits::Set(HashSet::from([0, 2]))
+    );
+
+    let operation = Operation::from(gate.clone());
+    assert_eq!(
+        GateOperation::try_from(operation.clone())
+            .unwrap()
+            .hqslang(),
+        "NegativeControl"
+    );
+    assert_eq!(
+        TwoQubitOperation::try_from(operation.clone())
+            .unwrap()
+            .control(),
+        &2
+    );
+    assert!(TwoQubitGateOperation::try_from(operation).is_err());
+}
+
+/// Test NegativeControl Substitute trait
+#[test]
+fn test_negative_control_substitute() {
+    let gate = NegativeControl::new(ControlledPhaseShift::new(2, 0, "theta".into()).into());
+
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 0.5);
+    let substituted = gate.substitute_parameters(&calculator).unwrap();
+    assert_eq!(
+        substituted,
+        NegativeControl::new(ControlledPhaseShift::new(2, 0, 0.5.into()).into())
+    );
+    assert!(gate.substitute_parameters(&Calculator::new()).is_err());
+
+    let mapping: HashMap<usize, usize> = HashMap::from([(0, 1), (1, 0), (2, 3), (3, 2)]);
+    let remapped = gate.remap_qubits(&mapping).unwrap();
+    assert_eq!(
+        remapped,
+        NegativeControl::new(ControlledPhaseShift::new(3, 1, "theta".into()).into())
+    );
+    let mapping: HashMap<usize, usize> = HashMap::from([(2, 4)]);
+    assert!(gate.remap_qubits(&mapping).is_err());
+}
+
+/// Test NegativeControl serialization and deserialization of the nested gate
+#[test]
+fn test_negative_control_serde() {
+    let gate = NegativeControl::new(CNOT::new(0, 1).into());
+    let json = serde_json::to_string(&gate).unwrap();
+    assert_eq!(json, r#"{"inner":{"CNOT":{"control":0,"target":1}}}"#);
+    let deserialized: NegativeControl = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, gate);
+
+    let operation = Operation::from(gate);
+    let encoded = bincode::serialize(&operation).unwrap();
+    let decoded: Operation = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, operation);
+}
+
+/// Test NegativeControl JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_negative_control_json_schema() {
+    let gate = NegativeControl::new(ControlledRotateXY::new(0, 1, 0.3.into(), 0.7.into()).into());
+    let test_value: serde_json::Value = serde_json::to_value(&gate).unwrap();
+    let schema_value: serde_json::Value =
+        serde_json::to_value(schema_for!(NegativeControl)).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+    assert!(compiled_schema.validate(&test_value).is_ok());
+}
@@ -0,0 +1,559 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for transformation passes
+
+use num_complex::Complex64;
+use roqoqo::devices::AllToAllDevice;
+use roqoqo::noise_models::ContinuousDecoherenceModel;
+use roqoqo::operations::*;
+use roqoqo::passes::{
+    circuits_equivalent, estimate_fidelity, estimate_fidelity_per_qubit, reuse_qubits,
+    transpile_to_device, unroll_conditional_loops,
+};
+use roqoqo::{Circuit, RoqoqoBackendError, RoqoqoError};
+use std::collections::HashMap;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+/// Circuit with 6 logical qubits of which at most 3 are alive at the same time
+fn six_qubit_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 6, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateX::new(2, 0.4.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += CNOT::new(2, 3);
+    circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+    circuit += Hadamard::new(4);
+    circuit += CNOT::new(4, 5);
+    circuit += CNOT::new(5, 2);
+    circuit += MeasureQubit::new(2, "ro".to_string(), 2);
+    circuit += MeasureQubit::new(4, "ro".to_string(), 4);
+    circuit += MeasureQubit::new(5, "ro".to_string(), 5);
+    circuit
+}
+
+/// Test the rewritten circuit and the qubit mapping
+#[test]
+fn reuse_qubits_six_onto_three() {
+    let (reused, mapping) = reuse_qubits(&six_qubit_circuit()).unwrap();
+
+    let expected_mapping: HashMap<usize, usize> = [(0, 0), (1, 1), (2, 2), (3, 0), (4, 0), (5, 1)]
+        .into_iter()
+        .collect();
+    assert_eq!(mapping, expected_mapping);
+
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 6, true);
+    expected += Hadamard::new(0);
+    expected += CNOT::new(0, 1);
+    expected += RotateX::new(2, 0.4.into());
+    expected += MeasureQubit::new(0, "ro".to_string(), 0);
+    expected += MeasureQubit::new(1, "ro".to_string(), 1);
+    expected += PragmaActiveReset::new(0);
+    expected += CNOT::new(2, 0);
+    expected += MeasureQubit::new(0, "ro".to_string(), 3);
+    expected += PragmaActiveReset::new(0);
+    expected += Hadamard::new(0);
+    expected += PragmaActiveReset::new(1);
+    expected += CNOT::new(0, 1);
+    expected += CNOT::new(1, 2);
+    expected += MeasureQubit::new(2, "ro".to_string(), 2);
+    expected += MeasureQubit::new(0, "ro".to_string(), 4);
+    expected += MeasureQubit::new(1, "ro".to_string(), 5);
+    assert_eq!(reused, expected);
+}
+
+/// Test that the measurement statistics of the readout register are preserved
+#[test]
+fn reuse_qubits_preserves_statistics() {
+    let circuit = six_qubit_circuit();
+    let (reused, _) = reuse_qubits(&circuit).unwrap();
+    let logical = simulate(&circuit, 6);
+    let physical = simulate(&reused, 3);
+    assert_eq!(logical.len(), physical.len());
+    for (bits, probability) in logical {
+        let reused_probability = physical.get(&bits).copied().unwrap_or(0.0);
+        assert!((probability - reused_probability).abs() < 1e-10);
+    }
+}
+
+/// Test circuits that cannot or do not need to be rewritten
+#[test]
+fn reuse_qubits_unsupported() {
+    let mut circuit = six_qubit_circuit();
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    assert!(matches!(
+        reuse_qubits(&circuit),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+
+    let mut circuit = six_qubit_circuit();
+    circuit += PragmaGetStateVector::new("ro".to_string(), None);
+    assert!(matches!(
+        reuse_qubits(&circuit),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+
+    // Operations involving all qubits are allowed when no qubit is reused afterwards
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("ro".to_string(), 4, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaGetStateVector::new("ro".to_string(), None);
+    let (reused, mapping) = reuse_qubits(&circuit).unwrap();
+    assert_eq!(reused, circuit);
+    assert_eq!(mapping, [(0, 0), (1, 1)].into_iter().collect());
+}
+
+/// Branches of a statevector simulation with the readout bits and the unnormalized state
+type Branches = Vec<(Vec<bool>, Vec<Complex64>)>;
+
+/// Computes the exact probabilities of all readout bit strings of a circuit
+fn simulate(circuit: &Circuit, number_qubits: usize) -> HashMap<Vec<bool>, f64> {
+    let mut initial = vec![Complex64::new(0.0, 0.0); 1 << number_qubits];
+    initial[0] = Complex64::new(1.0, 0.0);
+    let mut branches: Branches = vec![(vec![false; 6], initial)];
+    for operation in circuit.iter() {
+        branches = match operation {
+            Operation::Hadamard(op) => apply_single(
+                branches,
+                *op.qubit(),
+                [
+                    [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+                    [FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+                ]
+                .map(|row| row.map(|value| Complex64::new(value, 0.0))),
+            ),
+            Operation::RotateX(op) => {
+                let theta = *op.theta().float().unwrap();
+                let c = Complex64::new((theta / 2.0).cos(), 0.0);
+                let s = Complex64::new(0.0, -(theta / 2.0).sin());
+                apply_single(branches, *op.qubit(), [[c, s], [s, c]])
+            }
+            Operation::CNOT(op) => {
+                let (control, target) = (*op.control(), *op.target());
+                branches
+                    .into_iter()
+                    .map(|(bits, state)| {
+                        let mut new_state = state.clone();
+                        for (index, amplitude) in state.iter().enumerate() {
+                            if index & (1 << control) != 0 {
+                                new_state[index ^ (1 << target)] = *amplitude;
+                            }
+                        }
+                        (bits, new_state)
+                    })
+                    .collect()
+            }
+            Operation::MeasureQubit(op) => {
+                project(branches, *op.qubit(), Some(*op.readout_index()))
+            }
+            Operation::PragmaActiveReset(op) => {
+                let qubit = *op.qubit();
+                project(branches, qubit, None)
+                    .into_iter()
+                    .map(|(bits, state)| {
+                        let mut new_state = vec![Complex64::new(0.0, 0.0); state.len()];
+                        for (index, amplitude) in state.iter().enumerate() {
+                            new_state[index & !(1 << qubit)] += amplitude;
+                        }
+                        (bits, new_state)
+                    })
+                    .collect()
+            }
+            Operation::DefinitionBit(_) => branches,
+            _ => panic!(
+                "Operation {} not supported in test simulation",
+                operation.hqslang()
+            ),
+        };
+    }
+    let mut probabilities: HashMap<Vec<bool>, f64> = HashMap::new();
+    for (bits, state) in branches {
+        *probabilities.entry(bits).or_insert(0.0) +=
+            state.iter().map(|a| a.norm_sqr()).sum::<f64>();
+    }
+    probabilities.retain(|_, probability| *probability > 1e-12);
+    probabilities
+}
+
+fn apply_single(branches: Branches, qubit: usize, matrix: [[Complex64; 2]; 2]) -> Branches {
+    branches
+        .into_iter()
+        .map(|(bits, state)| {
+            let mut new_state = state.clone();
+            for index in (0..state.len()).filter(|index| index & (1 << qubit) == 0) {
+                let partner = index | (1 << qubit);
+                new_state[index] = matrix[0][0] * state[index] + matrix[0][1] * state[partner];
+                new_state[partner] = matrix[1][0] * state[index] + matrix[1][1] * state[partner];
+            }
+            (bits, new_state)
+        })
+        .collect()
+}
+
+/// Splits every branch into the projections onto the two basis states of the qubit
+fn project(branches: Branches, qubit: usize, readout_index: Option<usize>) -> Branches {
+    let mut new_branches: Branches = Vec::new();
+    for (bits, state) in branches {
+        for outcome in [false, true] {
+            let projected: Vec<Complex64> = state
+                .iter()
+                .enumerate()
+                .map(|(index, amplitude)| {
+                    if (index & (1 << qubit) != 0) == outcome {
+                        *amplitude
+                    } else {
+                        Complex64::new(0.0, 0.0)
+                    }
+                })
+                .collect();
+            if projected
+                .iter()
+                .all(|amplitude| amplitude.norm_sqr() < 1e-24)
+            {
+                continue;
+            }
+            let mut new_bits = bits.clone();
+            if let Some(index) = readout_index {
+                new_bits[index] = outcome;
+            }
+            new_branches.push((new_bits, projected));
+        }
+    }
+    new_branches
+}
+
+/// Device with damping on all qubits used for the fidelity estimates
+fn noisy_device() -> AllToAllDevice {
+    AllToAllDevice::new(
+        3,
+        &["RotateX".to_string(), "Hadamard".to_string()],
+        &["CNOT".to_string()],
+        1.0,
+    )
+    .add_damping_all(0.01)
+}
+
+/// Test the fidelity estimate and its breakdown per qubit
+#[test]
+fn estimate_fidelity_values() {
+    let device = noisy_device();
+    let noise = ContinuousDecoherenceModel::new().add_dephasing_rate(&[1], 0.02);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaDamping::new(2, 1.0.into(), 0.1.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    let per_qubit = estimate_fidelity_per_qubit(&circuit, &device, &noise).unwrap();
+    assert_eq!(per_qubit.len(), 3);
+    assert!((per_qubit[&0] - (-0.02_f64).exp()).abs() < 1e-12);
+    assert!((per_qubit[&1] - (-0.03_f64).exp()).abs() < 1e-12);
+    let damping_probability = 1.0 - (-0.1_f64).exp();
+    assert!((per_qubit[&2] - (1.0 - damping_probability)).abs() < 1e-12);
+
+    let fidelity = estimate_fidelity(&circuit, &device, &noise).unwrap();
+    assert!((fidelity - per_qubit.values().product::<f64>()).abs() < 1e-12);
+    assert!((fidelity - (-0.15_f64).exp()).abs() < 1e-12);
+
+    let empty = estimate_fidelity(&Circuit::new(), &device, &noise).unwrap();
+    assert_eq!(empty, 1.0);
+}
+
+/// Test that adding gates strictly decreases the fidelity estimate
+#[test]
+fn estimate_fidelity_decreases_with_gates() {
+    let device = noisy_device();
+    let noise = ContinuousDecoherenceModel::new().add_depolarising_rate(&[0, 1, 2], 0.001);
+    let mut circuit = Circuit::new();
+    let mut previous = estimate_fidelity(&circuit, &device, &noise).unwrap();
+    let gates: Vec<Operation> = vec![
+        Hadamard::new(0).into(),
+        CNOT::new(0, 1).into(),
+        RotateX::new(2, 0.5.into()).into(),
+        CNOT::new(1, 2).into(),
+        Hadamard::new(0).into(),
+    ];
+    for gate in gates {
+        circuit.add_operation(gate);
+        let fidelity = estimate_fidelity(&circuit, &device, &noise).unwrap();
+        assert!(fidelity < previous);
+        assert!(fidelity > 0.0);
+        previous = fidelity;
+    }
+}
+
+/// Test the errors of the fidelity estimate
+#[test]
+fn estimate_fidelity_errors() {
+    let device = noisy_device();
+    let noise = ContinuousDecoherenceModel::new();
+
+    // Gate not available on the device
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    let result = estimate_fidelity(&circuit, &device, &noise);
+    assert_eq!(
+        result,
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Gate PauliX on qubits [0] is not available on the device".to_string()
+        })
+    );
+
+    // Qubit not part of the device
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 3);
+    assert!(estimate_fidelity(&circuit, &device, &noise).is_err());
+
+    // Noise PRAGMA without a probability
+    let mut circuit = Circuit::new();
+    circuit += PragmaGeneralNoise::new(0, 1.0.into(), ndarray::Array2::zeros((3, 3)));
+    assert!(estimate_fidelity(&circuit, &device, &noise).is_err());
+
+    // Symbolic noise PRAGMA
+    let mut circuit = Circuit::new();
+    circuit += PragmaDamping::new(0, "t".into(), 0.1.into());
+    assert!(estimate_fidelity(&circuit, &device, &noise).is_err());
+}
+
+/// Test unrolling of (nested) conditional loops
+#[test]
+fn unroll_nested_conditional_loops() {
+    let mut body = Circuit::new();
+    body += Hadamard::new(1);
+    body += MeasureQubit::new(1, "flag".to_string(), 0);
+    let inner = PragmaConditionalLoop::new("flag".to_string(), 0, 2, body.clone());
+
+    let mut outer_body = Circuit::new();
+    outer_body += PauliX::new(0);
+    outer_body += inner.clone();
+    outer_body += MeasureQubit::new(0, "flag".to_string(), 1);
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("flag".to_string(), 2, true);
+    circuit += PragmaConditionalLoop::new("flag".to_string(), 1, 2, outer_body);
+    circuit += Hadamard::new(0);
+
+    let mut unrolled_outer_body = Circuit::new();
+    unrolled_outer_body += PauliX::new(0);
+    unrolled_outer_body += inner.unroll();
+    unrolled_outer_body += MeasureQubit::new(0, "flag".to_string(), 1);
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("flag".to_string(), 2, true);
+    expected += unrolled_outer_body.clone();
+    expected += PragmaConditional::new("flag".to_string(), 1, unrolled_outer_body);
+    expected += Hadamard::new(0);
+
+    let unrolled = unroll_conditional_loops(&circuit);
+    assert_eq!(unrolled, expected);
+    assert!(!unrolled
+        .iter()
+        .any(|op| matches!(op, Operation::PragmaConditionalLoop(_))));
+}
+
+/// Test that transpiled circuits only use native gates and implement the same unitary up to a global phase
+#[test]
+fn transpile_to_device_native_gates() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += Toffoli::new(0, 1, 2);
+    circuit += ControlledPhaseShift::new(1, 2, 0.3.into());
+    circuit += RotateX::new(2, 0.4.into());
+    circuit += RotateZ::new(0, 0.2.into());
+    circuit += SWAP::new(0, 2);
+    circuit += Fredkin::new(2, 0, 1);
+    circuit += PhaseShiftedControlledControlledPhase::new(1, 2, 0, 0.7.into(), (-0.4).into());
+    circuit += SGate::new(1);
+
+    for (single_qubit_gates, two_qubit_gate) in [
+        (["RotateZ", "SqrtPauliX"], "ControlledPauliZ"),
+        (["RotateZ", "RotateY"], "ISwap"),
+        (["RotateZ", "RotateX"], "CNOT"),
+    ] {
+        let device = AllToAllDevice::new(
+            3,
+            &single_qubit_gates.map(|gate| gate.to_string()),
+            &[two_qubit_gate.to_string()],
+            1.0,
+        );
+        let transpiled = transpile_to_device(&circuit, &device).unwrap();
+        for operation in transpiled.iter() {
+            assert!(
+                single_qubit_gates.contains(&operation.hqslang())
+                    || operation.hqslang() == two_qubit_gate
+                    || operation.hqslang() == "PragmaGlobalPhase"
+            );
+        }
+
+        assert!(circuits_equivalent(&circuit, &transpiled, 1, 0, 1e-10).unwrap());
+    }
+}
+
+/// Test transpiling multi-qubit gates, non-gate operations and gates available on the device
+#[test]
+fn transpile_to_device_keeps_native_operations() {
+    let device = AllToAllDevice::new(
+        4,
+        &["RotateZ".to_string(), "SqrtPauliX".to_string()],
+        &["ControlledPauliZ".to_string()],
+        1.0,
+    );
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 4, true);
+    circuit += RotateZ::new(0, "theta".into());
+    circuit += SqrtPauliX::new(0);
+    circuit += ControlledPauliZ::new(0, 1);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    assert_eq!(transpile_to_device(&circuit, &device).unwrap(), circuit);
+
+    circuit += MultiQubitMS::new(vec![0, 1, 2], 0.5.into());
+    circuit += TripleControlledPauliX::new(0, 1, 2, 3);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let transpiled = transpile_to_device(&circuit, &device).unwrap();
+    for operation in transpiled.iter() {
+        if GateOperation::try_from(operation).is_ok() {
+            assert!(["RotateZ", "SqrtPauliX", "ControlledPauliZ"].contains(&operation.hqslang()));
+        }
+    }
+    // The global phase of the decompositions is added after the last operation
+    let operations: Vec<&str> = transpiled.iter().map(|op| op.hqslang()).collect();
+    assert_eq!(
+        operations[operations.len() - 2..],
+        ["PragmaRepeatedMeasurement", "PragmaGlobalPhase"]
+    );
+    assert_eq!(
+        transpiled
+            .iter()
+            .filter(|operation| operation.hqslang() == "MeasureQubit")
+            .count(),
+        1
+    );
+}
+
+/// Test the errors of the transpilation
+#[test]
+fn transpile_to_device_errors() {
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 1);
+
+    // No entangling gate on the device
+    let device = AllToAllDevice::new(
+        2,
+        &["RotateZ".to_string(), "SqrtPauliX".to_string()],
+        &["SWAP".to_string()],
+        1.0,
+    );
+    assert!(matches!(
+        transpile_to_device(&circuit, &device),
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+
+    // No single-qubit gate set on the device
+    let device = AllToAllDevice::new(
+        2,
+        &["RotateX".to_string()],
+        &["ControlledPauliZ".to_string()],
+        1.0,
+    );
+    assert!(matches!(
+        transpile_to_device(&circuit, &device),
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+
+    // Qubit outside of the device
+    let device = AllToAllDevice::new(
+        2,
+        &["RotateZ".to_string(), "SqrtPauliX".to_string()],
+        &["ControlledPauliZ".to_string()],
+        1.0,
+    );
+    let mut circuit = Circuit::new();
+    circuit += ControlledPauliZ::new(0, 5);
+    assert!(matches!(
+        transpile_to_device(&circuit, &device),
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+
+    // Symbolic gate that needs to be decomposed
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "theta".into());
+    assert!(transpile_to_device(&circuit, &device).is_err());
+}
+
+/// Test equivalence of circuits with the same symbolic parameters
+#[test]
+fn circuits_equivalent_symbolic() {
+    let mut a = Circuit::new();
+    a += RotateX::new(0, "theta".into());
+    a += ControlledPhaseShift::new(0, 1, "2 * phi".into());
+    let mut b = Circuit::new();
+    b += Hadamard::new(0);
+    b += RotateZ::new(0, "theta".into());
+    b += Hadamard::new(0);
+    b += ControlledPhaseShift::new(1, 0, "phi + phi".into());
+    assert!(circuits_equivalent(&a, &b, 5, 42, 1e-10).unwrap());
+
+    // Global phases are ignored
+    b += PragmaGlobalPhase::new("theta".into());
+    assert!(circuits_equivalent(&a, &b, 5, 42, 1e-10).unwrap());
+
+    // Parameters only used in one of the circuits are sampled as well
+    let mut c = a.clone();
+    c += RotateZ::new(1, "gamma".into());
+    assert!(!circuits_equivalent(&a, &c, 5, 42, 1e-10).unwrap());
+
+    let mut d = Circuit::new();
+    d += RotateX::new(0, "2 * theta".into());
+    d += ControlledPhaseShift::new(0, 1, "2 * phi".into());
+    assert!(!circuits_equivalent(&a, &d, 5, 42, 1e-10).unwrap());
+}
+
+/// Test equivalence of circuits without symbolic parameters
+#[test]
+fn circuits_equivalent_constant() {
+    let mut a = Circuit::new();
+    a += SWAP::new(0, 2);
+    let mut b = Circuit::new();
+    b += CNOT::new(0, 2);
+    b += CNOT::new(2, 0);
+    b += CNOT::new(0, 2);
+    assert!(circuits_equivalent(&a, &b, 0, 0, 1e-10).unwrap());
+    b += PauliZ::new(1);
+    assert!(!circuits_equivalent(&a, &b, 0, 0, 1e-10).unwrap());
+    assert!(circuits_equivalent(&Circuit::new(), &Circuit::new(), 1, 0, 1e-10).unwrap());
+}
+
+/// Test the errors of the equivalence check
+#[test]
+fn circuits_equivalent_errors() {
+    let mut a = Circuit::new();
+    a += Hadamard::new(0);
+    let mut b = a.clone();
+    b += MeasureQubit::new(0, "ro".to_string(), 0);
+    assert!(matches!(
+        circuits_equivalent(&a, &b, 1, 0, 1e-10),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+
+    let mut c = Circuit::new();
+    c += PauliX::new(10);
+    assert!(matches!(
+        circuits_equivalent(&a, &c, 1, 0, 1e-10),
+        Err(RoqoqoError::GenericError { .. })
+    ));
+}
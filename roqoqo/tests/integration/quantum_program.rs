@@ -12,6 +12,7 @@
 
 #[cfg(feature = "jsonschema")]
 use jsonschema::{Draft, Validator};
+use qoqo_calculator::CalculatorFloat;
 use roqoqo::measurements::{
     Cheated, CheatedInput, CheatedPauliZProduct, CheatedPauliZProductInput, ClassicalRegister,
     PauliZProduct, PauliZProductInput,
@@ -21,9 +22,12 @@ use roqoqo::prelude::*;
 use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use roqoqo::Circuit;
 use roqoqo::QuantumProgram;
+use roqoqo::{RoqoqoBackendError, RoqoqoError};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 #[derive(Debug, Clone, Copy)]
 struct TestBackend;
@@ -48,6 +52,51 @@ impl EvaluatingBackend for TestBackend {
     }
 }
 
+/// Backend recording the circuits it runs in order
+#[derive(Debug, Clone, Default)]
+struct RecordingBackend {
+    circuits: Rc<RefCell<Vec<Circuit>>>,
+}
+
+impl EvaluatingBackend for RecordingBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a operations::Operation>,
+    ) -> roqoqo::backends::RegisterResult {
+        let mut recorded = Circuit::new();
+        for operation in circuit {
+            recorded.add_operation(operation.clone());
+        }
+        let index = self.circuits.borrow().len();
+        self.circuits.borrow_mut().push(recorded);
+        let mut result_bit: HashMap<String, BitOutputRegister> = HashMap::new();
+        result_bit.insert("ro".to_string(), vec![vec![index % 2 == 1]]);
+        let result_float: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let result_complex: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        Ok((result_bit, result_float, result_complex))
+    }
+}
+
+/// Returns a measurement input and circuits with the free parameters theta and theta2
+fn parametrized_circuits() -> (PauliZProductInput, Circuit, Vec<Circuit>) {
+    let mut input = PauliZProductInput::new(1, false);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_linear_exp_val("z".to_string(), HashMap::from([(0, 1.0)]))
+        .unwrap();
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    constant_circuit += operations::RotateZ::new(0, "theta2".into());
+    let mut circuits = Vec::new();
+    for index in 0..3 {
+        let mut circuit = Circuit::new();
+        circuit += operations::RotateX::new(0, CalculatorFloat::from("theta") * index as f64);
+        circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+        circuits.push(circuit);
+    }
+    (input, constant_circuit, circuits)
+}
+
 #[test]
 fn test_basis_rotation() {
     // setting ub BR measurement
@@ -198,6 +247,43 @@ fn test_registers() {
     assert!(result_fail.is_err());
 }
 
+#[test]
+fn test_registers_validation() {
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    let mut circuit = Circuit::new();
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(constant_circuit),
+            circuits: vec![Circuit::new(), circuit],
+        },
+        input_parameter_names: vec![],
+    };
+    assert_eq!(
+        program.validate(),
+        Err(RoqoqoError::RegisterTooSmall {
+            name: "ro".to_string(),
+            required: 2,
+            defined: 1,
+            circuit_index: 1
+        })
+    );
+    let result = program.run_registers(TestBackend, &[]);
+    assert_eq!(
+        result,
+        Err(RoqoqoBackendError::RoqoqoError(
+            RoqoqoError::RegisterTooSmall {
+                name: "ro".to_string(),
+                required: 2,
+                defined: 1,
+                circuit_index: 1
+            }
+        ))
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_basis_rotation_json_schema() {
@@ -372,3 +458,443 @@ fn test_registers_json_schema() {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+fn registers_program() -> QuantumProgram {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, "theta".into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    }
+}
+
+#[test]
+fn test_measurement_type() {
+    let program = registers_program();
+    assert_eq!(program.measurement_type(), "ClassicalRegister");
+    assert_eq!(format!("{}", program), "QuantumProgram::ClassicalRegister");
+
+    let program = QuantumProgram::Cheated {
+        measurement: Cheated {
+            constant_circuit: None,
+            circuits: Vec::new(),
+            input: CheatedInput::new(1),
+        },
+        input_parameter_names: Vec::new(),
+    };
+    assert_eq!(program.measurement_type(), "Cheated");
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serde_roundtrip() {
+    let program = registers_program();
+
+    let serialized = serde_json::to_string(&program).unwrap();
+    let deserialized: QuantumProgram = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, program);
+
+    let serialized = bincode::serialize(&program).unwrap();
+    let deserialized: QuantumProgram = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, program);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_deserialize_unknown_measurement_type() {
+    let program = registers_program();
+    let mut value = serde_json::to_value(&program).unwrap();
+    let content = value
+        .as_object_mut()
+        .unwrap()
+        .remove("ClassicalRegister")
+        .unwrap();
+    value
+        .as_object_mut()
+        .unwrap()
+        .insert("FutureMeasurement".to_string(), content.clone());
+
+    // Without a newer circuit the required version is unknown
+    let error = serde_json::from_value::<QuantumProgram>(value.clone())
+        .unwrap_err()
+        .to_string();
+    let expected = RoqoqoError::SerializationError {
+        msg: format!(
+            "Unknown QuantumProgram measurement type FutureMeasurement, the QuantumProgram requires a newer version of roqoqo (this is roqoqo {})",
+            roqoqo::ROQOQO_VERSION
+        ),
+    };
+    assert_eq!(error, expected.to_string());
+
+    // A newer circuit determines the required version
+    value["FutureMeasurement"]["measurement"]["circuits"][0]["_roqoqo_version"]["minor_version"] =
+        serde_json::json!(99);
+    let error = serde_json::from_value::<QuantumProgram>(value)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("FutureMeasurement"));
+    assert!(error.contains("requires roqoqo 1.99 or newer"));
+
+    // Binary formats only contain the variant index
+    let mut serialized = bincode::serialize(&program).unwrap();
+    serialized[0] = 7;
+    let error = bincode::deserialize::<QuantumProgram>(&serialized)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Unknown QuantumProgram measurement type with variant index 7"));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_deserialize_1_17() {
+    let mut input = PauliZProductInput::new(2, false);
+    let index = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_linear_exp_val("z0".to_string(), HashMap::from([(index, 2.0)]))
+        .unwrap();
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            constant_circuit: None,
+            circuits: vec![circuit],
+            input,
+        },
+        input_parameter_names: vec![],
+    };
+    let deserialized: QuantumProgram = bincode::deserialize(&QUANTUM_PROGRAM_1_17).unwrap();
+    assert_eq!(deserialized, program);
+    assert_eq!(bincode::serialize(&program).unwrap(), QUANTUM_PROGRAM_1_17);
+}
+
+// Bincode serialization of a PauliZProduct QuantumProgram written by roqoqo 1.17
+#[cfg(feature = "serialize")]
+#[rustfmt::skip]
+const QUANTUM_PROGRAM_1_17: [u8; 211] = [
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 114, 111, 2, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 114, 111, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 122, 48, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0,
+    0
+];
+
+/// Test strict json deserialization of QuantumProgram rejecting unknown fields
+#[test]
+fn test_from_json_strict() {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, "theta".into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+    let json = serde_json::to_string(&program).unwrap();
+    assert_eq!(QuantumProgram::from_json_strict(&json).unwrap(), program);
+
+    let misspelled = json.replace(
+        r#""input_parameter_names""#,
+        r#""input_parameter_namess":[],"input_parameter_names""#,
+    );
+    assert_eq!(
+        QuantumProgram::from_json_strict(&misspelled),
+        Err(RoqoqoError::SerializationError {
+            msg: "Unknown fields in json input: $.ClassicalRegister.input_parameter_namess"
+                .to_string()
+        })
+    );
+
+    let nested = json.replace(r#""readout":"ro""#, r#""readout":"ro","readoutt":"ro""#);
+    assert_eq!(
+        QuantumProgram::from_json_strict(&nested),
+        Err(RoqoqoError::SerializationError {
+            msg: "Unknown fields in json input: $.ClassicalRegister.measurement.circuits[0].operations[1].MeasureQubit.readoutt"
+                .to_string()
+        })
+    );
+}
+
+/// Test deserializing QuantumPrograms stored with the legacy BasisRotation measurement names
+#[cfg(feature = "serialize")]
+#[test]
+fn test_deserialize_legacy_measurement_names() {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, "theta".into());
+    circuit += operations::PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    let mut input = PauliZProductInput::new(2, false);
+    input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    input
+        .add_pauliz_product("ro".to_string(), vec![0, 1])
+        .unwrap();
+    input
+        .add_linear_exp_val("exp_val".to_string(), HashMap::from([(0, 0.5), (1, 2.0)]))
+        .unwrap();
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            constant_circuit: None,
+            circuits: vec![circuit],
+            input,
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+    let legacy_json = include_str!("../assets/legacy_basis_rotation_program.json");
+    let deserialized: QuantumProgram = serde_json::from_str(legacy_json).unwrap();
+    assert_eq!(deserialized, program);
+    // Serialization always uses the current variant name
+    let serialized = serde_json::to_string(&deserialized).unwrap();
+    assert!(serialized.starts_with(r#"{"PauliZProduct":"#));
+    let roundtrip: QuantumProgram = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(roundtrip, program);
+    assert_eq!(
+        QuantumProgram::from_json_strict(legacy_json).unwrap(),
+        program
+    );
+    let misspelled = legacy_json.replace(
+        r#""input_parameter_names""#,
+        r#""input_parameter_namess": [], "input_parameter_names""#,
+    );
+    assert_eq!(
+        QuantumProgram::from_json_strict(&misspelled),
+        Err(RoqoqoError::SerializationError {
+            msg: "Unknown fields in json input: $.PauliZProduct.input_parameter_namess".to_string()
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionFloat::new("ro_z".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, "theta".into());
+    let mut input = CheatedPauliZProductInput::new();
+    input.add_pauliz_product("ro_z".to_string());
+    input
+        .add_symbolic_exp_val("exp_val".to_string(), "2*pauli_product_0".into())
+        .unwrap();
+    let program = QuantumProgram::CheatedPauliZProduct {
+        measurement: CheatedPauliZProduct {
+            constant_circuit: None,
+            circuits: vec![circuit],
+            input,
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+    let legacy_json = include_str!("../assets/legacy_cheated_basis_rotation_program.json");
+    let deserialized: QuantumProgram = serde_json::from_str(legacy_json).unwrap();
+    assert_eq!(deserialized, program);
+    let serialized = serde_json::to_string(&deserialized).unwrap();
+    assert!(serialized.starts_with(r#"{"CheatedPauliZProduct":"#));
+    let roundtrip: QuantumProgram = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(roundtrip, program);
+    assert_eq!(
+        QuantumProgram::from_json_strict(legacy_json).unwrap(),
+        program
+    );
+}
+
+/// Test size_report aggregating the circuits of the measurement
+#[cfg(feature = "serialize")]
+#[test]
+fn test_size_report() {
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    let mut inner = Circuit::new();
+    inner += operations::PragmaSetStateVector::new(ndarray::Array1::zeros(8));
+    let mut circuit = Circuit::new();
+    circuit += operations::PragmaLoop::new(2.into(), inner);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(constant_circuit),
+            circuits: vec![circuit.clone(), circuit],
+        },
+        input_parameter_names: vec![],
+    };
+
+    let report = program.size_report();
+    assert_eq!(report.number_operations, 7);
+    assert_eq!(report.max_nesting_depth, 1);
+    assert_eq!(report.max_state_dimension, 8);
+    assert_eq!(
+        report.serialized_bytes,
+        bincode::serialize(&program).unwrap().len()
+    );
+}
+
+/// Test substituted_circuits yielding the full substituted circuits in order
+#[test]
+fn test_substituted_circuits() {
+    let (_, constant_circuit, circuits) = parametrized_circuits();
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(constant_circuit),
+            circuits,
+        },
+        input_parameter_names: vec!["theta".to_string(), "theta2".to_string()],
+    };
+
+    let substituted: Vec<Circuit> = program
+        .substituted_circuits(&[0.5, 1.0])
+        .unwrap()
+        .collect::<Result<Vec<Circuit>, RoqoqoError>>()
+        .unwrap();
+    assert_eq!(substituted.len(), 3);
+    for (index, circuit) in substituted.iter().enumerate() {
+        let mut expected = Circuit::new();
+        expected += operations::DefinitionBit::new("ro".to_string(), 1, true);
+        expected += operations::RotateZ::new(0, 1.0.into());
+        expected += operations::RotateX::new(0, (0.5 * index as f64).into());
+        expected += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+        assert_eq!(circuit, &expected);
+    }
+
+    let backend = RecordingBackend::default();
+    program.run_registers(backend.clone(), &[0.5, 1.0]).unwrap();
+    assert_eq!(*backend.circuits.borrow(), substituted);
+
+    assert_eq!(
+        program.substituted_circuits(&[0.5]).err(),
+        Some(RoqoqoError::GenericError {
+            msg: "Wrong number of parameters 2 parameters expected 1 parameters given".to_string()
+        })
+    );
+}
+
+/// Test that running a QuantumProgram substitutes one circuit after the other
+#[test]
+fn test_substituted_circuits_lazy() {
+    let (input, constant_circuit, mut circuits) = parametrized_circuits();
+    let mut failing = Circuit::new();
+    failing += operations::RotateX::new(0, "undefined".into());
+    circuits.insert(2, failing);
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            constant_circuit: Some(constant_circuit),
+            circuits,
+            input,
+        },
+        input_parameter_names: vec!["theta".to_string(), "theta2".to_string()],
+    };
+
+    let mut substituted = program.substituted_circuits(&[0.5, 1.0]).unwrap();
+    assert!(substituted.next().unwrap().is_ok());
+    assert!(substituted.next().unwrap().is_ok());
+    assert!(substituted.next().unwrap().is_err());
+
+    // The circuits before the failing circuit have been run when the substitution fails
+    let backend = RecordingBackend::default();
+    assert!(backend
+        .run_circuits_registers(None, program.substituted_circuits(&[0.5, 1.0]).unwrap())
+        .is_err());
+    assert_eq!(backend.circuits.borrow().len(), 2);
+}
+
+/// Test that running a QuantumProgram gives the same result as running the substituted measurement
+#[test]
+fn test_run_substituted_measurement() {
+    let (input, constant_circuit, circuits) = parametrized_circuits();
+    let measurement = PauliZProduct {
+        constant_circuit: Some(constant_circuit),
+        circuits,
+        input,
+    };
+    let program = QuantumProgram::PauliZProduct {
+        measurement: measurement.clone(),
+        input_parameter_names: vec!["theta".to_string(), "theta2".to_string()],
+    };
+
+    let backend = RecordingBackend::default();
+    let result = program.run(backend.clone(), &[0.5, 1.0]).unwrap();
+    let substituted_measurement = measurement
+        .substitute_parameters(HashMap::from([
+            ("theta".to_string(), 0.5),
+            ("theta2".to_string(), 1.0),
+        ]))
+        .unwrap();
+    let expected_backend = RecordingBackend::default();
+    let expected = expected_backend
+        .run_measurement(&substituted_measurement)
+        .unwrap();
+    assert_eq!(result, expected);
+    assert_eq!(
+        *backend.circuits.borrow(),
+        *expected_backend.circuits.borrow()
+    );
+}
+
+/// Backend overriding run_measurement and run_measurement_registers and counting their calls
+#[derive(Debug, Clone, Default)]
+struct OverridingBackend {
+    calls: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl EvaluatingBackend for OverridingBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        _circuit: impl Iterator<Item = &'a operations::Operation>,
+    ) -> roqoqo::backends::RegisterResult {
+        self.calls.borrow_mut().push("run_circuit_iterator");
+        Ok((HashMap::new(), HashMap::new(), HashMap::new()))
+    }
+
+    fn run_measurement_registers<T>(&self, _measurement: &T) -> roqoqo::backends::RegisterResult
+    where
+        T: roqoqo::measurements::Measure,
+    {
+        self.calls.borrow_mut().push("run_measurement_registers");
+        Ok((HashMap::new(), HashMap::new(), HashMap::new()))
+    }
+
+    fn run_measurement<T>(
+        &self,
+        _measurement: &T,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoBackendError>
+    where
+        T: roqoqo::measurements::MeasureExpectationValues,
+    {
+        self.calls.borrow_mut().push("run_measurement");
+        Ok(Some(HashMap::from([("overridden".to_string(), 1.0)])))
+    }
+}
+
+/// Test that running a QuantumProgram dispatches to the run_measurement methods of the backend
+#[test]
+fn test_run_overridden_run_measurement() {
+    let (input, constant_circuit, circuits) = parametrized_circuits();
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            constant_circuit: Some(constant_circuit.clone()),
+            circuits: circuits.clone(),
+            input,
+        },
+        input_parameter_names: vec!["theta".to_string(), "theta2".to_string()],
+    };
+    let backend = OverridingBackend::default();
+    let result = program.run(backend.clone(), &[0.5, 1.0]).unwrap();
+    assert_eq!(
+        result,
+        Some(HashMap::from([("overridden".to_string(), 1.0)]))
+    );
+    assert_eq!(*backend.calls.borrow(), vec!["run_measurement"]);
+
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(constant_circuit),
+            circuits,
+        },
+        input_parameter_names: vec!["theta".to_string(), "theta2".to_string()],
+    };
+    let backend = OverridingBackend::default();
+    program.run_registers(backend.clone(), &[0.5, 1.0]).unwrap();
+    assert_eq!(*backend.calls.borrow(), vec!["run_measurement_registers"]);
+}
@@ -0,0 +1,180 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the counts dictionaries of bit registers and the json serialization of registers
+
+use num_complex::Complex64;
+use roqoqo::registers::{
+    counts, register_from_counts, registers_from_json, registers_to_json, registers_to_json_lossy,
+    BitOrder, BitOutputRegister, Registers,
+};
+use roqoqo::RoqoqoError;
+use std::collections::HashMap;
+use test_case::test_case;
+
+fn register() -> BitOutputRegister {
+    vec![
+        vec![true, false, false],
+        vec![true, false, false],
+        vec![true, true, false],
+        vec![false, false, true],
+    ]
+}
+
+fn counts_map(entries: &[(&str, usize)]) -> HashMap<String, usize> {
+    entries
+        .iter()
+        .map(|(bitstring, count)| (bitstring.to_string(), *count))
+        .collect()
+}
+
+/// Test counts for both bit orders
+#[test_case(BitOrder::MsbFirst, &[("001", 2), ("011", 1), ("100", 1)]; "msb_first")]
+#[test_case(BitOrder::LsbFirst, &[("100", 2), ("110", 1), ("001", 1)]; "lsb_first")]
+fn test_counts(bit_order: BitOrder, expected: &[(&str, usize)]) {
+    assert_eq!(counts(&register(), bit_order), counts_map(expected));
+}
+
+/// Test counts keep the bits of register entries that were not measured
+#[test]
+fn test_counts_more_bits_than_measured() {
+    // Register of length 4, only qubits 0 and 1 were measured
+    let register: BitOutputRegister = vec![
+        vec![true, false, false, false],
+        vec![true, true, false, false],
+        vec![true, true, false, false],
+    ];
+    assert_eq!(
+        counts(&register, BitOrder::MsbFirst),
+        counts_map(&[("0001", 1), ("0011", 2)])
+    );
+    assert_eq!(
+        counts(&register, BitOrder::LsbFirst),
+        counts_map(&[("1000", 1), ("1100", 2)])
+    );
+}
+
+/// Test register_from_counts is the inverse of counts for both bit orders
+#[test_case(BitOrder::MsbFirst; "msb_first")]
+#[test_case(BitOrder::LsbFirst; "lsb_first")]
+fn test_register_from_counts_roundtrip(bit_order: BitOrder) {
+    let counted = counts(&register(), bit_order);
+    let register = register_from_counts(&counted, 4, bit_order).unwrap();
+    assert_eq!(register.len(), 4);
+    assert_eq!(counts(&register, bit_order), counted);
+    assert_eq!(register[0].len(), 3);
+}
+
+/// Test register_from_counts scales counts to the number of shots
+#[test]
+fn test_register_from_counts_scaled() {
+    let register =
+        register_from_counts(&counts_map(&[("01", 2), ("10", 1)]), 7, BitOrder::MsbFirst).unwrap();
+    assert_eq!(register.len(), 7);
+    // 14/3 and 7/3 are rounded to 5 and 2 by the largest remainder
+    assert_eq!(
+        counts(&register, BitOrder::MsbFirst),
+        counts_map(&[("01", 5), ("10", 2)])
+    );
+    assert_eq!(register[0], vec![true, false]);
+}
+
+/// Test register_from_counts errors
+#[test_case(counts_map(&[("0a", 1)]), 1, RoqoqoError::GenericError { msg: "Bitstring 0a contains characters other than 0 and 1".to_string() }; "invalid_character")]
+#[test_case(counts_map(&[("01", 1), ("1", 1)]), 2, RoqoqoError::GenericError { msg: "Bitstrings have different lengths 2 and 1".to_string() }; "different_lengths")]
+#[test_case(counts_map(&[("01", 0)]), 2, RoqoqoError::GenericError { msg: "Cannot construct register with measurement records from zero counts".to_string() }; "zero_counts")]
+fn test_register_from_counts_error(
+    counts: HashMap<String, usize>,
+    number_shots: usize,
+    error: RoqoqoError,
+) {
+    assert_eq!(
+        register_from_counts(&counts, number_shots, BitOrder::MsbFirst),
+        Err(error)
+    );
+}
+
+fn registers(float_value: f64, complex_value: Complex64) -> Registers {
+    let mut bit_registers = HashMap::new();
+    bit_registers.insert("ro".to_string(), vec![vec![true, false]]);
+    let mut float_registers = HashMap::new();
+    float_registers.insert(
+        "ro_float".to_string(),
+        vec![vec![0.5], vec![1.0, float_value]],
+    );
+    let mut complex_registers = HashMap::new();
+    complex_registers.insert(
+        "ro_complex".to_string(),
+        vec![vec![complex_value, Complex64::new(1.0, 0.0)]],
+    );
+    (bit_registers, float_registers, complex_registers)
+}
+
+/// Test that registers with finite values are written identically by the strict and lossy serialization
+#[test]
+fn test_registers_json_round_trip() {
+    let registers = registers(2.0, Complex64::new(0.0, -1.0));
+    let json = registers_to_json(&registers).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"ro":[[true,false]]},{"ro_float":[[0.5],[1.0,2.0]]},{"ro_complex":[[[0.0,-1.0],[1.0,0.0]]]}]"#
+    );
+    assert_eq!(registers_to_json_lossy(&registers).unwrap(), json);
+    assert_eq!(registers_from_json(&json).unwrap(), registers);
+}
+
+/// Test that the lossy serialization writes values that are not finite as strings and reads them back
+#[test]
+fn test_registers_json_lossy_round_trip() {
+    let registers = registers(f64::NAN, Complex64::new(f64::INFINITY, f64::NEG_INFINITY));
+    let json = registers_to_json_lossy(&registers).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"ro":[[true,false]]},{"ro_float":[[0.5],[1.0,"NaN"]]},{"ro_complex":[[["Infinity","-Infinity"],[1.0,0.0]]]}]"#
+    );
+    let deserialized = registers_from_json(&json).unwrap();
+    assert_eq!(deserialized.0, registers.0);
+    assert_eq!(deserialized.1["ro_float"][0], vec![0.5]);
+    assert_eq!(deserialized.1["ro_float"][1][0], 1.0);
+    assert!(deserialized.1["ro_float"][1][1].is_nan());
+    assert_eq!(deserialized.2, registers.2);
+}
+
+/// Test that the strict serialization names the register, row and index of the first value that is not finite
+#[test_case(registers(f64::NAN, Complex64::new(0.0, 0.0)), "ro_float", 1, 1; "float_nan")]
+#[test_case(registers(f64::NEG_INFINITY, Complex64::new(0.0, 0.0)), "ro_float", 1, 1; "float_infinity")]
+#[test_case(registers(0.0, Complex64::new(0.0, f64::NAN)), "ro_complex", 0, 0; "complex_nan")]
+fn test_registers_to_json_non_finite(registers: Registers, name: &str, row: usize, index: usize) {
+    let error = registers_to_json(&registers).unwrap_err();
+    assert_eq!(
+        error,
+        RoqoqoError::NonFiniteRegisterValue {
+            name: name.to_string(),
+            row,
+            index
+        }
+    );
+    assert!(error.to_string().starts_with(&format!(
+        "Register {} contains a value that is not finite in row {} at index {}.",
+        name, row, index
+    )));
+}
+
+/// Test that deserializing registers fails for invalid strings
+#[test]
+fn test_registers_from_json_error() {
+    let result = registers_from_json(r#"[{},{"ro_float":[["nan"]]},{}]"#);
+    assert!(matches!(
+        result,
+        Err(RoqoqoError::SerializationError { .. })
+    ));
+}
@@ -0,0 +1,173 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::devices::GenericDevice;
+use roqoqo::operations;
+use roqoqo::schedule::{schedule_asap, ScheduledCircuit, TimelineEntry};
+use roqoqo::{Circuit, RoqoqoBackendError, RoqoqoError};
+
+fn reference_device() -> GenericDevice {
+    let mut device = GenericDevice::new(3);
+    for qubit in 0..3 {
+        device
+            .set_single_qubit_gate_time("Hadamard", qubit, 1.0)
+            .unwrap();
+        device
+            .set_single_qubit_gate_time("RotateZ", qubit, 0.5)
+            .unwrap();
+    }
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 3.0).unwrap();
+    device.set_two_qubit_gate_time("CNOT", 1, 2, 3.0).unwrap();
+    device
+}
+
+fn reference_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += operations::Hadamard::new(0);
+    circuit += operations::RotateZ::new(1, 1.0.into());
+    circuit += operations::CNOT::new(0, 1);
+    circuit += operations::PragmaSleep::new(vec![2], 2.0.into());
+    circuit += operations::CNOT::new(1, 2);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::Hadamard::new(2);
+    circuit
+}
+
+/// Test that ASAP scheduling starts operations after their predecessors in the dag
+#[test]
+fn schedule_asap_start_times() {
+    let scheduled = schedule_asap(&reference_circuit(), &reference_device()).unwrap();
+    assert_eq!(
+        scheduled.start_times(),
+        &[0.0, 0.0, 0.0, 1.0, 0.0, 4.0, 4.0, 7.0]
+    );
+    assert_eq!(scheduled.circuit, reference_circuit());
+    assert_eq!(scheduled.qubit_assignment, None);
+}
+
+/// Test that the total duration equals the critical path of the dag
+#[test]
+fn schedule_asap_total_duration_critical_path() {
+    let scheduled = schedule_asap(&reference_circuit(), &reference_device()).unwrap();
+    // Critical path: Hadamard(0) -> CNOT(0, 1) -> CNOT(1, 2) -> Hadamard(2)
+    let critical_path = 1.0 + 3.0 + 3.0 + 1.0;
+    assert_eq!(scheduled.total_duration(), critical_path);
+    assert_eq!(ScheduledCircuit::default().total_duration(), 0.0);
+}
+
+/// Test that a loop takes the duration of its circuit times its repetitions
+#[test]
+fn schedule_asap_loop() {
+    let mut inner = Circuit::new();
+    inner += operations::Hadamard::new(0);
+    inner += operations::CNOT::new(0, 1);
+    let mut circuit = Circuit::new();
+    circuit += operations::PragmaLoop::new(3.0.into(), inner.clone());
+    circuit += operations::Hadamard::new(2);
+    circuit += operations::CNOT::new(1, 2);
+    let scheduled = schedule_asap(&circuit, &reference_device()).unwrap();
+    assert_eq!(scheduled.durations, vec![12.0, 1.0, 3.0]);
+    assert_eq!(scheduled.start_times(), &[0.0, 0.0, 12.0]);
+    assert_eq!(scheduled.total_duration(), 15.0);
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::PragmaLoop::new("n".into(), inner);
+    assert_eq!(
+        schedule_asap(&circuit, &reference_device()),
+        Err(RoqoqoBackendError::RoqoqoError(
+            RoqoqoError::SymbolicRepetitions {
+                repetitions: "n".to_string(),
+                index: 1
+            }
+        ))
+    );
+}
+
+/// Test that scheduling fails for gates that are not available on the device
+#[test]
+fn schedule_asap_unavailable_gate() {
+    let mut circuit = reference_circuit();
+    circuit += operations::CNOT::new(0, 2);
+    let error = schedule_asap(&circuit, &reference_device());
+    assert_eq!(
+        error,
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Gate CNOT on qubits [0, 2] is not available on the device".to_string()
+        })
+    );
+}
+
+/// Test serialization and deserialization of a ScheduledCircuit
+#[cfg(feature = "serialize")]
+#[test]
+fn scheduled_circuit_serde() {
+    let mut scheduled = schedule_asap(&reference_circuit(), &reference_device()).unwrap();
+    scheduled.qubit_assignment = Some([(0, 2), (1, 0), (2, 1)].into_iter().collect());
+    let serialized = serde_json::to_string(&scheduled).unwrap();
+    let deserialized: ScheduledCircuit = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, scheduled);
+    let encoded = bincode::serialize(&scheduled).unwrap();
+    let decoded: ScheduledCircuit = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, scheduled);
+}
+
+/// Test that the exported timeline json has one entry per operation
+#[cfg(feature = "serialize")]
+#[test]
+fn timeline_json() {
+    let scheduled = schedule_asap(&reference_circuit(), &reference_device()).unwrap();
+    let json = scheduled.to_timeline_json();
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+    assert_eq!(entries.len(), reference_circuit().len());
+    assert_eq!(
+        entries[3],
+        serde_json::json!({"qubits": [0, 1], "hqslang": "CNOT", "start": 1.0, "duration": 3.0, "index": 3})
+    );
+    let timeline: Vec<TimelineEntry> = serde_json::from_str(&json).unwrap();
+    assert_eq!(timeline, scheduled.timeline());
+    assert!(timeline[0].qubits.is_empty());
+}
+
+/// Test that the operations of each qubit row do not overlap
+#[test]
+fn per_qubit_timeline_no_overlaps() {
+    let scheduled = schedule_asap(&reference_circuit(), &reference_device()).unwrap();
+    let rows = scheduled.per_qubit_timeline().unwrap();
+    assert_eq!(rows.keys().copied().collect::<Vec<usize>>(), vec![0, 1, 2]);
+    let indices: Vec<usize> = rows[&2].iter().map(|entry| entry.index).collect();
+    assert_eq!(indices, vec![4, 5, 7]);
+    for row in rows.values() {
+        for pair in row.windows(2) {
+            assert!(pair[0].start + pair[0].duration <= pair[1].start);
+        }
+    }
+}
+
+/// Test that overlapping operations on a qubit are reported
+#[test]
+fn per_qubit_timeline_overlap() {
+    let mut scheduled = schedule_asap(&reference_circuit(), &reference_device()).unwrap();
+    // Start CNOT(1, 2) while CNOT(0, 1) is still running
+    scheduled.start_times[5] = 3.5;
+    assert_eq!(
+        scheduled.per_qubit_timeline(),
+        Err(RoqoqoError::OverlappingOperations {
+            qubit: 1,
+            first_hqslang: "CNOT".to_string(),
+            first_index: 3,
+            second_hqslang: "CNOT".to_string(),
+            second_index: 5,
+        })
+    );
+}
@@ -0,0 +1,167 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for random circuit generation
+
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::operations::*;
+use roqoqo::prelude::*;
+use roqoqo::testing::{
+    random_circuit, random_clifford_circuit, random_pauli_measurement_input, CLIFFORD_GATES,
+    RANDOM_CIRCUIT_GATES,
+};
+use roqoqo::{Circuit, RoqoqoError};
+use std::collections::HashSet;
+use test_case::test_case;
+
+/// Checks that every operation acts on qubits below number_qubits
+fn check_qubits(circuit: &Circuit, number_qubits: usize) {
+    for operation in circuit.iter() {
+        match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => {
+                assert!(qubits.iter().all(|q| *q < number_qubits));
+            }
+            _ => panic!("Random circuit contains operation not acting on a set of qubits"),
+        }
+    }
+}
+
+/// Test that every supported gate is created with the expected number of qubits
+#[test]
+fn test_all_gates() {
+    for (name, n) in RANDOM_CIRCUIT_GATES {
+        let circuit = random_circuit(4, 3, 11, &[name]).unwrap();
+        assert!(!circuit.is_empty());
+        for operation in circuit.iter() {
+            assert_eq!(operation.hqslang(), *name);
+            match operation.involved_qubits() {
+                InvolvedQubits::Set(qubits) => assert_eq!(qubits.len(), *n),
+                _ => panic!("Gate {} does not act on a set of qubits", name),
+            }
+        }
+        check_qubits(&circuit, 4);
+    }
+}
+
+/// Test that random circuits are reproducible from the seed
+#[test_case(1, 5, 0; "single qubit")]
+#[test_case(5, 10, 1; "five qubits")]
+#[test_case(8, 4, 12345; "eight qubits")]
+fn test_random_circuit_determinism(number_qubits: usize, depth: usize, seed: u64) {
+    let gates: Vec<&str> = RANDOM_CIRCUIT_GATES.iter().map(|(name, _)| *name).collect();
+    let circuit = random_circuit(number_qubits, depth, seed, &gates).unwrap();
+    let repeated = random_circuit(number_qubits, depth, seed, &gates).unwrap();
+    assert_eq!(circuit, repeated);
+    let other = random_circuit(number_qubits, depth, seed + 1, &gates).unwrap();
+    assert_ne!(circuit, other);
+    check_qubits(&circuit, number_qubits);
+}
+
+/// Test that every qubit is used exactly once per layer when only single-qubit gates are used
+#[test]
+fn test_random_circuit_layers() {
+    let circuit = random_circuit(6, 4, 3, &["RotateX", "Hadamard", "SGate"]).unwrap();
+    assert_eq!(circuit.len(), 24);
+    let operations: Vec<&Operation> = circuit.iter().collect();
+    for layer in operations.chunks(6) {
+        let mut qubits: HashSet<usize> = HashSet::new();
+        for operation in layer {
+            if let InvolvedQubits::Set(involved) = operation.involved_qubits() {
+                qubits.extend(involved);
+            }
+        }
+        assert_eq!(qubits, (0..6).collect());
+    }
+}
+
+/// Test errors of random_circuit
+#[test]
+fn test_random_circuit_errors() {
+    assert_eq!(
+        random_circuit(2, 1, 0, &["RotateX", "NotAGate"]),
+        Err(RoqoqoError::UnknownOperation {
+            hqslang: "NotAGate".to_string()
+        })
+    );
+    assert_eq!(
+        random_circuit(2, 1, 0, &[]),
+        Err(RoqoqoError::GenericError {
+            msg: "Gate set of random circuit is empty".to_string()
+        })
+    );
+    assert_eq!(
+        random_circuit(2, 1, 0, &["Toffoli"]),
+        Err(RoqoqoError::GenericError {
+            msg: "No gate of the gate set acts on 2 or fewer qubits".to_string()
+        })
+    );
+    assert_eq!(random_circuit(0, 3, 0, &["CNOT"]), Ok(Circuit::new()));
+}
+
+/// Test that random Clifford circuits only contain Clifford gates and are reproducible
+#[test_case(1, 3, 0; "single qubit")]
+#[test_case(6, 8, 42; "six qubits")]
+fn test_random_clifford_circuit(number_qubits: usize, depth: usize, seed: u64) {
+    let circuit = random_clifford_circuit(number_qubits, depth, seed);
+    assert!(circuit
+        .iter()
+        .all(|operation| CLIFFORD_GATES.contains(&operation.hqslang())));
+    assert_eq!(circuit, random_clifford_circuit(number_qubits, depth, seed));
+    assert_ne!(
+        circuit,
+        random_clifford_circuit(number_qubits, depth, seed + 1)
+    );
+    check_qubits(&circuit, number_qubits);
+}
+
+/// Test that random PauliZProductInputs are reproducible and form valid measurements with random circuits
+#[test_case(1, 1, 0; "single qubit")]
+#[test_case(4, 6, 7; "four qubits")]
+#[test_case(10, 20, 99; "ten qubits")]
+fn test_random_pauli_measurement_input(number_qubits: usize, n_products: usize, seed: u64) {
+    let input = random_pauli_measurement_input(number_qubits, n_products, seed).unwrap();
+    assert_eq!(
+        input,
+        random_pauli_measurement_input(number_qubits, n_products, seed).unwrap()
+    );
+    assert_eq!(input.number_qubits, number_qubits);
+    assert!(input.number_pauli_products >= 1);
+    assert!(input.number_pauli_products <= n_products);
+    assert!(input.measured_exp_vals.contains_key("random"));
+
+    let mut circuit = random_clifford_circuit(number_qubits, 5, seed);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += DefinitionBit::new("ro".to_string(), number_qubits, true);
+    let measurement = PauliZProduct {
+        constant_circuit: Some(constant_circuit),
+        circuits: vec![circuit],
+        input,
+    };
+    assert_eq!(measurement.validate(), Ok(()));
+}
+
+/// Test errors of random_pauli_measurement_input
+#[test]
+fn test_random_pauli_measurement_input_errors() {
+    assert_eq!(
+        random_pauli_measurement_input(0, 1, 0),
+        Err(RoqoqoError::GenericError {
+            msg: "PauliZ products require at least one qubit".to_string()
+        })
+    );
+    let mut empty = PauliZProductInput::new(0, false);
+    empty
+        .add_linear_exp_val("random".to_string(), Default::default())
+        .unwrap();
+    assert_eq!(random_pauli_measurement_input(0, 0, 0), Ok(empty));
+}